@@ -20,6 +20,7 @@ use common_datavalues::IntervalKind;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::OrderByExpr;
 use crate::ast::Query;
 use crate::parser::token::Token;
 
@@ -131,6 +132,8 @@ pub enum Expr<'a> {
         name: Identifier<'a>,
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
+        /// Set if the function is called as a window function, like `ROW_NUMBER() OVER (...)`
+        window: Option<WindowSpec<'a>>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -181,6 +184,30 @@ pub enum Expr<'a> {
     },
 }
 
+/// `OVER ([PARTITION BY ...] [ORDER BY ...] [ROWS ...])`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowSpec<'a> {
+    pub partition_by: Vec<Expr<'a>>,
+    pub order_by: Vec<OrderByExpr<'a>>,
+    pub window_frame: Option<WindowFrame>,
+}
+
+/// `ROWS BETWEEN <start> AND <end>`, or `ROWS <start>` which ends at the current row
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFrame {
+    pub start_bound: WindowFrameBound,
+    pub end_bound: WindowFrameBound,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowFrameBound {
+    CurrentRow,
+    /// `<N> PRECEDING`, or `UNBOUNDED PRECEDING` if `None`
+    Preceding(Option<u64>),
+    /// `<N> FOLLOWING`, or `UNBOUNDED FOLLOWING` if `None`
+    Following(Option<u64>),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
     Integer(u64),
@@ -471,6 +498,54 @@ impl Display for TypeName {
     }
 }
 
+impl<'a> Display for WindowSpec<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        if !self.partition_by.is_empty() {
+            first = false;
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+        }
+        if !self.order_by.is_empty() {
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+        }
+        if let Some(frame) = &self.window_frame {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ROWS BETWEEN {} AND {}",
+            self.start_bound, self.end_bound
+        )
+    }
+}
+
+impl Display for WindowFrameBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Preceding(None) => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(Some(n)) => write!(f, "{n} PRECEDING"),
+            WindowFrameBound::Following(None) => write!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Following(Some(n)) => write!(f, "{n} FOLLOWING"),
+        }
+    }
+}
+
 impl Display for TrimWhere {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.write_str(match self {
@@ -657,6 +732,7 @@ impl<'a> Display for Expr<'a> {
                 name,
                 args,
                 params,
+                window,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -671,6 +747,9 @@ impl<'a> Display for Expr<'a> {
                 }
                 write_comma_separated_list(f, args)?;
                 write!(f, ")")?;
+                if let Some(window) = window {
+                    write!(f, " OVER ({window})")?;
+                }
             }
             Expr::Case {
                 operand,
@@ -26,6 +26,9 @@ use crate::parser::token::Token;
 pub struct Query<'a> {
     pub span: &'a [Token<'a>],
 
+    // `WITH` clause, the common table expressions visible to the query
+    pub with: Option<With<'a>>,
+
     // Set operator: SELECT or UNION / EXCEPT / INTERSECT
     pub body: SetExpr<'a>,
 
@@ -40,6 +43,20 @@ pub struct Query<'a> {
     pub format: Option<String>,
 }
 
+// `WITH [RECURSIVE] <cte> [, ...]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct With<'a> {
+    pub recursive: bool,
+    pub ctes: Vec<CTE<'a>>,
+}
+
+// A common table expression, like `t(a, b) AS (SELECT ...)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CTE<'a> {
+    pub alias: TableAlias<'a>,
+    pub query: Query<'a>,
+}
+
 // A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SetExpr<'a> {
@@ -66,6 +83,8 @@ pub enum SetOperator {
 pub struct SelectStmt<'a> {
     pub span: &'a [Token<'a>],
     pub distinct: bool,
+    // `DISTINCT ON` expressions, only the first row of each group is kept
+    pub distinct_on: Vec<Expr<'a>>,
     // Result set of current subquery
     pub select_list: Vec<SelectTarget<'a>>,
     // `FROM` clause, a list of table references.
@@ -118,8 +137,9 @@ pub enum Indirection<'a> {
 
 // Time Travel specification
 #[derive(Debug, Clone, PartialEq)]
-pub enum TimeTravelPoint {
+pub enum TimeTravelPoint<'a> {
     Snapshot(String),
+    Timestamp(Box<Expr<'a>>),
 }
 
 // A table name or a parenthesized subquery with an optional alias
@@ -131,7 +151,7 @@ pub enum TableReference<'a> {
         database: Option<Identifier<'a>>,
         table: Identifier<'a>,
         alias: Option<TableAlias<'a>>,
-        travel_point: Option<TimeTravelPoint>,
+        travel_point: Option<TimeTravelPoint<'a>>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
@@ -206,7 +226,7 @@ impl<'a> Display for TableAlias<'a> {
         write!(f, "{}", &self.name)?;
         if !self.columns.is_empty() {
             write!(f, " (")?;
-            write_period_separated_list(f, &self.columns)?;
+            write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
         Ok(())
@@ -228,8 +248,14 @@ impl<'a> Display for TableReference<'a> {
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
 
-                if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
-                    write!(f, " AT (SNAPSHOT => {sid})")?;
+                match travel_point {
+                    Some(TimeTravelPoint::Snapshot(sid)) => {
+                        write!(f, " AT (SNAPSHOT => {sid})")?;
+                    }
+                    Some(TimeTravelPoint::Timestamp(ts)) => {
+                        write!(f, " AT (TIMESTAMP => {ts})")?;
+                    }
+                    None => {}
                 }
 
                 if let Some(alias) = alias {
@@ -331,6 +357,11 @@ impl<'a> Display for SelectStmt<'a> {
         write!(f, "SELECT ")?;
         if self.distinct {
             write!(f, "DISTINCT ")?;
+            if !self.distinct_on.is_empty() {
+                write!(f, "ON (")?;
+                write_comma_separated_list(f, &self.distinct_on)?;
+                write!(f, ") ")?;
+            }
         }
         write_comma_separated_list(f, &self.select_list)?;
 
@@ -378,7 +409,7 @@ impl<'a> Display for SetExpr<'a> {
                 write!(f, "{left}")?;
                 match op {
                     SetOperator::Union => {
-                        write!(f, " UNION ")?;
+                        write!(f, " UNION")?;
                     }
                     SetOperator::Except => {
                         write!(f, " EXCEPT")?;
@@ -390,15 +421,36 @@ impl<'a> Display for SetExpr<'a> {
                 if *all {
                     write!(f, " ALL")?;
                 }
-                write!(f, "{right}")?;
+                write!(f, " {right}")?;
             }
         }
         Ok(())
     }
 }
 
+impl<'a> Display for With<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WITH ")?;
+        if self.recursive {
+            write!(f, "RECURSIVE ")?;
+        }
+        write_comma_separated_list(f, &self.ctes)
+    }
+}
+
+impl<'a> Display for CTE<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS ({})", self.alias, self.query)
+    }
+}
+
 impl<'a> Display for Query<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // WITH clause
+        if let Some(with) = &self.with {
+            write!(f, "{with} ")?;
+        }
+
         // Query body
         write!(f, "{}", self.body)?;
 
@@ -205,6 +205,9 @@ pub enum ExplainKind {
     Syntax,
     Graph,
     Pipeline,
+    Json,
+    Analyze,
+    Pruning,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -303,6 +306,7 @@ pub enum OptimizeTableAction {
     All,
     Purge,
     Compact,
+    Recluster,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -399,6 +403,9 @@ impl<'a> Display for Statement<'a> {
                     ExplainKind::Syntax => (),
                     ExplainKind::Graph => write!(f, " GRAPH")?,
                     ExplainKind::Pipeline => write!(f, " PIPELINE")?,
+                    ExplainKind::Json => write!(f, " (FORMAT JSON)")?,
+                    ExplainKind::Analyze => write!(f, " ANALYZE")?,
+                    ExplainKind::Pruning => write!(f, " (PRUNING)")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -618,6 +625,7 @@ impl<'a> Display for Statement<'a> {
                         OptimizeTableAction::All => write!(f, " ALL")?,
                         OptimizeTableAction::Purge => write!(f, " PURGE")?,
                         OptimizeTableAction::Compact => write!(f, " COMPACT")?,
+                        OptimizeTableAction::Recluster => write!(f, " RECLUSTER")?,
                     }
                 }
             }
@@ -286,6 +286,7 @@ pub enum ExprElement<'a> {
         name: Identifier<'a>,
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
+        window: Option<WindowSpec<'a>>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -451,12 +452,14 @@ impl<'a, I: Iterator<Item = WithSpan<'a>>> PrattParser<I> for ExprParser {
                 name,
                 args,
                 params,
+                window,
             } => Expr::FunctionCall {
                 span: elem.span.0,
                 distinct,
                 name,
                 args,
                 params,
+                window,
             },
             ExprElement::Case {
                 operand,
@@ -759,12 +762,14 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
             ~ DISTINCT?
             ~ #comma_separated_list0(subexpr(0))?
             ~ ")"
+            ~ ( OVER ~ "(" ~ #window_spec ~ ")" )?
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, opt_window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
+            window: opt_window.map(|(_, _, window, _)| window),
         },
     );
     let function_call_with_param = map(
@@ -778,6 +783,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan> {
             name,
             args: opt_args.unwrap_or_default(),
             params,
+            window: None,
         },
     );
     let case = map(
@@ -943,6 +949,73 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
     ))(i)
 }
 
+pub fn window_spec(i: Input) -> IResult<WindowSpec> {
+    map(
+        rule! {
+            ( PARTITION ~ ^BY ~ ^#comma_separated_list1(subexpr(0)) )?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
+            ~ #window_frame?
+        },
+        |(opt_partition_by, opt_order_by, window_frame)| WindowSpec {
+            partition_by: opt_partition_by
+                .map(|(_, _, exprs)| exprs)
+                .unwrap_or_default(),
+            order_by: opt_order_by.map(|(_, _, exprs)| exprs).unwrap_or_default(),
+            window_frame,
+        },
+    )(i)
+}
+
+pub fn window_frame(i: Input) -> IResult<WindowFrame> {
+    let between = map(
+        rule! {
+            ROWS ~ BETWEEN ~ ^#window_frame_bound ~ ^AND ~ ^#window_frame_bound
+        },
+        |(_, _, start_bound, _, end_bound)| WindowFrame {
+            start_bound,
+            end_bound,
+        },
+    );
+    let start_only = map(
+        rule! {
+            ROWS ~ ^#window_frame_bound
+        },
+        |(_, start_bound)| WindowFrame {
+            start_bound,
+            end_bound: WindowFrameBound::CurrentRow,
+        },
+    );
+
+    rule!(
+        #between
+        | #start_only
+    )(i)
+}
+
+pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
+    let current_row = value(WindowFrameBound::CurrentRow, rule! { CURRENT ~ ^ROW });
+    let unbounded_preceding = value(WindowFrameBound::Preceding(None), rule! {
+        UNBOUNDED ~ PRECEDING
+    });
+    let unbounded_following = value(WindowFrameBound::Following(None), rule! {
+        UNBOUNDED ~ ^FOLLOWING
+    });
+    let preceding = map(rule! { #literal_u64 ~ PRECEDING }, |(n, _)| {
+        WindowFrameBound::Preceding(Some(n))
+    });
+    let following = map(rule! { #literal_u64 ~ ^FOLLOWING }, |(n, _)| {
+        WindowFrameBound::Following(Some(n))
+    });
+
+    rule!(
+        #current_row
+        | #unbounded_preceding
+        | #unbounded_following
+        | #preceding
+        | #following
+    )(i)
+}
+
 pub fn literal(i: Input) -> IResult<Literal> {
     let string = map(literal_string, Literal::String);
     let integer = map(literal_u64, Literal::Integer);
@@ -25,6 +25,7 @@ use common_exception::Result;
 
 use self::error::DisplayError;
 use crate::ast::Expr;
+use crate::ast::Query;
 use crate::ast::Statement;
 use crate::parser::error::Backtrace;
 use crate::parser::statement::statements;
@@ -54,6 +55,23 @@ pub fn parse_sql<'a>(
     }
 }
 
+/// Parse a query, e.g. the body of a common table expression.
+pub fn parse_query<'a>(
+    sql_tokens: &'a [Token<'a>],
+    backtrace: &'a Backtrace<'a>,
+) -> Result<Query<'a>> {
+    match query::query(Input(sql_tokens, backtrace)) {
+        Ok((rest, query)) if rest[0].kind == TokenKind::EOI => Ok(query),
+        Ok((rest, _)) => Err(ErrorCode::SyntaxException(
+            rest[0].display_error("unable to parse rest of the sql".to_string()),
+        )),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(ErrorCode::SyntaxException(err.display_error(())))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
+}
+
 /// Parse udf function into Expr
 pub fn parse_expr<'a>(
     sql_tokens: &'a [Token<'a>],
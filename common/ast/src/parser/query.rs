@@ -26,17 +26,89 @@ use crate::rule;
 pub fn query(i: Input) -> IResult<Query> {
     map(
         consumed(rule! {
-            SELECT ~ DISTINCT? ~ #comma_separated_list1(select_target)
-            ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
-            ~ ( WHERE ~ ^#expr )?
-            ~ ( GROUP ~ ^BY ~ ^#comma_separated_list1(expr) )?
-            ~ ( HAVING ~ ^#expr )?
+            #with?
+            ~ #set_expr
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
             ~ ( LIMIT ~ ^#comma_separated_list1(expr) )?
             ~ ( OFFSET ~ ^#expr )?
             ~ ( FORMAT ~ #ident )?
             : "`SELECT ...`"
         }),
+        |(
+            span,
+            (with, body, opt_order_by_block, opt_limit_block, opt_offset_block, opt_format),
+        )| Query {
+            span: span.0,
+            with,
+            body,
+            order_by: opt_order_by_block
+                .map(|(_, _, order_by)| order_by)
+                .unwrap_or_default(),
+            limit: opt_limit_block.map(|(_, limit)| limit).unwrap_or_default(),
+            offset: opt_offset_block.map(|(_, offset)| offset),
+            format: opt_format.map(|(_, format)| format.name),
+        },
+    )(i)
+}
+
+pub fn with(i: Input) -> IResult<With> {
+    let cte = map(
+        rule! {
+            #ident ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ AS ~ ^"(" ~ ^#query ~ ^")"
+        },
+        |(name, opt_columns, _, _, query, _)| CTE {
+            alias: TableAlias {
+                name,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+            },
+            query,
+        },
+    );
+
+    map(
+        rule! {
+            WITH ~ RECURSIVE? ~ ^#comma_separated_list1(cte)
+        },
+        |(_, opt_recursive, ctes)| With {
+            recursive: opt_recursive.is_some(),
+            ctes,
+        },
+    )(i)
+}
+
+/// `SELECT ...` statements combined with `UNION [ALL]`, left associative.
+pub fn set_expr(i: Input) -> IResult<SetExpr> {
+    map(
+        rule! {
+            #select_stmt ~ ( UNION ~ ALL? ~ ^#select_stmt )*
+        },
+        |(first, others)| {
+            others.into_iter().fold(
+                SetExpr::Select(Box::new(first)),
+                |left, (_, opt_all, right)| SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all: opt_all.is_some(),
+                    left: Box::new(left),
+                    right: Box::new(SetExpr::Select(Box::new(right))),
+                },
+            )
+        },
+    )(i)
+}
+
+pub fn select_stmt(i: Input) -> IResult<SelectStmt> {
+    map(
+        consumed(rule! {
+            SELECT ~ ( DISTINCT ~ ( ON ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )? )?
+            ~ #comma_separated_list1(select_target)
+            ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
+            ~ ( WHERE ~ ^#expr )?
+            ~ ( GROUP ~ ^BY ~ ^#comma_separated_list1(expr) )?
+            ~ ( HAVING ~ ^#expr )?
+        }),
         |(
             span,
             (
@@ -47,35 +119,23 @@ pub fn query(i: Input) -> IResult<Query> {
                 opt_where_block,
                 opt_group_by_block,
                 opt_having_block,
-                opt_order_by_block,
-                opt_limit_block,
-                opt_offset_block,
-                opt_format,
             ),
-        )| {
-            Query {
-                span: span.0,
-                body: SetExpr::Select(Box::new(SelectStmt {
-                    // TODO(andylokandy): span should exclude order by
-                    span: span.0,
-                    distinct: opt_distinct.is_some(),
-                    select_list,
-                    from: opt_from_block
-                        .map(|(_, table_refs)| table_refs)
-                        .unwrap_or_default(),
-                    selection: opt_where_block.map(|(_, selection)| selection),
-                    group_by: opt_group_by_block
-                        .map(|(_, _, group_by)| group_by)
-                        .unwrap_or_default(),
-                    having: opt_having_block.map(|(_, having)| having),
-                })),
-                order_by: opt_order_by_block
-                    .map(|(_, _, order_by)| order_by)
-                    .unwrap_or_default(),
-                limit: opt_limit_block.map(|(_, limit)| limit).unwrap_or_default(),
-                offset: opt_offset_block.map(|(_, offset)| offset),
-                format: opt_format.map(|(_, format)| format.name),
-            }
+        )| SelectStmt {
+            span: span.0,
+            distinct: opt_distinct.is_some(),
+            distinct_on: opt_distinct
+                .and_then(|(_, opt_on)| opt_on)
+                .map(|(_, _, distinct_on, _)| distinct_on)
+                .unwrap_or_default(),
+            select_list,
+            from: opt_from_block
+                .map(|(_, table_refs)| table_refs)
+                .unwrap_or_default(),
+            selection: opt_where_block.map(|(_, selection)| selection),
+            group_by: opt_group_by_block
+                .map(|(_, _, group_by)| group_by)
+                .unwrap_or_default(),
+            having: opt_having_block.map(|(_, having)| having),
         },
     )(i)
 }
@@ -150,11 +210,22 @@ pub fn aliased_table(i: Input) -> IResult<TableReference> {
 }
 
 pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
-    map(
+    let at_snapshot = map(
         rule! {
             AT ~ "(" ~ SNAPSHOT ~ "=>" ~ #literal_string ~ ")"
         },
         |(_, _, _, _, s, _)| TimeTravelPoint::Snapshot(s),
+    );
+    let at_timestamp = map(
+        rule! {
+            AT ~ "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")"
+        },
+        |(_, _, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
+    );
+
+    rule!(
+        #at_snapshot
+        | #at_timestamp
     )(i)
 }
 
@@ -38,17 +38,20 @@ pub fn statements(i: Input) -> IResult<Vec<Statement>> {
 }
 
 pub fn statement(i: Input) -> IResult<Statement> {
+    let explain_kind = alt((
+        value(ExplainKind::Pipeline, rule! { PIPELINE }),
+        value(ExplainKind::Graph, rule! { GRAPH }),
+        value(ExplainKind::Analyze, rule! { ANALYZE }),
+        value(ExplainKind::Json, rule! { "(" ~ FORMAT ~ JSON ~ ")" }),
+        value(ExplainKind::Pruning, rule! { "(" ~ PRUNING ~ ")" }),
+        value(ExplainKind::Graph, rule! { "(" ~ FORMAT ~ GRAPH ~ ")" }),
+    ));
     let explain = map(
         rule! {
-            EXPLAIN ~ ( PIPELINE | GRAPH )? ~ #statement
+            EXPLAIN ~ #explain_kind? ~ #statement
         },
         |(_, opt_kind, statement)| Statement::Explain {
-            kind: match opt_kind.map(|token| token.kind) {
-                Some(TokenKind::PIPELINE) => ExplainKind::Pipeline,
-                Some(TokenKind::GRAPH) => ExplainKind::Graph,
-                None => ExplainKind::Syntax,
-                _ => unreachable!(),
-            },
+            kind: opt_kind.unwrap_or(ExplainKind::Syntax),
             query: Box::new(statement),
         },
     );
@@ -436,7 +439,7 @@ pub fn statement(i: Input) -> IResult<Statement> {
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
-            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT)`"
+            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT | RECLUSTER)`"
         ),
         rule!(
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
@@ -592,6 +595,7 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
         value(OptimizeTableAction::All, rule! { ALL }),
         value(OptimizeTableAction::Purge, rule! { PURGE }),
         value(OptimizeTableAction::Compact, rule! { COMPACT }),
+        value(OptimizeTableAction::Recluster, rule! { RECLUSTER }),
     ))(i)
 }
 
@@ -294,6 +294,8 @@ pub enum TokenKind {
     CROSS,
     #[token("CSV", ignore(ascii_case))]
     CSV,
+    #[token("CURRENT", ignore(ascii_case))]
+    CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
     #[token("DATABASE", ignore(ascii_case))]
@@ -360,6 +362,8 @@ pub enum TokenKind {
     FLOAT32,
     #[token("FLOAT64", ignore(ascii_case))]
     FLOAT64,
+    #[token("FOLLOWING", ignore(ascii_case))]
+    FOLLOWING,
     #[token("FOR", ignore(ascii_case))]
     FOR,
     #[token("FORMAT", ignore(ascii_case))]
@@ -480,10 +484,14 @@ pub enum TokenKind {
     ORDER,
     #[token("OUTER", ignore(ascii_case))]
     OUTER,
+    #[token("OVER", ignore(ascii_case))]
+    OVER,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PARTITION", ignore(ascii_case))]
+    PARTITION,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -492,16 +500,24 @@ pub enum TokenKind {
     PLAINTEXT_PASSWORD,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
+    #[token("PRECEDING", ignore(ascii_case))]
+    PRECEDING,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
+    #[token("PRUNING", ignore(ascii_case))]
+    PRUNING,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
     #[token("QUARTER", ignore(ascii_case))]
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("RECLUSTER", ignore(ascii_case))]
+    RECLUSTER,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
     RECORD_DELIMITER,
+    #[token("RECURSIVE", ignore(ascii_case))]
+    RECURSIVE,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
@@ -510,6 +526,10 @@ pub enum TokenKind {
     RIGHT,
     #[token("RLIKE", ignore(ascii_case))]
     RLIKE,
+    #[token("ROW", ignore(ascii_case))]
+    ROW,
+    #[token("ROWS", ignore(ascii_case))]
+    ROWS,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -584,8 +604,12 @@ pub enum TokenKind {
     UINT64,
     #[token("UINT8", ignore(ascii_case))]
     UINT8,
+    #[token("UNBOUNDED", ignore(ascii_case))]
+    UNBOUNDED,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNION", ignore(ascii_case))]
+    UNION,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -805,7 +829,7 @@ impl TokenKind {
             // | TokenKind::PRECISION
             // | TokenKind::RETURNING
             | TokenKind::TO
-            // | TokenKind::UNION
+            | TokenKind::UNION
             | TokenKind::WHERE
             // | TokenKind::WINDOW
             | TokenKind::WITH
@@ -913,7 +937,7 @@ impl TokenKind {
             // | TokenKind::OVERLAPS 
             // | TokenKind::RETURNING
             | TokenKind::TO
-            // | TokenKind::UNION
+            | TokenKind::UNION
             | TokenKind::WHERE
             // | TokenKind::WINDOW
             | TokenKind::WITH
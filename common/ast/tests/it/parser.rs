@@ -66,6 +66,7 @@ fn test_statement() {
         r#"show processlist;"#,
         r#"show create table a.b;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain (format json) select a from b;"#,
         r#"describe a;"#,
         r#"describe a; describe b"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -209,6 +210,7 @@ fn test_query() {
             group by c_count
             order by custdist desc, c_count asc, totacctbal
             limit 10, totacctbal"#,
+        r#"with recursive t(n) as (select 1 union all select n + 1 from t) select n from t"#,
     ];
 
     for case in cases {
@@ -291,6 +293,7 @@ fn test_expr() {
             AND l_shipinstruct = 'DELIVER IN PERSON'"#,
         r#"nullif(1, 1)"#,
         r#"nullif(a, b)"#,
+        r#"row_number() over (partition by a order by b desc rows between 1 preceding and current row)"#,
     ];
 
     for case in cases {
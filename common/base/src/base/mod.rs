@@ -15,6 +15,7 @@
 mod format;
 mod http_shutdown_handlers;
 mod net;
+mod numa;
 mod profiling;
 mod progress;
 mod runtime;
@@ -30,6 +31,8 @@ pub use format::Format;
 pub use http_shutdown_handlers::HttpShutdownHandler;
 pub use net::get_free_tcp_port;
 pub use net::get_free_udp_port;
+pub use numa::numa_nodes;
+pub use numa::parse_cpu_list;
 pub use profiling::Profiling;
 pub use progress::Progress;
 pub use progress::ProgressValues;
@@ -0,0 +1,68 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The cpus of each NUMA node. Machines without NUMA, or the platforms we cannot detect it on,
+/// are treated as a single node with all the cpus.
+pub fn numa_nodes() -> Vec<Vec<usize>> {
+    match detect_numa_nodes() {
+        Some(nodes) if !nodes.is_empty() => nodes,
+        _ => vec![(0..num_cpus()).collect()],
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_numa_nodes() -> Option<Vec<Vec<usize>>> {
+    let mut nodes = vec![];
+    for entry in std::fs::read_dir("/sys/devices/system/node").ok()? {
+        let entry = entry.ok()?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let node_id = match name.strip_prefix("node").map(str::parse::<usize>) {
+            Some(Ok(node_id)) => node_id,
+            _ => continue,
+        };
+
+        let cpu_list = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+        let cpus = parse_cpu_list(&cpu_list)?;
+        if !cpus.is_empty() {
+            nodes.push((node_id, cpus));
+        }
+    }
+
+    nodes.sort_by_key(|(node_id, _)| *node_id);
+    Some(nodes.into_iter().map(|(_, cpus)| cpus).collect())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn detect_numa_nodes() -> Option<Vec<Vec<usize>>> {
+    None
+}
+
+/// Parse the cpu list format of the kernel, e.g. `0-3,8-11,16`.
+pub fn parse_cpu_list(cpu_list: &str) -> Option<Vec<usize>> {
+    let mut cpus = vec![];
+    for range in cpu_list.trim().split(',').filter(|range| !range.is_empty()) {
+        match range.split_once('-') {
+            None => cpus.push(range.parse().ok()?),
+            Some((start, end)) => cpus.extend(start.parse::<usize>().ok()?..=end.parse().ok()?),
+        }
+    }
+
+    Some(cpus)
+}
+
+fn num_cpus() -> usize {
+    std::thread::available_parallelism()
+        .map(|parallelism| parallelism.get())
+        .unwrap_or(1)
+}
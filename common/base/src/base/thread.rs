@@ -51,4 +51,29 @@ impl Thread {
     {
         Self::named_spawn(None, f)
     }
+
+    /// Bind the current thread to the cpus, returns false if it failed or is not supported
+    /// on the platform.
+    #[cfg(target_os = "linux")]
+    pub fn bind_to_cpus(cpus: &[usize]) -> bool {
+        if cpus.is_empty() || cpus.iter().any(|cpu| *cpu >= libc::CPU_SETSIZE as usize) {
+            return false;
+        }
+
+        unsafe {
+            let mut cpu_set: libc::cpu_set_t = std::mem::zeroed();
+            libc::CPU_ZERO(&mut cpu_set);
+            for cpu in cpus {
+                libc::CPU_SET(*cpu, &mut cpu_set);
+            }
+
+            let size = std::mem::size_of::<libc::cpu_set_t>();
+            libc::sched_setaffinity(0, size, &cpu_set) == 0
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn bind_to_cpus(_cpus: &[usize]) -> bool {
+        false
+    }
 }
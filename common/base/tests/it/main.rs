@@ -16,6 +16,7 @@ mod derive;
 mod format;
 mod malloc_size;
 mod mutex;
+mod numa;
 mod pool;
 mod progress;
 mod range_key_test;
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::numa_nodes;
+use common_base::base::parse_cpu_list;
+
+#[test]
+fn test_parse_cpu_list() {
+    assert_eq!(parse_cpu_list("0"), Some(vec![0]));
+    assert_eq!(parse_cpu_list("0-3\n"), Some(vec![0, 1, 2, 3]));
+    assert_eq!(parse_cpu_list("0-1,8-9,16"), Some(vec![0, 1, 8, 9, 16]));
+    assert_eq!(parse_cpu_list(""), Some(vec![]));
+    assert_eq!(parse_cpu_list("0-a"), None);
+}
+
+#[test]
+fn test_numa_nodes() {
+    let nodes = numa_nodes();
+    assert!(!nodes.is_empty());
+    assert!(nodes.iter().all(|cpus| !cpus.is_empty()));
+}
@@ -78,10 +78,12 @@ impl Layer for DalContext {
 #[async_trait]
 impl Accessor for DalContext {
     async fn create(&self, args: &OpCreate) -> Result<()> {
+        self.metrics.inc_requests(1);
         self.get_inner()?.create(args).await
     }
 
     async fn read(&self, args: &OpRead) -> Result<BytesReader> {
+        self.metrics.inc_requests(1);
         let metric = self.metrics.clone();
 
         self.get_inner()?.read(args).await.map(|r| {
@@ -108,6 +110,7 @@ impl Accessor for DalContext {
     }
 
     async fn write(&self, args: &OpWrite) -> Result<BytesWriter> {
+        self.metrics.inc_requests(1);
         let metric = self.metrics.clone();
 
         self.get_inner()?.write(args).await.map(|w| {
@@ -134,14 +137,17 @@ impl Accessor for DalContext {
     }
 
     async fn stat(&self, args: &OpStat) -> Result<Metadata> {
+        self.metrics.inc_requests(1);
         self.get_inner()?.stat(args).await
     }
 
     async fn delete(&self, args: &OpDelete) -> Result<()> {
+        self.metrics.inc_requests(1);
         self.get_inner()?.delete(args).await
     }
 
     async fn list(&self, args: &OpList) -> Result<ObjectStreamer> {
+        self.metrics.inc_requests(1);
         self.get_inner()?.list(args).await
     }
 }
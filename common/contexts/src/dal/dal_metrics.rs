@@ -17,7 +17,8 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
-/// DalMetrics represents the metrics of a DAL (all bytes metrics are compressed size).
+/// DalMetrics represents the IO metrics of a query: the DAL, the caches, the exchange between
+/// nodes and the spill files (all bytes metrics are compressed size).
 #[derive(Clone, Debug, Default)]
 pub struct DalMetrics {
     /// Read bytes.
@@ -32,6 +33,16 @@ pub struct DalMetrics {
     partitions_scanned: Arc<AtomicU64>,
     /// Number of partitions, before pruning
     partitions_total: Arc<AtomicU64>,
+    /// Number of requests sent to the storage, e.g. the object store.
+    requests: Arc<AtomicU64>,
+    /// Number of objects read from the caches.
+    cache_hits: Arc<AtomicU64>,
+    /// Number of objects not found in the caches, which are loaded from the storage.
+    cache_misses: Arc<AtomicU64>,
+    /// Bytes received from the other nodes of the cluster.
+    network_received_bytes: Arc<AtomicUsize>,
+    /// Bytes written to the temporary storage, e.g. the spill files.
+    spill_bytes: Arc<AtomicUsize>,
 }
 
 impl DalMetrics {
@@ -94,4 +105,54 @@ impl DalMetrics {
     pub fn get_partitions_total(&self) -> u64 {
         self.partitions_total.load(Ordering::Relaxed)
     }
+
+    pub fn inc_requests(&self, v: u64) {
+        if v > 0 {
+            self.requests.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_requests(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_cache_hits(&self, v: u64) {
+        if v > 0 {
+            self.cache_hits.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_cache_hits(&self) -> u64 {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_cache_misses(&self, v: u64) {
+        if v > 0 {
+            self.cache_misses.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_cache_misses(&self) -> u64 {
+        self.cache_misses.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_network_received_bytes(&self, v: usize) {
+        if v > 0 {
+            self.network_received_bytes.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_network_received_bytes(&self) -> usize {
+        self.network_received_bytes.load(Ordering::Relaxed)
+    }
+
+    pub fn inc_spill_bytes(&self, v: usize) {
+        if v > 0 {
+            self.spill_bytes.fetch_add(v, Ordering::Relaxed);
+        }
+    }
+
+    pub fn get_spill_bytes(&self) -> usize {
+        self.spill_bytes.load(Ordering::Relaxed)
+    }
 }
@@ -25,16 +25,23 @@ use common_exception::Result;
 
 use crate::pretty_format_blocks;
 
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct DataBlock {
     schema: DataSchemaRef,
     columns: Vec<ColumnRef>,
+    // The position of the block in its source, used to restore the input order
+    // of the blocks processed in parallel. It's not a part of the block data.
+    sequence: Option<u64>,
 }
 
 impl DataBlock {
     #[inline]
     pub fn create(schema: DataSchemaRef, columns: Vec<ColumnRef>) -> Self {
-        DataBlock { schema, columns }
+        DataBlock {
+            schema,
+            columns,
+            sequence: None,
+        }
     }
 
     #[inline]
@@ -42,6 +49,7 @@ impl DataBlock {
         DataBlock {
             schema: Arc::new(DataSchema::empty()),
             columns: vec![],
+            sequence: None,
         }
     }
 
@@ -52,7 +60,11 @@ impl DataBlock {
             let col = f.data_type().create_column(&[]).unwrap();
             columns.push(col)
         }
-        DataBlock { schema, columns }
+        DataBlock {
+            schema,
+            columns,
+            sequence: None,
+        }
     }
 
     #[inline]
@@ -60,6 +72,17 @@ impl DataBlock {
         self.num_columns() == 0 || self.num_rows() == 0
     }
 
+    #[inline]
+    pub fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+
+    #[inline]
+    pub fn with_sequence(mut self, sequence: Option<u64>) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
     #[inline]
     pub fn schema(&self) -> &DataSchemaRef {
         &self.schema
@@ -220,6 +243,14 @@ impl TryFrom<DataBlock> for Chunk<ArrayRef> {
     }
 }
 
+impl PartialEq for DataBlock {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema == other.schema && self.columns == other.columns
+    }
+}
+
+impl Eq for DataBlock {}
+
 impl fmt::Debug for DataBlock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let formatted = pretty_format_blocks(&[self.clone()]).expect("Pretty format batches error");
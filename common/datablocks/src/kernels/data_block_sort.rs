@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 
@@ -146,6 +147,64 @@ impl DataBlock {
         Ok(DataBlock::create(lhs.schema().clone(), columns))
     }
 
+    /// Keep only the rows of `block` that would be placed before the last row of the
+    /// sorted `threshold` block, the others can not make it into a top-n result.
+    pub fn filter_by_sort_threshold(
+        block: &DataBlock,
+        threshold: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<DataBlock> {
+        if block.num_rows() == 0 || threshold.num_rows() == 0 {
+            return Ok(block.clone());
+        }
+
+        let sort_arrays = sort_columns_descriptions
+            .iter()
+            .map(|f| {
+                let column = block.try_column_by_name(&f.column_name)?.as_arrow_array();
+                let threshold = threshold
+                    .try_column_by_name(&f.column_name)?
+                    .as_arrow_array();
+                Ok(vec![column, threshold])
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let sort_dyn_arrays = sort_arrays
+            .iter()
+            .map(|f| vec![f[0].as_ref(), f[1].as_ref()])
+            .collect::<Vec<_>>();
+
+        let sort_options = sort_columns_descriptions
+            .iter()
+            .map(|f| arrow_sort::SortOptions {
+                descending: !f.asc,
+                nulls_first: f.nulls_first,
+            })
+            .collect::<Vec<_>>();
+
+        let sort_options_with_array = sort_dyn_arrays
+            .iter()
+            .zip(sort_options.iter())
+            .map(|(s, opt)| {
+                let paris: (&[&dyn Array], &SortOptions) = (s, opt);
+                paris
+            })
+            .collect::<Vec<_>>();
+
+        let comparator = build_comparator_impl(&sort_options_with_array, &build_compare)?;
+        let last = threshold.num_rows() - 1;
+        let indices = (0..block.num_rows())
+            .filter(|row| comparator(0, *row, 1, last) == Ordering::Less)
+            .map(|row| row as u32)
+            .collect::<Vec<_>>();
+
+        match indices.len() {
+            0 => Ok(DataBlock::empty_with_schema(block.schema().clone())),
+            len if len == block.num_rows() => Ok(block.clone()),
+            _ => DataBlock::block_take_by_indices(block, &indices),
+        }
+    }
+
     pub fn take_arrays_by_slices(
         arrays: &[&dyn Array],
         slices: &[MergeSlice],
@@ -375,3 +375,64 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_filter_by_sort_threshold() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    let raw = DataBlock::create(schema.clone(), vec![
+        Series::from_data(vec![6, 4, 3, 2, 1, 7]),
+        Series::from_data(vec!["b1", "b2", "b3", "b4", "b5", "b6"]),
+    ]);
+
+    let threshold = DataBlock::create(schema, vec![
+        Series::from_data(vec![1, 3]),
+        Series::from_data(vec!["t1", "t2"]),
+    ]);
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "a".to_owned(),
+            asc: true,
+            nulls_first: false,
+        }];
+        let results = DataBlock::filter_by_sort_threshold(&raw, &threshold, &options)?;
+        assert_eq!(raw.schema(), results.schema());
+
+        let expected = vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 2 | b4 |",
+            "| 1 | b5 |",
+            "+---+----+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "a".to_owned(),
+            asc: true,
+            nulls_first: false,
+        }];
+        let threshold = DataBlock::slice_block(&threshold, 0, 1);
+        let results = DataBlock::filter_by_sort_threshold(&raw, &threshold, &options)?;
+        assert!(results.is_empty());
+    }
+
+    {
+        let options = vec![SortColumnDescription {
+            column_name: "b".to_owned(),
+            asc: false,
+            nulls_first: false,
+        }];
+        let results = DataBlock::filter_by_sort_threshold(&raw, &threshold, &options)?;
+        assert_eq!(results.num_rows(), 0);
+    }
+
+    Ok(())
+}
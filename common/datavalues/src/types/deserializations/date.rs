@@ -110,14 +110,14 @@ where
     fn de_text_csv<R: BufferRead>(
         &mut self,
         reader: &mut R,
-        _format: &FormatSettings,
+        format: &FormatSettings,
     ) -> Result<()> {
-        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let maybe_quote = reader.ignore(|f| format.quote_char.contains(&f))?;
         let date = reader.read_date_text()?;
         let days = uniform(date);
         check_date(days.as_i32())?;
         if maybe_quote {
-            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+            reader.must_ignore(|f| format.quote_char.contains(&f))?;
         }
         self.builder.append_value(days);
         Ok(())
@@ -80,6 +80,20 @@ impl TypeDeserializer for NullableDeserializer {
         Ok(())
     }
 
+    fn de_text_csv<R: BufferRead>(
+        &mut self,
+        reader: &mut R,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        if reader.ignore_insensitive_bytes(b"null")? {
+            self.de_default(format);
+            return Ok(());
+        }
+        self.inner.de_text_csv(reader, format)?;
+        self.bitmap.push(true);
+        Ok(())
+    }
+
     fn de_text_quoted<R: BufferRead>(
         &mut self,
         reader: &mut R,
@@ -101,9 +101,9 @@ where
     fn de_text_csv<R: BufferRead>(
         &mut self,
         reader: &mut R,
-        _settings: &FormatSettings,
+        settings: &FormatSettings,
     ) -> Result<()> {
-        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let maybe_quote = reader.ignore(|f| settings.quote_char.contains(&f))?;
 
         let v: T = if !T::FLOATING {
             reader.read_int_text()
@@ -112,7 +112,7 @@ where
         }?;
 
         if maybe_quote {
-            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+            reader.must_ignore(|f| settings.quote_char.contains(&f))?;
         }
 
         self.builder.append_value(v);
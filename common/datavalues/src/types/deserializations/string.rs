@@ -114,7 +114,7 @@ impl TypeDeserializer for StringDeserializer {
         }
 
         let maybe_quote = read_buffer[0];
-        if maybe_quote == b'\'' || maybe_quote == b'"' {
+        if settings.quote_char.contains(&maybe_quote) {
             let mut index = 1;
             let mut bytes = 0;
 
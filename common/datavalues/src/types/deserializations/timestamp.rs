@@ -105,12 +105,12 @@ impl TypeDeserializer for TimestampDeserializer {
         reader: &mut R,
         format: &FormatSettings,
     ) -> Result<()> {
-        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let maybe_quote = reader.ignore(|f| format.quote_char.contains(&f))?;
         let ts = reader.read_timestamp_text(&format.timezone)?;
         let micros = ts.timestamp_micros();
         check_timestamp(micros)?;
         if maybe_quote {
-            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+            reader.must_ignore(|f| format.quote_char.contains(&f))?;
         }
         self.builder.append_value(micros.as_());
         Ok(())
@@ -371,3 +371,59 @@ pub fn merge_types(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<D
         }
     }
 }
+
+/// Coercion rule for implicitly casting a value of `from_type` to `target_type`,
+/// e.g. when an argument is passed to a function expecting `target_type`.
+///
+/// The nullability of `from_type` is propagated to the result. In strict mode only
+/// lossless numeric widening, date to timestamp, string to date/timestamp and variant casts
+/// are allowed, otherwise any numeric, string and datetime types can be casted to each other.
+pub fn implicit_cast_coercion(
+    from_type: &DataTypeImpl,
+    target_type: &DataTypeImpl,
+    strict: bool,
+) -> Result<DataTypeImpl> {
+    if from_type.data_type_id() == TypeID::Null {
+        return Ok(wrap_nullable(target_type));
+    }
+
+    if from_type.is_nullable() {
+        let from_type = remove_nullable(from_type);
+        let target_type = remove_nullable(target_type);
+        let cast_type = implicit_cast_coercion(&from_type, &target_type, strict)?;
+        return Ok(wrap_nullable(&cast_type));
+    }
+
+    let from_id = from_type.data_type_id();
+    let target_id = remove_nullable(target_type).data_type_id();
+
+    let allowed = if from_id == target_id {
+        true
+    } else if from_id.is_numeric() && target_id.is_numeric() {
+        !strict
+            || numerical_coercion(from_type, &remove_nullable(target_type), false)
+                .map_or(false, |t| t.data_type_id() == target_id)
+    } else if from_id.is_string() && target_id.is_date_or_date_time() {
+        true
+    } else if from_id == TypeID::Date && target_id == TypeID::Timestamp {
+        true
+    } else if from_id.is_variant() || target_id.is_variant() {
+        // The variant values are checked while casting.
+        true
+    } else {
+        let is_castable =
+            |id: TypeID| id.is_numeric() || id.is_string() || id.is_date_or_date_time();
+        !strict && is_castable(from_id) && is_castable(target_id)
+    };
+
+    if !allowed {
+        return Err(ErrorCode::IllegalDataType(format!(
+            "Can not implicitly cast {:?} to {:?}{}",
+            from_type,
+            target_type,
+            if strict { " in strict mode" } else { "" }
+        )));
+    }
+
+    Ok(target_type.clone())
+}
@@ -16,4 +16,5 @@ mod create_column;
 mod date_ts_bound;
 mod deserializations;
 mod serializations;
+mod type_coercion;
 mod viewer;
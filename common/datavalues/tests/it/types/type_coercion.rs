@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::implicit_cast_coercion;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_implicit_cast_coercion() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        from: DataTypeImpl,
+        target: DataTypeImpl,
+        strict: bool,
+        expect: Option<DataTypeImpl>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "numeric widening",
+            from: u8::to_data_type(),
+            target: i32::to_data_type(),
+            strict: true,
+            expect: Some(i32::to_data_type()),
+        },
+        Test {
+            name: "lossy numeric cast in strict mode",
+            from: i64::to_data_type(),
+            target: u64::to_data_type(),
+            strict: true,
+            expect: None,
+        },
+        Test {
+            name: "lossy numeric cast",
+            from: i64::to_data_type(),
+            target: u64::to_data_type(),
+            strict: false,
+            expect: Some(u64::to_data_type()),
+        },
+        Test {
+            name: "string to date in strict mode",
+            from: Vu8::to_data_type(),
+            target: DateType::new_impl(),
+            strict: true,
+            expect: Some(DateType::new_impl()),
+        },
+        Test {
+            name: "string to number in strict mode",
+            from: Vu8::to_data_type(),
+            target: f64::to_data_type(),
+            strict: true,
+            expect: None,
+        },
+        Test {
+            name: "number to string",
+            from: u8::to_data_type(),
+            target: Vu8::to_data_type(),
+            strict: false,
+            expect: Some(Vu8::to_data_type()),
+        },
+        Test {
+            name: "nullable propagation",
+            from: wrap_nullable(&u8::to_data_type()),
+            target: u64::to_data_type(),
+            strict: true,
+            expect: Some(wrap_nullable(&u64::to_data_type())),
+        },
+        Test {
+            name: "null",
+            from: NullType::new_impl(),
+            target: u64::to_data_type(),
+            strict: true,
+            expect: Some(wrap_nullable(&u64::to_data_type())),
+        },
+        Test {
+            name: "boolean to number",
+            from: bool::to_data_type(),
+            target: u64::to_data_type(),
+            strict: false,
+            expect: None,
+        },
+    ];
+
+    for test in tests {
+        let result = implicit_cast_coercion(&test.from, &test.target, test.strict);
+        match test.expect {
+            Some(expect) => assert_eq!(result?, expect, "{}", test.name),
+            None => assert!(result.is_err(), "{}", test.name),
+        }
+    }
+
+    Ok(())
+}
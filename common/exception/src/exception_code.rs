@@ -153,6 +153,9 @@ build_exceptions! {
 
     PanicError(1104),
 
+    // The limits of the query, such as max_result_rows.
+    QueryLimitExceeded(1105),
+
 }
 
 // Metasvr errors [2001, 3000].
@@ -167,6 +170,9 @@ build_exceptions! {
     OCCRetryFailure(2011),
     TableNotWritable(2012),
     TableHistoricalDataNotFound(2013),
+    TableSchemaMismatch(2014),
+    TransactionConflict(2015),
+    InvalidTransactionState(2016),
 
     // User api error codes.
     UnknownUser(2201),
@@ -190,6 +196,9 @@ build_exceptions! {
     DropTableWithDropTime(2314),
     DropDbWithDropTime(2315),
     UndropDbWithNoDropTime(2316),
+    CatalogIsReadOnly(2317),
+    CatalogReplicaLagging(2318),
+    TableHasDependentViews(2319),
 
     // Cluster error codes.
     ClusterUnknownNode(2401),
@@ -204,6 +213,7 @@ build_exceptions! {
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
+    UDFRuntimeError(2604),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -222,12 +232,26 @@ build_exceptions! {
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
+
+    // Password policy error codes.
+    IllegalPasswordPolicyFormat(2921),
+    InvalidPassword(2922),
+    PasswordPolicyUnknown(2923),
+
+    // Load error codes.
+    IllegalLoadInfoFormat(2951),
+    LoadAlreadyRunning(2952),
+    UnknownLoad(2953),
+
+    // Copy error codes.
+    IllegalCopiedFileInfoFormat(2961),
 }
 
 // Storage errors [3001, 4000].
 build_exceptions! {
     StorageNotFound(3001),
     StoragePermissionDenied(3002),
+    CorruptedBlock(3003),
     StorageUnavailable(3901),
     StorageOther(4000),
 }
@@ -80,6 +80,13 @@ impl AggregateFunctionDescription {
         }
     }
 
+    /// Introduce the function in brief, it's listed in `system.functions`.
+    #[must_use]
+    pub fn description(mut self, description: &'static str) -> AggregateFunctionDescription {
+        self.features.description = description;
+        self
+    }
+
     pub fn creator_with_features(
         creator: AggregateFunctionCreator,
         features: AggregateFunctionFeatures,
@@ -120,7 +127,10 @@ impl AggregateFunctionFactory {
         FACTORY.as_ref()
     }
 
-    pub fn register(&mut self, name: &str, desc: AggregateFunctionDescription) {
+    pub fn register(&mut self, name: &str, mut desc: AggregateFunctionDescription) {
+        if desc.features.category.is_empty() {
+            desc.features.category = "aggregate";
+        }
         let case_insensitive_desc = &mut self.case_insensitive_desc;
         case_insensitive_desc.insert(name.to_lowercase(), desc);
     }
@@ -233,12 +243,10 @@ impl AggregateFunctionFactory {
         self.case_insensitive_desc.keys().cloned().collect()
     }
 
-    pub fn registered_features(&self) -> Vec<AggregateFunctionFeatures> {
+    pub fn registered_features(&self) -> Vec<(String, AggregateFunctionFeatures)> {
         self.case_insensitive_desc
-            .values()
-            .into_iter()
-            .map(|v| &v.features)
-            .cloned()
+            .iter()
+            .map(|(name, desc)| (name.clone(), desc.features.clone()))
             .collect::<Vec<_>>()
     }
 }
@@ -33,26 +33,83 @@ pub struct Aggregators;
 impl Aggregators {
     pub fn register(factory: &mut AggregateFunctionFactory) {
         // DatabendQuery always uses lowercase function names to get functions.
-        factory.register("sum", aggregate_sum_function_desc());
-        factory.register("count", AggregateCountFunction::desc());
-        factory.register("avg", aggregate_avg_function_desc());
-        factory.register("min", aggregate_min_function_desc());
-        factory.register("max", aggregate_max_function_desc());
+        factory.register(
+            "sum",
+            aggregate_sum_function_desc().description("Returns the sum of the values."),
+        );
+        factory.register(
+            "count",
+            AggregateCountFunction::desc()
+                .description("Returns the number of rows, or of the non-NULL values."),
+        );
+        factory.register(
+            "avg",
+            aggregate_avg_function_desc().description("Returns the average of the values."),
+        );
+        factory.register(
+            "min",
+            aggregate_min_function_desc().description("Returns the minimum of the values."),
+        );
+        factory.register(
+            "max",
+            aggregate_max_function_desc().description("Returns the maximum of the values."),
+        );
 
-        factory.register("arg_min", aggregate_arg_min_function_desc());
-        factory.register("arg_max", aggregate_arg_max_function_desc());
+        factory.register(
+            "arg_min",
+            aggregate_arg_min_function_desc()
+                .description("Returns the value of arg at the row of the minimum val."),
+        );
+        factory.register(
+            "arg_max",
+            aggregate_arg_max_function_desc()
+                .description("Returns the value of arg at the row of the maximum val."),
+        );
 
-        factory.register("stddev", aggregate_stddev_pop_function_desc());
-        factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
-        factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register(
+            "stddev",
+            aggregate_stddev_pop_function_desc()
+                .description("Returns the population standard deviation of the values."),
+        );
+        factory.register(
+            "stddev_pop",
+            aggregate_stddev_pop_function_desc()
+                .description("Returns the population standard deviation of the values."),
+        );
+        factory.register(
+            "std",
+            aggregate_stddev_pop_function_desc()
+                .description("Returns the population standard deviation of the values."),
+        );
 
-        factory.register("covar_samp", aggregate_covariance_sample_desc());
-        factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register(
+            "covar_samp",
+            aggregate_covariance_sample_desc()
+                .description("Returns the sample covariance of the pairs of values."),
+        );
+        factory.register(
+            "covar_pop",
+            aggregate_covariance_population_desc()
+                .description("Returns the population covariance of the pairs of values."),
+        );
 
-        factory.register("window_funnel", aggregate_window_funnel_function_desc());
-        factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register(
+            "window_funnel",
+            aggregate_window_funnel_function_desc()
+                .description("Searches for event chains in a sliding time window."),
+        );
+        factory.register(
+            "uniq",
+            AggregateDistinctCombinator::uniq_desc()
+                .description("Returns the number of distinct values."),
+        );
 
-        factory.register("retention", aggregate_retention_function_desc());
+        factory.register(
+            "retention",
+            aggregate_retention_function_desc().description(
+                "Returns whether each of the conditions is met for the rows meeting the first one.",
+            ),
+        );
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
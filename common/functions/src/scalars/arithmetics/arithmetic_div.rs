@@ -27,8 +27,13 @@ use crate::scalars::FunctionFeatures;
 use crate::scalars::Monotonicity;
 
 #[inline]
-fn div_scalar(l: impl AsPrimitive<f64>, r: impl AsPrimitive<f64>, _ctx: &mut EvalContext) -> f64 {
-    l.as_() / r.as_()
+fn div_scalar(l: impl AsPrimitive<f64>, r: impl AsPrimitive<f64>, ctx: &mut EvalContext) -> f64 {
+    let r = r.as_();
+    if std::intrinsics::unlikely(ctx.strict && r == 0.0) {
+        ctx.set_error(ErrorCode::BadArguments("Division by zero"));
+        return 0.0;
+    }
+    l.as_() / r
 }
 
 pub struct ArithmeticDivFunction;
@@ -21,6 +21,7 @@ use common_datavalues::with_match_primitive_types_error;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use num::traits::AsPrimitive;
+use num_traits::CheckedSub;
 use num_traits::WrappingSub;
 
 use crate::scalars::BinaryArithmeticFunction;
@@ -41,12 +42,19 @@ where O: PrimitiveType + Sub<Output = O> {
 fn wrapping_sub_scalar<O>(
     l: impl AsPrimitive<O>,
     r: impl AsPrimitive<O>,
-    _ctx: &mut EvalContext,
+    ctx: &mut EvalContext,
 ) -> O
 where
-    O: IntegerType + WrappingSub<Output = O>,
+    O: IntegerType + WrappingSub<Output = O> + CheckedSub<Output = O>,
 {
-    l.as_().wrapping_sub(&r.as_())
+    let (l, r) = (l.as_(), r.as_());
+    if ctx.strict {
+        return l.checked_sub(&r).unwrap_or_else(|| {
+            ctx.set_error(ErrorCode::Overflow("Integer overflow in subtraction"));
+            O::default()
+        });
+    }
+    l.wrapping_sub(&r)
 }
 
 pub struct ArithmeticMinusFunction;
@@ -19,6 +19,7 @@ use common_datavalues::with_match_primitive_types_error;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use num::traits::AsPrimitive;
+use num_traits::CheckedMul;
 use num_traits::WrappingMul;
 
 use crate::scalars::BinaryArithmeticFunction;
@@ -38,12 +39,19 @@ where O: PrimitiveType + Mul<Output = O> {
 fn wrapping_mul_scalar<O>(
     l: impl AsPrimitive<O>,
     r: impl AsPrimitive<O>,
-    _ctx: &mut EvalContext,
+    ctx: &mut EvalContext,
 ) -> O
 where
-    O: IntegerType + WrappingMul<Output = O>,
+    O: IntegerType + WrappingMul<Output = O> + CheckedMul<Output = O>,
 {
-    l.as_().wrapping_mul(&r.as_())
+    let (l, r) = (l.as_(), r.as_());
+    if ctx.strict {
+        return l.checked_mul(&r).unwrap_or_else(|| {
+            ctx.set_error(ErrorCode::Overflow("Integer overflow in multiplication"));
+            O::default()
+        });
+    }
+    l.wrapping_mul(&r)
 }
 
 pub struct ArithmeticMulFunction;
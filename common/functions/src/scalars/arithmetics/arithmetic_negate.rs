@@ -19,6 +19,7 @@ use common_datavalues::with_match_primitive_types_error;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use num::traits::AsPrimitive;
+use num_traits::CheckedNeg;
 use num_traits::WrappingNeg;
 
 use crate::scalars::EvalContext;
@@ -33,9 +34,16 @@ where O: PrimitiveType + Neg<Output = O> {
     -l.as_()
 }
 
-fn wrapping_neg<O>(l: impl AsPrimitive<O>, _ctx: &mut EvalContext) -> O
-where O: IntegerType + WrappingNeg {
-    l.as_().wrapping_neg()
+fn wrapping_neg<O>(l: impl AsPrimitive<O>, ctx: &mut EvalContext) -> O
+where O: IntegerType + WrappingNeg + CheckedNeg {
+    let l = l.as_();
+    if ctx.strict {
+        return l.checked_neg().unwrap_or_else(|| {
+            ctx.set_error(ErrorCode::Overflow("Integer overflow in negation"));
+            O::default()
+        });
+    }
+    l.wrapping_neg()
 }
 
 pub struct ArithmeticNegateFunction;
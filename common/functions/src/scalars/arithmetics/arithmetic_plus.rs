@@ -21,6 +21,7 @@ use common_datavalues::with_match_primitive_types_error;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use num::traits::AsPrimitive;
+use num_traits::CheckedAdd;
 use num_traits::WrappingAdd;
 
 use crate::scalars::BinaryArithmeticFunction;
@@ -41,12 +42,19 @@ where O: PrimitiveType + Add<Output = O> {
 fn wrapping_add_scalar<O>(
     l: impl AsPrimitive<O>,
     r: impl AsPrimitive<O>,
-    _ctx: &mut EvalContext,
+    ctx: &mut EvalContext,
 ) -> O
 where
-    O: IntegerType + WrappingAdd<Output = O>,
+    O: IntegerType + WrappingAdd<Output = O> + CheckedAdd<Output = O>,
 {
-    l.as_().wrapping_add(&r.as_())
+    let (l, r) = (l.as_(), r.as_());
+    if ctx.strict {
+        return l.checked_add(&r).unwrap_or_else(|| {
+            ctx.set_error(ErrorCode::Overflow("Integer overflow in addition"));
+            O::default()
+        });
+    }
+    l.wrapping_add(&r)
 }
 
 pub struct ArithmeticPlusFunction;
@@ -76,7 +76,7 @@ where
 
     fn eval(
         &self,
-        _func_ctx: FunctionContext,
+        func_ctx: FunctionContext,
         columns: &ColumnsWithField,
         _input_rows: usize,
     ) -> Result<ColumnRef> {
@@ -84,7 +84,7 @@ where
             columns[0].column(),
             columns[1].column(),
             self.func.clone(),
-            &mut EvalContext::default(),
+            &mut EvalContext::from_function_context(&func_ctx),
         )?;
         Ok(Arc::new(col))
     }
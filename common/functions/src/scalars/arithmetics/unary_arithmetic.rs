@@ -71,14 +71,14 @@ where
 
     fn eval(
         &self,
-        _func_ctx: FunctionContext,
+        func_ctx: FunctionContext,
         columns: &ColumnsWithField,
         _input_rows: usize,
     ) -> Result<ColumnRef> {
         let col = scalar_unary_op(
             columns[0].column(),
             self.func.clone(),
-            &mut EvalContext::default(),
+            &mut EvalContext::from_function_context(&func_ctx),
         )?;
         Ok(Arc::new(col))
     }
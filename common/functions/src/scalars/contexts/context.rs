@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use crate::scalars::ConnectionIdFunction;
+use crate::scalars::CurrentRoleFunction;
 use crate::scalars::CurrentUserFunction;
 use crate::scalars::DatabaseFunction;
 use crate::scalars::FunctionFactory;
+use crate::scalars::LastQueryIdFunction;
 use crate::scalars::UserFunction;
 use crate::scalars::VersionFunction;
 
@@ -29,5 +31,7 @@ impl ContextFunction {
         factory.register("version", VersionFunction::desc());
         factory.register("current_user", CurrentUserFunction::desc());
         factory.register("user", UserFunction::desc());
+        factory.register("current_role", CurrentRoleFunction::desc());
+        factory.register("last_query_id", LastQueryIdFunction::desc());
     }
 }
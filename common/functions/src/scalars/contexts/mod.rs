@@ -14,14 +14,18 @@
 
 mod connection_id;
 mod context;
+mod current_role;
 mod current_user;
 mod database;
+mod last_query_id;
 mod user;
 mod version;
 
 pub use connection_id::ConnectionIdFunction;
 pub use context::ContextFunction;
+pub use current_role::CurrentRoleFunction;
 pub use current_user::CurrentUserFunction;
 pub use database::DatabaseFunction;
+pub use last_query_id::LastQueryIdFunction;
 pub use user::UserFunction;
 pub use version::VersionFunction;
@@ -314,7 +314,9 @@ pub fn arrow_cast_compute(
     }
 
     let arrow_array = column.as_arrow_array();
-    let arrow_options = cast_options.as_arrow();
+    let mut arrow_options = cast_options.as_arrow();
+    // The out of range numbers are NULLs instead of wrapping around in the strict mode.
+    arrow_options.wrapped = !func_ctx.strict;
     let result = cast::cast(arrow_array.as_ref(), &data_type.arrow_type(), arrow_options)?;
     let result: ArrayRef = Arc::from(result);
     let bitmap = result.validity().cloned();
@@ -15,12 +15,16 @@
 use chrono_tz::Tz;
 use common_exception::ErrorCode;
 
+use crate::scalars::FunctionContext;
+
 #[derive(Debug, Clone)]
 pub struct EvalContext {
     pub factor: i64,
     pub precision: usize,
     pub error: Option<ErrorCode>,
     pub tz: Tz,
+    /// See `FunctionContext::strict`
+    pub strict: bool,
 }
 
 impl Default for EvalContext {
@@ -31,6 +35,7 @@ impl Default for EvalContext {
             precision: 0,
             error: None,
             tz,
+            strict: false,
         }
     }
 }
@@ -42,6 +47,15 @@ impl EvalContext {
             precision,
             error,
             tz,
+            strict: false,
+        }
+    }
+
+    pub fn from_function_context(func_ctx: &FunctionContext) -> Self {
+        Self {
+            tz: func_ctx.tz,
+            strict: func_ctx.strict,
+            ..Default::default()
         }
     }
 
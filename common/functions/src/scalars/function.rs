@@ -23,16 +23,26 @@ use dyn_clone::DynClone;
 
 use super::Monotonicity;
 
-/// for now, this is only store Timezone
+/// The session states that the functions depend on when evaluating.
 #[derive(Clone)]
 pub struct FunctionContext {
     pub tz: Tz,
+    /// The strict SQL mode: integer overflow, division by zero and out of range casts
+    /// raise errors instead of wrapping around or producing infinities.
+    pub strict: bool,
+    /// The seed of the random functions, `None` seeds them from entropy.
+    pub random_seed: Option<u64>,
+    /// The position of the evaluated block in its source, see `DataBlock::sequence`.
+    pub block_sequence: Option<u64>,
 }
 
 impl Default for FunctionContext {
     fn default() -> Self {
         Self {
             tz: "UTC".parse::<Tz>().unwrap(),
+            strict: false,
+            random_seed: None,
+            block_sequence: None,
         }
     }
 }
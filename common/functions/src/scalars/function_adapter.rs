@@ -19,7 +19,9 @@ use common_arrow::arrow::bitmap::MutableBitmap;
 use common_datavalues::combine_validities;
 use common_datavalues::combine_validities_2;
 use common_datavalues::remove_nullable;
+use common_datavalues::type_coercion::implicit_cast_coercion;
 use common_datavalues::wrap_nullable;
+use common_datavalues::BooleanColumn;
 use common_datavalues::Column;
 use common_datavalues::ColumnRef;
 use common_datavalues::ColumnWithField;
@@ -38,12 +40,17 @@ use common_exception::Result;
 use super::Function;
 use super::FunctionDescription;
 use super::Monotonicity;
+use crate::scalars::cast_with_type;
 use crate::scalars::FunctionContext;
+use crate::scalars::DEFAULT_CAST_OPTIONS;
 
 #[derive(Clone)]
 pub struct FunctionAdapter {
     inner: Option<Box<dyn Function>>,
     has_nullable: bool,
+    skip_null_rows: bool,
+    // The non-nullable types the arguments are casted to, None if the argument is passed as is.
+    casts: Vec<Option<DataTypeImpl>>,
 }
 
 impl FunctionAdapter {
@@ -51,6 +58,8 @@ impl FunctionAdapter {
         Box::new(Self {
             inner: Some(inner),
             has_nullable,
+            skip_null_rows: false,
+            casts: vec![],
         })
     }
 
@@ -58,13 +67,38 @@ impl FunctionAdapter {
         desc: &FunctionDescription,
         name: &str,
         args: &[&DataTypeImpl],
+        strict: bool,
     ) -> Result<Box<dyn Function>> {
+        let mut casts = Vec::with_capacity(args.len());
+        let mut cast_args = Vec::with_capacity(args.len());
+        for (i, arg) in args.iter().enumerate() {
+            match desc.implicit_cast_arguments.get(i) {
+                Some(target) if arg.data_type_id() != TypeID::Null => {
+                    let cast_type = implicit_cast_coercion(arg, target, strict)?;
+                    let nonull_cast_type = remove_nullable(&cast_type);
+                    if remove_nullable(arg) != nonull_cast_type {
+                        casts.push(Some(nonull_cast_type));
+                    } else {
+                        casts.push(None);
+                    }
+                    cast_args.push(cast_type);
+                }
+                _ => {
+                    casts.push(None);
+                    cast_args.push((*arg).clone());
+                }
+            }
+        }
+        let args = cast_args.iter().collect::<Vec<_>>();
+
         let (inner, has_nullable) = if desc.features.passthrough_null {
             // one is null, result is null
             if args.iter().any(|v| v.data_type_id() == TypeID::Null) {
                 return Ok(Box::new(Self {
                     inner: None,
                     has_nullable: false,
+                    skip_null_rows: false,
+                    casts: vec![],
                 }));
             }
 
@@ -73,10 +107,19 @@ impl FunctionAdapter {
             let types = types.iter().collect::<Vec<_>>();
             ((desc.function_creator)(name, &types)?, has_nullable)
         } else {
-            ((desc.function_creator)(name, args)?, false)
+            ((desc.function_creator)(name, &args)?, false)
         };
 
-        Ok(Self::create(inner, has_nullable))
+        if casts.iter().all(|v| v.is_none()) {
+            casts.clear();
+        }
+
+        Ok(Box::new(Self {
+            inner: Some(inner),
+            has_nullable,
+            skip_null_rows: desc.features.skip_null_rows,
+            casts,
+        }))
     }
 }
 
@@ -110,6 +153,68 @@ impl Function for FunctionAdapter {
             return Ok(Arc::new(NullColumn::new(input_rows)));
         }
 
+        if !self.casts.is_empty() {
+            let columns = self.cast_columns(&func_ctx, columns)?;
+            return self.eval_with_nullable(func_ctx, &columns, input_rows);
+        }
+
+        self.eval_with_nullable(func_ctx, columns, input_rows)
+    }
+
+    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
+        self.inner
+            .as_ref()
+            .map_or(Ok(Monotonicity::create_constant()), |v| {
+                v.get_monotonicity(args)
+            })
+    }
+
+    fn passthrough_constant(&self) -> bool {
+        self.inner
+            .as_ref()
+            .map_or(true, |v| v.passthrough_constant())
+    }
+}
+
+impl FunctionAdapter {
+    fn cast_columns(
+        &self,
+        func_ctx: &FunctionContext,
+        columns: &ColumnsWithField,
+    ) -> Result<Vec<ColumnWithField>> {
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, v)| match self.casts.get(i) {
+                Some(Some(target)) => {
+                    let target = if v.data_type().is_nullable() {
+                        wrap_nullable(target)
+                    } else {
+                        target.clone()
+                    };
+                    let col = cast_with_type(
+                        v.column(),
+                        v.data_type(),
+                        &target,
+                        &DEFAULT_CAST_OPTIONS,
+                        func_ctx,
+                    )?;
+                    Ok(ColumnWithField::new(
+                        col,
+                        DataField::new(v.field().name(), target),
+                    ))
+                }
+                _ => Ok(v.clone()),
+            })
+            .collect()
+    }
+
+    fn eval_with_nullable(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
         let inner = self.inner.as_ref().unwrap();
         if columns.is_empty() {
             return inner.eval(func_ctx, columns, input_rows);
@@ -128,7 +233,7 @@ impl Function for FunctionAdapter {
                 })
                 .collect::<Vec<_>>();
 
-            let col = self.eval(func_ctx, &columns, 1)?;
+            let col = self.eval_with_nullable(func_ctx, &columns, 1)?;
             let col = if col.is_const() && col.len() != input_rows {
                 col.replicate(&[input_rows])
             } else if col.is_null() {
@@ -164,7 +269,12 @@ impl Function for FunctionAdapter {
                 input.push(col);
             }
 
-            let col = self.eval(func_ctx, &input, input_rows)?;
+            let col = match &validity {
+                Some(v) if self.skip_null_rows && v.null_count() > 0 => {
+                    self.eval_valid_rows(func_ctx, &input, v, input_rows)?
+                }
+                _ => self.eval_with_nullable(func_ctx, &input, input_rows)?,
+            };
 
             // The'try' series functions always return Null when they failed the try.
             // For example, try_inet_aton("helloworld") will return Null because it failed to parse "helloworld" to a valid IP address.
@@ -194,18 +304,41 @@ impl Function for FunctionAdapter {
         inner.eval(func_ctx, columns, input_rows)
     }
 
-    fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
-        self.inner
-            .as_ref()
-            .map_or(Ok(Monotonicity::create_constant()), |v| {
-                v.get_monotonicity(args)
-            })
-    }
+    // Evaluate the function on the valid rows only, and expand the result to all rows.
+    // The values of the null rows in the result are taken from their previous valid rows,
+    // they are masked out by the validity afterwards.
+    fn eval_valid_rows(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        validity: &Bitmap,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let valid_rows = input_rows - validity.null_count();
+        if valid_rows == 0 {
+            let inner_type = remove_nullable(&self.inner.as_ref().unwrap().return_type());
+            return inner_type.create_constant_column(&inner_type.default_value(), input_rows);
+        }
 
-    fn passthrough_constant(&self) -> bool {
-        self.inner
-            .as_ref()
-            .map_or(true, |v| v.passthrough_constant())
+        let filter = BooleanColumn::from_arrow_data(validity.clone());
+        let input = columns
+            .iter()
+            .map(|v| ColumnWithField::new(v.column().filter(&filter), v.field().clone()))
+            .collect::<Vec<_>>();
+        let col = self.eval_with_nullable(func_ctx, &input, valid_rows)?;
+
+        // offsets[i] is the end of the rows replicated from the i-th valid row,
+        // i.e. the index of the next valid row.
+        let mut offsets = validity
+            .iter()
+            .enumerate()
+            .filter(|(_, valid)| *valid)
+            .skip(1)
+            .map(|(row, _)| row)
+            .collect::<Vec<_>>();
+        offsets.push(input_rows);
+
+        Ok(col.replicate(&offsets))
     }
 }
 
@@ -15,10 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 
 use super::commons::CommonFunction;
 use super::function::Function;
@@ -45,6 +47,8 @@ pub type FactoryCreator =
 pub struct FunctionDescription {
     pub(crate) features: FunctionFeatures,
     pub(crate) function_creator: FactoryCreator,
+    // The types the arguments are implicitly casted to before creating the function.
+    pub(crate) implicit_cast_arguments: Vec<DataTypeImpl>,
 }
 
 impl FunctionDescription {
@@ -52,6 +56,7 @@ impl FunctionDescription {
         FunctionDescription {
             function_creator: creator,
             features: FunctionFeatures::default(),
+            implicit_cast_arguments: vec![],
         }
     }
 
@@ -60,30 +65,49 @@ impl FunctionDescription {
         self.features = features;
         self
     }
+
+    /// Cast the arguments to the given types by the coercion rules before calling the function,
+    /// the arguments beyond the given types are passed as is.
+    #[must_use]
+    pub fn implicit_cast_arguments(mut self, types: Vec<DataTypeImpl>) -> FunctionDescription {
+        self.implicit_cast_arguments = types;
+        self
+    }
+
+    /// Introduce the function in brief, it's listed in `system.functions`.
+    #[must_use]
+    pub fn description(mut self, description: &'static str) -> FunctionDescription {
+        self.features = self.features.description(description);
+        self
+    }
 }
 
 pub struct FunctionFactory {
     case_insensitive_desc: HashMap<String, FunctionDescription>,
+    // The functions created at runtime (e.g. WASM user-defined functions), they are not builtin.
+    user_desc: RwLock<HashMap<String, Arc<FunctionDescription>>>,
 }
 
 static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     let mut function_factory = FunctionFactory::create();
 
-    ArithmeticFunction::register(&mut function_factory);
-    CommonFunction::register(&mut function_factory);
-    ToCastFunction::register(&mut function_factory);
-    TupleClassFunction::register(&mut function_factory);
-    ComparisonFunction::register(&mut function_factory);
-    ContextFunction::register(&mut function_factory);
-    SemiStructuredFunction::register(&mut function_factory);
-    StringFunction::register(&mut function_factory);
-    HashesFunction::register(&mut function_factory);
-    ConditionalFunction::register(&mut function_factory);
-    LogicFunction::register(&mut function_factory);
-    DateFunction::register(&mut function_factory);
-    OtherFunction::register(&mut function_factory);
-    UUIDFunction::register(&mut function_factory);
-    MathsFunction::register(&mut function_factory);
+    // The categories are named as the function documents, e.g. `numeric` for the functions
+    // documented in `20-numeric-functions`.
+    function_factory.register_category("arithmetic", ArithmeticFunction::register);
+    function_factory.register_category("string", CommonFunction::register);
+    function_factory.register_category("conversion", ToCastFunction::register);
+    function_factory.register_category("tuple", TupleClassFunction::register);
+    function_factory.register_category("comparison", ComparisonFunction::register);
+    function_factory.register_category("context", ContextFunction::register);
+    function_factory.register_category("semi-structured", SemiStructuredFunction::register);
+    function_factory.register_category("string", StringFunction::register);
+    function_factory.register_category("hash", HashesFunction::register);
+    function_factory.register_category("conditional", ConditionalFunction::register);
+    function_factory.register_category("logic", LogicFunction::register);
+    function_factory.register_category("datetime", DateFunction::register);
+    function_factory.register_category("other", OtherFunction::register);
+    function_factory.register_category("uuid", UUIDFunction::register);
+    function_factory.register_category("numeric", MathsFunction::register);
 
     Arc::new(function_factory)
 });
@@ -92,6 +116,7 @@ impl FunctionFactory {
     pub(in crate::scalars::function_factory) fn create() -> FunctionFactory {
         FunctionFactory {
             case_insensitive_desc: Default::default(),
+            user_desc: Default::default(),
         }
     }
 
@@ -99,24 +124,74 @@ impl FunctionFactory {
         FUNCTION_FACTORY.as_ref()
     }
 
-    pub fn register(&mut self, name: &str, desc: FunctionDescription) {
+    pub fn register(&mut self, name: &str, mut desc: FunctionDescription) {
+        desc.features.argument_types = desc
+            .implicit_cast_arguments
+            .iter()
+            .map(|data_type| data_type.name())
+            .collect();
         let case_insensitive_desc = &mut self.case_insensitive_desc;
         case_insensitive_desc.insert(name.to_lowercase(), desc);
     }
 
+    /// Register a family of functions, the functions without a category are of the given one.
+    pub fn register_category(
+        &mut self,
+        category: &'static str,
+        register: fn(&mut FunctionFactory),
+    ) {
+        let mut family = FunctionFactory::create();
+        register(&mut family);
+
+        for (name, mut desc) in family.case_insensitive_desc {
+            if desc.features.category.is_empty() {
+                desc.features = desc.features.category(category);
+            }
+            self.case_insensitive_desc.insert(name, desc);
+        }
+    }
+
+    /// Register a function created at runtime, the builtin functions take precedence over it.
+    pub fn register_user_function(&self, name: &str, desc: FunctionDescription) {
+        let mut user_desc = self.user_desc.write();
+        user_desc.insert(name.to_lowercase(), Arc::new(desc));
+    }
+
+    pub fn check_user_function(&self, name: impl AsRef<str>) -> bool {
+        let lowercase_name = name.as_ref().to_lowercase();
+        self.user_desc.read().contains_key(&lowercase_name)
+    }
+
     pub fn get(&self, name: impl AsRef<str>, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        self.get_with_coercion(name, args, false)
+    }
+
+    /// Get the function with the implicit casts of the arguments checked by the strict coercion rules
+    /// if `strict` is true, see `implicit_cast_coercion`.
+    pub fn get_with_coercion(
+        &self,
+        name: impl AsRef<str>,
+        args: &[&DataTypeImpl],
+        strict: bool,
+    ) -> Result<Box<dyn Function>> {
         let origin_name = name.as_ref();
         let lowercase_name = origin_name.to_lowercase();
 
+        if let Some(desc) = self.case_insensitive_desc.get(&lowercase_name) {
+            return FunctionAdapter::try_create(desc, origin_name, args, strict);
+        }
+
         let desc = self
-            .case_insensitive_desc
+            .user_desc
+            .read()
             .get(&lowercase_name)
+            .cloned()
             .ok_or_else(|| {
                 // TODO(Winter): we should write similar function names into error message if function name is not found.
                 ErrorCode::UnknownFunction(format!("Unsupported Function: {}", origin_name))
             })?;
 
-        FunctionAdapter::try_create(desc, origin_name, args)
+        FunctionAdapter::try_create(&desc, origin_name, args, strict)
     }
 
     pub fn get_features(&self, name: impl AsRef<str>) -> Result<FunctionFeatures> {
@@ -148,12 +223,10 @@ impl FunctionFactory {
             .collect::<Vec<_>>()
     }
 
-    pub fn registered_features(&self) -> Vec<FunctionFeatures> {
+    pub fn registered_features(&self) -> Vec<(String, FunctionFeatures)> {
         self.case_insensitive_desc
-            .values()
-            .into_iter()
-            .map(|v| &v.features)
-            .cloned()
+            .iter()
+            .map(|(name, desc)| (name.clone(), desc.features.clone()))
             .collect::<Vec<_>>()
     }
 }
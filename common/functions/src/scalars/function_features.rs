@@ -14,6 +14,9 @@
 
 use serde::Serialize;
 
+// Variadic functions accepting more optional arguments are shown as `...`.
+const MAX_LISTED_OPTIONAL_ARGUMENTS: usize = 8;
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct FunctionFeatures {
     pub is_deterministic: bool,
@@ -31,11 +34,26 @@ pub struct FunctionFeatures {
     /// While IS_NULL function  treats null input as a valid one. For example IS_NULL(NULL, 'test') will return 'test'.
     pub passthrough_null: bool,
 
+    /// Whether the function is only evaluated on the rows without null input, it takes effect
+    /// with passthrough_null.
+    ///
+    /// The values behind the nulls are the default values of the types, which may be invalid input
+    /// for the function. For example, the empty string is not a valid IPv4 address for INET_ATON,
+    /// so the rows with null input are filtered out before evaluating it.
+    pub skip_null_rows: bool,
+
     // The number of arguments the function accepts.
     pub num_arguments: usize,
     // (1, 2) means we only accept [1, 2] arguments
     // None means it's not variadic function.
     pub variadic_arguments: Option<(usize, usize)>,
+
+    // Function category, listed in `system.functions`.
+    pub category: &'static str,
+    // Introduce the function in brief.
+    pub description: &'static str,
+    // The types of the leading arguments, if the function casts them implicitly.
+    pub argument_types: Vec<String>,
 }
 
 impl FunctionFeatures {
@@ -46,8 +64,12 @@ impl FunctionFeatures {
             is_context_func: false,
             maybe_monotonic: false,
             passthrough_null: true,
+            skip_null_rows: false,
             num_arguments: 0,
             variadic_arguments: None,
+            category: "",
+            description: "",
+            argument_types: vec![],
         }
     }
 
@@ -76,6 +98,11 @@ impl FunctionFeatures {
         self
     }
 
+    pub fn skip_null_rows(mut self) -> FunctionFeatures {
+        self.skip_null_rows = true;
+        self
+    }
+
     pub fn variadic_arguments(mut self, min: usize, max: usize) -> FunctionFeatures {
         self.variadic_arguments = Some((min, max));
         self
@@ -85,4 +112,51 @@ impl FunctionFeatures {
         self.num_arguments = num_arguments;
         self
     }
+
+    pub fn category(mut self, category: &'static str) -> FunctionFeatures {
+        self.category = category;
+        self
+    }
+
+    pub fn description(mut self, description: &'static str) -> FunctionFeatures {
+        self.description = description;
+        self
+    }
+
+    /// The argument signature derived from the arity and the argument types of the function.
+    ///
+    /// For example `(arg1 UInt64, arg2 String)` for a function accepting two arguments,
+    /// `(arg1 UInt64[, arg2 String])` for one accepting [1, 2] arguments and
+    /// `(arg1 UInt64, ...)` for one accepting any number of arguments from one.
+    /// Empty if the types of the arguments are not declared.
+    pub fn arguments_signature(&self) -> String {
+        let (min, max) = self
+            .variadic_arguments
+            .unwrap_or((self.num_arguments, self.num_arguments));
+
+        let listed = match max - min > MAX_LISTED_OPTIONAL_ARGUMENTS {
+            true => min,
+            false => max,
+        };
+        if self.argument_types.len() < listed {
+            return "".to_string();
+        }
+
+        let argument = |i: usize| format!("arg{} {}", i, self.argument_types[i - 1]);
+
+        let required = (1..=min).map(argument).collect::<Vec<_>>();
+        let mut signature = required.join(", ");
+
+        if max > min {
+            let separator = if min == 0 { "" } else { ", " };
+            if max - min > MAX_LISTED_OPTIONAL_ARGUMENTS {
+                signature.push_str(&format!("{}...", separator));
+            } else {
+                let optional = (min + 1..=max).map(argument).collect::<Vec<_>>();
+                signature.push_str(&format!("[{}{}]", separator, optional.join(", ")));
+            }
+        }
+
+        format!("({})", signature)
+    }
 }
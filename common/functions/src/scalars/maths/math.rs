@@ -31,6 +31,8 @@ use crate::scalars::PiFunction;
 use crate::scalars::PowFunction;
 use crate::scalars::RadiansFunction;
 use crate::scalars::RandomFunction;
+use crate::scalars::RandomNormalFunction;
+use crate::scalars::RandomStringFunction;
 use crate::scalars::SignFunction;
 use crate::scalars::SqrtFunction;
 use crate::scalars::TrigonometricAcosFunction;
@@ -48,36 +50,146 @@ pub struct MathsFunction;
 
 impl MathsFunction {
     pub fn register(factory: &mut FunctionFactory) {
-        factory.register("abs", AbsFunction::desc());
-        factory.register("sign", SignFunction::desc());
-        factory.register("pi", PiFunction::desc());
-        factory.register("crc32", CRC32Function::desc());
-        factory.register("exp", ExpFunction::desc());
-        factory.register("sqrt", SqrtFunction::desc());
-        factory.register("ceil", CeilFunction::desc());
-        factory.register("ceiling", CeilFunction::desc());
-        factory.register("floor", FloorFunction::desc());
+        factory.register(
+            "abs",
+            AbsFunction::desc().description("Returns the absolute value of x."),
+        );
+        factory.register(
+            "sign",
+            SignFunction::desc().description("Returns the sign of x as -1, 0 or 1."),
+        );
+        factory.register(
+            "pi",
+            PiFunction::desc().description("Returns the value of π."),
+        );
+        factory.register(
+            "crc32",
+            CRC32Function::desc().description("Returns the CRC32 checksum of x."),
+        );
+        factory.register(
+            "exp",
+            ExpFunction::desc().description("Returns the value of e raised to the power of x."),
+        );
+        factory.register(
+            "sqrt",
+            SqrtFunction::desc().description("Returns the square root of x."),
+        );
+        factory.register(
+            "ceil",
+            CeilFunction::desc().description("Rounds x up to the nearest integer."),
+        );
+        factory.register(
+            "ceiling",
+            CeilFunction::desc().description("Rounds x up to the nearest integer."),
+        );
+        factory.register(
+            "floor",
+            FloorFunction::desc().description("Rounds x down to the nearest integer."),
+        );
 
-        factory.register("log", LogFunction::desc());
-        factory.register("log10", Log10Function::desc());
-        factory.register("log2", Log2Function::desc());
-        factory.register("ln", LnFunction::desc());
-        factory.register("pow", PowFunction::desc());
-        factory.register("power", PowFunction::desc());
-        factory.register("rand", RandomFunction::desc());
-        factory.register("round", RoundNumberFunction::desc());
-        factory.register("truncate", TruncNumberFunction::desc());
+        factory.register(
+            "log",
+            LogFunction::desc().description(
+                "Returns the logarithm of x, the natural logarithm if the base is not given.",
+            ),
+        );
+        factory.register(
+            "log10",
+            Log10Function::desc().description("Returns the base-10 logarithm of x."),
+        );
+        factory.register(
+            "log2",
+            Log2Function::desc().description("Returns the base-2 logarithm of x."),
+        );
+        factory.register(
+            "ln",
+            LnFunction::desc().description("Returns the natural logarithm of x."),
+        );
+        factory.register(
+            "pow",
+            PowFunction::desc().description("Returns the value of x raised to the power of y."),
+        );
+        factory.register(
+            "power",
+            PowFunction::desc().description("Returns the value of x raised to the power of y."),
+        );
+        factory.register(
+            "rand",
+            RandomFunction::desc()
+                .description("Returns a random floating-point value in the range [0, 1)."),
+        );
+        factory.register(
+            "random",
+            RandomFunction::desc()
+                .description("Returns a random floating-point value in the range [0, 1)."),
+        );
+        factory.register(
+            "random_normal",
+            RandomNormalFunction::desc()
+                .description("Returns a random value of the normal distribution."),
+        );
+        factory.register(
+            "random_string",
+            RandomStringFunction::desc()
+                .description("Returns a random string of the given length."),
+        );
+        factory.register(
+            "round",
+            RoundNumberFunction::desc()
+                .description("Rounds x to the given number of decimal places."),
+        );
+        factory.register(
+            "truncate",
+            TruncNumberFunction::desc()
+                .description("Truncates x to the given number of decimal places."),
+        );
 
-        factory.register("sin", TrigonometricSinFunction::desc());
-        factory.register("cos", TrigonometricCosFunction::desc());
-        factory.register("tan", TrigonometricTanFunction::desc());
-        factory.register("cot", TrigonometricCotFunction::desc());
-        factory.register("asin", TrigonometricAsinFunction::desc());
-        factory.register("acos", TrigonometricAcosFunction::desc());
-        factory.register("atan", TrigonometricAtanFunction::desc());
-        factory.register("atan2", TrigonometricAtan2Function::desc());
+        factory.register(
+            "sin",
+            TrigonometricSinFunction::desc()
+                .description("Returns the sine of x, where x is given in radians."),
+        );
+        factory.register(
+            "cos",
+            TrigonometricCosFunction::desc()
+                .description("Returns the cosine of x, where x is given in radians."),
+        );
+        factory.register(
+            "tan",
+            TrigonometricTanFunction::desc()
+                .description("Returns the tangent of x, where x is given in radians."),
+        );
+        factory.register(
+            "cot",
+            TrigonometricCotFunction::desc()
+                .description("Returns the cotangent of x, where x is given in radians."),
+        );
+        factory.register(
+            "asin",
+            TrigonometricAsinFunction::desc().description("Returns the arc sine of x."),
+        );
+        factory.register(
+            "acos",
+            TrigonometricAcosFunction::desc().description("Returns the arc cosine of x."),
+        );
+        factory.register(
+            "atan",
+            TrigonometricAtanFunction::desc().description(
+                "Returns the arc tangent of x, or of y / x if two arguments are given.",
+            ),
+        );
+        factory.register(
+            "atan2",
+            TrigonometricAtan2Function::desc().description("Returns the arc tangent of y / x."),
+        );
 
-        factory.register("degrees", DegressFunction::desc());
-        factory.register("radians", RadiansFunction::desc());
+        factory.register(
+            "degrees",
+            DegressFunction::desc().description("Converts x from radians to degrees."),
+        );
+        factory.register(
+            "radians",
+            RadiansFunction::desc().description("Converts x from degrees to radians."),
+        );
     }
 }
@@ -42,6 +42,8 @@ pub use math::MathsFunction;
 pub use pi::PiFunction;
 pub use pow::PowFunction;
 pub use random::RandomFunction;
+pub use random::RandomNormalFunction;
+pub use random::RandomStringFunction;
 pub use round::RoundNumberFunction;
 pub use round::TruncNumberFunction;
 pub use sign::SignFunction;
@@ -17,11 +17,15 @@ use std::sync::Arc;
 
 use common_datavalues::prelude::*;
 use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use num_traits::AsPrimitive;
+use rand::distributions::Alphanumeric;
 use rand::prelude::*;
+use rand::rngs::SmallRng;
 
 use crate::scalars::assert_numeric;
+use crate::scalars::default_column_cast;
 use crate::scalars::scalar_unary_op;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
@@ -29,6 +33,23 @@ use crate::scalars::FunctionContext;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
 
+/// The longest string RANDOM_STRING is allowed to generate.
+const MAX_RANDOM_STRING_LENGTH: u64 = 1024 * 1024;
+
+/// Create the generator of one evaluation. With the session `random_seed` the
+/// generator is seeded from it and the position of the block in its source, so
+/// that the blocks of a query get different values, which are the same whatever
+/// order the blocks are evaluated in.
+fn create_rng(func_ctx: &FunctionContext) -> SmallRng {
+    match func_ctx.random_seed {
+        None => SmallRng::from_entropy(),
+        Some(seed) => {
+            let sequence = func_ctx.block_sequence.unwrap_or_default();
+            SmallRng::seed_from_u64(seed ^ sequence.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct RandomFunction {
     display_name: String,
@@ -61,13 +82,13 @@ impl Function for RandomFunction {
 
     fn eval(
         &self,
-        _func_ctx: FunctionContext,
+        func_ctx: FunctionContext,
         columns: &ColumnsWithField,
         input_rows: usize,
     ) -> Result<ColumnRef> {
         match columns.len() {
             0 => {
-                let mut rng = rand::rngs::SmallRng::from_entropy();
+                let mut rng = create_rng(&func_ctx);
                 Ok(Float64Column::from_owned_iterator(
                     (0..input_rows).into_iter().map(|_| rng.gen::<f64>()),
                 )
@@ -96,3 +117,148 @@ impl fmt::Display for RandomFunction {
         write!(f, "{}", self.display_name)
     }
 }
+
+/// RANDOM_NORMAL([mean, stddev]): normally distributed values, the standard
+/// normal distribution by default.
+#[derive(Clone)]
+pub struct RandomNormalFunction {
+    display_name: String,
+}
+
+impl RandomNormalFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        if args.len() == 1 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function {} expects 0 or 2 arguments, but got 1",
+                display_name
+            )));
+        }
+        for arg in args {
+            assert_numeric(*arg)?;
+        }
+        Ok(Box::new(RandomNormalFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().variadic_arguments(0, 2))
+    }
+}
+
+impl Function for RandomNormalFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        Float64Type::new_impl()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let mut rng = create_rng(&func_ctx);
+        let mut standard_normal = move || {
+            // Box-Muller transform, 1 - x keeps the logarithm away from zero.
+            let u1 = 1.0 - rng.gen::<f64>();
+            let u2 = rng.gen::<f64>();
+            (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+        };
+
+        if columns.is_empty() {
+            return Ok(Float64Column::from_owned_iterator(
+                (0..input_rows).into_iter().map(|_| standard_normal()),
+            )
+            .arc());
+        }
+
+        let mean = default_column_cast(columns[0].column(), &Float64Type::new_impl())?;
+        let stddev = default_column_cast(columns[1].column(), &Float64Type::new_impl())?;
+        let mean = f64::try_create_viewer(&mean)?;
+        let stddev = f64::try_create_viewer(&stddev)?;
+
+        Ok(Float64Column::from_owned_iterator(
+            (0..input_rows)
+                .into_iter()
+                .map(|row| mean.value_at(row) + stddev.value_at(row) * standard_normal()),
+        )
+        .arc())
+    }
+}
+
+impl fmt::Display for RandomNormalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// RANDOM_STRING(length): random alphanumeric strings of the given length.
+#[derive(Clone)]
+pub struct RandomStringFunction {
+    display_name: String,
+}
+
+impl RandomStringFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        assert_numeric(args[0])?;
+        Ok(Box::new(RandomStringFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().num_arguments(1))
+    }
+}
+
+impl Function for RandomStringFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        StringType::new_impl()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let lengths = default_column_cast(columns[0].column(), &UInt64Type::new_impl())?;
+        let lengths = u64::try_create_viewer(&lengths)?;
+
+        let mut rng = create_rng(&func_ctx);
+        let mut builder = MutableStringColumn::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let length = lengths.value_at(row);
+            if length > MAX_RANDOM_STRING_LENGTH {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The length of {} must be at most {}, but got {}",
+                    self.display_name, MAX_RANDOM_STRING_LENGTH, length
+                )));
+            }
+
+            let value = (&mut rng)
+                .sample_iter(Alphanumeric)
+                .take(length as usize)
+                .collect::<Vec<u8>>();
+            builder.append_value(value);
+        }
+
+        Ok(builder.to_column())
+    }
+}
+
+impl fmt::Display for RandomStringFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -33,6 +33,8 @@ mod semi_structureds;
 mod strings;
 mod tuples;
 mod uuids;
+#[cfg(feature = "wasm-udf")]
+mod wasm;
 
 pub use arithmetics::*;
 pub use commons::*;
@@ -55,3 +57,5 @@ pub use semi_structureds::*;
 pub use strings::*;
 pub use tuples::*;
 pub use uuids::*;
+#[cfg(feature = "wasm-udf")]
+pub use wasm::*;
@@ -47,8 +47,12 @@ impl<const SUPPRESS_PARSE_ERROR: bool> InetAtonFunctionImpl<SUPPRESS_PARSE_ERROR
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default().deterministic().num_arguments(1))
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .deterministic()
+                .skip_null_rows()
+                .num_arguments(1),
+        )
     }
 }
 
@@ -77,14 +81,12 @@ impl<const SUPPRESS_PARSE_ERROR: bool> Function for InetAtonFunctionImpl<SUPPRES
         if SUPPRESS_PARSE_ERROR {
             let mut builder = NullableColumnBuilder::<u32>::with_capacity(input_rows);
 
-            for (i, input) in viewer_iter.enumerate() {
-                // This is arguably correct because the address parsing is not optimized by SIMD, not quite sure how much we can gain from skipping branch prediction.
-                // Think about the case if we have 1000 rows and 999 are Nulls.
+            for input in viewer_iter {
                 let addr_str = String::from_utf8_lossy(input);
                 match addr_str.parse::<Ipv4Addr>() {
                     Ok(addr) => {
                         let addr_binary: u32 = u32::from(addr);
-                        builder.append(addr_binary, viewer.valid_at(i));
+                        builder.append(addr_binary, true);
                     }
                     Err(_) => builder.append_null(),
                 }
@@ -92,7 +94,7 @@ impl<const SUPPRESS_PARSE_ERROR: bool> Function for InetAtonFunctionImpl<SUPPRES
             return Ok(builder.build(input_rows));
         }
 
-        // We skip the null check because the function has passthrough_null is true.
+        // The rows with null input are skipped by the adapter, so an error is returned only on invalid addresses.
         let mut builder = ColumnBuilder::<u32>::with_capacity(input_rows);
         for input in viewer_iter {
             let addr_str = String::from_utf8_lossy(input);
@@ -19,14 +19,10 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
-use crate::scalars::assert_numeric;
-use crate::scalars::assert_string;
-use crate::scalars::cast_with_type;
 use crate::scalars::Function;
 use crate::scalars::FunctionContext;
 use crate::scalars::FunctionDescription;
 use crate::scalars::FunctionFeatures;
-use crate::scalars::DEFAULT_CAST_OPTIONS;
 
 #[derive(Clone)]
 pub struct ExportSetFunction {
@@ -34,30 +30,26 @@ pub struct ExportSetFunction {
 }
 
 impl ExportSetFunction {
-    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
-        assert_numeric(args[0])?;
-        assert_string(args[1])?;
-        assert_string(args[2])?;
-
-        if args.len() >= 4 {
-            assert_string(args[3])?;
-        }
-
-        if args.len() >= 5 {
-            assert_numeric(args[4])?;
-        }
-
+    pub fn try_create(display_name: &str, _args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
         Ok(Box::new(Self {
             display_name: display_name.to_string(),
         }))
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create)).features(
-            FunctionFeatures::default()
-                .deterministic()
-                .variadic_arguments(3, 5),
-        )
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(
+                FunctionFeatures::default()
+                    .deterministic()
+                    .variadic_arguments(3, 5),
+            )
+            .implicit_cast_arguments(vec![
+                u64::to_data_type(),
+                Vu8::to_data_type(),
+                Vu8::to_data_type(),
+                Vu8::to_data_type(),
+                u64::to_data_type(),
+            ])
     }
 }
 
@@ -72,7 +64,7 @@ impl Function for ExportSetFunction {
 
     fn eval(
         &self,
-        func_ctx: FunctionContext,
+        _func_ctx: FunctionContext,
         columns: &ColumnsWithField,
         input_rows: usize,
     ) -> Result<ColumnRef> {
@@ -88,22 +80,7 @@ impl Function for ExportSetFunction {
             ConstColumn::new(Series::from_data(vec![64u64]), input_rows).arc()
         };
 
-        let t = u64::to_data_type();
-        let number_bits_column = cast_with_type(
-            &number_bits_column,
-            &number_bits_column.data_type(),
-            &t,
-            &DEFAULT_CAST_OPTIONS,
-            &func_ctx,
-        )?;
-
-        let bits_column = cast_with_type(
-            columns[0].column(),
-            &columns[0].column().data_type(),
-            &t,
-            &DEFAULT_CAST_OPTIONS,
-            &func_ctx,
-        )?;
+        let bits_column = columns[0].column();
 
         if input_rows != 1
             && (!number_bits_column.is_const() || !bits_column.is_const() || !sep_col.is_const())
@@ -0,0 +1,288 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_primitive_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::ExternType;
+use wasmtime::Instance;
+use wasmtime::Memory;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+use wasmtime::TypedFunc;
+use wasmtime::Val;
+use wasmtime::ValType;
+
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// The resources one call of a WASM function may consume.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WasmLimits {
+    pub fuel_per_row: u64,
+    pub max_memory_bytes: usize,
+}
+
+/// A user-defined function implemented by a WebAssembly module.
+///
+/// The module runs in a sandbox without any host imports and has to export:
+/// - `memory`: the linear memory the columns are exchanged through.
+/// - `alloc(size: i32) -> i32`: allocate `size` bytes and return the offset.
+/// - `<function name>(arg_ptr_1: i32, ..., arg_ptr_n: i32, out_ptr: i32, rows: i32)`: read `rows`
+///   packed little-endian values from each argument buffer and write `rows` results to `out_ptr`.
+///
+/// Only numeric arguments and results are supported, NULLs never reach the module.
+/// Every call instantiates the module in a new store, which is limited to `fuel_per_row * rows`
+/// fuel and `max_memory_bytes` of linear memory.
+#[derive(Clone)]
+pub struct WasmFunction {
+    display_name: String,
+    export_name: String,
+    engine: Engine,
+    module: Module,
+    arg_types: Vec<DataTypeImpl>,
+    return_type: DataTypeImpl,
+    limits: WasmLimits,
+}
+
+impl WasmFunction {
+    /// Compile the module and check it implements the ABI of the function.
+    ///
+    /// The module is either WAT text or base64 encoded binary.
+    pub fn try_create_desc(
+        name: &str,
+        module: &str,
+        arg_types: Vec<DataTypeImpl>,
+        return_type: DataTypeImpl,
+        limits: WasmLimits,
+    ) -> Result<FunctionDescription> {
+        for data_type in arg_types.iter().chain(std::iter::once(&return_type)) {
+            if !data_type.data_type_id().is_numeric() {
+                return Err(ErrorCode::IllegalUDFFormat(format!(
+                    "WASM function {} only supports numeric types, but got {}",
+                    name,
+                    data_type.name()
+                )));
+            }
+        }
+
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| {
+            ErrorCode::UDFRuntimeError(format!("Cannot create WASM engine, cause: {}", e))
+        })?;
+
+        let bytes = match base64::decode(module.trim()) {
+            Ok(bytes) if bytes.starts_with(WASM_MAGIC) => bytes,
+            _ => module.as_bytes().to_vec(),
+        };
+        let module = Module::new(&engine, &bytes).map_err(|e| {
+            ErrorCode::IllegalUDFFormat(format!(
+                "Cannot compile WASM module of function {}, cause: {}",
+                name, e
+            ))
+        })?;
+
+        Self::check_abi(name, &module, arg_types.len())?;
+
+        let num_arguments = arg_types.len();
+        let function = WasmFunction {
+            display_name: name.to_string(),
+            export_name: name.to_string(),
+            engine,
+            module,
+            arg_types: arg_types.clone(),
+            return_type,
+            limits,
+        };
+
+        let creator = move |_display_name: &str, _args: &[&DataTypeImpl]| {
+            Ok(Box::new(function.clone()) as Box<dyn Function>)
+        };
+
+        Ok(FunctionDescription::creator(Box::new(creator))
+            .features(
+                FunctionFeatures::default()
+                    .num_arguments(num_arguments)
+                    .category("UDF")
+                    .description("WASM user-defined function"),
+            )
+            .implicit_cast_arguments(arg_types))
+    }
+
+    fn check_abi(name: &str, module: &Module, num_arguments: usize) -> Result<()> {
+        let illegal = |msg: String| {
+            Err(ErrorCode::IllegalUDFFormat(format!(
+                "Illegal WASM module of function {}: {}",
+                name, msg
+            )))
+        };
+
+        if module.imports().len() != 0 {
+            return illegal("imports are not allowed".to_string());
+        }
+
+        match module.get_export("memory") {
+            Some(ExternType::Memory(_)) => {}
+            _ => return illegal("`memory` is not exported".to_string()),
+        }
+
+        match module.get_export("alloc") {
+            Some(ExternType::Func(func))
+                if func.params().eq([ValType::I32]) && func.results().eq([ValType::I32]) => {}
+            _ => return illegal("`alloc(i32) -> i32` is not exported".to_string()),
+        }
+
+        // One pointer per argument, the output pointer and the number of rows.
+        let num_params = num_arguments + 2;
+        match module.get_export(name) {
+            Some(ExternType::Func(func))
+                if func.params().len() == num_params
+                    && func.params().all(|t| t == ValType::I32)
+                    && func.results().len() == 0 => {}
+            _ => {
+                return illegal(format!(
+                    "`{}` with {} i32 parameters and no result is not exported",
+                    name, num_params
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn runtime_error(&self, e: impl fmt::Display) -> ErrorCode {
+        ErrorCode::UDFRuntimeError(format!(
+            "Execute WASM function {} failed, cause: {}",
+            self.display_name, e
+        ))
+    }
+
+    fn write_column(
+        &self,
+        store: &mut Store<StoreLimits>,
+        memory: &Memory,
+        alloc: &TypedFunc<i32, i32>,
+        column: &ColumnRef,
+        data_type: &DataTypeImpl,
+    ) -> Result<i32> {
+        let column = column.convert_full_column();
+        with_match_primitive_type_id!(data_type.data_type_id(), |$T| {
+            let column: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+            let values = column.values();
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    values.as_ptr() as *const u8,
+                    values.len() * std::mem::size_of::<$T>(),
+                )
+            };
+            let ptr = alloc
+                .call(&mut *store, bytes.len() as i32)
+                .map_err(|e| self.runtime_error(e))?;
+            memory
+                .write(&mut *store, ptr as usize, bytes)
+                .map_err(|e| self.runtime_error(e))?;
+            Ok(ptr)
+        },{
+            Err(self.runtime_error(format!("unsupported type {}", data_type.name())))
+        })
+    }
+}
+
+impl Function for WasmFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.return_type.clone()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.max_memory_bytes)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store
+            .add_fuel(
+                self.limits
+                    .fuel_per_row
+                    .saturating_mul(input_rows.max(1) as u64),
+            )
+            .map_err(|e| self.runtime_error(e))?;
+
+        let instance =
+            Instance::new(&mut store, &self.module, &[]).map_err(|e| self.runtime_error(e))?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| self.runtime_error("`memory` is not exported"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32, _>(&mut store, "alloc")
+            .map_err(|e| self.runtime_error(e))?;
+        let func = instance
+            .get_func(&mut store, &self.export_name)
+            .ok_or_else(|| self.runtime_error(format!("`{}` is not exported", self.export_name)))?;
+
+        let mut params = Vec::with_capacity(columns.len() + 2);
+        for (column, data_type) in columns.iter().zip(self.arg_types.iter()) {
+            let ptr = self.write_column(&mut store, &memory, &alloc, column.column(), data_type)?;
+            params.push(Val::I32(ptr));
+        }
+
+        with_match_primitive_type_id!(self.return_type.data_type_id(), |$T| {
+            let size = input_rows * std::mem::size_of::<$T>();
+            let out_ptr = alloc
+                .call(&mut store, size as i32)
+                .map_err(|e| self.runtime_error(e))?;
+            params.push(Val::I32(out_ptr));
+            params.push(Val::I32(input_rows as i32));
+            func.call(&mut store, &params, &mut [])
+                .map_err(|e| self.runtime_error(e))?;
+
+            let mut values = vec![<$T>::default(); input_rows];
+            let bytes = unsafe {
+                std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, size)
+            };
+            memory
+                .read(&store, out_ptr as usize, bytes)
+                .map_err(|e| self.runtime_error(e))?;
+            Ok(PrimitiveColumn::<$T>::new_from_vec(values).arc())
+        },{
+            Err(self.runtime_error(format!("unsupported type {}", self.return_type.name())))
+        })
+    }
+}
+
+impl fmt::Display for WasmFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
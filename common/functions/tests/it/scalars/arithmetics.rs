@@ -15,6 +15,10 @@
 use common_datavalues::chrono;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_functions::scalars::cast_with_type;
+use common_functions::scalars::FunctionContext;
+use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::DEFAULT_CAST_OPTIONS;
 
 use super::scalar_function_test::test_scalar_functions;
 use super::scalar_function_test::test_scalar_functions_with_type;
@@ -349,3 +353,81 @@ fn test_arithmetic_date_interval() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_strict_mode() -> Result<()> {
+    let strict = FunctionContext {
+        strict: true,
+        ..Default::default()
+    };
+    let eval =
+        |op: &str, func_ctx: FunctionContext, columns: Vec<ColumnRef>| -> Result<ColumnRef> {
+            let types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+            let types = types.iter().collect::<Vec<_>>();
+            let arguments = columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    ColumnWithField::new(
+                        c.clone(),
+                        DataField::new(&format!("dummy_{}", i), c.data_type()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let func = FunctionFactory::instance().get(op, &types)?;
+            func.eval(func_ctx, &arguments, columns[0].len())
+        };
+
+    let max = || Series::from_data(vec![i64::MAX]);
+    let one = || Series::from_data(vec![1i64]);
+    let zero = || Series::from_data(vec![0i64]);
+
+    // Wraps around by default.
+    let col = eval("+", FunctionContext::default(), vec![max(), one()])?;
+    assert_eq!(col, Series::from_data(vec![i64::MIN]));
+    let col = eval("/", FunctionContext::default(), vec![one(), zero()])?;
+    assert_eq!(col, Series::from_data(vec![f64::INFINITY]));
+
+    let cases = [
+        ("+", vec![max(), one()], "Integer overflow in addition"),
+        (
+            "-",
+            vec![Series::from_data(vec![i64::MIN]), one()],
+            "Integer overflow in subtraction",
+        ),
+        (
+            "*",
+            vec![max(), Series::from_data(vec![2i64])],
+            "Integer overflow in multiplication",
+        ),
+        (
+            "negate",
+            vec![Series::from_data(vec![i64::MIN])],
+            "Integer overflow in negation",
+        ),
+        ("/", vec![one(), zero()], "Division by zero"),
+    ];
+    for (op, columns, error) in cases {
+        let cause = eval(op, strict.clone(), columns).unwrap_err();
+        assert_eq!(cause.message(), error, "{}", op);
+    }
+
+    let col = eval("+", strict.clone(), vec![one(), one()])?;
+    assert_eq!(col, Series::from_data(vec![2i64]));
+
+    // Out of range casts are errors instead of wrapping around.
+    let column = Series::from_data(vec![300i64]);
+    let from = column.data_type();
+    let to = UInt8Type::new_impl();
+    let col = cast_with_type(
+        &column,
+        &from,
+        &to,
+        &DEFAULT_CAST_OPTIONS,
+        &Default::default(),
+    )?;
+    assert_eq!(col, Series::from_data(vec![44u8]));
+    assert!(cast_with_type(&column, &from, &to, &DEFAULT_CAST_OPTIONS, &strict).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::FunctionFeatures;
+
+#[test]
+fn test_function_features_arguments_signature() {
+    let typed = |features: FunctionFeatures, types: &[&str]| FunctionFeatures {
+        argument_types: types.iter().map(|t| t.to_string()).collect(),
+        ..features
+    };
+
+    let tests = vec![
+        (FunctionFeatures::default(), "()"),
+        (FunctionFeatures::default().num_arguments(1), ""),
+        (
+            typed(FunctionFeatures::default().num_arguments(1), &["UInt64"]),
+            "(arg1 UInt64)",
+        ),
+        (
+            typed(FunctionFeatures::default().num_arguments(2), &["UInt64"]),
+            "",
+        ),
+        (
+            typed(FunctionFeatures::default().variadic_arguments(0, 1), &[
+                "String",
+            ]),
+            "([arg1 String])",
+        ),
+        (
+            typed(FunctionFeatures::default().variadic_arguments(1, 3), &[
+                "UInt64", "String", "String",
+            ]),
+            "(arg1 UInt64[, arg2 String, arg3 String])",
+        ),
+        (
+            FunctionFeatures::default().variadic_arguments(0, usize::MAX),
+            "(...)",
+        ),
+        (
+            typed(FunctionFeatures::default().variadic_arguments(2, 1024), &[
+                "UInt64", "String",
+            ]),
+            "(arg1 UInt64, arg2 String, ...)",
+        ),
+    ];
+
+    for (features, expect) in tests {
+        assert_eq!(features.arguments_signature(), expect);
+    }
+}
+
+#[test]
+fn test_function_features_metadata() {
+    let features = FunctionFeatures::default()
+        .category("String")
+        .description("Returns the length of a string.");
+    assert_eq!(features.category, "String");
+    assert_eq!(features.description, "Returns the length of a string.");
+
+    let features = FunctionFactory::instance()
+        .get_features("substring")
+        .unwrap();
+    assert_eq!(features.category, "string");
+    assert_eq!(features.arguments_signature(), "");
+
+    let features = FunctionFactory::instance().get_features("sqrt").unwrap();
+    assert_eq!(features.category, "numeric");
+    assert_eq!(features.description, "Returns the square root of x.");
+
+    let features = FunctionFactory::instance()
+        .get_features("export_set")
+        .unwrap();
+    assert_eq!(
+        features.arguments_signature(),
+        "(arg1 UInt64, arg2 String, arg3 String[, arg4 String, arg5 UInt64])"
+    );
+}
+
+#[test]
+fn test_function_factory_registered_features() {
+    let factory = FunctionFactory::instance();
+    for (name, features) in factory.registered_features() {
+        assert_eq!(factory.get_features(&name).unwrap(), features, "{}", name);
+        assert!(!features.category.is_empty(), "{}", name);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::FunctionContext;
+use common_functions::scalars::FunctionFactory;
+
+fn eval_random(op: &str, func_ctx: &FunctionContext, columns: &[ColumnRef]) -> Result<ColumnRef> {
+    let types = columns.iter().map(|c| c.data_type()).collect::<Vec<_>>();
+    let types = types.iter().collect::<Vec<_>>();
+    let arguments = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            ColumnWithField::new(
+                c.clone(),
+                DataField::new(&format!("dummy_{}", i), c.data_type()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Every evaluation creates the function again, like a new query does.
+    let func = FunctionFactory::instance().get(op, &types)?;
+    let input_rows = columns.first().map(|c| c.len()).unwrap_or(100);
+    func.eval(func_ctx.clone(), &arguments, input_rows)
+}
+
+#[test]
+fn test_random_seed() -> Result<()> {
+    let seeded = FunctionContext {
+        random_seed: Some(42),
+        ..Default::default()
+    };
+    let lengths = vec![ConstColumn::new(Series::from_data(vec![16u64]), 100).arc()];
+    let normal_args = vec![
+        ConstColumn::new(Series::from_data(vec![10.0f64]), 100).arc(),
+        ConstColumn::new(Series::from_data(vec![2.0f64]), 100).arc(),
+    ];
+
+    for (op, columns) in [
+        ("random", vec![]),
+        ("rand", vec![]),
+        ("random_normal", vec![]),
+        ("random_normal", normal_args),
+        ("random_string", lengths),
+    ] {
+        let first = eval_random(op, &seeded, &columns)?;
+        let second = eval_random(op, &seeded, &columns)?;
+        assert_eq!(first, second, "{} should be reproducible with a seed", op);
+
+        let unseeded = eval_random(op, &FunctionContext::default(), &columns)?;
+        assert_ne!(first, unseeded, "{} should not be seeded by default", op);
+
+        // Rows within one evaluation are not repeated.
+        assert_ne!(first.get(0), first.get(1));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_random_seed_block_sequence() -> Result<()> {
+    let block = |seed: u64, sequence: u64| FunctionContext {
+        random_seed: Some(seed),
+        block_sequence: Some(sequence),
+        ..Default::default()
+    };
+
+    // The values of a block only depend on the seed and the position of the block.
+    let first = eval_random("random", &block(42, 1), &[])?;
+    assert_eq!(first, eval_random("random", &block(42, 1), &[])?);
+    assert_ne!(first, eval_random("random", &block(42, 2), &[])?);
+
+    // Zero is a seed like the others.
+    let zero = eval_random("random", &block(0, 1), &[])?;
+    assert_eq!(zero, eval_random("random", &block(0, 1), &[])?);
+    assert_ne!(zero, first);
+    Ok(())
+}
+
+#[test]
+fn test_random_string() -> Result<()> {
+    let lengths = Series::from_data(vec![0u64, 1, 8, 64]);
+    let column = eval_random("random_string", &FunctionContext::default(), &[lengths])?;
+    let viewer = Vu8::try_create_viewer(&column)?;
+
+    for (row, length) in [0usize, 1, 8, 64].into_iter().enumerate() {
+        let value = viewer.value_at(row);
+        assert_eq!(length, value.len());
+        assert!(value.iter().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    let too_long = Series::from_data(vec![u64::MAX]);
+    let result = eval_random("random_string", &FunctionContext::default(), &[too_long]);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_random_normal_arguments() -> Result<()> {
+    let result = FunctionFactory::instance().get("random_normal", &[&Float64Type::new_impl()]);
+    assert!(result.is_err());
+    Ok(())
+}
@@ -18,6 +18,7 @@ mod comparisons;
 mod conditionals;
 mod dates;
 mod expressions;
+mod function_features;
 mod hashes;
 mod logics;
 mod maths;
@@ -29,3 +30,5 @@ mod strings;
 mod tuples;
 mod udfs;
 mod uuids;
+#[cfg(feature = "wasm-udf")]
+mod wasm;
@@ -39,6 +39,16 @@ fn test_try_inet_aton_function() -> Result<()> {
             expect: Series::from_data(vec![Option::<u32>::None]),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "null and invalid input",
+            columns: vec![Series::from_data(vec![
+                None,
+                Some("invalid"),
+                Some("127.0.0.1"),
+            ])],
+            expect: Series::from_data(vec![None, None, Some(2130706433_u32)]),
+            error: "",
+        },
     ];
 
     test_scalar_functions("try_inet_aton", &tests)
@@ -59,6 +69,18 @@ fn test_inet_aton_function() -> Result<()> {
             expect: Series::from_data([Option::<u32>::None]),
             error: "",
         },
+        ScalarFunctionTest {
+            name: "null and valid input",
+            columns: vec![Series::from_data([
+                None,
+                Some("127.0.0.1"),
+                None,
+                None,
+                Some("1.1.1.1"),
+            ])],
+            expect: Series::from_data([None, Some(2130706433_u32), None, None, Some(16843009)]),
+            error: "",
+        },
         ScalarFunctionTest {
             name: "invalid input",
             columns: vec![Series::from_data([Some("1.1.1.1"), Some("batman")])],
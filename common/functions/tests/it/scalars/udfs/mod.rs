@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod connection_id;
+mod current_role;
 mod database;
+mod last_query_id;
 mod version;
@@ -0,0 +1,154 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::WasmFunction;
+use common_functions::scalars::WasmLimits;
+
+use crate::scalars::scalar_function_test::test_eval;
+use crate::scalars::scalar_function_test::test_scalar_functions;
+use crate::scalars::scalar_function_test::ScalarFunctionTest;
+
+const PLUS_MODULE: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (global $heap (mut i32) (i32.const 0))
+  (func (export "alloc") (param $size i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $heap))
+    (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+    (local.get $ptr))
+  (func (export "wasm_plus") (param $a i32) (param $b i32) (param $out i32) (param $rows i32)
+    (local $offset i32)
+    (local $end i32)
+    (local.set $end (i32.shl (local.get $rows) (i32.const 3)))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $offset) (local.get $end)))
+        (i64.store
+          (i32.add (local.get $out) (local.get $offset))
+          (i64.add
+            (i64.load (i32.add (local.get $a) (local.get $offset)))
+            (i64.load (i32.add (local.get $b) (local.get $offset)))))
+        (local.set $offset (i32.add (local.get $offset) (i32.const 8)))
+        (br $next)))))
+"#;
+
+const LOOP_MODULE: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (func (export "alloc") (param $size i32) (result i32)
+    (i32.const 0))
+  (func (export "wasm_loop") (param $a i32) (param $out i32) (param $rows i32)
+    (loop $forever
+      (br $forever))))
+"#;
+
+const LIMITS: WasmLimits = WasmLimits {
+    fuel_per_row: 10000,
+    max_memory_bytes: 1024 * 1024,
+};
+
+#[test]
+fn test_wasm_function() -> Result<()> {
+    let desc = WasmFunction::try_create_desc(
+        "wasm_plus",
+        PLUS_MODULE,
+        vec![Int64Type::new_impl(), Int64Type::new_impl()],
+        Int64Type::new_impl(),
+        LIMITS,
+    )?;
+    FunctionFactory::instance().register_user_function("wasm_plus", desc);
+
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "plus",
+            columns: vec![
+                Series::from_data(vec![1i64, 2, 3]),
+                Series::from_data(vec![10i64, 20, 30]),
+            ],
+            expect: Series::from_data(vec![11i64, 22, 33]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "plus with implicit cast",
+            columns: vec![
+                Series::from_data(vec![1i32, 2, 3]),
+                Series::from_data(vec![10u8, 20, 30]),
+            ],
+            expect: Series::from_data(vec![11i64, 22, 33]),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "plus with null",
+            columns: vec![
+                Series::from_data(vec![Some(1i64), None, Some(3)]),
+                Series::from_data(vec![10i64, 20, 30]),
+            ],
+            expect: Series::from_data(vec![Some(11i64), None, Some(33)]),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions("wasm_plus", &tests)
+}
+
+#[test]
+fn test_wasm_function_fuel_exhausted() -> Result<()> {
+    let desc = WasmFunction::try_create_desc(
+        "wasm_loop",
+        LOOP_MODULE,
+        vec![Int64Type::new_impl()],
+        Int64Type::new_impl(),
+        LIMITS,
+    )?;
+    FunctionFactory::instance().register_user_function("wasm_loop", desc);
+
+    let result = test_eval("wasm_loop", &[Series::from_data(vec![1i64, 2, 3])]);
+    let cause = result.err().unwrap();
+    assert!(cause.message().contains("fuel"), "{}", cause.message());
+
+    Ok(())
+}
+
+#[test]
+fn test_wasm_function_illegal_module() -> Result<()> {
+    let result = WasmFunction::try_create_desc(
+        "wasm_missing",
+        PLUS_MODULE,
+        vec![Int64Type::new_impl(), Int64Type::new_impl()],
+        Int64Type::new_impl(),
+        LIMITS,
+    );
+    assert_eq!(
+        result.err().unwrap().message(),
+        "Illegal WASM module of function wasm_missing: `wasm_missing` with 4 i32 parameters and no result is not exported"
+    );
+
+    let result = WasmFunction::try_create_desc(
+        "wasm_plus",
+        PLUS_MODULE,
+        vec![StringType::new_impl(), Int64Type::new_impl()],
+        Int64Type::new_impl(),
+        LIMITS,
+    );
+    assert_eq!(
+        result.err().unwrap().message(),
+        "WASM function wasm_plus only supports numeric types, but got String"
+    );
+
+    Ok(())
+}
@@ -51,9 +51,30 @@ pub trait BufferReadExt: BufferRead {
     }
 
     fn read_escaped_string_text(&mut self, buf: &mut Vec<u8>) -> Result<()> {
-        self.keep_read(buf, |f| f != b'\t' && f != b'\n' && f != b'\\')?;
-        // TODO judge escaped '\\'
-        Ok(())
+        loop {
+            self.keep_read(buf, |f| {
+                f != b'\t' && f != b'\n' && f != b'\r' && f != b'\\'
+            })?;
+
+            if !self.ignore_byte(b'\\')? {
+                return Ok(());
+            }
+
+            let available = self.fill_buf()?;
+            if available.is_empty() {
+                buf.push(b'\\');
+                return Ok(());
+            }
+
+            buf.push(match available[0] {
+                b't' => b'\t',
+                b'n' => b'\n',
+                b'r' => b'\r',
+                b'0' => b'\0',
+                other => other,
+            });
+            self.consume(1);
+        }
     }
 
     fn eof(&mut self) -> Result<bool> {
@@ -33,6 +33,7 @@ pub struct StorageConfig {
 pub enum StorageParams {
     Azblob(StorageAzblobConfig),
     Fs(StorageFsConfig),
+    Gcs(StorageGcsConfig),
     #[cfg(feature = "storage-hdfs")]
     Hdfs(StorageHdfsConfig),
     Memory,
@@ -46,6 +47,8 @@ impl Default for StorageParams {
 }
 
 /// Config for storage backend azblob.
+///
+/// Authenticated by the shared key of the storage account, SAS tokens are not supported yet.
 #[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StorageAzblobConfig {
     pub endpoint_url: String,
@@ -81,6 +84,49 @@ impl Default for StorageFsConfig {
     }
 }
 
+/// Config for storage backend gcs.
+///
+/// GCS is accessed through its XML API, which is compatible with S3, and authenticated
+/// by an HMAC key of a service account. The JSON credentials of a service account are not
+/// supported yet.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageGcsConfig {
+    pub endpoint_url: String,
+    pub bucket: String,
+    pub root: String,
+    /// The access id of the HMAC key.
+    pub access_key_id: String,
+    /// The secret of the HMAC key.
+    pub secret_access_key: String,
+}
+
+impl Default for StorageGcsConfig {
+    fn default() -> Self {
+        StorageGcsConfig {
+            endpoint_url: "https://storage.googleapis.com".to_string(),
+            bucket: "".to_string(),
+            root: "".to_string(),
+            access_key_id: "".to_string(),
+            secret_access_key: "".to_string(),
+        }
+    }
+}
+
+impl Debug for StorageGcsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageGcsConfig")
+            .field("endpoint_url", &self.endpoint_url)
+            .field("bucket", &self.bucket)
+            .field("root", &self.root)
+            .field("access_key_id", &mask_string(&self.access_key_id, 3))
+            .field(
+                "secret_access_key",
+                &mask_string(&self.secret_access_key, 3),
+            )
+            .finish()
+    }
+}
+
 /// Config for storage backend hdfs.
 ///
 /// # Notes
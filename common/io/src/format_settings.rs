@@ -24,6 +24,7 @@ use serde::Serialize;
 pub struct FormatSettings {
     pub record_delimiter: Vec<u8>,
     pub field_delimiter: Vec<u8>,
+    pub quote_char: Vec<u8>,
     pub empty_as_default: bool,
     pub skip_header: bool,
     pub compression: Compression,
@@ -35,6 +36,7 @@ impl Default for FormatSettings {
         Self {
             record_delimiter: vec![b'\n'],
             field_delimiter: vec![b','],
+            quote_char: vec![b'"', b'\''],
             empty_as_default: false,
             skip_header: false,
             compression: Compression::None,
@@ -24,6 +24,7 @@ use opendal::Operator;
 use super::configs::StorageAzblobConfig;
 use super::configs::StorageConfig;
 use super::configs::StorageFsConfig;
+use super::configs::StorageGcsConfig;
 use super::configs::StorageParams;
 use super::configs::StorageS3Config;
 
@@ -32,6 +33,7 @@ pub async fn init_operator(cfg: &StorageConfig) -> Result<Operator> {
     Ok(match &cfg.params {
         StorageParams::Azblob(cfg) => init_azblob_operator(cfg).await?,
         StorageParams::Fs(cfg) => init_fs_operator(cfg).await?,
+        StorageParams::Gcs(cfg) => init_gcs_operator(cfg).await?,
         #[cfg(feature = "storage-hdfs")]
         StorageParams::Hdfs(cfg) => init_hdfs_operator(cfg).await?,
         StorageParams::Memory => init_memory_operator().await?,
@@ -72,6 +74,32 @@ pub async fn init_fs_operator(cfg: &StorageFsConfig) -> Result<Operator> {
     Ok(Operator::new(builder.finish().await?))
 }
 
+/// init_gcs_operator will init an opendal s3 operator on the XML API of gcs.
+pub async fn init_gcs_operator(cfg: &StorageGcsConfig) -> Result<Operator> {
+    let mut builder = s3::Backend::build();
+
+    // Endpoint.
+    builder.endpoint(&cfg.endpoint_url);
+
+    // The XML API of gcs signs the requests in region `auto`.
+    builder.region("auto");
+
+    // Credential, the HMAC key of a service account.
+    builder.access_key_id(&cfg.access_key_id);
+    builder.secret_access_key(&cfg.secret_access_key);
+
+    // Bucket.
+    builder.bucket(&cfg.bucket);
+
+    // Root.
+    builder.root(&cfg.root);
+
+    // The credentials of aws in the environment are not for gcs.
+    builder.disable_credential_loader();
+
+    Ok(Operator::new(builder.finish().await?))
+}
+
 /// init_hdfs_operator will init a opendal hdfs operator.
 #[cfg(feature = "storage-hdfs")]
 pub async fn init_hdfs_operator(cfg: &super::configs::StorageHdfsConfig) -> Result<Operator> {
@@ -30,6 +30,7 @@ pub use crate::buffer::MemoryReader;
 pub use crate::configs::StorageAzblobConfig;
 pub use crate::configs::StorageConfig;
 pub use crate::configs::StorageFsConfig;
+pub use crate::configs::StorageGcsConfig;
 pub use crate::configs::StorageHdfsConfig;
 pub use crate::configs::StorageParams;
 pub use crate::configs::StorageS3Config;
@@ -38,6 +39,7 @@ pub use crate::format_settings::FormatSettings;
 pub use crate::marshal::Marshal;
 pub use crate::operator::init_azblob_operator;
 pub use crate::operator::init_fs_operator;
+pub use crate::operator::init_gcs_operator;
 #[cfg(feature = "storage-hdfs")]
 pub use crate::operator::init_hdfs_operator;
 pub use crate::operator::init_memory_operator;
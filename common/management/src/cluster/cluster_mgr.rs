@@ -147,9 +147,11 @@ impl ClusterApi for ClusterMgr {
             Some(exact) => MatchSeq::Exact(exact),
         };
 
-        let upsert_meta =
-            self.kv_api
-                .upsert_kv(UpsertKVReq::new(&node_key, seq, Operation::AsIs, meta));
+        // Rewrite the value as well as the ttl, so that the node load is kept up to date.
+        let value = Operation::Update(serde_json::to_vec(node)?);
+        let upsert_meta = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
 
         match upsert_meta.await? {
             UpsertKVReply {
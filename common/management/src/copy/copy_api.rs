@@ -0,0 +1,39 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+
+#[async_trait::async_trait]
+pub trait CopyApi: Sync + Send {
+    // Get the records of the files which were copied into the table, the files without
+    // record are not in the result.
+    async fn get_copied_files(
+        &self,
+        table_id: u64,
+        files: &[String],
+    ) -> Result<BTreeMap<String, CopiedFileInfo>>;
+
+    // Record the files which are copied into the table, replacing the old records.
+    async fn add_copied_files(
+        &self,
+        table_id: u64,
+        files: BTreeMap<String, CopiedFileInfo>,
+    ) -> Result<()>;
+
+    // Drop all the records of the table, the files can be copied again.
+    async fn drop_copied_files(&self, table_id: u64) -> Result<()>;
+}
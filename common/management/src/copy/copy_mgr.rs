@@ -0,0 +1,117 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+
+use crate::copy::CopyApi;
+
+static COPY_API_KEY_PREFIX: &str = "__fd_copied_files";
+
+pub struct CopyMgr {
+    kv_api: Arc<dyn KVApi>,
+    copy_prefix: String,
+}
+
+impl CopyMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while copy mgr create)",
+            ));
+        }
+
+        Ok(CopyMgr {
+            kv_api,
+            copy_prefix: format!("{}/{}", COPY_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    fn table_prefix(&self, table_id: u64) -> String {
+        format!("{}/{}/", self.copy_prefix, table_id)
+    }
+
+    fn file_key(&self, table_id: u64, file: &str) -> Result<String> {
+        Ok(format!(
+            "{}{}",
+            self.table_prefix(table_id),
+            escape_for_key(file)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl CopyApi for CopyMgr {
+    async fn get_copied_files(
+        &self,
+        table_id: u64,
+        files: &[String],
+    ) -> Result<BTreeMap<String, CopiedFileInfo>> {
+        let keys = files
+            .iter()
+            .map(|file| self.file_key(table_id, file))
+            .collect::<Result<Vec<_>>>()?;
+        let values = self.kv_api.mget_kv(&keys).await?;
+
+        let mut copied = BTreeMap::new();
+        for (file, value) in files.iter().zip(values.into_iter()) {
+            if let Some(v) = value {
+                copied.insert(file.clone(), CopiedFileInfo::try_from(v.data)?);
+            }
+        }
+        Ok(copied)
+    }
+
+    async fn add_copied_files(
+        &self,
+        table_id: u64,
+        files: BTreeMap<String, CopiedFileInfo>,
+    ) -> Result<()> {
+        for (file, info) in files {
+            let key = self.file_key(table_id, &file)?;
+            let value = Operation::Update(serde_json::to_vec(&info)?);
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::Any, value, None))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn drop_copied_files(&self, table_id: u64) -> Result<()> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.table_prefix(table_id))
+            .await?;
+        for (key, _) in values {
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -13,21 +13,33 @@
 // limitations under the License.
 
 mod cluster;
+mod copy;
+mod load;
+mod password_policy;
 mod quota;
 mod role;
 mod serde;
+mod setting;
 mod stage;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use copy::CopyApi;
+pub use copy::CopyMgr;
+pub use load::LoadApi;
+pub use load::LoadMgr;
+pub use password_policy::PasswordPolicyApi;
+pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
+pub use setting::SettingApi;
+pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
 pub use udf::UdfApi;
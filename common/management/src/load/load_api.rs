@@ -0,0 +1,45 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_types::LoadInfo;
+
+#[async_trait::async_trait]
+pub trait LoadApi: Sync + Send {
+    // Claim the idempotency key of a table for a new load, with a lease which must be renewed
+    // while the load is running. Returns the seq of the claim.
+    // Fails with LoadAlreadyRunning if another load holds the key.
+    async fn claim_load(&self, table_id: u64, key: &str, info: LoadInfo) -> Result<u64>;
+
+    // Extend the lease of a claimed key. Returns the new seq of the claim.
+    // Fails with UnknownLoad if the lease is expired or held by another load.
+    async fn renew_load(&self, table_id: u64, key: &str, seq: u64, info: LoadInfo) -> Result<u64>;
+
+    // Release a claimed key, so that it can be claimed by another load.
+    async fn drop_load(&self, table_id: u64, key: &str, seq: u64) -> Result<()>;
+
+    // Record the load with the key, the record expires after `ttl`.
+    async fn record_load(
+        &self,
+        table_id: u64,
+        key: &str,
+        info: LoadInfo,
+        ttl: Duration,
+    ) -> Result<()>;
+
+    // Get the record of the load with the key, none if it is not recorded or expired.
+    async fn get_load_record(&self, table_id: u64, key: &str) -> Result<Option<LoadInfo>>;
+}
@@ -0,0 +1,176 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::KVMeta;
+use common_meta_types::LoadInfo;
+use common_meta_types::MatchSeq;
+use common_meta_types::OkOrExist;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVReq;
+
+use crate::load::LoadApi;
+
+static LOAD_API_KEY_PREFIX: &str = "__fd_loads";
+static LOAD_RECORD_API_KEY_PREFIX: &str = "__fd_load_records";
+
+pub struct LoadMgr {
+    kv_api: Arc<dyn KVApi>,
+    // How long a claimed idempotency key is held without being renewed.
+    lease: Duration,
+    load_prefix: String,
+    record_prefix: String,
+}
+
+impl LoadMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str, lease: Duration) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while load mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(LoadMgr {
+            kv_api,
+            lease,
+            load_prefix: format!("{}/{}", LOAD_API_KEY_PREFIX, tenant),
+            record_prefix: format!("{}/{}", LOAD_RECORD_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn new_lease(&self) -> KVMeta {
+        Self::expire_after(self.lease)
+    }
+
+    fn expire_after(ttl: Duration) -> KVMeta {
+        let now = std::time::SystemTime::now();
+        let expire_at = now
+            .add(ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+
+        KVMeta {
+            expire_at: Some(expire_at.as_secs()),
+        }
+    }
+
+    fn load_key(&self, table_id: u64, key: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{}",
+            self.load_prefix,
+            table_id,
+            escape_for_key(key)?
+        ))
+    }
+
+    fn record_key(&self, table_id: u64, key: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{}",
+            self.record_prefix,
+            table_id,
+            escape_for_key(key)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl LoadApi for LoadMgr {
+    async fn claim_load(&self, table_id: u64, key: &str, info: LoadInfo) -> Result<u64> {
+        // Only when there are no record, i.e. seq=0
+        let seq = MatchSeq::Exact(0);
+        let meta = Some(self.new_lease());
+        let value = Operation::Update(serde_json::to_vec(&info)?);
+        let load_key = self.load_key(table_id, key)?;
+        let upsert_load = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&load_key, seq, value, meta));
+
+        match upsert_load.await?.into_add_result()?.res {
+            OkOrExist::Ok(v) => Ok(v.seq),
+            OkOrExist::Exists(v) => Err(ErrorCode::LoadAlreadyRunning(format!(
+                "Load {} with idempotency key '{}' is still running",
+                LoadInfo::try_from(v.data)?.id,
+                key
+            ))),
+        }
+    }
+
+    async fn renew_load(&self, table_id: u64, key: &str, seq: u64, info: LoadInfo) -> Result<u64> {
+        let meta = Some(self.new_lease());
+        let value = Operation::Update(serde_json::to_vec(&info)?);
+        let load_key = self.load_key(table_id, key)?;
+        let upsert_load = self.kv_api.upsert_kv(UpsertKVReq::new(
+            &load_key,
+            MatchSeq::Exact(seq),
+            value,
+            meta,
+        ));
+
+        let reply = upsert_load.await?;
+        match (reply.changed(), reply.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::UnknownLoad(format!(
+                "load with idempotency key '{}' not found, its lease may be expired",
+                key
+            ))),
+        }
+    }
+
+    async fn drop_load(&self, table_id: u64, key: &str, seq: u64) -> Result<()> {
+        let load_key = self.load_key(table_id, key)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &load_key,
+                MatchSeq::Exact(seq),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn record_load(
+        &self,
+        table_id: u64,
+        key: &str,
+        info: LoadInfo,
+        ttl: Duration,
+    ) -> Result<()> {
+        let meta = Some(Self::expire_after(ttl));
+        let value = Operation::Update(serde_json::to_vec(&info)?);
+        let record_key = self.record_key(table_id, key)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&record_key, MatchSeq::Any, value, meta))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_load_record(&self, table_id: u64, key: &str) -> Result<Option<LoadInfo>> {
+        let record_key = self.record_key(table_id, key)?;
+        match self.kv_api.get_kv(&record_key).await? {
+            Some(v) => Ok(Some(LoadInfo::try_from(v.data)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::SeqV;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserPasswordState;
+
+#[async_trait::async_trait]
+pub trait PasswordPolicyApi: Sync + Send {
+    async fn get_policy(&self, seq: Option<u64>) -> Result<SeqV<PasswordPolicy>>;
+
+    async fn set_policy(&self, policy: &PasswordPolicy, seq: Option<u64>) -> Result<u64>;
+
+    async fn get_password_state(&self, user: &UserIdentity) -> Result<SeqV<UserPasswordState>>;
+
+    async fn set_password_state(
+        &self,
+        user: &UserIdentity,
+        state: &UserPasswordState,
+        seq: Option<u64>,
+    ) -> Result<u64>;
+
+    async fn drop_password_state(&self, user: &UserIdentity) -> Result<()>;
+}
@@ -0,0 +1,137 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::Operation;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVReq;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserPasswordState;
+
+use super::password_policy_api::PasswordPolicyApi;
+
+static PASSWORD_POLICY_API_KEY_PREFIX: &str = "__fd_password_policies";
+static PASSWORD_STATE_API_KEY_PREFIX: &str = "__fd_password_states";
+
+pub struct PasswordPolicyMgr {
+    kv_api: Arc<dyn KVApi>,
+    policy_key: String,
+    state_prefix: String,
+}
+
+impl PasswordPolicyMgr {
+    pub fn create(kv_api: Arc<dyn KVApi>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while password policy mgr create)",
+            ));
+        }
+        let tenant = escape_for_key(tenant)?;
+        Ok(PasswordPolicyMgr {
+            kv_api,
+            policy_key: format!("{}/{}", PASSWORD_POLICY_API_KEY_PREFIX, tenant),
+            state_prefix: format!("{}/{}", PASSWORD_STATE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn state_key(&self, user: &UserIdentity) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.state_prefix,
+            escape_for_key(&user.to_string())?
+        ))
+    }
+
+    async fn upsert(&self, key: &str, value: Vec<u8>, seq: Option<u64>) -> Result<u64> {
+        let match_seq = match seq {
+            None => MatchSeq::Any,
+            Some(seq) => MatchSeq::Exact(seq),
+        };
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                key,
+                match_seq,
+                Operation::Update(value),
+                None,
+            ))
+            .await?;
+
+        // The value is left as it is if the seq doesn't match.
+        match (res.changed(), res.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::PasswordPolicyUnknown(format!(
+                "{} not found, or seq not match",
+                key
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordPolicyApi for PasswordPolicyMgr {
+    async fn get_policy(&self, seq: Option<u64>) -> Result<SeqV<PasswordPolicy>> {
+        let res = self.kv_api.get_kv(&self.policy_key).await?;
+        match res {
+            Some(seq_value) => match MatchSeq::from(seq).match_seq(&seq_value) {
+                Ok(_) => Ok(seq_value.into_seqv()?),
+                Err(_) => Err(ErrorCode::PasswordPolicyUnknown("seq not match")),
+            },
+            None => Ok(SeqV::new(0, PasswordPolicy::default())),
+        }
+    }
+
+    async fn set_policy(&self, policy: &PasswordPolicy, seq: Option<u64>) -> Result<u64> {
+        let value = serde_json::to_vec(policy)?;
+        self.upsert(&self.policy_key, value, seq).await
+    }
+
+    async fn get_password_state(&self, user: &UserIdentity) -> Result<SeqV<UserPasswordState>> {
+        let res = self.kv_api.get_kv(&self.state_key(user)?).await?;
+        match res {
+            Some(seq_value) => Ok(seq_value.into_seqv()?),
+            None => Ok(SeqV::new(0, UserPasswordState::default())),
+        }
+    }
+
+    async fn set_password_state(
+        &self,
+        user: &UserIdentity,
+        state: &UserPasswordState,
+        seq: Option<u64>,
+    ) -> Result<u64> {
+        let value = serde_json::to_vec(state)?;
+        self.upsert(&self.state_key(user)?, value, seq).await
+    }
+
+    async fn drop_password_state(&self, user: &UserIdentity) -> Result<()> {
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.state_key(user)?,
+                MatchSeq::Any,
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_types::SeqV;
+use common_meta_types::UserSetting;
+
+#[async_trait::async_trait]
+pub trait SettingApi: Sync + Send {
+    // Set a global setting to /tenant/setting-name, overwrite the old value if any.
+    async fn set_setting(&self, setting: UserSetting) -> Result<u64>;
+
+    // Get all the global settings for a tenant.
+    async fn get_settings(&self) -> Result<Vec<UserSetting>>;
+
+    // Get a global setting by name.
+    async fn get_setting(&self, name: &str, seq: Option<u64>) -> Result<SeqV<UserSetting>>;
+
+    // Drop the tenant's global setting by name.
+    async fn drop_setting(&self, name: &str, seq: Option<u64>) -> Result<()>;
+}
@@ -26,6 +26,7 @@ use common_meta_types::MatchSeqExt;
 use common_meta_types::OkOrExist;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
+use common_meta_types::UDFLanguage;
 use common_meta_types::UpsertKVReq;
 use common_meta_types::UserDefinedFunction;
 
@@ -63,8 +64,11 @@ impl UdfApi for UdfMgr {
             )));
         }
 
-        let mut udf_parser = UDFParser::default();
-        udf_parser.parse(&info.name, &info.parameters, &info.definition)?;
+        // WASM modules are validated by the query node which compiles them.
+        if info.language == UDFLanguage::Sql {
+            let mut udf_parser = UDFParser::default();
+            udf_parser.parse(&info.name, &info.parameters, &info.definition)?;
+        }
 
         let seq = MatchSeq::Exact(0);
         let val = Operation::Update(serde_json::to_vec(&info)?);
@@ -22,6 +22,7 @@ use common_management::*;
 use common_meta_api::KVApi;
 use common_meta_embedded::MetaEmbedded;
 use common_meta_types::NodeInfo;
+use common_meta_types::NodeLoad;
 use common_meta_types::SeqV;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -127,13 +128,22 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
 
     let current_time = current_seconds_time();
+    let mut node_info = node_info;
+    node_info.load = NodeLoad {
+        active_sessions: 2,
+        running_queries: 1,
+        max_sessions: 256,
+    };
     cluster_api.heartbeat(&node_info, None).await?;
 
     let value = kv_api
         .get_kv("__fd_clusters/admin//databend_query/test_node")
-        .await?;
+        .await?
+        .unwrap();
 
-    assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
+    assert!(value.meta.unwrap().expire_at.unwrap() - current_time >= 60);
+    let stored = serde_json::from_slice::<NodeInfo>(&value.data)?;
+    assert_eq!(stored.load, node_info.load);
     Ok(())
 }
 
@@ -150,6 +160,8 @@ fn create_test_node_info() -> NodeInfo {
         cpu_nums: 0,
         version: 0,
         flight_address: String::from("ip:port"),
+        http_address: String::from("ip:port"),
+        load: NodeLoad::default(),
     }
 }
 
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::CopiedFileInfo;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_copied_files() -> Result<()> {
+    let copy_api = new_copy_api().await?;
+
+    let files = vec!["data/a.csv".to_string(), "data/b.csv".to_string()];
+    assert!(copy_api.get_copied_files(1, &files).await?.is_empty());
+
+    let info = CopiedFileInfo {
+        size: 10,
+        last_modified: Some(1000),
+    };
+    let mut copied = BTreeMap::new();
+    copied.insert("data/a.csv".to_string(), info.clone());
+    copy_api.add_copied_files(1, copied.clone()).await?;
+
+    assert_eq!(copy_api.get_copied_files(1, &files).await?, copied);
+    // The records are per table.
+    assert!(copy_api.get_copied_files(2, &files).await?.is_empty());
+
+    // The records of a table can be dropped.
+    copy_api.drop_copied_files(1).await?;
+    assert!(copy_api.get_copied_files(1, &files).await?.is_empty());
+
+    Ok(())
+}
+
+async fn new_copy_api() -> Result<CopyMgr> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    CopyMgr::create(test_api, "admin")
+}
@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::LoadInfo;
+use common_meta_types::LoadState;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_claim_load() -> Result<()> {
+    let (kv_api, load_api) = new_load_api().await?;
+
+    let info = LoadInfo::new("load_1");
+    load_api.claim_load(1, "key_1", info.clone()).await?;
+    let value = kv_api.get_kv("__fd_loads/admin/1/key_1").await?;
+    assert!(value.unwrap().meta.unwrap().expire_at.is_some());
+
+    // The retry can't claim the key while the first load is running.
+    let retry = LoadInfo::new("load_2");
+    match load_api.claim_load(1, "key_1", retry.clone()).await {
+        Ok(_) => panic!("Claim a running load must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2952),
+    }
+
+    // The keys are scoped by table.
+    load_api.claim_load(2, "key_1", retry).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_renew_and_drop_load() -> Result<()> {
+    let (_, load_api) = new_load_api().await?;
+
+    let info = LoadInfo::new("load_1");
+    let seq = load_api.claim_load(1, "key_1", info.clone()).await?;
+    let new_seq = load_api.renew_load(1, "key_1", seq, info.clone()).await?;
+    assert!(new_seq > seq);
+
+    // Only the current claim can be renewed.
+    match load_api.renew_load(1, "key_1", seq, info.clone()).await {
+        Ok(_) => panic!("Renew a stale claim must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2953),
+    }
+
+    // A stale claim doesn't release the key.
+    load_api.drop_load(1, "key_1", seq).await?;
+    let retry = LoadInfo::new("load_2");
+    assert!(load_api
+        .claim_load(1, "key_1", retry.clone())
+        .await
+        .is_err());
+
+    // A dropped key can be claimed again.
+    load_api.drop_load(1, "key_1", new_seq).await?;
+    load_api.claim_load(1, "key_1", retry).await?;
+
+    // Only a claimed key can be renewed.
+    match load_api
+        .renew_load(1, "key_2", 1, LoadInfo::new("load_3"))
+        .await
+    {
+        Ok(_) => panic!("Renew unclaimed load must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2953),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_record_load() -> Result<()> {
+    let (kv_api, load_api) = new_load_api().await?;
+    assert!(load_api.get_load_record(1, "key_1").await?.is_none());
+
+    let mut info = LoadInfo::new("load_1");
+    info.state = LoadState::Committing;
+    info.snapshot_id = Some("snapshot_1".to_string());
+    let ttl = Duration::from_secs(3600);
+    load_api.record_load(1, "key_1", info.clone(), ttl).await?;
+    let value = kv_api.get_kv("__fd_load_records/admin/1/key_1").await?;
+    assert!(value.unwrap().meta.unwrap().expire_at.is_some());
+    assert_eq!(
+        load_api.get_load_record(1, "key_1").await?,
+        Some(info.clone())
+    );
+
+    // The record is overwritten once the load succeeded.
+    info.state = LoadState::Success;
+    load_api.record_load(1, "key_1", info.clone(), ttl).await?;
+    assert_eq!(load_api.get_load_record(1, "key_1").await?, Some(info));
+
+    // The records are scoped by table, and independent of the claims.
+    assert!(load_api.get_load_record(2, "key_1").await?.is_none());
+    load_api
+        .claim_load(1, "key_1", LoadInfo::new("load_2"))
+        .await?;
+
+    Ok(())
+}
+
+async fn new_load_api() -> Result<(Arc<MetaEmbedded>, LoadMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let load_manager = LoadMgr::create(test_api.clone(), "admin", Duration::from_secs(60))?;
+    Ok((test_api, load_manager))
+}
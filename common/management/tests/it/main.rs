@@ -13,6 +13,10 @@
 // limitations under the License.
 
 mod cluster;
+mod copy;
+mod load;
+mod password_policy;
+mod setting;
 mod stage;
 mod udf;
 mod user;
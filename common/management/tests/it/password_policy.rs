@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserPasswordState;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_password_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_password_policy_api().await?;
+
+    let policy = policy_api.get_policy(None).await?;
+    assert_eq!(policy.seq, 0);
+    assert_eq!(policy.data, PasswordPolicy::default());
+
+    let policy = PasswordPolicy {
+        min_length: 8,
+        max_retries: 3,
+        ..Default::default()
+    };
+    let seq = policy_api.set_policy(&policy, None).await?;
+    let value = kv_api.get_kv("__fd_password_policies/admin").await?;
+    assert_eq!(value.unwrap().data, serde_json::to_vec(&policy)?);
+
+    let got = policy_api.get_policy(Some(seq)).await?;
+    assert_eq!(got.data, policy);
+
+    match policy_api.set_policy(&policy, Some(seq + 1)).await {
+        Ok(_) => panic!("Set password policy with wrong seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2923),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_password_state() -> Result<()> {
+    let (_, policy_api) = new_password_policy_api().await?;
+    let user = UserIdentity::new("test", "%");
+
+    let state = policy_api.get_password_state(&user).await?;
+    assert_eq!(state.data, UserPasswordState::default());
+
+    let state = UserPasswordState {
+        password_changed_at: 100,
+        history: vec![b"hash".to_vec()],
+        failed_attempts: 1,
+        locked_until: 0,
+    };
+    policy_api.set_password_state(&user, &state, None).await?;
+    let got = policy_api.get_password_state(&user).await?;
+    assert_eq!(got.data, state);
+
+    // A state updated since it is read can't be overwritten by its seq.
+    let seq = policy_api
+        .set_password_state(&user, &state, Some(got.seq))
+        .await?;
+    assert!(seq > got.seq);
+    match policy_api
+        .set_password_state(&user, &state, Some(got.seq))
+        .await
+    {
+        Ok(_) => panic!("Set password state with stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2923),
+    }
+
+    policy_api.drop_password_state(&user).await?;
+    let got = policy_api.get_password_state(&user).await?;
+    assert_eq!(got.data, UserPasswordState::default());
+
+    Ok(())
+}
+
+async fn new_password_policy_api() -> Result<(Arc<MetaEmbedded>, PasswordPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = PasswordPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::SeqV;
+use common_meta_types::UserSetting;
+use common_meta_types::UserSettingValue;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_set_setting() -> Result<()> {
+    let (kv_api, setting_api) = new_setting_api().await?;
+
+    let setting = UserSetting::create("max_threads", UserSettingValue::UInt64(3));
+    setting_api.set_setting(setting.clone()).await?;
+    let value = kv_api.get_kv("__fd_settings/admin/max_threads").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&setting)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    // Overwrite.
+    let setting = UserSetting::create("max_threads", UserSettingValue::UInt64(4));
+    setting_api.set_setting(setting.clone()).await?;
+    let value = setting_api.get_setting("max_threads", None).await?;
+    assert_eq!(value.data, setting);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_settings() -> Result<()> {
+    let (_, setting_api) = new_setting_api().await?;
+
+    let settings = setting_api.get_settings().await?;
+    assert_eq!(settings, vec![]);
+
+    let setting = UserSetting::create(
+        "timezone",
+        UserSettingValue::String(b"Asia/Shanghai".to_vec()),
+    );
+    setting_api.set_setting(setting.clone()).await?;
+
+    let settings = setting_api.get_settings().await?;
+    assert_eq!(settings, vec![setting]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_setting() -> Result<()> {
+    let (_, setting_api) = new_setting_api().await?;
+
+    let setting = UserSetting::create("max_threads", UserSettingValue::UInt64(3));
+    setting_api.set_setting(setting).await?;
+    setting_api.drop_setting("max_threads", None).await?;
+    assert_eq!(setting_api.get_settings().await?, vec![]);
+
+    match setting_api.drop_setting("max_threads", None).await {
+        Ok(_) => panic!("Drop unknown setting must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2801),
+    }
+
+    Ok(())
+}
+
+async fn new_setting_api() -> Result<(Arc<MetaEmbedded>, SettingMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SettingMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -50,6 +50,7 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::GCDroppedDataReq;
@@ -1591,6 +1592,106 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    pub async fn update_multi_table_meta<MT: SchemaApi>(self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb2", "tb3"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(DataSchema::new(vec![DataField::new(
+                "number",
+                u64::to_data_type(),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        tracing::info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_of = |table: &TableInfo, data_bytes, seq| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+            }
+        };
+
+        tracing::info!("--- update multi table meta, normal case");
+        {
+            let mut updates = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                updates.push(update_of(table.as_ref(), 1, table.ident.seq));
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: updates.clone(),
+            })
+            .await?;
+
+            for (tbl_name, update) in tbl_names.iter().zip(updates) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, update.new_table_meta);
+            }
+        }
+
+        tracing::info!("--- update multi table meta: version mismatch of one table");
+        {
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            let tb3 = mt.get_table((tenant, db_name, "tb3").into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_of(tb2.as_ref(), 2, tb2.ident.seq),
+                        update_of(tb3.as_ref(), 2, tb3.ident.seq + 1),
+                    ],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::table_version_mismatched_code(), err.code());
+
+            // none of the tables is updated
+            let got = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb2.meta, got.meta);
+            assert_eq!(tb2.ident.seq, got.ident.seq);
+        }
+        Ok(())
+    }
+
     pub async fn table_upsert_option<MT: SchemaApi>(self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
         let db_name = "db1";
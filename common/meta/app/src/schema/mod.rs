@@ -56,6 +56,8 @@ pub use table::TableNameIdent;
 pub use table::TableStatistics;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableOptionReply;
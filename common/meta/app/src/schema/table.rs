@@ -525,6 +525,16 @@ pub struct UpsertTableOptionReply {}
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpdateTableMetaReply {}
 
+/// Updates the metas of several tables at once, none of them is updated if the version of
+/// any table mismatches.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateMultiTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -90,6 +90,12 @@ async fn test_meta_embedded_table_update_meta() -> anyhow::Result<()> {
     SchemaApiTestSuite {}.update_table_meta(&mt).await
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_embedded_table_update_multi_meta() -> anyhow::Result<()> {
+    let mt = MetaEmbedded::new_temp().await?;
+    SchemaApiTestSuite {}.update_multi_table_meta(&mt).await
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_meta_embedded_table_list() -> anyhow::Result<()> {
     let mt = MetaEmbedded::new_temp().await?;
@@ -81,6 +81,11 @@ pub struct RaftConfig {
     /// The maximum number of applied logs to keep before purging
     pub max_applied_log_to_keep: u64,
 
+    /// Whether to pack concurrent client writes into one raft log.
+    /// Nodes of a version without group commit can't apply such a log,
+    /// only enable it after every node of the cluster is upgraded.
+    pub group_commit: bool,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -127,6 +132,7 @@ impl Default for RaftConfig {
             heartbeat_interval: 1000,
             install_snapshot_timeout: 4000,
             max_applied_log_to_keep: 1000,
+            group_commit: false,
             single: false,
             join: vec![],
             id: 0,
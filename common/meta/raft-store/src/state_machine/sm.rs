@@ -236,24 +236,7 @@ impl StateMachine {
             match entry.payload {
                 EntryPayload::Blank => {}
                 EntryPayload::Normal(ref data) => {
-                    if let Some(ref txid) = data.txid {
-                        let (serial, resp) =
-                            self.txn_get_client_last_resp(&txid.client, &txn_tree)?;
-                        if serial == txid.serial {
-                            return Ok(Some(resp));
-                        }
-                    }
-
-                    let res = self.apply_cmd(&data.cmd, &txn_tree);
-                    let applied_state = res?;
-
-                    if let Some(ref txid) = data.txid {
-                        self.txn_client_last_resp_update(
-                            &txid.client,
-                            (txid.serial, applied_state.clone()),
-                            &txn_tree,
-                        )?;
-                    }
+                    let applied_state = self.apply_log_entry(data, &txn_tree)?;
                     return Ok(Some(applied_state));
                 }
                 EntryPayload::Membership(ref mem) => {
@@ -283,6 +266,45 @@ impl StateMachine {
         Ok(applied_state)
     }
 
+    /// Apply a client `LogEntry`, skipping it if the `txid` shows it is a duplicate.
+    fn apply_log_entry(
+        &self,
+        data: &LogEntry,
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        if let Some(ref txid) = data.txid {
+            let (serial, resp) = self.txn_get_client_last_resp(&txid.client, txn_tree)?;
+            if serial == txid.serial {
+                return Ok(resp);
+            }
+        }
+
+        let applied_state = self.apply_cmd(&data.cmd, txn_tree)?;
+
+        if let Some(ref txid) = data.txid {
+            self.txn_client_last_resp_update(
+                &txid.client,
+                (txid.serial, applied_state.clone()),
+                txn_tree,
+            )?;
+        }
+        Ok(applied_state)
+    }
+
+    /// Apply every entry of a group-committed batch and collect their states in order.
+    #[tracing::instrument(level = "debug", skip(self, entries, txn_tree))]
+    fn apply_batch_cmd(
+        &self,
+        entries: &[LogEntry],
+        txn_tree: &TransactionSledTree,
+    ) -> MetaStorageResult<AppliedState> {
+        let mut states = Vec::with_capacity(entries.len());
+        for entry in entries {
+            states.push(self.apply_log_entry(entry, txn_tree)?);
+        }
+        Ok(AppliedState::Batch(states))
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_incr_seq_cmd(
         &self,
@@ -678,6 +700,8 @@ impl StateMachine {
             } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, txn_tree),
 
             Cmd::Transaction(txn) => self.apply_txn_cmd(txn, txn_tree),
+
+            Cmd::Batch(entries) => self.apply_batch_cmd(entries, txn_tree),
         }
     }
 
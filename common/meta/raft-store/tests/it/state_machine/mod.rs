@@ -28,6 +28,7 @@ use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
+use common_meta_types::RaftTxId;
 use common_meta_types::SeqV;
 use common_tracing::tracing;
 use openraft::raft::Entry;
@@ -109,6 +110,68 @@ async fn test_state_machine_apply_incr_seq() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_batch() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let incr = |txid: Option<RaftTxId>, k: &str| LogEntry {
+        txid,
+        cmd: Cmd::IncrSeq { key: k.to_string() },
+    };
+
+    // The third entry is a retry of the first one and must not be applied twice.
+    let batch = Cmd::Batch(vec![
+        incr(Some(RaftTxId::new("foo", 1)), "a"),
+        incr(None, "a"),
+        incr(Some(RaftTxId::new("foo", 1)), "b"),
+    ]);
+
+    let resp = sm
+        .apply(&Entry {
+            log_id: LogId { term: 0, index: 5 },
+            payload: EntryPayload::Normal(LogEntry {
+                txid: None,
+                cmd: batch.clone(),
+            }),
+        })
+        .await?;
+
+    assert_eq!(
+        AppliedState::Batch(vec![
+            AppliedState::Seq { seq: 1 },
+            AppliedState::Seq { seq: 2 },
+            AppliedState::Seq { seq: 1 },
+        ]),
+        resp
+    );
+    assert!(resp.changed());
+
+    let resp = sm
+        .apply(&Entry {
+            log_id: LogId { term: 0, index: 6 },
+            payload: EntryPayload::Normal(LogEntry {
+                txid: None,
+                cmd: batch,
+            }),
+        })
+        .await?;
+
+    assert_eq!(
+        AppliedState::Batch(vec![
+            AppliedState::Seq { seq: 1 },
+            AppliedState::Seq { seq: 3 },
+            AppliedState::Seq { seq: 1 },
+        ]),
+        resp
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
@@ -135,6 +135,16 @@ async fn test_meta_embedded_table_update_meta() -> anyhow::Result<()> {
     SchemaApiTestSuite {}.update_table_meta(&sm).await
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_embedded_table_update_multi_meta() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    SchemaApiTestSuite {}.update_multi_table_meta(&sm).await
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_meta_embedded_table_list() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
@@ -49,6 +49,11 @@ pub enum AppliedState {
 
     TxnReply(TxnReply),
 
+    /// The states of every entry in a `Cmd::Batch`, in the same order.
+    #[from(ignore)]
+    #[try_into(ignore)]
+    Batch(Vec<AppliedState>),
+
     #[try_into(ignore)]
     None,
 }
@@ -114,6 +119,7 @@ impl AppliedState {
             AppliedState::KV(ref ch) => ch.changed(),
             AppliedState::None => false,
             AppliedState::TxnReply(txn) => txn.success,
+            AppliedState::Batch(states) => states.iter().any(|s| s.changed()),
         }
     }
 
@@ -141,6 +147,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(_txn) => true,
+            AppliedState::Batch(states) => states.iter().all(|s| s.prev_is_none()),
         }
     }
 
@@ -152,6 +159,7 @@ impl AppliedState {
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
             AppliedState::TxnReply(txn) => !txn.success,
+            AppliedState::Batch(states) => states.iter().all(|s| s.result_is_none()),
         }
     }
 }
@@ -46,6 +46,15 @@ impl fmt::Display for Node {
     }
 }
 
+/// Load of a query node, refreshed on every heartbeat.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
+#[serde(default)]
+pub struct NodeLoad {
+    pub active_sessions: u64,
+    pub running_queries: u64,
+    pub max_sessions: u64,
+}
+
 /// Query node
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
 #[serde(default)]
@@ -54,6 +63,8 @@ pub struct NodeInfo {
     pub cpu_nums: u64,
     pub version: u32,
     pub flight_address: String,
+    pub http_address: String,
+    pub load: NodeLoad,
 }
 
 impl TryFrom<Vec<u8>> for NodeInfo {
@@ -77,6 +88,8 @@ impl NodeInfo {
             cpu_nums,
             version: 0,
             flight_address,
+            http_address: String::new(),
+            load: NodeLoad::default(),
         }
     }
 
@@ -19,6 +19,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::KVMeta;
+use crate::LogEntry;
 use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
@@ -74,6 +75,12 @@ pub enum Cmd {
     },
 
     Transaction(TxnRequest),
+
+    /// Apply several client log entries in one raft log, a.k.a. group commit.
+    ///
+    /// Every entry is applied in order, with its own `txid` dedup,
+    /// and produces one `AppliedState` in `AppliedState::Batch`.
+    Batch(Vec<LogEntry>),
 }
 
 impl fmt::Display for Cmd {
@@ -112,6 +119,16 @@ impl fmt::Display for Cmd {
             Cmd::Transaction(txn) => {
                 write!(f, "txn:{:?}", txn)
             }
+            Cmd::Batch(entries) => {
+                write!(f, "batch:[")?;
+                for (i, entry) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", entry.cmd)?;
+                }
+                write!(f, "]")
+            }
         }
     }
 }
@@ -0,0 +1,41 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The record of a file loaded into a table by COPY, a file is not loaded again
+/// as long as its size and last modified time are the same.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CopiedFileInfo {
+    pub size: u64,
+    // Seconds since the unix epoch, if the storage returns it.
+    pub last_modified: Option<i64>,
+}
+
+impl TryFrom<Vec<u8>> for CopiedFileInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(info) => Ok(info),
+            Err(err) => Err(ErrorCode::IllegalCopiedFileInfoFormat(format!(
+                "Cannot deserialize copied file info from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
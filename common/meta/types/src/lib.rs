@@ -21,9 +21,11 @@ mod change;
 mod cluster;
 mod cmd;
 pub mod config;
+mod copied_file_info;
 mod endpoint;
 mod errors;
 mod kv_message;
+mod load_info;
 mod log_entry;
 mod match_seq;
 mod message;
@@ -34,6 +36,7 @@ mod meta_raft_errors;
 mod meta_result_error;
 mod meta_storage_errors;
 mod operation;
+mod password_policy;
 mod raft_txid;
 mod raft_types;
 mod role_info;
@@ -47,6 +50,7 @@ mod user_identity;
 mod user_info;
 mod user_privilege;
 mod user_quota;
+mod user_setting;
 mod user_stage;
 
 pub mod app_error;
@@ -92,8 +96,10 @@ pub use change::Change;
 pub use change::OkOrExist;
 pub use cluster::Node;
 pub use cluster::NodeInfo;
+pub use cluster::NodeLoad;
 pub use cluster::Slot;
 pub use cmd::Cmd;
+pub use copied_file_info::CopiedFileInfo;
 pub use endpoint::Endpoint;
 pub use errors::ConflictSeq;
 pub use kv_message::GetKVReply;
@@ -104,6 +110,8 @@ pub use kv_message::MGetKVReply;
 pub use kv_message::MGetKVReq;
 pub use kv_message::UpsertKVReply;
 pub use kv_message::UpsertKVReq;
+pub use load_info::LoadInfo;
+pub use load_info::LoadState;
 pub use log_entry::LogEntry;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
@@ -129,6 +137,8 @@ pub use operation::GCDroppedDataReply;
 pub use operation::GCDroppedDataReq;
 pub use operation::MetaId;
 pub use operation::Operation;
+pub use password_policy::PasswordPolicy;
+pub use password_policy::UserPasswordState;
 pub use principal_identity::PrincipalIdentity;
 pub use protobuf::txn_condition;
 pub use protobuf::txn_condition::ConditionResult;
@@ -166,6 +176,7 @@ pub use tenant_quota::TenantQuota;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
+pub use user_defined_function::UDFLanguage;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
@@ -177,4 +188,6 @@ pub use user_info::UserOptionFlag;
 pub use user_privilege::UserPrivilegeSet;
 pub use user_privilege::UserPrivilegeType;
 pub use user_quota::UserQuota;
+pub use user_setting::UserSetting;
+pub use user_setting::UserSettingValue;
 pub use user_stage::*;
@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum LoadState {
+    Running,
+    /// The snapshot of the load is being committed to the table.
+    Committing,
+    Success,
+}
+
+/// The record of a load with an idempotency key, which makes the retries of the load
+/// return the result of the first successful one instead of loading the data again.
+///
+/// A running load holds a short lease on the key in the meta service. The load is recorded
+/// under a separate key expiring after the retention of the key, as committing before its
+/// snapshot is committed to the table and as succeeded after.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct LoadInfo {
+    pub id: String,
+    pub state: LoadState,
+    pub rows: u64,
+    pub bytes: u64,
+    /// Seconds since the epoch when the load was committed, none while it is running.
+    #[serde(default)]
+    pub committed_at: Option<u64>,
+    /// The id of the snapshot committed by the load, none while it is running.
+    #[serde(default)]
+    pub snapshot_id: Option<String>,
+}
+
+impl LoadInfo {
+    pub fn new(id: &str) -> Self {
+        LoadInfo {
+            id: id.to_string(),
+            state: LoadState::Running,
+            rows: 0,
+            bytes: 0,
+            committed_at: None,
+            snapshot_id: None,
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for LoadInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(info) => Ok(info),
+            Err(err) => Err(ErrorCode::IllegalLoadInfoFormat(format!(
+                "Cannot deserialize load info from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
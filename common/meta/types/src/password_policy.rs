@@ -0,0 +1,195 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The password policy of a tenant, zero disables the corresponding rule.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    // The min length of the password.
+    pub min_length: u32,
+
+    // The min upper case characters of the password.
+    pub min_upper_case_chars: u32,
+
+    // The min lower case characters of the password.
+    pub min_lower_case_chars: u32,
+
+    // The min numeric characters of the password.
+    pub min_numeric_chars: u32,
+
+    // The min special characters of the password.
+    pub min_special_chars: u32,
+
+    // The days a password is valid after it was set.
+    pub max_age_days: u32,
+
+    // The number of recent passwords which can not be reused.
+    pub history: u32,
+
+    // The failed login attempts before the user is locked.
+    pub max_retries: u32,
+
+    // The minutes a locked user must wait before logging in again.
+    pub lockout_time_mins: u32,
+}
+
+impl PasswordPolicy {
+    /// Check the length and the complexity of a new plaintext password.
+    pub fn check_password(&self, password: &str) -> Result<()> {
+        let count = |f: fn(&char) -> bool| password.chars().filter(f).count() as u32;
+
+        let checks = [
+            (
+                password.chars().count() as u32,
+                self.min_length,
+                "characters",
+            ),
+            (
+                count(char::is_ascii_uppercase),
+                self.min_upper_case_chars,
+                "upper case characters",
+            ),
+            (
+                count(char::is_ascii_lowercase),
+                self.min_lower_case_chars,
+                "lower case characters",
+            ),
+            (
+                count(char::is_ascii_digit),
+                self.min_numeric_chars,
+                "numeric characters",
+            ),
+            (
+                count(|c| !c.is_ascii_alphanumeric()),
+                self.min_special_chars,
+                "special characters",
+            ),
+        ];
+
+        for (actual, expected, what) in checks {
+            if actual < expected {
+                return Err(ErrorCode::InvalidPassword(format!(
+                    "The password must contain at least {} {}",
+                    expected, what
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_expired(&self, state: &UserPasswordState, now: u64) -> bool {
+        self.max_age_days > 0
+            && state.password_changed_at > 0
+            && now >= state.password_changed_at + self.max_age_days as u64 * 24 * 3600
+    }
+
+    pub fn is_locked(&self, state: &UserPasswordState, now: u64) -> bool {
+        state.locked_until > now
+    }
+}
+
+impl TryFrom<Vec<u8>> for PasswordPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(err) => Err(ErrorCode::IllegalPasswordPolicyFormat(format!(
+                "Cannot deserialize password policy from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
+
+/// The password history and the login failures of a user, timestamps are unix seconds.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UserPasswordState {
+    // When the current password was set, zero if unknown.
+    pub password_changed_at: u64,
+
+    // The hashes of the recent passwords, the newest is the last.
+    pub history: Vec<Vec<u8>>,
+
+    // The continuous failed login attempts.
+    pub failed_attempts: u32,
+
+    // The user can not log in before this time.
+    pub locked_until: u64,
+}
+
+impl UserPasswordState {
+    /// Record a new password hash, rejects it if it's one of the recent `policy.history` passwords.
+    pub fn change_password(
+        &mut self,
+        policy: &PasswordPolicy,
+        hash_value: &[u8],
+        now: u64,
+    ) -> Result<()> {
+        let history = policy.history as usize;
+        if history > 0 {
+            let recent = self.history.len().saturating_sub(history);
+            if self.history[recent..].iter().any(|h| h == hash_value) {
+                return Err(ErrorCode::InvalidPassword(format!(
+                    "The password can not be one of the last {} passwords",
+                    history
+                )));
+            }
+        }
+
+        self.history.push(hash_value.to_vec());
+        let keep = history.max(1);
+        if self.history.len() > keep {
+            self.history.drain(..self.history.len() - keep);
+        }
+        self.password_changed_at = now;
+        self.failed_attempts = 0;
+        self.locked_until = 0;
+        Ok(())
+    }
+
+    /// Record a login attempt, locks the user once the failures reach `policy.max_retries`.
+    pub fn login_attempt(&mut self, policy: &PasswordPolicy, success: bool, now: u64) {
+        if success {
+            self.failed_attempts = 0;
+            self.locked_until = 0;
+            return;
+        }
+
+        self.failed_attempts += 1;
+        if policy.max_retries > 0 && self.failed_attempts >= policy.max_retries {
+            self.failed_attempts = 0;
+            self.locked_until = now + policy.lockout_time_mins as u64 * 60;
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for UserPasswordState {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(state) => Ok(state),
+            Err(err) => Err(ErrorCode::IllegalPasswordPolicyFormat(format!(
+                "Cannot deserialize user password state from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
@@ -15,6 +15,7 @@ use std::str::FromStr;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use rand::RngCore;
 use sha2::Digest;
 use sha2::Sha256;
 
@@ -23,6 +24,13 @@ const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
 
+/// The stored sha256 password is `salt <concat> digest`, the digest is SHA256 applied
+/// SHA256_ROUNDS times over the salt and the password.
+const SHA256_SALT_LENGTH: usize = 16;
+const SHA256_ROUNDS: usize = 5000;
+/// The length of the unsalted SHA256( password ) stored by the previous versions.
+const SHA256_DIGEST_LENGTH: usize = 32;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
     NoPassword,
@@ -98,6 +106,21 @@ fn double_sha1(v: &[u8]) -> [u8; 20] {
     calc_sha1(&calc_sha1(v)[..])
 }
 
+fn salted_sha256(salt: &[u8], v: &[u8]) -> Vec<u8> {
+    let mut digest = Sha256::new();
+    digest.update(salt);
+    digest.update(v);
+    let mut digest = digest.finalize();
+    for _ in 1..SHA256_ROUNDS {
+        let mut m = Sha256::new();
+        m.update(digest);
+        m.update(salt);
+        m.update(v);
+        digest = m.finalize();
+    }
+    [salt, digest.as_slice()].concat()
+}
+
 impl AuthInfo {
     pub fn new(auth_type: AuthType, auth_string: &Option<String>) -> Result<AuthInfo> {
         match auth_type {
@@ -137,7 +160,24 @@ impl AuthInfo {
             .map(|s| AuthType::from_str(&s))
             .transpose()?
             .unwrap_or(old_auth_type);
-        AuthInfo::new(new_auth_type, auth_string)
+        match (self, &new_auth_type, auth_string) {
+            // Keep the salt of the sha256 password, so that the same password gets the same
+            // hash and the password history can still compare the stored hashes.
+            (
+                AuthInfo::Password {
+                    hash_value,
+                    hash_method: PasswordHashMethod::Sha256,
+                },
+                AuthType::Sha256Password,
+                Some(p),
+            ) if hash_value.len() == SHA256_SALT_LENGTH + SHA256_DIGEST_LENGTH => {
+                Ok(AuthInfo::Password {
+                    hash_value: salted_sha256(&hash_value[..SHA256_SALT_LENGTH], p.as_bytes()),
+                    hash_method: PasswordHashMethod::Sha256,
+                })
+            }
+            _ => AuthInfo::new(new_auth_type, auth_string),
+        }
     }
 
     pub fn get_type(&self) -> AuthType {
@@ -201,6 +241,31 @@ impl AuthInfo {
         Ok(s)
     }
 
+    fn restore_sha256_mysql(salt: &[u8], input: &[u8], cached_digest: &[u8]) -> Result<Vec<u8>> {
+        // SHA256( password ) XOR SHA256( SHA256( SHA256( password ) ) <concat> "20-bytes random data from server" )
+        let mut m = Sha256::new();
+        m.update(cached_digest);
+        m.update(salt);
+
+        let result: [u8; 32] = m.finalize().into();
+        if input.len() != result.len() {
+            return Err(ErrorCode::AuthenticateFailure("SHA256 check failed"));
+        }
+        let mut s = Vec::with_capacity(result.len());
+        for i in 0..result.len() {
+            s.push(input[i] ^ result[i]);
+        }
+        Ok(s)
+    }
+
+    /// The MySQL auth plugin that can verify the password of this user.
+    pub fn mysql_auth_plugin(&self) -> &'static str {
+        match self.get_password_type() {
+            Some(PasswordHashMethod::Sha256) => "caching_sha2_password",
+            _ => "mysql_native_password",
+        }
+    }
+
     pub fn auth_mysql(&self, password_input: &[u8], salt: &[u8]) -> Result<bool> {
         match self {
             AuthInfo::None => Ok(true),
@@ -212,8 +277,10 @@ impl AuthInfo {
                     let password_sha1 = AuthInfo::restore_sha1_mysql(salt, password_input, p)?;
                     Ok(*p == calc_sha1(&password_sha1))
                 }
+                // caching_sha2_password can only be verified against the digest cached by a
+                // previous full authentication, see auth_caching_sha2_mysql.
                 PasswordHashMethod::Sha256 => Err(ErrorCode::AuthenticateFailure(
-                    "login with sha256_password user for mysql protocol not supported yet.",
+                    "caching_sha2_password requires the full authentication",
                 )),
             },
             _ => Err(ErrorCode::AuthenticateFailure(format!(
@@ -222,6 +289,23 @@ impl AuthInfo {
             ))),
         }
     }
+
+    /// The digest cached in memory for the caching_sha2_password fast authentication,
+    /// SHA256( SHA256( password ) ). It is never stored in the meta.
+    pub fn caching_sha2_digest(password: &[u8]) -> Vec<u8> {
+        Sha256::digest(Sha256::digest(password)).to_vec()
+    }
+
+    /// The caching_sha2_password fast authentication, checks the scramble of the client
+    /// against the SHA256( SHA256( password ) ) cached by a previous full authentication.
+    pub fn auth_caching_sha2_mysql(
+        cached_digest: &[u8],
+        password_input: &[u8],
+        salt: &[u8],
+    ) -> Result<bool> {
+        let password_sha256 = AuthInfo::restore_sha256_mysql(salt, password_input, cached_digest)?;
+        Ok(Sha256::digest(&password_sha256)[..] == *cached_digest)
+    }
 }
 
 impl Default for AuthInfo {
@@ -251,7 +335,26 @@ impl PasswordHashMethod {
     pub fn hash(self, user_input: &[u8]) -> Vec<u8> {
         match self {
             PasswordHashMethod::DoubleSha1 => double_sha1(user_input).to_vec(),
-            PasswordHashMethod::Sha256 => Sha256::digest(user_input).to_vec(),
+            PasswordHashMethod::Sha256 => {
+                let mut salt = [0u8; SHA256_SALT_LENGTH];
+                rand::thread_rng().fill_bytes(&mut salt);
+                salted_sha256(&salt, user_input)
+            }
+        }
+    }
+
+    /// Check the plaintext password against the stored hash value.
+    pub fn verify(self, user_input: &[u8], hash_value: &[u8]) -> bool {
+        match self {
+            PasswordHashMethod::DoubleSha1 => double_sha1(user_input)[..] == *hash_value,
+            // The unsalted hash stored by the previous versions.
+            PasswordHashMethod::Sha256 if hash_value.len() == SHA256_DIGEST_LENGTH => {
+                Sha256::digest(user_input)[..] == *hash_value
+            }
+            PasswordHashMethod::Sha256 if hash_value.len() > SHA256_SALT_LENGTH => {
+                salted_sha256(&hash_value[..SHA256_SALT_LENGTH], user_input) == hash_value
+            }
+            PasswordHashMethod::Sha256 => false,
         }
     }
 
@@ -13,12 +13,39 @@
 // limitations under the License.
 
 use std::convert::TryFrom;
+use std::fmt;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UDFLanguage {
+    /// The definition is a SQL expression over the parameters.
+    Sql,
+    /// The definition is a WebAssembly module, either as WAT text or as base64 encoded binary.
+    Wasm,
+    /// The function is served by the handler of an external UDF server over Arrow Flight.
+    Python,
+}
+
+impl Default for UDFLanguage {
+    fn default() -> Self {
+        UDFLanguage::Sql
+    }
+}
+
+impl fmt::Display for UDFLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UDFLanguage::Sql => write!(f, "SQL"),
+            UDFLanguage::Wasm => write!(f, "WASM"),
+            UDFLanguage::Python => write!(f, "PYTHON"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
 pub struct UserDefinedFunction {
@@ -27,6 +54,15 @@ pub struct UserDefinedFunction {
 
     pub description: String,
     pub definition: String,
+
+    pub language: UDFLanguage,
+    /// Type names of the parameters, only used by WASM and external functions.
+    pub arg_types: Vec<String>,
+    /// Type name of the result, only used by WASM and external functions.
+    pub return_type: String,
+    /// The handler name and the address of the external UDF server.
+    pub handler: String,
+    pub address: String,
 }
 
 impl UserDefinedFunction {
@@ -36,6 +72,49 @@ impl UserDefinedFunction {
             parameters,
             definition: definition.to_string(),
             description: description.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_wasm(
+        name: &str,
+        parameters: Vec<String>,
+        arg_types: Vec<String>,
+        return_type: &str,
+        module: &str,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: module.to_string(),
+            description: description.to_string(),
+            language: UDFLanguage::Wasm,
+            arg_types,
+            return_type: return_type.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn new_python(
+        name: &str,
+        parameters: Vec<String>,
+        arg_types: Vec<String>,
+        return_type: &str,
+        handler: &str,
+        address: &str,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            description: description.to_string(),
+            language: UDFLanguage::Python,
+            arg_types,
+            return_type: return_type.to_string(),
+            handler: handler.to_string(),
+            address: address.to_string(),
+            ..Default::default()
         }
     }
 }
@@ -26,6 +26,9 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    /// The tables created in the database after the grant, the privileges are copied to every
+    /// new table when it is created.
+    FutureTables(String, String),
 }
 
 impl GrantObject {
@@ -46,6 +49,11 @@ impl GrantObject {
                 GrantObject::Table(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (GrantObject::Database(_, _), GrantObject::FutureTables(_, _)) => false,
+            (GrantObject::FutureTables(lcat, ldb), GrantObject::FutureTables(rcat, rdb)) => {
+                lcat == rcat && ldb == rdb
+            }
+            (GrantObject::FutureTables(_, _), _) => false,
         }
     }
 
@@ -54,7 +62,9 @@ impl GrantObject {
         match self {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
-            GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Table(_, _, _) | GrantObject::FutureTables(_, _) => {
+                UserPrivilegeSet::available_privileges_on_table()
+            }
         }
     }
 }
@@ -67,6 +77,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::FutureTables(ref cat, ref db) => {
+                write!(f, "FUTURE TABLES IN '{}'.'{}'", cat, db)
+            }
         }
     }
 }
@@ -162,6 +175,17 @@ impl UserGrantSet {
             .any(|e| e.verify_privilege(object, privilege))
     }
 
+    /// The privileges granted on the future tables of the database, they are granted to every
+    /// table created in the database later.
+    pub fn future_table_privileges(&self, catalog: &str, database: &str) -> UserPrivilegeSet {
+        let object = GrantObject::FutureTables(catalog.to_string(), database.to_string());
+        self.entries
+            .iter()
+            .filter(|e| e.matches_entry(&object))
+            .fold(BitFlags::empty(), |privileges, e| privileges | e.privileges)
+            .into()
+    }
+
     pub fn grant_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
         let privileges: BitFlags<UserPrivilegeType> = privileges.into();
         let mut new_entries: Vec<GrantEntry> = vec![];
@@ -128,6 +128,8 @@ impl UserOption {
 pub enum UserOptionFlag {
     TenantSetting = 1 << 0,
     ConfigReload = 1 << 1,
+    // Run in the restricted sandbox profile, for the untrusted users.
+    Sandbox = 1 << 2,
 }
 
 impl std::fmt::Display for UserOptionFlag {
@@ -135,6 +137,7 @@ impl std::fmt::Display for UserOptionFlag {
         match self {
             UserOptionFlag::TenantSetting => write!(f, "TENANTSETTING"),
             UserOptionFlag::ConfigReload => write!(f, "CONFIGRELOAD"),
+            UserOptionFlag::Sandbox => write!(f, "SANDBOX"),
         }
     }
 }
@@ -0,0 +1,67 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum UserSettingValue {
+    UInt64(u64),
+    String(Vec<u8>),
+}
+
+impl fmt::Display for UserSettingValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserSettingValue::UInt64(v) => write!(f, "{}", v),
+            UserSettingValue::String(v) => write!(f, "{}", String::from_utf8_lossy(v)),
+        }
+    }
+}
+
+/// A setting of the GLOBAL scope, it overrides the default value of the setting for all the
+/// sessions of the tenant.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UserSetting {
+    pub name: String,
+    pub value: UserSettingValue,
+}
+
+impl UserSetting {
+    pub fn create(name: &str, value: UserSettingValue) -> UserSetting {
+        UserSetting {
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for UserSetting {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(setting) => Ok(setting),
+            Err(serialize_error) => Err(ErrorCode::IllegalUserSettingFormat(format!(
+                "Cannot deserialize setting from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
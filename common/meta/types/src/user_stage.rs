@@ -211,11 +211,52 @@ impl FromStr for OnErrorMode {
     }
 }
 
+/// How the columns of a staged file are mapped onto the table columns.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchByColumnName {
+    /// Map the columns by position.
+    None,
+    CaseSensitive,
+    CaseInsensitive,
+}
+
+impl Default for MatchByColumnName {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl MatchByColumnName {
+    /// Whether the file column `file_name` maps onto the table column `table_name`.
+    pub fn matches(&self, table_name: &str, file_name: &str) -> bool {
+        match self {
+            MatchByColumnName::CaseInsensitive => table_name.eq_ignore_ascii_case(file_name),
+            _ => table_name == file_name,
+        }
+    }
+}
+
+impl FromStr for MatchByColumnName {
+    type Err = String;
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "" | "NONE" => Ok(MatchByColumnName::None),
+            "CASE_SENSITIVE" => Ok(MatchByColumnName::CaseSensitive),
+            "CASE_INSENSITIVE" => Ok(MatchByColumnName::CaseInsensitive),
+            v => Err(format!(
+                "Unknown MatchByColumnName mode:{:?}, must one of {{ CASE_SENSITIVE | CASE_INSENSITIVE | NONE }}",
+                v
+            )),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
 #[serde(default)]
 pub struct CopyOptions {
     pub on_error: OnErrorMode,
     pub size_limit: usize,
+    pub match_by_column_name: MatchByColumnName,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
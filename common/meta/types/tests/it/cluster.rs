@@ -22,6 +22,7 @@ fn test_node_info_ip_port() -> Result<()> {
         cpu_nums: 1,
         version: 1,
         flight_address: "1.2.3.4:123".to_string(),
+        ..Default::default()
     };
 
     let (ip, port) = n.ip_port()?;
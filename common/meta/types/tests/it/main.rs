@@ -14,6 +14,7 @@
 
 mod cluster;
 mod match_seq;
+mod password_policy;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
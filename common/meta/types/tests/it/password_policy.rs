@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::exception::Result;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::UserPasswordState;
+
+#[test]
+fn test_password_policy_check_password() -> Result<()> {
+    let policy = PasswordPolicy::default();
+    assert!(policy.check_password("").is_ok());
+
+    let policy = PasswordPolicy {
+        min_length: 8,
+        min_upper_case_chars: 1,
+        min_lower_case_chars: 1,
+        min_numeric_chars: 2,
+        min_special_chars: 1,
+        ..Default::default()
+    };
+    assert!(policy.check_password("Abc12#xyz").is_ok());
+
+    let err = policy.check_password("Ab12#x").unwrap_err();
+    assert_eq!(
+        "Code: 2922, displayText = The password must contain at least 8 characters.",
+        err.to_string()
+    );
+    assert!(policy.check_password("abc12#xyz").is_err());
+    assert!(policy.check_password("ABC12#XYZ").is_err());
+    assert!(policy.check_password("Abc1#xyzw").is_err());
+    assert!(policy.check_password("Abc12xyzw").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_user_password_state_history() -> Result<()> {
+    let policy = PasswordPolicy {
+        history: 2,
+        ..Default::default()
+    };
+    let mut state = UserPasswordState::default();
+
+    state.change_password(&policy, b"h1", 10)?;
+    state.change_password(&policy, b"h2", 20)?;
+    assert_eq!(state.password_changed_at, 20);
+    assert!(state.change_password(&policy, b"h1", 30).is_err());
+    assert!(state.change_password(&policy, b"h2", 30).is_err());
+
+    state.change_password(&policy, b"h3", 30)?;
+    assert_eq!(state.history, vec![b"h2".to_vec(), b"h3".to_vec()]);
+    state.change_password(&policy, b"h1", 40)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_user_password_state_lockout_and_expiry() -> Result<()> {
+    let policy = PasswordPolicy {
+        max_age_days: 1,
+        max_retries: 2,
+        lockout_time_mins: 10,
+        ..Default::default()
+    };
+    let mut state = UserPasswordState::default();
+    assert!(!policy.is_expired(&state, 1_000_000));
+
+    state.change_password(&policy, b"h1", 100)?;
+    assert!(!policy.is_expired(&state, 100 + 3600));
+    assert!(policy.is_expired(&state, 100 + 24 * 3600));
+
+    state.login_attempt(&policy, false, 200);
+    assert!(!policy.is_locked(&state, 200));
+    state.login_attempt(&policy, true, 210);
+    state.login_attempt(&policy, false, 220);
+    assert!(!policy.is_locked(&state, 220));
+    state.login_attempt(&policy, false, 230);
+    assert!(policy.is_locked(&state, 230));
+    assert!(policy.is_locked(&state, 230 + 599));
+    assert!(!policy.is_locked(&state, 230 + 600));
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_exception::exception::Result;
+use common_meta_types::UDFLanguage;
 use common_meta_types::UserDefinedFunction;
 
 #[test]
@@ -30,3 +31,49 @@ fn test_udf() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_wasm_udf() -> Result<()> {
+    let udf = UserDefinedFunction::new_wasm(
+        "plus",
+        vec!["a".to_string(), "b".to_string()],
+        vec!["Int64".to_string(), "Int64".to_string()],
+        "Int64",
+        "(module)",
+        "",
+    );
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert_eq!(de.language, UDFLanguage::Wasm);
+
+    // Functions stored before the language was introduced are SQL functions.
+    let legacy = r#"{"name":"f","parameters":["p"],"description":"","definition":"p"}"#;
+    let de = UserDefinedFunction::try_from(legacy.as_bytes().to_vec())?;
+    assert_eq!(de.language, UDFLanguage::Sql);
+    assert!(de.arg_types.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_python_udf() -> Result<()> {
+    let udf = UserDefinedFunction::new_python(
+        "gcd",
+        vec!["a".to_string(), "b".to_string()],
+        vec!["Int32".to_string(), "Int32".to_string()],
+        "Int32",
+        "gcd",
+        "grpc://127.0.0.1:8815",
+        "",
+    );
+    let ser = serde_json::to_string(&udf)?;
+
+    let de = UserDefinedFunction::try_from(ser.into_bytes())?;
+    assert_eq!(udf, de);
+    assert_eq!(de.language, UDFLanguage::Python);
+    assert_eq!(de.address, "grpc://127.0.0.1:8815");
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ use common_exception::exception::Result;
 use common_meta_types::GrantEntry;
 use common_meta_types::GrantObject;
 use common_meta_types::UserGrantSet;
+use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
 use enumflags2::make_bitflags;
 
@@ -77,6 +78,21 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            rhs: GrantObject::FutureTables("default".into(), "db1".into()),
+            expect: true,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -200,3 +216,31 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_future_tables() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+
+    grants.grant_privileges(
+        &GrantObject::FutureTables("default".into(), "db1".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        &GrantObject::FutureTables("default".into(), "db1".into()),
+        make_bitflags!(UserPrivilegeType::{Insert}).into(),
+    );
+    assert_eq!(1, grants.entries().len());
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "table1".into()),
+        UserPrivilegeType::Select
+    ));
+    assert_eq!(
+        grants.future_table_privileges("default", "db1"),
+        make_bitflags!(UserPrivilegeType::{Select | Insert}).into()
+    );
+    assert_eq!(
+        grants.future_table_privileges("default", "db2"),
+        UserPrivilegeSet::empty()
+    );
+    Ok(())
+}
@@ -49,3 +49,82 @@ fn test_user_info() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_auth_mysql_caching_sha2() -> Result<()> {
+    use sha2::Digest;
+    use sha2::Sha256;
+
+    let auth_info = AuthInfo::create(
+        &Some("sha256_password".to_string()),
+        &Some("password".to_string()),
+    )?;
+    assert_eq!(auth_info.mysql_auth_plugin(), "caching_sha2_password");
+
+    // The scramble sent by the client:
+    // SHA256( password ) XOR SHA256( SHA256( SHA256( password ) ) <concat> salt )
+    let scramble = |password: &[u8], salt: &[u8]| {
+        let stage1 = Sha256::digest(password);
+        let stage2 = Sha256::digest(&stage1);
+        let mut m = Sha256::new();
+        m.update(&stage2);
+        m.update(salt);
+        let xor = m.finalize();
+        stage1
+            .iter()
+            .zip(xor.iter())
+            .map(|(l, r)| l ^ r)
+            .collect::<Vec<_>>()
+    };
+
+    // The stored hash is never enough for the fast authentication.
+    let salt = [7u8; 20];
+    assert!(auth_info
+        .auth_mysql(&scramble(b"password", &salt), &salt)
+        .is_err());
+
+    let digest = AuthInfo::caching_sha2_digest(b"password");
+    let fast_auth = |input: &[u8]| AuthInfo::auth_caching_sha2_mysql(&digest, input, &salt);
+    assert!(fast_auth(&scramble(b"password", &salt))?);
+    assert!(!fast_auth(&scramble(b"wrong", &salt))?);
+    assert!(fast_auth(&[1u8; 20]).is_err());
+
+    let auth_info = AuthInfo::create(&None, &Some("password".to_string()))?;
+    assert_eq!(auth_info.mysql_auth_plugin(), "mysql_native_password");
+
+    Ok(())
+}
+
+#[test]
+fn test_sha256_password_hash() -> Result<()> {
+    use sha2::Digest;
+    use sha2::Sha256;
+
+    let method = PasswordHashMethod::Sha256;
+
+    // Salted, the same password is hashed differently for different users.
+    let hash_value = method.hash(b"password");
+    assert_ne!(hash_value, method.hash(b"password"));
+    assert_ne!(hash_value, Sha256::digest(b"password").to_vec());
+    assert!(method.verify(b"password", &hash_value));
+    assert!(!method.verify(b"wrong", &hash_value));
+
+    // The unsalted hash stored by the previous versions.
+    let hash_value = Sha256::digest(b"password").to_vec();
+    assert!(method.verify(b"password", &hash_value));
+    assert!(!method.verify(b"wrong", &hash_value));
+
+    // The salt is kept by ALTER USER, so the password history can compare the hashes.
+    let auth_info = AuthInfo::create(
+        &Some("sha256_password".to_string()),
+        &Some("password".to_string()),
+    )?;
+    let same = auth_info.alter(&None, &Some("password".to_string()))?;
+    assert_eq!(auth_info, same);
+    let other = auth_info.alter(&None, &Some("other".to_string()))?;
+    assert_ne!(auth_info, other);
+    let password = other.get_password().unwrap();
+    assert!(method.verify(b"other", &password));
+
+    Ok(())
+}
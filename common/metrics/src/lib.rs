@@ -27,4 +27,13 @@ pub use metrics_exporter_prometheus::PrometheusHandle;
 pub use recorder::init_default_metrics_recorder;
 pub use recorder::label_counter;
 pub use recorder::label_counter_with_val;
+pub use recorder::label_counter_with_val_and_labels;
+pub use recorder::label_gauge_with_val_and_labels;
+pub use recorder::label_histogram_with_val;
 pub use recorder::try_handle;
+pub use recorder::LABEL_KEY_CACHE;
+pub use recorder::LABEL_KEY_CLUSTER;
+pub use recorder::LABEL_KEY_HANDLER;
+pub use recorder::LABEL_KEY_QUERY_KIND;
+pub use recorder::LABEL_KEY_QUERY_TAG;
+pub use recorder::LABEL_KEY_TENANT;
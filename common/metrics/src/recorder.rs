@@ -18,6 +18,8 @@ use std::sync::Once;
 use common_base::infallible::RwLock;
 use common_tracing::tracing;
 use metrics::counter;
+use metrics::gauge;
+use metrics::histogram;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use metrics_exporter_prometheus::PrometheusHandle;
 use once_cell::sync::Lazy;
@@ -27,6 +29,10 @@ static PROMETHEUS_HANDLE: Lazy<Arc<RwLock<Option<PrometheusHandle>>>> =
 
 pub const LABEL_KEY_TENANT: &str = "tenant";
 pub const LABEL_KEY_CLUSTER: &str = "cluster_name";
+pub const LABEL_KEY_HANDLER: &str = "handler";
+pub const LABEL_KEY_QUERY_KIND: &str = "kind";
+pub const LABEL_KEY_QUERY_TAG: &str = "query_tag";
+pub const LABEL_KEY_CACHE: &str = "cache";
 
 #[inline]
 pub fn label_counter(name: &'static str, tenant_id: &str, cluster_id: &str) {
@@ -42,6 +48,29 @@ pub fn label_counter_with_val(name: &'static str, val: u64, tenant_id: &str, clu
     counter!(name, val, &labels);
 }
 
+#[inline]
+pub fn label_counter_with_val_and_labels(
+    name: &'static str,
+    labels: &[(&'static str, String)],
+    val: u64,
+) {
+    counter!(name, val, labels.to_vec());
+}
+
+#[inline]
+pub fn label_gauge_with_val_and_labels(
+    name: &'static str,
+    labels: &[(&'static str, String)],
+    val: f64,
+) {
+    gauge!(name, val, labels.to_vec());
+}
+
+#[inline]
+pub fn label_histogram_with_val(name: &'static str, labels: &[(&'static str, String)], val: f64) {
+    histogram!(name, val, labels.to_vec());
+}
+
 pub fn init_default_metrics_recorder() {
     static START: Once = Once::new();
     START.call_once(init_prometheus_recorder)
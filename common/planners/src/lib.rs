@@ -16,6 +16,7 @@ mod plan_aggregator_final;
 mod plan_aggregator_partial;
 mod plan_alter_cluster_key;
 mod plan_broadcast;
+mod plan_cache_drop;
 mod plan_call;
 mod plan_copy;
 mod plan_database_create;
@@ -23,6 +24,7 @@ mod plan_database_drop;
 mod plan_database_rename;
 mod plan_database_show_create;
 mod plan_database_undrop;
+mod plan_delete;
 mod plan_empty;
 mod plan_explain;
 mod plan_expression;
@@ -67,29 +69,38 @@ mod plan_role_revoke;
 mod plan_select;
 mod plan_setting;
 mod plan_show;
+mod plan_show_caches;
+mod plan_show_clusters;
 mod plan_show_databases;
 mod plan_show_engines;
 mod plan_show_functions;
 mod plan_show_grants;
 mod plan_show_metrics;
 mod plan_show_processlist;
+mod plan_show_replication_status;
 mod plan_show_roles;
 mod plan_show_settings;
 mod plan_show_stages;
 mod plan_show_tab_stat;
 mod plan_show_tables;
 mod plan_show_users;
+mod plan_show_variables;
 mod plan_sink;
 mod plan_sort;
 mod plan_subqueries_set;
 mod plan_table_create;
 mod plan_table_describe;
 mod plan_table_drop;
+mod plan_table_drop_partition;
+mod plan_table_export_snapshot;
 mod plan_table_optimize;
+mod plan_table_promote;
 mod plan_table_rename;
 mod plan_table_show_create;
 mod plan_table_truncate;
 mod plan_table_undrop;
+mod plan_transaction;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -103,11 +114,13 @@ mod plan_user_udf_drop;
 mod plan_view_alter;
 mod plan_view_create;
 mod plan_view_drop;
+mod plan_view_show_create;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_alter_cluster_key::AlterClusterKeyPlan;
 pub use plan_broadcast::BroadcastPlan;
+pub use plan_cache_drop::DropCachePlan;
 pub use plan_call::CallPlan;
 pub use plan_copy::CopyMode;
 pub use plan_copy::CopyPlan;
@@ -118,6 +131,7 @@ pub use plan_database_rename::RenameDatabaseEntity;
 pub use plan_database_rename::RenameDatabasePlan;
 pub use plan_database_show_create::ShowCreateDatabasePlan;
 pub use plan_database_undrop::UnDropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
 pub use plan_explain::ExplainType;
@@ -134,6 +148,8 @@ pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
 pub use plan_expression_common::find_aggregate_exprs_in_expr;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_non_deterministic_exprs;
+pub use plan_expression_common::find_subquery_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -193,18 +209,22 @@ pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
 pub use plan_show::PlanShowKind;
 pub use plan_show::ShowPlan;
+pub use plan_show_caches::ShowCachesPlan;
+pub use plan_show_clusters::ShowClustersPlan;
 pub use plan_show_databases::ShowDatabasesPlan;
 pub use plan_show_engines::ShowEnginesPlan;
 pub use plan_show_functions::ShowFunctionsPlan;
 pub use plan_show_grants::ShowGrantsPlan;
 pub use plan_show_metrics::ShowMetricsPlan;
 pub use plan_show_processlist::ShowProcessListsPlan;
+pub use plan_show_replication_status::ShowReplicationStatusPlan;
 pub use plan_show_roles::ShowRolesPlan;
 pub use plan_show_settings::ShowSettingsPlan;
 pub use plan_show_stages::ShowStagesPlan;
 pub use plan_show_tab_stat::ShowTabStatPlan;
 pub use plan_show_tables::ShowTablesPlan;
 pub use plan_show_users::ShowUsersPlan;
+pub use plan_show_variables::ShowVariablesPlan;
 pub use plan_sink::SinkPlan;
 pub use plan_sink::SINK_SCHEMA;
 pub use plan_sort::SortPlan;
@@ -213,13 +233,20 @@ pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_describe::DescribeTablePlan;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_table_drop_partition::DropTablePartitionPlan;
+pub use plan_table_drop_partition::PartitionRef;
+pub use plan_table_export_snapshot::ExportTableSnapshotPlan;
 pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
+pub use plan_table_promote::PromoteTablePlan;
 pub use plan_table_rename::RenameTableEntity;
 pub use plan_table_rename::RenameTablePlan;
 pub use plan_table_show_create::ShowCreateTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
 pub use plan_table_undrop::UnDropTablePlan;
+pub use plan_transaction::TransactionAction;
+pub use plan_transaction::TransactionPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -233,3 +260,4 @@ pub use plan_user_udf_drop::DropUserUDFPlan;
 pub use plan_view_alter::AlterViewPlan;
 pub use plan_view_create::CreateViewPlan;
 pub use plan_view_drop::DropViewPlan;
+pub use plan_view_show_create::ShowCreateViewPlan;
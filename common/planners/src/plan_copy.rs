@@ -16,7 +16,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
-use common_datavalues::DataSchemaRef;
+use common_datavalues::prelude::*;
 use common_meta_types::MetaId;
 
 use crate::PlanNode;
@@ -79,6 +79,12 @@ pub enum CopyMode {
     IntoStage {
         stage_table_info: StageTableInfo,
         query: Box<PlanNode>,
+        /// The rows of a file are all before the rows of the next file, see `ORDERED`.
+        ordered: bool,
+        /// The bytes of a file at most, 0 is unlimited, see `MAX_FILE_SIZE`.
+        max_file_size: usize,
+        /// The files are written while the query runs, see `SELECT ... INTO`.
+        spool: bool,
     },
 }
 
@@ -86,9 +92,12 @@ impl CopyPlan {
     pub fn schema(&self) -> DataSchemaRef {
         match &self.copy_mode {
             CopyMode::IntoTable { schema, .. } => schema.clone(),
-            CopyMode::IntoStage {
-                stage_table_info, ..
-            } => stage_table_info.schema.clone(),
+            // The files written.
+            CopyMode::IntoStage { .. } => DataSchemaRefExt::create(vec![
+                DataField::new("file_name", Vu8::to_data_type()),
+                DataField::new("rows", u64::to_data_type()),
+                DataField::new("bytes", u64::to_data_type()),
+            ]),
         }
     }
 }
@@ -118,9 +127,21 @@ impl Debug for CopyPlan {
             CopyMode::IntoStage {
                 stage_table_info,
                 query,
+                ordered,
+                max_file_size,
+                spool,
             } => {
                 write!(f, "Copy into {:?}", stage_table_info)?;
                 write!(f, ", query: {:?})", query)?;
+                if *ordered {
+                    write!(f, " ,ordered")?;
+                }
+                if *max_file_size > 0 {
+                    write!(f, " ,max_file_size:{}", max_file_size)?;
+                }
+                if *spool {
+                    write!(f, " ,spool")?;
+                }
             }
         }
         Ok(())
@@ -23,6 +23,7 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    Pruning,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
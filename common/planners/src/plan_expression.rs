@@ -28,10 +28,18 @@ use crate::ExpressionVisitor;
 use crate::PlanNode;
 
 static OP_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
-    ["database", "version", "current_user", "user"]
-        .iter()
-        .copied()
-        .collect()
+    [
+        "database",
+        "version",
+        "current_user",
+        "user",
+        "current_role",
+        "connection_id",
+        "last_query_id",
+    ]
+    .iter()
+    .copied()
+    .collect()
 });
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
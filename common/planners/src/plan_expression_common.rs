@@ -52,6 +52,30 @@ pub fn find_aggregate_exprs_in_expr(expr: &Expression) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `Expression::Subquery` and `Expression::ScalarSubquery`.
+/// They are returned in order of occurrence (depth first), with duplicates omitted.
+pub fn find_subquery_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(
+            nest_exprs,
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. }
+        )
+    })
+}
+
+/// Collect all deeply nested `Expression::ScalarFunction` which may return different
+/// results for the same arguments, e.g. `rand()`. Unknown functions are taken as
+/// non-deterministic.
+pub fn find_non_deterministic_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => !FunctionFactory::instance()
+            .get_features(op)
+            .map(|features| features.is_deterministic)
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -33,10 +33,13 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DropCachePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
+use crate::DropTablePartitionPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -44,6 +47,7 @@ use crate::DropUserUDFPlan;
 use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTableSnapshotPlan;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
@@ -56,6 +60,7 @@ use crate::LimitPlan;
 use crate::ListPlan;
 use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
+use crate::PromoteTablePlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameDatabasePlan;
@@ -66,13 +71,16 @@ use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
+use crate::ShowCreateViewPlan;
 use crate::ShowPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
 use crate::UnDropDatabasePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 #[allow(clippy::large_enum_variant)]
@@ -105,6 +113,10 @@ pub enum PlanNode {
     // Insert.
     Insert(InsertPlan),
 
+    // Delete and update.
+    Delete(DeletePlan),
+    Update(UpdatePlan),
+
     // Copy.
     Copy(CopyPlan),
 
@@ -116,6 +128,9 @@ pub enum PlanNode {
 
     // Alter.
     AlterClusterKey(AlterClusterKeyPlan),
+    ExportTableSnapshot(ExportTableSnapshotPlan),
+    PromoteTable(PromoteTablePlan),
+    DropTablePartition(DropTablePartitionPlan),
 
     // Show.
     Show(ShowPlan),
@@ -140,6 +155,7 @@ pub enum PlanNode {
     // View.
     CreateView(CreateViewPlan),
     DropView(DropViewPlan),
+    ShowCreateView(ShowCreateViewPlan),
     AlterView(AlterViewPlan),
 
     // User.
@@ -177,6 +193,12 @@ pub enum PlanNode {
 
     // Kill.
     Kill(KillPlan),
+
+    // Cache.
+    DropCache(DropCachePlan),
+
+    // Transaction.
+    Transaction(TransactionPlan),
 }
 
 impl PlanNode {
@@ -209,6 +231,8 @@ impl PlanNode {
 
             // Insert.
             PlanNode::Insert(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
 
             // Copy.
             PlanNode::Copy(v) => v.schema(),
@@ -240,6 +264,7 @@ impl PlanNode {
             PlanNode::CreateView(v) => v.schema(),
             PlanNode::AlterView(v) => v.schema(),
             PlanNode::DropView(v) => v.schema(),
+            PlanNode::ShowCreateView(v) => v.schema(),
 
             // User.
             PlanNode::CreateUser(v) => v.schema(),
@@ -280,8 +305,17 @@ impl PlanNode {
             // Kill.
             PlanNode::Kill(v) => v.schema(),
 
+            // Cache.
+            PlanNode::DropCache(v) => v.schema(),
+
+            // Transaction.
+            PlanNode::Transaction(v) => v.schema(),
+
             // Alter
             PlanNode::AlterClusterKey(v) => v.schema(),
+            PlanNode::ExportTableSnapshot(v) => v.schema(),
+            PlanNode::PromoteTable(v) => v.schema(),
+            PlanNode::DropTablePartition(v) => v.schema(),
         }
     }
 
@@ -313,6 +347,8 @@ impl PlanNode {
 
             // Insert.
             PlanNode::Insert(_) => "InsertPlan",
+            PlanNode::Delete(_) => "DeletePlan",
+            PlanNode::Update(_) => "UpdatePlan",
 
             // Copy.
             PlanNode::Copy(_) => "CopyPlan",
@@ -344,6 +380,7 @@ impl PlanNode {
             PlanNode::CreateView(_) => "CreateViewPlan",
             PlanNode::AlterView(_) => "AlterViewPlan",
             PlanNode::DropView(_) => "DropViewPlan",
+            PlanNode::ShowCreateView(_) => "ShowCreateViewPlan",
 
             // User.
             PlanNode::CreateUser(_) => "CreateUser",
@@ -384,8 +421,17 @@ impl PlanNode {
             // Kill.
             PlanNode::Kill(_) => "KillQuery",
 
+            // Cache.
+            PlanNode::DropCache(_) => "DropCachePlan",
+
+            // Transaction.
+            PlanNode::Transaction(_) => "TransactionPlan",
+
             // Alter.
             PlanNode::AlterClusterKey(_) => "AlterClusterKeyPlan",
+            PlanNode::ExportTableSnapshot(_) => "ExportTableSnapshotPlan",
+            PlanNode::PromoteTable(_) => "PromoteTablePlan",
+            PlanNode::DropTablePartition(_) => "DropTablePartitionPlan",
         }
     }
 
@@ -215,10 +215,16 @@ impl PlanBuilder {
     }
 
     pub fn sort(&self, exprs: &[Expression]) -> Result<Self> {
+        self.sort_limit(exprs, None)
+    }
+
+    /// Apply a sort which only keeps the first `limit` rows (Top-N)
+    pub fn sort_limit(&self, exprs: &[Expression], limit: Option<usize>) -> Result<Self> {
         Ok(Self::from(&PlanNode::Sort(SortPlan {
             order_by: exprs.to_vec(),
             schema: self.plan.schema(),
             input: self.wrap_subquery_plan(exprs)?,
+            limit,
         })))
     }
 
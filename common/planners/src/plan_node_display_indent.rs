@@ -28,12 +28,15 @@ use crate::CreateRolePlan;
 use crate::CreateTablePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
+use crate::DropTablePartitionPlan;
 use crate::DropTablePlan;
+use crate::ExportTableSnapshotPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
+use crate::PromoteTablePlan;
 use crate::ReadDataSourcePlan;
 use crate::RenameDatabasePlan;
 use crate::RenameTablePlan;
@@ -87,6 +90,9 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Copy(plan) => Self::format_copy(f, plan),
             PlanNode::Call(plan) => Self::format_call(f, plan),
             PlanNode::AlterClusterKey(plan) => Self::format_alter_cluster_key(f, plan),
+            PlanNode::DropTablePartition(plan) => Self::format_drop_table_partition(f, plan),
+            PlanNode::PromoteTable(plan) => Self::format_promote_table(f, plan),
+            PlanNode::ExportTableSnapshot(plan) => Self::format_export_table_snapshot(f, plan),
             _ => {
                 let mut printed = true;
 
@@ -202,6 +208,10 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             )?;
         }
 
+        if let Some(limit) = plan.limit {
+            write!(f, ", limit: {}", limit)?;
+        }
+
         fmt::Result::Ok(())
     }
 
@@ -367,4 +377,35 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         )?;
         write!(f, " cluster by {:?}", plan.cluster_keys)
     }
+
+    fn format_drop_table_partition(
+        f: &mut Formatter,
+        plan: &DropTablePartitionPlan,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "Alter table {:}.{:}",
+            plan.database_name, plan.table_name
+        )?;
+        write!(f, " drop partition {:?}", plan.partitions)
+    }
+
+    fn format_promote_table(f: &mut Formatter, plan: &PromoteTablePlan) -> fmt::Result {
+        write!(
+            f,
+            "Alter table {:}.{:} promote",
+            plan.database_name, plan.table_name
+        )
+    }
+
+    fn format_export_table_snapshot(
+        f: &mut Formatter,
+        plan: &ExportTableSnapshotPlan,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "Alter table {:}.{:} export snapshot to @{:}{:}",
+            plan.database_name, plan.table_name, plan.stage.stage_name, plan.path
+        )
+    }
 }
@@ -39,10 +39,13 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DropCachePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
+use crate::DropTablePartitionPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -50,6 +53,7 @@ use crate::DropUserUDFPlan;
 use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTableSnapshotPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::ExpressionRewriter;
@@ -67,6 +71,7 @@ use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
+use crate::PromoteTablePlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameDatabasePlan;
@@ -77,12 +82,15 @@ use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
+use crate::ShowCreateViewPlan;
 use crate::ShowPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
 use crate::UnDropDatabasePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
@@ -133,6 +141,8 @@ pub trait PlanRewriter: Sized {
 
             // Insert.
             PlanNode::Insert(plan) => self.rewrite_insert_into(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
 
             // Copy.
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
@@ -163,6 +173,7 @@ pub trait PlanRewriter: Sized {
             PlanNode::CreateView(plan) => self.rewrite_create_view(plan),
             PlanNode::AlterView(plan) => self.rewrite_alter_view(plan),
             PlanNode::DropView(plan) => self.rewrite_drop_view(plan),
+            PlanNode::ShowCreateView(plan) => self.rewrite_show_create_view(plan),
 
             // User.
             PlanNode::CreateUser(plan) => self.create_user(plan),
@@ -201,8 +212,17 @@ pub trait PlanRewriter: Sized {
             // Kill.
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
 
+            // Cache.
+            PlanNode::DropCache(plan) => self.rewrite_drop_cache(plan),
+
+            // Transaction.
+            PlanNode::Transaction(plan) => self.rewrite_transaction(plan),
+
             // Alter.
             PlanNode::AlterClusterKey(plan) => self.rewrite_alter_cluster_key(plan),
+            PlanNode::ExportTableSnapshot(plan) => self.rewrite_export_table_snapshot(plan),
+            PlanNode::PromoteTable(plan) => self.rewrite_promote_table(plan),
+            PlanNode::DropTablePartition(plan) => self.rewrite_drop_table_partition(plan),
         }
     }
 
@@ -323,7 +343,9 @@ pub trait PlanRewriter: Sized {
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
-        PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
+        PlanBuilder::from(&new_input)
+            .sort_limit(&new_order_by, plan.limit)?
+            .build()
     }
 
     fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
@@ -384,6 +406,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::AlterView(plan.clone()))
     }
 
+    fn rewrite_show_create_view(&mut self, plan: &ShowCreateViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::ShowCreateView(plan.clone()))
+    }
+
     fn rewrite_create_database(&mut self, plan: &CreateDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::CreateDatabase(plan.clone()))
     }
@@ -428,6 +454,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Insert(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
     fn rewrite_copy(&mut self, plan: &CopyPlan) -> Result<PlanNode> {
         Ok(PlanNode::Copy(plan.clone()))
     }
@@ -448,6 +482,14 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Kill(plan.clone()))
     }
 
+    fn rewrite_drop_cache(&mut self, plan: &DropCachePlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropCache(plan.clone()))
+    }
+
+    fn rewrite_transaction(&mut self, plan: &TransactionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::Transaction(plan.clone()))
+    }
+
     fn create_user(&mut self, plan: &CreateUserPlan) -> Result<PlanNode> {
         Ok(PlanNode::CreateUser(plan.clone()))
     }
@@ -519,6 +561,21 @@ pub trait PlanRewriter: Sized {
     fn rewrite_alter_cluster_key(&mut self, plan: &AlterClusterKeyPlan) -> Result<PlanNode> {
         Ok(PlanNode::AlterClusterKey(plan.clone()))
     }
+
+    fn rewrite_export_table_snapshot(
+        &mut self,
+        plan: &ExportTableSnapshotPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::ExportTableSnapshot(plan.clone()))
+    }
+
+    fn rewrite_promote_table(&mut self, plan: &PromoteTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::PromoteTable(plan.clone()))
+    }
+
+    fn rewrite_drop_table_partition(&mut self, plan: &DropTablePartitionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropTablePartition(plan.clone()))
+    }
 }
 
 pub struct RewriteHelper {}
@@ -32,10 +32,13 @@ use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
 use crate::CreateUserUDFPlan;
 use crate::CreateViewPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DescribeUserStagePlan;
+use crate::DropCachePlan;
 use crate::DropDatabasePlan;
 use crate::DropRolePlan;
+use crate::DropTablePartitionPlan;
 use crate::DropTablePlan;
 use crate::DropUserPlan;
 use crate::DropUserStagePlan;
@@ -43,6 +46,7 @@ use crate::DropUserUDFPlan;
 use crate::DropViewPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
+use crate::ExportTableSnapshotPlan;
 use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
@@ -57,6 +61,7 @@ use crate::ListPlan;
 use crate::OptimizeTablePlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
+use crate::PromoteTablePlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
 use crate::RenameDatabasePlan;
@@ -67,12 +72,15 @@ use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateDatabasePlan;
 use crate::ShowCreateTablePlan;
+use crate::ShowCreateViewPlan;
 use crate::ShowPlan;
 use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
 use crate::UnDropDatabasePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
@@ -145,6 +153,8 @@ pub trait PlanVisitor {
 
             // Insert.
             PlanNode::Insert(plan) => self.visit_insert_into(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
 
             // Copy.
             PlanNode::Copy(plan) => self.visit_copy(plan),
@@ -175,6 +185,7 @@ pub trait PlanVisitor {
             PlanNode::CreateView(v) => self.visit_create_view(v),
             PlanNode::AlterView(v) => self.visit_alter_view(v),
             PlanNode::DropView(v) => self.visit_drop_view(v),
+            PlanNode::ShowCreateView(v) => self.visit_show_create_view(v),
 
             // User.
             PlanNode::CreateUser(plan) => self.visit_create_user(plan),
@@ -213,8 +224,17 @@ pub trait PlanVisitor {
             // Kill.
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
 
+            // Cache.
+            PlanNode::DropCache(plan) => self.visit_drop_cache(plan),
+
+            // Transaction.
+            PlanNode::Transaction(plan) => self.visit_transaction(plan),
+
             // Alter.
             PlanNode::AlterClusterKey(plan) => self.visit_alter_cluster_key(plan),
+            PlanNode::ExportTableSnapshot(plan) => self.visit_export_table_snapshot(plan),
+            PlanNode::PromoteTable(plan) => self.visit_promote_table(plan),
+            PlanNode::DropTablePartition(plan) => self.visit_drop_table_partition(plan),
         }
     }
 
@@ -418,6 +438,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_copy(&mut self, _: &CopyPlan) -> Result<()> {
         Ok(())
     }
@@ -446,9 +474,21 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_show_create_view(&mut self, _: &ShowCreateViewPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_drop_cache(&mut self, _: &DropCachePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_transaction(&mut self, _: &TransactionPlan) -> Result<()> {
+        Ok(())
+    }
     fn visit_append(&mut self, _: &SinkPlan) -> Result<()> {
         Ok(())
     }
@@ -484,4 +524,16 @@ pub trait PlanVisitor {
     fn visit_alter_cluster_key(&mut self, _: &AlterClusterKeyPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_export_table_snapshot(&mut self, _: &ExportTableSnapshotPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_promote_table(&mut self, _: &PromoteTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_table_partition(&mut self, _: &DropTablePartitionPlan) -> Result<()> {
+        Ok(())
+    }
 }
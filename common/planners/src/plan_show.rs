@@ -18,17 +18,21 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 
 use crate::plan_show_tab_stat::ShowTabStatPlan;
+use crate::ShowCachesPlan;
+use crate::ShowClustersPlan;
 use crate::ShowDatabasesPlan;
 use crate::ShowEnginesPlan;
 use crate::ShowFunctionsPlan;
 use crate::ShowGrantsPlan;
 use crate::ShowMetricsPlan;
 use crate::ShowProcessListsPlan;
+use crate::ShowReplicationStatusPlan;
 use crate::ShowRolesPlan;
 use crate::ShowSettingsPlan;
 use crate::ShowStagesPlan;
 use crate::ShowTablesPlan;
 use crate::ShowUsersPlan;
+use crate::ShowVariablesPlan;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub enum PlanShowKind {
@@ -48,6 +52,7 @@ pub enum ShowPlan {
     ShowEngines(ShowEnginesPlan),
     ShowFunctions(ShowFunctionsPlan),
     ShowMetrics(ShowMetricsPlan),
+    ShowClusters(ShowClustersPlan),
     ShowProcessList(ShowProcessListsPlan),
     ShowSettings(ShowSettingsPlan),
     ShowUsers(ShowUsersPlan),
@@ -55,6 +60,9 @@ pub enum ShowPlan {
     ShowRoles(ShowRolesPlan),
     ShowTabStat(ShowTabStatPlan),
     ShowStages(ShowStagesPlan),
+    ShowVariables(ShowVariablesPlan),
+    ShowReplicationStatus(ShowReplicationStatusPlan),
+    ShowCaches(ShowCachesPlan),
 }
 
 impl ShowPlan {
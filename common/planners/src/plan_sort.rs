@@ -27,6 +27,8 @@ pub struct SortPlan {
     pub input: Arc<PlanNode>,
     /// Output data schema
     pub schema: DataSchemaRef,
+    /// Only the first `limit` rows of the sorted output are needed, set by the TopN optimizer
+    pub limit: Option<usize>,
 }
 
 impl SortPlan {
@@ -28,6 +28,8 @@ pub struct DropTablePlan {
     /// The table name
     pub table: String,
     pub all: bool,
+    /// Drop the views depending on the table as well, otherwise the table can't be dropped
+    pub cascade: bool,
 }
 
 impl DropTablePlan {
@@ -23,6 +23,7 @@ bitflags! {
     pub struct Optimization: u32 {
         const PURGE   = 0b00000001;
         const COMPACT = 0b00000010;
+        const RECLUSTER = 0b00000100;
         const ALL = Self::PURGE.bits | Self::COMPACT.bits;
     }
 }
@@ -24,6 +24,8 @@ pub struct AlterViewPlan {
     pub db: String,
     pub viewname: String,
     pub subquery: String,
+    /// The (database, table) the view reads from
+    pub dependencies: Vec<(String, String)>,
 }
 
 impl AlterViewPlan {
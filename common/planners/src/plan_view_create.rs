@@ -25,6 +25,8 @@ pub struct CreateViewPlan {
     pub db: String,
     pub viewname: String,
     pub subquery: String,
+    /// The (database, table) the view reads from
+    pub dependencies: Vec<(String, String)>,
 }
 
 impl CreateViewPlan {
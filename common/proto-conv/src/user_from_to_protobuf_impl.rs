@@ -140,6 +140,9 @@ impl FromToProto<pb::GrantObject> for mt::GrantObject {
                 db,
                 table,
             })) => Ok(mt::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::FutureTables(
+                pb::grant_object::GrantFutureTablesObject { catalog, db },
+            )) => Ok(mt::GrantObject::FutureTables(catalog, db)),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -164,6 +167,12 @@ impl FromToProto<pb::GrantObject> for mt::GrantObject {
                     table: table.clone(),
                 },
             )),
+            mt::GrantObject::FutureTables(catalog, db) => Some(
+                pb::grant_object::Object::FutureTables(pb::grant_object::GrantFutureTablesObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -513,6 +522,33 @@ impl FromToProto<pb::user_stage_info::OnErrorMode> for mt::OnErrorMode {
     }
 }
 
+impl FromToProto<pb::user_stage_info::MatchByColumnName> for mt::MatchByColumnName {
+    fn from_pb(p: pb::user_stage_info::MatchByColumnName) -> Result<Self, Incompatible>
+    where Self: Sized {
+        match p {
+            pb::user_stage_info::MatchByColumnName::MatchNone => Ok(mt::MatchByColumnName::None),
+            pb::user_stage_info::MatchByColumnName::CaseSensitive => {
+                Ok(mt::MatchByColumnName::CaseSensitive)
+            }
+            pb::user_stage_info::MatchByColumnName::CaseInsensitive => {
+                Ok(mt::MatchByColumnName::CaseInsensitive)
+            }
+        }
+    }
+
+    fn to_pb(&self) -> Result<pb::user_stage_info::MatchByColumnName, Incompatible> {
+        match *self {
+            mt::MatchByColumnName::None => Ok(pb::user_stage_info::MatchByColumnName::MatchNone),
+            mt::MatchByColumnName::CaseSensitive => {
+                Ok(pb::user_stage_info::MatchByColumnName::CaseSensitive)
+            }
+            mt::MatchByColumnName::CaseInsensitive => {
+                Ok(pb::user_stage_info::MatchByColumnName::CaseInsensitive)
+            }
+        }
+    }
+}
+
 impl FromToProto<pb::user_stage_info::CopyOptions> for mt::CopyOptions {
     fn from_pb(p: pb::user_stage_info::CopyOptions) -> Result<Self, Incompatible>
     where Self: Sized {
@@ -522,9 +558,15 @@ impl FromToProto<pb::user_stage_info::CopyOptions> for mt::CopyOptions {
         let size_limit = usize::try_from(p.size_limit).map_err(|err| Incompatible {
             reason: format!("CopyOptions.size_limit cannot be convert to usize: {}", err),
         })?;
+        let match_by_column_name = mt::MatchByColumnName::from_pb(
+            FromPrimitive::from_i32(p.match_by_column_name).ok_or_else(|| Incompatible {
+                reason: format!("invalid MatchByColumnName: {}", p.match_by_column_name),
+            })?,
+        )?;
         Ok(mt::CopyOptions {
             on_error,
             size_limit,
+            match_by_column_name,
         })
     }
 
@@ -533,9 +575,11 @@ impl FromToProto<pb::user_stage_info::CopyOptions> for mt::CopyOptions {
         let size_limit = u64::try_from(self.size_limit).map_err(|err| Incompatible {
             reason: format!("CopyOptions.size_limit cannot be convert to u64: {}", err),
         })?;
+        let match_by_column_name = mt::MatchByColumnName::to_pb(&self.match_by_column_name)? as i32;
         Ok(pb::user_stage_info::CopyOptions {
             on_error: Some(on_error),
             size_limit,
+            match_by_column_name,
         })
     }
 }
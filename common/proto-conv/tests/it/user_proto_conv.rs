@@ -83,6 +83,7 @@ fn test_user_stage_info() -> mt::UserStageInfo {
         copy_options: mt::CopyOptions {
             on_error: mt::OnErrorMode::SkipFileNum(666),
             size_limit: 1038,
+            match_by_column_name: mt::MatchByColumnName::None,
         },
         comment: "test".to_string(),
     }
@@ -105,6 +106,12 @@ fn test_user_stage_pb_from_to() -> anyhow::Result<()> {
     let got = mt::UserStageInfo::from_pb(test_user_stage_info_pb)?;
     assert_eq!(got, test_user_stage_info);
 
+    let mut test_user_stage_info = test_user_stage_info;
+    test_user_stage_info.copy_options.match_by_column_name = mt::MatchByColumnName::CaseInsensitive;
+    let test_user_stage_info_pb = test_user_stage_info.to_pb()?;
+    let got = mt::UserStageInfo::from_pb(test_user_stage_info_pb)?;
+    assert_eq!(got, test_user_stage_info);
+
     Ok(())
 }
 
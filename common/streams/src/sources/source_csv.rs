@@ -129,6 +129,8 @@ pub struct CsvSource<R> {
     builder: CsvSourceBuilder,
     reader: AsyncReader<R>,
     rows: usize,
+    // The record column of each field of the schema, `None` is the same position.
+    positions: Option<Vec<usize>>,
 }
 
 impl<R> CsvSource<R>
@@ -145,8 +147,31 @@ where R: AsyncRead + Unpin + Send
             builder,
             reader,
             rows: 0,
+            positions: None,
         })
     }
+
+    /// The column names in the header of the file, the header must not be skipped as data.
+    pub async fn headers(&mut self) -> Result<Vec<String>> {
+        if !self.builder.skip_header {
+            return Err(ErrorCode::BadArguments(
+                "The csv file has no header to read the column names",
+            ));
+        }
+
+        let headers = self
+            .reader
+            .headers()
+            .await
+            .map_err_to_code(ErrorCode::BadBytes, || "Parse csv header error")?;
+        Ok(headers.iter().map(|name| name.trim().to_string()).collect())
+    }
+
+    /// Read the record columns at `positions` into the fields of `schema`.
+    pub fn project(&mut self, schema: DataSchemaRef, positions: Vec<usize>) {
+        self.builder.schema = schema;
+        self.positions = Some(positions);
+    }
 }
 
 #[async_trait]
@@ -183,6 +208,10 @@ where R: AsyncRead + Unpin + Send
                 ..Default::default()
             };
             for (col, pack) in packs.iter_mut().enumerate() {
+                let col = match &self.positions {
+                    Some(positions) => positions[col],
+                    None => col,
+                };
                 match record.get(col) {
                     Some(bytes) => {
                         if bytes.is_empty() && self.builder.empty_as_default {
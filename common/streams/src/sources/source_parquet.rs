@@ -17,6 +17,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
 use common_arrow::arrow::io::parquet::read::RowGroupDeserializer;
@@ -91,6 +92,38 @@ where R: AsyncRead + AsyncSeek + Unpin + Send
             rows: 0,
         }
     }
+
+    /// The column names of the file.
+    pub async fn column_names(&mut self) -> Result<Vec<String>> {
+        if self.builder.metadata.is_none() {
+            let metadata = read_metadata_async(&mut self.reader)
+                .instrument(debug_span!("parquet_source_read_meta"))
+                .await
+                .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+            self.builder.metadata = Some(metadata);
+        }
+
+        match &self.builder.metadata {
+            Some(metadata) => {
+                let schema =
+                    infer_schema(metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+                Ok(schema.fields.into_iter().map(|f| f.name).collect())
+            }
+            None => unreachable!(),
+        }
+    }
+
+    /// Read the file columns `names` into the fields of `schema`.
+    pub fn project(&mut self, schema: DataSchemaRef, names: Vec<String>) {
+        let mut arrow_table_schema = schema.to_arrow();
+        for (field, name) in arrow_table_schema.fields.iter_mut().zip(names) {
+            field.name = name;
+        }
+
+        self.builder.projection = (0..schema.fields().len()).collect();
+        self.builder.schema = schema;
+        self.arrow_table_schema = arrow_table_schema;
+    }
 }
 
 #[async_trait]
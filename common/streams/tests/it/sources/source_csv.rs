@@ -162,3 +162,62 @@ async fn test_parse_csv_text() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_project_by_header() -> Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+
+    write!(
+        file,
+        r#"c,extra,a
+100,x,1
+80,y,2"#
+    )
+    .unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", i8::to_data_type()),
+        DataField::new("c", f64::to_data_type()),
+    ]);
+
+    let local = Operator::new(
+        fs::Backend::build()
+            .root(dir.path().to_str().unwrap())
+            .finish()
+            .await
+            .unwrap(),
+    );
+
+    let mut builder = CsvSourceBuilder::create(schema.clone(), FormatSettings::default());
+    builder.skip_header(true);
+    builder.block_size(10);
+
+    let reader = local.object(name).reader().await?;
+    let mut csv_source = builder.build(reader)?;
+    assert_eq!(csv_source.headers().await?, vec!["c", "extra", "a"]);
+
+    csv_source.project(schema, vec![2, 0]);
+    let block = csv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+-----+",
+            "| a | c   |",
+            "+---+-----+",
+            "| 1 | 100 |",
+            "| 2 | 80  |",
+            "+---+-----+",
+        ],
+        &[block],
+    );
+
+    let block = csv_source.read().await?;
+    assert!(block.is_none());
+
+    drop(file);
+    dir.close().unwrap();
+
+    Ok(())
+}
@@ -93,7 +93,7 @@ async fn test_source_parquet() -> Result<()> {
         .into_iter()
         .collect::<Vec<usize>>();
 
-    let mut builder = ParquetSourceBuilder::create(schema);
+    let mut builder = ParquetSourceBuilder::create(schema.clone());
     builder.projection(default_proj);
 
     let mut page_nums = 0;
@@ -120,5 +120,22 @@ async fn test_source_parquet() -> Result<()> {
     }
 
     assert_eq!(page_nums_expects, page_nums);
+
+    // Read the columns by name into another schema.
+    let stream = local.object(name).seekable_reader(..len);
+    let mut parquet_source = ParquetSourceBuilder::create(schema.clone()).build(stream)?;
+    assert_eq!(parquet_source.column_names().await?, vec!["a", "b"]);
+
+    let projected = DataSchemaRefExt::create(vec![DataField::new("B", Vu8::to_data_type())]);
+    parquet_source.project(projected, vec!["b".to_string()]);
+    let block = parquet_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+", "| B |", "+---+", "| 1 |", "| 1 |", "| 2 |", "| 1 |", "| 2 |", "| 3 |",
+            "+---+",
+        ],
+        &[block],
+    );
+
     Ok(())
 }
@@ -181,6 +181,7 @@ pub struct ConfigViaEnv {
     pub kvsrv_heartbeat_intervalt: u64,
     pub kvsrv_install_snapshot_timeout: u64,
     pub raft_max_applied_log_to_keep: u64,
+    pub raft_group_commit: bool,
     pub kvsrv_single: bool,
     pub metasrv_join: Vec<String>,
     pub kvsrv_id: u64,
@@ -216,6 +217,7 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_heartbeat_intervalt: cfg.raft_config.heartbeat_interval,
             kvsrv_install_snapshot_timeout: cfg.raft_config.install_snapshot_timeout,
             raft_max_applied_log_to_keep: cfg.raft_config.max_applied_log_to_keep,
+            raft_group_commit: cfg.raft_config.group_commit,
             kvsrv_single: cfg.raft_config.single,
             metasrv_join: cfg.raft_config.join,
             kvsrv_id: cfg.raft_config.id,
@@ -240,6 +242,7 @@ impl Into<Config> for ConfigViaEnv {
             heartbeat_interval: self.kvsrv_heartbeat_intervalt,
             install_snapshot_timeout: self.kvsrv_install_snapshot_timeout,
             max_applied_log_to_keep: self.raft_max_applied_log_to_keep,
+            group_commit: self.raft_group_commit,
             single: self.kvsrv_single,
             join: self.metasrv_join,
             id: self.kvsrv_id,
@@ -317,6 +320,12 @@ pub struct RaftConfig {
     #[clap(long, default_value = "1000")]
     pub max_applied_log_to_keep: u64,
 
+    /// Whether to pack concurrent client writes into one raft log.
+    /// Nodes of a version without group commit can't apply such a log,
+    /// only enable it after every node of the cluster is upgraded.
+    #[clap(long)]
+    pub group_commit: bool,
+
     /// Single node metasrv. It creates a single node cluster if meta data is not initialized.
     /// Otherwise it opens the previous one.
     /// This is mainly for testing purpose.
@@ -364,6 +373,7 @@ impl From<RaftConfig> for InnerRaftConfig {
             heartbeat_interval: x.heartbeat_interval,
             install_snapshot_timeout: x.install_snapshot_timeout,
             max_applied_log_to_keep: x.max_applied_log_to_keep,
+            group_commit: x.group_commit,
             single: x.single,
             join: x.join,
             id: x.id,
@@ -386,6 +396,7 @@ impl From<InnerRaftConfig> for RaftConfig {
             heartbeat_interval: inner.heartbeat_interval,
             install_snapshot_timeout: inner.install_snapshot_timeout,
             max_applied_log_to_keep: inner.max_applied_log_to_keep,
+            group_commit: inner.group_commit,
             single: inner.single,
             join: inner.join,
             id: inner.id,
@@ -0,0 +1,228 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Group commit of client writes.
+//!
+//! Writes that arrive while the raft logs in flight are being committed are queued,
+//! and the next round proposes all of them in a single `Cmd::Batch` log.
+//! Thus a burst of writes costs one raft round trip instead of one per write.
+//!
+//! `Cmd::Batch` can't be applied by the nodes of a version without it,
+//! thus writes are only packed if `RaftConfig::group_commit` is enabled.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc;
+use common_base::base::tokio::sync::oneshot;
+use common_base::base::tokio::sync::watch;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::task::JoinHandle;
+use common_meta_sled_store::openraft;
+use common_meta_sled_store::openraft::error::ClientWriteError;
+use common_meta_sled_store::openraft::raft::EntryPayload;
+use common_meta_types::AppliedState;
+use common_meta_types::Cmd;
+use common_meta_types::ForwardToLeader;
+use common_meta_types::LogEntry;
+use common_meta_types::MetaError;
+use common_meta_types::MetaRaftError;
+use common_meta_types::MetaResult;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
+use openraft::raft::ClientWriteRequest;
+
+use crate::meta_service::raftmeta::MetaRaft;
+use crate::metrics::incr_meta_metrics_group_commits;
+use crate::metrics::incr_meta_metrics_proposals;
+
+/// The max number of client writes packed into one raft log.
+pub const MAX_GROUP_COMMIT_SIZE: usize = 256;
+
+/// The max number of raft logs proposed by group commit and not yet applied.
+pub const MAX_INFLIGHT_COMMITS: usize = 8;
+
+type Proposal = (LogEntry, oneshot::Sender<Result<AppliedState, MetaError>>);
+
+/// The sending end of the group commit queue.
+pub struct GroupCommitter {
+    tx: mpsc::UnboundedSender<Proposal>,
+}
+
+impl GroupCommitter {
+    /// Spawn the task that drains the queue and writes to `raft`.
+    ///
+    /// Up to `MAX_INFLIGHT_COMMITS` raft logs are written concurrently, so that they are
+    /// pipelined by raft. If `group_commit` is disabled, every write is a raft log of its own.
+    ///
+    /// The task quits when `running_rx` is notified or all the senders are dropped.
+    pub fn spawn(
+        raft: MetaRaft,
+        group_commit: bool,
+        mut running_rx: watch::Receiver<()>,
+    ) -> (Self, JoinHandle<MetaResult<()>>) {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Proposal>();
+        let inflight = Arc::new(Semaphore::new(MAX_INFLIGHT_COMMITS));
+        let max_size = match group_commit {
+            true => MAX_GROUP_COMMIT_SIZE,
+            false => 1,
+        };
+
+        let span = tracing::span!(tracing::Level::INFO, "group-commit");
+
+        let h = tokio::task::spawn(
+            async move {
+                loop {
+                    // Writes queue up while the max number of raft logs are in flight.
+                    let permit = tokio::select! {
+                        _ = running_rx.changed() => {
+                            return Ok::<(), MetaError>(());
+                        }
+                        permit = inflight.clone().acquire_owned() => {
+                            permit.map_err(|e| MetaError::MetaServiceError(e.to_string()))?
+                        }
+                    };
+
+                    let first = tokio::select! {
+                        _ = running_rx.changed() => {
+                            return Ok(());
+                        }
+                        first = rx.recv() => {
+                            first
+                        }
+                    };
+
+                    let first = match first {
+                        Some(p) => p,
+                        None => return Ok(()),
+                    };
+
+                    let mut proposals = vec![first];
+                    while proposals.len() < max_size {
+                        match rx.try_recv() {
+                            Ok(p) => proposals.push(p),
+                            Err(_) => break,
+                        }
+                    }
+
+                    let raft = raft.clone();
+                    tokio::task::spawn(
+                        async move {
+                            commit(&raft, proposals).await;
+                            drop(permit);
+                        }
+                        .in_current_span(),
+                    );
+                }
+            }
+            .instrument(span),
+        );
+
+        (GroupCommitter { tx }, h)
+    }
+
+    /// Queue a write and wait for the state after it is applied.
+    #[tracing::instrument(level = "debug", skip(self, entry))]
+    pub async fn write(&self, entry: LogEntry) -> Result<AppliedState, MetaError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx
+            .send((entry, tx))
+            .map_err(|_| MetaError::MetaServiceError("group commit task has quit".to_string()))?;
+
+        rx.await.map_err(|_| {
+            MetaError::MetaServiceError("group commit task dropped the write".to_string())
+        })?
+    }
+}
+
+/// Write the queued proposals as one raft log and dispatch the result of every one of them.
+async fn commit(raft: &MetaRaft, proposals: Vec<Proposal>) {
+    let n = proposals.len();
+    incr_meta_metrics_proposals(n as u64);
+
+    if n == 1 {
+        let (entry, tx) = proposals.into_iter().next().unwrap();
+        let _ = tx.send(client_write(raft, entry).await);
+        return;
+    }
+
+    incr_meta_metrics_group_commits();
+    tracing::debug!("group commit {} proposals", n);
+
+    let (entries, txs): (Vec<_>, Vec<_>) = proposals.into_iter().unzip();
+
+    let res = client_write(raft, LogEntry {
+        txid: None,
+        cmd: Cmd::Batch(entries),
+    })
+    .await;
+
+    match res {
+        Ok(AppliedState::Batch(states)) if states.len() == n => {
+            for (tx, state) in txs.into_iter().zip(states) {
+                let _ = tx.send(Ok(state));
+            }
+        }
+        Ok(state) => {
+            let err = MetaError::MetaServiceError(format!(
+                "expect {} states from a batch of proposals, got: {:?}",
+                n, state
+            ));
+            for tx in txs {
+                let _ = tx.send(Err(err.clone()));
+            }
+        }
+        Err(e) => {
+            for tx in txs {
+                let _ = tx.send(Err(e.clone()));
+            }
+        }
+    }
+}
+
+/// Write a log through local raft node and return the states before and after applying the log.
+///
+/// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
+/// If the leadership is lost during writing the log, it returns an UnknownError.
+/// TODO(xp): elaborate the UnknownError, e.g. LeaderLostError
+async fn client_write(raft: &MetaRaft, entry: LogEntry) -> Result<AppliedState, MetaError> {
+    tracing::debug!(entry = debug(&entry), "write LogEntry");
+    let write_rst = raft
+        .client_write(ClientWriteRequest::new(EntryPayload::Normal(entry)))
+        .await;
+
+    tracing::debug!("raft.client_write rst: {:?}", write_rst);
+
+    match write_rst {
+        Ok(resp) => {
+            let data = resp.data;
+            Ok(data)
+        }
+
+        Err(cli_write_err) => match cli_write_err {
+            // fatal error
+            ClientWriteError::Fatal(fatal) => Err(MetaRaftError::RaftFatal(fatal).into()),
+            // retryable error
+            ClientWriteError::ForwardToLeader(to_leader) => {
+                Err(MetaRaftError::ForwardToLeader(ForwardToLeader {
+                    leader_id: to_leader.leader_id,
+                })
+                .into())
+            }
+            ClientWriteError::ChangeMembershipError(_) => {
+                unreachable!("there should not be a ChangeMembershipError for client_write")
+            }
+        },
+    }
+}
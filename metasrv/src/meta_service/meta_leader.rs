@@ -15,11 +15,9 @@
 use std::collections::BTreeSet;
 
 use common_meta_api::KVApi;
-use common_meta_sled_store::openraft;
 use common_meta_sled_store::openraft::error::ChangeMembershipError;
 use common_meta_sled_store::openraft::error::ClientWriteError;
 use common_meta_sled_store::openraft::error::InProgress;
-use common_meta_sled_store::openraft::raft::EntryPayload;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
 use common_meta_types::ForwardRequest;
@@ -31,7 +29,6 @@ use common_meta_types::MetaRaftError;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_tracing::tracing;
-use openraft::raft::ClientWriteRequest;
 
 use crate::meta_service::ForwardRequestBody;
 use crate::meta_service::JoinRequest;
@@ -207,40 +204,10 @@ impl<'a> MetaLeader<'a> {
 
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
+    /// The log is group-committed with other concurrent writes, see `GroupCommitter`.
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
-    /// If the leadership is lost during writing the log, it returns an UnknownError.
-    /// TODO(xp): elaborate the UnknownError, e.g. LeaderLostError
     #[tracing::instrument(level = "debug", skip(self, entry))]
     pub async fn write(&self, entry: LogEntry) -> Result<AppliedState, MetaError> {
-        tracing::debug!(entry = debug(&entry), "write LogEntry");
-        let write_rst = self
-            .meta_node
-            .raft
-            .client_write(ClientWriteRequest::new(EntryPayload::Normal(entry)))
-            .await;
-
-        tracing::debug!("raft.client_write rst: {:?}", write_rst);
-
-        match write_rst {
-            Ok(resp) => {
-                let data = resp.data;
-                Ok(data)
-            }
-
-            Err(cli_write_err) => match cli_write_err {
-                // fatal error
-                ClientWriteError::Fatal(fatal) => Err(MetaRaftError::RaftFatal(fatal).into()),
-                // retryable error
-                ClientWriteError::ForwardToLeader(to_leader) => {
-                    Err(MetaRaftError::ForwardToLeader(ForwardToLeader {
-                        leader_id: to_leader.leader_id,
-                    })
-                    .into())
-                }
-                ClientWriteError::ChangeMembershipError(_) => {
-                    unreachable!("there should not be a ChangeMembershipError for client_write")
-                }
-            },
-        }
+        self.meta_node.group_committer.write(entry).await
     }
 }
@@ -19,6 +19,7 @@ pub use common_meta_types::LeaveRequest;
 pub use meta_service_impl::RaftServiceImpl;
 pub use raftmeta::MetaNode;
 
+pub mod group_commit;
 pub mod meta_leader;
 mod meta_node_kv_api_impl;
 pub mod meta_service_impl;
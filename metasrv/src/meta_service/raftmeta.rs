@@ -55,6 +55,7 @@ use openraft::RaftMetrics;
 use openraft::SnapshotPolicy;
 use tonic::Status;
 
+use crate::meta_service::group_commit::GroupCommitter;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::ForwardRequestBody;
 use crate::meta_service::JoinRequest;
@@ -82,6 +83,7 @@ pub struct MetaNode {
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<MetaResult<()>>>>,
     pub joined_tasks: AtomicI32,
+    pub group_committer: GroupCommitter,
 }
 
 impl Opened for MetaNode {
@@ -96,6 +98,7 @@ pub struct MetaNodeBuilder {
     sto: Option<Arc<MetaRaftStore>>,
     monitor_metrics: bool,
     endpoint: Option<Endpoint>,
+    group_commit: bool,
 }
 
 impl MetaNodeBuilder {
@@ -121,6 +124,9 @@ impl MetaNodeBuilder {
 
         let (tx, rx) = watch::channel::<()>(());
 
+        let (group_committer, group_commit_handle) =
+            GroupCommitter::spawn(raft.clone(), self.group_commit, rx.clone());
+
         let watcher = WatcherManager::create();
 
         sto.get_state_machine()
@@ -133,8 +139,9 @@ impl MetaNodeBuilder {
             raft,
             running_tx: tx,
             running_rx: rx,
-            join_handles: Mutex::new(Vec::new()),
+            join_handles: Mutex::new(vec![group_commit_handle]),
             joined_tasks: AtomicI32::new(1),
+            group_committer,
         });
 
         if self.monitor_metrics {
@@ -190,6 +197,7 @@ impl MetaNode {
             sto: None,
             monitor_metrics: true,
             endpoint: None,
+            group_commit: config.group_commit,
         }
     }
 
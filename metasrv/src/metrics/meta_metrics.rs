@@ -74,6 +74,24 @@ lazy_static! {
             .subsystem(SERVER_SUBSYSTEM)
     )
     .expect("meta metric cannot be created");
+    pub static ref PROPOSALS: IntCounter = IntCounter::with_opts(
+        Opts::new(
+            "proposals",
+            "Total number of client proposals written to raft, rate() of it is proposals/sec."
+        )
+        .namespace(META_NAMESPACE)
+        .subsystem(SERVER_SUBSYSTEM)
+    )
+    .expect("meta metric cannot be created");
+    pub static ref GROUP_COMMITS: IntCounter = IntCounter::with_opts(
+        Opts::new(
+            "group_commits",
+            "Total number of raft logs carrying more than one proposal."
+        )
+        .namespace(META_NAMESPACE)
+        .subsystem(SERVER_SUBSYSTEM)
+    )
+    .expect("meta metric cannot be created");
     pub static ref READ_FAILED: IntCounter = IntCounter::with_opts(
         Opts::new("read_failed", "Total number of failed read request.")
             .namespace(META_NAMESPACE)
@@ -123,6 +141,14 @@ fn init_meta_recorder() {
         .register(Box::new(PROPOSALS_FAILED.clone()))
         .expect("collector can be registered");
 
+    REGISTRY
+        .register(Box::new(PROPOSALS.clone()))
+        .expect("collector can be registered");
+
+    REGISTRY
+        .register(Box::new(GROUP_COMMITS.clone()))
+        .expect("collector can be registered");
+
     REGISTRY
         .register(Box::new(READ_FAILED.clone()))
         .expect("collector can be registered");
@@ -160,6 +186,14 @@ pub fn incr_meta_metrics_proposals_failed() {
     PROPOSALS_FAILED.inc();
 }
 
+pub fn incr_meta_metrics_proposals(cnt: u64) {
+    PROPOSALS.inc_by(cnt);
+}
+
+pub fn incr_meta_metrics_group_commits() {
+    GROUP_COMMITS.inc();
+}
+
 pub fn incr_meta_metrics_read_failed() {
     READ_FAILED.inc();
 }
@@ -15,7 +15,9 @@
 mod meta_metrics;
 
 pub use meta_metrics::incr_meta_metrics_applying_snapshot;
+pub use meta_metrics::incr_meta_metrics_group_commits;
 pub use meta_metrics::incr_meta_metrics_leader_change;
+pub use meta_metrics::incr_meta_metrics_proposals;
 pub use meta_metrics::incr_meta_metrics_proposals_failed;
 pub use meta_metrics::incr_meta_metrics_proposals_pending;
 pub use meta_metrics::incr_meta_metrics_read_failed;
@@ -20,5 +20,7 @@ criterion_main! {
     suites::bench_aggregate_query_sql::benches,
     suites::bench_filter_query_sql::benches,
     suites::bench_limit_query_sql::benches,
+    suites::bench_mysql_point_query::benches,
+    suites::bench_output_format::benches,
     suites::bench_sort_query_sql::benches,
 }
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use databend_query::servers::MySQLHandler;
+use databend_query::sessions::SessionManager;
+use databend_query::Config;
+use mysql_async::prelude::Queryable;
+
+fn criterion_benchmark_mysql_point_query(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    // Keep the handler alive during the whole bench.
+    let (_handler, mut connection) = runtime.block_on(async {
+        let sessions = SessionManager::from_conf(Config::default()).await.unwrap();
+        let mut handler = MySQLHandler::create(sessions);
+        let listening = "127.0.0.1:0".parse::<SocketAddr>().unwrap();
+        let address = handler.start(listening).await.unwrap();
+
+        let uri = format!("mysql://root@127.0.0.1:{}", address.port());
+        let opts = mysql_async::Opts::from_url(&uri).unwrap();
+        (handler, mysql_async::Conn::new(opts).await.unwrap())
+    });
+
+    let queries = vec![
+        "SELECT 1",
+        "SELECT number FROM numbers(10) WHERE number = 5",
+    ];
+
+    for query in queries {
+        c.bench_function(&format!("mysql: {}", query), |b| {
+            b.iter(|| runtime.block_on(connection.query_drop(query)).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark_mysql_point_query);
+criterion_main!(benches);
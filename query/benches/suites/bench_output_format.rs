@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_io::prelude::FormatSettings;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use databend_query::formats::FormatFactory;
+
+// Serialize the same block with each registered output format.
+fn criterion_benchmark_output_format(c: &mut Criterion) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", i64::to_data_type()),
+        DataField::new("name", Vu8::to_data_type()),
+        DataField::new("score", f64::to_data_type()),
+    ]);
+    let rows = 100_000;
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::from_data((0..rows).collect::<Vec<i64>>()),
+        Series::from_data((0..rows).map(|i| format!("name_{}", i)).collect::<Vec<_>>()),
+        Series::from_data((0..rows).map(|i| i as f64 / 3.0).collect::<Vec<f64>>()),
+    ]);
+    let settings = FormatSettings::default();
+
+    let factory = FormatFactory::instance();
+    for (name, _) in factory.output_formats() {
+        c.bench_function(&format!("output format {}", name), |b| {
+            b.iter(|| {
+                let mut output = factory.get_output(name, schema.clone()).unwrap();
+                output.serialize_block(&block, &settings).unwrap();
+                output.finalize().unwrap()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark_output_format);
+criterion_main!(benches);
@@ -26,6 +26,8 @@ use futures::StreamExt;
 pub mod bench_aggregate_query_sql;
 pub mod bench_filter_query_sql;
 pub mod bench_limit_query_sql;
+pub mod bench_mysql_point_query;
+pub mod bench_output_format;
 pub mod bench_sort_query_sql;
 
 pub async fn select_executor(sql: &str) -> Result<()> {
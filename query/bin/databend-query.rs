@@ -32,6 +32,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::sessions::SessionManager;
+use databend_query::storages::fuse::ttl_expiry::TtlExpiryJob;
 use databend_query::Config;
 use databend_query::QUERY_SEMVER;
 
@@ -155,6 +156,9 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // Expiry of the rows of the tables with a TTL.
+    TtlExpiryJob::start(session_manager.clone());
+
     tracing::info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
     tracing::info!("Shutdown server.");
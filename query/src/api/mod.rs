@@ -19,11 +19,14 @@ pub use rpc::BroadcastAction;
 pub use rpc::CancelAction;
 pub use rpc::DatabendQueryFlightDispatcher;
 pub use rpc::DatabendQueryFlightService;
+pub use rpc::ExchangeSkew;
+pub use rpc::ExchangeSkewDetector;
 pub use rpc::FlightAction;
 pub use rpc::FlightClient;
 pub use rpc::FlightTicket;
 pub use rpc::ShuffleAction;
 pub use rpc::StreamTicket;
+pub use rpc::UDFFlightClient;
 pub use rpc_service::RpcService;
 
 pub mod http;
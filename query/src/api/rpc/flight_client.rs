@@ -19,6 +19,7 @@ use common_arrow::arrow_format::flight::data::FlightData;
 use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::base::tokio::time::Duration;
+use common_contexts::DalMetrics;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -47,10 +48,13 @@ impl FlightClient {
         ticket: FlightTicket,
         schema: DataSchemaRef,
         timeout: u64,
+        metrics: DalMetrics,
     ) -> Result<SendableDataBlockStream> {
         let ticket = ticket.try_into()?;
         let inner = self.do_get(ticket, timeout).await?;
-        Ok(Box::pin(FlightDataStream::from_remote(schema, inner)))
+        Ok(Box::pin(FlightDataStream::from_remote(
+            schema, inner, metrics,
+        )))
     }
 
     pub async fn execute_action(&mut self, action: FlightAction, timeout: u64) -> Result<()> {
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::io::flight::deserialize_batch;
 use common_arrow::arrow_format::flight::data::FlightData;
+use common_contexts::DalMetrics;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
@@ -31,11 +32,15 @@ impl FlightDataStream {
     pub fn from_remote(
         schema: DataSchemaRef,
         inner: Streaming<FlightData>,
+        metrics: DalMetrics,
     ) -> impl Stream<Item = Result<DataBlock, ErrorCode>> {
         inner.map(move |flight_data| -> Result<DataBlock, ErrorCode> {
             match flight_data {
                 Err(status) => Err(ErrorCode::UnknownException(status.message())),
                 Ok(flight_data) => {
+                    metrics.inc_network_received_bytes(
+                        flight_data.data_header.len() + flight_data.data_body.len(),
+                    );
                     let arrow_schema = Arc::new(schema.to_arrow());
                     let ipc_fields = common_arrow::arrow::io::ipc::write::default_ipc_fields(
                         &arrow_schema.fields,
@@ -19,15 +19,21 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
+use common_tracing::tracing;
+use metrics::counter;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
+use crate::api::rpc::flight_scatter_skew::ExchangeSkewDetector;
 use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
 
+const EXCHANGE_SKEWED_PARTITIONS: &str = "exchange_skewed_partitions";
+
 pub struct HashFlightScatter {
     scatter_expression_executor: Arc<ExpressionExecutor>,
     scatter_expression_name: String,
     scattered_size: usize,
+    skew_detector: Option<ExchangeSkewDetector>,
 }
 
 impl FlightScatter for HashFlightScatter {
@@ -51,7 +57,16 @@ impl FlightScatter for HashFlightScatter {
         let indices = evaluated_data_block.try_column_by_name(&self.scatter_expression_name)?;
 
         let col: &PrimitiveColumn<u64> = Series::check_get(indices)?;
-        let indices: Vec<usize> = col.iter().map(|c| *c as usize).collect();
+        if let Some(skew_detector) = &self.skew_detector {
+            skew_detector.observe(col.values());
+            if let Some(skew) = skew_detector.take_skew() {
+                counter!(EXCHANGE_SKEWED_PARTITIONS, 1);
+                tracing::warn!("Skewed hash exchange detected: {}", skew);
+            }
+        }
+
+        let num = self.scattered_size as u64;
+        let indices: Vec<usize> = col.iter().map(|c| (*c % num) as usize).collect();
         DataBlock::scatter_block(data_block, &indices, self.scattered_size)
     }
 }
@@ -63,7 +78,8 @@ impl HashFlightScatter {
         expr: Expression,
         ctx: Arc<QueryContext>,
     ) -> Result<Self> {
-        let expression = Self::expr_action(num, expr);
+        let skew_factor = ctx.get_settings().get_exchange_skew_factor()?;
+        let expression = Self::expr_action(expr);
         let indices_expr_executor = Self::expr_executor(ctx, schema, &expression)?;
         indices_expr_executor.validate()?;
 
@@ -71,6 +87,10 @@ impl HashFlightScatter {
             scatter_expression_executor: Arc::new(indices_expr_executor),
             scatter_expression_name: expression.column_name(),
             scattered_size: num,
+            skew_detector: match skew_factor {
+                0 => None,
+                factor => Some(ExchangeSkewDetector::create(num, factor)),
+            },
         })
     }
 
@@ -93,20 +113,12 @@ impl HashFlightScatter {
         )
     }
 
-    fn expr_action(num: usize, expr: Expression) -> Expression {
-        Expression::ScalarFunction {
-            op: String::from("modulo"),
-            args: vec![
-                Expression::Cast {
-                    expr: Box::new(expr),
-                    data_type: u64::to_data_type(),
-                    pg_style: false,
-                },
-                Expression::create_literal_with_type(
-                    DataValue::UInt64(num as u64),
-                    u64::to_data_type(),
-                ),
-            ],
+    // The modulo is applied in `execute`, so the skew detector can see the key hashes.
+    fn expr_action(expr: Expression) -> Expression {
+        Expression::Cast {
+            expr: Box::new(expr),
+            data_type: u64::to_data_type(),
+            pg_style: false,
         }
     }
 }
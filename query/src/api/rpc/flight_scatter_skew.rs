@@ -0,0 +1,135 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::fmt;
+
+use common_base::infallible::Mutex;
+
+// Skew is only reported once the exchange has seen enough rows to be meaningful.
+const MIN_SKEW_ROWS: u64 = 65536;
+// Number of hot keys tracked by the space-saving sketch.
+const HOT_KEYS_CAPACITY: usize = 16;
+
+/// A hot partition found by the `ExchangeSkewDetector`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExchangeSkew {
+    pub partition: usize,
+    pub rows: u64,
+    pub avg_rows: f64,
+    /// Hashes of the hottest keys routed to the partition and their estimated row counts.
+    pub hot_keys: Vec<(u64, u64)>,
+}
+
+impl fmt::Display for ExchangeSkew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "partition {} received {} rows, avg rows {:.2}, hot keys {:?}",
+            self.partition, self.rows, self.avg_rows, self.hot_keys
+        )
+    }
+}
+
+struct SkewState {
+    partition_rows: Vec<u64>,
+    total_rows: u64,
+    hot_keys: HashMap<u64, u64>,
+    reported: bool,
+}
+
+/// Tracks the rows sent to every destination of a hash exchange and the heaviest keys
+/// (by hash, with the space-saving algorithm), so that power-law distributed keys that
+/// turn one node into the bottleneck can be reported at runtime.
+pub struct ExchangeSkewDetector {
+    factor: u64,
+    state: Mutex<SkewState>,
+}
+
+impl ExchangeSkewDetector {
+    pub fn create(partitions: usize, factor: u64) -> ExchangeSkewDetector {
+        ExchangeSkewDetector {
+            factor,
+            state: Mutex::new(SkewState {
+                partition_rows: vec![0; partitions],
+                total_rows: 0,
+                hot_keys: HashMap::with_capacity(HOT_KEYS_CAPACITY),
+                reported: false,
+            }),
+        }
+    }
+
+    pub fn observe(&self, hashes: &[u64]) {
+        let mut state = self.state.lock();
+        let partitions = state.partition_rows.len() as u64;
+
+        for hash in hashes {
+            state.partition_rows[(hash % partitions) as usize] += 1;
+
+            if let Some(count) = state.hot_keys.get_mut(hash) {
+                *count += 1;
+            } else if state.hot_keys.len() < HOT_KEYS_CAPACITY {
+                state.hot_keys.insert(*hash, 1);
+            } else {
+                // Space-saving: the new key replaces the least frequent one and inherits its count.
+                let (min_hash, min_count) = state
+                    .hot_keys
+                    .iter()
+                    .min_by_key(|(_, count)| **count)
+                    .map(|(hash, count)| (*hash, *count))
+                    .unwrap();
+                state.hot_keys.remove(&min_hash);
+                state.hot_keys.insert(*hash, min_count + 1);
+            }
+        }
+
+        state.total_rows += hashes.len() as u64;
+    }
+
+    /// Returns the hottest partition the first time it exceeds `factor` times the average rows.
+    pub fn take_skew(&self) -> Option<ExchangeSkew> {
+        let mut state = self.state.lock();
+        if state.reported || state.total_rows < MIN_SKEW_ROWS {
+            return None;
+        }
+
+        let partitions = state.partition_rows.len() as u64;
+        let avg_rows = state.total_rows as f64 / partitions as f64;
+        let (partition, rows) = state
+            .partition_rows
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, rows)| **rows)
+            .map(|(partition, rows)| (partition, *rows))?;
+
+        if (rows as f64) < avg_rows * self.factor as f64 {
+            return None;
+        }
+
+        let mut hot_keys = state
+            .hot_keys
+            .iter()
+            .filter(|(hash, _)| (*hash % partitions) as usize == partition)
+            .map(|(hash, count)| (*hash, *count))
+            .collect::<Vec<_>>();
+        hot_keys.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        state.reported = true;
+        Some(ExchangeSkew {
+            partition,
+            rows,
+            avg_rows,
+            hot_keys,
+        })
+    }
+}
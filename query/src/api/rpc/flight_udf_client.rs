@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::deserialize_schemas;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::flight::serialize_schema;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow_format::flight::data::flight_descriptor::DescriptorType;
+use common_arrow::arrow_format::flight::data::FlightDescriptor;
+use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use common_base::base::tokio::time::Duration;
+use common_base::infallible::Mutex;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::ConnectionFactory;
+use common_tracing::tracing;
+use once_cell::sync::Lazy;
+use tonic::transport::channel::Channel;
+use tonic::Request;
+
+// The channels to the UDF servers, a channel multiplexes the concurrent calls to the same address.
+static UDF_CHANNELS: Lazy<Mutex<HashMap<String, Channel>>> = Lazy::new(Default::default);
+
+/// The client of the external UDF servers.
+///
+/// A call is one DoExchange: the first message carries the schema of the arguments and a path
+/// descriptor with the handler name, the following messages carry the argument batches. The server
+/// replies with the schema of the result (a single column) and the result batches, the rows of the
+/// result must be in the same order as the arguments.
+pub struct UDFFlightClient {
+    inner: FlightServiceClient<Channel>,
+    timeout: Duration,
+}
+
+impl UDFFlightClient {
+    /// Connect to `grpc://host:port`, reusing the pooled channel of the address if any.
+    pub async fn connect(address: &str, timeout: Duration) -> Result<UDFFlightClient> {
+        let endpoint = address.strip_prefix("grpc://").ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The address of UDF server must start with grpc://, but got: {}",
+                address
+            ))
+        })?;
+
+        let channel = UDF_CHANNELS.lock().get(endpoint).cloned();
+        let channel = match channel {
+            Some(channel) => channel,
+            None => {
+                let channel =
+                    ConnectionFactory::create_rpc_channel(endpoint, Some(timeout), None).await?;
+                UDF_CHANNELS
+                    .lock()
+                    .insert(endpoint.to_string(), channel.clone());
+                channel
+            }
+        };
+
+        Ok(UDFFlightClient {
+            inner: FlightServiceClient::new(channel),
+            timeout,
+        })
+    }
+
+    /// Call the handler with the argument blocks, returns the result column of all the rows.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn call(
+        &mut self,
+        handler: &str,
+        blocks: Vec<DataBlock>,
+        return_type: &DataTypeImpl,
+    ) -> Result<ColumnRef> {
+        let num_rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
+        let schema = match blocks.first() {
+            Some(block) => block.schema().clone(),
+            None => return Ok(return_type.create_mutable(0).to_column()),
+        };
+
+        let arrow_schema = schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let options = WriteOptions { compression: None };
+
+        let mut messages = Vec::with_capacity(blocks.len() + 1);
+        let mut schema_message = serialize_schema(&arrow_schema, Some(&ipc_fields));
+        schema_message.flight_descriptor = Some(FlightDescriptor {
+            r#type: DescriptorType::Path as i32,
+            cmd: vec![],
+            path: vec![handler.to_string()],
+        });
+        messages.push(schema_message);
+        for block in blocks {
+            let chunk = block.try_into()?;
+            let (_, message) = serialize_batch(&chunk, &ipc_fields, &options);
+            messages.push(message);
+        }
+
+        let request = Request::new(futures::stream::iter(messages));
+        let mut request = common_tracing::inject_span_to_tonic_request(request);
+        request.set_timeout(self.timeout);
+        let mut response = self.inner.do_exchange(request).await?.into_inner();
+
+        let result_schema =
+            DataSchemaRefExt::create(vec![DataField::new("result", return_type.clone())]);
+        let (arrow_schema, ipc_schema) = match response.message().await? {
+            Some(message) => deserialize_schemas(&message.data_header)?,
+            None => {
+                return Err(ErrorCode::UDFRuntimeError(format!(
+                    "UDF server returned nothing for handler {}",
+                    handler
+                )));
+            }
+        };
+
+        let mut columns = vec![];
+        while let Some(message) = response.message().await? {
+            let chunk = deserialize_batch(
+                &message,
+                &arrow_schema.fields,
+                &ipc_schema,
+                &Default::default(),
+            )?;
+            let block = DataBlock::from_chunk(&result_schema, &chunk)?;
+            columns.push(block.column(0).clone());
+        }
+
+        let result_rows: usize = columns.iter().map(|column| column.len()).sum();
+        if result_rows != num_rows {
+            return Err(ErrorCode::UDFRuntimeError(format!(
+                "UDF server returned {} rows for handler {}, but expected {}",
+                result_rows, handler, num_rows
+            )));
+        }
+        Series::concat(&columns)
+    }
+}
@@ -18,9 +18,12 @@ pub use flight_actions::FlightAction;
 pub use flight_actions::ShuffleAction;
 pub use flight_client::FlightClient;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
+pub use flight_scatter_skew::ExchangeSkew;
+pub use flight_scatter_skew::ExchangeSkewDetector;
 pub use flight_service::DatabendQueryFlightService;
 pub use flight_tickets::FlightTicket;
 pub use flight_tickets::StreamTicket;
+pub use flight_udf_client::UDFFlightClient;
 
 mod flight_actions;
 mod flight_client;
@@ -29,6 +32,8 @@ mod flight_dispatcher;
 mod flight_scatter;
 mod flight_scatter_broadcast;
 mod flight_scatter_hash;
+mod flight_scatter_skew;
 mod flight_service;
 mod flight_service_stream;
 mod flight_tickets;
+mod flight_udf_client;
@@ -35,6 +35,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
@@ -43,6 +45,7 @@ use common_meta_types::MetaId;
 use dyn_clone::DynClone;
 
 use crate::databases::Database;
+use crate::storages::mock::MockTableSpec;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
 use crate::table_functions::TableArgs;
@@ -132,6 +135,12 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply>;
 
+    /// Updates the metas of the tables atomically, used to commit a transaction.
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply>;
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     ///
@@ -151,4 +160,23 @@ pub trait Catalog: DynClone + Send + Sync {
     fn get_table_engines(&self) -> Vec<StorageDescription> {
         unimplemented!()
     }
+
+    // Get the table engine by name, case insensitive.
+    fn get_table_engine(&self, engine: &str) -> Option<StorageDescription> {
+        self.get_table_engines()
+            .into_iter()
+            .find(|desc| desc.engine_name.eq_ignore_ascii_case(engine))
+    }
+
+    ///
+    /// Testing
+    ///
+
+    // Register a mock table described by the spec into the system database, e.g. `system.test_xxx`.
+    fn register_test_table(&self, spec: MockTableSpec) -> Result<Arc<dyn Table>> {
+        Err(ErrorCode::UnImplement(format!(
+            "Cannot register test table {} in this catalog",
+            spec.name
+        )))
+    }
 }
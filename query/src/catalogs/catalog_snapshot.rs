@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::catalogs::Catalog;
+use crate::databases::Database;
+use crate::storages::Table;
+
+/// Times to re-collect the catalog before giving up on a stable read.
+const MAX_COLLECT_ATTEMPTS: usize = 5;
+
+/// The databases and tables of a catalog as seen by one query.
+///
+/// The meta service can not read as of a revision, thus a snapshot is taken by collecting
+/// the catalog until two collections in a row see the same revisions (the `seq` of the
+/// database and table idents), i.e., no CREATE/DROP/ALTER from other sessions is committed
+/// in between.
+pub struct CatalogSnapshot {
+    databases: Vec<Arc<dyn Database>>,
+    /// Tables of each database, including the dropped ones that are still retained.
+    tables: HashMap<String, Vec<Arc<dyn Table>>>,
+}
+
+impl CatalogSnapshot {
+    pub async fn load(catalog: &dyn Catalog, tenant: &str) -> Result<CatalogSnapshot> {
+        let mut prev = Self::collect(catalog, tenant).await?;
+
+        for _ in 1..MAX_COLLECT_ATTEMPTS {
+            let curr = Self::collect(catalog, tenant).await?;
+            let stable = matches!(
+                (&prev, &curr),
+                (Some(p), Some(c)) if p.revisions() == c.revisions()
+            );
+            match curr {
+                Some(snapshot) if stable => return Ok(snapshot),
+                curr => prev = curr,
+            }
+        }
+
+        match prev {
+            Some(snapshot) => {
+                tracing::warn!(
+                    "catalog kept changing in {} collections, use the last one",
+                    MAX_COLLECT_ATTEMPTS
+                );
+                Ok(snapshot)
+            }
+            None => Err(ErrorCode::UnknownDatabase(
+                "catalog kept changing while listing the databases",
+            )),
+        }
+    }
+
+    /// Lists the databases and then their tables.
+    ///
+    /// Returns `None` if a database is dropped in between, so that the caller collects again.
+    async fn collect(catalog: &dyn Catalog, tenant: &str) -> Result<Option<CatalogSnapshot>> {
+        let databases = catalog.list_databases(tenant).await?;
+
+        let mut tables = HashMap::with_capacity(databases.len());
+        for database in databases.iter() {
+            let name = database.name();
+            match catalog.list_tables_history(tenant, name).await {
+                Ok(v) => {
+                    tables.insert(name.to_string(), v);
+                }
+                Err(e) if e.code() == ErrorCode::unknown_database_code() => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Some(CatalogSnapshot { databases, tables }))
+    }
+
+    fn revisions(&self) -> Vec<(u64, u64)> {
+        let mut revisions = Vec::new();
+        for database in self.databases.iter() {
+            let ident = &database.get_db_info().ident;
+            revisions.push((ident.db_id, ident.seq));
+        }
+        for tables in self.tables.values() {
+            for table in tables {
+                let ident = &table.get_table_info().ident;
+                revisions.push((ident.table_id, ident.seq));
+            }
+        }
+        revisions.sort_unstable();
+        revisions
+    }
+
+    pub fn list_databases(&self) -> Vec<Arc<dyn Database>> {
+        self.databases.clone()
+    }
+
+    /// Returns `None` if the database is not in the snapshot.
+    pub fn list_tables(&self, db_name: &str) -> Option<Vec<Arc<dyn Table>>> {
+        self.tables.get(db_name).map(|tables| {
+            tables
+                .iter()
+                .filter(|t| t.get_table_info().meta.drop_on.is_none())
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns `None` if the database is not in the snapshot.
+    pub fn list_tables_history(&self, db_name: &str) -> Option<Vec<Arc<dyn Table>>> {
+        self.tables.get(db_name).cloned()
+    }
+
+    /// Returns `None` if the database is not in the snapshot,
+    /// or an `UnknownTable` error if the table is not in the database.
+    pub fn get_table(&self, db_name: &str, table_name: &str) -> Option<Result<Arc<dyn Table>>> {
+        let tables = self.list_tables(db_name)?;
+        let table = tables.into_iter().find(|t| t.name() == table_name);
+        Some(
+            table.ok_or_else(|| ErrorCode::UnknownTable(format!("Unknown table '{}'", table_name))),
+        )
+    }
+}
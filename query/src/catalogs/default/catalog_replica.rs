@@ -0,0 +1,261 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::infallible::RwLock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_grpc::ClientHandle;
+use common_meta_grpc::MetaGrpcClient;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+use common_tracing::tracing;
+use metrics::counter;
+use metrics::gauge;
+
+use crate::common::MetaStore;
+use crate::Config;
+
+/// The key prefixes of the catalog, i.e. the databases and the tables, in the meta store.
+const CATALOG_KEY_PREFIXES: &[&str] = &[
+    "__fd_database",
+    "__fd_database_by_id",
+    "__fd_db_id_list",
+    "__fd_table",
+    "__fd_table_by_id",
+    "__fd_table_id_list",
+    "__fd_table_count",
+];
+
+/// The range `[WATCH_KEY_BEGIN, WATCH_KEY_END]` covers all the catalog keys.
+const WATCH_KEY_BEGIN: &str = "__fd_database";
+const WATCH_KEY_END: &str = "__fd_table~";
+
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+pub static METRIC_CATALOG_REPLICA_LAG_SECONDS: &str = "catalog_replica.lag_seconds";
+pub static METRIC_CATALOG_REPLICA_APPLIED_CHANGES: &str = "catalog_replica.applied_changes";
+
+#[derive(Default)]
+struct ReplicaState {
+    synced: bool,
+    out_of_sync_since: Option<Instant>,
+}
+
+/// Makes the catalog of a read replica cluster follow the catalog of the primary cluster.
+///
+/// The replica does a full copy of the catalog keys from the meta service of the primary
+/// (`meta.replica_source_address`) into its own meta store, then applies the changes received
+/// from the watch stream. If the stream breaks, it reconnects and copies again.
+///
+/// The catalog of a replica is read-only, and reading it fails once the replica has been out of
+/// sync for longer than `meta.replica_max_lag_in_second`.
+pub struct CatalogReplica {
+    source_address: String,
+    max_lag: Duration,
+    state: RwLock<ReplicaState>,
+}
+
+impl CatalogReplica {
+    /// Starts following the primary if `meta.replica_source_address` is set.
+    pub fn try_create(conf: &Config, target: MetaStore) -> Result<Option<Arc<CatalogReplica>>> {
+        if conf.meta.replica_source_address.is_empty() {
+            return Ok(None);
+        }
+
+        let mut source_conf = conf.meta.to_meta_grpc_client_conf();
+        source_conf.address = conf.meta.replica_source_address.clone();
+        source_conf.endpoints = vec![];
+        let source = MetaGrpcClient::try_new(&source_conf)?;
+
+        let replica = Arc::new(CatalogReplica {
+            source_address: conf.meta.replica_source_address.clone(),
+            max_lag: Duration::from_secs(conf.meta.replica_max_lag_in_second),
+            state: RwLock::new(ReplicaState::default()),
+        });
+
+        let follower = replica.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(cause) = follower.follow(source.clone(), &target).await {
+                    tracing::warn!(
+                        "Catalog replica lost the primary {}: {}",
+                        follower.source_address,
+                        cause
+                    );
+                }
+
+                follower.mark_out_of_sync();
+                tokio::time::sleep(RETRY_INTERVAL).await;
+            }
+        });
+
+        Ok(Some(replica))
+    }
+
+    /// Checks that the catalog of the replica is fresh enough to be read.
+    pub fn check_readable(&self) -> Result<()> {
+        let state = self.state.read();
+        if !state.synced {
+            return Err(ErrorCode::CatalogReplicaLagging(format!(
+                "Catalog replica has not synced with the primary {} yet",
+                self.source_address
+            )));
+        }
+
+        if let Some(since) = state.out_of_sync_since {
+            let lag = since.elapsed();
+            if lag > self.max_lag {
+                return Err(ErrorCode::CatalogReplicaLagging(format!(
+                    "Catalog replica is lagging behind the primary {} for {}s, max lag is {}s",
+                    self.source_address,
+                    lag.as_secs(),
+                    self.max_lag.as_secs()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// How long the replica has been out of sync with the primary.
+    pub fn lag(&self) -> Duration {
+        match self.state.read().out_of_sync_since {
+            Some(since) => since.elapsed(),
+            None => Duration::ZERO,
+        }
+    }
+
+    pub fn read_only_error(&self) -> ErrorCode {
+        ErrorCode::CatalogIsReadOnly(format!(
+            "Catalog is read-only on a replica cluster, run DDL on the primary {}",
+            self.source_address
+        ))
+    }
+
+    async fn follow(&self, source: Arc<ClientHandle>, target: &MetaStore) -> Result<()> {
+        // Start watching before the full copy, so no changes in between are missed.
+        let mut stream = source
+            .request(WatchRequest {
+                key: WATCH_KEY_BEGIN.to_string(),
+                key_end: Some(WATCH_KEY_END.to_string()),
+                filter_type: FilterType::All.into(),
+            })
+            .await?;
+
+        let copied = sync_catalog(&source, target).await?;
+        tracing::info!(
+            "Catalog replica copied {} keys from the primary {}",
+            copied,
+            self.source_address
+        );
+        self.mark_in_sync();
+
+        while let Some(resp) = stream.message().await? {
+            if let Some(event) = resp.event {
+                let value = event.current.map(|v| v.data);
+                if apply_catalog_change(target, &event.key, value).await? {
+                    gauge!(METRIC_CATALOG_REPLICA_LAG_SECONDS, 0.0);
+                    counter!(METRIC_CATALOG_REPLICA_APPLIED_CHANGES, 1);
+                }
+            }
+        }
+
+        Err(ErrorCode::MetaServiceError(format!(
+            "Watch stream of the primary {} closed",
+            self.source_address
+        )))
+    }
+
+    fn mark_in_sync(&self) {
+        let mut state = self.state.write();
+        state.synced = true;
+        state.out_of_sync_since = None;
+        gauge!(METRIC_CATALOG_REPLICA_LAG_SECONDS, 0.0);
+    }
+
+    fn mark_out_of_sync(&self) {
+        let mut state = self.state.write();
+        let since = *state.out_of_sync_since.get_or_insert_with(Instant::now);
+        gauge!(
+            METRIC_CATALOG_REPLICA_LAG_SECONDS,
+            since.elapsed().as_secs_f64()
+        );
+    }
+}
+
+fn is_catalog_key(key: &str) -> bool {
+    match key.split_once('/') {
+        Some((prefix, _)) => CATALOG_KEY_PREFIXES.contains(&prefix),
+        None => false,
+    }
+}
+
+/// Makes the catalog keys of `target` the same as `source`, returns the number of keys copied.
+pub async fn sync_catalog(source: &impl KVApi, target: &impl KVApi) -> Result<usize> {
+    let mut copied = 0;
+    for prefix in CATALOG_KEY_PREFIXES {
+        let prefix = format!("{}/", prefix);
+        let source_kvs = source.prefix_list_kv(&prefix).await?;
+        let target_kvs = target.prefix_list_kv(&prefix).await?;
+
+        for (key, _) in &target_kvs {
+            if !source_kvs.iter().any(|(k, _)| k == key) {
+                apply_catalog_change(target, key, None).await?;
+            }
+        }
+
+        for (key, seqv) in source_kvs {
+            let unchanged = target_kvs
+                .iter()
+                .any(|(k, v)| k == &key && v.data == seqv.data);
+            if !unchanged {
+                apply_catalog_change(target, &key, Some(seqv.data)).await?;
+                copied += 1;
+            }
+        }
+    }
+
+    Ok(copied)
+}
+
+/// Applies a change of the primary to `target`, `None` means the key is removed.
+///
+/// Returns false if `key` is not a catalog key, which is ignored.
+pub async fn apply_catalog_change(
+    target: &impl KVApi,
+    key: &str,
+    value: Option<Vec<u8>>,
+) -> Result<bool> {
+    if !is_catalog_key(key) {
+        return Ok(false);
+    }
+
+    let operation = match value {
+        Some(data) => Operation::Update(data),
+        None => Operation::Delete,
+    };
+    target
+        .upsert_kv(UpsertKVReq::new(key, MatchSeq::Any, operation, None))
+        .await?;
+    Ok(true)
+}
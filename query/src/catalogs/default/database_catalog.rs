@@ -36,6 +36,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
@@ -47,6 +49,7 @@ use crate::catalogs::catalog::Catalog;
 use crate::catalogs::default::ImmutableCatalog;
 use crate::catalogs::default::MutableCatalog;
 use crate::databases::Database;
+use crate::storages::mock::MockTableSpec;
 use crate::storages::StorageDescription;
 use crate::storages::Table;
 use crate::table_functions::TableArgs;
@@ -444,6 +447,13 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.update_table_meta(req).await
     }
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.mutable_catalog.update_multi_table_meta(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -456,4 +466,9 @@ impl Catalog for DatabaseCatalog {
         // only return mutable_catalog storage table engines
         self.mutable_catalog.get_table_engines()
     }
+
+    fn register_test_table(&self, spec: MockTableSpec) -> Result<Arc<dyn Table>> {
+        // the test tables are registered into the system database
+        self.immutable_catalog.register_test_table(spec)
+    }
 }
@@ -35,6 +35,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
@@ -48,6 +50,8 @@ use crate::catalogs::SYS_TBL_ID_BEGIN;
 use crate::databases::Database;
 use crate::databases::InformationSchemaDatabase;
 use crate::databases::SystemDatabase;
+use crate::storages::mock::MockTable;
+use crate::storages::mock::MockTableSpec;
 use crate::storages::Table;
 use crate::Config;
 
@@ -203,4 +207,27 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "update table meta not allowed for system database {:?}",
+            req
+        )))
+    }
+
+    fn register_test_table(&self, spec: MockTableSpec) -> Result<Arc<dyn Table>> {
+        if self.sys_db_meta.get_by_name("system", &spec.name).is_ok() {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "Table system.{} already exists",
+                spec.name
+            )));
+        }
+
+        let table = MockTable::try_create(self.sys_db_meta.next_table_id(), spec)?;
+        self.sys_db_meta.insert("system", table.clone());
+        Ok(table)
+    }
 }
@@ -13,12 +13,16 @@
 // limitations under the License.
 
 mod catalog_context;
+mod catalog_replica;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
 pub mod table_id_ranges;
 pub mod table_memory_meta;
 
+pub use catalog_replica::apply_catalog_change;
+pub use catalog_replica::sync_catalog;
+pub use catalog_replica::CatalogReplica;
 pub use database_catalog::DatabaseCatalog;
 // for "unit" test
 pub use immutable_catalog::ImmutableCatalog;
@@ -44,6 +44,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
@@ -52,6 +54,7 @@ use common_meta_types::MetaId;
 use common_tracing::tracing;
 
 use super::catalog_context::CatalogContext;
+use super::catalog_replica::CatalogReplica;
 use crate::catalogs::catalog::Catalog;
 use crate::common::MetaStoreProvider;
 use crate::databases::Database;
@@ -68,9 +71,11 @@ use crate::Config;
 /// - Meta data of databases are saved in meta store
 /// - Instances of `Database` are created by using database factories according to the engine
 /// - Database engines are free to save table meta in metastore or not
+/// - On a read replica cluster, it follows the catalog of the primary and is read-only
 #[derive(Clone)]
 pub struct MutableCatalog {
     ctx: CatalogContext,
+    replica: Option<Arc<CatalogReplica>>,
 }
 
 impl MutableCatalog {
@@ -96,7 +101,10 @@ impl MutableCatalog {
 
         let tenant = conf.query.tenant_id.clone();
 
-        // Create default database.
+        // Follow the catalog of the primary on a read replica cluster.
+        let replica = CatalogReplica::try_create(&conf, meta.clone())?;
+
+        // Create default database, which is replicated from the primary on a read replica.
         let req = CreateDatabaseReq {
             if_not_exists: true,
             name_ident: DatabaseNameIdent {
@@ -108,7 +116,9 @@ impl MutableCatalog {
                 ..Default::default()
             },
         };
-        meta.create_database(req).await?;
+        if replica.is_none() {
+            meta.create_database(req).await?;
+        }
 
         // Storage factory.
         let storage_factory = StorageFactory::create(conf.clone());
@@ -122,7 +132,21 @@ impl MutableCatalog {
             database_factory: Arc::new(database_factory),
             in_memory_data: Arc::new(Default::default()),
         };
-        Ok(MutableCatalog { ctx })
+        Ok(MutableCatalog { ctx, replica })
+    }
+
+    fn check_readable(&self) -> Result<()> {
+        match &self.replica {
+            Some(replica) => replica.check_readable(),
+            None => Ok(()),
+        }
+    }
+
+    fn check_writable(&self) -> Result<()> {
+        match &self.replica {
+            Some(replica) => Err(replica.read_only_error()),
+            None => Ok(()),
+        }
     }
 
     fn build_db_instance(&self, db_info: &Arc<DatabaseInfo>) -> Result<Arc<dyn Database>> {
@@ -145,6 +169,7 @@ impl MutableCatalog {
 #[async_trait::async_trait]
 impl Catalog for MutableCatalog {
     async fn get_database(&self, tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        self.check_readable()?;
         let db_info = self
             .ctx
             .meta
@@ -154,6 +179,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn list_databases(&self, tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
+        self.check_readable()?;
         let dbs = self
             .ctx
             .meta
@@ -170,6 +196,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        self.check_writable()?;
         // Create database.
         let res = self.ctx.meta.create_database(req.clone()).await?;
         tracing::error!(
@@ -193,11 +220,13 @@ impl Catalog for MutableCatalog {
     }
 
     async fn drop_database(&self, req: DropDatabaseReq) -> Result<()> {
+        self.check_writable()?;
         self.ctx.meta.drop_database(req).await?;
         Ok(())
     }
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.rename_database(req).await?;
         Ok(res)
     }
@@ -215,6 +244,7 @@ impl Catalog for MutableCatalog {
         &self,
         table_id: MetaId,
     ) -> common_exception::Result<(TableIdent, Arc<TableMeta>)> {
+        self.check_readable()?;
         let res = self.ctx.meta.get_table_by_id(table_id).await?;
         Ok(res)
     }
@@ -225,6 +255,7 @@ impl Catalog for MutableCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
+        self.check_readable()?;
         let table_info = self
             .ctx
             .meta
@@ -234,6 +265,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        self.check_readable()?;
         let table_infos = self
             .ctx
             .meta
@@ -248,6 +280,7 @@ impl Catalog for MutableCatalog {
         tenant: &str,
         db_name: &str,
     ) -> Result<Vec<Arc<dyn Table>>> {
+        self.check_readable()?;
         // `get_table_history` will not fetch the tables that created before the
         // "metasrv time travel functions" is added.
         // thus, only the table-infos of dropped tables are used.
@@ -272,26 +305,31 @@ impl Catalog for MutableCatalog {
     }
 
     async fn create_table(&self, req: CreateTableReq) -> Result<()> {
+        self.check_writable()?;
         self.ctx.meta.create_table(req).await?;
         Ok(())
     }
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.drop_table(req).await?;
         Ok(res)
     }
 
     async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.undrop_table(req).await?;
         Ok(res)
     }
 
     async fn undrop_database(&self, req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.undrop_database(req).await?;
         Ok(res)
     }
 
     async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.rename_table(req).await?;
         Ok(res)
     }
@@ -300,16 +338,28 @@ impl Catalog for MutableCatalog {
         &self,
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.upsert_table_option(req).await?;
         Ok(res)
     }
 
     async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        self.check_writable()?;
         let res = self.ctx.meta.update_table_meta(req).await?;
         Ok(res)
     }
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.check_writable()?;
+        let res = self.ctx.meta.update_multi_table_meta(req).await?;
+        Ok(res)
+    }
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
+        self.check_readable()?;
         let res = self.ctx.meta.count_tables(req).await?;
         Ok(res)
     }
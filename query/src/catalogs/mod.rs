@@ -15,6 +15,7 @@
 mod catalog;
 
 mod catalog_manager;
+mod catalog_snapshot;
 pub mod default;
 #[cfg(feature = "hive")]
 pub mod hive;
@@ -22,6 +23,7 @@ pub mod hive;
 pub use catalog::Catalog;
 pub use catalog_manager::CatalogManager;
 pub use catalog_manager::CATALOG_DEFAULT;
+pub use catalog_snapshot::CatalogSnapshot;
 pub use default::table_id_ranges::*;
 pub use default::table_memory_meta::InMemoryMetas;
 pub use default::DatabaseCatalog;
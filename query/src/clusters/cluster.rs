@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
@@ -28,6 +30,7 @@ use common_base::base::DummySignalStream;
 use common_base::base::GlobalUniqName;
 use common_base::base::SignalStream;
 use common_base::base::SignalType;
+use common_base::infallible::RwLock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::ConnectionFactory;
@@ -35,6 +38,7 @@ use common_management::ClusterApi;
 use common_management::ClusterMgr;
 use common_meta_api::KVApi;
 use common_meta_types::NodeInfo;
+use common_meta_types::NodeLoad;
 use common_tracing::tracing;
 use futures::future::select;
 use futures::future::Either;
@@ -47,10 +51,27 @@ use crate::api::FlightClient;
 use crate::common::MetaStoreProvider;
 use crate::Config;
 
+/// Reports the load of the local node, which is published to the metastore on every heartbeat.
+pub trait NodeLoadReporter: Send + Sync {
+    fn node_load(&self) -> NodeLoad;
+}
+
+/// Notified when the heartbeat observes nodes joining or leaving the cluster, e.g. to warm up
+/// a joined node before dispatching queries to it.
+pub trait ClusterListener: Send + Sync {
+    fn on_node_joined(&self, node: &NodeInfo);
+
+    fn on_node_left(&self, node: &NodeInfo);
+}
+
+type ClusterListeners = Arc<RwLock<Vec<Weak<dyn ClusterListener>>>>;
+
 pub struct ClusterDiscovery {
     local_id: String,
     heartbeat: Mutex<ClusterHeartbeat>,
     api_provider: Arc<dyn ClusterApi>,
+    load_reporter: RwLock<Option<Weak<dyn NodeLoadReporter>>>,
+    listeners: ClusterListeners,
 }
 
 impl ClusterDiscovery {
@@ -67,13 +88,46 @@ impl ClusterDiscovery {
         let meta_client = ClusterDiscovery::create_meta_client(&cfg).await?;
         let (lift_time, provider) = Self::create_provider(&cfg, meta_client)?;
 
+        let listeners = Arc::new(RwLock::new(vec![]));
         Ok(Arc::new(ClusterDiscovery {
             local_id: local_id.clone(),
             api_provider: provider.clone(),
-            heartbeat: Mutex::new(ClusterHeartbeat::create(lift_time, provider)),
+            heartbeat: Mutex::new(ClusterHeartbeat::create(
+                lift_time,
+                provider,
+                listeners.clone(),
+            )),
+            load_reporter: RwLock::new(None),
+            listeners,
         }))
     }
 
+    pub fn set_load_reporter(&self, reporter: Weak<dyn NodeLoadReporter>) {
+        *self.load_reporter.write() = Some(reporter);
+    }
+
+    pub fn get_load_reporter(&self) -> Option<Weak<dyn NodeLoadReporter>> {
+        self.load_reporter.read().clone()
+    }
+
+    pub fn add_listener(&self, listener: Weak<dyn ClusterListener>) {
+        self.listeners.write().push(listener);
+    }
+
+    pub fn get_listeners(&self) -> Vec<Weak<dyn ClusterListener>> {
+        self.listeners.read().clone()
+    }
+
+    fn local_load(&self) -> NodeLoad {
+        match self
+            .get_load_reporter()
+            .and_then(|reporter| reporter.upgrade())
+        {
+            Some(reporter) => reporter.node_load(),
+            None => NodeLoad::default(),
+        }
+    }
+
     fn create_provider(
         cfg: &Config,
         api: Arc<dyn KVApi>,
@@ -159,18 +213,36 @@ impl ClusterDiscovery {
         let cpus = cfg.query.num_cpus;
         // TODO: 127.0.0.1 || ::0
         let address = cfg.query.flight_api_address.clone();
-        let node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        let mut node_info = NodeInfo::create(self.local_id.clone(), cpus, address);
+        node_info.http_address = format!(
+            "{}:{}",
+            cfg.query.http_handler_host, cfg.query.http_handler_port
+        );
+        node_info.load = self.local_load();
 
         self.drop_invalid_nodes(&node_info).await?;
         match self.api_provider.add_node(node_info.clone()).await {
-            Ok(_) => self.start_heartbeat(node_info).await,
+            Ok(_) => {
+                // The nodes alive at registration are known, only the later changes are notified.
+                let known_nodes = match self.api_provider.get_nodes().await {
+                    Ok(nodes) => nodes,
+                    Err(cause) => {
+                        return Err(cause.add_message_back("(while cluster api get_nodes)."));
+                    }
+                };
+                self.start_heartbeat(node_info, known_nodes).await
+            }
             Err(cause) => Err(cause.add_message_back("(while cluster api add_node).")),
         }
     }
 
-    async fn start_heartbeat(self: &Arc<Self>, node_info: NodeInfo) -> Result<()> {
+    async fn start_heartbeat(
+        self: &Arc<Self>,
+        node_info: NodeInfo,
+        known_nodes: Vec<NodeInfo>,
+    ) -> Result<()> {
         let mut heartbeat = self.heartbeat.lock().await;
-        heartbeat.start(node_info);
+        heartbeat.start(node_info, known_nodes, self.get_load_reporter());
         Ok(())
     }
 }
@@ -244,27 +316,44 @@ struct ClusterHeartbeat {
     shutdown: Arc<AtomicBool>,
     shutdown_notify: Arc<Notify>,
     cluster_api: Arc<dyn ClusterApi>,
+    listeners: ClusterListeners,
     shutdown_handler: Option<JoinHandle<()>>,
 }
 
 impl ClusterHeartbeat {
-    pub fn create(timeout: Duration, cluster_api: Arc<dyn ClusterApi>) -> ClusterHeartbeat {
+    pub fn create(
+        timeout: Duration,
+        cluster_api: Arc<dyn ClusterApi>,
+        listeners: ClusterListeners,
+    ) -> ClusterHeartbeat {
         ClusterHeartbeat {
             timeout,
             cluster_api,
+            listeners,
             shutdown: Arc::new(AtomicBool::new(false)),
             shutdown_notify: Arc::new(Notify::new()),
             shutdown_handler: None,
         }
     }
 
-    fn heartbeat_loop(&self, node: NodeInfo) -> impl Future<Output = ()> + 'static {
+    fn heartbeat_loop(
+        &self,
+        mut node: NodeInfo,
+        known_nodes: Vec<NodeInfo>,
+        reporter: Option<Weak<dyn NodeLoadReporter>>,
+    ) -> impl Future<Output = ()> + 'static {
         let shutdown = self.shutdown.clone();
         let shutdown_notify = self.shutdown_notify.clone();
         let cluster_api = self.cluster_api.clone();
+        let listeners = self.listeners.clone();
         let sleep_range = self.heartbeat_interval(self.timeout);
 
         async move {
+            let mut known_nodes = known_nodes
+                .into_iter()
+                .map(|node| (node.id.clone(), node))
+                .collect::<HashMap<_, _>>();
+
             let mut shutdown_notified = Box::pin(shutdown_notify.notified());
 
             while !shutdown.load(Ordering::Relaxed) {
@@ -281,22 +370,82 @@ impl ClusterHeartbeat {
                     }
                     Either::Right((_, new_shutdown_notified)) => {
                         shutdown_notified = new_shutdown_notified;
+                        if let Some(reporter) = reporter.as_ref().and_then(|r| r.upgrade()) {
+                            node.load = reporter.node_load();
+                        }
+
                         let heartbeat = cluster_api.heartbeat(&node, None);
                         if let Err(failure) = heartbeat.await {
                             tracing::error!("Cluster cluster api heartbeat failure: {:?}", failure);
                         }
+
+                        match cluster_api.get_nodes().await {
+                            Ok(nodes) => Self::notify_changes(&listeners, &mut known_nodes, nodes),
+                            Err(failure) => {
+                                tracing::error!(
+                                    "Cluster cluster api get_nodes failure: {:?}",
+                                    failure
+                                )
+                            }
+                        }
                     }
                 }
             }
         }
     }
 
+    // The dead nodes are evicted by the ttl of their keys, so they are reported as left once
+    // the meta service stops returning them.
+    fn notify_changes(
+        listeners: &ClusterListeners,
+        known_nodes: &mut HashMap<String, NodeInfo>,
+        nodes: Vec<NodeInfo>,
+    ) {
+        let mut alive_nodes = HashMap::with_capacity(nodes.len());
+        let mut joined = vec![];
+        for node in nodes {
+            if known_nodes.remove(&node.id).is_none() {
+                joined.push(node.clone());
+            }
+            alive_nodes.insert(node.id.clone(), node);
+        }
+        let left = std::mem::replace(known_nodes, alive_nodes);
+
+        if joined.is_empty() && left.is_empty() {
+            return;
+        }
+
+        let listeners = listeners
+            .read()
+            .iter()
+            .filter_map(|listener| listener.upgrade())
+            .collect::<Vec<_>>();
+        for node in &joined {
+            tracing::info!("Cluster node joined: {}({})", node.id, node.flight_address);
+            for listener in &listeners {
+                listener.on_node_joined(node);
+            }
+        }
+        for node in left.values() {
+            tracing::info!("Cluster node left: {}({})", node.id, node.flight_address);
+            for listener in &listeners {
+                listener.on_node_left(node);
+            }
+        }
+    }
+
     fn heartbeat_interval(&self, duration: Duration) -> RangeInclusive<u128> {
         (duration / 3).as_millis()..=((duration / 3) * 2).as_millis()
     }
 
-    pub fn start(&mut self, node_info: NodeInfo) {
-        self.shutdown_handler = Some(tokio::spawn(self.heartbeat_loop(node_info)));
+    pub fn start(
+        &mut self,
+        node_info: NodeInfo,
+        known_nodes: Vec<NodeInfo>,
+        reporter: Option<Weak<dyn NodeLoadReporter>>,
+    ) {
+        let heartbeat_loop = self.heartbeat_loop(node_info, known_nodes, reporter);
+        self.shutdown_handler = Some(tokio::spawn(heartbeat_loop));
     }
 
     pub async fn shutdown(&mut self) -> Result<()> {
@@ -16,3 +16,5 @@ mod cluster;
 
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
+pub use cluster::ClusterListener;
+pub use cluster::NodeLoadReporter;
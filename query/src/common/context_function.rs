@@ -49,6 +49,12 @@ impl ContextFunction {
             "connection_id" => vec![Expression::create_literal(DataValue::String(
                 ctx.get_connection_id().into_bytes(),
             ))],
+            "current_role" => vec![Expression::create_literal(DataValue::String(
+                ctx.get_current_role()?.into_bytes(),
+            ))],
+            "last_query_id" => vec![Expression::create_literal(DataValue::String(
+                ctx.get_last_query_id().into_bytes(),
+            ))],
             _ => vec![],
         })
     }
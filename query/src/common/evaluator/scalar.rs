@@ -122,6 +122,9 @@ impl ScalarEvaluator {
             Scalar::AggregateFunction(_) => Err(ErrorCode::LogicalError(
                 "Cannot evaluate aggregate function",
             )),
+            Scalar::WindowFunction(_) => {
+                Err(ErrorCode::LogicalError("Cannot evaluate window function"))
+            }
         }
     }
 
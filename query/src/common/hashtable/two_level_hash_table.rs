@@ -86,6 +86,14 @@ impl<
         }
     }
 
+    #[inline(always)]
+    pub fn find_key(&self, key: &Key) -> Option<*mut Entity> {
+        match self {
+            HashTableKind::HashTable(data) => data.find_key(key),
+            HashTableKind::TwoLevelHashTable(data) => data.find_key(key),
+        }
+    }
+
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn convert_to_two_level(&mut self) {
         let mut two_level_hash_table = Self::create_two_level_hash_table();
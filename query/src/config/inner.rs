@@ -101,6 +101,12 @@ pub struct QueryConfig {
     pub num_cpus: u64,
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
+    /// Certificate for MySQL handler TLS, TLS is disabled if empty
+    pub mysql_tls_server_cert: String,
+    /// Key for MySQL handler TLS certificate
+    pub mysql_tls_server_key: String,
+    /// Reject the MySQL connections which are not secured by TLS
+    pub mysql_require_secure_transport: bool,
     pub max_active_sessions: u64,
     pub clickhouse_handler_host: String,
     pub clickhouse_handler_port: u16,
@@ -137,12 +143,30 @@ pub struct QueryConfig {
     pub table_cache_segment_count: u64,
     /// Max number of cached table block meta
     pub table_cache_block_meta_count: u64,
-    /// Table memory cache size (mb)
+    /// Max bytes (mb) of the cached blocks of broadcast tables
     pub table_memory_cache_mb_size: u64,
     /// Table disk cache folder root
     pub table_disk_cache_root: String,
     /// Table disk cache size (mb)
     pub table_disk_cache_mb_size: u64,
+    /// Max size of the cached query results (mb), 0 means disabled
+    pub query_result_cache_mb_size: u64,
+    /// The local directory of the temporary storage(e.g. spill files), exclusive to the node
+    pub temp_storage_root: String,
+    /// Max size of the temporary storage used by the node (mb), 0 means unlimited
+    pub temp_storage_quota_mb: u64,
+    /// Max size of the temporary storage(e.g. spill files) used by each user (mb), 0 means unlimited
+    pub temp_storage_quota_mb_per_user: u64,
+    /// The key file to encrypt the data at rest, encryption is disabled if both of the key file and the kms command are empty
+    pub storage_encryption_key_file: String,
+    /// The command to fetch the keys from a KMS, it prints the keys in the same format as the key file
+    pub storage_encryption_kms_command: String,
+    /// Reject the objects which are not encrypted, e.g. written before the encryption is enabled
+    pub storage_encryption_required: bool,
+    /// Interval (secs) of dropping the expired rows of the tables with a TTL, 0 means disabled
+    pub table_ttl_interval_secs: u64,
+    /// Comma separated addresses (grpc://host:port) of the external UDF servers which the external functions can call
+    pub external_udf_allowed_addresses: String,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
     pub jwt_key_file: String,
@@ -156,6 +180,9 @@ impl Default for QueryConfig {
             num_cpus: 0,
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
+            mysql_tls_server_cert: "".to_string(),
+            mysql_tls_server_key: "".to_string(),
+            mysql_require_secure_transport: false,
             max_active_sessions: 256,
             clickhouse_handler_host: "127.0.0.1".to_string(),
             clickhouse_handler_port: 9000,
@@ -186,6 +213,15 @@ impl Default for QueryConfig {
             table_memory_cache_mb_size: 256,
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
+            query_result_cache_mb_size: 64,
+            temp_storage_root: "_temp".to_string(),
+            temp_storage_quota_mb: 0,
+            temp_storage_quota_mb_per_user: 0,
+            storage_encryption_key_file: "".to_string(),
+            storage_encryption_kms_command: "".to_string(),
+            storage_encryption_required: false,
+            table_ttl_interval_secs: 0,
+            external_udf_allowed_addresses: "".to_string(),
             management_mode: false,
             jwt_key_file: "".to_string(),
         }
@@ -258,6 +294,11 @@ pub struct MetaConfig {
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
+    /// The address of the meta service of the primary cluster, if this cluster is a read
+    /// replica of it. The catalog follows the primary and the DDL is rejected.
+    pub replica_source_address: String,
+    /// The max lag of the catalog of a replica, in seconds, the queries fail beyond it.
+    pub replica_max_lag_in_second: u64,
 }
 
 impl Default for MetaConfig {
@@ -271,6 +312,8 @@ impl Default for MetaConfig {
             client_timeout_in_second: 10,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
+            replica_source_address: "".to_string(),
+            replica_max_lag_in_second: 60,
         }
     }
 }
@@ -322,6 +365,8 @@ impl Debug for MetaConfig {
                 "rpc_tls_meta_service_domain_name",
                 &self.rpc_tls_meta_service_domain_name,
             )
+            .field("replica_source_address", &self.replica_source_address)
+            .field("replica_max_lag_in_second", &self.replica_max_lag_in_second)
             .finish()
     }
 }
@@ -24,6 +24,7 @@ use common_exception::Result;
 use common_io::prelude::StorageAzblobConfig as InnerStorageAzblobConfig;
 use common_io::prelude::StorageConfig as InnerStorageConfig;
 use common_io::prelude::StorageFsConfig as InnerStorageFsConfig;
+use common_io::prelude::StorageGcsConfig as InnerStorageGcsConfig;
 use common_io::prelude::StorageHdfsConfig as InnerStorageHdfsConfig;
 use common_io::prelude::StorageParams;
 use common_io::prelude::StorageS3Config as InnerStorageS3Config;
@@ -159,7 +160,7 @@ impl TryInto<InnerConfig> for Config {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct StorageConfig {
-    /// Current storage type: fs|s3
+    /// Current storage type: fs|s3|azblob|gcs
     #[clap(long, default_value = "fs")]
     #[serde(rename = "type", alias = "storage_type")]
     pub storage_type: String,
@@ -180,6 +181,10 @@ pub struct StorageConfig {
     #[clap(flatten)]
     pub azblob: AzblobStorageConfig,
 
+    // google cloud storage config.
+    #[clap(flatten)]
+    pub gcs: GcsStorageConfig,
+
     // hdfs storage backend config
     #[clap(flatten)]
     pub hdfs: HdfsConfig,
@@ -199,6 +204,7 @@ impl From<InnerStorageConfig> for StorageConfig {
             fs: Default::default(),
             s3: Default::default(),
             azblob: Default::default(),
+            gcs: Default::default(),
             hdfs: Default::default(),
         };
 
@@ -211,6 +217,10 @@ impl From<InnerStorageConfig> for StorageConfig {
                 cfg.storage_type = "fs".to_string();
                 cfg.fs = v.into();
             }
+            StorageParams::Gcs(v) => {
+                cfg.storage_type = "gcs".to_string();
+                cfg.gcs = v.into();
+            }
             #[cfg(feature = "storage-hdfs")]
             StorageParams::Hdfs(v) => {
                 cfg.storage_type = "hdfs".to_string();
@@ -239,6 +249,7 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
                 match self.storage_type.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
                     "fs" => StorageParams::Fs(self.fs.try_into()?),
+                    "gcs" => StorageParams::Gcs(self.gcs.try_into()?),
                     #[cfg(feature = "storage-hdfs")]
                     "hdfs" => StorageParams::Hdfs(self.hdfs.try_into()?),
                     "memory" => StorageParams::Memory,
@@ -447,6 +458,84 @@ impl TryInto<InnerStorageAzblobConfig> for AzblobStorageConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct GcsStorageConfig {
+    /// Bucket for GCS
+    #[clap(long = "storage-gcs-bucket", default_value_t)]
+    #[serde(rename = "bucket")]
+    pub gcs_bucket: String,
+
+    /// Access id of the HMAC key of the service account for GCS
+    #[clap(long = "storage-gcs-access-key-id", default_value_t)]
+    #[serde(rename = "access_key_id")]
+    pub gcs_access_key_id: String,
+
+    /// Secret of the HMAC key of the service account for GCS
+    #[clap(long = "storage-gcs-secret-access-key", default_value_t)]
+    #[serde(rename = "secret_access_key")]
+    pub gcs_secret_access_key: String,
+
+    /// Endpoint URL for GCS
+    #[clap(
+        long = "storage-gcs-endpoint-url",
+        default_value = "https://storage.googleapis.com"
+    )]
+    #[serde(rename = "endpoint_url")]
+    pub gcs_endpoint_url: String,
+
+    #[clap(long = "storage-gcs-root", default_value_t)]
+    #[serde(rename = "root")]
+    pub gcs_root: String,
+}
+
+impl Default for GcsStorageConfig {
+    fn default() -> Self {
+        InnerStorageGcsConfig::default().into()
+    }
+}
+
+impl fmt::Debug for GcsStorageConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GcsStorageConfig")
+            .field("endpoint_url", &self.gcs_endpoint_url)
+            .field("bucket", &self.gcs_bucket)
+            .field("root", &self.gcs_root)
+            .field("access_key_id", &mask_string(&self.gcs_access_key_id, 3))
+            .field(
+                "secret_access_key",
+                &mask_string(&self.gcs_secret_access_key, 3),
+            )
+            .finish()
+    }
+}
+
+impl From<InnerStorageGcsConfig> for GcsStorageConfig {
+    fn from(inner: InnerStorageGcsConfig) -> Self {
+        Self {
+            gcs_bucket: inner.bucket,
+            gcs_access_key_id: inner.access_key_id,
+            gcs_secret_access_key: inner.secret_access_key,
+            gcs_endpoint_url: inner.endpoint_url,
+            gcs_root: inner.root,
+        }
+    }
+}
+
+impl TryInto<InnerStorageGcsConfig> for GcsStorageConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageGcsConfig> {
+        Ok(InnerStorageGcsConfig {
+            endpoint_url: self.gcs_endpoint_url,
+            bucket: self.gcs_bucket,
+            root: self.gcs_root,
+            access_key_id: self.gcs_access_key_id,
+            secret_access_key: self.gcs_secret_access_key,
+        })
+    }
+}
+
 #[derive(Clone, PartialEq, Serialize, Deserialize, Args, Debug)]
 #[serde(default)]
 pub struct HdfsConfig {
@@ -507,6 +596,18 @@ pub struct QueryConfig {
     #[clap(long, default_value = "3307")]
     pub mysql_handler_port: u16,
 
+    /// Certificate for MySQL handler TLS, TLS is disabled if empty
+    #[clap(long, default_value_t)]
+    pub mysql_tls_server_cert: String,
+
+    /// Key for MySQL handler TLS certificate
+    #[clap(long, default_value_t)]
+    pub mysql_tls_server_key: String,
+
+    /// Reject the MySQL connections which are not secured by TLS
+    #[clap(long)]
+    pub mysql_require_secure_transport: bool,
+
     #[clap(long, default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -597,7 +698,7 @@ pub struct QueryConfig {
     #[clap(long, default_value = "102400")]
     pub table_cache_block_meta_count: u64,
 
-    /// Table memory cache size (mb)
+    /// Max bytes (mb) of the cached blocks of broadcast tables
     #[clap(long, default_value = "256")]
     pub table_memory_cache_mb_size: u64,
 
@@ -609,6 +710,42 @@ pub struct QueryConfig {
     #[clap(long, default_value = "1024")]
     pub table_disk_cache_mb_size: u64,
 
+    /// Max size of the cached query results (mb), 0 means disabled
+    #[clap(long, default_value = "64")]
+    pub query_result_cache_mb_size: u64,
+
+    /// The local directory of the temporary storage(e.g. spill files), exclusive to the node
+    #[clap(long, default_value = "_temp")]
+    pub temp_storage_root: String,
+
+    /// Max size of the temporary storage used by the node (mb), 0 means unlimited
+    #[clap(long, default_value = "0")]
+    pub temp_storage_quota_mb: u64,
+
+    /// Max size of the temporary storage(e.g. spill files) used by each user (mb), 0 means unlimited
+    #[clap(long, default_value = "0")]
+    pub temp_storage_quota_mb_per_user: u64,
+
+    /// The key file to encrypt the data at rest, encryption is disabled if both of the key file and the kms command are empty
+    #[clap(long, default_value_t)]
+    pub storage_encryption_key_file: String,
+
+    /// The command to fetch the keys from a KMS, it prints the keys in the same format as the key file
+    #[clap(long, default_value_t)]
+    pub storage_encryption_kms_command: String,
+
+    /// Reject the objects which are not encrypted, e.g. written before the encryption is enabled
+    #[clap(long)]
+    pub storage_encryption_required: bool,
+
+    /// Interval (secs) of dropping the expired rows of the tables with a TTL, 0 means disabled
+    #[clap(long, default_value = "0")]
+    pub table_ttl_interval_secs: u64,
+
+    /// Comma separated addresses (grpc://host:port) of the external UDF servers which the external functions can call
+    #[clap(long, default_value_t)]
+    pub external_udf_allowed_addresses: String,
+
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     #[clap(long)]
     pub management_mode: bool,
@@ -633,6 +770,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             num_cpus: self.num_cpus,
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
+            mysql_tls_server_cert: self.mysql_tls_server_cert,
+            mysql_tls_server_key: self.mysql_tls_server_key,
+            mysql_require_secure_transport: self.mysql_require_secure_transport,
             max_active_sessions: self.max_active_sessions,
             clickhouse_handler_host: self.clickhouse_handler_host,
             clickhouse_handler_port: self.clickhouse_handler_port,
@@ -663,6 +803,15 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_memory_cache_mb_size: self.table_memory_cache_mb_size,
             table_disk_cache_root: self.table_disk_cache_root,
             table_disk_cache_mb_size: self.table_disk_cache_mb_size,
+            query_result_cache_mb_size: self.query_result_cache_mb_size,
+            temp_storage_root: self.temp_storage_root,
+            temp_storage_quota_mb: self.temp_storage_quota_mb,
+            temp_storage_quota_mb_per_user: self.temp_storage_quota_mb_per_user,
+            storage_encryption_key_file: self.storage_encryption_key_file,
+            storage_encryption_kms_command: self.storage_encryption_kms_command,
+            storage_encryption_required: self.storage_encryption_required,
+            table_ttl_interval_secs: self.table_ttl_interval_secs,
+            external_udf_allowed_addresses: self.external_udf_allowed_addresses,
             management_mode: self.management_mode,
             jwt_key_file: self.jwt_key_file,
         })
@@ -677,6 +826,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             num_cpus: inner.num_cpus,
             mysql_handler_host: inner.mysql_handler_host,
             mysql_handler_port: inner.mysql_handler_port,
+            mysql_tls_server_cert: inner.mysql_tls_server_cert,
+            mysql_tls_server_key: inner.mysql_tls_server_key,
+            mysql_require_secure_transport: inner.mysql_require_secure_transport,
             max_active_sessions: inner.max_active_sessions,
             clickhouse_handler_host: inner.clickhouse_handler_host,
             clickhouse_handler_port: inner.clickhouse_handler_port,
@@ -707,6 +859,15 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_memory_cache_mb_size: inner.table_memory_cache_mb_size,
             table_disk_cache_root: inner.table_disk_cache_root,
             table_disk_cache_mb_size: inner.table_disk_cache_mb_size,
+            query_result_cache_mb_size: inner.query_result_cache_mb_size,
+            temp_storage_root: inner.temp_storage_root,
+            temp_storage_quota_mb: inner.temp_storage_quota_mb,
+            temp_storage_quota_mb_per_user: inner.temp_storage_quota_mb_per_user,
+            storage_encryption_key_file: inner.storage_encryption_key_file,
+            storage_encryption_kms_command: inner.storage_encryption_kms_command,
+            storage_encryption_required: inner.storage_encryption_required,
+            table_ttl_interval_secs: inner.table_ttl_interval_secs,
+            external_udf_allowed_addresses: inner.external_udf_allowed_addresses,
             management_mode: inner.management_mode,
             jwt_key_file: inner.jwt_key_file,
         }
@@ -837,6 +998,14 @@ pub struct MetaConfig {
 
     #[clap(long = "meta-rpc-tls-meta-service-domain-name", default_value_t)]
     pub rpc_tls_meta_service_domain_name: String,
+
+    /// The address of the meta service of the primary cluster, if this cluster is a read replica
+    #[clap(long = "meta-replica-source-address", default_value_t)]
+    pub replica_source_address: String,
+
+    /// The max lag of the catalog of a replica, in seconds
+    #[clap(long = "meta-replica-max-lag-in-second", default_value = "60")]
+    pub replica_max_lag_in_second: u64,
 }
 
 impl Default for MetaConfig {
@@ -858,6 +1027,8 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: self.client_timeout_in_second,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
+            replica_source_address: self.replica_source_address,
+            replica_max_lag_in_second: self.replica_max_lag_in_second,
         })
     }
 }
@@ -873,6 +1044,8 @@ impl From<InnerMetaConfig> for MetaConfig {
             client_timeout_in_second: inner.client_timeout_in_second,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
+            replica_source_address: inner.replica_source_address,
+            replica_max_lag_in_second: inner.replica_max_lag_in_second,
         }
     }
 }
@@ -894,6 +1067,8 @@ impl Debug for MetaConfig {
                 "rpc_tls_meta_service_domain_name",
                 &self.rpc_tls_meta_service_domain_name,
             )
+            .field("replica_source_address", &self.replica_source_address)
+            .field("replica_max_lag_in_second", &self.replica_max_lag_in_second)
             .finish()
     }
 }
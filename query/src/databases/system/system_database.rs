@@ -55,6 +55,14 @@ impl SystemDatabase {
             system::EnginesTable::create(sys_db_meta.next_table_id()),
             system::RolesTable::create(sys_db_meta.next_table_id()),
             system::StagesTable::create(sys_db_meta.next_table_id()),
+            system::ReplicationsTable::create(sys_db_meta.next_table_id()),
+            system::TempStorageTable::create(sys_db_meta.next_table_id()),
+            system::CorruptBlocksTable::create(sys_db_meta.next_table_id()),
+            system::QueryProfileTable::create(sys_db_meta.next_table_id()),
+            system::LoadTable::create(sys_db_meta.next_table_id()),
+            system::CachesTable::create(sys_db_meta.next_table_id()),
+            system::ClusteringTable::create(sys_db_meta.next_table_id()),
+            system::FormatsTable::create(sys_db_meta.next_table_id()),
         ];
 
         for tbl in table_list.into_iter() {
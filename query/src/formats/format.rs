@@ -15,6 +15,10 @@
 use std::any::Any;
 
 use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 pub trait InputState: Send {
@@ -34,3 +38,79 @@ pub trait InputFormat: Send + Sync {
 
     fn skip_header(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<usize>;
 }
+
+/// The header rows in front of the data of a text input format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputHeader {
+    None,
+    /// One row that is skipped without validation (`skip_header = 1`).
+    Skip,
+    /// One row with the column names.
+    Names,
+    /// One row with the column names followed by one row with the column types.
+    NamesAndTypes,
+}
+
+impl InputHeader {
+    pub fn rows(&self) -> usize {
+        match self {
+            InputHeader::None => 0,
+            InputHeader::Skip | InputHeader::Names => 1,
+            InputHeader::NamesAndTypes => 2,
+        }
+    }
+
+    /// Validate the header rows against the schema of the table.
+    pub fn check(&self, schema: &DataSchemaRef, rows: &[Vec<String>]) -> Result<()> {
+        let fields = schema.fields();
+
+        if matches!(self, InputHeader::Names | InputHeader::NamesAndTypes) {
+            let names = rows.get(0).map(|row| row.as_slice()).unwrap_or_default();
+            if names.len() != fields.len() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Header has {} columns, but the schema has {} columns",
+                    names.len(),
+                    fields.len()
+                )));
+            }
+
+            for (name, field) in names.iter().zip(fields.iter()) {
+                if !name.trim().eq_ignore_ascii_case(field.name()) {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Header column name mismatch, expected: {}, but got: {}",
+                        field.name(),
+                        name
+                    )));
+                }
+            }
+        }
+
+        if matches!(self, InputHeader::NamesAndTypes) {
+            let types = rows.get(1).map(|row| row.as_slice()).unwrap_or_default();
+            if types.len() != fields.len() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Header has {} column types, but the schema has {} columns",
+                    types.len(),
+                    fields.len()
+                )));
+            }
+
+            for (type_name, field) in types.iter().zip(fields.iter()) {
+                let type_name = type_name.trim();
+                let data_type = field.data_type();
+                if !type_name.eq_ignore_ascii_case(&data_type.name())
+                    && !type_name.eq_ignore_ascii_case(&remove_nullable(data_type).name())
+                {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Header column type mismatch for column {}, expected: {}, but got: {}",
+                        field.name(),
+                        data_type.name(),
+                        type_name
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -17,6 +17,8 @@ use std::any::Any;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::StringType;
 use common_datavalues::TypeDeserializer;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -26,8 +28,10 @@ use common_io::prelude::BufferReadExt;
 use common_io::prelude::FormatSettings;
 use common_io::prelude::MemoryReader;
 
+use crate::formats::format_options::TEXT_INPUT_OPTIONS;
 use crate::formats::FormatFactory;
 use crate::formats::InputFormat;
+use crate::formats::InputHeader;
 use crate::formats::InputState;
 
 pub struct CsvInputState {
@@ -48,7 +52,7 @@ impl InputState for CsvInputState {
 pub struct CsvInputFormat {
     schema: DataSchemaRef,
     field_delimiter: u8,
-    need_skip_header: bool,
+    header: InputHeader,
     row_delimiter: Option<u8>,
     min_accepted_rows: usize,
     min_accepted_bytes: usize,
@@ -57,18 +61,21 @@ pub struct CsvInputFormat {
 
 impl CsvInputFormat {
     pub fn register(factory: &mut FormatFactory) {
-        factory.register_input(
-            "csv",
-            Box::new(
-                |name: &str, schema: DataSchemaRef, settings: FormatSettings| {
-                    CsvInputFormat::try_create(name, schema, settings, 8192, 10 * 1024 * 1024)
-                },
-            ),
-        )
+        for name in ["csv", "csvwithnames", "csvwithnamesandtypes"] {
+            factory.register_input(
+                name,
+                TEXT_INPUT_OPTIONS,
+                Box::new(
+                    |name: &str, schema: DataSchemaRef, settings: FormatSettings| {
+                        CsvInputFormat::try_create(name, schema, settings, 8192, 10 * 1024 * 1024)
+                    },
+                ),
+            )
+        }
     }
 
     pub fn try_create(
-        _name: &str,
+        name: &str,
         schema: DataSchemaRef,
         settings: FormatSettings,
         min_accepted_rows: usize,
@@ -88,21 +95,36 @@ impl CsvInputFormat {
             row_delimiter = Some(settings.record_delimiter[0]);
         }
 
-        let need_skip_header = settings.skip_header;
+        let header = match name.to_lowercase().as_str() {
+            "csvwithnames" => InputHeader::Names,
+            "csvwithnamesandtypes" => InputHeader::NamesAndTypes,
+            _ if settings.skip_header => InputHeader::Skip,
+            _ => InputHeader::None,
+        };
 
         Ok(Box::new(CsvInputFormat {
             schema,
             settings,
             row_delimiter,
             field_delimiter,
-            need_skip_header,
+            header,
             min_accepted_rows,
             min_accepted_bytes,
         }))
     }
 
-    fn find_quotes(buf: &[u8], pos: usize, state: &mut CsvInputState) -> usize {
-        let index = pos + position2::<true, b'"', b'\''>(&buf[pos..]);
+    fn has_default_quotes(&self) -> bool {
+        self.settings.quote_char == [b'"', b'\'']
+    }
+
+    fn find_quotes(&self, buf: &[u8], pos: usize, state: &mut CsvInputState) -> usize {
+        let index = match self.has_default_quotes() {
+            true => pos + position2::<true, b'"', b'\''>(&buf[pos..]),
+            false => buf[pos..]
+                .iter()
+                .position(|b| *b == state.quotes)
+                .map_or(buf.len(), |index| pos + index),
+        };
 
         if index != buf.len() {
             state.quotes = 0;
@@ -115,7 +137,7 @@ impl CsvInputFormat {
     fn find_delimiter(&self, buf: &[u8], pos: usize, state: &mut CsvInputState) -> usize {
         if let Some(b) = &self.row_delimiter {
             for index in pos..buf.len() {
-                if buf[index] == b'"' || buf[index] == b'\'' {
+                if self.settings.quote_char.contains(&buf[index]) {
                     state.quotes = buf[index];
                     return index + 1;
                 }
@@ -125,16 +147,24 @@ impl CsvInputFormat {
                 }
             }
         } else {
-            let position = pos + position4::<true, b'"', b'\'', b'\r', b'\n'>(&buf[pos..]);
+            let position = match self.has_default_quotes() {
+                true => pos + position4::<true, b'"', b'\'', b'\r', b'\n'>(&buf[pos..]),
+                false => buf[pos..]
+                    .iter()
+                    .position(|b| {
+                        *b == b'\r' || *b == b'\n' || self.settings.quote_char.contains(b)
+                    })
+                    .map_or(buf.len(), |index| pos + index),
+            };
 
             if position != buf.len() {
-                if buf[position] == b'"' || buf[position] == b'\'' {
-                    state.quotes = buf[position];
-                    return position + 1;
-                } else if buf[position] == b'\r' {
+                if buf[position] == b'\r' {
                     return self.accept_row::<b'\n'>(buf, pos, state, position);
                 } else if buf[position] == b'\n' {
                     return self.accept_row::<b'\r'>(buf, pos, state, position);
+                } else {
+                    state.quotes = buf[position];
+                    return position + 1;
                 }
             }
         }
@@ -169,6 +199,64 @@ impl CsvInputFormat {
 
         index + 1
     }
+
+    fn ignore_row_delimiter(&self, reader: &mut MemoryReader, row_index: usize) -> Result<()> {
+        if let Some(delimiter) = &self.row_delimiter {
+            if !reader.ignore_white_spaces_and_byte(*delimiter)? && !reader.eof()? {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Parse csv error at line {}",
+                    row_index
+                )));
+            }
+        } else {
+            if (!reader.ignore_white_spaces_and_byte(b'\n')?
+                & !reader.ignore_white_spaces_and_byte(b'\r')?)
+                && !reader.eof()?
+            {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Parse csv error at line {}",
+                    row_index
+                )));
+            }
+
+            // \r\n
+            reader.ignore_white_spaces_and_byte(b'\n')?;
+        }
+
+        Ok(())
+    }
+
+    fn read_header(&self, memory: Vec<u8>) -> Result<Vec<Vec<String>>> {
+        let mut reader = MemoryReader::new(memory);
+        let mut rows = Vec::with_capacity(self.header.rows());
+
+        while rows.len() < self.header.rows() && !reader.eof()? {
+            let mut deserializer = StringType::new_impl().create_deserializer(1);
+            let mut num_fields = 0;
+
+            loop {
+                deserializer.de_text_csv(&mut reader, &self.settings)?;
+                num_fields += 1;
+
+                if !reader.ignore_white_spaces_and_byte(self.field_delimiter)? {
+                    break;
+                }
+            }
+
+            self.ignore_row_delimiter(&mut reader, rows.len())?;
+
+            let column = deserializer.finish_to_column();
+            let mut row = Vec::with_capacity(num_fields);
+            for index in 0..num_fields {
+                if let DataValue::String(name) = column.get(index) {
+                    row.push(String::from_utf8_lossy(&name).to_string());
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
 }
 
 impl InputFormat for CsvInputFormat {
@@ -214,30 +302,7 @@ impl InputFormat for CsvInputFormat {
             }
 
             memory_reader.ignore_white_spaces_and_byte(self.field_delimiter)?;
-
-            if let Some(delimiter) = &self.row_delimiter {
-                if !memory_reader.ignore_white_spaces_and_byte(*delimiter)?
-                    && !memory_reader.eof()?
-                {
-                    return Err(ErrorCode::BadBytes(format!(
-                        "Parse csv error at line {}",
-                        row_index
-                    )));
-                }
-            } else {
-                if (!memory_reader.ignore_white_spaces_and_byte(b'\n')?
-                    & !memory_reader.ignore_white_spaces_and_byte(b'\r')?)
-                    && !memory_reader.eof()?
-                {
-                    return Err(ErrorCode::BadBytes(format!(
-                        "Parse csv error at line {}",
-                        row_index
-                    )));
-                }
-
-                // \r\n
-                memory_reader.ignore_white_spaces_and_byte(b'\n')?;
-            }
+            self.ignore_row_delimiter(&mut memory_reader, row_index)?;
 
             row_index += 1;
         }
@@ -263,7 +328,7 @@ impl InputFormat for CsvInputFormat {
         state.need_more_data = true;
         while index < buf.len() && state.need_more_data {
             index = match state.quotes != 0 {
-                true => Self::find_quotes(buf, index, state),
+                true => self.find_quotes(buf, index, state),
                 false => self.find_delimiter(buf, index, state),
             }
         }
@@ -273,24 +338,35 @@ impl InputFormat for CsvInputFormat {
     }
 
     fn skip_header(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<usize> {
-        if self.need_skip_header {
-            let mut index = 0;
-            let state = state.as_any().downcast_mut::<CsvInputState>().unwrap();
-
-            while index < buf.len() {
-                index = match state.quotes != 0 {
-                    true => Self::find_quotes(buf, index, state),
-                    false => self.find_delimiter(buf, index, state),
-                };
-
-                if state.accepted_rows == 1 {
-                    return Ok(index);
+        let header_rows = self.header.rows();
+        let state = state.as_any().downcast_mut::<CsvInputState>().unwrap();
+
+        if state.accepted_rows >= header_rows {
+            return Ok(0);
+        }
+
+        let mut index = 0;
+        while index < buf.len() {
+            index = match state.quotes != 0 {
+                true => self.find_quotes(buf, index, state),
+                false => self.find_delimiter(buf, index, state),
+            };
+
+            if state.accepted_rows == header_rows {
+                if self.header != InputHeader::Skip {
+                    state.memory.extend_from_slice(&buf[..index]);
+                    let rows = self.read_header(std::mem::take(&mut state.memory))?;
+                    self.header.check(&self.schema, &rows)?;
                 }
+
+                return Ok(index);
             }
+        }
 
-            return Ok(buf.len());
+        if self.header != InputHeader::Skip {
+            state.memory.extend_from_slice(buf);
         }
 
-        Ok(0)
+        Ok(buf.len())
     }
 }
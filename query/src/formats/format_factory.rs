@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -23,13 +24,34 @@ use once_cell::sync::Lazy;
 
 use crate::formats::format::InputFormat;
 use crate::formats::format_csv::CsvInputFormat;
+use crate::formats::format_options::suggest;
+use crate::formats::format_options::FormatOption;
+use crate::formats::format_options::COMPRESSION;
+use crate::formats::format_options::FORMAT_TYPE_OPTION;
 use crate::formats::format_parquet::ParquetInputFormat;
+use crate::formats::format_tsv::TsvInputFormat;
+use crate::formats::output_format::OutputFormat;
+use crate::formats::output_format_arrow::ArrowOutputFormat;
+use crate::formats::output_format_csv::CSVOutputFormat;
+use crate::formats::output_format_csv::TSVOutputFormat;
+use crate::formats::output_format_json::JsonCompactOutputFormat;
+use crate::formats::output_format_json::JsonOutputFormat;
+use crate::formats::output_format_ndjson::NDJsonOutputFormat;
+use crate::formats::output_format_parquet::ParquetOutputFormat;
+use crate::formats::output_format_values::ValuesOutputFormat;
 
 pub type InputFormatFactoryCreator =
     Box<dyn Fn(&str, DataSchemaRef, FormatSettings) -> Result<Box<dyn InputFormat>> + Send + Sync>;
 
+pub type OutputFormatFactoryCreator =
+    Box<dyn Fn(&str, DataSchemaRef) -> Result<Box<dyn OutputFormat>> + Send + Sync>;
+
 pub struct FormatFactory {
     case_insensitive_desc: HashMap<String, InputFormatFactoryCreator>,
+    case_insensitive_output_desc: HashMap<String, OutputFormatFactoryCreator>,
+    // The options supported by the formats, sorted by the names of the formats.
+    input_options: BTreeMap<String, Vec<FormatOption>>,
+    output_options: BTreeMap<String, Vec<FormatOption>>,
 }
 
 static FORMAT_FACTORY: Lazy<Arc<FormatFactory>> = Lazy::new(|| {
@@ -37,6 +59,21 @@ static FORMAT_FACTORY: Lazy<Arc<FormatFactory>> = Lazy::new(|| {
 
     CsvInputFormat::register(&mut format_factory);
     ParquetInputFormat::register(&mut format_factory);
+    TsvInputFormat::register(&mut format_factory);
+
+    CSVOutputFormat::register(&mut format_factory);
+    TSVOutputFormat::register(&mut format_factory);
+    JsonOutputFormat::register(&mut format_factory);
+    JsonCompactOutputFormat::register(&mut format_factory);
+    NDJsonOutputFormat::register(&mut format_factory);
+    ValuesOutputFormat::register(&mut format_factory);
+    ParquetOutputFormat::register(&mut format_factory);
+    ArrowOutputFormat::register(&mut format_factory);
+
+    // NDJSON is loaded by the NDJSON source of COPY and streaming load, not by an `InputFormat`.
+    for name in ["json", "ndjson", "jsoneachrow"] {
+        format_factory.register_input_options(name, &[COMPRESSION]);
+    }
 
     Arc::new(format_factory)
 });
@@ -45,6 +82,9 @@ impl FormatFactory {
     pub(in crate::formats::format_factory) fn create() -> FormatFactory {
         FormatFactory {
             case_insensitive_desc: Default::default(),
+            case_insensitive_output_desc: Default::default(),
+            input_options: Default::default(),
+            output_options: Default::default(),
         }
     }
 
@@ -52,9 +92,24 @@ impl FormatFactory {
         FORMAT_FACTORY.as_ref()
     }
 
-    pub fn register_input(&mut self, name: &str, creator: InputFormatFactoryCreator) {
+    pub fn register_input(
+        &mut self,
+        name: &str,
+        options: &[FormatOption],
+        creator: InputFormatFactoryCreator,
+    ) {
         let case_insensitive_desc = &mut self.case_insensitive_desc;
         case_insensitive_desc.insert(name.to_lowercase(), creator);
+        self.register_input_options(name, options);
+    }
+
+    pub fn register_input_options(&mut self, name: &str, options: &[FormatOption]) {
+        self.input_options.insert(name.to_lowercase(), options.to_vec());
+    }
+
+    pub fn has_input(&self, name: impl AsRef<str>) -> bool {
+        let lowercase_name = name.as_ref().to_lowercase();
+        self.case_insensitive_desc.contains_key(&lowercase_name)
     }
 
     pub fn get_input(
@@ -75,4 +130,118 @@ impl FormatFactory {
 
         creator(origin_name, schema, settings)
     }
+
+    pub fn register_output(
+        &mut self,
+        name: &str,
+        options: &[FormatOption],
+        creator: OutputFormatFactoryCreator,
+    ) {
+        let case_insensitive_desc = &mut self.case_insensitive_output_desc;
+        case_insensitive_desc.insert(name.to_lowercase(), creator);
+        self.output_options.insert(name.to_lowercase(), options.to_vec());
+    }
+
+    pub fn has_output(&self, name: impl AsRef<str>) -> bool {
+        let lowercase_name = name.as_ref().to_lowercase();
+        self.case_insensitive_output_desc
+            .contains_key(&lowercase_name)
+    }
+
+    pub fn get_output(
+        &self,
+        name: impl AsRef<str>,
+        schema: DataSchemaRef,
+    ) -> Result<Box<dyn OutputFormat>> {
+        let origin_name = name.as_ref();
+        let lowercase_name = origin_name.to_lowercase();
+
+        let creator = self
+            .case_insensitive_output_desc
+            .get(&lowercase_name)
+            .ok_or_else(|| {
+                ErrorCode::UnknownFormat(format!("Unsupported output formats: {}", origin_name))
+            })?;
+
+        creator(origin_name, schema)
+    }
+
+    /// The input formats and their options, sorted by the names.
+    pub fn input_formats(&self) -> impl Iterator<Item = (&str, &[FormatOption])> {
+        self.input_options
+            .iter()
+            .map(|(name, options)| (name.as_str(), options.as_slice()))
+    }
+
+    /// The output formats and their options, sorted by the names.
+    pub fn output_formats(&self) -> impl Iterator<Item = (&str, &[FormatOption])> {
+        self.output_options
+            .iter()
+            .map(|(name, options)| (name.as_str(), options.as_slice()))
+    }
+
+    /// Check the `FILE_FORMAT` options of reading files of the format, so that an unknown or
+    /// invalid option fails the statement before any file is read.
+    pub fn check_input_options(
+        &self,
+        name: impl AsRef<str>,
+        options: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        Self::check_options(&self.input_options, "input", name.as_ref(), options)
+    }
+
+    /// Check the `FILE_FORMAT` options of writing files of the format.
+    pub fn check_output_options(
+        &self,
+        name: impl AsRef<str>,
+        options: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        Self::check_options(&self.output_options, "output", name.as_ref(), options)
+    }
+
+    fn check_options(
+        formats: &BTreeMap<String, Vec<FormatOption>>,
+        direction: &str,
+        name: &str,
+        options: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        let supported = formats.get(&name.to_lowercase()).ok_or_else(|| {
+            let names = formats.keys().map(|name| name.as_str());
+            ErrorCode::UnknownFormat(format!(
+                "Unsupported {} format: {}{}",
+                direction,
+                name,
+                did_you_mean(suggest(name, names)),
+            ))
+        })?;
+
+        for (key, value) in options {
+            let key = key.to_lowercase();
+            if key == FORMAT_TYPE_OPTION {
+                continue;
+            }
+
+            match supported.iter().find(|option| option.name == key) {
+                Some(option) => option.check(value)?,
+                None => {
+                    let names = supported.iter().map(|option| option.name);
+                    return Err(ErrorCode::BadOption(format!(
+                        "Unknown option {} of {} format {}{}",
+                        key,
+                        direction,
+                        name,
+                        did_you_mean(suggest(&key, names)),
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn did_you_mean(suggestion: Option<&str>) -> String {
+    match suggestion {
+        None => "".to_string(),
+        Some(suggestion) => format!(", did you mean {}?", suggestion),
+    }
 }
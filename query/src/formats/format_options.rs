@@ -0,0 +1,149 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::parse_escape_string;
+use common_meta_types::StageFileCompression;
+
+/// The values accepted by a format option.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FormatOptionKind {
+    UInt,
+    /// One byte, escapes like `'\t'` are allowed.
+    Delimiter,
+    /// One byte or `'\r\n'`.
+    RecordDelimiter,
+    Compression,
+}
+
+/// An option of a format given in `FILE_FORMAT = (...)`, registered with the format in the
+/// `FormatFactory`, so that the options are checked when the statement is planned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FormatOption {
+    pub name: &'static str,
+    pub kind: FormatOptionKind,
+    pub description: &'static str,
+}
+
+pub const SKIP_HEADER: FormatOption = FormatOption {
+    name: "skip_header",
+    kind: FormatOptionKind::UInt,
+    description: "Number of lines at the start of the file to skip",
+};
+
+pub const FIELD_DELIMITER: FormatOption = FormatOption {
+    name: "field_delimiter",
+    kind: FormatOptionKind::Delimiter,
+    description: "The character between the fields of a record",
+};
+
+pub const RECORD_DELIMITER: FormatOption = FormatOption {
+    name: "record_delimiter",
+    kind: FormatOptionKind::RecordDelimiter,
+    description: "The character or '\\r\\n' between the records",
+};
+
+pub const COMPRESSION: FormatOption = FormatOption {
+    name: "compression",
+    kind: FormatOptionKind::Compression,
+    description: "The compression of the files, auto detects it by the file extension",
+};
+
+/// The options of the delimited text formats, like CSV and TSV.
+pub const TEXT_INPUT_OPTIONS: &[FormatOption] =
+    &[SKIP_HEADER, FIELD_DELIMITER, RECORD_DELIMITER, COMPRESSION];
+
+pub const TEXT_OUTPUT_OPTIONS: &[FormatOption] = &[FIELD_DELIMITER, RECORD_DELIMITER];
+
+/// The option choosing the format, accepted by all the formats.
+pub const FORMAT_TYPE_OPTION: &str = "type";
+
+impl FormatOption {
+    pub fn check(&self, value: &str) -> Result<()> {
+        // `NONE` has always been accepted by the delimiters, e.g. in the stages created before
+        // the options are checked.
+        let is_delimiter = matches!(
+            self.kind,
+            FormatOptionKind::Delimiter | FormatOptionKind::RecordDelimiter
+        );
+        if is_delimiter && value.eq_ignore_ascii_case("none") {
+            return Ok(());
+        }
+
+        let valid = match self.kind {
+            FormatOptionKind::UInt => value.parse::<u64>().is_ok(),
+            FormatOptionKind::Delimiter => parse_escape_string(value.as_bytes()).len() == 1,
+            FormatOptionKind::RecordDelimiter => {
+                let value = parse_escape_string(value.as_bytes());
+                value.len() == 1 || value == "\r\n"
+            }
+            FormatOptionKind::Compression => {
+                return StageFileCompression::from_str(value)
+                    .map(|_| ())
+                    .map_err(|cause| {
+                        ErrorCode::BadOption(format!("Invalid value of {}: {}", self.name, cause))
+                    });
+            }
+        };
+
+        match valid {
+            true => Ok(()),
+            false => Err(ErrorCode::BadOption(format!(
+                "Invalid value '{}' of {}, expected {}",
+                value,
+                self.name,
+                self.kind.expected()
+            ))),
+        }
+    }
+}
+
+impl FormatOptionKind {
+    pub fn expected(&self) -> &'static str {
+        match self {
+            FormatOptionKind::UInt => "an unsigned integer",
+            FormatOptionKind::Delimiter => "one character",
+            FormatOptionKind::RecordDelimiter => "one character or '\\r\\n'",
+            FormatOptionKind::Compression => "a compression type",
+        }
+    }
+}
+
+/// The candidate closest to `name` by the edit distance, if it's close enough to be a typo.
+pub fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let name = name.to_lowercase();
+    candidates
+        .map(|candidate| (edit_distance(&name, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= candidate.len().max(name.len()) / 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs = rhs.as_bytes();
+    let mut row = (0..=rhs.len()).collect::<Vec<_>>();
+    for (i, l) in lhs.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, r) in rhs.iter().enumerate() {
+            let substitution = diagonal + (l != *r) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[rhs.len()]
+}
@@ -55,6 +55,7 @@ impl ParquetInputFormat {
     pub fn register(factory: &mut FormatFactory) {
         factory.register_input(
             "parquet",
+            &[],
             Box::new(
                 |name: &str, schema: DataSchemaRef, _settings: FormatSettings| {
                     ParquetInputFormat::try_create(name, schema)
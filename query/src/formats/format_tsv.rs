@@ -0,0 +1,305 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::StringType;
+use common_datavalues::TypeDeserializer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::position3;
+use common_io::prelude::BufferRead;
+use common_io::prelude::BufferReadExt;
+use common_io::prelude::FormatSettings;
+use common_io::prelude::MemoryReader;
+
+use crate::formats::format_options::TEXT_INPUT_OPTIONS;
+use crate::formats::FormatFactory;
+use crate::formats::InputFormat;
+use crate::formats::InputHeader;
+use crate::formats::InputState;
+
+pub struct TsvInputState {
+    pub escaped: bool,
+    pub memory: Vec<u8>,
+    pub accepted_rows: usize,
+    pub accepted_bytes: usize,
+    pub need_more_data: bool,
+    pub ignore_if_first: Option<u8>,
+}
+
+impl InputState for TsvInputState {
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Tab separated values: fields are separated by `\t` and rows by `\n` (or `\r\n`).
+/// Special characters inside a field are escaped with a backslash and `\N` is NULL.
+pub struct TsvInputFormat {
+    schema: DataSchemaRef,
+    header: InputHeader,
+    min_accepted_rows: usize,
+    min_accepted_bytes: usize,
+    settings: FormatSettings,
+}
+
+impl TsvInputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        for name in ["tsv", "tsvwithnames", "tsvwithnamesandtypes"] {
+            factory.register_input(
+                name,
+                TEXT_INPUT_OPTIONS,
+                Box::new(
+                    |name: &str, schema: DataSchemaRef, settings: FormatSettings| {
+                        TsvInputFormat::try_create(name, schema, settings, 8192, 10 * 1024 * 1024)
+                    },
+                ),
+            )
+        }
+    }
+
+    pub fn try_create(
+        name: &str,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+        min_accepted_rows: usize,
+        min_accepted_bytes: usize,
+    ) -> Result<Box<dyn InputFormat>> {
+        let header = match name.to_lowercase().as_str() {
+            "tsvwithnames" => InputHeader::Names,
+            "tsvwithnamesandtypes" => InputHeader::NamesAndTypes,
+            _ if settings.skip_header => InputHeader::Skip,
+            _ => InputHeader::None,
+        };
+
+        Ok(Box::new(TsvInputFormat {
+            schema,
+            header,
+            settings,
+            min_accepted_rows,
+            min_accepted_bytes,
+        }))
+    }
+
+    fn find_delimiter(&self, buf: &[u8], pos: usize, state: &mut TsvInputState) -> usize {
+        if state.escaped {
+            state.escaped = false;
+            return pos + 1;
+        }
+
+        let position = pos + position3::<true, b'\\', b'\r', b'\n'>(&buf[pos..]);
+
+        if position != buf.len() {
+            if buf[position] == b'\r' {
+                return self.accept_row::<b'\n'>(buf, pos, state, position);
+            } else if buf[position] == b'\n' {
+                return self.accept_row::<b'\r'>(buf, pos, state, position);
+            } else {
+                state.escaped = true;
+                return position + 1;
+            }
+        }
+
+        buf.len()
+    }
+
+    #[inline(always)]
+    fn accept_row<const C: u8>(
+        &self,
+        buf: &[u8],
+        pos: usize,
+        state: &mut TsvInputState,
+        index: usize,
+    ) -> usize {
+        state.accepted_rows += 1;
+        state.accepted_bytes += index - pos;
+
+        if state.accepted_rows >= self.min_accepted_rows
+            || (state.accepted_bytes + index) >= self.min_accepted_bytes
+        {
+            state.need_more_data = false;
+        }
+
+        if buf.len() <= index + 1 {
+            state.ignore_if_first = Some(C);
+        } else if buf[index + 1] == C {
+            return index + 2;
+        }
+
+        index + 1
+    }
+
+    fn is_field_end(reader: &mut MemoryReader) -> Result<bool> {
+        let buf = reader.fill_buf()?;
+        Ok(buf.is_empty() || buf[0] == b'\t' || buf[0] == b'\n' || buf[0] == b'\r')
+    }
+
+    fn ignore_row_delimiter(reader: &mut MemoryReader, row_index: usize) -> Result<()> {
+        if !reader.ignore_byte(b'\n')? && !reader.ignore_byte(b'\r')? && !reader.eof()? {
+            return Err(ErrorCode::BadBytes(format!(
+                "Parse tsv error at line {}",
+                row_index
+            )));
+        }
+
+        // \r\n
+        reader.ignore_byte(b'\n')?;
+        Ok(())
+    }
+
+    fn read_header(&self, memory: Vec<u8>) -> Result<Vec<Vec<String>>> {
+        let mut reader = MemoryReader::new(memory);
+        let mut rows = Vec::with_capacity(self.header.rows());
+
+        while rows.len() < self.header.rows() && !reader.eof()? {
+            let mut deserializer = StringType::new_impl().create_deserializer(1);
+            let mut num_fields = 0;
+
+            loop {
+                deserializer.de_text(&mut reader, &self.settings)?;
+                num_fields += 1;
+
+                if !reader.ignore_byte(b'\t')? {
+                    break;
+                }
+            }
+
+            Self::ignore_row_delimiter(&mut reader, rows.len())?;
+
+            let column = deserializer.finish_to_column();
+            let mut row = Vec::with_capacity(num_fields);
+            for index in 0..num_fields {
+                if let DataValue::String(name) = column.get(index) {
+                    row.push(String::from_utf8_lossy(&name).to_string());
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(rows)
+    }
+}
+
+impl InputFormat for TsvInputFormat {
+    fn support_parallel(&self) -> bool {
+        true
+    }
+
+    fn create_state(&self) -> Box<dyn InputState> {
+        Box::new(TsvInputState {
+            escaped: false,
+            memory: vec![],
+            accepted_rows: 0,
+            accepted_bytes: 0,
+            need_more_data: false,
+            ignore_if_first: None,
+        })
+    }
+
+    fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>> {
+        let mut deserializers = Vec::with_capacity(self.schema.num_fields());
+        for field in self.schema.fields() {
+            let data_type = field.data_type();
+            deserializers.push(data_type.create_deserializer(self.min_accepted_rows));
+        }
+
+        let mut state = std::mem::replace(state, self.create_state());
+        let state = state.as_any().downcast_mut::<TsvInputState>().unwrap();
+        let memory = std::mem::take(&mut state.memory);
+        let mut memory_reader = MemoryReader::new(memory);
+
+        let mut row_index = 0;
+        while !memory_reader.eof()? {
+            for column_index in 0..deserializers.len() {
+                if column_index != 0 && !memory_reader.ignore_byte(b'\t')? {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Parse tsv error at line {}, expected {} columns",
+                        row_index,
+                        deserializers.len()
+                    )));
+                }
+
+                if Self::is_field_end(&mut memory_reader)? || memory_reader.ignore_bytes(b"\\N")? {
+                    deserializers[column_index].de_default(&self.settings);
+                } else {
+                    deserializers[column_index].de_text(&mut memory_reader, &self.settings)?;
+                }
+            }
+
+            Self::ignore_row_delimiter(&mut memory_reader, row_index)?;
+            row_index += 1;
+        }
+
+        let mut columns = Vec::with_capacity(deserializers.len());
+        for deserializer in &mut deserializers {
+            columns.push(deserializer.finish_to_column());
+        }
+
+        Ok(vec![DataBlock::create(self.schema.clone(), columns)])
+    }
+
+    fn read_buf(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<usize> {
+        let mut index = 0;
+        let state = state.as_any().downcast_mut::<TsvInputState>().unwrap();
+
+        if let Some(first) = state.ignore_if_first.take() {
+            if buf[0] == first {
+                index += 1;
+            }
+        }
+
+        state.need_more_data = true;
+        while index < buf.len() && state.need_more_data {
+            index = self.find_delimiter(buf, index, state);
+        }
+
+        state.memory.extend_from_slice(&buf[0..index]);
+        Ok(index)
+    }
+
+    fn skip_header(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<usize> {
+        let header_rows = self.header.rows();
+        let state = state.as_any().downcast_mut::<TsvInputState>().unwrap();
+
+        if state.accepted_rows >= header_rows {
+            return Ok(0);
+        }
+
+        let mut index = 0;
+        while index < buf.len() {
+            index = self.find_delimiter(buf, index, state);
+
+            if state.accepted_rows == header_rows {
+                if self.header != InputHeader::Skip {
+                    state.memory.extend_from_slice(&buf[..index]);
+                    let rows = self.read_header(std::mem::take(&mut state.memory))?;
+                    self.header.check(&self.schema, &rows)?;
+                }
+
+                return Ok(index);
+            }
+        }
+
+        if self.header != InputHeader::Skip {
+            state.memory.extend_from_slice(buf);
+        }
+
+        Ok(buf.len())
+    }
+}
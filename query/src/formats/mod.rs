@@ -15,13 +15,20 @@
 pub mod format;
 pub mod format_csv;
 mod format_factory;
+pub mod format_options;
 mod format_parquet;
+pub mod format_tsv;
 pub mod output_format;
+mod output_format_arrow;
 pub mod output_format_csv;
+mod output_format_json;
 mod output_format_ndjson;
 mod output_format_parquet;
 mod output_format_values;
 
 pub use format::InputFormat;
+pub use format::InputHeader;
 pub use format::InputState;
 pub use format_factory::FormatFactory;
+pub use format_options::FormatOption;
+pub use format_options::FormatOptionKind;
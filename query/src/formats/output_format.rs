@@ -12,20 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
-
 use common_datablocks::DataBlock;
-use common_datavalues::DataSchemaRef;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
-use super::output_format_ndjson::NDJsonOutputFormat;
-use super::output_format_parquet::ParquetOutputFormat;
-use super::output_format_values::ValuesOutputFormat;
-use crate::formats::output_format_csv::CSVOutputFormat;
-use crate::formats::output_format_csv::TSVOutputFormat;
-
+/// Serialize the result blocks of a query, the writers are registered in the
+/// `FormatFactory` and created with `FormatFactory::get_output`.
 pub trait OutputFormat: Send {
     fn serialize_block(
         &mut self,
@@ -35,46 +27,3 @@ pub trait OutputFormat: Send {
 
     fn finalize(&mut self) -> Result<Vec<u8>>;
 }
-
-#[derive(Clone, Copy)]
-pub enum OutputFormatType {
-    Tsv,
-    Csv,
-    Parquet,
-    NDJson,
-    Values,
-}
-
-impl OutputFormatType {
-    pub fn create_format(&self, schema: DataSchemaRef) -> Box<dyn OutputFormat> {
-        match self {
-            OutputFormatType::Tsv => Box::new(TSVOutputFormat::create(schema)),
-            OutputFormatType::Csv => Box::new(CSVOutputFormat::create(schema)),
-            OutputFormatType::Parquet => Box::new(ParquetOutputFormat::create(schema)),
-            OutputFormatType::NDJson => Box::new(NDJsonOutputFormat::create(schema)),
-            OutputFormatType::Values => Box::new(ValuesOutputFormat::create(schema)),
-        }
-    }
-}
-
-impl Default for OutputFormatType {
-    fn default() -> Self {
-        Self::Tsv
-    }
-}
-
-impl FromStr for OutputFormatType {
-    type Err = ErrorCode;
-    fn from_str(s: &str) -> std::result::Result<Self, ErrorCode> {
-        match s.to_uppercase().as_str() {
-            "TSV" => Ok(OutputFormatType::Tsv),
-            "CSV" => Ok(OutputFormatType::Csv),
-            "NDJSON" | "JSONEACHROW" => Ok(OutputFormatType::NDJson),
-            "PARQUET" => Ok(OutputFormatType::Parquet),
-            "VALUES" => Ok(OutputFormatType::Values),
-            _ => Err(ErrorCode::StrParseError(
-                "Unknown file format type, must be one of { TSV, CSV, PARQUET, Values, NDJSON | JSONEACHROW }".to_string(),
-            )),
-        }
-    }
-}
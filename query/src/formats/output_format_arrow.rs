@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+
+use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
+
+/// The Arrow IPC streaming format, the blocks are written as record batches on finalize.
+pub struct ArrowOutputFormat {
+    schema: DataSchemaRef,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl ArrowOutputFormat {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        Self {
+            schema,
+            data_blocks: vec![],
+        }
+    }
+
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "arrow",
+            &[],
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(ArrowOutputFormat::create(schema)))
+            }),
+        )
+    }
+}
+
+impl OutputFormat for ArrowOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock, _format: &FormatSettings) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let arrow_schema = self.schema.to_arrow();
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+
+        let mut writer = StreamWriter::new(vec![], WriteOptions { compression: None });
+        writer.start(&arrow_schema, Some(ipc_fields.clone()))?;
+        for block in std::mem::take(&mut self.data_blocks) {
+            let chunk = block.try_into()?;
+            writer.write(&chunk, Some(&ipc_fields))?;
+        }
+        writer.finish()?;
+
+        Ok(writer.into_inner())
+    }
+}
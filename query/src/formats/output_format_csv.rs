@@ -20,7 +20,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
+use crate::formats::format_options::TEXT_OUTPUT_OPTIONS;
 use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
 
 const FIELD_DELIMITER: u8 = b'\t';
 const ROW_DELIMITER: u8 = b'\n';
@@ -37,6 +39,32 @@ impl<const TSV: bool> TCSVOutputFormat<TSV> {
     }
 }
 
+impl CSVOutputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "csv",
+            TEXT_OUTPUT_OPTIONS,
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(CSVOutputFormat::create(schema)))
+            }),
+        )
+    }
+}
+
+impl TSVOutputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        for name in ["tsv", "tabseparated"] {
+            factory.register_output(
+                name,
+                TEXT_OUTPUT_OPTIONS,
+                Box::new(|_name: &str, schema: DataSchemaRef| {
+                    Ok(Box::new(TSVOutputFormat::create(schema)))
+                }),
+            )
+        }
+    }
+}
+
 impl<const TSV: bool> OutputFormat for TCSVOutputFormat<TSV> {
     fn serialize_block(&mut self, block: &DataBlock, format: &FormatSettings) -> Result<Vec<u8>> {
         let rows_size = block.column(0).len();
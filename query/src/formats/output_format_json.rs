@@ -0,0 +1,146 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeSerializer;
+use common_datavalues::TypeSerializerImpl;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
+
+pub type JsonOutputFormat = TJsonOutputFormat<false>;
+pub type JsonCompactOutputFormat = TJsonOutputFormat<true>;
+
+/// A single JSON document: `{"meta":[...],"data":[...],"rows":N}`. The rows of `data`
+/// are objects keyed by column name, or arrays of values in the compact format.
+pub struct TJsonOutputFormat<const COMPACT: bool> {
+    schema: DataSchemaRef,
+    serializers: Vec<TypeSerializerImpl>,
+    started: bool,
+    rows: usize,
+}
+
+impl<const COMPACT: bool> TJsonOutputFormat<COMPACT> {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        let serializers: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| field.data_type().create_serializer())
+            .collect();
+
+        Self {
+            schema,
+            serializers,
+            started: false,
+            rows: 0,
+        }
+    }
+
+    fn start(&mut self, buf: &mut Vec<u8>) -> Result<()> {
+        if !self.started {
+            self.started = true;
+
+            let meta: Vec<Value> = self
+                .schema
+                .fields()
+                .iter()
+                .map(|field| json!({"name": field.name(), "type": field.data_type().name()}))
+                .collect();
+
+            buf.extend_from_slice(b"{\"meta\":");
+            serde_json::to_writer(&mut *buf, &meta)?;
+            buf.extend_from_slice(b",\"data\":[");
+        }
+        Ok(())
+    }
+}
+
+impl JsonOutputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "json",
+            &[],
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(JsonOutputFormat::create(schema)))
+            }),
+        )
+    }
+}
+
+impl JsonCompactOutputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "jsoncompact",
+            &[],
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(JsonCompactOutputFormat::create(schema)))
+            }),
+        )
+    }
+}
+
+impl<const COMPACT: bool> OutputFormat for TJsonOutputFormat<COMPACT> {
+    fn serialize_block(&mut self, block: &DataBlock, format: &FormatSettings) -> Result<Vec<u8>> {
+        let rows_size = block.num_rows();
+        let columns_size = block.num_columns();
+
+        assert_eq!(self.serializers.len(), columns_size);
+
+        let mut buf = Vec::with_capacity(block.memory_size());
+        self.start(&mut buf)?;
+
+        let mut col_table = Vec::with_capacity(columns_size);
+        for col_index in 0..columns_size {
+            let column = block.column(col_index).convert_full_column();
+            col_table.push(self.serializers[col_index].serialize_json(&column, format)?);
+        }
+
+        for row_index in 0..rows_size {
+            if self.rows != 0 {
+                buf.push(b',');
+            }
+            buf.push(b'\n');
+
+            buf.push(if COMPACT { b'[' } else { b'{' });
+            for (i, (col, field)) in col_table.iter().zip(self.schema.fields()).enumerate() {
+                if i != 0 {
+                    buf.push(b',');
+                }
+
+                // Written one by one to keep the column order of the schema.
+                if !COMPACT {
+                    serde_json::to_writer(&mut buf, field.name())?;
+                    buf.push(b':');
+                }
+                serde_json::to_writer(&mut buf, &col[row_index])?;
+            }
+            buf.push(if COMPACT { b']' } else { b'}' });
+            self.rows += 1;
+        }
+        Ok(buf)
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        self.start(&mut buf)?;
+        buf.extend_from_slice(format!("\n],\"rows\":{}}}\n", self.rows).as_bytes());
+        Ok(buf)
+    }
+}
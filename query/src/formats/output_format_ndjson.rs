@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
 
 #[derive(Default)]
 pub struct NDJsonOutputFormat {
@@ -37,6 +38,18 @@ impl NDJsonOutputFormat {
 
         Self { serializers }
     }
+
+    pub fn register(factory: &mut FormatFactory) {
+        for name in ["ndjson", "jsoneachrow"] {
+            factory.register_output(
+                name,
+                &[],
+                Box::new(|_name: &str, schema: DataSchemaRef| {
+                    Ok(Box::new(NDJsonOutputFormat::create(schema)))
+                }),
+            )
+        }
+    }
 }
 
 impl OutputFormat for NDJsonOutputFormat {
@@ -18,6 +18,7 @@ use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
 use crate::storages::fuse::io::serialize_data_blocks;
 
 #[derive(Default)]
@@ -33,6 +34,16 @@ impl ParquetOutputFormat {
             data_blocks: vec![],
         }
     }
+
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "parquet",
+            &[],
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(ParquetOutputFormat::create(schema)))
+            }),
+        )
+    }
 }
 
 impl OutputFormat for ParquetOutputFormat {
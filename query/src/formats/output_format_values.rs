@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use crate::formats::output_format::OutputFormat;
+use crate::formats::FormatFactory;
 
 #[derive(Default)]
 pub struct ValuesOutputFormat {
@@ -37,6 +38,16 @@ impl ValuesOutputFormat {
 
         Self { serializers }
     }
+
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_output(
+            "values",
+            &[],
+            Box::new(|_name: &str, schema: DataSchemaRef| {
+                Ok(Box::new(ValuesOutputFormat::create(schema)))
+            }),
+        )
+    }
 }
 
 impl OutputFormat for ValuesOutputFormat {
@@ -13,5 +13,9 @@
 // limitations under the License.
 
 mod management_mode_access;
+mod privilege_access;
+mod sandbox_access;
 
 pub use management_mode_access::ManagementModeAccess;
+pub use privilege_access::PrivilegeAccess;
+pub use sandbox_access::SandboxAccess;
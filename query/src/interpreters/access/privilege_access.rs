@@ -0,0 +1,352 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PrincipalIdentity;
+use common_meta_types::UserPrivilegeType;
+use common_planners::CopyMode;
+use common_planners::PlanNode;
+use common_planners::ShowPlan;
+
+use crate::sessions::QueryContext;
+
+pub struct PrivilegeAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl PrivilegeAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> Self {
+        PrivilegeAccess { ctx }
+    }
+
+    // Check the privileges the current user requires for the plan.
+    pub async fn check(&self, plan: &PlanNode) -> Result<()> {
+        match plan {
+            // Query, the tables are checked when the query is analyzed.
+            PlanNode::Select(_) | PlanNode::Explain(_) => Ok(()),
+
+            PlanNode::Insert(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Insert,
+                )
+                .await
+            }
+            PlanNode::Delete(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Delete,
+                )
+                .await
+            }
+            PlanNode::Update(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Update,
+                )
+                .await
+            }
+            PlanNode::Copy(plan) => match &plan.copy_mode {
+                CopyMode::IntoTable {
+                    catalog_name,
+                    db_name,
+                    tbl_name,
+                    ..
+                } => {
+                    self.validate_table(catalog_name, db_name, tbl_name, UserPrivilegeType::Insert)
+                        .await
+                }
+                // The query is checked when it is analyzed.
+                CopyMode::IntoStage { .. } => Ok(()),
+            },
+            // The procedures read or change the internals of the tables and the tenant.
+            PlanNode::Call(_) => self.validate_global(UserPrivilegeType::Super).await,
+            PlanNode::List(_) => self.validate_global(UserPrivilegeType::CreateStage).await,
+
+            // Alter.
+            PlanNode::AlterClusterKey(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Alter,
+                )
+                .await
+            }
+            PlanNode::ExportTableSnapshot(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Select,
+                )
+                .await
+            }
+            PlanNode::PromoteTable(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Alter,
+                )
+                .await
+            }
+            PlanNode::DropTablePartition(plan) => {
+                self.validate_table(
+                    &plan.catalog_name,
+                    &plan.database_name,
+                    &plan.table_name,
+                    UserPrivilegeType::Delete,
+                )
+                .await
+            }
+
+            // Show, the users and the grants of the others are only shown to the admins.
+            PlanNode::Show(ShowPlan::ShowUsers(_)) => {
+                self.validate_global(UserPrivilegeType::CreateUser).await
+            }
+            PlanNode::Show(ShowPlan::ShowGrants(plan)) => match &plan.principal {
+                Some(PrincipalIdentity::User(user))
+                    if *user == self.ctx.get_current_user()?.identity() =>
+                {
+                    Ok(())
+                }
+                Some(_) => self.validate_global(UserPrivilegeType::Grant).await,
+                None => Ok(()),
+            },
+            PlanNode::Show(_) => Ok(()),
+
+            // Database.
+            PlanNode::CreateDatabase(_) => self.validate_global(UserPrivilegeType::Create).await,
+            PlanNode::DropDatabase(_) => self.validate_global(UserPrivilegeType::Drop).await,
+            PlanNode::UnDropDatabase(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Drop)
+                    .await
+            }
+            PlanNode::RenameDatabase(plan) => {
+                for entity in &plan.entities {
+                    self.validate_database(
+                        &entity.catalog_name,
+                        &entity.db,
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+                }
+                self.validate_global(UserPrivilegeType::Create).await
+            }
+            PlanNode::ShowCreateDatabase(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Select)
+                    .await
+            }
+
+            // Table, CREATE TABLE is checked by CreateTableInterpreter, which the new planner
+            // runs without this interceptor.
+            PlanNode::CreateTable(_) => Ok(()),
+            PlanNode::DropTable(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Drop)
+                    .await
+            }
+            PlanNode::UnDropTable(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Drop)
+                    .await
+            }
+            PlanNode::RenameTable(plan) => {
+                for entity in &plan.entities {
+                    self.validate_table(
+                        &entity.catalog_name,
+                        &entity.database_name,
+                        &entity.table_name,
+                        UserPrivilegeType::Alter,
+                    )
+                    .await?;
+                    self.validate_database(
+                        &entity.catalog_name,
+                        &entity.new_database_name,
+                        UserPrivilegeType::Create,
+                    )
+                    .await?;
+                }
+                Ok(())
+            }
+            PlanNode::TruncateTable(plan) => {
+                self.validate_table(
+                    &plan.catalog,
+                    &plan.db,
+                    &plan.table,
+                    UserPrivilegeType::Delete,
+                )
+                .await
+            }
+            PlanNode::OptimizeTable(plan) => {
+                self.validate_table(
+                    &plan.catalog,
+                    &plan.database,
+                    &plan.table,
+                    UserPrivilegeType::Alter,
+                )
+                .await
+            }
+            PlanNode::DescribeTable(plan) => {
+                self.validate_table(
+                    &plan.catalog,
+                    &plan.db,
+                    &plan.table,
+                    UserPrivilegeType::Select,
+                )
+                .await
+            }
+            PlanNode::ShowCreateTable(plan) => {
+                self.validate_table(
+                    &plan.catalog,
+                    &plan.db,
+                    &plan.table,
+                    UserPrivilegeType::Select,
+                )
+                .await
+            }
+
+            // View.
+            PlanNode::CreateView(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Create)
+                    .await
+            }
+            PlanNode::AlterView(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Create)
+                    .await
+            }
+            PlanNode::DropView(plan) => {
+                self.validate_database(&plan.catalog, &plan.db, UserPrivilegeType::Drop)
+                    .await
+            }
+            PlanNode::ShowCreateView(plan) => {
+                self.validate_table(
+                    &plan.catalog,
+                    &plan.db,
+                    &plan.viewname,
+                    UserPrivilegeType::Select,
+                )
+                .await
+            }
+
+            // User.
+            PlanNode::CreateUser(_) | PlanNode::DropUser(_) => {
+                self.validate_global(UserPrivilegeType::CreateUser).await
+            }
+            // Users are always allowed to change their own authentication, but changing
+            // user options (e.g. TENANTSETTING) on anyone requires the privilege.
+            PlanNode::AlterUser(plan) => {
+                let current_user = self.ctx.get_current_user()?;
+                let alter_self_auth = current_user.identity() == plan.user
+                    && plan.auth_info.is_some()
+                    && plan.user_option.is_none();
+                match alter_self_auth {
+                    true => Ok(()),
+                    false => self.validate_global(UserPrivilegeType::CreateUser).await,
+                }
+            }
+
+            // Grant, revoke and role.
+            PlanNode::GrantPrivilege(_)
+            | PlanNode::GrantRole(_)
+            | PlanNode::RevokePrivilege(_)
+            | PlanNode::RevokeRole(_) => self.validate_global(UserPrivilegeType::Grant).await,
+            PlanNode::CreateRole(_) | PlanNode::DropRole(_) => {
+                self.validate_global(UserPrivilegeType::CreateRole).await
+            }
+
+            // Stage, the description of a stage contains its credentials.
+            PlanNode::CreateUserStage(_)
+            | PlanNode::DropUserStage(_)
+            | PlanNode::DescribeUserStage(_) => {
+                self.validate_global(UserPrivilegeType::CreateStage).await
+            }
+
+            // UDF, the external functions make the server call their address.
+            PlanNode::CreateUserUDF(_) | PlanNode::DropUserUDF(_) | PlanNode::AlterUserUDF(_) => {
+                self.validate_global(UserPrivilegeType::Super).await
+            }
+
+            // SET GLOBAL changes the settings of every session of the tenant.
+            PlanNode::SetVariable(plan) if plan.vars.iter().any(|v| v.is_global) => {
+                self.validate_global(UserPrivilegeType::Super).await
+            }
+            PlanNode::Kill(_) | PlanNode::DropCache(_) => {
+                self.validate_global(UserPrivilegeType::Super).await
+            }
+            PlanNode::UseDatabase(_)
+            | PlanNode::SetVariable(_)
+            | PlanNode::Transaction(_)
+            | PlanNode::Empty(_) => Ok(()),
+
+            // The nodes of a query plan, they are never executed as statements.
+            PlanNode::Stage(_)
+            | PlanNode::Broadcast(_)
+            | PlanNode::Remote(_)
+            | PlanNode::Projection(_)
+            | PlanNode::Expression(_)
+            | PlanNode::AggregatorPartial(_)
+            | PlanNode::AggregatorFinal(_)
+            | PlanNode::Filter(_)
+            | PlanNode::Having(_)
+            | PlanNode::Sort(_)
+            | PlanNode::Limit(_)
+            | PlanNode::LimitBy(_)
+            | PlanNode::ReadSource(_)
+            | PlanNode::SubQueryExpression(_)
+            | PlanNode::Sink(_) => Ok(()),
+        }
+    }
+
+    async fn validate_global(&self, privilege: UserPrivilegeType) -> Result<()> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(&GrantObject::Global, privilege)
+            .await
+    }
+
+    async fn validate_database(
+        &self,
+        catalog: &str,
+        db: &str,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(&GrantObject::Database(catalog.into(), db.into()), privilege)
+            .await
+    }
+
+    async fn validate_table(
+        &self,
+        catalog: &str,
+        db: &str,
+        table: &str,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let object = GrantObject::Table(catalog.into(), db.into(), table.into());
+        self.ctx
+            .get_current_session()
+            .validate_privilege(&object, privilege)
+            .await
+    }
+}
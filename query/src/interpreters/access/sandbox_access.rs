@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::ShowPlan;
+
+use crate::sessions::QueryContext;
+
+pub struct SandboxAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl SandboxAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> Self {
+        SandboxAccess { ctx }
+    }
+
+    // Check what we can do if the user is in sandbox.
+    pub fn check(&self, plan: &PlanNode) -> Result<()> {
+        // Allows for sandbox.
+        if self.ctx.get_settings().is_sandboxed() {
+            return match plan {
+                PlanNode::Empty(_)
+
+                // Query.
+                | PlanNode::Select(_)
+                | PlanNode::Explain(_)
+                | PlanNode::Insert(_)
+
+                // Show.
+                | PlanNode::Show(ShowPlan::ShowDatabases(_))
+                | PlanNode::Show(ShowPlan::ShowTables(_))
+                | PlanNode::Show(ShowPlan::ShowTabStat(_))
+                | PlanNode::Show(ShowPlan::ShowFunctions(_))
+                | PlanNode::Show(ShowPlan::ShowSettings(_))
+                | PlanNode::Show(ShowPlan::ShowVariables(_))
+
+                // Table.
+                | PlanNode::DescribeTable(_)
+                | PlanNode::ShowCreateTable(_)
+
+                // USE.
+                | PlanNode::UseDatabase(_) => Ok(()),
+
+                // SET, the limits of the sandbox are checked by the settings.
+                PlanNode::SetVariable(plan) if plan.vars.iter().all(|v| !v.is_global) => Ok(()),
+                _ => Err(ErrorCode::PermissionDenied(format!(
+                    "Access denied for operation:{:?} in sandbox",
+                    plan.name()
+                ))),
+            };
+        };
+        Ok(())
+    }
+}
@@ -15,8 +15,6 @@
 use std::sync::Arc;
 
 use common_exception::Result;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::validate_expression;
 use common_planners::AlterClusterKeyPlan;
 use common_streams::DataBlockStream;
@@ -48,17 +46,6 @@ impl Interpreter for AlterClusterKeyInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = &self.plan;
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Table(
-                    plan.catalog_name.clone(),
-                    plan.database_name.clone(),
-                    plan.table_name.clone(),
-                ),
-                UserPrivilegeType::Alter,
-            )
-            .await?;
 
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&plan.catalog_name)?;
@@ -0,0 +1,77 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::DropCachePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::cache::BROADCAST_TABLE_CACHE;
+use crate::storages::cache::QUERY_RESULT_CACHE;
+use crate::storages::cache::SEGMENT_INFO_CACHE;
+use crate::storages::cache::TABLE_SNAPSHOT_CACHE;
+
+pub struct DropCacheInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropCachePlan,
+}
+
+impl DropCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropCachePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DropCacheInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropCacheInterpreter {
+    fn name(&self) -> &str {
+        "DropCacheInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        // Only the caches of this node are dropped, the hits and misses are kept.
+        let cache_manager = self.ctx.get_storage_cache_manager();
+        match self.plan.name.to_lowercase().as_str() {
+            TABLE_SNAPSHOT_CACHE => cache_manager.clear_table_snapshot_cache().await,
+            SEGMENT_INFO_CACHE => cache_manager.clear_table_segment_cache().await,
+            QUERY_RESULT_CACHE => self.ctx.get_query_cache().clear(),
+            BROADCAST_TABLE_CACHE => cache_manager.get_broadcast_table_cache().clear(),
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unknown cache '{}', expected one of: {}, {}, {}, {}",
+                    self.plan.name,
+                    TABLE_SNAPSHOT_CACHE,
+                    SEGMENT_INFO_CACHE,
+                    QUERY_RESULT_CACHE,
+                    BROADCAST_TABLE_CACHE
+                )));
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
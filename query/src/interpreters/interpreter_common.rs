@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeSet;
 
 use crate::sessions::QueryContext;
 
@@ -38,7 +39,8 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
-        GrantObject::Database(catalog_name, database_name) => {
+        GrantObject::Database(catalog_name, database_name)
+        | GrantObject::FutureTables(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name)?;
             if !catalog
                 .exists_database(tenant.as_str(), database_name)
@@ -55,3 +57,44 @@ pub async fn validate_grant_object_exists(
 
     Ok(())
 }
+
+/// Grants the privileges on the future tables of the database to the new table, for all the
+/// users and roles.
+pub async fn apply_future_grants(
+    ctx: &Arc<QueryContext>,
+    catalog_name: &str,
+    database_name: &str,
+    table_name: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+    let object = GrantObject::Table(
+        catalog_name.to_string(),
+        database_name.to_string(),
+        table_name.to_string(),
+    );
+
+    for user in user_mgr.get_users(&tenant).await? {
+        let privileges = user
+            .grants
+            .future_table_privileges(catalog_name, database_name);
+        if privileges != UserPrivilegeSet::empty() {
+            user_mgr
+                .grant_privileges_to_user(&tenant, user.identity(), object.clone(), privileges)
+                .await?;
+        }
+    }
+
+    for role in user_mgr.get_roles(&tenant).await? {
+        let privileges = role
+            .grants
+            .future_table_privileges(catalog_name, database_name);
+        if privileges != UserPrivilegeSet::empty() {
+            user_mgr
+                .grant_privileges_to_role(&tenant, role.name.clone(), object.clone(), privileges)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
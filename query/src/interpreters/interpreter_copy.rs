@@ -16,8 +16,11 @@ use std::path::Path;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MatchByColumnName;
 use common_planners::CopyMode;
 use common_planners::CopyPlan;
 use common_planners::PlanNode;
@@ -42,6 +45,7 @@ use crate::pipelines::new::NewPipeline;
 use crate::sessions::QueryContext;
 use crate::storages::stage::StageSource;
 use crate::storages::stage::StageTable;
+use crate::storages::Table;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -106,6 +110,55 @@ impl CopyInterpreter {
         files
     }
 
+    // Filter out the files which were copied into the table and not changed since, the
+    // records of the files to copy are returned along with them, in the order of the files.
+    // The records are keyed by the stage and the path of the file in it.
+    async fn filter_copied_files(
+        &self,
+        table_id: u64,
+        from: &ReadDataSourcePlan,
+        files: Vec<String>,
+    ) -> Result<(Vec<String>, Vec<(String, CopiedFileInfo)>)> {
+        let stage_info = match &from.source_info {
+            SourceInfo::StageSource(table_info) => &table_info.stage_info,
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Cannot filter copied files for the source info: {:?}",
+                    other
+                )));
+            }
+        };
+
+        let keys = files
+            .iter()
+            .map(|file| format!("{}/{}", stage_info.stage_name, file.trim_start_matches('/')))
+            .collect::<Vec<_>>();
+        let copy_api = self
+            .ctx
+            .get_user_manager()
+            .get_copy_api_client(&self.ctx.get_tenant())?;
+        let copied = copy_api.get_copied_files(table_id, &keys).await?;
+
+        let op = StageSource::get_op(&self.ctx, stage_info).await?;
+        let mut to_copy = vec![];
+        let mut infos = vec![];
+        for (file, key) in files.into_iter().zip(keys.into_iter()) {
+            let meta = op.object(&file).metadata().await?;
+            let info = CopiedFileInfo {
+                size: meta.content_length(),
+                last_modified: meta.last_modified().map(|t| t.unix_timestamp()),
+            };
+
+            if copied.get(&key) == Some(&info) {
+                tracing::info!("copy skip the file already copied: {}", file);
+                continue;
+            }
+            to_copy.push(file);
+            infos.push((key, info));
+        }
+        Ok((to_copy, infos))
+    }
+
     // Rewrite the ReadDataSourcePlan.S3StageSource.file_name to new file name.
     fn rewrite_read_plan_file_name(
         mut plan: ReadDataSourcePlan,
@@ -124,12 +177,10 @@ impl CopyInterpreter {
     // 3. Read from the stream and write to the table.
     // Note:
     //  We parse the `s3://` to ReadSourcePlan instead of to a SELECT plan is that:
-    #[tracing::instrument(level = "debug", name = "copy_files_to_table", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[tracing::instrument(level = "debug", name = "copy_files_to_table", skip(self, table), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn copy_files_to_table(
         &self,
-        catalog_name: &String,
-        db_name: &String,
-        tbl_name: &String,
+        table: &Arc<dyn Table>,
         from: &ReadDataSourcePlan,
         files: Vec<String>,
     ) -> Result<Vec<DataBlock>> {
@@ -140,19 +191,19 @@ impl CopyInterpreter {
         let read_source_plan = from.clone();
         let read_source_plan = Self::rewrite_read_plan_file_name(read_source_plan, files);
         tracing::info!("copy_files_to_table: source plan:{:?}", read_source_plan);
-        let table = ctx.build_table_from_source_plan(&read_source_plan)?;
-        let res = table.read2(ctx.clone(), &read_source_plan, &mut pipeline);
+        let source_table = ctx.build_table_from_source_plan(&read_source_plan)?;
+        let res = source_table.read2(ctx.clone(), &read_source_plan, &mut pipeline);
         if let Err(e) = res {
             return Err(e);
         }
 
-        let table = ctx.get_table(catalog_name, db_name, tbl_name).await?;
-
         if ctx.get_settings().get_enable_new_processor_framework()? != 0
             && self.ctx.get_cluster().is_empty()
         {
             table.append2(ctx.clone(), &mut pipeline)?;
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+            pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
 
             let async_runtime = ctx.get_storage_runtime();
             let executor = PipelineCompleteExecutor::try_create(async_runtime, pipeline)?;
@@ -162,6 +213,8 @@ impl CopyInterpreter {
         }
 
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+        pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
 
         let async_runtime = ctx.get_storage_runtime();
         let executor = PipelinePullingExecutor::try_create(async_runtime, pipeline)?;
@@ -176,33 +229,166 @@ impl CopyInterpreter {
         Ok(operations)
     }
 
+    // The table as it is now, `QueryContext::get_table` returns the table cached when the
+    // statement was planned.
+    // Bind the read plan to the schema the table changed to, None if the files left can't be
+    // read with it. The files are mapped onto the table by the column names only with
+    // MATCH_BY_COLUMN_NAME, otherwise a file keeps the columns of the schema it was written
+    // for.
+    fn rebind_read_plan(
+        from: &ReadDataSourcePlan,
+        bound: &dyn Table,
+        latest: &dyn Table,
+    ) -> Option<ReadDataSourcePlan> {
+        if latest.get_id() != bound.get_id() {
+            return None;
+        }
+
+        let mut plan = from.clone();
+        if let SourceInfo::StageSource(ref mut stage_table_info) = plan.source_info {
+            let match_by_column_name = stage_table_info
+                .stage_info
+                .copy_options
+                .match_by_column_name;
+            if match_by_column_name == MatchByColumnName::None {
+                return None;
+            }
+
+            // The columns listed by the COPY, or all the columns.
+            let latest_schema = latest.schema();
+            stage_table_info.schema = if stage_table_info.schema == bound.schema() {
+                latest_schema
+            } else {
+                let fields = stage_table_info
+                    .schema
+                    .fields()
+                    .iter()
+                    .map(|field| latest_schema.field_with_name(field.name()).ok().cloned())
+                    .collect::<Option<Vec<_>>>()?;
+                DataSchemaRefExt::create(fields)
+            };
+        }
+        Some(plan)
+    }
+
+    // Copy the files in batches of `copy_files_per_commit`, each batch is committed and
+    // recorded on its own. The schema of the table is checked before each batch, and the
+    // commit fails if it changed while the batch was written, so the blocks of a commit are
+    // all written with the schema of the table.
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_files_in_batches(
+        &self,
+        catalog_name: &str,
+        db_name: &str,
+        tbl_name: &str,
+        mut table: Arc<dyn Table>,
+        from: &ReadDataSourcePlan,
+        files: Vec<String>,
+        copied_files: Vec<(String, CopiedFileInfo)>,
+    ) -> Result<()> {
+        let batch_size = match self.ctx.get_settings().get_copy_files_per_commit()? as usize {
+            0 => files.len(),
+            n => n,
+        };
+
+        let mut from = from.clone();
+        let mut loaded = 0;
+        while loaded < files.len() {
+            let latest = self
+                .ctx
+                .get_latest_table(catalog_name, db_name, tbl_name)
+                .await?;
+            if latest.get_id() != table.get_id() || latest.schema() != table.schema() {
+                from = match Self::rebind_read_plan(&from, table.as_ref(), latest.as_ref()) {
+                    Some(from) => from,
+                    None => {
+                        return Err(ErrorCode::TableSchemaMismatch(format!(
+                            "The schema of table {}.{} changed during COPY, loaded files: {:?}, pending files: {:?}",
+                            db_name,
+                            tbl_name,
+                            &files[..loaded],
+                            &files[loaded..],
+                        )));
+                    }
+                };
+                tracing::info!("copy rebinds the files left to the schema: {:?}", from.schema());
+                table = latest;
+            }
+
+            let end = (loaded + batch_size).min(files.len());
+            let write_results = self
+                .copy_files_to_table(&table, &from, files[loaded..end].to_vec())
+                .await?;
+
+            // The written blocks are aborted if the schema changed, the batch is copied again
+            // after the rebinding.
+            match table
+                .commit_insertion(self.ctx.clone(), catalog_name, write_results, false)
+                .await
+            {
+                Ok(_) => {}
+                Err(cause) if cause.code() == ErrorCode::TableSchemaMismatchCode() => {
+                    continue;
+                }
+                Err(cause) => return Err(cause),
+            }
+
+            // Record the files after the commit, a failed copy can be retried.
+            let files = copied_files[loaded..end].iter().cloned().collect();
+            match self.ctx.get_transaction() {
+                Some(txn) => txn.add_copied_files(table.get_id(), files),
+                None => {
+                    self.ctx
+                        .get_user_manager()
+                        .get_copy_api_client(&self.ctx.get_tenant())?
+                        .add_copied_files(table.get_id(), files)
+                        .await?
+                }
+            }
+            loaded = end;
+        }
+        Ok(())
+    }
+
     async fn execute_copy_into_stage(
         &self,
         stage_table_info: &StageTableInfo,
         query: &PlanNode,
+        ordered: bool,
+        max_file_size: usize,
+        spool: bool,
     ) -> Result<SendableDataBlockStream> {
-        let table = StageTable::try_create(stage_table_info.clone())?;
+        let table = StageTable::create(stage_table_info.clone());
 
         let select_interpreter = SelectInterpreter::try_create(self.ctx.clone(), SelectPlan {
             input: Arc::new(query.clone()),
         })?;
 
+        // The blocks come in the order of the query, the writers take the consecutive
+        // blocks of the stream if the output is ordered.
         let stream = select_interpreter.execute(None).await?;
-        let results = table.append_data(self.ctx.clone(), stream).await?;
-
-        table
-            .commit_insertion(
-                self.ctx.clone(),
-                &self.ctx.get_current_catalog(),
-                results.try_collect().await?,
-                false,
-            )
-            .await?;
-
+        let files = if spool {
+            table
+                .spool_files(self.ctx.clone(), stream, max_file_size)
+                .await?
+        } else {
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            table
+                .write_files(self.ctx.clone(), blocks, ordered, max_file_size)
+                .await?
+        };
+        tracing::info!("copy into stage, files written: {:?}", files);
+
+        // Report the files written.
+        let block = DataBlock::create(self.plan.schema(), vec![
+            Series::from_data(files.iter().map(|f| f.path.as_str()).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.rows as u64).collect::<Vec<_>>()),
+            Series::from_data(files.iter().map(|f| f.bytes as u64).collect::<Vec<_>>()),
+        ]);
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
-            vec![],
+            vec![block],
         )))
     }
 }
@@ -247,18 +433,25 @@ impl Interpreter for CopyInterpreter {
                     files = matched_files;
                 }
 
-                tracing::info!("copy file list:{:?}, pattern:{}", &files, pattern,);
-
-                let write_results = self
-                    .copy_files_to_table(catalog_name, db_name, tbl_name, from, files)
+                let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+                let (files, copied_files) = self
+                    .filter_copied_files(table.get_id(), from, files)
                     .await?;
 
-                let table = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+                tracing::info!("copy file list:{:?}, pattern:{}", &files, pattern,);
 
-                // Commit.
-                table
-                    .commit_insertion(self.ctx.clone(), catalog_name, write_results, false)
+                if !files.is_empty() {
+                    self.copy_files_in_batches(
+                        catalog_name,
+                        db_name,
+                        tbl_name,
+                        table,
+                        from,
+                        files,
+                        copied_files,
+                    )
                     .await?;
+                }
 
                 Ok(Box::pin(DataBlockStream::create(
                     self.plan.schema(),
@@ -269,9 +462,18 @@ impl Interpreter for CopyInterpreter {
             CopyMode::IntoStage {
                 stage_table_info,
                 query,
+                ordered,
+                max_file_size,
+                spool,
             } => {
-                self.execute_copy_into_stage(stage_table_info, query.as_ref())
-                    .await
+                self.execute_copy_into_stage(
+                    stage_table_info,
+                    query.as_ref(),
+                    *ordered,
+                    *max_file_size,
+                    *spool,
+                )
+                .await
             }
         }
     }
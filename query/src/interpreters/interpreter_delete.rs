@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use super::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct DeleteInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DeletePlan,
+}
+
+impl DeleteInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DeletePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DeleteInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DeleteInterpreter {
+    fn name(&self) -> &str {
+        "DeleteInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+
+        let table = self
+            .ctx
+            .get_latest_table(&plan.catalog_name, &plan.database_name, &plan.table_name)
+            .await?;
+
+        let affected_rows = table
+            .delete(self.ctx.clone(), &plan.catalog_name, plan.selection.clone())
+            .await?;
+        self.ctx.set_affected_rows(affected_rows);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
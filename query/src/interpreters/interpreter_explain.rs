@@ -50,6 +50,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Pruning => self.explain_pruning(),
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -111,4 +112,33 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create(schema, vec![formatted_pipeline]))
     }
+
+    // The table scans are pruned while planning, so the plan is not executed.
+    fn explain_pruning(&self) -> Result<DataBlock> {
+        let lines = format_pruning_statistics(&self.ctx);
+        Ok(DataBlock::create(self.schema(), vec![Series::from_data(
+            lines,
+        )]))
+    }
+}
+
+pub(crate) fn format_pruning_statistics(ctx: &QueryContext) -> Vec<String> {
+    let statistics = ctx.get_pruning_statistics();
+    if statistics.is_empty() {
+        return vec!["No table is pruned".to_string()];
+    }
+
+    let mut lines = vec![];
+    for (table, s) in statistics {
+        lines.push(format!("Table: {}", table));
+        lines.push(format!(
+            "  segments: total {}, pruned by zone map {}, pruned by limit {}",
+            s.segments_total, s.segments_pruned_by_zone_map, s.segments_pruned_by_limit
+        ));
+        lines.push(format!(
+            "  blocks: total {}, pruned by zone map {}, pruned by limit {}, scanned {}",
+            s.blocks_total, s.blocks_pruned_by_zone_map, s.blocks_pruned_by_limit, s.blocks_scanned
+        ));
+    }
+    lines
 }
@@ -21,8 +21,11 @@ use common_exception::Result;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::interpreters::interpreter_explain::format_pruning_statistics;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::new::executor::PipelineExecutor;
+use crate::pipelines::new::executor::PipelinePullingExecutor;
 use crate::sessions::QueryContext;
 use crate::sql::exec::PipelineBuilder;
 use crate::sql::optimizer::SExpr;
@@ -62,9 +65,27 @@ impl Interpreter for ExplainInterpreterV2 {
                     return Err(ErrorCode::UnImplement("Unsupported EXPLAIN statement"));
                 }
             },
-            ExplainKind::Graph => {
-                return Err(ErrorCode::UnImplement("ExplainKind graph is unimplemented"));
-            }
+            ExplainKind::Analyze => match &self.plan {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                } => {
+                    self.explain_analyze(s_expr.clone(), *bind_context.clone(), metadata.clone())
+                        .await?
+                }
+                _ => {
+                    return Err(ErrorCode::UnImplement(
+                        "Unsupported EXPLAIN ANALYZE statement",
+                    ));
+                }
+            },
+            ExplainKind::Graph => self.explain_graph(&self.plan).await?,
+            ExplainKind::Json => self.explain_json(&self.plan).await?,
+            // The table scans are pruned while binding, so the plan is not executed.
+            ExplainKind::Pruning => vec![DataBlock::create(self.schema.clone(), vec![
+                Series::from_data(format_pruning_statistics(&self.ctx)),
+            ])],
         };
         Ok(Box::pin(DataBlockStream::create(
             self.schema.clone(),
@@ -106,6 +127,22 @@ impl ExplainInterpreterV2 {
         ])])
     }
 
+    pub async fn explain_json(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
+        let result = plan.format_json()?;
+        let formatted_plan = Series::from_data(vec![result]);
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![
+            formatted_plan,
+        ])])
+    }
+
+    pub async fn explain_graph(&self, plan: &Plan) -> Result<Vec<DataBlock>> {
+        let result = plan.format_graph()?;
+        let formatted_plan = Series::from_data(vec![result]);
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![
+            formatted_plan,
+        ])])
+    }
+
     pub async fn explain_pipeline(
         &self,
         s_expr: SExpr,
@@ -142,4 +179,65 @@ impl ExplainInterpreterV2 {
         }
         Ok(blocks)
     }
+
+    // Execute the query, and output the profile of the pipelines instead of the result.
+    pub async fn explain_analyze(
+        &self,
+        s_expr: SExpr,
+        bind_context: BindContext,
+        metadata: MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
+        let pb = PipelineBuilder::new(
+            self.ctx.clone(),
+            bind_context.result_columns(),
+            metadata,
+            s_expr,
+        );
+        let (root_pipeline, pipelines, _) = pb.spawn()?;
+        let async_runtime = self.ctx.get_storage_runtime();
+
+        let mut profiles = Vec::with_capacity(pipelines.len() + 1);
+        for pipeline in pipelines {
+            let executor = PipelineExecutor::create(async_runtime.clone(), pipeline)?;
+            executor.execute()?;
+            profiles.push(executor.get_profile());
+        }
+
+        let mut executor = PipelinePullingExecutor::try_create(async_runtime, root_pipeline)?;
+        executor.start();
+        while executor.pull_data()?.is_some() {}
+        profiles.insert(0, executor.get_profile());
+
+        let mut lines = vec![];
+        for (index, profile) in profiles.iter().enumerate() {
+            if index != 0 {
+                lines.push(String::new());
+            }
+            lines.push(format!(
+                "Pipeline {}, elapsed: {:?}",
+                index, profile.elapsed
+            ));
+            for processor in &profile.processors {
+                lines.push(format!(
+                    "  {} #{}: cpu time: {:?}, async time: {:?}, input rows: {}, input wait: {:?}, output rows: {}, output stall: {:?}, output fill: {:.1}%",
+                    processor.name,
+                    processor.id,
+                    processor.cpu_time,
+                    processor.async_time,
+                    processor.input_rows,
+                    processor.input_wait_time,
+                    processor.output_rows,
+                    processor.output_stall_time,
+                    profile.output_fill(processor) * 100.0,
+                ));
+            }
+            for diagnosis in profile.diagnose() {
+                lines.push(format!("  {}", diagnosis));
+            }
+        }
+
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![
+            Series::from_data(lines),
+        ])])
+    }
 }
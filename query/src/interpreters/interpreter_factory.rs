@@ -38,15 +38,19 @@ use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
 use crate::interpreters::CreateUserUDFInterpreter;
 use crate::interpreters::CreateViewInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
+use crate::interpreters::DropCacheInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
 use crate::interpreters::DropRoleInterpreter;
 use crate::interpreters::DropTableInterpreter;
+use crate::interpreters::DropTablePartitionInterpreter;
 use crate::interpreters::DropUserInterpreter;
 use crate::interpreters::DropUserUDFInterpreter;
 use crate::interpreters::DropViewInterpreter;
 use crate::interpreters::EmptyInterpreter;
 use crate::interpreters::ExplainInterpreter;
+use crate::interpreters::ExportTableSnapshotInterpreter;
 use crate::interpreters::GrantPrivilegeInterpreter;
 use crate::interpreters::GrantRoleInterpreter;
 use crate::interpreters::InsertInterpreter;
@@ -54,26 +58,34 @@ use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
 use crate::interpreters::OptimizeTableInterpreter;
+use crate::interpreters::PromoteTableInterpreter;
 use crate::interpreters::RenameDatabaseInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
 use crate::interpreters::RevokeRoleInterpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
+use crate::interpreters::ShowCachesInterpreter;
+use crate::interpreters::ShowClustersInterpreter;
 use crate::interpreters::ShowCreateDatabaseInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
+use crate::interpreters::ShowCreateViewInterpreter;
 use crate::interpreters::ShowDatabasesInterpreter;
 use crate::interpreters::ShowFunctionsInterpreter;
 use crate::interpreters::ShowGrantsInterpreter;
 use crate::interpreters::ShowMetricsInterpreter;
 use crate::interpreters::ShowProcessListInterpreter;
+use crate::interpreters::ShowReplicationStatusInterpreter;
 use crate::interpreters::ShowRolesInterpreter;
 use crate::interpreters::ShowSettingsInterpreter;
 use crate::interpreters::ShowTabStatInterpreter;
 use crate::interpreters::ShowTablesInterpreter;
 use crate::interpreters::ShowUsersInterpreter;
+use crate::interpreters::ShowVariablesInterpreter;
+use crate::interpreters::TransactionInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
 use crate::interpreters::UnDropDatabaseInterpreter;
 use crate::interpreters::UnDropTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
 use crate::sessions::QueryContext;
 
@@ -84,11 +96,14 @@ pub struct InterpreterFactory;
 /// Such as: SelectPlan -> SelectInterpreter, ExplainPlan -> ExplainInterpreter, ...
 impl InterpreterFactory {
     pub fn get(ctx: Arc<QueryContext>, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
+        Self::check_transaction(&ctx, &plan)?;
         let ctx_clone = ctx.clone();
         let inner = match plan.clone() {
-            PlanNode::Select(v) => SelectInterpreter::try_create(ctx_clone, v),
+            PlanNode::Select(v) => SelectInterpreter::try_create_with_query_cache(ctx_clone, v),
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx_clone, v),
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx_clone, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx_clone, v),
             PlanNode::Copy(v) => CopyInterpreter::try_create(ctx_clone, v),
             PlanNode::Call(v) => CallInterpreter::try_create(ctx_clone, v),
             PlanNode::Show(ShowPlan::ShowDatabases(v)) => {
@@ -112,6 +127,9 @@ impl InterpreterFactory {
             PlanNode::Show(ShowPlan::ShowMetrics(_)) => {
                 ShowMetricsInterpreter::try_create(ctx_clone)
             }
+            PlanNode::Show(ShowPlan::ShowClusters(_)) => {
+                ShowClustersInterpreter::try_create(ctx_clone)
+            }
             PlanNode::Show(ShowPlan::ShowProcessList(_)) => {
                 ShowProcessListInterpreter::try_create(ctx_clone)
             }
@@ -127,6 +145,15 @@ impl InterpreterFactory {
             PlanNode::Show(ShowPlan::ShowStages(v)) => {
                 ShowStagesInterpreter::try_create(ctx_clone, v)
             }
+            PlanNode::Show(ShowPlan::ShowVariables(v)) => {
+                ShowVariablesInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::Show(ShowPlan::ShowReplicationStatus(v)) => {
+                ShowReplicationStatusInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::Show(ShowPlan::ShowCaches(v)) => {
+                ShowCachesInterpreter::try_create(ctx_clone, v)
+            }
 
             // Database related transforms.
             PlanNode::CreateDatabase(v) => CreateDatabaseInterpreter::try_create(ctx_clone, v),
@@ -151,6 +178,7 @@ impl InterpreterFactory {
             PlanNode::CreateView(v) => CreateViewInterpreter::try_create(ctx_clone, v),
             PlanNode::AlterView(v) => AlterViewInterpreter::try_create(ctx_clone, v),
             PlanNode::DropView(v) => DropViewInterpreter::try_create(ctx_clone, v),
+            PlanNode::ShowCreateView(v) => ShowCreateViewInterpreter::try_create(ctx_clone, v),
 
             // User related transforms
             PlanNode::CreateUser(v) => CreateUserInterpreter::try_create(ctx_clone, v),
@@ -181,11 +209,20 @@ impl InterpreterFactory {
 
             // alter.
             PlanNode::AlterClusterKey(v) => AlterClusterKeyInterpreter::try_create(ctx_clone, v),
+            PlanNode::ExportTableSnapshot(v) => {
+                ExportTableSnapshotInterpreter::try_create(ctx_clone, v)
+            }
+            PlanNode::PromoteTable(v) => PromoteTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropTablePartition(v) => {
+                DropTablePartitionInterpreter::try_create(ctx_clone, v)
+            }
 
             // others
             PlanNode::List(v) => ListInterpreter::try_create(ctx_clone, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx_clone, v),
+            PlanNode::DropCache(v) => DropCacheInterpreter::try_create(ctx_clone, v),
+            PlanNode::Transaction(v) => TransactionInterpreter::try_create(ctx_clone, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx_clone, v),
             PlanNode::Empty(v) => EmptyInterpreter::try_create(ctx_clone, v),
 
@@ -196,4 +233,33 @@ impl InterpreterFactory {
         }?;
         Ok(Arc::new(InterceptorInterpreter::create(ctx, inner, plan)))
     }
+
+    // The statements changing the metas of the tables directly can't be rolled back, and
+    // the transaction would commit the snapshots on top of the metas they replaced.
+    fn check_transaction(ctx: &QueryContext, plan: &PlanNode) -> Result<()> {
+        if ctx.get_transaction().is_none() {
+            return Ok(());
+        }
+
+        match plan {
+            PlanNode::CreateDatabase(_)
+            | PlanNode::DropDatabase(_)
+            | PlanNode::RenameDatabase(_)
+            | PlanNode::UnDropDatabase(_)
+            | PlanNode::CreateTable(_)
+            | PlanNode::DropTable(_)
+            | PlanNode::UnDropTable(_)
+            | PlanNode::RenameTable(_)
+            | PlanNode::OptimizeTable(_)
+            | PlanNode::CreateView(_)
+            | PlanNode::AlterView(_)
+            | PlanNode::DropView(_)
+            | PlanNode::AlterClusterKey(_)
+            | PlanNode::PromoteTable(_) => Err(ErrorCode::InvalidTransactionState(format!(
+                "{} is not allowed in a transaction, COMMIT or ROLLBACK it first",
+                plan.name()
+            ))),
+            _ => Ok(()),
+        }
+    }
 }
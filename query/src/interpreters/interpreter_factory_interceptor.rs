@@ -21,8 +21,14 @@ use common_planners::PlanNode;
 use common_streams::ErrorStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use common_tracing::tracing_futures::Instrument;
 
 use crate::interpreters::access::ManagementModeAccess;
+use crate::interpreters::access::PrivilegeAccess;
+use crate::interpreters::access::SandboxAccess;
+use crate::interpreters::stream::QueryLimitStream;
+use crate::interpreters::stream::ResultCacheStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::InterpreterQueryLog;
@@ -36,6 +42,8 @@ pub struct InterceptorInterpreter {
     query_log: InterpreterQueryLog,
     source_pipe_builder: Mutex<Option<SourcePipeBuilder>>,
     management_mode_access: ManagementModeAccess,
+    sandbox_access: SandboxAccess,
+    privilege_access: PrivilegeAccess,
 }
 
 impl InterceptorInterpreter {
@@ -46,7 +54,9 @@ impl InterceptorInterpreter {
             inner,
             query_log: InterpreterQueryLog::create(ctx.clone(), Some(plan)),
             source_pipe_builder: Mutex::new(None),
-            management_mode_access: ManagementModeAccess::create(ctx),
+            management_mode_access: ManagementModeAccess::create(ctx.clone()),
+            sandbox_access: SandboxAccess::create(ctx.clone()),
+            privilege_access: PrivilegeAccess::create(ctx),
         }
     }
 }
@@ -64,10 +74,22 @@ impl Interpreter for InterceptorInterpreter {
         // Management mode access check.
         self.management_mode_access.check(&self.plan)?;
 
+        // Sandbox access check.
+        self.sandbox_access.check(&self.plan)?;
+
+        // Privilege access check.
+        self.privilege_access.check(&self.plan).await?;
+
         let _ = self
             .inner
             .set_source_pipe_builder((*self.source_pipe_builder.lock()).clone());
-        let result_stream = match self.inner.execute(input_stream).await {
+        let query_tag = String::from_utf8(self.ctx.get_settings().get_query_tag()?)?;
+        let span = tracing::info_span!(
+            "execute",
+            query_id = self.ctx.get_id().as_str(),
+            query_tag = query_tag.as_str()
+        );
+        let result_stream = match self.inner.execute(input_stream).instrument(span).await {
             Ok(s) => s,
             Err(e) => {
                 self.ctx.set_error(e.clone());
@@ -78,7 +100,14 @@ impl Interpreter for InterceptorInterpreter {
         let error_stream = ErrorStream::create(result_stream, self.ctx.get_error());
         let metric_stream =
             ProgressStream::try_create(Box::pin(error_stream), self.ctx.get_result_progress())?;
-        Ok(Box::pin(metric_stream))
+        let limit_stream = QueryLimitStream::try_create(self.ctx.clone(), Box::pin(metric_stream))?;
+
+        match &self.plan {
+            PlanNode::Select(plan) => {
+                ResultCacheStream::try_create(self.ctx.clone(), limit_stream, plan.schema())
+            }
+            _ => Ok(limit_stream),
+        }
     }
 
     async fn start(&self) -> Result<()> {
@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use metrics::counter;
+
+use super::InterpreterFactory;
+use super::InterpreterFactoryV2;
+use super::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::sql::Planner;
+
+/// The planner serving a query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlannerKind {
+    /// The legacy `PlanParser`.
+    Legacy,
+    /// The new `sql/planner`.
+    V2,
+}
+
+impl Display for PlannerKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlannerKind::Legacy => write!(f, "legacy"),
+            PlannerKind::V2 => write!(f, "v2"),
+        }
+    }
+}
+
+/// InterpreterFactoryRouter chooses the planner of a query: the new planner if it's
+/// enabled by `enable_planner_v2` and supports the statement, otherwise the legacy one.
+/// A statement the new planner fails to plan as unimplemented falls back to the legacy
+/// planner, so enabling the new planner doesn't lose any statement.
+pub struct InterpreterFactoryRouter;
+
+impl InterpreterFactoryRouter {
+    /// Returns the planner which should plan the statements.
+    pub fn route(ctx: &Arc<QueryContext>, stmts: &[DfStatement]) -> Result<PlannerKind> {
+        let settings = ctx.get_settings();
+        let supported = settings.get_enable_planner_v2()? != 0
+            && settings.get_enable_new_processor_framework()? != 0
+            && !ctx.get_config().query.management_mode
+            && ctx.get_cluster().is_empty()
+            && stmts.len() == 1
+            && InterpreterFactoryV2::check(&stmts[0]);
+
+        Ok(match supported {
+            true => PlannerKind::V2,
+            false => PlannerKind::Legacy,
+        })
+    }
+
+    /// Whether the error of the new planner means the statement is not supported by it yet.
+    pub fn is_fallback_error(error: &ErrorCode) -> bool {
+        error.code() == ErrorCode::UnImplement("").code()
+            || error.code() == ErrorCode::SyntaxException("").code()
+    }
+
+    /// Plans the query by the routed planner, returns the interpreter and the planner served it.
+    pub async fn get(
+        ctx: Arc<QueryContext>,
+        query: &str,
+        stmts: &[DfStatement],
+    ) -> Result<(InterpreterPtr, PlannerKind)> {
+        if Self::route(&ctx, stmts)? == PlannerKind::V2 {
+            let mut planner = Planner::new(ctx.clone());
+            match planner.plan_sql(query).await {
+                Ok((plan, _)) => {
+                    Self::served(PlannerKind::V2, query);
+                    return Ok((InterpreterFactoryV2::get(ctx, &plan)?, PlannerKind::V2));
+                }
+                Err(e) if Self::is_fallback_error(&e) => {
+                    tracing::info!(
+                        "Fall back to the legacy planner, the new planner fails to plan: {}",
+                        e
+                    );
+                    counter!(super::metrics::METRIC_QUERY_PLANNER_FALLBACK, 1);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let (plan, _) = PlanParser::parse_with_hint(query, ctx.clone()).await;
+        let interpreter = InterpreterFactory::get(ctx, plan?)?;
+        Self::served(PlannerKind::Legacy, query);
+        Ok((interpreter, PlannerKind::Legacy))
+    }
+
+    fn served(kind: PlannerKind, query: &str) {
+        tracing::info!("Planned by the {} planner: {}", kind, query);
+        match kind {
+            PlannerKind::Legacy => counter!(super::metrics::METRIC_QUERY_PLANNER_LEGACY, 1),
+            PlannerKind::V2 => counter!(super::metrics::METRIC_QUERY_PLANNER_V2, 1),
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::CreateTableInterpreter;
@@ -62,6 +63,12 @@ impl InterpreterFactoryV2 {
                 ExplainInterpreterV2::try_create(ctx, *plan.clone(), kind.clone())
             }
             Plan::CreateTable(create_table) => {
+                // As in InterpreterFactory, DDL can't be rolled back.
+                if ctx.get_transaction().is_some() {
+                    return Err(ErrorCode::InvalidTransactionState(
+                        "CreateTablePlan is not allowed in a transaction, COMMIT or ROLLBACK it first",
+                    ));
+                }
                 CreateTableInterpreter::try_create(ctx, *create_table.clone())
             }
             Plan::ShowMetrics => ShowMetricsInterpreter::try_create(ctx),
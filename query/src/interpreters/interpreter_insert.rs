@@ -15,16 +15,12 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
-use chrono_tz::Tz;
 use common_base::base::TrySpawn;
 use common_base::infallible::Mutex;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::CastFunction;
-use common_functions::scalars::FunctionContext;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::InsertInputSource;
 use common_planners::InsertPlan;
 use common_planners::PlanNode;
@@ -118,14 +114,7 @@ impl InsertInterpreter {
                             CastFunction::create("cast", &target_type_name, from_type).unwrap();
                         functions.push(cast_function);
                     }
-                    let tz = self.ctx.get_settings().get_timezone()?;
-                    let tz = String::from_utf8(tz).map_err(|_| {
-                        ErrorCode::LogicalError("Timezone has been checked and should be valid.")
-                    })?;
-                    let tz = tz.parse::<Tz>().map_err(|_| {
-                        ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
-                    })?;
-                    let func_ctx = FunctionContext { tz };
+                    let func_ctx = self.ctx.try_get_function_context()?;
                     pipeline.add_transform(|transform_input_port, transform_output_port| {
                         TransformCastSchema::try_create(
                             transform_input_port,
@@ -157,6 +146,8 @@ impl InsertInterpreter {
         let async_runtime = self.ctx.get_storage_runtime();
 
         pipeline.set_max_threads(self.ctx.get_settings().get_max_threads()? as usize);
+        pipeline.set_max_execution_time(self.ctx.get_settings().get_max_execution_time()?);
+        pipeline.set_cpu_affinity(&self.ctx.get_settings().get_executor_cpu_affinity()?)?;
         let executor = PipelineCompleteExecutor::try_create(async_runtime, pipeline)?;
         executor.execute()?;
         drop(executor);
@@ -225,17 +216,6 @@ impl Interpreter for InsertInterpreter {
         }
 
         let plan = &self.plan;
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Table(
-                    plan.catalog_name.clone(),
-                    plan.database_name.clone(),
-                    plan.table_name.clone(),
-                ),
-                UserPrivilegeType::Insert,
-            )
-            .await?;
 
         let table = self
             .ctx
@@ -22,6 +22,13 @@ use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metrics::label_counter_with_val_and_labels;
+use common_metrics::label_histogram_with_val;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_HANDLER;
+use common_metrics::LABEL_KEY_QUERY_KIND;
+use common_metrics::LABEL_KEY_QUERY_TAG;
+use common_metrics::LABEL_KEY_TENANT;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 use serde::Serialize;
@@ -56,8 +63,10 @@ pub struct LogEvent {
     pub query_id: String,
     pub query_kind: String,
     pub query_text: String,
+    pub query_tag: String,
     pub event_date: i32,
     pub event_time: u64,
+    pub query_duration_ms: u64,
 
     // Schema.
     pub current_database: String,
@@ -77,6 +86,11 @@ pub struct LogEvent {
     pub scan_io_bytes_cost_ms: u64,
     pub scan_partitions: u64,
     pub total_partitions: u64,
+    pub io_requests: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub network_received_bytes: u64,
+    pub spill_bytes: u64,
     pub result_rows: u64,
     pub result_bytes: u64,
     pub cpu_usage: u32,
@@ -157,8 +171,10 @@ impl InterpreterQueryLog {
             Series::from_data(vec![event.query_id.as_str()]),
             Series::from_data(vec![event.query_kind.as_str()]),
             Series::from_data(vec![event.query_text.as_str()]),
+            Series::from_data(vec![event.query_tag.as_str()]),
             Series::from_data(vec![event.event_date as i32]),
             Series::from_data(vec![event.event_time as i64]),
+            Series::from_data(vec![event.query_duration_ms]),
             // Schema.
             Series::from_data(vec![event.current_database.as_str()]),
             Series::from_data(vec![event.databases.as_str()]),
@@ -176,6 +192,11 @@ impl InterpreterQueryLog {
             Series::from_data(vec![event.scan_io_bytes_cost_ms as u64]),
             Series::from_data(vec![event.scan_partitions as u64]),
             Series::from_data(vec![event.total_partitions as u64]),
+            Series::from_data(vec![event.io_requests]),
+            Series::from_data(vec![event.cache_hits]),
+            Series::from_data(vec![event.cache_misses]),
+            Series::from_data(vec![event.network_received_bytes]),
+            Series::from_data(vec![event.spill_bytes]),
             Series::from_data(vec![event.result_rows as u64]),
             Series::from_data(vec![event.result_bytes as u64]),
             Series::from_data(vec![event.cpu_usage]),
@@ -194,6 +215,8 @@ impl InterpreterQueryLog {
             // Extra.
             Series::from_data(vec![event.extra.as_str()]),
         ]);
+        Self::write_metrics(event);
+
         let blocks = vec![Ok(block)];
         let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
         let _ = query_log
@@ -213,6 +236,47 @@ impl InterpreterQueryLog {
         Ok(())
     }
 
+    // The queries are attributed by the query_tag, besides the tenant and the cluster.
+    fn write_metrics(event: &LogEvent) {
+        let labels = [
+            (LABEL_KEY_TENANT, event.tenant_id.clone()),
+            (LABEL_KEY_CLUSTER, event.cluster_id.clone()),
+            (LABEL_KEY_HANDLER, event.handler_type.clone()),
+            (LABEL_KEY_QUERY_KIND, event.query_kind.clone()),
+            (LABEL_KEY_QUERY_TAG, event.query_tag.clone()),
+        ];
+
+        match event.log_type {
+            LogType::Start => {
+                label_counter_with_val_and_labels(super::metrics::METRIC_QUERY_START, &labels, 1)
+            }
+            LogType::Finish => {
+                label_counter_with_val_and_labels(super::metrics::METRIC_QUERY_SUCCESS, &labels, 1)
+            }
+            LogType::Error | LogType::Aborted => {
+                label_counter_with_val_and_labels(super::metrics::METRIC_QUERY_ERROR, &labels, 1)
+            }
+        }
+
+        if !matches!(event.log_type, LogType::Start) {
+            label_histogram_with_val(
+                super::metrics::METRIC_QUERY_DURATION_MS,
+                &labels,
+                event.query_duration_ms as f64,
+            );
+            label_counter_with_val_and_labels(
+                super::metrics::METRIC_QUERY_SCAN_BYTES,
+                &labels,
+                event.scan_bytes,
+            );
+            label_counter_with_val_and_labels(
+                super::metrics::METRIC_QUERY_WRITTEN_BYTES,
+                &labels,
+                event.written_bytes,
+            );
+        }
+    }
+
     pub async fn fail_to_start(ctx: Arc<QueryContext>, err: ErrorCode) {
         ctx.set_error(err.clone());
         InterpreterQueryLog::create(ctx, None)
@@ -239,6 +303,7 @@ impl InterpreterQueryLog {
             .map(|p| p.name().to_string())
             .unwrap_or_else(|| "".to_string());
         let query_text = self.ctx.get_query_str();
+        let query_tag = String::from_utf8(self.ctx.get_settings().get_query_tag()?)?;
         // Schema.
         let current_database = self.ctx.get_current_database();
 
@@ -248,6 +313,7 @@ impl InterpreterQueryLog {
             .expect("Time went backwards")
             .as_millis() as u64;
         let event_date = (event_time / (24 * 3600000)) as i32;
+        let query_duration_ms = 0u64;
 
         let written_rows = 0u64;
         let written_bytes = 0u64;
@@ -259,6 +325,11 @@ impl InterpreterQueryLog {
         let scan_io_bytes_cost_ms = 0u64;
         let scan_partitions = 0u64;
         let total_partitions = 0u64;
+        let io_requests = 0u64;
+        let cache_hits = 0u64;
+        let cache_misses = 0u64;
+        let network_received_bytes = 0u64;
+        let spill_bytes = 0u64;
         let result_rows = 0u64;
         let result_bytes = 0u64;
         let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
@@ -296,8 +367,10 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
+            query_duration_ms,
             current_database,
             databases: "".to_string(),
             tables: "".to_string(),
@@ -313,6 +386,11 @@ impl InterpreterQueryLog {
             scan_io_bytes_cost_ms,
             scan_partitions,
             total_partitions,
+            io_requests,
+            cache_hits,
+            cache_misses,
+            network_received_bytes,
+            spill_bytes,
             result_rows,
             result_bytes,
             cpu_usage,
@@ -350,6 +428,7 @@ impl InterpreterQueryLog {
             .unwrap_or("")
             .to_string();
         let query_text = self.ctx.get_query_str();
+        let query_tag = String::from_utf8(self.ctx.get_settings().get_query_tag()?)?;
 
         // Stats.
         let event_time = now
@@ -357,6 +436,10 @@ impl InterpreterQueryLog {
             .expect("Time went backwards")
             .as_millis() as u64;
         let event_date = (event_time / (24 * 3600000)) as i32;
+        let query_duration_ms = now
+            .duration_since(self.ctx.get_created_time())
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
         let dal_metrics = self.ctx.get_dal_metrics();
 
         let written_rows = self.ctx.get_write_progress_value().rows as u64;
@@ -371,6 +454,13 @@ impl InterpreterQueryLog {
 
         let scan_partitions = dal_metrics.get_partitions_scanned();
         let total_partitions = dal_metrics.get_partitions_total();
+
+        let io_requests = dal_metrics.get_requests();
+        let cache_hits = dal_metrics.get_cache_hits();
+        let cache_misses = dal_metrics.get_cache_misses();
+        let network_received_bytes = dal_metrics.get_network_received_bytes() as u64;
+        let spill_bytes = dal_metrics.get_spill_bytes() as u64;
+
         let cpu_usage = self.ctx.get_settings().get_max_threads()? as u32;
         let memory_usage = self.ctx.get_current_session().get_memory_usage() as u64;
 
@@ -413,8 +503,10 @@ impl InterpreterQueryLog {
             query_id,
             query_kind,
             query_text,
+            query_tag,
             event_date,
             event_time,
+            query_duration_ms,
             databases: "".to_string(),
             tables: "".to_string(),
             columns: "".to_string(),
@@ -429,6 +521,11 @@ impl InterpreterQueryLog {
             scan_io_bytes_cost_ms,
             scan_partitions,
             total_partitions,
+            io_requests,
+            cache_hits,
+            cache_misses,
+            network_received_bytes,
+            spill_bytes,
             result_rows,
             result_bytes,
             cpu_usage,
@@ -48,8 +48,8 @@ impl Interpreter for CreateRoleInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        // TODO: add privilege check about CREATE ROLE
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         let role_info = RoleInfo::new(&plan.role_name);
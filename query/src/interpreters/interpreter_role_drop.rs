@@ -47,8 +47,8 @@ impl Interpreter for DropRoleInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        // TODO: add privilege check about DROP role
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         user_mgr
@@ -49,6 +49,7 @@ impl Interpreter for RevokeRoleInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         match plan.principal {
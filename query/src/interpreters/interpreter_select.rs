@@ -18,29 +18,52 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
 use crate::interpreters::plan_schedulers;
 use crate::interpreters::stream::ProcessorExecutorStream;
+use crate::interpreters::stream::QueryCacheStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
 use crate::pipelines::new::executor::PipelinePullingExecutor;
 use crate::pipelines::new::NewPipeline;
 use crate::pipelines::new::QueryPipelineBuilder;
+use crate::sessions::QueryCacheKey;
 use crate::sessions::QueryContext;
 
 /// SelectInterpreter struct which interprets SelectPlan
 pub struct SelectInterpreter {
     ctx: Arc<QueryContext>,
     select: SelectPlan,
+    // Only the top level SELECT of a query is cached, not the subqueries or the
+    // SELECT of INSERT/COPY which share the query text.
+    query_cache: bool,
 }
 
 impl SelectInterpreter {
     /// Create the SelectInterpreter from SelectPlan
     pub fn try_create(ctx: Arc<QueryContext>, select: SelectPlan) -> Result<InterpreterPtr> {
-        Ok(Arc::new(SelectInterpreter { ctx, select }))
+        Ok(Arc::new(SelectInterpreter {
+            ctx,
+            select,
+            query_cache: false,
+        }))
+    }
+
+    /// Create the SelectInterpreter of a query, whose result may be served from and kept
+    /// in the node level query cache.
+    pub fn try_create_with_query_cache(
+        ctx: Arc<QueryContext>,
+        select: SelectPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(SelectInterpreter {
+            ctx,
+            select,
+            query_cache: true,
+        }))
     }
 
     /// Call this method to optimize the logical plan before executing
@@ -50,6 +73,37 @@ impl SelectInterpreter {
             &self.select.input,
         )
     }
+
+    fn query_cache_key(&self) -> Result<Option<QueryCacheKey>> {
+        if !self.query_cache
+            || self.ctx.get_settings().get_enable_query_result_cache()? == 0
+            || self.ctx.get_query_cache().capacity() == 0
+        {
+            return Ok(None);
+        }
+
+        // The tables written by a transaction keep their versions until it commits.
+        if self.ctx.get_transaction().is_some() {
+            return Ok(None);
+        }
+
+        QueryCacheKey::try_create(&self.ctx, &self.select.input)
+    }
+
+    async fn execute_plan(&self) -> Result<SendableDataBlockStream> {
+        let settings = self.ctx.get_settings();
+
+        if settings.get_enable_new_processor_framework()? != 0 && self.ctx.get_cluster().is_empty()
+        {
+            let async_runtime = self.ctx.get_storage_runtime();
+            let new_pipeline = self.create_new_pipeline()?;
+            let executor = PipelinePullingExecutor::try_create(async_runtime, new_pipeline)?;
+            let executor_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
+            return Ok(Box::pin(self.ctx.try_create_abortable(executor_stream)?));
+        }
+        let optimized_plan = self.rewrite_plan()?;
+        plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -72,25 +126,34 @@ impl Interpreter for SelectInterpreter {
         &self,
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        let settings = self.ctx.get_settings();
+        let key = match self.query_cache_key()? {
+            None => return self.execute_plan().await,
+            Some(key) => key,
+        };
 
-        if settings.get_enable_new_processor_framework()? != 0 && self.ctx.get_cluster().is_empty()
-        {
-            let async_runtime = self.ctx.get_storage_runtime();
-            let new_pipeline = self.create_new_pipeline()?;
-            let executor = PipelinePullingExecutor::try_create(async_runtime, new_pipeline)?;
-            let executor_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
-            return Ok(Box::pin(self.ctx.try_create_abortable(executor_stream)?));
+        let query_cache = self.ctx.get_query_cache();
+        if let Some(cached) = query_cache.get(&key) {
+            return Ok(Box::pin(DataBlockStream::create(
+                cached.schema.clone(),
+                None,
+                cached.blocks.clone(),
+            )));
         }
-        let optimized_plan = self.rewrite_plan()?;
-        plan_schedulers::schedule_query(&self.ctx, &optimized_plan).await
+
+        let stream = self.execute_plan().await?;
+        Ok(QueryCacheStream::create(
+            query_cache,
+            key,
+            stream,
+            self.schema(),
+        ))
     }
 
     /// This method will create a new pipeline
     /// The QueryPipelineBuilder will use the optimized plan to generate a NewPipeline
     fn create_new_pipeline(&self) -> Result<NewPipeline> {
         let settings = self.ctx.get_settings();
-        let builder = QueryPipelineBuilder::create(self.ctx.clone());
+        let builder = QueryPipelineBuilder::create(self.ctx.clone())?;
 
         let optimized_plan = self.rewrite_plan()?;
         let select_plan = SelectPlan {
@@ -98,6 +161,9 @@ impl Interpreter for SelectInterpreter {
         };
         let mut new_pipeline = builder.finalize(&select_plan)?;
         new_pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        new_pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+        new_pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
+        new_pipeline.set_profile_callback(self.ctx.create_profile_callback());
         Ok(new_pipeline)
     }
 }
@@ -19,6 +19,7 @@ use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 
 use crate::interpreters::stream::ProcessorExecutorStream;
+use crate::interpreters::stream::ResultCacheStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::new::executor::PipelineExecutor;
@@ -74,7 +75,7 @@ impl Interpreter for SelectInterpreterV2 {
         if let Some(handle) = self.ctx.get_http_query() {
             return handle.execute(self.ctx.clone(), pb).await;
         }
-        let (root_pipeline, pipelines, _) = pb.spawn()?;
+        let (root_pipeline, pipelines, schema) = pb.spawn()?;
         let async_runtime = self.ctx.get_storage_runtime();
 
         // Spawn sub-pipelines
@@ -86,7 +87,8 @@ impl Interpreter for SelectInterpreterV2 {
         // Spawn root pipeline
         let executor = PipelinePullingExecutor::try_create(async_runtime, root_pipeline)?;
         let executor_stream = Box::pin(ProcessorExecutorStream::create(executor)?);
-        Ok(Box::pin(self.ctx.try_create_abortable(executor_stream)?))
+        let stream = Box::pin(self.ctx.try_create_abortable(executor_stream)?);
+        ResultCacheStream::try_create(self.ctx.clone(), stream, schema)
     }
 
     async fn start(&self) -> Result<()> {
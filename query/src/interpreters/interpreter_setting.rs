@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
@@ -25,6 +26,7 @@ use common_streams::SendableDataBlockStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::sql::ColumnNameCase;
 
 pub struct SettingInterpreter {
     ctx: Arc<QueryContext>,
@@ -35,6 +37,20 @@ impl SettingInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, set: SettingPlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(SettingInterpreter { ctx, set }))
     }
+
+    // The GLOBAL setting is persisted to metasrv first, so it also takes effect in new sessions.
+    async fn set_setting(&self, key: String, value: String, is_global: bool) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        if is_global {
+            let tenant = self.ctx.get_tenant();
+            let setting = settings.get_global_setting(&key, &value)?;
+            self.ctx
+                .get_user_manager()
+                .set_setting(&tenant, setting)
+                .await?;
+        }
+        settings.set_settings(key, value, is_global)
+    }
 }
 
 #[async_trait::async_trait]
@@ -58,14 +74,18 @@ impl Interpreter for SettingInterpreter {
                     let _ = tz.parse::<Tz>().map_err(|_| {
                         ErrorCode::InvalidTimezone(format!("Invalid Timezone: {}", var.value))
                     })?;
-                    self.ctx
-                        .get_settings()
-                        .set_settings(var.variable, tz.to_string(), false)?;
+                    self.set_setting(var.variable, tz.to_string(), var.is_global)
+                        .await?;
+                }
+                "column_name_case" => {
+                    let case = var.value.trim_matches(|c| c == '\'' || c == '\"');
+                    let _ = ColumnNameCase::from_str(case)?;
+                    self.set_setting(var.variable, case.to_string(), var.is_global)
+                        .await?;
                 }
                 _ => {
-                    self.ctx
-                        .get_settings()
-                        .set_settings(var.variable, var.value, false)?;
+                    self.set_setting(var.variable, var.value, var.is_global)
+                        .await?;
                 }
             }
         }
@@ -41,15 +41,15 @@ impl ShowFunctionsInterpreter {
     fn build_query(&self) -> Result<String> {
         return match &self.plan.kind {
             PlanShowKind::All => Ok(
-                "SELECT name, is_builtin, is_aggregate, definition, description FROM system.functions ORDER BY name"
+                "SELECT name, is_builtin, is_aggregate, definition, arguments, description FROM system.functions ORDER BY name"
                     .to_string(),
             ),
             PlanShowKind::Like(expr) => Ok(format!(
-                "SELECT name, is_builtin, is_aggregate, definition, description FROM system.functions WHERE name LIKE {} ORDER BY name",
+                "SELECT name, is_builtin, is_aggregate, definition, arguments, description FROM system.functions WHERE name LIKE {} ORDER BY name",
                 expr
             )),
             PlanShowKind::Where(v) => Ok(format!(
-                "SELECT name, is_builtin, is_aggregate, definition, description FROM system.functions WHERE {} ORDER BY name",
+                "SELECT name, is_builtin, is_aggregate, definition, arguments, description FROM system.functions WHERE {} ORDER BY name",
                 v
             )),
         };
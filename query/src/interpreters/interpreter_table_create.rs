@@ -28,10 +28,10 @@ use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
 use super::InsertInterpreter;
+use crate::interpreters::interpreter_common::apply_future_grants;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
-use crate::storages::StorageDescription;
 
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -85,15 +85,7 @@ impl Interpreter for CreateTableInterpreter {
             .iter()
             .all(|table| table.name() != self.plan.table.as_str());
 
-        let engine_desc: Option<StorageDescription> = catalog
-            .get_table_engines()
-            .iter()
-            .find(|desc| {
-                desc.engine_name.to_string().to_lowercase() == engine.to_string().to_lowercase()
-            })
-            .cloned();
-
-        match engine_desc {
+        match catalog.get_table_engine(engine) {
             Some(engine) => {
                 if !self.plan.cluster_keys.is_empty() && !engine.support_cluster_key {
                     return Err(ErrorCode::UnsupportedEngineParams(format!(
@@ -112,13 +104,25 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
-        match &self.plan.as_select {
+        let stream = match &self.plan.as_select {
             Some(select_plan_node) => {
                 self.create_table_as_select(input_stream, select_plan_node.clone())
-                    .await
+                    .await?
             }
-            None => self.create_table().await,
+            None => self.create_table().await?,
+        };
+
+        // CREATE TABLE IF NOT EXISTS creates nothing if the table exists
+        if name_not_duplicate {
+            apply_future_grants(
+                &self.ctx,
+                &self.plan.catalog,
+                &self.plan.db,
+                &self.plan.table,
+            )
+            .await?;
         }
+        Ok(stream)
     }
 }
 
@@ -16,16 +16,18 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
+use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::TableNameIdent;
 use common_planners::DropTablePlan;
 use common_planners::TruncateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::view_table::view_dependencies;
 use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct DropTableInterpreter {
@@ -58,14 +60,6 @@ impl Interpreter for DropTableInterpreter {
             .await
             .ok();
 
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Database(catalog_name.into(), db_name.into()),
-                UserPrivilegeType::Drop,
-            )
-            .await?;
-
         if let Some(table) = &tbl {
             if table.get_table_info().engine() == VIEW_ENGINE {
                 return Err(ErrorCode::UnexpectedError(format!(
@@ -76,6 +70,9 @@ impl Interpreter for DropTableInterpreter {
         };
 
         let catalog = self.ctx.get_catalog(catalog_name)?;
+        if tbl.is_some() {
+            self.drop_dependent_views(catalog.as_ref()).await?;
+        }
         catalog.drop_table(self.plan.clone().into()).await?;
 
         if let Some(tbl) = tbl {
@@ -100,3 +97,47 @@ impl Interpreter for DropTableInterpreter {
         )))
     }
 }
+
+impl DropTableInterpreter {
+    /// A table referenced by views is only dropped with CASCADE, which drops the views first.
+    async fn drop_dependent_views(&self, catalog: &dyn Catalog) -> Result<()> {
+        let tenant = self.plan.tenant.as_str();
+        let dependency = (self.plan.db.clone(), self.plan.table.clone());
+
+        let mut views = vec![];
+        for database in catalog.list_databases(tenant).await? {
+            for table in catalog.list_tables(tenant, database.name()).await? {
+                let table_info = table.get_table_info();
+                if table_info.engine() == VIEW_ENGINE
+                    && view_dependencies(table_info.options())?.contains(&dependency)
+                {
+                    views.push(format!("{}.{}", database.name(), table.name()));
+                    if self.plan.cascade {
+                        catalog
+                            .drop_table(DropTableReq {
+                                if_exists: true,
+                                name_ident: TableNameIdent {
+                                    tenant: tenant.to_string(),
+                                    db_name: database.name().to_string(),
+                                    table_name: table.name().to_string(),
+                                },
+                            })
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        if !views.is_empty() && !self.plan.cascade {
+            return Err(ErrorCode::TableHasDependentViews(format!(
+                "Can not drop table {}.{}, views {} depend on it, use `DROP TABLE {}.{} CASCADE` to drop them as well",
+                &self.plan.db,
+                &self.plan.table,
+                views.join(", "),
+                &self.plan.db,
+                &self.plan.table
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::ExportTableSnapshotPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use super::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::stage::StageSource;
+
+pub struct ExportTableSnapshotInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExportTableSnapshotPlan,
+}
+
+impl ExportTableSnapshotInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: ExportTableSnapshotPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ExportTableSnapshotInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExportTableSnapshotInterpreter {
+    fn name(&self) -> &str {
+        "ExportTableSnapshotInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog_name)?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database_name, &plan.table_name)
+            .await?;
+
+        let target = StageSource::get_op(&self.ctx, &plan.stage).await?;
+        table
+            .export_snapshot(self.ctx.clone(), &target, &plan.path)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -15,17 +15,24 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::InsertInputSource;
+use common_planners::InsertPlan;
 use common_planners::Optimization;
 use common_planners::OptimizeTablePlan;
+use common_planners::PlanBuilder;
+use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use futures::StreamExt;
 
+use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
-use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
 use crate::sql::PlanParser;
+use crate::sql::OPT_KEY_TTL;
+use crate::storages::Table;
 
 pub struct OptimizeTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -36,6 +43,38 @@ impl OptimizeTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: OptimizeTablePlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(OptimizeTableInterpreter { ctx, plan }))
     }
+
+    // Rewrite all the rows of the table except the expired ones,
+    // the rows whose TTL is NULL never expire.
+    async fn drop_expired_rows(&self, table: &dyn Table, ttl: &str) -> Result<()> {
+        let ttl = PlanParser::parse_expr(ttl)?;
+        let not_expired = ttl
+            .gt(Expression::create_scalar_function("now", vec![]))
+            .or(Expression::create_scalar_function("is_null", vec![
+                ttl.clone()
+            ]));
+
+        let source = table.read_plan(self.ctx.clone(), None).await?;
+        let select_plan = PlanBuilder::from(&PlanNode::ReadSource(source))
+            .filter(not_expired)?
+            .build()?;
+        let insert_plan = InsertPlan {
+            catalog_name: self.plan.catalog.clone(),
+            database_name: self.plan.database.clone(),
+            table_name: self.plan.table.clone(),
+            table_id: table.get_id(),
+            schema: table.schema(),
+            overwrite: true,
+            source: InsertInputSource::SelectPlan(Box::new(select_plan)),
+        };
+
+        let interpreter = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?;
+        let mut stream = interpreter.execute(None).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -57,17 +96,21 @@ impl Interpreter for OptimizeTableInterpreter {
 
         let do_purge = operation.contains(Optimization::PURGE);
         let do_compact = operation.contains(Optimization::COMPACT);
+        let do_recluster = operation.contains(Optimization::RECLUSTER);
+
+        if do_recluster {
+            table.recluster(self.ctx.clone(), &plan.catalog).await?;
+        }
 
         if do_compact {
-            // it is a "simple and violent" strategy, to be optimized later
-            let obj_name = format!("{}.{}", &plan.database, &plan.table);
-            let rewritten_query =
-                format!("INSERT OVERWRITE {} SELECT * FROM {}", obj_name, obj_name);
-            let rewritten_plan =
-                PlanParser::parse(self.ctx.clone(), rewritten_query.as_str()).await?;
-            let interpreter = InterpreterFactory::get(self.ctx.clone(), rewritten_plan)?;
-            let mut stream = interpreter.execute(None).await?;
-            while let Some(Ok(_)) = stream.next().await {}
+            match table.get_table_info().options().get(OPT_KEY_TTL) {
+                // expired rows (if table TTL is specified) are dropped while rewriting all the
+                // rows of the table
+                Some(ttl) => self.drop_expired_rows(table.as_ref(), ttl).await?,
+                // only the small blocks and segments are merged
+                None => table.compact(self.ctx.clone(), &plan.catalog).await?,
+            }
+
             if do_purge {
                 // currently, context caches the table, we have to "refresh"
                 // the table by using the catalog API directly
@@ -25,8 +25,15 @@ use common_tracing::tracing;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::sql::is_ddl_opt_key;
 use crate::sql::is_internal_opt_key;
 use crate::sql::PlanParser;
+use crate::sql::OPT_KEY_PARTITIONS;
+use crate::sql::OPT_KEY_PARTITION_BY;
+use crate::sql::OPT_KEY_REPLICA_OF;
+use crate::storages::fuse::partitioning::PartitionDefs;
+use crate::storages::view::view_table::show_create_view;
+use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -60,6 +67,11 @@ impl Interpreter for ShowCreateTableInterpreter {
         let engine = table.engine();
         let schema = table.schema();
 
+        if engine == VIEW_ENGINE {
+            let view_create_sql = show_create_view(name, table.get_table_info().options())?;
+            return self.show_create_result(name, view_create_sql);
+        }
+
         let mut table_create_sql = format!("CREATE TABLE `{}` (\n", name);
 
         // Append columns.
@@ -98,26 +110,78 @@ impl Interpreter for ShowCreateTableInterpreter {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
 
+        let options = table_info.options();
+        if let Some(partition_by) = options.get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(&format_partition_by(
+                partition_by,
+                options.get(OPT_KEY_PARTITIONS),
+            )?);
+        }
+        if let Some(replica_of) = options.get(OPT_KEY_REPLICA_OF) {
+            table_create_sql.push_str(&format!(" REPLICA OF '{}'", escape_quote(replica_of)));
+        }
+
         table_create_sql.push_str({
-            let mut opts = table_info.options().iter().collect::<Vec<_>>();
+            let mut opts = options.iter().collect::<Vec<_>>();
             opts.sort_by_key(|(k, _)| *k);
             opts.iter()
-                .filter(|(k, _)| !is_internal_opt_key(k))
-                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                .filter(|(k, _)| !is_internal_opt_key(k) && !is_ddl_opt_key(k))
+                .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), escape_quote(v)))
                 .collect::<Vec<_>>()
                 .join("")
                 .as_str()
         });
 
-        let show_fields = vec![
-            DataField::new("Table", Vu8::to_data_type()),
-            DataField::new("Create Table", Vu8::to_data_type()),
-        ];
-        let show_schema = DataSchemaRefExt::create(show_fields);
+        self.show_create_result(name, table_create_sql)
+    }
+}
+
+/// Escapes the single quotes of a value quoted by single quotes.
+fn escape_quote(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+/// Formats the `PARTITION BY` clause, with the RANGE or LIST partitions if any.
+fn format_partition_by(column: &str, partitions: Option<&String>) -> Result<String> {
+    let column = column.replace('`', "``");
+    let partitions = match partitions {
+        None => return Ok(format!(" PARTITION BY (`{}`)", column)),
+        Some(partitions) => serde_json::from_str::<PartitionDefs>(partitions)?,
+    };
+    Ok(match partitions {
+        PartitionDefs::Range(ranges) => {
+            let defs = ranges
+                .iter()
+                .map(|(name, bound)| match bound {
+                    Some(bound) => format!("PARTITION {} VALUES LESS THAN ({})", name, bound),
+                    None => format!("PARTITION {} VALUES LESS THAN MAXVALUE", name),
+                })
+                .collect::<Vec<_>>();
+            format!(" PARTITION BY RANGE (`{}`) ({})", column, defs.join(", "))
+        }
+        PartitionDefs::List(lists) => {
+            let defs = lists
+                .iter()
+                .map(|(name, values)| {
+                    format!("PARTITION {} VALUES IN ({})", name, values.join(", "))
+                })
+                .collect::<Vec<_>>();
+            format!(" PARTITION BY LIST (`{}`) ({})", column, defs.join(", "))
+        }
+    })
+}
+
+impl ShowCreateTableInterpreter {
+    fn show_create_result(
+        &self,
+        name: &str,
+        create_sql: String,
+    ) -> Result<SendableDataBlockStream> {
+        let show_schema = self.plan.schema();
 
         let block = DataBlock::create(show_schema.clone(), vec![
             Series::from_data(vec![name.as_bytes()]),
-            Series::from_data(vec![table_create_sql.into_bytes()]),
+            Series::from_data(vec![create_sql.into_bytes()]),
         ]);
         tracing::debug!("Show create table executor result: {:?}", block);
 
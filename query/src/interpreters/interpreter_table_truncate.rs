@@ -15,8 +15,6 @@
 use std::sync::Arc;
 
 use common_exception::Result;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::TruncateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -50,16 +48,15 @@ impl Interpreter for TruncateTableInterpreter {
         let db_name = self.plan.db.as_str();
         let tbl_name = self.plan.table.as_str();
 
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Table(catalog_name.into(), db_name.into(), tbl_name.into()),
-                UserPrivilegeType::Delete,
-            )
-            .await?;
-
         let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
         tbl.truncate(self.ctx.clone(), self.plan.clone()).await?;
+
+        // The files copied into the table before can be copied again.
+        self.ctx
+            .get_user_manager()
+            .get_copy_api_client(&self.ctx.get_tenant())?
+            .drop_copied_files(tbl.get_id())
+            .await?;
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
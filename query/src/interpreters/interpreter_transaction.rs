@@ -0,0 +1,114 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_planners::TransactionAction;
+use common_planners::TransactionPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionTransaction;
+
+pub struct TransactionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: TransactionPlan,
+}
+
+impl TransactionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: TransactionPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(TransactionInterpreter { ctx, plan }))
+    }
+
+    // The tables written are committed at once, none of them is committed if any one
+    // was changed by another commit since the transaction wrote it.
+    async fn commit(&self, txn: &SessionTransaction) -> Result<()> {
+        let (catalog_name, updates) = match txn.take_updates() {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+
+        let catalog = self.ctx.get_catalog(&catalog_name)?;
+        let req = UpdateMultiTableMetaReq {
+            update_table_metas: updates,
+        };
+        match catalog.update_multi_table_meta(req).await {
+            Ok(_) => {}
+            Err(e) if e.code() == ErrorCode::table_version_mismatched_code() => {
+                return Err(ErrorCode::TransactionConflict(format!(
+                    "the transaction is rolled back, a table it writes was changed by another commit: {}",
+                    e.message()
+                )));
+            }
+            Err(e) => return Err(e),
+        }
+
+        // As COPY out of a transaction, the files are recorded after the commit.
+        let copy_api = self
+            .ctx
+            .get_user_manager()
+            .get_copy_api_client(&self.ctx.get_tenant())?;
+        for (table_id, files) in txn.take_copied_files() {
+            copy_api.add_copied_files(table_id, files).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for TransactionInterpreter {
+    fn name(&self) -> &str {
+        "TransactionInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let session = self.ctx.get_current_session();
+        match self.plan.action {
+            TransactionAction::Begin => {
+                if session.get_transaction().is_some() {
+                    return Err(ErrorCode::InvalidTransactionState(
+                        "the session is in a transaction already, COMMIT or ROLLBACK it first",
+                    ));
+                }
+                session.set_transaction(Some(Arc::new(SessionTransaction::default())));
+            }
+            // As in MySQL, COMMIT and ROLLBACK out of a transaction do nothing.
+            TransactionAction::Commit => {
+                if let Some(txn) = session.take_transaction() {
+                    self.commit(txn.as_ref()).await?;
+                }
+            }
+            // The blocks and snapshots written by the transaction are not referenced by
+            // the tables, "major GC" collects them.
+            TransactionAction::Rollback => {
+                session.take_transaction();
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -48,8 +48,14 @@ impl Interpreter for AlterUserInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
+        if let Some(auth_info) = &plan.auth_info {
+            user_mgr
+                .change_password(&tenant, &plan.user, auth_info)
+                .await?;
+        }
         if plan.auth_info.is_some() || plan.user_option.is_some() {
             user_mgr
                 .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
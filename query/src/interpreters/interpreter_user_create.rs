@@ -51,6 +51,7 @@ impl Interpreter for CreateUserInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
 
         let user_mgr = self.ctx.get_user_manager();
@@ -58,15 +59,21 @@ impl Interpreter for CreateUserInterpreter {
 
         let user_info = UserInfo {
             auth_info: plan.auth_info.clone(),
-            name: plan.user.username,
-            hostname: plan.user.hostname,
+            name: plan.user.username.clone(),
+            hostname: plan.user.hostname.clone(),
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
             option: plan.user_option,
         };
-        user_mgr
+        let seq = user_mgr
             .add_user(&tenant, user_info, plan.if_not_exists)
             .await?;
+        // Zero means the user already exists and `IF NOT EXISTS` is given.
+        if seq != 0 {
+            user_mgr
+                .change_password(&tenant, &plan.user, &plan.auth_info)
+                .await?;
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -48,11 +48,13 @@ impl Interpreter for DropUserInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let plan = self.plan.clone();
+
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         user_mgr
-            .drop_user(&tenant, plan.user, plan.if_exists)
+            .drop_user(&tenant, plan.user.clone(), plan.if_exists)
             .await?;
+        user_mgr.drop_password_state(&tenant, &plan.user).await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::UDFLanguage;
 use common_planners::CreateUserUDFPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -23,6 +24,7 @@ use common_tracing::tracing;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::users::register_udf_function;
 
 #[derive(Debug)]
 pub struct CreateUserUDFInterpreter {
@@ -51,6 +53,10 @@ impl Interpreter for CreateUserUDFInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         let udf = plan.udf;
+        if udf.language != UDFLanguage::Sql {
+            // Check the signature and compile the WASM module to reject the broken ones early.
+            register_udf_function(&udf, &self.ctx)?;
+        }
         let _ = user_mgr.add_udf(&tenant, udf, plan.if_not_exists).await?;
 
         Ok(Box::pin(DataBlockStream::create(
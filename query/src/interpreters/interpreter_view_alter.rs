@@ -21,8 +21,6 @@ use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::AlterViewPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -30,6 +28,8 @@ use common_streams::SendableDataBlockStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::view_table::DEPENDENCIES;
+use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct AlterViewInterpreter {
@@ -51,13 +51,6 @@ impl Interpreter for AlterViewInterpreter {
 
     async fn execute(&self, _: Option<SendableDataBlockStream>) -> Result<SendableDataBlockStream> {
         // check privilige
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Database(self.plan.catalog.clone(), self.plan.db.clone()),
-                UserPrivilegeType::Create,
-            )
-            .await?;
 
         // check whether view has exists
         if !self
@@ -98,7 +91,11 @@ impl AlterViewInterpreter {
 
         // create new view
         let mut options = BTreeMap::new();
-        options.insert("query".to_string(), self.plan.subquery.clone());
+        options.insert(QUERY.to_string(), self.plan.subquery.clone());
+        options.insert(
+            DEPENDENCIES.to_string(),
+            serde_json::to_string(&self.plan.dependencies)?,
+        );
         let plan = CreateTableReq {
             if_not_exists: true,
             name_ident: TableNameIdent {
@@ -20,8 +20,6 @@ use common_exception::Result;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::CreateViewPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -29,6 +27,8 @@ use common_streams::SendableDataBlockStream;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::view_table::DEPENDENCIES;
+use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
 
 pub struct CreateViewInterpreter {
@@ -50,13 +50,6 @@ impl Interpreter for CreateViewInterpreter {
 
     async fn execute(&self, _: Option<SendableDataBlockStream>) -> Result<SendableDataBlockStream> {
         // check privilige
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Database(self.plan.catalog.clone(), self.plan.db.clone()),
-                UserPrivilegeType::Create,
-            )
-            .await?;
 
         // check whether view has exists
         if self
@@ -81,7 +74,11 @@ impl CreateViewInterpreter {
     async fn create_view(&self) -> Result<SendableDataBlockStream> {
         let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
         let mut options = BTreeMap::new();
-        options.insert("query".to_string(), self.plan.subquery.clone());
+        options.insert(QUERY.to_string(), self.plan.subquery.clone());
+        options.insert(
+            DEPENDENCIES.to_string(),
+            serde_json::to_string(&self.plan.dependencies)?,
+        );
         let plan = CreateTableReq {
             if_not_exists: self.plan.if_not_exists,
             name_ident: TableNameIdent {
@@ -18,8 +18,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::DropTableReq;
 use common_meta_app::schema::TableNameIdent;
-use common_meta_types::GrantObject;
-use common_meta_types::UserPrivilegeType;
 use common_planners::DropViewPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -59,14 +57,6 @@ impl Interpreter for DropViewInterpreter {
             .await
             .ok();
 
-        self.ctx
-            .get_current_session()
-            .validate_privilege(
-                &GrantObject::Database(catalog_name.clone(), db_name.clone()),
-                UserPrivilegeType::Drop,
-            )
-            .await?;
-
         if let Some(table) = &tbl {
             if table.get_table_info().engine() != VIEW_ENGINE {
                 return Err(ErrorCode::UnexpectedError(format!(
@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ShowCreateViewPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+use crate::storages::view::view_table::show_create_view;
+use crate::storages::view::view_table::VIEW_ENGINE;
+
+pub struct ShowCreateViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowCreateViewPlan,
+}
+
+impl ShowCreateViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowCreateViewPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ShowCreateViewInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowCreateViewInterpreter {
+    fn name(&self) -> &str {
+        "ShowCreateViewInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.db, &self.plan.viewname)
+            .await?;
+        let table_info = table.get_table_info();
+        if table_info.engine() != VIEW_ENGINE {
+            return Err(ErrorCode::UnexpectedError(format!(
+                "{}.{} is not VIEW, please use `SHOW CREATE TABLE {}.{}`",
+                &self.plan.db, &self.plan.viewname, &self.plan.db, &self.plan.viewname
+            )));
+        }
+
+        let name = table.name();
+        let view_create_sql = show_create_view(name, table_info.options())?;
+
+        let show_schema = self.plan.schema();
+        let block = DataBlock::create(show_schema.clone(), vec![
+            Series::from_data(vec![name.as_bytes()]),
+            Series::from_data(vec![view_create_sql.into_bytes()]),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(show_schema, None, vec![
+            block,
+        ])))
+    }
+}
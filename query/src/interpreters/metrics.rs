@@ -0,0 +1,23 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub static METRIC_QUERY_START: &str = "query.start_numbers";
+pub static METRIC_QUERY_SUCCESS: &str = "query.success_numbers";
+pub static METRIC_QUERY_ERROR: &str = "query.error_numbers";
+pub static METRIC_QUERY_DURATION_MS: &str = "query.duration_ms";
+pub static METRIC_QUERY_SCAN_BYTES: &str = "query.scan_bytes";
+pub static METRIC_QUERY_WRITTEN_BYTES: &str = "query.written_bytes";
+pub static METRIC_QUERY_PLANNER_LEGACY: &str = "query.planner_legacy_numbers";
+pub static METRIC_QUERY_PLANNER_V2: &str = "query.planner_v2_numbers";
+pub static METRIC_QUERY_PLANNER_FALLBACK: &str = "query.planner_fallback_numbers";
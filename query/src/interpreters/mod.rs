@@ -15,6 +15,7 @@
 mod access;
 mod interpreter;
 mod interpreter_alter_cluster_key;
+mod interpreter_cache_drop;
 mod interpreter_call;
 mod interpreter_common;
 mod interpreter_copy;
@@ -23,11 +24,13 @@ mod interpreter_database_drop;
 mod interpreter_database_rename;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
+mod interpreter_delete;
 mod interpreter_empty;
 mod interpreter_explain;
 mod interpreter_explain_v2;
 mod interpreter_factory;
 mod interpreter_factory_interceptor;
+mod interpreter_factory_router;
 mod interpreter_factory_v2;
 mod interpreter_insert;
 mod interpreter_insert_with_stream;
@@ -43,26 +46,35 @@ mod interpreter_role_revoke;
 mod interpreter_select;
 mod interpreter_select_v2;
 mod interpreter_setting;
+mod interpreter_show_caches;
+mod interpreter_show_clusters;
 mod interpreter_show_databases;
 mod interpreter_show_engines;
 mod interpreter_show_functions;
 mod interpreter_show_grants;
 mod interpreter_show_metrics;
 mod interpreter_show_processlist;
+mod interpreter_show_replication_status;
 mod interpreter_show_roles;
 mod interpreter_show_settings;
 mod interpreter_show_stages;
 mod interpreter_show_tab_stat;
 mod interpreter_show_tables;
 mod interpreter_show_users;
+mod interpreter_show_variables;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
+mod interpreter_table_drop_partition;
+mod interpreter_table_export_snapshot;
 mod interpreter_table_optimize;
+mod interpreter_table_promote;
 mod interpreter_table_rename;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_transaction;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
@@ -76,13 +88,17 @@ mod interpreter_user_udf_drop;
 mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_drop;
+mod interpreter_view_show_create;
+mod metrics;
 mod plan_schedulers;
 mod stream;
 
 pub use access::ManagementModeAccess;
+pub use access::SandboxAccess;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_alter_cluster_key::AlterClusterKeyInterpreter;
+pub use interpreter_cache_drop::DropCacheInterpreter;
 pub use interpreter_call::CallInterpreter;
 pub use interpreter_copy::CopyInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
@@ -90,11 +106,14 @@ pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UnDropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_empty::EmptyInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_explain_v2::ExplainInterpreterV2;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_factory_interceptor::InterceptorInterpreter;
+pub use interpreter_factory_router::InterpreterFactoryRouter;
+pub use interpreter_factory_router::PlannerKind;
 pub use interpreter_factory_v2::InterpreterFactoryV2;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
@@ -111,25 +130,34 @@ pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_select_v2::SelectInterpreterV2;
 pub use interpreter_setting::SettingInterpreter;
+pub use interpreter_show_caches::ShowCachesInterpreter;
+pub use interpreter_show_clusters::ShowClustersInterpreter;
 pub use interpreter_show_databases::ShowDatabasesInterpreter;
 pub use interpreter_show_functions::ShowFunctionsInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_metrics::ShowMetricsInterpreter;
 pub use interpreter_show_processlist::ShowProcessListInterpreter;
+pub use interpreter_show_replication_status::ShowReplicationStatusInterpreter;
 pub use interpreter_show_roles::ShowRolesInterpreter;
 pub use interpreter_show_settings::ShowSettingsInterpreter;
 pub use interpreter_show_stages::ShowStagesInterpreter;
 pub use interpreter_show_tab_stat::ShowTabStatInterpreter;
 pub use interpreter_show_tables::ShowTablesInterpreter;
 pub use interpreter_show_users::ShowUsersInterpreter;
+pub use interpreter_show_variables::ShowVariablesInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_drop_partition::DropTablePartitionInterpreter;
+pub use interpreter_table_export_snapshot::ExportTableSnapshotInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
+pub use interpreter_table_promote::PromoteTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UnDropTableInterpreter;
+pub use interpreter_transaction::TransactionInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
@@ -141,4 +169,5 @@ pub use interpreter_user_udf_drop::DropUserUDFInterpreter;
 pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
+pub use interpreter_view_show_create::ShowCreateViewInterpreter;
 pub use plan_schedulers::PlanScheduler;
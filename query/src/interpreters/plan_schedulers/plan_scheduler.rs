@@ -713,6 +713,7 @@ impl PlanScheduler {
             schema: plan.schema.clone(),
             order_by: plan.order_by.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+            limit: plan.limit,
         });
     }
 
@@ -722,6 +723,7 @@ impl PlanScheduler {
                 schema: plan.schema.clone(),
                 order_by: plan.order_by.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
+                limit: plan.limit,
             });
         }
     }
@@ -13,5 +13,11 @@
 // limitations under the License.
 
 mod processor_executor_stream;
+mod query_cache_stream;
+mod query_limit_stream;
+mod result_cache_stream;
 
 pub use processor_executor_stream::ProcessorExecutorStream;
+pub use query_cache_stream::QueryCacheStream;
+pub use query_limit_stream::QueryLimitStream;
+pub use result_cache_stream::ResultCacheStream;
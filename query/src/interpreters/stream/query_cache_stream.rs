@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::sessions::CachedBlocks;
+use crate::sessions::QueryCache;
+use crate::sessions::QueryCacheKey;
+
+/// Pass the blocks through and put them into the node level query cache once the stream
+/// is fully consumed. Results larger than the capacity of the cache are not kept.
+pub struct QueryCacheStream {
+    cache: Arc<QueryCache>,
+    key: Option<QueryCacheKey>,
+    input: SendableDataBlockStream,
+    schema: DataSchemaRef,
+    max_bytes: usize,
+    bytes: usize,
+    blocks: Vec<DataBlock>,
+}
+
+impl QueryCacheStream {
+    pub fn create(
+        cache: Arc<QueryCache>,
+        key: QueryCacheKey,
+        input: SendableDataBlockStream,
+        schema: DataSchemaRef,
+    ) -> SendableDataBlockStream {
+        let max_bytes = cache.capacity();
+        Box::pin(QueryCacheStream {
+            cache,
+            key: Some(key),
+            input,
+            schema,
+            max_bytes,
+            bytes: 0,
+            blocks: vec![],
+        })
+    }
+
+    fn cache_block(&mut self, block: &DataBlock) {
+        if self.key.is_some() {
+            self.bytes += block.memory_size();
+            match self.bytes > self.max_bytes {
+                true => self.key = None,
+                false => self.blocks.push(block.clone()),
+            }
+        }
+    }
+
+    fn cache_result(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.cache.put(key, CachedBlocks {
+                schema: self.schema.clone(),
+                blocks: std::mem::take(&mut self.blocks),
+                bytes: self.bytes,
+            });
+        }
+    }
+}
+
+impl Stream for QueryCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let res = self.input.poll_next_unpin(cx);
+        match &res {
+            Poll::Ready(Some(Ok(block))) => self.cache_block(block),
+            // The result of a failed query is never cached.
+            Poll::Ready(Some(Err(_))) => self.key = None,
+            Poll::Ready(None) => self.cache_result(),
+            Poll::Pending => {}
+        }
+
+        res
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::sessions::QueryContext;
+
+/// Fail the query once the result exceeds `max_result_rows`, or the memory usage of the query
+/// exceeds `max_memory_usage`. The memory usage is checked when a block is pulled.
+pub struct QueryLimitStream {
+    ctx: Arc<QueryContext>,
+    input: SendableDataBlockStream,
+    max_result_rows: usize,
+    max_memory_usage: usize,
+    rows: usize,
+}
+
+impl QueryLimitStream {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let settings = ctx.get_settings();
+        let max_result_rows = settings.get_max_result_rows()? as usize;
+        let max_memory_usage = settings.get_max_memory_usage()? as usize;
+        if max_result_rows == 0 && max_memory_usage == 0 {
+            return Ok(input);
+        }
+
+        Ok(Box::pin(QueryLimitStream {
+            ctx,
+            input,
+            max_result_rows,
+            max_memory_usage,
+            rows: 0,
+        }))
+    }
+
+    fn check_limits(&mut self, block: &DataBlock) -> Result<()> {
+        self.rows += block.num_rows();
+        if self.max_result_rows != 0 && self.rows > self.max_result_rows {
+            return Err(ErrorCode::QueryLimitExceeded(format!(
+                "Result rows exceed the limit max_result_rows: {}",
+                self.max_result_rows
+            )));
+        }
+
+        if self.max_memory_usage != 0 {
+            let memory_usage = self.ctx.get_memory_usage();
+            if memory_usage > self.max_memory_usage {
+                return Err(ErrorCode::QueryLimitExceeded(format!(
+                    "Memory usage {} exceeds the limit max_memory_usage: {}",
+                    memory_usage, self.max_memory_usage
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Stream for QueryLimitStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.input.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(block))) => match self.check_limits(&block) {
+                Ok(_) => Poll::Ready(Some(Ok(block))),
+                Err(cause) => {
+                    self.ctx.set_error(cause.clone());
+                    Poll::Ready(Some(Err(cause)))
+                }
+            },
+            other => other,
+        }
+    }
+}
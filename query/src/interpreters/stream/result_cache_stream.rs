@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::sessions::CachedQueryResult;
+use crate::sessions::QueryContext;
+
+/// Pass the blocks through and keep them in the session once the stream is fully consumed,
+/// so that the result can be read again by RESULT_SCAN. Results larger than
+/// `max_result_scan_cache_bytes` are not kept.
+pub struct ResultCacheStream {
+    ctx: Arc<QueryContext>,
+    input: SendableDataBlockStream,
+    schema: DataSchemaRef,
+    max_bytes: usize,
+    bytes: usize,
+    blocks: Option<Vec<DataBlock>>,
+}
+
+impl ResultCacheStream {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: SendableDataBlockStream,
+        schema: DataSchemaRef,
+    ) -> Result<SendableDataBlockStream> {
+        let max_bytes = ctx.get_settings().get_max_result_scan_cache_bytes()? as usize;
+        if max_bytes == 0 {
+            return Ok(input);
+        }
+
+        Ok(Box::pin(ResultCacheStream {
+            ctx,
+            input,
+            schema,
+            max_bytes,
+            bytes: 0,
+            blocks: Some(vec![]),
+        }))
+    }
+
+    fn cache_block(&mut self, block: &DataBlock) {
+        if let Some(blocks) = self.blocks.as_mut() {
+            self.bytes += block.memory_size();
+            match self.bytes > self.max_bytes {
+                true => self.blocks = None,
+                false => blocks.push(block.clone()),
+            }
+        }
+    }
+
+    fn cache_result(&mut self) {
+        if let Some(blocks) = self.blocks.take() {
+            let result = CachedQueryResult {
+                query_id: self.ctx.get_id(),
+                schema: self.schema.clone(),
+                blocks,
+                bytes: self.bytes,
+            };
+
+            let session = self.ctx.get_current_session();
+            session.put_query_result(result, self.max_bytes);
+        }
+    }
+}
+
+impl Stream for ResultCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let res = self.input.poll_next_unpin(cx);
+        match &res {
+            Poll::Ready(Some(Ok(block))) => self.cache_block(block),
+            // The result of a failed query is never kept.
+            Poll::Ready(Some(Err(_))) => self.blocks = None,
+            Poll::Ready(None) => self.cache_result(),
+            Poll::Pending => {}
+        }
+
+        res
+    }
+}
@@ -14,16 +14,24 @@
 
 mod metrics;
 mod optimizer;
+mod optimizer_common_subexpression;
 mod optimizer_constant_folding;
 mod optimizer_expression_transform;
+mod optimizer_limit_push_down;
+mod optimizer_predicate_push_down;
 mod optimizer_scatters;
 mod optimizer_statistics_exact;
+mod optimizer_top_n;
 mod optimizer_top_n_push_down;
 
 pub use optimizer::Optimizer;
 pub use optimizer::Optimizers;
+pub use optimizer_common_subexpression::CommonSubexpressionOptimizer;
 pub use optimizer_constant_folding::ConstantFoldingOptimizer;
 pub use optimizer_expression_transform::ExprTransformOptimizer;
+pub use optimizer_limit_push_down::LimitPushDownOptimizer;
+pub use optimizer_predicate_push_down::PredicatePushDownOptimizer;
 pub use optimizer_scatters::ScattersOptimizer;
 pub use optimizer_statistics_exact::StatisticsExactOptimizer;
+pub use optimizer_top_n::TopNOptimizer;
 pub use optimizer_top_n_push_down::TopNPushDownOptimizer;
@@ -0,0 +1,217 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::*;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Compute the deterministic expressions shared by a Filter and the Expression above it only once.
+///
+/// The Filter drops the columns it computes, so the expressions in both of them are evaluated
+/// twice, e.g. `f(x)` in `SELECT f(x) + 1 FROM t WHERE f(x) > 0`. This optimizer computes them in
+/// an Expression below the Filter, and rewrites the Filter and the Expression to reference the
+/// computed columns:
+///
+/// ```text
+/// Expression: (f(x) + 1)            Expression: (f(x) + 1)
+///   Filter: (f(x) > 0)        =>      Filter: (f(x) > 0)
+///     ReadDataSource                    Expression: f(x) (Common Subexpressions)
+///                                         ReadDataSource
+/// ```
+///
+/// The expressions in the same Expression are already computed once by the ExpressionExecutor.
+pub struct CommonSubexpressionOptimizer {}
+
+struct CommonSubexpressionImpl {}
+
+impl PlanRewriter for CommonSubexpressionImpl {
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = CommonSubexpressionOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+
+    fn rewrite_expression(&mut self, plan: &ExpressionPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        if let PlanNode::Filter(filter) = &new_input {
+            let common = Self::common_exprs(filter, &plan.exprs)?;
+            if !common.is_empty() {
+                let names = common
+                    .iter()
+                    .map(|expr| expr.column_name())
+                    .collect::<HashSet<_>>();
+                let predicate = Self::replace(&filter.predicate, &names);
+                let exprs = plan
+                    .exprs
+                    .iter()
+                    .map(|expr| Self::replace(expr, &names))
+                    .collect::<Vec<_>>();
+
+                return PlanBuilder::from(filter.input.as_ref())
+                    .expression(&common, "Common Subexpressions")?
+                    .filter(predicate)?
+                    .expression(&exprs, &plan.desc)?
+                    .build();
+            }
+        }
+
+        PlanBuilder::from(&new_input)
+            .expression(&plan.exprs, &plan.desc)?
+            .build()
+    }
+}
+
+impl CommonSubexpressionImpl {
+    // The expressions of the predicate which are used by the exprs as well, in the order
+    // of the predicate.
+    fn common_exprs(filter: &FilterPlan, exprs: &[Expression]) -> Result<Vec<Expression>> {
+        // Leave the subqueries alone, they are wrapped with the plan.
+        let mut all_exprs = exprs.to_vec();
+        all_exprs.push(filter.predicate.clone());
+        if !RewriteHelper::collect_exprs_sub_queries(&all_exprs)?.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut candidates = vec![];
+        Self::collect_candidates(&filter.predicate, &mut candidates);
+
+        let mut used = vec![];
+        for expr in exprs {
+            Self::collect_candidates(expr, &mut used);
+        }
+        let used = used
+            .iter()
+            .map(|expr| expr.column_name())
+            .collect::<HashSet<_>>();
+
+        let input_schema = filter.input.schema();
+        Ok(candidates
+            .into_iter()
+            .filter(|expr| {
+                let name = expr.column_name();
+                used.contains(&name) && !input_schema.has_field(&name)
+            })
+            .collect())
+    }
+
+    // Collects the deterministic sub-expressions (including itself) which compute
+    // something from the columns.
+    fn collect_candidates(expr: &Expression, candidates: &mut Vec<Expression>) {
+        Self::visit(expr, candidates);
+    }
+
+    // Returns (deterministic, has column).
+    fn visit(expr: &Expression, candidates: &mut Vec<Expression>) -> (bool, bool) {
+        let (deterministic, has_column) = match expr {
+            Expression::Column(_) => return (true, true),
+            Expression::Literal { .. } => return (true, false),
+            Expression::Alias(_, expr) => return Self::visit(expr, candidates),
+            Expression::Cast { expr, .. } => Self::visit(expr, candidates),
+            Expression::UnaryExpression { op, expr } => {
+                let (deterministic, has_column) = Self::visit(expr, candidates);
+                (deterministic && Self::is_deterministic(op), has_column)
+            }
+            Expression::BinaryExpression { left, op, right } => {
+                let (left_deterministic, left_has_column) = Self::visit(left, candidates);
+                let (right_deterministic, right_has_column) = Self::visit(right, candidates);
+                (
+                    left_deterministic && right_deterministic && Self::is_deterministic(op),
+                    left_has_column || right_has_column,
+                )
+            }
+            Expression::ScalarFunction { op, args } => {
+                let mut deterministic = Self::is_deterministic(op);
+                let mut has_column = false;
+                for arg in args {
+                    let (arg_deterministic, arg_has_column) = Self::visit(arg, candidates);
+                    deterministic &= arg_deterministic;
+                    has_column |= arg_has_column;
+                }
+                (deterministic, has_column)
+            }
+            _ => return (false, false),
+        };
+
+        if deterministic && has_column {
+            let name = expr.column_name();
+            if !candidates.iter().any(|expr| expr.column_name() == name) {
+                candidates.push(expr.clone());
+            }
+        }
+        (deterministic, has_column)
+    }
+
+    fn is_deterministic(op: &str) -> bool {
+        FunctionFactory::instance()
+            .get_features(op)
+            .map(|features| features.is_deterministic)
+            .unwrap_or(false)
+    }
+
+    // Replace the computed expressions by the columns, the column names are unchanged.
+    fn replace(expr: &Expression, names: &HashSet<String>) -> Expression {
+        match expr {
+            Expression::Alias(alias, expr) => {
+                Expression::Alias(alias.clone(), Box::new(Self::replace(expr, names)))
+            }
+            Expression::Column(_) | Expression::Literal { .. } => expr.clone(),
+            _ if names.contains(&expr.column_name()) => Expression::Column(expr.column_name()),
+            Expression::Cast {
+                expr,
+                data_type,
+                pg_style,
+            } => Expression::Cast {
+                expr: Box::new(Self::replace(expr, names)),
+                data_type: data_type.clone(),
+                pg_style: *pg_style,
+            },
+            Expression::UnaryExpression { op, expr } => Expression::UnaryExpression {
+                op: op.clone(),
+                expr: Box::new(Self::replace(expr, names)),
+            },
+            Expression::BinaryExpression { left, op, right } => Expression::BinaryExpression {
+                left: Box::new(Self::replace(left, names)),
+                op: op.clone(),
+                right: Box::new(Self::replace(right, names)),
+            },
+            Expression::ScalarFunction { op, args } => Expression::ScalarFunction {
+                op: op.clone(),
+                args: args.iter().map(|arg| Self::replace(arg, names)).collect(),
+            },
+            _ => expr.clone(),
+        }
+    }
+}
+
+impl Optimizer for CommonSubexpressionOptimizer {
+    fn name(&self) -> &str {
+        "CommonSubexpression"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = CommonSubexpressionImpl {};
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl CommonSubexpressionOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> CommonSubexpressionOptimizer {
+        CommonSubexpressionOptimizer {}
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::*;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Record the `Limit N` in the push_downs of the ReadDataSource below it, so that the
+/// table engines could stop producing blocks after N rows and the distributed scans could
+/// apply the limit on every partition.
+///
+/// The limit is only pushed through the Projection and the Expression, which keep the rows
+/// as they are. Any Filter, Sort or aggregation in between stops it.
+pub struct LimitPushDownOptimizer {}
+
+struct LimitPushDownImpl {}
+
+impl PlanRewriter for LimitPushDownImpl {
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = LimitPushDownOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+
+    fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_input = match plan.n {
+            Some(n) => Self::push_down(&new_input, n + plan.offset)?,
+            None => new_input,
+        };
+
+        PlanBuilder::from(&new_input)
+            .limit_offset(plan.n, plan.offset)?
+            .build()
+    }
+}
+
+impl LimitPushDownImpl {
+    fn push_down(input: &PlanNode, limit: usize) -> Result<PlanNode> {
+        match input {
+            PlanNode::Projection(plan) => {
+                let new_input = Self::push_down(plan.input.as_ref(), limit)?;
+                PlanBuilder::from(&new_input).project(&plan.expr)?.build()
+            }
+            PlanNode::Expression(plan) => {
+                let new_input = Self::push_down(plan.input.as_ref(), limit)?;
+                PlanBuilder::from(&new_input)
+                    .expression(&plan.exprs, &plan.desc)?
+                    .build()
+            }
+            PlanNode::ReadSource(plan) => {
+                let mut new_plan = plan.clone();
+                let mut extras = plan.push_downs.clone().unwrap_or_else(Extras::default);
+                extras.limit = Some(match extras.limit {
+                    Some(current_limit) => current_limit.min(limit),
+                    None => limit,
+                });
+                new_plan.push_downs = Some(extras);
+                Ok(PlanNode::ReadSource(new_plan))
+            }
+            _ => Ok(input.clone()),
+        }
+    }
+}
+
+impl Optimizer for LimitPushDownOptimizer {
+    fn name(&self) -> &str {
+        "LimitPushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = LimitPushDownImpl {};
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl LimitPushDownOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> LimitPushDownOptimizer {
+        LimitPushDownOptimizer {}
+    }
+}
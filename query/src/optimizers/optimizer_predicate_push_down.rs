@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::*;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Push the Filter down through the Sort and the Projection, and into the push_downs
+/// of the ReadDataSource so that the table engines could prune the partitions by it.
+///
+/// A Filter never moves below a Limit, which would change the rows returned.
+pub struct PredicatePushDownOptimizer {}
+
+struct PredicatePushDownImpl {}
+
+impl PlanRewriter for PredicatePushDownImpl {
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = PredicatePushDownOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+
+    fn rewrite_filter(&mut self, plan: &FilterPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
+
+        // The subqueries are evaluated right below the Filter.
+        match find_subquery_exprs(&[new_predicate.clone()]).is_empty() {
+            true => Self::push_down(&new_input, new_predicate),
+            false => PlanBuilder::from(&new_input).filter(new_predicate)?.build(),
+        }
+    }
+}
+
+impl PredicatePushDownImpl {
+    fn push_down(input: &PlanNode, predicate: Expression) -> Result<PlanNode> {
+        match input {
+            // A Top-N sort keeps only some of the rows, filtering before it changes the result.
+            PlanNode::Sort(plan) if plan.limit.is_none() => {
+                let new_input = Self::push_down(plan.input.as_ref(), predicate)?;
+                PlanBuilder::from(&new_input).sort(&plan.order_by)?.build()
+            }
+            PlanNode::Projection(plan) if Self::is_pass_through(plan, &predicate)? => {
+                let new_input = Self::push_down(plan.input.as_ref(), predicate)?;
+                PlanBuilder::from(&new_input).project(&plan.expr)?.build()
+            }
+            PlanNode::ReadSource(plan) => {
+                let mut new_plan = plan.clone();
+                let mut extras = plan.push_downs.clone().unwrap_or_else(Extras::default);
+                // The filters collected from the WHERE clause are kept as they are.
+                if extras.filters.is_empty() {
+                    extras.filters = vec![predicate.clone()];
+                }
+                new_plan.push_downs = Some(extras);
+
+                PlanBuilder::from(&PlanNode::ReadSource(new_plan))
+                    .filter(predicate)?
+                    .build()
+            }
+            _ => PlanBuilder::from(input).filter(predicate)?.build(),
+        }
+    }
+
+    // The predicate could be evaluated below the Projection only if all the columns it
+    // requires are passed through as they are, not renamed or computed.
+    fn is_pass_through(plan: &ProjectionPlan, predicate: &Expression) -> Result<bool> {
+        let columns = RequireColumnsVisitor::collect_columns_from_expr(predicate)?;
+        Ok(columns.iter().all(|column| {
+            plan.expr
+                .iter()
+                .any(|expr| matches!(expr, Expression::Column(name) if name == column))
+        }))
+    }
+}
+
+impl Optimizer for PredicatePushDownOptimizer {
+    fn name(&self) -> &str {
+        "PredicatePushDown"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = PredicatePushDownImpl {};
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl PredicatePushDownOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> PredicatePushDownOptimizer {
+        PredicatePushDownOptimizer {}
+    }
+}
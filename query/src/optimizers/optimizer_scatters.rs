@@ -49,6 +49,8 @@ struct ScattersOptimizerImpl {
     ctx: Arc<QueryContext>,
     running_mode: RunningMode,
     before_group_by_schema: Option<DataSchemaRef>,
+    // Rows read by the data sources under the current aggregation, None if any is unknown.
+    read_rows: Option<usize>,
 
     // temporary node
     input: Option<Arc<PlanNode>>,
@@ -60,6 +62,7 @@ impl ScattersOptimizerImpl {
             ctx,
             running_mode: RunningMode::Standalone,
             before_group_by_schema: None,
+            read_rows: Some(0),
             input: None,
         }
     }
@@ -94,6 +97,14 @@ impl ScattersOptimizerImpl {
     }
 
     fn cluster_aggregate(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        // Shuffling the partial states is not worth it if the input is known to be tiny,
+        // so we convergent them in local node like aggregation without key.
+        // This is decided at plan time only, unknown inputs are assumed to be large.
+        let shuffle_threshold = self.ctx.get_settings().get_group_by_shuffle_threshold()?;
+        if matches!(self.read_rows, Some(read_rows) if read_rows < shuffle_threshold as usize) {
+            return self.cluster_aggregate_without_key(plan);
+        }
+
         match plan.group_expr.len() {
             0 => self.cluster_aggregate_without_key(plan),
             _ => self.cluster_aggregate_with_key(plan),
@@ -116,7 +127,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Cluster sort input is None")),
             Some(input) => Self::convergent_shuffle_stage_builder(input)
-                .sort(&plan.order_by)?
+                .sort_limit(&plan.order_by, plan.limit)?
                 .build(),
         }
     }
@@ -125,7 +136,7 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Standalone sort input is None")),
             Some(input) => PlanBuilder::from(input.as_ref())
-                .sort(&plan.order_by)?
+                .sort_limit(&plan.order_by, plan.limit)?
                 .build(),
         }
     }
@@ -229,15 +240,23 @@ impl PlanRewriter for ScattersOptimizerImpl {
     }
 
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
+        // Only the data sources under the aggregation are counted for its input.
+        let outer_read_rows = self.read_rows.replace(0);
         let new_input = Arc::new(self.rewrite_plan_node(&plan.input)?);
 
         self.input = Some(new_input.clone());
         self.before_group_by_schema = Some(new_input.schema());
 
-        match self.running_mode {
+        let new_plan = match self.running_mode {
             RunningMode::Cluster => self.cluster_aggregate(plan),
             RunningMode::Standalone => self.standalone_aggregate(plan),
-        }
+        };
+
+        self.read_rows = match (outer_read_rows, self.read_rows) {
+            (Some(outer), Some(inner)) => Some(outer + inner),
+            _ => None,
+        };
+        new_plan
     }
 
     fn rewrite_aggregate_final(&mut self, plan: &AggregatorFinalPlan) -> Result<PlanNode> {
@@ -280,6 +299,13 @@ impl PlanRewriter for ScattersOptimizerImpl {
 
     fn rewrite_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<PlanNode> {
         let t = self.ctx.build_table_from_source_plan(plan)?;
+        let statistics = &plan.statistics;
+        self.read_rows = match statistics.read_rows == 0 && !statistics.is_exact {
+            true => None,
+            false => self
+                .read_rows
+                .map(|read_rows| read_rows + statistics.read_rows),
+        };
 
         match t.is_local() {
             false => self.running_mode = RunningMode::Cluster,
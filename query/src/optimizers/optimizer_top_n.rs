@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::*;
+
+use crate::optimizers::Optimizer;
+use crate::sessions::QueryContext;
+
+/// Merge the `Limit N` into the Sort below it, so that the sort only keeps the top N
+/// (plus offset) rows of every block instead of materializing the whole input.
+///
+/// Like the LimitPushDown, the limit is only merged through the Projection and the
+/// Expression. Any Filter or aggregation in between stops it.
+pub struct TopNOptimizer {}
+
+struct TopNImpl {}
+
+impl PlanRewriter for TopNImpl {
+    fn rewrite_subquery_plan(&mut self, subquery_plan: &PlanNode) -> Result<PlanNode> {
+        let mut optimizer = TopNOptimizer {};
+        optimizer.optimize(subquery_plan)
+    }
+
+    fn rewrite_limit(&mut self, plan: &LimitPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        let new_input = match plan.n {
+            Some(n) => Self::merge_sort(&new_input, n + plan.offset)?,
+            None => new_input,
+        };
+
+        PlanBuilder::from(&new_input)
+            .limit_offset(plan.n, plan.offset)?
+            .build()
+    }
+}
+
+impl TopNImpl {
+    fn merge_sort(input: &PlanNode, limit: usize) -> Result<PlanNode> {
+        match input {
+            PlanNode::Projection(plan) => {
+                let new_input = Self::merge_sort(plan.input.as_ref(), limit)?;
+                PlanBuilder::from(&new_input).project(&plan.expr)?.build()
+            }
+            PlanNode::Expression(plan) => {
+                let new_input = Self::merge_sort(plan.input.as_ref(), limit)?;
+                PlanBuilder::from(&new_input)
+                    .expression(&plan.exprs, &plan.desc)?
+                    .build()
+            }
+            PlanNode::Sort(plan) => {
+                let limit = match plan.limit {
+                    Some(current_limit) => current_limit.min(limit),
+                    None => limit,
+                };
+                PlanBuilder::from(plan.input.as_ref())
+                    .sort_limit(&plan.order_by, Some(limit))?
+                    .build()
+            }
+            _ => Ok(input.clone()),
+        }
+    }
+}
+
+impl Optimizer for TopNOptimizer {
+    fn name(&self) -> &str {
+        "TopN"
+    }
+
+    fn optimize(&mut self, plan: &PlanNode) -> Result<PlanNode> {
+        let mut visitor = TopNImpl {};
+        visitor.rewrite_plan_node(plan)
+    }
+}
+
+impl TopNOptimizer {
+    pub fn create(_ctx: Arc<QueryContext>) -> TopNOptimizer {
+        TopNOptimizer {}
+    }
+}
@@ -137,7 +137,9 @@ impl PlanRewriter for TopNPushDownImpl {
         }
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_order_by = self.rewrite_exprs(&new_input.schema(), &plan.order_by)?;
-        PlanBuilder::from(&new_input).sort(&new_order_by)?.build()
+        PlanBuilder::from(&new_input)
+            .sort_limit(&new_order_by, plan.limit)?
+            .build()
     }
 }
 
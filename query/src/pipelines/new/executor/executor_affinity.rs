@@ -0,0 +1,95 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use common_base::base::numa_nodes;
+use common_base::base::Thread;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use metrics::counter;
+
+pub static METRIC_EXECUTOR_BOUND_THREADS: &str = "pipeline_executor.bound_threads";
+pub static METRIC_EXECUTOR_BIND_FAILED_THREADS: &str = "pipeline_executor.bind_failed_threads";
+
+/// How the pipeline executor threads are bound to cpus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CpuAffinity {
+    None,
+    /// Each thread is bound to a single core, the cores of a NUMA node are used up first.
+    Core,
+    /// Each thread is bound to all the cores of a NUMA node, the nodes are used in turn.
+    Numa,
+}
+
+impl FromStr for CpuAffinity {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "" | "none" => Ok(CpuAffinity::None),
+            "core" => Ok(CpuAffinity::Core),
+            "numa" => Ok(CpuAffinity::Numa),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown executor_cpu_affinity: {}, it must be one of none, core or numa",
+                s
+            ))),
+        }
+    }
+}
+
+// The next cpu set to assign, shared by all the executors of the process, so that the threads
+// of the concurrent queries are spread over the cpus instead of all starting from the first.
+static NEXT_CPU_SET: AtomicUsize = AtomicUsize::new(0);
+
+impl CpuAffinity {
+    /// The cpu sets the threads of an executor are bound to, one per thread, empty if the
+    /// threads are not bound. The sets are assigned round-robin across all the executors.
+    pub fn assign_cpu_sets(&self, threads_num: usize) -> Vec<Vec<usize>> {
+        let cpu_sets = self.cpu_sets();
+        if cpu_sets.is_empty() {
+            return vec![];
+        }
+
+        let start = NEXT_CPU_SET.fetch_add(threads_num, Ordering::Relaxed);
+        (0..threads_num)
+            .map(|thread_num| cpu_sets[(start + thread_num) % cpu_sets.len()].clone())
+            .collect()
+    }
+
+    fn cpu_sets(&self) -> Vec<Vec<usize>> {
+        match self {
+            CpuAffinity::None => vec![],
+            CpuAffinity::Core => numa_nodes()
+                .into_iter()
+                .flatten()
+                .map(|cpu| vec![cpu])
+                .collect(),
+            CpuAffinity::Numa => numa_nodes(),
+        }
+    }
+
+    pub fn bind_current_thread(cpus: &[usize]) {
+        match Thread::bind_to_cpus(cpus) {
+            true => counter!(METRIC_EXECUTOR_BOUND_THREADS, 1),
+            false => {
+                tracing::warn!("Cannot bind the executor thread to cpus {:?}", cpus);
+                counter!(METRIC_EXECUTOR_BIND_FAILED_THREADS, 1);
+            }
+        }
+    }
+}
@@ -26,6 +26,7 @@ use petgraph::prelude::NodeIndex;
 use petgraph::prelude::StableGraph;
 use petgraph::Direction;
 
+use crate::pipelines::new::executor::executor_profile::ProcessorProfileInfo;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
@@ -52,9 +53,7 @@ struct Node {
     processor: ProcessorPtr,
 
     updated_list: Arc<UpdateList>,
-    #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
-    #[allow(dead_code)]
     outputs_port: Vec<Arc<OutputPort>>,
 }
 
@@ -80,6 +79,22 @@ impl Node {
     pub unsafe fn create_trigger(&self, index: EdgeIndex) -> *mut UpdateTrigger {
         self.updated_list.create_trigger(index)
     }
+
+    pub unsafe fn profile(&self, index: NodeIndex) -> ProcessorProfileInfo {
+        let processor_profile = self.processor.profile();
+        let inputs = self.inputs_port.iter().map(|port| port.profile());
+        let outputs = self.outputs_port.iter().map(|port| port.profile());
+        ProcessorProfileInfo {
+            id: index.index(),
+            name: self.processor.name().to_string(),
+            cpu_time: processor_profile.cpu_time(),
+            async_time: processor_profile.async_time(),
+            input_rows: inputs.clone().map(|port| port.rows()).sum(),
+            input_wait_time: inputs.map(|port| port.empty_time()).sum(),
+            output_rows: outputs.clone().map(|port| port.rows()).sum(),
+            output_stall_time: outputs.map(|port| port.full_time()).sum(),
+        }
+    }
 }
 
 struct ExecutingGraph {
@@ -347,6 +362,14 @@ impl RunningGraph {
         ExecutingGraph::schedule_queue(&self.0, node_index, &mut schedule_queue)?;
         Ok(schedule_queue)
     }
+
+    pub fn get_profiles(&self) -> Vec<ProcessorProfileInfo> {
+        let graph = &self.0.graph;
+        graph
+            .node_indices()
+            .map(|index| unsafe { graph[index].profile(index) })
+            .collect()
+    }
 }
 
 impl Debug for Node {
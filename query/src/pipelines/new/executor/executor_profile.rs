@@ -0,0 +1,155 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use common_base::infallible::RwLock;
+
+const MAX_QUERY_PROFILES: usize = 128;
+// The parallel processors are considered skewed if the busiest one receives more
+// rows than the factor times of the average.
+const SKEW_FACTOR: f64 = 2.0;
+
+pub type ProfileCallback = Box<dyn FnOnce(PipelineProfile) + Send + Sync + 'static>;
+
+#[derive(Clone, Debug)]
+pub struct ProcessorProfileInfo {
+    pub id: usize,
+    pub name: String,
+    /// The time spent in the synchronous work.
+    pub cpu_time: Duration,
+    /// The time spent in the asynchronous work, mostly waiting for IO.
+    pub async_time: Duration,
+    pub input_rows: u64,
+    /// The time the processor waited for the upstream data.
+    pub input_wait_time: Duration,
+    pub output_rows: u64,
+    /// The time the pushed data waited for the downstream to consume it.
+    pub output_stall_time: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct PipelineProfile {
+    pub elapsed: Duration,
+    pub processors: Vec<ProcessorProfileInfo>,
+}
+
+impl PipelineProfile {
+    /// The fraction of the execution time the output port of the processor held data.
+    pub fn output_fill(&self, processor: &ProcessorProfileInfo) -> f64 {
+        match self.elapsed.as_nanos() {
+            0 => 0.0,
+            elapsed => (processor.output_stall_time.as_nanos() as f64 / elapsed as f64).min(1.0),
+        }
+    }
+
+    /// Tell whether the pipeline is CPU-bound or IO-bound, and the parallel processors
+    /// receiving skewed data.
+    pub fn diagnose(&self) -> Vec<String> {
+        let mut diagnosis = vec![];
+
+        let cpu_time = self.processors.iter().map(|p| p.cpu_time).sum::<Duration>();
+        let async_time = self
+            .processors
+            .iter()
+            .map(|p| p.async_time)
+            .sum::<Duration>();
+        if cpu_time >= async_time {
+            diagnosis.push(format!(
+                "CPU-bound: cpu time {:?}, async time {:?}",
+                cpu_time, async_time
+            ));
+        } else {
+            diagnosis.push(format!(
+                "IO-bound: async time {:?}, cpu time {:?}",
+                async_time, cpu_time
+            ));
+        }
+
+        let mut parallel_processors: Vec<(&str, Vec<u64>)> = vec![];
+        for processor in &self.processors {
+            match parallel_processors
+                .iter_mut()
+                .find(|(name, _)| *name == processor.name)
+            {
+                Some((_, rows)) => rows.push(processor.input_rows),
+                None => parallel_processors.push((&processor.name, vec![processor.input_rows])),
+            }
+        }
+
+        for (name, rows) in parallel_processors {
+            if rows.len() < 2 {
+                continue;
+            }
+
+            let max = *rows.iter().max().unwrap_or(&0);
+            let avg = rows.iter().sum::<u64>() as f64 / rows.len() as f64;
+            if avg > 0.0 && max as f64 > avg * SKEW_FACTOR {
+                diagnosis.push(format!(
+                    "Skewed: {} × {} processors, max input rows {}, avg input rows {:.0}",
+                    name,
+                    rows.len(),
+                    max,
+                    avg
+                ));
+            }
+        }
+
+        diagnosis
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QueryProfile {
+    pub query_id: String,
+    pub pipelines: Vec<PipelineProfile>,
+}
+
+/// The pipeline profiles of the recent queries, a query may execute several pipelines.
+#[derive(Default)]
+pub struct QueryProfileManager {
+    profiles: RwLock<VecDeque<QueryProfile>>,
+}
+
+impl QueryProfileManager {
+    pub fn init() -> QueryProfileManager {
+        Default::default()
+    }
+
+    pub fn record(&self, query_id: &str, profile: PipelineProfile) {
+        let mut profiles = self.profiles.write();
+        if let Some(query_profile) = profiles.iter_mut().find(|p| p.query_id == query_id) {
+            query_profile.pipelines.push(profile);
+            return;
+        }
+
+        if profiles.len() == MAX_QUERY_PROFILES {
+            profiles.pop_front();
+        }
+        profiles.push_back(QueryProfile {
+            query_id: query_id.to_string(),
+            pipelines: vec![profile],
+        });
+    }
+
+    pub fn get(&self, query_id: &str) -> Option<QueryProfile> {
+        let profiles = self.profiles.read();
+        profiles.iter().find(|p| p.query_id == query_id).cloned()
+    }
+
+    pub fn get_all(&self) -> Vec<QueryProfile> {
+        self.profiles.read().iter().cloned().collect()
+    }
+}
@@ -14,15 +14,23 @@
 
 mod pipeline_executor;
 
+mod executor_affinity;
 mod executor_condvar;
 mod executor_graph;
+mod executor_profile;
 mod executor_tasks;
 mod executor_worker_context;
 mod pipeline_complete_executor;
 mod pipeline_pulling_executor;
 mod pipeline_pushing_executor;
 
+pub use executor_affinity::CpuAffinity;
 pub use executor_graph::RunningGraph;
+pub use executor_profile::PipelineProfile;
+pub use executor_profile::ProcessorProfileInfo;
+pub use executor_profile::ProfileCallback;
+pub use executor_profile::QueryProfile;
+pub use executor_profile::QueryProfileManager;
 pub use pipeline_complete_executor::PipelineCompleteExecutor;
 pub use pipeline_executor::PipelineExecutor;
 pub use pipeline_pulling_executor::PipelinePullingExecutor;
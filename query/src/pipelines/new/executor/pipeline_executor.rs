@@ -13,17 +13,27 @@
 // limitations under the License.
 
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
 
+use common_base::base::tokio;
 use common_base::base::Runtime;
 use common_base::base::Thread;
+use common_base::base::TrySpawn;
+use common_base::infallible::Mutex;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing;
 
+use crate::pipelines::new::executor::executor_affinity::CpuAffinity;
 use crate::pipelines::new::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::new::executor::executor_graph::RunningGraph;
+use crate::pipelines::new::executor::executor_profile::PipelineProfile;
+use crate::pipelines::new::executor::executor_profile::ProfileCallback;
 use crate::pipelines::new::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::new::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::new::pipeline::NewPipeline;
@@ -32,14 +42,25 @@ pub struct PipelineExecutor {
     threads_num: usize,
     graph: RunningGraph,
     workers_condvar: Arc<WorkersCondvar>,
+    max_execution_time: Option<Duration>,
+    timed_out: AtomicBool,
+    cpu_sets: Vec<Vec<usize>>,
+    created_at: Instant,
+    profile_callback: Mutex<Option<ProfileCallback>>,
     pub async_runtime: Arc<Runtime>,
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
 }
 
 impl PipelineExecutor {
-    pub fn create(async_rt: Arc<Runtime>, pipeline: NewPipeline) -> Result<Arc<PipelineExecutor>> {
+    pub fn create(
+        async_rt: Arc<Runtime>,
+        mut pipeline: NewPipeline,
+    ) -> Result<Arc<PipelineExecutor>> {
         unsafe {
+            let profile_callback = pipeline.take_profile_callback();
             let threads_num = pipeline.get_max_threads();
+            let max_execution_time = pipeline.get_max_execution_time();
+            let cpu_sets = pipeline.get_cpu_affinity().assign_cpu_sets(threads_num);
             let workers_condvar = WorkersCondvar::create(threads_num);
             let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
 
@@ -56,6 +77,11 @@ impl PipelineExecutor {
                 graph,
                 threads_num,
                 workers_condvar,
+                max_execution_time,
+                timed_out: AtomicBool::new(false),
+                cpu_sets,
+                created_at: Instant::now(),
+                profile_callback: Mutex::new(profile_callback),
                 global_tasks_queue,
                 async_runtime: async_rt,
             }))
@@ -67,7 +93,51 @@ impl PipelineExecutor {
         Ok(())
     }
 
+    pub fn get_profile(&self) -> PipelineProfile {
+        PipelineProfile {
+            elapsed: self.created_at.elapsed(),
+            processors: self.graph.get_profiles(),
+        }
+    }
+
     pub fn execute(self: &Arc<Self>) -> Result<()> {
+        let timeout_watcher = self.watch_execution_time();
+        let res = self.execute_and_join_threads();
+
+        if let Some(timeout_watcher) = timeout_watcher {
+            timeout_watcher.abort();
+        }
+
+        match self.max_execution_time {
+            Some(max_execution_time) if self.timed_out.load(Ordering::SeqCst) => {
+                Err(ErrorCode::AbortedQuery(format!(
+                    "Query execution was interrupted, maximum execution time exceeded ({}ms)",
+                    max_execution_time.as_millis()
+                )))
+            }
+            _ => res,
+        }
+    }
+
+    // Finish the executor once the execution runs longer than max_execution_time.
+    fn watch_execution_time(self: &Arc<Self>) -> Option<tokio::task::JoinHandle<()>> {
+        let max_execution_time = self.max_execution_time?;
+        let executor = Arc::downgrade(self);
+
+        Some(self.async_runtime.spawn(async move {
+            tokio::time::sleep(max_execution_time).await;
+
+            if let Some(executor) = executor.upgrade() {
+                executor.timed_out.store(true, Ordering::SeqCst);
+
+                if let Err(cause) = executor.finish() {
+                    tracing::warn!("Catch error when finish timed out executor {:?}", cause);
+                }
+            }
+        }))
+    }
+
+    fn execute_and_join_threads(self: &Arc<Self>) -> Result<()> {
         let mut thread_join_handles = self.execute_threads(self.threads_num);
 
         while let Some(join_handle) = thread_join_handles.pop() {
@@ -88,7 +158,13 @@ impl PipelineExecutor {
         for thread_num in 0..threads_size {
             let this = self.clone();
             let name = format!("PipelineExecutor-{}", thread_num);
+            let cpus = self.cpu_sets.get(thread_num).cloned();
+
             thread_join_handles.push(Thread::named_spawn(Some(name), move || unsafe {
+                if let Some(cpus) = cpus {
+                    CpuAffinity::bind_current_thread(&cpus);
+                }
+
                 match this.execute_single_thread(thread_num) {
                     Ok(_) => Ok(()),
                     Err(cause) => this.throw_error(thread_num, cause),
@@ -136,5 +212,9 @@ impl Drop for PipelineExecutor {
         if let Err(cause) = self.finish() {
             tracing::warn!("Catch error when drop pipeline executor {:?}", cause);
         }
+
+        if let Some(profile_callback) = self.profile_callback.lock().take() {
+            profile_callback(self.get_profile());
+        }
     }
 }
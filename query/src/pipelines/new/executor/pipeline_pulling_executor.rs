@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::pipelines::new::executor::PipelineExecutor;
+use crate::pipelines::new::executor::PipelineProfile;
 use crate::pipelines::new::processors::port::InputPort;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Sink;
@@ -111,6 +112,10 @@ impl PipelinePullingExecutor {
         self.executor.finish()
     }
 
+    pub fn get_profile(&self) -> PipelineProfile {
+        self.executor.get_profile()
+    }
+
     pub fn pull_data(&mut self) -> Result<Option<DataBlock>> {
         match self.receiver.recv() {
             Ok(data_block) => data_block,
@@ -13,16 +13,21 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::pipelines::new::executor::CpuAffinity;
+use crate::pipelines::new::executor::ProfileCallback;
 use crate::pipelines::new::pipe::NewPipe;
 use crate::pipelines::new::pipe::TransformPipeBuilder;
 use crate::pipelines::new::processors::port::InputPort;
 use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::ResizeProcessor;
+use crate::pipelines::new::processors::SequenceMergeProcessor;
+use crate::pipelines::new::processors::TransformRepartition;
 
 /// The struct of new pipeline
 ///                                                                              +----------+
@@ -45,6 +50,10 @@ use crate::pipelines::new::processors::ResizeProcessor;
 ///
 pub struct NewPipeline {
     max_threads: usize,
+    max_execution_time: Option<Duration>,
+    cpu_affinity: CpuAffinity,
+    profile_callback: Option<ProfileCallback>,
+    preserve_order: bool,
     pub pipes: Vec<NewPipe>,
 }
 
@@ -52,6 +61,10 @@ impl NewPipeline {
     pub fn create() -> NewPipeline {
         NewPipeline {
             max_threads: 0,
+            max_execution_time: None,
+            cpu_affinity: CpuAffinity::None,
+            profile_callback: None,
+            preserve_order: false,
             pipes: Vec::new(),
         }
     }
@@ -118,6 +131,46 @@ impl NewPipeline {
         self.max_threads
     }
 
+    // The pipeline executor aborts the execution once it runs longer than the limit, 0 means no limit.
+    pub fn set_max_execution_time(&mut self, millis: u64) {
+        self.max_execution_time = match millis {
+            0 => None,
+            millis => Some(Duration::from_millis(millis)),
+        };
+    }
+
+    pub fn get_max_execution_time(&self) -> Option<Duration> {
+        self.max_execution_time
+    }
+
+    // The callback receives the profile of the processors once the pipeline executor is dropped.
+    pub fn set_profile_callback(&mut self, callback: ProfileCallback) {
+        self.profile_callback = Some(callback);
+    }
+
+    pub fn take_profile_callback(&mut self) -> Option<ProfileCallback> {
+        self.profile_callback.take()
+    }
+
+    // Once set, resizing to one output merges the parallel outputs in the order of the block
+    // sequence numbers instead of the order the blocks arrive.
+    pub fn set_preserve_order(&mut self, preserve_order: bool) {
+        self.preserve_order = preserve_order;
+    }
+
+    pub fn get_preserve_order(&self) -> bool {
+        self.preserve_order
+    }
+
+    pub fn set_cpu_affinity(&mut self, cpu_affinity: &[u8]) -> Result<()> {
+        self.cpu_affinity = String::from_utf8_lossy(cpu_affinity).parse()?;
+        Ok(())
+    }
+
+    pub fn get_cpu_affinity(&self) -> CpuAffinity {
+        self.cpu_affinity
+    }
+
     pub fn add_transform<F>(&mut self, f: F) -> Result<()>
     where F: Fn(Arc<InputPort>, Arc<OutputPort>) -> Result<ProcessorPtr> {
         let mut transform_builder = TransformPipeBuilder::create();
@@ -141,6 +194,17 @@ impl NewPipeline {
                 Err(ErrorCode::LogicalError("Cannot resize empty pipe."))
             }
             Some(pipe) if pipe.output_size() == new_size => Ok(()),
+            Some(pipe) if self.preserve_order && new_size == 1 => {
+                let processor = SequenceMergeProcessor::create(pipe.output_size());
+                let inputs_port = processor.get_inputs().to_vec();
+                let outputs_port = vec![processor.get_output().clone()];
+                self.pipes.push(NewPipe::ResizePipe {
+                    inputs_port,
+                    outputs_port,
+                    processor: ProcessorPtr::create(Box::new(processor)),
+                });
+                Ok(())
+            }
             Some(pipe) => {
                 let processor = ResizeProcessor::create(pipe.output_size(), new_size);
                 let inputs_port = processor.get_inputs().to_vec();
@@ -154,4 +218,30 @@ impl NewPipeline {
             }
         }
     }
+
+    /// Repartition the rows to `new_size` outputs by the hash of keys. Unlike `resize`, which
+    /// moves the whole blocks, the rows with the same keys always go to the same output.
+    pub fn repartition(&mut self, new_size: usize, keys: Vec<String>) -> Result<()> {
+        if keys.is_empty() {
+            return self.resize(new_size);
+        }
+
+        match self.pipes.last() {
+            None => Err(ErrorCode::LogicalError("Cannot repartition empty pipe.")),
+            Some(pipe) if pipe.output_size() == 0 => {
+                Err(ErrorCode::LogicalError("Cannot repartition empty pipe."))
+            }
+            Some(pipe) => {
+                let processor = TransformRepartition::create(pipe.output_size(), new_size, keys);
+                let inputs_port = processor.get_inputs().to_vec();
+                let outputs_port = processor.get_outputs().to_vec();
+                self.pipes.push(NewPipe::ResizePipe {
+                    inputs_port,
+                    outputs_port,
+                    processor: ProcessorPtr::create(Box::new(processor)),
+                });
+                Ok(())
+            }
+        }
+    }
 }
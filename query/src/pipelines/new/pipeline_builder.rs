@@ -38,6 +38,7 @@ use crate::pipelines::new::processors::AggregatorTransformParams;
 use crate::pipelines::new::processors::ExpressionTransform;
 use crate::pipelines::new::processors::ProjectionTransform;
 use crate::pipelines::new::processors::SubQueriesPuller;
+use crate::pipelines::new::processors::TopNCompactor;
 use crate::pipelines::new::processors::TransformAggregator;
 use crate::pipelines::new::processors::TransformCreateSets;
 use crate::pipelines::new::processors::TransformFilter;
@@ -46,29 +47,28 @@ use crate::pipelines::new::processors::TransformLimit;
 use crate::pipelines::new::processors::TransformLimitBy;
 use crate::pipelines::new::processors::TransformSortMerge;
 use crate::pipelines::new::processors::TransformSortPartial;
+use crate::pipelines::new::processors::TransformSortSpill;
+use crate::pipelines::new::processors::TransformTopN;
 use crate::pipelines::transforms::get_sort_descriptions;
 use crate::sessions::QueryContext;
+use crate::sessions::TempStorageReservation;
 /// Builder for query pipeline
 /// ```
-/// # let builder = QueryPipelineBuilder::create(ctx);
+/// # let builder = QueryPipelineBuilder::create(ctx)?;
 /// # let pipeline = builder.finalize(plan)?;
 /// ```
 pub struct QueryPipelineBuilder {
     ctx: Arc<QueryContext>,
     pipeline: NewPipeline,
-    limit: Option<usize>,
-    offset: usize,
 }
 
 impl QueryPipelineBuilder {
     /// Create a Builder from QueryContext, others params are default
-    pub fn create(ctx: Arc<QueryContext>) -> QueryPipelineBuilder {
-        QueryPipelineBuilder {
-            ctx,
-            pipeline: NewPipeline::create(),
-            limit: None,
-            offset: 0,
-        }
+    pub fn create(ctx: Arc<QueryContext>) -> Result<QueryPipelineBuilder> {
+        let mut pipeline = NewPipeline::create();
+        let settings = ctx.get_settings();
+        pipeline.set_preserve_order(settings.get_preserve_insertion_order()? != 0);
+        Ok(QueryPipelineBuilder { ctx, pipeline })
     }
     /// The core of generating the pipeline
     /// It will recursively visit the entire plan tree, and create a `SimplePipe` for each node,
@@ -101,6 +101,13 @@ impl PlanVisitor for QueryPipelineBuilder {
     fn visit_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<()> {
         self.visit_plan_node(&plan.input)?;
 
+        // Spread the work to all the cores even if the source has few partitions.
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        if self.pipeline.output_len() < max_threads {
+            let keys = plan.group_expr.iter().map(|expr| expr.column_name());
+            self.pipeline.repartition(max_threads, keys.collect())?;
+        }
+
         let aggregator_params = AggregatorParams::try_create(
             &plan.aggr_expr,
             &plan.group_expr,
@@ -209,8 +216,6 @@ impl PlanVisitor for QueryPipelineBuilder {
     }
 
     fn visit_limit(&mut self, plan: &LimitPlan) -> Result<()> {
-        self.limit = plan.n;
-        self.offset = plan.offset;
         self.visit_plan_node(&plan.input)?;
 
         self.pipeline.resize(1)?;
@@ -246,10 +251,21 @@ impl PlanVisitor for QueryPipelineBuilder {
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<()> {
         self.visit_plan_node(&plan.input)?;
 
-        // The number of rows should be limit + offset. For example, for the query
-        // 'select * from numbers(100) order by number desc limit 10 offset 5', the
+        // The limit is set by the TopN optimizer to limit + offset. For example, for the
+        // query 'select * from numbers(100) order by number desc limit 10 offset 5', the
         // sort pipeline should return at least 15 rows.
-        let rows_limit = self.limit.map(|limit| limit + self.offset);
+        let rows_limit = plan.limit;
+
+        if let Some(rows_limit) = rows_limit {
+            return self.build_top_n(plan, rows_limit);
+        }
+
+        let settings = self.ctx.get_settings();
+        let memory_budget =
+            settings.get_max_memory_usage()? * settings.get_sort_spilling_memory_ratio()? / 100;
+        if memory_budget != 0 {
+            return self.build_sort_spill(plan, memory_budget as usize);
+        }
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
@@ -298,6 +314,65 @@ impl PlanVisitor for QueryPipelineBuilder {
             })
     }
 
+    // The sorted blocks are merged in one processor, which spills sorted runs to disk once
+    // the buffered blocks exceed the memory budget.
+    fn build_sort_spill(&mut self, plan: &SortPlan, memory_budget: usize) -> Result<()> {
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformSortPartial::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    None,
+                    get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                )
+            })?;
+
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        self.pipeline.resize(1)?;
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformSortSpill::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                    memory_budget,
+                    block_size,
+                    TempStorageReservation::create(&self.ctx)?,
+                )
+            })
+    }
+
+    // ORDER BY ... LIMIT n only needs to keep the first n rows of each processor.
+    fn build_top_n(&mut self, plan: &SortPlan, rows_limit: usize) -> Result<()> {
+        // processor 1: block ---> top n rows
+        // processor 2: block ---> top n rows
+        // processor 3: block ---> top n rows
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformTopN::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    TopNCompactor::new(
+                        rows_limit,
+                        get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                    ),
+                )
+            })?;
+
+        self.pipeline.resize(1)?;
+        self.pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformTopN::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    TopNCompactor::new(
+                        rows_limit,
+                        get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                    ),
+                )
+            })
+    }
+
     fn visit_limit_by(&mut self, plan: &LimitByPlan) -> Result<()> {
         self.visit_plan_node(&plan.input)?;
 
@@ -17,7 +17,9 @@ pub mod processor;
 
 mod format;
 mod port_trigger;
+mod profile;
 mod resize_processor;
+mod sequence_merge_processor;
 mod sinks;
 mod sources;
 pub(crate) mod transforms;
@@ -28,7 +30,10 @@ pub use port_trigger::UpdateList;
 pub use port_trigger::UpdateTrigger;
 pub use processor::Processor;
 pub use processor::Processors;
+pub use profile::PortProfile;
+pub use profile::ProcessorProfile;
 pub use resize_processor::ResizeProcessor;
+pub use sequence_merge_processor::SequenceMergeProcessor;
 pub use sinks::AsyncSink;
 pub use sinks::AsyncSinker;
 pub use sinks::EmptySink;
@@ -38,13 +43,18 @@ pub use sinks::SyncSenderSink;
 pub use sources::AsyncSource;
 pub use sources::AsyncSourcer;
 pub use sources::BlocksSource;
+pub use sources::CteSource;
 pub use sources::EmptySource;
+pub use sources::MaterializedCte;
 pub use sources::StreamSource;
 pub use sources::StreamSourceV2;
 pub use sources::SyncReceiverCkSource;
 pub use sources::SyncReceiverSource;
 pub use sources::SyncSource;
 pub use sources::SyncSourcer;
+pub use sources::Tee;
+pub use sources::TeeProducer;
+pub use sources::TeeSource;
 pub use transforms::AggregatorParams;
 pub use transforms::AggregatorTransformParams;
 pub use transforms::BlockCompactor;
@@ -60,10 +70,15 @@ pub use transforms::KeyU512HashTable;
 pub use transforms::KeyU64HashTable;
 pub use transforms::KeyU8HashTable;
 pub use transforms::ProjectionTransform;
+pub use transforms::RuntimeFilter;
+pub use transforms::ScanRuntimeFilter;
 pub use transforms::SerializerHashTable;
+pub use transforms::SinkMergeJoinRight;
 pub use transforms::SinkBuildHashTable;
 pub use transforms::SortMergeCompactor;
+pub use transforms::SortMergeJoinState;
 pub use transforms::SubQueriesPuller;
+pub use transforms::TopNCompactor;
 pub use transforms::TransformAddOn;
 pub use transforms::TransformAggregator;
 pub use transforms::TransformApply;
@@ -71,12 +86,20 @@ pub use transforms::TransformBlockCompact;
 pub use transforms::TransformCastSchema;
 pub use transforms::TransformCompact;
 pub use transforms::TransformCreateSets;
+pub use transforms::TransformDistinct;
 pub use transforms::TransformDummy;
+pub use transforms::TransformExternalFunction;
 pub use transforms::TransformFilter;
 pub use transforms::TransformHashJoinProbe;
 pub use transforms::TransformHaving;
 pub use transforms::TransformLimit;
 pub use transforms::TransformLimitBy;
 pub use transforms::TransformMax1Row;
+pub use transforms::TransformMergeJoin;
+pub use transforms::TransformRepartition;
 pub use transforms::TransformSortMerge;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformSortSpill;
+pub use transforms::TransformTopN;
+pub use transforms::TransformWindow;
+pub use transforms::WindowCompactor;
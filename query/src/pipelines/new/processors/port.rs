@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_datablocks::DataBlock;
 use common_exception::Result;
 
+use crate::pipelines::new::processors::PortProfile;
 use crate::pipelines::new::processors::UpdateTrigger;
 use crate::pipelines::new::unsafe_cell_wrap::UnSafeCellWrap;
 
@@ -34,6 +35,7 @@ pub struct SharedData(pub Result<DataBlock>);
 
 pub struct SharedStatus {
     data: AtomicPtr<SharedData>,
+    profile: PortProfile,
 }
 
 unsafe impl Send for SharedStatus {}
@@ -42,6 +44,7 @@ impl SharedStatus {
     pub fn create() -> Arc<SharedStatus> {
         Arc::new(SharedStatus {
             data: AtomicPtr::new(std::ptr::null_mut()),
+            profile: PortProfile::default(),
         })
     }
 
@@ -106,6 +109,11 @@ impl SharedStatus {
     pub fn get_flags(&self) -> usize {
         self.data.load(Ordering::Relaxed) as usize & FLAGS_MASK
     }
+
+    #[inline(always)]
+    pub fn profile(&self) -> &PortProfile {
+        &self.profile
+    }
 }
 
 pub struct InputPort {
@@ -146,6 +154,7 @@ impl InputPort {
         unsafe {
             let flags = self.shared.set_flags(NEED_DATA, NEED_DATA);
             if flags & NEED_DATA == 0 {
+                self.shared.profile().on_need_data();
                 UpdateTrigger::update_input(&self.update_trigger);
             }
         }
@@ -161,6 +170,10 @@ impl InputPort {
         (self.shared.get_flags() & HAS_DATA) != 0
     }
 
+    pub fn profile(&self) -> &PortProfile {
+        self.shared.profile()
+    }
+
     #[inline(always)]
     pub fn pull_data(&self) -> Option<Result<DataBlock>> {
         unsafe {
@@ -168,7 +181,10 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    self.shared.profile().on_pull();
+                    Some((*Box::from_raw(address)).0)
+                }
             }
         }
     }
@@ -206,6 +222,8 @@ impl OutputPort {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
+            let rows = data.as_ref().map(|block| block.num_rows()).unwrap_or(0);
+            self.shared.profile().on_push(rows);
             let data = Box::into_raw(Box::new(SharedData(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
@@ -227,6 +245,10 @@ impl OutputPort {
         (self.shared.get_flags() & IS_FINISHED) != 0
     }
 
+    pub fn profile(&self) -> &PortProfile {
+        self.shared.profile()
+    }
+
     #[inline(always)]
     pub fn can_push(&self) -> bool {
         let flags = self.shared.get_flags();
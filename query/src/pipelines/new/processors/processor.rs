@@ -14,6 +14,7 @@
 
 use std::cell::UnsafeCell;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,8 @@ use futures::FutureExt;
 use petgraph::graph::node_index;
 use petgraph::prelude::NodeIndex;
 
+use crate::pipelines::new::processors::ProcessorProfile;
+
 pub enum Event {
     NeedData,
     NeedConsume,
@@ -52,6 +55,7 @@ pub trait Processor: Send {
 pub struct ProcessorPtr {
     id: Arc<UnsafeCell<NodeIndex>>,
     inner: Arc<UnsafeCell<Box<dyn Processor>>>,
+    profile: Arc<ProcessorProfile>,
 }
 
 unsafe impl Send for ProcessorPtr {}
@@ -63,6 +67,7 @@ impl ProcessorPtr {
         ProcessorPtr {
             id: Arc::new(UnsafeCell::new(node_index(0))),
             inner: Arc::new(UnsafeCell::new(inner)),
+            profile: Arc::new(ProcessorProfile::default()),
         }
     }
 
@@ -88,12 +93,27 @@ impl ProcessorPtr {
 
     /// # Safety
     pub unsafe fn process(&self) -> Result<()> {
-        (*self.inner.get()).process()
+        let start = Instant::now();
+        let res = (*self.inner.get()).process();
+        self.profile.add_cpu_time(start.elapsed());
+        res
     }
 
     /// # Safety
     pub unsafe fn async_process(&self) -> BoxFuture<'static, Result<()>> {
-        (*self.inner.get()).async_process().boxed()
+        let profile = self.profile.clone();
+        let future = (*self.inner.get()).async_process();
+        async move {
+            let start = Instant::now();
+            let res = future.await;
+            profile.add_async_time(start.elapsed());
+            res
+        }
+        .boxed()
+    }
+
+    pub fn profile(&self) -> &ProcessorProfile {
+        &self.profile
     }
 }
 
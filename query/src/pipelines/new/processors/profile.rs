@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PROFILE_EPOCH: Instant = Instant::now();
+}
+
+// Nanoseconds since the profile epoch, never 0 which means unset.
+fn now_nanos() -> u64 {
+    (PROFILE_EPOCH.elapsed().as_nanos() as u64).max(1)
+}
+
+/// The time a processor spent in the synchronous and the asynchronous work.
+#[derive(Default)]
+pub struct ProcessorProfile {
+    cpu_time: AtomicU64,
+    async_time: AtomicU64,
+}
+
+impl ProcessorProfile {
+    pub fn add_cpu_time(&self, elapsed: Duration) {
+        self.cpu_time
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_async_time(&self, elapsed: Duration) {
+        self.async_time
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn cpu_time(&self) -> Duration {
+        Duration::from_nanos(self.cpu_time.load(Ordering::Relaxed))
+    }
+
+    pub fn async_time(&self) -> Duration {
+        Duration::from_nanos(self.async_time.load(Ordering::Relaxed))
+    }
+}
+
+/// The statistics of the data passed through a port, shared by the connected input and output.
+///
+/// The port holds at most one block. The full time is how long the pushed blocks waited for
+/// the consumer, the upstream stalls on it. The empty time is how long the consumer waited for
+/// the data it needed, the downstream stalls on it.
+#[derive(Default)]
+pub struct PortProfile {
+    blocks: AtomicU64,
+    rows: AtomicU64,
+    pushed_at: AtomicU64,
+    needed_at: AtomicU64,
+    full_time: AtomicU64,
+    empty_time: AtomicU64,
+}
+
+impl PortProfile {
+    pub fn on_need_data(&self) {
+        let _ =
+            self.needed_at
+                .compare_exchange(0, now_nanos(), Ordering::Relaxed, Ordering::Relaxed);
+    }
+
+    pub fn on_push(&self, rows: usize) {
+        let now = now_nanos();
+        self.blocks.fetch_add(1, Ordering::Relaxed);
+        self.rows.fetch_add(rows as u64, Ordering::Relaxed);
+
+        let needed_at = self.needed_at.swap(0, Ordering::Relaxed);
+        if needed_at != 0 {
+            self.empty_time
+                .fetch_add(now.saturating_sub(needed_at), Ordering::Relaxed);
+        }
+        self.pushed_at.store(now, Ordering::Relaxed);
+    }
+
+    pub fn on_pull(&self) {
+        let pushed_at = self.pushed_at.swap(0, Ordering::Relaxed);
+        if pushed_at != 0 {
+            self.full_time
+                .fetch_add(now_nanos().saturating_sub(pushed_at), Ordering::Relaxed);
+        }
+    }
+
+    pub fn blocks(&self) -> u64 {
+        self.blocks.load(Ordering::Relaxed)
+    }
+
+    pub fn rows(&self) -> u64 {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    pub fn full_time(&self) -> Duration {
+        Duration::from_nanos(self.full_time.load(Ordering::Relaxed))
+    }
+
+    pub fn empty_time(&self) -> Duration {
+        Duration::from_nanos(self.empty_time.load(Ordering::Relaxed))
+    }
+}
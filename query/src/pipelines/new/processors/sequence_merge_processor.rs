@@ -0,0 +1,137 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::Processor;
+
+/// Merges the outputs of parallel transforms into one output in the order of the block
+/// sequence numbers assigned by the sources.
+///
+/// The blocks of each input are in ascending sequence order, so the processor only pushes
+/// the block with the smallest sequence number when every unfinished input has a block
+/// pending. Blocks without sequence number are pushed as soon as they arrive.
+pub struct SequenceMergeProcessor {
+    inputs: Vec<Arc<InputPort>>,
+    output: Arc<OutputPort>,
+
+    pending: Vec<Option<DataBlock>>,
+}
+
+impl SequenceMergeProcessor {
+    pub fn create(inputs: usize) -> Self {
+        let mut inputs_port = Vec::with_capacity(inputs);
+        for _index in 0..inputs {
+            inputs_port.push(InputPort::create());
+        }
+
+        SequenceMergeProcessor {
+            inputs: inputs_port,
+            output: OutputPort::create(),
+            pending: vec![None; inputs],
+        }
+    }
+
+    pub fn get_inputs(&self) -> &[Arc<InputPort>] {
+        &self.inputs
+    }
+
+    pub fn get_output(&self) -> &Arc<OutputPort> {
+        &self.output
+    }
+
+    fn finish_inputs(&mut self) {
+        for input in &self.inputs {
+            input.finish();
+        }
+    }
+
+    // Returns the index of the pending block with the smallest sequence number, or `None` if
+    // some unfinished input has no pending block yet.
+    fn next_pending(&self) -> Option<Option<usize>> {
+        let mut next: Option<(usize, u64)> = None;
+        for (index, pending) in self.pending.iter().enumerate() {
+            match pending {
+                None if self.inputs[index].is_finished() => continue,
+                None => return None,
+                Some(block) => {
+                    let sequence = block.sequence().unwrap_or_default();
+                    if next.map_or(true, |(_, min)| sequence < min) {
+                        next = Some((index, sequence));
+                    }
+                }
+            }
+        }
+
+        Some(next.map(|(index, _)| index))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for SequenceMergeProcessor {
+    fn name(&self) -> &'static str {
+        "SequenceMerge"
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.finish_inputs();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        for (index, input) in self.inputs.iter().enumerate() {
+            if self.pending[index].is_some() || !input.has_data() {
+                continue;
+            }
+
+            match input.pull_data().unwrap() {
+                Ok(block) if block.sequence().is_some() => self.pending[index] = Some(block),
+                data => {
+                    self.output.push_data(data);
+                    return Ok(Event::NeedConsume);
+                }
+            }
+        }
+
+        match self.next_pending() {
+            Some(Some(index)) => {
+                let block = self.pending[index].take().unwrap();
+                self.output.push_data(Ok(block));
+                Ok(Event::NeedConsume)
+            }
+            Some(None) => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            None => {
+                for (index, input) in self.inputs.iter().enumerate() {
+                    if self.pending[index].is_none() {
+                        input.set_need_data();
+                    }
+                }
+                Ok(Event::NeedData)
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::pipelines::new::executor::PipelineExecutor;
+use crate::pipelines::new::executor::PipelinePullingExecutor;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::new::processors::Tee;
+use crate::pipelines::new::processors::TeeProducer;
+use crate::pipelines::new::NewPipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TempStorageReservation;
+use crate::sql::exec::format_field_name;
+use crate::sql::exec::PipelineBuilder;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::CteScan;
+use crate::sql::IndexType;
+use crate::sql::MetadataRef;
+
+/// The result of a common table expression, computed on the first read and shared by
+/// all the `CteSource`s of the same CTE.
+///
+/// Blocks are stored positionally, i.e. the n-th column of a block is the n-th column
+/// of the CTE, so that they can be relabeled with the schema of every `CteScan`.
+pub struct MaterializedCte {
+    ctx: Arc<QueryContext>,
+    metadata: MetadataRef,
+    plan: CteScan,
+    anchor: SExpr,
+    recursive_term: Option<SExpr>,
+    blocks: Mutex<Option<Arc<Vec<DataBlock>>>>,
+    tee: Mutex<Option<Arc<Tee>>>,
+}
+
+impl MaterializedCte {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        metadata: MetadataRef,
+        plan: CteScan,
+        s_expr: &SExpr,
+    ) -> Result<Arc<MaterializedCte>> {
+        let anchor = s_expr.child(0)?.clone();
+        let recursive_term = match plan.has_recursive_term() {
+            true => Some(s_expr.child(1)?.clone()),
+            false => None,
+        };
+        Ok(Arc::new(MaterializedCte {
+            ctx,
+            metadata,
+            plan,
+            anchor,
+            recursive_term,
+            blocks: Mutex::new(None),
+            tee: Mutex::new(None),
+        }))
+    }
+
+    /// The blocks of a CTE without recursive term are streamed to all the `CteScan`s by a
+    /// `Tee`, which executes the CTE on the first read.
+    pub fn get_tee(&self) -> Result<Arc<Tee>> {
+        let mut guard = self.tee.lock();
+        if let Some(tee) = guard.as_ref() {
+            return Ok(tee.clone());
+        }
+
+        let settings = self.ctx.get_settings();
+        let memory_budget =
+            settings.get_max_memory_usage()? * settings.get_shared_scan_spilling_ratio()? / 100;
+        let producer = CteProducer {
+            ctx: self.ctx.clone(),
+            metadata: self.metadata.clone(),
+            plan: self.plan.clone(),
+            anchor: self.anchor.clone(),
+            executor: None,
+        };
+        let tee = Tee::create(
+            Box::new(producer),
+            memory_budget as usize,
+            TempStorageReservation::create(&self.ctx)?,
+        );
+        *guard = Some(tee.clone());
+        Ok(tee)
+    }
+
+    pub fn get_blocks(&self) -> Result<Arc<Vec<DataBlock>>> {
+        let mut guard = self.blocks.lock();
+        if let Some(blocks) = guard.as_ref() {
+            return Ok(blocks.clone());
+        }
+
+        let blocks = Arc::new(self.materialize()?);
+        *guard = Some(blocks.clone());
+        Ok(blocks)
+    }
+
+    fn materialize(&self) -> Result<Vec<DataBlock>> {
+        let mut results = self.execute(&self.anchor, &self.plan.anchor_columns, None)?;
+        let recursive_term = match &self.recursive_term {
+            Some(recursive_term) => recursive_term,
+            None => return Ok(results),
+        };
+
+        let max_depth = self.ctx.get_settings().get_max_recursion_depth()?;
+        let mut working_table = results.clone();
+        let mut depth = 0;
+        loop {
+            depth += 1;
+            if depth > max_depth {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Recursive CTE '{}' exceeded max_recursion_depth: {}",
+                    self.metadata.read().cte_name(self.plan.cte_index),
+                    max_depth
+                )));
+            }
+
+            let blocks = self.execute(
+                recursive_term,
+                &self.plan.recursive_columns,
+                Some(working_table),
+            )?;
+            if blocks.iter().all(|block| block.num_rows() == 0) {
+                break;
+            }
+            results.extend(blocks.iter().cloned());
+
+            // The recursive term is evaluated only once if it doesn't read the working table.
+            if !self.plan.recursive {
+                break;
+            }
+            working_table = blocks;
+        }
+
+        Ok(results)
+    }
+
+    fn execute(
+        &self,
+        s_expr: &SExpr,
+        columns: &[IndexType],
+        working_table: Option<Vec<DataBlock>>,
+    ) -> Result<Vec<DataBlock>> {
+        let (mut executor, schema) = start_executor(
+            &self.ctx,
+            &self.metadata,
+            &self.plan,
+            s_expr,
+            columns,
+            working_table,
+        )?;
+
+        let mut results = vec![];
+        while let Some(block) = executor.pull_data()? {
+            results.push(project_block(&schema, &block)?);
+        }
+
+        Ok(results)
+    }
+}
+
+/// Pulls the blocks of a CTE without recursive term for a `Tee`.
+struct CteProducer {
+    ctx: Arc<QueryContext>,
+    metadata: MetadataRef,
+    plan: CteScan,
+    anchor: SExpr,
+    executor: Option<(PipelinePullingExecutor, DataSchemaRef)>,
+}
+
+impl TeeProducer for CteProducer {
+    fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.executor.is_none() {
+            self.executor = Some(start_executor(
+                &self.ctx,
+                &self.metadata,
+                &self.plan,
+                &self.anchor,
+                &self.plan.anchor_columns,
+                None,
+            )?);
+        }
+
+        let (executor, schema) = self.executor.as_mut().unwrap();
+        match executor.pull_data()? {
+            None => Ok(None),
+            Some(block) => Ok(Some(project_block(schema, &block)?)),
+        }
+    }
+}
+
+// Build the pipeline of `s_expr` and start to execute it, returns the executor and the schema
+// of the CTE columns.
+fn start_executor(
+    ctx: &Arc<QueryContext>,
+    metadata: &MetadataRef,
+    plan: &CteScan,
+    s_expr: &SExpr,
+    columns: &[IndexType],
+    working_table: Option<Vec<DataBlock>>,
+) -> Result<(PipelinePullingExecutor, DataSchemaRef)> {
+    let mut pipeline = NewPipeline::create();
+    let mut pb = PipelineBuilder::new(ctx.clone(), vec![], metadata.clone(), s_expr.clone());
+    if let Some(working_table) = working_table {
+        pb.set_working_table(plan.cte_index, working_table);
+    }
+    pb.build_pipeline(
+        QueryContext::create_from(ctx.clone()),
+        s_expr,
+        &mut pipeline,
+    )?;
+
+    let mut children = pb.pipelines;
+
+    // Set max threads
+    let settings = ctx.get_settings();
+    pipeline.set_max_threads(settings.get_max_threads()? as usize);
+    pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+    pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
+    for pipeline in children.iter_mut() {
+        pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+        pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
+    }
+
+    let runtime = ctx.get_storage_runtime();
+    // Spawn sub-pipelines
+    for pipeline in children {
+        let executor = PipelineExecutor::create(runtime.clone(), pipeline)?;
+        executor.execute()?;
+    }
+
+    let fields: Vec<DataField> = {
+        let metadata = metadata.read();
+        columns
+            .iter()
+            .map(|index| {
+                let column = metadata.column(*index);
+                DataField::new(
+                    format_field_name(column.name.as_str(), *index).as_str(),
+                    column.data_type.clone(),
+                )
+            })
+            .collect()
+    };
+    let schema = DataSchemaRefExt::create(fields);
+
+    let mut executor = PipelinePullingExecutor::try_create(runtime, pipeline)?;
+    executor.start();
+    Ok((executor, schema))
+}
+
+// Relabel the columns of a block in the order of the CTE columns.
+fn project_block(schema: &DataSchemaRef, block: &DataBlock) -> Result<DataBlock> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| block.try_column_by_name(field.name()).cloned())
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataBlock::create(schema.clone(), columns))
+}
+
+/// Emits the blocks of a materialized CTE, or of the working table of a recursive CTE,
+/// relabeled with the schema of the scan.
+pub struct CteSource {
+    schema: DataSchemaRef,
+    cte: Option<Arc<MaterializedCte>>,
+    blocks: Option<Arc<Vec<DataBlock>>>,
+    pos: usize,
+}
+
+impl CteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        cte: Arc<MaterializedCte>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, CteSource {
+            schema,
+            cte: Some(cte),
+            blocks: None,
+            pos: 0,
+        })
+    }
+
+    pub fn create_working_table(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        blocks: Arc<Vec<DataBlock>>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, CteSource {
+            schema,
+            cte: None,
+            blocks: Some(blocks),
+            pos: 0,
+        })
+    }
+}
+
+impl SyncSource for CteSource {
+    const NAME: &'static str = "CteSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_none() {
+            if let Some(cte) = &self.cte {
+                self.blocks = Some(cte.get_blocks()?);
+            }
+        }
+
+        let blocks = match &self.blocks {
+            Some(blocks) => blocks,
+            None => return Ok(None),
+        };
+        match blocks.get(self.pos) {
+            None => Ok(None),
+            Some(block) => {
+                self.pos += 1;
+                Ok(Some(DataBlock::create(
+                    self.schema.clone(),
+                    block.columns().to_vec(),
+                )))
+            }
+        }
+    }
+}
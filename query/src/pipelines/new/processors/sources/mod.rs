@@ -14,16 +14,20 @@
 
 mod async_source;
 mod blocks_source;
+mod cte_source;
 mod empty_source;
 mod stream_source;
 mod stream_source_v2;
 mod sync_ck_source_receiver;
 mod sync_source;
 mod sync_source_receiver;
+mod tee_source;
 
 pub use async_source::AsyncSource;
 pub use async_source::AsyncSourcer;
 pub use blocks_source::BlocksSource;
+pub use cte_source::CteSource;
+pub use cte_source::MaterializedCte;
 pub use empty_source::EmptySource;
 pub use stream_source::StreamSource;
 pub use stream_source_v2::StreamSourceV2;
@@ -31,6 +35,9 @@ pub use sync_ck_source_receiver::SyncReceiverCkSource;
 pub use sync_source::SyncSource;
 pub use sync_source::SyncSourcer;
 pub use sync_source_receiver::SyncReceiverSource;
+pub use tee_source::Tee;
+pub use tee_source::TeeProducer;
+pub use tee_source::TeeSource;
 
 #[allow(dead_code)]
 mod source_example {
@@ -0,0 +1,284 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_tracing::tracing;
+use uuid::Uuid;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::sessions::QueryContext;
+use crate::sessions::TempStorageReservation;
+
+/// The producer of the blocks teed by a `Tee`.
+pub trait TeeProducer: Send {
+    fn next_block(&mut self) -> Result<Option<DataBlock>>;
+}
+
+/// Pulls the blocks from the producer on demand and tees them to all the consumers.
+///
+/// A block is kept until all the consumers have read it, e.g. the probe side of a join reads
+/// the blocks after the build side has read all of them. The blocks exceeding the memory
+/// budget are spilled to local disk, 0 means all the blocks are kept in memory.
+pub struct Tee {
+    state: Mutex<TeeState>,
+}
+
+struct TeeState {
+    producer: Box<dyn TeeProducer>,
+    finished: bool,
+    // Index of the first buffered block.
+    first: usize,
+    blocks: VecDeque<TeeBlock>,
+    // Index of the next block to read of each consumer.
+    cursors: Vec<usize>,
+
+    memory_budget: usize,
+    memory_bytes: usize,
+    spill_prefix: String,
+    temp_storage: TempStorageReservation,
+}
+
+enum TeeBlock {
+    Memory(DataBlock),
+    Spilled(SpilledBlock),
+}
+
+struct SpilledBlock {
+    path: PathBuf,
+    schema: DataSchemaRef,
+    bytes: u64,
+}
+
+impl Tee {
+    pub fn create(
+        producer: Box<dyn TeeProducer>,
+        memory_budget: usize,
+        temp_storage: TempStorageReservation,
+    ) -> Arc<Tee> {
+        Arc::new(Tee {
+            state: Mutex::new(TeeState {
+                producer,
+                finished: false,
+                first: 0,
+                blocks: VecDeque::new(),
+                cursors: vec![],
+                memory_budget,
+                memory_bytes: 0,
+                spill_prefix: format!("tee-{}", Uuid::new_v4().to_simple()),
+                temp_storage,
+            }),
+        })
+    }
+
+    /// Registers a consumer before the blocks are read, returns the id of the consumer.
+    pub fn add_consumer(&self) -> usize {
+        let mut state = self.state.lock();
+        state.cursors.push(0);
+        state.cursors.len() - 1
+    }
+
+    pub fn read(&self, consumer: usize) -> Result<Option<DataBlock>> {
+        let mut state = self.state.lock();
+        let pos = state.cursors[consumer];
+
+        let block = if pos < state.first + state.blocks.len() {
+            let index = pos - state.first;
+            state.load(index)?
+        } else if state.finished {
+            return Ok(None);
+        } else {
+            match state.producer.next_block()? {
+                None => {
+                    state.finished = true;
+                    return Ok(None);
+                }
+                Some(block) => {
+                    state.push(consumer, pos, &block)?;
+                    block
+                }
+            }
+        };
+
+        state.cursors[consumer] += 1;
+        state.trim();
+        Ok(Some(block))
+    }
+}
+
+impl TeeState {
+    fn load(&self, index: usize) -> Result<DataBlock> {
+        match &self.blocks[index] {
+            TeeBlock::Memory(block) => Ok(block.clone()),
+            TeeBlock::Spilled(spilled) => spilled.read(),
+        }
+    }
+
+    // Buffer the block at `pos` for the other consumers.
+    fn push(&mut self, consumer: usize, pos: usize, block: &DataBlock) -> Result<()> {
+        let needed = self
+            .cursors
+            .iter()
+            .enumerate()
+            .any(|(id, cursor)| id != consumer && *cursor <= pos);
+        if !needed {
+            // Nothing is buffered, all the other consumers have read the blocks before.
+            self.first += 1;
+            return Ok(());
+        }
+
+        let bytes = block.memory_size();
+        if self.memory_budget == 0 || self.memory_bytes + bytes <= self.memory_budget {
+            self.memory_bytes += bytes;
+            self.blocks.push_back(TeeBlock::Memory(block.clone()));
+            return Ok(());
+        }
+
+        let path = self
+            .temp_storage
+            .file_path(&format!("{}-{}", self.spill_prefix, pos));
+        let spilled = SpilledBlock::write(path, block, &mut self.temp_storage)?;
+        self.blocks.push_back(TeeBlock::Spilled(spilled));
+        Ok(())
+    }
+
+    // Drop the blocks read by all the consumers.
+    fn trim(&mut self) {
+        let min_cursor = self.cursors.iter().min().cloned().unwrap_or_default();
+        while self.first < min_cursor {
+            match self.blocks.pop_front() {
+                None => break,
+                Some(TeeBlock::Memory(block)) => self.memory_bytes -= block.memory_size(),
+                Some(TeeBlock::Spilled(spilled)) => self.temp_storage.release(spilled.bytes),
+            }
+            self.first += 1;
+        }
+    }
+}
+
+impl SpilledBlock {
+    fn write(
+        path: PathBuf,
+        block: &DataBlock,
+        temp_storage: &mut TempStorageReservation,
+    ) -> Result<SpilledBlock> {
+        let mut spilled = SpilledBlock {
+            path,
+            schema: block.schema().clone(),
+            bytes: 0,
+        };
+
+        let columns = block.columns().iter().collect::<Vec<_>>();
+        let rows = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+        let mut writer = BufWriter::new(File::create(&spilled.path)?);
+        for row in rows.iter() {
+            let bytes = 8 + row.len() as u64;
+            temp_storage.reserve(bytes)?;
+            spilled.bytes += bytes;
+
+            writer.write_all(&(row.len() as u64).to_le_bytes())?;
+            writer.write_all(row.as_slice())?;
+        }
+        writer.flush()?;
+        Ok(spilled)
+    }
+
+    fn read(&self) -> Result<DataBlock> {
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        let mut rows = vec![];
+        loop {
+            let mut len = [0u8; 8];
+            match reader.read_exact(&mut len) {
+                Ok(_) => {}
+                Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => break,
+                Err(cause) => return Err(cause.into()),
+            }
+
+            let mut row = vec![0u8; u64::from_le_bytes(len) as usize];
+            reader.read_exact(&mut row)?;
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Ok(DataBlock::empty_with_schema(self.schema.clone()));
+        }
+
+        let method = HashMethodSerializer::default();
+        let columns = method.deserialize_group_columns(rows, self.schema.fields())?;
+        Ok(DataBlock::create(self.schema.clone(), columns))
+    }
+}
+
+impl Drop for SpilledBlock {
+    fn drop(&mut self) {
+        if let Err(cause) = std::fs::remove_file(&self.path) {
+            if cause.kind() != ErrorKind::NotFound {
+                tracing::warn!("Cannot remove tee spill file {:?}, {}", self.path, cause);
+            }
+        }
+    }
+}
+
+/// Emits the blocks teed to a consumer, relabeled with the schema of the consumer.
+pub struct TeeSource {
+    schema: DataSchemaRef,
+    tee: Arc<Tee>,
+    consumer: usize,
+}
+
+impl TeeSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        tee: Arc<Tee>,
+    ) -> Result<ProcessorPtr> {
+        let consumer = tee.add_consumer();
+        SyncSourcer::create(ctx, output, TeeSource {
+            schema,
+            tee,
+            consumer,
+        })
+    }
+}
+
+impl SyncSource for TeeSource {
+    const NAME: &'static str = "TeeSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        match self.tee.read(self.consumer)? {
+            None => Ok(None),
+            Some(block) => Ok(Some(DataBlock::create(
+                self.schema.clone(),
+                block.columns().to_vec(),
+            ))),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::borrow::BorrowMut;
+use std::path::Path;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -26,15 +27,19 @@ use common_datablocks::HashMethodKeysU64;
 use common_datablocks::HashMethodKeysU8;
 use common_datablocks::HashMethodSerializer;
 use common_datablocks::HashMethodSingleString;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_datavalues::MutableColumn;
 use common_datavalues::ScalarColumn;
 use common_datavalues::Series;
 use common_datavalues::StringColumn;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
+use common_tracing::tracing;
 
+use crate::pipelines::new::processors::transforms::spiller::Spiller;
 use crate::pipelines::new::processors::transforms::transform_aggregator::Aggregator;
 use crate::pipelines::new::processors::AggregatorParams;
 use crate::pipelines::transforms::group_by::AggregatorState;
@@ -43,6 +48,7 @@ use crate::pipelines::transforms::group_by::KeysColumnIter;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
 use crate::pipelines::transforms::group_by::StateEntity;
 use crate::sessions::QueryContext;
+use crate::sessions::TempStorageReservation;
 
 pub type KeysU8FinalAggregator<const HAS_AGG: bool> = FinalAggregator<HAS_AGG, HashMethodKeysU8>;
 pub type KeysU16FinalAggregator<const HAS_AGG: bool> = FinalAggregator<HAS_AGG, HashMethodKeysU16>;
@@ -61,6 +67,12 @@ pub type SingleStringFinalAggregator<const HAS_AGG: bool> =
 pub type SerializerFinalAggregator<const HAS_AGG: bool> =
     FinalAggregator<HAS_AGG, HashMethodSerializer>;
 
+/// Merge the partial aggregation states by the group keys.
+///
+/// Once the memory usage of the query exceeds `max_memory_usage`, the groups in memory keep
+/// merging while the rows of unseen groups are spilled to partitions on local disk by the hash
+/// of the group key. After the groups in memory are output, each spilled partition is merged
+/// and output on its own.
 pub struct FinalAggregator<
     const HAS_AGG: bool,
     Method: HashMethod + PolymorphicKeysHelper<Method> + Send,
@@ -74,6 +86,10 @@ pub struct FinalAggregator<
     // used for deserialization only, so we can reuse it during the loop
     temp_place: Option<StateAddr>,
     ctx: Arc<QueryContext>,
+
+    spiller: Option<Spiller>,
+    // The schema of the spilled rows, which is the schema of the partial aggregation.
+    spilled_schema: Option<DataSchemaRef>,
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + Send>
@@ -99,8 +115,114 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
             params,
             temp_place,
             ctx,
+            spiller: None,
+            spilled_schema: None,
         })
     }
+
+    // Spill the rows whose group keys are not in memory once the memory limit is exceeded,
+    // returns the rest rows to merge.
+    fn spill_unseen_keys(
+        &mut self,
+        block: DataBlock,
+        key_index: usize,
+    ) -> Result<Option<DataBlock>> {
+        if self.spiller.is_none() {
+            let max_memory_usage = self.ctx.get_settings().get_max_memory_usage()? as usize;
+            if max_memory_usage == 0 || self.ctx.get_memory_usage() <= max_memory_usage {
+                return Ok(Some(block));
+            }
+
+            tracing::info!(
+                "Aggregation exceeds the memory limit {} bytes, spill the unseen groups to disk",
+                max_memory_usage
+            );
+            let temp_storage = TempStorageReservation::create(&self.ctx)?;
+            self.spiller = Some(Spiller::try_create("aggregate", true, temp_storage)?);
+        }
+
+        let mut indices = Vec::with_capacity(block.num_rows());
+        let mut spill_indices = vec![];
+        {
+            let keys_iter = self.method.keys_iter_from_column(block.column(key_index))?;
+            for (row, key) in keys_iter.get_slice().iter().enumerate() {
+                match self.state.contains_key(key) {
+                    true => indices.push(row as u32),
+                    false => spill_indices.push(row),
+                }
+            }
+        }
+
+        if !spill_indices.is_empty() {
+            if self.spilled_schema.is_none() {
+                self.spilled_schema = Some(block.schema().clone());
+            }
+
+            let serializer = HashMethodSerializer::default();
+            let keys = serializer.build_keys(&[block.column(key_index)], block.num_rows())?;
+            let columns = block.columns().iter().collect::<Vec<_>>();
+            let rows = serializer.build_keys(&columns, block.num_rows())?;
+
+            let spiller = self.spiller.as_mut().unwrap();
+            for row in spill_indices {
+                spiller.write(keys[row].as_slice(), Some(rows[row].as_slice()))?;
+            }
+        }
+
+        match indices.len() {
+            0 => Ok(None),
+            len if len == block.num_rows() => Ok(Some(block)),
+            _ => Ok(Some(DataBlock::block_take_by_indices(&block, &indices)?)),
+        }
+    }
+
+    // Read the rows of the next spilled partition.
+    fn next_spilled_block(&mut self) -> Result<Option<DataBlock>> {
+        let spiller = match &mut self.spiller {
+            None => return Ok(None),
+            Some(spiller) => spiller,
+        };
+
+        while let Some(path) = spiller.next_partition()? {
+            if let Some(block) = Self::read_partition(spiller, &self.spilled_schema, &path)? {
+                return Ok(Some(block));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn read_partition(
+        spiller: &mut Spiller,
+        schema: &Option<DataSchemaRef>,
+        path: &Path,
+    ) -> Result<Option<DataBlock>> {
+        let records = spiller.read(path)?;
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        let schema = schema.as_ref().ok_or_else(|| {
+            ErrorCode::LogicalError("The schema of spilled aggregation rows is unknown")
+        })?;
+
+        let rows = records.into_iter().filter_map(|(_, row)| row).collect();
+        let columns =
+            HashMethodSerializer::default().deserialize_group_columns(rows, schema.fields())?;
+        Ok(Some(DataBlock::create(schema.clone(), columns)))
+    }
+
+    // Drop the groups which are output and start over with an empty state.
+    fn reset_state(&mut self) {
+        self.drop_states();
+        self.state = self.method.aggregate_state();
+        self.temp_place = match self.params.aggregate_functions.is_empty() {
+            true => None,
+            false => self.state.alloc_layout2(&self.params),
+        };
+        self.states_dropped = false;
+        self.is_generated = false;
+    }
 }
 
 impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
@@ -140,6 +262,35 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        let key_index = self.params.aggregate_functions.len();
+        match self.spill_unseen_keys(block, key_index)? {
+            None => Ok(()),
+            Some(block) => self.merge_block(block),
+        }
+    }
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if self.state.len() != 0 && !self.is_generated {
+                return self.generate_block().map(Some);
+            }
+
+            match self.next_spilled_block()? {
+                None => {
+                    self.drop_states();
+                    return Ok(None);
+                }
+                Some(block) => {
+                    self.reset_state();
+                    self.merge_block(block)?;
+                }
+            }
+        }
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
+    fn merge_block(&mut self, block: DataBlock) -> Result<()> {
         // 1.1 and 1.2.
         let aggregate_function_len = self.params.aggregate_functions.len();
         let keys_column = block.column(aggregate_function_len);
@@ -182,56 +333,47 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         Ok(())
     }
 
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.state.len() == 0 || self.is_generated {
-            true => {
-                self.drop_states();
-                Ok(None)
-            }
-            false => {
-                self.is_generated = true;
-                let mut group_columns_builder = self
-                    .method
-                    .group_columns_builder(self.state.len(), &self.params);
-
-                let aggregate_functions = &self.params.aggregate_functions;
-                let offsets_aggregate_states = &self.params.offsets_aggregate_states;
-
-                let mut aggregates_column_builder: Vec<Box<dyn MutableColumn>> = {
-                    let mut values = vec![];
-                    for aggregate_function in aggregate_functions {
-                        let builder = aggregate_function.return_type()?.create_mutable(1024);
-                        values.push(builder)
-                    }
-                    values
-                };
+    fn generate_block(&mut self) -> Result<DataBlock> {
+        self.is_generated = true;
+        let mut group_columns_builder = self
+            .method
+            .group_columns_builder(self.state.len(), &self.params);
 
-                for group_entity in self.state.iter() {
-                    let place: StateAddr = (*group_entity.get_state_value()).into();
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
 
-                    for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                        let arg_place = place.next(offsets_aggregate_states[idx]);
-                        let builder: &mut dyn MutableColumn =
-                            aggregates_column_builder[idx].borrow_mut();
-                        aggregate_function.merge_result(arg_place, builder)?;
-                    }
+        let mut aggregates_column_builder: Vec<Box<dyn MutableColumn>> = {
+            let mut values = vec![];
+            for aggregate_function in aggregate_functions {
+                let builder = aggregate_function.return_type()?.create_mutable(1024);
+                values.push(builder)
+            }
+            values
+        };
 
-                    group_columns_builder.append_value(group_entity.get_state_key());
-                }
+        for group_entity in self.state.iter() {
+            let place: StateAddr = (*group_entity.get_state_value()).into();
 
-                // Build final state block.
-                let fields_len = self.params.schema.fields().len();
-                let mut columns = Vec::with_capacity(fields_len);
+            for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(offsets_aggregate_states[idx]);
+                let builder: &mut dyn MutableColumn = aggregates_column_builder[idx].borrow_mut();
+                aggregate_function.merge_result(arg_place, builder)?;
+            }
 
-                for mut array in aggregates_column_builder {
-                    columns.push(array.to_column());
-                }
+            group_columns_builder.append_value(group_entity.get_state_key());
+        }
 
-                columns.extend_from_slice(&group_columns_builder.finish()?);
+        // Build final state block.
+        let fields_len = self.params.schema.fields().len();
+        let mut columns = Vec::with_capacity(fields_len);
 
-                Ok(Some(DataBlock::create(self.params.schema.clone(), columns)))
-            }
+        for mut array in aggregates_column_builder {
+            columns.push(array.to_column());
         }
+
+        columns.extend_from_slice(&group_columns_builder.finish()?);
+
+        Ok(DataBlock::create(self.params.schema.clone(), columns))
     }
 }
 
@@ -241,6 +383,31 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        match self.spill_unseen_keys(block, 0)? {
+            None => Ok(()),
+            Some(block) => self.merge_block(block),
+        }
+    }
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if self.state.len() != 0 && !self.is_generated {
+                return self.generate_block().map(Some);
+            }
+
+            match self.next_spilled_block()? {
+                None => return Ok(None),
+                Some(block) => {
+                    self.reset_state();
+                    self.merge_block(block)?;
+                }
+            }
+        }
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<false, Method> {
+    fn merge_block(&mut self, block: DataBlock) -> Result<()> {
         let key_array = block.column(0);
         let keys_iter = self.method.keys_iter_from_column(key_array)?;
 
@@ -258,22 +425,17 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         Ok(())
     }
 
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.state.len() == 0 || self.is_generated {
-            true => Ok(None),
-            false => {
-                self.is_generated = true;
-                let mut columns_builder = self
-                    .method
-                    .group_columns_builder(self.state.len(), &self.params);
-                for group_entity in self.state.iter() {
-                    columns_builder.append_value(group_entity.get_state_key());
-                }
-
-                let columns = columns_builder.finish()?;
-                Ok(Some(DataBlock::create(self.params.schema.clone(), columns)))
-            }
+    fn generate_block(&mut self) -> Result<DataBlock> {
+        self.is_generated = true;
+        let mut columns_builder = self
+            .method
+            .group_columns_builder(self.state.len(), &self.params);
+        for group_entity in self.state.iter() {
+            columns_builder.append_value(group_entity.get_state_key());
         }
+
+        let columns = columns_builder.finish()?;
+        Ok(DataBlock::create(self.params.schema.clone(), columns))
     }
 }
 
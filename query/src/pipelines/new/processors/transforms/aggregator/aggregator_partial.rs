@@ -64,6 +64,10 @@ pub type SerializerPartialAggregator<const HAS_AGG: bool> =
 pub type SingleStringPartialAggregator<const HAS_AGG: bool> =
     PartialAggregator<HAS_AGG, HashMethodSingleString>;
 
+/// Aggregate the input blocks into states by the group keys.
+///
+/// Once the memory usage of the query exceeds `max_memory_usage`, the states in memory are
+/// flushed to the final aggregator, which merges the states of the same group anyway.
 pub struct PartialAggregator<
     const HAS_AGG: bool,
     Method: HashMethod + PolymorphicKeysHelper<Method>,
@@ -92,6 +96,23 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
         }
     }
 
+    fn memory_exceeded(&self) -> Result<bool> {
+        if self.state.len() == 0 {
+            return Ok(false);
+        }
+
+        let max_memory_usage = self.ctx.get_settings().get_max_memory_usage()? as usize;
+        Ok(max_memory_usage != 0 && self.ctx.get_memory_usage() > max_memory_usage)
+    }
+
+    // Drop the states which are flushed and start over with an empty state.
+    fn reset_state(&mut self) {
+        self.drop_states();
+        self.state = self.method.aggregate_state();
+        self.states_dropped = false;
+        self.is_generated = false;
+    }
+
     #[inline(always)]
     fn lookup_key(keys: Vec<Method::HashKey<'_>>, state: &mut Method::State) {
         let mut inserted = true;
@@ -253,6 +274,16 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         self.generate_data()
     }
+
+    fn flush(&mut self) -> Result<Option<DataBlock>> {
+        if !self.memory_exceeded()? {
+            return Ok(None);
+        }
+
+        let block = self.generate_data()?;
+        self.reset_state();
+        Ok(block)
+    }
 }
 
 impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
@@ -295,6 +326,16 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
             }
         }
     }
+
+    fn flush(&mut self) -> Result<Option<DataBlock>> {
+        if !self.memory_exceeded()? {
+            return Ok(None);
+        }
+
+        let block = self.generate()?;
+        self.reset_state();
+        Ok(block)
+    }
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method>>
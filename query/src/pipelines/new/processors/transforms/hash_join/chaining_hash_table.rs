@@ -18,15 +18,25 @@ use std::hash::Hash;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::infallible::RwLock;
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethod;
 use common_datablocks::HashMethodFixedKeys;
 use common_datablocks::HashMethodSerializer;
+use common_datavalues::combine_validities_2;
+use common_datavalues::const_validitiess;
+use common_datavalues::wrap_nullable;
 use common_datavalues::Column;
 use common_datavalues::ColumnRef;
 use common_datavalues::ConstColumn;
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_datavalues::NullableColumn;
+use common_datavalues::Series;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
 use primitive_types::U256;
@@ -35,10 +45,12 @@ use primitive_types::U512;
 use crate::common::ExpressionEvaluator;
 use crate::common::HashMap;
 use crate::common::HashTableKeyable;
+use crate::common::KeyValueEntity;
 use crate::pipelines::new::processors::transforms::hash_join::row::Chunk;
 use crate::pipelines::new::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::new::processors::transforms::hash_join::row::RowSpace;
 use crate::pipelines::new::processors::HashJoinState;
+use crate::pipelines::new::processors::RuntimeFilter;
 use crate::pipelines::transforms::group_by::keys_ref::KeysRef;
 use crate::sessions::QueryContext;
 use crate::sql::planner::plans::JoinType;
@@ -108,6 +120,14 @@ pub struct ChainingHashTable {
     hash_table: RwLock<HashTable>,
     row_space: RowSpace,
     join_type: JoinType,
+
+    probe_data_schema: DataSchemaRef,
+    /// The number of probe processors which haven't finished
+    probe_ref_count: Mutex<usize>,
+    /// Whether the build rows are matched by some probe rows, only for right and full join
+    build_matched: Mutex<Vec<Vec<bool>>>,
+    /// Collects the build keys to filter the probe side scans
+    runtime_filter: Option<Arc<RuntimeFilter>>,
 }
 
 impl ChainingHashTable {
@@ -118,7 +138,8 @@ impl ChainingHashTable {
         build_expressions: Vec<Expression>,
         probe_expressions: Vec<Expression>,
         build_data_schema: DataSchemaRef,
-        _probe_data_schema: DataSchemaRef,
+        probe_data_schema: DataSchemaRef,
+        runtime_filter: Option<Arc<RuntimeFilter>>,
     ) -> Result<Self> {
         Ok(Self {
             row_space: RowSpace::new(build_data_schema),
@@ -129,23 +150,51 @@ impl ChainingHashTable {
             ctx,
             hash_table: RwLock::new(hash_table),
             join_type,
+            probe_data_schema,
+            probe_ref_count: Mutex::new(0),
+            build_matched: Mutex::new(vec![]),
+            runtime_filter,
         })
     }
 
     fn result_blocks<Key>(
         &self,
         hash_table: &HashMap<Key, Vec<RowPtr>>,
-        method: &HashMethodFixedKeys<Key>,
-        probe_keys: Vec<&ColumnRef>,
+        keys: Vec<Key>,
+        validity: Option<&Bitmap>,
         input: &DataBlock,
     ) -> Result<Vec<DataBlock>>
     where
-        Key: HashTableKeyable + Hash + Clone + Default + Debug + 'static,
+        Key: HashTableKeyable,
+    {
+        match self.join_type {
+            JoinType::InnerJoin => self.probe_inner_join(hash_table, keys, validity, input),
+            JoinType::SemiJoin => self.probe_semi_join(hash_table, keys, validity, input, true),
+            JoinType::AntiJoin => self.probe_semi_join(hash_table, keys, validity, input, false),
+            JoinType::LeftJoin => self.probe_left_join(hash_table, keys, validity, input),
+            JoinType::RightJoin | JoinType::FullJoin => {
+                self.probe_right_join(hash_table, keys, validity, input)
+            }
+            _ => Err(ErrorCode::UnImplement(format!(
+                "Unsupported join type in hash join: {:?}",
+                self.join_type
+            ))),
+        }
+    }
+
+    fn probe_inner_join<Key>(
+        &self,
+        hash_table: &HashMap<Key, Vec<RowPtr>>,
+        keys: Vec<Key>,
+        validity: Option<&Bitmap>,
+        input: &DataBlock,
+    ) -> Result<Vec<DataBlock>>
+    where
+        Key: HashTableKeyable,
     {
         let mut results: Vec<DataBlock> = vec![];
-        let keys = method.build_keys(&probe_keys, input.num_rows())?;
         for (i, key) in keys.iter().enumerate().take(input.num_rows()) {
-            let probe_result_ptr = hash_table.find_key(key);
+            let probe_result_ptr = find_valid_key(hash_table, key, validity, i);
             if probe_result_ptr.is_none() {
                 // No matched row for current probe row
                 continue;
@@ -158,6 +207,132 @@ impl ChainingHashTable {
         Ok(results)
     }
 
+    // Keep the probe rows which have (semi join) or don't have (anti join) matched rows.
+    fn probe_semi_join<Key>(
+        &self,
+        hash_table: &HashMap<Key, Vec<RowPtr>>,
+        keys: Vec<Key>,
+        validity: Option<&Bitmap>,
+        input: &DataBlock,
+        matched: bool,
+    ) -> Result<Vec<DataBlock>>
+    where
+        Key: HashTableKeyable,
+    {
+        let indices = keys
+            .iter()
+            .enumerate()
+            .take(input.num_rows())
+            .filter(|(i, key)| find_valid_key(hash_table, key, validity, *i).is_some() == matched)
+            .map(|(i, _)| i as u32)
+            .collect::<Vec<_>>();
+        if indices.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![DataBlock::block_take_by_indices(input, &indices)?])
+    }
+
+    // Every probe row is emitted, the unmatched ones are padded with NULLs on the build side.
+    fn probe_left_join<Key>(
+        &self,
+        hash_table: &HashMap<Key, Vec<RowPtr>>,
+        keys: Vec<Key>,
+        validity: Option<&Bitmap>,
+        input: &DataBlock,
+    ) -> Result<Vec<DataBlock>>
+    where
+        Key: HashTableKeyable,
+    {
+        let mut results: Vec<DataBlock> = vec![];
+        for (i, key) in keys.iter().enumerate().take(input.num_rows()) {
+            let probe_block = DataBlock::block_take_by_indices(input, &[i as u32])?;
+            let build_block = match find_valid_key(hash_table, key, validity, i) {
+                Some(probe_result_ptr) => {
+                    let build_block = self.row_space.gather(probe_result_ptr.get_value())?;
+                    nullable_block(&build_block)?
+                }
+                None => null_block(&self.row_space.data_schema, 1)?,
+            };
+            results.push(self.merge_block(&build_block, &probe_block)?);
+        }
+        Ok(results)
+    }
+
+    // Every build row is emitted, the matched ones are marked here and the unmatched ones are
+    // padded with NULLs on the probe side after probing. Full join also emits the unmatched
+    // probe rows like left join.
+    fn probe_right_join<Key>(
+        &self,
+        hash_table: &HashMap<Key, Vec<RowPtr>>,
+        keys: Vec<Key>,
+        validity: Option<&Bitmap>,
+        input: &DataBlock,
+    ) -> Result<Vec<DataBlock>>
+    where
+        Key: HashTableKeyable,
+    {
+        let is_full_join = self.join_type == JoinType::FullJoin;
+        let mut results: Vec<DataBlock> = vec![];
+        let mut matched_ptrs: Vec<RowPtr> = vec![];
+        for (i, key) in keys.iter().enumerate().take(input.num_rows()) {
+            let probe_block = DataBlock::block_take_by_indices(input, &[i as u32])?;
+            let build_block = match find_valid_key(hash_table, key, validity, i) {
+                Some(probe_result_ptr) => {
+                    let ptrs = probe_result_ptr.get_value();
+                    matched_ptrs.extend_from_slice(ptrs);
+                    let build_block = self.row_space.gather(ptrs)?;
+                    match is_full_join {
+                        true => nullable_block(&build_block)?,
+                        false => build_block,
+                    }
+                }
+                None if is_full_join => null_block(&self.row_space.data_schema, 1)?,
+                None => continue,
+            };
+            results.push(self.merge_block(&build_block, &nullable_block(&probe_block)?)?);
+        }
+
+        let mut build_matched = self.build_matched.lock().unwrap();
+        for ptr in matched_ptrs {
+            build_matched[ptr.chunk_index as usize][ptr.row_index as usize] = true;
+        }
+        Ok(results)
+    }
+
+    // The build rows never matched, with NULLs on the probe side.
+    fn unmatched_build_blocks(&self) -> Result<Vec<DataBlock>> {
+        let build_matched = self.build_matched.lock().unwrap();
+        let chunks = self.row_space.chunks.read().unwrap();
+        let mut results = Vec::with_capacity(chunks.len());
+        for (chunk, matched) in chunks.iter().zip(build_matched.iter()) {
+            let indices = matched
+                .iter()
+                .enumerate()
+                .filter(|(_, matched)| !**matched)
+                .map(|(i, _)| i as u32)
+                .collect::<Vec<_>>();
+            if indices.is_empty() {
+                continue;
+            }
+
+            let build_block = DataBlock::block_take_by_indices(&chunk.data_block, &indices)?;
+            let build_block = match self.join_type {
+                JoinType::FullJoin => nullable_block(&build_block)?,
+                _ => build_block,
+            };
+            let mut block = null_block(&self.probe_data_schema, indices.len())?;
+            for (col, field) in build_block
+                .columns()
+                .iter()
+                .zip(build_block.schema().fields().iter())
+            {
+                block = block.add_column(col.clone(), field.clone())?;
+            }
+            results.push(block);
+        }
+        Ok(results)
+    }
+
     // Merge build block and probe block
     fn merge_block(&self, build_block: &DataBlock, probe_block: &DataBlock) -> Result<DataBlock> {
         let mut replicated_probe_block = DataBlock::empty();
@@ -202,6 +377,14 @@ impl HashJoinState for ChainingHashTable {
             .iter()
             .map(|expr| ExpressionEvaluator::eval(&func_ctx, expr, &input))
             .collect::<Result<Vec<ColumnRef>>>()?;
+        if let Some(runtime_filter) = &self.runtime_filter {
+            runtime_filter.add_build_keys(&build_cols)?;
+        }
+        let validity = keys_validity(&build_cols, input.num_rows());
+        let build_cols = build_cols
+            .iter()
+            .map(Series::remove_nullable)
+            .collect::<Vec<_>>();
 
         match &*self.hash_table.read() {
             HashTable::SerializerHashTable(table) => {
@@ -213,9 +396,9 @@ impl HashJoinState for ChainingHashTable {
                     .hash_method
                     .build_keys(&build_cols_ref, input.num_rows())?;
                 // Save build_keys in row_space to avoid memory leak
-                self.row_space.push_keys(input, build_keys)
+                self.row_space.push_keys(input, build_keys, validity)
             }
-            _ => self.row_space.push_cols(input, build_cols),
+            _ => self.row_space.push_cols(input, build_cols, validity),
         }
     }
 
@@ -229,88 +412,67 @@ impl HashJoinState for ChainingHashTable {
             .iter()
             .map(|expr| ExpressionEvaluator::eval(&func_ctx, expr, input))
             .collect::<Result<Vec<ColumnRef>>>()?;
+        let validity = keys_validity(&probe_keys, input.num_rows());
+        let validity = validity.as_ref();
+        let probe_keys = probe_keys
+            .iter()
+            .map(Series::remove_nullable)
+            .collect::<Vec<_>>();
         let probe_keys = probe_keys.iter().collect::<Vec<&ColumnRef>>();
-        let mut results: Vec<DataBlock> = vec![];
         match &*self.hash_table.read() {
             HashTable::SerializerHashTable(table) => {
                 let serialized_probe_keys = table
                     .hash_method
                     .build_keys(&probe_keys, input.num_rows())?;
-                for (i, key) in serialized_probe_keys
+                let keys = serialized_probe_keys
                     .iter()
-                    .enumerate()
-                    .take(input.num_rows())
-                {
-                    let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
-                    let probe_result_ptr = table.hash_table.find_key(&keys_ref);
-                    if probe_result_ptr.is_none() {
-                        // No matched row for current probe row
-                        continue;
-                    }
-                    let probe_result_ptrs = probe_result_ptr.unwrap().get_value();
-                    let build_block = self.row_space.gather(probe_result_ptrs)?;
-                    let probe_block = DataBlock::block_take_by_indices(input, &[i as u32])?;
-                    results.push(self.merge_block(&build_block, &probe_block)?);
-                }
+                    .map(|key| KeysRef::create(key.as_ptr() as usize, key.len()))
+                    .collect::<Vec<_>>();
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU8HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU16HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU32HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU64HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU128HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU256HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
             HashTable::KeyU512HashTable(table) => {
-                return self.result_blocks(
-                    &table.hash_table,
-                    &table.hash_method,
-                    probe_keys,
-                    input,
-                );
+                let keys = table
+                    .hash_method
+                    .build_keys(&probe_keys, input.num_rows())?;
+                self.result_blocks(&table.hash_table, keys, validity, input)
             }
         }
-        Ok(results)
     }
 
     fn attach(&self) -> Result<()> {
@@ -324,6 +486,9 @@ impl HashJoinState for ChainingHashTable {
         *count -= 1;
         if *count == 0 {
             self.finish()?;
+            if let Some(runtime_filter) = &self.runtime_filter {
+                runtime_filter.finish_build();
+            }
             let mut is_finished = self.is_finished.lock().unwrap();
             *is_finished = true;
             Ok(())
@@ -336,8 +501,30 @@ impl HashJoinState for ChainingHashTable {
         Ok(*self.is_finished.lock().unwrap())
     }
 
+    fn attach_probe(&self) -> Result<()> {
+        let mut count = self.probe_ref_count.lock().unwrap();
+        *count += 1;
+        Ok(())
+    }
+
+    fn detach_probe(&self) -> Result<Vec<DataBlock>> {
+        let mut count = self.probe_ref_count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 && matches!(self.join_type, JoinType::RightJoin | JoinType::FullJoin) {
+            return self.unmatched_build_blocks();
+        }
+        Ok(vec![])
+    }
+
     fn finish(&self) -> Result<()> {
         let chunks = self.row_space.chunks.write().unwrap();
+        if matches!(self.join_type, JoinType::RightJoin | JoinType::FullJoin) {
+            let mut build_matched = self.build_matched.lock().unwrap();
+            *build_matched = chunks
+                .iter()
+                .map(|chunk| vec![false; chunk.num_rows()])
+                .collect();
+        }
         for chunk_index in 0..chunks.len() {
             let chunk = &chunks[chunk_index];
             let mut columns = vec![];
@@ -351,6 +538,9 @@ impl HashJoinState for ChainingHashTable {
                 HashTable::SerializerHashTable(table) => {
                     if let Some(keys) = chunk.keys.as_ref() {
                         for (row_index, key) in keys.iter().enumerate().take(chunk.num_rows()) {
+                            if !is_valid_row(chunk.validity.as_ref(), row_index) {
+                                continue;
+                            }
                             let mut inserted = true;
                             let ptr = RowPtr {
                                 chunk_index: chunk_index as u32,
@@ -434,6 +624,9 @@ where
 {
     let build_keys = method.build_keys(&columns, chunk.num_rows())?;
     for (row_index, key) in build_keys.iter().enumerate().take(chunk.num_rows()) {
+        if !is_valid_row(chunk.validity.as_ref(), row_index) {
+            continue;
+        }
         let mut inserted = true;
         let ptr = RowPtr {
             chunk_index: chunk_index as u32,
@@ -448,3 +641,60 @@ where
     }
     Ok(())
 }
+
+// A NULL join key never equals anything, so the rows whose keys contain NULL are invalid:
+// they are not inserted into the hash table and never match a probe row.
+// Returns `None` if all the rows are valid.
+//
+// The keys are hashed without their validity then, so that the same value is the same key
+// whether the column of the build side or the probe side is nullable or not.
+pub(crate) fn keys_validity(cols: &[ColumnRef], rows: usize) -> Option<Bitmap> {
+    let mut validity = None;
+    for col in cols.iter() {
+        let (is_all_null, bitmap) = col.validity();
+        if is_all_null {
+            return Some(const_validitiess(rows, false));
+        }
+        validity = combine_validities_2(validity, bitmap.cloned());
+    }
+    validity
+}
+
+fn is_valid_row(validity: Option<&Bitmap>, row: usize) -> bool {
+    validity.map_or(true, |validity| validity.get_bit(row))
+}
+
+fn find_valid_key<Key>(
+    hash_table: &HashMap<Key, Vec<RowPtr>>,
+    key: &Key,
+    validity: Option<&Bitmap>,
+    row: usize,
+) -> Option<*mut KeyValueEntity<Key, Vec<RowPtr>>>
+where
+    Key: HashTableKeyable,
+{
+    match is_valid_row(validity, row) {
+        true => hash_table.find_key(key),
+        false => None,
+    }
+}
+
+pub(crate) fn nullable_block(block: &DataBlock) -> Result<DataBlock> {
+    let mut nullable_block = DataBlock::empty();
+    for (col, field) in block.columns().iter().zip(block.schema().fields().iter()) {
+        let field = DataField::new(field.name(), wrap_nullable(field.data_type()));
+        nullable_block =
+            nullable_block.add_column(NullableColumn::wrap_inner(col.clone(), None), field)?;
+    }
+    Ok(nullable_block)
+}
+
+pub(crate) fn null_block(schema: &DataSchemaRef, rows: usize) -> Result<DataBlock> {
+    let mut null_block = DataBlock::empty();
+    for field in schema.fields().iter() {
+        let data_type = wrap_nullable(field.data_type());
+        let col = data_type.create_constant_column(&DataValue::Null, rows)?;
+        null_block = null_block.add_column(col, DataField::new(field.name(), data_type))?;
+    }
+    Ok(null_block)
+}
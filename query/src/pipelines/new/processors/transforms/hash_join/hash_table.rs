@@ -32,6 +32,13 @@ pub trait HashJoinState: Send + Sync {
     /// Is building finished.
     fn is_finished(&self) -> Result<bool>;
 
+    /// Attach a probe processor to state
+    fn attach_probe(&self) -> Result<()>;
+
+    /// Detach a probe processor from state, the last one gets the unmatched build rows
+    /// of right join and full join.
+    fn detach_probe(&self) -> Result<Vec<DataBlock>>;
+
     /// Finish building hash table, will be called only once as soon as all handles
     /// have been detached from current state.
     fn finish(&self) -> Result<()>;
@@ -15,7 +15,10 @@
 mod chaining_hash_table;
 mod hash_table;
 pub(crate) mod row;
+mod runtime_filter;
 
+pub(crate) use chaining_hash_table::null_block;
+pub(crate) use chaining_hash_table::nullable_block;
 pub use chaining_hash_table::ChainingHashTable;
 pub use chaining_hash_table::HashTable;
 pub use chaining_hash_table::KeyU128HashTable;
@@ -27,3 +30,5 @@ pub use chaining_hash_table::KeyU64HashTable;
 pub use chaining_hash_table::KeyU8HashTable;
 pub use chaining_hash_table::SerializerHashTable;
 pub use hash_table::HashJoinState;
+pub use runtime_filter::RuntimeFilter;
+pub use runtime_filter::ScanRuntimeFilter;
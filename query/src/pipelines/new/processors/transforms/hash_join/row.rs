@@ -14,6 +14,7 @@
 
 use std::sync::RwLock;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_datablocks::DataBlock;
 use common_datavalues::ColumnRef;
 use common_datavalues::DataSchemaRef;
@@ -27,6 +28,8 @@ pub struct Chunk {
     pub data_block: DataBlock,
     pub cols: Option<ColumnVector>,
     pub keys: Option<KeysVector>,
+    /// Rows whose join keys contain NULL are invalid and never inserted into the hash table
+    pub validity: Option<Bitmap>,
 }
 
 impl Chunk {
@@ -54,11 +57,17 @@ impl RowSpace {
         }
     }
 
-    pub fn push_keys(&self, data_block: DataBlock, keys: KeysVector) -> Result<()> {
+    pub fn push_keys(
+        &self,
+        data_block: DataBlock,
+        keys: KeysVector,
+        validity: Option<Bitmap>,
+    ) -> Result<()> {
         let chunk = Chunk {
             data_block,
             cols: None,
             keys: Some(keys),
+            validity,
         };
 
         {
@@ -70,11 +79,17 @@ impl RowSpace {
         Ok(())
     }
 
-    pub fn push_cols(&self, data_block: DataBlock, cols: ColumnVector) -> Result<()> {
+    pub fn push_cols(
+        &self,
+        data_block: DataBlock,
+        cols: ColumnVector,
+        validity: Option<Bitmap>,
+    ) -> Result<()> {
         let chunk = Chunk {
             data_block,
             cols: Some(cols),
             keys: None,
+            validity,
         };
 
         {
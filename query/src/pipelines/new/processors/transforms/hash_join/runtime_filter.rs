@@ -0,0 +1,294 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_base::infallible::RwLock;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+// The bloom filter is skipped for a larger build side, only the min/max is kept then.
+const MAX_BLOOM_FILTER_ROWS: usize = 8 * 1024 * 1024;
+const BLOOM_FILTER_BITS_PER_ROW: usize = 8;
+const BLOOM_FILTER_HASHES: u64 = 3;
+
+/// The filter on the join keys built from the build side of a hash join, which lets the
+/// probe side scans skip the blocks and the rows that can not find a match.
+///
+/// The filter is ready when all the hash tables of the join are built, the scans started
+/// before that read everything as usual.
+pub struct RuntimeFilter {
+    // The build side hash tables which are not finished.
+    pending_builds: Mutex<usize>,
+    builders: Mutex<Vec<KeyFilterBuilder>>,
+    keys: RwLock<Option<Arc<Vec<KeyFilter>>>>,
+}
+
+impl RuntimeFilter {
+    pub fn create(num_keys: usize, num_builds: usize) -> Arc<RuntimeFilter> {
+        Arc::new(RuntimeFilter {
+            pending_builds: Mutex::new(num_builds),
+            builders: Mutex::new((0..num_keys).map(|_| KeyFilterBuilder::default()).collect()),
+            keys: RwLock::new(None),
+        })
+    }
+
+    /// Collect the join keys of a build side block, one column per key.
+    pub fn add_build_keys(&self, columns: &[ColumnRef]) -> Result<()> {
+        let mut builders = self.builders.lock().unwrap();
+        for (builder, column) in builders.iter_mut().zip(columns) {
+            builder.add(column);
+        }
+        Ok(())
+    }
+
+    /// Called once by every hash table of the join when it's built.
+    pub fn finish_build(&self) {
+        let mut pending_builds = self.pending_builds.lock().unwrap();
+        *pending_builds = pending_builds.saturating_sub(1);
+        if *pending_builds == 0 {
+            let builders = std::mem::take(&mut *self.builders.lock().unwrap());
+            let keys = builders.into_iter().map(KeyFilterBuilder::finish).collect();
+            *self.keys.write() = Some(Arc::new(keys));
+        }
+    }
+
+    fn keys(&self) -> Option<Arc<Vec<KeyFilter>>> {
+        self.keys.read().clone()
+    }
+}
+
+/// A runtime filter bound to the columns of a table scan on the probe side.
+pub struct ScanRuntimeFilter {
+    filter: Arc<RuntimeFilter>,
+    // (The index of the join key, the index of the column in the table, the column name)
+    columns: Vec<(usize, usize, String)>,
+}
+
+impl ScanRuntimeFilter {
+    pub fn create(
+        filter: Arc<RuntimeFilter>,
+        columns: Vec<(usize, usize, String)>,
+    ) -> Arc<ScanRuntimeFilter> {
+        Arc::new(ScanRuntimeFilter { filter, columns })
+    }
+
+    /// Whether a block with the min/max of the columns, indexed by the columns in the table,
+    /// can be skipped.
+    pub fn skip_block(&self, columns_range: &HashMap<usize, (DataValue, DataValue)>) -> bool {
+        let keys = match self.filter.keys() {
+            None => return false,
+            Some(keys) => keys,
+        };
+
+        self.columns
+            .iter()
+            .any(|(key, column, _)| match columns_range.get(column) {
+                Some((min, max)) => !keys[*key].may_overlap(min, max),
+                None => false,
+            })
+    }
+
+    /// Remove the rows which can not find a match, returns the block and the removed rows.
+    pub fn filter_block(&self, block: DataBlock) -> Result<(DataBlock, usize)> {
+        let keys = match self.filter.keys() {
+            None => return Ok((block, 0)),
+            Some(keys) => keys,
+        };
+
+        let num_rows = block.num_rows();
+        let mut selection = vec![true; num_rows];
+        for (key, _, name) in &self.columns {
+            if let Ok(column) = block.try_column_by_name(name) {
+                keys[*key].filter(column, &mut selection);
+            }
+        }
+
+        let filtered_rows = selection.iter().filter(|selected| !**selected).count();
+        if filtered_rows == 0 {
+            return Ok((block, 0));
+        }
+        let predicate = Series::from_data(selection);
+        Ok((DataBlock::filter_block(&block, &predicate)?, filtered_rows))
+    }
+}
+
+#[derive(Default)]
+struct KeyFilterBuilder {
+    min: Option<DataValue>,
+    max: Option<DataValue>,
+    hashes: Vec<u64>,
+    // Some values are not comparable or hashable, the key can not be filtered then.
+    unsupported: bool,
+}
+
+impl KeyFilterBuilder {
+    fn add(&mut self, column: &ColumnRef) {
+        if self.unsupported {
+            return;
+        }
+
+        for row in 0..column.len() {
+            let value = column.get(row);
+            if value.is_null() {
+                // NULL never matches.
+                continue;
+            }
+
+            let hash = match hash_value(&value) {
+                Some(hash) => hash,
+                None => {
+                    self.unsupported = true;
+                    return;
+                }
+            };
+            if self.hashes.len() <= MAX_BLOOM_FILTER_ROWS {
+                self.hashes.push(hash);
+            }
+
+            match &self.min {
+                Some(min) if compare_values(&value, min) != Some(Ordering::Less) => {}
+                _ => self.min = Some(value.clone()),
+            }
+            match &self.max {
+                Some(max) if compare_values(&value, max) != Some(Ordering::Greater) => {}
+                _ => self.max = Some(value),
+            }
+        }
+    }
+
+    fn finish(self) -> KeyFilter {
+        if self.unsupported {
+            return KeyFilter::Unsupported;
+        }
+
+        let (min, max) = match (self.min, self.max) {
+            (Some(min), Some(max)) => (min, max),
+            // The build side has no rows, nothing can match.
+            _ => return KeyFilter::Empty,
+        };
+
+        let bloom = match self.hashes.len() > MAX_BLOOM_FILTER_ROWS {
+            true => None,
+            false => {
+                let num_bits = (self.hashes.len() * BLOOM_FILTER_BITS_PER_ROW).max(64) as u64;
+                let mut bits = vec![0u64; ((num_bits + 63) / 64) as usize];
+                for hash in self.hashes {
+                    for bit in bloom_bits(hash, num_bits) {
+                        bits[(bit / 64) as usize] |= 1 << (bit % 64);
+                    }
+                }
+                Some((bits, num_bits))
+            }
+        };
+
+        KeyFilter::Values { min, max, bloom }
+    }
+}
+
+enum KeyFilter {
+    Unsupported,
+    Empty,
+    Values {
+        min: DataValue,
+        max: DataValue,
+        bloom: Option<(Vec<u64>, u64)>,
+    },
+}
+
+impl KeyFilter {
+    fn may_overlap(&self, block_min: &DataValue, block_max: &DataValue) -> bool {
+        match self {
+            KeyFilter::Unsupported => true,
+            KeyFilter::Empty => false,
+            KeyFilter::Values { min, max, .. } => {
+                // The min/max of a block with only NULLs are NULL.
+                if block_min.is_null() || block_max.is_null() {
+                    return block_min.is_null() != block_max.is_null();
+                }
+                compare_values(block_max, min) != Some(Ordering::Less)
+                    && compare_values(block_min, max) != Some(Ordering::Greater)
+            }
+        }
+    }
+
+    fn may_contain(&self, value: &DataValue) -> bool {
+        match self {
+            KeyFilter::Unsupported => true,
+            KeyFilter::Empty => false,
+            KeyFilter::Values { min, max, bloom } => {
+                if value.is_null() {
+                    return false;
+                }
+                if compare_values(value, min) == Some(Ordering::Less)
+                    || compare_values(value, max) == Some(Ordering::Greater)
+                {
+                    return false;
+                }
+                match (bloom, hash_value(value)) {
+                    (Some((bits, num_bits)), Some(hash)) => bloom_bits(hash, *num_bits)
+                        .all(|bit| bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0),
+                    _ => true,
+                }
+            }
+        }
+    }
+
+    fn filter(&self, column: &ColumnRef, selection: &mut [bool]) {
+        if matches!(self, KeyFilter::Unsupported) {
+            return;
+        }
+        for (row, selected) in selection.iter_mut().enumerate() {
+            if *selected {
+                *selected = self.may_contain(&column.get(row));
+            }
+        }
+    }
+}
+
+fn bloom_bits(hash: u64, num_bits: u64) -> impl Iterator<Item = u64> {
+    let delta = hash.rotate_left(32) | 1;
+    (0..BLOOM_FILTER_HASHES).map(move |i| hash.wrapping_add(i.wrapping_mul(delta)) % num_bits)
+}
+
+// The build keys and the probe keys have the same type, the integers are widened so that
+// the same number is hashed the same whether it's signed or not.
+fn hash_value(value: &DataValue) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+    match value {
+        DataValue::Boolean(v) => v.hash(&mut hasher),
+        DataValue::Int64(v) => (*v as i128).hash(&mut hasher),
+        DataValue::UInt64(v) => (*v as i128).hash(&mut hasher),
+        DataValue::Float64(v) => v.to_bits().hash(&mut hasher),
+        DataValue::String(v) => v.hash(&mut hasher),
+        _ => return None,
+    }
+    Some(hasher.finish())
+}
+
+fn compare_values(left: &DataValue, right: &DataValue) -> Option<Ordering> {
+    if left.value_type() == right.value_type() {
+        return Some(left.cmp(right));
+    }
+    match (left.as_f64(), right.as_f64()) {
+        (Ok(left), Ok(right)) => left.partial_cmp(&right),
+        _ => None,
+    }
+}
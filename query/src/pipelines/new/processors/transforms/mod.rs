@@ -14,6 +14,7 @@
 
 mod aggregator;
 pub(crate) mod hash_join;
+mod spiller;
 mod transform;
 mod transform_addon;
 mod transform_aggregator;
@@ -22,15 +23,22 @@ mod transform_block_compact;
 mod transform_cast_schema;
 mod transform_compact;
 mod transform_create_sets;
+mod transform_distinct;
 mod transform_dummy;
 mod transform_expression;
+mod transform_external_function;
 mod transform_filter;
 mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_max_one_row;
+mod transform_merge_join;
+mod transform_repartition;
 mod transform_sort_merge;
 mod transform_sort_partial;
+mod transform_sort_spill;
+mod transform_sort_topn;
+mod transform_window;
 
 pub use aggregator::AggregatorParams;
 pub use aggregator::AggregatorTransformParams;
@@ -44,6 +52,8 @@ pub use hash_join::KeyU32HashTable;
 pub use hash_join::KeyU512HashTable;
 pub use hash_join::KeyU64HashTable;
 pub use hash_join::KeyU8HashTable;
+pub use hash_join::RuntimeFilter;
+pub use hash_join::ScanRuntimeFilter;
 pub use hash_join::SerializerHashTable;
 pub use transform_addon::TransformAddOn;
 pub use transform_aggregator::TransformAggregator;
@@ -55,9 +65,11 @@ pub use transform_compact::Compactor;
 pub use transform_compact::TransformCompact;
 pub use transform_create_sets::SubQueriesPuller;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_distinct::TransformDistinct;
 pub use transform_dummy::TransformDummy;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression::ProjectionTransform;
+pub use transform_external_function::TransformExternalFunction;
 pub use transform_filter::TransformFilter;
 pub use transform_filter::TransformHaving;
 pub use transform_hash_join::SinkBuildHashTable;
@@ -65,6 +77,15 @@ pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_limit::TransformLimit;
 pub use transform_limit_by::TransformLimitBy;
 pub use transform_max_one_row::TransformMax1Row;
+pub use transform_merge_join::SinkMergeJoinRight;
+pub use transform_merge_join::SortMergeJoinState;
+pub use transform_merge_join::TransformMergeJoin;
+pub use transform_repartition::TransformRepartition;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
+pub use transform_sort_topn::TopNCompactor;
+pub use transform_sort_topn::TransformTopN;
+pub use transform_window::TransformWindow;
+pub use transform_window::WindowCompactor;
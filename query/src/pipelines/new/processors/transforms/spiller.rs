@@ -0,0 +1,168 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use uuid::Uuid;
+
+use crate::sessions::TempStorageReservation;
+
+const SPILL_PARTITIONS: usize = 16;
+
+/// Spill the records to partitions on local disk by the hash of key, a record is the key
+/// and optionally the row. The partitions are read back one by one once all the records are
+/// written, so the records of the same key are always restored together.
+pub struct Spiller {
+    name: &'static str,
+    // True if the whole rows are written after the keys.
+    with_rows: bool,
+    writers: Vec<Option<BufWriter<File>>>,
+    paths: VecDeque<PathBuf>,
+    // The spill files are accounted to the temporary storage of the user.
+    temp_storage: TempStorageReservation,
+}
+
+impl Spiller {
+    pub fn try_create(
+        name: &'static str,
+        with_rows: bool,
+        temp_storage: TempStorageReservation,
+    ) -> Result<Spiller> {
+        let prefix = format!("{}-{}", name, Uuid::new_v4().to_simple());
+
+        let mut writers = Vec::with_capacity(SPILL_PARTITIONS);
+        let mut paths = VecDeque::with_capacity(SPILL_PARTITIONS);
+        for partition in 0..SPILL_PARTITIONS {
+            let path = temp_storage.file_path(&format!("{}-{}", prefix, partition));
+            writers.push(Some(BufWriter::new(File::create(&path)?)));
+            paths.push_back(path);
+        }
+
+        Ok(Spiller {
+            name,
+            with_rows,
+            writers,
+            paths,
+            temp_storage,
+        })
+    }
+
+    pub fn write(&mut self, key: &[u8], row: Option<&[u8]>) -> Result<()> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let partition = hasher.finish() as usize % SPILL_PARTITIONS;
+
+        match &mut self.writers[partition] {
+            None => Err(ErrorCode::LogicalError(format!(
+                "The {} spill partition is closed",
+                self.name
+            ))),
+            Some(writer) => {
+                let bytes = 8 + key.len() + row.map(|row| 8 + row.len()).unwrap_or(0);
+                self.temp_storage.reserve(bytes as u64)?;
+
+                writer.write_all(&(key.len() as u64).to_le_bytes())?;
+                writer.write_all(key)?;
+                if let Some(row) = row {
+                    writer.write_all(&(row.len() as u64).to_le_bytes())?;
+                    writer.write_all(row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn has_partitions(&self) -> bool {
+        !self.paths.is_empty()
+    }
+
+    pub fn next_partition(&mut self) -> Result<Option<PathBuf>> {
+        for writer in self.writers.iter_mut() {
+            if let Some(mut writer) = writer.take() {
+                writer.flush()?;
+            }
+        }
+
+        Ok(self.paths.pop_front())
+    }
+
+    // Read all the records of a partition and remove the file.
+    #[allow(clippy::type_complexity)]
+    pub fn read(&mut self, path: &Path) -> Result<Vec<(Vec<u8>, Option<Vec<u8>>)>> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut records = vec![];
+        while let Some(key) = Self::read_bytes(&mut reader)? {
+            let row = match self.with_rows {
+                false => None,
+                true => match Self::read_bytes(&mut reader)? {
+                    Some(row) => Some(row),
+                    None => {
+                        return Err(ErrorCode::LogicalError(format!(
+                            "Corrupted {} spill file",
+                            self.name
+                        )));
+                    }
+                },
+            };
+            records.push((key, row));
+        }
+        let file_size = std::fs::metadata(path)?.len();
+        std::fs::remove_file(path)?;
+        self.temp_storage.release(file_size);
+
+        Ok(records)
+    }
+
+    fn read_bytes(reader: &mut impl Read) -> Result<Option<Vec<u8>>> {
+        let mut len = [0u8; 8];
+        match reader.read_exact(&mut len) {
+            Ok(_) => {}
+            Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        }
+
+        let mut bytes = vec![0u8; u64::from_le_bytes(len) as usize];
+        reader.read_exact(&mut bytes)?;
+        Ok(Some(bytes))
+    }
+}
+
+impl Drop for Spiller {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            if let Err(cause) = std::fs::remove_file(path) {
+                tracing::warn!(
+                    "Cannot remove {} spill file {:?}, {}",
+                    self.name,
+                    path,
+                    cause
+                );
+            }
+        }
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_exception::Result;
+use futures::Future;
 
 use crate::pipelines::new::processors::port::InputPort;
 use crate::pipelines::new::processors::port::OutputPort;
@@ -23,7 +24,6 @@ use crate::pipelines::new::processors::processor::Event;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Processor;
 
-// TODO: maybe we also need async transform for `SELECT sleep(1)`?
 pub trait Transform: Send {
     const NAME: &'static str;
     const SKIP_EMPTY_DATA_BLOCK: bool = false;
@@ -75,7 +75,12 @@ impl<T: Transform + 'static> Processor for Transformer<T> {
 
     fn process(&mut self) -> Result<()> {
         if let Some(data_block) = self.input_data.take() {
-            let data_block = self.transform.transform(data_block)?;
+            // Keep the sequence number of the block to restore the input order at the sink.
+            let sequence = data_block.sequence();
+            let data_block = self
+                .transform
+                .transform(data_block)?
+                .with_sequence(sequence);
 
             if !T::SKIP_EMPTY_DATA_BLOCK || !data_block.is_empty() {
                 self.output_data = Some(data_block);
@@ -112,3 +117,86 @@ impl<T: Transform> Transformer<T> {
         Ok(Event::Finished)
     }
 }
+
+/// A transform which waits for something else, e.g. the external functions, without blocking
+/// the executor thread.
+pub trait AsyncTransform: Send {
+    const NAME: &'static str;
+
+    type TransformFuture<'a>: Future<Output = Result<DataBlock>> + Send
+    where Self: 'a;
+
+    fn transform(&mut self, data: DataBlock) -> Self::TransformFuture<'_>;
+}
+
+pub struct AsyncTransformer<T: AsyncTransform + 'static> {
+    transform: T,
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+}
+
+impl<T: AsyncTransform + 'static> AsyncTransformer<T> {
+    pub fn create(input: Arc<InputPort>, output: Arc<OutputPort>, inner: T) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(AsyncTransformer {
+            input,
+            output,
+            transform: inner,
+            input_data: None,
+            output_data: None,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: AsyncTransform + 'static> Processor for AsyncTransformer<T> {
+    fn name(&self) -> &'static str {
+        T::NAME
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data) = self.output_data.take() {
+            self.output.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Async);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Async);
+        }
+
+        if self.input.is_finished() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let sequence = data_block.sequence();
+            let data_block = self.transform.transform(data_block).await?;
+            self.output_data = Some(data_block.with_sequence(sequence));
+        }
+
+        Ok(())
+    }
+}
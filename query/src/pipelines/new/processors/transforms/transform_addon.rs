@@ -49,6 +49,16 @@ where Self: Transform
         output_schema: DataSchemaRef,
         ctx: Arc<QueryContext>,
     ) -> Result<ProcessorPtr> {
+        let transform = Self::try_new(input_schema, output_schema, ctx)?;
+        Ok(Transformer::create(input, output, transform))
+    }
+
+    /// Create the transform without the ports, to fill the missing columns of blocks directly.
+    pub fn try_new(
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        ctx: Arc<QueryContext>,
+    ) -> Result<Self> {
         let mut default_expr_fields = Vec::new();
         let mut default_exprs = Vec::new();
         let mut default_nonexpr_fields = Vec::new();
@@ -83,19 +93,25 @@ where Self: Transform
             true,
         )?;
 
-        Ok(Transformer::create(input, output, Self {
+        Ok(Self {
             default_expr_fields,
             default_nonexpr_fields,
             expression_executor,
             output_schema,
-        }))
+        })
     }
 }
 
 impl Transform for TransformAddOn {
     const NAME: &'static str = "AddOnTransform";
 
-    fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        self.add_missing_columns(block)
+    }
+}
+
+impl TransformAddOn {
+    pub fn add_missing_columns(&self, mut block: DataBlock) -> Result<DataBlock> {
         let num_rows = block.num_rows();
         let expr_block = self.expression_executor.execute(&block)?;
 
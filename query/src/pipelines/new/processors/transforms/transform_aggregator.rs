@@ -265,6 +265,12 @@ pub trait Aggregator: Sized + Send {
 
     fn consume(&mut self, data: DataBlock) -> Result<()>;
     fn generate(&mut self) -> Result<Option<DataBlock>>;
+
+    /// Called after each block is consumed, the returned block is output before the input is
+    /// finished, so that the aggregator can release the memory of its states early.
+    fn flush(&mut self) -> Result<Option<DataBlock>> {
+        Ok(None)
+    }
 }
 
 enum AggregatorTransform<TAggregator: Aggregator> {
@@ -287,6 +293,7 @@ impl<TAggregator: Aggregator + 'static> AggregatorTransform<TAggregator> {
                 input_port,
                 output_port,
                 input_data_block: None,
+                output_data_block: None,
             },
         ))))
     }
@@ -332,6 +339,17 @@ impl<TAggregator: Aggregator + 'static> AggregatorTransform<TAggregator> {
     #[inline(always)]
     fn consume_event(&mut self) -> Result<Event> {
         if let AggregatorTransform::ConsumeData(state) = self {
+            if state.output_data_block.is_some() {
+                if !state.output_port.can_push() {
+                    state.input_port.set_not_need_data();
+                    return Ok(Event::NeedConsume);
+                }
+
+                let block = state.output_data_block.take().unwrap();
+                state.output_port.push_data(Ok(block));
+                return Ok(Event::NeedConsume);
+            }
+
             if state.input_data_block.is_some() {
                 return Ok(Event::Sync);
             }
@@ -400,12 +418,14 @@ struct ConsumeState<TAggregator: Aggregator> {
     input_port: Arc<InputPort>,
     output_port: Arc<OutputPort>,
     input_data_block: Option<DataBlock>,
+    output_data_block: Option<DataBlock>,
 }
 
 impl<TAggregator: Aggregator> ConsumeState<TAggregator> {
     pub fn consume(&mut self) -> Result<()> {
         if let Some(input_data) = self.input_data_block.take() {
             self.inner.consume(input_data)?;
+            self.output_data_block = self.inner.flush()?;
         }
 
         Ok(())
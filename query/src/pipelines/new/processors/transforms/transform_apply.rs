@@ -94,6 +94,16 @@ impl OuterRefRewriter {
 
                 Ok(SExpr::create_unary(plan.into(), input))
             }
+            RelOperator::Distinct(mut plan) => {
+                let input = self.rewrite(s_expr.child(0)?)?;
+
+                for item in plan.items.iter_mut() {
+                    let scalar = self.rewrite_scalar(&item.scalar)?;
+                    item.scalar = scalar;
+                }
+
+                Ok(SExpr::create_unary(plan.into(), input))
+            }
 
             RelOperator::PhysicalHashJoin(mut plan) => {
                 let probe_side = self.rewrite(s_expr.child(0)?)?;
@@ -108,15 +118,39 @@ impl OuterRefRewriter {
                 Ok(SExpr::create_binary(plan.into(), build_side, probe_side))
             }
 
+            RelOperator::PhysicalSortMergeJoin(mut plan) => {
+                let left = self.rewrite(s_expr.child(0)?)?;
+                let right = self.rewrite(s_expr.child(1)?)?;
+                for scalar in plan.left_keys.iter_mut() {
+                    *scalar = self.rewrite_scalar(scalar)?;
+                }
+                for scalar in plan.right_keys.iter_mut() {
+                    *scalar = self.rewrite_scalar(scalar)?;
+                }
+
+                Ok(SExpr::create_binary(plan.into(), left, right))
+            }
+
             RelOperator::Max1Row(_)
             | RelOperator::Project(_)
             | RelOperator::Limit(_)
-            | RelOperator::Sort(_) => Ok(SExpr::create_unary(
+            | RelOperator::Sort(_)
+            | RelOperator::Window(_) => Ok(SExpr::create_unary(
                 s_expr.plan().clone(),
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::PhysicalScan(_) => Ok(s_expr.clone()),
+            RelOperator::CteScan(_) => Ok(SExpr::create(
+                s_expr.plan().clone(),
+                s_expr
+                    .children()
+                    .iter()
+                    .map(|child| self.rewrite(child))
+                    .collect::<Result<Vec<_>>>()?,
+                None,
+            )),
+
+            RelOperator::PhysicalScan(_) | RelOperator::WorkingTableScan(_) => Ok(s_expr.clone()),
 
             RelOperator::CrossApply(plan) => {
                 let left = self.rewrite(s_expr.child(0)?)?;
@@ -214,6 +248,10 @@ impl OuterRefRewriter {
             Scalar::SubqueryExpr(_) => Err(ErrorCode::LogicalError(
                 "Physical plan shouldn't contain subquery expression",
             )),
+
+            Scalar::WindowFunction(_) => Err(ErrorCode::LogicalError(
+                "Physical plan shouldn't contain window function",
+            )),
         }
     }
 }
@@ -288,8 +326,12 @@ impl TransformApply {
         // Set max threads
         let settings = self.ctx.get_settings();
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+        pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
         for pipeline in children.iter_mut() {
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+            pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
         }
 
         let runtime = self.ctx.get_storage_runtime();
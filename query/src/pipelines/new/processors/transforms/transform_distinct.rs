@@ -0,0 +1,320 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use super::spiller::Spiller;
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::Processor;
+use crate::sessions::TempStorageReservation;
+
+/// Streaming distinct based on a hash set of the serialized distinct keys. The first row of
+/// each key is output as soon as it is seen, so the rows are never buffered.
+///
+/// The partial distinct runs before the pipeline is resized to one, it only drops the rows
+/// it has seen in its own stream and simply forgets all the keys when the memory limit is
+/// exceeded. The final distinct has to be exact, when the memory limit is exceeded the keys
+/// in memory are frozen and the rows with unseen keys are spilled to partitions on local
+/// disk by the hash of key, each partition is deduplicated on its own after the input is
+/// finished.
+pub struct TransformDistinct {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+
+    schema: DataSchemaRef,
+    distinct_columns: Vec<String>,
+    // True if the distinct keys are the whole rows, so the rows need not be serialized twice.
+    keys_are_rows: bool,
+    partial: bool,
+    max_memory_usage: usize,
+
+    keys: HashSet<Vec<u8>>,
+    keys_memory_usage: usize,
+    spiller: Option<Spiller>,
+    // Taken by the spiller once the final distinct starts to spill.
+    temp_storage: Option<TempStorageReservation>,
+}
+
+impl TransformDistinct {
+    pub fn try_create_partial(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        distinct_columns: Vec<String>,
+        max_memory_usage: usize,
+    ) -> Result<ProcessorPtr> {
+        Self::try_create(
+            input,
+            output,
+            schema,
+            distinct_columns,
+            max_memory_usage,
+            true,
+            None,
+        )
+    }
+
+    pub fn try_create_final(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        distinct_columns: Vec<String>,
+        max_memory_usage: usize,
+        temp_storage: TempStorageReservation,
+    ) -> Result<ProcessorPtr> {
+        Self::try_create(
+            input,
+            output,
+            schema,
+            distinct_columns,
+            max_memory_usage,
+            false,
+            Some(temp_storage),
+        )
+    }
+
+    fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        distinct_columns: Vec<String>,
+        max_memory_usage: usize,
+        partial: bool,
+        temp_storage: Option<TempStorageReservation>,
+    ) -> Result<ProcessorPtr> {
+        let keys_are_rows = schema.num_fields() == distinct_columns.len()
+            && schema
+                .fields()
+                .iter()
+                .zip(distinct_columns.iter())
+                .all(|(field, column)| field.name() == column);
+
+        Ok(ProcessorPtr::create(Box::new(TransformDistinct {
+            input,
+            output,
+            input_data: None,
+            output_data: VecDeque::new(),
+            schema,
+            distinct_columns,
+            keys_are_rows,
+            partial,
+            max_memory_usage,
+            keys: HashSet::new(),
+            keys_memory_usage: 0,
+            spiller: None,
+            temp_storage,
+        })))
+    }
+
+    fn distinct(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let method = HashMethodSerializer::default();
+        let mut key_columns = Vec::with_capacity(self.distinct_columns.len());
+        for name in &self.distinct_columns {
+            key_columns.push(block.try_column_by_name(name)?);
+        }
+        let keys = method.build_keys(&key_columns, block.num_rows())?;
+
+        let mut indices = Vec::with_capacity(block.num_rows());
+        let mut spill_indices = vec![];
+        for (row, key) in keys.iter().enumerate() {
+            if self.keys.contains(key.as_slice()) {
+                continue;
+            }
+
+            if self.spiller.is_some() {
+                spill_indices.push(row);
+                continue;
+            }
+
+            self.keys_memory_usage += key.len() + std::mem::size_of::<Vec<u8>>();
+            self.keys.insert(key.to_vec());
+            indices.push(row as u32);
+        }
+
+        if !spill_indices.is_empty() {
+            self.spill(&block, &keys, &spill_indices)?;
+        }
+
+        if self.max_memory_usage != 0 && self.keys_memory_usage > self.max_memory_usage {
+            self.on_memory_exceeded()?;
+        }
+
+        match indices.len() == block.num_rows() {
+            true => Ok(block),
+            false => DataBlock::block_take_by_indices(&block, &indices),
+        }
+    }
+
+    fn on_memory_exceeded(&mut self) -> Result<()> {
+        if self.partial {
+            // The final distinct will drop the duplicated rows anyway.
+            self.keys.clear();
+            self.keys_memory_usage = 0;
+        } else if self.spiller.is_none() {
+            tracing::info!(
+                "Distinct keys exceed the memory limit {} bytes, spill the unseen rows to disk",
+                self.max_memory_usage
+            );
+            let temp_storage = self.temp_storage.take().ok_or_else(|| {
+                ErrorCode::LogicalError("Distinct temporary storage is not reserved")
+            })?;
+            self.spiller = Some(Spiller::try_create(
+                "distinct",
+                !self.keys_are_rows,
+                temp_storage,
+            )?);
+        }
+
+        Ok(())
+    }
+
+    fn spill(
+        &mut self,
+        block: &DataBlock,
+        keys: &[impl AsRef<[u8]>],
+        rows: &[usize],
+    ) -> Result<()> {
+        let spiller = self.spiller.as_mut().unwrap();
+        if self.keys_are_rows {
+            for row in rows {
+                spiller.write(keys[*row].as_ref(), None)?;
+            }
+            return Ok(());
+        }
+
+        let columns = block.columns().iter().collect::<Vec<_>>();
+        let values = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+        for row in rows {
+            spiller.write(keys[*row].as_ref(), Some(values[*row].as_slice()))?;
+        }
+        Ok(())
+    }
+
+    // Deduplicate the rows of one spilled partition.
+    fn restore(&mut self, path: PathBuf) -> Result<()> {
+        let records = match &mut self.spiller {
+            None => return Err(ErrorCode::LogicalError("Distinct spiller is not created")),
+            Some(spiller) => spiller.read(&path)?,
+        };
+
+        let mut keys = HashSet::with_capacity(records.len());
+        let mut rows = Vec::with_capacity(records.len());
+        for (key, row) in records {
+            if keys.contains(&key) {
+                continue;
+            }
+
+            match row {
+                None => rows.push(key.clone()),
+                Some(row) => rows.push(row),
+            }
+            keys.insert(key);
+        }
+
+        if !rows.is_empty() {
+            let method = HashMethodSerializer::default();
+            let columns = method.deserialize_group_columns(rows, self.schema.fields())?;
+            let block = DataBlock::create(self.schema.clone(), columns);
+            self.output_data.push_back(block);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformDistinct {
+    fn name(&self) -> &'static str {
+        match self.partial {
+            true => "DistinctPartialTransform",
+            false => "DistinctFinalTransform",
+        }
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            if let Some(spiller) = &self.spiller {
+                if spiller.has_partitions() {
+                    return Ok(Event::Sync);
+                }
+            }
+
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let data_block = self.distinct(data_block)?;
+            if !data_block.is_empty() {
+                self.output_data.push_back(data_block);
+            }
+            return Ok(());
+        }
+
+        if let Some(path) = self
+            .spiller
+            .as_mut()
+            .and_then(|v| v.next_partition().transpose())
+        {
+            self.restore(path?)?;
+        }
+
+        Ok(())
+    }
+}
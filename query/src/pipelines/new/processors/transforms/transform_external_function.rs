@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::ColumnWithField;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::Recursion;
+use futures::Future;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::transforms::transform::AsyncTransform;
+use crate::pipelines::new::processors::transforms::transform::AsyncTransformer;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::users::ExternalFunction;
+
+/// Evaluates the calls of the external functions in the expressions without blocking the
+/// executor, the results are added to the block as the columns of the calls, which the
+/// expression executors take as is instead of evaluating the calls again.
+pub struct TransformExternalFunction {
+    ctx: Arc<QueryContext>,
+    // Inner calls first, so that the arguments of a call are evaluated after its inner calls.
+    calls: Vec<ExternalCall>,
+}
+
+struct ExternalCall {
+    function: ExternalFunction,
+    field: DataField,
+    args: ExpressionExecutor,
+}
+
+impl TransformExternalFunction {
+    /// The calls of the external functions in the expressions, in evaluation order.
+    pub fn find_calls(exprs: &[Expression]) -> Result<Vec<Expression>> {
+        let mut visitor = ExternalCallVisitor { calls: vec![] };
+        for expr in exprs {
+            visitor = expr.accept(visitor)?;
+        }
+        Ok(visitor.calls)
+    }
+
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        input_schema: DataSchemaRef,
+        calls: &[Expression],
+        ctx: Arc<QueryContext>,
+    ) -> Result<ProcessorPtr> {
+        let mut external_calls = Vec::with_capacity(calls.len());
+        for call in calls {
+            // The call is evaluated by the former transforms.
+            if input_schema.has_field(&call.column_name()) {
+                continue;
+            }
+
+            if let Expression::ScalarFunction { op, args } = call {
+                if let Some(function) = ExternalFunction::get(op) {
+                    let args_fields = args
+                        .iter()
+                        .map(|arg| arg.to_data_field(&input_schema))
+                        .collect::<Result<Vec<_>>>()?;
+                    let args = ExpressionExecutor::try_create(
+                        ctx.clone(),
+                        "external function arguments executor",
+                        input_schema.clone(),
+                        DataSchemaRefExt::create(args_fields),
+                        args.clone(),
+                        false,
+                    )?;
+                    external_calls.push(ExternalCall {
+                        function,
+                        field: call.to_data_field(&input_schema)?,
+                        args,
+                    });
+                }
+            }
+        }
+
+        Ok(AsyncTransformer::create(input, output, Self {
+            ctx,
+            calls: external_calls,
+        }))
+    }
+}
+
+impl AsyncTransform for TransformExternalFunction {
+    const NAME: &'static str = "ExternalFunctionTransform";
+
+    type TransformFuture<'a> = impl Future<Output = Result<DataBlock>> where Self: 'a;
+
+    fn transform(&mut self, mut data: DataBlock) -> Self::TransformFuture<'_> {
+        async move {
+            let func_ctx = self.ctx.try_get_function_context()?;
+            for call in &self.calls {
+                let args_block = call.args.execute(&data)?;
+                let args = args_block
+                    .schema()
+                    .fields()
+                    .iter()
+                    .zip(args_block.columns())
+                    .map(|(field, column)| ColumnWithField::new(column.clone(), field.clone()))
+                    .collect::<Vec<_>>();
+
+                let column = call
+                    .function
+                    .eval_async(&func_ctx, &args, data.num_rows(), call.field.data_type())
+                    .await?;
+                data = data.add_column(column, call.field.clone())?;
+            }
+            Ok(data)
+        }
+    }
+}
+
+struct ExternalCallVisitor {
+    calls: Vec<Expression>,
+}
+
+impl ExpressionVisitor for ExternalCallVisitor {
+    fn pre_visit(self, _expr: &Expression) -> Result<Recursion<Self>> {
+        Ok(Recursion::Continue(self))
+    }
+
+    fn post_visit(mut self, expr: &Expression) -> Result<Self> {
+        if let Expression::ScalarFunction { op, .. } = expr {
+            if ExternalFunction::get(op).is_some() && !self.calls.contains(expr) {
+                self.calls.push(expr.clone());
+            }
+        }
+        Ok(self)
+    }
+}
@@ -34,7 +34,8 @@ pub type TransformFilter = TransformFilterImpl<false>;
 
 pub struct TransformFilterImpl<const HAVING: bool> {
     schema: DataSchemaRef,
-    executor: ExpressionExecutor,
+    // One executor per conjunct of the predicate, cheapest first.
+    executors: Vec<ExpressionExecutor>,
 }
 
 impl<const HAVING: bool> TransformFilterImpl<HAVING>
@@ -47,14 +48,38 @@ where Self: Transform
         output: Arc<OutputPort>,
         ctx: Arc<QueryContext>,
     ) -> Result<ProcessorPtr> {
-        let executor = Self::expr_executor(&schema, &predicate, ctx)?;
-        executor.validate()?;
+        let mut conjunctions = vec![];
+        split_conjunctions(&predicate, &mut conjunctions);
+        conjunctions.sort_by_key(estimate_cost);
+
+        let mut executors = Vec::with_capacity(conjunctions.len());
+        for conjunction in &conjunctions {
+            let executor = Self::expr_executor(&schema, conjunction, ctx.clone())?;
+            executor.validate()?;
+            executors.push(executor);
+        }
+
         Ok(Transformer::create(input, output, TransformFilterImpl {
             schema,
-            executor,
+            executors,
         }))
     }
 
+    // Evaluate the conjunctions one by one, so that the later (and more expensive)
+    // ones only run on the rows that survived the former ones.
+    fn filter(&self, mut data: DataBlock) -> Result<DataBlock> {
+        for executor in &self.executors {
+            let filter_block = executor.execute(&data)?;
+            data = DataBlock::filter_block(&data, filter_block.column(0))?;
+
+            if data.is_empty() {
+                break;
+            }
+        }
+
+        self.correct_with_schema(data)
+    }
+
     fn expr_executor(
         schema: &DataSchemaRef,
         expr: &Expression,
@@ -102,8 +127,7 @@ impl Transform for TransformFilterImpl<true> {
     const SKIP_EMPTY_DATA_BLOCK: bool = true;
 
     fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
-        let filter_block = self.executor.execute(&data)?;
-        self.correct_with_schema(DataBlock::filter_block(&data, filter_block.column(0))?)
+        self.filter(data)
     }
 }
 
@@ -113,7 +137,51 @@ impl Transform for TransformFilterImpl<false> {
     const SKIP_EMPTY_DATA_BLOCK: bool = true;
 
     fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
-        let filter_block = self.executor.execute(&data)?;
-        self.correct_with_schema(DataBlock::filter_block(&data, filter_block.column(0))?)
+        self.filter(data)
+    }
+}
+
+fn split_conjunctions(expr: &Expression, conjunctions: &mut Vec<Expression>) {
+    match expr {
+        Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("and") => {
+            split_conjunctions(left, conjunctions);
+            split_conjunctions(right, conjunctions);
+        }
+        _ => conjunctions.push(expr.clone()),
+    }
+}
+
+// A rough estimation of the evaluation cost of the expression. Equality is preferred
+// because it is usually the most selective one, pattern matching and sub queries are
+// deferred as late as possible.
+fn estimate_cost(expr: &Expression) -> usize {
+    match expr {
+        Expression::Column(_)
+        | Expression::QualifiedColumn(_)
+        | Expression::Literal { .. }
+        | Expression::Wildcard => 0,
+        Expression::Alias(_, expr) | Expression::Sort { expr, .. } => estimate_cost(expr),
+        Expression::Cast { expr, .. } => 2 + estimate_cost(expr),
+        Expression::UnaryExpression { op, expr } => function_cost(op) + estimate_cost(expr),
+        Expression::BinaryExpression { left, op, right } => {
+            function_cost(op) + estimate_cost(left) + estimate_cost(right)
+        }
+        Expression::ScalarFunction { op, args }
+        | Expression::AggregateFunction { op, args, .. } => {
+            function_cost(op) + args.iter().map(estimate_cost).sum::<usize>()
+        }
+        Expression::MapAccess { args, .. } => 4 + args.iter().map(estimate_cost).sum::<usize>(),
+        Expression::ScalarSubquery { .. } | Expression::Subquery { .. } => 1000,
+    }
+}
+
+fn function_cost(op: &str) -> usize {
+    match op.to_lowercase().as_str() {
+        "=" => 1,
+        "<>" | "!=" | "<" | "<=" | ">" | ">=" | "not" | "and" | "or" | "xor" => 2,
+        "like" | "not like" => 20,
+        "regexp" | "not regexp" | "rlike" | "not rlike" => 50,
+        name if name.starts_with("regexp_") => 50,
+        _ => 4,
     }
 }
@@ -64,6 +64,7 @@ pub struct TransformHashJoinProbe {
     output_port: Arc<OutputPort>,
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
+    probe_detached: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -73,15 +74,17 @@ impl TransformHashJoinProbe {
         output_port: Arc<OutputPort>,
         join_state: Arc<dyn HashJoinState>,
         _output_schema: DataSchemaRef,
-    ) -> ProcessorPtr {
-        ProcessorPtr::create(Box::new(TransformHashJoinProbe {
+    ) -> Result<ProcessorPtr> {
+        join_state.attach_probe()?;
+        Ok(ProcessorPtr::create(Box::new(TransformHashJoinProbe {
             input_data: None,
             output_data_blocks: vec![],
             input_port,
             output_port,
             step: HashJoinStep::Build,
             join_state,
-        }))
+            probe_detached: false,
+        })))
     }
 
     fn probe(&mut self, block: &DataBlock) -> Result<()> {
@@ -89,6 +92,16 @@ impl TransformHashJoinProbe {
             .append(&mut self.join_state.probe(block)?);
         Ok(())
     }
+
+    // The last detached probe outputs the unmatched build rows of right join and full join.
+    fn detach_probe(&mut self) -> Result<()> {
+        if !self.probe_detached {
+            self.probe_detached = true;
+            self.output_data_blocks
+                .append(&mut self.join_state.detach_probe()?);
+        }
+        Ok(())
+    }
 }
 
 impl Processor for TransformHashJoinProbe {
@@ -110,6 +123,7 @@ impl Processor for TransformHashJoinProbe {
             }
             HashJoinStep::Probe => {
                 if self.output_port.is_finished() {
+                    self.detach_probe()?;
                     self.input_port.finish();
                     return Ok(Event::Finished);
                 }
@@ -129,6 +143,9 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
+                    if !self.probe_detached {
+                        return Ok(Event::Sync);
+                    }
                     self.output_port.finish();
                     self.step = HashJoinStep::Finished;
                     return Ok(Event::Finished);
@@ -150,12 +167,10 @@ impl Processor for TransformHashJoinProbe {
         match self.step {
             HashJoinStep::Finished => Ok(()),
             HashJoinStep::Build => Ok(()),
-            HashJoinStep::Probe => {
-                if let Some(data) = self.input_data.take() {
-                    self.probe(&data)?;
-                }
-                Ok(())
-            }
+            HashJoinStep::Probe => match self.input_data.take() {
+                Some(data) => self.probe(&data),
+                None => self.detach_probe(),
+            },
         }
     }
 }
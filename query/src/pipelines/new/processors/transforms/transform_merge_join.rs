@@ -0,0 +1,480 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_datablocks::DataBlock;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_tracing::tracing;
+use uuid::Uuid;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::transforms::hash_join::null_block;
+use crate::pipelines::new::processors::transforms::hash_join::nullable_block;
+use crate::pipelines::new::processors::transforms::transform_sort_spill::SortRun;
+use crate::pipelines::new::processors::Processor;
+use crate::pipelines::new::processors::Sink;
+use crate::sessions::TempStorageReservation;
+use crate::sql::planner::plans::JoinType;
+
+/// The sorted right side of a sort-merge join. The right blocks are buffered until they
+/// exceed the memory budget, then they are appended to a sorted run on local disk, which is
+/// read back block by block while merging.
+pub struct SortMergeJoinState {
+    memory_budget: usize,
+    block_size: usize,
+    /// Reference count of the right side sinks
+    ref_count: Mutex<usize>,
+    is_finished: Mutex<bool>,
+    right: Mutex<Option<RightRows>>,
+}
+
+impl SortMergeJoinState {
+    pub fn create(
+        memory_budget: usize,
+        block_size: usize,
+        temp_storage: TempStorageReservation,
+    ) -> Arc<SortMergeJoinState> {
+        Arc::new(SortMergeJoinState {
+            memory_budget,
+            block_size: block_size.max(1),
+            ref_count: Mutex::new(0),
+            is_finished: Mutex::new(false),
+            right: Mutex::new(Some(RightRows {
+                id: Uuid::new_v4().to_simple().to_string(),
+                schema: None,
+                buffer: vec![],
+                buffer_bytes: 0,
+                writer: None,
+                run: None,
+                temp_storage,
+            })),
+        })
+    }
+
+    pub fn attach(&self) -> Result<()> {
+        let mut count = self.ref_count.lock().unwrap();
+        *count += 1;
+        Ok(())
+    }
+
+    pub fn detach(&self) -> Result<()> {
+        let mut count = self.ref_count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            if let Some(right) = self.right.lock().unwrap().as_mut() {
+                right.finish()?;
+            }
+            let mut is_finished = self.is_finished.lock().unwrap();
+            *is_finished = true;
+        }
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> Result<bool> {
+        Ok(*self.is_finished.lock().unwrap())
+    }
+
+    // The right blocks arrive in the order of the keys.
+    pub fn build(&self, block: DataBlock) -> Result<()> {
+        match self.right.lock().unwrap().as_mut() {
+            None => Ok(()),
+            Some(right) => right.push(block, self.memory_budget),
+        }
+    }
+
+    fn take_right(&self) -> Option<RightRows> {
+        self.right.lock().unwrap().take()
+    }
+}
+
+struct RightRows {
+    id: String,
+    schema: Option<DataSchemaRef>,
+    buffer: Vec<DataBlock>,
+    buffer_bytes: usize,
+    writer: Option<BufWriter<File>>,
+    run: Option<SortRun>,
+    // The spilled run is accounted to the temporary storage of the user.
+    temp_storage: TempStorageReservation,
+}
+
+impl RightRows {
+    fn push(&mut self, block: DataBlock, memory_budget: usize) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        if self.schema.is_none() {
+            self.schema = Some(block.schema().clone());
+        }
+
+        self.buffer_bytes += block.memory_size();
+        self.buffer.push(block);
+
+        if self.buffer_bytes > memory_budget {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        if self.writer.is_none() {
+            let path = self.temp_storage.file_path(&format!("merge-join-{}", self.id));
+            tracing::debug!(
+                "Sort-merge join right side exceeds the memory budget, spill to {:?}",
+                path
+            );
+
+            // Create the run before writing, so that the file is removed on failure.
+            self.run = Some(SortRun::create_file(path.clone()));
+            self.writer = Some(BufWriter::new(File::create(&path)?));
+        }
+
+        let writer = self.writer.as_mut().unwrap();
+        let run = self.run.as_mut().unwrap();
+        for block in std::mem::take(&mut self.buffer) {
+            run.write_block(writer, &block, &mut self.temp_storage)?;
+        }
+        self.buffer_bytes = 0;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        match self.writer.is_some() {
+            true => {
+                self.spill()?;
+                if let Some(mut writer) = self.writer.take() {
+                    writer.flush()?;
+                }
+            }
+            false => {
+                let blocks = std::mem::take(&mut self.buffer);
+                self.run = Some(SortRun::create_memory(blocks));
+            }
+        }
+        Ok(())
+    }
+
+    fn next_block(&mut self, block_size: usize) -> Result<Option<DataBlock>> {
+        match (&self.schema, self.run.as_mut()) {
+            (Some(schema), Some(run)) => run.next_block(schema, block_size, &mut self.temp_storage),
+            _ => Ok(None),
+        }
+    }
+}
+
+pub struct SinkMergeJoinRight {
+    join_state: Arc<SortMergeJoinState>,
+}
+
+impl SinkMergeJoinRight {
+    pub fn try_create(join_state: Arc<SortMergeJoinState>) -> Result<Self> {
+        join_state.attach()?;
+        Ok(Self { join_state })
+    }
+}
+
+impl Sink for SinkMergeJoinRight {
+    const NAME: &'static str = "MergeJoinRight";
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.join_state.detach()
+    }
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.join_state.build(data_block)
+    }
+}
+
+enum MergeJoinStep {
+    Build,
+    Merge,
+    Finished,
+}
+
+/// Merge the left blocks sorted by the keys with the sorted right side. The right rows of
+/// the current key are kept in memory, so that all the left rows of the key are joined with
+/// them, and the right rows of smaller keys are skipped.
+pub struct TransformMergeJoin {
+    input_data: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    step: MergeJoinStep,
+    join_state: Arc<SortMergeJoinState>,
+    join_type: JoinType,
+    left_keys: Vec<String>,
+    right_keys: Vec<String>,
+    right_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+
+    right: Option<RightRows>,
+    right_block: Option<DataBlock>,
+    right_row: usize,
+    // The key of the right rows read last and the rows, if they are equal to some left rows.
+    group: Option<(Vec<DataValue>, DataBlock)>,
+}
+
+impl TransformMergeJoin {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        join_state: Arc<SortMergeJoinState>,
+        join_type: JoinType,
+        left_keys: Vec<String>,
+        right_keys: Vec<String>,
+        right_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(TransformMergeJoin {
+            input_data: None,
+            output_data_blocks: VecDeque::new(),
+            input_port,
+            output_port,
+            step: MergeJoinStep::Build,
+            join_state,
+            join_type,
+            left_keys,
+            right_keys,
+            right_schema,
+            output_schema,
+            right: None,
+            right_block: None,
+            right_row: 0,
+            group: None,
+        })))
+    }
+
+    fn merge(&mut self, left: &DataBlock) -> Result<()> {
+        let left_keys = key_columns(left, &self.left_keys)?;
+        let mut indices = vec![];
+        let mut blocks = vec![];
+
+        let mut start = 0;
+        while start < left.num_rows() {
+            let key = row_key(&left_keys, start);
+            let mut end = start + 1;
+            while end < left.num_rows() && row_key(&left_keys, end) == key {
+                end += 1;
+            }
+
+            // NULL keys are never equal, and they are sorted after all the other keys.
+            let group = match key.iter().any(DataValue::is_null) {
+                true => None,
+                false => self.seek(&key)?,
+            };
+
+            match (&self.join_type, group) {
+                (JoinType::SemiJoin, Some(_)) | (JoinType::AntiJoin, None) => {
+                    indices.extend(start as u32..end as u32);
+                }
+                (JoinType::LeftJoin, None) => {
+                    let left_rows = left.slice(start, end - start);
+                    let right_rows = null_block(&self.right_schema, end - start)?;
+                    blocks.push(self.merge_block(&left_rows, &right_rows)?);
+                }
+                (JoinType::InnerJoin | JoinType::LeftJoin, Some(group)) => {
+                    // Every left row of the key is joined with every right row of the key.
+                    let rows = group.num_rows() as u32;
+                    let left_indices = (start as u32..end as u32)
+                        .flat_map(|row| std::iter::repeat(row).take(rows as usize))
+                        .collect::<Vec<_>>();
+                    let right_indices = (start..end).flat_map(|_| 0..rows).collect::<Vec<_>>();
+                    let left_rows = DataBlock::block_take_by_indices(left, &left_indices)?;
+                    let right_rows = DataBlock::block_take_by_indices(&group, &right_indices)?;
+                    let right_rows = match self.join_type {
+                        JoinType::LeftJoin => nullable_block(&right_rows)?,
+                        _ => right_rows,
+                    };
+                    blocks.push(self.merge_block(&left_rows, &right_rows)?);
+                }
+                _ => {}
+            }
+            start = end;
+        }
+
+        if !indices.is_empty() {
+            blocks.push(DataBlock::block_take_by_indices(left, &indices)?);
+        }
+        if !blocks.is_empty() {
+            self.output_data_blocks
+                .push_back(DataBlock::concat_blocks(&blocks)?);
+        }
+        Ok(())
+    }
+
+    fn merge_block(&self, left: &DataBlock, right: &DataBlock) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(left.num_columns() + right.num_columns());
+        columns.extend(left.columns().iter().cloned());
+        columns.extend(right.columns().iter().cloned());
+        Ok(DataBlock::create(self.output_schema.clone(), columns))
+    }
+
+    // Returns the right rows equal to the key. The keys are sought in the ascending order,
+    // the right rows of the smaller keys are skipped.
+    fn seek(&mut self, key: &[DataValue]) -> Result<Option<DataBlock>> {
+        if let Some((group_key, group)) = &self.group {
+            if group_key.as_slice() == key {
+                return Ok(Some(group.clone()));
+            }
+        }
+        self.group = None;
+
+        let mut rows = vec![];
+        while let Some(block) = self.current_right_block()? {
+            let keys = key_columns(&block, &self.right_keys)?;
+            let num_rows = block.num_rows();
+            if rows.is_empty() {
+                if row_key(&keys, num_rows - 1).as_slice() < key {
+                    self.right_block = None;
+                    continue;
+                }
+                while self.right_row < num_rows && row_key(&keys, self.right_row).as_slice() < key
+                {
+                    self.right_row += 1;
+                }
+            }
+
+            let begin = self.right_row;
+            while self.right_row < num_rows && row_key(&keys, self.right_row) == key {
+                self.right_row += 1;
+            }
+            if self.right_row > begin {
+                rows.push(block.slice(begin, self.right_row - begin));
+            }
+
+            // A bigger key is reached, otherwise the rows of the key may go on in the next block.
+            if self.right_row < num_rows {
+                break;
+            }
+            self.right_block = None;
+        }
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let group = DataBlock::concat_blocks(&rows)?;
+        self.group = Some((key.to_vec(), group.clone()));
+        Ok(Some(group))
+    }
+
+    fn current_right_block(&mut self) -> Result<Option<DataBlock>> {
+        if let Some(block) = &self.right_block {
+            if self.right_row < block.num_rows() {
+                return Ok(Some(block.clone()));
+            }
+        }
+
+        self.right_block = None;
+        self.right_row = 0;
+        let right = match self.right.as_mut() {
+            None => return Ok(None),
+            Some(right) => right,
+        };
+        while let Some(block) = right.next_block(self.join_state.block_size)? {
+            if !block.is_empty() {
+                self.right_block = Some(block.clone());
+                return Ok(Some(block));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl Processor for TransformMergeJoin {
+    fn name(&self) -> &'static str {
+        "TransformMergeJoin"
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match self.step {
+            MergeJoinStep::Build => {
+                if self.join_state.is_finished()? {
+                    self.step = MergeJoinStep::Merge;
+                    self.right = self.join_state.take_right();
+                    Ok(Event::Sync)
+                } else {
+                    // Idle till the right side is finished
+                    Ok(Event::NeedData)
+                }
+            }
+            MergeJoinStep::Merge => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    return Ok(Event::NeedConsume);
+                }
+
+                if let Some(data_block) = self.output_data_blocks.pop_front() {
+                    self.output_port.push_data(Ok(data_block));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    self.output_port.finish();
+                    self.step = MergeJoinStep::Finished;
+                    return Ok(Event::Finished);
+                }
+
+                if let Some(data) = self.input_port.pull_data() {
+                    self.input_data = Some(data?);
+                    return Ok(Event::Sync);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+            MergeJoinStep::Finished => Ok(Event::Finished),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.input_data.take() {
+            Some(data) if !data.is_empty() => self.merge(&data),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn key_columns(block: &DataBlock, names: &[String]) -> Result<Vec<ColumnRef>> {
+    names
+        .iter()
+        .map(|name| block.try_column_by_name(name).cloned())
+        .collect()
+}
+
+fn row_key(columns: &[ColumnRef], row: usize) -> Vec<DataValue> {
+    columns.iter().map(|column| column.get(row)).collect()
+}
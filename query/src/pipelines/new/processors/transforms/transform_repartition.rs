@@ -0,0 +1,174 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_exception::Result;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::Processor;
+
+/// Repartition the rows of all the inputs to the outputs by the hash of keys, so that the
+/// rows with the same keys always go to the same output. It's used to spread the work of
+/// a source with few partitions to all the cores.
+pub struct TransformRepartition {
+    inputs: Vec<Arc<InputPort>>,
+    outputs: Vec<Arc<OutputPort>>,
+    keys: Vec<String>,
+
+    input_data: Option<DataBlock>,
+    output_data: Vec<VecDeque<DataBlock>>,
+}
+
+impl TransformRepartition {
+    pub fn create(inputs: usize, outputs: usize, keys: Vec<String>) -> Self {
+        let mut inputs_port = Vec::with_capacity(inputs);
+        let mut outputs_port = Vec::with_capacity(outputs);
+        let mut output_data = Vec::with_capacity(outputs);
+
+        for _index in 0..inputs {
+            inputs_port.push(InputPort::create());
+        }
+
+        for _index in 0..outputs {
+            outputs_port.push(OutputPort::create());
+            output_data.push(VecDeque::new());
+        }
+
+        TransformRepartition {
+            inputs: inputs_port,
+            outputs: outputs_port,
+            keys,
+            input_data: None,
+            output_data,
+        }
+    }
+
+    pub fn get_inputs(&self) -> &[Arc<InputPort>] {
+        &self.inputs
+    }
+
+    pub fn get_outputs(&self) -> &[Arc<OutputPort>] {
+        &self.outputs
+    }
+
+    fn scatter(&self, block: &DataBlock) -> Result<Vec<DataBlock>> {
+        let mut key_columns = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            key_columns.push(block.try_column_by_name(key)?);
+        }
+
+        let method = HashMethodSerializer::default();
+        let keys = method.build_keys(&key_columns, block.num_rows())?;
+
+        let mut indices = Vec::with_capacity(keys.len());
+        for key in keys.iter() {
+            let mut hasher = DefaultHasher::new();
+            key.as_slice().hash(&mut hasher);
+            indices.push(hasher.finish() as usize % self.outputs.len());
+        }
+
+        DataBlock::scatter_block(block, &indices, self.outputs.len())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformRepartition {
+    fn name(&self) -> &'static str {
+        "Repartition"
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.outputs.iter().all(|output| output.is_finished()) {
+            for input in &self.inputs {
+                input.finish();
+            }
+
+            return Ok(Event::Finished);
+        }
+
+        let mut has_pending_data = false;
+        for (output, blocks) in self.outputs.iter().zip(self.output_data.iter_mut()) {
+            if output.is_finished() {
+                blocks.clear();
+                continue;
+            }
+
+            if output.can_push() {
+                if let Some(block) = blocks.pop_front() {
+                    output.push_data(Ok(block));
+                }
+            }
+
+            has_pending_data |= !blocks.is_empty();
+        }
+
+        // Wait for all the outputs to consume the scattered blocks.
+        if has_pending_data {
+            for input in &self.inputs {
+                input.set_not_need_data();
+            }
+
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        for input in &self.inputs {
+            if input.has_data() {
+                self.input_data = Some(input.pull_data().unwrap()?);
+                return Ok(Event::Sync);
+            }
+        }
+
+        if self.inputs.iter().all(|input| input.is_finished()) {
+            for output in &self.outputs {
+                output.finish();
+            }
+
+            return Ok(Event::Finished);
+        }
+
+        for input in &self.inputs {
+            input.set_need_data();
+        }
+
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let blocks = self.scatter(&data_block)?;
+
+            for (index, block) in blocks.into_iter().enumerate() {
+                if !block.is_empty() {
+                    self.output_data[index].push_back(block);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use uuid::Uuid;
+
+use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::Event;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::Processor;
+use crate::sessions::TempStorageReservation;
+
+/// External merge sort of the sorted blocks. The blocks are buffered until they exceed the
+/// memory budget, then they are merged into a sorted run and spilled to local disk. After the
+/// input is finished, the runs are merged block by block, so only the head block of each run
+/// is kept in memory.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+    input_finished: bool,
+    merging: bool,
+    finished: bool,
+
+    id: String,
+    schema: Option<DataSchemaRef>,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    memory_budget: usize,
+    block_size: usize,
+
+    buffer: Vec<DataBlock>,
+    buffer_bytes: usize,
+    runs: Vec<SortRun>,
+    // The spilled runs are accounted to the temporary storage of the user.
+    temp_storage: TempStorageReservation,
+}
+
+impl TransformSortSpill {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        memory_budget: usize,
+        block_size: usize,
+        temp_storage: TempStorageReservation,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Box::new(TransformSortSpill {
+            input,
+            output,
+            input_data: None,
+            output_data: None,
+            input_finished: false,
+            merging: false,
+            finished: false,
+            id: Uuid::new_v4().to_simple().to_string(),
+            schema: None,
+            sort_columns_descriptions,
+            memory_budget,
+            block_size: block_size.max(1),
+            buffer: vec![],
+            buffer_bytes: 0,
+            runs: vec![],
+            temp_storage,
+        })))
+    }
+
+    fn consume(&mut self, block: DataBlock) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        if self.schema.is_none() {
+            self.schema = Some(block.schema().clone());
+        }
+
+        self.buffer_bytes += block.memory_size();
+        self.buffer.push(block);
+
+        if self.buffer_bytes > self.memory_budget {
+            self.spill_run()?;
+        }
+
+        Ok(())
+    }
+
+    fn merge_buffer(&mut self) -> Result<DataBlock> {
+        let blocks = std::mem::take(&mut self.buffer);
+        self.buffer_bytes = 0;
+        DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, None)
+    }
+
+    // Merge the buffered blocks into a sorted run and write it to local disk.
+    fn spill_run(&mut self) -> Result<()> {
+        let block = self.merge_buffer()?;
+        let path = self
+            .temp_storage
+            .file_path(&format!("sort-{}-{}", self.id, self.runs.len()));
+        tracing::debug!(
+            "Sort exceeds the memory budget {} bytes, spill {} rows to {:?}",
+            self.memory_budget,
+            block.num_rows(),
+            path
+        );
+
+        // Create the run before writing, so that the file is removed on failure.
+        let mut run = SortRun::create_file(path.clone());
+        let mut writer = BufWriter::new(File::create(&path)?);
+        run.write_block(&mut writer, &block, &mut self.temp_storage)?;
+
+        writer.flush()?;
+        self.runs.push(run);
+        Ok(())
+    }
+
+    // Called once the input is finished.
+    fn prepare_merge(&mut self) -> Result<()> {
+        self.merging = true;
+        if self.runs.is_empty() {
+            // Nothing is spilled, sort in memory.
+            if !self.buffer.is_empty() {
+                self.output_data = Some(self.merge_buffer()?);
+            }
+            self.finished = true;
+            return Ok(());
+        }
+
+        if !self.buffer.is_empty() {
+            let block = self.merge_buffer()?;
+            self.runs.push(SortRun::create_memory(vec![block]));
+        }
+        Ok(())
+    }
+
+    // Returns true if the last row of `lhs` is placed before the last row of `rhs`.
+    fn last_row_less(&self, lhs: &DataBlock, rhs: &DataBlock) -> Result<bool> {
+        let last = lhs.slice(lhs.num_rows() - 1, 1);
+        let filtered =
+            DataBlock::filter_by_sort_threshold(&last, rhs, &self.sort_columns_descriptions)?;
+        Ok(!filtered.is_empty())
+    }
+
+    // Merge the next block of the runs. The run whose head block ends first bounds the rows
+    // which can be output: its whole head block and the rows before its last row in the
+    // other runs.
+    fn merge_next(&mut self) -> Result<Option<DataBlock>> {
+        let schema = match &self.schema {
+            None => return Ok(None),
+            Some(schema) => schema.clone(),
+        };
+
+        for run in self.runs.iter_mut() {
+            if run.head.is_none() {
+                run.head = run.next_block(&schema, self.block_size, &mut self.temp_storage)?;
+            }
+        }
+        self.runs.retain(|run| run.head.is_some());
+
+        match self.runs.len() {
+            0 => return Ok(None),
+            1 => return Ok(self.runs[0].head.take()),
+            _ => {}
+        }
+
+        let mut bound = 0;
+        for index in 1..self.runs.len() {
+            let head = self.runs[index].head.as_ref().unwrap();
+            let bound_head = self.runs[bound].head.as_ref().unwrap();
+            if self.last_row_less(head, bound_head)? {
+                bound = index;
+            }
+        }
+
+        let threshold = self.runs[bound].head.take().unwrap();
+        let mut blocks = Vec::with_capacity(self.runs.len());
+        for run in self.runs.iter_mut() {
+            if let Some(head) = run.head.take() {
+                let prefix = DataBlock::filter_by_sort_threshold(
+                    &head,
+                    &threshold,
+                    &self.sort_columns_descriptions,
+                )?;
+
+                let rest = head.num_rows() - prefix.num_rows();
+                if rest != 0 {
+                    run.head = Some(head.slice(prefix.num_rows(), rest));
+                }
+                if !prefix.is_empty() {
+                    blocks.push(prefix);
+                }
+            }
+        }
+        blocks.push(threshold);
+
+        let block = DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, None)?;
+        Ok(Some(block))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> &'static str {
+        "SortSpillTransform"
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.output_data.take() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.input_data.is_some() || self.input_finished {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            self.input_finished = true;
+            return Ok(Event::Sync);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            return self.consume(data_block);
+        }
+
+        if !self.merging {
+            return self.prepare_merge();
+        }
+
+        match self.merge_next()? {
+            None => self.finished = true,
+            Some(data_block) => self.output_data = Some(data_block),
+        }
+        Ok(())
+    }
+}
+
+/// A sorted run, either spilled to a file or kept in memory.
+pub(crate) struct SortRun {
+    blocks: VecDeque<DataBlock>,
+    path: Option<PathBuf>,
+    reader: Option<BufReader<File>>,
+    file_size: u64,
+    // The rows of the run which are read but not output yet.
+    head: Option<DataBlock>,
+}
+
+impl SortRun {
+    pub(crate) fn create_memory(blocks: Vec<DataBlock>) -> SortRun {
+        SortRun {
+            blocks: VecDeque::from(blocks),
+            path: None,
+            reader: None,
+            file_size: 0,
+            head: None,
+        }
+    }
+
+    pub(crate) fn create_file(path: PathBuf) -> SortRun {
+        SortRun {
+            blocks: VecDeque::new(),
+            path: Some(path),
+            reader: None,
+            file_size: 0,
+            head: None,
+        }
+    }
+
+    // Append the rows of the block to the file of the run.
+    pub(crate) fn write_block(
+        &mut self,
+        writer: &mut impl Write,
+        block: &DataBlock,
+        temp_storage: &mut TempStorageReservation,
+    ) -> Result<()> {
+        let columns = block.columns().iter().collect::<Vec<_>>();
+        let rows = HashMethodSerializer::default().build_keys(&columns, block.num_rows())?;
+        for row in rows.iter() {
+            let bytes = 8 + row.len() as u64;
+            temp_storage.reserve(bytes)?;
+            self.file_size += bytes;
+
+            writer.write_all(&(row.len() as u64).to_le_bytes())?;
+            writer.write_all(row.as_slice())?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn next_block(
+        &mut self,
+        schema: &DataSchemaRef,
+        block_size: usize,
+        temp_storage: &mut TempStorageReservation,
+    ) -> Result<Option<DataBlock>> {
+        if let Some(block) = self.blocks.pop_front() {
+            return Ok(Some(block));
+        }
+
+        let path = match &self.path {
+            None => return Ok(None),
+            Some(path) => path.clone(),
+        };
+
+        if self.reader.is_none() {
+            self.reader = Some(BufReader::new(File::open(&path)?));
+        }
+
+        let reader = self.reader.as_mut().unwrap();
+        let mut rows = Vec::with_capacity(block_size);
+        while rows.len() < block_size {
+            match Self::read_row(reader)? {
+                None => break,
+                Some(row) => rows.push(row),
+            }
+        }
+
+        if rows.is_empty() {
+            self.reader = None;
+            self.path = None;
+            std::fs::remove_file(&path)?;
+            temp_storage.release(self.file_size);
+            return Ok(None);
+        }
+
+        let method = HashMethodSerializer::default();
+        let columns = method.deserialize_group_columns(rows, schema.fields())?;
+        Ok(Some(DataBlock::create(schema.clone(), columns)))
+    }
+
+    fn read_row(reader: &mut impl Read) -> Result<Option<Vec<u8>>> {
+        let mut len = [0u8; 8];
+        match reader.read_exact(&mut len) {
+            Ok(_) => {}
+            Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        }
+
+        let mut row = vec![0u8; u64::from_le_bytes(len) as usize];
+        reader.read_exact(&mut row).map_err(|cause| {
+            ErrorCode::LogicalError(format!("Corrupted sort spill file, {}", cause))
+        })?;
+        Ok(Some(row))
+    }
+}
+
+impl Drop for SortRun {
+    fn drop(&mut self) {
+        if let Some(path) = &self.path {
+            if let Err(cause) = std::fs::remove_file(path) {
+                tracing::warn!("Cannot remove sort spill file {:?}, {}", path, cause);
+            }
+        }
+    }
+}
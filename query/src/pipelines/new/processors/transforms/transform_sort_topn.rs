@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_exception::Result;
+
+use super::Compactor;
+use super::TransformCompact;
+
+/// Keep the top `limit` rows of all the pushed blocks in one sorted block, instead of
+/// buffering every partially sorted block until the end.
+pub struct TopNCompactor {
+    limit: usize,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+}
+
+impl TopNCompactor {
+    pub fn new(limit: usize, sort_columns_descriptions: Vec<SortColumnDescription>) -> Self {
+        TopNCompactor {
+            limit,
+            sort_columns_descriptions,
+        }
+    }
+}
+
+impl Compactor for TopNCompactor {
+    fn name() -> &'static str {
+        "TopNTransform"
+    }
+
+    fn use_partial_compact() -> bool {
+        true
+    }
+
+    fn compact_partial(&self, blocks: &mut Vec<DataBlock>) -> Result<Vec<DataBlock>> {
+        let descriptions = &self.sort_columns_descriptions;
+        let limit = Some(self.limit);
+
+        if blocks.len() == 1 {
+            blocks[0] = DataBlock::sort_block(&blocks[0], descriptions, limit)?;
+            return Ok(vec![]);
+        }
+
+        let mut block = blocks.pop().unwrap();
+        let top = &blocks[0];
+
+        // Once the top n rows are collected, only the rows before the last one can change
+        // the result. Blocks without such rows are skipped without being sorted.
+        if !top.is_empty() && top.num_rows() >= self.limit {
+            block = DataBlock::filter_by_sort_threshold(&block, top, descriptions)?;
+
+            if block.is_empty() {
+                return Ok(vec![]);
+            }
+        }
+
+        let block = DataBlock::sort_block(&block, descriptions, limit)?;
+        let top = DataBlock::merge_sort_block(top, &block, descriptions, limit)?;
+        blocks[0] = top;
+        Ok(vec![])
+    }
+
+    fn compact_final(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        if blocks.is_empty() {
+            Ok(vec![])
+        } else {
+            let block = DataBlock::merge_sort_blocks(
+                blocks,
+                &self.sort_columns_descriptions,
+                Some(self.limit),
+            )?;
+            Ok(vec![block])
+        }
+    }
+}
+
+pub type TransformTopN = TransformCompact<TopNCompactor>;
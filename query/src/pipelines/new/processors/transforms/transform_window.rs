@@ -0,0 +1,309 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use bumpalo::Bump;
+use common_ast::ast::WindowFrame;
+use common_ast::ast::WindowFrameBound;
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_datavalues::MutableColumn;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+
+use super::Compactor;
+use super::TransformCompact;
+use crate::sql::plans::WindowFuncType;
+
+/// Evaluates a window function over all the input blocks and appends the result as the
+/// last column. The input is sorted by the partition keys and then the order keys.
+///
+/// All the input blocks are concatenated into one block in memory to be sorted, there is no
+/// memory limit or spilling, so the input of a window function must fit in memory.
+pub struct WindowCompactor {
+    schema: DataSchemaRef,
+    func: WindowFuncType,
+    aggregate: Option<AggregateFunctionRef>,
+    arguments: Vec<String>,
+    partition_by: Vec<String>,
+    order_by: Vec<SortColumnDescription>,
+    frame: Option<WindowFrame>,
+}
+
+impl WindowCompactor {
+    pub fn try_create(
+        schema: DataSchemaRef,
+        func: WindowFuncType,
+        arguments: Vec<String>,
+        partition_by: Vec<String>,
+        order_by: Vec<SortColumnDescription>,
+        frame: Option<WindowFrame>,
+    ) -> Result<Self> {
+        let aggregate = match &func {
+            WindowFuncType::Aggregate { func_name, params } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|name| schema.field_with_name(name).cloned())
+                    .collect::<Result<Vec<DataField>>>()?;
+                Some(AggregateFunctionFactory::instance().get(
+                    func_name,
+                    params.clone(),
+                    arguments,
+                )?)
+            }
+            _ => None,
+        };
+
+        Ok(WindowCompactor {
+            schema,
+            func,
+            aggregate,
+            arguments,
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        let fields = self.schema.fields();
+        fields[fields.len() - 1].data_type().clone()
+    }
+
+    fn evaluate(&self, block: &DataBlock) -> Result<ColumnRef> {
+        let columns = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| Ok(block.try_column_by_name(name)?.convert_full_column()))
+                .collect::<Result<Vec<_>>>()
+        };
+        let arguments = columns(&self.arguments)?;
+        let partition_by = columns(&self.partition_by)?;
+        let order_names = self
+            .order_by
+            .iter()
+            .map(|item| item.column_name.clone())
+            .collect::<Vec<_>>();
+        let order_by = columns(&order_names)?;
+
+        let rows = block.num_rows();
+        let mut builder = self.return_type().create_mutable(rows);
+        let arena = Bump::new();
+
+        let mut start = 0;
+        while start < rows {
+            let mut end = start + 1;
+            while end < rows && is_peer(&partition_by, start, end) {
+                end += 1;
+            }
+
+            let partition = Partition {
+                start,
+                end,
+                arguments: &arguments,
+                order_by: &order_by,
+            };
+            self.evaluate_partition(&partition, &arena, builder.as_mut())?;
+            start = end;
+        }
+
+        Ok(builder.to_column())
+    }
+
+    fn evaluate_partition(
+        &self,
+        partition: &Partition,
+        arena: &Bump,
+        builder: &mut dyn MutableColumn,
+    ) -> Result<()> {
+        let Partition {
+            start,
+            end,
+            arguments,
+            order_by,
+        } = *partition;
+
+        match &self.func {
+            WindowFuncType::RowNumber => {
+                for row in start..end {
+                    builder.append_data_value(DataValue::UInt64((row - start + 1) as u64))?;
+                }
+            }
+            WindowFuncType::Rank | WindowFuncType::DenseRank => {
+                let dense = matches!(&self.func, WindowFuncType::DenseRank);
+                let mut rank = 1;
+                for row in start..end {
+                    if row > start && !is_peer(order_by, row - 1, row) {
+                        rank = match dense {
+                            true => rank + 1,
+                            false => row - start + 1,
+                        };
+                    }
+                    builder.append_data_value(DataValue::UInt64(rank as u64))?;
+                }
+            }
+            WindowFuncType::Lag { offset } | WindowFuncType::Lead { offset } => {
+                let lag = matches!(&self.func, WindowFuncType::Lag { .. });
+                let offset = *offset as usize;
+                for row in start..end {
+                    let target = match lag {
+                        true => row.checked_sub(offset).filter(|target| *target >= start),
+                        false => row.checked_add(offset).filter(|target| *target < end),
+                    };
+                    let value = match (target, arguments.get(1)) {
+                        (Some(target), _) => arguments[0].get(target),
+                        (None, Some(default)) => default.get(row),
+                        (None, None) => DataValue::Null,
+                    };
+                    builder.append_data_value(value)?;
+                }
+            }
+            WindowFuncType::Aggregate { .. } => {
+                let func = self.aggregate.as_ref().unwrap();
+                let place: StateAddr = arena.alloc_layout(func.state_layout()).into();
+                // If the frames start at the start of the partition, the frame of a row only
+                // extends the frame of the previous row, so the new rows are accumulated into
+                // the same state instead of accumulating the whole frame again.
+                let cumulative = self.is_frame_start_unbounded();
+                let mut accumulated_end = start;
+                if cumulative {
+                    func.init_state(place);
+                }
+                for row in start..end {
+                    let (frame_start, frame_end) = self.frame_bounds(partition, row);
+                    let accumulate_start = match cumulative {
+                        true => accumulated_end,
+                        false => {
+                            func.init_state(place);
+                            frame_start
+                        }
+                    };
+                    if accumulate_start < frame_end {
+                        let rows = frame_end - accumulate_start;
+                        let columns = arguments
+                            .iter()
+                            .map(|column| column.slice(accumulate_start, rows))
+                            .collect::<Vec<_>>();
+                        func.accumulate(place, &columns, None, rows)?;
+                        accumulated_end = frame_end;
+                    }
+                    func.merge_result(place, builder)?;
+                    if !cumulative && func.need_manual_drop_state() {
+                        unsafe { func.drop_state(place) }
+                    }
+                }
+                if cumulative && func.need_manual_drop_state() {
+                    unsafe { func.drop_state(place) }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Whether the frames start at the start of the partition, their ends never go backwards.
+    fn is_frame_start_unbounded(&self) -> bool {
+        match &self.frame {
+            None => true,
+            Some(frame) => matches!(frame.start_bound, WindowFrameBound::Preceding(None)),
+        }
+    }
+
+    /// Returns the rows `[frame_start, frame_end)` of the window frame of `row`.
+    fn frame_bounds(&self, partition: &Partition, row: usize) -> (usize, usize) {
+        let (start, end) = (partition.start, partition.end);
+        match &self.frame {
+            // The whole partition if there is no `ORDER BY`
+            None if partition.order_by.is_empty() => (start, end),
+            // From the start of the partition to the last peer of the current row
+            None => {
+                let mut peer_end = row + 1;
+                while peer_end < end && is_peer(partition.order_by, row, peer_end) {
+                    peer_end += 1;
+                }
+                (start, peer_end)
+            }
+            Some(frame) => {
+                let position = |bound: &WindowFrameBound| -> i64 {
+                    match bound {
+                        WindowFrameBound::CurrentRow => row as i64,
+                        WindowFrameBound::Preceding(None) => start as i64,
+                        WindowFrameBound::Preceding(Some(n)) => row as i64 - *n as i64,
+                        WindowFrameBound::Following(None) => end as i64 - 1,
+                        WindowFrameBound::Following(Some(n)) => row as i64 + *n as i64,
+                    }
+                };
+                let clamp = |position: i64| position.clamp(start as i64, end as i64) as usize;
+                let frame_start = clamp(position(&frame.start_bound));
+                let frame_end = clamp(position(&frame.end_bound) + 1);
+                (frame_start, frame_end.max(frame_start))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Partition<'a> {
+    start: usize,
+    end: usize,
+    arguments: &'a [ColumnRef],
+    order_by: &'a [ColumnRef],
+}
+
+fn is_peer(columns: &[ColumnRef], lhs: usize, rhs: usize) -> bool {
+    columns
+        .iter()
+        .all(|column| column.get(lhs) == column.get(rhs))
+}
+
+impl Compactor for WindowCompactor {
+    fn name() -> &'static str {
+        "WindowTransform"
+    }
+
+    fn compact_final(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut sort_columns_descriptions = self
+            .partition_by
+            .iter()
+            .map(|name| SortColumnDescription {
+                column_name: name.clone(),
+                asc: true,
+                nulls_first: false,
+            })
+            .collect::<Vec<_>>();
+        sort_columns_descriptions.extend(self.order_by.iter().cloned());
+
+        let block = DataBlock::concat_blocks(blocks)?;
+        let block = match sort_columns_descriptions.is_empty() {
+            true => block,
+            false => DataBlock::sort_block(&block, &sort_columns_descriptions, None)?,
+        };
+
+        let mut columns = block.columns().to_vec();
+        columns.push(self.evaluate(&block)?);
+        Ok(vec![DataBlock::create(self.schema.clone(), columns)])
+    }
+}
+
+pub type TransformWindow = TransformCompact<WindowCompactor>;
@@ -57,18 +57,11 @@ use crate::sessions::QueryContext;
 
 pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
-
-    limit: Option<usize>,
-    offset: usize,
 }
 
 impl PipelineBuilder {
     pub fn create(ctx: Arc<QueryContext>) -> PipelineBuilder {
-        PipelineBuilder {
-            ctx,
-            limit: None,
-            offset: 0,
-        }
+        PipelineBuilder { ctx }
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -243,10 +236,10 @@ impl PipelineBuilder {
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
 
-        // The number of rows should be limit + offset. For example, for the query
-        // 'select * from numbers(100) order by number desc limit 10 offset 5', the
+        // The limit is set by the TopN optimizer to limit + offset. For example, for the
+        // query 'select * from numbers(100) order by number desc limit 10 offset 5', the
         // sort pipeline should return at least 15 rows.
-        let rows_limit = self.limit.map(|limit| limit + self.offset);
+        let rows_limit = plan.limit;
 
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
@@ -289,9 +282,6 @@ impl PipelineBuilder {
     }
 
     fn visit_limit(&mut self, node: &LimitPlan) -> Result<Pipeline> {
-        self.limit = node.n;
-        self.offset = node.offset;
-
         let mut pipeline = self.visit(&*node.input)?;
         pipeline.merge_processor()?;
         pipeline.add_simple_transform(|| {
@@ -82,6 +82,9 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
 
     fn entity_by_key(&mut self, key: &Self::Key, inserted: &mut bool) -> *mut Self::Entity;
 
+    /// Returns true if the key is in the state, the key is not inserted.
+    fn contains_key(&self, key: &Self::Key) -> bool;
+
     fn is_two_level(&self) -> bool {
         false
     }
@@ -188,6 +191,11 @@ where
         self.entity(key, inserted)
     }
 
+    #[inline(always)]
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        unsafe { (*self.data.offset(key.lookup())).fill }
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -261,6 +269,11 @@ where
         self.entity(key, inserted)
     }
 
+    #[inline(always)]
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        self.data.find_key(key).is_some()
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -349,6 +362,11 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
         state_entity
     }
 
+    #[inline(always)]
+    fn contains_key(&self, keys_ref: &KeysRef) -> bool {
+        self.data_state_map.find_key(keys_ref).is_some()
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -421,6 +439,11 @@ impl AggregatorState<HashMethodSingleString> for SerializedKeysAggregatorState {
         state_entity
     }
 
+    #[inline(always)]
+    fn contains_key(&self, keys_ref: &KeysRef) -> bool {
+        self.data_state_map.find_key(keys_ref).is_some()
+    }
+
     #[inline(always)]
     fn is_two_level(&self) -> bool {
         self.two_level_flag
@@ -15,12 +15,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use chrono_tz::Tz;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_functions::scalars::FunctionContext;
 use common_planners::ActionFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
@@ -119,7 +117,8 @@ impl ExpressionExecutor {
                     column_map.insert(input.name.as_str(), column);
                 }
                 ExpressionAction::Function(f) => {
-                    let column_with_field = self.execute_function(&mut column_map, f, rows)?;
+                    let column_with_field =
+                        self.execute_function(&mut column_map, f, rows, block.sequence())?;
                     column_map.insert(f.name.as_str(), column_with_field);
                 }
                 ExpressionAction::Constant(constant) => {
@@ -183,6 +182,7 @@ impl ExpressionExecutor {
         column_map: &mut HashMap<&str, ColumnWithField>,
         f: &ActionFunction,
         rows: usize,
+        sequence: Option<u64>,
     ) -> Result<ColumnWithField> {
         // check if it's cached
         let mut arg_columns = Vec::with_capacity(f.arg_names.len());
@@ -194,14 +194,8 @@ impl ExpressionExecutor {
             arg_columns.push(column);
         }
 
-        let tz = self.ctx.get_settings().get_timezone()?;
-        let tz = String::from_utf8(tz).map_err(|_| {
-            ErrorCode::LogicalError("Timezone has beeen checked and should be valid.")
-        })?;
-        let tz = tz.parse::<Tz>().map_err(|_| {
-            ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
-        })?;
-        let func_ctx = FunctionContext { tz };
+        let mut func_ctx = self.ctx.try_get_function_context()?;
+        func_ctx.block_sequence = sequence;
         let column = f.func.eval(func_ctx, &arg_columns, rows)?;
         Ok(ColumnWithField::new(
             column,
@@ -93,8 +93,9 @@ impl Processor for RemoteTransform {
 
         let fetch_ticket = self.ticket.clone();
         let mut flight_client = self.flight_client().await?;
+        let metrics = self.ctx.get_dal_metrics();
         let fetch_stream = flight_client
-            .fetch_stream(fetch_ticket, data_schema, timeout)
+            .fetch_stream(fetch_ticket, data_schema, timeout, metrics)
             .await?;
         Ok(Box::pin(self.ctx.try_create_abortable(fetch_stream)?))
     }
@@ -15,13 +15,10 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use chrono_tz::Tz;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
-use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::CastFunction;
-use common_functions::scalars::FunctionContext;
 use common_meta_app::schema::TableInfo;
 use common_streams::CastStream;
 use common_streams::SendableDataBlockStream;
@@ -99,14 +96,7 @@ impl Processor for SinkTransform {
                 let cast_function = CastFunction::create("cast", &name, from_type).unwrap();
                 functions.push(cast_function);
             }
-            let tz = self.ctx.get_settings().get_timezone()?;
-            let tz = String::from_utf8(tz).map_err(|_| {
-                ErrorCode::LogicalError("Timezone has beeen checked and should be valid.")
-            })?;
-            let tz = tz.parse::<Tz>().map_err(|_| {
-                ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
-            })?;
-            let func_ctx = FunctionContext { tz };
+            let func_ctx = self.ctx.try_get_function_context()?;
             input_stream = Box::pin(CastStream::try_create(
                 input_stream,
                 cast_schema.clone(),
@@ -14,7 +14,11 @@
 
 use super::tenant_quota::TenantQuotaProcedure;
 use crate::procedures::admins::bootstrap_tenant::BootstrapTenantProcedure;
+use crate::procedures::admins::dump::DumpProcedure;
+use crate::procedures::admins::password_policy::PasswordPolicyProcedure;
+use crate::procedures::admins::reencrypt_storage::ReencryptStorageProcedure;
 use crate::procedures::admins::reload_config::ReloadConfigProcedure;
+use crate::procedures::admins::restore::RestoreProcedure;
 use crate::procedures::ProcedureFactory;
 
 pub struct AdminProcedure;
@@ -33,5 +37,15 @@ impl AdminProcedure {
             "admin$tenant_quota",
             Box::new(TenantQuotaProcedure::try_create),
         );
+        factory.register(
+            "admin$password_policy",
+            Box::new(PasswordPolicyProcedure::try_create),
+        );
+        factory.register("admin$dump", Box::new(DumpProcedure::try_create));
+        factory.register("admin$restore", Box::new(RestoreProcedure::try_create));
+        factory.register(
+            "admin$reencrypt_storage",
+            Box::new(ReencryptStorageProcedure::try_create),
+        );
     }
 }
@@ -0,0 +1,278 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+
+use crate::interpreters::InterpreterFactory;
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::sql::statements::parse_stage_location;
+use crate::sql::PlanParser;
+use crate::storages::fuse::FuseTable;
+use crate::storages::stage::StageSource;
+use crate::storages::view::view_table::show_create_view;
+use crate::storages::view::view_table::VIEW_ENGINE;
+
+pub const DUMP_MANIFEST_FILE_NAME: &str = "dump.json";
+pub const DATABASE_SQL_FILE_NAME: &str = "database.sql";
+pub const TABLE_SQL_FILE_NAME: &str = "table.sql";
+
+/// The layout of a dump under the stage location:
+///
+/// ```text
+/// dump.json
+/// <database>/database.sql
+/// <database>/<table>/table.sql
+/// <database>/<table>/manifest.json
+/// <database>/<table>/data/*.parquet
+/// ```
+///
+/// `dump.json` is written last, a dump without it is incomplete.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DumpManifest {
+    pub tenant: String,
+    pub databases: Vec<DumpedDatabase>,
+    pub dumped_at: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DumpedDatabase {
+    pub name: String,
+    pub tables: Vec<DumpedTable>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DumpedTable {
+    pub name: String,
+    pub engine: String,
+    /// None if the data of the table is not dumped, e.g. views and the non-fuse tables.
+    pub row_count: Option<u64>,
+}
+
+impl DumpedTable {
+    pub fn is_view(&self) -> bool {
+        self.engine == VIEW_ENGINE
+    }
+}
+
+pub struct DumpProcedure;
+
+impl DumpProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(Box::new(DumpProcedure {}))
+    }
+}
+
+#[async_trait::async_trait]
+impl Procedure for DumpProcedure {
+    fn name(&self) -> &str {
+        "DUMP"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default().variadic_arguments(1, 2)
+    }
+
+    /// args:
+    /// location: string, e.g. '@backup/2022-07-01/'
+    /// database: string, dump all the databases if not specified
+    async fn inner_eval(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        let (stage, path) = parse_stage_location(&ctx, &args[0]).await?;
+        let target = StageSource::get_op(&ctx, &stage).await?;
+        let dir = path.trim_end_matches('/');
+
+        let tenant = ctx.get_tenant();
+        let catalog_name = ctx.get_current_catalog();
+        let catalog = ctx.get_catalog(&catalog_name)?;
+
+        let mut dbs = catalog.list_databases(tenant.as_str()).await?;
+        dbs.sort_by(|a, b| a.name().cmp(b.name()));
+
+        let mut databases = vec![];
+        for database in dbs {
+            let db_name = database.name().to_string();
+            if is_system_database(&db_name) || matches!(args.get(1), Some(name) if name != &db_name)
+            {
+                continue;
+            }
+
+            let create_sql = show_create(
+                &ctx,
+                &format!("SHOW CREATE DATABASE `{}`", escape_identifier(&db_name)),
+            )
+            .await?;
+            target
+                .object(&format!("{}/{}/{}", dir, db_name, DATABASE_SQL_FILE_NAME))
+                .write(create_sql.into_bytes())
+                .await?;
+
+            let mut tbls = catalog.list_tables(tenant.as_str(), &db_name).await?;
+            tbls.sort_by(|a, b| a.name().cmp(b.name()));
+
+            let mut tables = vec![];
+            for table in tbls {
+                let table_name = table.name().to_string();
+                ctx.get_current_session()
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            catalog_name.clone(),
+                            db_name.clone(),
+                            table_name.clone(),
+                        ),
+                        UserPrivilegeType::Select,
+                    )
+                    .await?;
+
+                let table_dir = format!("{}/{}/{}", dir, db_name, table_name);
+                let mut dumped = DumpedTable {
+                    name: table_name.clone(),
+                    engine: table.engine().to_string(),
+                    row_count: None,
+                };
+
+                let create_sql = match dumped.is_view() {
+                    true => show_create_view(&table_name, table.get_table_info().options())?,
+                    false => {
+                        show_create(
+                            &ctx,
+                            &format!(
+                                "SHOW CREATE TABLE `{}`.`{}`",
+                                escape_identifier(&db_name),
+                                escape_identifier(&table_name)
+                            ),
+                        )
+                        .await?
+                    }
+                };
+                target
+                    .object(&format!("{}/{}", table_dir, TABLE_SQL_FILE_NAME))
+                    .write(create_sql.into_bytes())
+                    .await?;
+
+                if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                    let manifest = fuse_table
+                        .do_export_snapshot(&ctx, &target, &table_dir)
+                        .await?;
+                    dumped.row_count = Some(manifest.row_count);
+                }
+
+                tables.push(dumped);
+            }
+
+            databases.push(DumpedDatabase {
+                name: db_name,
+                tables,
+            });
+        }
+
+        let manifest = DumpManifest {
+            tenant,
+            databases,
+            dumped_at: Utc::now(),
+        };
+        target
+            .object(&format!("{}/{}", dir, DUMP_MANIFEST_FILE_NAME))
+            .write(serde_json::to_vec_pretty(&manifest)?)
+            .await?;
+
+        tracing::info!(
+            "dumped {} databases to {}",
+            manifest.databases.len(),
+            args[0]
+        );
+        dump_result_block(self.schema(), &manifest)
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        dump_result_schema()
+    }
+}
+
+fn is_system_database(name: &str) -> bool {
+    name == "system" || name.to_uppercase() == "INFORMATION_SCHEMA"
+}
+
+// Runs the statement and returns the second column of the result, i.e. the
+// `Create Table` or `Create Database` of a SHOW CREATE statement.
+async fn show_create(ctx: &Arc<QueryContext>, query: &str) -> Result<String> {
+    let blocks = execute_query(ctx, query).await?;
+    match blocks.iter().find(|block| block.num_rows() > 0) {
+        Some(block) => {
+            let value = block.column(1).get(0).as_string()?;
+            Ok(String::from_utf8(value)?)
+        }
+        None => Err(ErrorCode::LogicalError(format!(
+            "Logical error: {} returns nothing",
+            query
+        ))),
+    }
+}
+
+/// Quotes by backticks are doubled in a quoted identifier.
+pub(crate) fn escape_identifier(name: &str) -> String {
+    name.replace('`', "``")
+}
+
+pub(crate) async fn execute_query(ctx: &Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+pub(crate) fn dump_result_schema() -> Arc<DataSchema> {
+    DataSchemaRefExt::create(vec![
+        DataField::new("database", Vu8::to_data_type()),
+        DataField::new("name", Vu8::to_data_type()),
+        DataField::new("engine", Vu8::to_data_type()),
+        DataField::new_nullable("rows", u64::to_data_type()),
+    ])
+}
+
+pub(crate) fn dump_result_block(
+    schema: Arc<DataSchema>,
+    manifest: &DumpManifest,
+) -> Result<DataBlock> {
+    let mut databases = vec![];
+    let mut names = vec![];
+    let mut engines = vec![];
+    let mut rows = vec![];
+    for database in &manifest.databases {
+        for table in &database.tables {
+            databases.push(database.name.as_bytes().to_vec());
+            names.push(table.name.as_bytes().to_vec());
+            engines.push(table.engine.as_bytes().to_vec());
+            rows.push(table.row_count);
+        }
+    }
+
+    Ok(DataBlock::create(schema, vec![
+        Series::from_data(databases),
+        Series::from_data(names),
+        Series::from_data(engines),
+        Series::from_data(rows),
+    ]))
+}
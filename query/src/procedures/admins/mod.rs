@@ -14,7 +14,11 @@
 
 mod admin;
 pub mod bootstrap_tenant;
+pub mod dump;
+pub mod password_policy;
+pub mod reencrypt_storage;
 pub mod reload_config;
+pub mod restore;
 pub mod tenant_quota;
 
 pub use admin::AdminProcedure;
@@ -0,0 +1,133 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::UserOptionFlag;
+
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+
+pub struct PasswordPolicyProcedure;
+
+impl PasswordPolicyProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(Box::new(PasswordPolicyProcedure {}))
+    }
+}
+
+#[async_trait::async_trait]
+impl Procedure for PasswordPolicyProcedure {
+    fn name(&self) -> &str {
+        "PASSWORD_POLICY"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default()
+            .variadic_arguments(0, 10)
+            .management_mode_required(true)
+    }
+
+    /// args:
+    /// tenant_id: string
+    /// min_length: u32
+    /// min_upper_case_chars: u32
+    /// min_lower_case_chars: u32
+    /// min_numeric_chars: u32
+    /// min_special_chars: u32
+    /// max_age_days: u32
+    /// history: u32
+    /// max_retries: u32
+    /// lockout_time_mins: u32
+    async fn inner_eval(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        let mut tenant = ctx.get_tenant();
+        if !args.is_empty() {
+            let user_info = ctx.get_current_user()?;
+            if !user_info.has_option_flag(UserOptionFlag::TenantSetting) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Access denied: '{}' requires user {} option flag",
+                    self.name(),
+                    UserOptionFlag::TenantSetting
+                )));
+            }
+            tenant = args[0].clone();
+        }
+        let policy_api = ctx
+            .get_user_manager()
+            .get_password_policy_api_client(&tenant)?;
+        let res = policy_api.get_policy(None).await?;
+        let mut policy = res.data;
+
+        if args.len() <= 1 {
+            return self.to_block(&policy);
+        };
+
+        let fields = [
+            &mut policy.min_length,
+            &mut policy.min_upper_case_chars,
+            &mut policy.min_lower_case_chars,
+            &mut policy.min_numeric_chars,
+            &mut policy.min_special_chars,
+            &mut policy.max_age_days,
+            &mut policy.history,
+            &mut policy.max_retries,
+            &mut policy.lockout_time_mins,
+        ];
+        for (field, arg) in fields.into_iter().zip(args.iter().skip(1)) {
+            *field = arg.parse::<u32>()?;
+        }
+
+        policy_api.set_policy(&policy, Some(res.seq)).await?;
+
+        self.to_block(&policy)
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        DataSchemaRefExt::create(vec![
+            DataField::new("min_length", u32::to_data_type()),
+            DataField::new("min_upper_case_chars", u32::to_data_type()),
+            DataField::new("min_lower_case_chars", u32::to_data_type()),
+            DataField::new("min_numeric_chars", u32::to_data_type()),
+            DataField::new("min_special_chars", u32::to_data_type()),
+            DataField::new("max_age_days", u32::to_data_type()),
+            DataField::new("history", u32::to_data_type()),
+            DataField::new("max_retries", u32::to_data_type()),
+            DataField::new("lockout_time_mins", u32::to_data_type()),
+        ])
+    }
+}
+
+impl PasswordPolicyProcedure {
+    fn to_block(&self, policy: &PasswordPolicy) -> Result<DataBlock> {
+        Ok(DataBlock::create(self.schema(), vec![
+            Series::from_data(vec![policy.min_length]),
+            Series::from_data(vec![policy.min_upper_case_chars]),
+            Series::from_data(vec![policy.min_lower_case_chars]),
+            Series::from_data(vec![policy.min_numeric_chars]),
+            Series::from_data(vec![policy.min_special_chars]),
+            Series::from_data(vec![policy.max_age_days]),
+            Series::from_data(vec![policy.history]),
+            Series::from_data(vec![policy.max_retries]),
+            Series::from_data(vec![policy.lockout_time_mins]),
+        ]))
+    }
+}
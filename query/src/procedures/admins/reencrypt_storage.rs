@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::storages::encryption::reencrypt_objects;
+
+pub struct ReencryptStorageProcedure;
+
+impl ReencryptStorageProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(Box::new(ReencryptStorageProcedure {}))
+    }
+}
+
+#[async_trait::async_trait]
+impl Procedure for ReencryptStorageProcedure {
+    fn name(&self) -> &str {
+        "REENCRYPT_STORAGE"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default()
+            .variadic_arguments(0, 1)
+            .management_mode_required(true)
+    }
+
+    /// args:
+    /// path: string, the prefix of the objects to re-encrypt, the whole storage if not specified
+    async fn inner_eval(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        let keyring = ctx.get_storage_keyring().ok_or_else(|| {
+            ErrorCode::InvalidConfig(
+                "Storage encryption is not enabled, please set storage_encryption_key_file or storage_encryption_kms_command",
+            )
+        })?;
+
+        let path = args.get(0).map(|path| path.as_str()).unwrap_or("/");
+        let operator = ctx.get_raw_storage_operator()?;
+        let stats = reencrypt_objects(&operator, &keyring, path).await?;
+
+        tracing::info!(
+            "re-encrypted {} of {} objects under {} with the key {}",
+            stats.reencrypted,
+            stats.scanned,
+            path,
+            keyring.active_key().0
+        );
+        Ok(DataBlock::create(self.schema(), vec![
+            Series::from_data(vec![stats.scanned]),
+            Series::from_data(vec![stats.reencrypted]),
+        ]))
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        DataSchemaRefExt::create(vec![
+            DataField::new("scanned", u64::to_data_type()),
+            DataField::new("reencrypted", u64::to_data_type()),
+        ])
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use opendal::Operator;
+
+use crate::interpreters::InterpreterFactory;
+use crate::procedures::admins::dump::dump_result_block;
+use crate::procedures::admins::dump::dump_result_schema;
+use crate::procedures::admins::dump::escape_identifier;
+use crate::procedures::admins::dump::execute_query;
+use crate::procedures::admins::dump::DumpManifest;
+use crate::procedures::admins::dump::DATABASE_SQL_FILE_NAME;
+use crate::procedures::admins::dump::DUMP_MANIFEST_FILE_NAME;
+use crate::procedures::admins::dump::TABLE_SQL_FILE_NAME;
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::sql::statements::parse_stage_location;
+use crate::sql::statements::resolve_database;
+use crate::sql::statements::resolve_table;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::storages::stage::StageSource;
+
+pub struct RestoreProcedure;
+
+impl RestoreProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(Box::new(RestoreProcedure {}))
+    }
+}
+
+#[async_trait::async_trait]
+impl Procedure for RestoreProcedure {
+    fn name(&self) -> &str {
+        "RESTORE"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default().num_arguments(1)
+    }
+
+    /// args:
+    /// location: string, the location passed to `admin$dump`
+    async fn inner_eval(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        // The restore creates the databases and the tables of the whole dump.
+        ctx.get_current_session()
+            .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+            .await?;
+
+        let location = args[0].trim_end_matches('/');
+        let (stage, path) = parse_stage_location(&ctx, location).await?;
+        let source = StageSource::get_op(&ctx, &stage).await?;
+        let dir = path.trim_end_matches('/');
+
+        let bytes = source
+            .object(&format!("{}/{}", dir, DUMP_MANIFEST_FILE_NAME))
+            .read()
+            .await?;
+        let manifest: DumpManifest = serde_json::from_slice(&bytes)?;
+
+        // The statements of the dump are not qualified by the database, they are run
+        // with the current database switched, which is recovered at last.
+        let current_database = ctx.get_current_database();
+        let restored = self.restore(&ctx, &source, dir, location, &manifest).await;
+        ctx.set_current_database(current_database).await?;
+        restored?;
+
+        tracing::info!(
+            "restored {} databases from {}",
+            manifest.databases.len(),
+            args[0]
+        );
+        dump_result_block(self.schema(), &manifest)
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        dump_result_schema()
+    }
+}
+
+impl RestoreProcedure {
+    async fn restore(
+        &self,
+        ctx: &Arc<QueryContext>,
+        source: &Operator,
+        dir: &str,
+        location: &str,
+        manifest: &DumpManifest,
+    ) -> Result<()> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(&ctx.get_current_catalog())?;
+
+        for database in &manifest.databases {
+            // The databases that already exist are reused, e.g. `default`.
+            if !catalog.exists_database(&tenant, &database.name).await? {
+                let database_dir = format!("{}/{}", dir, database.name);
+                let sql = read_sql(source, &database_dir, DATABASE_SQL_FILE_NAME).await?;
+                execute_create(ctx, &sql, &database.name, None).await?;
+            }
+            ctx.set_current_database(database.name.clone()).await?;
+
+            for table in database.tables.iter().filter(|table| !table.is_view()) {
+                let table_dir = format!("{}/{}/{}", dir, database.name, table.name);
+                let sql = read_sql(source, &table_dir, TABLE_SQL_FILE_NAME).await?;
+                execute_create(ctx, &sql, &database.name, Some(&table.name)).await?;
+
+                if matches!(table.row_count, Some(rows) if rows > 0) {
+                    let copy = format!(
+                        "COPY INTO `{}`.`{}` FROM '{}' FILE_FORMAT = (type = 'PARQUET')",
+                        escape_identifier(&database.name),
+                        escape_identifier(&table.name),
+                        escape_quote(&format!(
+                            "{}/{}/{}/data/",
+                            location, database.name, table.name
+                        )),
+                    );
+                    execute_query(ctx, &copy).await?;
+                }
+            }
+        }
+
+        // The views are restored after all the tables, which they may refer to.
+        for database in &manifest.databases {
+            ctx.set_current_database(database.name.clone()).await?;
+            for table in database.tables.iter().filter(|table| table.is_view()) {
+                let table_dir = format!("{}/{}/{}", dir, database.name, table.name);
+                let sql = read_sql(source, &table_dir, TABLE_SQL_FILE_NAME).await?;
+                execute_create(ctx, &sql, &database.name, Some(&table.name)).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn read_sql(source: &Operator, dir: &str, file_name: &str) -> Result<String> {
+    let bytes = source
+        .object(&format!("{}/{}", dir, file_name))
+        .read()
+        .await?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+/// Runs a statement read from the dump, which must be the CREATE DATABASE of `database`,
+/// or the CREATE TABLE or CREATE VIEW of `table` if specified. The dump files are not
+/// trusted, any other statement is rejected.
+async fn execute_create(
+    ctx: &Arc<QueryContext>,
+    sql: &str,
+    database: &str,
+    table: Option<&str>,
+) -> Result<()> {
+    let (statements, _) = DfParser::parse_sql(sql, ctx.get_current_session().get_type())?;
+    let name = match (statements.as_slice(), table) {
+        ([DfStatement::CreateDatabase(stmt)], None) => {
+            Some(resolve_database(ctx, &stmt.name, "RESTORE")?.1)
+        }
+        ([DfStatement::CreateTable(stmt)], Some(_))
+            if stmt.like.is_none() && stmt.query.is_none() =>
+        {
+            let (_, db, tbl) = resolve_table(ctx, &stmt.name, "RESTORE")?;
+            (db == database).then_some(tbl)
+        }
+        ([DfStatement::CreateView(stmt)], Some(_)) => {
+            let (_, db, tbl) = resolve_table(ctx, &stmt.name, "RESTORE")?;
+            (db == database).then_some(tbl)
+        }
+        _ => None,
+    };
+    if name.as_deref() != Some(table.unwrap_or(database)) {
+        return Err(ErrorCode::BadArguments(format!(
+            "Restore expects the CREATE statement of `{}`, but got: {}",
+            table.map_or_else(|| database.to_string(), |t| format!("{}.{}", database, t)),
+            sql
+        )));
+    }
+
+    let plan = PlanParser::build_plan(statements, ctx.clone()).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+fn escape_quote(value: &str) -> String {
+    value.replace('\'', "''")
+}
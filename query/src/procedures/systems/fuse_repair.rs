@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+
+pub struct FuseRepairProcedure {}
+
+impl FuseRepairProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(Box::new(FuseRepairProcedure {}))
+    }
+}
+
+#[async_trait::async_trait]
+impl Procedure for FuseRepairProcedure {
+    fn name(&self) -> &str {
+        "FUSE_REPAIR"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default().num_arguments(2)
+    }
+
+    async fn inner_eval(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        let catalog_name = ctx.get_current_catalog();
+        let database_name = args[0].clone();
+        let table_name = args[1].clone();
+        let tenant_id = ctx.get_tenant();
+        let tbl = ctx
+            .get_catalog(&catalog_name)?
+            .get_table(
+                tenant_id.as_str(),
+                database_name.as_str(),
+                table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = FuseTable::try_from_table(tbl.as_ref())?;
+        let repaired = tbl.do_repair(&ctx).await?;
+
+        let mut locations: Vec<Vec<u8>> = Vec::with_capacity(repaired.len());
+        let mut states: Vec<Vec<u8>> = Vec::with_capacity(repaired.len());
+        for (location, state) in repaired {
+            locations.push(location.into_bytes());
+            states.push(state.to_string().into_bytes());
+        }
+
+        Ok(DataBlock::create(self.schema(), vec![
+            Series::from_data(locations),
+            Series::from_data(states),
+        ]))
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        DataSchemaRefExt::create(vec![
+            DataField::new("block_location", Vu8::to_data_type()),
+            DataField::new("state", Vu8::to_data_type()),
+        ])
+    }
+}
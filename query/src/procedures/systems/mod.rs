@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod clustering_information;
+mod fuse_repair;
 mod fuse_segment;
 mod fuse_snapshot;
 mod search_tables;
 mod system;
 
 pub use clustering_information::ClusteringInformationProcedure;
+pub use fuse_repair::FuseRepairProcedure;
 pub use fuse_segment::FuseSegmentProcedure;
 pub use fuse_snapshot::FuseSnapshotProcedure;
 pub use search_tables::SearchTablesProcedure;
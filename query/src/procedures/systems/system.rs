@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::procedures::systems::ClusteringInformationProcedure;
+use crate::procedures::systems::FuseRepairProcedure;
 use crate::procedures::systems::FuseSegmentProcedure;
 use crate::procedures::systems::FuseSnapshotProcedure;
 use crate::procedures::systems::SearchTablesProcedure;
@@ -34,6 +35,10 @@ impl SystemProcedure {
             "system$fuse_segment",
             Box::new(FuseSegmentProcedure::try_create),
         );
+        factory.register(
+            "system$fuse_repair",
+            Box::new(FuseRepairProcedure::try_create),
+        );
         factory.register(
             "system$search_tables",
             Box::new(SearchTablesProcedure::try_create),
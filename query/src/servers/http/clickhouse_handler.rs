@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
 use std::sync::Arc;
 
 use async_stream::stream;
@@ -22,6 +21,7 @@ use common_exception::ToErrorCode;
 use common_io::prelude::BufferReader;
 use common_io::prelude::CheckpointReader;
 use common_io::prelude::FormatSettings;
+use common_planners::InsertPlan;
 use common_planners::PlanNode;
 use common_streams::NDJsonSourceBuilder;
 use common_streams::SendableDataBlockStream;
@@ -32,6 +32,7 @@ use nom::AsBytes;
 use poem::error::BadRequest;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
+use poem::http::StatusCode;
 use poem::post;
 use poem::web::Query;
 use poem::Body;
@@ -40,12 +41,14 @@ use poem::EndpointExt;
 use poem::Route;
 use serde::Deserialize;
 
-use crate::formats::output_format::OutputFormatType;
+use crate::formats::FormatFactory;
 use crate::interpreters::InterpreterFactory;
 use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::StreamSource;
 use crate::pipelines::new::SourcePipeBuilder;
 use crate::servers::http::v1::HttpQueryContext;
+use crate::servers::http::v1::IdempotentLoad;
+use crate::servers::http::v1::LoadClaim;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionType;
 use crate::sql::statements::ValueSource;
@@ -54,6 +57,7 @@ use crate::sql::PlanParser;
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     query: Option<String>,
+    idempotency_key: Option<String>,
 }
 
 async fn execute(
@@ -62,6 +66,13 @@ async fn execute(
     format: Option<String>,
     input_stream: Option<SendableDataBlockStream>,
 ) -> Result<Body> {
+    // The format of an INSERT describes its input, the output is empty.
+    let format = match (&plan, format) {
+        (PlanNode::Insert(_), _) | (_, None) => "TSV".to_string(),
+        (_, Some(format)) => format,
+    };
+    let mut output_format = FormatFactory::instance().get_output(&format, plan.schema())?;
+
     let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
     let _ = interpreter
         .start()
@@ -85,12 +96,6 @@ async fn execute(
         };
     let mut data_stream = ctx.try_create_abortable(data_stream)?;
     let format_setting = ctx.get_format_settings()?;
-    let mut fmt = OutputFormatType::Tsv;
-    if let Some(format) = format {
-        fmt = OutputFormatType::from_str(format.as_str())?;
-    }
-
-    let mut output_format = fmt.create_format(plan.schema());
     let stream = stream! {
         while let Some(block) = data_stream.next().await {
             match block{
@@ -185,9 +190,50 @@ pub async fn clickhouse_handler_post(
     };
 
     ctx.attach_query_str(&sql);
-    execute(ctx, plan, format, input_stream)
-        .await
-        .map_err(InternalServerError)
+    match (&plan, params.idempotency_key) {
+        (PlanNode::Insert(insert), Some(key)) => {
+            let insert = insert.clone();
+            execute_idempotent(ctx, plan, &insert, format, input_stream, &key).await
+        }
+        _ => execute(ctx, plan, format, input_stream)
+            .await
+            .map_err(InternalServerError),
+    }
+}
+
+// Execute an INSERT deduplicated by the idempotency key, a retry of a succeeded INSERT
+// returns an empty body without inserting the data again.
+async fn execute_idempotent(
+    ctx: Arc<QueryContext>,
+    plan: PlanNode,
+    insert: &InsertPlan,
+    format: Option<String>,
+    input_stream: Option<SendableDataBlockStream>,
+    key: &str,
+) -> PoemResult<Body> {
+    let load = match IdempotentLoad::claim(&ctx, insert, key, &ctx.get_id()).await {
+        Ok(LoadClaim::Claimed(load)) => load,
+        Ok(LoadClaim::Succeeded(_)) => return Ok(Body::empty()),
+        Err(cause) if cause.code() == ErrorCode::LoadAlreadyRunning("").code() => {
+            return Err(poem::Error::from_string(
+                cause.message(),
+                StatusCode::CONFLICT,
+            ));
+        }
+        Err(cause) if cause.code() == ErrorCode::BadArguments("").code() => {
+            return Err(BadRequest(cause));
+        }
+        Err(cause) => return Err(InternalServerError(cause)),
+    };
+
+    // The body must be drained to know whether the INSERT succeeded. The key is recorded
+    // by the commit of the INSERT, whatever the release of the key results in.
+    let result = match execute(ctx.clone(), plan, format, input_stream).await {
+        Ok(body) => body.into_vec().await.map_err(InternalServerError),
+        Err(cause) => Err(InternalServerError(cause)),
+    };
+    load.release().await;
+    result.map(Body::from)
 }
 
 // TODO: use format pipeline
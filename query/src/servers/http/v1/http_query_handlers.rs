@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
-
 use common_base::base::ProgressValues;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
@@ -40,7 +38,9 @@ use serde_json::Value as JsonValue;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
-use crate::formats::output_format::OutputFormatType;
+use super::query::NodeLoadHint;
+use super::query::ScanPartitions;
+use crate::formats::FormatFactory;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::SessionType;
@@ -80,6 +80,9 @@ impl QueryError {
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct QueryStats {
     pub scan_progress: Option<ProgressValues>,
+    /// The partitions to scan after pruning, out of all the partitions of the tables read.
+    #[serde(default)]
+    pub scan_partitions: Option<ScanPartitions>,
     pub running_time_ms: f64,
 }
 
@@ -98,6 +101,9 @@ pub struct QueryResponse {
     pub final_uri: Option<String>,
     pub next_uri: Option<String>,
     pub kill_uri: Option<String>,
+    // query nodes of the cluster with their load, for client side load balancing
+    #[serde(default)]
+    pub nodes: Vec<NodeLoadHint>,
 }
 
 impl QueryResponse {
@@ -113,6 +119,7 @@ impl QueryResponse {
         let session_id = r.session_id.clone();
         let stats = QueryStats {
             scan_progress: state.scan_progress.clone(),
+            scan_partitions: state.scan_partitions,
             running_time_ms: state.running_time_ms,
         };
         QueryResponse {
@@ -127,6 +134,7 @@ impl QueryResponse {
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
+            nodes: r.nodes,
         }
     }
 
@@ -143,6 +151,7 @@ impl QueryResponse {
             final_uri: None,
             kill_uri: None,
             error: Some(QueryError::from_error_code(err)),
+            nodes: vec![],
         }
     }
 }
@@ -283,8 +292,13 @@ async fn result_download_handler(
 ) -> PoemResult<Body> {
     let default_format = "csv".to_string();
     let session = ctx.get_session(SessionType::HTTPQuery);
-    let format =
-        OutputFormatType::from_str(&params.format.unwrap_or(default_format)).map_err(BadRequest)?;
+    let format = params.format.unwrap_or(default_format);
+    if !FormatFactory::instance().has_output(&format) {
+        return Err(BadRequest(ErrorCode::UnknownFormat(format!(
+            "Unsupported output formats: {}",
+            format
+        ))));
+    }
 
     let ctx = session
         .create_query_context()
@@ -302,7 +316,7 @@ async fn result_download_handler(
         })?;
 
     let stream = result_table
-        .download(ctx, format)
+        .download(ctx, &format)
         .await
         .map_err(InternalServerError)?;
 
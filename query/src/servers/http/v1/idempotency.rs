@@ -0,0 +1,178 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::LoadApi;
+use common_meta_app::schema::TableInfo;
+use common_meta_types::LoadInfo;
+use common_planners::InsertPlan;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+
+/// The name of the header or the parameter carrying the idempotency key of a load.
+pub const IDEMPOTENCY_KEY: &str = "idempotency_key";
+
+/// How long a running load holds its idempotency key without renewing it. The key of a load
+/// interrupted by a disconnection or a crash is released after this.
+const LOAD_LEASE: Duration = Duration::from_secs(30);
+
+pub enum LoadClaim {
+    // The key is claimed by the current load, which should go on.
+    Claimed(IdempotentLoad),
+    // A load with the same key has succeeded, the current one is a retry and should be skipped.
+    Succeeded(LoadInfo),
+}
+
+/// A load deduplicated by the idempotency key of the client, so that the retries of a load
+/// after timeouts don't load the data twice.
+///
+/// While the load is running, the key is held by a lease in the meta service which is renewed
+/// in background. The commit of the load records the key in the meta service until the
+/// retention of the keys expires, see `FuseTable::get_committed_load`.
+pub struct IdempotentLoad {
+    api: Arc<dyn LoadApi>,
+    table_id: u64,
+    key: String,
+    // The seq of the lease, updated by the heartbeat on every renewal.
+    seq: Arc<AtomicU64>,
+    heartbeat: JoinHandle<()>,
+}
+
+impl IdempotentLoad {
+    pub async fn claim(
+        ctx: &Arc<QueryContext>,
+        plan: &InsertPlan,
+        key: &str,
+        id: &str,
+    ) -> Result<LoadClaim> {
+        let api = ctx
+            .get_user_manager()
+            .get_load_api_client(&ctx.get_tenant(), LOAD_LEASE)?;
+        if let Some(info) = committed_load(ctx, api.as_ref(), plan, key).await? {
+            return Ok(LoadClaim::Succeeded(info));
+        }
+
+        let info = LoadInfo::new(id);
+        let seq = match api.claim_load(plan.table_id, key, info.clone()).await {
+            Ok(seq) => Arc::new(AtomicU64::new(seq)),
+            // The load holding the key may have been committed without releasing it.
+            Err(cause) if cause.code() == ErrorCode::LoadAlreadyRunning("").code() => {
+                return match committed_load(ctx, api.as_ref(), plan, key).await? {
+                    Some(info) => Ok(LoadClaim::Succeeded(info)),
+                    None => Err(cause),
+                };
+            }
+            Err(cause) => return Err(cause),
+        };
+
+        let heartbeat = tokio::spawn(heartbeat(
+            api.clone(),
+            plan.table_id,
+            key.to_string(),
+            info.clone(),
+            seq.clone(),
+        ));
+        let load = IdempotentLoad {
+            api,
+            table_id: plan.table_id,
+            key: key.to_string(),
+            seq,
+            heartbeat,
+        };
+
+        // A load with the key may have been committed and released it since the first check.
+        if let Some(info) = committed_load(ctx, api.as_ref(), plan, key).await? {
+            load.release().await;
+            return Ok(LoadClaim::Succeeded(info));
+        }
+
+        ctx.set_load_idempotency_key(plan.table_id, key, info, load.api.clone());
+        Ok(LoadClaim::Claimed(load))
+    }
+
+    // Release the key once the load is committed or failed, the committed load is recorded
+    // and the failed one can be retried. The key is released by the lease
+    // expiration anyway, so the errors are only logged.
+    pub async fn release(self) {
+        self.heartbeat.abort();
+        let seq = self.seq.load(Ordering::SeqCst);
+        if let Err(cause) = self.api.drop_load(self.table_id, &self.key, seq).await {
+            tracing::warn!("release idempotency key '{}' error: {:?}", self.key, cause);
+        }
+    }
+}
+
+impl Drop for IdempotentLoad {
+    fn drop(&mut self) {
+        // The load is interrupted, e.g. the client disconnected, let the lease expire.
+        self.heartbeat.abort();
+    }
+}
+
+async fn heartbeat(
+    api: Arc<dyn LoadApi>,
+    table_id: u64,
+    key: String,
+    info: LoadInfo,
+    seq: Arc<AtomicU64>,
+) {
+    loop {
+        tokio::time::sleep(LOAD_LEASE / 3).await;
+        let current = seq.load(Ordering::SeqCst);
+        match api.renew_load(table_id, &key, current, info.clone()).await {
+            Ok(new_seq) => seq.store(new_seq, Ordering::SeqCst),
+            Err(cause) if cause.code() == ErrorCode::UnknownLoad("").code() => {
+                tracing::warn!("the lease of idempotency key '{}' is lost", key);
+                return;
+            }
+            Err(cause) => tracing::warn!("renew idempotency key '{}' error: {:?}", key, cause),
+        }
+    }
+}
+
+// The load committed to the table of the plan with the key, read from the latest table meta.
+async fn committed_load(
+    ctx: &Arc<QueryContext>,
+    api: &dyn LoadApi,
+    plan: &InsertPlan,
+    key: &str,
+) -> Result<Option<LoadInfo>> {
+    let catalog = ctx.get_catalog(&plan.catalog_name)?;
+    let (ident, meta) = catalog.get_table_meta_by_id(plan.table_id).await?;
+    let table_info = TableInfo {
+        ident,
+        desc: "".to_owned(),
+        name: plan.table_name.clone(),
+        meta: meta.as_ref().clone(),
+    };
+    let table = catalog.get_table_by_info(&table_info)?;
+    match table.as_any().downcast_ref::<FuseTable>() {
+        Some(table) => table.get_committed_load(ctx, api, key).await,
+        None => Err(ErrorCode::BadArguments(format!(
+            "Idempotency keys are only supported by FUSE tables, but table {} is {}",
+            plan.table_name,
+            table.engine()
+        ))),
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::future::Future;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use async_compat::CompatExt;
@@ -22,9 +23,10 @@ use common_base::base::TrySpawn;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_exception::ToErrorCode;
 use common_io::prelude::parse_escape_string;
 use common_io::prelude::FormatSettings;
+use common_meta_types::FileFormatOptions;
+use common_meta_types::MatchByColumnName;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
 use common_streams::CsvSourceBuilder;
@@ -35,25 +37,36 @@ use common_streams::Source;
 use common_tracing::tracing;
 use futures::io::Cursor;
 use futures::StreamExt;
+use poem::error::BadRequest;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
 use poem::web::Json;
 use poem::web::Multipart;
+use poem::Body;
+use poem::FromRequest;
 use poem::Request;
+use poem::RequestBody;
 use serde::Deserialize;
 use serde::Serialize;
 
 use super::HttpQueryContext;
+use super::IdempotentLoad;
+use super::LoadClaim;
+use super::IDEMPOTENCY_KEY;
+use crate::formats::FormatFactory;
 use crate::interpreters::InterpreterFactory;
 use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::StreamSourceV2;
 use crate::pipelines::new::SourcePipeBuilder;
+use crate::servers::http::v1::multipart_format::LoadFiles;
 use crate::servers::http::v1::multipart_format::MultipartFormat;
 use crate::servers::http::v1::multipart_format::MultipartWorker;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionType;
+use crate::sessions::Settings;
 use crate::sql::PlanParser;
+use crate::storages::stage::MatchByColumnNameSource;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LoadResponse {
@@ -105,13 +118,14 @@ fn execute_query(
 async fn new_processor_format(
     ctx: &Arc<QueryContext>,
     node: &PlanNode,
-    multipart: Multipart,
+    files: LoadFiles,
+    id: String,
 ) -> Result<Json<LoadResponse>> {
     let format = get_input_format(node)?;
     let format_settings = ctx.get_format_settings()?;
 
     let (mut worker, builder) =
-        format_source_pipe_builder(format, ctx, node.schema(), multipart, &format_settings)?;
+        format_source_pipe_builder(format, ctx, node.schema(), files, &format_settings)?;
 
     let handler = ctx.spawn(execute_query(ctx.clone(), node.clone(), builder));
 
@@ -126,7 +140,7 @@ async fn new_processor_format(
     Ok(Json(LoadResponse {
         error: None,
         state: "SUCCESS".to_string(),
-        id: uuid::Uuid::new_v4().to_string(),
+        id,
         stats: ctx.get_scan_progress_value(),
     }))
 }
@@ -135,7 +149,7 @@ async fn new_processor_format(
 pub async fn streaming_load(
     ctx: &HttpQueryContext,
     req: &Request,
-    mut multipart: Multipart,
+    body: Body,
 ) -> PoemResult<Json<LoadResponse>> {
     let session = ctx.get_session(SessionType::HTTPStreamingLoad);
     let context = session
@@ -143,13 +157,37 @@ pub async fn streaming_load(
         .await
         .map_err(InternalServerError)?;
 
-    let insert_sql = req
+    let insert_sql = get_insert_sql(req)?;
+
+    // A multipart body may have several files, any other body is loaded as one file.
+    let is_multipart = req
+        .content_type()
+        .map(|v| v.starts_with("multipart/form-data"))
+        .unwrap_or(false);
+    let files = match is_multipart {
+        true => {
+            LoadFiles::Multipart(Multipart::from_request(req, &mut RequestBody::new(body)).await?)
+        }
+        false => LoadFiles::Body(Some(body)),
+    };
+
+    let settings = context.get_settings();
+
+    // The file format of the stage is the default of the load, overridden by the headers.
+    if let Some(stage_name) = req
         .headers()
-        .get("insert_sql")
+        .get("stage_name")
         .and_then(|v| v.to_str().ok())
-        .unwrap_or("");
+    {
+        let stage = context
+            .get_user_manager()
+            .get_stage(&context.get_tenant(), stage_name)
+            .await
+            .map_err(InternalServerError)?;
+        apply_stage_file_format(&settings, &stage.file_format_options)
+            .map_err(InternalServerError)?;
+    }
 
-    let settings = context.get_settings();
     for (key, value) in req.headers().iter() {
         if settings.has_setting(key.as_str()) {
             let value = value.to_str().map_err(InternalServerError)?;
@@ -161,11 +199,109 @@ pub async fn streaming_load(
         }
     }
 
-    let plan = PlanParser::parse(context.clone(), insert_sql)
+    let plan = PlanParser::parse(context.clone(), &insert_sql)
         .await
         .map_err(InternalServerError)?;
-    context.attach_query_str(insert_sql);
+    context.attach_query_str(&insert_sql);
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let idempotency_key = req
+        .headers()
+        .get(IDEMPOTENCY_KEY)
+        .and_then(|v| v.to_str().ok());
+    let idempotent_load = match (&plan, idempotency_key) {
+        (PlanNode::Insert(insert), Some(key)) => {
+            match IdempotentLoad::claim(&context, insert, key, &id).await {
+                Ok(LoadClaim::Claimed(load)) => Some(load),
+                // The load is a retry of a succeeded one, return the result of the first load.
+                Ok(LoadClaim::Succeeded(info)) => {
+                    return Ok(Json(LoadResponse {
+                        id: info.id,
+                        state: "SUCCESS".to_string(),
+                        stats: ProgressValues {
+                            rows: info.rows as usize,
+                            bytes: info.bytes as usize,
+                        },
+                        error: None,
+                    }));
+                }
+                Err(cause) if cause.code() == ErrorCode::LoadAlreadyRunning("").code() => {
+                    return Err(poem::Error::from_string(
+                        cause.message(),
+                        StatusCode::CONFLICT,
+                    ));
+                }
+                Err(cause) if cause.code() == ErrorCode::BadArguments("").code() => {
+                    return Err(BadRequest(cause));
+                }
+                Err(cause) => return Err(InternalServerError(cause)),
+            }
+        }
+        _ => None,
+    };
+
+    // The key is recorded by the commit of the load, whatever the release of the key results
+    // in. If the handler is dropped before, e.g. the client disconnected, the lease expires.
+    let response = execute_streaming_load(context, plan, files, id).await;
+    if let Some(load) = idempotent_load {
+        load.release().await;
+    }
+    response
+}
+
+fn apply_stage_file_format(settings: &Settings, options: &FileFormatOptions) -> Result<()> {
+    let values = [
+        ("field_delimiter", options.field_delimiter.clone()),
+        ("record_delimiter", options.record_delimiter.clone()),
+        ("skip_header", options.skip_header.to_string()),
+        (
+            "compression",
+            format!("{:?}", options.compression).to_lowercase(),
+        ),
+    ];
+    // Empty delimiters are not specified by the stage.
+    for (key, value) in values.into_iter().filter(|(_, v)| !v.is_empty()) {
+        settings.set_settings(key.to_string(), value, false)?;
+    }
+    Ok(())
+}
+
+/// The insert statement of the load, given by the `insert_sql` header, or built from the
+/// `table`, `database` and `format` headers.
+fn get_insert_sql(req: &Request) -> PoemResult<String> {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    };
+
+    if let Some(insert_sql) = header("insert_sql") {
+        return Ok(insert_sql.to_string());
+    }
+
+    match (header("table"), header("format")) {
+        (Some(table), Some(format)) => {
+            let table = match header("database") {
+                Some(database) => format!("`{}`.`{}`", database, table),
+                None => format!("`{}`", table),
+            };
+            Ok(format!("INSERT INTO {} FORMAT {}", table, format))
+        }
+        _ => Err(poem::Error::from_string(
+            "Either the insert_sql header, or the table and format headers are required",
+            StatusCode::BAD_REQUEST,
+        )),
+    }
+}
 
+async fn execute_streaming_load(
+    context: Arc<QueryContext>,
+    plan: PlanNode,
+    files: LoadFiles,
+    id: String,
+) -> PoemResult<Json<LoadResponse>> {
     // Block size.
     let max_block_size = context
         .get_settings()
@@ -184,19 +320,26 @@ pub async fn streaming_load(
         let source_pipe_builder = match &plan {
             PlanNode::Insert(insert) => match &insert.source {
                 InsertInputSource::StreamingWithFormat(format) => {
-                    if format.to_lowercase().as_str() == "csv"
-                        || format.to_lowercase().as_str() == "parquet"
-                    {
-                        return match new_processor_format(&context, &plan, multipart).await {
+                    let match_by_column_name =
+                        get_match_by_column_name(&context).map_err(InternalServerError)?;
+                    if match_by_column_name != MatchByColumnName::None {
+                        match_by_column_name_source_pipe_builder(
+                            context.clone(),
+                            &plan,
+                            format,
+                            match_by_column_name,
+                            files,
+                        )
+                        .await
+                    } else if FormatFactory::instance().has_input(format) {
+                        return match new_processor_format(&context, &plan, files, id).await {
                             Ok(res) => Ok(res),
                             Err(cause) => Err(InternalServerError(cause)),
                         };
-                    }
-
-                    if format.to_lowercase().as_str() == "ndjson"
+                    } else if format.to_lowercase().as_str() == "ndjson"
                         || format.to_lowercase().as_str() == "jsoneachrow"
                     {
-                        ndjson_source_pipe_builder(context.clone(), &plan, multipart).await
+                        ndjson_source_pipe_builder(context.clone(), &plan, files).await
                     } else {
                         Err(poem::Error::from_string(
                             format!(
@@ -236,9 +379,6 @@ pub async fn streaming_load(
             .await
             .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
 
-        // TODO generate id
-        // TODO duplicate by insert_label
-        let mut id = uuid::Uuid::new_v4().to_string();
         return Ok(Json(LoadResponse {
             id,
             state: "SUCCESS".to_string(),
@@ -253,13 +393,13 @@ pub async fn streaming_load(
         PlanNode::Insert(insert) => match &insert.source {
             InsertInputSource::StreamingWithFormat(format) => {
                 if format.to_lowercase().as_str() == "csv" {
-                    build_csv_stream(&plan, &format_settings, multipart, max_block_size)
+                    build_csv_stream(&plan, &format_settings, files, max_block_size)
                 } else if format.to_lowercase().as_str() == "parquet" {
-                    build_parquet_stream(&plan, multipart)
+                    build_parquet_stream(&plan, files)
                 } else if format.to_lowercase().as_str() == "ndjson"
                     || format.to_lowercase().as_str() == "jsoneachrow"
                 {
-                    build_ndjson_stream(&plan, multipart)
+                    build_ndjson_stream(&plan, files)
                 } else {
                     Err(poem::Error::from_string(
                         format!(
@@ -307,9 +447,6 @@ pub async fn streaming_load(
         .await
         .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
 
-    // TODO generate id
-    // TODO duplicate by insert_label
-    let mut id = uuid::Uuid::new_v4().to_string();
     Ok(Json(LoadResponse {
         id,
         state: "SUCCESS".to_string(),
@@ -320,12 +457,11 @@ pub async fn streaming_load(
 
 fn build_parquet_stream(
     plan: &PlanNode,
-    mut multipart: Multipart,
+    mut files: LoadFiles,
 ) -> PoemResult<SendableDataBlockStream> {
     let builder = ParquetSourceBuilder::create(plan.schema());
     let stream = stream! {
-        while let Ok(Some(field)) = multipart.next_field().await {
-            let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+        while let Some(bytes) = files.next_file_bytes().await? {
             let cursor = Cursor::new(bytes);
 
             let mut source = builder.build(cursor)?;
@@ -346,12 +482,11 @@ fn build_parquet_stream(
 
 fn build_ndjson_stream(
     plan: &PlanNode,
-    mut multipart: Multipart,
+    mut files: LoadFiles,
 ) -> PoemResult<SendableDataBlockStream> {
     let builder = NDJsonSourceBuilder::create(plan.schema(), FormatSettings::default());
     let stream = stream! {
-        while let Ok(Some(field)) = multipart.next_field().await {
-            let bytes = field.bytes().await.map_err_to_code(ErrorCode::BadBytes,  || "Read part to field bytes error")?;
+        while let Some(bytes) = files.next_file_bytes().await? {
             let cursor = futures::io::Cursor::new(bytes);
             let mut source = builder.build(cursor)?;
 
@@ -372,15 +507,14 @@ fn build_ndjson_stream(
 fn build_csv_stream(
     plan: &PlanNode,
     format_settings: &FormatSettings,
-    mut multipart: Multipart,
+    mut files: LoadFiles,
     block_size: usize,
 ) -> PoemResult<SendableDataBlockStream> {
     let mut builder = CsvSourceBuilder::create(plan.schema(), format_settings.clone());
     builder.block_size(block_size);
 
     let stream = stream! {
-        while let Ok(Some(field)) = multipart.next_field().await {
-            let reader = field.into_async_read();
+        while let Some((_, reader)) = files.next_file().await? {
             let mut source = builder.build(reader.compat())?;
 
             loop {
@@ -401,31 +535,89 @@ fn format_source_pipe_builder(
     format: &str,
     context: &Arc<QueryContext>,
     schema: DataSchemaRef,
-    multipart: Multipart,
+    files: LoadFiles,
     format_settings: &FormatSettings,
 ) -> Result<(Box<dyn MultipartWorker>, SourcePipeBuilder)> {
     MultipartFormat::input_sources(
         format,
         context.clone(),
-        multipart,
+        files,
         schema,
         format_settings.clone(),
     )
 }
 
+fn get_match_by_column_name(ctx: &Arc<QueryContext>) -> Result<MatchByColumnName> {
+    let value = ctx.get_settings().get_match_by_column_name()?;
+    let value = String::from_utf8(value)
+        .map_err(|_| ErrorCode::BadArguments("match_by_column_name must be valid utf-8"))?;
+    MatchByColumnName::from_str(&value).map_err(ErrorCode::BadArguments)
+}
+
+// Build the sources mapping the columns of the files onto the table by name.
+async fn match_by_column_name_source_pipe_builder(
+    ctx: Arc<QueryContext>,
+    plan: &PlanNode,
+    format: &str,
+    match_by_column_name: MatchByColumnName,
+    mut files: LoadFiles,
+) -> PoemResult<SourcePipeBuilder> {
+    let format_settings = ctx.get_format_settings().map_err(InternalServerError)?;
+    let mut source_pipe_builder = SourcePipeBuilder::create();
+    while let Some(bytes) = files.next_file_bytes().await.map_err(InternalServerError)? {
+        let cursor = Cursor::new(bytes);
+        let source = match format.to_lowercase().as_str() {
+            "csv" | "csvwithnames" => {
+                let mut builder = CsvSourceBuilder::create(plan.schema(), format_settings.clone());
+                builder.skip_header(true);
+                let csv_source = builder.build(cursor).map_err(InternalServerError)?;
+                MatchByColumnNameSource::try_create_csv(
+                    &ctx,
+                    plan.schema(),
+                    match_by_column_name,
+                    csv_source,
+                )
+                .await
+            }
+            "parquet" => {
+                let builder = ParquetSourceBuilder::create(plan.schema());
+                let parquet_source = builder.build(cursor).map_err(InternalServerError)?;
+                MatchByColumnNameSource::try_create_parquet(
+                    &ctx,
+                    plan.schema(),
+                    match_by_column_name,
+                    parquet_source,
+                )
+                .await
+            }
+            _ => {
+                return Err(poem::Error::from_string(
+                    format!(
+                        "Match by column name only supports csv and parquet format, but got {}",
+                        format
+                    ),
+                    StatusCode::BAD_REQUEST,
+                ))
+            }
+        }
+        .map_err(InternalServerError)?;
+
+        let output_port = OutputPort::create();
+        let source = StreamSourceV2::create(ctx.clone(), source, output_port.clone())
+            .map_err(InternalServerError)?;
+        source_pipe_builder.add_source(output_port, source);
+    }
+    Ok(source_pipe_builder)
+}
+
 async fn ndjson_source_pipe_builder(
     ctx: Arc<QueryContext>,
     plan: &PlanNode,
-    mut multipart: Multipart,
+    mut files: LoadFiles,
 ) -> PoemResult<SourcePipeBuilder> {
     let builder = NDJsonSourceBuilder::create(plan.schema(), FormatSettings::default());
     let mut source_pipe_builder = SourcePipeBuilder::create();
-    while let Ok(Some(field)) = multipart.next_field().await {
-        let bytes = field
-            .bytes()
-            .await
-            .map_err_to_code(ErrorCode::BadBytes, || "Read part to field bytes error")
-            .map_err(InternalServerError)?;
+    while let Some(bytes) = files.next_file_bytes().await.map_err(InternalServerError)? {
         let cursor = Cursor::new(bytes);
         let ndjson_source = builder.build(cursor).map_err(InternalServerError)?;
         let output_port = OutputPort::create();
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod http_query_handlers;
+mod idempotency;
 pub mod json_block;
 mod load;
 mod multipart_format;
@@ -27,6 +28,9 @@ pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
+pub use idempotency::IdempotentLoad;
+pub use idempotency::LoadClaim;
+pub use idempotency::IDEMPOTENCY_KEY;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
@@ -36,6 +40,7 @@ pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
 pub use query::HttpSession;
 pub use query::HttpSessionConf;
+pub use query::ScanPartitions;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
 
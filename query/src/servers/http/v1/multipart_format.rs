@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::pin::Pin;
 use std::sync::Arc;
 
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +25,7 @@ use common_io::prelude::FormatSettings;
 use opendal::io_util::CompressAlgorithm;
 use opendal::io_util::DecompressDecoder;
 use poem::web::Multipart;
+use poem::Body;
 
 use crate::formats::FormatFactory;
 use crate::pipelines::new::processors::port::OutputPort;
@@ -32,6 +36,57 @@ use crate::servers::http::v1::sequential_format_source::SequentialInputFormatSou
 use crate::servers::http::v1::sequential_format_source::SequentialMultipartWorker;
 use crate::sessions::QueryContext;
 
+pub type LoadFileReader = Pin<Box<dyn AsyncRead + Send>>;
+
+/// The files of a streaming load, either the fields of a multipart body, or the whole body,
+/// e.g. sent with `Transfer-Encoding: chunked`.
+pub enum LoadFiles {
+    Multipart(Multipart),
+    Body(Option<Body>),
+}
+
+impl LoadFiles {
+    /// Returns the name and the reader of the next file.
+    pub async fn next_file(&mut self) -> Result<Option<(String, LoadFileReader)>> {
+        match self {
+            LoadFiles::Multipart(multipart) => match multipart.next_field().await {
+                Err(cause) => Err(ErrorCode::BadBytes(format!(
+                    "Parse multipart error, cause {:?}",
+                    cause
+                ))),
+                Ok(None) => Ok(None),
+                Ok(Some(field)) => {
+                    let filename = field.file_name().unwrap_or("Unknown file name").to_string();
+                    Ok(Some((filename, Box::pin(field.into_async_read()))))
+                }
+            },
+            LoadFiles::Body(body) => Ok(body.take().map(|body| {
+                (
+                    "Request body".to_string(),
+                    Box::pin(body.into_async_read()) as _,
+                )
+            })),
+        }
+    }
+
+    /// Reads the next file into memory, for the formats not read incrementally.
+    pub async fn next_file_bytes(&mut self) -> Result<Option<Vec<u8>>> {
+        match self.next_file().await? {
+            None => Ok(None),
+            Some((filename, mut reader)) => {
+                let mut bytes = vec![];
+                reader.read_to_end(&mut bytes).await.map_err(|cause| {
+                    ErrorCode::BadBytes(format!(
+                        "Read part to field bytes error, cause {:?}, filename: '{}'",
+                        cause, filename
+                    ))
+                })?;
+                Ok(Some(bytes))
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 pub trait MultipartWorker: Send {
     async fn work(&mut self);
@@ -43,7 +98,7 @@ impl MultipartFormat {
     pub fn input_sources(
         name: &str,
         ctx: Arc<QueryContext>,
-        multipart: Multipart,
+        files: LoadFiles,
         schema: DataSchemaRef,
         settings: FormatSettings,
     ) -> Result<(Box<dyn MultipartWorker>, SourcePipeBuilder)> {
@@ -78,7 +133,7 @@ impl MultipartFormat {
             }
 
             Ok((
-                Box::new(ParallelMultipartWorker::create(multipart, tx, input_format)),
+                Box::new(ParallelMultipartWorker::create(files, tx, input_format)),
                 source_pipe_builder,
             ))
         } else {
@@ -122,7 +177,7 @@ impl MultipartFormat {
             );
 
             Ok((
-                Box::new(SequentialMultipartWorker::create(multipart, tx)),
+                Box::new(SequentialMultipartWorker::create(files, tx)),
                 source_pipe_builder,
             ))
         }
@@ -23,7 +23,6 @@ use common_base::base::ProgressValues;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use poem::web::Multipart;
 
 use crate::formats::InputFormat;
 use crate::formats::InputState;
@@ -31,22 +30,23 @@ use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::processor::Event;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Processor;
+use crate::servers::http::v1::multipart_format::LoadFiles;
 use crate::servers::http::v1::multipart_format::MultipartWorker;
 
 pub struct ParallelMultipartWorker {
-    multipart: Multipart,
+    files: LoadFiles,
     input_format: Box<dyn InputFormat>,
     tx: Option<Sender<Result<Box<dyn InputState>>>>,
 }
 
 impl ParallelMultipartWorker {
     pub fn create(
-        multipart: Multipart,
+        files: LoadFiles,
         tx: Sender<Result<Box<dyn InputState>>>,
         input_format: Box<dyn InputFormat>,
     ) -> ParallelMultipartWorker {
         ParallelMultipartWorker {
-            multipart,
+            files,
             input_format,
             tx: Some(tx),
         }
@@ -70,15 +70,9 @@ impl MultipartWorker for ParallelMultipartWorker {
     async fn work(&mut self) {
         if let Some(tx) = self.tx.take() {
             'outer: loop {
-                match self.multipart.next_field().await {
+                match self.files.next_file().await {
                     Err(cause) => {
-                        if let Err(cause) = tx
-                            .send(Err(ErrorCode::BadBytes(format!(
-                                "Parse multipart error, cause {:?}",
-                                cause
-                            ))))
-                            .await
-                        {
+                        if let Err(cause) = tx.send(Err(cause)).await {
                             common_tracing::tracing::warn!(
                                 "Multipart channel disconnect. {}",
                                 cause
@@ -90,14 +84,12 @@ impl MultipartWorker for ParallelMultipartWorker {
                     Ok(None) => {
                         break 'outer;
                     }
-                    Ok(Some(field)) => {
+                    Ok(Some((filename, mut async_reader))) => {
                         let mut skipped_header = false;
-                        let filename = field.file_name().unwrap_or("Unknown file name").to_string();
 
                         let mut buf = vec![0; 1048576];
                         let mut has_data_in_state = false;
                         let mut state = self.input_format.create_state();
-                        let mut async_reader = field.into_async_read();
 
                         'read: loop {
                             let read_res = async_reader.read(&mut buf[..]).await;
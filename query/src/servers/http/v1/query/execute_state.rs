@@ -35,8 +35,9 @@ use ExecuteState::*;
 use super::http_query::HttpQueryRequest;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
-use crate::interpreters::InterpreterFactoryV2;
+use crate::interpreters::InterpreterFactoryRouter;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::PlannerKind;
 use crate::pipelines::new::executor::PipelineCompleteExecutor;
 use crate::pipelines::new::executor::PipelineExecutor;
 use crate::pipelines::new::processors::port::InputPort;
@@ -47,7 +48,6 @@ use crate::sql::exec::PipelineBuilder;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
-use crate::sql::Planner;
 use crate::storages::result::block_buffer::BlockBuffer;
 use crate::storages::result::block_buffer::BlockBufferWriterMemOnly;
 use crate::storages::result::block_buffer::BlockBufferWriterWithResultTable;
@@ -86,8 +86,26 @@ pub struct ExecuteRunning {
     interpreter: Arc<dyn Interpreter>,
 }
 
+/// How many partitions (blocks of FUSE tables) are left to scan after pruning, out of all of them.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScanPartitions {
+    pub total: u64,
+    pub scanned: u64,
+}
+
+impl ScanPartitions {
+    fn from_ctx(ctx: &QueryContext) -> Self {
+        let metrics = ctx.get_dal_context().get_metrics();
+        ScanPartitions {
+            total: metrics.get_partitions_total(),
+            scanned: metrics.get_partitions_scanned(),
+        }
+    }
+}
+
 pub struct ExecuteStopped {
     progress: Option<ProgressValues>,
+    partitions: Option<ScanPartitions>,
     reason: Result<()>,
     stop_time: Instant,
 }
@@ -105,6 +123,13 @@ impl Executor {
         }
     }
 
+    pub(crate) fn get_scan_partitions(&self) -> Option<ScanPartitions> {
+        match &self.state {
+            Running(r) => Some(ScanPartitions::from_ctx(&r.ctx)),
+            Stopped(f) => f.partitions,
+        }
+    }
+
     pub(crate) fn elapsed(&self) -> Duration {
         match &self.state {
             Running(_) => Instant::now() - self.start_time,
@@ -117,6 +142,7 @@ impl Executor {
         if let Running(r) = &guard.state {
             // release session
             let progress = Some(r.ctx.get_scan_progress_value());
+            let partitions = Some(ScanPartitions::from_ctx(&r.ctx));
             if kill {
                 r.session.force_kill_query();
             }
@@ -128,6 +154,7 @@ impl Executor {
                 .map_err(|e| tracing::error!("interpreter.finish error: {:?}", e));
             guard.state = Stopped(ExecuteStopped {
                 progress,
+                partitions,
                 reason: reason.clone(),
                 stop_time: Instant::now(),
             });
@@ -163,79 +190,66 @@ impl ExecuteState {
             }
         };
 
-        let settings = ctx.get_settings();
-        if settings.get_enable_new_processor_framework()? != 0
-            && !ctx.get_config().query.management_mode
-            && ctx.get_cluster().is_empty()
-            && settings.get_enable_planner_v2()? != 0
-            && matches!(stmts.get(0), Some(DfStatement::Query(_)))
-        {
-            let mut planner = Planner::new(ctx.clone());
-            let (plan, _) = planner.plan_sql(sql).await?;
-            let interpreter = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
-
-            // Write Start to query log table.
-            let _ = interpreter
-                .start()
-                .await
-                .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
-
-            let running_state = ExecuteRunning {
-                session,
-                ctx: ctx.clone(),
-                interpreter: interpreter.clone(),
-            };
-            let executor = Arc::new(RwLock::new(Executor {
-                start_time,
-                state: Running(running_state),
-            }));
-            ctx.attach_http_query(HttpQueryHandle {
-                executor: executor.clone(),
-                block_buffer,
-            });
-            interpreter.execute(None).await.unwrap();
-
-            Ok(executor)
-        } else {
-            let plan = match PlanParser::parse(ctx.clone(), sql).await {
-                Ok(p) => p,
-                Err(e) => {
-                    InterpreterQueryLog::fail_to_start(ctx, e.clone()).await;
-                    return Err(e);
-                }
-            };
-
-            let interpreter = InterpreterFactory::get(ctx.clone(), plan.clone())?;
-            // Write Start to query log table.
-            let _ = interpreter
-                .start()
-                .await
-                .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
+        // The new planner serves only the queries over http, their results are pulled from
+        // the pipeline attached to the context.
+        let planned = match stmts.get(0) {
+            Some(DfStatement::Query(_)) => {
+                InterpreterFactoryRouter::get(ctx.clone(), sql, &stmts).await
+            }
+            _ => match PlanParser::parse(ctx.clone(), sql).await {
+                Ok(plan) => InterpreterFactory::get(ctx.clone(), plan)
+                    .map(|interpreter| (interpreter, PlannerKind::Legacy)),
+                Err(e) => Err(e),
+            },
+        };
+        let (interpreter, planner) = match planned {
+            Ok(v) => v,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(ctx, e.clone()).await;
+                return Err(e);
+            }
+        };
 
-            let running_state = ExecuteRunning {
-                session,
-                ctx: ctx.clone(),
-                interpreter: interpreter.clone(),
-            };
-            let executor = Arc::new(RwLock::new(Executor {
-                start_time,
-                state: Running(running_state),
-            }));
-
-            let executor_clone = executor.clone();
-            let ctx_clone = ctx.clone();
-            let block_buffer_clone = block_buffer.clone();
-            ctx.try_spawn(async move {
-                if let Err(err) =
-                    execute(interpreter, ctx_clone, block_buffer, executor_clone.clone()).await
-                {
-                    Executor::stop(&executor_clone, Err(err), false).await;
-                    block_buffer_clone.stop_push().await.unwrap();
-                };
-            })?;
+        // Write Start to query log table.
+        let _ = interpreter
+            .start()
+            .await
+            .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
 
-            Ok(executor)
+        let running_state = ExecuteRunning {
+            session,
+            ctx: ctx.clone(),
+            interpreter: interpreter.clone(),
+        };
+        let executor = Arc::new(RwLock::new(Executor {
+            start_time,
+            state: Running(running_state),
+        }));
+
+        match planner {
+            PlannerKind::V2 => {
+                ctx.attach_http_query(HttpQueryHandle {
+                    executor: executor.clone(),
+                    block_buffer,
+                });
+                interpreter.execute(None).await.unwrap();
+            }
+            PlannerKind::Legacy => {
+                let executor_clone = executor.clone();
+                let ctx_clone = ctx.clone();
+                let block_buffer_clone = block_buffer.clone();
+                ctx.try_spawn(async move {
+                    if let Err(err) =
+                        execute(interpreter, ctx_clone, block_buffer, executor_clone.clone()).await
+                    {
+                        Executor::stop(&executor_clone, Err(err), false).await;
+                        block_buffer_clone.stop_push().await.unwrap();
+                    };
+                })?;
+            }
         }
+
+        Ok(executor)
     }
 }
 
@@ -25,8 +25,10 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 use serde::Deserialize;
+use serde::Serialize;
 
 use super::HttpQueryContext;
+use crate::clusters::NodeLoadReporter;
 use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::query::expirable::ExpiringState;
 use crate::servers::http::v1::query::http_query_manager::HttpQueryConfig;
@@ -35,6 +37,7 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ScanPartitions;
 use crate::servers::http::v1::query::Wait;
 use crate::sessions::SessionType;
 use crate::storages::result::block_buffer::BlockBuffer;
@@ -122,14 +125,27 @@ impl Default for HttpSession {
 pub struct ResponseState {
     pub running_time_ms: f64,
     pub scan_progress: Option<ProgressValues>,
+    pub scan_partitions: Option<ScanPartitions>,
     pub state: ExecuteStateKind,
     pub error: Option<ErrorCode>,
 }
 
+/// Topology and load of a query node, so that clients can spread new sessions across the cluster.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeLoadHint {
+    pub id: String,
+    pub http_address: String,
+    pub cpu_nums: u64,
+    pub active_sessions: u64,
+    pub running_queries: u64,
+    pub max_sessions: u64,
+}
+
 pub struct HttpQueryResponseInternal {
     pub data: Option<ResponseData>,
     pub session_id: String,
     pub state: ResponseState,
+    pub nodes: Vec<NodeLoadHint>,
 }
 
 pub struct HttpQuery {
@@ -141,6 +157,7 @@ pub struct HttpQuery {
     data: Arc<TokioMutex<PageManager>>,
     config: HttpQueryConfig,
     expire_at: Arc<TokioMutex<Option<Instant>>>,
+    nodes: Vec<NodeLoadHint>,
 }
 
 impl HttpQuery {
@@ -200,10 +217,32 @@ impl HttpQuery {
             }
         };
         let session_id = session.get_id().clone();
+        let local_load = ctx.session_mgr.node_load();
 
         let ctx = session.create_query_context().await?;
         let id = ctx.get_id();
 
+        // The load of other nodes is the one reported by their last heartbeat.
+        let cluster = ctx.get_cluster();
+        let nodes = cluster
+            .get_nodes()
+            .iter()
+            .map(|node| {
+                let load = match cluster.is_local(node) {
+                    true => local_load.clone(),
+                    false => node.load.clone(),
+                };
+                NodeLoadHint {
+                    id: node.id.clone(),
+                    http_address: node.http_address.clone(),
+                    cpu_nums: node.cpu_nums,
+                    active_sessions: load.active_sessions,
+                    running_queries: load.running_queries,
+                    max_sessions: load.max_sessions,
+                }
+            })
+            .collect();
+
         let block_buffer = BlockBuffer::new(request.pagination.max_rows_in_buffer);
         let state = ExecuteState::try_create(&request, session, ctx, block_buffer.clone()).await?;
         let data = Arc::new(TokioMutex::new(PageManager::new(
@@ -218,6 +257,7 @@ impl HttpQuery {
             data,
             config,
             expire_at: Arc::new(TokioMutex::new(None)),
+            nodes,
         };
         let query = Arc::new(query);
         Ok(query)
@@ -236,6 +276,7 @@ impl HttpQuery {
             data: Some(self.get_page(page_no, format).await?),
             session_id: self.session_id.clone(),
             state: self.get_state().await,
+            nodes: self.nodes.clone(),
         })
     }
 
@@ -244,6 +285,7 @@ impl HttpQuery {
             data: None,
             session_id: self.session_id.clone(),
             state: self.get_state().await,
+            nodes: self.nodes.clone(),
         }
     }
 
@@ -253,6 +295,7 @@ impl HttpQuery {
         ResponseState {
             running_time_ms: state.elapsed().as_secs_f64() * 1000.0,
             scan_progress: state.get_progress(),
+            scan_partitions: state.get_scan_partitions(),
             state: exe_state,
             error: err,
         }
@@ -24,11 +24,13 @@ pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateKind;
 pub(crate) use execute_state::Executor;
 pub use execute_state::HttpQueryHandle;
+pub use execute_state::ScanPartitions;
 pub use http_query::HttpQuery;
 pub use http_query::HttpQueryRequest;
 pub use http_query::HttpQueryResponseInternal;
 pub use http_query::HttpSession;
 pub use http_query::HttpSessionConf;
+pub use http_query::NodeLoadHint;
 pub use http_query::PaginationConf;
 pub use http_query::ResponseState;
 pub use http_query_context::HttpQueryContext;
@@ -25,7 +25,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use opendal::io_util::DecompressDecoder;
 use opendal::io_util::DecompressState;
-use poem::web::Multipart;
 
 use crate::formats::InputFormat;
 use crate::formats::InputState;
@@ -33,17 +32,18 @@ use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::processor::Event;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Processor;
+use crate::servers::http::v1::multipart_format::LoadFiles;
 use crate::servers::http::v1::multipart_format::MultipartWorker;
 
 pub struct SequentialMultipartWorker {
-    multipart: Multipart,
+    files: LoadFiles,
     tx: Option<Sender<Result<Vec<u8>>>>,
 }
 
 impl SequentialMultipartWorker {
-    pub fn create(multipart: Multipart, tx: Sender<Result<Vec<u8>>>) -> SequentialMultipartWorker {
+    pub fn create(files: LoadFiles, tx: Sender<Result<Vec<u8>>>) -> SequentialMultipartWorker {
         SequentialMultipartWorker {
-            multipart,
+            files,
             tx: Some(tx),
         }
     }
@@ -54,15 +54,9 @@ impl MultipartWorker for SequentialMultipartWorker {
     async fn work(&mut self) {
         if let Some(tx) = self.tx.take() {
             'outer: loop {
-                match self.multipart.next_field().await {
+                match self.files.next_file().await {
                     Err(cause) => {
-                        if let Err(cause) = tx
-                            .send(Err(ErrorCode::BadBytes(format!(
-                                "Parse multipart error, cause {:?}",
-                                cause
-                            ))))
-                            .await
-                        {
+                        if let Err(cause) = tx.send(Err(cause)).await {
                             common_tracing::tracing::warn!(
                                 "Multipart channel disconnect. {}",
                                 cause
@@ -74,9 +68,7 @@ impl MultipartWorker for SequentialMultipartWorker {
                     Ok(None) => {
                         break 'outer;
                     }
-                    Ok(Some(field)) => {
-                        let filename = field.file_name().unwrap_or("Unknown file name").to_string();
-
+                    Ok(Some((filename, mut async_reader))) => {
                         if let Err(cause) = tx.send(Ok(vec![])).await {
                             common_tracing::tracing::warn!(
                                 "Multipart channel disconnect. {}, filename '{}'",
@@ -87,8 +79,6 @@ impl MultipartWorker for SequentialMultipartWorker {
                             break 'outer;
                         }
 
-                        let mut async_reader = field.into_async_read();
-
                         'read: loop {
                             // 1048576 from clickhouse DBMS_DEFAULT_BUFFER_SIZE
                             let mut buf = vec![0; 1048576];
@@ -24,6 +24,7 @@ use serde::Serialize;
 
 use super::HttpQueryContext;
 use crate::sessions::SessionType;
+use crate::storages::stage::StageSource;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UploadToStageResponse {
@@ -47,11 +48,6 @@ pub async fn upload_to_stage(
 
     let user_mgr = context.get_user_manager();
 
-    // TODO(xuanwo): logic here seems buggy, we need to fix it.
-    // It's incorrect to get operator from context if we are uploading an external stage.
-    let op = context
-        .get_storage_operator()
-        .map_err(InternalServerError)?;
     let mut files = vec![];
 
     let stage_name = req
@@ -69,6 +65,11 @@ pub async fn upload_to_stage(
         .await
         .map_err(InternalServerError)?;
 
+    // The external stage is written with the credentials saved in the stage.
+    let op = StageSource::get_op(&context, &stage)
+        .await
+        .map_err(InternalServerError)?;
+
     let mut relative_path = req
         .headers()
         .get("relative_path")
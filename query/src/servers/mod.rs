@@ -22,6 +22,7 @@ pub use self::http::HttpHandler;
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
+pub use self::mysql::MySQLTlsConfig;
 
 pub(crate) mod clickhouse;
 pub mod http;
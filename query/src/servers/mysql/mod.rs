@@ -17,6 +17,7 @@ mod mysql_handler;
 mod mysql_interactive_worker;
 mod mysql_metrics;
 mod mysql_session;
+mod mysql_tls;
 #[allow(clippy::unused_io_amount)]
 mod reject_connection;
 mod writers;
@@ -24,5 +25,6 @@ mod writers;
 pub use self::mysql_federated::MySQLFederated;
 pub use self::mysql_handler::MySQLHandler;
 pub use self::mysql_session::MySQLConnection;
+pub use self::mysql_tls::MySQLTlsConfig;
 
 const MYSQL_VERSION: &str = "8.0.26";
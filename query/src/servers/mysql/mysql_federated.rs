@@ -12,19 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_datavalues::DataSchemaRefExt;
+use regex::Regex;
 use regex::RegexSet;
 
 use crate::servers::mysql::MYSQL_VERSION;
-type LazyBlockFunc = fn(&str) -> Option<DataBlock>;
+use crate::sessions::Settings;
+type LazyBlockFunc = fn(&MySQLFederated, &str) -> Option<DataBlock>;
 
 pub struct MySQLFederated {
     mysql_version: String,
     databend_version: String,
+    // The settings of the session, used to answer `SELECT @@variable`.
+    settings: BTreeMap<String, DataValue>,
 }
 
 impl MySQLFederated {
@@ -32,6 +37,14 @@ impl MySQLFederated {
         MySQLFederated {
             mysql_version: MYSQL_VERSION.to_string(),
             databend_version: crate::version::DATABEND_COMMIT_VERSION.to_string(),
+            settings: BTreeMap::new(),
+        }
+    }
+
+    pub fn create_with_settings(settings: &Settings) -> Self {
+        MySQLFederated {
+            settings: settings.get_setting_values_short(),
+            ..Self::create()
         }
     }
 
@@ -96,7 +109,7 @@ impl MySQLFederated {
         let matches = regex_set.matches(query);
         for (index, (_regex, func)) in rules.iter().enumerate() {
             if matches.matched(index) {
-                return match func(query) {
+                return match func(self, query) {
                     None => Some(DataBlock::empty()),
                     Some(data_block) => Some(data_block),
                 };
@@ -105,23 +118,54 @@ impl MySQLFederated {
         None
     }
 
+    // Get the value of @@variable, the settings of the session come first.
+    // @@session.xx, @@global.xx and @@local.xx are all resolved to the setting xx.
+    fn variable_value(&self, default_map: &HashMap<&str, String>, var: &str) -> String {
+        let name = var
+            .trim_start_matches("session.")
+            .trim_start_matches("global.")
+            .trim_start_matches("local.");
+        let setting_name = match name {
+            "time_zone" => "timezone",
+            v => v,
+        };
+        if let Some(value) = self.settings.get(setting_name) {
+            return value.to_string();
+        }
+
+        default_map
+            .get(var)
+            .or_else(|| default_map.get(name))
+            .cloned()
+            .unwrap_or_else(|| "0".to_string())
+    }
+
     // SELECT @@aa, @@bb as cc, @dd...
     // Block is built by the variables.
-    fn select_variable_data_block(query: &str) -> Option<DataBlock> {
+    fn select_variable_data_block(&self, query: &str) -> Option<DataBlock> {
         let mut default_map = HashMap::new();
         // DBeaver.
-        default_map.insert("tx_isolation", "REPEATABLE-READ");
-        default_map.insert("session.tx_isolation", "REPEATABLE-READ");
-        default_map.insert("transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_read_only", "0");
-        default_map.insert("time_zone", "UTC");
-        default_map.insert("system_time_zone", "UTC");
+        default_map.insert("tx_isolation", "REPEATABLE-READ".to_string());
+        default_map.insert("session.tx_isolation", "REPEATABLE-READ".to_string());
+        default_map.insert("transaction_isolation", "REPEATABLE-READ".to_string());
+        default_map.insert(
+            "session.transaction_isolation",
+            "REPEATABLE-READ".to_string(),
+        );
+        default_map.insert("session.transaction_read_only", "0".to_string());
+        default_map.insert("time_zone", "UTC".to_string());
+        default_map.insert("system_time_zone", "UTC".to_string());
         // 128M
-        default_map.insert("max_allowed_packet", "134217728");
-        default_map.insert("interactive_timeout", "31536000");
-        default_map.insert("wait_timeout", "31536000");
-        default_map.insert("net_write_timeout", "31536000");
+        default_map.insert("max_allowed_packet", "134217728".to_string());
+        default_map.insert("interactive_timeout", "31536000".to_string());
+        default_map.insert("wait_timeout", "31536000".to_string());
+        default_map.insert("net_write_timeout", "31536000".to_string());
+        // Handshake of the drivers.
+        default_map.insert(
+            "version",
+            format!("{}-{}", self.mysql_version, self.databend_version),
+        );
+        default_map.insert("version_comment", self.databend_version.clone());
 
         let mut fields = vec![];
         let mut values = vec![];
@@ -132,6 +176,8 @@ impl MySQLFederated {
         if vars.len() > 1 {
             vars.remove(0);
             for var in vars {
+                // select @@version_comment limit 1
+                let var = var.split(" limit ").next().unwrap_or(var);
                 let var = var.trim_end_matches(|c| c == ' ' || c == ',');
                 let vars_as: Vec<&str> = var.split(" as ").collect();
                 if vars_as.len() == 2 {
@@ -142,7 +188,7 @@ impl MySQLFederated {
 
                     // var is 'cc'.
                     let var = vars_as[0];
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
+                    let value = self.variable_value(&default_map, var);
                     values.push(Series::from_data(vec![value]));
                 } else {
                     // @@aa
@@ -152,7 +198,7 @@ impl MySQLFederated {
                         StringType::new_impl(),
                     ));
 
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
+                    let value = self.variable_value(&default_map, var);
                     values.push(Series::from_data(vec![value]));
                 }
             }
@@ -188,7 +234,6 @@ impl MySQLFederated {
                                            "0"),
             ),
             ("(?i)^(show collation where(.*))", Self::show_variables_block("", "")),
-            ("(?i)^(SHOW VARIABLES(.*))", Self::show_variables_block("", "")),
         ];
         self.block_match_rule(query, rules)
     }
@@ -203,10 +248,6 @@ impl MySQLFederated {
                     format!("{}-{}", self.mysql_version, self.databend_version.clone()).as_str(),
                 ),
             ),
-            // Txn.
-            ("(?i)^(ROLLBACK(.*))", None),
-            ("(?i)^(COMMIT(.*))", None),
-            ("(?i)^(START(.*))", None),
             // Set.
             ("(?i)^(SET NAMES(.*))", None),
             ("(?i)^(SET character_set_results(.*))", None),
@@ -248,9 +289,23 @@ impl MySQLFederated {
         self.block_match_rule(query, rules)
     }
 
+    // SET [SESSION | GLOBAL] xx = yy, where xx is a setting of Databend.
+    fn is_setting_set(&self, query: &str) -> bool {
+        let re = Regex::new("(?i)^SET\\s+(SESSION\\s+|GLOBAL\\s+)?([a-z0-9_]+)\\s*=").unwrap();
+        match re.captures(query) {
+            None => false,
+            Some(caps) => self.settings.contains_key(&caps[2].to_lowercase()),
+        }
+    }
+
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
     pub fn check(&self, query: &str) -> Option<DataBlock> {
+        // The settings of Databend are set by the SET statement.
+        if self.is_setting_set(query) {
+            return None;
+        }
+
         // First to check the select @@variables.
         let select_variable = self.federated_select_variable_check(query);
         if select_variable.is_some() {
@@ -29,9 +29,11 @@ use futures::future::AbortRegistration;
 use futures::future::Abortable;
 use futures::StreamExt;
 use opensrv_mysql::*;
+use tokio_rustls::rustls::ServerConfig;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::servers::mysql::mysql_session::MySQLConnection;
+use crate::servers::mysql::mysql_tls::MySQLTlsConfig;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
@@ -66,27 +68,51 @@ impl MySQLHandler {
         Ok((TcpListenerStream::new(listener), listener_addr))
     }
 
-    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+    fn listen_loop(
+        &self,
+        stream: ListeningStream,
+        rt: Arc<Runtime>,
+        tls: Option<Arc<ServerConfig>>,
+    ) -> impl Future<Output = ()> {
         let sessions = self.sessions.clone();
+        let require_secure_transport = sessions.get_conf().query.mysql_require_secure_transport;
         stream.for_each(move |accept_socket| {
             let executor = rt.clone();
             let sessions = sessions.clone();
+            let tls = tls.clone();
             async move {
                 match accept_socket {
                     Err(error) => tracing::error!("Broken session connection: {}", error),
-                    Ok(socket) => MySQLHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => MySQLHandler::accept_socket(
+                        sessions,
+                        executor,
+                        socket,
+                        tls,
+                        require_secure_transport,
+                    ),
                 };
             }
         })
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        require_secure_transport: bool,
+    ) {
         executor.spawn(async move {
             match sessions.create_session(SessionType::MySQL).await {
                 Err(error) => Self::reject_session(socket, error).await,
                 Ok(session) => {
                     tracing::info!("MySQL connection coming: {:?}", socket.peer_addr());
-                    if let Err(error) = MySQLConnection::run_on_stream(session, socket) {
+                    if let Err(error) = MySQLConnection::run_on_stream(
+                        session,
+                        socket,
+                        tls,
+                        require_secure_transport,
+                    ) {
                         tracing::error!("Unexpected error occurred during query: {:?}", error);
                     };
                 }
@@ -136,9 +162,20 @@ impl Server for MySQLHandler {
                     1,
                     Some("mysql-handler".to_string()),
                 )?);
+                let config = self.sessions.get_conf();
+                let tls = MySQLTlsConfig::try_create(
+                    &config.query.mysql_tls_server_cert,
+                    &config.query.mysql_tls_server_key,
+                )?;
+                if tls.is_none() && config.query.mysql_require_secure_transport {
+                    return Err(ErrorCode::TLSConfigurationFailure(
+                        "mysql_require_secure_transport requires mysql_tls_server_cert and mysql_tls_server_key",
+                    ));
+                }
+
                 let (stream, listener) = Self::listener_tcp(listening).await?;
                 let stream = Abortable::new(stream, registration);
-                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt)));
+                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt, tls)));
                 Ok(listener)
             }
         }
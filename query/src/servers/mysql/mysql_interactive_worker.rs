@@ -12,22 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::marker::PhantomData;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::base::tokio::io::AsyncWrite;
 use common_base::base::TrySpawn;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_exception::ToErrorCode;
 use common_io::prelude::*;
+use common_meta_types::PasswordHashMethod;
 use common_tracing::tracing;
 use common_tracing::tracing::Instrument;
+use futures::channel::mpsc::Sender;
+use futures::SinkExt;
 use metrics::histogram;
 use opensrv_mysql::AsyncMysqlShim;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
+use opensrv_mysql::OkResponse;
 use opensrv_mysql::ParamParser;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
@@ -35,35 +38,39 @@ use rand::RngCore;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::Interpreter;
-use crate::interpreters::InterpreterFactory;
-use crate::interpreters::InterpreterFactoryV2;
+use crate::interpreters::InterpreterFactoryRouter;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
+use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
+use crate::sql::check_result_hints;
+use crate::sql::DfHint;
+use crate::sql::DfHintMismatch;
 use crate::sql::DfParser;
-use crate::sql::PlanParser;
-use crate::sql::Planner;
+use crate::sql::SQLCommon;
+use crate::users::auth::auth_mgr::Credential;
 use crate::users::CertifiedInfo;
 
-struct InteractiveWorkerBase<W: std::io::Write> {
+struct InteractiveWorkerBase {
     session: SessionRef,
-    generic_hold: PhantomData<W>,
 }
 
-pub struct InteractiveWorker<W: std::io::Write> {
+pub struct InteractiveWorker {
     session: SessionRef,
-    base: InteractiveWorkerBase<W>,
+    base: InteractiveWorkerBase,
     version: String,
     salt: [u8; 20],
     client_addr: String,
+    // Whether the connection has been upgraded to TLS.
+    secure: bool,
 }
 
 #[async_trait::async_trait]
-impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W> {
+impl<W: AsyncWrite + Send + Unpin> AsyncMysqlShim<W> for InteractiveWorker {
     type Error = ErrorCode;
 
     fn version(&self) -> &str {
@@ -71,21 +78,26 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
     }
 
     fn connect_id(&self) -> u32 {
-        match self.session.get_mysql_conn_id() {
-            Some(conn_id) => conn_id,
-            None => {
-                //default conn id
-                u32::from_le_bytes([0x08, 0x00, 0x00, 0x00])
-            }
-        }
+        // MySQL sessions always get a connection id from the SessionManager,
+        // zero is only reported if the session was created with another type.
+        self.session.get_mysql_conn_id().unwrap_or_default()
     }
 
     fn default_auth_plugin(&self) -> &str {
         "mysql_native_password"
     }
 
-    fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    async fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        // The plugin depends on how the password of the user is hashed, the client
+        // will be asked to switch to it if it has chosen another one.
+        let username = String::from_utf8_lossy(user);
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0];
+        let auth_plugin = self.base.get_auth_plugin(&username, client_ip, self.secure);
+        match auth_plugin.await {
+            Ok(auth_plugin) => auth_plugin,
+            // Unknown users are rejected in authenticate.
+            Err(_) => <Self as AsyncMysqlShim<W>>::default_auth_plugin(self),
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -94,7 +106,7 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
 
     async fn authenticate(
         &self,
-        _auth_plugin: &str,
+        auth_plugin: &str,
         username: &[u8],
         salt: &[u8],
         auth_data: &[u8],
@@ -103,7 +115,7 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         let client_addr = self.client_addr.clone();
         let info = CertifiedInfo::create(&username, auth_data, &client_addr);
 
-        let authenticate = self.base.authenticate(salt, info);
+        let authenticate = self.base.authenticate(auth_plugin, salt, info);
         match authenticate.await {
             Ok(res) => res,
             Err(failure) => {
@@ -127,10 +139,12 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         writer: StatementMetaWriter<'a, W>,
     ) -> Result<()> {
         if self.session.is_aborting() {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                "Aborting this connection. because we are try aborting server.".as_bytes(),
-            )?;
+            writer
+                .error(
+                    ErrorKind::ER_ABORTING_CONNECTION,
+                    "Aborting this connection. because we are try aborting server.".as_bytes(),
+                )
+                .await?;
 
             return Err(ErrorCode::AbortedSession(
                 "Aborting this connection. because we are try aborting server.",
@@ -147,10 +161,12 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
         if self.session.is_aborting() {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                "Aborting this connection. because we are try aborting server.".as_bytes(),
-            )?;
+            writer
+                .error(
+                    ErrorKind::ER_ABORTING_CONNECTION,
+                    "Aborting this connection. because we are try aborting server.".as_bytes(),
+                )
+                .await?;
 
             return Err(ErrorCode::AbortedSession(
                 "Aborting this connection. because we are try aborting server.",
@@ -171,10 +187,12 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
         if self.session.is_aborting() {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                "Aborting this connection. because we are try aborting server.".as_bytes(),
-            )?;
+            writer
+                .error(
+                    ErrorKind::ER_ABORTING_CONNECTION,
+                    "Aborting this connection. because we are try aborting server.".as_bytes(),
+                )
+                .await?;
 
             return Err(ErrorCode::AbortedSession(
                 "Aborting this connection. because we are try aborting server.",
@@ -183,27 +201,49 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
 
         let mut writer = DFQueryResultWriter::create(writer);
 
-        let instant = Instant::now();
-        let blocks = self.base.do_query(query).await;
-
-        let format = self
-            .session
-            .get_shared_query_context()
-            .await?
-            .get_format_settings()?;
-        let mut write_result = writer.write(blocks, &format);
-
-        if let Err(cause) = write_result {
-            let suffix = format!("(while in query {})", query);
-            write_result = Err(cause.add_message_back(suffix));
-        }
+        // The statements in one packet are executed one by one, the remaining statements are
+        // skipped once one of them fails. opensrv-mysql writes a single result for a COM_QUERY,
+        // so only the result of the last statement is returned, the others are drained.
+        let statements = SQLCommon::split_statements(query);
+        for (index, statement) in statements.iter().enumerate() {
+            let instant = Instant::now();
+            let mut blocks = self.base.do_query(statement).await;
+
+            if index + 1 < statements.len() {
+                if let Ok(query_result) = blocks {
+                    blocks = Self::drain(query_result)
+                        .await
+                        .map(|_| QueryResult::empty());
+                }
+                if blocks.is_ok() {
+                    histogram!(
+                        super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+                        instant.elapsed()
+                    );
+                    continue;
+                }
+            }
 
-        histogram!(
-            super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
-            instant.elapsed()
-        );
+            let format = self
+                .session
+                .get_shared_query_context()
+                .await?
+                .get_format_settings()?;
+            let write_result = writer.write(blocks, &format).await;
+
+            histogram!(
+                super::mysql_metrics::METRIC_MYSQL_PROCESSOR_REQUEST_DURATION,
+                instant.elapsed()
+            );
+
+            if let Err(cause) = write_result {
+                let suffix = format!("(while in query {})", statement);
+                return Err(cause.add_message_back(suffix));
+            }
+            break;
+        }
 
-        write_result
+        Ok(())
     }
 
     async fn on_init<'a>(
@@ -212,56 +252,108 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
         writer: InitWriter<'a, W>,
     ) -> Result<()> {
         if self.session.is_aborting() {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                "Aborting this connection. because we are try aborting server.".as_bytes(),
-            )?;
+            writer
+                .error(
+                    ErrorKind::ER_ABORTING_CONNECTION,
+                    "Aborting this connection. because we are try aborting server.".as_bytes(),
+                )
+                .await?;
 
             return Err(ErrorCode::AbortedSession(
                 "Aborting this connection. because we are try aborting server.",
             ));
         }
 
-        DFInitResultWriter::create(writer).write(self.base.do_init(database_name).await)
+        DFInitResultWriter::create(writer)
+            .write(self.base.do_init(database_name).await)
+            .await
     }
 }
 
-impl<W: std::io::Write> InteractiveWorkerBase<W> {
-    async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
-        let user_name = &info.user_name;
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
-
+impl InteractiveWorkerBase {
+    // The auth plugin of the user, which is looked up with the client ip like in authenticate.
+    async fn get_auth_plugin(
+        &self,
+        username: &str,
+        client_ip: &str,
+        secure: bool,
+    ) -> Result<&'static str> {
         let ctx = self.session.create_query_context().await?;
-        let user_manager = ctx.get_user_manager();
-        let user_info = user_manager
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+        let tenant = ctx.get_tenant();
+        let user_mgr = ctx.get_user_manager();
+        let user = user_mgr
+            .get_user_with_client_ip(&tenant, username, client_ip)
             .await?;
 
-        let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
-        if authed {
-            self.session.set_current_user(user_info);
+        // The full authentication of caching_sha2_password, until then the fast one can't
+        // verify the password. The password is sent in cleartext, so only over TLS.
+        match user.auth_info.get_password_type() {
+            Some(PasswordHashMethod::Sha256)
+                if secure && user_mgr.get_caching_sha2_digest(&tenant, &user).is_none() =>
+            {
+                Ok("mysql_clear_password")
+            }
+            _ => Ok(user.auth_info.mysql_auth_plugin()),
         }
-        Ok(authed)
     }
 
-    async fn do_prepare(&mut self, _: &str, writer: StatementMetaWriter<'_, W>) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Prepare is not support in Databend.".as_bytes(),
-        )?;
+    async fn authenticate(
+        &self,
+        auth_plugin: &str,
+        salt: &[u8],
+        info: CertifiedInfo,
+    ) -> Result<bool> {
+        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let credential = match auth_plugin {
+            // The password is terminated by NUL in mysql_clear_password.
+            "mysql_clear_password" => {
+                let password = info.user_password.split(|c| *c == 0).next().unwrap_or(&[]);
+                Credential::Password {
+                    name: info.user_name.clone(),
+                    password: Some(password.to_vec()),
+                    hostname: Some(client_ip.to_string()),
+                }
+            }
+            _ => Credential::MySQL {
+                name: info.user_name.clone(),
+                salt: salt.to_vec(),
+                scramble: info.user_password.clone(),
+                hostname: Some(client_ip.to_string()),
+            },
+        };
+
+        let ctx = self.session.create_query_context().await?;
+        let (_, user_info) = ctx.get_auth_manager().auth(&credential).await?;
+        self.session.set_current_user(user_info);
+        Ok(true)
+    }
+
+    async fn do_prepare<W: AsyncWrite + Send + Unpin>(
+        &mut self,
+        _: &str,
+        writer: StatementMetaWriter<'_, W>,
+    ) -> Result<()> {
+        writer
+            .error(
+                ErrorKind::ER_UNKNOWN_ERROR,
+                "Prepare is not support in Databend.".as_bytes(),
+            )
+            .await?;
         Ok(())
     }
 
-    async fn do_execute(
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
         &mut self,
         _: u32,
         _: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer.error(
-            ErrorKind::ER_UNKNOWN_ERROR,
-            "Execute is not support in Databend.".as_bytes(),
-        )?;
+        writer
+            .error(
+                ErrorKind::ER_UNKNOWN_ERROR,
+                "Execute is not support in Databend.".as_bytes(),
+            )
+            .await?;
         Ok(())
     }
 
@@ -270,45 +362,32 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
     fn federated_server_command_check(&self, query: &str) -> Option<DataBlock> {
-        let federated = MySQLFederated::create();
+        let federated = MySQLFederated::create_with_settings(&self.session.get_settings());
         federated.check(query)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
+    async fn do_query(&mut self, query: &str) -> Result<QueryResult> {
         match self.federated_server_command_check(query) {
             Some(data_block) => {
                 tracing::info!("Federated query: {}", query);
                 if data_block.num_rows() > 0 {
                     tracing::info!("Federated response: {:?}", data_block);
                 }
-                Ok((vec![data_block], String::from("")))
+                Ok(QueryResult::from_blocks(vec![data_block]))
             }
             None => {
                 tracing::info!("Normal query: {}", query);
                 let context = self.session.create_query_context().await?;
                 context.attach_query_str(query);
 
-                let settings = context.get_settings();
-
                 let (stmts, hints) =
                     DfParser::parse_sql(query, context.get_current_session().get_type())?;
 
                 let interpreter: Result<Arc<dyn Interpreter>> =
-                    if settings.get_enable_new_processor_framework()? != 0
-                        && context.get_cluster().is_empty()
-                        && settings.get_enable_planner_v2()? != 0
-                        && stmts.get(0).map_or(false, InterpreterFactoryV2::check)
-                    {
-                        let mut planner = Planner::new(context.clone());
-                        planner
-                            .plan_sql(query)
-                            .await
-                            .and_then(|v| InterpreterFactoryV2::get(context.clone(), &v.0))
-                    } else {
-                        let (plan, _) = PlanParser::parse_with_hint(query, context.clone()).await;
-                        plan.and_then(|v| InterpreterFactory::get(context.clone(), v))
-                    };
+                    InterpreterFactoryRouter::get(context.clone(), query, &stmts)
+                        .await
+                        .map(|(interpreter, _)| interpreter);
 
                 let hint = hints
                     .iter()
@@ -316,22 +395,41 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
                     .and_then(|x| x.error_code);
 
                 match (hint, interpreter) {
+                    (None, Ok(interpreter)) if hints.iter().any(DfHint::has_result_hints) => {
+                        let blocks = Self::exec_query(interpreter, &context)
+                            .await?
+                            .blocks
+                            .collect::<Result<Vec<DataBlock>>>()
+                            .await?;
+                        // The first mismatch of the hints fails the statement.
+                        if let Some(mismatch) = check_result_hints(&hints, &blocks)?.pop() {
+                            return Err(mismatch.into_error());
+                        }
+                        Ok(QueryResult::from_blocks(blocks))
+                    }
                     (None, Ok(interpreter)) => Self::exec_query(interpreter, &context).await,
                     (Some(code), Ok(interpreter)) => {
-                        let res = Self::exec_query(interpreter, &context).await;
+                        let res = match Self::exec_query(interpreter, &context).await {
+                            Ok(query_result) => query_result
+                                .blocks
+                                .collect::<Result<Vec<DataBlock>>>()
+                                .await
+                                .map(|_| ()),
+                            Err(cause) => Err(cause),
+                        };
                         match res {
-                            Ok(_) => Err(ErrorCode::UnexpectedError(format!(
-                                "Expected server error code: {} but got: Ok.",
-                                code
-                            ))),
+                            Ok(_) => Err(DfHintMismatch::create("server error code", code, "Ok")
+                                .into_error()),
                             Err(e) => {
                                 if code != e.code() {
-                                    return Err(ErrorCode::UnexpectedError(format!(
-                                        "Expected server error code: {} but got: Ok.",
-                                        code
-                                    )));
+                                    return Err(DfHintMismatch::create(
+                                        "server error code",
+                                        code,
+                                        e.code(),
+                                    )
+                                    .into_error());
                                 }
-                                Ok((vec![DataBlock::empty()], String::from("")))
+                                Ok(QueryResult::empty())
                             }
                         }
                     }
@@ -342,12 +440,14 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
                     (Some(code), Err(e)) => {
                         if code != e.code() {
                             InterpreterQueryLog::fail_to_start(context, e.clone()).await;
-                            return Err(ErrorCode::UnexpectedError(format!(
-                                "Expected server error code: {} but got: Ok.",
-                                code
-                            )));
+                            return Err(DfHintMismatch::create(
+                                "server error code",
+                                code,
+                                e.code(),
+                            )
+                            .into_error());
                         }
-                        Ok((vec![DataBlock::empty()], String::from("")))
+                        Ok(QueryResult::empty())
                     }
                 }
             }
@@ -358,54 +458,150 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     async fn exec_query(
         interpreter: Arc<dyn Interpreter>,
         context: &Arc<QueryContext>,
-    ) -> Result<(Vec<DataBlock>, String)> {
+    ) -> Result<QueryResult> {
         let instant = Instant::now();
+        let flush_threshold = context.get_settings().get_mysql_flush_threshold()? as usize;
 
-        let query_result = context.try_spawn(
+        // Blocks are batched up to the flush threshold on the query runtime and handed over
+        // to the writer one batch at a time, so that the whole result is never buffered.
+        let (mut tx, rx) = futures::channel::mpsc::channel::<Result<DataBlock>>(1);
+        context.try_spawn(
             async move {
                 // Write start query log.
                 let _ = interpreter
                     .start()
                     .await
                     .map_err(|e| tracing::error!("interpreter.start.error: {:?}", e));
-                let data_stream = interpreter.execute(None).await?;
-                histogram!(
-                    super::mysql_metrics::METRIC_INTERPRETER_USEDTIME,
-                    instant.elapsed()
-                );
+                Self::send_blocks(&interpreter, &mut tx, flush_threshold, instant).await;
 
-                let collector = data_stream.collect::<Result<Vec<DataBlock>>>();
-                let query_result = collector.await?;
-                // Write finish query log.
+                // Write finish query log, also when the query failed or the client has gone.
                 let _ = interpreter
                     .finish()
                     .await
                     .map_err(|e| tracing::error!("interpreter.finish.error: {:?}", e));
-
-                Ok::<Vec<DataBlock>, ErrorCode>(query_result)
             }
             .in_current_span(),
         )?;
 
-        let query_result = query_result
-            .await
-            .map_err_to_code(ErrorCode::TokioError, || {
-                "Cannot join handle from context's runtime"
-            })?;
-        query_result.map(|data| (data, Self::extra_info(context, instant)))
+        let context = context.clone();
+        Ok(QueryResult::create(Box::pin(rx), move || {
+            Self::ok_response(&context, instant)
+        }))
+    }
+
+    // Sends the result blocks of the query to the writer, or the error that stops it.
+    async fn send_blocks(
+        interpreter: &Arc<dyn Interpreter>,
+        tx: &mut Sender<Result<DataBlock>>,
+        flush_threshold: usize,
+        instant: Instant,
+    ) {
+        let mut data_stream = match interpreter.execute(None).await {
+            Ok(data_stream) => data_stream,
+            Err(cause) => {
+                let _ = tx.send(Err(cause)).await;
+                return;
+            }
+        };
+        histogram!(
+            super::mysql_metrics::METRIC_INTERPRETER_USEDTIME,
+            instant.elapsed()
+        );
+
+        let mut buffered_rows = 0;
+        let mut buffered_blocks = vec![];
+        while let Some(block) = data_stream.next().await {
+            let block = match block {
+                Ok(block) => block,
+                Err(cause) => {
+                    let _ = tx.send(Err(cause)).await;
+                    return;
+                }
+            };
+
+            buffered_rows += block.num_rows();
+            buffered_blocks.push(block);
+            if buffered_rows >= flush_threshold {
+                let blocks = std::mem::take(&mut buffered_blocks);
+                buffered_rows = 0;
+                // The client has gone away, stop pulling the query.
+                if tx.send(Self::merge_blocks(blocks)).await.is_err() {
+                    return;
+                }
+            }
+        }
+
+        if !buffered_blocks.is_empty() {
+            let _ = tx.send(Self::merge_blocks(buffered_blocks)).await;
+        }
     }
 
-    fn extra_info(context: &Arc<QueryContext>, instant: Instant) -> String {
-        let progress = context.get_scan_progress_value();
+    fn merge_blocks(mut blocks: Vec<DataBlock>) -> Result<DataBlock> {
+        match blocks.len() {
+            1 => Ok(blocks.remove(0)),
+            _ => DataBlock::concat_blocks(&blocks),
+        }
+    }
+
+    // The rows written by INSERT/COPY, or deleted/updated by DELETE/UPDATE, are reported as
+    // the affected rows, along with the written and read progress and the IO accounting of
+    // the query in the info.
+    fn ok_response(context: &Arc<QueryContext>, instant: Instant) -> OkResponse {
         let seconds = instant.elapsed().as_nanos() as f64 / 1e9f64;
-        format!(
+        let written = context.get_write_progress_value();
+        let read = context.get_scan_progress_value();
+
+        let mut info = String::new();
+        if written.rows > 0 {
+            info.push_str(&format!(
+                "Written {} rows, {}. ",
+                written.rows,
+                convert_byte_size(written.bytes as f64),
+            ));
+        }
+        info.push_str(&format!(
             "Read {} rows, {} in {:.3} sec., {} rows/sec., {}/sec.",
-            progress.rows,
-            convert_byte_size(progress.bytes as f64),
+            read.rows,
+            convert_byte_size(read.bytes as f64),
             seconds,
-            convert_number_size((progress.rows as f64) / (seconds as f64)),
-            convert_byte_size((progress.bytes as f64) / (seconds as f64)),
-        )
+            convert_number_size((read.rows as f64) / (seconds as f64)),
+            convert_byte_size((read.bytes as f64) / (seconds as f64)),
+        ));
+
+        // The IO accounting of the query, only the non-zero ones are reported.
+        let metrics = context.get_dal_metrics();
+        let mut io = vec![];
+        if metrics.get_requests() > 0 {
+            io.push(format!("{} IO requests", metrics.get_requests()));
+        }
+        if metrics.get_cache_hits() + metrics.get_cache_misses() > 0 {
+            io.push(format!(
+                "{} cache hits, {} cache misses",
+                metrics.get_cache_hits(),
+                metrics.get_cache_misses()
+            ));
+        }
+        if metrics.get_network_received_bytes() > 0 {
+            io.push(format!(
+                "{} received from network",
+                convert_byte_size(metrics.get_network_received_bytes() as f64)
+            ));
+        }
+        if metrics.get_spill_bytes() > 0 {
+            io.push(format!(
+                "{} spilled",
+                convert_byte_size(metrics.get_spill_bytes() as f64)
+            ));
+        }
+        if !io.is_empty() {
+            info.push_str(&format!(" {}.", io.join(", ")));
+        }
+
+        OkResponse {
+            affected_rows: context.get_affected_rows().unwrap_or(written.rows as u64),
+            info,
+            ..Default::default()
+        }
     }
 
     async fn do_init(&mut self, database_name: &str) -> Result<()> {
@@ -414,16 +610,26 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
         }
         let init_query = format!("USE `{}`;", database_name);
 
-        let do_query = self.do_query(&init_query).await;
-        match do_query {
-            Ok(_) => Ok(()),
-            Err(error_code) => Err(error_code),
-        }
+        let query_result = self.do_query(&init_query).await?;
+        query_result
+            .blocks
+            .collect::<Result<Vec<DataBlock>>>()
+            .await
+            .map(|_| ())
     }
 }
 
-impl<W: std::io::Write> InteractiveWorker<W> {
-    pub fn create(session: SessionRef, client_addr: String) -> InteractiveWorker<W> {
+impl InteractiveWorker {
+    // Run a statement whose result is not returned to the client to the end.
+    async fn drain(query_result: QueryResult) -> Result<()> {
+        let mut blocks = query_result.blocks;
+        while let Some(block) = blocks.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    pub fn create(session: SessionRef, client_addr: String) -> InteractiveWorker {
         let mut bs = vec![0u8; 20];
         let mut rng = rand::thread_rng();
         rng.fill_bytes(bs.as_mut());
@@ -436,12 +642,9 @@ impl<W: std::io::Write> InteractiveWorker<W> {
             }
         }
 
-        InteractiveWorker::<W> {
+        InteractiveWorker {
             session: session.clone(),
-            base: InteractiveWorkerBase::<W> {
-                session,
-                generic_hold: PhantomData::default(),
-            },
+            base: InteractiveWorkerBase { session },
             salt: scramble,
             version: format!(
                 "{}-{}",
@@ -449,6 +652,11 @@ impl<W: std::io::Write> InteractiveWorker<W> {
                 *crate::version::DATABEND_COMMIT_VERSION
             ),
             client_addr,
+            secure: false,
         }
     }
+
+    pub fn set_secure(&mut self, secure: bool) {
+        self.secure = secure;
+    }
 }
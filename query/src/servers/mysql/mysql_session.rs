@@ -13,50 +13,93 @@
 // limitations under the License.
 
 use std::net::Shutdown;
+use std::sync::Arc;
 
 use common_base::base::tokio::net::TcpStream;
-use common_base::base::Runtime;
-use common_base::base::Thread;
 use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_tracing::tracing;
+use opensrv_mysql::plain_run_with_options;
+use opensrv_mysql::secure_run_with_options;
 use opensrv_mysql::AsyncMysqlIntermediary;
 use opensrv_mysql::IntermediaryOptions;
+use tokio_rustls::rustls::ServerConfig;
 
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
+use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::sessions::SessionRef;
 
 pub struct MySQLConnection;
 
 impl MySQLConnection {
-    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+    pub fn run_on_stream(
+        session: SessionRef,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        require_secure_transport: bool,
+    ) -> Result<()> {
         let blocking_stream = Self::convert_stream(stream)?;
         MySQLConnection::attach_session(&session, &blocking_stream)?;
 
         let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
-        let query_executor =
-            Runtime::with_worker_threads(1, Some("mysql-query-executor".to_string()))?;
-        Thread::spawn(move || {
-            let join_handle = query_executor.spawn(async move {
-                let client_addr = non_blocking_stream.peer_addr().unwrap().to_string();
-                let interactive_worker = InteractiveWorker::create(session, client_addr);
-                let opts = IntermediaryOptions {
-                    process_use_statement_on_query: true,
-                };
-                AsyncMysqlIntermediary::run_with_options(
-                    interactive_worker,
-                    non_blocking_stream,
-                    &opts,
-                )
-                .await
-            });
-            let _ = futures::executor::block_on(join_handle);
+        let query_executor = session.get_session_manager().get_query_runtime();
+        query_executor.spawn(async move {
+            let client_addr = non_blocking_stream.peer_addr().unwrap().to_string();
+            let interactive_worker = InteractiveWorker::create(session, client_addr);
+
+            let res = Self::run_on_worker(
+                interactive_worker,
+                non_blocking_stream,
+                tls,
+                require_secure_transport,
+            )
+            .await;
+
+            if let Err(error) = res {
+                tracing::error!(
+                    "Unexpected error occurred during MySQL session: {:?}",
+                    error
+                );
+            }
         });
         Ok(())
     }
 
+    // The connection is upgraded to TLS by opensrv-mysql if the client sends the SSLRequest
+    // after the initial handshake, in which CLIENT_SSL is advertised if TLS is enabled.
+    async fn run_on_worker(
+        mut interactive_worker: InteractiveWorker,
+        stream: TcpStream,
+        tls: Option<Arc<ServerConfig>>,
+        require_secure_transport: bool,
+    ) -> Result<()> {
+        let opts = IntermediaryOptions {
+            process_use_statement_on_query: true,
+        };
+
+        let (reader, mut writer) = stream.into_split();
+        let (is_ssl, init_params) = AsyncMysqlIntermediary::init_before_ssl(
+            &mut interactive_worker,
+            reader,
+            &mut writer,
+            &tls,
+        )
+        .await?;
+
+        interactive_worker.set_secure(tls.is_some() && is_ssl);
+        match tls {
+            Some(tls) if is_ssl => {
+                secure_run_with_options(interactive_worker, writer, opts, tls, init_params).await
+            }
+            _ if require_secure_transport => {
+                RejectConnection::reject_insecure_connection(&mut writer, init_params.1).await
+            }
+            _ => plain_run_with_options(interactive_worker, writer, opts, init_params).await,
+        }
+    }
+
     fn attach_session(session: &SessionRef, blocking_stream: &std::net::TcpStream) -> Result<()> {
         let host = blocking_stream.peer_addr().ok();
         let blocking_stream_ref = blocking_stream.try_clone()?;
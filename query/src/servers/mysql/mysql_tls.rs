@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tokio_rustls::rustls::Certificate;
+use tokio_rustls::rustls::PrivateKey;
+use tokio_rustls::rustls::ServerConfig;
+
+/// TLS config of the MySQL handler.
+///
+/// CLIENT_SSL is advertised in the initial handshake if TLS is enabled, and the connection
+/// is upgraded by opensrv-mysql once the client sends the SSLRequest.
+pub struct MySQLTlsConfig;
+
+impl MySQLTlsConfig {
+    /// Returns None if TLS is disabled, i.e. the cert or the key is not configured.
+    pub fn try_create(cert_file: &str, key_file: &str) -> Result<Option<Arc<ServerConfig>>> {
+        if cert_file.is_empty() || key_file.is_empty() {
+            return Ok(None);
+        }
+
+        let certs = Self::load_certs(cert_file)?;
+        let key = Self::load_key(key_file)?;
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map_err(|cause| {
+                ErrorCode::TLSConfigurationFailure(format!(
+                    "Cannot build MySQL TLS config: {}",
+                    cause
+                ))
+            })?;
+
+        Ok(Some(Arc::new(config)))
+    }
+
+    fn load_certs(cert_file: &str) -> Result<Vec<Certificate>> {
+        let mut reader = BufReader::new(File::open(cert_file)?);
+        let certs = rustls_pemfile::certs(&mut reader)?;
+        match certs.is_empty() {
+            true => Err(ErrorCode::TLSConfigurationFailure(format!(
+                "No certificate found in {}",
+                cert_file
+            ))),
+            false => Ok(certs.into_iter().map(Certificate).collect()),
+        }
+    }
+
+    fn load_key(key_file: &str) -> Result<PrivateKey> {
+        let mut reader = BufReader::new(File::open(key_file)?);
+        let mut keys = rustls_pemfile::pkcs8_private_keys(&mut reader)?;
+        if keys.is_empty() {
+            let mut reader = BufReader::new(File::open(key_file)?);
+            keys = rustls_pemfile::rsa_private_keys(&mut reader)?;
+        }
+
+        match keys.into_iter().next() {
+            Some(key) => Ok(PrivateKey(key)),
+            None => Err(ErrorCode::TLSConfigurationFailure(format!(
+                "No private key found in {}",
+                key_file
+            ))),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
 use common_base::base::tokio::io::AsyncWriteExt;
 use common_base::base::tokio::net::TcpStream;
 use common_exception::Result;
@@ -30,20 +31,45 @@ impl RejectConnection {
         RejectConnection::receive_handshake_response(&mut stream).await?;
 
         // Send error. Packet[seq = 2]
-        let mut buffer = vec![0xFF_u8];
-        buffer.extend(&(code as u16).to_le_bytes());
-        buffer.extend(&vec![b'#']);
-        buffer.extend(code.sqlstate());
-        buffer.extend(error_message.into().as_bytes());
-
-        let size = buffer.len().to_le_bytes();
-        buffer.splice(0..0, [size[0], size[1], size[2], 2].iter().cloned());
+        let message = error_message.into();
+        let buffer = Self::error_packet(2, code as u16, code.sqlstate(), &message);
         stream.write_all(&buffer).await?;
         stream.flush().await?;
 
         Ok(())
     }
 
+    /// Rejects the connection of a client which has not requested TLS, after its
+    /// handshake response of sequence `seq` is received.
+    pub async fn reject_insecure_connection<W: AsyncWrite + Unpin>(
+        writer: &mut W,
+        seq: u8,
+    ) -> Result<()> {
+        // ER_SECURE_TRANSPORT_REQUIRED, which is not in the ErrorKind of opensrv-mysql.
+        let buffer = Self::error_packet(
+            seq.wrapping_add(1),
+            3159,
+            b"HY000",
+            "Connections using insecure transport are prohibited while mysql_require_secure_transport is enabled.",
+        );
+        writer.write_all(&buffer).await?;
+        writer.flush().await?;
+
+        Ok(())
+    }
+
+    fn error_packet(seq: u8, code: u16, sqlstate: &[u8], message: &str) -> Vec<u8> {
+        let mut buffer = vec![0xFF_u8];
+        buffer.extend(&code.to_le_bytes());
+        buffer.extend(&vec![b'#']);
+        buffer.extend(sqlstate);
+        buffer.extend(message.as_bytes());
+
+        let size = buffer.len().to_le_bytes();
+        buffer.splice(0..0, [size[0], size[1], size[2], seq].iter().cloned());
+        buffer
+    }
+
     async fn send_handshake(stream: &mut TcpStream) -> Result<()> {
         // Send handshake, packet from opensrv-mysql. Packet[seq = 0]
         stream
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::base::tokio::io::AsyncWrite;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::TypeID;
 use common_datavalues::remove_nullable;
@@ -27,48 +28,93 @@ use common_exception::Result;
 use common_exception::ABORT_QUERY;
 use common_exception::ABORT_SESSION;
 use common_io::prelude::FormatSettings;
+use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 use opensrv_mysql::*;
 
-pub struct DFQueryResultWriter<'a, W: std::io::Write> {
+/// The result of a query, blocks are written to the client as they come out of the stream.
+pub struct QueryResult {
+    pub blocks: SendableDataBlockStream,
+    // Builds the final OK packet once all the blocks are written, e.g. the affected rows and
+    // the progress info of the query.
+    ok_response: Box<dyn FnOnce() -> OkResponse + Send>,
+}
+
+impl QueryResult {
+    pub fn create(
+        blocks: SendableDataBlockStream,
+        ok_response: impl FnOnce() -> OkResponse + Send + 'static,
+    ) -> QueryResult {
+        QueryResult {
+            blocks,
+            ok_response: Box::new(ok_response),
+        }
+    }
+
+    pub fn from_blocks(blocks: Vec<DataBlock>) -> QueryResult {
+        let blocks = futures::stream::iter(blocks.into_iter().map(Ok));
+        QueryResult::create(Box::pin(blocks), OkResponse::default)
+    }
+
+    pub fn empty() -> QueryResult {
+        QueryResult::from_blocks(vec![])
+    }
+}
+
+pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
 }
 
-impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
+impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> { inner: Some(inner) }
     }
 
-    pub fn write(
+    pub async fn write(
         &mut self,
-        query_result: Result<(Vec<DataBlock>, String)>,
+        query_result: Result<QueryResult>,
         format: &FormatSettings,
     ) -> Result<()> {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer, format)?,
-                Err(error) => Self::err(&error, writer)?,
+                Ok(query_result) => Self::ok(query_result, writer, format).await?,
+                Err(error) => Self::err(&error, writer).await?,
             }
         }
         Ok(())
     }
 
-    fn ok(
-        blocks: Vec<DataBlock>,
-        extra_info: String,
+    async fn ok(
+        query_result: QueryResult,
         dataset_writer: QueryResultWriter<'a, W>,
         format: &FormatSettings,
     ) -> Result<()> {
-        // XXX: num_columns == 0 may is error?
-        let default_response = OkResponse {
-            info: extra_info,
-            ..Default::default()
+        let QueryResult {
+            mut blocks,
+            ok_response,
+        } = query_result;
+
+        // TODO: report the progress of long-running queries periodically to the clients with
+        // CLIENT_PROGRESS (MariaDB progress reporting), opensrv-mysql exposes neither the client
+        // capabilities nor the progress packet yet, so only the final OK packet carries it.
+
+        // Errors raised before the first block are reported to the client as an error packet,
+        // once the columns are sent we can only abort the result set.
+        let block = match blocks.next().await {
+            None => None,
+            Some(Ok(block)) => Some(block),
+            Some(Err(error)) => return Self::err(&error, dataset_writer).await,
         };
 
-        if blocks.is_empty() || (blocks[0].num_columns() == 0) {
-            dataset_writer.completed(default_response)?;
-            return Ok(());
-        }
+        // XXX: num_columns == 0 may is error?
+        let block = match block {
+            Some(block) if block.num_columns() != 0 => block,
+            _ => {
+                dataset_writer.completed(ok_response()).await?;
+                return Ok(());
+            }
+        };
 
         fn convert_field_type(field: &DataField) -> Result<ColumnType> {
             match remove_nullable(field.data_type()).data_type_id() {
@@ -113,105 +159,119 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             schema.fields().iter().map(make_column_from_field).collect()
         }
 
-        let block = blocks[0].clone();
-        let tz = format.timezone;
         match convert_schema(block.schema()) {
-            Err(error) => Self::err(&error, dataset_writer),
+            Err(error) => Self::err(&error, dataset_writer).await,
             Ok(columns) => {
-                let columns_size = block.num_columns();
-                let mut row_writer = dataset_writer.start(&columns)?;
-
-                for block in &blocks {
-                    let rows_size = block.column(0).len();
-                    for row_index in 0..rows_size {
-                        for col_index in 0..columns_size {
-                            let val = block.column(col_index).get_checked(row_index)?;
-                            if val.is_null() {
-                                row_writer.write_col(None::<u8>)?;
-                                continue;
-                            }
-                            let data_type =
-                                remove_nullable(block.schema().fields()[col_index].data_type());
-
-                            match (data_type.data_type_id(), val.clone()) {
-                                (TypeID::Boolean, DataValue::Boolean(v)) => {
-                                    row_writer.write_col(v as i8)?
-                                }
-                                (TypeID::Date, DataValue::Int64(v)) => {
-                                    let v = v as i32;
-                                    row_writer.write_col(v.to_date(&tz).naive_local())?
-                                }
-                                (TypeID::Timestamp, DataValue::Int64(v)) => {
-                                    let data_type: &TimestampType =
-                                        data_type.as_any().downcast_ref().unwrap();
-
-                                    row_writer.write_col(
-                                        v.to_timestamp(&tz)
-                                            .naive_local()
-                                            .format(data_type.format_string().as_str())
-                                            .to_string(),
-                                    )?
-                                }
-                                (TypeID::String, DataValue::String(v)) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (TypeID::Array, DataValue::Array(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer
-                                        .write_col(serializer.serialize_value(&val, format)?)?
-                                }
-                                (TypeID::Struct, DataValue::Struct(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer
-                                        .write_col(serializer.serialize_value(&val, format)?)?
-                                }
-                                (TypeID::Variant, DataValue::Variant(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer
-                                        .write_col(serializer.serialize_value(&val, format)?)?
-                                }
-                                (TypeID::VariantArray, DataValue::Variant(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer
-                                        .write_col(serializer.serialize_value(&val, format)?)?
-                                }
-                                (TypeID::VariantObject, DataValue::Variant(_)) => {
-                                    let serializer = data_type.create_serializer();
-                                    row_writer
-                                        .write_col(serializer.serialize_value(&val, format)?)?
-                                }
-                                (_, DataValue::Int64(v)) => row_writer.write_col(v)?,
-
-                                (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
-
-                                (_, DataValue::Float64(v)) => row_writer.write_col(v)?,
-                                (_, v) => {
-                                    return Err(ErrorCode::BadDataValueType(format!(
-                                        "Unsupported column type:{:?}, expected type in schema: {:?}",
-                                        v.data_type(), data_type
-                                    )));
-                                }
-                            }
+                let mut row_writer = dataset_writer.start(&columns).await?;
+
+                // The result set is aborted if the query fails in the middle of it, the error
+                // is returned and the connection is closed, so that the client doesn't take the
+                // rows written so far as the complete result.
+                Self::write_rows(&mut row_writer, block, &mut blocks, format).await?;
+                row_writer.finish_with_info(&ok_response().info).await?;
+
+                Ok(())
+            }
+        }
+    }
+
+    async fn write_rows(
+        row_writer: &mut RowWriter<'_, W>,
+        block: DataBlock,
+        blocks: &mut SendableDataBlockStream,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        let tz = format.timezone;
+        let columns_size = block.num_columns();
+        let mut next_block = Some(block);
+        while let Some(block) = next_block.take() {
+            let rows_size = block.column(0).len();
+            for row_index in 0..rows_size {
+                for col_index in 0..columns_size {
+                    let val = block.column(col_index).get_checked(row_index)?;
+                    if val.is_null() {
+                        row_writer.write_col(None::<u8>)?;
+                        continue;
+                    }
+                    let data_type = remove_nullable(block.schema().fields()[col_index].data_type());
+
+                    match (data_type.data_type_id(), val.clone()) {
+                        (TypeID::Boolean, DataValue::Boolean(v)) => {
+                            row_writer.write_col(v as i8)?
+                        }
+                        (TypeID::Date, DataValue::Int64(v)) => {
+                            let v = v as i32;
+                            row_writer.write_col(
+                                v.to_date(&tz).naive_local().format("%Y-%m-%d").to_string(),
+                            )?
+                        }
+                        (TypeID::Timestamp, DataValue::Int64(v)) => {
+                            let data_type: &TimestampType =
+                                data_type.as_any().downcast_ref().unwrap();
+
+                            row_writer.write_col(
+                                v.to_timestamp(&tz)
+                                    .naive_local()
+                                    .format(data_type.format_string().as_str())
+                                    .to_string(),
+                            )?
+                        }
+                        (TypeID::String, DataValue::String(v)) => row_writer.write_col(v)?,
+                        (TypeID::Array, DataValue::Array(_)) => {
+                            let serializer = data_type.create_serializer();
+                            row_writer.write_col(serializer.serialize_value(&val, format)?)?
+                        }
+                        (TypeID::Struct, DataValue::Struct(_)) => {
+                            let serializer = data_type.create_serializer();
+                            row_writer.write_col(serializer.serialize_value(&val, format)?)?
+                        }
+                        (TypeID::Variant, DataValue::Variant(_)) => {
+                            let serializer = data_type.create_serializer();
+                            row_writer.write_col(serializer.serialize_value(&val, format)?)?
+                        }
+                        (TypeID::VariantArray, DataValue::Variant(_)) => {
+                            let serializer = data_type.create_serializer();
+                            row_writer.write_col(serializer.serialize_value(&val, format)?)?
+                        }
+                        (TypeID::VariantObject, DataValue::Variant(_)) => {
+                            let serializer = data_type.create_serializer();
+                            row_writer.write_col(serializer.serialize_value(&val, format)?)?
+                        }
+                        (_, DataValue::Int64(v)) => row_writer.write_col(v)?,
+
+                        (_, DataValue::UInt64(v)) => row_writer.write_col(v)?,
+
+                        (_, DataValue::Float64(v)) => row_writer.write_col(v)?,
+                        (_, v) => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "Unsupported column type:{:?}, expected type in schema: {:?}",
+                                v.data_type(),
+                                data_type
+                            )));
                         }
-                        row_writer.end_row()?;
                     }
                 }
-                row_writer.finish_with_info(&default_response.info)?;
-
-                Ok(())
+                row_writer.end_row().await?;
             }
+
+            next_block = blocks.next().await.transpose()?;
         }
+        Ok(())
     }
 
-    fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
+    async fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
         if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
             tracing::error!("OnQuery Error: {:?}", error);
-            writer.error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())?;
+            writer
+                .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                .await?;
         } else {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                error.to_string().as_bytes(),
-            )?;
+            writer
+                .error(
+                    ErrorKind::ER_ABORTING_CONNECTION,
+                    error.to_string().as_bytes(),
+                )
+                .await?;
         }
 
         Ok(())
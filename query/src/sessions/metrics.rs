@@ -14,3 +14,5 @@
 
 pub static METRIC_SESSION_CONNECT_NUMBERS: &str = "session.connect_numbers";
 pub static METRIC_SESSION_CLOSE_NUMBERS: &str = "session.close_numbers";
+pub static METRIC_TEMP_STORAGE_USED_BYTES: &str = "temp_storage.used_bytes";
+pub static METRIC_TEMP_STORAGE_ORPHAN_BYTES: &str = "temp_storage.orphan_removed_bytes";
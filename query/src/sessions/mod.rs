@@ -13,8 +13,10 @@
 // limitations under the License.
 
 mod metrics;
+mod query_cache;
 mod query_ctx;
 mod query_ctx_shared;
+mod query_result_cache;
 mod session;
 mod session_ctx;
 mod session_info;
@@ -24,10 +26,18 @@ mod session_mgr_status;
 mod session_ref;
 mod session_settings;
 mod session_status;
+mod session_txn;
 mod session_type;
+mod temp_storage;
 
+pub use query_cache::normalize_query;
+pub use query_cache::CachedBlocks;
+pub use query_cache::QueryCache;
+pub use query_cache::QueryCacheKey;
 pub use query_ctx::QueryContext;
 pub use query_ctx_shared::QueryContextShared;
+pub use query_result_cache::CachedQueryResult;
+pub use query_result_cache::QueryResultCache;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
@@ -36,4 +46,9 @@ pub use session_mgr_status::SessionManagerStatus;
 pub use session_ref::SessionRef;
 pub use session_settings::Settings;
 pub use session_status::SessionStatus;
+pub use session_txn::SessionTransaction;
 pub use session_type::SessionType;
+pub use temp_storage::QueryTempDir;
+pub use temp_storage::TempStorageManager;
+pub use temp_storage::TempStorageReservation;
+pub use temp_storage::TempStorageUsage;
@@ -0,0 +1,259 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Recursion;
+use common_planners::SourceInfo;
+
+use crate::config::QueryConfig;
+use crate::sessions::QueryContext;
+use crate::storages::cache::CacheStatistics;
+use crate::storages::cache::CacheStatus;
+use crate::storages::cache::QUERY_RESULT_CACHE;
+use crate::storages::fuse::cache::record_cache_size;
+use crate::storages::fuse::cache::CacheDeferMetrics;
+use crate::storages::fuse::cache::TenantLabel;
+
+/// The final result of a SELECT, shared by all the sessions of this node.
+pub struct CachedBlocks {
+    pub schema: DataSchemaRef,
+    pub blocks: Vec<DataBlock>,
+    pub bytes: usize,
+}
+
+struct CachedBlocksMeter;
+
+impl<K> Meter<K, Arc<CachedBlocks>> for CachedBlocksMeter {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<CachedBlocks>) -> usize
+    where K: Borrow<Q> {
+        v.bytes
+    }
+}
+
+/// The key of a cached result: the normalized query together with the version of every
+/// table it reads. Committing to a table bumps its version, so the results computed from
+/// the previous version can never be hit again and are evicted by the LRU in time.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QueryCacheKey(String);
+
+impl QueryCacheKey {
+    /// Returns None if the result of the plan may change while its tables do not, i.e.
+    /// it calls a non-deterministic function or reads a source which is not versioned.
+    pub fn try_create(ctx: &QueryContext, plan: &PlanNode) -> Result<Option<QueryCacheKey>> {
+        let mut visitor = CacheableVisitor {
+            cacheable: true,
+            table_versions: vec![],
+        };
+        visitor.visit_plan_node(plan)?;
+
+        if !visitor.cacheable || visitor.table_versions.is_empty() {
+            return Ok(None);
+        }
+
+        let settings = ctx.get_settings().get_setting_values_short();
+        Ok(Some(QueryCacheKey(format!(
+            "{}/{}/{}/{:?}/{}/{:?}",
+            ctx.get_tenant(),
+            ctx.get_current_user()?.identity(),
+            ctx.get_current_database(),
+            settings,
+            normalize_query(&ctx.get_query_str()),
+            visitor.table_versions,
+        ))))
+    }
+}
+
+/// Collapses the whitespaces outside of the quotes and strips the trailing semicolons,
+/// so that the same query formatted differently shares the cached result.
+pub fn normalize_query(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut quote = None;
+    let mut pending_space = false;
+    for c in query.trim().trim_end_matches(';').trim_end().chars() {
+        match quote {
+            Some(q) => {
+                normalized.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c.is_whitespace() => pending_space = true,
+            None => {
+                if pending_space {
+                    normalized.push(' ');
+                    pending_space = false;
+                }
+                if c == '\'' || c == '"' || c == '`' {
+                    quote = Some(c);
+                }
+                normalized.push(c);
+            }
+        }
+    }
+    normalized
+}
+
+struct CacheableVisitor {
+    cacheable: bool,
+    // (table_id, seq) of the tables read by the query.
+    table_versions: Vec<(u64, u64)>,
+}
+
+impl PlanVisitor for CacheableVisitor {
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        match &plan.source_info {
+            SourceInfo::TableSource(table_info)
+                if plan.tbl_args.is_none()
+                    && table_info.meta.engine.eq_ignore_ascii_case("FUSE") =>
+            {
+                self.table_versions
+                    .push((table_info.ident.table_id, table_info.ident.seq));
+            }
+            // System tables, table functions and stages are not versioned.
+            _ => self.cacheable = false,
+        }
+
+        if let Some(push_downs) = &plan.push_downs {
+            self.visit_exprs(&push_downs.filters)?;
+        }
+        Ok(())
+    }
+
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        let visitor = expr.accept(DeterministicVisitor {
+            deterministic: true,
+            subqueries: vec![],
+        })?;
+
+        if !visitor.deterministic {
+            self.cacheable = false;
+        }
+
+        for subquery in visitor.subqueries {
+            self.visit_plan_node(subquery.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+struct DeterministicVisitor {
+    deterministic: bool,
+    subqueries: Vec<Arc<PlanNode>>,
+}
+
+impl ExpressionVisitor for DeterministicVisitor {
+    fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
+        match expr {
+            Expression::ScalarFunction { op, .. } => {
+                // Unknown functions(e.g. UDFs) are taken as non-deterministic.
+                self.deterministic &= FunctionFactory::instance()
+                    .get_features(op)
+                    .map(|features| features.is_deterministic && !features.is_context_func)
+                    .unwrap_or(false);
+            }
+            Expression::Subquery { query_plan, .. }
+            | Expression::ScalarSubquery { query_plan, .. } => {
+                self.subqueries.push(query_plan.clone());
+            }
+            _ => {}
+        }
+
+        Ok(Recursion::Continue(self))
+    }
+}
+
+/// The node level cache of the SELECT results, bounded by `query_result_cache_mb_size`.
+pub struct QueryCache {
+    results:
+        Mutex<LruCache<QueryCacheKey, Arc<CachedBlocks>, DefaultHashBuilder, CachedBlocksMeter>>,
+    statistics: CacheStatistics,
+    tenant_label: TenantLabel,
+}
+
+impl QueryCache {
+    pub fn init(config: &QueryConfig) -> QueryCache {
+        let capacity = config.query_result_cache_mb_size * 1024 * 1024;
+        QueryCache {
+            results: Mutex::new(LruCache::with_meter(capacity, CachedBlocksMeter)),
+            statistics: CacheStatistics::default(),
+            tenant_label: TenantLabel {
+                tenant_id: config.tenant_id.clone(),
+                cluster_id: config.cluster_id.clone(),
+            },
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.results.lock().capacity() as usize
+    }
+
+    pub fn get(&self, key: &QueryCacheKey) -> Option<Arc<CachedBlocks>> {
+        let cached = self.results.lock().get(key).cloned();
+        let _metrics = CacheDeferMetrics {
+            tenant_label: self.tenant_label.clone(),
+            cache_name: QUERY_RESULT_CACHE,
+            cache_hit: cached.is_some(),
+            read_bytes: cached.as_ref().map(|v| v.bytes as u64).unwrap_or(0),
+        };
+
+        match cached {
+            Some(_) => self.statistics.inc_hits(),
+            None => self.statistics.inc_misses(),
+        }
+        cached
+    }
+
+    pub fn put(&self, key: QueryCacheKey, result: CachedBlocks) {
+        let mut results = self.results.lock();
+        if result.bytes as u64 <= results.capacity() {
+            results.put(key, Arc::new(result));
+            record_cache_size(&self.tenant_label, QUERY_RESULT_CACHE, results.size());
+        }
+    }
+
+    pub fn clear(&self) {
+        self.results.lock().clear();
+        record_cache_size(&self.tenant_label, QUERY_RESULT_CACHE, 0);
+    }
+
+    pub fn status(&self) -> CacheStatus {
+        let results = self.results.lock();
+        CacheStatus {
+            name: QUERY_RESULT_CACHE,
+            unit: "bytes",
+            capacity: results.capacity(),
+            size: results.size(),
+            entries: results.len() as u64,
+            hits: self.statistics.hits(),
+            misses: self.statistics.misses(),
+        }
+    }
+}
@@ -15,9 +15,11 @@
 use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use chrono_tz::Tz;
 use common_base::base::tokio::task::JoinHandle;
@@ -34,7 +36,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionContext;
 use common_io::prelude::FormatSettings;
+use common_management::LoadApi;
 use common_meta_app::schema::TableInfo;
+use common_meta_types::LoadInfo;
 use common_meta_types::UserInfo;
 use common_planners::Expression;
 use common_planners::PartInfoPtr;
@@ -52,28 +56,71 @@ use opendal::Operator;
 
 use crate::catalogs::Catalog;
 use crate::catalogs::CatalogManager;
+use crate::catalogs::CatalogSnapshot;
+use crate::catalogs::CATALOG_DEFAULT;
 use crate::clusters::Cluster;
+use crate::pipelines::new::executor::ProfileCallback;
+use crate::pipelines::new::executor::QueryProfileManager;
+use crate::pipelines::new::processors::ScanRuntimeFilter;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryCache;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryTempDir;
 use crate::sessions::Session;
 use crate::sessions::SessionRef;
+use crate::sessions::SessionTransaction;
 use crate::sessions::Settings;
+use crate::sessions::TempStorageManager;
 use crate::storages::cache::CacheManager;
+use crate::storages::encryption::StorageKeyring;
+use crate::storages::fuse::corruption::CorruptBlockManager;
+use crate::storages::fuse::io::ReadLatencyTracker;
+use crate::storages::fuse::pruning::PruningStatistics;
+use crate::storages::fuse::replication::ReplicationManager;
 use crate::storages::stage::StageTable;
 use crate::storages::Table;
+use crate::table_functions::ResultScanTable;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+use crate::table_functions::RESULT_SCAN_FUNC_NAME;
 use crate::users::auth::auth_mgr::AuthMgr;
 use crate::users::RoleCacheMgr;
 use crate::users::UserApiProvider;
 use crate::Config;
 
+// The system tables visible in sandbox, the others are masked as they expose the
+// configs, users and queries of the tenant.
+const SANDBOX_SYSTEM_TABLES: [&str; 8] = [
+    "one",
+    "databases",
+    "tables",
+    "columns",
+    "functions",
+    "settings",
+    "contributors",
+    "credits",
+];
+
+// The table functions allowed in sandbox, the others read the storage internals.
+const SANDBOX_TABLE_FUNCTIONS: [&str; 4] = [
+    "numbers",
+    "numbers_mt",
+    "numbers_local",
+    RESULT_SCAN_FUNC_NAME,
+];
+
 #[derive(Clone)]
 pub struct QueryContext {
     version: String,
     statistics: Arc<RwLock<Statistics>>,
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
+    // The number of partitions stolen from the partition pool.
+    partition_sequence: Arc<AtomicU64>,
     shared: Arc<QueryContextShared>,
     precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
+    // The filters built by the hash joins for the table scan being built with this context.
+    runtime_filters: Arc<RwLock<Vec<Arc<ScanRuntimeFilter>>>>,
 }
 
 impl QueryContext {
@@ -89,9 +136,11 @@ impl QueryContext {
         Arc::new(QueryContext {
             statistics: Arc::new(RwLock::new(Statistics::default())),
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
+            partition_sequence: Arc::new(AtomicU64::new(0)),
             version: format!("DatabendQuery {}", *crate::version::DATABEND_COMMIT_VERSION),
             shared,
             precommit_blocks: Arc::new(RwLock::new(Vec::new())),
+            runtime_filters: Arc::new(RwLock::new(Vec::new())),
         })
     }
 
@@ -126,7 +175,7 @@ impl QueryContext {
         if table_args.is_none() {
             catalog.get_table_by_info(table_info)
         } else {
-            Ok(catalog
+            Ok(self
                 .get_table_function(&table_info.name, table_args)?
                 .as_table())
         }
@@ -177,6 +226,20 @@ impl QueryContext {
         error.clone()
     }
 
+    // Get the memory usage in bytes of the query runtime.
+    pub fn get_memory_usage(&self) -> usize {
+        match self.shared.try_get_runtime() {
+            Ok(runtime) => {
+                let memory_usage = runtime
+                    .get_tracker()
+                    .get_memory_tracker()
+                    .get_memory_usage();
+                memory_usage.max(0) as usize
+            }
+            Err(_) => 0,
+        }
+    }
+
     pub fn set_error(&self, err: ErrorCode) {
         self.shared.set_error(err);
     }
@@ -196,17 +259,36 @@ impl QueryContext {
         Ok(partitions)
     }
 
+    // Steal one partition from the partition pool with its sequence number, i.e. the number of
+    // partitions stolen before it, which orders the blocks read from the partitions.
+    pub fn try_get_partition_with_sequence(&self) -> Result<Option<(u64, PartInfoPtr)>> {
+        let mut partition_queue = self.partition_queue.write();
+        Ok(partition_queue.pop_back().map(|partition| {
+            let sequence = self.partition_sequence.fetch_add(1, Ordering::Relaxed);
+            (sequence, partition)
+        }))
+    }
+
     // Update the context partition pool from the pipeline builder.
     pub fn try_set_partitions(&self, partitions: Partitions) -> Result<()> {
         let mut partition_queue = self.partition_queue.write();
 
         partition_queue.clear();
+        self.partition_sequence.store(0, Ordering::Relaxed);
         for part in partitions {
             partition_queue.push_back(part);
         }
         Ok(())
     }
 
+    pub fn set_runtime_filters(&self, filters: Vec<Arc<ScanRuntimeFilter>>) {
+        *self.runtime_filters.write() = filters;
+    }
+
+    pub fn get_runtime_filters(&self) -> Vec<Arc<ScanRuntimeFilter>> {
+        self.runtime_filters.read().clone()
+    }
+
     pub fn try_get_statistics(&self) -> Result<Statistics> {
         let statistics = self.statistics.read();
         Ok((*statistics).clone())
@@ -247,6 +329,32 @@ impl QueryContext {
             .get_catalog(catalog_name.as_ref())
     }
 
+    /// Table functions always reside in the default catalog, except RESULT_SCAN which reads
+    /// the results kept in the current session.
+    pub fn get_table_function(
+        &self,
+        func_name: &str,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        if self.get_settings().is_sandboxed()
+            && !SANDBOX_TABLE_FUNCTIONS
+                .iter()
+                .any(|name| func_name.eq_ignore_ascii_case(name))
+        {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Access denied for table function {} in sandbox",
+                func_name
+            )));
+        }
+
+        match func_name.eq_ignore_ascii_case(RESULT_SCAN_FUNC_NAME) {
+            true => ResultScanTable::create(&self.shared.session, tbl_args),
+            false => self
+                .get_catalog(CATALOG_DEFAULT)?
+                .get_table_function(func_name, tbl_args),
+        }
+    }
+
     /// Fetch a Table by db and table name.
     ///
     /// It guaranteed to return a consistent result for multiple calls, in a same query.
@@ -260,9 +368,50 @@ impl QueryContext {
         database: &str,
         table: &str,
     ) -> Result<Arc<dyn Table>> {
+        if self.get_settings().is_sandboxed()
+            && database.eq_ignore_ascii_case("system")
+            && !SANDBOX_SYSTEM_TABLES
+                .iter()
+                .any(|name| table.eq_ignore_ascii_case(name))
+        {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Access denied for table system.{} in sandbox",
+                table
+            )));
+        }
+
         self.shared.get_table(catalog, database, table).await
     }
 
+    /// Get the table from the catalog instead of the one cached by the query, for the
+    /// statements which read the table right before changing it.
+    pub async fn get_latest_table(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let tenant = self.get_tenant();
+        let table = self
+            .get_catalog(catalog)?
+            .get_table(tenant.as_str(), database, table)
+            .await?;
+        self.shared.resolve_transaction_table(catalog, table)
+    }
+
+    /// The (catalog, database, table) of the tables the query has resolved so far.
+    pub fn get_referenced_tables(&self) -> Vec<(String, String, String)> {
+        self.shared.get_referenced_tables()
+    }
+
+    /// The databases and tables of the catalog as of the first listing in this query.
+    ///
+    /// Listing a catalog (SHOW TABLES, system.tables, information_schema...) more than once in
+    /// a query returns the same result, even if other sessions create or drop tables meanwhile.
+    pub async fn get_catalog_snapshot(&self, catalog: &str) -> Result<Arc<CatalogSnapshot>> {
+        self.shared.get_catalog_snapshot(catalog).await
+    }
+
     pub fn get_id(&self) -> String {
         self.shared.init_query_id.as_ref().read().clone()
     }
@@ -351,6 +500,11 @@ impl QueryContext {
         self.shared.session.clone()
     }
 
+    // Get the transaction of the current session, if any.
+    pub fn get_transaction(&self) -> Option<Arc<SessionTransaction>> {
+        self.shared.session.get_transaction()
+    }
+
     // Get one session by session id.
     pub async fn get_session_by_id(self: &Arc<Self>, id: &str) -> Option<SessionRef> {
         self.shared
@@ -391,6 +545,10 @@ impl QueryContext {
         self.shared.get_query_str()
     }
 
+    pub fn get_created_time(&self) -> SystemTime {
+        self.shared.get_created_time()
+    }
+
     /// Get the client socket address.
     pub fn get_client_address(&self) -> Option<SocketAddr> {
         self.shared.session.session_ctx.get_client_host()
@@ -401,6 +559,42 @@ impl QueryContext {
         self.shared.session.session_mgr.get_storage_cache_manager()
     }
 
+    pub fn get_replication_manager(&self) -> Arc<ReplicationManager> {
+        self.shared.session.session_mgr.get_replication_manager()
+    }
+
+    pub fn get_corrupt_block_manager(&self) -> Arc<CorruptBlockManager> {
+        self.shared.session.session_mgr.get_corrupt_block_manager()
+    }
+
+    pub fn get_read_latency_tracker(&self) -> Arc<ReadLatencyTracker> {
+        self.shared.session.session_mgr.get_read_latency_tracker()
+    }
+
+    pub fn get_query_profile_manager(&self) -> Arc<QueryProfileManager> {
+        self.shared.session.session_mgr.get_query_profile_manager()
+    }
+
+    // Record the profile of a pipeline of the query into the query profile manager.
+    pub fn create_profile_callback(&self) -> ProfileCallback {
+        let query_id = self.get_id();
+        let manager = self.get_query_profile_manager();
+        Box::new(move |profile| manager.record(&query_id, profile))
+    }
+
+    pub fn get_query_cache(&self) -> Arc<QueryCache> {
+        self.shared.session.session_mgr.get_query_cache()
+    }
+
+    pub fn get_temp_storage_manager(&self) -> Arc<TempStorageManager> {
+        self.shared.session.session_mgr.get_temp_storage_manager()
+    }
+
+    /// The directory of the temporary files of the query, removed once the query ends.
+    pub fn get_temp_dir(&self) -> Result<Arc<QueryTempDir>> {
+        self.shared.get_temp_dir()
+    }
+
     // Get the storage data accessor operator from the session manager.
     pub fn get_storage_operator(&self) -> Result<Operator> {
         let operator = self.shared.session.get_storage_operator();
@@ -408,10 +602,65 @@ impl QueryContext {
         Ok(operator.layer(self.shared.dal_ctx.as_ref().clone()))
     }
 
+    pub fn get_raw_storage_operator(&self) -> Result<Operator> {
+        let operator = self.shared.session.session_mgr.get_raw_storage_operator();
+
+        Ok(operator.layer(self.shared.dal_ctx.as_ref().clone()))
+    }
+
+    pub fn get_storage_keyring(&self) -> Option<Arc<StorageKeyring>> {
+        self.shared.session.session_mgr.get_storage_keyring()
+    }
+
     pub fn get_dal_context(&self) -> &DalContext {
         self.shared.dal_ctx.as_ref()
     }
 
+    pub fn add_pruning_statistics(&self, table: String, statistics: PruningStatistics) {
+        self.shared
+            .pruning_statistics
+            .lock()
+            .push((table, statistics));
+    }
+
+    /// The pruning decisions of the table scans planned by the query, in planning order.
+    pub fn get_pruning_statistics(&self) -> Vec<(String, PruningStatistics)> {
+        self.shared.pruning_statistics.lock().clone()
+    }
+
+    pub fn set_affected_rows(&self, rows: u64) {
+        *self.shared.affected_rows.lock() = Some(rows);
+    }
+
+    /// The rows deleted or updated by the query, none if the query does not mutate rows in place.
+    pub fn get_affected_rows(&self) -> Option<u64> {
+        *self.shared.affected_rows.lock()
+    }
+
+    pub fn set_load_idempotency_key(
+        &self,
+        table_id: u64,
+        key: &str,
+        info: LoadInfo,
+        api: Arc<dyn LoadApi>,
+    ) {
+        *self.shared.load_idempotency.lock() = Some((table_id, key.to_string(), info, api));
+    }
+
+    /// The idempotency key of the load to the table and the api recording it, none if the
+    /// query is not such a load.
+    pub fn get_load_idempotency_key(
+        &self,
+        table_id: u64,
+    ) -> Option<(String, LoadInfo, Arc<dyn LoadApi>)> {
+        match &*self.shared.load_idempotency.lock() {
+            Some((id, key, info, api)) if *id == table_id => {
+                Some((key.clone(), info.clone(), api.clone()))
+            }
+            _ => None,
+        }
+    }
+
     pub fn get_storage_runtime(&self) -> Arc<Runtime> {
         self.shared.session.session_mgr.get_storage_runtime()
     }
@@ -443,12 +692,34 @@ impl QueryContext {
         let tz = tz.parse::<Tz>().map_err(|_| {
             ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
         })?;
-        Ok(FunctionContext { tz })
+        let strict = self.get_settings().get_sql_strict_mode()? != 0;
+        let random_seed = self.get_settings().get_random_seed()?;
+        Ok(FunctionContext {
+            tz,
+            strict,
+            random_seed,
+            block_sequence: None,
+        })
     }
 
     pub fn get_connection_id(&self) -> String {
         self.shared.get_connection_id()
     }
+
+    /// The roles granted to the current user, which are all in effect, `NONE` if there is none.
+    pub fn get_current_role(&self) -> Result<String> {
+        let mut roles = self.get_current_user()?.grants.roles();
+        if roles.is_empty() {
+            return Ok("NONE".to_string());
+        }
+        roles.sort();
+        Ok(roles.join(","))
+    }
+
+    /// The id of the previous query of the session, empty if this is the first one.
+    pub fn get_last_query_id(&self) -> String {
+        self.shared.session.get_last_query_id().unwrap_or_default()
+    }
 }
 
 impl TrySpawn for QueryContext {
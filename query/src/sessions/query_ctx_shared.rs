@@ -16,6 +16,7 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use chrono_tz::Tz;
 use common_base::base::Progress;
@@ -26,17 +27,23 @@ use common_contexts::DalContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
+use common_management::LoadApi;
+use common_meta_app::schema::TableInfo;
+use common_meta_types::LoadInfo;
 use common_meta_types::UserInfo;
 use common_planners::PlanNode;
 use futures::future::AbortHandle;
 use uuid::Uuid;
 
 use crate::catalogs::CatalogManager;
+use crate::catalogs::CatalogSnapshot;
 use crate::clusters::Cluster;
 use crate::servers::http::v1::HttpQueryHandle;
+use crate::sessions::QueryTempDir;
 use crate::sessions::Session;
 use crate::sessions::Settings;
 use crate::sql::SQLCommon;
+use crate::storages::fuse::pruning::PruningStatistics;
 use crate::storages::Table;
 use crate::users::auth::auth_mgr::AuthMgr;
 use crate::users::RoleCacheMgr;
@@ -44,6 +51,7 @@ use crate::users::UserApiProvider;
 use crate::Config;
 
 type DatabaseAndTable = (String, String, String);
+type LoadIdempotency = (u64, String, LoadInfo, Arc<dyn LoadApi>);
 
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
@@ -76,6 +84,19 @@ pub struct QueryContextShared {
     pub(in crate::sessions) dal_ctx: Arc<DalContext>,
     pub(in crate::sessions) user_manager: Arc<UserApiProvider>,
     pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
+    /// The time when the query is created, for the elapsed time of the query.
+    pub(in crate::sessions) created_time: SystemTime,
+    /// The directory of the temporary files, created on the first use.
+    pub(in crate::sessions) temp_dir: Arc<Mutex<Option<Arc<QueryTempDir>>>>,
+    /// The pruning decisions of the table scans, for `EXPLAIN (PRUNING)`.
+    pub(in crate::sessions) pruning_statistics: Arc<Mutex<Vec<(String, PruningStatistics)>>>,
+    /// The snapshots of the catalogs listed by the query, keyed by the catalog name.
+    pub(in crate::sessions) catalog_snapshots: Arc<Mutex<HashMap<String, Arc<CatalogSnapshot>>>>,
+    /// The rows deleted or updated by the query, reported as the affected rows.
+    pub(in crate::sessions) affected_rows: Arc<Mutex<Option<u64>>>,
+    /// The table id and the idempotency key of the load run by the query, the key is
+    /// recorded by the commit of the load.
+    pub(in crate::sessions) load_idempotency: Arc<Mutex<Option<LoadIdempotency>>>,
 }
 
 impl QueryContextShared {
@@ -106,9 +127,29 @@ impl QueryContextShared {
             dal_ctx: Arc::new(Default::default()),
             user_manager: user_manager.clone(),
             auth_manager: Arc::new(AuthMgr::create(conf, user_manager.clone()).await?),
+            created_time: SystemTime::now(),
+            temp_dir: Arc::new(Mutex::new(None)),
+            pruning_statistics: Arc::new(Mutex::new(Vec::new())),
+            catalog_snapshots: Arc::new(Mutex::new(HashMap::new())),
+            affected_rows: Arc::new(Mutex::new(None)),
+            load_idempotency: Arc::new(Mutex::new(None)),
         }))
     }
 
+    pub fn get_temp_dir(&self) -> Result<Arc<QueryTempDir>> {
+        let mut temp_dir = self.temp_dir.lock();
+        match &*temp_dir {
+            Some(dir) => Ok(dir.clone()),
+            None => {
+                let query_id = self.init_query_id.read().clone();
+                let manager = self.session.session_mgr.get_temp_storage_manager();
+                let dir = manager.create_query_dir(&query_id)?;
+                *temp_dir = Some(dir.clone());
+                Ok(dir)
+            }
+        }
+    }
+
     pub fn set_error(&self, err: ErrorCode) {
         let mut guard = self.error.lock();
         *guard = Some(err);
@@ -206,8 +247,18 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self.get_catalogs().get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // Once the catalog is listed by the query, resolve tables from the same snapshot,
+        // so that a table just listed is not reported as unknown, or vice versa.
+        let snapshot = self.catalog_snapshots.lock().get(catalog).cloned();
+        let cache_table = match snapshot.as_ref().and_then(|s| s.get_table(database, table)) {
+            Some(table) => table?,
+            None => {
+                let catalog = self.get_catalogs().get_catalog(catalog)?;
+                catalog.get_table(tenant.as_str(), database, table).await?
+            }
+        };
+
+        let cache_table = self.resolve_transaction_table(catalog, cache_table)?;
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -217,6 +268,51 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_referenced_tables(&self) -> Vec<DatabaseAndTable> {
+        self.tables_refs.lock().keys().cloned().collect()
+    }
+
+    /// The tables written by the transaction of the session are read as of the transaction,
+    /// the other sessions don't see its writes until it commits.
+    pub fn resolve_transaction_table(
+        &self,
+        catalog: &str,
+        table: Arc<dyn Table>,
+    ) -> Result<Arc<dyn Table>> {
+        let txn_meta = self
+            .session
+            .get_transaction()
+            .and_then(|txn| txn.get_table_meta(table.get_table_info().ident.table_id));
+        match txn_meta {
+            Some(meta) => {
+                let table_info = TableInfo {
+                    meta,
+                    ..table.get_table_info().clone()
+                };
+                let catalog = self.get_catalogs().get_catalog(catalog)?;
+                catalog.get_table_by_info(&table_info)
+            }
+            None => Ok(table),
+        }
+    }
+
+    /// Get the databases and tables of the catalog, taken on the first call in the query.
+    pub async fn get_catalog_snapshot(&self, catalog: &str) -> Result<Arc<CatalogSnapshot>> {
+        if let Some(snapshot) = self.catalog_snapshots.lock().get(catalog) {
+            return Ok(snapshot.clone());
+        }
+
+        let tenant = self.get_tenant();
+        let catalog_impl = self.get_catalogs().get_catalog(catalog)?;
+        let snapshot = CatalogSnapshot::load(catalog_impl.as_ref(), tenant.as_str()).await?;
+
+        let mut snapshots = self.catalog_snapshots.lock();
+        match snapshots.entry(catalog.to_string()) {
+            Entry::Occupied(v) => Ok(v.get().clone()),
+            Entry::Vacant(v) => Ok(v.insert(Arc::new(snapshot)).clone()),
+        }
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -254,6 +350,10 @@ impl QueryContextShared {
         running_query.as_ref().unwrap_or(&"".to_string()).clone()
     }
 
+    pub fn get_created_time(&self) -> SystemTime {
+        self.created_time
+    }
+
     pub fn attach_query_plan(&self, plan: &PlanNode) {
         let mut running_plan = self.running_plan.write();
         *running_plan = Some(plan.clone());
@@ -274,6 +374,7 @@ impl QueryContextShared {
         {
             format.record_delimiter = settings.get_record_delimiter()?;
             format.field_delimiter = settings.get_field_delimiter()?;
+            format.quote_char = settings.get_quote_char()?;
             format.empty_as_default = settings.get_empty_as_default()? > 0;
             format.skip_header = settings.get_skip_header()? > 0;
 
@@ -303,6 +404,9 @@ impl QueryContextShared {
 
 impl Session {
     pub(in crate::sessions) fn destroy_context_shared(&self) {
-        self.session_ctx.take_query_context_shared();
+        if let Some(shared) = self.session_ctx.take_query_context_shared() {
+            let query_id = shared.init_query_id.read().clone();
+            self.session_ctx.set_last_query_id(query_id);
+        }
     }
 }
@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+
+/// The result of a finished query, kept in the session to be read again by RESULT_SCAN.
+pub struct CachedQueryResult {
+    pub query_id: String,
+    pub schema: DataSchemaRef,
+    pub blocks: Vec<DataBlock>,
+    pub bytes: usize,
+}
+
+/// The results of the latest queries of a session. The oldest results are evicted
+/// once the total size exceeds the limit.
+#[derive(Default)]
+pub struct QueryResultCache {
+    results: VecDeque<Arc<CachedQueryResult>>,
+    bytes: usize,
+}
+
+impl QueryResultCache {
+    pub fn get(&self, query_id: &str) -> Option<Arc<CachedQueryResult>> {
+        self.results
+            .iter()
+            .find(|result| result.query_id == query_id)
+            .cloned()
+    }
+
+    pub fn put(&mut self, result: CachedQueryResult, max_bytes: usize) {
+        // A query keeps only its latest result.
+        if let Some(pos) = self
+            .results
+            .iter()
+            .position(|v| v.query_id == result.query_id)
+        {
+            if let Some(replaced) = self.results.remove(pos) {
+                self.bytes -= replaced.bytes;
+            }
+        }
+
+        if result.bytes > max_bytes {
+            return;
+        }
+
+        while self.bytes + result.bytes > max_bytes {
+            match self.results.pop_front() {
+                None => break,
+                Some(evicted) => self.bytes -= evicted.bytes,
+            }
+        }
+
+        self.bytes += result.bytes;
+        self.results.push_back(Arc::new(result));
+    }
+
+    pub fn clear(&mut self) {
+        self.results.clear();
+        self.bytes = 0;
+    }
+}
@@ -23,16 +23,19 @@ use common_exception::Result;
 use common_macros::MallocSizeOf;
 use common_meta_types::GrantObject;
 use common_meta_types::UserInfo;
+use common_meta_types::UserOptionFlag;
 use common_meta_types::UserPrivilegeType;
 use futures::channel::*;
 use opendal::Operator;
 
 use crate::catalogs::CatalogManager;
+use crate::sessions::CachedQueryResult;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
+use crate::sessions::SessionTransaction;
 use crate::sessions::SessionType;
 use crate::sessions::Settings;
 use crate::users::RoleCacheMgr;
@@ -64,6 +67,11 @@ impl Session {
     ) -> Result<Arc<Session>> {
         let session_ctx = Arc::new(SessionContext::try_create(conf.clone())?);
         let session_settings = Settings::try_create(&conf)?;
+        let global_settings = session_mgr
+            .get_user_api_provider()
+            .get_settings(&conf.query.tenant_id)
+            .await?;
+        session_settings.apply_global_settings(global_settings)?;
         let ref_count = Arc::new(AtomicUsize::new(0));
         let status = Arc::new(Default::default());
         Ok(Arc::new(Session {
@@ -152,6 +160,32 @@ impl Session {
         self.session_ctx.get_current_query_id()
     }
 
+    pub fn get_last_query_id(&self) -> Option<String> {
+        self.session_ctx.get_last_query_id()
+    }
+
+    // The results of the latest queries are kept in the session for RESULT_SCAN.
+    pub fn get_query_result(&self, query_id: &str) -> Option<Arc<CachedQueryResult>> {
+        self.session_ctx.get_query_result(query_id)
+    }
+
+    pub fn put_query_result(&self, result: CachedQueryResult, max_bytes: usize) {
+        self.session_ctx.put_query_result(result, max_bytes)
+    }
+
+    // The transaction started by BEGIN, if any.
+    pub fn get_transaction(&self) -> Option<Arc<SessionTransaction>> {
+        self.session_ctx.get_transaction()
+    }
+
+    pub fn set_transaction(&self, txn: Option<Arc<SessionTransaction>>) {
+        self.session_ctx.set_transaction(txn)
+    }
+
+    pub fn take_transaction(&self) -> Option<Arc<SessionTransaction>> {
+        self.session_ctx.take_transaction()
+    }
+
     pub fn attach<F>(self: &Arc<Self>, host: Option<SocketAddr>, io_shutdown: F)
     where F: FnOnce() + Send + 'static {
         let (tx, rx) = futures::channel::oneshot::channel();
@@ -193,6 +227,9 @@ impl Session {
     }
 
     pub fn set_current_user(self: &Arc<Self>, user: UserInfo) {
+        if user.has_option_flag(UserOptionFlag::Sandbox) {
+            self.session_settings.apply_sandbox_profile();
+        }
         self.session_ctx.set_current_user(user)
     }
 
@@ -23,7 +23,10 @@ use common_macros::MallocSizeOf;
 use common_meta_types::UserInfo;
 use futures::channel::oneshot::Sender;
 
+use crate::sessions::CachedQueryResult;
 use crate::sessions::QueryContextShared;
+use crate::sessions::QueryResultCache;
+use crate::sessions::SessionTransaction;
 use crate::Config;
 
 #[derive(MallocSizeOf)]
@@ -42,6 +45,11 @@ pub struct SessionContext {
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     #[ignore_malloc_size_of = "insignificant"]
     query_context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    query_result_cache: RwLock<QueryResultCache>,
+    last_query_id: RwLock<Option<String>>,
+    #[ignore_malloc_size_of = "insignificant"]
+    transaction: RwLock<Option<Arc<SessionTransaction>>>,
 }
 
 impl SessionContext {
@@ -56,6 +64,9 @@ impl SessionContext {
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
+            query_result_cache: Default::default(),
+            last_query_id: Default::default(),
+            transaction: Default::default(),
         })
     }
 
@@ -161,4 +172,41 @@ impl SessionContext {
         let mut lock = self.query_context_shared.write();
         lock.take()
     }
+
+    // Get the id of the last finished query.
+    pub fn get_last_query_id(&self) -> Option<String> {
+        let lock = self.last_query_id.read();
+        lock.clone()
+    }
+
+    pub fn set_last_query_id(&self, query_id: String) {
+        let mut lock = self.last_query_id.write();
+        *lock = Some(query_id)
+    }
+
+    pub fn get_query_result(&self, query_id: &str) -> Option<Arc<CachedQueryResult>> {
+        let lock = self.query_result_cache.read();
+        lock.get(query_id)
+    }
+
+    pub fn put_query_result(&self, result: CachedQueryResult, max_bytes: usize) {
+        let mut lock = self.query_result_cache.write();
+        lock.put(result, max_bytes)
+    }
+
+    pub fn get_transaction(&self) -> Option<Arc<SessionTransaction>> {
+        let lock = self.transaction.read();
+        lock.clone()
+    }
+
+    pub fn set_transaction(&self, txn: Option<Arc<SessionTransaction>>) {
+        let mut lock = self.transaction.write();
+        *lock = txn
+    }
+
+    //  Take the transaction, the session is not in a transaction afterwards.
+    pub fn take_transaction(&self) -> Option<Arc<SessionTransaction>> {
+        let mut lock = self.transaction.write();
+        lock.take()
+    }
 }
@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::ProgressValues;
 use common_contexts::DalMetrics;
@@ -37,6 +38,8 @@ pub struct ProcessInfo {
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
     pub mysql_connection_id: Option<u32>,
+    pub query_id: Option<String>,
+    pub elapsed: Option<Duration>,
 }
 
 impl Session {
@@ -69,6 +72,8 @@ impl Session {
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
             mysql_connection_id: self.mysql_connection_id,
+            query_id: Session::query_id(status),
+            elapsed: Session::query_elapsed(status),
         }
     }
 
@@ -113,4 +118,18 @@ impl Session {
             .as_ref()
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
+
+    fn query_id(status: &SessionContext) -> Option<String> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.init_query_id.read().clone())
+    }
+
+    fn query_elapsed(status: &SessionContext) -> Option<Duration> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .and_then(|context_shared| context_shared.get_created_time().elapsed().ok())
+    }
 }
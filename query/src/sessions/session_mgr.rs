@@ -14,12 +14,13 @@
 
 use std::collections::HashMap;
 use std::future::Future;
-use std::ops::DerefMut;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
+use common_base::base::numa_nodes;
 use common_base::base::tokio;
 use common_base::base::Runtime;
 use common_base::base::SignalStream;
@@ -28,27 +29,46 @@ use common_contexts::DalRuntime;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::init_operator;
+use common_meta_types::NodeInfo;
+use common_meta_types::NodeLoad;
 use common_metrics::label_counter;
 use common_tracing::init_query_logger;
 use common_tracing::tracing;
 use common_tracing::tracing_appender::non_blocking::WorkerGuard;
 use futures::future::Either;
 use futures::StreamExt;
+use metrics::gauge;
 use opendal::Operator;
 
 use crate::catalogs::CatalogManager;
 use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterListener;
+use crate::clusters::NodeLoadReporter;
+use crate::pipelines::new::executor::QueryProfileManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryCache;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::TempStorageManager;
 use crate::storages::cache::CacheManager;
+use crate::storages::encryption::StorageEncryption;
+use crate::storages::encryption::StorageKeyring;
+use crate::storages::fuse::corruption::CorruptBlockManager;
+use crate::storages::fuse::io::ReadLatencyTracker;
+use crate::storages::fuse::replication::ReplicationManager;
 use crate::users::RoleCacheMgr;
 use crate::users::UserApiProvider;
 use crate::Config;
 
+static METRIC_STORAGE_RUNTIME_THREADS: &str = "runtime.storage_worker_threads";
+static METRIC_QUERY_RUNTIME_THREADS: &str = "runtime.query_worker_threads";
+static METRIC_NUMA_NODES: &str = "runtime.numa_nodes";
+static CLUSTER_NODE_JOINED_COUNT: &str = "cluster.node_joined_count";
+static CLUSTER_NODE_LEFT_COUNT: &str = "cluster.node_left_count";
+
 pub struct SessionManager {
     pub(in crate::sessions) conf: RwLock<Config>,
     pub(in crate::sessions) discovery: RwLock<Arc<ClusterDiscovery>>,
@@ -58,11 +78,20 @@ pub struct SessionManager {
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     pub(in crate::sessions) storage_cache_manager: RwLock<Arc<CacheManager>>,
+    pub(in crate::sessions) replication_manager: Arc<ReplicationManager>,
+    pub(in crate::sessions) corrupt_block_manager: Arc<CorruptBlockManager>,
+    pub(in crate::sessions) read_latency_tracker: Arc<ReadLatencyTracker>,
+    pub(in crate::sessions) query_profile_manager: Arc<QueryProfileManager>,
+    pub(in crate::sessions) query_cache: Arc<QueryCache>,
+    pub(in crate::sessions) temp_storage_manager: Arc<TempStorageManager>,
     pub(in crate::sessions) query_logger:
         RwLock<Option<Arc<dyn tracing::Subscriber + Send + Sync>>>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
     storage_operator: RwLock<Operator>,
+    raw_storage_operator: RwLock<Operator>,
+    storage_keyring: RwLock<Option<Arc<StorageKeyring>>>,
     storage_runtime: Arc<Runtime>,
+    query_runtime: Arc<Runtime>,
     _guards: Vec<WorkerGuard>,
 
     user_api_provider: RwLock<Arc<UserApiProvider>>,
@@ -76,24 +105,47 @@ impl SessionManager {
     pub async fn from_conf(conf: Config) -> Result<Arc<SessionManager>> {
         let catalogs = Arc::new(CatalogManager::new(&conf).await?);
         let storage_cache_manager = Arc::new(CacheManager::init(&conf.query));
+        let replication_manager = Arc::new(ReplicationManager::init(&conf.query));
+        let corrupt_block_manager = Arc::new(CorruptBlockManager::init());
+        let read_latency_tracker = Arc::new(ReadLatencyTracker::init());
+        let query_profile_manager = Arc::new(QueryProfileManager::init());
+        let query_cache = Arc::new(QueryCache::init(&conf.query));
+        let temp_storage_manager = Arc::new(TempStorageManager::init(&conf.query)?);
 
         // Cluster discovery.
         let discovery = ClusterDiscovery::create_global(conf.clone()).await?;
 
+        // The IO and the CPU pools are sized independently, by storage.num_cpus and query.num_cpus.
         let storage_runtime = {
             let mut storage_num_cpus = conf.storage.num_cpus as usize;
             if storage_num_cpus == 0 {
                 storage_num_cpus = std::cmp::max(1, num_cpus::get() / 2)
             }
 
+            gauge!(METRIC_STORAGE_RUNTIME_THREADS, storage_num_cpus as f64);
             Runtime::with_worker_threads(storage_num_cpus, Some("IO-worker".to_owned()))?
         };
 
+        // Shared by all the interactive sessions(e.g. MySQL), instead of building a runtime per connection.
+        let query_runtime = {
+            let mut query_num_cpus = conf.query.num_cpus as usize;
+            if query_num_cpus == 0 {
+                query_num_cpus = std::cmp::max(1, num_cpus::get())
+            }
+
+            gauge!(METRIC_QUERY_RUNTIME_THREADS, query_num_cpus as f64);
+            gauge!(METRIC_NUMA_NODES, numa_nodes().len() as f64);
+            Runtime::with_worker_threads(query_num_cpus, Some("query-executor".to_owned()))?
+        };
+
         // NOTE: Magic happens here. We will add a layer upon original storage operator
         // so that all underlying storage operations will send to storage runtime.
-        let storage_operator = Self::init_storage_operator(&conf)
+        let raw_storage_operator = Self::init_storage_operator(&conf)
             .await?
             .layer(DalRuntime::new(storage_runtime.inner()));
+        let storage_keyring = StorageKeyring::try_create(&conf)?;
+        let storage_operator =
+            Self::encrypt_storage_operator(&conf, &raw_storage_operator, &storage_keyring);
 
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
         let max_sessions = conf.query.max_active_sessions as usize;
@@ -110,24 +162,39 @@ impl SessionManager {
         let user_api_provider = UserApiProvider::create_global(conf.clone()).await?;
         let role_cache_manager = Arc::new(RoleCacheMgr::new(user_api_provider.clone()));
 
-        Ok(Arc::new(SessionManager {
+        let session_manager = Arc::new(SessionManager {
             conf: RwLock::new(conf),
             catalogs: RwLock::new(catalogs),
-            discovery: RwLock::new(discovery),
+            discovery: RwLock::new(discovery.clone()),
             http_query_manager,
             max_sessions,
             active_sessions,
             storage_cache_manager: RwLock::new(storage_cache_manager),
+            replication_manager,
+            corrupt_block_manager,
+            read_latency_tracker,
+            query_profile_manager,
+            query_cache,
+            temp_storage_manager,
             query_logger: RwLock::new(query_logger),
             status,
             storage_operator: RwLock::new(storage_operator),
+            raw_storage_operator: RwLock::new(raw_storage_operator),
+            storage_keyring: RwLock::new(storage_keyring),
             storage_runtime: Arc::new(storage_runtime),
+            query_runtime: Arc::new(query_runtime),
             _guards,
             user_api_provider: RwLock::new(user_api_provider),
             role_cache_manager: RwLock::new(role_cache_manager),
             mysql_conn_map,
             mysql_basic_conn_id: AtomicU32::new(9_u32.to_le() as u32),
-        }))
+        });
+
+        let reporter: Weak<dyn NodeLoadReporter> = Arc::downgrade(&session_manager);
+        discovery.set_load_reporter(reporter);
+        let listener: Weak<dyn ClusterListener> = Arc::downgrade(&session_manager);
+        discovery.add_listener(listener);
+        Ok(session_manager)
     }
 
     pub fn get_conf(&self) -> Config {
@@ -150,14 +217,51 @@ impl SessionManager {
         self.storage_operator.read().clone()
     }
 
+    /// The storage operator without the encryption layer, the objects are read and written as they are.
+    pub fn get_raw_storage_operator(self: &Arc<Self>) -> Operator {
+        self.raw_storage_operator.read().clone()
+    }
+
+    pub fn get_storage_keyring(&self) -> Option<Arc<StorageKeyring>> {
+        self.storage_keyring.read().clone()
+    }
+
     pub fn get_storage_cache_manager(&self) -> Arc<CacheManager> {
         self.storage_cache_manager.read().clone()
     }
 
+    pub fn get_replication_manager(&self) -> Arc<ReplicationManager> {
+        self.replication_manager.clone()
+    }
+
+    pub fn get_corrupt_block_manager(&self) -> Arc<CorruptBlockManager> {
+        self.corrupt_block_manager.clone()
+    }
+
+    pub fn get_read_latency_tracker(&self) -> Arc<ReadLatencyTracker> {
+        self.read_latency_tracker.clone()
+    }
+
+    pub fn get_query_profile_manager(&self) -> Arc<QueryProfileManager> {
+        self.query_profile_manager.clone()
+    }
+
+    pub fn get_query_cache(&self) -> Arc<QueryCache> {
+        self.query_cache.clone()
+    }
+
+    pub fn get_temp_storage_manager(&self) -> Arc<TempStorageManager> {
+        self.temp_storage_manager.clone()
+    }
+
     pub fn get_storage_runtime(&self) -> Arc<Runtime> {
         self.storage_runtime.clone()
     }
 
+    pub fn get_query_runtime(&self) -> Arc<Runtime> {
+        self.query_runtime.clone()
+    }
+
     pub fn get_user_api_provider(&self) -> Arc<UserApiProvider> {
         self.user_api_provider.read().clone()
     }
@@ -183,14 +287,15 @@ impl SessionManager {
         match session_typ {
             SessionType::MySQL => {
                 let mut conn_id_session_id = self.mysql_conn_map.write();
-                mysql_conn_id = Some(self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed));
-                if conn_id_session_id.len() < self.max_sessions {
-                    conn_id_session_id.insert(mysql_conn_id, id.clone());
-                } else {
+                if conn_id_session_id.len() >= self.max_sessions {
                     return Err(ErrorCode::TooManyUserConnections(
                         "The current accept connection has exceeded max_active_sessions config",
                     ));
                 }
+
+                let conn_id = self.next_mysql_conn_id(&conn_id_session_id);
+                conn_id_session_id.insert(Some(conn_id), id.clone());
+                mysql_conn_id = Some(conn_id);
             }
             _ => {
                 tracing::debug!(
@@ -200,7 +305,13 @@ impl SessionManager {
             }
         }
         let session =
-            Session::try_create(config.clone(), id, typ, self.clone(), mysql_conn_id).await?;
+            match Session::try_create(config.clone(), id, typ, self.clone(), mysql_conn_id).await {
+                Ok(session) => session,
+                Err(cause) => {
+                    self.release_mysql_conn_id(mysql_conn_id);
+                    return Err(cause);
+                }
+            };
 
         let mut sessions = self.active_sessions.write();
         if sessions.len() < self.max_sessions {
@@ -214,12 +325,21 @@ impl SessionManager {
 
             Ok(SessionRef::create(session))
         } else {
+            drop(sessions);
+            self.release_mysql_conn_id(mysql_conn_id);
             Err(ErrorCode::TooManyUserConnections(
                 "The current accept connection has exceeded max_active_sessions config",
             ))
         }
     }
 
+    // Release the connection id allocated for a MySQL session which failed to be created.
+    fn release_mysql_conn_id(&self, mysql_conn_id: Option<u32>) {
+        if mysql_conn_id.is_some() {
+            self.mysql_conn_map.write().remove(&mysql_conn_id);
+        }
+    }
+
     pub async fn create_rpc_session(
         self: &Arc<Self>,
         id: String,
@@ -291,12 +411,25 @@ impl SessionManager {
         );
 
         let mut sessions = self.active_sessions.write();
-        sessions.remove(session_id);
-        //also need remove mysql_conn_map
+        let session = sessions.remove(session_id);
+
+        // Release the MySQL connection id so that KILL can no longer address this session.
         let mut mysql_conns_map = self.mysql_conn_map.write();
-        for (k, v) in mysql_conns_map.deref_mut().clone() {
-            if &v == session_id {
-                mysql_conns_map.remove(&k);
+        match session.and_then(|session| session.get_mysql_conn_id()) {
+            Some(conn_id) => {
+                mysql_conns_map.remove(&Some(conn_id));
+            }
+            None => mysql_conns_map.retain(|_, v| v != session_id),
+        }
+    }
+
+    // Allocate a connection id that is not used by any alive MySQL connection.
+    // Zero is skipped because clients treat it as "no connection id".
+    fn next_mysql_conn_id(&self, conn_map: &HashMap<Option<u32>, String>) -> u32 {
+        loop {
+            let conn_id = self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed);
+            if conn_id != 0 && !conn_map.contains_key(&Some(conn_id)) {
+                return conn_id;
             }
         }
     }
@@ -376,6 +509,20 @@ impl SessionManager {
         Ok(op)
     }
 
+    fn encrypt_storage_operator(
+        conf: &Config,
+        operator: &Operator,
+        keyring: &Option<Arc<StorageKeyring>>,
+    ) -> Operator {
+        match keyring {
+            None => operator.clone(),
+            Some(keyring) => operator.clone().layer(StorageEncryption::create(
+                keyring.clone(),
+                conf.query.storage_encryption_required,
+            )),
+        }
+    }
+
     pub async fn reload_config(&self) -> Result<()> {
         // TODO(xp): Potential race condition if fields are updated one by one.
         //           These fields should all be got prepared then update in one atomic update.
@@ -401,11 +548,21 @@ impl SessionManager {
             let operator = Self::init_storage_operator(&config)
                 .await?
                 .layer(DalRuntime::new(self.storage_runtime.inner()));
-            *self.storage_operator.write() = operator;
+            let keyring = StorageKeyring::try_create(&config)?;
+            *self.storage_operator.write() =
+                Self::encrypt_storage_operator(&config, &operator, &keyring);
+            *self.raw_storage_operator.write() = operator;
+            *self.storage_keyring.write() = keyring;
         }
 
         {
             let discovery = ClusterDiscovery::create_global(config.clone()).await?;
+            if let Some(reporter) = self.discovery.read().get_load_reporter() {
+                discovery.set_load_reporter(reporter);
+            }
+            for listener in self.discovery.read().get_listeners() {
+                discovery.add_listener(listener);
+            }
             *self.discovery.write() = discovery;
         }
 
@@ -424,3 +581,39 @@ impl SessionManager {
         self.query_logger.write().to_owned()
     }
 }
+
+impl NodeLoadReporter for SessionManager {
+    fn node_load(&self) -> NodeLoad {
+        let sessions = self.active_sessions.read();
+        let running_queries = sessions
+            .values()
+            .filter(|session| session.get_current_query_id().is_some())
+            .count();
+
+        NodeLoad {
+            active_sessions: sessions.len() as u64,
+            running_queries: running_queries as u64,
+            max_sessions: self.max_sessions as u64,
+        }
+    }
+}
+
+impl ClusterListener for SessionManager {
+    fn on_node_joined(&self, _node: &NodeInfo) {
+        let conf = self.conf.read();
+        label_counter(
+            CLUSTER_NODE_JOINED_COUNT,
+            &conf.query.tenant_id,
+            &conf.query.cluster_id,
+        );
+    }
+
+    fn on_node_left(&self, _node: &NodeInfo) {
+        let conf = self.conf.read();
+        label_counter(
+            CLUSTER_NODE_LEFT_COUNT,
+            &conf.query.tenant_id,
+            &conf.query.cluster_id,
+        );
+    }
+}
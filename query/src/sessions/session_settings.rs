@@ -16,19 +16,25 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_base::infallible::RwLock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::UserSetting as GlobalSetting;
+use common_meta_types::UserSettingValue;
 use itertools::Itertools;
 
+use crate::pipelines::new::executor::CpuAffinity;
 use crate::Config;
 
-#[derive(Clone)]
+// Where the current value of a setting comes from, SESSION overrides GLOBAL overrides DEFAULT.
+#[derive(Clone, PartialEq)]
 enum ScopeLevel {
-    #[allow(dead_code)]
+    Default,
     Global,
     Session,
 }
@@ -36,6 +42,9 @@ enum ScopeLevel {
 impl Debug for ScopeLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
+            ScopeLevel::Default => {
+                write!(f, "DEFAULT")
+            }
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
             }
@@ -58,8 +67,24 @@ pub struct SettingValue {
 #[derive(Clone)]
 pub struct Settings {
     settings: Arc<RwLock<HashMap<String, SettingValue>>>,
+    // Whether the sandbox profile is applied, see `apply_sandbox_profile`.
+    sandboxed: Arc<AtomicBool>,
 }
 
+// The upper bounds of the settings in the sandbox profile.
+const SANDBOX_LIMITS: [(&str, u64); 4] = [
+    ("max_threads", 2),
+    // 10 seconds.
+    ("max_execution_time", 10000),
+    ("max_result_rows", 10000),
+    // 256MB.
+    ("max_memory_usage", 256 * 1024 * 1024),
+];
+
+// The settings which are fixed in the sandbox profile.
+// The planner v2 is disabled, so that all the plans are checked by the sandbox access.
+const SANDBOX_FIXED: [(&str, u64); 1] = [("enable_planner_v2", 0)];
+
 impl Settings {
     pub fn try_create(conf: &Config) -> Result<Settings> {
         let values = vec![
@@ -67,7 +92,7 @@ impl Settings {
             SettingValue {
                 default_value: DataValue::UInt64(10000),
                 user_setting: UserSetting::create("max_block_size", DataValue::UInt64(10000)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Maximum block size for reading",
             },
 
@@ -75,7 +100,7 @@ impl Settings {
             SettingValue {
                 default_value: DataValue::UInt64(16),
                 user_setting: UserSetting::create("max_threads", DataValue::UInt64(16)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The maximum number of threads to execute the request. By default, it is determined automatically.",
             },
 
@@ -83,7 +108,7 @@ impl Settings {
             SettingValue {
                 default_value: DataValue::UInt64(60),
                 user_setting: UserSetting::create("flight_client_timeout", DataValue::UInt64(60)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds",
             },
 
@@ -91,7 +116,7 @@ impl Settings {
             SettingValue {
                 default_value: DataValue::UInt64(1024 * 1024),
                 user_setting: UserSetting::create("storage_read_buffer_size", DataValue::UInt64(1024 * 1024)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.",
             },
 
@@ -99,58 +124,328 @@ impl Settings {
             SettingValue {
                 default_value: DataValue::UInt64(1),
                 user_setting: UserSetting::create("enable_new_processor_framework", DataValue::UInt64(1)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Enable new processor framework if value != 0, default value: 1",
             },
             // enable_planner_v2
             SettingValue {
                 default_value: DataValue::UInt64(0),
                 user_setting: UserSetting::create("enable_planner_v2", DataValue::UInt64(0)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Enable planner v2 by setting this variable to 1, default value: 0",
             },
             SettingValue {
                 default_value: DataValue::String("\n".as_bytes().to_vec()),
                 user_setting: UserSetting::create("record_delimiter", DataValue::String("\n".as_bytes().to_vec())),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format record_delimiter, default value: \n",
             },
             SettingValue {
                 default_value: DataValue::String(",".as_bytes().to_vec()),
                 user_setting: UserSetting::create("field_delimiter", DataValue::String(",".as_bytes().to_vec())),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format field delimiter, default value: ,",
             },
+            SettingValue {
+                default_value: DataValue::String("\"'".as_bytes().to_vec()),
+                user_setting: UserSetting::create("quote_char", DataValue::String("\"'".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Format quote chars, default value: \"'",
+            },
             SettingValue {
                 default_value: DataValue::UInt64(1),
                 user_setting: UserSetting::create("empty_as_default", DataValue::UInt64(1)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format empty_as_default, default value: 1",
             },
             SettingValue {
                 default_value: DataValue::UInt64(0),
                 user_setting: UserSetting::create("skip_header", DataValue::UInt64(0)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Whether to skip the input header, default value: 0",
             },
             SettingValue {
                 default_value: DataValue::String("None".as_bytes().to_vec()),
                 user_setting: UserSetting::create("compression", DataValue::String("None".as_bytes().to_vec())),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Format compression, default value: None",
             },
             SettingValue {
                 default_value: DataValue::String("UTC".as_bytes().to_vec()),
                 user_setting: UserSetting::create("timezone", DataValue::String("UTC".as_bytes().to_vec())),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "Timezone, default value: UTC,",
             },
             SettingValue {
                 default_value: DataValue::UInt64(10000),
                 user_setting: UserSetting::create("group_by_two_level_threshold", DataValue::UInt64(10000)),
-                level: ScopeLevel::Session,
+                level: ScopeLevel::Default,
                 desc: "The threshold of keys to open two-level aggregation, default value: 10000",
             },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("join_sample_blocks", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Number of blocks sampled per join side to estimate join cardinality, 0 disables it",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(100),
+                user_setting: UserSetting::create("join_sample_timeout_ms", DataValue::UInt64(100)),
+                level: ScopeLevel::Default,
+                desc: "Time budget in milliseconds for sampling join cardinality, default value: 100",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(10000),
+                user_setting: UserSetting::create("mysql_flush_threshold", DataValue::UInt64(10000)),
+                level: ScopeLevel::Default,
+                desc: "Rows buffered before the results are written to MySQL clients, default value: 10000",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("group_by_shuffle_threshold", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Aggregate inputs with fewer rows than this are converged instead of shuffled, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_execution_time", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum query execution time in milliseconds, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_distinct_memory_usage", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum memory in bytes of the distinct keys before spilling to disk, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::String("none".as_bytes().to_vec()),
+                user_setting: UserSetting::create("executor_cpu_affinity", DataValue::String("none".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Bind pipeline executor threads to CPUs: none, core (a core per thread) or numa (a node per thread)",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(4194304),
+                user_setting: UserSetting::create("max_result_scan_cache_bytes", DataValue::UInt64(4194304)),
+                level: ScopeLevel::Default,
+                desc: "The maximum bytes of query results a session keeps for RESULT_SCAN, 0 means disabled",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_strict_type_coercion", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Reject lossy implicit casts of function arguments and comparisons in planner v2, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(100000),
+                user_setting: UserSetting::create("wasm_udf_fuel_per_row", DataValue::UInt64(100000)),
+                level: ScopeLevel::Default,
+                desc: "The fuel each row may consume in WASM user-defined functions, default value: 100000",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(64),
+                user_setting: UserSetting::create("wasm_udf_max_memory_mb", DataValue::UInt64(64)),
+                level: ScopeLevel::Default,
+                desc: "The max linear memory of one WASM user-defined function call in MB, default value: 64",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(65536),
+                user_setting: UserSetting::create("external_udf_batch_rows", DataValue::UInt64(65536)),
+                level: ScopeLevel::Default,
+                desc: "The max rows of one batch sent to the external UDF server, default value: 65536",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(60),
+                user_setting: UserSetting::create("external_udf_timeout_secs", DataValue::UInt64(60)),
+                level: ScopeLevel::Default,
+                desc: "The timeout in seconds of calling the external UDF server, default value: 60",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_result_rows", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum number of rows in the result of a query, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_memory_usage", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum memory usage in bytes of a query, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("enable_query_result_cache", DataValue::UInt64(1)),
+                level: ScopeLevel::Default,
+                desc: "Enable the node level cache of deterministic SELECT results, default value: 1",
+            },
+            SettingValue {
+                default_value: DataValue::String("".as_bytes().to_vec()),
+                user_setting: UserSetting::create("query_tag", DataValue::String("".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Tag of the query, e.g. the job it belongs to",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_temp_storage_bytes", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum bytes of temporary storage(e.g. spill files) a query uses, 0 means unlimited",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_cbo", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Enable the cost-based join reorder of planner v2 if value != 0, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("verify_block_checksum", DataValue::UInt64(1)),
+                level: ScopeLevel::Default,
+                desc: "Verify the checksums of the blocks on read, default value: 1.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_hedged_reads", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Issue a backup request for the block reads slower than hedged_read_percentile, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(95),
+                user_setting: UserSetting::create("hedged_read_percentile", DataValue::UInt64(95)),
+                level: ScopeLevel::Default,
+                desc: "The read latency percentile to issue a backup request after, default value: 95",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(10),
+                user_setting: UserSetting::create("hedged_read_min_delay_ms", DataValue::UInt64(10)),
+                level: ScopeLevel::Default,
+                desc: "The minimum delay in milliseconds to issue a backup request after, default value: 10",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("exchange_skew_factor", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Warn when an exchange partition receives this many times the average rows, 0 disables it.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_materialized_cte", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Materialize the CTEs of planner v2 once instead of inlining them if value != 0, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1000),
+                user_setting: UserSetting::create("max_recursion_depth", DataValue::UInt64(1000)),
+                level: ScopeLevel::Default,
+                desc: "The maximum number of iterations of a recursive CTE, default value: 1000",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("preserve_insertion_order", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Keep the input order of blocks read in parallel in the query results, 0 for disable.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(86400),
+                user_setting: UserSetting::create("load_idempotency_key_ttl", DataValue::UInt64(86400)),
+                level: ScopeLevel::Default,
+                desc: "How long the idempotency key of a load is kept in seconds, 86400 by default.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("sort_spilling_memory_ratio", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The percentage of max_memory_usage the sort buffers before spilling to disk, 0 means no spilling.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_shared_scan", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Scan a table referenced several times in a query only once, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("shared_scan_spilling_ratio", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The percentage of max_memory_usage a shared scan buffers before spilling to disk, 0: no spilling.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1000000),
+                user_setting: UserSetting::create("broadcast_join_threshold", DataValue::UInt64(1000000)),
+                level: ScopeLevel::Default,
+                desc: "Max estimated rows of the hash join build side to broadcast, bigger ones are hash repartitioned.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1),
+                user_setting: UserSetting::create("enable_runtime_filter", DataValue::UInt64(1)),
+                level: ScopeLevel::Default,
+                desc: "Enables the hash join runtime filters to skip probe side blocks and rows, default value: 1.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("sort_merge_join_threshold", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Min estimated bytes of both join sides for a sort-merge join, 0 means half of max_memory_usage.",
+            },
+            SettingValue {
+                default_value: DataValue::String("lower".as_bytes().to_vec()),
+                user_setting: UserSetting::create("column_name_case", DataValue::String("lower".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Case of the result column names: lower, preserve or alias (alias only), default value: lower.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("sql_strict_mode", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "ANSI strict mode: integer / truncates, overflows, division by zero and bad casts raise errors.",
+            },
+            SettingValue {
+                default_value: DataValue::String("".as_bytes().to_vec()),
+                user_setting: UserSetting::create("random_seed", DataValue::String("".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Seed of the random functions and join sampling, empty means unseeded, default value: empty",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_bytes_to_read", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum estimated bytes a query reads from a table, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("max_partitions_to_read", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum partitions a query reads from a table, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(100),
+                user_setting: UserSetting::create("max_partitions_per_insert", DataValue::UInt64(100)),
+                level: ScopeLevel::Default,
+                desc: "The maximum partitions an inserted block spans, 0 means no limit.",
+            },
+            SettingValue {
+                default_value: DataValue::String("none".as_bytes().to_vec()),
+                user_setting: UserSetting::create("match_by_column_name", DataValue::String("none".as_bytes().to_vec())),
+                level: ScopeLevel::Default,
+                desc: "Map the columns of the loaded files onto the table by name, default value: none",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("error_on_extra_columns", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "Whether to error on the file columns not in the table, default value: 0",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("copy_files_per_commit", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The maximum files COPY commits at once, 0 means all the files in one commit.",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("purge_retention_minutes", DataValue::UInt64(0)),
+                level: ScopeLevel::Default,
+                desc: "The minutes of the table history OPTIMIZE TABLE PURGE keeps, 0 means only the current snapshot.",
+            },
         ];
 
         let settings = Arc::new(RwLock::new(HashMap::default()));
@@ -164,7 +459,10 @@ impl Settings {
             }
         }
 
-        let ret = Settings { settings };
+        let ret = Settings {
+            settings,
+            sandboxed: Arc::new(AtomicBool::new(false)),
+        };
 
         // Overwrite settings from conf.
         {
@@ -174,7 +472,11 @@ impl Settings {
             } else {
                 conf.query.num_cpus
             };
-            ret.set_max_threads(cpus)?;
+            // It's still the DEFAULT level, so that it can be overridden by the GLOBAL setting.
+            let mut settings = ret.settings.write();
+            if let Some(setting) = settings.get_mut("max_threads") {
+                setting.user_setting.value = DataValue::UInt64(cpus);
+            }
         }
 
         Ok(ret)
@@ -226,6 +528,12 @@ impl Settings {
             .and_then(|v| v.user_setting.value.as_string())
     }
 
+    pub fn get_quote_char(&self) -> Result<Vec<u8>> {
+        let key = "quote_char";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
     pub fn get_record_delimiter(&self) -> Result<Vec<u8>> {
         let key = "record_delimiter";
         self.check_and_get_setting_value(key)
@@ -266,6 +574,277 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    // Get join sample blocks
+    pub fn get_join_sample_blocks(&self) -> Result<u64> {
+        let key = "join_sample_blocks";
+        self.try_get_u64(key)
+    }
+
+    // Get join sample timeout in milliseconds
+    pub fn get_join_sample_timeout_ms(&self) -> Result<u64> {
+        let key = "join_sample_timeout_ms";
+        self.try_get_u64(key)
+    }
+
+    // Get the number of rows buffered before writing results to MySQL clients
+    pub fn get_mysql_flush_threshold(&self) -> Result<u64> {
+        let key = "mysql_flush_threshold";
+        self.try_get_u64(key)
+    }
+
+    // Get group by shuffle threshold
+    pub fn get_group_by_shuffle_threshold(&self) -> Result<u64> {
+        let key = "group_by_shuffle_threshold";
+        self.try_get_u64(key)
+    }
+
+    // Get max_execution_time in milliseconds.
+    pub fn get_max_execution_time(&self) -> Result<u64> {
+        let key = "max_execution_time";
+        self.try_get_u64(key)
+    }
+
+    // Get max_distinct_memory_usage in bytes.
+    pub fn get_max_distinct_memory_usage(&self) -> Result<u64> {
+        let key = "max_distinct_memory_usage";
+        self.try_get_u64(key)
+    }
+
+    // Get executor_cpu_affinity.
+    pub fn get_executor_cpu_affinity(&self) -> Result<Vec<u8>> {
+        let key = "executor_cpu_affinity";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // Get max_result_scan_cache_bytes.
+    pub fn get_max_result_scan_cache_bytes(&self) -> Result<u64> {
+        let key = "max_result_scan_cache_bytes";
+        self.try_get_u64(key)
+    }
+
+    // Get strict type coercion.
+    pub fn get_enable_strict_type_coercion(&self) -> Result<u64> {
+        let key = "enable_strict_type_coercion";
+        self.try_get_u64(key)
+    }
+
+    // Get WASM UDF fuel per row.
+    pub fn get_wasm_udf_fuel_per_row(&self) -> Result<u64> {
+        let key = "wasm_udf_fuel_per_row";
+        self.try_get_u64(key)
+    }
+
+    // Get WASM UDF max memory in MB.
+    pub fn get_wasm_udf_max_memory_mb(&self) -> Result<u64> {
+        let key = "wasm_udf_max_memory_mb";
+        self.try_get_u64(key)
+    }
+
+    // Get external UDF batch rows.
+    pub fn get_external_udf_batch_rows(&self) -> Result<u64> {
+        let key = "external_udf_batch_rows";
+        self.try_get_u64(key)
+    }
+
+    // Get external UDF timeout in seconds.
+    pub fn get_external_udf_timeout_secs(&self) -> Result<u64> {
+        let key = "external_udf_timeout_secs";
+        self.try_get_u64(key)
+    }
+
+    // Get max_result_rows, 0 means no limit.
+    pub fn get_max_result_rows(&self) -> Result<u64> {
+        let key = "max_result_rows";
+        self.try_get_u64(key)
+    }
+
+    // Get max_memory_usage in bytes, 0 means no limit.
+    pub fn get_max_memory_usage(&self) -> Result<u64> {
+        let key = "max_memory_usage";
+        self.try_get_u64(key)
+    }
+
+    // Get enable_query_result_cache.
+    pub fn get_enable_query_result_cache(&self) -> Result<u64> {
+        let key = "enable_query_result_cache";
+        self.try_get_u64(key)
+    }
+
+    // Get query_tag.
+    pub fn get_query_tag(&self) -> Result<Vec<u8>> {
+        let key = "query_tag";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // Get max_temp_storage_bytes.
+    pub fn get_max_temp_storage_bytes(&self) -> Result<u64> {
+        let key = "max_temp_storage_bytes";
+        self.try_get_u64(key)
+    }
+
+    // Get enable cost-based optimization
+    pub fn get_enable_cbo(&self) -> Result<u64> {
+        let key = "enable_cbo";
+        self.try_get_u64(key)
+    }
+
+    // Get verify_block_checksum.
+    pub fn get_verify_block_checksum(&self) -> Result<u64> {
+        let key = "verify_block_checksum";
+        self.try_get_u64(key)
+    }
+
+    // Get enable_hedged_reads.
+    pub fn get_enable_hedged_reads(&self) -> Result<u64> {
+        let key = "enable_hedged_reads";
+        self.try_get_u64(key)
+    }
+
+    // Get hedged_read_percentile.
+    pub fn get_hedged_read_percentile(&self) -> Result<u64> {
+        let key = "hedged_read_percentile";
+        self.try_get_u64(key)
+    }
+
+    // Get hedged_read_min_delay_ms.
+    pub fn get_hedged_read_min_delay_ms(&self) -> Result<u64> {
+        let key = "hedged_read_min_delay_ms";
+        self.try_get_u64(key)
+    }
+
+    // Get exchange_skew_factor.
+    pub fn get_exchange_skew_factor(&self) -> Result<u64> {
+        let key = "exchange_skew_factor";
+        self.try_get_u64(key)
+    }
+
+    // Get enable materialized CTE
+    pub fn get_enable_materialized_cte(&self) -> Result<u64> {
+        let key = "enable_materialized_cte";
+        self.try_get_u64(key)
+    }
+
+    // Get max recursion depth of recursive CTE
+    pub fn get_max_recursion_depth(&self) -> Result<u64> {
+        let key = "max_recursion_depth";
+        self.try_get_u64(key)
+    }
+
+    // Get preserve insertion order.
+    pub fn get_preserve_insertion_order(&self) -> Result<u64> {
+        let key = "preserve_insertion_order";
+        self.try_get_u64(key)
+    }
+
+    // Get the ttl of load idempotency keys in seconds.
+    pub fn get_load_idempotency_key_ttl(&self) -> Result<u64> {
+        let key = "load_idempotency_key_ttl";
+        self.try_get_u64(key)
+    }
+
+    // Get sort_spilling_memory_ratio, the percentage of max_memory_usage.
+    pub fn get_sort_spilling_memory_ratio(&self) -> Result<u64> {
+        let key = "sort_spilling_memory_ratio";
+        self.try_get_u64(key)
+    }
+
+    // Get enable_shared_scan.
+    pub fn get_enable_shared_scan(&self) -> Result<u64> {
+        let key = "enable_shared_scan";
+        self.try_get_u64(key)
+    }
+
+    // Get shared_scan_spilling_ratio, the percentage of max_memory_usage.
+    pub fn get_shared_scan_spilling_ratio(&self) -> Result<u64> {
+        let key = "shared_scan_spilling_ratio";
+        self.try_get_u64(key)
+    }
+
+    // Get broadcast_join_threshold.
+    pub fn get_broadcast_join_threshold(&self) -> Result<u64> {
+        let key = "broadcast_join_threshold";
+        self.try_get_u64(key)
+    }
+
+    // Whether the hash joins build runtime filters for the probe side scans.
+    pub fn get_enable_runtime_filter(&self) -> Result<u64> {
+        let key = "enable_runtime_filter";
+        self.try_get_u64(key)
+    }
+
+    // Get sort_merge_join_threshold, the estimated bytes of a join side.
+    pub fn get_sort_merge_join_threshold(&self) -> Result<u64> {
+        let key = "sort_merge_join_threshold";
+        self.try_get_u64(key)
+    }
+
+    // The case of the column names of the result set, see ColumnNameCase.
+    pub fn get_column_name_case(&self) -> Result<Vec<u8>> {
+        let key = "column_name_case";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // Whether the arithmetic and the casts follow the ANSI strict semantics, see FunctionContext::strict.
+    pub fn get_sql_strict_mode(&self) -> Result<u64> {
+        let key = "sql_strict_mode";
+        self.try_get_u64(key)
+    }
+
+    // Get the seed of the random functions, None if it's unseeded.
+    pub fn get_random_seed(&self) -> Result<Option<u64>> {
+        let key = "random_seed";
+        let seed = self
+            .check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())?;
+        Self::parse_random_seed(&String::from_utf8_lossy(&seed))
+    }
+
+    // Get max_bytes_to_read, 0 means no limit.
+    pub fn get_max_bytes_to_read(&self) -> Result<u64> {
+        let key = "max_bytes_to_read";
+        self.try_get_u64(key)
+    }
+
+    // Get max_partitions_to_read, 0 means no limit.
+    pub fn get_max_partitions_to_read(&self) -> Result<u64> {
+        let key = "max_partitions_to_read";
+        self.try_get_u64(key)
+    }
+
+    // Get max_partitions_per_insert, 0 means no limit.
+    pub fn get_max_partitions_per_insert(&self) -> Result<u64> {
+        let key = "max_partitions_per_insert";
+        self.try_get_u64(key)
+    }
+
+    // Get copy_files_per_commit, 0 means all the files in one commit.
+    pub fn get_copy_files_per_commit(&self) -> Result<u64> {
+        let key = "copy_files_per_commit";
+        self.try_get_u64(key)
+    }
+
+    // Get purge_retention_minutes, 0 means only the current snapshot is kept.
+    pub fn get_purge_retention_minutes(&self) -> Result<u64> {
+        let key = "purge_retention_minutes";
+        self.try_get_u64(key)
+    }
+
+    // Get the column name matching mode of streaming load
+    pub fn get_match_by_column_name(&self) -> Result<Vec<u8>> {
+        let key = "match_by_column_name";
+        self.check_and_get_setting_value(key)
+            .and_then(|v| v.user_setting.value.as_string())
+    }
+
+    // Whether the columns of a file not in the table are an error when matching by name
+    pub fn get_error_on_extra_columns(&self) -> Result<u64> {
+        let key = "error_on_extra_columns";
+        self.try_get_u64(key)
+    }
+
     pub fn has_setting(&self, key: &str) -> bool {
         let settings = self.settings.read();
         settings.get(key).is_some()
@@ -285,27 +864,140 @@ impl Settings {
         setting.user_setting.value.as_u64()
     }
 
-    // Set u64 value to settings map, if is_global will write to metasrv.
-    fn try_set_u64(&self, key: &str, val: u64, _is_global: bool) -> Result<()> {
+    // Set u64 value to settings map, the GLOBAL value is written to metasrv by the caller.
+    fn try_set_u64(&self, key: &str, val: u64, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::UInt64(val), is_global)
+    }
+
+    fn try_set_string(&self, key: &str, val: Vec<u8>, is_global: bool) -> Result<()> {
+        self.try_set_value(key, DataValue::String(val), is_global)
+    }
+
+    fn try_set_value(&self, key: &str, val: DataValue, is_global: bool) -> Result<()> {
         let mut settings = self.settings.write();
-        let mut setting = settings
+        let setting = settings
             .get_mut(key)
             .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-        setting.user_setting.value = DataValue::UInt64(val);
 
+        if !is_global {
+            setting.user_setting.value = val;
+            setting.level = ScopeLevel::Session;
+        } else if setting.level != ScopeLevel::Session {
+            // The value set in this session takes precedence over the GLOBAL one.
+            setting.user_setting.value = val;
+            setting.level = ScopeLevel::Global;
+        }
+
+        Ok(())
+    }
+
+    // Apply the GLOBAL settings of the tenant, the unknown ones are ignored as they may be
+    // removed in this version.
+    pub fn apply_global_settings(&self, global_settings: Vec<GlobalSetting>) -> Result<()> {
+        for global_setting in global_settings {
+            if !self.has_setting(&global_setting.name) {
+                continue;
+            }
+
+            match global_setting.value {
+                UserSettingValue::UInt64(v) => self.try_set_u64(&global_setting.name, v, true)?,
+                UserSettingValue::String(v) => {
+                    self.try_set_string(&global_setting.name, v, true)?
+                }
+            }
+        }
         Ok(())
     }
 
-    fn try_set_string(&self, key: &str, val: Vec<u8>, _is_global: bool) -> Result<()> {
+    // Parse the value by the type of the setting, returns the GLOBAL setting to write to metasrv.
+    pub fn get_global_setting(&self, key: &str, val: &str) -> Result<GlobalSetting> {
+        let setting = self.check_and_get_setting_value(key)?;
+        Self::check_setting_value(key, val)?;
+        let value = match setting.user_setting.value.max_data_type().data_type_id() {
+            TypeID::UInt64 => UserSettingValue::UInt64(val.parse::<u64>()?),
+            TypeID::String => UserSettingValue::String(val.as_bytes().to_vec()),
+            v => {
+                return Err(ErrorCode::UnknownVariable(format!(
+                    "Unsupported variable:{:?} type:{:?} when get_global_setting().",
+                    key, v
+                )));
+            }
+        };
+        Ok(GlobalSetting::create(key, value))
+    }
+
+    // Tighten the limits of the session for the untrusted user, they can't be raised afterwards.
+    pub fn apply_sandbox_profile(&self) {
         let mut settings = self.settings.write();
-        let mut setting = settings
-            .get_mut(key)
-            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
-        setting.user_setting.value = DataValue::String(val);
+        for (key, limit) in SANDBOX_LIMITS {
+            if let Some(setting) = settings.get_mut(key) {
+                let value = match setting.user_setting.value.as_u64() {
+                    Ok(0) | Err(_) => limit,
+                    Ok(v) => v.min(limit),
+                };
+                setting.user_setting.value = DataValue::UInt64(value);
+                setting.level = ScopeLevel::Session;
+            }
+        }
+        for (key, value) in SANDBOX_FIXED {
+            if let Some(setting) = settings.get_mut(key) {
+                setting.user_setting.value = DataValue::UInt64(value);
+                setting.level = ScopeLevel::Session;
+            }
+        }
+        self.sandboxed.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_sandboxed(&self) -> bool {
+        self.sandboxed.load(Ordering::Relaxed)
+    }
+
+    fn check_sandbox_setting(&self, key: &str, val: &str) -> Result<()> {
+        if !self.is_sandboxed() {
+            return Ok(());
+        }
+
+        if SANDBOX_FIXED.iter().any(|(k, _)| *k == key) {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Variable {} can't be changed in sandbox",
+                key
+            )));
+        }
 
+        if let Some((_, limit)) = SANDBOX_LIMITS.iter().find(|(k, _)| *k == key) {
+            let v = val.parse::<u64>()?;
+            if v == 0 || v > *limit {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Variable {} must be in [1, {}] in sandbox",
+                    key, limit
+                )));
+            }
+        }
         Ok(())
     }
 
+    // The settings of a few given values are checked when they are set, instead of failing
+    // the queries using them later.
+    fn check_setting_value(key: &str, val: &str) -> Result<()> {
+        match key {
+            "executor_cpu_affinity" => val.parse::<CpuAffinity>().map(|_| ()),
+            "random_seed" => Self::parse_random_seed(val).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_random_seed(val: &str) -> Result<Option<u64>> {
+        match val.trim() {
+            "" => Ok(None),
+            seed => seed.parse::<u64>().map(Some).map_err(|_| {
+                ErrorCode::BadArguments(format!(
+                    "random_seed must be an unsigned integer or empty, but got {}",
+                    val
+                ))
+            }),
+        }
+    }
+
     pub fn get_setting_values(&self) -> Vec<DataValue> {
         let settings = self.settings.read();
 
@@ -340,6 +1032,8 @@ impl Settings {
 
     pub fn set_settings(&self, key: String, val: String, is_global: bool) -> Result<()> {
         let setting = self.check_and_get_setting_value(&key)?;
+        self.check_sandbox_setting(&key, &val)?;
+        Self::check_setting_value(&key, &val)?;
 
         match setting.user_setting.value.max_data_type().data_type_id() {
             TypeID::UInt64 => {
@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use common_base::infallible::RwLock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::CopiedFileInfo;
+
+/// The writes of the transaction started by `BEGIN` in a session.
+///
+/// The new metas of the tables written are kept here instead of the meta service, the
+/// statements of the transaction read the tables as of these metas, and `COMMIT` updates
+/// them at once. A table keeps the version it had when the transaction first wrote it, so
+/// that the commit fails if the table was changed by another commit since.
+#[derive(Default)]
+pub struct SessionTransaction {
+    // The tables of one catalog are committed atomically by the meta service, the
+    // transaction is not allowed to write the tables of another catalog.
+    catalog: RwLock<Option<String>>,
+    // table id -> the update of the table meta to commit
+    updates: RwLock<BTreeMap<u64, UpdateTableMetaReq>>,
+    // table id -> the files copied into the table, recorded once committed
+    copied_files: RwLock<BTreeMap<u64, BTreeMap<String, CopiedFileInfo>>>,
+}
+
+impl SessionTransaction {
+    pub fn update_table_meta(&self, catalog_name: &str, req: UpdateTableMetaReq) -> Result<()> {
+        {
+            let mut catalog = self.catalog.write();
+            match catalog.as_ref() {
+                Some(name) if name != catalog_name => {
+                    return Err(ErrorCode::InvalidTransactionState(format!(
+                        "the transaction has written the tables of catalog {}, can not write the tables of catalog {}",
+                        name, catalog_name
+                    )));
+                }
+                Some(_) => {}
+                None => *catalog = Some(catalog_name.to_string()),
+            }
+        }
+
+        let mut updates = self.updates.write();
+        match updates.entry(req.table_id) {
+            Entry::Occupied(mut v) => v.get_mut().new_table_meta = req.new_table_meta,
+            Entry::Vacant(v) => {
+                v.insert(req);
+            }
+        }
+        Ok(())
+    }
+
+    /// The meta of the table written by the transaction, not committed yet.
+    pub fn get_table_meta(&self, table_id: u64) -> Option<TableMeta> {
+        let updates = self.updates.read();
+        updates.get(&table_id).map(|v| v.new_table_meta.clone())
+    }
+
+    pub fn add_copied_files(&self, table_id: u64, files: BTreeMap<String, CopiedFileInfo>) {
+        let mut copied_files = self.copied_files.write();
+        copied_files.entry(table_id).or_default().extend(files);
+    }
+
+    pub fn take_copied_files(&self) -> BTreeMap<u64, BTreeMap<String, CopiedFileInfo>> {
+        std::mem::take(&mut *self.copied_files.write())
+    }
+
+    /// The catalog and the table meta updates to commit, None if nothing was written.
+    pub fn take_updates(&self) -> Option<(String, Vec<UpdateTableMetaReq>)> {
+        let catalog = self.catalog.write().take()?;
+        let updates = std::mem::take(&mut *self.updates.write());
+        Some((catalog, updates.into_values().collect()))
+    }
+}
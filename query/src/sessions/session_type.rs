@@ -23,6 +23,7 @@ pub enum SessionType {
     ClickHouseHttpHandler,
     FlightRPC,
     HTTPAPI(String),
+    Background(String),
     Dummy,
     Fuzz,
 }
@@ -31,7 +32,10 @@ impl SessionType {
     pub fn is_user_session(&self) -> bool {
         !matches!(
             self,
-            SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
+            SessionType::HTTPAPI(_)
+                | SessionType::Background(_)
+                | SessionType::Dummy
+                | SessionType::Fuzz
         )
     }
 }
@@ -47,6 +51,7 @@ impl fmt::Display for SessionType {
             SessionType::Dummy => "Dummy".to_string(),
             SessionType::FlightRPC => "FlightRPC".to_string(),
             SessionType::HTTPAPI(usage) => format!("HTTPAPI({})", usage),
+            SessionType::Background(usage) => format!("Background({})", usage),
             SessionType::Fuzz => "Fuzz".to_string(),
         };
         write!(f, "{}", name)
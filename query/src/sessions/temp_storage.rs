@@ -0,0 +1,297 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_contexts::DalMetrics;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use metrics::counter;
+use metrics::gauge;
+
+use crate::config::QueryConfig;
+use crate::sessions::metrics::METRIC_TEMP_STORAGE_ORPHAN_BYTES;
+use crate::sessions::metrics::METRIC_TEMP_STORAGE_USED_BYTES;
+use crate::sessions::QueryContext;
+
+/// The temporary storage used by a running query, e.g. the spill files.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TempStorageUsage {
+    pub user: String,
+    pub session_id: String,
+    pub query_id: String,
+    pub used_bytes: u64,
+}
+
+/// Manages the local temporary storage of the node, e.g. the spill files of the sorts, the
+/// aggregations and the spooled blocks of the CTEs.
+///
+/// The files of a query are written to its own directory `<temp_storage_root>/<query id>`,
+/// which is removed once the query ends. The directories left by a crashed node are removed
+/// when the node starts, so the root must not be shared by the nodes.
+///
+/// The space is tracked against the quota of the node, bounded by `temp_storage_quota_mb`,
+/// the quota of each user, bounded by `temp_storage_quota_mb_per_user`, and the limit of
+/// each query, bounded by the setting `max_temp_storage_bytes`. Only the query asking for
+/// more space fails, the space used by the others is left as it is.
+pub struct TempStorageManager {
+    root: PathBuf,
+    quota: u64,
+    quota_per_user: u64,
+    // The usages of the running queries, by query id.
+    usages: Mutex<HashMap<String, TempStorageUsage>>,
+}
+
+impl TempStorageManager {
+    pub fn init(config: &QueryConfig) -> Result<TempStorageManager> {
+        let root = PathBuf::from(&config.temp_storage_root);
+        Self::remove_orphans(&root)?;
+        std::fs::create_dir_all(&root)?;
+
+        gauge!(METRIC_TEMP_STORAGE_USED_BYTES, 0.0);
+        Ok(TempStorageManager {
+            root,
+            quota: config.temp_storage_quota_mb * 1024 * 1024,
+            quota_per_user: config.temp_storage_quota_mb_per_user * 1024 * 1024,
+            usages: Mutex::new(HashMap::new()),
+        })
+    }
+
+    // No query is running while the node starts, everything under the root is left by the
+    // queries before a crash.
+    fn remove_orphans(root: &Path) -> Result<()> {
+        let entries = match std::fs::read_dir(root) {
+            Ok(entries) => entries,
+            Err(cause) if cause.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(cause) => return Err(cause.into()),
+        };
+
+        let mut removed_bytes = 0;
+        for entry in entries {
+            let path = entry?.path();
+            removed_bytes += Self::disk_usage(&path);
+            let res = match path.is_dir() {
+                true => std::fs::remove_dir_all(&path),
+                false => std::fs::remove_file(&path),
+            };
+            if let Err(cause) = res {
+                tracing::warn!("Cannot remove orphan temporary file {:?}, {}", path, cause);
+            }
+        }
+
+        if removed_bytes != 0 {
+            tracing::info!(
+                "Removed {} bytes of orphan temporary files under {:?}",
+                removed_bytes,
+                root
+            );
+            counter!(METRIC_TEMP_STORAGE_ORPHAN_BYTES, removed_bytes);
+        }
+        Ok(())
+    }
+
+    fn disk_usage(path: &Path) -> u64 {
+        match std::fs::read_dir(path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| Self::disk_usage(&entry.path()))
+                .sum(),
+            Err(_) => std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn quota(&self) -> u64 {
+        self.quota
+    }
+
+    pub fn quota_per_user(&self) -> u64 {
+        self.quota_per_user
+    }
+
+    pub fn used_bytes(&self) -> u64 {
+        self.usages
+            .lock()
+            .values()
+            .map(|usage| usage.used_bytes)
+            .sum()
+    }
+
+    /// Creates the directory of the temporary files of a query, removed when it is dropped.
+    pub fn create_query_dir(&self, query_id: &str) -> Result<Arc<QueryTempDir>> {
+        let path = self.root.join(query_id);
+        std::fs::create_dir_all(&path)?;
+        Ok(Arc::new(QueryTempDir { path }))
+    }
+
+    pub fn usages(&self) -> Vec<TempStorageUsage> {
+        self.usages.lock().values().cloned().collect()
+    }
+
+    pub fn user_used_bytes(&self, user: &str) -> u64 {
+        Self::used_bytes_of(&self.usages.lock(), user)
+    }
+
+    fn used_bytes_of(usages: &HashMap<String, TempStorageUsage>, user: &str) -> u64 {
+        usages
+            .values()
+            .filter(|usage| usage.user == user)
+            .map(|usage| usage.used_bytes)
+            .sum()
+    }
+
+    fn reserve(&self, query: &TempStorageUsage, bytes: u64, max_query_bytes: u64) -> Result<()> {
+        let mut usages = self.usages.lock();
+
+        let query_used_bytes = usages
+            .get(&query.query_id)
+            .map(|usage| usage.used_bytes)
+            .unwrap_or(0);
+        if max_query_bytes != 0 && query_used_bytes + bytes > max_query_bytes {
+            return Err(ErrorCode::QueryLimitExceeded(format!(
+                "Temporary storage of the query exceeds max_temp_storage_bytes: {} bytes",
+                max_query_bytes
+            )));
+        }
+
+        let used_bytes = usages.values().map(|usage| usage.used_bytes).sum::<u64>();
+        if self.quota != 0 && used_bytes + bytes > self.quota {
+            return Err(ErrorCode::QueryLimitExceeded(format!(
+                "Temporary storage of the node exceeds the quota: {} bytes, used: {} bytes",
+                self.quota, used_bytes
+            )));
+        }
+
+        let user_used_bytes = Self::used_bytes_of(&usages, &query.user);
+        if self.quota_per_user != 0 && user_used_bytes + bytes > self.quota_per_user {
+            return Err(ErrorCode::QueryLimitExceeded(format!(
+                "Temporary storage of user {} exceeds the quota: {} bytes, used: {} bytes",
+                query.user, self.quota_per_user, user_used_bytes
+            )));
+        }
+
+        usages
+            .entry(query.query_id.clone())
+            .or_insert_with(|| query.clone())
+            .used_bytes += bytes;
+        gauge!(METRIC_TEMP_STORAGE_USED_BYTES, (used_bytes + bytes) as f64);
+        Ok(())
+    }
+
+    fn release(&self, query_id: &str, bytes: u64) {
+        let mut usages = self.usages.lock();
+        if let Some(usage) = usages.get_mut(query_id) {
+            usage.used_bytes = usage.used_bytes.saturating_sub(bytes);
+            if usage.used_bytes == 0 {
+                usages.remove(query_id);
+            }
+        }
+
+        let used_bytes = usages.values().map(|usage| usage.used_bytes).sum::<u64>();
+        gauge!(METRIC_TEMP_STORAGE_USED_BYTES, used_bytes as f64);
+    }
+}
+
+/// The directory of the temporary files of a query, shared by the operators of the query.
+pub struct QueryTempDir {
+    path: PathBuf,
+}
+
+impl QueryTempDir {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for QueryTempDir {
+    fn drop(&mut self) {
+        if let Err(cause) = std::fs::remove_dir_all(&self.path) {
+            if cause.kind() != ErrorKind::NotFound {
+                tracing::warn!(
+                    "Cannot remove the temporary directory {:?}, {}",
+                    self.path,
+                    cause
+                );
+            }
+        }
+    }
+}
+
+/// The temporary storage reserved by an operator of a query, released when it is dropped.
+pub struct TempStorageReservation {
+    manager: Arc<TempStorageManager>,
+    dir: Arc<QueryTempDir>,
+    query: TempStorageUsage,
+    max_query_bytes: u64,
+    reserved_bytes: u64,
+    metrics: DalMetrics,
+}
+
+impl TempStorageReservation {
+    pub fn create(ctx: &QueryContext) -> Result<TempStorageReservation> {
+        Ok(TempStorageReservation {
+            manager: ctx.get_temp_storage_manager(),
+            dir: ctx.get_temp_dir()?,
+            query: TempStorageUsage {
+                user: ctx.get_current_user()?.name,
+                session_id: ctx.get_current_session().get_id(),
+                query_id: ctx.get_id(),
+                used_bytes: 0,
+            },
+            max_query_bytes: ctx.get_settings().get_max_temp_storage_bytes()?,
+            reserved_bytes: 0,
+            metrics: ctx.get_dal_metrics(),
+        })
+    }
+
+    /// Reserves the space before writing `bytes` to the temporary storage, fails if the
+    /// query limit or the user quota would be exceeded.
+    pub fn reserve(&mut self, bytes: u64) -> Result<()> {
+        self.manager
+            .reserve(&self.query, bytes, self.max_query_bytes)?;
+        self.reserved_bytes += bytes;
+        self.metrics.inc_spill_bytes(bytes as usize);
+        Ok(())
+    }
+
+    pub fn release(&mut self, bytes: u64) {
+        let bytes = bytes.min(self.reserved_bytes);
+        self.manager.release(&self.query.query_id, bytes);
+        self.reserved_bytes -= bytes;
+    }
+
+    pub fn reserved_bytes(&self) -> u64 {
+        self.reserved_bytes
+    }
+
+    /// The path of a temporary file in the directory of the query.
+    pub fn file_path(&self, name: &str) -> PathBuf {
+        self.dir.path().join(name)
+    }
+}
+
+impl Drop for TempStorageReservation {
+    fn drop(&mut self) {
+        self.release(self.reserved_bytes);
+    }
+}
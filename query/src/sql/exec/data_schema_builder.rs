@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::wrap_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
@@ -20,8 +21,10 @@ use common_planners::Expression;
 
 use crate::sql::exec::util::format_field_name;
 use crate::sql::plans::EvalScalar;
+use crate::sql::plans::JoinType;
 use crate::sql::plans::PhysicalScan;
 use crate::sql::plans::Project;
+use crate::sql::plans::WindowPlan;
 use crate::sql::IndexType;
 use crate::sql::MetadataRef;
 
@@ -62,6 +65,22 @@ impl DataSchemaBuilder {
         Ok(DataSchemaRefExt::create(fields))
     }
 
+    pub fn build_window(
+        &self,
+        plan: &WindowPlan,
+        input_schema: DataSchemaRef,
+    ) -> Result<DataSchemaRef> {
+        let mut fields = input_schema.fields().clone();
+        let column_entry = self.metadata.read().column(plan.index).clone();
+        let field_name = format_field_name(column_entry.name.as_str(), plan.index);
+        fields.push(DataField::new(
+            field_name.as_str(),
+            column_entry.data_type.clone(),
+        ));
+
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
     pub fn build_physical_scan(&self, plan: &PhysicalScan) -> Result<DataSchemaRef> {
         let mut fields: Vec<DataField> = vec![];
         for index in plan.columns.iter() {
@@ -99,6 +118,38 @@ impl DataSchemaBuilder {
         DataSchemaRefExt::create(fields)
     }
 
+    pub fn build_hash_join(
+        &self,
+        probe: DataSchemaRef,
+        build: DataSchemaRef,
+        join_type: &JoinType,
+    ) -> DataSchemaRef {
+        match join_type {
+            // Semi join and anti join only output the columns of probe side
+            JoinType::SemiJoin | JoinType::AntiJoin => probe,
+            // Columns of build side can be NULL if there is no matched row, and so do the
+            // columns of probe side for right join and full join
+            JoinType::LeftJoin | JoinType::RightJoin | JoinType::FullJoin => {
+                let nullable_probe = matches!(join_type, JoinType::RightJoin | JoinType::FullJoin);
+                let nullable_build = matches!(join_type, JoinType::LeftJoin | JoinType::FullJoin);
+                let nullable_field = |field: &DataField, nullable: bool| match nullable {
+                    true => DataField::new(field.name(), wrap_nullable(field.data_type())),
+                    false => field.clone(),
+                };
+
+                let mut fields = Vec::with_capacity(probe.num_fields() + build.num_fields());
+                for field in probe.fields().iter() {
+                    fields.push(nullable_field(field, nullable_probe));
+                }
+                for field in build.fields().iter() {
+                    fields.push(nullable_field(field, nullable_build));
+                }
+                DataSchemaRefExt::create(fields)
+            }
+            _ => self.build_join(probe, build),
+        }
+    }
+
     pub fn build_sort(
         &self,
         input_schema: &DataSchemaRef,
@@ -67,6 +67,9 @@ impl ExpressionBuilder {
                 args,
                 ..
             }) => self.build_aggr_function(func_name.clone(), *distinct, params.clone(), args),
+            Scalar::WindowFunction(_) => Err(ErrorCode::LogicalError(
+                "Window function should be evaluated by the window plan",
+            )),
             Scalar::AndExpr(AndExpr { left, right }) => {
                 let left = self.build(&**left)?;
                 let right = self.build(&**right)?;
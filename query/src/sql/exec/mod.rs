@@ -16,11 +16,14 @@ mod data_schema_builder;
 mod expression_builder;
 mod util;
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datablocks::HashMethodKind;
 use common_datablocks::HashMethodSerializer;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::remove_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
@@ -42,10 +45,12 @@ use super::plans::RelOperator;
 use super::MetadataRef;
 use crate::common::HashMap;
 use crate::pipelines::new::processors::port::InputPort;
+use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::transforms::hash_join::row::RowPtr;
 use crate::pipelines::new::processors::AggregatorParams;
 use crate::pipelines::new::processors::AggregatorTransformParams;
 use crate::pipelines::new::processors::ChainingHashTable;
+use crate::pipelines::new::processors::CteSource;
 use crate::pipelines::new::processors::ExpressionTransform;
 use crate::pipelines::new::processors::HashJoinState;
 use crate::pipelines::new::processors::HashTable;
@@ -56,39 +61,64 @@ use crate::pipelines::new::processors::KeyU32HashTable;
 use crate::pipelines::new::processors::KeyU512HashTable;
 use crate::pipelines::new::processors::KeyU64HashTable;
 use crate::pipelines::new::processors::KeyU8HashTable;
+use crate::pipelines::new::processors::MaterializedCte;
 use crate::pipelines::new::processors::ProjectionTransform;
+use crate::pipelines::new::processors::RuntimeFilter;
+use crate::pipelines::new::processors::ScanRuntimeFilter;
 use crate::pipelines::new::processors::SerializerHashTable;
 use crate::pipelines::new::processors::SinkBuildHashTable;
+use crate::pipelines::new::processors::SinkMergeJoinRight;
 use crate::pipelines::new::processors::Sinker;
 use crate::pipelines::new::processors::SortMergeCompactor;
+use crate::pipelines::new::processors::SortMergeJoinState;
+use crate::pipelines::new::processors::TeeSource;
+use crate::pipelines::new::processors::TopNCompactor;
 use crate::pipelines::new::processors::TransformAggregator;
 use crate::pipelines::new::processors::TransformApply;
+use crate::pipelines::new::processors::TransformDistinct;
+use crate::pipelines::new::processors::TransformExternalFunction;
 use crate::pipelines::new::processors::TransformFilter;
 use crate::pipelines::new::processors::TransformHashJoinProbe;
 use crate::pipelines::new::processors::TransformLimit;
 use crate::pipelines::new::processors::TransformMax1Row;
+use crate::pipelines::new::processors::TransformMergeJoin;
 use crate::pipelines::new::processors::TransformSortMerge;
 use crate::pipelines::new::processors::TransformSortPartial;
+use crate::pipelines::new::processors::TransformSortSpill;
+use crate::pipelines::new::processors::TransformTopN;
+use crate::pipelines::new::processors::TransformWindow;
+use crate::pipelines::new::processors::WindowCompactor;
+use crate::pipelines::new::NewPipe;
 use crate::pipelines::new::NewPipeline;
 use crate::pipelines::new::SinkPipeBuilder;
+use crate::pipelines::new::TransformPipeBuilder;
 use crate::pipelines::transforms::get_sort_descriptions;
 use crate::pipelines::transforms::group_by::keys_ref::KeysRef;
 use crate::sessions::QueryContext;
+use crate::sessions::TempStorageReservation;
 use crate::sql::exec::data_schema_builder::DataSchemaBuilder;
 use crate::sql::exec::expression_builder::ExpressionBuilder;
 use crate::sql::exec::util::check_physical;
+use crate::sql::optimizer::sort_merge_join_threshold;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::AggregatePlan;
 use crate::sql::plans::AndExpr;
 use crate::sql::plans::CrossApply;
+use crate::sql::plans::CteScan;
+use crate::sql::plans::DistinctPlan;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::FilterPlan;
+use crate::sql::plans::JoinDistribution;
 use crate::sql::plans::LimitPlan;
 use crate::sql::plans::PhysicalHashJoin;
 use crate::sql::plans::PhysicalScan;
+use crate::sql::plans::PhysicalSortMergeJoin;
 use crate::sql::plans::Project;
+use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarExpr;
 use crate::sql::plans::SortPlan;
+use crate::sql::plans::WindowPlan;
+use crate::sql::plans::WorkingTableScan;
 use crate::sql::IndexType;
 
 /// Helper to build a `Pipeline` from `SExpr`
@@ -100,6 +130,13 @@ pub struct PipelineBuilder {
     pub pipelines: Vec<NewPipeline>,
     limit: Option<usize>,
     offset: usize,
+    // Materialized CTEs, shared by all the `CteScan`s of the same CTE.
+    ctes: BTreeMap<IndexType, Arc<MaterializedCte>>,
+    // Working tables of the recursive CTEs being evaluated.
+    working_tables: BTreeMap<IndexType, Arc<Vec<DataBlock>>>,
+    // Runtime filters of the hash joins whose probe side is being built, with the probe key
+    // column of each join key.
+    runtime_filters: Vec<(Arc<RuntimeFilter>, Vec<Option<IndexType>>)>,
 }
 
 impl PipelineBuilder {
@@ -117,9 +154,17 @@ impl PipelineBuilder {
             pipelines: vec![],
             limit: None,
             offset: 0,
+            ctes: BTreeMap::new(),
+            working_tables: BTreeMap::new(),
+            runtime_filters: vec![],
         }
     }
 
+    /// Set the working table of a recursive CTE, read by its `WorkingTableScan`s.
+    pub fn set_working_table(&mut self, cte_index: IndexType, blocks: Vec<DataBlock>) {
+        self.working_tables.insert(cte_index, Arc::new(blocks));
+    }
+
     fn get_field_name(&self, column_index: IndexType) -> String {
         let name = &self.metadata.read().column(column_index).name.clone();
         format_field_name(name.as_str(), column_index)
@@ -128,12 +173,19 @@ impl PipelineBuilder {
     pub fn spawn(mut self) -> Result<(NewPipeline, Vec<NewPipeline>, Arc<DataSchema>)> {
         let expr = self.expression.clone();
         let mut pipeline = NewPipeline::create();
+        let settings = self.ctx.get_settings();
+        pipeline.set_preserve_order(settings.get_preserve_insertion_order()? != 0);
         let schema = self.build_pipeline(self.ctx.clone(), &expr, &mut pipeline)?;
         let schema = self.align_data_schema(schema, &mut pipeline)?;
-        let settings = self.ctx.get_settings();
         pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+        pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
+        pipeline.set_profile_callback(self.ctx.create_profile_callback());
         for pipeline in self.pipelines.iter_mut() {
             pipeline.set_max_threads(settings.get_max_threads()? as usize);
+            pipeline.set_max_execution_time(settings.get_max_execution_time()?);
+            pipeline.set_cpu_affinity(&settings.get_executor_cpu_affinity()?)?;
+            pipeline.set_profile_callback(self.ctx.create_profile_callback());
         }
         Ok((pipeline, self.pipelines, schema))
     }
@@ -181,6 +233,24 @@ impl PipelineBuilder {
 
         let plan = s_expr.plan();
 
+        // The rows removed by the runtime filters change the results of these operators, the
+        // filters of the joins above them can't reach the scans below.
+        if !self.runtime_filters.is_empty()
+            && matches!(
+                plan,
+                RelOperator::Limit(_)
+                    | RelOperator::Window(_)
+                    | RelOperator::Max1Row(_)
+                    | RelOperator::CrossApply(_)
+                    | RelOperator::CteScan(_)
+            )
+        {
+            let runtime_filters = std::mem::take(&mut self.runtime_filters);
+            let schema = self.build_pipeline(context, s_expr, pipeline);
+            self.runtime_filters = runtime_filters;
+            return schema;
+        }
+
         match plan {
             RelOperator::PhysicalScan(physical_scan) => {
                 self.build_physical_scan(context, physical_scan, pipeline)
@@ -205,9 +275,21 @@ impl PipelineBuilder {
                     self.build_pipeline(context.clone(), s_expr.child(0)?, pipeline)?;
                 self.build_aggregate(context, aggregate, input_schema, pipeline)
             }
-            RelOperator::PhysicalHashJoin(hash_join) => {
-                let probe_schema =
+            RelOperator::Distinct(distinct) => {
+                let input_schema =
                     self.build_pipeline(context.clone(), s_expr.child(0)?, pipeline)?;
+                self.build_distinct(context, distinct, input_schema, pipeline)
+            }
+            RelOperator::PhysicalHashJoin(hash_join) => {
+                let runtime_filter = self.create_runtime_filter(&context, hash_join)?;
+                if let Some(runtime_filter) = &runtime_filter {
+                    self.runtime_filters.push(runtime_filter.clone());
+                }
+                let probe_schema = self.build_pipeline(context.clone(), s_expr.child(0)?, pipeline);
+                if runtime_filter.is_some() {
+                    self.runtime_filters.pop();
+                }
+                let probe_schema = probe_schema?;
                 let mut child_pipeline = NewPipeline::create();
                 let build_schema = self.build_pipeline(
                     QueryContext::create_from(context.clone()),
@@ -221,6 +303,25 @@ impl PipelineBuilder {
                     probe_schema,
                     child_pipeline,
                     pipeline,
+                    runtime_filter.map(|(runtime_filter, _)| runtime_filter),
+                )
+            }
+            RelOperator::PhysicalSortMergeJoin(merge_join) => {
+                let left_schema =
+                    self.build_pipeline(context.clone(), s_expr.child(0)?, pipeline)?;
+                let mut child_pipeline = NewPipeline::create();
+                let right_schema = self.build_pipeline(
+                    QueryContext::create_from(context.clone()),
+                    s_expr.child(1)?,
+                    &mut child_pipeline,
+                )?;
+                self.build_sort_merge_join(
+                    context,
+                    merge_join,
+                    left_schema,
+                    right_schema,
+                    child_pipeline,
+                    pipeline,
                 )
             }
             RelOperator::Sort(sort_plan) => {
@@ -244,6 +345,19 @@ impl PipelineBuilder {
                     pipeline,
                 )
             }
+            RelOperator::Window(window) => {
+                let input_schema =
+                    self.build_pipeline(context.clone(), s_expr.child(0)?, pipeline)?;
+                self.build_window(context, window, input_schema, pipeline)
+            }
+            // The children of `CteScan` are executed lazily by the `CteSource`, so that they are
+            // evaluated only once for all the references of the CTE.
+            RelOperator::CteScan(cte_scan) => {
+                self.build_cte_scan(context, cte_scan, s_expr, pipeline)
+            }
+            RelOperator::WorkingTableScan(scan) => {
+                self.build_working_table_scan(context, scan, pipeline)
+            }
             RelOperator::Max1Row(_) => {
                 let input_schema = self.build_pipeline(context, s_expr.child(0)?, pipeline)?;
                 pipeline
@@ -298,6 +412,7 @@ impl PipelineBuilder {
             let expression = expr_builder.build_and_rename(scalar, item.index)?;
             expressions.push(expression);
         }
+        build_external_functions(&ctx, pipeline, &input_schema, &expressions)?;
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             ProjectionTransform::try_create(
                 transform_input_port,
@@ -339,6 +454,7 @@ impl PipelineBuilder {
         if !no_agg_expression && filter.is_having {
             pred = eb.normalize_aggr_to_col(pred.clone())?;
         }
+        build_external_functions(&ctx, pipeline, &input_schema, std::slice::from_ref(&pred))?;
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformFilter::try_create(
                 input_schema.clone(),
@@ -362,7 +478,27 @@ impl PipelineBuilder {
 
         let table = ctx.build_table_from_source_plan(&plan)?;
         ctx.try_set_partitions(plan.parts.clone())?;
+        let mut runtime_filters = vec![];
+        for (runtime_filter, probe_columns) in &self.runtime_filters {
+            let mut columns = vec![];
+            for (key, probe_column) in probe_columns.iter().enumerate() {
+                match probe_column {
+                    Some(index) if scan.columns.contains(index) => {
+                        let name = self.metadata.read().column(*index).name.clone();
+                        if let Ok(column) = table.schema().index_of(&name) {
+                            columns.push((key, column, name));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if !columns.is_empty() {
+                runtime_filters.push(ScanRuntimeFilter::create(runtime_filter.clone(), columns));
+            }
+        }
+        ctx.set_runtime_filters(runtime_filters);
         table.read2(ctx.clone(), &plan, pipeline)?;
+        ctx.set_runtime_filters(vec![]);
         let columns: Vec<IndexType> = scan.columns.iter().cloned().collect();
         let projections: Vec<Expression> = columns
             .iter()
@@ -392,6 +528,84 @@ impl PipelineBuilder {
         Ok(output_schema)
     }
 
+    fn build_cte_scan(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        cte_scan: &CteScan,
+        s_expr: &SExpr,
+        pipeline: &mut NewPipeline,
+    ) -> Result<DataSchemaRef> {
+        let cte = match self.ctes.get(&cte_scan.cte_index) {
+            Some(cte) => cte.clone(),
+            None => {
+                let cte = MaterializedCte::create(
+                    ctx.clone(),
+                    self.metadata.clone(),
+                    cte_scan.clone(),
+                    s_expr,
+                )?;
+                self.ctes.insert(cte_scan.cte_index, cte.clone());
+                cte
+            }
+        };
+
+        let output_schema = self.build_cte_schema(&cte_scan.columns);
+        let output = OutputPort::create();
+        let source = match cte_scan.has_recursive_term() {
+            true => CteSource::create(ctx, output.clone(), output_schema.clone(), cte)?,
+            false => TeeSource::create(ctx, output.clone(), output_schema.clone(), cte.get_tee()?)?,
+        };
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output],
+            processors: vec![source],
+        });
+        Ok(output_schema)
+    }
+
+    fn build_working_table_scan(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        scan: &WorkingTableScan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<DataSchemaRef> {
+        let blocks = self
+            .working_tables
+            .get(&scan.cte_index)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::LogicalError(format!(
+                    "Working table of CTE '{}' is not available",
+                    self.metadata.read().cte_name(scan.cte_index)
+                ))
+            })?;
+
+        let output_schema = self.build_cte_schema(&scan.columns);
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![CteSource::create_working_table(
+                ctx,
+                output,
+                output_schema.clone(),
+                blocks,
+            )?],
+        });
+        Ok(output_schema)
+    }
+
+    fn build_cte_schema(&self, columns: &[IndexType]) -> DataSchemaRef {
+        let fields = columns
+            .iter()
+            .map(|index| {
+                let data_type = self.metadata.read().column(*index).data_type.clone();
+                DataField::new(self.get_field_name(*index).as_str(), data_type)
+            })
+            .collect();
+        DataSchemaRefExt::create(fields)
+    }
+
     fn build_aggregate(
         &mut self,
         ctx: Arc<QueryContext>,
@@ -453,6 +667,13 @@ impl PipelineBuilder {
             RewriteHelper::exprs_to_fields(final_exprs.as_slice(), &input_schema)?;
         let final_schema = DataSchemaRefExt::create(final_data_fields);
 
+        // Spread the work to all the cores even if the source has few partitions.
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        if pipeline.output_len() < max_threads {
+            let keys = group_expressions.iter().map(|expr| expr.column_name());
+            pipeline.repartition(max_threads, keys.collect())?;
+        }
+
         let partial_aggr_params = AggregatorParams::try_create(
             &agg_expressions,
             &group_expressions,
@@ -509,6 +730,71 @@ impl PipelineBuilder {
         Ok(output_schema)
     }
 
+    fn build_distinct(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        distinct: &DistinctPlan,
+        input_schema: DataSchemaRef,
+        pipeline: &mut NewPipeline,
+    ) -> Result<DataSchemaRef> {
+        let distinct_columns: Vec<String> = distinct
+            .items
+            .iter()
+            .map(|item| self.get_field_name(item.index))
+            .collect();
+
+        // Only the distinct keys are output unless it's `DISTINCT ON`.
+        let output_schema = match distinct.first_row {
+            true => input_schema,
+            false => {
+                let mut output_fields = Vec::with_capacity(distinct_columns.len());
+                let mut expressions = Vec::with_capacity(distinct_columns.len());
+                for (name, item) in distinct_columns.iter().zip(distinct.items.iter()) {
+                    output_fields.push(DataField::new(name.as_str(), item.scalar.data_type()));
+                    expressions.push(Expression::Column(name.clone()));
+                }
+
+                let output_schema = DataSchemaRefExt::create(output_fields);
+                pipeline.add_transform(|input, output| {
+                    ProjectionTransform::try_create(
+                        input,
+                        output,
+                        input_schema.clone(),
+                        output_schema.clone(),
+                        expressions.clone(),
+                        ctx.clone(),
+                    )
+                })?;
+                output_schema
+            }
+        };
+
+        let max_memory_usage = ctx.get_settings().get_max_distinct_memory_usage()? as usize;
+        pipeline.add_transform(|input, output| {
+            TransformDistinct::try_create_partial(
+                input,
+                output,
+                output_schema.clone(),
+                distinct_columns.clone(),
+                max_memory_usage,
+            )
+        })?;
+
+        pipeline.resize(1)?;
+        pipeline.add_transform(|input, output| {
+            TransformDistinct::try_create_final(
+                input,
+                output,
+                output_schema.clone(),
+                distinct_columns.clone(),
+                max_memory_usage,
+                TempStorageReservation::create(&ctx)?,
+            )
+        })?;
+
+        Ok(output_schema)
+    }
+
     fn build_hash_join(
         &mut self,
         ctx: Arc<QueryContext>,
@@ -517,9 +803,14 @@ impl PipelineBuilder {
         probe_schema: DataSchemaRef,
         mut child_pipeline: NewPipeline,
         pipeline: &mut NewPipeline,
+        runtime_filter: Option<Arc<RuntimeFilter>>,
     ) -> Result<DataSchemaRef> {
         let builder = DataSchemaBuilder::new(self.metadata.clone());
-        let output_schema = builder.build_join(probe_schema.clone(), build_schema.clone());
+        let output_schema = builder.build_hash_join(
+            probe_schema.clone(),
+            build_schema.clone(),
+            &hash_join.join_type,
+        );
 
         let eb = ExpressionBuilder::create(self.metadata.clone());
         let build_expressions = hash_join
@@ -533,47 +824,112 @@ impl PipelineBuilder {
             .map(|scalar| eb.build(scalar))
             .collect::<Result<Vec<Expression>>>()?;
 
-        let hash_join_state = create_join_state(
-            ctx.clone(),
-            hash_join.join_type.clone(),
-            build_expressions,
-            probe_expressions,
-            build_schema,
-            probe_schema,
-        )?;
+        let partitions = join_partitions(&ctx, hash_join)?;
+        match hash_join.distribution {
+            JoinDistribution::Broadcast => {}
+            JoinDistribution::Hash => {
+                let column_names = |expressions: &[Expression]| {
+                    expressions
+                        .iter()
+                        .map(|expr| expr.column_name())
+                        .collect::<Vec<_>>()
+                };
+                child_pipeline.repartition(partitions, column_names(&build_expressions))?;
+                pipeline.repartition(partitions, column_names(&probe_expressions))?;
+            }
+        }
+        let mut hash_join_states: Vec<Arc<dyn HashJoinState>> = Vec::with_capacity(partitions);
+        for _ in 0..partitions {
+            hash_join_states.push(create_join_state(
+                ctx.clone(),
+                hash_join.join_type.clone(),
+                build_expressions.clone(),
+                probe_expressions.clone(),
+                build_schema.clone(),
+                probe_schema.clone(),
+                runtime_filter.clone(),
+            )?);
+        }
 
         // Build side
-        self.build_sink_hash_table(hash_join_state.clone(), &mut child_pipeline)?;
+        self.build_sink_hash_table(&hash_join_states, &mut child_pipeline)?;
 
-        // Probe side
-        pipeline.add_transform(|input, output| {
-            Ok(TransformHashJoinProbe::create(
+        // Probe side, the partition `i` of probe side is joined with the hash table `i`
+        let mut transform_builder = TransformPipeBuilder::create();
+        for index in 0..pipeline.output_len() {
+            let input = InputPort::create();
+            let output = OutputPort::create();
+            let processor = TransformHashJoinProbe::create(
                 ctx.clone(),
-                input,
-                output,
-                hash_join_state.clone(),
+                input.clone(),
+                output.clone(),
+                hash_join_states[index % partitions].clone(),
                 output_schema.clone(),
-            ))
-        })?;
+            )?;
+            transform_builder.add_transform(input, output, processor);
+        }
+        pipeline.add_pipe(transform_builder.finalize());
 
         self.pipelines.push(child_pipeline);
 
         Ok(output_schema)
     }
 
+    // The probe side rows without a match are dropped by inner, right and semi joins, so the
+    // probe side scans can skip them by the values of the build keys.
+    fn create_runtime_filter(
+        &self,
+        ctx: &Arc<QueryContext>,
+        hash_join: &PhysicalHashJoin,
+    ) -> Result<Option<(Arc<RuntimeFilter>, Vec<Option<IndexType>>)>> {
+        if ctx.get_settings().get_enable_runtime_filter()? == 0
+            || !matches!(
+                hash_join.join_type,
+                JoinType::InnerJoin | JoinType::RightJoin | JoinType::SemiJoin
+            )
+        {
+            return Ok(None);
+        }
+
+        let probe_columns = hash_join
+            .build_keys
+            .iter()
+            .zip(hash_join.probe_keys.iter())
+            .map(|(build_key, probe_key)| {
+                let probe_key = unwrap_nullable_cast(probe_key);
+                match probe_key {
+                    Scalar::BoundColumnRef(column)
+                        if remove_nullable(&build_key.data_type())
+                            == remove_nullable(&probe_key.data_type()) =>
+                    {
+                        Some(column.column.index)
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        if probe_columns.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+
+        let runtime_filter =
+            RuntimeFilter::create(probe_columns.len(), join_partitions(ctx, hash_join)?);
+        Ok(Some((runtime_filter, probe_columns)))
+    }
+
     fn build_sink_hash_table(
         &mut self,
-        state: Arc<dyn HashJoinState>,
+        states: &[Arc<dyn HashJoinState>],
         pipeline: &mut NewPipeline,
     ) -> Result<()> {
         let mut sink_pipeline_builder = SinkPipeBuilder::create();
-        for _ in 0..pipeline.output_len() {
+        for index in 0..pipeline.output_len() {
             let input_port = InputPort::create();
             sink_pipeline_builder.add_sink(
                 input_port.clone(),
                 Sinker::<SinkBuildHashTable>::create(
                     input_port,
-                    SinkBuildHashTable::try_create(state.clone())?,
+                    SinkBuildHashTable::try_create(states[index % states.len()].clone())?,
                 ),
             );
         }
@@ -582,6 +938,76 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_sort_merge_join(
+        &mut self,
+        ctx: Arc<QueryContext>,
+        merge_join: &PhysicalSortMergeJoin,
+        left_schema: DataSchemaRef,
+        right_schema: DataSchemaRef,
+        mut child_pipeline: NewPipeline,
+        pipeline: &mut NewPipeline,
+    ) -> Result<DataSchemaRef> {
+        let builder = DataSchemaBuilder::new(self.metadata.clone());
+        let output_schema =
+            builder.build_hash_join(left_schema, right_schema.clone(), &merge_join.join_type);
+
+        let eb = ExpressionBuilder::create(self.metadata.clone());
+        let key_names = |keys: &[Scalar]| {
+            keys.iter()
+                .map(|scalar| Ok(eb.build(scalar)?.column_name()))
+                .collect::<Result<Vec<String>>>()
+        };
+        let left_keys = key_names(&merge_join.left_keys)?;
+        let right_keys = key_names(&merge_join.right_keys)?;
+
+        // Both sides are sorted at the same time, each of them within the threshold.
+        let memory_budget = sort_merge_join_threshold(&ctx)? as usize;
+        let block_size = ctx.get_settings().get_max_block_size()? as usize;
+        build_external_sort(
+            &ctx,
+            &mut child_pipeline,
+            &right_keys,
+            memory_budget,
+            block_size,
+        )?;
+        build_external_sort(&ctx, pipeline, &left_keys, memory_budget, block_size)?;
+
+        // Right side
+        let join_state = SortMergeJoinState::create(
+            memory_budget,
+            block_size,
+            TempStorageReservation::create(&ctx)?,
+        );
+        let mut sink_pipeline_builder = SinkPipeBuilder::create();
+        let input_port = InputPort::create();
+        sink_pipeline_builder.add_sink(
+            input_port.clone(),
+            Sinker::<SinkMergeJoinRight>::create(
+                input_port,
+                SinkMergeJoinRight::try_create(join_state.clone())?,
+            ),
+        );
+        child_pipeline.add_pipe(sink_pipeline_builder.finalize());
+
+        // Left side, merged with the right side once it's sorted
+        pipeline.add_transform(|input, output| {
+            TransformMergeJoin::create(
+                input,
+                output,
+                join_state.clone(),
+                merge_join.join_type.clone(),
+                left_keys.clone(),
+                right_keys.clone(),
+                right_schema.clone(),
+                output_schema.clone(),
+            )
+        })?;
+
+        self.pipelines.push(child_pipeline);
+
+        Ok(output_schema)
+    }
+
     fn build_order_by(
         &mut self,
         ctx: Arc<QueryContext>,
@@ -618,6 +1044,63 @@ impl PipelineBuilder {
         })?;
 
         let rows_limit = self.limit.map(|limit| limit + self.offset);
+        if let Some(rows_limit) = rows_limit {
+            let sort_descriptions = get_sort_descriptions(&output_schema, expressions.as_slice())?;
+
+            // processor 1: block ---> top n rows
+            // processor 2: block ---> top n rows
+            // processor 3: block ---> top n rows
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformTopN::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    TopNCompactor::new(rows_limit, sort_descriptions.clone()),
+                )
+            })?;
+
+            pipeline.resize(1)?;
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformTopN::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    TopNCompactor::new(rows_limit, sort_descriptions.clone()),
+                )
+            })?;
+
+            return Ok(output_schema);
+        }
+
+        let settings = ctx.get_settings();
+        let memory_budget =
+            settings.get_max_memory_usage()? * settings.get_sort_spilling_memory_ratio()? / 100;
+        if memory_budget != 0 {
+            let block_size = settings.get_max_block_size()? as usize;
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformSortPartial::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    None,
+                    get_sort_descriptions(&output_schema, expressions.as_slice())?,
+                )
+            })?;
+
+            // Merge the sorted blocks in one processor, which spills sorted runs to disk once
+            // the buffered blocks exceed the memory budget.
+            pipeline.resize(1)?;
+            pipeline.add_transform(|transform_input_port, transform_output_port| {
+                TransformSortSpill::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    get_sort_descriptions(&output_schema, expressions.as_slice())?,
+                    memory_budget as usize,
+                    block_size,
+                    TempStorageReservation::create(&ctx)?,
+                )
+            })?;
+
+            return Ok(output_schema);
+        }
+
         // processor 1: block ---> sort_stream
         // processor 2: block ---> sort_stream
         // processor 3: block ---> sort_stream
@@ -664,6 +1147,59 @@ impl PipelineBuilder {
         Ok(output_schema)
     }
 
+    fn build_window(
+        &mut self,
+        _ctx: Arc<QueryContext>,
+        window: &WindowPlan,
+        input_schema: DataSchemaRef,
+        pipeline: &mut NewPipeline,
+    ) -> Result<DataSchemaRef> {
+        let schema_builder = DataSchemaBuilder::new(self.metadata.clone());
+        let output_schema = schema_builder.build_window(window, input_schema)?;
+
+        let arguments: Vec<String> = window
+            .arguments
+            .iter()
+            .map(|item| self.get_field_name(item.index))
+            .collect();
+        let partition_by: Vec<String> = window
+            .partition_by
+            .iter()
+            .map(|item| self.get_field_name(item.index))
+            .collect();
+        let order_by: Vec<SortColumnDescription> = window
+            .order_by
+            .iter()
+            .map(|item| {
+                let asc = item.asc.unwrap_or(true);
+                SortColumnDescription {
+                    column_name: self.get_field_name(item.index),
+                    asc,
+                    nulls_first: item.nulls_first.unwrap_or(!asc),
+                }
+            })
+            .collect();
+
+        // All the rows of a partition must be seen by the same processor.
+        pipeline.resize(1)?;
+        pipeline.add_transform(|transform_input_port, transform_output_port| {
+            TransformWindow::try_create(
+                transform_input_port,
+                transform_output_port,
+                WindowCompactor::try_create(
+                    output_schema.clone(),
+                    window.func.clone(),
+                    arguments.clone(),
+                    partition_by.clone(),
+                    order_by.clone(),
+                    window.frame.clone(),
+                )?,
+            )
+        })?;
+
+        Ok(output_schema)
+    }
+
     fn build_limit(
         &mut self,
         _ctx: Arc<QueryContext>,
@@ -723,6 +1259,91 @@ impl PipelineBuilder {
     }
 }
 
+// One hash table for broadcast, and one per partition for hash distribution
+// The key of the side which is not nullable is cast to nullable if the other side is, which
+// doesn't change the values to filter the scan with.
+fn unwrap_nullable_cast(scalar: &Scalar) -> &Scalar {
+    match scalar {
+        Scalar::CastExpr(cast)
+            if remove_nullable(&cast.from_type) == remove_nullable(&cast.target_type) =>
+        {
+            unwrap_nullable_cast(&cast.argument)
+        }
+        _ => scalar,
+    }
+}
+
+fn join_partitions(ctx: &Arc<QueryContext>, hash_join: &PhysicalHashJoin) -> Result<usize> {
+    Ok(match hash_join.distribution {
+        JoinDistribution::Broadcast => 1,
+        JoinDistribution::Hash => ctx.get_settings().get_max_threads()? as usize,
+    })
+}
+
+// Sort the rows by the columns in ascending order with NULLs last, the sorted blocks are merged
+// in one processor which spills sorted runs to disk once they exceed the memory budget.
+fn build_external_sort(
+    ctx: &Arc<QueryContext>,
+    pipeline: &mut NewPipeline,
+    columns: &[String],
+    memory_budget: usize,
+    block_size: usize,
+) -> Result<()> {
+    let sort_descriptions = columns
+        .iter()
+        .map(|column_name| SortColumnDescription {
+            column_name: column_name.clone(),
+            asc: true,
+            nulls_first: false,
+        })
+        .collect::<Vec<_>>();
+
+    pipeline.add_transform(|transform_input_port, transform_output_port| {
+        TransformSortPartial::try_create(
+            transform_input_port,
+            transform_output_port,
+            None,
+            sort_descriptions.clone(),
+        )
+    })?;
+
+    pipeline.resize(1)?;
+    pipeline.add_transform(|transform_input_port, transform_output_port| {
+        TransformSortSpill::try_create(
+            transform_input_port,
+            transform_output_port,
+            sort_descriptions.clone(),
+            memory_budget,
+            block_size,
+            TempStorageReservation::create(ctx)?,
+        )
+    })
+}
+
+// The external functions are called by an async transform before the expressions, which take
+// the results of the calls as is.
+fn build_external_functions(
+    ctx: &Arc<QueryContext>,
+    pipeline: &mut NewPipeline,
+    input_schema: &DataSchemaRef,
+    expressions: &[Expression],
+) -> Result<()> {
+    let calls = TransformExternalFunction::find_calls(expressions)?;
+    if calls.is_empty() {
+        return Ok(());
+    }
+
+    pipeline.add_transform(|transform_input_port, transform_output_port| {
+        TransformExternalFunction::try_create(
+            transform_input_port,
+            transform_output_port,
+            input_schema.clone(),
+            &calls,
+            ctx.clone(),
+        )
+    })
+}
+
 fn create_join_state(
     ctx: Arc<QueryContext>,
     join_type: JoinType,
@@ -730,10 +1351,12 @@ fn create_join_state(
     probe_expressions: Vec<Expression>,
     build_schema: DataSchemaRef,
     probe_schema: DataSchemaRef,
+    runtime_filter: Option<Arc<RuntimeFilter>>,
 ) -> Result<Arc<ChainingHashTable>> {
+    // The NULL keys never match, the keys are hashed without the validity of the columns.
     let hash_key_types = build_expressions
         .iter()
-        .map(|expr| expr.to_data_type(&build_schema))
+        .map(|expr| Ok(remove_nullable(&expr.to_data_type(&build_schema)?)))
         .collect::<Result<Vec<DataTypeImpl>>>()?;
     let method = DataBlock::choose_hash_method_with_types(&hash_key_types)?;
     Ok(match method {
@@ -749,6 +1372,7 @@ fn create_join_state(
                 probe_expressions,
                 build_schema,
                 probe_schema,
+                runtime_filter,
             )?)
         }
         HashMethodKind::KeysU8(hash_method) => Arc::new(ChainingHashTable::try_create(
@@ -762,6 +1386,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU16(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -774,6 +1399,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU32(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -786,6 +1412,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU64(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -798,6 +1425,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU128(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -810,6 +1438,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU256(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -822,6 +1451,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
         HashMethodKind::KeysU512(hash_method) => Arc::new(ChainingHashTable::try_create(
             ctx,
@@ -834,6 +1464,7 @@ fn create_join_state(
             probe_expressions,
             build_schema,
             probe_schema,
+            runtime_filter,
         )?),
     })
 }
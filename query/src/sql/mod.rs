@@ -20,6 +20,7 @@ mod parsers;
 mod plan_parser;
 pub mod planner;
 mod sql_common;
+mod sql_hint;
 mod sql_parser;
 mod sql_statement;
 pub mod statements;
@@ -29,6 +30,9 @@ pub use common::*;
 pub use plan_parser::PlanParser;
 pub use planner::*;
 pub use sql_common::SQLCommon;
+pub use sql_hint::check_result_hints;
+pub use sql_hint::result_hash;
+pub use sql_hint::DfHintMismatch;
 pub use sql_parser::DfParser;
 pub use sql_statement::*;
 pub use table_option_keys::*;
@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::RelOperator;
+use crate::sql::IndexType;
+use crate::sql::MetadataRef;
+
+/// The fraction of rows assumed to pass a filter, there are no column statistics to do better.
+const DEFAULT_FILTER_SELECTIVITY: f64 = 0.2;
+
+/// Estimate the output rows of a `SExpr` from the statistics of the scanned tables,
+/// which are collected into the `ReadDataSourcePlan` while binding.
+pub struct CardinalityEstimator {
+    metadata: MetadataRef,
+}
+
+impl CardinalityEstimator {
+    pub fn create(metadata: MetadataRef) -> Self {
+        CardinalityEstimator { metadata }
+    }
+
+    pub fn estimate(&self, s_expr: &SExpr) -> f64 {
+        let child = |index: usize| {
+            s_expr
+                .children()
+                .get(index)
+                .map(|child| self.estimate(child))
+                .unwrap_or_default()
+        };
+
+        match s_expr.plan() {
+            RelOperator::LogicalGet(get) => self.table_rows(get.table_index),
+            RelOperator::PhysicalScan(scan) => self.table_rows(scan.table_index),
+            RelOperator::LogicalInnerJoin(join) => Self::join_rows(
+                &join.join_type,
+                join.left_conditions.is_empty(),
+                child(0),
+                child(1),
+            ),
+            RelOperator::PhysicalHashJoin(join) => Self::join_rows(
+                &join.join_type,
+                join.build_keys.is_empty(),
+                child(0),
+                child(1),
+            ),
+            RelOperator::PhysicalSortMergeJoin(join) => Self::join_rows(
+                &join.join_type,
+                join.left_keys.is_empty(),
+                child(0),
+                child(1),
+            ),
+            RelOperator::Filter(_) => child(0) * DEFAULT_FILTER_SELECTIVITY,
+            RelOperator::Aggregate(aggregate) if aggregate.group_items.is_empty() => 1.0,
+            RelOperator::Limit(limit) => match limit.limit {
+                Some(limit) => child(0).min(limit as f64),
+                None => child(0),
+            },
+            RelOperator::Max1Row(_) => child(0).min(1.0),
+            RelOperator::Pattern(_) => 0.0,
+            _ => child(0),
+        }
+    }
+
+    /// Estimate the output bytes of a `SExpr`, the rows are assumed to be as wide as the rows
+    /// of all the tables scanned below it put together.
+    pub fn estimate_bytes(&self, s_expr: &SExpr) -> f64 {
+        self.estimate(s_expr) * self.row_bytes(s_expr)
+    }
+
+    fn row_bytes(&self, s_expr: &SExpr) -> f64 {
+        let table_row_bytes = |table_index: IndexType| {
+            let metadata = self.metadata.read();
+            let statistics = &metadata.table(table_index).source.statistics;
+            match statistics.read_rows {
+                0 => 0.0,
+                rows => statistics.read_bytes as f64 / rows as f64,
+            }
+        };
+
+        let bytes = match s_expr.plan() {
+            RelOperator::LogicalGet(get) => table_row_bytes(get.table_index),
+            RelOperator::PhysicalScan(scan) => table_row_bytes(scan.table_index),
+            _ => 0.0,
+        };
+        bytes
+            + s_expr
+                .children()
+                .iter()
+                .map(|child| self.row_bytes(child))
+                .sum::<f64>()
+    }
+
+    fn table_rows(&self, table_index: IndexType) -> f64 {
+        let metadata = self.metadata.read();
+        metadata.table(table_index).source.statistics.read_rows as f64
+    }
+
+    // Without the distinct count of the join keys, an equi-join is assumed to join a
+    // foreign key with a primary key, so it outputs as many rows as the larger side.
+    fn join_rows(join_type: &JoinType, no_keys: bool, left: f64, right: f64) -> f64 {
+        match join_type {
+            JoinType::CrossJoin => left * right,
+            // Semi join and anti join only filter the rows of left side
+            JoinType::SemiJoin | JoinType::AntiJoin => left,
+            _ if no_keys => left * right,
+            _ => left.max(right),
+        }
+    }
+}
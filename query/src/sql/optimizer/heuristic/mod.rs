@@ -16,12 +16,18 @@ mod implement;
 mod rule_list;
 
 use common_exception::Result;
+use lazy_static::lazy_static;
 
 use crate::sql::optimizer::heuristic::implement::HeuristicImplementor;
 use crate::sql::optimizer::heuristic::rule_list::RuleList;
+use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::rule::TransformState;
 use crate::sql::optimizer::SExpr;
 
+lazy_static! {
+    static ref DEFAULT_REWRITE_RULES: Vec<RuleID> = vec![RuleID::NormalizeAggregate];
+}
+
 /// A heuristic query optimizer. It will apply specific transformation rules in order and
 /// implement the logical plans with default implementation rules.
 pub struct HeuristicOptimizer {
@@ -32,7 +38,7 @@ pub struct HeuristicOptimizer {
 impl HeuristicOptimizer {
     pub fn create() -> Result<Self> {
         Ok(HeuristicOptimizer {
-            rules: RuleList::create(vec![])?,
+            rules: RuleList::create(DEFAULT_REWRITE_RULES.clone())?,
             implementor: HeuristicImplementor::new(),
         })
     }
@@ -57,6 +63,10 @@ impl HeuristicOptimizer {
         let mut result = s_expr.clone();
 
         for rule in rule_list.iter() {
+            if !result.match_pattern(rule.pattern()) {
+                continue;
+            }
+
             let mut state = TransformState::new();
             rule.apply(&result, &mut state)?;
             if !state.results().is_empty() {
@@ -66,7 +76,7 @@ impl HeuristicOptimizer {
 
         // Implement expression with Implementor
         let mut state = TransformState::new();
-        self.implementor.implement(s_expr, &mut state)?;
+        self.implementor.implement(&result, &mut state)?;
         if !state.results().is_empty() {
             result = state.results()[0].clone();
         }
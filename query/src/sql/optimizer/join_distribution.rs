@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+
+use crate::sql::optimizer::CardinalityEstimator;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinDistribution;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::PhysicalHashJoin;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::IndexType;
+use crate::sql::MetadataRef;
+use crate::storages::fuse::FuseTable;
+
+/// Choose the distribution of the hash joins from the estimated rows of the build side,
+/// which come from the statistics of the scanned tables in the `ReadDataSourcePlan`.
+/// A build side with more rows than `threshold` is repartitioned by the join keys, so the
+/// hash tables are built in parallel instead of into one shared hash table.
+///
+/// Both distributions are across the threads of the local node, as the pipelines of the new
+/// planner are not distributed yet. Distributing the joins across the nodes of a cluster,
+/// which needs exchanges between the nodes, is out of the scope here.
+///
+/// A build side which only scans broadcast tables is never repartitioned, these tables are
+/// read as a whole by every node.
+pub struct JoinDistributionSelector {
+    metadata: MetadataRef,
+    estimator: CardinalityEstimator,
+    threshold: f64,
+}
+
+impl JoinDistributionSelector {
+    pub fn create(metadata: MetadataRef, threshold: u64) -> Self {
+        JoinDistributionSelector {
+            metadata: metadata.clone(),
+            estimator: CardinalityEstimator::create(metadata),
+            threshold: threshold as f64,
+        }
+    }
+
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize(child))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = match s_expr.plan() {
+            RelOperator::PhysicalHashJoin(join) if self.is_repartitionable(join) => {
+                let build_rows = self.estimator.estimate(s_expr.child(1)?);
+                let broadcast = self.scans_broadcast_tables(s_expr.child(1)?);
+                let distribution = match !broadcast && build_rows > self.threshold {
+                    true => JoinDistribution::Hash,
+                    false => JoinDistribution::Broadcast,
+                };
+                PhysicalHashJoin {
+                    distribution,
+                    ..join.clone()
+                }
+                .into()
+            }
+            plan => plan.clone(),
+        };
+        Ok(SExpr::create(plan, children, s_expr.original_group()))
+    }
+
+    fn scans_broadcast_tables(&self, s_expr: &SExpr) -> bool {
+        let mut tables = vec![];
+        Self::collect_scanned_tables(s_expr, &mut tables);
+
+        let metadata = self.metadata.read();
+        !tables.is_empty()
+            && tables.iter().all(|index| {
+                FuseTable::try_from_table(metadata.table(*index).table.as_ref())
+                    .map(|table| table.is_broadcast())
+                    .unwrap_or(false)
+            })
+    }
+
+    fn collect_scanned_tables(s_expr: &SExpr, tables: &mut Vec<IndexType>) {
+        match s_expr.plan() {
+            RelOperator::LogicalGet(get) => tables.push(get.table_index),
+            RelOperator::PhysicalScan(scan) => tables.push(scan.table_index),
+            _ => {}
+        }
+        for child in s_expr.children() {
+            Self::collect_scanned_tables(child, tables);
+        }
+    }
+
+    // The rows are repartitioned by the hash of the serialized key columns, so the keys of
+    // both sides must be columns of the same types to put the equal keys into the same
+    // partition.
+    fn is_repartitionable(&self, join: &PhysicalHashJoin) -> bool {
+        if join.join_type == JoinType::CrossJoin || join.build_keys.is_empty() {
+            return false;
+        }
+
+        join.build_keys
+            .iter()
+            .zip(join.probe_keys.iter())
+            .all(|(build_key, probe_key)| match (build_key, probe_key) {
+                (Scalar::BoundColumnRef(_), Scalar::BoundColumnRef(_)) => {
+                    build_key.data_type() == probe_key.data_type()
+                }
+                _ => false,
+            })
+    }
+}
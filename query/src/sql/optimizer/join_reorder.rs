@@ -0,0 +1,188 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_exception::Result;
+
+use crate::sql::optimizer::CardinalityEstimator;
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::LogicalInnerJoin;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::MetadataRef;
+
+/// One input of a tree of inner joins, which can be any relational expression except
+/// an inner equi-join.
+struct JoinLeaf {
+    s_expr: SExpr,
+    columns: ColumnSet,
+    rows: f64,
+}
+
+/// A statistics-driven join reorder rule. The adjacent inner equi-joins are flattened into
+/// their inputs and rebuilt as a left-deep tree: the largest input is the probe side of the
+/// bottom join, and the smallest input connected to the joined ones by a join condition is
+/// picked as the next build side.
+///
+/// The tree is left as it is if some inputs can't be connected by the join conditions.
+pub struct JoinReorder {
+    estimator: CardinalityEstimator,
+}
+
+impl JoinReorder {
+    pub fn create(metadata: MetadataRef) -> Self {
+        JoinReorder {
+            estimator: CardinalityEstimator::create(metadata),
+        }
+    }
+
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if Self::is_inner_equi_join(s_expr) {
+            let mut leaves = vec![];
+            let mut conditions = vec![];
+            self.flatten(s_expr, &mut leaves, &mut conditions)?;
+            if let Some(reordered) = Self::reorder(leaves, conditions) {
+                return Ok(reordered);
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize(child))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SExpr::create(
+            s_expr.plan().clone(),
+            children,
+            s_expr.original_group(),
+        ))
+    }
+
+    fn is_inner_equi_join(s_expr: &SExpr) -> bool {
+        matches!(
+            s_expr.plan(),
+            RelOperator::LogicalInnerJoin(join)
+                if join.join_type == JoinType::InnerJoin && !join.left_conditions.is_empty()
+        )
+    }
+
+    fn flatten(
+        &self,
+        s_expr: &SExpr,
+        leaves: &mut Vec<JoinLeaf>,
+        conditions: &mut Vec<(Scalar, Scalar)>,
+    ) -> Result<()> {
+        if let RelOperator::LogicalInnerJoin(join) = s_expr.plan() {
+            if Self::is_inner_equi_join(s_expr) {
+                conditions.extend(
+                    join.left_conditions
+                        .iter()
+                        .cloned()
+                        .zip(join.right_conditions.iter().cloned()),
+                );
+                for child in s_expr.children() {
+                    self.flatten(child, leaves, conditions)?;
+                }
+                return Ok(());
+            }
+        }
+
+        let s_expr = self.optimize(s_expr)?;
+        let columns = RelExpr::with_s_expr(&s_expr)
+            .derive_relational_prop()?
+            .output_columns;
+        let rows = self.estimator.estimate(&s_expr);
+        leaves.push(JoinLeaf {
+            s_expr,
+            columns,
+            rows,
+        });
+        Ok(())
+    }
+
+    fn reorder(mut leaves: Vec<JoinLeaf>, mut conditions: Vec<(Scalar, Scalar)>) -> Option<SExpr> {
+        // On a tie, the leftmost input stays the probe side.
+        let probe = leaves
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.rows.partial_cmp(&b.rows).unwrap_or(Ordering::Equal))
+            .map(|(index, _)| index)?;
+        let probe = leaves.remove(probe);
+        let mut s_expr = probe.s_expr;
+        let mut columns = probe.columns;
+
+        while !leaves.is_empty() {
+            let build = leaves
+                .iter()
+                .enumerate()
+                .filter(|(_, leaf)| {
+                    conditions.iter().any(|(left, right)| {
+                        Self::orient(left, right, &columns, &leaf.columns).is_some()
+                    })
+                })
+                .min_by(|(_, a), (_, b)| a.rows.partial_cmp(&b.rows).unwrap_or(Ordering::Equal))
+                .map(|(index, _)| index)?;
+            let build = leaves.remove(build);
+
+            let mut left_conditions = vec![];
+            let mut right_conditions = vec![];
+            conditions.retain(|(left, right)| {
+                match Self::orient(left, right, &columns, &build.columns) {
+                    Some((left, right)) => {
+                        left_conditions.push(left);
+                        right_conditions.push(right);
+                        false
+                    }
+                    None => true,
+                }
+            });
+
+            let join = LogicalInnerJoin {
+                left_conditions,
+                right_conditions,
+                join_type: JoinType::InnerJoin,
+            };
+            s_expr = SExpr::create_binary(join.into(), s_expr, build.s_expr);
+            columns.extend(build.columns);
+        }
+
+        match conditions.is_empty() {
+            true => Some(s_expr),
+            false => None,
+        }
+    }
+
+    // Returns the condition as (probe side, build side) if it joins the two column sets.
+    fn orient(
+        left: &Scalar,
+        right: &Scalar,
+        probe_columns: &ColumnSet,
+        build_columns: &ColumnSet,
+    ) -> Option<(Scalar, Scalar)> {
+        let (left_used, right_used) = (left.used_columns(), right.used_columns());
+        if left_used.is_subset(probe_columns) && right_used.is_subset(build_columns) {
+            Some((left.clone(), right.clone()))
+        } else if right_used.is_subset(probe_columns) && left_used.is_subset(build_columns) {
+            Some((right.clone(), left.clone()))
+        } else {
+            None
+        }
+    }
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cardinality;
 mod cascades;
 mod group;
 mod heuristic;
+mod join_distribution;
+mod join_reorder;
 mod m_expr;
 mod memo;
 mod optimize_context;
@@ -22,9 +25,16 @@ mod pattern_extractor;
 mod property;
 mod rule;
 mod s_expr;
+mod shared_scan;
+mod sort_merge_join;
 
+use std::sync::Arc;
+
+pub use cardinality::CardinalityEstimator;
 use common_exception::Result;
 pub use heuristic::HeuristicOptimizer;
+pub use join_distribution::JoinDistributionSelector;
+pub use join_reorder::JoinReorder;
 pub use m_expr::MExpr;
 pub use memo::Memo;
 pub use optimize_context::OptimizeContext;
@@ -35,25 +45,29 @@ pub use property::RelExpr;
 pub use property::RelationalProperty;
 pub use property::RequiredProperty;
 pub use s_expr::SExpr;
+pub use shared_scan::SharedScan;
+pub use sort_merge_join::SortMergeJoinSelector;
 
 use super::plans::Plan;
+use crate::sessions::QueryContext;
 use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::rule::RuleSet;
+use crate::sql::MetadataRef;
 
-pub fn optimize(plan: Plan) -> Result<Plan> {
+pub fn optimize(ctx: Arc<QueryContext>, plan: Plan) -> Result<Plan> {
     match plan {
         Plan::Query {
             s_expr,
             bind_context,
             metadata,
         } => Ok(Plan::Query {
-            s_expr: optimize_query(s_expr)?,
+            s_expr: optimize_query(ctx, s_expr, metadata.clone())?,
             bind_context,
             metadata,
         }),
         Plan::Explain { kind, plan } => Ok(Plan::Explain {
             kind,
-            plan: Box::new(optimize(*plan)?),
+            plan: Box::new(optimize(ctx, *plan)?),
         }),
 
         // Passthrough
@@ -63,12 +77,41 @@ pub fn optimize(plan: Plan) -> Result<Plan> {
     }
 }
 
-pub fn optimize_query(expression: SExpr) -> Result<SExpr> {
+pub fn optimize_query(
+    ctx: Arc<QueryContext>,
+    expression: SExpr,
+    metadata: MetadataRef,
+) -> Result<SExpr> {
+    let expression = match ctx.get_settings().get_enable_cbo()? {
+        0 => expression,
+        _ => JoinReorder::create(metadata.clone()).optimize(&expression)?,
+    };
+
     let mut heuristic = HeuristicOptimizer::create()?;
     let s_expr = heuristic.optimize(expression)?;
+    let s_expr = match sort_merge_join_threshold(&ctx)? {
+        0 => s_expr,
+        threshold => SortMergeJoinSelector::create(metadata.clone(), threshold).optimize(&s_expr)?,
+    };
+    let threshold = ctx.get_settings().get_broadcast_join_threshold()?;
+    let s_expr = JoinDistributionSelector::create(metadata.clone(), threshold).optimize(&s_expr)?;
+    let s_expr = match ctx.get_settings().get_enable_shared_scan()? {
+        0 => s_expr,
+        _ => SharedScan::create(metadata).optimize(&s_expr)?,
+    };
     // TODO: enable cascades optimizer
     // let mut cascades = CascadesOptimizer::create(ctx);
     // cascades.optimize(s_expr)
 
     Ok(s_expr)
 }
+
+/// The estimated bytes of a join side above which the join is a sort-merge join, half of
+/// `max_memory_usage` by default as both sides are sorted at the same time.
+pub fn sort_merge_join_threshold(ctx: &QueryContext) -> Result<u64> {
+    let settings = ctx.get_settings();
+    Ok(match settings.get_sort_merge_join_threshold()? {
+        0 => settings.get_max_memory_usage()? / 2,
+        threshold => threshold,
+    })
+}
@@ -16,6 +16,7 @@ use common_exception::Result;
 
 use crate::sql::optimizer::rule::rule_implement_get::RuleImplementGet;
 use crate::sql::optimizer::rule::rule_implement_hash_join::RuleImplementHashJoin;
+use crate::sql::optimizer::rule::rule_normalize_aggregate::RuleNormalizeAggregate;
 use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::rule::RulePtr;
 
@@ -30,6 +31,7 @@ impl RuleFactory {
         match id {
             RuleID::ImplementGet => Ok(Box::new(RuleImplementGet::create())),
             RuleID::ImplementHashJoin => Ok(Box::new(RuleImplementHashJoin::create())),
+            RuleID::NormalizeAggregate => Ok(Box::new(RuleNormalizeAggregate::create())),
         }
     }
 }
@@ -19,6 +19,7 @@ use crate::sql::optimizer::SExpr;
 mod factory;
 mod rule_implement_get;
 mod rule_implement_hash_join;
+mod rule_normalize_aggregate;
 mod rule_set;
 mod transform_state;
 
@@ -40,6 +41,7 @@ pub trait Rule {
 pub enum RuleID {
     ImplementGet,
     ImplementHashJoin,
+    NormalizeAggregate,
 }
 
 impl RuleID {
@@ -47,6 +49,7 @@ impl RuleID {
         match self {
             RuleID::ImplementGet => "ImplementGet",
             RuleID::ImplementHashJoin => "ImplementHashJoin",
+            RuleID::NormalizeAggregate => "NormalizeAggregate",
         }
     }
 
@@ -57,6 +60,7 @@ impl RuleID {
         match self {
             RuleID::ImplementGet => 0,
             RuleID::ImplementHashJoin => 1,
+            RuleID::NormalizeAggregate => 2,
         }
     }
 }
@@ -18,6 +18,7 @@ use crate::sql::optimizer::rule::transform_state::TransformState;
 use crate::sql::optimizer::rule::Rule;
 use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinDistribution;
 use crate::sql::plans::LogicalInnerJoin;
 use crate::sql::plans::PatternPlan;
 use crate::sql::plans::PhysicalHashJoin;
@@ -68,6 +69,7 @@ impl Rule for RuleImplementHashJoin {
                 build_keys: logical_join.right_conditions,
                 probe_keys: logical_join.left_conditions,
                 join_type: logical_join.join_type,
+                distribution: JoinDistribution::Broadcast,
             }
             .into(),
             expression.children().to_vec(),
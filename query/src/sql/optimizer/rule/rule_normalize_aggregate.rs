@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::optimizer::rule::transform_state::TransformState;
+use crate::sql::optimizer::rule::Rule;
+use crate::sql::optimizer::rule::RuleID;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::AggregatePlan;
+use crate::sql::plans::DistinctPlan;
+use crate::sql::plans::PatternPlan;
+use crate::sql::plans::RelOp;
+
+/// Rewrite an aggregate without any aggregate function, such as `SELECT DISTINCT a, b FROM t`
+/// or `SELECT a, b FROM t GROUP BY a, b`, into a distinct, which can stream the rows out
+/// without waiting for the whole input.
+pub struct RuleNormalizeAggregate {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RuleNormalizeAggregate {
+    pub fn create() -> Self {
+        RuleNormalizeAggregate {
+            id: RuleID::NormalizeAggregate,
+            pattern: SExpr::create_unary(
+                PatternPlan {
+                    plan_type: RelOp::Aggregate,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RuleNormalizeAggregate {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, expression: &SExpr, state: &mut TransformState) -> Result<()> {
+        let aggregate: AggregatePlan = expression.plan().clone().try_into()?;
+        if !aggregate.aggregate_functions.is_empty() || aggregate.group_items.is_empty() {
+            return Ok(());
+        }
+
+        let result = SExpr::create(
+            DistinctPlan {
+                items: aggregate.group_items,
+                first_row: false,
+            }
+            .into(),
+            expression.children().to_vec(),
+            expression.original_group(),
+        );
+        state.add_result(result);
+
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
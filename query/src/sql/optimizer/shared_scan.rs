@@ -0,0 +1,153 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+
+use common_exception::Result;
+
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::CteScan;
+use crate::sql::plans::PhysicalScan;
+use crate::sql::plans::RelOperator;
+use crate::sql::IndexType;
+use crate::sql::MetadataRef;
+
+/// Scans of the same table which read the same data, i.e. all the columns of the table
+/// without table arguments and push-downs.
+struct SharedScanGroup {
+    cte_index: Option<IndexType>,
+    anchor: SExpr,
+    anchor_columns: Vec<IndexType>,
+    references: usize,
+}
+
+/// Rewrites the scans of a table referenced more than once in a query into `CteScan`s of the
+/// same CTE, whose anchor is the first scan, so that the table is scanned once and the
+/// blocks are teed to all the references.
+pub struct SharedScan {
+    metadata: MetadataRef,
+}
+
+impl SharedScan {
+    pub fn create(metadata: MetadataRef) -> Self {
+        SharedScan { metadata }
+    }
+
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut groups = HashMap::new();
+        self.collect(s_expr, &mut groups);
+        if groups.values().all(|group| group.references < 2) {
+            return Ok(s_expr.clone());
+        }
+
+        self.rewrite(s_expr, &mut groups)
+    }
+
+    // The key of the data read by a scan, None if the scan can't be shared.
+    fn scan_key(&self, scan: &PhysicalScan) -> Option<(String, String, String)> {
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index);
+        if table.source.tbl_args.is_some() || table.source.push_downs.is_some() {
+            return None;
+        }
+
+        let columns = metadata.columns_by_table_index(scan.table_index);
+        if columns.len() != scan.columns.len() {
+            return None;
+        }
+
+        Some((
+            table.catalog.clone(),
+            table.database.clone(),
+            table.name.clone(),
+        ))
+    }
+
+    // The output columns of a scan, in the order of the table columns.
+    fn scan_columns(&self, scan: &PhysicalScan) -> Vec<IndexType> {
+        self.metadata
+            .read()
+            .columns_by_table_index(scan.table_index)
+            .iter()
+            .map(|column| column.column_index)
+            .collect()
+    }
+
+    fn collect(
+        &self,
+        s_expr: &SExpr,
+        groups: &mut HashMap<(String, String, String), SharedScanGroup>,
+    ) {
+        if let RelOperator::PhysicalScan(scan) = s_expr.plan() {
+            if let Some(key) = self.scan_key(scan) {
+                groups
+                    .entry(key)
+                    .or_insert_with(|| SharedScanGroup {
+                        cte_index: None,
+                        anchor: s_expr.clone(),
+                        anchor_columns: self.scan_columns(scan),
+                        references: 0,
+                    })
+                    .references += 1;
+            }
+        }
+
+        for child in s_expr.children() {
+            self.collect(child, groups);
+        }
+    }
+
+    fn rewrite(
+        &self,
+        s_expr: &SExpr,
+        groups: &mut HashMap<(String, String, String), SharedScanGroup>,
+    ) -> Result<SExpr> {
+        if let RelOperator::PhysicalScan(scan) = s_expr.plan() {
+            if let Some(key) = self.scan_key(scan) {
+                if let Some(group) = groups.get_mut(&key) {
+                    if group.references > 1 {
+                        let cte_index = match group.cte_index {
+                            Some(cte_index) => cte_index,
+                            None => {
+                                let name = format!("{}.{}", key.1, key.2);
+                                let cte_index = self.metadata.write().add_cte(name);
+                                group.cte_index = Some(cte_index);
+                                cte_index
+                            }
+                        };
+
+                        let cte_scan = CteScan {
+                            cte_index,
+                            columns: self.scan_columns(scan),
+                            anchor_columns: group.anchor_columns.clone(),
+                            recursive_columns: vec![],
+                            recursive: false,
+                        };
+                        return Ok(SExpr::create_unary(cte_scan.into(), group.anchor.clone()));
+                    }
+                }
+            }
+        }
+
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.rewrite(child, groups))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SExpr::create(
+            s_expr.plan().clone(),
+            children,
+            s_expr.original_group(),
+        ))
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::TypeID;
+use common_exception::Result;
+
+use crate::sql::optimizer::CardinalityEstimator;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::PhysicalHashJoin;
+use crate::sql::plans::PhysicalSortMergeJoin;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::MetadataRef;
+
+/// Replace the hash joins whose both sides are estimated to take more than `threshold` bytes
+/// with sort-merge joins. The hash table of such a join can't be held in memory, while the
+/// sort-merge join sorts both sides with the external sort and only keeps the right rows of
+/// one key in memory.
+pub struct SortMergeJoinSelector {
+    estimator: CardinalityEstimator,
+    threshold: f64,
+}
+
+impl SortMergeJoinSelector {
+    pub fn create(metadata: MetadataRef, threshold: u64) -> Self {
+        SortMergeJoinSelector {
+            estimator: CardinalityEstimator::create(metadata),
+            threshold: threshold as f64,
+        }
+    }
+
+    pub fn optimize(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let children = s_expr
+            .children()
+            .iter()
+            .map(|child| self.optimize(child))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = match s_expr.plan() {
+            RelOperator::PhysicalHashJoin(join)
+                if Self::is_mergeable(join)
+                    && self.estimator.estimate_bytes(s_expr.child(0)?) > self.threshold
+                    && self.estimator.estimate_bytes(s_expr.child(1)?) > self.threshold =>
+            {
+                PhysicalSortMergeJoin {
+                    left_keys: join.probe_keys.clone(),
+                    right_keys: join.build_keys.clone(),
+                    join_type: join.join_type.clone(),
+                }
+                .into()
+            }
+            plan => plan.clone(),
+        };
+        Ok(SExpr::create(plan, children, s_expr.original_group()))
+    }
+
+    // Both sides are sorted by the key columns and merged by comparing the key values, so
+    // the keys must be columns of the same types with a total order. The rows of the left
+    // side are output in order, so the joins which output unmatched right rows are left
+    // to the hash join.
+    fn is_mergeable(join: &PhysicalHashJoin) -> bool {
+        if !matches!(
+            join.join_type,
+            JoinType::InnerJoin | JoinType::LeftJoin | JoinType::SemiJoin | JoinType::AntiJoin
+        ) || join.build_keys.is_empty()
+        {
+            return false;
+        }
+
+        join.build_keys
+            .iter()
+            .zip(join.probe_keys.iter())
+            .all(|(build_key, probe_key)| match (build_key, probe_key) {
+                (Scalar::BoundColumnRef(_), Scalar::BoundColumnRef(_)) => {
+                    build_key.data_type() == probe_key.data_type()
+                        && is_sortable(&build_key.data_type())
+                }
+                _ => false,
+            })
+    }
+}
+
+fn is_sortable(data_type: &DataTypeImpl) -> bool {
+    let type_id = remove_nullable(data_type).data_type_id();
+    type_id.is_integer()
+        || type_id.is_string()
+        || type_id.is_date_or_date_time()
+        || type_id == TypeID::Boolean
+}
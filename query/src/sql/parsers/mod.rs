@@ -15,6 +15,7 @@
 mod parser_call;
 mod parser_copy;
 mod parser_database;
+mod parser_delete;
 mod parser_explain;
 mod parser_insert;
 mod parser_kill;
@@ -23,8 +24,11 @@ mod parser_query;
 mod parser_set;
 mod parser_show;
 mod parser_stage;
+mod parser_system;
 mod parser_table;
+mod parser_transaction;
 mod parser_udf;
+mod parser_update;
 mod parser_use;
 mod parser_user;
 mod parser_view;
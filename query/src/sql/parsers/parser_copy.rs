@@ -17,8 +17,11 @@
 
 use std::collections::BTreeMap;
 
+use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
 use sqlparser::dialect::GenericDialect;
+use sqlparser::dialect::MySqlDialect;
 use sqlparser::keywords::Keyword;
 use sqlparser::parser::IsOptional;
 use sqlparser::parser::ParserError;
@@ -30,22 +33,30 @@ use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
     // copy into table from [?] ...
+    // copy into { @stage | 's3://mybucket/data/' } from { table | (query) } ...
     pub(crate) fn parse_copy(&mut self) -> Result<DfStatement<'a>, ParserError> {
         self.parser.expect_keyword(Keyword::INTO)?;
-        let mut location = match self.parser.next_token() {
-            Token::AtString(s) => {
-                format!("@{}", s)
-            }
+        let (mut location, unload) = match self.parser.next_token() {
+            Token::AtString(s) => (format!("@{}", s), true),
+            Token::SingleQuotedString(s) => (s, true),
             _ => {
                 self.parser.prev_token();
-                "".to_string()
+                ("".to_string(), false)
             }
         };
 
+        // The credentials of an external location may follow the location.
+        let mut credential_options = BTreeMap::default();
+        let mut encryption_options = BTreeMap::default();
+        if unload {
+            credential_options = self.parse_copy_credentials()?;
+            encryption_options = self.parse_copy_encryption()?;
+        }
+
         let name;
         let mut query = None;
         let mut columns = vec![];
-        if location.starts_with('@') {
+        if unload {
             self.parser.expect_keyword(Keyword::FROM)?;
             if self.parser.consume_token(&Token::LParen) {
                 query = Some(self.parser.parse_query()?);
@@ -68,22 +79,72 @@ impl<'a> DfParser<'a> {
             location = self.parser.parse_literal_string()?;
         }
 
-        // credentials=(aws_key_id='$AWS_ACCESS_KEY_ID' aws_secret_key='$AWS_SECRET_ACCESS_KEY')
-        let mut credential_options = BTreeMap::default();
-        if self.consume_token("CREDENTIALS") {
-            self.expect_token("=")?;
-            self.expect_token("(")?;
-            credential_options = self.parse_options()?;
-            self.expect_token(")")?;
+        self.parse_copy_options(
+            name,
+            columns,
+            location,
+            credential_options,
+            encryption_options,
+            query,
+            false,
+        )
+    }
+
+    // select ... into { @stage | 's3://mybucket/data/' } ...
+    //
+    // The query is parsed from its tokens before the top-level `INTO`, which is not
+    // accepted by the query parser.
+    pub(crate) fn parse_select_into(
+        &mut self,
+        into: usize,
+    ) -> Result<DfStatement<'a>, ParserError> {
+        let sql = (0..into)
+            .map(|_| self.parser.next_token().to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut inner_parser = DfParser::new_with_dialect(&sql, &MySqlDialect {})?;
+        let query = inner_parser.parser.parse_query()?;
+        if inner_parser.parser.peek_token() != Token::EOF {
+            return inner_parser.expected("end of query", inner_parser.parser.peek_token());
         }
 
-        // encryption=(master_key = '$MASER_KEY')
-        let mut encryption_options = BTreeMap::default();
-        if self.consume_token("ENCRYPTION") {
-            self.expect_token("=")?;
-            self.expect_token("(")?;
-            encryption_options = self.parse_options()?;
-            self.expect_token(")")?;
+        self.parser.expect_keyword(Keyword::INTO)?;
+        let location = match self.parser.next_token() {
+            Token::AtString(s) => format!("@{}", s),
+            Token::SingleQuotedString(s) => s,
+            unexpected => return self.expected("stage or location", unexpected),
+        };
+
+        self.parse_copy_options(
+            ObjectName(vec![]),
+            vec![],
+            location,
+            BTreeMap::default(),
+            BTreeMap::default(),
+            Some(query),
+            true,
+        )
+    }
+
+    // [ CREDENTIALS = (...) ] [ ENCRYPTION = (...) ] [ FILES = (...) ] ... [ VALIDATION_MODE = ... ]
+    #[allow(clippy::too_many_arguments)]
+    fn parse_copy_options(
+        &mut self,
+        name: ObjectName,
+        columns: Vec<Ident>,
+        location: String,
+        mut credential_options: BTreeMap<String, String>,
+        mut encryption_options: BTreeMap<String, String>,
+        query: Option<Query>,
+        spool: bool,
+    ) -> Result<DfStatement<'a>, ParserError> {
+        let credentials = self.parse_copy_credentials()?;
+        if !credentials.is_empty() {
+            credential_options = credentials;
+        }
+        let encryption = self.parse_copy_encryption()?;
+        if !encryption.is_empty() {
+            encryption_options = encryption;
         }
 
         // FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] )
@@ -115,6 +176,9 @@ impl<'a> DfParser<'a> {
          copyOptions ::=
          ON_ERROR = { CONTINUE | SKIP_FILE | SKIP_FILE_<num> | SKIP_FILE_<num>% | ABORT_STATEMENT }
          SIZE_LIMIT = <num>
+         MATCH_BY_COLUMN_NAME = CASE_SENSITIVE | CASE_INSENSITIVE | NONE
+         MAX_FILE_SIZE = <num>
+         ORDERED = TRUE | FALSE
         */
         let mut on_error = "".to_string();
         if self.consume_token("ON_ERROR") {
@@ -128,6 +192,24 @@ impl<'a> DfParser<'a> {
             size_limit = self.parse_value_or_ident()?;
         }
 
+        let mut match_by_column_name = "".to_string();
+        if self.consume_token("MATCH_BY_COLUMN_NAME") {
+            self.expect_token("=")?;
+            match_by_column_name = self.parse_value_or_ident()?;
+        }
+
+        let mut max_file_size = "".to_string();
+        if self.consume_token("MAX_FILE_SIZE") {
+            self.expect_token("=")?;
+            max_file_size = self.parse_value_or_ident()?;
+        }
+
+        let mut ordered = "".to_string();
+        if self.consume_token("ORDERED") {
+            self.expect_token("=")?;
+            ordered = self.parse_value_or_ident()?;
+        }
+
         // VALIDATION_MODE = RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS
         let mut validation_mode = "".to_string();
         if self.consume_token("VALIDATION_MODE") {
@@ -146,8 +228,36 @@ impl<'a> DfParser<'a> {
             pattern,
             on_error,
             size_limit,
+            match_by_column_name,
+            max_file_size,
+            ordered,
             validation_mode,
             query,
+            spool,
         }))
     }
+
+    // credentials=(aws_key_id='$AWS_ACCESS_KEY_ID' aws_secret_key='$AWS_SECRET_ACCESS_KEY')
+    fn parse_copy_credentials(&mut self) -> Result<BTreeMap<String, String>, ParserError> {
+        let mut credential_options = BTreeMap::default();
+        if self.consume_token("CREDENTIALS") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            credential_options = self.parse_options()?;
+            self.expect_token(")")?;
+        }
+        Ok(credential_options)
+    }
+
+    // encryption=(master_key = '$MASER_KEY')
+    fn parse_copy_encryption(&mut self) -> Result<BTreeMap<String, String>, ParserError> {
+        let mut encryption_options = BTreeMap::default();
+        if self.consume_token("ENCRYPTION") {
+            self.expect_token("=")?;
+            self.expect_token("(")?;
+            encryption_options = self.parse_options()?;
+            self.expect_token(")")?;
+        }
+        Ok(encryption_options)
+    }
 }
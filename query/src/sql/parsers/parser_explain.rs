@@ -27,6 +27,19 @@ impl<'a> DfParser<'a> {
     // Parse an SQL EXPLAIN statement.
     pub(crate) fn parse_explain(&mut self) -> Result<DfStatement<'a>, ParserError> {
         // Parser is at the token immediately after EXPLAIN
+        // Check for EXPLAIN (PRUNING)
+        if self.parser.consume_token(&Token::LParen) {
+            if self.consume_token("PRUNING") {
+                self.parser.expect_token(&Token::RParen)?;
+                let statement = Box::new(self.parse_query()?);
+                return Ok(DfStatement::Explain(DfExplain {
+                    typ: ExplainType::Pruning,
+                    statement,
+                }));
+            }
+            self.parser.prev_token();
+        }
+
         // Check for EXPLAIN VERBOSE
         let typ = match self.parser.peek_token() {
             Token::Word(w) => match w.value.to_uppercase().as_str() {
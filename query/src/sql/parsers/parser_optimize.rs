@@ -26,7 +26,7 @@ use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
     pub(crate) fn parse_optimize(&mut self) -> Result<DfStatement<'a>, ParserError> {
-        // syntax: "optimize TABLE t [purge | compact | all]",  default action is "purge"
+        // syntax: "optimize TABLE t [purge | compact | recluster | all]",  default action is "purge"
         self.expect_token("OPTIMIZE")?;
         self.parser.expect_keyword(Keyword::TABLE)?;
         let object_name = self.parser.parse_object_name()?;
@@ -38,9 +38,12 @@ impl<'a> DfParser<'a> {
                 Keyword::NoKeyword if w.value.to_uppercase().as_str() == "COMPACT" => {
                     Ok(Optimization::COMPACT)
                 }
-                _ => self.expected("one of PURGE, COMPACT, ALL", Token::Word(w)),
+                Keyword::NoKeyword if w.value.to_uppercase().as_str() == "RECLUSTER" => {
+                    Ok(Optimization::RECLUSTER)
+                }
+                _ => self.expected("one of PURGE, COMPACT, RECLUSTER, ALL", Token::Word(w)),
             },
-            t => self.expected("Nothing, or one of PURGE, COMPACT, ALL", t),
+            t => self.expected("Nothing, or one of PURGE, COMPACT, RECLUSTER, ALL", t),
         }?;
 
         Ok(DfStatement::OptimizeTable(DfOptimizeTable {
@@ -15,7 +15,9 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
 
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfParser;
@@ -24,9 +26,34 @@ use crate::sql::DfStatement;
 impl<'a> DfParser<'a> {
     // SELECT.
     pub(crate) fn parse_query(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        if let Some(into) = self.find_select_into() {
+            return self.parse_select_into(into);
+        }
+
         let native_query = self.parser.parse_query()?;
         Ok(DfStatement::Query(Box::new(DfQueryStatement::try_from(
             native_query,
         )?)))
     }
+
+    // The offset of the top-level `INTO` of `SELECT ... INTO { @stage | 'location' }`.
+    fn find_select_into(&self) -> Option<usize> {
+        let mut depth = 0;
+        let mut n = 0;
+        loop {
+            match self.parser.peek_nth_token(n) {
+                Token::EOF | Token::SemiColon => return None,
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::Word(w) if depth == 0 && w.keyword == Keyword::INTO => {
+                    return match self.parser.peek_nth_token(n + 1) {
+                        Token::AtString(_) | Token::SingleQuotedString(_) => Some(n),
+                        _ => None,
+                    };
+                }
+                _ => {}
+            }
+            n += 1;
+        }
+    }
 }
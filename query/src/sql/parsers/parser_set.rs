@@ -27,6 +27,8 @@ impl<'a> DfParser<'a> {
     // Set.
     pub(crate) fn parse_set(&mut self) -> Result<DfStatement<'a>, ParserError> {
         self.parser.next_token();
+        // sqlparser only knows SESSION and LOCAL, GLOBAL is consumed here.
+        let global = self.consume_token("GLOBAL");
         match self.parser.parse_set()? {
             Statement::SetVariable {
                 local,
@@ -35,6 +37,7 @@ impl<'a> DfParser<'a> {
                 value,
             } => Ok(DfStatement::SetVariable(DfSetVariable {
                 local,
+                global,
                 hivevar,
                 variable,
                 value,
@@ -26,6 +26,7 @@ use crate::sql::statements::DfShowKind;
 use crate::sql::statements::DfShowStages;
 use crate::sql::statements::DfShowTabStat;
 use crate::sql::statements::DfShowTables;
+use crate::sql::statements::DfShowVariables;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -73,6 +74,12 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::ShowStages(DfShowStages::create(kind)))
     }
 
+    // The values of the session are shown for both SESSION and GLOBAL variables.
+    pub(crate) fn parse_show_variables(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let kind = self.parse_show_kind()?;
+        Ok(DfStatement::ShowVariables(DfShowVariables::create(kind)))
+    }
+
     pub(crate) fn parse_show_kind(&mut self) -> Result<DfShowKind, ParserError> {
         let tok = self.parser.next_token();
         match &tok {
@@ -0,0 +1,39 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::ast::Ident;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+
+use crate::sql::statements::DfDropCache;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // Parse 'SYSTEM DROP CACHE <name>'.
+    pub(crate) fn parse_system(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        self.expect_token("SYSTEM")?;
+        self.expect_token("DROP")?;
+        self.expect_token("CACHE")?;
+
+        let token = self.parser.next_token();
+        match &token {
+            Token::Word(w) => Ok(DfStatement::DropCache(DfDropCache { name: w.to_ident() })),
+            Token::SingleQuotedString(s) => Ok(DfStatement::DropCache(DfDropCache {
+                name: Ident::with_quote('\'', s),
+            })),
+            _ => self.expected("cache name", token),
+        }
+    }
+}
@@ -15,6 +15,7 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use sqlparser::ast::ColumnDef;
@@ -27,6 +28,7 @@ use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Word;
 
 use crate::parser_err;
+use crate::sql::is_ddl_opt_key;
 use crate::sql::statements::AlterTableAction;
 use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfCreateTable;
@@ -39,6 +41,12 @@ use crate::sql::statements::DfTruncateTable;
 use crate::sql::statements::DfUnDropTable;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::sql::OPT_KEY_BROADCAST;
+use crate::sql::OPT_KEY_PARTITIONS;
+use crate::sql::OPT_KEY_PARTITION_BY;
+use crate::sql::OPT_KEY_REPLICA_OF;
+use crate::sql::OPT_KEY_TTL;
+use crate::storages::fuse::partitioning::PartitionDefs;
 
 impl<'a> DfParser<'a> {
     // Create table.
@@ -70,8 +78,75 @@ impl<'a> DfParser<'a> {
             self.parser.expect_token(&Token::RParen)?;
         }
 
+        // parse partition key, and the RANGE or LIST partitions
+        let mut partition_by = None;
+        let mut partitions = None;
+        if self
+            .parser
+            .parse_keywords(&[Keyword::PARTITION, Keyword::BY])
+        {
+            let is_range = self.consume_token("RANGE");
+            let is_list = !is_range && self.consume_token("LIST");
+            self.parser.expect_token(&Token::LParen)?;
+            partition_by = Some(self.parser.parse_identifier()?.value);
+            self.parser.expect_token(&Token::RParen)?;
+            if is_range || is_list {
+                partitions = Some(self.parse_partition_defs(is_range)?);
+            }
+        }
+
+        // parse replication source
+        let mut replica_of = None;
+        if self.consume_token("REPLICA") {
+            self.parser.expect_keyword(Keyword::OF)?;
+            replica_of = Some(self.parse_value_or_ident()?);
+        }
+
+        // parse ttl, both `TTL expr` and `TTL = 'expr'` are accepted
+        let mut ttl = None;
+        if self.consume_token("TTL") {
+            if self.parser.consume_token(&Token::Eq) {
+                ttl = Some(self.parse_value_or_ident()?);
+            } else {
+                ttl = Some(self.parser.parse_expr()?.to_string());
+            }
+        }
+
+        // parse broadcast, both `BROADCAST` and `BROADCAST = value` are accepted
+        let mut broadcast = None;
+        if self.consume_token("BROADCAST") {
+            if self.parser.consume_token(&Token::Eq) {
+                broadcast = Some(self.parse_value_or_ident()?);
+            } else {
+                broadcast = Some("true".to_string());
+            }
+        }
+
         // parse table options: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
-        let options = self.parse_options()?;
+        let mut options = self.parse_options()?;
+        if let Some(key) = options.keys().find(|k| is_ddl_opt_key(k)) {
+            return parser_err!(format!(
+                "table option {} is reserved, it is set by the PARTITION BY and REPLICA OF clauses",
+                key
+            ));
+        }
+        if let Some(broadcast) = broadcast {
+            options.insert(OPT_KEY_BROADCAST.to_string(), broadcast);
+        }
+        if let Some(ttl) = ttl {
+            options.insert(OPT_KEY_TTL.to_string(), ttl);
+        }
+        if let Some(partition_by) = partition_by {
+            options.insert(OPT_KEY_PARTITION_BY.to_string(), partition_by);
+        }
+        if let Some(partitions) = partitions {
+            let partitions = serde_json::to_string(&partitions)
+                .map_err(|e| ParserError::ParserError(e.to_string()))?;
+            options.insert(OPT_KEY_PARTITIONS.to_string(), partitions);
+        }
+        if let Some(replica_of) = replica_of {
+            options.insert(OPT_KEY_REPLICA_OF.to_string(), replica_of);
+        }
 
         let mut query = None;
         if let Token::Word(Word { keyword, .. }) = self.parser.peek_token() {
@@ -106,11 +181,13 @@ impl<'a> DfParser<'a> {
         let table_name = self.parser.parse_object_name()?;
 
         let all = self.parser.parse_keyword(Keyword::ALL);
+        let cascade = self.parser.parse_keyword(Keyword::CASCADE);
 
         let drop = DfDropTable {
             if_exists,
             name: table_name,
             all,
+            cascade,
         };
 
         Ok(DfStatement::DropTable(drop))
@@ -158,6 +235,72 @@ impl<'a> DfParser<'a> {
 
                     Ok(DfStatement::AlterTable(cluster_by))
                 }
+                Keyword::DROP => {
+                    self.parser.expect_keyword(Keyword::PARTITION)?;
+                    let partitions = self.parser.parse_comma_separated(Parser::parse_expr)?;
+
+                    let drop_partition = DfAlterTable {
+                        if_exists,
+                        table_name,
+                        action: AlterTableAction::DropPartition(partitions),
+                    };
+
+                    Ok(DfStatement::AlterTable(drop_partition))
+                }
+                Keyword::NoKeyword if w.value.to_uppercase().as_str() == "PROMOTE" => {
+                    let promote = DfAlterTable {
+                        if_exists,
+                        table_name,
+                        action: AlterTableAction::Promote,
+                    };
+
+                    Ok(DfStatement::AlterTable(promote))
+                }
+                _ if w.value.to_uppercase().as_str() == "EXPORT" => {
+                    self.expect_token("SNAPSHOT")?;
+                    self.parser.expect_keyword(Keyword::TO)?;
+                    let location = match self.parser.next_token() {
+                        Token::AtString(s) => format!("@{}", s),
+                        _ => {
+                            self.parser.prev_token();
+                            self.parser.parse_literal_string()?
+                        }
+                    };
+
+                    // credentials=(aws_key_id='$AWS_ACCESS_KEY_ID' aws_secret_key='$AWS_SECRET_ACCESS_KEY')
+                    let mut credential_options = BTreeMap::default();
+                    if self.consume_token("CREDENTIALS") {
+                        self.expect_token("=")?;
+                        self.expect_token("(")?;
+                        credential_options = self.parse_options()?;
+                        self.expect_token(")")?;
+                    }
+
+                    // encryption=(master_key = '$MASER_KEY')
+                    let mut encryption_options = BTreeMap::default();
+                    if self.consume_token("ENCRYPTION") {
+                        self.expect_token("=")?;
+                        self.expect_token("(")?;
+                        encryption_options = self.parse_options()?;
+                        self.expect_token(")")?;
+                    }
+
+                    self.expect_token("FORMAT")?;
+                    let format = self.parser.parse_identifier()?.value;
+
+                    let export = DfAlterTable {
+                        if_exists,
+                        table_name,
+                        action: AlterTableAction::ExportSnapshot {
+                            location,
+                            credential_options,
+                            encryption_options,
+                            format,
+                        },
+                    };
+
+                    Ok(DfStatement::AlterTable(export))
+                }
                 _ => Err(ParserError::ParserError(String::from(
                     "Unsupported alter table statement!",
                 ))),
@@ -284,6 +427,48 @@ impl<'a> DfParser<'a> {
     }
 
     /// Parses the set of valid formats
+    // Parse the RANGE partitions `(PARTITION p0 VALUES LESS THAN (10), ...)`, or the LIST
+    // partitions `(PARTITION p0 VALUES IN (1, 2), ...)`.
+    fn parse_partition_defs(&mut self, is_range: bool) -> Result<PartitionDefs, ParserError> {
+        let mut ranges = vec![];
+        let mut lists = vec![];
+        self.parser.expect_token(&Token::LParen)?;
+        loop {
+            self.parser.expect_keyword(Keyword::PARTITION)?;
+            let name = self.parser.parse_identifier()?.value;
+            self.parser.expect_keyword(Keyword::VALUES)?;
+            if is_range {
+                self.expect_token("LESS")?;
+                self.expect_token("THAN")?;
+                let parens = self.parser.consume_token(&Token::LParen);
+                if self.consume_token("MAXVALUE") {
+                    ranges.push((name, None));
+                } else {
+                    ranges.push((name, Some(self.parser.parse_expr()?.to_string())));
+                }
+                if parens {
+                    self.parser.expect_token(&Token::RParen)?;
+                }
+            } else {
+                self.parser.expect_keyword(Keyword::IN)?;
+                self.parser.expect_token(&Token::LParen)?;
+                let values = self.parser.parse_comma_separated(Parser::parse_expr)?;
+                self.parser.expect_token(&Token::RParen)?;
+                lists.push((name, values.iter().map(ToString::to_string).collect()));
+            }
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        self.parser.expect_token(&Token::RParen)?;
+
+        match is_range {
+            true => Ok(PartitionDefs::Range(ranges)),
+            false => Ok(PartitionDefs::List(lists)),
+        }
+    }
+
     fn parse_table_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_planners::TransactionAction;
+use sqlparser::parser::ParserError;
+
+use crate::sql::statements::DfTransaction;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+impl<'a> DfParser<'a> {
+    // Parse 'BEGIN [WORK | TRANSACTION]', 'START TRANSACTION', 'COMMIT [WORK]' or 'ROLLBACK [WORK]'.
+    pub(crate) fn parse_transaction(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let action = if self.consume_token("BEGIN") {
+            if !self.consume_token("WORK") {
+                self.consume_token("TRANSACTION");
+            }
+            TransactionAction::Begin
+        } else if self.consume_token("START") {
+            self.expect_token("TRANSACTION")?;
+            TransactionAction::Begin
+        } else if self.consume_token("COMMIT") {
+            self.consume_token("WORK");
+            TransactionAction::Commit
+        } else {
+            self.expect_token("ROLLBACK")?;
+            self.consume_token("WORK");
+            TransactionAction::Rollback
+        };
+
+        Ok(DfStatement::Transaction(DfTransaction { action }))
+    }
+}
@@ -15,6 +15,7 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use common_meta_types::UDFLanguage;
 use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
@@ -28,8 +29,10 @@ use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
 impl<'a> DfParser<'a> {
-    fn parse_udf_parameters(&mut self) -> Result<Vec<String>, ParserError> {
+    // Parse `(p1 [type1], p2 [type2], ...)`, the types are only allowed for WASM functions.
+    fn parse_udf_parameters(&mut self) -> Result<(Vec<String>, Vec<String>), ParserError> {
         let mut params = vec![];
+        let mut types = vec![];
         let mut found_right_paren = false;
         let mut expect_next_param = false;
 
@@ -71,6 +74,11 @@ impl<'a> DfParser<'a> {
 
                     expect_next_param = false;
                     params.push(value);
+
+                    if let Token::Word(Word { value, .. }) = self.parser.peek_token() {
+                        self.parser.next_token();
+                        types.push(value);
+                    }
                 }
                 Token::Comma => {
                     expect_next_param = true;
@@ -87,8 +95,11 @@ impl<'a> DfParser<'a> {
         if expect_next_param {
             return parser_err!("Found a redundant `,` in the parameters");
         }
+        if !types.is_empty() && types.len() != params.len() {
+            return parser_err!("Either all or none of the parameters should have a type");
+        }
 
-        Ok(params)
+        Ok((params, types))
     }
 
     fn parse_udf_definition_expr(&mut self, until_token: Vec<&str>) -> Result<String, ParserError> {
@@ -116,8 +127,44 @@ impl<'a> DfParser<'a> {
         self.parser.expect_keyword(Keyword::AS)?;
 
         let desc_token = "DESC";
-        let parameters = self.parse_udf_parameters()?;
-        let definition = self.parse_udf_definition_expr(vec![desc_token])?;
+        let (parameters, arg_types) = self.parse_udf_parameters()?;
+
+        // CREATE FUNCTION name AS (p1 type1, ...) RETURNS type LANGUAGE WASM AS '<module>'
+        // CREATE FUNCTION name AS (p1 type1, ...) RETURNS type LANGUAGE PYTHON HANDLER = '<handler>' ADDRESS = '<address>'
+        let mut handler = String::new();
+        let mut address = String::new();
+        let (language, return_type, definition) = if self.consume_token("RETURNS") {
+            if arg_types.len() != parameters.len() {
+                return parser_err!("Parameters of WASM or PYTHON function must have types");
+            }
+            let return_type = self.parser.parse_identifier()?.value;
+            if !self.consume_token("LANGUAGE") {
+                return parser_err!(format!(
+                    "Expected LANGUAGE, found: {}",
+                    self.parser.peek_token()
+                ));
+            }
+            if self.consume_token("WASM") {
+                self.parser.expect_keyword(Keyword::AS)?;
+                let module = self.parser.parse_literal_string()?;
+                (UDFLanguage::Wasm, return_type, module)
+            } else if self.consume_token("PYTHON") {
+                handler = self.parse_udf_option("HANDLER")?;
+                address = self.parse_udf_option("ADDRESS")?;
+                (UDFLanguage::Python, return_type, String::new())
+            } else {
+                return parser_err!(format!(
+                    "Expected WASM or PYTHON after LANGUAGE, found: {}",
+                    self.parser.peek_token()
+                ));
+            }
+        } else {
+            if !arg_types.is_empty() {
+                return parser_err!("Parameter types are only allowed in WASM or PYTHON function");
+            }
+            let definition = self.parse_udf_definition_expr(vec![desc_token])?;
+            (UDFLanguage::Sql, String::new(), definition)
+        };
 
         let description = self.parse_udf_desc(desc_token)?;
         let create_udf = DfCreateUDF {
@@ -126,6 +173,11 @@ impl<'a> DfParser<'a> {
             parameters,
             definition,
             description,
+            language,
+            arg_types,
+            return_type,
+            handler,
+            address,
         };
 
         Ok(DfStatement::CreateUDF(create_udf))
@@ -137,7 +189,10 @@ impl<'a> DfParser<'a> {
         self.parser.expect_token(&as_token)?;
 
         let desc_token = "DESC";
-        let parameters = self.parse_udf_parameters()?;
+        let (parameters, arg_types) = self.parse_udf_parameters()?;
+        if !arg_types.is_empty() {
+            return parser_err!("Parameter types are only allowed in WASM or PYTHON function");
+        }
         let definition = self.parse_udf_definition_expr(vec![desc_token])?;
 
         let description = self.parse_udf_desc(desc_token)?;
@@ -162,6 +217,19 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropUDF(drop_udf))
     }
 
+    // Parse `<name> = '<value>'`.
+    fn parse_udf_option(&mut self, name: &str) -> Result<String, ParserError> {
+        if !self.consume_token(name) {
+            return parser_err!(format!(
+                "Expected {}, found: {}",
+                name,
+                self.parser.peek_token()
+            ));
+        }
+        self.parser.expect_token(&Token::Eq)?;
+        self.parser.parse_literal_string()
+    }
+
     fn parse_udf_desc(&mut self, desc_token: &str) -> Result<String, ParserError> {
         if self.consume_token(desc_token) {
             self.parser.expect_token(&Token::Eq)?;
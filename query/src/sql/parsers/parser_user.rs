@@ -135,6 +135,7 @@ impl<'a> DfParser<'a> {
 
     /// GRANT privs TO [USER] 'name'@'host'
     /// GRANT privs TO ROLE 'name'
+    /// GRANT privs ON FUTURE TABLES IN DATABASE db TO ROLE 'name'
     pub(crate) fn parse_grant_privilege(&mut self) -> Result<DfStatement<'a>, ParserError> {
         let privileges = self.parse_privileges()?;
         if !self.parser.parse_keyword(Keyword::ON) {
@@ -245,6 +246,22 @@ impl<'a> DfParser<'a> {
     /// Parse a possibly qualified, possibly quoted identifier or wild card, e.g.
     /// `*` or `myschema`.*. The sub string pattern like "db0%" is not in planned.
     fn parse_grant_object(&mut self) -> Result<DfGrantObject, ParserError> {
+        // FUTURE TABLES IN DATABASE db
+        if self.consume_token("FUTURE") {
+            if self.consume_token("TABLES") {
+                if !self.parser.parse_keyword(Keyword::IN) {
+                    return self.expected("keyword IN", self.parser.peek_token());
+                }
+                if !self.parser.parse_keyword(Keyword::DATABASE) {
+                    return self.expected("keyword DATABASE", self.parser.peek_token());
+                }
+                let database = self.parser.parse_identifier()?;
+                return Ok(DfGrantObject::FutureTables(Some(database.value)));
+            }
+            // A table named `future`
+            self.parser.prev_token();
+        }
+
         let chunk0 = self.parse_grant_object_pattern_chunk()?;
         // "*" as current db or "table" with current db
         if !self.consume_token(".") {
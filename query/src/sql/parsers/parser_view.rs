@@ -15,14 +15,20 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use sqlparser::ast::Query;
+use sqlparser::dialect::MySqlDialect;
 use sqlparser::keywords::Keyword;
+use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Tokenizer;
 
 use crate::parser_err;
 use crate::sql::statements::DfAlterView;
 use crate::sql::statements::DfCreateView;
 use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfShowCreateView;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
 
@@ -38,7 +44,7 @@ impl<'a> DfParser<'a> {
         if self.consume_token("AS") {
             let native_query = self.parser.parse_query()?;
             let query = DfQueryStatement::try_from(native_query.clone())?;
-            let subquery = native_query.to_string();
+            let subquery = self.original_query_text(&native_query);
             let create = DfCreateView {
                 if_not_exists,
                 name,
@@ -68,7 +74,7 @@ impl<'a> DfParser<'a> {
         if self.consume_token("AS") {
             let native_query = self.parser.parse_query()?;
             let query = DfQueryStatement::try_from(native_query.clone())?;
-            let subquery = native_query.to_string();
+            let subquery = self.original_query_text(&native_query);
             let alter = DfAlterView {
                 name,
                 subquery,
@@ -79,4 +85,51 @@ impl<'a> DfParser<'a> {
             parser_err!("need `AS` after VIEW NAME")
         }
     }
+
+    pub(crate) fn parse_show_create_view(&mut self) -> Result<DfStatement<'a>, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        Ok(DfStatement::ShowCreateView(DfShowCreateView { name }))
+    }
+
+    // The query of a view is stored as it was written, so that SHOW CREATE VIEW gives it back.
+    // It's the text following an `AS` that parses to the same query, up to a `;` or the end.
+    // If there is no such text, e.g. the SQL is written in another dialect, it's normalized.
+    fn original_query_text(&self, query: &Query) -> String {
+        let sql = self.sql;
+        let bytes = sql.as_bytes();
+        let is_ident = |i: usize| bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_';
+
+        for start in 0..bytes.len().saturating_sub(1) {
+            let is_as = bytes[start].eq_ignore_ascii_case(&b'a')
+                && bytes[start + 1].eq_ignore_ascii_case(&b's')
+                && (start == 0 || !is_ident(start - 1))
+                && (start + 2 == bytes.len() || !is_ident(start + 2));
+            if !is_as {
+                continue;
+            }
+
+            let ends = (start + 2..bytes.len())
+                .filter(|i| bytes[*i] == b';')
+                .chain(std::iter::once(bytes.len()));
+            for end in ends {
+                let text = sql[start + 2..end].trim();
+                if Self::parse_single_query(text).as_ref() == Some(query) {
+                    return text.to_string();
+                }
+            }
+        }
+
+        query.to_string()
+    }
+
+    fn parse_single_query(text: &str) -> Option<Query> {
+        let dialect = MySqlDialect {};
+        let (tokens, position_map) = Tokenizer::new(&dialect, text).tokenize().ok()?;
+        let mut parser = Parser::new(tokens, position_map, &dialect);
+        let query = parser.parse_query().ok()?;
+        match parser.peek_token() {
+            Token::EOF => Some(query),
+            _ => None,
+        }
+    }
 }
@@ -44,6 +44,8 @@ use crate::sql::plans::OrExpr;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarExpr;
 use crate::sql::plans::ScalarItem;
+use crate::sql::plans::WindowFunction;
+use crate::sql::plans::WindowOrderBy;
 use crate::sql::BindContext;
 
 #[derive(Default, Clone, PartialEq, Debug)]
@@ -135,6 +137,37 @@ impl<'a> AggregateRewriter<'a> {
             Scalar::SubqueryExpr(_) => Ok(scalar.clone()),
 
             Scalar::AggregateFunction(agg_func) => self.replace_aggregate_function(agg_func),
+
+            // Aggregate functions can be used as the arguments or keys of window functions,
+            // e.g. `SUM(COUNT(*)) OVER (PARTITION BY a)`.
+            Scalar::WindowFunction(window) => Ok(WindowFunction {
+                display_name: window.display_name.clone(),
+                func: window.func.clone(),
+                args: window
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?,
+                partition_by: window
+                    .partition_by
+                    .iter()
+                    .map(|item| self.visit(item))
+                    .collect::<Result<Vec<_>>>()?,
+                order_by: window
+                    .order_by
+                    .iter()
+                    .map(|item| {
+                        Ok(WindowOrderBy {
+                            expr: self.visit(&item.expr)?,
+                            asc: item.asc,
+                            nulls_first: item.nulls_first,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                frame: window.frame.clone(),
+                return_type: window.return_type.clone(),
+            }
+            .into()),
         }
     }
 
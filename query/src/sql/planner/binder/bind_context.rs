@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_ast::ast::Identifier;
 use common_ast::ast::TableAlias;
 use common_ast::parser::error::DisplayError as _;
@@ -20,6 +22,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::AggregateInfo;
+use super::CteInfo;
+use super::WindowInfo;
 use crate::sql::common::IndexType;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -48,10 +52,19 @@ pub struct BindContext {
 
     pub aggregate_info: AggregateInfo,
 
+    pub window_info: WindowInfo,
+
     /// True if there is aggregation in current context, which means
     /// non-grouping columns cannot be referenced outside aggregation
     /// functions, otherwise a grouping error will be raised.
     pub in_grouping: bool,
+
+    /// Common table expressions visible in current context, inherited from the parent.
+    pub ctes_map: HashMap<String, CteInfo>,
+
+    /// Names of the columns in the result set if they differ from the column names,
+    /// e.g. the case is preserved by the setting `column_name_case`.
+    pub display_names: Vec<String>,
 }
 
 impl BindContext {
@@ -61,10 +74,13 @@ impl BindContext {
 
     pub fn with_parent(parent: Box<BindContext>) -> Self {
         BindContext {
+            ctes_map: parent.ctes_map.clone(),
             parent: Some(parent),
             columns: vec![],
             aggregate_info: Default::default(),
+            window_info: Default::default(),
             in_grouping: false,
+            display_names: vec![],
         }
     }
 
@@ -85,6 +101,7 @@ impl BindContext {
     /// Apply table alias like `SELECT * FROM t AS t1(a, b, c)`.
     /// This method will rename column bindings according to table alias.
     pub fn apply_table_alias(&mut self, alias: &TableAlias) -> Result<()> {
+        self.display_names.clear();
         for column in self.columns.iter_mut() {
             column.table_name = Some(alias.name.to_string());
         }
@@ -171,7 +188,11 @@ impl BindContext {
     pub fn result_columns(&self) -> Vec<(IndexType, String)> {
         self.columns
             .iter()
-            .map(|col| (col.index, col.column_name.clone()))
+            .enumerate()
+            .map(|(i, col)| match self.display_names.get(i) {
+                Some(name) => (col.index, name.clone()),
+                None => (col.index, col.column_name.clone()),
+            })
             .collect()
     }
 }
@@ -0,0 +1,384 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_recursion::async_recursion;
+use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::With;
+use common_ast::parser::error::Backtrace;
+use common_ast::parser::error::DisplayError;
+use common_ast::parser::parse_query;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_datavalues::DataTypeImpl;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::binder::Binder;
+use crate::sql::binder::ColumnBinding;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::CteScan;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::ScalarItem;
+use crate::sql::plans::WorkingTableScan;
+use crate::sql::BindContext;
+use crate::sql::IndexType;
+
+#[derive(Clone, Debug)]
+pub struct CteInfo {
+    /// Index of the CTE in `Metadata`
+    pub index: IndexType,
+    /// Column names of `t(a, b) AS (...)`, empty if not specified
+    pub columns: Vec<String>,
+    /// The SQL text of the statement defining the CTE. The AST borrows the text, so it
+    /// can't be kept in the context, and the query is parsed again on every reference.
+    pub sql: Arc<str>,
+    /// The byte range of the CTE query in `sql`
+    pub span: Range<usize>,
+    /// The context the CTE is defined in, with the CTEs declared before it, the query
+    /// of the CTE is bound in it
+    pub bind_context: Box<BindContext>,
+    /// True if the CTE is defined in `WITH RECURSIVE`
+    pub recursive: bool,
+    /// Column types of the working table, only set in the recursive term of the CTE
+    pub working_table: Option<Vec<DataTypeImpl>>,
+}
+
+impl<'a> Binder {
+    /// Register the CTEs of a `WITH` clause, returns the context to bind the query with.
+    pub(super) fn bind_with(
+        &mut self,
+        bind_context: &BindContext,
+        with: &With<'a>,
+    ) -> Result<BindContext> {
+        let mut bind_context = bind_context.clone();
+        let mut names = HashSet::new();
+        for cte in with.ctes.iter() {
+            let name = cte.alias.name.name.to_lowercase();
+            if !names.insert(name.clone()) {
+                return Err(ErrorCode::SemanticError(
+                    cte.alias
+                        .name
+                        .span
+                        .display_error(format!("duplicate CTE name {name}")),
+                ));
+            }
+
+            let (first, last) = match (cte.query.span.first(), cte.query.span.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return Err(ErrorCode::LogicalError(format!("Invalid CTE {name}"))),
+            };
+
+            let index = self.metadata.write().add_cte(name.clone());
+            let cte_info = CteInfo {
+                index,
+                columns: cte
+                    .alias
+                    .columns
+                    .iter()
+                    .map(|column| column.name.to_lowercase())
+                    .collect(),
+                sql: Arc::from(first.source),
+                span: first.span.start..last.span.end,
+                bind_context: Box::new(bind_context.clone()),
+                recursive: with.recursive,
+                working_table: None,
+            };
+            bind_context.ctes_map.insert(name, cte_info);
+        }
+
+        Ok(bind_context)
+    }
+
+    /// Bind a reference to a CTE. The CTE is inlined like a subquery, unless
+    /// `enable_materialized_cte` is set or the CTE is recursive, in which case it is read
+    /// with a `CteScan`.
+    #[async_recursion]
+    pub(super) async fn bind_cte(
+        &mut self,
+        bind_context: &BindContext,
+        name: &str,
+        cte: &CteInfo,
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, BindContext)> {
+        if let Some(data_types) = &cte.working_table {
+            let columns = cte
+                .columns
+                .iter()
+                .zip(data_types.iter())
+                .map(|(column, data_type)| {
+                    self.create_column_binding(
+                        Some(name.to_string()),
+                        column.clone(),
+                        data_type.clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let scan = WorkingTableScan {
+                cte_index: cte.index,
+                columns: columns.iter().map(|column| column.index).collect(),
+            };
+            let output_context = cte_output_context(bind_context, columns, alias)?;
+            return Ok((SExpr::create_leaf(scan.into()), output_context));
+        }
+
+        // The query of the CTE can't see the columns of the query referencing it, nor the
+        // CTEs declared after it. It can't reference itself either, except for the recursive
+        // term of a recursive CTE.
+        let cte_context = cte.bind_context.as_ref();
+
+        // Tokenize the whole statement, so that the spans in the errors point into it.
+        let mut tokens = tokenize_sql(&cte.sql)?
+            .into_iter()
+            .filter(|token| {
+                token.kind == TokenKind::EOI
+                    || (token.span.start >= cte.span.start && token.span.end <= cte.span.end)
+            })
+            .collect::<Vec<_>>();
+        if let Some(eoi) = tokens.last_mut() {
+            eoi.span = cte.span.end..cte.span.end;
+        }
+        let backtrace = Backtrace::new();
+        let query = parse_query(&tokens, &backtrace)?;
+
+        if let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all,
+            left,
+            right,
+        } = &query.body
+        {
+            if cte.recursive {
+                return self
+                    .bind_recursive_cte(
+                        cte_context,
+                        name,
+                        cte,
+                        &query,
+                        *all,
+                        (left.as_ref(), right.as_ref()),
+                        alias,
+                    )
+                    .await;
+            }
+        }
+
+        let (s_expr, query_context) = self.bind_query(cte_context, &query).await?;
+        let anchor_columns = rename_cte_columns(name, cte, &query_context.columns)?;
+        if self.ctx.get_settings().get_enable_materialized_cte()? == 0 {
+            let output_context = cte_output_context(bind_context, anchor_columns, alias)?;
+            return Ok((s_expr, output_context));
+        }
+
+        let columns = self.create_cte_columns(name, &anchor_columns);
+        let scan = CteScan {
+            cte_index: cte.index,
+            columns: columns.iter().map(|column| column.index).collect(),
+            anchor_columns: anchor_columns.iter().map(|column| column.index).collect(),
+            recursive_columns: vec![],
+            recursive: false,
+        };
+        let output_context = cte_output_context(bind_context, columns, alias)?;
+        Ok((SExpr::create_unary(scan.into(), s_expr), output_context))
+    }
+
+    /// Bind a recursive CTE like `SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 10`.
+    /// The anchor decides the columns of the CTE, the recursive term reads the rows returned
+    /// by its last evaluation from the working table, and is cast to the column types.
+    #[allow(clippy::too_many_arguments)]
+    async fn bind_recursive_cte(
+        &mut self,
+        bind_context: &BindContext,
+        name: &str,
+        cte: &CteInfo,
+        query: &Query<'a>,
+        all: bool,
+        terms: (&SetExpr<'a>, &SetExpr<'a>),
+        alias: &Option<TableAlias<'a>>,
+    ) -> Result<(SExpr, BindContext)> {
+        if !all {
+            return Err(ErrorCode::UnImplement(
+                "recursive CTE only supports UNION ALL",
+            ));
+        }
+        if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            return Err(ErrorCode::UnImplement(
+                "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE",
+            ));
+        }
+        let (anchor, recursive): (&SelectStmt, &SelectStmt) = match terms {
+            (SetExpr::Select(anchor), SetExpr::Select(recursive)) => (anchor, recursive),
+            _ => {
+                return Err(ErrorCode::UnImplement(
+                    "recursive CTE must be the UNION ALL of two SELECTs",
+                ))
+            }
+        };
+
+        let (anchor_expr, anchor_context) =
+            self.bind_select_stmt(bind_context, anchor, &[]).await?;
+        let anchor_columns = rename_cte_columns(name, cte, &anchor_context.columns)?;
+
+        let mut recursive_context = bind_context.clone();
+        recursive_context
+            .ctes_map
+            .insert(name.to_string(), CteInfo {
+                columns: anchor_columns
+                    .iter()
+                    .map(|column| column.column_name.clone())
+                    .collect(),
+                working_table: Some(
+                    anchor_columns
+                        .iter()
+                        .map(|column| column.data_type.clone())
+                        .collect(),
+                ),
+                ..cte.clone()
+            });
+        let (mut recursive_expr, recursive_context) = self
+            .bind_select_stmt(&recursive_context, recursive, &[])
+            .await?;
+        if recursive_context.columns.len() != anchor_columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "the recursive term of CTE {name} returns {} columns, but the anchor returns {}",
+                recursive_context.columns.len(),
+                anchor_columns.len()
+            )));
+        }
+
+        let mut items = vec![];
+        let mut recursive_columns = Vec::with_capacity(anchor_columns.len());
+        for (column, anchor_column) in recursive_context.columns.iter().zip(anchor_columns.iter()) {
+            if column.data_type == anchor_column.data_type {
+                recursive_columns.push(column.index);
+                continue;
+            }
+
+            let index = self.metadata.write().add_column(
+                anchor_column.column_name.clone(),
+                anchor_column.data_type.clone(),
+                None,
+            );
+            items.push(ScalarItem {
+                scalar: CastExpr {
+                    argument: Box::new(
+                        BoundColumnRef {
+                            column: column.clone(),
+                        }
+                        .into(),
+                    ),
+                    from_type: column.data_type.clone(),
+                    target_type: anchor_column.data_type.clone(),
+                }
+                .into(),
+                index,
+            });
+            recursive_columns.push(index);
+        }
+        if !items.is_empty() {
+            recursive_expr = SExpr::create_unary(EvalScalar { items }.into(), recursive_expr);
+        }
+
+        let columns = self.create_cte_columns(name, &anchor_columns);
+        let scan = CteScan {
+            cte_index: cte.index,
+            columns: columns.iter().map(|column| column.index).collect(),
+            anchor_columns: anchor_columns.iter().map(|column| column.index).collect(),
+            recursive_columns,
+            recursive: reads_working_table(&recursive_expr, cte.index),
+        };
+        let output_context = cte_output_context(bind_context, columns, alias)?;
+        Ok((
+            SExpr::create_binary(scan.into(), anchor_expr, recursive_expr),
+            output_context,
+        ))
+    }
+
+    fn create_cte_columns(&mut self, name: &str, columns: &[ColumnBinding]) -> Vec<ColumnBinding> {
+        columns
+            .iter()
+            .map(|column| {
+                self.create_column_binding(
+                    Some(name.to_string()),
+                    column.column_name.clone(),
+                    column.data_type.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Apply the column names of `t(a, b) AS (...)` to the output columns of the CTE query.
+fn rename_cte_columns(
+    name: &str,
+    cte: &CteInfo,
+    columns: &[ColumnBinding],
+) -> Result<Vec<ColumnBinding>> {
+    if !cte.columns.is_empty() && cte.columns.len() != columns.len() {
+        return Err(ErrorCode::SemanticError(format!(
+            "CTE {name} has {} columns available but {} columns specified",
+            columns.len(),
+            cte.columns.len()
+        )));
+    }
+
+    Ok(columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| ColumnBinding {
+            table_name: Some(name.to_string()),
+            column_name: cte
+                .columns
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| column.column_name.clone()),
+            ..column.clone()
+        })
+        .collect())
+}
+
+fn cte_output_context(
+    bind_context: &BindContext,
+    columns: Vec<ColumnBinding>,
+    alias: &Option<TableAlias>,
+) -> Result<BindContext> {
+    let mut output_context = BindContext::with_parent(Box::new(bind_context.clone()));
+    for column in columns {
+        output_context.add_column_binding(column);
+    }
+    if let Some(alias) = alias {
+        output_context.apply_table_alias(alias)?;
+    }
+    Ok(output_context)
+}
+
+fn reads_working_table(s_expr: &SExpr, cte_index: IndexType) -> bool {
+    match s_expr.plan() {
+        RelOperator::WorkingTableScan(scan) => scan.cte_index == cte_index,
+        _ => s_expr
+            .children()
+            .iter()
+            .any(|child| reads_working_table(child, cte_index)),
+    }
+}
@@ -31,6 +31,7 @@ use common_planners::CreateTablePlan;
 
 use crate::sql::binder::scalar::ScalarBinder;
 use crate::sql::binder::Binder;
+use crate::sql::is_ddl_opt_key;
 use crate::sql::is_reserved_opt_key;
 use crate::sql::plans::Plan;
 use crate::sql::BindContext;
@@ -91,7 +92,7 @@ impl<'a> Binder {
         key: String,
         value: String,
     ) -> Result<()> {
-        if is_reserved_opt_key(&key) {
+        if is_reserved_opt_key(&key) || is_ddl_opt_key(&key) {
             Err(ErrorCode::BadOption(format!("the following table options are reserved, please do not specify them in the CREATE TABLE statement: {}",
                         key
                         )))
@@ -0,0 +1,325 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::binder::scalar_common::split_equivalent_predicate;
+use crate::sql::binder::subquery::SubqueryRewriter;
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::AggregatePlan;
+use crate::sql::plans::FilterPlan;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::LogicalInnerJoin;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::sql::plans::SubqueryExpr;
+use crate::sql::plans::SubqueryType;
+use crate::sql::IndexType;
+
+/// Aggregate functions which return NULL for empty input, a scalar subquery with these
+/// functions returns the same result with a left join when there is no matched row.
+/// Notice that `COUNT` is not in this list since it returns 0 for empty input.
+const NULL_ON_EMPTY_AGGREGATES: &[&str] = &["min", "max", "sum", "avg", "any"];
+
+/// Equi-predicates that correlate a subquery with its outer query, e.g. `t.a = t1.a`
+/// in `SELECT * FROM t WHERE EXISTS (SELECT * FROM t1 WHERE t.a = t1.a)`.
+struct CorrelatedPredicates {
+    /// Conditions that only reference outer columns
+    outer_conditions: Vec<Scalar>,
+    /// Conditions that only reference the columns of subquery
+    inner_conditions: Vec<Scalar>,
+    /// Predicates that don't reference any outer column
+    other_predicates: Vec<Scalar>,
+}
+
+impl SubqueryRewriter {
+    /// Try to decorrelate a correlated `EXISTS` or `NOT EXISTS` predicate of `Filter` into a
+    /// semi join or an anti join with `left`. For example:
+    ///
+    ///   `SELECT * FROM t WHERE EXISTS (SELECT * FROM t1 WHERE t.a = t1.a AND t1.b > 1)`
+    ///
+    /// will be rewritten into:
+    ///
+    ///   `t SEMI JOIN (SELECT * FROM t1 WHERE t1.b > 1) ON t.a = t1.a`
+    ///
+    /// Returns `None` if the predicate can't be decorrelated, then it will be rewritten into
+    /// `CrossApply` instead.
+    pub(super) fn try_decorrelate_exists(
+        &mut self,
+        predicate: &Scalar,
+        left: &SExpr,
+    ) -> Result<Option<SExpr>> {
+        let (subquery, join_type) = match predicate {
+            Scalar::SubqueryExpr(subquery) if subquery.typ == SubqueryType::Exists => {
+                (subquery, JoinType::SemiJoin)
+            }
+            Scalar::SubqueryExpr(subquery) if subquery.typ == SubqueryType::NotExists => {
+                (subquery, JoinType::AntiJoin)
+            }
+            Scalar::FunctionCall(func) if func.func_name == "not" && func.arguments.len() == 1 => {
+                match &func.arguments[0] {
+                    Scalar::SubqueryExpr(subquery) if subquery.typ == SubqueryType::Exists => {
+                        (subquery, JoinType::AntiJoin)
+                    }
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+        if subquery.outer_columns.is_empty() {
+            return Ok(None);
+        }
+
+        // The output of EXISTS subquery doesn't matter, so we can skip the projections.
+        let mut s_expr = self.rewrite(&subquery.subquery)?;
+        while matches!(
+            s_expr.plan(),
+            RelOperator::Project(_) | RelOperator::EvalScalar(_)
+        ) {
+            s_expr = s_expr.child(0)?.clone();
+        }
+
+        let filter = match s_expr.plan() {
+            RelOperator::Filter(filter) => filter,
+            _ => return Ok(None),
+        };
+        let filter_input = s_expr.child(0)?;
+        let predicates = match Self::extract_correlated_predicates(
+            &filter.predicates,
+            &subquery.outer_columns,
+            left,
+            filter_input,
+        )? {
+            Some(predicates) => predicates,
+            None => return Ok(None),
+        };
+
+        let right = Self::build_filter(predicates.other_predicates, filter_input.clone());
+        let join = LogicalInnerJoin {
+            left_conditions: predicates.outer_conditions,
+            right_conditions: predicates.inner_conditions,
+            join_type,
+        };
+        Ok(Some(SExpr::create_binary(join.into(), left.clone(), right)))
+    }
+
+    /// Try to decorrelate a correlated scalar subquery with aggregation into a left join
+    /// with `left`. For example:
+    ///
+    ///   `SELECT (SELECT MAX(t1.b) FROM t1 WHERE t.a = t1.a) FROM t`
+    ///
+    /// will be rewritten into:
+    ///
+    ///   `SELECT max_b FROM t LEFT JOIN (SELECT t1.a, MAX(t1.b) AS max_b FROM t1 GROUP BY t1.a)
+    ///   ON t.a = t1.a`
+    ///
+    /// Returns the join and the index of the column which holds the result of subquery, or
+    /// `None` if the subquery can't be decorrelated.
+    pub(super) fn try_decorrelate_scalar_subquery(
+        &mut self,
+        left: &SExpr,
+        subquery: &SubqueryExpr,
+    ) -> Result<Option<(SExpr, IndexType)>> {
+        if subquery.typ != SubqueryType::Scalar || subquery.outer_columns.is_empty() {
+            return Ok(None);
+        }
+
+        // Project
+        //     EvalScalar (optional)
+        //         Aggregate
+        //             EvalScalar (optional)
+        //                 Filter
+        let s_expr = &subquery.subquery;
+        let mut output_column = match s_expr.plan() {
+            RelOperator::Project(project) if project.columns.len() == 1 => {
+                *project.columns.iter().next().unwrap()
+            }
+            _ => return Ok(None),
+        };
+        let mut s_expr = s_expr.child(0)?;
+        if let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+            for item in eval_scalar.items.iter() {
+                if item.index != output_column {
+                    continue;
+                }
+                match &item.scalar {
+                    Scalar::BoundColumnRef(column_ref) => output_column = column_ref.column.index,
+                    _ => return Ok(None),
+                }
+            }
+            s_expr = s_expr.child(0)?;
+        }
+
+        let aggregate = match s_expr.plan() {
+            RelOperator::Aggregate(aggregate) if aggregate.group_items.is_empty() => aggregate,
+            _ => return Ok(None),
+        };
+        let mut null_on_empty = false;
+        for item in aggregate.aggregate_functions.iter() {
+            match &item.scalar {
+                Scalar::AggregateFunction(agg)
+                    if NULL_ON_EMPTY_AGGREGATES
+                        .contains(&agg.func_name.to_lowercase().as_str()) =>
+                {
+                    null_on_empty |= item.index == output_column;
+                }
+                _ => return Ok(None),
+            }
+        }
+        if !null_on_empty {
+            return Ok(None);
+        }
+
+        let mut s_expr = s_expr.child(0)?;
+        let arguments = match s_expr.plan() {
+            RelOperator::EvalScalar(eval_scalar) => {
+                let arguments = eval_scalar.clone();
+                s_expr = s_expr.child(0)?;
+                Some(arguments)
+            }
+            _ => None,
+        };
+        let filter = match s_expr.plan() {
+            RelOperator::Filter(filter) if !filter.is_having => filter,
+            _ => return Ok(None),
+        };
+        let filter_input = s_expr.child(0)?;
+        let predicates = match Self::extract_correlated_predicates(
+            &filter.predicates,
+            &subquery.outer_columns,
+            left,
+            filter_input,
+        )? {
+            Some(predicates) => predicates,
+            None => return Ok(None),
+        };
+
+        // The correlated columns of subquery become the group items.
+        let mut group_items = Vec::with_capacity(predicates.inner_conditions.len());
+        for condition in predicates.inner_conditions.iter() {
+            match condition {
+                Scalar::BoundColumnRef(column_ref) => group_items.push(ScalarItem {
+                    scalar: condition.clone(),
+                    index: column_ref.column.index,
+                }),
+                _ => return Ok(None),
+            }
+        }
+
+        let mut right = Self::build_filter(predicates.other_predicates, filter_input.clone());
+        if let Some(arguments) = arguments {
+            right = SExpr::create_unary(arguments.into(), right);
+        }
+        let right = SExpr::create_unary(
+            AggregatePlan {
+                group_items,
+                aggregate_functions: aggregate.aggregate_functions.clone(),
+                from_distinct: false,
+            }
+            .into(),
+            right,
+        );
+
+        // The rest of the subquery mustn't reference any outer column.
+        let prop = RelExpr::with_s_expr(&right).derive_relational_prop()?;
+        if !prop.outer_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let join = LogicalInnerJoin {
+            left_conditions: predicates.outer_conditions,
+            right_conditions: predicates.inner_conditions,
+            join_type: JoinType::LeftJoin,
+        };
+        Ok(Some((
+            SExpr::create_binary(join.into(), left.clone(), right),
+            output_column,
+        )))
+    }
+
+    /// Split the predicates of subquery into correlated equi-predicates and the others.
+    /// Returns `None` if there is a correlated predicate that isn't an equi-predicate, or
+    /// the subquery references outer columns that can't be found in `left`.
+    fn extract_correlated_predicates(
+        predicates: &[Scalar],
+        outer_columns: &ColumnSet,
+        left: &SExpr,
+        input: &SExpr,
+    ) -> Result<Option<CorrelatedPredicates>> {
+        let left_columns = RelExpr::with_s_expr(left)
+            .derive_relational_prop()?
+            .output_columns;
+        let input_prop = RelExpr::with_s_expr(input).derive_relational_prop()?;
+        if !input_prop.outer_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let is_outer = |scalar: &Scalar| {
+            let used_columns = scalar.used_columns();
+            !used_columns.is_empty()
+                && used_columns.is_subset(outer_columns)
+                && used_columns.is_subset(&left_columns)
+        };
+        let is_inner = |scalar: &Scalar| {
+            let used_columns = scalar.used_columns();
+            !used_columns.is_empty() && used_columns.is_disjoint(outer_columns)
+        };
+
+        let mut result = CorrelatedPredicates {
+            outer_conditions: vec![],
+            inner_conditions: vec![],
+            other_predicates: vec![],
+        };
+        for predicate in predicates.iter() {
+            if predicate.used_columns().is_disjoint(outer_columns) {
+                result.other_predicates.push(predicate.clone());
+                continue;
+            }
+            match split_equivalent_predicate(predicate) {
+                Some((left, right)) if is_outer(&left) && is_inner(&right) => {
+                    result.outer_conditions.push(left);
+                    result.inner_conditions.push(right);
+                }
+                Some((left, right)) if is_inner(&left) && is_outer(&right) => {
+                    result.outer_conditions.push(right);
+                    result.inner_conditions.push(left);
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        if result.outer_conditions.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(result))
+    }
+
+    fn build_filter(predicates: Vec<Scalar>, child: SExpr) -> SExpr {
+        if predicates.is_empty() {
+            return child;
+        }
+        SExpr::create_unary(
+            FilterPlan {
+                predicates,
+                is_having: false,
+            }
+            .into(),
+            child,
+        )
+    }
+}
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
+use common_ast::ast::Expr;
+use common_ast::parser::error::DisplayError;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::sql::binder::Binder;
@@ -22,6 +26,7 @@ use crate::sql::optimizer::SExpr;
 use crate::sql::planner::semantic::GroupingChecker;
 use crate::sql::plans::AggregatePlan;
 use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::DistinctPlan;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarItem;
@@ -77,4 +82,74 @@ impl Binder {
 
         Ok(SExpr::create_unary(distinct_plan.into(), new_expr))
     }
+
+    /// Bind `DISTINCT ON (...)`, which keeps the first row of each distinct key. The keys
+    /// can only be columns, either an alias in select list or a column of the from clause.
+    pub(super) fn bind_distinct_on(
+        &self,
+        bind_context: &BindContext,
+        distinct_on: &[Expr<'_>],
+        projections: &[ColumnBinding],
+        scalar_items: &mut HashMap<IndexType, ScalarItem>,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(distinct_on.len());
+        let mut scalars = vec![];
+        for expr in distinct_on {
+            let ident = match expr {
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column,
+                    ..
+                } => column,
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        expr.span()
+                            .display_error("can only distinct on column".to_string()),
+                    ));
+                }
+            };
+
+            // We first search the identifier in select list, then fallback to from clause.
+            let column = match projections.iter().find(|v| v.column_name == ident.name) {
+                Some(column) => column.clone(),
+                None => bind_context.resolve_column(None, ident)?,
+            };
+
+            // The alias scalar expression should be evaluated before distinct.
+            if let Entry::Occupied(entry) = scalar_items.entry(column.index) {
+                if !matches!(&entry.get().scalar, Scalar::BoundColumnRef(_)) {
+                    let (index, item) = entry.remove_entry();
+                    let mut scalar = item.scalar;
+
+                    if bind_context.in_grouping {
+                        let mut group_checker = GroupingChecker::new(bind_context);
+                        scalar = group_checker.resolve(&scalar)?;
+                    }
+                    scalars.push(ScalarItem { scalar, index });
+                }
+            }
+
+            items.push(ScalarItem {
+                scalar: Scalar::BoundColumnRef(BoundColumnRef {
+                    column: column.clone(),
+                }),
+                index: column.index,
+            });
+        }
+
+        let mut new_expr = child;
+        if !scalars.is_empty() {
+            let eval_scalar = EvalScalar { items: scalars };
+            new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
+        }
+
+        let distinct_plan = DistinctPlan {
+            items,
+            first_row: true,
+        };
+
+        Ok(SExpr::create_unary(distinct_plan.into(), new_expr))
+    }
 }
@@ -20,6 +20,7 @@ use common_ast::ast::Join;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
 use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -56,6 +57,7 @@ impl<'a> Binder {
         check_duplicate_join_tables(&left_context, &right_context)?;
 
         let mut bind_context = BindContext::new();
+        bind_context.ctes_map = left_context.ctes_map.clone();
         for column in left_context.all_column_bindings() {
             bind_context.add_column_binding(column.clone());
         }
@@ -99,22 +101,44 @@ impl<'a> Binder {
             .await?;
 
         let mut s_expr = match &join.op {
-            JoinOperator::Inner => self.bind_join_with_type(
-                JoinType::InnerJoin,
-                left_join_conditions,
-                right_join_conditions,
-                left_child,
-                right_child,
-            ),
-            JoinOperator::LeftOuter => Err(ErrorCode::UnImplement(
-                "Unsupported join type: LEFT OUTER JOIN",
-            )),
-            JoinOperator::RightOuter => Err(ErrorCode::UnImplement(
-                "Unsupported join type: RIGHT OUTER JOIN",
-            )),
-            JoinOperator::FullOuter => Err(ErrorCode::UnImplement(
-                "Unsupported join type: FULL OUTER JOIN",
-            )),
+            JoinOperator::Inner => {
+                let (left_child, right_child, left_join_conditions, right_join_conditions) = self
+                    .reorder_join_children(
+                        left_child,
+                        right_child,
+                        left_join_conditions,
+                        right_join_conditions,
+                    )
+                    .await?;
+                self.bind_join_with_type(
+                    JoinType::InnerJoin,
+                    left_join_conditions,
+                    right_join_conditions,
+                    left_child,
+                    right_child,
+                )
+            }
+            JoinOperator::LeftOuter | JoinOperator::RightOuter | JoinOperator::FullOuter => {
+                // The other conditions can't be evaluated after an outer join, which would
+                // filter out the rows padded with NULLs.
+                if left_join_conditions.is_empty() || !other_conditions.is_empty() {
+                    return Err(ErrorCode::UnImplement(
+                        "Outer join only supports equi-conditions",
+                    ));
+                }
+                let join_type = match &join.op {
+                    JoinOperator::LeftOuter => JoinType::LeftJoin,
+                    JoinOperator::RightOuter => JoinType::RightJoin,
+                    _ => JoinType::FullJoin,
+                };
+                self.bind_join_with_type(
+                    join_type,
+                    left_join_conditions,
+                    right_join_conditions,
+                    left_child,
+                    right_child,
+                )
+            }
             JoinOperator::CrossJoin => self.bind_join_with_type(
                 JoinType::CrossJoin,
                 left_join_conditions,
@@ -124,6 +148,30 @@ impl<'a> Binder {
             ),
         }?;
 
+        // The columns of the side without matched rows are padded with NULLs in outer joins.
+        // It's done after the join conditions are resolved, whose keys are evaluated on the
+        // rows of the children, which are not padded yet.
+        let (nullable_left, nullable_right) = match &join.op {
+            JoinOperator::LeftOuter => (false, true),
+            JoinOperator::RightOuter => (true, false),
+            JoinOperator::FullOuter => (true, true),
+            _ => (false, false),
+        };
+        let left_columns = left_context
+            .all_column_bindings()
+            .iter()
+            .map(|column| column.index)
+            .collect::<ColumnSet>();
+        for column in bind_context.columns.iter_mut() {
+            let nullable = match left_columns.contains(&column.index) {
+                true => nullable_left,
+                false => nullable_right,
+            };
+            if nullable {
+                column.data_type = wrap_nullable(&column.data_type);
+            }
+        }
+
         if !other_conditions.is_empty() {
             let filter_plan = FilterPlan {
                 predicates: other_conditions,
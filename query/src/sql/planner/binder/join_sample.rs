@@ -0,0 +1,232 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Extras;
+use common_tracing::tracing;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::sessions::QueryContext;
+use crate::sql::optimizer::SExpr;
+use crate::sql::planner::binder::Binder;
+use crate::sql::plans::LogicalGet;
+use crate::sql::plans::RelOperator;
+use crate::sql::plans::Scalar;
+use crate::sql::IndexType;
+use crate::storages::Table;
+
+/// Sampled keys of one join side, `rows` is the estimated row count of the whole side.
+struct JoinKeySample {
+    rows: f64,
+    sampled_rows: usize,
+    keys: HashMap<String, usize>,
+}
+
+impl Binder {
+    /// Choose the build side of an inner equi-join by estimating the cardinality of both
+    /// children. The right child is used as the build side, so the children are swapped
+    /// if the left one is expected to be smaller.
+    ///
+    /// Estimations are made by sampling a few blocks of the joined tables, they are skipped
+    /// if `join_sample_blocks` is zero or the sampling exceeds `join_sample_timeout_ms`.
+    pub(super) async fn reorder_join_children(
+        &self,
+        left_child: SExpr,
+        right_child: SExpr,
+        left_conditions: Vec<Scalar>,
+        right_conditions: Vec<Scalar>,
+    ) -> Result<(SExpr, SExpr, Vec<Scalar>, Vec<Scalar>)> {
+        let settings = self.ctx.get_settings();
+        let sample_blocks = settings.get_join_sample_blocks()? as usize;
+        if sample_blocks == 0 || left_conditions.is_empty() {
+            return Ok((left_child, right_child, left_conditions, right_conditions));
+        }
+
+        let timeout = Duration::from_millis(settings.get_join_sample_timeout_ms()?);
+        let estimation = async {
+            let left_rows = self
+                .estimate_cardinality(&left_child, sample_blocks)
+                .await?;
+            let right_rows = self
+                .estimate_cardinality(&right_child, sample_blocks)
+                .await?;
+            Ok::<_, ErrorCode>(left_rows.zip(right_rows))
+        };
+
+        match tokio::time::timeout(timeout, estimation).await {
+            Ok(Ok(Some((left_rows, right_rows)))) if left_rows < right_rows => {
+                tracing::debug!(
+                    "Swap join children, estimated left rows: {}, right rows: {}",
+                    left_rows,
+                    right_rows
+                );
+                Ok((right_child, left_child, right_conditions, left_conditions))
+            }
+            Ok(Err(cause)) => Err(cause),
+            Err(_) => {
+                tracing::debug!("Join cardinality sampling exceeds {:?}", timeout);
+                Ok((left_child, right_child, left_conditions, right_conditions))
+            }
+            _ => Ok((left_child, right_child, left_conditions, right_conditions)),
+        }
+    }
+
+    /// Estimate the output rows of a `SExpr`. Only table scans and inner joins
+    /// with a column equi-condition between two table scans are supported.
+    async fn estimate_cardinality(
+        &self,
+        s_expr: &SExpr,
+        sample_blocks: usize,
+    ) -> Result<Option<f64>> {
+        match s_expr.plan() {
+            RelOperator::LogicalGet(get) => {
+                let entry = self.metadata.read().table(get.table_index).clone();
+                let statistics = &entry.source.statistics;
+                match statistics.read_rows {
+                    0 if !statistics.is_exact => Ok(self
+                        .sample_join_keys(get, None, sample_blocks)
+                        .await?
+                        .map(|sample| sample.rows)),
+                    rows => Ok(Some(rows as f64)),
+                }
+            }
+            RelOperator::LogicalInnerJoin(join) if join.left_conditions.len() == 1 => {
+                let (left, right) = (s_expr.child(0)?, s_expr.child(1)?);
+                let left_key = column_index(&join.left_conditions[0]);
+                let right_key = column_index(&join.right_conditions[0]);
+                match (left.plan(), right.plan(), left_key, right_key) {
+                    (
+                        RelOperator::LogicalGet(left_get),
+                        RelOperator::LogicalGet(right_get),
+                        Some(left_key),
+                        Some(right_key),
+                    ) => {
+                        let left_sample = self
+                            .sample_join_keys(left_get, Some(left_key), sample_blocks)
+                            .await?;
+                        let right_sample = self
+                            .sample_join_keys(right_get, Some(right_key), sample_blocks)
+                            .await?;
+                        Ok(left_sample
+                            .zip(right_sample)
+                            .map(|(left, right)| estimate_join_rows(&left, &right)))
+                    }
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Read at most `sample_blocks` blocks of the table, keeping only the `key` column.
+    /// The first blocks are read, or randomly chosen ones if `random_seed` is set.
+    async fn sample_join_keys(
+        &self,
+        get: &LogicalGet,
+        key: Option<IndexType>,
+        sample_blocks: usize,
+    ) -> Result<Option<JoinKeySample>> {
+        let entry = self.metadata.read().table(get.table_index).clone();
+        let engine = entry.table.engine();
+        if !engine.eq_ignore_ascii_case("FUSE") && !engine.eq_ignore_ascii_case("MEMORY") {
+            return Ok(None);
+        }
+
+        let schema = entry.table.schema();
+        let projection = match key {
+            None => 0,
+            Some(key) => {
+                let column_name = self.metadata.read().column(key).name.clone();
+                schema.index_of(&column_name)?
+            }
+        };
+
+        let mut plan = entry.source.clone();
+        let total_parts = plan.parts.len();
+        if let Some(random_seed) = self.ctx.get_settings().get_random_seed()? {
+            plan.parts.shuffle(&mut StdRng::seed_from_u64(random_seed));
+        }
+        plan.parts.truncate(sample_blocks);
+        plan.push_downs = Some(Extras {
+            projection: Some(vec![projection]),
+            ..Extras::default()
+        });
+
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        ctx.try_set_partitions(plan.parts.clone())?;
+        let stream = entry.table.read(ctx, &plan).await?;
+        let blocks = stream
+            .take(sample_blocks)
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let mut sample = JoinKeySample {
+            rows: 0.0,
+            sampled_rows: 0,
+            keys: HashMap::new(),
+        };
+        for block in blocks.iter() {
+            let column = block.column(0);
+            for row in 0..block.num_rows() {
+                *sample.keys.entry(column.get(row).to_string()).or_default() += 1;
+            }
+            sample.sampled_rows += block.num_rows();
+        }
+
+        // Scale the sampled rows up to the whole table.
+        let sampled_parts = blocks.len().max(1);
+        sample.rows = match entry.source.statistics.read_rows {
+            0 => sample.sampled_rows as f64 * (total_parts.max(1) as f64 / sampled_parts as f64),
+            rows => rows as f64,
+        };
+        Ok(Some(sample))
+    }
+}
+
+fn column_index(scalar: &Scalar) -> Option<IndexType> {
+    match scalar {
+        Scalar::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+        _ => None,
+    }
+}
+
+/// The selectivity is the fraction of matched pairs in the cross product of the samples.
+fn estimate_join_rows(left: &JoinKeySample, right: &JoinKeySample) -> f64 {
+    if left.sampled_rows == 0 || right.sampled_rows == 0 {
+        return 0.0;
+    }
+
+    let matched: usize = left
+        .keys
+        .iter()
+        .filter_map(|(key, left_count)| {
+            right
+                .keys
+                .get(key)
+                .map(|right_count| left_count * right_count)
+        })
+        .sum();
+    let selectivity = matched as f64 / (left.sampled_rows as f64 * right.sampled_rows as f64);
+    selectivity * left.rows * right.rows
+}
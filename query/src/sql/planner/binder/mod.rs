@@ -20,22 +20,35 @@ pub use bind_context::ColumnBinding;
 use common_ast::ast::Statement;
 use common_ast::ast::TimeTravelPoint;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::CastFunction;
+pub use cte::CteInfo;
+pub use project::ColumnNameCase;
+pub use subquery::SubqueryRewriter;
+pub use window::WindowInfo;
 
-use self::subquery::SubqueryRewriter;
 use super::plans::Plan;
 use crate::catalogs::CatalogManager;
+use crate::common::ScalarEvaluator;
 use crate::sessions::QueryContext;
+use crate::sql::binder::scalar::ScalarBinder;
 use crate::sql::planner::metadata::MetadataRef;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::Scalar;
+use crate::storages::validate_time_travel;
 use crate::storages::NavigationPoint;
 use crate::storages::Table;
 
 mod aggregate;
 mod bind_context;
+mod cte;
 mod ddl;
+mod decorrelate;
 mod distinct;
 mod join;
+mod join_sample;
 mod limit;
 mod project;
 mod scalar;
@@ -45,6 +58,7 @@ mod select;
 mod sort;
 mod subquery;
 mod table;
+mod window;
 
 /// Binder is responsible to transform AST of a query into a canonical logical SExpr.
 ///
@@ -125,19 +139,66 @@ impl<'a> Binder {
         catalog_name: &str,
         database_name: &str,
         table_name: &str,
-        travel_point: &Option<TimeTravelPoint>,
+        travel_point: &Option<TimeTravelPoint<'a>>,
     ) -> Result<Arc<dyn Table>> {
         // Resolve table with catalog
         let catalog = self.catalogs.get_catalog(catalog_name)?;
         let mut table_meta = catalog.get_table(tenant, database_name, table_name).await?;
-        if let Some(TimeTravelPoint::Snapshot(s)) = travel_point {
+        if let Some(travel_point) = travel_point {
+            validate_time_travel(catalog.as_ref(), table_meta.as_ref())?;
+            let navigation_point = self.resolve_navigation_point(travel_point).await?;
             table_meta = table_meta
-                .navigate_to(self.ctx.clone(), &NavigationPoint::SnapshotID(s.to_owned()))
+                .navigate_to(self.ctx.clone(), &navigation_point)
                 .await?;
         }
         Ok(table_meta)
     }
 
+    async fn resolve_navigation_point(
+        &self,
+        travel_point: &TimeTravelPoint<'a>,
+    ) -> Result<NavigationPoint> {
+        match travel_point {
+            TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Timestamp(expr) => {
+                // The time point must be a constant, e.g. '2022-06-01 00:00:00' or now() - 1,
+                // which is cast to TIMESTAMP and evaluated right here.
+                let bind_context = BindContext::new();
+                let mut type_checker =
+                    ScalarBinder::new(&bind_context, self.ctx.clone(), self.metadata.clone());
+                let (scalar, data_type) = type_checker.bind(expr).await?;
+
+                let cast_func = CastFunction::create("", "TIMESTAMP", data_type.clone())?;
+                let target_type = cast_func.return_type();
+                let scalar = Scalar::CastExpr(CastExpr {
+                    argument: Box::new(scalar),
+                    from_type: data_type,
+                    target_type: target_type.clone(),
+                });
+
+                let func_ctx = self.ctx.try_get_function_context()?;
+                let (value, _) = ScalarEvaluator::try_create(&scalar)?
+                    .try_eval_const(&func_ctx)
+                    .map_err(|_| {
+                        ErrorCode::BadArguments(format!(
+                            "time travel point must be a constant timestamp, but got: {}",
+                            expr
+                        ))
+                    })?;
+
+                match (value, target_type) {
+                    (DataValue::Int64(v), DataTypeImpl::Timestamp(ts_type)) => {
+                        Ok(NavigationPoint::TimePoint(ts_type.utc_timestamp(v)))
+                    }
+                    (value, _) => Err(ErrorCode::BadArguments(format!(
+                        "invalid time travel point: {}",
+                        value
+                    ))),
+                }
+            }
+        }
+    }
+
     /// Create a new ColumnBinding with assigned index
     pub(super) fn create_column_binding(
         &mut self,
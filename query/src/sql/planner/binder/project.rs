@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use common_ast::ast::Expr;
 use common_ast::ast::Indirection;
 use common_ast::ast::SelectTarget;
 use common_exception::ErrorCode;
@@ -36,7 +38,72 @@ use crate::sql::plans::ScalarExpr;
 use crate::sql::plans::ScalarItem;
 use crate::sql::IndexType;
 
+// The generated names longer than this are replaced by the positional names.
+const MAX_GENERATED_NAME_LENGTH: usize = 64;
+
+/// How the column names of the result set are displayed, set by the setting `column_name_case`.
+///
+/// The names only affect the result set metadata, the columns are always resolved
+/// case-insensitively in the query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnNameCase {
+    /// Lowercase the identifiers and the aliases, e.g. `SELECT A AS X` displays `x`.
+    Lower,
+    /// Keep the identifiers and the aliases as written in the query, and the columns expanded
+    /// from `*` as declared in the table.
+    Preserve,
+    /// Like `Preserve`, but the expressions without an alias are named by their positions,
+    /// e.g. `SELECT a, a + 1` displays `a` and `_col2`.
+    Alias,
+}
+
+impl FromStr for ColumnNameCase {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "lower" => Ok(ColumnNameCase::Lower),
+            "preserve" => Ok(ColumnNameCase::Preserve),
+            "alias" => Ok(ColumnNameCase::Alias),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown column_name_case: {}, must be one of lower, preserve and alias",
+                s
+            ))),
+        }
+    }
+}
+
+impl ColumnNameCase {
+    fn apply(&self, name: &str) -> String {
+        match self {
+            ColumnNameCase::Lower => name.to_lowercase(),
+            ColumnNameCase::Preserve | ColumnNameCase::Alias => name.to_string(),
+        }
+    }
+
+    // The name of an expression without an alias, `position` starts from 1.
+    fn generated_name(&self, expr: &Expr, position: usize) -> String {
+        match expr {
+            // `SELECT t.a` is displayed as `a`.
+            Expr::ColumnRef { column, .. } => self.apply(&column.name),
+            _ if *self == ColumnNameCase::Alias => format!("_col{}", position),
+            _ => {
+                let name = format!("{:#}", expr);
+                match name.chars().count() > MAX_GENERATED_NAME_LENGTH {
+                    true => format!("_col{}", position),
+                    false => name,
+                }
+            }
+        }
+    }
+}
+
 impl<'a> Binder {
+    fn column_name_case(&self) -> Result<ColumnNameCase> {
+        let value = self.ctx.get_settings().get_column_name_case()?;
+        ColumnNameCase::from_str(&String::from_utf8_lossy(&value))
+    }
+
     pub(super) fn analyze_projection(
         &mut self,
         select_list: &SelectList<'a>,
@@ -125,8 +192,10 @@ impl<'a> Binder {
         input_context: &BindContext,
         select_list: &'a [SelectTarget<'a>],
     ) -> Result<SelectList<'a>> {
+        let case = self.column_name_case()?;
         let mut output = SelectList::<'a>::default();
         for select_target in select_list {
+            let position = output.items.len() + 1;
             match select_target {
                 SelectTarget::QualifiedName(names) => {
                     // Handle qualified name as select target
@@ -142,6 +211,7 @@ impl<'a> Binder {
                                     }
                                     .into(),
                                     alias: ident.name.clone(),
+                                    display_name: case.apply(&ident.name),
                                 });
                             }
                             Indirection::Star => {
@@ -151,6 +221,15 @@ impl<'a> Binder {
                                     if !column_binding.visible_in_unqualified_wildcard {
                                         continue;
                                     }
+                                    let display_name = match case {
+                                        ColumnNameCase::Lower => column_binding.column_name.clone(),
+                                        _ => self
+                                            .metadata
+                                            .read()
+                                            .column(column_binding.index)
+                                            .name
+                                            .clone(),
+                                    };
                                     output.items.push(SelectItem {
                                         select_target,
                                         scalar: BoundColumnRef {
@@ -158,6 +237,7 @@ impl<'a> Binder {
                                         }
                                         .into(),
                                         alias: column_binding.column_name.clone(),
+                                        display_name,
                                     });
                                 }
                             }
@@ -177,11 +257,16 @@ impl<'a> Binder {
                         Some(alias) => alias.name.to_lowercase(),
                         None => format!("{:#}", expr),
                     };
+                    let display_name = match alias {
+                        Some(alias) => case.apply(&alias.name),
+                        None => case.generated_name(expr, position),
+                    };
 
                     output.items.push(SelectItem {
                         select_target,
                         scalar: bound_expr,
                         alias: expr_name,
+                        display_name,
                     });
                 }
             }
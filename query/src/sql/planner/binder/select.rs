@@ -45,6 +45,8 @@ pub struct SelectItem<'a> {
     pub select_target: &'a SelectTarget<'a>,
     pub scalar: Scalar,
     pub alias: String,
+    /// The name of the column in the result set, see `ColumnNameCase`
+    pub display_name: String,
 }
 
 impl<'a> Binder {
@@ -91,6 +93,9 @@ impl<'a> Binder {
 
         self.analyze_aggregate_select(&mut from_context, &mut select_list)?;
 
+        // This will replace the window functions in `scalar_items` with their output columns.
+        self.analyze_window_select(&mut from_context, &mut scalar_items)?;
+
         let having = if let Some(having) = &stmt.having {
             Some(
                 self.analyze_aggregate_having(&mut from_context, having)
@@ -105,7 +110,7 @@ impl<'a> Binder {
             &scalar_items,
             &projections,
             order_by,
-            stmt.distinct,
+            stmt.distinct && stmt.distinct_on.is_empty(),
         )?;
 
         if !from_context.aggregate_info.aggregate_functions.is_empty()
@@ -119,7 +124,11 @@ impl<'a> Binder {
             }
         }
 
-        if stmt.distinct {
+        if !from_context.window_info.window_functions.is_empty() {
+            s_expr = self.bind_window(&from_context, s_expr)?;
+        }
+
+        if stmt.distinct && stmt.distinct_on.is_empty() {
             s_expr = self.bind_distinct(&from_context, &projections, &mut scalar_items, s_expr)?;
         }
 
@@ -129,11 +138,27 @@ impl<'a> Binder {
                 .await?;
         }
 
+        // `DISTINCT ON` keeps the first row of each key in the order of `ORDER BY`.
+        if !stmt.distinct_on.is_empty() {
+            s_expr = self.bind_distinct_on(
+                &from_context,
+                &stmt.distinct_on,
+                &projections,
+                &mut scalar_items,
+                s_expr,
+            )?;
+        }
+
         s_expr = self.bind_projection(&mut from_context, &projections, &scalar_items, s_expr)?;
 
         let mut output_context = BindContext::new();
         output_context.parent = from_context.parent;
         output_context.columns = from_context.columns;
+        output_context.display_names = select_list
+            .items
+            .iter()
+            .map(|item| item.display_name.clone())
+            .collect();
 
         Ok((s_expr, output_context))
     }
@@ -144,6 +169,12 @@ impl<'a> Binder {
         bind_context: &BindContext,
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
+        let with_context = match &query.with {
+            Some(with) => Some(self.bind_with(bind_context, with)?),
+            None => None,
+        };
+        let bind_context = with_context.as_ref().unwrap_or(bind_context);
+
         let (mut s_expr, bind_context) = match &query.body {
             SetExpr::Select(stmt) => {
                 self.bind_select_stmt(bind_context, stmt, &query.order_by)
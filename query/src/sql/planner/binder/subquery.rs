@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::wrap_nullable;
 use common_datavalues::BooleanType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
@@ -38,19 +39,26 @@ use crate::sql::plans::OrExpr;
 use crate::sql::plans::Project;
 use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
 use crate::sql::plans::ScalarItem;
 use crate::sql::plans::SubqueryExpr;
 use crate::sql::plans::SubqueryType;
 use crate::sql::MetadataRef;
 
-/// Rewrite subquery into `Apply` operator
+/// Rewrite subquery into `Apply` operator, or join if the subquery can be decorrelated
 pub struct SubqueryRewriter {
     metadata: MetadataRef,
+    // Columns derived from the decorrelated scalar subqueries, they are nullable since
+    // the subqueries are rewritten into left joins.
+    nullable_columns: ColumnSet,
 }
 
 impl SubqueryRewriter {
     pub fn new(metadata: MetadataRef) -> Self {
-        Self { metadata }
+        Self {
+            metadata,
+            nullable_columns: ColumnSet::new(),
+        }
     }
 
     pub fn rewrite(&mut self, s_expr: &SExpr) -> Result<SExpr> {
@@ -62,6 +70,7 @@ impl SubqueryRewriter {
                     let res = self.try_rewrite_subquery(&item.scalar, &input)?;
                     input = res.1;
                     item.scalar = res.0;
+                    self.derive_nullable(item);
                 }
 
                 Ok(SExpr::create_unary(plan.into(), input))
@@ -69,12 +78,23 @@ impl SubqueryRewriter {
             RelOperator::Filter(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
 
-                for pred in plan.predicates.iter_mut() {
+                let mut predicates = Vec::with_capacity(plan.predicates.len());
+                for pred in plan.predicates.iter() {
+                    // Correlated `EXISTS` and `NOT EXISTS` are decorrelated into semi/anti join
+                    if let Some(join) = self.try_decorrelate_exists(pred, &input)? {
+                        input = join;
+                        continue;
+                    }
                     let res = self.try_rewrite_subquery(pred, &input)?;
                     input = res.1;
-                    *pred = res.0;
+                    predicates.push(res.0);
                 }
 
+                if predicates.is_empty() {
+                    return Ok(input);
+                }
+                plan.predicates = predicates;
+
                 Ok(SExpr::create_unary(plan.into(), input))
             }
             RelOperator::Aggregate(mut plan) => {
@@ -84,6 +104,7 @@ impl SubqueryRewriter {
                     let res = self.try_rewrite_subquery(&item.scalar, &input)?;
                     input = res.1;
                     item.scalar = res.0;
+                    self.derive_nullable(item);
                 }
 
                 for item in plan.aggregate_functions.iter_mut() {
@@ -101,15 +122,31 @@ impl SubqueryRewriter {
                 self.rewrite(s_expr.child(1)?)?,
             )),
 
-            RelOperator::Project(_) | RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(
-                SExpr::create_unary(s_expr.plan().clone(), self.rewrite(s_expr.child(0)?)?),
-            ),
+            RelOperator::Project(_)
+            | RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::Distinct(_)
+            | RelOperator::Window(_) => Ok(SExpr::create_unary(
+                s_expr.plan().clone(),
+                self.rewrite(s_expr.child(0)?)?,
+            )),
 
-            RelOperator::LogicalGet(_) => Ok(s_expr.clone()),
+            RelOperator::CteScan(_) => Ok(SExpr::create(
+                s_expr.plan().clone(),
+                s_expr
+                    .children()
+                    .iter()
+                    .map(|child| self.rewrite(child))
+                    .collect::<Result<Vec<_>>>()?,
+                None,
+            )),
+
+            RelOperator::LogicalGet(_) | RelOperator::WorkingTableScan(_) => Ok(s_expr.clone()),
 
             RelOperator::CrossApply(_)
             | RelOperator::Max1Row(_)
             | RelOperator::PhysicalHashJoin(_)
+            | RelOperator::PhysicalSortMergeJoin(_)
             | RelOperator::Pattern(_)
             | RelOperator::PhysicalScan(_) => Err(ErrorCode::LogicalError("Invalid plan type")),
         }
@@ -227,6 +264,21 @@ impl SubqueryRewriter {
         }
     }
 
+    // The column of `item` becomes nullable if it's derived from a decorrelated scalar subquery.
+    fn derive_nullable(&mut self, item: &ScalarItem) {
+        if item
+            .scalar
+            .used_columns()
+            .is_disjoint(&self.nullable_columns)
+        {
+            return;
+        }
+        let mut metadata = self.metadata.write();
+        let column = metadata.column_mut(item.index);
+        column.data_type = wrap_nullable(&column.data_type);
+        self.nullable_columns.insert(item.index);
+    }
+
     /// Try to extract subquery from a scalar expression. Returns replaced scalar expression
     /// and the subqueries.
     fn try_rewrite_subquery(&mut self, scalar: &Scalar, s_expr: &SExpr) -> Result<(Scalar, SExpr)> {
@@ -275,7 +327,9 @@ impl SubqueryRewriter {
                 ))
             }
 
-            Scalar::AggregateFunction(_) => Ok((scalar.clone(), s_expr.clone())),
+            Scalar::AggregateFunction(_) | Scalar::WindowFunction(_) => {
+                Ok((scalar.clone(), s_expr.clone()))
+            }
 
             Scalar::FunctionCall(func) => {
                 let mut args = vec![];
@@ -315,6 +369,21 @@ impl SubqueryRewriter {
                 let mut subquery = subquery.clone();
                 subquery.subquery = self.rewrite(&subquery.subquery)?;
 
+                if let Some((s_expr, index)) =
+                    self.try_decorrelate_scalar_subquery(s_expr, &subquery)?
+                {
+                    // The subquery outputs NULL if there is no matched row of left join.
+                    self.nullable_columns.insert(index);
+                    let column_ref = ColumnBinding {
+                        table_name: None,
+                        column_name: format!("subquery_{}", index),
+                        index,
+                        data_type: wrap_nullable(&subquery.data_type),
+                        visible_in_unqualified_wildcard: false,
+                    };
+                    return Ok((BoundColumnRef { column: column_ref }.into(), s_expr));
+                }
+
                 let s_expr = self.build_apply(s_expr, &subquery)?;
 
                 let rel_expr = RelExpr::with_s_expr(s_expr.child(1)?);
@@ -25,6 +25,8 @@ use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use common_planners::Expression;
 
 use crate::catalogs::CATALOG_DEFAULT;
@@ -89,6 +91,13 @@ impl<'a> Binder {
                 alias,
                 travel_point,
             } => {
+                if catalog.is_none() && database.is_none() {
+                    let name = table.name.to_lowercase();
+                    if let Some(cte) = bind_context.ctes_map.get(&name) {
+                        return self.bind_cte(bind_context, &name, cte, alias).await;
+                    }
+                }
+
                 // Get catalog name
                 let catalog = catalog
                     .as_ref()
@@ -107,6 +116,17 @@ impl<'a> Binder {
                 let table = table.to_lowercase();
                 let tenant = self.ctx.get_tenant();
 
+                // The system databases are readable by all the users.
+                if database != "system" && database != "information_schema" {
+                    self.ctx
+                        .get_current_session()
+                        .validate_privilege(
+                            &GrantObject::Table(catalog.clone(), database.clone(), table.clone()),
+                            UserPrivilegeType::Select,
+                        )
+                        .await?;
+                }
+
                 // Resolve table with catalog
                 let table_meta: Arc<dyn Table> = self
                     .resolve_data_source(
@@ -189,8 +209,7 @@ impl<'a> Binder {
 
                 // Table functions always reside is default catalog
                 let table_meta: Arc<dyn TableFunction> = self
-                    .catalogs
-                    .get_catalog(CATALOG_DEFAULT)?
+                    .ctx
                     .get_table_function(name.name.as_str(), table_args)?;
                 let table = table_meta.as_table();
 
@@ -0,0 +1,263 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::binder::scalar_common::find_window_functions;
+use crate::sql::binder::Binder;
+use crate::sql::binder::ColumnBinding;
+use crate::sql::optimizer::SExpr;
+use crate::sql::planner::semantic::GroupingChecker;
+use crate::sql::plans::AndExpr;
+use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::ComparisonExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::FunctionCall;
+use crate::sql::plans::OrExpr;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::sql::plans::SortItem;
+use crate::sql::plans::WindowPlan;
+use crate::sql::BindContext;
+use crate::sql::IndexType;
+
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct WindowInfo {
+    /// Window functions, the index of each item is its output column
+    pub window_functions: Vec<ScalarItem>,
+
+    /// Mapping: (window function display name) -> (index of window function in `window_functions`)
+    pub window_functions_map: HashMap<String, usize>,
+}
+
+/// Replace the window functions with a BoundColumnRef to their output columns.
+struct WindowRewriter<'a> {
+    pub bind_context: &'a BindContext,
+}
+
+impl<'a> WindowRewriter<'a> {
+    pub fn visit(&self, scalar: &Scalar) -> Result<Scalar> {
+        match scalar {
+            Scalar::BoundColumnRef(_)
+            | Scalar::ConstantExpr(_)
+            | Scalar::SubqueryExpr(_)
+            | Scalar::AggregateFunction(_) => Ok(scalar.clone()),
+            Scalar::AndExpr(scalar) => Ok(AndExpr {
+                left: Box::new(self.visit(&scalar.left)?),
+                right: Box::new(self.visit(&scalar.right)?),
+            }
+            .into()),
+            Scalar::OrExpr(scalar) => Ok(OrExpr {
+                left: Box::new(self.visit(&scalar.left)?),
+                right: Box::new(self.visit(&scalar.right)?),
+            }
+            .into()),
+            Scalar::ComparisonExpr(scalar) => Ok(ComparisonExpr {
+                op: scalar.op.clone(),
+                left: Box::new(self.visit(&scalar.left)?),
+                right: Box::new(self.visit(&scalar.right)?),
+            }
+            .into()),
+            Scalar::FunctionCall(func) => Ok(FunctionCall {
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?,
+                func_name: func.func_name.clone(),
+                arg_types: func.arg_types.clone(),
+                return_type: func.return_type.clone(),
+            }
+            .into()),
+            Scalar::Cast(cast) => Ok(CastExpr {
+                argument: Box::new(self.visit(&cast.argument)?),
+                from_type: cast.from_type.clone(),
+                target_type: cast.target_type.clone(),
+            }
+            .into()),
+            Scalar::WindowFunction(window) => {
+                let window_info = &self.bind_context.window_info;
+                let item = window_info
+                    .window_functions_map
+                    .get(&window.display_name)
+                    .map(|index| &window_info.window_functions[*index])
+                    .ok_or_else(|| ErrorCode::LogicalError("Invalid window function"))?;
+                Ok(BoundColumnRef {
+                    column: ColumnBinding {
+                        table_name: None,
+                        column_name: window.display_name.clone(),
+                        index: item.index,
+                        data_type: window.return_type.clone(),
+                        visible_in_unqualified_wildcard: true,
+                    },
+                }
+                .into())
+            }
+        }
+    }
+}
+
+impl Binder {
+    /// Analyze window functions in select clause. The window functions are registered
+    /// in `WindowInfo`, and replaced with their output columns in the projections.
+    pub(super) fn analyze_window_select(
+        &mut self,
+        bind_context: &mut BindContext,
+        scalar_items: &mut HashMap<IndexType, ScalarItem>,
+    ) -> Result<()> {
+        for item in scalar_items.values() {
+            for scalar in find_window_functions(&item.scalar)? {
+                let window = match &scalar {
+                    Scalar::WindowFunction(window) => window,
+                    _ => unreachable!(),
+                };
+                let window_info = &mut bind_context.window_info;
+                if window_info
+                    .window_functions_map
+                    .contains_key(&window.display_name)
+                {
+                    continue;
+                }
+
+                let index = self.metadata.write().add_column(
+                    window.display_name.clone(),
+                    window.return_type.clone(),
+                    None,
+                );
+                window_info.window_functions_map.insert(
+                    window.display_name.clone(),
+                    window_info.window_functions.len(),
+                );
+                window_info
+                    .window_functions
+                    .push(ScalarItem { scalar, index });
+            }
+        }
+
+        if bind_context.window_info.window_functions.is_empty() {
+            return Ok(());
+        }
+
+        let rewriter = WindowRewriter { bind_context };
+        for item in scalar_items.values_mut() {
+            item.scalar = rewriter.visit(&item.scalar)?;
+        }
+
+        Ok(())
+    }
+
+    /// Build a `WindowPlan` for each window function. The arguments, partition keys and
+    /// order keys which are not columns yet are evaluated by an `EvalScalar` below it.
+    pub(super) fn bind_window(
+        &mut self,
+        bind_context: &BindContext,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let mut new_expr = child;
+        for item in bind_context.window_info.window_functions.iter() {
+            let window = match &item.scalar {
+                Scalar::WindowFunction(window) => window,
+                _ => return Err(ErrorCode::LogicalError("Invalid window function")),
+            };
+
+            let mut scalar_items = vec![];
+            let mut arguments = Vec::with_capacity(window.args.len());
+            for (i, arg) in window.args.iter().enumerate() {
+                let name = format!("{}_arg_{}", &window.display_name, i);
+                arguments.push(self.bind_window_item(
+                    bind_context,
+                    arg,
+                    name,
+                    &mut scalar_items,
+                )?);
+            }
+
+            let mut partition_by = Vec::with_capacity(window.partition_by.len());
+            for (i, key) in window.partition_by.iter().enumerate() {
+                let name = format!("{}_partition_{}", &window.display_name, i);
+                partition_by.push(self.bind_window_item(
+                    bind_context,
+                    key,
+                    name,
+                    &mut scalar_items,
+                )?);
+            }
+
+            let mut order_by = Vec::with_capacity(window.order_by.len());
+            for (i, key) in window.order_by.iter().enumerate() {
+                let name = format!("{}_order_{}", &window.display_name, i);
+                let key_item =
+                    self.bind_window_item(bind_context, &key.expr, name, &mut scalar_items)?;
+                order_by.push(SortItem {
+                    index: key_item.index,
+                    asc: Some(key.asc),
+                    nulls_first: Some(key.nulls_first),
+                });
+            }
+
+            if !scalar_items.is_empty() {
+                let eval_scalar = EvalScalar {
+                    items: scalar_items,
+                };
+                new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
+            }
+
+            let window_plan = WindowPlan {
+                index: item.index,
+                func: window.func.clone(),
+                arguments,
+                partition_by,
+                order_by,
+                frame: window.frame.clone(),
+            };
+            new_expr = SExpr::create_unary(window_plan.into(), new_expr);
+        }
+
+        Ok(new_expr)
+    }
+
+    fn bind_window_item(
+        &mut self,
+        bind_context: &BindContext,
+        scalar: &Scalar,
+        name: String,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<ScalarItem> {
+        let scalar = if bind_context.in_grouping {
+            let mut grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(scalar)?
+        } else {
+            scalar.clone()
+        };
+
+        if let Scalar::BoundColumnRef(column_ref) = &scalar {
+            return Ok(ScalarItem {
+                index: column_ref.column.index,
+                scalar,
+            });
+        }
+
+        let index = self
+            .metadata
+            .write()
+            .add_column(name, scalar.data_type(), None);
+        let item = ScalarItem { scalar, index };
+        scalar_items.push(item.clone());
+        Ok(item)
+    }
+}
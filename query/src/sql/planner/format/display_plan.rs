@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::format_data_type_sql;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use serde_json::json;
 
 use crate::sql::plans::Plan;
 
@@ -32,4 +35,60 @@ impl Plan {
             Plan::ShowSettings => Ok("SHOW SETTINGS".to_string()),
         }
     }
+
+    pub fn format_json(&self) -> Result<String> {
+        match self {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => {
+                let metadata_guard = metadata.read();
+                let tables = metadata_guard
+                    .tables()
+                    .iter()
+                    .map(|table| {
+                        json!({
+                            "index": table.index,
+                            "catalog": table.catalog,
+                            "database": table.database,
+                            "name": table.name,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let columns = metadata_guard
+                    .columns()
+                    .iter()
+                    .map(|column| {
+                        json!({
+                            "index": column.column_index,
+                            "name": column.name,
+                            "data_type": format_data_type_sql(&column.data_type),
+                            "table_index": column.table_index,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                drop(metadata_guard);
+
+                let result = json!({
+                    "plan": s_expr.to_format_tree(metadata).format_json(),
+                    "tables": tables,
+                    "columns": columns,
+                });
+                Ok(serde_json::to_string_pretty(&result)?)
+            }
+            _ => Err(ErrorCode::UnImplement(
+                "EXPLAIN (FORMAT JSON) only supports queries",
+            )),
+        }
+    }
+
+    pub fn format_graph(&self) -> Result<String> {
+        match self {
+            Plan::Query {
+                s_expr, metadata, ..
+            } => s_expr.to_format_tree(metadata).format_dot(),
+            _ => Err(ErrorCode::UnImplement(
+                "EXPLAIN (FORMAT GRAPH) only supports queries",
+            )),
+        }
+    }
 }
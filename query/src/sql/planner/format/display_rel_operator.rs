@@ -17,28 +17,38 @@ use std::fmt::Display;
 use common_datavalues::format_data_type_sql;
 
 use super::FormatTreeNode;
+use crate::sql::optimizer::CardinalityEstimator;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::AggregatePlan;
 use crate::sql::plans::AndExpr;
 use crate::sql::plans::ComparisonExpr;
 use crate::sql::plans::ComparisonOp;
 use crate::sql::plans::CrossApply;
+use crate::sql::plans::CteScan;
+use crate::sql::plans::DistinctPlan;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::FilterPlan;
+use crate::sql::plans::JoinDistribution;
+use crate::sql::plans::JoinType;
 use crate::sql::plans::LimitPlan;
 use crate::sql::plans::LogicalGet;
 use crate::sql::plans::LogicalInnerJoin;
 use crate::sql::plans::PhysicalHashJoin;
 use crate::sql::plans::PhysicalScan;
+use crate::sql::plans::PhysicalSortMergeJoin;
 use crate::sql::plans::Project;
 use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::SortPlan;
+use crate::sql::plans::WindowPlan;
+use crate::sql::plans::WorkingTableScan;
 use crate::sql::MetadataRef;
 
 pub struct FormatContext {
     metadata: MetadataRef,
     rel_operator: RelOperator,
+    // Estimated output rows, shown for the scans and the joins
+    cardinality: f64,
 }
 
 impl SExpr {
@@ -53,6 +63,7 @@ impl SExpr {
             FormatContext {
                 metadata: metadata.clone(),
                 rel_operator: self.plan().clone(),
+                cardinality: CardinalityEstimator::create(metadata.clone()).estimate(self),
             },
             children,
         )
@@ -62,17 +73,37 @@ impl SExpr {
 impl Display for FormatContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.rel_operator {
-            RelOperator::LogicalGet(op) => format_logical_get(f, &self.metadata, op),
-            RelOperator::LogicalInnerJoin(op) => format_logical_inner_join(f, &self.metadata, op),
-            RelOperator::PhysicalScan(op) => format_physical_scan(f, &self.metadata, op),
-            RelOperator::PhysicalHashJoin(op) => format_hash_join(f, &self.metadata, op),
+            RelOperator::LogicalGet(op) => {
+                format_logical_get(f, &self.metadata, op)?;
+                format_cardinality(f, self.cardinality)
+            }
+            RelOperator::LogicalInnerJoin(op) => {
+                format_logical_inner_join(f, &self.metadata, op)?;
+                format_cardinality(f, self.cardinality)
+            }
+            RelOperator::PhysicalScan(op) => {
+                format_physical_scan(f, &self.metadata, op)?;
+                format_cardinality(f, self.cardinality)
+            }
+            RelOperator::PhysicalHashJoin(op) => {
+                format_hash_join(f, &self.metadata, op)?;
+                format_cardinality(f, self.cardinality)
+            }
+            RelOperator::PhysicalSortMergeJoin(op) => {
+                format_sort_merge_join(f, &self.metadata, op)?;
+                format_cardinality(f, self.cardinality)
+            }
             RelOperator::Project(op) => format_project(f, &self.metadata, op),
             RelOperator::EvalScalar(op) => format_eval_scalar(f, &self.metadata, op),
             RelOperator::Filter(op) => format_filter(f, &self.metadata, op),
             RelOperator::Aggregate(op) => format_aggregate(f, &self.metadata, op),
+            RelOperator::Distinct(op) => format_distinct(f, &self.metadata, op),
             RelOperator::Sort(op) => format_sort(f, &self.metadata, op),
             RelOperator::Limit(op) => format_limit(f, &self.metadata, op),
             RelOperator::CrossApply(op) => format_cross_apply(f, &self.metadata, op),
+            RelOperator::Window(op) => format_window(f, &self.metadata, op),
+            RelOperator::CteScan(op) => format_cte_scan(f, &self.metadata, op),
+            RelOperator::WorkingTableScan(op) => format_working_table_scan(f, &self.metadata, op),
             RelOperator::Max1Row(_) => write!(f, "Max1Row"),
             RelOperator::Pattern(_) => write!(f, "Pattern"),
         }
@@ -100,6 +131,7 @@ pub fn format_scalar(metadata: &MetadataRef, scalar: &Scalar) -> String {
             format_scalar(metadata, &comp.right)
         ),
         Scalar::AggregateFunction(agg) => agg.display_name.clone(),
+        Scalar::WindowFunction(window) => window.display_name.clone(),
         Scalar::FunctionCall(func) => {
             format!(
                 "{}({})",
@@ -122,6 +154,10 @@ pub fn format_scalar(metadata: &MetadataRef, scalar: &Scalar) -> String {
     }
 }
 
+pub fn format_cardinality(f: &mut std::fmt::Formatter<'_>, cardinality: f64) -> std::fmt::Result {
+    write!(f, ", estimated rows: {:.0}", cardinality)
+}
+
 pub fn format_logical_get(
     f: &mut std::fmt::Formatter<'_>,
     metadata: &MetadataRef,
@@ -153,13 +189,14 @@ pub fn format_logical_inner_join(
             .into()
         })
         .collect();
-    let pred: Scalar = preds.iter().fold(preds[0].clone(), |prev, next| {
+    let pred: Scalar = preds.iter().skip(1).fold(preds[0].clone(), |prev, next| {
         Scalar::AndExpr(AndExpr {
             left: Box::new(prev),
             right: Box::new(next.clone()),
         })
     });
-    write!(f, "LogicalInnerJoin: {}", format_scalar(metadata, &pred))
+    write!(f, "LogicalInnerJoin: {}", format_scalar(metadata, &pred))?;
+    format_join_type(f, &op.join_type)
 }
 
 pub fn format_hash_join(
@@ -183,7 +220,52 @@ pub fn format_hash_join(
         f,
         "PhysicalHashJoin: build keys: [{}], probe keys: [{}]",
         build_keys, probe_keys
-    )
+    )?;
+    format_join_type(f, &op.join_type)?;
+    // Broadcast is the default, so only the hash distribution is displayed.
+    if op.distribution == JoinDistribution::Hash {
+        write!(f, ", distribution: Hash")?;
+    }
+    Ok(())
+}
+
+pub fn format_sort_merge_join(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &PhysicalSortMergeJoin,
+) -> std::fmt::Result {
+    let left_keys = op
+        .left_keys
+        .iter()
+        .map(|scalar| format_scalar(metadata, scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let right_keys = op
+        .right_keys
+        .iter()
+        .map(|scalar| format_scalar(metadata, scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+    write!(
+        f,
+        "PhysicalSortMergeJoin: left keys: [{}], right keys: [{}]",
+        left_keys, right_keys
+    )?;
+    format_join_type(f, &op.join_type)
+}
+
+// Inner join is the default, so only the other join types are displayed.
+fn format_join_type(f: &mut std::fmt::Formatter<'_>, join_type: &JoinType) -> std::fmt::Result {
+    let name = match join_type {
+        JoinType::InnerJoin => return Ok(()),
+        JoinType::LeftJoin => "Left",
+        JoinType::RightJoin => "Right",
+        JoinType::FullJoin => "Full",
+        JoinType::SemiJoin => "Semi",
+        JoinType::AntiJoin => "Anti",
+        JoinType::CrossJoin => "Cross",
+    };
+    write!(f, ", join type: {}", name)
 }
 
 pub fn format_physical_scan(
@@ -271,6 +353,23 @@ pub fn format_aggregate(
     )
 }
 
+pub fn format_distinct(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &DistinctPlan,
+) -> std::fmt::Result {
+    let items = op
+        .items
+        .iter()
+        .map(|item| format_scalar(metadata, &item.scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+    match op.first_row {
+        true => write!(f, "Distinct: on: [{}]", items),
+        false => write!(f, "Distinct: [{}]", items),
+    }
+}
+
 pub fn format_sort(
     f: &mut std::fmt::Formatter<'_>,
     metadata: &MetadataRef,
@@ -296,6 +395,58 @@ pub fn format_sort(
     write!(f, "Sort: [{}]", scalars)
 }
 
+pub fn format_window(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &WindowPlan,
+) -> std::fmt::Result {
+    let name = metadata.read().column(op.index).name.clone();
+    let partition_by = op
+        .partition_by
+        .iter()
+        .map(|item| format_scalar(metadata, &item.scalar))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let order_by = op
+        .order_by
+        .iter()
+        .map(|item| {
+            let name = metadata.read().column(item.index).name.clone();
+            match item.asc.unwrap_or(true) {
+                true => format!("{} ASC", name),
+                false => format!("{} DESC", name),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    write!(
+        f,
+        "Window: {}, partition by: [{}], order by: [{}]",
+        name, partition_by, order_by
+    )
+}
+
+pub fn format_cte_scan(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &CteScan,
+) -> std::fmt::Result {
+    let name = metadata.read().cte_name(op.cte_index).to_string();
+    match op.recursive {
+        true => write!(f, "CteScan: {}, recursive", name),
+        false => write!(f, "CteScan: {}", name),
+    }
+}
+
+pub fn format_working_table_scan(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &WorkingTableScan,
+) -> std::fmt::Result {
+    let name = metadata.read().cte_name(op.cte_index).to_string();
+    write!(f, "WorkingTableScan: {}", name)
+}
+
 pub fn format_limit(
     f: &mut std::fmt::Formatter<'_>,
     _metadata: &MetadataRef,
@@ -0,0 +1,52 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Write;
+
+use common_exception::Result;
+
+use super::FormatTreeNode;
+
+impl<T> FormatTreeNode<T>
+where T: Display
+{
+    /// Format the tree as a Graphviz DOT graph, with the edges from the parents to the children.
+    pub fn format_dot(&self) -> Result<String> {
+        let mut buf = String::new();
+        writeln!(buf, "digraph plan {{").unwrap();
+        writeln!(buf, "    node [shape=box];").unwrap();
+        self.format_dot_impl(&mut 0, &mut buf)?;
+        writeln!(buf, "}}").unwrap();
+        Ok(buf)
+    }
+
+    // Returns the id of the node, the nodes are numbered in pre-order.
+    fn format_dot_impl(&self, next_id: &mut usize, f: &mut String) -> Result<usize> {
+        let id = *next_id;
+        *next_id += 1;
+
+        let label = self.payload.to_string();
+        writeln!(f, "    {} [label=\"{}\"];", id, escape_label(&label)).unwrap();
+        for child in self.children.iter() {
+            let child_id = child.format_dot_impl(next_id, f)?;
+            writeln!(f, "    {} -> {};", id, child_id).unwrap();
+        }
+        Ok(id)
+    }
+}
+
+fn escape_label(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,35 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use serde_json::json;
+use serde_json::Value;
+
+use super::FormatTreeNode;
+
+impl<T> FormatTreeNode<T>
+where T: Display
+{
+    pub fn format_json(&self) -> Value {
+        json!({
+            "operator": self.payload.to_string(),
+            "children": self
+                .children
+                .iter()
+                .map(|child| child.format_json())
+                .collect::<Vec<_>>(),
+        })
+    }
+}
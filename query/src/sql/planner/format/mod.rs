@@ -15,7 +15,9 @@
 mod display_rel_operator;
 use std::fmt::Display;
 mod display_plan;
+mod dot_format;
 mod indent_format;
+mod json_format;
 
 pub struct FormatTreeNode<T: Display> {
     payload: T,
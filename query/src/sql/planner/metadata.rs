@@ -90,6 +90,8 @@ impl ColumnEntry {
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    // Names of the common table expressions
+    ctes: Vec<String>,
 }
 
 impl Metadata {
@@ -97,6 +99,7 @@ impl Metadata {
         Self {
             tables: vec![],
             columns: vec![],
+            ctes: vec![],
         }
     }
 
@@ -108,6 +111,18 @@ impl Metadata {
         self.columns.get(index).unwrap()
     }
 
+    pub fn column_mut(&mut self, index: IndexType) -> &mut ColumnEntry {
+        self.columns.get_mut(index).unwrap()
+    }
+
+    pub fn cte_name(&self, index: IndexType) -> &str {
+        self.ctes[index].as_str()
+    }
+
+    pub fn tables(&self) -> &[TableEntry] {
+        self.tables.as_slice()
+    }
+
     pub fn columns(&self) -> &[ColumnEntry] {
         self.columns.as_slice()
     }
@@ -138,6 +153,11 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_cte(&mut self, name: String) -> IndexType {
+        self.ctes.push(name);
+        self.ctes.len() - 1
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -34,6 +34,8 @@ pub mod plans;
 mod semantic;
 
 pub use binder::ColumnBinding;
+pub use binder::ColumnNameCase;
+pub use binder::SubqueryRewriter;
 pub use format::FormatTreeNode;
 pub use metadata::ColumnEntry;
 pub use metadata::Metadata;
@@ -67,7 +69,7 @@ impl Planner {
         let plan = binder.bind(&stmts[0]).await?;
 
         // Step 3: optimize the SExpr with optimizers, and generate optimized physical SExpr
-        let optimized_plan = optimize(plan)?;
+        let optimized_plan = optimize(self.ctx.clone(), plan)?;
 
         Ok((optimized_plan, metadata.clone()))
     }
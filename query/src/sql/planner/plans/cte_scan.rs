@@ -0,0 +1,139 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::LogicalPlan;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalPlan;
+use crate::sql::plans::RelOp;
+use crate::sql::IndexType;
+
+/// Reads the result of a common table expression, which is computed once and shared by all
+/// the `CteScan`s of the same CTE in a query.
+///
+/// The first child computes the CTE, or the anchor of a recursive CTE. A recursive CTE has
+/// its recursive term as the second child, which is evaluated over the rows returned by its
+/// last evaluation, i.e. the working table, until it returns no rows.
+#[derive(Clone, Debug)]
+pub struct CteScan {
+    // Index of the CTE in `Metadata`
+    pub cte_index: IndexType,
+    // Output columns, in the order of the CTE columns
+    pub columns: Vec<IndexType>,
+    // Output columns of the first child, in the order of the CTE columns
+    pub anchor_columns: Vec<IndexType>,
+    // Output columns of the second child, empty if there is no recursive term
+    pub recursive_columns: Vec<IndexType>,
+    // True if the recursive term reads the working table, otherwise it is evaluated once
+    pub recursive: bool,
+}
+
+impl CteScan {
+    pub fn has_recursive_term(&self) -> bool {
+        !self.recursive_columns.is_empty()
+    }
+}
+
+impl Operator for CteScan {
+    fn plan_type(&self) -> RelOp {
+        RelOp::CteScan
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalPlan> {
+        Some(self)
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalPlan> {
+        Some(self)
+    }
+}
+
+impl PhysicalPlan for CteScan {
+    fn compute_physical_prop(&self, _expression: &SExpr) -> PhysicalProperty {
+        todo!()
+    }
+}
+
+impl LogicalPlan for CteScan {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let mut outer_columns = rel_expr.derive_relational_prop_child(0)?.outer_columns;
+        if self.has_recursive_term() {
+            outer_columns.extend(rel_expr.derive_relational_prop_child(1)?.outer_columns);
+        }
+
+        Ok(RelationalProperty {
+            output_columns: self.columns.iter().cloned().collect(),
+            outer_columns,
+        })
+    }
+}
+
+/// Reads the working table of a recursive CTE, only valid in the recursive term of the CTE.
+#[derive(Clone, Debug)]
+pub struct WorkingTableScan {
+    // Index of the CTE in `Metadata`
+    pub cte_index: IndexType,
+    pub columns: Vec<IndexType>,
+}
+
+impl Operator for WorkingTableScan {
+    fn plan_type(&self) -> RelOp {
+        RelOp::WorkingTableScan
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalPlan> {
+        Some(self)
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalPlan> {
+        Some(self)
+    }
+}
+
+impl PhysicalPlan for WorkingTableScan {
+    fn compute_physical_prop(&self, _expression: &SExpr) -> PhysicalProperty {
+        todo!()
+    }
+}
+
+impl LogicalPlan for WorkingTableScan {
+    fn derive_relational_prop<'a>(&self, _rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        Ok(RelationalProperty {
+            output_columns: self.columns.iter().cloned().collect(),
+            outer_columns: ColumnSet::new(),
+        })
+    }
+}
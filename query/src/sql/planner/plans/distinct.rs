@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::LogicalPlan;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalPlan;
+use crate::sql::plans::RelOp;
+use crate::sql::plans::ScalarItem;
+
+#[derive(Clone, Debug)]
+pub struct DistinctPlan {
+    // Distinct keys, each item must be a column of the input
+    pub items: Vec<ScalarItem>,
+    // True if the plan is generated from `DISTINCT ON`, which keeps all the columns of
+    // the first row of each distinct key, else only the distinct keys are output.
+    pub first_row: bool,
+}
+
+impl Operator for DistinctPlan {
+    fn plan_type(&self) -> RelOp {
+        RelOp::Distinct
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalPlan> {
+        Some(self)
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalPlan> {
+        Some(self)
+    }
+}
+
+impl PhysicalPlan for DistinctPlan {
+    fn compute_physical_prop(&self, _expression: &SExpr) -> PhysicalProperty {
+        todo!()
+    }
+}
+
+impl LogicalPlan for DistinctPlan {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+        if self.first_row {
+            return Ok(input_prop);
+        }
+
+        let output_columns: ColumnSet = self.items.iter().map(|item| item.index).collect();
+        let outer_columns = input_prop
+            .outer_columns
+            .difference(&output_columns)
+            .cloned()
+            .collect();
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns,
+        })
+    }
+}
@@ -21,11 +21,23 @@ use crate::sql::plans::PhysicalPlan;
 use crate::sql::plans::RelOp;
 use crate::sql::plans::Scalar;
 
+/// How the rows of the build side are distributed to the hash tables.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JoinDistribution {
+    /// All the build rows are in one hash table shared by all the probe processors,
+    /// it's cheap for a small build side.
+    Broadcast,
+    /// Both sides are repartitioned by the hash of the join keys, and every partition
+    /// builds and probes its own hash table in parallel.
+    Hash,
+}
+
 #[derive(Clone, Debug)]
 pub struct PhysicalHashJoin {
     pub build_keys: Vec<Scalar>,
     pub probe_keys: Vec<Scalar>,
     pub join_type: JoinType,
+    pub distribution: JoinDistribution,
 }
 
 impl Operator for PhysicalHashJoin {
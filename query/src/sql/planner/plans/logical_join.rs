@@ -15,6 +15,7 @@
 use common_exception::Result;
 
 use super::ScalarExpr;
+use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
 use crate::sql::plans::LogicalPlan;
@@ -68,9 +69,8 @@ impl LogicalPlan for LogicalInnerJoin {
         let left_prop = rel_expr.derive_relational_prop_child(0)?;
         let right_prop = rel_expr.derive_relational_prop_child(1)?;
 
-        // Derive output columns
-        let mut output_columns = left_prop.output_columns;
-        output_columns = output_columns
+        let input_columns: ColumnSet = left_prop
+            .output_columns
             .union(&right_prop.output_columns)
             .cloned()
             .collect();
@@ -87,10 +87,16 @@ impl LogicalPlan for LogicalInnerJoin {
             .chain(self.right_conditions.iter())
         {
             let used_columns = cond.used_columns();
-            let outer = used_columns.difference(&output_columns).cloned().collect();
+            let outer = used_columns.difference(&input_columns).cloned().collect();
             outer_columns = outer_columns.union(&outer).cloned().collect();
         }
-        outer_columns = outer_columns.difference(&output_columns).cloned().collect();
+        outer_columns = outer_columns.difference(&input_columns).cloned().collect();
+
+        // Derive output columns, semi join and anti join only output the columns of left side
+        let output_columns = match self.join_type {
+            JoinType::SemiJoin | JoinType::AntiJoin => left_prop.output_columns,
+            _ => input_columns,
+        };
 
         Ok(RelationalProperty {
             output_columns,
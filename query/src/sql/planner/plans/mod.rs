@@ -14,6 +14,8 @@
 
 mod aggregate;
 mod apply;
+mod cte_scan;
+mod distinct;
 mod eval_scalar;
 mod filter;
 mod hash_join;
@@ -27,14 +29,20 @@ mod physical_scan;
 mod project;
 mod scalar;
 mod sort;
+mod sort_merge_join;
+mod window;
 
 pub use aggregate::AggregatePlan;
 pub use apply::CrossApply;
 use common_ast::ast::ExplainKind;
 use common_planners::CreateTablePlan;
+pub use cte_scan::CteScan;
+pub use cte_scan::WorkingTableScan;
+pub use distinct::DistinctPlan;
 pub use eval_scalar::EvalScalar;
 pub use eval_scalar::ScalarItem;
 pub use filter::FilterPlan;
+pub use hash_join::JoinDistribution;
 pub use hash_join::PhysicalHashJoin;
 pub use limit::LimitPlan;
 pub use logical_get::LogicalGet;
@@ -48,6 +56,8 @@ pub use project::Project;
 pub use scalar::*;
 pub use sort::SortItem;
 pub use sort::SortPlan;
+pub use sort_merge_join::PhysicalSortMergeJoin;
+pub use window::WindowPlan;
 
 use super::BindContext;
 use super::MetadataRef;
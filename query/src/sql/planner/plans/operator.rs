@@ -17,6 +17,9 @@ use enum_dispatch::enum_dispatch;
 
 use super::aggregate::AggregatePlan;
 use super::apply::CrossApply;
+use super::cte_scan::CteScan;
+use super::cte_scan::WorkingTableScan;
+use super::distinct::DistinctPlan;
 use super::eval_scalar::EvalScalar;
 use super::filter::FilterPlan;
 use super::hash_join::PhysicalHashJoin;
@@ -28,6 +31,8 @@ use super::pattern::PatternPlan;
 use super::physical_scan::PhysicalScan;
 use super::project::Project;
 use super::sort::SortPlan;
+use super::sort_merge_join::PhysicalSortMergeJoin;
+use super::window::WindowPlan;
 use crate::sql::optimizer::PhysicalProperty;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
@@ -68,16 +73,21 @@ pub enum RelOp {
     // Physical operators
     PhysicalScan,
     PhysicalHashJoin,
+    PhysicalSortMergeJoin,
 
     // Operators that are both logical and physical
     Project,
     EvalScalar,
     Filter,
     Aggregate,
+    Distinct,
     Sort,
     Limit,
     CrossApply,
     Max1Row,
+    Window,
+    CteScan,
+    WorkingTableScan,
 
     // Pattern
     Pattern,
@@ -92,15 +102,20 @@ pub enum RelOperator {
 
     PhysicalScan(PhysicalScan),
     PhysicalHashJoin(PhysicalHashJoin),
+    PhysicalSortMergeJoin(PhysicalSortMergeJoin),
 
     Project(Project),
     EvalScalar(EvalScalar),
     Filter(FilterPlan),
     Aggregate(AggregatePlan),
+    Distinct(DistinctPlan),
     Sort(SortPlan),
     Limit(LimitPlan),
     CrossApply(CrossApply),
     Max1Row(Max1Row),
+    Window(WindowPlan),
+    CteScan(CteScan),
+    WorkingTableScan(WorkingTableScan),
 
     Pattern(PatternPlan),
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::BinaryOperator;
+use common_ast::ast::WindowFrame;
 use common_datavalues::BooleanType;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
@@ -48,6 +49,7 @@ pub enum Scalar {
     OrExpr(OrExpr),
     ComparisonExpr(ComparisonExpr),
     AggregateFunction(AggregateFunction),
+    WindowFunction(WindowFunction),
     FunctionCall(FunctionCall),
     // TODO(leiysky): maybe we don't need this variant any more
     // after making functions static typed?
@@ -218,6 +220,66 @@ impl ScalarExpr for AggregateFunction {
     }
 }
 
+#[derive(Clone, PartialEq, Debug)]
+pub enum WindowFuncType {
+    RowNumber,
+    Rank,
+    DenseRank,
+    /// `LAG(expr [, offset [, default]])`, the default is passed as the second argument
+    Lag {
+        offset: u64,
+    },
+    /// `LEAD(expr [, offset [, default]])`, the default is passed as the second argument
+    Lead {
+        offset: u64,
+    },
+    /// Aggregate function evaluated over the window frame, e.g. `SUM(a) OVER (...)`
+    Aggregate {
+        func_name: String,
+        params: Vec<DataValue>,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct WindowOrderBy {
+    pub expr: Scalar,
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct WindowFunction {
+    pub display_name: String,
+
+    pub func: WindowFuncType,
+    pub args: Vec<Scalar>,
+    pub partition_by: Vec<Scalar>,
+    pub order_by: Vec<WindowOrderBy>,
+    /// `None` means the default frame, which is the whole partition without `ORDER BY`,
+    /// or from the start of the partition to the last peer of the current row with it.
+    pub frame: Option<WindowFrame>,
+    pub return_type: DataTypeImpl,
+}
+
+impl ScalarExpr for WindowFunction {
+    fn data_type(&self) -> DataTypeImpl {
+        self.return_type.clone()
+    }
+
+    fn used_columns(&self) -> ColumnSet {
+        let mut result = ColumnSet::new();
+        let scalars = self
+            .args
+            .iter()
+            .chain(self.partition_by.iter())
+            .chain(self.order_by.iter().map(|item| &item.expr));
+        for scalar in scalars {
+            result = result.union(&scalar.used_columns()).cloned().collect();
+        }
+        result
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct FunctionCall {
     pub arguments: Vec<Scalar>,
@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_ast::ast::WindowFrame;
+use common_exception::Result;
+
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::LogicalPlan;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalPlan;
+use crate::sql::plans::RelOp;
+use crate::sql::plans::ScalarItem;
+use crate::sql::plans::SortItem;
+use crate::sql::plans::WindowFuncType;
+use crate::sql::IndexType;
+
+/// Evaluates a window function, appending its result to the input as a new column.
+#[derive(Clone, Debug)]
+pub struct WindowPlan {
+    // Index of the output column
+    pub index: IndexType,
+    pub func: WindowFuncType,
+    // Arguments, partition keys and order keys, each item must be a column of the input
+    pub arguments: Vec<ScalarItem>,
+    pub partition_by: Vec<ScalarItem>,
+    pub order_by: Vec<SortItem>,
+    pub frame: Option<WindowFrame>,
+}
+
+impl Operator for WindowPlan {
+    fn plan_type(&self) -> RelOp {
+        RelOp::Window
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalPlan> {
+        Some(self)
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalPlan> {
+        Some(self)
+    }
+}
+
+impl PhysicalPlan for WindowPlan {
+    fn compute_physical_prop(&self, _expression: &SExpr) -> PhysicalProperty {
+        todo!()
+    }
+}
+
+impl LogicalPlan for WindowPlan {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+        let mut output_columns = input_prop.output_columns;
+        output_columns.insert(self.index);
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns,
+        })
+    }
+}
@@ -60,6 +60,17 @@ impl<'a> GroupingChecker<'a> {
         }
 
         match scalar {
+            Scalar::BoundColumnRef(column)
+                if self
+                    .bind_context
+                    .window_info
+                    .window_functions
+                    .iter()
+                    .any(|item| item.index == column.column.index) =>
+            {
+                // Output column of a window function, which is evaluated after aggregation
+                Ok(scalar.clone())
+            }
             Scalar::BoundColumnRef(column) => {
                 // If this is a group item, then it should have been replaced with `group_items_map`
                 Err(ErrorCode::SemanticError(format!("column \"{}\" must appear in the GROUP BY clause or be used in an aggregate function", &column.column.column_name)))
@@ -106,6 +117,10 @@ impl<'a> GroupingChecker<'a> {
                 Ok(scalar.clone())
             }
 
+            Scalar::WindowFunction(_) => Err(ErrorCode::LogicalError(
+                "Window function should have been replaced with its output column",
+            )),
+
             Scalar::AggregateFunction(agg) => {
                 if let Some(column) = self
                     .bind_context
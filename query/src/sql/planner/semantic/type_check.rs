@@ -22,14 +22,20 @@ use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
 use common_ast::ast::TrimWhere;
 use common_ast::ast::UnaryOperator;
+use common_ast::ast::WindowSpec;
 use common_ast::parser::error::Backtrace;
 use common_ast::parser::error::DisplayError;
 use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
+use common_datavalues::remove_nullable;
+use common_datavalues::type_coercion::compare_coercion;
+use common_datavalues::type_coercion::implicit_cast_coercion;
 use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
 use common_datavalues::ArrayType;
 use common_datavalues::BooleanType;
 use common_datavalues::DataField;
+use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
 use common_datavalues::IntervalKind;
@@ -37,6 +43,7 @@ use common_datavalues::IntervalType;
 use common_datavalues::NullType;
 use common_datavalues::StringType;
 use common_datavalues::TimestampType;
+use common_datavalues::UInt64Type;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
@@ -44,6 +51,7 @@ use common_functions::is_builtin_function;
 use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::TupleFunction;
+use common_meta_types::UDFLanguage;
 
 use crate::sessions::QueryContext;
 use crate::sql::binder::Binder;
@@ -62,7 +70,11 @@ use crate::sql::plans::OrExpr;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::SubqueryExpr;
 use crate::sql::plans::SubqueryType;
+use crate::sql::plans::WindowFuncType;
+use crate::sql::plans::WindowFunction;
+use crate::sql::plans::WindowOrderBy;
 use crate::sql::BindContext;
+use crate::users::register_udf_function;
 
 /// A helper for type checking.
 ///
@@ -81,6 +93,10 @@ pub struct TypeChecker<'a> {
     // true if current expr is inside an aggregate function.
     // This is used to check if there is nested aggregate function.
     in_aggregate_function: bool,
+
+    // true if current expr is inside a window function.
+    // This is used to check if there is nested window function.
+    in_window_function: bool,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -94,6 +110,7 @@ impl<'a> TypeChecker<'a> {
             ctx,
             metadata,
             in_aggregate_function: false,
+            in_window_function: false,
         }
     }
 
@@ -261,9 +278,15 @@ impl<'a> TypeChecker<'a> {
                 name,
                 args,
                 params,
+                window,
                 ..
             } => {
                 let func_name = name.name.as_str();
+                if let Some(window) = window {
+                    return self
+                        .resolve_window_function(expr, func_name, args, params, window)
+                        .await;
+                }
                 if !is_builtin_function(func_name) {
                     return self.resolve_udf(func_name, args).await;
                 }
@@ -489,7 +512,21 @@ impl<'a> TypeChecker<'a> {
 
         let arg_types_ref: Vec<&DataTypeImpl> = arg_types.iter().collect();
 
-        let func = FunctionFactory::instance().get(func_name, &arg_types_ref)?;
+        // The division of two integers is an integer division in the ANSI strict mode.
+        let func_name = match func_name {
+            "/" if self.ctx.get_settings().get_sql_strict_mode()? != 0
+                && arg_types
+                    .iter()
+                    .all(|ty| remove_nullable(ty).data_type_id().is_integer()) =>
+            {
+                "div"
+            }
+            _ => func_name,
+        };
+
+        let strict = self.ctx.get_settings().get_enable_strict_type_coercion()? != 0;
+        let func =
+            FunctionFactory::instance().get_with_coercion(func_name, &arg_types_ref, strict)?;
         Ok((
             FunctionCall {
                 arguments: args,
@@ -502,6 +539,150 @@ impl<'a> TypeChecker<'a> {
         ))
     }
 
+    /// Resolve window function call, e.g. `ROW_NUMBER() OVER (PARTITION BY a ORDER BY b)`.
+    /// Besides the ranking functions and `LAG`/`LEAD`, any aggregate function can be
+    /// evaluated over the window frame.
+    pub async fn resolve_window_function(
+        &mut self,
+        expr: &Expr<'_>,
+        func_name: &str,
+        args: &[Expr<'_>],
+        params: &[Literal],
+        window: &WindowSpec<'_>,
+    ) -> Result<(Scalar, DataTypeImpl)> {
+        if self.in_aggregate_function || self.in_window_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            self.in_window_function = false;
+            return Err(ErrorCode::SemanticError(expr.span().display_error(
+                "window function calls cannot be nested".to_string(),
+            )));
+        }
+
+        self.in_window_function = true;
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            arguments.push(self.resolve(arg, None).await?);
+        }
+        let mut partition_by = Vec::with_capacity(window.partition_by.len());
+        for key in window.partition_by.iter() {
+            partition_by.push(self.resolve(key, None).await?.0);
+        }
+        let mut order_by = Vec::with_capacity(window.order_by.len());
+        for key in window.order_by.iter() {
+            let asc = key.asc.unwrap_or(true);
+            order_by.push(WindowOrderBy {
+                expr: self.resolve(&key.expr, None).await?.0,
+                asc,
+                // NULLS FIRST is the default for DESC order, and NULLS LAST otherwise
+                nulls_first: key.nulls_first.unwrap_or(!asc),
+            });
+        }
+        self.in_window_function = false;
+
+        let invalid_arguments = || {
+            ErrorCode::SemanticError(
+                expr.span()
+                    .display_error(format!("invalid arguments for window function {func_name}")),
+            )
+        };
+
+        let lower_name = func_name.to_lowercase();
+        let (func, args, return_type) = match lower_name.as_str() {
+            "row_number" | "rank" | "dense_rank" => {
+                if !arguments.is_empty() {
+                    return Err(invalid_arguments());
+                }
+                let func = match lower_name.as_str() {
+                    "row_number" => WindowFuncType::RowNumber,
+                    "rank" => WindowFuncType::Rank,
+                    _ => WindowFuncType::DenseRank,
+                };
+                (func, vec![], UInt64Type::new_impl())
+            }
+            "lag" | "lead" => {
+                if arguments.is_empty() || arguments.len() > 3 {
+                    return Err(invalid_arguments());
+                }
+                let offset = match arguments.get(1) {
+                    None => 1,
+                    Some((Scalar::ConstantExpr(ConstantExpr { value, .. }), _))
+                        if value.is_unsigned_integer() =>
+                    {
+                        value.as_u64()?
+                    }
+                    Some(_) => return Err(invalid_arguments()),
+                };
+                let func = match lower_name.as_str() {
+                    "lag" => WindowFuncType::Lag { offset },
+                    _ => WindowFuncType::Lead { offset },
+                };
+
+                // The value and the default are both casted to the return type, so the
+                // result can be picked from either of them.
+                let return_type = wrap_nullable(&arguments[0].1);
+                let mut args = vec![arguments[0].clone()];
+                if let Some(default) = arguments.get(2) {
+                    args.push(default.clone());
+                }
+                let args = args
+                    .into_iter()
+                    .map(|(arg, data_type)| match data_type == return_type {
+                        true => arg,
+                        false => CastExpr {
+                            argument: Box::new(arg),
+                            from_type: data_type,
+                            target_type: return_type.clone(),
+                        }
+                        .into(),
+                    })
+                    .collect();
+                (func, args, return_type)
+            }
+            _ if AggregateFunctionFactory::instance().check(func_name) => {
+                let params = params
+                    .iter()
+                    .map(|literal| self.resolve_literal(literal, None).map(|(value, _)| value))
+                    .collect::<Result<Vec<DataValue>>>()?;
+                let data_fields = arguments
+                    .iter()
+                    .map(|(_, data_type)| DataField::new("", data_type.clone()))
+                    .collect();
+                let agg_func = AggregateFunctionFactory::instance().get(
+                    func_name,
+                    params.clone(),
+                    data_fields,
+                )?;
+                let func = WindowFuncType::Aggregate {
+                    func_name: func_name.to_string(),
+                    params,
+                };
+                let args = arguments.into_iter().map(|(arg, _)| arg).collect();
+                (func, args, agg_func.return_type()?)
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    expr.span()
+                        .display_error(format!("unknown window function {func_name}")),
+                ));
+            }
+        };
+
+        Ok((
+            WindowFunction {
+                display_name: format!("{:#}", expr),
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame: window.window_frame.clone(),
+                return_type: return_type.clone(),
+            }
+            .into(),
+            return_type,
+        ))
+    }
+
     /// Resolve binary expressions. Most of the binary expressions
     /// would be transformed into `FunctionCall`, except comparison
     /// expressions, conjunction(`AND`) and disjunction(`OR`).
@@ -540,8 +721,14 @@ impl<'a> TypeChecker<'a> {
             | BinaryOperator::Eq
             | BinaryOperator::NotEq => {
                 let op = ComparisonOp::try_from(op)?;
-                let (left, _) = self.resolve(left, None).await?;
-                let (right, _) = self.resolve(right, None).await?;
+                let (left, left_type) = self.resolve(left, None).await?;
+                let (right, right_type) = self.resolve(right, None).await?;
+
+                if self.ctx.get_settings().get_enable_strict_type_coercion()? != 0 {
+                    let common_type = compare_coercion(&left_type, &right_type)?;
+                    implicit_cast_coercion(&left_type, &common_type, true)?;
+                    implicit_cast_coercion(&right_type, &common_type, true)?;
+                }
 
                 Ok((
                     ComparisonExpr {
@@ -839,6 +1026,23 @@ impl<'a> TypeChecker<'a> {
                 };
                 Some(self.resolve_function("connection_id", &[&arg], None).await)
             }
+            "current_role" => match self.ctx.get_current_role() {
+                Ok(role) => {
+                    let arg = Expr::Literal {
+                        span: &[],
+                        lit: Literal::String(role),
+                    };
+                    Some(self.resolve_function("current_role", &[&arg], None).await)
+                }
+                Err(e) => Some(Err(e)),
+            },
+            "last_query_id" => {
+                let arg = Expr::Literal {
+                    span: &[],
+                    lit: Literal::String(self.ctx.get_last_query_id()),
+                };
+                Some(self.resolve_function("last_query_id", &[&arg], None).await)
+            }
             _ => None,
         }
     }
@@ -982,6 +1186,11 @@ impl<'a> TypeChecker<'a> {
                     arguments.len()
                 )));
             }
+            if udf.language != UDFLanguage::Sql {
+                let registered_name = register_udf_function(&udf, &self.ctx)?;
+                let args: Vec<&Expr> = arguments.iter().collect();
+                return self.resolve_function(&registered_name, &args, None).await;
+            }
             let backtrace = Backtrace::new();
             let sql_tokens = tokenize_sql(udf.definition.as_str())?;
             let expr = parse_expr(&sql_tokens, &backtrace)?;
@@ -1161,6 +1370,7 @@ impl<'a> TypeChecker<'a> {
                     name,
                     args,
                     params,
+                    window,
                 } => Ok(Expr::FunctionCall {
                     span,
                     distinct: *distinct,
@@ -1170,6 +1380,7 @@ impl<'a> TypeChecker<'a> {
                         .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
+                    window: window.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -97,4 +97,69 @@ impl SQLCommon {
             query.to_string()
         }
     }
+
+    /// Split the query text into statements at the top level `;`, semicolons in quotes and
+    /// comments are skipped. Comments following the last `;` of a statement are kept with it,
+    /// so that hints like `-- {ErrorCode 1002}` still apply to the statement before them.
+    pub fn split_statements(query: &str) -> Vec<&str> {
+        let bytes = query.as_bytes();
+        let mut ranges: Vec<(usize, usize)> = vec![];
+        let mut start = 0;
+        let mut pos = 0;
+        // Whether the current piece contains anything other than whitespaces and comments.
+        let mut has_tokens = false;
+
+        while pos <= bytes.len() {
+            match bytes.get(pos) {
+                Some(quote @ (b'\'' | b'"' | b'`')) => {
+                    has_tokens = true;
+                    pos += 1;
+                    while pos < bytes.len() {
+                        if bytes[pos] == b'\\' && *quote != b'`' {
+                            pos += 2;
+                        } else if bytes[pos] == *quote && bytes.get(pos + 1) == Some(quote) {
+                            pos += 2;
+                        } else if bytes[pos] == *quote {
+                            break;
+                        } else {
+                            pos += 1;
+                        }
+                    }
+                    pos = std::cmp::min(pos + 1, bytes.len());
+                }
+                Some(b'-') if bytes.get(pos + 1) == Some(&b'-') => {
+                    while pos < bytes.len() && bytes[pos] != b'\n' {
+                        pos += 1;
+                    }
+                }
+                Some(b'/') if bytes.get(pos + 1) == Some(&b'*') => {
+                    pos += 2;
+                    while pos < bytes.len() && !bytes[pos..].starts_with(b"*/") {
+                        pos += 1;
+                    }
+                    pos = std::cmp::min(pos + 2, bytes.len());
+                }
+                Some(b';') | None => {
+                    match ranges.last_mut() {
+                        Some(last) if !has_tokens => last.1 = pos,
+                        _ if has_tokens => ranges.push((start, pos)),
+                        _ => {}
+                    }
+
+                    start = pos + 1;
+                    has_tokens = false;
+                    pos += 1;
+                }
+                Some(c) => {
+                    has_tokens |= !c.is_ascii_whitespace();
+                    pos += 1;
+                }
+            }
+        }
+
+        match ranges.is_empty() {
+            true => vec![query],
+            false => ranges.iter().map(|(s, e)| query[*s..*e].trim()).collect(),
+        }
+    }
 }
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataType;
+use common_datavalues::TypeSerializer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use sha2::Digest;
+
+use crate::sql::DfHint;
+
+/// A hint of a statement not satisfied by the outcome of the statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfHintMismatch {
+    pub hint: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl DfHintMismatch {
+    pub fn create(hint: &str, expected: impl ToString, actual: impl ToString) -> Self {
+        DfHintMismatch {
+            hint: hint.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+
+    pub fn into_error(self) -> ErrorCode {
+        ErrorCode::UnexpectedError(format!(
+            "Expected {}: {} but got: {}.",
+            self.hint, self.expected, self.actual
+        ))
+    }
+}
+
+/// Checks the `rows` and `result_hash` hints against the result of a statement.
+///
+/// The result hash is the hex SHA-256 of the rows in text, the values of a row are separated
+/// by a tab and each row ends with a newline, the same as the TSV output of the result. So the
+/// expected hash of a test corpus can be computed by the test runner of any front end.
+pub fn check_result_hints(hints: &[DfHint], blocks: &[DataBlock]) -> Result<Vec<DfHintMismatch>> {
+    let mut mismatches = vec![];
+    if let Some(expected) = hints.iter().find_map(|h| h.rows) {
+        let actual = blocks.iter().map(|b| b.num_rows() as u64).sum::<u64>();
+        if expected != actual {
+            mismatches.push(DfHintMismatch::create("rows", expected, actual));
+        }
+    }
+
+    if let Some(expected) = hints.iter().find_map(|h| h.result_hash.as_ref()) {
+        let actual = result_hash(blocks)?;
+        if expected != &actual {
+            mismatches.push(DfHintMismatch::create("result_hash", expected, actual));
+        }
+    }
+    Ok(mismatches)
+}
+
+pub fn result_hash(blocks: &[DataBlock]) -> Result<String> {
+    let format = FormatSettings::default();
+    let mut hasher = sha2::Sha256::new();
+    for block in blocks {
+        let mut columns = Vec::with_capacity(block.num_columns());
+        for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+            let serializer = field.data_type().create_serializer();
+            columns.push(serializer.serialize_column(column, &format)?);
+        }
+
+        for row in 0..block.num_rows() {
+            let values = columns.iter().map(|c| c[row].as_str()).collect::<Vec<_>>();
+            hasher.update(values.join("\t").as_bytes());
+            hasher.update(b"\n");
+        }
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
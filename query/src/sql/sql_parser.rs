@@ -35,9 +35,12 @@ use sqlparser::tokenizer::Whitespace;
 
 use super::statements::DfShowRoles;
 use crate::sessions::SessionType;
+use crate::sql::statements::DfShowCaches;
+use crate::sql::statements::DfShowClusters;
 use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowMetrics;
 use crate::sql::statements::DfShowProcessList;
+use crate::sql::statements::DfShowReplicationStatus;
 use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::DfHint;
@@ -198,6 +201,8 @@ impl<'a> DfParser<'a> {
                     Keyword::RENAME => self.parse_rename(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::DELETE => self.parse_delete(),
+                    Keyword::UPDATE => self.parse_update(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -225,6 +230,14 @@ impl<'a> DfParser<'a> {
                         self.parse_list_cmd()
                     }
 
+                    // `SYSTEM` may be reserved by the dialect, so it is matched by its value.
+                    _ if w.value.eq_ignore_ascii_case("SYSTEM") => self.parse_system(),
+                    _ if ["BEGIN", "START", "COMMIT", "ROLLBACK"]
+                        .iter()
+                        .any(|v| w.value.eq_ignore_ascii_case(v)) =>
+                    {
+                        self.parse_transaction()
+                    }
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
@@ -385,6 +398,12 @@ impl<'a> DfParser<'a> {
             self.parse_show_databases()
         } else if self.consume_token("SETTINGS") {
             Ok(DfStatement::ShowSettings(DfShowSettings))
+        } else if self.consume_token("VARIABLES") {
+            self.parse_show_variables()
+        } else if (self.consume_token("SESSION") || self.consume_token("GLOBAL"))
+            && self.consume_token("VARIABLES")
+        {
+            self.parse_show_variables()
         } else if self.consume_token("CREATE") {
             self.parse_show_create()
         } else if self.consume_token("FIELDS") {
@@ -393,6 +412,8 @@ impl<'a> DfParser<'a> {
             Ok(DfStatement::ShowProcessList(DfShowProcessList))
         } else if self.consume_token("METRICS") {
             Ok(DfStatement::ShowMetrics(DfShowMetrics))
+        } else if self.consume_token("CLUSTERS") {
+            Ok(DfStatement::ShowClusters(DfShowClusters))
         } else if self.consume_token("USERS") {
             Ok(DfStatement::ShowUsers(DfShowUsers))
         } else if self.consume_token("ROLES") {
@@ -405,6 +426,10 @@ impl<'a> DfParser<'a> {
             Ok(DfStatement::ShowEngines(DfShowEngines))
         } else if self.consume_token("STAGES") {
             self.parse_show_stages()
+        } else if self.consume_token("REPLICATION") && self.consume_token("STATUS") {
+            Ok(DfStatement::ShowReplicationStatus(DfShowReplicationStatus))
+        } else if self.consume_token("CACHES") {
+            Ok(DfStatement::ShowCaches(DfShowCaches))
         } else {
             self.expected("show statement", self.parser.peek_token())
         }
@@ -414,6 +439,7 @@ impl<'a> DfParser<'a> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::TABLE => self.parse_show_create_table(),
+                Keyword::VIEW => self.parse_show_create_view(),
                 Keyword::DATABASE | Keyword::SCHEMA => self.parse_show_create_database(),
                 _ => self.expected("show create statement", Token::Word(w)),
             },
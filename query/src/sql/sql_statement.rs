@@ -41,7 +41,9 @@ use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
 use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
+use crate::sql::statements::DfDropCache;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropRole;
 use crate::sql::statements::DfDropTable;
@@ -56,21 +58,28 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokePrivilegeStatement;
 use crate::sql::statements::DfSetVariable;
+use crate::sql::statements::DfShowCaches;
+use crate::sql::statements::DfShowClusters;
 use crate::sql::statements::DfShowCreateDatabase;
 use crate::sql::statements::DfShowCreateTable;
+use crate::sql::statements::DfShowCreateView;
 use crate::sql::statements::DfShowDatabases;
 use crate::sql::statements::DfShowEngines;
 use crate::sql::statements::DfShowFunctions;
 use crate::sql::statements::DfShowGrants;
 use crate::sql::statements::DfShowMetrics;
 use crate::sql::statements::DfShowProcessList;
+use crate::sql::statements::DfShowReplicationStatus;
 use crate::sql::statements::DfShowRoles;
 use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTabStat;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUsers;
+use crate::sql::statements::DfShowVariables;
+use crate::sql::statements::DfTransaction;
 use crate::sql::statements::DfTruncateTable;
 use crate::sql::statements::DfUnDropTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 
 /// Tokens parsed by `DFParser` are converted into these values.
@@ -108,15 +117,19 @@ pub enum DfStatement<'a> {
     // TODO(veeupup) make alter and delete view done
     AlterView(DfAlterView),
     DropView(DfDropView),
+    ShowCreateView(DfShowCreateView),
 
     // Settings.
     ShowSettings(DfShowSettings),
+    ShowVariables(DfShowVariables),
+    ShowReplicationStatus(DfShowReplicationStatus),
 
     // ProcessList
     ShowProcessList(DfShowProcessList),
 
     // Metrics
     ShowMetrics(DfShowMetrics),
+    ShowClusters(DfShowClusters),
 
     // Functions
     ShowFunctions(DfShowFunctions),
@@ -124,12 +137,23 @@ pub enum DfStatement<'a> {
     // Kill
     KillStatement(DfKillStatement),
 
+    // Cache
+    ShowCaches(DfShowCaches),
+    DropCache(DfDropCache),
+
+    // Transaction
+    Transaction(DfTransaction),
+
     // Set
     SetVariable(DfSetVariable),
 
     // Insert
     InsertQuery(DfInsertStatement<'a>),
 
+    // Delete and update
+    Delete(DfDeleteStatement),
+    Update(DfUpdateStatement),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
@@ -175,9 +199,15 @@ pub enum DfStatement<'a> {
 /// Comment hints from SQL.
 /// It'll be enabled when using `--comment` in mysql client.
 /// Eg: `SELECT * FROM system.number LIMIT 1; -- { ErrorCode 25 }`
+///
+/// The result of a statement can be checked by the hints too, Eg:
+/// `SELECT number FROM numbers(3); -- { rows 3 } { result_hash <sha256> }`,
+/// see [crate::sql::check_result_hints].
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfHint {
     pub error_code: Option<u16>,
+    pub rows: Option<u64>,
+    pub result_hash: Option<String>,
     pub comment: String,
     pub prefix: String,
 }
@@ -191,11 +221,29 @@ impl DfHint {
 
         Self {
             error_code,
+            rows: Self::parse_value(comment, "rows").and_then(|v| v.parse::<u64>().ok()),
+            result_hash: Self::parse_value(comment, "result_hash").map(|v| v.to_lowercase()),
             comment: comment.to_owned(),
             prefix: prefix.to_owned(),
         }
     }
 
+    pub fn has_result_hints(&self) -> bool {
+        self.rows.is_some() || self.result_hash.is_some()
+    }
+
+    //  { <name> <value> }, there may be several hints in one comment.
+    fn parse_value<'a>(comment: &'a str, name: &str) -> Option<&'a str> {
+        comment.split('{').skip(1).find_map(|hint| {
+            let hint = hint.split('}').next()?;
+            let mut parts = hint.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(key), Some(value), None) if key == name => Some(value),
+                _ => None,
+            }
+        })
+    }
+
     //  { ErrorCode 25 }
     pub fn parse_code(comment: &str) -> IResult<&str, Option<u16>> {
         let (comment, _) = take_till1(|c| c == '{')(comment)?;
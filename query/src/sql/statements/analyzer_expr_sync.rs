@@ -24,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_meta_types::UDFLanguage;
 use common_meta_types::UserDefinedFunction;
 use common_planners::Expression;
 use sqlparser::ast::DateTimeField;
@@ -556,6 +557,12 @@ impl UDFFetcher for ExprRPNBuilder {
         let udf = self.udfs.iter().find(|udf| udf.name == name);
 
         if let Some(udf) = udf {
+            if udf.language != UDFLanguage::Sql {
+                return Err(ErrorCode::UnImplement(format!(
+                    "{} function {} is only supported by the new planner, enable it by `set enable_planner_v2 = 1`",
+                    udf.language, name
+                )));
+            }
             let mut udf_parser = UDFParser::default();
             let definition = udf_parser.parse(&udf.name, &udf.parameters, &udf.definition)?;
             return Ok(UDFDefinition::new(udf.parameters.clone(), definition));
@@ -163,9 +163,15 @@ impl<'a> AnalyzableStatement for DfStatement<'a> {
             DfStatement::ShowProcessList(v) => v.analyze(ctx).await,
             DfStatement::ShowRoles(v) => v.analyze(ctx).await,
             DfStatement::ShowMetrics(v) => v.analyze(ctx).await,
+            DfStatement::ShowClusters(v) => v.analyze(ctx).await,
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
+            DfStatement::ShowCaches(v) => v.analyze(ctx).await,
+            DfStatement::DropCache(v) => v.analyze(ctx).await,
+            DfStatement::Transaction(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
+            DfStatement::Update(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
@@ -191,8 +197,11 @@ impl<'a> AnalyzableStatement for DfStatement<'a> {
             DfStatement::CreateView(v) => v.analyze(ctx).await,
             DfStatement::AlterView(v) => v.analyze(ctx).await,
             DfStatement::DropView(v) => v.analyze(ctx).await,
+            DfStatement::ShowCreateView(v) => v.analyze(ctx).await,
             DfStatement::ShowTabStat(v) => v.analyze(ctx).await,
             DfStatement::ShowStages(v) => v.analyze(ctx).await,
+            DfStatement::ShowVariables(v) => v.analyze(ctx).await,
+            DfStatement::ShowReplicationStatus(v) => v.analyze(ctx).await,
         }
     }
 }
@@ -33,8 +33,10 @@ mod statement_create_udf;
 mod statement_create_user;
 mod statement_create_user_stage;
 mod statement_create_view;
+mod statement_delete;
 mod statement_describe_table;
 mod statement_describe_user_stage;
+mod statement_drop_cache;
 mod statement_drop_database;
 mod statement_drop_role;
 mod statement_drop_table;
@@ -53,8 +55,11 @@ mod statement_revoke;
 mod statement_select;
 mod statement_select_convert;
 mod statement_set_variable;
+mod statement_show_caches;
+mod statement_show_clusters;
 mod statement_show_create_database;
 mod statement_show_create_table;
+mod statement_show_create_view;
 mod statement_show_databases;
 mod statement_show_engines;
 mod statement_show_functions;
@@ -62,15 +67,19 @@ mod statement_show_grants;
 mod statement_show_kind;
 mod statement_show_metrics;
 mod statement_show_processlist;
+mod statement_show_replication_status;
 mod statement_show_roles;
 mod statement_show_settings;
 mod statement_show_stages;
 mod statement_show_tab_stat;
 mod statement_show_tables;
 mod statement_show_users;
+mod statement_show_variables;
+mod statement_transaction;
 mod statement_truncate_table;
 mod statement_undrop_database;
 mod statement_undrop_table;
+mod statement_update;
 mod statement_use_database;
 mod value_source;
 
@@ -100,8 +109,10 @@ pub use statement_create_user::DfCreateUser;
 pub use statement_create_user::DfUserWithOption;
 pub use statement_create_user_stage::DfCreateUserStage;
 pub use statement_create_view::DfCreateView;
+pub use statement_delete::DfDeleteStatement;
 pub use statement_describe_table::DfDescribeTable;
 pub use statement_describe_user_stage::DfDescribeUserStage;
+pub use statement_drop_cache::DfDropCache;
 pub use statement_drop_database::DfDropDatabase;
 pub use statement_drop_role::DfDropRole;
 pub use statement_drop_table::DfDropTable;
@@ -123,8 +134,11 @@ pub use statement_revoke::DfRevokePrivilegeStatement;
 pub use statement_revoke::DfRevokeRoleStatement;
 pub use statement_select::DfQueryStatement;
 pub use statement_set_variable::DfSetVariable;
+pub use statement_show_caches::DfShowCaches;
+pub use statement_show_clusters::DfShowClusters;
 pub use statement_show_create_database::DfShowCreateDatabase;
 pub use statement_show_create_table::DfShowCreateTable;
+pub use statement_show_create_view::DfShowCreateView;
 pub use statement_show_databases::DfShowDatabases;
 pub use statement_show_engines::DfShowEngines;
 pub use statement_show_functions::DfShowFunctions;
@@ -132,14 +146,18 @@ pub use statement_show_grants::DfShowGrants;
 pub use statement_show_kind::DfShowKind;
 pub use statement_show_metrics::DfShowMetrics;
 pub use statement_show_processlist::DfShowProcessList;
+pub use statement_show_replication_status::DfShowReplicationStatus;
 pub use statement_show_roles::DfShowRoles;
 pub use statement_show_settings::DfShowSettings;
 pub use statement_show_stages::DfShowStages;
 pub use statement_show_tab_stat::DfShowTabStat;
 pub use statement_show_tables::DfShowTables;
 pub use statement_show_users::DfShowUsers;
+pub use statement_show_variables::DfShowVariables;
+pub use statement_transaction::DfTransaction;
 pub use statement_truncate_table::DfTruncateTable;
 pub use statement_undrop_database::DfUnDropDatabase;
 pub use statement_undrop_table::DfUnDropTable;
+pub use statement_update::DfUpdateStatement;
 pub use statement_use_database::DfUseDatabase;
 pub use value_source::ValueSource;
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
 use sqlparser::ast::Instant;
@@ -26,7 +28,6 @@ use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::TableWithJoins;
 
-use crate::catalogs::CATALOG_DEFAULT;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
@@ -36,6 +37,7 @@ use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfParser;
 use crate::sql::DfStatement;
+use crate::storages::validate_time_travel;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::view::view_table::VIEW_ENGINE;
 use crate::storages::NavigationPoint;
@@ -103,8 +105,22 @@ impl JoinedSchemaAnalyzer {
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (catalog, database, table) = resolve_table(&self.ctx, &item.name, "SELECT")?;
+
+        // The system databases are readable by all the users.
+        if database != "system" && database != "information_schema" {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(
+                    &GrantObject::Table(catalog.clone(), database.clone(), table.clone()),
+                    UserPrivilegeType::Select,
+                )
+                .await?;
+        }
+
         let mut read_table = self.ctx.get_table(&catalog, &database, &table).await?;
         if let Some(Instant::SnapshotID(s)) = &item.instant {
+            let catalog = self.ctx.get_catalog(&catalog)?;
+            validate_time_travel(catalog.as_ref(), read_table.as_ref())?;
             let navigation_point = NavigationPoint::SnapshotID(s.to_owned());
             read_table = read_table
                 .navigate_to(self.ctx.clone(), &navigation_point)
@@ -164,8 +180,7 @@ impl JoinedSchemaAnalyzer {
 
         // always look up table_function in the default catalog?
         // TODO seems buggy
-        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
-        let table_function = catalog.get_table_function(&table_name, Some(table_args))?;
+        let table_function = self.ctx.get_table_function(&table_name, Some(table_args))?;
         match &item.alias {
             None => JoinedSchema::from_table(table_function.as_table(), Vec::new()),
             Some(table_alias) => {
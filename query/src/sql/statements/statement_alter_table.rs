@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::AlterClusterKeyPlan;
+use common_planners::DropTablePartitionPlan;
+use common_planners::ExportTableSnapshotPlan;
+use common_planners::Expression;
+use common_planners::PartitionRef;
 use common_planners::PlanNode;
+use common_planners::PromoteTablePlan;
 use common_planners::RenameTableEntity;
 use common_planners::RenameTablePlan;
 use common_tracing::tracing;
@@ -25,6 +31,8 @@ use sqlparser::ast::Expr;
 use sqlparser::ast::ObjectName;
 
 use super::analyzer_expr::ExpressionAnalyzer;
+use super::parse_stage_location;
+use super::parse_uri_location;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
@@ -40,6 +48,15 @@ pub struct DfAlterTable {
 pub enum AlterTableAction {
     RenameTable(ObjectName),
     AlterClusterKey(Vec<Expr>),
+    /// The names of the RANGE or LIST partitions, or the values of the partition column
+    DropPartition(Vec<Expr>),
+    Promote,
+    ExportSnapshot {
+        location: String,
+        credential_options: BTreeMap<String, String>,
+        encryption_options: BTreeMap<String, String>,
+        format: String,
+    },
     // TODO AddColumn etc.
 }
 
@@ -88,6 +105,74 @@ impl AnalyzableStatement for DfAlterTable {
                     }),
                 )))
             }
+            AlterTableAction::DropPartition(exprs) => {
+                let expression_analyzer = ExpressionAnalyzer::create(ctx);
+                let mut partitions = Vec::with_capacity(exprs.len());
+                for expr in exprs {
+                    let partition = match expr {
+                        Expr::Identifier(name) => PartitionRef::Name(name.value.clone()),
+                        _ => match expression_analyzer.analyze_sync(expr)? {
+                            Expression::Literal { value, .. } => PartitionRef::Value(value),
+                            other => {
+                                return Err(ErrorCode::SyntaxException(format!(
+                                    "partition must be a name or a constant, but got {}",
+                                    other.column_name()
+                                )));
+                            }
+                        },
+                    };
+                    partitions.push(partition);
+                }
+
+                Ok(AnalyzedResult::SimpleQuery(Box::new(
+                    PlanNode::DropTablePartition(DropTablePartitionPlan {
+                        tenant,
+                        catalog_name,
+                        database_name,
+                        table_name,
+                        partitions,
+                    }),
+                )))
+            }
+            AlterTableAction::Promote => Ok(AnalyzedResult::SimpleQuery(Box::new(
+                PlanNode::PromoteTable(PromoteTablePlan {
+                    tenant,
+                    catalog_name,
+                    database_name,
+                    table_name,
+                }),
+            ))),
+            AlterTableAction::ExportSnapshot {
+                location,
+                credential_options,
+                encryption_options,
+                format,
+            } => {
+                // The blocks of the fuse tables are exported as they are.
+                if !format.eq_ignore_ascii_case("PARQUET") {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Unsupported snapshot export format: {}, only PARQUET is supported",
+                        format
+                    )));
+                }
+
+                let (stage, path) = if location.starts_with('@') {
+                    parse_stage_location(&ctx, location).await?
+                } else {
+                    parse_uri_location(location, credential_options, encryption_options)?
+                };
+
+                Ok(AnalyzedResult::SimpleQuery(Box::new(
+                    PlanNode::ExportTableSnapshot(ExportTableSnapshotPlan {
+                        tenant,
+                        catalog_name,
+                        database_name,
+                        table_name,
+                        stage,
+                        path,
+                    }),
+                )))
+            }
         }
     }
 }
@@ -48,6 +48,7 @@ impl AnalyzableStatement for DfAlterUser {
         };
 
         let new_auth_info = if let Some(auth_option) = &self.auth_option {
+            auth_option.check_password(&ctx).await?;
             let auth_info = user_info
                 .auth_info
                 .alter(&auth_option.auth_type, &auth_option.by_value)?;
@@ -22,6 +22,7 @@ use sqlparser::ast::ObjectName;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::resolve_table;
+use crate::sql::statements::resolve_view_dependencies;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
@@ -45,6 +46,7 @@ impl AnalyzableStatement for DfAlterView {
         let subquery = self.subquery.clone();
         let tenant = ctx.get_tenant();
         let (catalog, db, viewname) = resolve_table(&ctx, &self.name, "ALTER VIEW")?;
+        let dependencies = resolve_view_dependencies(&ctx, &catalog);
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterView(
             AlterViewPlan {
                 tenant,
@@ -52,6 +54,7 @@ impl AnalyzableStatement for DfAlterView {
                 db,
                 viewname,
                 subquery,
+                dependencies,
             },
         ))))
     }
@@ -16,9 +16,11 @@ use std::collections::BTreeMap;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::parse_escape_string;
+use common_io::prelude::StorageFsConfig;
 use common_io::prelude::StorageParams;
 use common_io::prelude::StorageS3Config;
 use common_meta_types::FileFormatOptions;
@@ -26,10 +28,16 @@ use common_meta_types::StageFileFormatType;
 use common_meta_types::StageParams;
 use common_meta_types::StageType;
 use common_meta_types::UserStageInfo;
+use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::find_subquery_exprs;
+use common_planners::Expression;
 use common_tracing::tracing::debug;
+use sqlparser::ast::Expr;
 use sqlparser::ast::ObjectName;
 
+use crate::formats::FormatFactory;
 use crate::sessions::QueryContext;
+use crate::sql::statements::ExpressionAnalyzer;
 
 /// Named stage(start with `@`):
 ///
@@ -96,6 +104,8 @@ pub async fn parse_stage_location(
 ///     encryption=(master_key = 'my_master_key')
 ///     file_format = (type = csv field_delimiter = '|' skip_header = 1)"
 /// ```
+///
+/// The files on the local fs of the query node are located by `'fs:///<path>[/]'`.
 pub fn parse_uri_location(
     location: &str,
     credential_options: &BTreeMap<String, String>,
@@ -104,6 +114,34 @@ pub fn parse_uri_location(
     // TODO(xuanwo): we should support use non-aws s3 as stage like oss.
     // TODO(xuanwo): we should make the path logic more clear, ref: https://github.com/datafuselabs/databend/issues/5295
 
+    // Local fs, the uri has no host.
+    // 'fs:///<path>[/]'
+    if let Some(path) = location.strip_prefix("fs://") {
+        if !path.starts_with('/') {
+            return Err(ErrorCode::SyntaxException(
+                "File location uri of local fs must be absolute, for example: 'fs:///<path>[/]'",
+            ));
+        }
+
+        // Same as s3, a directory is the root, a file is the path towards `/`.
+        let (root, path) = if path.ends_with('/') {
+            (path, "/")
+        } else {
+            ("/", path)
+        };
+        let stage = UserStageInfo {
+            stage_name: location.to_string(),
+            stage_type: StageType::External,
+            stage_params: StageParams {
+                storage: StorageParams::Fs(StorageFsConfig {
+                    root: root.to_string(),
+                }),
+            },
+            ..Default::default()
+        };
+        return Ok((stage, path.to_string()));
+    }
+
     // Parse uri.
     // 's3://<bucket>[/<path>/]'
     let uri = location.parse::<http::Uri>().map_err(|_e| {
@@ -168,7 +206,7 @@ pub fn parse_uri_location(
 
             // Others.
             _ => Err(ErrorCode::SyntaxException(
-                "File location uri unsupported, must be one of [s3, @stage]",
+                "File location uri unsupported, must be one of [s3, fs, @stage]",
             )),
         },
     }?;
@@ -185,6 +223,39 @@ pub fn parse_uri_location(
     Ok((stage, path))
 }
 
+/// Check the `FILE_FORMAT` options against the options registered with the format in the
+/// `FormatFactory`, `unload` is for the formats writing the files.
+pub fn check_copy_file_format_options(
+    file_format_options: &BTreeMap<String, String>,
+    unload: bool,
+) -> Result<()> {
+    let factory = FormatFactory::instance();
+    let format = match file_format_options.get("type") {
+        // The missing type is reported by `parse_copy_file_format_options`.
+        None => return Ok(()),
+        Some(format) => format,
+    };
+
+    // The stage file formats without options registered, e.g. AVRO, ORC and XML, are
+    // accepted as before, they are reported when the files are read or written.
+    let registered = match unload {
+        true => factory
+            .output_formats()
+            .any(|(name, _)| name.eq_ignore_ascii_case(format)),
+        false => factory
+            .input_formats()
+            .any(|(name, _)| name.eq_ignore_ascii_case(format)),
+    };
+    if !registered && StageFileFormatType::from_str(format).is_ok() {
+        return Ok(());
+    }
+
+    match unload {
+        true => factory.check_output_options(format, file_format_options),
+        false => factory.check_input_options(format, file_format_options),
+    }
+}
+
 pub fn parse_copy_file_format_options(
     file_format_options: &BTreeMap<String, String>,
 ) -> Result<FileFormatOptions> {
@@ -269,6 +340,22 @@ pub fn resolve_table(
     }
 }
 
+/// The tables of `catalog` that the analyzed query of a view reads, the system tables excluded.
+pub fn resolve_view_dependencies(ctx: &QueryContext, catalog: &str) -> Vec<(String, String)> {
+    let mut dependencies = ctx
+        .get_referenced_tables()
+        .into_iter()
+        .filter(|(table_catalog, db, _)| {
+            table_catalog == catalog
+                && db != "system"
+                && !db.eq_ignore_ascii_case("information_schema")
+        })
+        .map(|(_, db, table)| (db, table))
+        .collect::<Vec<_>>();
+    dependencies.sort();
+    dependencies
+}
+
 pub fn resolve_database(
     ctx: &QueryContext,
     name: &ObjectName,
@@ -288,3 +375,28 @@ pub fn resolve_database(
         ))),
     }
 }
+
+/// Analyzes the WHERE and the SET values of DELETE and UPDATE against the schema of the table,
+/// the rows are rewritten block by block, so subqueries and aggregate functions are not allowed.
+pub async fn analyze_mutation_selection(
+    ctx: Arc<QueryContext>,
+    selection: &Expr,
+    schema: &DataSchemaRef,
+    statement_name: &str,
+) -> Result<Expression> {
+    let expression = ExpressionAnalyzer::create(ctx).analyze(selection).await?;
+    if !find_subquery_exprs(&[expression.clone()]).is_empty() {
+        return Err(ErrorCode::SyntaxException(format!(
+            "subquery is not supported in {}",
+            statement_name
+        )));
+    }
+    if !find_aggregate_exprs_in_expr(&expression).is_empty() {
+        return Err(ErrorCode::SyntaxException(format!(
+            "aggregate function is not supported in {}",
+            statement_name
+        )));
+    }
+    expression.to_data_field(schema)?;
+    Ok(expression)
+}
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::MatchByColumnName;
 use common_meta_types::OnErrorMode;
 use common_meta_types::UserStageInfo;
 use common_planners::CopyMode;
@@ -32,6 +33,7 @@ use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 
+use super::check_copy_file_format_options;
 use super::parse_copy_file_format_options;
 use super::parse_stage_location;
 use super::parse_uri_location;
@@ -55,8 +57,13 @@ pub struct DfCopy {
     pub pattern: String,
     pub on_error: String,
     pub size_limit: String,
+    pub match_by_column_name: String,
+    pub max_file_size: String,
+    pub ordered: String,
     pub validation_mode: String,
     pub query: Option<Query>,
+    /// `SELECT ... INTO`, the result is written while the query runs.
+    pub spool: bool,
 }
 
 #[async_trait::async_trait]
@@ -66,6 +73,7 @@ impl AnalyzableStatement for DfCopy {
         let (mut stage_info, path) = self.analyze_location(&ctx).await?;
 
         if !self.file_format_options.is_empty() {
+            check_copy_file_format_options(&self.file_format_options, self.query.is_some())?;
             stage_info.file_format_options =
                 parse_copy_file_format_options(&self.file_format_options)?;
         }
@@ -88,13 +96,46 @@ impl AnalyzableStatement for DfCopy {
                 })?;
                 stage_info.copy_options.size_limit = size_limit;
             }
+
+            // match_by_column_name.
+            if !self.match_by_column_name.is_empty() {
+                stage_info.copy_options.match_by_column_name =
+                    MatchByColumnName::from_str(&self.match_by_column_name)
+                        .map_err(ErrorCode::SyntaxException)?;
+            }
         }
 
         // Validation mode.
         let validation_mode = ValidationMode::from_str(self.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
+        // Max file size of unloading, 0 is unlimited.
+        let max_file_size = match self.max_file_size.as_str() {
+            "" => 0,
+            v => v.parse::<usize>().map_err(|_e| {
+                ErrorCode::SyntaxException(format!("max_file_size must be number, got: {}", v))
+            })?,
+        };
+
+        // Ordered unloading.
+        let ordered = match self.ordered.to_lowercase().as_str() {
+            "" | "false" => false,
+            "true" => true,
+            _ => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "ordered must be TRUE or FALSE, got: {}",
+                    self.ordered
+                )));
+            }
+        };
+
         let plan_node = if let Some(query) = &self.query {
+            if ordered && query.order_by.is_empty() {
+                return Err(ErrorCode::SyntaxException(
+                    "ORDERED = TRUE requires the query to have ORDER BY",
+                ));
+            }
+
             let statement = DfQueryStatement::try_from(query.clone())?;
             let query =
                 PlanParser::build_plan(vec![DfStatement::Query(Box::new(statement))], ctx.clone())
@@ -110,9 +151,23 @@ impl AnalyzableStatement for DfCopy {
                         files: vec![],
                     },
                     query: Box::new(query),
+                    ordered,
+                    max_file_size,
+                    spool: self.spool,
                 },
             }
         } else {
+            if ordered {
+                return Err(ErrorCode::SyntaxException(
+                    "ORDERED is only supported when copying into a stage",
+                ));
+            }
+            if max_file_size > 0 {
+                return Err(ErrorCode::SyntaxException(
+                    "MAX_FILE_SIZE is only supported when copying into a stage",
+                ));
+            }
+
             let (catalog_name, db_name, tbl_name) = resolve_table(&ctx, &self.name, "COPY")?;
             let table = ctx.get_table(&catalog_name, &db_name, &tbl_name).await?;
             let mut schema = table.schema();
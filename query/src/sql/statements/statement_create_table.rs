@@ -16,9 +16,11 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::remove_nullable;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableMeta;
@@ -34,6 +36,7 @@ use sqlparser::ast::ObjectName;
 use super::analyzer_expr::ExpressionAnalyzer;
 use crate::sessions::QueryContext;
 use crate::sql::is_reserved_opt_key;
+use crate::sql::parse_broadcast_option;
 use crate::sql::statements::resolve_table;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
@@ -41,7 +44,11 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::sql::OPT_KEY_BROADCAST;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::sql::OPT_KEY_PARTITION_BY;
+use crate::sql::OPT_KEY_TTL;
+use crate::storages::fuse::partitioning::TablePartitioning;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -137,6 +144,9 @@ impl DfCreateTable {
 
         self.validate_table_options()?;
         self.validata_default_exprs(&schema)?;
+        self.validate_ttl_expr(&schema)?;
+        self.validate_partition_by(&schema)?;
+        self.validate_broadcast()?;
 
         let meta = TableMeta {
             schema,
@@ -266,4 +276,54 @@ impl DfCreateTable {
         }
         Ok(())
     }
+
+    fn validate_ttl_expr(&self, schema: &DataSchemaRef) -> Result<()> {
+        if let Some(ttl) = self.options.get(OPT_KEY_TTL) {
+            if self.engine.to_uppercase().as_str() != "FUSE" {
+                return Err(ErrorCode::BadOption(format!(
+                    "TTL is not supported by table engine {}",
+                    self.engine
+                )));
+            }
+            let expr = PlanParser::parse_expr(ttl)?;
+            validate_expression(&expr, schema)?;
+            // The rows expire once the time of the TTL is earlier than now.
+            let data_type = remove_nullable(&expr.to_data_type(schema)?);
+            if !data_type.data_type_id().is_date_or_date_time() {
+                return Err(ErrorCode::BadOption(format!(
+                    "TTL must be an expression of Date or Timestamp, but got {}",
+                    data_type.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_partition_by(&self, schema: &DataSchemaRef) -> Result<()> {
+        if let Some(column) = self.options.get(OPT_KEY_PARTITION_BY) {
+            if self.engine.to_uppercase().as_str() != "FUSE" {
+                return Err(ErrorCode::BadOption(format!(
+                    "PARTITION BY is not supported by table engine {}",
+                    self.engine
+                )));
+            }
+            schema.index_of(column)?;
+            // Check the RANGE or LIST partitions could be cast to the type of the column.
+            TablePartitioning::try_create(&self.options, schema)?;
+        }
+        Ok(())
+    }
+
+    fn validate_broadcast(&self) -> Result<()> {
+        if let Some(broadcast) = self.options.get(OPT_KEY_BROADCAST) {
+            if self.engine.to_uppercase().as_str() != "FUSE" {
+                return Err(ErrorCode::BadOption(format!(
+                    "BROADCAST is not supported by table engine {}",
+                    self.engine
+                )));
+            }
+            parse_broadcast_option(broadcast)?;
+        }
+        Ok(())
+    }
 }
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::UDFLanguage;
 use common_meta_types::UserDefinedFunction;
 use common_planners::CreateUserUDFPlan;
 use common_planners::PlanNode;
@@ -31,6 +32,11 @@ pub struct DfCreateUDF {
     pub parameters: Vec<String>,
     pub definition: String,
     pub description: String,
+    pub language: UDFLanguage,
+    pub arg_types: Vec<String>,
+    pub return_type: String,
+    pub handler: String,
+    pub address: String,
 }
 
 #[async_trait::async_trait]
@@ -40,12 +46,31 @@ impl AnalyzableStatement for DfCreateUDF {
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateUserUDF(CreateUserUDFPlan {
                 if_not_exists: self.if_not_exists,
-                udf: UserDefinedFunction::new(
-                    self.udf_name.as_str(),
-                    self.parameters.clone(),
-                    self.definition.as_str(),
-                    self.description.as_str(),
-                ),
+                udf: match self.language {
+                    UDFLanguage::Sql => UserDefinedFunction::new(
+                        self.udf_name.as_str(),
+                        self.parameters.clone(),
+                        self.definition.as_str(),
+                        self.description.as_str(),
+                    ),
+                    UDFLanguage::Wasm => UserDefinedFunction::new_wasm(
+                        self.udf_name.as_str(),
+                        self.parameters.clone(),
+                        self.arg_types.clone(),
+                        self.return_type.as_str(),
+                        self.definition.as_str(),
+                        self.description.as_str(),
+                    ),
+                    UDFLanguage::Python => UserDefinedFunction::new_python(
+                        self.udf_name.as_str(),
+                        self.parameters.clone(),
+                        self.arg_types.clone(),
+                        self.return_type.as_str(),
+                        self.handler.as_str(),
+                        self.address.as_str(),
+                        self.description.as_str(),
+                    ),
+                },
             }),
         )))
     }
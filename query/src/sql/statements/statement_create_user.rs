@@ -41,6 +41,18 @@ impl DfAuthOption {
             by_value: None,
         }
     }
+
+    /// Check the plaintext password against the password policy of the tenant.
+    pub async fn check_password(&self, ctx: &QueryContext) -> Result<()> {
+        match &self.by_value {
+            None => Ok(()),
+            Some(password) => {
+                ctx.get_user_manager()
+                    .check_password(&ctx.get_tenant(), password.as_bytes())
+                    .await
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +61,8 @@ pub enum DfUserWithOption {
     NoTenantSetting,
     ConfigReload,
     NoConfigReload,
+    Sandbox,
+    NoSandbox,
 }
 
 impl TryFrom<&str> for DfUserWithOption {
@@ -60,6 +74,8 @@ impl TryFrom<&str> for DfUserWithOption {
             "NOTENANTSETTING" => Ok(DfUserWithOption::NoTenantSetting),
             "CONFIGRELOAD" => Ok(DfUserWithOption::ConfigReload),
             "NOCONFIGRELOAD" => Ok(DfUserWithOption::NoConfigReload),
+            "SANDBOX" => Ok(DfUserWithOption::Sandbox),
+            "NOSANDBOX" => Ok(DfUserWithOption::NoSandbox),
             _ => Err(format!("Unknown user option: {}", value)),
         }
     }
@@ -80,6 +96,12 @@ impl DfUserWithOption {
             Self::NoConfigReload => {
                 option.unset_option_flag(UserOptionFlag::ConfigReload);
             }
+            Self::Sandbox => {
+                option.set_option_flag(UserOptionFlag::Sandbox);
+            }
+            Self::NoSandbox => {
+                option.unset_option_flag(UserOptionFlag::Sandbox);
+            }
         }
     }
 }
@@ -94,8 +116,10 @@ pub struct DfCreateUser {
 
 #[async_trait::async_trait]
 impl AnalyzableStatement for DfCreateUser {
-    #[tracing::instrument(level = "debug", skip(self, _ctx), fields(ctx.id = _ctx.get_id().as_str()))]
-    async fn analyze(&self, _ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        self.auth_option.check_password(&ctx).await?;
+
         let mut user_option = UserOption::default();
         for option in &self.with_options {
             option.apply(&mut user_option);
@@ -25,6 +25,7 @@ use common_planners::CreateUserStagePlan;
 use common_planners::PlanNode;
 use common_tracing::tracing;
 
+use super::check_copy_file_format_options;
 use super::parse_copy_file_format_options;
 use super::parse_uri_location;
 use crate::sessions::QueryContext;
@@ -69,6 +70,7 @@ impl AnalyzableStatement for DfCreateUserStage {
         stage_info.stage_name = self.stage_name.clone();
 
         if !self.file_format_options.is_empty() {
+            check_copy_file_format_options(&self.file_format_options, false)?;
             stage_info.file_format_options =
                 parse_copy_file_format_options(&self.file_format_options)?;
         }
@@ -23,6 +23,7 @@ use sqlparser::ast::ObjectName;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::resolve_table;
+use crate::sql::statements::resolve_view_dependencies;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
 use crate::sql::statements::DfQueryStatement;
@@ -48,6 +49,7 @@ impl AnalyzableStatement for DfCreateView {
         let subquery = self.subquery.clone();
         let tenant = ctx.get_tenant();
         let (catalog, db, viewname) = resolve_table(&ctx, &self.name, "CREATE VIEW")?;
+        let dependencies = resolve_view_dependencies(&ctx, &catalog);
         Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::CreateView(
             CreateViewPlan {
                 if_not_exists,
@@ -56,6 +58,7 @@ impl AnalyzableStatement for DfCreateView {
                 db,
                 viewname,
                 subquery,
+                dependencies,
             },
         ))))
     }
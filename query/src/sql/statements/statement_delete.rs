@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyze_mutation_selection;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::storages::validate_delete;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDeleteStatement {
+    pub name: ObjectName,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfDeleteStatement {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (catalog_name, database_name, table_name) =
+            super::resolve_table(&ctx, &self.name, "DELETE")?;
+        let table = ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let catalog = ctx.get_catalog(&catalog_name)?;
+        validate_delete(catalog.as_ref(), table.as_ref(), "DELETE")?;
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => {
+                let schema = table.schema();
+                Some(analyze_mutation_selection(ctx.clone(), expr, &schema, "DELETE").await?)
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Delete(
+            DeletePlan {
+                catalog_name,
+                database_name,
+                table_name,
+                selection,
+            },
+        ))))
+    }
+}
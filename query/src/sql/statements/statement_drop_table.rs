@@ -30,6 +30,8 @@ pub struct DfDropTable {
     pub if_exists: bool,
     pub name: ObjectName,
     pub all: bool,
+    /// Drop the views depending on the table as well
+    pub cascade: bool,
 }
 
 #[async_trait::async_trait]
@@ -38,6 +40,7 @@ impl AnalyzableStatement for DfDropTable {
     async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
         let if_exists = self.if_exists;
         let all = self.all;
+        let cascade = self.cascade;
         let tenant = ctx.get_tenant();
         let (catalog, db, table) = resolve_table(&ctx, &self.name, "DROP TABLE")?;
 
@@ -49,6 +52,7 @@ impl AnalyzableStatement for DfDropTable {
                 db,
                 table,
                 all,
+                cascade,
             },
         ))))
     }
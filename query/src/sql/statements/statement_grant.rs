@@ -39,6 +39,7 @@ pub enum DfGrantObject {
     Global,
     Database(Option<String>),
     Table(Option<String>, String),
+    FutureTables(Option<String>),
 }
 
 impl DfGrantObject {
@@ -59,6 +60,12 @@ impl DfGrantObject {
                     .unwrap_or_else(|| ctx.get_current_database());
                 GrantObject::Database(catalog_name, database_name)
             }
+            DfGrantObject::FutureTables(database_name) => {
+                let database_name = database_name
+                    .clone()
+                    .unwrap_or_else(|| ctx.get_current_database());
+                GrantObject::FutureTables(catalog_name, database_name)
+            }
         }
     }
 }
@@ -30,6 +30,7 @@ use crate::sql::statements::AnalyzedResult;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfSetVariable {
     pub local: bool,
+    pub global: bool,
     pub hivevar: bool,
     pub variable: Ident,
     pub value: Vec<SetVariableValue>,
@@ -45,7 +46,6 @@ impl AnalyzableStatement for DfSetVariable {
             ));
         }
 
-        // TODO: session variable and local variable
         let vars = self.mapping_set_vars();
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::SetVariable(SettingPlan { vars }),
@@ -54,8 +54,9 @@ impl AnalyzableStatement for DfSetVariable {
 }
 
 impl DfSetVariable {
-    fn mapping_set_var(variable: String, value: &SetVariableValue) -> VarValue {
+    fn mapping_set_var(variable: String, is_global: bool, value: &SetVariableValue) -> VarValue {
         VarValue {
+            is_global,
             variable,
             value: match value {
                 sqlparser::ast::SetVariableValue::Ident(v) => v.value.clone(),
@@ -68,7 +69,7 @@ impl DfSetVariable {
         let variable = self.variable.value.clone();
         self.value
             .iter()
-            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), value))
+            .map(|value| DfSetVariable::mapping_set_var(variable.clone(), self.global, value))
             .collect()
     }
 }
@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_planners::ShowCreateViewPlan;
+use common_tracing::tracing;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfShowCreateView {
+    pub name: ObjectName,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfShowCreateView {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let schema = Self::schema();
+        let (catalog, db, viewname) = super::resolve_table(&ctx, &self.name, "SHOW CREATE VIEW")?;
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::ShowCreateView(ShowCreateViewPlan {
+                catalog,
+                db,
+                viewname,
+                schema,
+            }),
+        )))
+    }
+}
+
+impl DfShowCreateView {
+    fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("View", Vu8::to_data_type()),
+            DataField::new("Create View", Vu8::to_data_type()),
+        ])
+    }
+}
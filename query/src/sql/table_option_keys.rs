@@ -14,11 +14,49 @@
 
 use std::collections::HashSet;
 
+use common_exception::ErrorCode;
+use common_exception::Result;
 use lazy_static::lazy_static;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 
+/// Table row time-to-live expression
+///
+/// Rows for which the expression evaluates to a time point earlier than `now()`
+/// are considered expired, and will be dropped while the table is compacted.
+pub const OPT_KEY_TTL: &str = "ttl";
+
+/// Partition column of table
+///
+/// Each block of a partitioned table holds rows of exactly one partition (i.e. one
+/// distinct value of the partition column, or one of the partitions defined).
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+
+/// RANGE or LIST partitions of table
+///
+/// The partitions defined by `PARTITION BY RANGE|LIST (column) (...)`, in json, see
+/// `PartitionDefs`.
+pub const OPT_KEY_PARTITIONS: &str = "partitions";
+
+/// Replication target of table
+///
+/// Name of an external stage, new snapshots of the table (and the segments and blocks
+/// they refer to) are copied to the stage asynchronously after each commit.
+pub const OPT_KEY_REPLICATE_TO: &str = "replicate_to";
+
+/// Replication source of table
+///
+/// Storage prefix of the primary table which replicates to the storage of this table.
+/// A replica is read-only, until it is promoted by `ALTER TABLE .. PROMOTE`.
+pub const OPT_KEY_REPLICA_OF: &str = "replica_of";
+
+/// Broadcast table
+///
+/// The blocks of a broadcast table are read as a whole and cached by every query node,
+/// joins against it are planned without a shuffle.
+pub const OPT_KEY_BROADCAST: &str = "broadcast";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -47,12 +85,39 @@ lazy_static! {
         r.insert(OPT_KEY_DATABASE_ID);
         r
     };
+
+    /// Table option keys that are set by the clauses of `create table` only
+    /// - Users are not allowed to specified this option keys as table options
+    /// - Shown as the clauses in `show create table` statement
+    pub static ref DDL_TABLE_OPTION_KEYS: HashSet<&'static str> = {
+        let mut r = HashSet::new();
+        r.insert(OPT_KEY_PARTITION_BY);
+        r.insert(OPT_KEY_PARTITIONS);
+        r.insert(OPT_KEY_REPLICA_OF);
+        r
+    };
 }
 
 pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     RESERVED_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
 
+pub fn is_ddl_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
+    DDL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
+}
+
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+/// Parses the value of `OPT_KEY_BROADCAST`, one of `true`, `false`, `1` and `0`.
+pub fn parse_broadcast_option(value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        _ => Err(ErrorCode::BadOption(format!(
+            "Invalid value '{}' of table option {}, expected true or false",
+            value, OPT_KEY_BROADCAST
+        ))),
+    }
+}
@@ -12,16 +12,70 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_cache::Cache;
+
 use crate::config::QueryConfig;
 use crate::storages::fuse::cache;
+use crate::storages::fuse::cache::BroadcastTableCache;
 use crate::storages::fuse::cache::MemoryCache;
 use crate::storages::fuse::cache::SegmentInfoCache;
 use crate::storages::fuse::cache::TableSnapshotCache;
+use crate::storages::fuse::cache::TenantLabel;
+
+pub const TABLE_SNAPSHOT_CACHE: &str = "table_snapshot";
+pub const SEGMENT_INFO_CACHE: &str = "segment_info";
+pub const QUERY_RESULT_CACHE: &str = "query_result";
+pub const BROADCAST_TABLE_CACHE: &str = "broadcast_table";
+
+/// Hits and misses of a cache since it is created
+#[derive(Default)]
+pub struct CacheStatistics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStatistics {
+    pub fn inc_hits(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_misses(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+/// The state of a cache, as listed by `system.caches`.
+///
+/// `capacity` and `size` are measured in `unit`, i.e. the number of entries or bytes.
+pub struct CacheStatus {
+    pub name: &'static str,
+    pub unit: &'static str,
+    pub capacity: u64,
+    pub size: u64,
+    pub entries: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
 
 /// Where all the caches reside
 pub struct CacheManager {
     table_snapshot_cache: Option<TableSnapshotCache>,
     segment_info_cache: Option<SegmentInfoCache>,
+    table_snapshot_statistics: Arc<CacheStatistics>,
+    segment_info_statistics: Arc<CacheStatistics>,
+    broadcast_table_cache: Arc<BroadcastTableCache>,
     cluster_id: String,
     tenant_id: String,
 }
@@ -31,22 +85,33 @@ impl CacheManager {
     ///
     /// For convenience, ids of cluster and tenant are also kept
     pub fn init(config: &QueryConfig) -> CacheManager {
-        if !config.table_cache_enabled {
-            Self {
-                table_snapshot_cache: None,
-                segment_info_cache: None,
-                cluster_id: config.cluster_id.clone(),
-                tenant_id: config.tenant_id.clone(),
-            }
+        let (table_snapshot_cache, segment_info_cache) = if !config.table_cache_enabled {
+            (None, None)
         } else {
-            let table_snapshot_cache = Self::with_capacity(config.table_cache_snapshot_count);
-            let segment_info_cache = Self::with_capacity(config.table_cache_segment_count);
-            Self {
-                table_snapshot_cache,
-                segment_info_cache,
-                cluster_id: config.cluster_id.clone(),
+            (
+                Self::with_capacity(config.table_cache_snapshot_count),
+                Self::with_capacity(config.table_cache_segment_count),
+            )
+        };
+
+        // Tables are marked as broadcast explicitly, so the cache is not gated by
+        // `table_cache_enabled`.
+        let broadcast_table_cache = Arc::new(BroadcastTableCache::create(
+            config.table_memory_cache_mb_size * 1024 * 1024,
+            TenantLabel {
                 tenant_id: config.tenant_id.clone(),
-            }
+                cluster_id: config.cluster_id.clone(),
+            },
+        ));
+
+        Self {
+            table_snapshot_cache,
+            segment_info_cache,
+            table_snapshot_statistics: Arc::new(CacheStatistics::default()),
+            segment_info_statistics: Arc::new(CacheStatistics::default()),
+            broadcast_table_cache,
+            cluster_id: config.cluster_id.clone(),
+            tenant_id: config.tenant_id.clone(),
         }
     }
 
@@ -58,6 +123,18 @@ impl CacheManager {
         self.segment_info_cache.clone()
     }
 
+    pub fn get_table_snapshot_statistics(&self) -> Arc<CacheStatistics> {
+        self.table_snapshot_statistics.clone()
+    }
+
+    pub fn get_table_segment_statistics(&self) -> Arc<CacheStatistics> {
+        self.segment_info_statistics.clone()
+    }
+
+    pub fn get_broadcast_table_cache(&self) -> Arc<BroadcastTableCache> {
+        self.broadcast_table_cache.clone()
+    }
+
     pub fn get_tenant_id(&self) -> &str {
         self.tenant_id.as_str()
     }
@@ -66,6 +143,61 @@ impl CacheManager {
         self.cluster_id.as_str()
     }
 
+    /// The caches of tables, a disabled cache is listed with zero capacity.
+    pub async fn status(&self) -> Vec<CacheStatus> {
+        vec![
+            Self::memory_cache_status(
+                TABLE_SNAPSHOT_CACHE,
+                &self.table_snapshot_cache,
+                &self.table_snapshot_statistics,
+            )
+            .await,
+            Self::memory_cache_status(
+                SEGMENT_INFO_CACHE,
+                &self.segment_info_cache,
+                &self.segment_info_statistics,
+            )
+            .await,
+            self.broadcast_table_cache.status(),
+        ]
+    }
+
+    pub async fn clear_table_snapshot_cache(&self) {
+        if let Some(cache) = &self.table_snapshot_cache {
+            cache.write().await.clear();
+        }
+    }
+
+    pub async fn clear_table_segment_cache(&self) {
+        if let Some(cache) = &self.segment_info_cache {
+            cache.write().await.clear();
+        }
+    }
+
+    async fn memory_cache_status<T>(
+        name: &'static str,
+        cache: &Option<MemoryCache<T>>,
+        statistics: &CacheStatistics,
+    ) -> CacheStatus {
+        let (capacity, size, entries) = match cache {
+            None => (0, 0, 0),
+            Some(cache) => {
+                let cache = cache.read().await;
+                (cache.capacity(), cache.size(), cache.len() as u64)
+            }
+        };
+
+        CacheStatus {
+            name,
+            unit: "count",
+            capacity,
+            size,
+            entries,
+            hits: statistics.hits(),
+            misses: statistics.misses(),
+        }
+    }
+
     fn with_capacity<T>(capacity: u64) -> Option<MemoryCache<T>> {
         if capacity > 0 {
             Some(cache::new_memory_cache(capacity))
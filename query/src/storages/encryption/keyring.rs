@@ -0,0 +1,147 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::process::Command;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Config;
+
+/// AES-256-GCM keys are 32 bytes.
+const KEY_LEN: usize = 32;
+
+/// The keys to encrypt the data at rest, identified by a key id.
+///
+/// Each line of the key file is `<key_id>:<base64 encoded 32 bytes key>`, lines starting with `#`
+/// are ignored. The key with the largest id encrypts the new objects, the others are kept to read
+/// the objects written before the key rotation, until they are rewritten by `admin$reencrypt_storage`.
+pub struct StorageKeyring {
+    keys: BTreeMap<u32, Vec<u8>>,
+}
+
+impl StorageKeyring {
+    /// Returns None if the encryption is not configured.
+    pub fn try_create(conf: &Config) -> Result<Option<Arc<StorageKeyring>>> {
+        let query = &conf.query;
+        let content = if !query.storage_encryption_kms_command.is_empty() {
+            Self::run_kms_command(&query.storage_encryption_kms_command)?
+        } else if !query.storage_encryption_key_file.is_empty() {
+            std::fs::read_to_string(&query.storage_encryption_key_file).map_err(|cause| {
+                ErrorCode::InvalidConfig(format!(
+                    "Cannot read the storage encryption key file {}: {}",
+                    query.storage_encryption_key_file, cause
+                ))
+            })?
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Arc::new(Self::parse(&content)?)))
+    }
+
+    pub fn parse(content: &str) -> Result<StorageKeyring> {
+        let mut keys = BTreeMap::new();
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key_id, key) = line.split_once(':').ok_or_else(|| {
+                ErrorCode::InvalidConfig("Storage encryption key must be <key_id>:<base64 key>")
+            })?;
+            let key_id = key_id.trim().parse::<u32>().map_err(|cause| {
+                ErrorCode::InvalidConfig(format!("Invalid storage encryption key id: {}", cause))
+            })?;
+            let key = base64::decode(key.trim()).map_err(|cause| {
+                ErrorCode::InvalidConfig(format!("Invalid storage encryption key: {}", cause))
+            })?;
+            if key.len() != KEY_LEN {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "Storage encryption key {} must be {} bytes, but got {}",
+                    key_id,
+                    KEY_LEN,
+                    key.len()
+                )));
+            }
+            if keys.insert(key_id, key).is_some() {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "Duplicated storage encryption key id: {}",
+                    key_id
+                )));
+            }
+        }
+
+        match keys.is_empty() {
+            true => Err(ErrorCode::InvalidConfig(
+                "No storage encryption key is configured",
+            )),
+            false => Ok(StorageKeyring { keys }),
+        }
+    }
+
+    /// The key to encrypt the new objects.
+    pub fn active_key(&self) -> (u32, &[u8]) {
+        // The keys are never empty.
+        let (key_id, key) = self.keys.iter().next_back().unwrap();
+        (*key_id, key)
+    }
+
+    pub fn key(&self, key_id: u32) -> std::io::Result<&[u8]> {
+        self.keys
+            .get(&key_id)
+            .map(|key| key.as_slice())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Unknown storage encryption key id: {}", key_id),
+                )
+            })
+    }
+
+    // The KMS hook prints the keys in the same format as the key file.
+    fn run_kms_command(command: &str) -> Result<String> {
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .output()
+            .map_err(|cause| {
+                ErrorCode::InvalidConfig(format!("Cannot run the kms command: {}", cause))
+            })?;
+
+        if !output.status.success() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "The kms command exits with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        String::from_utf8(output.stdout).map_err(|cause| {
+            ErrorCode::InvalidConfig(format!("Invalid output of the kms command: {}", cause))
+        })
+    }
+}
+
+impl Debug for StorageKeyring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StorageKeyring")
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,515 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use async_trait::async_trait;
+use futures::io::Cursor;
+use futures::ready;
+use futures::AsyncReadExt;
+use futures::AsyncWrite;
+use opendal::ops::OpCreate;
+use opendal::ops::OpDelete;
+use opendal::ops::OpList;
+use opendal::ops::OpRead;
+use opendal::ops::OpStat;
+use opendal::ops::OpWrite;
+use opendal::Accessor;
+use opendal::BytesReader;
+use opendal::BytesWriter;
+use opendal::Layer;
+use opendal::Metadata;
+use opendal::ObjectStreamer;
+use openssl::symm::decrypt_aead;
+use openssl::symm::encrypt_aead;
+use openssl::symm::Cipher;
+
+use crate::storages::encryption::StorageKeyring;
+
+/// The last byte is the version of the format.
+const MAGIC: [u8; 8] = *b"DBENC\0\0\x01";
+/// magic + key id + nonce prefix
+pub const HEADER_LEN: usize = 8 + 4 + 8;
+/// The objects are encrypted in frames, so that a range could be read without the whole object.
+pub const FRAME_LEN: usize = 64 * 1024;
+const TAG_LEN: usize = 16;
+const ENCRYPTED_FRAME_LEN: usize = FRAME_LEN + TAG_LEN;
+
+/// The header at the beginning of an encrypted object. The nonce of a frame is the random nonce
+/// prefix followed by the frame index. The header and whether the frame is the final one of the
+/// object are authenticated with every frame, so that the frames can't be reordered and the
+/// object can't be truncated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EncryptionHeader {
+    pub key_id: u32,
+    nonce_prefix: [u8; 8],
+}
+
+impl EncryptionHeader {
+    pub fn create(key_id: u32) -> Result<EncryptionHeader> {
+        let mut nonce_prefix = [0; 8];
+        openssl::rand::rand_bytes(&mut nonce_prefix).map_err(to_io_error)?;
+        Ok(EncryptionHeader {
+            key_id,
+            nonce_prefix,
+        })
+    }
+
+    /// Returns None if the bytes are not the header of an encrypted object, which is
+    /// written before the encryption is enabled.
+    pub fn parse(bytes: &[u8]) -> Option<EncryptionHeader> {
+        if bytes.len() < HEADER_LEN || bytes[..8] != MAGIC {
+            return None;
+        }
+
+        let mut key_id = [0; 4];
+        key_id.copy_from_slice(&bytes[8..12]);
+        let mut nonce_prefix = [0; 8];
+        nonce_prefix.copy_from_slice(&bytes[12..HEADER_LEN]);
+        Some(EncryptionHeader {
+            key_id: u32::from_le_bytes(key_id),
+            nonce_prefix,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&self.key_id.to_le_bytes());
+        bytes.extend_from_slice(&self.nonce_prefix);
+        bytes
+    }
+
+    fn nonce(&self, frame_index: u64) -> Result<Vec<u8>> {
+        let frame_index = u32::try_from(frame_index)
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "Object is too large to encrypt"))?;
+        let mut nonce = self.nonce_prefix.to_vec();
+        nonce.extend_from_slice(&frame_index.to_be_bytes());
+        Ok(nonce)
+    }
+
+    fn aad(&self, is_final: bool) -> Vec<u8> {
+        let mut aad = self.to_bytes();
+        aad.push(is_final as u8);
+        aad
+    }
+
+    fn encrypt_frame(
+        &self,
+        key: &[u8],
+        frame_index: u64,
+        is_final: bool,
+        plain: &[u8],
+    ) -> Result<Vec<u8>> {
+        let nonce = self.nonce(frame_index)?;
+        let mut tag = [0; TAG_LEN];
+        let cipher = Cipher::aes_256_gcm();
+        let aad = self.aad(is_final);
+        let mut frame =
+            encrypt_aead(cipher, key, Some(&nonce), &aad, plain, &mut tag).map_err(to_io_error)?;
+        frame.extend_from_slice(&tag);
+        Ok(frame)
+    }
+
+    fn decrypt_frame(
+        &self,
+        key: &[u8],
+        frame_index: u64,
+        is_final: bool,
+        frame: &[u8],
+    ) -> Result<Vec<u8>> {
+        if frame.len() < TAG_LEN {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "Encrypted frame is truncated",
+            ));
+        }
+
+        let nonce = self.nonce(frame_index)?;
+        let (data, tag) = frame.split_at(frame.len() - TAG_LEN);
+        let cipher = Cipher::aes_256_gcm();
+        let aad = self.aad(is_final);
+        decrypt_aead(cipher, key, Some(&nonce), &aad, data, tag).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Cannot decrypt the frame {} of the object, it may be truncated or corrupted",
+                    frame_index
+                ),
+            )
+        })
+    }
+
+    /// Decrypt the consecutive frames starting from `first_frame`.
+    ///
+    /// `last_frame` is the last frame requested, or None if the frames are read to the end of
+    /// the object. If the frames end before `last_frame`, the object must end with the final
+    /// frame, otherwise it is truncated.
+    fn decrypt_frames(
+        &self,
+        key: &[u8],
+        first_frame: u64,
+        last_frame: Option<u64>,
+        data: &[u8],
+    ) -> Result<Vec<u8>> {
+        if data.is_empty() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "Encrypted object is truncated, the final frame is missing",
+            ));
+        }
+
+        let frames = data.chunks(ENCRYPTED_FRAME_LEN).collect::<Vec<_>>();
+        let mut plain = Vec::with_capacity(data.len());
+        for (index, frame) in frames.iter().enumerate() {
+            let frame_index = first_frame + index as u64;
+            let is_last_read = index + 1 == frames.len();
+            let bytes = if !is_last_read {
+                self.decrypt_frame(key, frame_index, false, frame)?
+            } else if frame.len() == ENCRYPTED_FRAME_LEN && last_frame == Some(frame_index) {
+                // All the requested frames are read, the last one may be the final one or not.
+                self.decrypt_frame(key, frame_index, false, frame)
+                    .or_else(|_| self.decrypt_frame(key, frame_index, true, frame))?
+            } else {
+                self.decrypt_frame(key, frame_index, true, frame)?
+            };
+            plain.extend_from_slice(&bytes);
+        }
+        Ok(plain)
+    }
+}
+
+/// The size of an object after encryption, an empty object still has an empty final frame.
+pub fn encrypted_len(plain_len: u64) -> u64 {
+    let frames = std::cmp::max((plain_len + FRAME_LEN as u64 - 1) / FRAME_LEN as u64, 1);
+    HEADER_LEN as u64 + plain_len + frames * TAG_LEN as u64
+}
+
+/// The size of an object before encryption.
+pub fn plain_len(encrypted_len: u64) -> u64 {
+    let body = encrypted_len.saturating_sub(HEADER_LEN as u64);
+    let frame = ENCRYPTED_FRAME_LEN as u64;
+    let last = body % frame;
+    (body / frame) * FRAME_LEN as u64 + last.saturating_sub(TAG_LEN as u64)
+}
+
+/// Encrypt a whole object with the active key.
+pub fn encrypt_object(keyring: &StorageKeyring, plain: &[u8]) -> Result<Vec<u8>> {
+    let (key_id, key) = keyring.active_key();
+    let header = EncryptionHeader::create(key_id)?;
+
+    let mut data = Vec::with_capacity(encrypted_len(plain.len() as u64) as usize);
+    data.extend_from_slice(&header.to_bytes());
+    let frames = std::cmp::max((plain.len() + FRAME_LEN - 1) / FRAME_LEN, 1);
+    for index in 0..frames {
+        let frame = &plain[index * FRAME_LEN..std::cmp::min((index + 1) * FRAME_LEN, plain.len())];
+        let is_final = index + 1 == frames;
+        data.extend_from_slice(&header.encrypt_frame(key, index as u64, is_final, frame)?);
+    }
+    Ok(data)
+}
+
+/// Decrypt a whole encrypted object.
+pub fn decrypt_object(keyring: &StorageKeyring, data: &[u8]) -> Result<Vec<u8>> {
+    match EncryptionHeader::parse(data) {
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "Object is not encrypted",
+        )),
+        Some(header) => {
+            let key = keyring.key(header.key_id)?;
+            header.decrypt_frames(key, 0, None, &data[HEADER_LEN..])
+        }
+    }
+}
+
+fn to_io_error(cause: openssl::error::ErrorStack) -> Error {
+    Error::new(ErrorKind::Other, cause.to_string())
+}
+
+/// A storage layer to encrypt the objects at rest with AES-256-GCM, it's transparent to the readers
+/// and the writers. The objects written before the encryption is enabled are still readable,
+/// unless the encryption is required.
+#[derive(Clone, Debug)]
+pub struct StorageEncryption {
+    keyring: Arc<StorageKeyring>,
+    // Reject the objects without the encryption header, which may be substituted by anyone
+    // able to write the storage.
+    require_encryption: bool,
+    inner: Option<Arc<dyn Accessor>>,
+}
+
+impl StorageEncryption {
+    pub fn create(keyring: Arc<StorageKeyring>, require_encryption: bool) -> Self {
+        StorageEncryption {
+            keyring,
+            require_encryption,
+            inner: None,
+        }
+    }
+
+    fn get_inner(&self) -> Result<Arc<dyn Accessor>> {
+        match &self.inner {
+            None => Err(Error::new(
+                ErrorKind::Other,
+                "storage encryption must init wrongly, inner accessor is empty",
+            )),
+            Some(inner) => Ok(inner.clone()),
+        }
+    }
+
+    fn check_plain(&self, path: &str) -> Result<()> {
+        match self.require_encryption {
+            true => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Object {} is not encrypted", path),
+            )),
+            false => Ok(()),
+        }
+    }
+
+    async fn read_range(
+        inner: &Arc<dyn Accessor>,
+        path: &str,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<Vec<u8>> {
+        let args = match end {
+            Some(end) => OpRead::new(path, start..end)?,
+            None => OpRead::new(path, start..)?,
+        };
+        let mut reader = inner.read(&args).await?;
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(bytes)
+    }
+
+    async fn read_header(
+        inner: &Arc<dyn Accessor>,
+        path: &str,
+    ) -> Result<Option<EncryptionHeader>> {
+        let bytes = Self::read_range(inner, path, 0, Some(HEADER_LEN as u64)).await?;
+        Ok(EncryptionHeader::parse(&bytes))
+    }
+}
+
+impl Layer for StorageEncryption {
+    fn layer(&self, inner: Arc<dyn Accessor>) -> Arc<dyn Accessor> {
+        Arc::new(StorageEncryption {
+            keyring: self.keyring.clone(),
+            require_encryption: self.require_encryption,
+            inner: Some(inner),
+        })
+    }
+}
+
+#[async_trait]
+impl Accessor for StorageEncryption {
+    async fn create(&self, args: &OpCreate) -> Result<()> {
+        self.get_inner()?.create(args).await
+    }
+
+    async fn read(&self, args: &OpRead) -> Result<BytesReader> {
+        let inner = self.get_inner()?;
+        let path = args.path();
+        let offset = args.offset().unwrap_or(0);
+        if args.size() == Some(0) {
+            return Ok(Box::new(Cursor::new(vec![])));
+        }
+
+        // The frames covering the range, the last frame may be shorter. Without the size,
+        // the frames are read to the end of the object.
+        let frame_len = ENCRYPTED_FRAME_LEN as u64;
+        let first_frame = offset / FRAME_LEN as u64;
+        let last_frame = args.size().map(|size| (offset + size - 1) / FRAME_LEN as u64);
+        let range_start = HEADER_LEN as u64 + first_frame * frame_len;
+        let range_end = last_frame.map(|last| HEADER_LEN as u64 + (last + 1) * frame_len);
+
+        // The header is read along with the frames if the range starts at the first frame,
+        // which covers the plain range as well.
+        let (header, data) = match first_frame {
+            0 => {
+                let mut data = Self::read_range(&inner, path, 0, range_end).await?;
+                match EncryptionHeader::parse(&data) {
+                    Some(header) => (header, data.split_off(HEADER_LEN)),
+                    None => {
+                        self.check_plain(path)?;
+                        let start = std::cmp::min(offset as usize, data.len());
+                        let end = match args.size() {
+                            Some(size) => std::cmp::min(start + size as usize, data.len()),
+                            None => data.len(),
+                        };
+                        return Ok(Box::new(Cursor::new(data[start..end].to_vec())));
+                    }
+                }
+            }
+            _ => match Self::read_header(&inner, path).await? {
+                Some(header) => (
+                    header,
+                    Self::read_range(&inner, path, range_start, range_end).await?,
+                ),
+                None => {
+                    self.check_plain(path)?;
+                    return inner.read(args).await;
+                }
+            },
+        };
+        let key = self.keyring.key(header.key_id)?;
+
+        if data.is_empty() && first_frame > 0 {
+            // The range is beyond the end, which must be the final frame before the range.
+            let prev_start = range_start - frame_len;
+            let prev = Self::read_range(&inner, path, prev_start, Some(range_start)).await?;
+            header.decrypt_frame(key, first_frame - 1, true, &prev)?;
+            return Ok(Box::new(Cursor::new(vec![])));
+        }
+        let plain = header.decrypt_frames(key, first_frame, last_frame, &data)?;
+
+        let skip = std::cmp::min((offset - first_frame * FRAME_LEN as u64) as usize, plain.len());
+        let take = match args.size() {
+            Some(size) => std::cmp::min(size as usize, plain.len() - skip),
+            None => plain.len() - skip,
+        };
+        Ok(Box::new(Cursor::new(plain[skip..skip + take].to_vec())))
+    }
+
+    async fn write(&self, args: &OpWrite) -> Result<BytesWriter> {
+        let (key_id, key) = self.keyring.active_key();
+        let header = EncryptionHeader::create(key_id)?;
+
+        let path = args.path();
+        let inner = self
+            .get_inner()?
+            .write(&OpWrite::new(path, encrypted_len(args.size()))?)
+            .await?;
+        Ok(Box::new(EncryptWriter {
+            inner,
+            key: key.to_vec(),
+            header,
+            frame_index: 0,
+            plain: Vec::with_capacity(FRAME_LEN),
+            pending: header.to_bytes(),
+            pending_pos: 0,
+            sealed_final: false,
+        }))
+    }
+
+    async fn stat(&self, args: &OpStat) -> Result<Metadata> {
+        let inner = self.get_inner()?;
+        let mut meta = inner.stat(args).await?;
+        let path = args.path();
+        if path.is_empty() || path.ends_with('/') {
+            return Ok(meta);
+        }
+
+        // All the objects are encrypted if the encryption is required, the reads of them
+        // check the header anyway.
+        if self.require_encryption || Self::read_header(&inner, path).await?.is_some() {
+            let len = plain_len(meta.content_length());
+            meta.set_content_length(len);
+        }
+        Ok(meta)
+    }
+
+    async fn delete(&self, args: &OpDelete) -> Result<()> {
+        self.get_inner()?.delete(args).await
+    }
+
+    async fn list(&self, args: &OpList) -> Result<ObjectStreamer> {
+        self.get_inner()?.list(args).await
+    }
+}
+
+/// Encrypt the written bytes frame by frame, the encrypted bytes are written to the inner writer.
+struct EncryptWriter {
+    inner: BytesWriter,
+    key: Vec<u8>,
+    header: EncryptionHeader,
+    frame_index: u64,
+    // The plain bytes of the current frame.
+    plain: Vec<u8>,
+    // The encrypted bytes which are not written to the inner writer yet.
+    pending: Vec<u8>,
+    pending_pos: usize,
+    sealed_final: bool,
+}
+
+impl EncryptWriter {
+    fn seal_frame(&mut self, is_final: bool) -> Result<()> {
+        let frame =
+            self.header
+                .encrypt_frame(&self.key, self.frame_index, is_final, &self.plain)?;
+        self.pending.extend_from_slice(&frame);
+        self.plain.clear();
+        self.frame_index += 1;
+        Ok(())
+    }
+
+    fn poll_write_pending(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        while self.pending_pos < self.pending.len() {
+            let written = ready!(
+                Pin::new(&mut self.inner).poll_write(cx, &self.pending[self.pending_pos..])
+            )?;
+            if written == 0 {
+                return Poll::Ready(Err(Error::from(ErrorKind::WriteZero)));
+            }
+            self.pending_pos += written;
+        }
+
+        self.pending.clear();
+        self.pending_pos = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for EncryptWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        // A full frame is sealed once more bytes come, it's the final one otherwise.
+        if this.plain.len() == FRAME_LEN {
+            this.seal_frame(false)?;
+            ready!(this.poll_write_pending(cx))?;
+        }
+        let len = std::cmp::min(buf.len(), FRAME_LEN - this.plain.len());
+        this.plain.extend_from_slice(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        if !this.sealed_final {
+            this.seal_frame(true)?;
+            this.sealed_final = true;
+        }
+        ready!(this.poll_write_pending(cx))?;
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
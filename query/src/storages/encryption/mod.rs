@@ -0,0 +1,29 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod keyring;
+mod layer;
+mod reencrypt;
+
+pub use keyring::StorageKeyring;
+pub use layer::decrypt_object;
+pub use layer::encrypt_object;
+pub use layer::encrypted_len;
+pub use layer::plain_len;
+pub use layer::EncryptionHeader;
+pub use layer::StorageEncryption;
+pub use layer::FRAME_LEN;
+pub use layer::HEADER_LEN;
+pub use reencrypt::reencrypt_objects;
+pub use reencrypt::ReencryptStats;
@@ -0,0 +1,83 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::ErrorKind;
+
+use common_exception::Result;
+use futures::StreamExt;
+use opendal::Operator;
+
+use crate::storages::encryption::decrypt_object;
+use crate::storages::encryption::encrypt_object;
+use crate::storages::encryption::EncryptionHeader;
+use crate::storages::encryption::StorageKeyring;
+use crate::storages::encryption::HEADER_LEN;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReencryptStats {
+    pub scanned: u64,
+    pub reencrypted: u64,
+}
+
+/// Re-encrypt the objects under the path with the active key of the keyring, the objects which
+/// are plain or encrypted by the rotated keys are rewritten. The operator must be the raw one
+/// without the encryption layer.
+pub async fn reencrypt_objects(
+    raw_operator: &Operator,
+    keyring: &StorageKeyring,
+    path: &str,
+) -> Result<ReencryptStats> {
+    let (active_key_id, _) = keyring.active_key();
+    let mut stats = ReencryptStats::default();
+
+    let mut dirs = vec![path.to_string()];
+    while let Some(dir) = dirs.pop() {
+        let mut objects = raw_operator.object(&dir).list().await?;
+        while let Some(object) = objects.next().await {
+            let path = object?.path();
+            if path.ends_with('/') {
+                dirs.push(path);
+                continue;
+            }
+
+            stats.scanned += 1;
+            let object = raw_operator.object(&path);
+            let header = match object.range_read(0..HEADER_LEN as u64).await {
+                Ok(bytes) => EncryptionHeader::parse(&bytes),
+                // The object may be purged by others.
+                Err(cause) if cause.kind() == ErrorKind::NotFound => continue,
+                Err(cause) => return Err(cause.into()),
+            };
+
+            if matches!(header, Some(header) if header.key_id == active_key_id) {
+                continue;
+            }
+
+            let data = match object.read().await {
+                Ok(data) => data,
+                Err(cause) if cause.kind() == ErrorKind::NotFound => continue,
+                Err(cause) => return Err(cause.into()),
+            };
+            let plain = match header {
+                // The object written before the encryption is enabled.
+                None => data,
+                Some(_) => decrypt_object(keyring, &data)?,
+            };
+            object.write(encrypt_object(keyring, &plain)?).await?;
+            stats.reencrypted += 1;
+        }
+    }
+
+    Ok(stats)
+}
@@ -0,0 +1,123 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+
+use crate::storages::cache::CacheStatistics;
+use crate::storages::cache::CacheStatus;
+use crate::storages::cache::BROADCAST_TABLE_CACHE;
+use crate::storages::fuse::cache::record_cache_size;
+use crate::storages::fuse::cache::CacheDeferMetrics;
+use crate::storages::fuse::cache::TenantLabel;
+
+/// (table id, block location)
+type BlockKey = (u64, String);
+
+struct DataBlockMeter;
+
+impl<K> Meter<K, Arc<DataBlock>> for DataBlockMeter {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Arc<DataBlock>) -> usize
+    where K: Borrow<Q> {
+        v.memory_size()
+    }
+}
+
+/// The blocks of the broadcast tables, i.e. the small tables which are read as a whole by
+/// every node, bounded by `table_memory_cache_mb_size`.
+///
+/// The blocks are immutable, a new snapshot of a table refers to new blocks for the changed
+/// rows, so a cached block never goes stale. The blocks of a table are also dropped on the
+/// node which commits to the table, instead of waiting for the LRU to evict them.
+pub struct BroadcastTableCache {
+    blocks: Mutex<LruCache<BlockKey, Arc<DataBlock>, DefaultHashBuilder, DataBlockMeter>>,
+    statistics: CacheStatistics,
+    tenant_label: TenantLabel,
+}
+
+impl BroadcastTableCache {
+    pub fn create(capacity: u64, tenant_label: TenantLabel) -> BroadcastTableCache {
+        BroadcastTableCache {
+            blocks: Mutex::new(LruCache::with_meter(capacity, DataBlockMeter)),
+            statistics: CacheStatistics::default(),
+            tenant_label,
+        }
+    }
+
+    pub fn get(&self, table_id: u64, location: &str) -> Option<Arc<DataBlock>> {
+        let key = (table_id, location.to_string());
+        let cached = self.blocks.lock().get(&key).cloned();
+        let _metrics = CacheDeferMetrics {
+            tenant_label: self.tenant_label.clone(),
+            cache_name: BROADCAST_TABLE_CACHE,
+            cache_hit: cached.is_some(),
+            read_bytes: cached.as_ref().map(|v| v.memory_size() as u64).unwrap_or(0),
+        };
+
+        match cached {
+            Some(_) => self.statistics.inc_hits(),
+            None => self.statistics.inc_misses(),
+        }
+        cached
+    }
+
+    pub fn put(&self, table_id: u64, location: &str, block: Arc<DataBlock>) {
+        let mut blocks = self.blocks.lock();
+        if block.memory_size() as u64 <= blocks.capacity() {
+            blocks.put((table_id, location.to_string()), block);
+            record_cache_size(&self.tenant_label, BROADCAST_TABLE_CACHE, blocks.size());
+        }
+    }
+
+    /// Drops the cached blocks of the table.
+    pub fn invalidate(&self, table_id: u64) {
+        let mut blocks = self.blocks.lock();
+        let keys = blocks
+            .iter()
+            .filter(|(key, _)| key.0 == table_id)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            blocks.pop(&key);
+        }
+        record_cache_size(&self.tenant_label, BROADCAST_TABLE_CACHE, blocks.size());
+    }
+
+    pub fn clear(&self) {
+        self.blocks.lock().clear();
+        record_cache_size(&self.tenant_label, BROADCAST_TABLE_CACHE, 0);
+    }
+
+    pub fn status(&self) -> CacheStatus {
+        let blocks = self.blocks.lock();
+        CacheStatus {
+            name: BROADCAST_TABLE_CACHE,
+            unit: "bytes",
+            capacity: blocks.capacity(),
+            size: blocks.size(),
+            entries: blocks.len() as u64,
+            hits: self.statistics.hits(),
+            misses: self.statistics.misses(),
+        }
+    }
+}
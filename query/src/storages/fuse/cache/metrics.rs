@@ -13,47 +13,65 @@
 //  limitations under the License.
 //
 
-use common_metrics::label_counter;
-use common_metrics::label_counter_with_val;
+use common_metrics::label_counter_with_val_and_labels;
+use common_metrics::label_gauge_with_val_and_labels;
+use common_metrics::LABEL_KEY_CACHE;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
 
 const CACHE_READ_BYTES_FROM_REMOTE: &str = "cache_read_bytes_from_remote";
 const CACHE_READ_BYTES_FROM_LOCAL: &str = "cache_read_bytes_from_local";
 const CACHE_ACCESS_COUNT: &str = "cache_access_count";
 const CACHE_ACCESS_HIT_COUNT: &str = "cache_access_hit_count";
+const CACHE_SIZE: &str = "cache_size";
 
+#[derive(Clone)]
 pub struct TenantLabel {
     pub tenant_id: String,
     pub cluster_id: String,
 }
 
+impl TenantLabel {
+    fn with_cache(&self, cache_name: &str) -> [(&'static str, String); 3] {
+        [
+            (LABEL_KEY_TENANT, self.tenant_id.clone()),
+            (LABEL_KEY_CLUSTER, self.cluster_id.clone()),
+            (LABEL_KEY_CACHE, cache_name.to_string()),
+        ]
+    }
+}
+
 pub struct CacheDeferMetrics {
     pub tenant_label: TenantLabel,
+    pub cache_name: &'static str,
     pub cache_hit: bool,
     pub read_bytes: u64,
 }
 
 impl Drop for CacheDeferMetrics {
     fn drop(&mut self) {
-        let label = &self.tenant_label;
-        let tenant_id = &label.tenant_id;
-        let cluster_id = &label.cluster_id;
+        let labels = self.tenant_label.with_cache(self.cache_name);
 
-        label_counter(CACHE_ACCESS_COUNT, tenant_id, cluster_id);
+        label_counter_with_val_and_labels(CACHE_ACCESS_COUNT, &labels, 1);
         if self.cache_hit {
-            label_counter(CACHE_ACCESS_HIT_COUNT, tenant_id, cluster_id);
-            label_counter_with_val(
+            label_counter_with_val_and_labels(CACHE_ACCESS_HIT_COUNT, &labels, 1);
+            label_counter_with_val_and_labels(
                 CACHE_READ_BYTES_FROM_LOCAL,
+                &labels,
                 self.read_bytes,
-                tenant_id,
-                cluster_id,
             );
         } else {
-            label_counter_with_val(
+            label_counter_with_val_and_labels(
                 CACHE_READ_BYTES_FROM_REMOTE,
+                &labels,
                 self.read_bytes,
-                tenant_id,
-                cluster_id,
             );
         }
     }
 }
+
+/// Reports the size of a cache, in the unit of its meter.
+pub fn record_cache_size(tenant_label: &TenantLabel, cache_name: &'static str, size: u64) {
+    let labels = tenant_label.with_cache(cache_name);
+    label_gauge_with_val_and_labels(CACHE_SIZE, &labels, size as f64);
+}
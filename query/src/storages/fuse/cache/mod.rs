@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod broadcast_cache;
 mod memory_cache;
 mod metrics;
 
+pub use broadcast_cache::BroadcastTableCache;
 pub use memory_cache::new_memory_cache;
 pub use memory_cache::MemoryCache;
 pub use memory_cache::SegmentInfoCache;
 pub use memory_cache::TableSnapshotCache;
 
+pub use self::metrics::record_cache_size;
 pub use self::metrics::CacheDeferMetrics;
 pub use self::metrics::TenantLabel;
@@ -0,0 +1,177 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::infallible::RwLock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+use metrics::counter;
+use twox_hash::XxHash64;
+
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::ColumnMeta;
+
+const FUSE_CORRUPT_BLOCKS: &str = "fuse_corrupt_blocks";
+
+/// The checksum of a column chunk, as it is stored in the block file.
+pub fn column_checksum(chunk: &[u8]) -> u64 {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(chunk);
+    hasher.finish()
+}
+
+/// The checksums of all the column chunks of a serialized block.
+pub fn column_checksums(
+    data: &[u8],
+    col_metas: &HashMap<ColumnId, ColumnMeta>,
+) -> Result<HashMap<ColumnId, u64>> {
+    let mut checksums = HashMap::with_capacity(col_metas.len());
+    for (column_id, meta) in col_metas {
+        let range = meta.offset as usize..(meta.offset + meta.len) as usize;
+        let chunk = data.get(range).ok_or_else(|| {
+            ErrorCode::ParquetError(format!(
+                "invalid parquet file, column {} is out of the file",
+                column_id
+            ))
+        })?;
+        checksums.insert(*column_id, column_checksum(chunk));
+    }
+    Ok(checksums)
+}
+
+#[derive(Clone, Debug)]
+pub struct CorruptBlock {
+    pub table_id: u64,
+    pub location: String,
+    /// The first column found corrupted.
+    pub column_id: usize,
+    pub expected_checksum: u64,
+    pub actual_checksum: u64,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// The blocks whose checksums mismatched on read, on this node.
+///
+/// A corrupt block is quarantined: the reads of it fail fast until it is repaired from the
+/// replication target of the table by `system$fuse_repair`.
+#[derive(Default)]
+pub struct CorruptBlockManager {
+    blocks: RwLock<HashMap<String, CorruptBlock>>,
+}
+
+impl CorruptBlockManager {
+    pub fn init() -> CorruptBlockManager {
+        Default::default()
+    }
+
+    pub fn report(&self, block: CorruptBlock) {
+        tracing::error!(
+            "block {} of table {} is corrupted, checksum of column {} mismatched, expected {:x}, actual {:x}",
+            block.location,
+            block.table_id,
+            block.column_id,
+            block.expected_checksum,
+            block.actual_checksum
+        );
+
+        let mut blocks = self.blocks.write();
+        if !blocks.contains_key(&block.location) {
+            counter!(FUSE_CORRUPT_BLOCKS, 1);
+            blocks.insert(block.location.clone(), block);
+        }
+    }
+
+    pub fn is_quarantined(&self, location: &str) -> bool {
+        self.blocks.read().contains_key(location)
+    }
+
+    pub fn get_all(&self) -> Vec<CorruptBlock> {
+        self.blocks.read().values().cloned().collect()
+    }
+
+    pub fn get_table_blocks(&self, table_id: u64) -> Vec<CorruptBlock> {
+        self.blocks
+            .read()
+            .values()
+            .filter(|block| block.table_id == table_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Releases the block from the quarantine once it is repaired.
+    pub fn release(&self, location: &str) {
+        self.blocks.write().remove(location);
+    }
+}
+
+/// Verifies the column chunks read by a `BlockReader` against the checksums in the block meta.
+#[derive(Clone)]
+pub struct ChecksumVerifier {
+    table_id: u64,
+    manager: Arc<CorruptBlockManager>,
+}
+
+impl ChecksumVerifier {
+    pub fn create(table_id: u64, manager: Arc<CorruptBlockManager>) -> ChecksumVerifier {
+        ChecksumVerifier { table_id, manager }
+    }
+
+    pub fn check_quarantine(&self, location: &str) -> Result<()> {
+        match self.manager.is_quarantined(location) {
+            false => Ok(()),
+            true => Err(ErrorCode::CorruptedBlock(format!(
+                "block {} is quarantined for checksum mismatch, try system$fuse_repair",
+                location
+            ))),
+        }
+    }
+
+    pub fn verify(
+        &self,
+        location: &str,
+        column_id: usize,
+        expected: Option<u64>,
+        chunk: &[u8],
+    ) -> Result<()> {
+        let expected = match expected {
+            // written by the versions without checksums
+            None => return Ok(()),
+            Some(expected) => expected,
+        };
+
+        let actual = column_checksum(chunk);
+        if actual == expected {
+            return Ok(());
+        }
+
+        self.manager.report(CorruptBlock {
+            table_id: self.table_id,
+            location: location.to_string(),
+            column_id,
+            expected_checksum: expected,
+            actual_checksum: actual,
+            detected_at: Utc::now(),
+        });
+        Err(ErrorCode::CorruptedBlock(format!(
+            "checksum of column {} of block {} mismatched, expected {:x}, actual {:x}",
+            column_id, location, expected, actual
+        )))
+    }
+}
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PartInfo;
@@ -28,14 +29,16 @@ pub struct ColumnMeta {
     pub offset: u64,
     pub length: u64,
     pub num_values: u64,
+    pub checksum: Option<u64>,
 }
 
 impl ColumnMeta {
-    pub fn create(offset: u64, length: u64, num_values: u64) -> ColumnMeta {
+    pub fn create(offset: u64, length: u64, num_values: u64, checksum: Option<u64>) -> ColumnMeta {
         ColumnMeta {
             offset,
             length,
             num_values,
+            checksum,
         }
     }
 }
@@ -49,6 +52,9 @@ pub struct FusePartInfo {
     pub nums_rows: usize,
     pub columns_meta: HashMap<usize, ColumnMeta>,
     pub compression: Compression,
+    /// The min/max of the columns, used to skip the block by the runtime filters
+    #[serde(default)]
+    pub columns_range: HashMap<usize, (DataValue, DataValue)>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -72,6 +78,7 @@ impl FusePartInfo {
         rows_count: u64,
         columns_meta: HashMap<usize, ColumnMeta>,
         compression: Compression,
+        columns_range: HashMap<usize, (DataValue, DataValue)>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -79,6 +86,7 @@ impl FusePartInfo {
             columns_meta,
             nums_rows: rows_count as usize,
             compression,
+            columns_range,
         }))
     }
 
@@ -16,6 +16,7 @@
 use std::any::Any;
 use std::convert::TryFrom;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_cache::Cache;
 use common_datablocks::DataBlock;
@@ -26,6 +27,7 @@ use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::PartitionRef;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
@@ -33,13 +35,17 @@ use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::StreamExt;
+use opendal::Operator;
 use uuid::Uuid;
 
 use crate::pipelines::new::NewPipeline;
 use crate::sessions::QueryContext;
+use crate::sql::parse_broadcast_option;
 use crate::sql::PlanParser;
+use crate::sql::OPT_KEY_BROADCAST;
 use crate::sql::OPT_KEY_DATABASE_ID;
 use crate::sql::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use crate::sql::OPT_KEY_REPLICA_OF;
 use crate::sql::OPT_KEY_SNAPSHOT_LOCATION;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
@@ -48,6 +54,7 @@ use crate::storages::fuse::meta::Statistics as FuseStatistics;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::meta::Versioned;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::partitioning::TablePartitioning;
 use crate::storages::NavigationPoint;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
@@ -92,6 +99,9 @@ impl FuseTable {
             engine_name: "FUSE".to_string(),
             comment: "FUSE Storage Engine".to_string(),
             support_cluster_key: true,
+            support_delete: true,
+            support_time_travel: true,
+            support_statistics: true,
         }
     }
 
@@ -148,6 +158,22 @@ impl FuseTable {
             .cloned()
     }
 
+    /// The partitions of the table, if the table is partitioned
+    pub fn partitioning(&self) -> Result<Option<Arc<TablePartitioning>>> {
+        let partitioning =
+            TablePartitioning::try_create(self.table_info.options(), &self.table_info.schema())?;
+        Ok(partitioning.map(Arc::new))
+    }
+
+    /// Whether the table is read as a whole, and cached, by every node
+    pub fn is_broadcast(&self) -> bool {
+        self.table_info
+            .options()
+            .get(OPT_KEY_BROADCAST)
+            .map(|v| parse_broadcast_option(v).unwrap_or(false))
+            .unwrap_or(false)
+    }
+
     pub fn try_from_table(tbl: &dyn Table) -> Result<&FuseTable> {
         tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
             ErrorCode::LogicalError(format!(
@@ -163,6 +189,11 @@ impl FuseTable {
                 "Table {} is in read-only mode",
                 self.table_info.desc.as_str()
             )))
+        } else if self.table_info.options().contains_key(OPT_KEY_REPLICA_OF) {
+            Err(ErrorCode::TableNotWritable(format!(
+                "Table {} is a replica, it is writable after being promoted",
+                self.table_info.name
+            )))
         } else {
             Ok(())
         }
@@ -172,7 +203,8 @@ impl FuseTable {
 #[async_trait::async_trait]
 impl Table for FuseTable {
     fn is_local(&self) -> bool {
-        false
+        // the parts of a broadcast table are never scattered to other nodes
+        self.is_broadcast()
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -336,6 +368,60 @@ impl Table for FuseTable {
             .await
     }
 
+    async fn drop_partition(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        partitions: Vec<PartitionRef>,
+    ) -> Result<()> {
+        self.check_mutable()?;
+        self.do_drop_partition(ctx, catalog_name, partitions).await
+    }
+
+    async fn recluster(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        self.check_mutable()?;
+        self.do_recluster(ctx, catalog_name).await
+    }
+
+    async fn delete(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        selection: Option<Expression>,
+    ) -> Result<u64> {
+        self.check_mutable()?;
+        self.do_delete(ctx, catalog_name, selection).await
+    }
+
+    async fn update(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        assignments: Vec<(String, Expression)>,
+        selection: Option<Expression>,
+    ) -> Result<u64> {
+        self.check_mutable()?;
+        self.do_update(ctx, catalog_name, assignments, selection)
+            .await
+    }
+
+    async fn promote(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        if self.read_only {
+            return self.check_mutable();
+        }
+        self.do_promote(ctx, catalog_name).await
+    }
+
+    async fn export_snapshot(
+        &self,
+        ctx: Arc<QueryContext>,
+        target: &Operator,
+        path: &str,
+    ) -> Result<()> {
+        self.do_export_snapshot(ctx.as_ref(), target, path).await?;
+        Ok(())
+    }
+
     async fn truncate(
         &self,
         ctx: Arc<QueryContext>,
@@ -347,7 +433,18 @@ impl Table for FuseTable {
 
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.check_mutable()?;
-        self.do_gc(&ctx, keep_last_snapshot).await
+        if !keep_last_snapshot {
+            return self.do_gc(&ctx, keep_last_snapshot).await;
+        }
+
+        // The history within the retention period is kept as well.
+        let minutes = ctx.get_settings().get_purge_retention_minutes()?;
+        self.do_purge(&ctx, Duration::from_secs(minutes * 60)).await
+    }
+
+    async fn compact(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        self.check_mutable()?;
+        self.do_compact(ctx, catalog_name).await
     }
 
     async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
@@ -365,10 +462,15 @@ impl Table for FuseTable {
         ctx: Arc<QueryContext>,
         point: &NavigationPoint,
     ) -> Result<Arc<dyn Table>> {
-        let NavigationPoint::SnapshotID(snapshot_id) = point;
-        let res = self
-            .navigate_to_snapshot(ctx.as_ref(), snapshot_id.as_str())
-            .await?;
+        let res = match point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                self.navigate_to_snapshot(ctx.as_ref(), snapshot_id.as_str())
+                    .await?
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                self.navigate_to_time_point(&ctx, *time_point).await?
+            }
+        };
         Ok(res)
     }
 }
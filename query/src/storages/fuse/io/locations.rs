@@ -20,6 +20,7 @@ use common_exception::Result;
 use uuid::Uuid;
 
 use crate::storages::fuse::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::storages::fuse::constants::FUSE_TBL_REPLICATION_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::storages::fuse::meta::SegmentInfo;
@@ -70,6 +71,13 @@ impl TableMetaLocationGenerator {
         Ok(snapshot_version.create(id, &self.prefix))
     }
 
+    pub fn replication_checkpoint_location(&self) -> String {
+        format!(
+            "{}/{}/checkpoint.json",
+            &self.prefix, FUSE_TBL_REPLICATION_PREFIX,
+        )
+    }
+
     pub fn snapshot_version(location: impl AsRef<str>) -> u64 {
         if location.as_ref().ends_with(SNAPHOST_V1.suffix()) {
             SNAPHOST_V1.version()
@@ -17,8 +17,11 @@ mod read;
 mod write;
 
 pub use locations::TableMetaLocationGenerator;
+pub use read::BlockReadOptions;
 pub use read::BlockReader;
+pub use read::HedgedReadPolicy;
 pub use read::MetaReaders;
+pub use read::ReadLatencyTracker;
 pub use read::SegmentInfoReader;
 pub use read::TableSnapshotReader;
 pub use write::serialize_data_blocks;
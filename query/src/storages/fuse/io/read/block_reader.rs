@@ -40,10 +40,20 @@ use futures::TryStreamExt;
 use opendal::Object;
 use opendal::Operator;
 
+use crate::storages::fuse::corruption::ChecksumVerifier;
 use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
+use crate::storages::fuse::io::HedgedReadPolicy;
 use crate::storages::fuse::meta::Compression;
 
+#[derive(Clone, Default)]
+pub struct BlockReadOptions {
+    /// The column chunks are verified against their checksums if specified.
+    pub checksum_verifier: Option<ChecksumVerifier>,
+    /// The column chunks are read with backup requests if specified.
+    pub hedged_read: Option<HedgedReadPolicy>,
+}
+
 #[derive(Clone)]
 pub struct BlockReader {
     operator: Operator,
@@ -51,6 +61,8 @@ pub struct BlockReader {
     arrow_schema: Arc<Schema>,
     projected_schema: DataSchemaRef,
     parquet_schema_descriptor: SchemaDescriptor,
+    checksum_verifier: Option<ChecksumVerifier>,
+    hedged_read: Option<HedgedReadPolicy>,
 }
 
 impl BlockReader {
@@ -58,6 +70,15 @@ impl BlockReader {
         operator: Operator,
         schema: DataSchemaRef,
         projection: Vec<usize>,
+    ) -> Result<Arc<BlockReader>> {
+        Self::create_with_options(operator, schema, projection, BlockReadOptions::default())
+    }
+
+    pub fn create_with_options(
+        operator: Operator,
+        schema: DataSchemaRef,
+        projection: Vec<usize>,
+        options: BlockReadOptions,
     ) -> Result<Arc<BlockReader>> {
         let projected_schema = DataSchemaRef::new(schema.project(projection.clone()));
 
@@ -69,6 +90,8 @@ impl BlockReader {
             projected_schema,
             parquet_schema_descriptor,
             arrow_schema: Arc::new(arrow_schema),
+            checksum_verifier: options.checksum_verifier,
+            hedged_read: options.hedged_read,
         }))
     }
 
@@ -105,6 +128,7 @@ impl BlockReader {
 
     async fn read_columns(&self, part: PartInfoPtr) -> Result<(usize, Vec<ArrayIter<'static>>)> {
         let part = FusePartInfo::from_part(&part)?;
+        self.check_quarantine(part)?;
 
         let rows = part.nums_rows;
         // TODO: add prefetch column data.
@@ -114,12 +138,15 @@ impl BlockReader {
         for index in &self.projection {
             let column_meta = &part.columns_meta[index];
             let column_reader = self.operator.object(&part.location);
+            let hedged_read = self.hedged_read.clone();
             let fut = async move {
                 // NOTE: move chunk inside future so that alloc only
                 // happen when future is ready to go.
-                let column_chunk = column_reader
-                    .range_read(column_meta.offset..column_meta.offset + column_meta.length)
-                    .await?;
+                let range = column_meta.offset..column_meta.offset + column_meta.length;
+                let column_chunk = match hedged_read {
+                    None => column_reader.range_read(range).await?,
+                    Some(hedged_read) => hedged_read.range_read(column_reader, range).await?,
+                };
                 Ok::<_, ErrorCode>(column_chunk)
             }
             .instrument(debug_span!("read_col_chunk"));
@@ -131,6 +158,7 @@ impl BlockReader {
             .buffered(std::cmp::min(10, num_cols))
             .try_collect::<Vec<_>>()
             .await?;
+        self.verify_chunks(part, &chunks)?;
 
         let mut columns_array_iter = Vec::with_capacity(num_cols);
         for (i, column_chunk) in chunks.into_iter().enumerate() {
@@ -184,6 +212,7 @@ impl BlockReader {
 
     pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<Vec<u8>>> {
         let part = FusePartInfo::from_part(&part)?;
+        self.check_quarantine(part)?;
         let mut join_handlers = Vec::with_capacity(self.projection.len());
 
         for index in &self.projection {
@@ -193,14 +222,44 @@ impl BlockReader {
                 self.operator.object(&part.location),
                 column_meta.offset,
                 column_meta.length,
+                self.hedged_read.clone(),
             ));
         }
 
-        futures::future::try_join_all(join_handlers).await
+        let chunks = futures::future::try_join_all(join_handlers).await?;
+        self.verify_chunks(part, &chunks)?;
+        Ok(chunks)
     }
 
-    async fn read_column(o: Object, offset: u64, length: u64) -> Result<Vec<u8>> {
+    fn check_quarantine(&self, part: &FusePartInfo) -> Result<()> {
+        match &self.checksum_verifier {
+            None => Ok(()),
+            Some(verifier) => verifier.check_quarantine(&part.location),
+        }
+    }
+
+    // The chunks are in the order of the projection.
+    fn verify_chunks(&self, part: &FusePartInfo, chunks: &[Vec<u8>]) -> Result<()> {
+        if let Some(verifier) = &self.checksum_verifier {
+            for (index, chunk) in self.projection.iter().zip(chunks) {
+                let column_meta = &part.columns_meta[index];
+                verifier.verify(&part.location, *index, column_meta.checksum, chunk)?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn read_column(
+        o: Object,
+        offset: u64,
+        length: u64,
+        hedged_read: Option<HedgedReadPolicy>,
+    ) -> Result<Vec<u8>> {
         let handler = common_base::base::tokio::spawn(async move {
+            if let Some(hedged_read) = hedged_read {
+                return hedged_read.range_read(o, offset..offset + length).await;
+            }
+
             let mut chunk = vec![0; length as usize];
             let mut r = o.range_reader(offset..offset + length).await?;
             r.read_exact(&mut chunk).await?;
@@ -16,8 +16,11 @@
 use std::sync::Arc;
 
 use common_cache::Cache;
+use common_contexts::DalMetrics;
 use common_exception::Result;
 
+use crate::storages::cache::CacheStatistics;
+use crate::storages::fuse::cache::record_cache_size;
 use crate::storages::fuse::cache::CacheDeferMetrics;
 use crate::storages::fuse::cache::MemoryCache;
 use crate::storages::fuse::cache::TenantLabel;
@@ -33,21 +36,33 @@ pub trait HasTenantLabel {
     fn tenant_label(&self) -> TenantLabel;
 }
 
+/// Provides the IO metrics of the query, which count the cache hits and misses
+pub trait HasDalMetrics {
+    fn dal_metrics(&self) -> DalMetrics;
+}
+
 /// A "cache-aware" reader
 pub struct CachedReader<T, L> {
     cache: Option<MemoryCache<T>>,
+    statistics: Arc<CacheStatistics>,
     loader: L,
-    name: String,
+    name: &'static str,
 }
 
 impl<T, L> CachedReader<T, L>
-where L: Loader<T> + HasTenantLabel
+where L: Loader<T> + HasTenantLabel + HasDalMetrics
 {
-    pub fn new(cache: Option<MemoryCache<T>>, loader: L, name: impl Into<String>) -> Self {
+    pub fn new(
+        cache: Option<MemoryCache<T>>,
+        statistics: Arc<CacheStatistics>,
+        loader: L,
+        name: &'static str,
+    ) -> Self {
         Self {
             cache,
+            statistics,
             loader,
-            name: name.into(),
+            name,
         }
     }
 
@@ -65,11 +80,11 @@ where L: Loader<T> + HasTenantLabel
 
                 // in PR #3798, the cache is degenerated to metered by count of cached item,
                 // later, when the size of BlockMeta could be acquired (needs some enhancements of crate `parquet2`)
-                // 1) the `read_bytes` metric should be re-enabled
-                // 2) the metrics need to be labeled by the name of cache as well
+                // the `read_bytes` metric should be re-enabled
 
                 let mut metrics = CacheDeferMetrics {
-                    tenant_label,
+                    tenant_label: tenant_label.clone(),
+                    cache_name: self.name,
                     cache_hit: false,
                     read_bytes: 0,
                 };
@@ -78,11 +93,16 @@ where L: Loader<T> + HasTenantLabel
                     Some(item) => {
                         metrics.cache_hit = true;
                         metrics.read_bytes = 0u64;
+                        self.statistics.inc_hits();
+                        self.loader.dal_metrics().inc_cache_hits(1);
                         Ok(item.clone())
                     }
                     None => {
+                        self.statistics.inc_misses();
+                        self.loader.dal_metrics().inc_cache_misses(1);
                         let item = self.load(location.as_ref(), len_hint, version).await?;
                         cache.put(location.as_ref().to_owned(), item.clone());
+                        record_cache_size(&tenant_label, self.name, cache.size());
                         Ok(item)
                     }
                 }
@@ -91,7 +111,7 @@ where L: Loader<T> + HasTenantLabel
     }
 
     pub fn name(&self) -> &str {
-        self.name.as_str()
+        self.name
     }
 
     async fn load(&self, loc: &str, len_hint: Option<u64>, version: u64) -> Result<Arc<T>> {
@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::infallible::RwLock;
+use common_exception::Result;
+use futures::future::select;
+use futures::future::Either;
+use metrics::counter;
+use opendal::Object;
+
+const FUSE_HEDGED_READS: &str = "fuse_hedged_reads";
+const FUSE_HEDGED_READ_WINS: &str = "fuse_hedged_read_wins";
+
+const LATENCY_SAMPLES: usize = 1024;
+// No hedged read is issued until the threshold is derived from enough samples.
+const MIN_LATENCY_SAMPLES: usize = 64;
+
+/// The latencies of the recent block reads, the threshold of the hedged reads is derived from.
+#[derive(Default)]
+pub struct ReadLatencyTracker {
+    samples: RwLock<VecDeque<Duration>>,
+}
+
+impl ReadLatencyTracker {
+    pub fn init() -> ReadLatencyTracker {
+        Default::default()
+    }
+
+    pub fn record(&self, latency: Duration) {
+        let mut samples = self.samples.write();
+        if samples.len() == LATENCY_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(latency);
+    }
+
+    /// Returns None if there are not enough samples yet.
+    pub fn percentile(&self, percentile: u64) -> Option<Duration> {
+        let mut samples = {
+            let samples = self.samples.read();
+            if samples.len() < MIN_LATENCY_SAMPLES {
+                return None;
+            }
+            samples.iter().cloned().collect::<Vec<_>>()
+        };
+
+        samples.sort_unstable();
+        let percentile = std::cmp::min(percentile, 100) as usize;
+        let index = (samples.len() * percentile / 100).min(samples.len() - 1);
+        Some(samples[index])
+    }
+}
+
+/// Issues a backup request if a read has not returned within the given percentile of the
+/// recent read latencies, and takes the first response.
+#[derive(Clone)]
+pub struct HedgedReadPolicy {
+    tracker: Arc<ReadLatencyTracker>,
+    percentile: u64,
+    min_delay: Duration,
+}
+
+impl HedgedReadPolicy {
+    pub fn create(
+        tracker: Arc<ReadLatencyTracker>,
+        percentile: u64,
+        min_delay: Duration,
+    ) -> HedgedReadPolicy {
+        HedgedReadPolicy {
+            tracker,
+            percentile,
+            min_delay,
+        }
+    }
+
+    pub fn delay(&self) -> Option<Duration> {
+        self.tracker
+            .percentile(self.percentile)
+            .map(|latency| latency.max(self.min_delay))
+    }
+
+    pub async fn range_read(&self, object: Object, range: Range<u64>) -> Result<Vec<u8>> {
+        let delay = match self.delay() {
+            None => return self.timed_read(object, range).await,
+            Some(delay) => delay,
+        };
+
+        let primary = Box::pin(self.timed_read(object.clone(), range.clone()));
+        let timer = Box::pin(common_base::base::tokio::time::sleep(delay));
+        let primary = match select(primary, timer).await {
+            Either::Left((res, _)) => return res,
+            Either::Right((_, primary)) => primary,
+        };
+
+        counter!(FUSE_HEDGED_READS, 1);
+        let backup = Box::pin(self.timed_read(object, range));
+        // The loser is dropped, which cancels the request.
+        match select(primary, backup).await {
+            Either::Left((Ok(data), _)) => Ok(data),
+            Either::Right((Ok(data), _)) => {
+                counter!(FUSE_HEDGED_READ_WINS, 1);
+                Ok(data)
+            }
+            Either::Left((Err(_), backup)) => backup.await,
+            Either::Right((Err(_), primary)) => primary.await,
+        }
+    }
+
+    async fn timed_read(&self, object: Object, range: Range<u64>) -> Result<Vec<u8>> {
+        let start = Instant::now();
+        let data = object.range_read(range).await?;
+        self.tracker.record(start.elapsed());
+        Ok(data)
+    }
+}
@@ -15,6 +15,7 @@
 use std::pin::Pin;
 use std::sync::Arc;
 
+use common_contexts::DalMetrics;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::io::BufReader;
@@ -22,10 +23,13 @@ use futures::stream;
 use opendal::BytesReader;
 
 use super::cached_reader::CachedReader;
+use super::cached_reader::HasDalMetrics;
 use super::cached_reader::HasTenantLabel;
 use super::cached_reader::Loader;
 use super::versioned_reader::VersionedReader;
 use crate::sessions::QueryContext;
+use crate::storages::cache::SEGMENT_INFO_CACHE;
+use crate::storages::cache::TABLE_SNAPSHOT_CACHE;
 use crate::storages::fuse::cache::TenantLabel;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::SegmentInfo;
@@ -49,18 +53,22 @@ pub struct MetaReaders;
 
 impl MetaReaders {
     pub fn segment_info_reader(ctx: &QueryContext) -> SegmentInfoReader {
+        let cache_manager = ctx.get_storage_cache_manager();
         SegmentInfoReader::new(
-            ctx.get_storage_cache_manager().get_table_segment_cache(),
+            cache_manager.get_table_segment_cache(),
+            cache_manager.get_table_segment_statistics(),
             ctx,
-            "SEGMENT_INFO_CACHE".to_owned(),
+            SEGMENT_INFO_CACHE,
         )
     }
 
     pub fn table_snapshot_reader(ctx: &QueryContext) -> TableSnapshotReader {
+        let cache_manager = ctx.get_storage_cache_manager();
         TableSnapshotReader::new(
-            ctx.get_storage_cache_manager().get_table_snapshot_cache(),
+            cache_manager.get_table_snapshot_cache(),
+            cache_manager.get_table_snapshot_statistics(),
             ctx,
-            "SNAPSHOT_CACHE".to_owned(),
+            TABLE_SNAPSHOT_CACHE,
         )
     }
 }
@@ -206,6 +214,18 @@ impl HasTenantLabel for Arc<QueryContext> {
     }
 }
 
+impl HasDalMetrics for &QueryContext {
+    fn dal_metrics(&self) -> DalMetrics {
+        self.get_dal_metrics()
+    }
+}
+
+impl HasDalMetrics for Arc<QueryContext> {
+    fn dal_metrics(&self) -> DalMetrics {
+        self.get_dal_metrics()
+    }
+}
+
 fn ctx_tenant_label(ctx: &QueryContext) -> TenantLabel {
     let mgr = ctx.get_storage_cache_manager();
     TenantLabel {
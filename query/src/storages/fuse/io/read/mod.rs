@@ -14,10 +14,14 @@
 
 mod block_reader;
 mod cached_reader;
+mod hedged_read;
 mod meta_readers;
 mod versioned_reader;
 
+pub use block_reader::BlockReadOptions;
 pub use block_reader::BlockReader;
+pub use hedged_read::HedgedReadPolicy;
+pub use hedged_read::ReadLatencyTracker;
 pub use meta_readers::MetaReaders;
 pub use meta_readers::SegmentInfoReader;
 pub use meta_readers::TableSnapshotReader;
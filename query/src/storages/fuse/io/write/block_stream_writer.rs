@@ -30,11 +30,13 @@ use opendal::Operator;
 use super::block_writer;
 use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::corruption::column_checksums;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::ColumnMeta;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::partitioning::TablePartitioning;
 use crate::storages::fuse::statistics::accumulator::BlockStatistics;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
 use crate::storages::index::ClusterKeyInfo;
@@ -49,6 +51,7 @@ pub struct BlockStreamWriter {
     statistics_accumulator: Option<StatisticsAccumulator>,
     meta_locations: TableMetaLocationGenerator,
     cluster_key_info: Option<ClusterKeyInfo>,
+    partitioning: Option<Arc<TablePartitioning>>,
     ctx: Arc<QueryContext>,
 }
 
@@ -60,6 +63,7 @@ impl BlockStreamWriter {
         block_per_segment: usize,
         meta_locations: TableMetaLocationGenerator,
         cluster_key_info: Option<ClusterKeyInfo>,
+        partitioning: Option<Arc<TablePartitioning>>,
     ) -> SegmentInfoStream {
         // filter out empty blocks
         let block_stream =
@@ -75,8 +79,13 @@ impl BlockStreamWriter {
 
         // Write out the blocks.
         // And transform the stream of DataBlocks into Stream of SegmentInfo at the same time.
-        let block_writer =
-            BlockStreamWriter::new(block_per_segment, meta_locations, ctx, cluster_key_info);
+        let block_writer = BlockStreamWriter::new(
+            block_per_segment,
+            meta_locations,
+            ctx,
+            cluster_key_info,
+            partitioning,
+        );
         let segments = Self::transform(Box::pin(block_stream), block_writer);
 
         Box::pin(segments)
@@ -87,6 +96,7 @@ impl BlockStreamWriter {
         meta_locations: TableMetaLocationGenerator,
         ctx: Arc<QueryContext>,
         cluster_key_info: Option<ClusterKeyInfo>,
+        partitioning: Option<Arc<TablePartitioning>>,
     ) -> Self {
         let data_accessor = ctx.get_storage_operator().unwrap();
         Self {
@@ -96,6 +106,7 @@ impl BlockStreamWriter {
             statistics_accumulator: None,
             meta_locations,
             cluster_key_info,
+            partitioning,
             ctx,
         }
     }
@@ -132,6 +143,40 @@ impl BlockStreamWriter {
     }
 
     async fn write_block(&mut self, data_block: DataBlock) -> Result<Option<SegmentInfo>> {
+        // For partitioned table, each block holds rows of exactly one partition, so that
+        // partitions can be pruned (or dropped) by block statistics.
+        let blocks = match &self.partitioning {
+            Some(partitioning) => {
+                let max_partitions = self.ctx.get_settings().get_max_partitions_per_insert()?;
+                partitioning.split_block(&data_block, max_partitions as usize)?
+            }
+            None => vec![data_block],
+        };
+        for block in blocks {
+            self.write_block_file(block).await?;
+        }
+
+        if self.number_of_blocks_accumulated >= self.num_block_threshold {
+            let acc = self.statistics_accumulator.take().unwrap_or_default();
+            let summary = acc.summary()?;
+            let seg = SegmentInfo::new(acc.blocks_metas, Statistics {
+                row_count: acc.summary_row_count,
+                block_count: acc.summary_block_count,
+                uncompressed_byte_size: acc.in_memory_size,
+                compressed_byte_size: acc.file_size,
+                col_stats: summary,
+            });
+
+            // Reset state
+            self.number_of_blocks_accumulated = 0;
+
+            Ok(Some(seg))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn write_block_file(&mut self, data_block: DataBlock) -> Result<()> {
         let mut cluster_stats = None;
         let mut block = data_block;
         if let Some(v) = self.cluster_key_info.as_mut() {
@@ -188,35 +233,22 @@ impl BlockStreamWriter {
 
         let mut acc = self.statistics_accumulator.take().unwrap_or_default();
         let partial_acc = acc.begin(&block, cluster_stats)?;
-        let schema = block.schema().to_arrow();
         let location = self.meta_locations.gen_block_location();
+
+        // we need a configuration of block size threshold here
+        let mut data = Vec::with_capacity(100 * 1024 * 1024);
+        let schema = block.schema().clone();
         let (file_size, file_meta_data) =
-            block_writer::write_block(&schema, block, self.data_accessor.clone(), &location)
-                .await?;
+            block_writer::serialize_data_blocks(vec![block], &schema, &mut data)?;
         let col_metas = Self::column_metas(&file_meta_data)?;
-        acc = partial_acc.end(file_size, location, col_metas);
+        let checksums = column_checksums(&data, &col_metas)?;
+        self.data_accessor.object(&location).write(data).await?;
+        acc = partial_acc.end(file_size, location, col_metas, checksums);
         self.number_of_blocks_accumulated += 1;
-        if self.number_of_blocks_accumulated >= self.num_block_threshold {
-            let summary = acc.summary()?;
-            let seg = SegmentInfo::new(acc.blocks_metas, Statistics {
-                row_count: acc.summary_row_count,
-                block_count: acc.summary_block_count,
-                uncompressed_byte_size: acc.in_memory_size,
-                compressed_byte_size: acc.file_size,
-                col_stats: summary,
-            });
-
-            // Reset state
-            self.number_of_blocks_accumulated = 0;
-            self.statistics_accumulator = None;
 
-            Ok(Some(seg))
-        } else {
-            // Stash the state
-            self.statistics_accumulator = Some(acc);
-
-            Ok(None)
-        }
+        // Stash the state
+        self.statistics_accumulator = Some(acc);
+        Ok(())
     }
 
     fn column_metas(file_meta: &FileMetaData) -> Result<HashMap<ColumnId, ColumnMeta>> {
@@ -59,6 +59,12 @@ pub struct BlockMeta {
     /// used in the write path.
     #[serde(default = "Compression::legacy")]
     pub compression: Compression,
+
+    /// xxHash64 of the column chunks, verified when the columns are read.
+    ///
+    /// None for the blocks written by the versions without checksums.
+    #[serde(default)]
+    pub checksums: Option<HashMap<ColumnId, u64>>,
 }
 
 impl SegmentInfo {
@@ -98,6 +104,7 @@ impl From<v0::BlockMeta> for BlockMeta {
             cluster_stats: None,
             location: (s.location.path, DataBlock::VERSION),
             compression: Compression::Lz4,
+            checksums: None,
         }
     }
 }
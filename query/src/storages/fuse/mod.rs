@@ -14,14 +14,18 @@
 
 pub mod cache;
 mod constants;
+pub mod corruption;
 mod fuse_part;
 mod fuse_table;
 pub mod io;
 pub mod meta;
 pub mod operations;
+pub mod partitioning;
 pub mod pruning;
+pub mod replication;
 pub mod statistics;
 pub mod table_functions;
+pub mod ttl_expiry;
 
 pub use constants::*;
 pub use fuse_table::FuseTable;
@@ -76,6 +76,7 @@ impl FuseTable {
             block_per_seg,
             self.meta_location_generator().clone(),
             cluster_key_info,
+            self.partitioning()?,
         )
         .await;
 
@@ -205,6 +206,7 @@ impl FuseTable {
             });
         }
 
+        let partitioning = self.partitioning()?;
         let mut sink_pipeline_builder = SinkPipeBuilder::create();
         for _ in 0..pipeline.output_len() {
             let input_port = InputPort::create();
@@ -217,6 +219,7 @@ impl FuseTable {
                     da.clone(),
                     self.meta_location_generator().clone(),
                     cluster_key_info.clone(),
+                    partitioning.clone(),
                 )?,
             );
         }
@@ -225,7 +228,7 @@ impl FuseTable {
         Ok(())
     }
 
-    fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
+    pub(crate) fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
             .get(opt_key)
@@ -28,11 +28,13 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::LoadState;
 use common_meta_types::MatchSeq;
 use common_tracing::tracing;
 use uuid::Uuid;
 
 use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_BROADCAST;
 use crate::sql::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use crate::sql::OPT_KEY_SNAPSHOT_LOCATION;
 use crate::storages::fuse::meta::ClusterKey;
@@ -41,6 +43,7 @@ use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::operations::record_load_committing;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::operations::TableOperationLog;
 use crate::storages::fuse::statistics;
@@ -95,7 +98,10 @@ impl FuseTable {
                 .try_commit(ctx.as_ref(), catalog_name, &operation_log, overwrite)
                 .await
             {
-                Ok(_) => break Ok(()),
+                Ok(_) => {
+                    tbl.schedule_replication(&ctx, catalog_name);
+                    break Ok(());
+                }
                 Err(e) if self::utils::is_error_recoverable(&e) => match backoff.next_backoff() {
                     Some(d) => {
                         let name = tbl.table_info.name.clone();
@@ -118,6 +124,20 @@ impl FuseTable {
                         latest = catalog.get_table_by_info(&table_info)?;
                         tbl = FuseTable::try_from_table(latest.as_ref())?;
                         retry_times += 1;
+
+                        // The blocks were written with the schema of `self`, they can't be
+                        // merged into the snapshots of another schema.
+                        if tbl.table_info.meta.schema != self.table_info.meta.schema {
+                            tracing::info!("aborting operations, the table schema changed");
+                            let _ =
+                                self::utils::abort_operations(ctx.as_ref(), operation_log).await;
+                            break Err(ErrorCode::TableSchemaMismatch(format!(
+                                "the schema of table {} changed since the data was written, \
+                                 identity {}",
+                                tbl.table_info.name.as_str(),
+                                tbl.table_info.ident,
+                            )));
+                        }
                         continue;
                     }
                     None => {
@@ -157,6 +177,15 @@ impl FuseTable {
         };
         ctx.get_write_progress().incr(&progress_values);
 
+        // A load with an idempotency key is recorded along with its snapshot.
+        let load_record = ctx
+            .get_load_idempotency_key(self.table_info.ident.table_id)
+            .map(|(key, mut info, api)| {
+                info.rows = summary.row_count;
+                info.bytes = summary.uncompressed_byte_size;
+                (key, info, api)
+            });
+
         let segments = segments
             .into_iter()
             .map(|loc| (loc, SegmentInfo::VERSION))
@@ -186,6 +215,22 @@ impl FuseTable {
         let snapshot_loc = self
             .meta_location_generator()
             .snapshot_location_from_uuid(&uuid, TableSnapshot::VERSION)?;
+
+        // The load is recorded as committing before the meta update, so that a retry after
+        // an interruption finds the snapshot of the load in the history of the table.
+        let table_id = self.table_info.ident.table_id;
+        let load_record = match load_record {
+            Some((key, mut info, api)) => {
+                let ttl = ctx.get_settings().get_load_idempotency_key_ttl()?;
+                let ttl = Duration::from_secs(ttl);
+                let snapshot_id = uuid.to_string();
+                record_load_committing(api.as_ref(), table_id, &key, &mut info, snapshot_id, ttl)
+                    .await?;
+                Some((key, info, api, ttl))
+            }
+            None => None,
+        };
+
         let bytes = serde_json::to_vec(&new_snapshot)?;
         let operator = ctx.get_storage_operator()?;
         operator.object(&snapshot_loc).write(bytes).await?;
@@ -199,6 +244,18 @@ impl FuseTable {
         )
         .await;
 
+        // The load is recorded as succeeded once the snapshot is committed. Otherwise the record
+        // is left committing: a snapshot committed along with a transaction, or by an update
+        // whose reply is lost, is looked up in the history of the table by the retries.
+        if let (Some((key, mut info, api, ttl)), Ok(_), None) =
+            (load_record, &result, ctx.get_transaction())
+        {
+            info.state = LoadState::Success;
+            if let Err(cause) = api.record_load(table_id, &key, info, ttl).await {
+                tracing::warn!("update the record of load '{}' error: {:?}", key, cause);
+            }
+        }
+
         match result {
             Ok(_) => {
                 if let Some(snapshot_cache) =
@@ -254,15 +311,13 @@ impl FuseTable {
         Ok(new_snapshot)
     }
 
-    async fn commit_to_meta_server(
+    pub(crate) async fn commit_to_meta_server(
         ctx: &QueryContext,
         catalog_name: &str,
         table_info: &TableInfo,
         new_snapshot_location: String,
         stats: &Statistics,
     ) -> Result<UpdateTableMetaReply> {
-        let catalog = ctx.get_catalog(catalog_name)?;
-
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
 
@@ -290,9 +345,79 @@ impl FuseTable {
             new_table_meta,
         };
 
+        let reply = Self::update_table_meta(ctx, catalog_name, req).await?;
+
+        // The cached blocks of the previous snapshot may be no longer referenced, drop them
+        // instead of waiting for the LRU to evict them.
+        if table_info.options().contains_key(OPT_KEY_BROADCAST) {
+            ctx.get_storage_cache_manager()
+                .get_broadcast_table_cache()
+                .invalidate(table_id);
+        }
+        Ok(reply)
+    }
+
+    /// Updates the meta of the table in the catalog. In a transaction, the update is kept in
+    /// the transaction instead, and made visible by COMMIT along with its other writes.
+    pub(crate) async fn update_table_meta(
+        ctx: &QueryContext,
+        catalog_name: &str,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply> {
+        if let Some(txn) = ctx.get_transaction() {
+            txn.update_table_meta(catalog_name, req)?;
+            return Ok(UpdateTableMetaReply {});
+        }
+
+        let catalog = ctx.get_catalog(catalog_name)?;
         catalog.update_table_meta(req).await
     }
 
+    /// Writes `new_snapshot` and makes it the current snapshot of the table.
+    ///
+    /// Used by operations that rewrite the segments of the table, the commit is not
+    /// retried on conflicts.
+    pub(crate) async fn commit_mutation(
+        &self,
+        ctx: &QueryContext,
+        catalog_name: &str,
+        new_snapshot: TableSnapshot,
+    ) -> Result<()> {
+        let operator = ctx.get_storage_operator()?;
+        let snapshot_loc = self
+            .meta_location_generator()
+            .snapshot_location_from_uuid(&new_snapshot.snapshot_id, TableSnapshot::VERSION)?;
+        let bytes = serde_json::to_vec(&new_snapshot)?;
+        operator.object(&snapshot_loc).write(bytes).await?;
+
+        let result = Self::commit_to_meta_server(
+            ctx,
+            catalog_name,
+            self.get_table_info(),
+            snapshot_loc.clone(),
+            &new_snapshot.summary,
+        )
+        .await;
+
+        match result {
+            Ok(_) => {
+                if let Some(snapshot_cache) =
+                    ctx.get_storage_cache_manager().get_table_snapshot_cache()
+                {
+                    let cache = &mut snapshot_cache.write().await;
+                    cache.put(snapshot_loc, Arc::new(new_snapshot));
+                }
+                Ok(())
+            }
+            Err(e) => {
+                // commit snapshot to meta server failed, try to delete it.
+                // "major GC" will collect this, if deletion failure (even after DAL retried)
+                let _ = operator.object(&snapshot_loc).delete().await;
+                Err(e)
+            }
+        }
+    }
+
     pub fn merge_append_operations(
         append_log_entries: &[AppendOperationLogEntry],
     ) -> Result<(Vec<String>, Statistics)> {
@@ -0,0 +1,194 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::AddOnStream;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::Location;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::operations::BlockPosition;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
+use crate::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
+
+impl FuseTable {
+    /// Merges the small blocks into blocks of `row_per_block` rows, and the segments not
+    /// full into segments of `block_per_segment` blocks.
+    ///
+    /// A block is small if it has less rows than the appends keep in a block, the other
+    /// blocks are kept as they are, only their metas are moved into the merged segments.
+    pub async fn do_compact(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        let prev = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let row_per_block = self.get_option(FUSE_OPT_KEY_ROW_PER_BLOCK, DEFAULT_ROW_PER_BLOCK);
+        let block_per_segment =
+            self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let min_rows_per_block = (row_per_block as f64 * 0.8) as u64;
+
+        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let mut prev_segments = Vec::with_capacity(prev.segments.len());
+        for (seg_loc, ver) in &prev.segments {
+            prev_segments.push(reader.read(seg_loc, None, *ver).await?);
+        }
+
+        let mut selected = HashSet::new();
+        for (seg_idx, segment) in prev_segments.iter().enumerate() {
+            for (block_idx, block) in segment.blocks.iter().enumerate() {
+                if block.row_count < min_rows_per_block {
+                    selected.insert((seg_idx, block_idx));
+                }
+            }
+        }
+        let loose_segments = prev_segments
+            .iter()
+            .filter(|segment| segment.blocks.len() < block_per_segment)
+            .count();
+
+        // A small block alone can't be merged, nor can a segment.
+        if selected.len() < 2 && loose_segments < 2 {
+            return Ok(());
+        }
+        if selected.len() < 2 {
+            selected.clear();
+        }
+
+        let mut log_entries = vec![];
+        if !selected.is_empty() {
+            let block_reader = self.create_block_reader(&ctx, &None)?;
+            let mut blocks = Vec::with_capacity(selected.len());
+            for (seg_idx, block_idx) in &selected {
+                let meta = &prev_segments[*seg_idx].blocks[*block_idx];
+                blocks.push(block_reader.read(Self::all_columns_part(meta)).await?);
+            }
+
+            let schema = self.table_info.schema();
+            let mut stream: SendableDataBlockStream =
+                Box::pin(DataBlockStream::create(schema.clone(), None, blocks));
+            if !self.cluster_keys.is_empty() {
+                stream = Box::pin(AddOnStream::try_create(
+                    stream,
+                    self.cluster_keys.clone(),
+                    schema.clone(),
+                    schema,
+                    ctx.clone(),
+                )?);
+            }
+            log_entries = self
+                .append_chunks(ctx.clone(), stream)
+                .await?
+                .try_collect::<Vec<AppendOperationLogEntry>>()
+                .await?;
+        }
+
+        self.commit_compacted(
+            ctx.as_ref(),
+            catalog_name,
+            &prev,
+            &prev_segments,
+            &selected,
+            log_entries,
+            block_per_segment,
+        )
+        .await
+    }
+
+    // The full segments keeping all their blocks are kept, the blocks of the other segments
+    // are packed into new segments, along with the merged blocks.
+    #[allow(clippy::too_many_arguments)]
+    async fn commit_compacted(
+        &self,
+        ctx: &QueryContext,
+        catalog_name: &str,
+        prev: &TableSnapshot,
+        prev_segments: &[Arc<SegmentInfo>],
+        merged: &HashSet<BlockPosition>,
+        log_entries: Vec<AppendOperationLogEntry>,
+        block_per_segment: usize,
+    ) -> Result<()> {
+        let operator = ctx.get_storage_operator()?;
+        let mut summary = Statistics::default();
+        let mut segments: Vec<Location> = Vec::with_capacity(prev.segments.len());
+        let mut loose_blocks: Vec<BlockMeta> = vec![];
+
+        for entry in log_entries {
+            if entry.segment_info.blocks.len() >= block_per_segment {
+                summary = statistics::merge_statistics(&summary, &entry.segment_info.summary)?;
+                segments.push((entry.segment_location, SegmentInfo::VERSION));
+                continue;
+            }
+
+            // Written by the append, but never referenced.
+            loose_blocks.extend(entry.segment_info.blocks.iter().cloned());
+            operator.object(&entry.segment_location).delete().await?;
+        }
+
+        for (seg_idx, segment) in prev_segments.iter().enumerate() {
+            let remains = segment
+                .blocks
+                .iter()
+                .enumerate()
+                .filter(|(block_idx, _)| !merged.contains(&(seg_idx, *block_idx)))
+                .map(|(_, b)| b.clone())
+                .collect::<Vec<_>>();
+
+            if remains.len() == segment.blocks.len() && remains.len() >= block_per_segment {
+                summary = statistics::merge_statistics(&summary, &segment.summary)?;
+                segments.push(prev.segments[seg_idx].clone());
+                continue;
+            }
+            loose_blocks.extend(remains);
+        }
+
+        for blocks in loose_blocks.chunks(block_per_segment) {
+            let seg_summary = Self::summarize_blocks(blocks)?;
+            summary = statistics::merge_statistics(&summary, &seg_summary)?;
+            let new_segment = SegmentInfo::new(blocks.to_vec(), seg_summary);
+            let new_seg_loc = self.meta_location_generator().gen_segment_info_location();
+            let bytes = serde_json::to_vec(&new_segment)?;
+            operator.object(&new_seg_loc).write(bytes).await?;
+            segments.push((new_seg_loc, SegmentInfo::VERSION));
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.timestamp,
+            Some((prev.snapshot_id, prev.format_version())),
+            prev.schema.clone(),
+            summary,
+            segments,
+            self.cluster_key_meta.clone(),
+        );
+        self.commit_mutation(ctx, catalog_name, new_snapshot).await
+    }
+}
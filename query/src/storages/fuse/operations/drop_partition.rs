@@ -0,0 +1,116 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PartitionRef;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// Drops all the rows of the given partitions.
+    ///
+    /// Since each block of a partitioned table holds rows of exactly one partition,
+    /// no data needs to be rewritten: blocks of the partitions are detached from the
+    /// segments, and a new snapshot is committed.
+    pub async fn do_drop_partition(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        partitions: Vec<PartitionRef>,
+    ) -> Result<()> {
+        let partitioning = self.partitioning()?.ok_or_else(|| {
+            ErrorCode::BadArguments(format!("table {} is not partitioned", self.table_info.name))
+        })?;
+        let targets = partitioning.resolve(&partitions)?;
+
+        let prev = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let operator = ctx.get_storage_operator()?;
+
+        let mut dropped = false;
+        let mut summary = Statistics::default();
+        let mut segments = Vec::with_capacity(prev.segments.len());
+        for (seg_loc, ver) in &prev.segments {
+            let segment = reader.read(seg_loc, None, *ver).await?;
+            let remains = segment
+                .blocks
+                .iter()
+                .filter(|b| !partitioning.is_block_of(b, &targets))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if remains.len() == segment.blocks.len() {
+                summary = statistics::merge_statistics(&summary, &segment.summary)?;
+                segments.push((seg_loc.clone(), *ver));
+                continue;
+            }
+
+            dropped = true;
+            if remains.is_empty() {
+                continue;
+            }
+
+            let seg_summary = Self::summarize_blocks(&remains)?;
+            summary = statistics::merge_statistics(&summary, &seg_summary)?;
+            let new_segment = SegmentInfo::new(remains, seg_summary);
+            let new_seg_loc = self.meta_location_generator().gen_segment_info_location();
+            let bytes = serde_json::to_vec(&new_segment)?;
+            operator.object(&new_seg_loc).write(bytes).await?;
+            segments.push((new_seg_loc, SegmentInfo::VERSION));
+        }
+
+        if !dropped {
+            return Ok(());
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.timestamp,
+            Some((prev.snapshot_id, prev.format_version())),
+            prev.schema.clone(),
+            summary,
+            segments,
+            self.cluster_key_meta.clone(),
+        );
+        self.commit_mutation(ctx.as_ref(), catalog_name, new_snapshot)
+            .await
+    }
+
+    pub(crate) fn summarize_blocks(blocks: &[BlockMeta]) -> Result<Statistics> {
+        let col_stats = blocks.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+        Ok(Statistics {
+            row_count: blocks.iter().map(|b| b.row_count).sum(),
+            block_count: blocks.len() as u64,
+            uncompressed_byte_size: blocks.iter().map(|b| b.block_size).sum(),
+            compressed_byte_size: blocks.iter().map(|b| b.file_size).sum(),
+            col_stats: statistics::reduce_block_stats(&col_stats)?,
+        })
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_tracing::tracing;
+use opendal::Operator;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::SnapshotId;
+use crate::storages::fuse::FuseTable;
+
+pub const EXPORT_MANIFEST_FILE_NAME: &str = "manifest.json";
+pub const EXPORT_DATA_DIR: &str = "data";
+
+/// Describes an exported snapshot, so that other engines could read the Parquet files
+/// without knowing anything about the fuse table format.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SnapshotExportManifest {
+    pub table: String,
+    pub snapshot_id: Option<SnapshotId>,
+    pub snapshot_timestamp: Option<DateTime<Utc>>,
+    pub format: String,
+    pub columns: Vec<ExportedColumn>,
+    pub row_count: u64,
+    pub files: Vec<ExportedFile>,
+    pub exported_at: DateTime<Utc>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ExportedColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+/// A Parquet file of the export, the path is relative to the export directory.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ExportedFile {
+    pub path: String,
+    pub row_count: u64,
+    pub file_size: u64,
+}
+
+impl FuseTable {
+    /// Exports the current snapshot of the table into the directory `path` of `target`.
+    ///
+    /// The blocks are Parquet files already, they are copied to `<path>/data/` as they are.
+    /// The manifest is written after all the blocks, a reader should take an export without
+    /// `<path>/manifest.json` as incomplete.
+    pub async fn do_export_snapshot(
+        &self,
+        ctx: &QueryContext,
+        target: &Operator,
+        path: &str,
+    ) -> Result<SnapshotExportManifest> {
+        let snapshot = self.read_table_snapshot(ctx).await?;
+        let dir = path.trim_end_matches('/');
+
+        let mut files = vec![];
+        if let Some(snapshot) = &snapshot {
+            let source = ctx.get_storage_operator()?;
+            let segment_reader = MetaReaders::segment_info_reader(ctx);
+            for (segment_location, ver) in &snapshot.segments {
+                let segment = segment_reader.read(segment_location, None, *ver).await?;
+                for block in &segment.blocks {
+                    let block_location = &block.location.0;
+                    let file_name = block_location.rsplit('/').next().unwrap_or(block_location);
+                    let file_path = format!("{}/{}", EXPORT_DATA_DIR, file_name);
+
+                    let bytes = source.object(block_location).read().await?;
+                    target
+                        .object(&format!("{}/{}", dir, file_path))
+                        .write(bytes)
+                        .await?;
+
+                    files.push(ExportedFile {
+                        path: file_path,
+                        row_count: block.row_count,
+                        file_size: block.file_size,
+                    });
+                }
+            }
+        }
+
+        let schema = match &snapshot {
+            Some(snapshot) => snapshot.schema.clone(),
+            None => self.table_info.schema().as_ref().clone(),
+        };
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| ExportedColumn {
+                name: field.name().clone(),
+                data_type: field.data_type().name(),
+                nullable: field.is_nullable(),
+            })
+            .collect();
+
+        let manifest = SnapshotExportManifest {
+            table: self.table_info.desc.clone(),
+            snapshot_id: snapshot.as_ref().map(|s| s.snapshot_id),
+            snapshot_timestamp: snapshot.as_ref().and_then(|s| s.timestamp),
+            format: "parquet".to_string(),
+            columns,
+            row_count: files.iter().map(|f| f.row_count).sum(),
+            files,
+            exported_at: Utc::now(),
+        };
+        target
+            .object(&format!("{}/{}", dir, EXPORT_MANIFEST_FILE_NAME))
+            .write(serde_json::to_vec_pretty(&manifest)?)
+            .await?;
+
+        tracing::info!(
+            "exported snapshot {:?} of table {} to {}, {} files",
+            manifest.snapshot_id,
+            self.table_info.ident,
+            path,
+            manifest.files.len()
+        );
+        Ok(manifest)
+    }
+}
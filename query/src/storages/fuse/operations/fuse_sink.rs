@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -32,12 +33,14 @@ use crate::storages::fuse::io::serialize_data_blocks;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::partitioning::TablePartitioning;
 use crate::storages::fuse::statistics::accumulator::BlockStatistics;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
 use crate::storages::index::ClusterKeyInfo;
 
 enum State {
     None,
+    NeedPartition(DataBlock),
     NeedSerialize(DataBlock),
     Serialized {
         data: Vec<u8>,
@@ -63,6 +66,8 @@ pub struct FuseTableSink {
     meta_locations: TableMetaLocationGenerator,
     accumulator: StatisticsAccumulator,
     cluster_key_info: Option<ClusterKeyInfo>,
+    partitioning: Option<Arc<TablePartitioning>>,
+    partitioned_blocks: VecDeque<DataBlock>,
 }
 
 impl FuseTableSink {
@@ -73,6 +78,7 @@ impl FuseTableSink {
         data_accessor: Operator,
         meta_locations: TableMetaLocationGenerator,
         cluster_key_info: Option<ClusterKeyInfo>,
+        partitioning: Option<Arc<TablePartitioning>>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
             ctx,
@@ -83,6 +89,8 @@ impl FuseTableSink {
             accumulator: Default::default(),
             num_block_threshold: num_block_threshold as u64,
             cluster_key_info,
+            partitioning,
+            partitioned_blocks: VecDeque::new(),
         })))
     }
 }
@@ -96,7 +104,7 @@ impl Processor for FuseTableSink {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::NeedSerialize(_) | State::GenerateSegment
+            State::NeedPartition(_) | State::NeedSerialize(_) | State::GenerateSegment
         ) {
             return Ok(Event::Sync);
         }
@@ -108,6 +116,11 @@ impl Processor for FuseTableSink {
             return Ok(Event::Async);
         }
 
+        if let Some(block) = self.partitioned_blocks.pop_front() {
+            self.state = State::NeedSerialize(block);
+            return Ok(Event::Sync);
+        }
+
         if self.input.is_finished() {
             if self.accumulator.summary_row_count != 0 {
                 self.state = State::GenerateSegment;
@@ -123,12 +136,26 @@ impl Processor for FuseTableSink {
             return Ok(Event::NeedData);
         }
 
-        self.state = State::NeedSerialize(self.input.pull_data().unwrap()?);
+        let data_block = self.input.pull_data().unwrap()?;
+        self.state = match self.partitioning {
+            Some(_) => State::NeedPartition(data_block),
+            None => State::NeedSerialize(data_block),
+        };
         Ok(Event::Sync)
     }
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
+            State::NeedPartition(data_block) => {
+                // Each block holds rows of exactly one partition, so that partitions
+                // can be pruned (or dropped) by block statistics.
+                if let Some(partitioning) = &self.partitioning {
+                    let settings = self.ctx.get_settings();
+                    let max_partitions = settings.get_max_partitions_per_insert()? as usize;
+                    let blocks = partitioning.split_block(&data_block, max_partitions)?;
+                    self.partitioned_blocks.extend(blocks);
+                }
+            }
             State::NeedSerialize(data_block) => {
                 let mut cluster_stats = None;
                 let mut block = data_block;
@@ -193,13 +220,11 @@ impl Processor for FuseTableSink {
                 meta_data,
                 block_statistics,
             } => {
-                self.data_accessor
-                    .object(&block_statistics.block_file_location)
-                    .write(data)
-                    .await?;
-
+                let location = block_statistics.block_file_location.clone();
                 self.accumulator
-                    .add_block(size, *meta_data, block_statistics)?;
+                    .add_block(size, *meta_data, block_statistics, &data)?;
+
+                self.data_accessor.object(&location).write(data).await?;
                 if self.accumulator.summary_block_count >= self.num_block_threshold {
                     self.state = State::GenerateSegment;
                 }
@@ -15,8 +15,11 @@
 
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
 use common_cache::Cache;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::TryStreamExt;
 use opendal::Operator;
@@ -29,6 +32,7 @@ use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
     pub async fn do_gc(&self, ctx: &Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
+        Self::check_no_transaction(ctx)?;
         let snapshot_opt = self.read_table_snapshot(ctx.as_ref()).await?;
 
         let last_snapshot = if let Some(s) = snapshot_opt {
@@ -121,6 +125,87 @@ impl FuseTable {
         .await
     }
 
+    /// Removes the snapshots taken before `retention` ago, except the last snapshot, and the
+    /// segments and blocks not referenced by the snapshots kept.
+    ///
+    /// The snapshots are walked from the last one, once a snapshot is older than the
+    /// retention, the snapshots before it are all older, and removed as well.
+    pub async fn do_purge(&self, ctx: &Arc<QueryContext>, retention: Duration) -> Result<()> {
+        Self::check_no_transaction(ctx)?;
+        let last_snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let (prev_id, prev_ver) = match last_snapshot.prev_snapshot_id {
+            Some(prev) => prev,
+            None => return Ok(()),
+        };
+        let prev_loc = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&prev_id, prev_ver)?;
+
+        let retention = chrono::Duration::from_std(retention)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid retention period: {}", e)))?;
+        let cutoff = Utc::now() - retention;
+
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let mut snapshot_history =
+            reader.snapshot_history(prev_loc, prev_ver, self.meta_location_generator.clone());
+
+        let mut segments_referenced_by_gc_root: HashSet<Location> =
+            HashSet::from_iter(last_snapshot.segments.clone());
+        let mut snapshots_to_be_deleted: Vec<_> = Vec::new();
+        let mut segments_of_deleted: HashSet<Location> = HashSet::new();
+        while let Some(s) = snapshot_history.try_next().await? {
+            let expired = !snapshots_to_be_deleted.is_empty()
+                || s.timestamp.map(|t| t < cutoff).unwrap_or(true);
+            if expired {
+                snapshots_to_be_deleted.push((s.snapshot_id, s.format_version()));
+                segments_of_deleted.extend(s.segments.clone());
+            } else {
+                segments_referenced_by_gc_root.extend(s.segments.clone());
+            }
+        }
+
+        if snapshots_to_be_deleted.is_empty() {
+            return Ok(());
+        }
+
+        let segments_to_be_deleted: HashSet<_> = segments_of_deleted
+            .difference(&segments_referenced_by_gc_root)
+            .cloned()
+            .collect();
+        let blocks_referenced_by_gc_root: HashSet<String> = self
+            .blocks_of(ctx, segments_referenced_by_gc_root.iter())
+            .await?;
+
+        self.purge_blocks(
+            ctx,
+            segments_to_be_deleted.iter(),
+            &blocks_referenced_by_gc_root,
+        )
+        .await?;
+
+        self.collect(
+            ctx.as_ref(),
+            segments_to_be_deleted,
+            snapshots_to_be_deleted,
+        )
+        .await
+    }
+
+    // The files removed may be referenced by the snapshots committed, which the transaction
+    // doesn't see.
+    fn check_no_transaction(ctx: &QueryContext) -> Result<()> {
+        match ctx.get_transaction() {
+            Some(_) => Err(ErrorCode::InvalidTransactionState(
+                "can not purge the history of a table in a transaction",
+            )),
+            None => Ok(()),
+        }
+    }
+
     async fn blocks_of(
         &self,
         ctx: &QueryContext,
@@ -0,0 +1,102 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::Result;
+use common_management::LoadApi;
+use common_meta_types::LoadInfo;
+use common_meta_types::LoadState;
+use futures::TryStreamExt;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::FuseTable;
+
+/// How much earlier than the record of a load its snapshot may be created, beyond which the
+/// history of the table is not searched for the snapshot.
+const SNAPSHOT_CREATION_SLACK_SECS: i64 = 600;
+
+impl FuseTable {
+    /// The load committed to the table with the idempotency key, if it is still recorded.
+    pub async fn get_committed_load(
+        &self,
+        ctx: &QueryContext,
+        api: &dyn LoadApi,
+        key: &str,
+    ) -> Result<Option<LoadInfo>> {
+        let info = match api
+            .get_load_record(self.table_info.ident.table_id, key)
+            .await?
+        {
+            None => return Ok(None),
+            Some(info) => info,
+        };
+
+        match info.state {
+            LoadState::Success => Ok(Some(info)),
+            // The load is being committed, or it was interrupted before its record was
+            // updated, the snapshot of the load tells whether it is committed.
+            LoadState::Committing if self.has_snapshot_of(ctx, &info).await? => Ok(Some(info)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn has_snapshot_of(&self, ctx: &QueryContext, info: &LoadInfo) -> Result<bool> {
+        let (snapshot_id, snapshot_location) = match (&info.snapshot_id, self.snapshot_loc()) {
+            (Some(id), Some(location)) => (id, location),
+            _ => return Ok(false),
+        };
+        let oldest = info.committed_at.unwrap_or(0) as i64 - SNAPSHOT_CREATION_SLACK_SECS;
+
+        let reader = MetaReaders::table_snapshot_reader(ctx);
+        let mut snapshots = reader.snapshot_history(
+            snapshot_location,
+            self.snapshot_format_version(),
+            self.meta_location_generator().clone(),
+        );
+        // Snapshots are ordered by timestamp DESC.
+        while let Some(snapshot) = snapshots.try_next().await? {
+            if snapshot.snapshot_id.to_string() == *snapshot_id {
+                return Ok(true);
+            }
+            if matches!(snapshot.timestamp, Some(ts) if ts.timestamp() < oldest) {
+                break;
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Records the load as committing the snapshot, before the snapshot is committed to the table.
+pub(crate) async fn record_load_committing(
+    api: &dyn LoadApi,
+    table_id: u64,
+    key: &str,
+    info: &mut LoadInfo,
+    snapshot_id: String,
+    ttl: Duration,
+) -> Result<()> {
+    info.state = LoadState::Committing;
+    info.committed_at = Some(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs(),
+    );
+    info.snapshot_id = Some(snapshot_id);
+    api.record_load(table_id, key, info.clone(), ttl).await
+}
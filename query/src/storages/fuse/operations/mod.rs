@@ -14,14 +14,30 @@
 
 mod append;
 mod commit;
+mod compact;
+mod drop_partition;
+mod export;
 mod fuse_sink;
 mod gc;
+mod load_record;
+mod mutation;
 mod navigate;
 mod operation_log;
 mod read;
 mod read_partitions;
+mod recluster;
+mod repair;
+mod replicate;
 mod truncate;
 
+pub use export::ExportedColumn;
+pub use export::ExportedFile;
+pub use export::SnapshotExportManifest;
+pub use export::EXPORT_DATA_DIR;
+pub use export::EXPORT_MANIFEST_FILE_NAME;
 pub use fuse_sink::FuseTableSink;
+pub(crate) use load_record::record_load_committing;
+pub(crate) use mutation::BlockPosition;
 pub use operation_log::AppendOperationLogEntry;
 pub use operation_log::TableOperationLog;
+pub use repair::BlockRepairState;
@@ -0,0 +1,322 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::mpsc;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::meta::Versioned;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::RangeFilter;
+
+/// (segment index, block index) of a block in the snapshot
+pub(crate) type BlockPosition = (usize, usize);
+
+/// The column holding whether the row matches the selection, while the new values are evaluated
+const MATCHED_COLUMN: &str = "_mutation_matched";
+
+impl FuseTable {
+    /// Deletes the rows matching the selection.
+    ///
+    /// The blocks having matched rows are rewritten without them (copy-on-write), the other
+    /// blocks are kept as they are. Without a selection, all the blocks are detached.
+    pub async fn do_delete(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        selection: Option<Expression>,
+    ) -> Result<u64> {
+        if selection.is_none() {
+            return self.delete_all(ctx, catalog_name).await;
+        }
+
+        self.mutate(ctx, catalog_name, selection, |block, matched| {
+            let not_matched = BooleanColumn::from_arrow_data(!matched.values());
+            let remains = DataBlock::filter_block(&block, &not_matched.arc())?;
+            Ok(Some(remains).filter(|b| b.num_rows() > 0))
+        })
+        .await
+    }
+
+    async fn delete_all(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<u64> {
+        let prev = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) if !snapshot.segments.is_empty() => snapshot,
+            _ => return Ok(0),
+        };
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.timestamp,
+            Some((prev.snapshot_id, prev.format_version())),
+            prev.schema.clone(),
+            Statistics::default(),
+            vec![],
+            self.cluster_key_meta.clone(),
+        );
+        self.commit_mutation(ctx.as_ref(), catalog_name, new_snapshot)
+            .await?;
+        Ok(prev.summary.row_count)
+    }
+
+    /// Assigns the values to the columns of the rows matching the selection.
+    ///
+    /// The values are evaluated against the rows being updated, the blocks having matched
+    /// rows are rewritten (copy-on-write), the other blocks are kept as they are.
+    pub async fn do_update(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        assignments: Vec<(String, Expression)>,
+        selection: Option<Expression>,
+    ) -> Result<u64> {
+        let schema = self.table_info.schema();
+        let mut input_fields = schema.fields().clone();
+        input_fields.push(DataField::new(MATCHED_COLUMN, bool::to_data_type()));
+        let input_schema = DataSchemaRefExt::create(input_fields);
+
+        let exprs = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                match assignments.iter().find(|(name, _)| name == field.name()) {
+                    // keep the values of the rows not matched
+                    Some((_, value)) => Expression::Alias(
+                        field.name().clone(),
+                        Box::new(Expression::Cast {
+                            expr: Box::new(Expression::ScalarFunction {
+                                op: "if".to_string(),
+                                args: vec![
+                                    Expression::Column(MATCHED_COLUMN.to_string()),
+                                    value.clone(),
+                                    Expression::Column(field.name().clone()),
+                                ],
+                            }),
+                            data_type: field.data_type().clone(),
+                            pg_style: false,
+                        }),
+                    ),
+                    None => Expression::Column(field.name().clone()),
+                }
+            })
+            .collect::<Vec<_>>();
+        let executor = ExpressionExecutor::try_create(
+            ctx.clone(),
+            "update expression executor",
+            input_schema.clone(),
+            schema,
+            exprs,
+            true,
+        )?;
+        executor.validate()?;
+
+        self.mutate(ctx, catalog_name, selection, |block, matched| {
+            let matched_field = input_schema.field_with_name(MATCHED_COLUMN)?.clone();
+            let block = block.add_column(matched.arc(), matched_field)?;
+            Ok(Some(executor.execute(&block)?))
+        })
+        .await
+    }
+
+    /// Rewrites the blocks having rows matching the selection, by the given function of the
+    /// block and the matched rows. Returns the number of the matched rows.
+    ///
+    /// Blocks whose column statistics rule out the selection are not read at all.
+    async fn mutate<F>(
+        &self,
+        ctx: Arc<QueryContext>,
+        catalog_name: &str,
+        selection: Option<Expression>,
+        rewrite: F,
+    ) -> Result<u64>
+    where
+        F: Fn(DataBlock, &BooleanColumn) -> Result<Option<DataBlock>>,
+    {
+        let prev = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(0),
+        };
+
+        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let mut prev_segments = Vec::with_capacity(prev.segments.len());
+        for (seg_loc, ver) in &prev.segments {
+            prev_segments.push(reader.read(seg_loc, None, *ver).await?);
+        }
+
+        let schema = self.table_info.schema();
+        let (range_filter, executor) = match &selection {
+            None => (None, None),
+            Some(expr) => {
+                let range_filter = RangeFilter::try_create(ctx.clone(), expr, schema.clone())?;
+                let field = expr.to_data_field(&schema)?;
+                let executor = ExpressionExecutor::try_create(
+                    ctx.clone(),
+                    "mutation selection executor",
+                    schema.clone(),
+                    DataSchemaRefExt::create(vec![field]),
+                    vec![expr.clone()],
+                    false,
+                )?;
+                executor.validate()?;
+                (Some(range_filter), Some(executor))
+            }
+        };
+
+        // The rewritten blocks are written while the others are being read, instead of being
+        // buffered until all the blocks are read.
+        let (sender, receiver) = mpsc::channel::<Result<DataBlock>>(1);
+        let stream: SendableDataBlockStream = Box::pin(ReceiverStream::new(receiver));
+        let writer = async {
+            self.append_chunks(ctx.clone(), stream)
+                .await?
+                .try_collect::<Vec<AppendOperationLogEntry>>()
+                .await
+        };
+
+        let block_reader = self.create_block_reader(&ctx, &None)?;
+        let rewriter = async {
+            let sender = sender;
+            let mut matched_rows = 0;
+            let mut rewritten = HashSet::new();
+            'segments: for (seg_idx, segment) in prev_segments.iter().enumerate() {
+                for (block_idx, meta) in segment.blocks.iter().enumerate() {
+                    if let Some(range_filter) = &range_filter {
+                        if !range_filter.eval(&meta.col_stats)? {
+                            continue;
+                        }
+                    }
+
+                    let block = block_reader.read(Self::all_columns_part(meta)).await?;
+                    let matched = match &executor {
+                        None => BooleanColumn::from_owned_iterator(
+                            std::iter::repeat(true).take(block.num_rows()),
+                        ),
+                        Some(executor) => {
+                            let predicate =
+                                executor.execute(&block)?.column(0).convert_full_column();
+                            let predicate = DataBlock::cast_to_nonull_boolean(&predicate)?;
+                            let predicate: &BooleanColumn = Series::check_get(&predicate)?;
+                            predicate.clone()
+                        }
+                    };
+
+                    let count = matched.len() - matched.values().null_count();
+                    if count == 0 {
+                        continue;
+                    }
+                    matched_rows += count as u64;
+                    rewritten.insert((seg_idx, block_idx));
+                    if let Some(new_block) = rewrite(block, &matched)? {
+                        // The writer is gone only if it failed, its error is returned instead.
+                        if sender.send(Ok(new_block)).await.is_err() {
+                            break 'segments;
+                        }
+                    }
+                }
+            }
+            Result::Ok((matched_rows, rewritten))
+        };
+
+        let ((matched_rows, rewritten), log_entries) = futures::try_join!(rewriter, writer)?;
+        if rewritten.is_empty() {
+            return Ok(0);
+        }
+
+        self.commit_rewritten_blocks(
+            ctx.as_ref(),
+            catalog_name,
+            &prev,
+            &prev_segments,
+            &rewritten,
+            log_entries,
+        )
+        .await?;
+        Ok(matched_rows)
+    }
+
+    /// Commits a new snapshot, in which the rewritten blocks are detached from their segments,
+    /// and the segments of the newly appended blocks are attached.
+    pub(crate) async fn commit_rewritten_blocks(
+        &self,
+        ctx: &QueryContext,
+        catalog_name: &str,
+        prev: &TableSnapshot,
+        prev_segments: &[Arc<SegmentInfo>],
+        rewritten: &HashSet<BlockPosition>,
+        log_entries: Vec<AppendOperationLogEntry>,
+    ) -> Result<()> {
+        let operator = ctx.get_storage_operator()?;
+        let mut summary = Statistics::default();
+        let mut segments = Vec::with_capacity(prev.segments.len() + log_entries.len());
+        for (seg_idx, segment) in prev_segments.iter().enumerate() {
+            let remains = segment
+                .blocks
+                .iter()
+                .enumerate()
+                .filter(|(block_idx, _)| !rewritten.contains(&(seg_idx, *block_idx)))
+                .map(|(_, b)| b.clone())
+                .collect::<Vec<_>>();
+
+            if remains.len() == segment.blocks.len() {
+                summary = statistics::merge_statistics(&summary, &segment.summary)?;
+                segments.push(prev.segments[seg_idx].clone());
+                continue;
+            }
+
+            if remains.is_empty() {
+                continue;
+            }
+
+            let seg_summary = Self::summarize_blocks(&remains)?;
+            summary = statistics::merge_statistics(&summary, &seg_summary)?;
+            let new_segment = SegmentInfo::new(remains, seg_summary);
+            let new_seg_loc = self.meta_location_generator().gen_segment_info_location();
+            let bytes = serde_json::to_vec(&new_segment)?;
+            operator.object(&new_seg_loc).write(bytes).await?;
+            segments.push((new_seg_loc, SegmentInfo::VERSION));
+        }
+
+        for entry in log_entries {
+            summary = statistics::merge_statistics(&summary, &entry.segment_info.summary)?;
+            segments.push((entry.segment_location, SegmentInfo::VERSION));
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.timestamp,
+            Some((prev.snapshot_id, prev.format_version())),
+            prev.schema.clone(),
+            summary,
+            segments,
+            self.cluster_key_meta.clone(),
+        );
+        self.commit_mutation(ctx, catalog_name, new_snapshot).await
+    }
+}
@@ -14,15 +14,23 @@
 //
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_datablocks::DataBlock;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metrics::label_counter_with_val;
+use common_planners::find_non_deterministic_exprs;
+use common_planners::find_subquery_exprs;
 use common_planners::Extras;
 use common_planners::PartInfoPtr;
 use common_planners::ReadDataSourcePlan;
+use common_planners::RequireColumnsVisitor;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing_futures::Instrument;
 use futures::StreamExt;
@@ -31,13 +39,24 @@ use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::processors::processor::Event;
 use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::Processor;
+use crate::pipelines::new::processors::ScanRuntimeFilter;
 use crate::pipelines::new::NewPipeline;
 use crate::pipelines::new::SourcePipeBuilder;
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::cache::BroadcastTableCache;
+use crate::storages::fuse::cache::TenantLabel;
+use crate::storages::fuse::corruption::ChecksumVerifier;
+use crate::storages::fuse::io::BlockReadOptions;
 use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::io::HedgedReadPolicy;
 use crate::storages::fuse::operations::read::State::Generated;
+use crate::storages::fuse::FusePartInfo;
 use crate::storages::fuse::FuseTable;
 
+const RUNTIME_FILTER_SKIPPED_BLOCKS: &str = "runtime_filter_skipped_blocks";
+const RUNTIME_FILTER_FILTERED_ROWS: &str = "runtime_filter_filtered_rows";
+
 impl FuseTable {
     #[inline]
     pub fn do_read(
@@ -66,12 +85,44 @@ impl FuseTable {
         Ok(Box::pin(stream))
     }
 
-    fn create_block_reader(
+    pub(crate) fn create_block_reader(
         &self,
         ctx: &Arc<QueryContext>,
         push_downs: &Option<Extras>,
     ) -> Result<Arc<BlockReader>> {
-        let projection = if let Some(Extras {
+        let projection = self.projection(push_downs);
+        let operator = ctx.get_storage_operator()?;
+        let table_schema = self.table_info.schema();
+        let options = self.block_read_options(ctx)?;
+        BlockReader::create_with_options(operator, table_schema, projection, options)
+    }
+
+    fn block_read_options(&self, ctx: &Arc<QueryContext>) -> Result<BlockReadOptions> {
+        let settings = ctx.get_settings();
+        let checksum_verifier = match settings.get_verify_block_checksum()? {
+            0 => None,
+            _ => Some(ChecksumVerifier::create(
+                self.table_info.ident.table_id,
+                ctx.get_corrupt_block_manager(),
+            )),
+        };
+        let hedged_read = match settings.get_enable_hedged_reads()? {
+            0 => None,
+            _ => Some(HedgedReadPolicy::create(
+                ctx.get_read_latency_tracker(),
+                settings.get_hedged_read_percentile()?,
+                Duration::from_millis(settings.get_hedged_read_min_delay_ms()?),
+            )),
+        };
+
+        Ok(BlockReadOptions {
+            checksum_verifier,
+            hedged_read,
+        })
+    }
+
+    fn projection(&self, push_downs: &Option<Extras>) -> Vec<usize> {
+        if let Some(Extras {
             projection: Some(prj),
             ..
         }) = push_downs
@@ -81,11 +132,102 @@ impl FuseTable {
             (0..self.table_info.schema().fields().len())
                 .into_iter()
                 .collect::<Vec<usize>>()
+        }
+    }
+
+    // Split the projection into the columns required by the push down filters and the
+    // others. Returns None if the filters can not be evaluated on the table columns
+    // alone, are non-deterministic, or if there would be nothing left to read lazily.
+    fn create_prewhere(
+        &self,
+        ctx: &Arc<QueryContext>,
+        push_downs: &Option<Extras>,
+    ) -> Result<Option<(Arc<BlockReader>, Arc<Prewhere>)>> {
+        let filters = match push_downs {
+            Some(extras) if !extras.filters.is_empty() => &extras.filters,
+            _ => return Ok(None),
         };
 
-        let operator = ctx.get_storage_operator()?;
+        // The filters are evaluated again above the scan, a non-deterministic filter
+        // (e.g. `rand() < 0.5`) would select the rows twice with different outcomes.
+        if !find_subquery_exprs(filters).is_empty()
+            || !find_non_deterministic_exprs(filters).is_empty()
+        {
+            return Ok(None);
+        }
+
         let table_schema = self.table_info.schema();
-        BlockReader::create(operator, table_schema, projection)
+        let projection = self.projection(push_downs);
+
+        let mut prewhere_projection = vec![];
+        for filter in filters {
+            for column in RequireColumnsVisitor::collect_columns_from_expr(filter)? {
+                match table_schema.index_of(&column) {
+                    Ok(index) if projection.contains(&index) => {
+                        if !prewhere_projection.contains(&index) {
+                            prewhere_projection.push(index);
+                        }
+                    }
+                    _ => return Ok(None),
+                }
+            }
+        }
+
+        let remain_projection = projection
+            .iter()
+            .filter(|index| !prewhere_projection.contains(index))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if prewhere_projection.is_empty() || remain_projection.is_empty() {
+            return Ok(None);
+        }
+
+        let predicate = filters
+            .iter()
+            .skip(1)
+            .fold(filters[0].clone(), |acc, filter| acc.and(filter.clone()));
+
+        let prewhere_schema = DataSchemaRef::new(table_schema.project(prewhere_projection.clone()));
+        let predicate_field = match predicate.to_data_field(&prewhere_schema) {
+            Ok(field) => field,
+            // e.g. the filters reference the columns of other tables in a join.
+            Err(_) => return Ok(None),
+        };
+
+        let executor = ExpressionExecutor::try_create(
+            ctx.clone(),
+            "prewhere expression executor",
+            prewhere_schema,
+            DataSchemaRefExt::create(vec![predicate_field]),
+            vec![predicate],
+            false,
+        )?;
+        executor.validate()?;
+
+        let operator = ctx.get_storage_operator()?;
+        let options = self.block_read_options(ctx)?;
+        let prewhere_reader = BlockReader::create_with_options(
+            operator.clone(),
+            table_schema.clone(),
+            prewhere_projection,
+            options.clone(),
+        )?;
+        let remain_reader = BlockReader::create_with_options(
+            operator,
+            table_schema.clone(),
+            remain_projection,
+            options,
+        )?;
+
+        Ok(Some((
+            prewhere_reader,
+            Arc::new(Prewhere {
+                remain_reader,
+                executor,
+                output_schema: DataSchemaRef::new(table_schema.project(projection)),
+            }),
+        )))
     }
 
     #[inline]
@@ -95,7 +237,30 @@ impl FuseTable {
         plan: &ReadDataSourcePlan,
         pipeline: &mut NewPipeline,
     ) -> Result<()> {
-        let block_reader = self.create_block_reader(&ctx, &plan.push_downs)?;
+        let broadcast = match self.is_broadcast() {
+            false => None,
+            true => Some(Arc::new(BroadcastRead {
+                table_id: self.table_info.ident.table_id,
+                cache: ctx.get_storage_cache_manager().get_broadcast_table_cache(),
+                output_schema: DataSchemaRef::new(
+                    self.table_info
+                        .schema()
+                        .project(self.projection(&plan.push_downs)),
+                ),
+            })),
+        };
+
+        let (block_reader, prewhere) = match broadcast {
+            // The blocks of a broadcast table are read and cached with all the columns,
+            // the projection is applied to the cached blocks.
+            Some(_) => (self.create_block_reader(&ctx, &None)?, None),
+            // Lazy materialization: read the columns required by the filters first, and only
+            // fetch the other projected columns for the blocks that have rows left.
+            None => match self.create_prewhere(&ctx, &plan.push_downs)? {
+                Some((prewhere_reader, prewhere)) => (prewhere_reader, Some(prewhere)),
+                None => (self.create_block_reader(&ctx, &plan.push_downs)?, None),
+            },
+        };
 
         let parts_len = plan.parts.len();
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -107,7 +272,13 @@ impl FuseTable {
             let output = OutputPort::create();
             source_builder.add_source(
                 output.clone(),
-                FuseTableSource::create(ctx.clone(), output, block_reader.clone())?,
+                FuseTableSource::create(
+                    ctx.clone(),
+                    output,
+                    block_reader.clone(),
+                    prewhere.clone(),
+                    broadcast.clone(),
+                )?,
             );
         }
 
@@ -116,9 +287,44 @@ impl FuseTable {
     }
 }
 
+struct Prewhere {
+    remain_reader: Arc<BlockReader>,
+    executor: ExpressionExecutor,
+    output_schema: DataSchemaRef,
+}
+
+impl Prewhere {
+    fn filter(&self, data_block: &DataBlock) -> Result<ColumnRef> {
+        let filter_block = self.executor.execute(data_block)?;
+        Ok(filter_block.column(0).clone())
+    }
+
+    fn merge(&self, prewhere_block: DataBlock, remain_block: DataBlock) -> Result<DataBlock> {
+        let mut columns = Vec::with_capacity(self.output_schema.num_fields());
+        for field in self.output_schema.fields() {
+            let column = match prewhere_block.try_column_by_name(field.name()) {
+                Ok(column) => column,
+                Err(_) => remain_block.try_column_by_name(field.name())?,
+            };
+            columns.push(column.clone());
+        }
+
+        Ok(DataBlock::create(self.output_schema.clone(), columns))
+    }
+}
+
+/// Serves the blocks of a broadcast table from the cache of the node, see `OPT_KEY_BROADCAST`.
+struct BroadcastRead {
+    table_id: u64,
+    cache: Arc<BroadcastTableCache>,
+    output_schema: DataSchemaRef,
+}
+
 enum State {
     ReadData(PartInfoPtr),
     Deserialize(PartInfoPtr, Vec<Vec<u8>>),
+    ReadRemainData(PartInfoPtr, DataBlock, ColumnRef),
+    DeserializeRemain(PartInfoPtr, DataBlock, ColumnRef, Vec<Vec<u8>>),
     Generated(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -128,7 +334,14 @@ struct FuseTableSource {
     ctx: Arc<QueryContext>,
     scan_progress: Arc<Progress>,
     block_reader: Arc<BlockReader>,
+    prewhere: Option<Arc<Prewhere>>,
+    broadcast: Option<Arc<BroadcastRead>>,
     output: Arc<OutputPort>,
+    // Sequence number of the partition being read
+    sequence: u64,
+    // The filters built by the hash joins on the probe side of which this scan is
+    runtime_filters: Vec<Arc<ScanRuntimeFilter>>,
+    tenant_label: TenantLabel,
 }
 
 impl FuseTableSource {
@@ -136,25 +349,92 @@ impl FuseTableSource {
         ctx: Arc<QueryContext>,
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
+        prewhere: Option<Arc<Prewhere>>,
+        broadcast: Option<Arc<BroadcastRead>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
-        let mut partitions = ctx.try_get_partitions(1)?;
-        match partitions.is_empty() {
-            true => Ok(ProcessorPtr::create(Box::new(FuseTableSource {
-                ctx,
-                output,
-                block_reader,
-                scan_progress,
-                state: State::Finish,
-            }))),
-            false => Ok(ProcessorPtr::create(Box::new(FuseTableSource {
-                ctx,
-                output,
-                block_reader,
-                scan_progress,
-                state: State::ReadData(partitions.remove(0)),
-            }))),
+        let runtime_filters = ctx.get_runtime_filters();
+        let cache_manager = ctx.get_storage_cache_manager();
+        let tenant_label = TenantLabel {
+            tenant_id: cache_manager.get_tenant_id().to_owned(),
+            cluster_id: cache_manager.get_cluster_id().to_owned(),
+        };
+        let mut source = FuseTableSource {
+            ctx,
+            runtime_filters,
+            tenant_label,
+            output,
+            block_reader,
+            prewhere,
+            broadcast,
+            scan_progress,
+            sequence: 0,
+            state: State::Finish,
+        };
+        if let Some(part) = source.next_partition()? {
+            source.state = State::ReadData(part);
+        }
+        Ok(ProcessorPtr::create(Box::new(source)))
+    }
+
+    fn next_partition(&mut self) -> Result<Option<PartInfoPtr>> {
+        let mut skipped_blocks = 0;
+        let part = loop {
+            match self.ctx.try_get_partition_with_sequence()? {
+                None => break None,
+                Some((sequence, part)) => {
+                    if self.skip_partition(&part)? {
+                        skipped_blocks += 1;
+                        continue;
+                    }
+                    self.sequence = sequence;
+                    break Some(part);
+                }
+            }
+        };
+
+        if skipped_blocks != 0 {
+            self.record_metrics(RUNTIME_FILTER_SKIPPED_BLOCKS, skipped_blocks);
+        }
+        Ok(part)
+    }
+
+    fn skip_partition(&self, part: &PartInfoPtr) -> Result<bool> {
+        if self.runtime_filters.is_empty() {
+            return Ok(false);
+        }
+
+        let columns_range = &FusePartInfo::from_part(part)?.columns_range;
+        Ok(self
+            .runtime_filters
+            .iter()
+            .any(|filter| filter.skip_block(columns_range)))
+    }
+
+    fn apply_runtime_filters(&self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let mut filtered_rows = 0;
+        for filter in &self.runtime_filters {
+            let (block, rows) = filter.filter_block(data_block)?;
+            data_block = block;
+            filtered_rows += rows;
         }
+
+        if filtered_rows != 0 {
+            self.record_metrics(RUNTIME_FILTER_FILTERED_ROWS, filtered_rows as u64);
+        }
+        Ok(data_block)
+    }
+
+    fn record_metrics(&self, name: &'static str, val: u64) {
+        let label = &self.tenant_label;
+        label_counter_with_val(name, val, &label.tenant_id, &label.cluster_id);
+    }
+
+    fn generate(&mut self, data_block: DataBlock) -> Result<()> {
+        let data_block = self.apply_runtime_filters(data_block)?;
+        let data_block = data_block.with_sequence(Some(self.sequence));
+        self.state = State::Generated(self.next_partition()?, data_block);
+        Ok(())
     }
 }
 
@@ -193,7 +473,9 @@ impl Processor for FuseTableSource {
         match self.state {
             State::Finish => Ok(Event::Finished),
             State::ReadData(_) => Ok(Event::Async),
+            State::ReadRemainData(_, _, _) => Ok(Event::Async),
             State::Deserialize(_, _) => Ok(Event::Sync),
+            State::DeserializeRemain(_, _, _, _) => Ok(Event::Sync),
             State::Generated(_, _) => Err(ErrorCode::LogicalError("It's a bug.")),
         }
     }
@@ -201,8 +483,18 @@ impl Processor for FuseTableSource {
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::Deserialize(part, chunks) => {
-                let data_block = self.block_reader.deserialize(part, chunks)?;
-                let mut partitions = self.ctx.try_get_partitions(1)?;
+                let mut data_block = self.block_reader.deserialize(part.clone(), chunks)?;
+                if let Some(broadcast) = &self.broadcast {
+                    let location = &FusePartInfo::from_part(&part)?.location;
+                    let cached = Arc::new(data_block);
+                    broadcast
+                        .cache
+                        .put(broadcast.table_id, location, cached.clone());
+                    data_block = cached
+                        .as_ref()
+                        .clone()
+                        .resort(broadcast.output_schema.clone())?;
+                }
 
                 let progress_values = ProgressValues {
                     rows: data_block.num_rows(),
@@ -210,11 +502,41 @@ impl Processor for FuseTableSource {
                 };
                 self.scan_progress.incr(&progress_values);
 
-                self.state = match partitions.is_empty() {
-                    true => State::Generated(None, data_block),
-                    false => State::Generated(Some(partitions.remove(0)), data_block),
+                match &self.prewhere {
+                    None => self.generate(data_block),
+                    Some(prewhere) => {
+                        let filter = prewhere.filter(&data_block)?;
+                        let data_block = DataBlock::filter_block(&data_block, &filter)?;
+
+                        if data_block.num_rows() != 0 {
+                            self.state = State::ReadRemainData(part, data_block, filter);
+                            return Ok(());
+                        }
+
+                        // No rows left, skip reading the other columns of the block.
+                        self.state = match self.next_partition()? {
+                            None => State::Finish,
+                            Some(part) => State::ReadData(part),
+                        };
+                        Ok(())
+                    }
+                }
+            }
+            State::DeserializeRemain(part, prewhere_block, filter, chunks) => {
+                let prewhere = self
+                    .prewhere
+                    .clone()
+                    .ok_or_else(|| ErrorCode::LogicalError("It's a bug. Prewhere must be set."))?;
+                let remain_block = prewhere.remain_reader.deserialize(part, chunks)?;
+
+                let progress_values = ProgressValues {
+                    rows: 0,
+                    bytes: remain_block.memory_size(),
                 };
-                Ok(())
+                self.scan_progress.incr(&progress_values);
+
+                let remain_block = DataBlock::filter_block(&remain_block, &filter)?;
+                self.generate(prewhere.merge(prewhere_block, remain_block)?)
             }
             _ => Err(ErrorCode::LogicalError("It's a bug.")),
         }
@@ -223,10 +545,38 @@ impl Processor for FuseTableSource {
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
             State::ReadData(part) => {
+                if let Some(broadcast) = self.broadcast.clone() {
+                    let location = &FusePartInfo::from_part(&part)?.location;
+                    if let Some(cached) = broadcast.cache.get(broadcast.table_id, location) {
+                        let data_block = cached
+                            .as_ref()
+                            .clone()
+                            .resort(broadcast.output_schema.clone())?;
+                        let progress_values = ProgressValues {
+                            rows: data_block.num_rows(),
+                            bytes: data_block.memory_size(),
+                        };
+                        self.scan_progress.incr(&progress_values);
+                        return self.generate(data_block);
+                    }
+                }
+
                 let chunks = self.block_reader.read_columns_data(part.clone()).await?;
                 self.state = State::Deserialize(part, chunks);
                 Ok(())
             }
+            State::ReadRemainData(part, prewhere_block, filter) => {
+                let prewhere = self
+                    .prewhere
+                    .clone()
+                    .ok_or_else(|| ErrorCode::LogicalError("It's a bug. Prewhere must be set."))?;
+                let chunks = prewhere
+                    .remain_reader
+                    .read_columns_data(part.clone())
+                    .await?;
+                self.state = State::DeserializeRemain(part, prewhere_block, filter, chunks);
+                Ok(())
+            }
             _ => Err(ErrorCode::LogicalError("It's a bug.")),
         }
     }
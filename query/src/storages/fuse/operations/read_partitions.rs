@@ -44,9 +44,10 @@ impl FuseTable {
                     return Ok(result);
                 }
                 let schema = self.table_info.schema();
-                let block_metas = BlockPruner::new(snapshot.clone())
-                    .apply(ctx.as_ref(), schema, &push_downs)
+                let (block_metas, pruning_statistics) = BlockPruner::new(snapshot.clone())
+                    .apply_with_statistics(ctx.as_ref(), schema, &push_downs)
                     .await?;
+                ctx.add_pruning_statistics(self.table_info.desc.clone(), pruning_statistics);
 
                 let partitions_scanned = block_metas.len();
                 let partitions_total = snapshot.summary.block_count as usize;
@@ -75,9 +76,11 @@ impl FuseTable {
         blocks_metas: &[BlockMeta],
         push_down: Option<Extras>,
     ) -> (Statistics, Partitions) {
+        // The partitions could be pruned by the limit only if all the rows of them are
+        // returned, i.e. there are no filters to apply.
         let limit = push_down
             .as_ref()
-            .filter(|p| p.order_by.is_empty())
+            .filter(|p| p.order_by.is_empty() && p.filters.is_empty())
             .and_then(|p| p.limit)
             .unwrap_or(usize::MAX);
 
@@ -175,11 +178,20 @@ impl FuseTable {
 
     pub(crate) fn all_columns_part(meta: &BlockMeta) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(meta.col_metas.len());
+        let mut columns_range = HashMap::with_capacity(meta.col_metas.len());
 
         for (idx, column_meta) in &meta.col_metas {
+            if let Some(stats) = meta.col_stats.get(idx) {
+                columns_range.insert(*idx as usize, (stats.min.clone(), stats.max.clone()));
+            }
             columns_meta.insert(
                 *idx as usize,
-                ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
+                ColumnMeta::create(
+                    column_meta.offset,
+                    column_meta.len,
+                    column_meta.num_values,
+                    Self::column_checksum(meta, *idx),
+                ),
             );
         }
 
@@ -192,18 +204,28 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression,
+            columns_range,
         )
     }
 
     fn projection_part(meta: &BlockMeta, projections: &[usize]) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projections.len());
+        let mut columns_range = HashMap::with_capacity(projections.len());
 
         for projection in projections {
             let column_meta = &meta.col_metas[&(*projection as u32)];
+            if let Some(stats) = meta.col_stats.get(&(*projection as u32)) {
+                columns_range.insert(*projection, (stats.min.clone(), stats.max.clone()));
+            }
 
             columns_meta.insert(
                 *projection,
-                ColumnMeta::create(column_meta.offset, column_meta.len, column_meta.num_values),
+                ColumnMeta::create(
+                    column_meta.offset,
+                    column_meta.len,
+                    column_meta.num_values,
+                    Self::column_checksum(meta, *projection as u32),
+                ),
             );
         }
 
@@ -216,9 +238,16 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression,
+            columns_range,
         )
     }
 
+    fn column_checksum(meta: &BlockMeta, column_id: u32) -> Option<u64> {
+        meta.checksums
+            .as_ref()
+            .and_then(|checksums| checksums.get(&column_id).cloned())
+    }
+
     fn check_quick_path(
         &self,
         snapshot: &TableSnapshot,
@@ -0,0 +1,154 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+
+use crate::pipelines::transforms::AddOnStream;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::operations::BlockPosition;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// Rewrites the blocks whose cluster key ranges overlap, so that the rows of
+    /// the table are ordered by the cluster key again.
+    ///
+    /// Blocks that are written before the current cluster key is defined (or
+    /// without cluster statistics at all) are always rewritten. Like `COMPACT`,
+    /// the selected blocks are merged in memory before being sorted.
+    pub async fn do_recluster(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        let cluster_key_id = match &self.cluster_key_meta {
+            Some((id, _)) if !self.cluster_keys.is_empty() => *id,
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "table {} has no cluster key",
+                    self.table_info.name
+                )))
+            }
+        };
+
+        let prev = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let mut prev_segments = Vec::with_capacity(prev.segments.len());
+        for (seg_loc, ver) in &prev.segments {
+            prev_segments.push(reader.read(seg_loc, None, *ver).await?);
+        }
+
+        let selected = Self::overlapping_blocks(
+            cluster_key_id,
+            prev_segments.iter().map(|s| s.blocks.as_slice()),
+        );
+        if selected.is_empty() {
+            return Ok(());
+        }
+
+        // sort the selected rows by the cluster key, and write them as new segments
+        let block_reader = self.create_block_reader(&ctx, &None)?;
+        let mut blocks = Vec::with_capacity(selected.len());
+        for (seg_idx, block_idx) in &selected {
+            let meta = &prev_segments[*seg_idx].blocks[*block_idx];
+            blocks.push(block_reader.read(Self::all_columns_part(meta)).await?);
+        }
+        let merged = DataBlock::concat_blocks(&blocks)?;
+        drop(blocks);
+
+        let schema = self.table_info.schema();
+        let stream: SendableDataBlockStream =
+            Box::pin(DataBlockStream::create(schema.clone(), None, vec![merged]));
+        let stream = Box::pin(AddOnStream::try_create(
+            stream,
+            self.cluster_keys.clone(),
+            schema.clone(),
+            schema,
+            ctx.clone(),
+        )?);
+        let log_entries = self
+            .append_chunks(ctx.clone(), stream)
+            .await?
+            .try_collect::<Vec<AppendOperationLogEntry>>()
+            .await?;
+
+        self.commit_rewritten_blocks(
+            ctx.as_ref(),
+            catalog_name,
+            &prev,
+            &prev_segments,
+            &selected,
+            log_entries,
+        )
+        .await
+    }
+
+    /// Picks the blocks to be reclustered: blocks without statistics of the current
+    /// cluster key, and blocks whose cluster key range overlaps with another block.
+    ///
+    /// Ranges that merely touch each other at their boundaries are not overlapping.
+    fn overlapping_blocks<'a>(
+        cluster_key_id: u32,
+        segments: impl Iterator<Item = &'a [BlockMeta]>,
+    ) -> HashSet<BlockPosition> {
+        let mut selected = HashSet::new();
+        let mut ranges = Vec::new();
+        for (seg_idx, blocks) in segments.enumerate() {
+            for (block_idx, block) in blocks.iter().enumerate() {
+                match &block.cluster_stats {
+                    Some(stats) if stats.cluster_key_id == cluster_key_id => {
+                        ranges.push((&stats.min, &stats.max, (seg_idx, block_idx)))
+                    }
+                    _ => {
+                        selected.insert((seg_idx, block_idx));
+                    }
+                }
+            }
+        }
+
+        ranges.sort_by(|a, b| a.0.cmp(b.0));
+        let mut group: Vec<BlockPosition> = vec![];
+        let mut group_max = None;
+        for (min, max, pos) in ranges {
+            match group_max {
+                Some(current) if min < current => {
+                    group.push(pos);
+                    if max > current {
+                        group_max = Some(max);
+                    }
+                }
+                _ => {
+                    if group.len() > 1 {
+                        selected.extend(group.drain(..));
+                    }
+                    group = vec![pos];
+                    group_max = Some(max);
+                }
+            }
+        }
+        if group.len() > 1 {
+            selected.extend(group);
+        }
+        selected
+    }
+}
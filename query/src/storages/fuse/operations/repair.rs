@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_REPLICATE_TO;
+use crate::storages::fuse::corruption::column_checksums;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::FuseTable;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlockRepairState {
+    /// Replaced by the copy in the replication target.
+    Repaired,
+    /// The table has no replication target.
+    NoBackup,
+    /// The block is not in the replication target yet.
+    MissingInBackup,
+    /// The copy in the replication target is corrupted as well.
+    CorruptInBackup,
+    /// The block is not referenced by the latest snapshot, it could not be verified.
+    NotReferenced,
+}
+
+impl fmt::Display for BlockRepairState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlockRepairState::Repaired => write!(f, "Repaired"),
+            BlockRepairState::NoBackup => write!(f, "NoBackup"),
+            BlockRepairState::MissingInBackup => write!(f, "MissingInBackup"),
+            BlockRepairState::CorruptInBackup => write!(f, "CorruptInBackup"),
+            BlockRepairState::NotReferenced => write!(f, "NotReferenced"),
+        }
+    }
+}
+
+impl FuseTable {
+    /// Replaces the corrupt blocks of the table found on this node by their copies in the
+    /// replication target, the copies are verified against the checksums before written back.
+    pub async fn do_repair(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Vec<(String, BlockRepairState)>> {
+        let manager = ctx.get_corrupt_block_manager();
+        let mut corrupt_blocks = manager.get_table_blocks(self.table_info.ident.table_id);
+        corrupt_blocks.sort_by(|a, b| a.location.cmp(&b.location));
+        if corrupt_blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let target = match self.table_info.options().get(OPT_KEY_REPLICATE_TO) {
+            None => {
+                return Ok(corrupt_blocks
+                    .into_iter()
+                    .map(|block| (block.location, BlockRepairState::NoBackup))
+                    .collect());
+            }
+            Some(target) => Self::replication_target(ctx, target).await?,
+        };
+
+        let metas = self.referenced_block_metas(ctx).await?;
+        let operator = ctx.get_storage_operator()?;
+        let mut result = Vec::with_capacity(corrupt_blocks.len());
+        for block in corrupt_blocks {
+            let location = block.location;
+            let state = match metas.get(&location) {
+                None => BlockRepairState::NotReferenced,
+                Some(meta) => match target.object(&location).read().await {
+                    Err(cause) if cause.kind() == ErrorKind::NotFound => {
+                        BlockRepairState::MissingInBackup
+                    }
+                    Err(cause) => return Err(cause.into()),
+                    Ok(data) if !Self::verify_block_data(meta, &data)? => {
+                        BlockRepairState::CorruptInBackup
+                    }
+                    Ok(data) => {
+                        operator.object(&location).write(data).await?;
+                        manager.release(&location);
+                        BlockRepairState::Repaired
+                    }
+                },
+            };
+
+            tracing::info!(
+                "repair block {} of table {}: {}",
+                location,
+                self.table_info.ident,
+                state
+            );
+            result.push((location, state));
+        }
+
+        Ok(result)
+    }
+
+    async fn referenced_block_metas(
+        &self,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<HashMap<String, BlockMeta>> {
+        let mut metas = HashMap::new();
+        if let Some(snapshot) = self.read_table_snapshot(ctx.as_ref()).await? {
+            let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+            for (location, ver) in &snapshot.segments {
+                let segment = reader.read(location, None, *ver).await?;
+                for block in &segment.blocks {
+                    metas.insert(block.location.0.clone(), block.clone());
+                }
+            }
+        }
+        Ok(metas)
+    }
+
+    fn verify_block_data(meta: &BlockMeta, data: &[u8]) -> Result<bool> {
+        if data.len() as u64 != meta.file_size {
+            return Ok(false);
+        }
+
+        match &meta.checksums {
+            None => Ok(false),
+            Some(checksums) => Ok(&column_checksums(data, &meta.col_metas)? == checksums),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::StageType;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use opendal::Operator;
+
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_REPLICATE_TO;
+use crate::sql::OPT_KEY_REPLICA_OF;
+use crate::sql::OPT_KEY_SNAPSHOT_LOCATION;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::io::TableMetaLocationGenerator;
+use crate::storages::fuse::replication::ReplicationCheckpoint;
+use crate::storages::fuse::FuseTable;
+use crate::storages::stage::StageSource;
+
+impl FuseTable {
+    /// Replicates the table to its `replicate_to` stage in the background, if there is one.
+    pub(crate) fn schedule_replication(&self, ctx: &Arc<QueryContext>, catalog_name: &str) {
+        let target = match self.table_info.options().get(OPT_KEY_REPLICATE_TO) {
+            None => return,
+            Some(target) => target.clone(),
+        };
+
+        let table_id = self.table_info.ident.table_id;
+        let table_name = self.table_info.name.clone();
+        let manager = ctx.get_replication_manager();
+        manager.schedule(table_id, &target);
+
+        let ctx = ctx.clone();
+        let catalog_name = catalog_name.to_string();
+        let task = async move {
+            let lock = manager.table_lock(table_id);
+            let _guard = lock.lock().await;
+
+            let covered = manager.start(table_id);
+            let checkpoint = manager.get_status(table_id).and_then(|s| s.checkpoint);
+            let res = async {
+                // always replicates the latest version of the table.
+                let catalog = ctx.get_catalog(&catalog_name)?;
+                let (ident, meta) = catalog.get_table_meta_by_id(table_id).await?;
+                let table_info = TableInfo {
+                    ident,
+                    desc: "".to_owned(),
+                    name: table_name,
+                    meta: meta.as_ref().clone(),
+                };
+                let latest = catalog.get_table_by_info(&table_info)?;
+                let tbl = FuseTable::try_from_table(latest.as_ref())?;
+
+                let target_operator = Self::replication_target(&ctx, &target).await?;
+                tbl.do_replicate(ctx.as_ref(), &target_operator, checkpoint.as_ref())
+                    .await
+            }
+            .await;
+
+            match res {
+                Ok(checkpoint) => manager.finish(table_id, covered, checkpoint),
+                Err(e) => {
+                    tracing::warn!("replication of table {} failed: {}", table_id, e);
+                    manager.fail(table_id, e.to_string())
+                }
+            }
+        };
+
+        if let Err(e) = ctx.get_storage_runtime().try_spawn(task) {
+            tracing::warn!("failed to spawn replication task: {}", e);
+        }
+    }
+
+    /// The operator of the `replicate_to` stage.
+    pub(crate) async fn replication_target(
+        ctx: &Arc<QueryContext>,
+        target: &str,
+    ) -> Result<Operator> {
+        let stage = ctx
+            .get_user_manager()
+            .get_stage(&ctx.get_tenant(), target)
+            .await?;
+        if stage.stage_type == StageType::Internal {
+            return Err(ErrorCode::BadOption(format!(
+                "replication target {} must be an external stage",
+                target
+            )));
+        }
+        StageSource::get_op(ctx, &stage).await
+    }
+
+    /// Copies the snapshots committed after `checkpoint` to `target`.
+    ///
+    /// For each snapshot, the blocks are copied before the segments, and the segments before
+    /// the snapshot; objects already in the target are skipped. At last, the checkpoint in
+    /// the target is advanced to the latest snapshot.
+    pub async fn do_replicate(
+        &self,
+        ctx: &QueryContext,
+        target: &Operator,
+        checkpoint: Option<&ReplicationCheckpoint>,
+    ) -> Result<Option<ReplicationCheckpoint>> {
+        let snapshot_location = match self.snapshot_loc() {
+            None => return Ok(checkpoint.cloned()),
+            Some(loc) => loc,
+        };
+
+        if let Some(checkpoint) = checkpoint {
+            if checkpoint.snapshot_location == snapshot_location {
+                return Ok(Some(checkpoint.clone()));
+            }
+        }
+
+        let source = ctx.get_storage_operator()?;
+        let location_gen = self.meta_location_generator();
+        let snapshot_reader = MetaReaders::table_snapshot_reader(ctx);
+        let segment_reader = MetaReaders::segment_info_reader(ctx);
+
+        // snapshots are order by timestamp DESC.
+        let mut history = snapshot_reader.snapshot_history(
+            snapshot_location.clone(),
+            self.snapshot_format_version(),
+            location_gen.clone(),
+        );
+
+        let mut snapshots = vec![];
+        while let Some(snapshot) = history.try_next().await? {
+            if checkpoint.map(|c| c.snapshot_id) == Some(snapshot.snapshot_id) {
+                break;
+            }
+            snapshots.push(snapshot);
+        }
+
+        let mut copied_bytes = 0;
+        for snapshot in snapshots.iter().rev() {
+            for (segment_location, ver) in &snapshot.segments {
+                if target.object(segment_location).is_exist().await? {
+                    continue;
+                }
+
+                let segment = segment_reader.read(segment_location, None, *ver).await?;
+                for block in &segment.blocks {
+                    copied_bytes += Self::copy_object(&source, target, &block.location.0).await?;
+                }
+                copied_bytes += Self::copy_object(&source, target, segment_location).await?;
+            }
+
+            let location = location_gen
+                .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version())?;
+            copied_bytes += Self::copy_object(&source, target, &location).await?;
+        }
+
+        let latest = match snapshots.first() {
+            None => return Ok(checkpoint.cloned()),
+            Some(latest) => latest,
+        };
+
+        let new_checkpoint = ReplicationCheckpoint {
+            snapshot_id: latest.snapshot_id,
+            snapshot_location,
+            snapshot_timestamp: latest.timestamp,
+            replicated_at: Utc::now(),
+        };
+        let bytes = serde_json::to_vec(&new_checkpoint)?;
+        target
+            .object(&location_gen.replication_checkpoint_location())
+            .write(bytes)
+            .await?;
+
+        tracing::info!(
+            "replicated {} snapshots ({} bytes) of table {}, checkpoint {}",
+            snapshots.len(),
+            copied_bytes,
+            self.table_info.ident,
+            new_checkpoint.snapshot_id
+        );
+        Ok(Some(new_checkpoint))
+    }
+
+    /// Makes a replica writable, by attaching the latest replicated snapshot of the primary.
+    pub async fn do_promote(&self, ctx: Arc<QueryContext>, catalog_name: &str) -> Result<()> {
+        let source_prefix = self
+            .table_info
+            .options()
+            .get(OPT_KEY_REPLICA_OF)
+            .ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "table {} is not a replica, table option {} not found",
+                    self.table_info.name, OPT_KEY_REPLICA_OF
+                ))
+            })?;
+
+        let operator = ctx.get_storage_operator()?;
+        let checkpoint_location = TableMetaLocationGenerator::with_prefix(source_prefix.clone())
+            .replication_checkpoint_location();
+        let bytes = operator.object(&checkpoint_location).read().await?;
+        let checkpoint: ReplicationCheckpoint = serde_json::from_slice(&bytes)?;
+
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let ver = TableMetaLocationGenerator::snapshot_version(&checkpoint.snapshot_location);
+        let snapshot = reader
+            .read(checkpoint.snapshot_location.as_str(), None, ver)
+            .await?;
+
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta.options.remove(OPT_KEY_REPLICA_OF);
+        new_table_meta.options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+            checkpoint.snapshot_location.clone(),
+        );
+        new_table_meta.schema = Arc::new(snapshot.schema.clone());
+        let summary = &snapshot.summary;
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: summary.row_count,
+            data_bytes: summary.uncompressed_byte_size,
+            compressed_data_bytes: summary.compressed_byte_size,
+            index_data_bytes: 0, // we do not have it yet
+        };
+
+        let table_id = self.table_info.ident.table_id;
+        let table_version = self.table_info.ident.seq;
+        ctx.get_catalog(catalog_name)?
+            .update_table_meta(UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn copy_object(source: &Operator, target: &Operator, path: &str) -> Result<u64> {
+        let object = target.object(path);
+        if object.is_exist().await? {
+            return Ok(0);
+        }
+
+        let bytes = source.object(path).read().await?;
+        let len = bytes.len() as u64;
+        object.write(bytes).await?;
+        Ok(len)
+    }
+}
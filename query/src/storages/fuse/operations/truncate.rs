@@ -66,13 +66,20 @@ impl FuseTable {
 
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
-            ctx.get_catalog(&plan.catalog)?
-                .update_table_meta(UpdateTableMetaReq {
-                    table_id,
-                    seq: MatchSeq::Exact(table_version),
-                    new_table_meta,
-                })
-                .await?;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta,
+            };
+            Self::update_table_meta(ctx.as_ref(), &plan.catalog, req).await?;
+
+            if self.is_broadcast() {
+                ctx.get_storage_cache_manager()
+                    .get_broadcast_table_cache()
+                    .invalidate(table_id);
+            }
+
+            self.schedule_replication(&ctx, &plan.catalog);
         }
 
         Ok(())
@@ -0,0 +1,323 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::default_column_cast;
+use common_functions::scalars::FunctionContext;
+use common_planners::Expression;
+use common_planners::PartitionRef;
+
+use crate::common::ExpressionEvaluator;
+use crate::sql::PlanParser;
+use crate::sql::OPT_KEY_PARTITIONS;
+use crate::sql::OPT_KEY_PARTITION_BY;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::index::ColumnStatistics;
+
+/// The RANGE or LIST partitions of a table, as they are defined in `CREATE TABLE`.
+///
+/// The bounds are kept as SQL literals, and cast to the type of the partition column
+/// when the table is written.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum PartitionDefs {
+    /// `PARTITION name VALUES LESS THAN (bound)`, the bound is None for `MAXVALUE`.
+    Range(Vec<(String, Option<String>)>),
+    /// `PARTITION name VALUES IN (value, ...)`
+    List(Vec<(String, Vec<String>)>),
+}
+
+enum Partitions {
+    /// One partition per distinct value of the partition column.
+    Values,
+    /// The exclusive upper bounds in ascending order, None for `MAXVALUE`. NULL is
+    /// greater than any value, as `DataValue` is ordered, so it is of the `MAXVALUE`
+    /// partition.
+    Range(Vec<Option<DataValue>>),
+    List(Vec<Vec<DataValue>>),
+}
+
+/// How the rows of a partitioned fuse table are assigned to the partitions.
+///
+/// Each block holds rows of exactly one partition, which is told by the min and max
+/// of the partition column in the block statistics.
+pub struct TablePartitioning {
+    column: String,
+    column_index: usize,
+    data_type: DataTypeImpl,
+    names: Vec<String>,
+    partitions: Partitions,
+}
+
+impl TablePartitioning {
+    /// Returns None if the table is not partitioned.
+    pub fn try_create(
+        options: &BTreeMap<String, String>,
+        schema: &DataSchemaRef,
+    ) -> Result<Option<TablePartitioning>> {
+        let column = match options.get(OPT_KEY_PARTITION_BY) {
+            Some(column) => column.clone(),
+            None => return Ok(None),
+        };
+        let column_index = schema.index_of(&column)?;
+        let data_type = remove_nullable(schema.field(column_index).data_type());
+
+        let defs = match options.get(OPT_KEY_PARTITIONS) {
+            Some(defs) => Some(serde_json::from_str::<PartitionDefs>(defs)?),
+            None => None,
+        };
+        let (names, partitions) = match defs {
+            None => (vec![], Partitions::Values),
+            Some(PartitionDefs::Range(defs)) => {
+                let mut bounds = Vec::with_capacity(defs.len());
+                for (name, bound) in defs.iter() {
+                    let bound = match bound {
+                        Some(bound) => Some(Self::literal_value(bound, &data_type)?),
+                        None => None,
+                    };
+                    if let (Some(Some(prev)), Some(bound)) = (bounds.last(), &bound) {
+                        if Self::compare(prev, bound) != Ordering::Less {
+                            return Err(ErrorCode::BadOption(format!(
+                                "VALUES LESS THAN of partition {} must be strictly increasing",
+                                name
+                            )));
+                        }
+                    }
+                    if matches!(bounds.last(), Some(None)) {
+                        return Err(ErrorCode::BadOption(
+                            "MAXVALUE can only be used in the last partition",
+                        ));
+                    }
+                    bounds.push(bound);
+                }
+                let names = defs.into_iter().map(|(name, _)| name).collect();
+                (names, Partitions::Range(bounds))
+            }
+            Some(PartitionDefs::List(defs)) => {
+                let mut lists: Vec<Vec<DataValue>> = Vec::with_capacity(defs.len());
+                for (name, values) in defs.iter() {
+                    let mut list = Vec::with_capacity(values.len());
+                    for value in values {
+                        let value = Self::literal_value(value, &data_type)?;
+                        let mut existing = lists.iter().flatten().chain(list.iter());
+                        if existing.any(|v| Self::compare(v, &value) == Ordering::Equal) {
+                            return Err(ErrorCode::BadOption(format!(
+                                "value {} of partition {} is in multiple partitions",
+                                value, name
+                            )));
+                        }
+                        list.push(value);
+                    }
+                    lists.push(list);
+                }
+                let names = defs.into_iter().map(|(name, _)| name).collect();
+                (names, Partitions::List(lists))
+            }
+        };
+
+        let mut unique = names.iter().collect::<Vec<_>>();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != names.len() {
+            return Err(ErrorCode::BadOption("duplicated partition names"));
+        }
+
+        Ok(Some(TablePartitioning {
+            column,
+            column_index,
+            data_type,
+            names,
+            partitions,
+        }))
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Splits the block into one block per partition.
+    ///
+    /// If the table has no RANGE or LIST partitions, the number of the partitions a block
+    /// spans is limited by `max_partitions`, 0 means no limit, so that a column with too many
+    /// distinct values doesn't turn every row into a block.
+    pub fn split_block(&self, block: &DataBlock, max_partitions: usize) -> Result<Vec<DataBlock>> {
+        let column = block.try_column_by_name(&self.column)?;
+        match &self.partitions {
+            Partitions::Values => {
+                let blocks = DataBlock::group_by_blocks(block, &[self.column.clone()])?;
+                if max_partitions != 0 && blocks.len() > max_partitions {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "an inserted block spans {} partitions of column {}, more than max_partitions_per_insert: {}",
+                        blocks.len(),
+                        self.column,
+                        max_partitions
+                    )));
+                }
+                Ok(blocks)
+            }
+            _ => {
+                let mut indices = Vec::with_capacity(column.len());
+                for row in 0..column.len() {
+                    let value = column.get(row);
+                    match self.partition_of(&value) {
+                        Some(index) => indices.push(index),
+                        None => {
+                            return Err(ErrorCode::BadDataValueType(format!(
+                                "no partition of column {} for value {}",
+                                self.column, value
+                            )));
+                        }
+                    }
+                }
+                let blocks = DataBlock::scatter_block(block, &indices, self.names.len())?;
+                Ok(blocks.into_iter().filter(|b| b.num_rows() > 0).collect())
+            }
+        }
+    }
+
+    /// Resolves the partitions to drop into the values of the partition column, or the
+    /// indexes of the RANGE or LIST partitions.
+    pub fn resolve(&self, partitions: &[PartitionRef]) -> Result<Vec<PartitionTarget>> {
+        let mut targets = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let target = match (partition, &self.partitions) {
+                (PartitionRef::Name(name), Partitions::Values) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "partition {} is not defined, the partitions of column {} are dropped by value",
+                        name, self.column
+                    )));
+                }
+                (PartitionRef::Name(name), _) => {
+                    match self.names.iter().position(|n| n.eq_ignore_ascii_case(name)) {
+                        Some(index) => PartitionTarget::Index(index),
+                        None => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "unknown partition {}",
+                                name
+                            )));
+                        }
+                    }
+                }
+                (PartitionRef::Value(value), Partitions::Values) => {
+                    PartitionTarget::Value(self.cast_value(value)?)
+                }
+                (PartitionRef::Value(value), _) => {
+                    let value = self.cast_value(value)?;
+                    match self.partition_of(&value) {
+                        Some(index) => PartitionTarget::Index(index),
+                        None => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "no partition of column {} for value {}",
+                                self.column, value
+                            )));
+                        }
+                    }
+                }
+            };
+            targets.push(target);
+        }
+        Ok(targets)
+    }
+
+    /// Whether the block holds the rows of one of the partitions.
+    pub fn is_block_of(&self, block: &BlockMeta, targets: &[PartitionTarget]) -> bool {
+        let (min, max) = match block.col_stats.get(&(self.column_index as ColumnId)) {
+            Some(ColumnStatistics { min, max, .. }) => (min, max),
+            None => return false,
+        };
+        targets.iter().any(|target| match target {
+            PartitionTarget::Value(value) => {
+                Self::compare(min, value) == Ordering::Equal
+                    && Self::compare(max, value) == Ordering::Equal
+            }
+            PartitionTarget::Index(index) => {
+                self.partition_of(min) == Some(*index) && self.partition_of(max) == Some(*index)
+            }
+        })
+    }
+
+    fn partition_of(&self, value: &DataValue) -> Option<usize> {
+        match &self.partitions {
+            Partitions::Values => None,
+            Partitions::Range(bounds) => bounds.iter().position(|bound| match bound {
+                Some(bound) => Self::compare(value, bound) == Ordering::Less,
+                None => true,
+            }),
+            Partitions::List(lists) => lists.iter().position(|list| {
+                list.iter()
+                    .any(|v| Self::compare(value, v) == Ordering::Equal)
+            }),
+        }
+    }
+
+    // The values of the column and the bounds are of the same type after the cast, but a
+    // NULL or an incomparable value (e.g. the stats of a block written before the column
+    // type changed) must not panic.
+    fn compare(left: &DataValue, right: &DataValue) -> Ordering {
+        let comparable = left.is_null()
+            || right.is_null()
+            || left.value_type() == right.value_type()
+            || (left.is_numeric() && right.is_numeric());
+        match comparable {
+            true => left.cmp(right),
+            false => Ordering::Greater,
+        }
+    }
+
+    fn cast_value(&self, value: &DataValue) -> Result<DataValue> {
+        if value.is_null() {
+            return Ok(DataValue::Null);
+        }
+        let column = value.as_const_column(&value.data_type(), 1)?;
+        let column = default_column_cast(&column, &self.data_type)?;
+        Ok(column.get(0))
+    }
+
+    fn literal_value(literal: &str, data_type: &DataTypeImpl) -> Result<DataValue> {
+        let expr = PlanParser::parse_expr(literal)?;
+        if let Expression::Literal {
+            value: DataValue::Null,
+            ..
+        } = expr
+        {
+            return Ok(DataValue::Null);
+        }
+
+        // A block of one row to evaluate the constant expression, e.g. `-1`.
+        let schema = DataSchemaRefExt::create(vec![DataField::new("_row", u8::to_data_type())]);
+        let row = DataBlock::create(schema, vec![Series::from_data(vec![0u8])]);
+        let column = ExpressionEvaluator::eval(&FunctionContext::default(), &expr, &row)?;
+        let column = default_column_cast(&column, data_type).map_err(|cause| {
+            ErrorCode::BadOption(format!(
+                "partition value {} is not of type {}: {}",
+                literal,
+                data_type.name(),
+                cause.message()
+            ))
+        })?;
+        Ok(column.get(0))
+    }
+}
+
+/// A partition to drop, resolved by [`TablePartitioning::resolve`].
+pub enum PartitionTarget {
+    Value(DataValue),
+    Index(usize),
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -36,6 +37,29 @@ pub struct BlockPruner {
     table_snapshot: Arc<TableSnapshot>,
 }
 
+/// The pruning decisions of a table scan, shown by `EXPLAIN (PRUNING)`.
+///
+/// The zone map is the min/max statistics of the columns, kept for each segment and block.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PruningStatistics {
+    pub segments_total: u64,
+    pub segments_pruned_by_zone_map: u64,
+    /// Segments not read since enough rows are collected for the limit.
+    pub segments_pruned_by_limit: u64,
+    pub blocks_total: u64,
+    /// Blocks pruned by the zone map of the block or of the segment they belong to.
+    pub blocks_pruned_by_zone_map: u64,
+    pub blocks_pruned_by_limit: u64,
+    pub blocks_scanned: u64,
+}
+
+#[derive(Default)]
+struct PruningCounters {
+    segments_pruned_by_zone_map: AtomicU64,
+    segments_pruned_by_limit: AtomicU64,
+    blocks_pruned_by_zone_map: AtomicU64,
+}
+
 type Pred = Box<dyn Fn(&ColumnsStatistics) -> Result<bool> + Send + Sync + Unpin>;
 impl BlockPruner {
     pub fn new(table_snapshot: Arc<TableSnapshot>) -> Self {
@@ -49,6 +73,17 @@ impl BlockPruner {
         schema: DataSchemaRef,
         push_down: &Option<Extras>,
     ) -> Result<Vec<BlockMeta>> {
+        let (block_metas, _) = self.apply_with_statistics(ctx, schema, push_down).await?;
+        Ok(block_metas)
+    }
+
+    /// Same as [BlockPruner::apply], also returns how the segments and blocks are pruned.
+    pub async fn apply_with_statistics(
+        &self,
+        ctx: &QueryContext,
+        schema: DataSchemaRef,
+        push_down: &Option<Extras>,
+    ) -> Result<(Vec<BlockMeta>, PruningStatistics)> {
         let block_pred: Pred = match push_down {
             Some(exprs) if !exprs.filters.is_empty() => {
                 // for the time being, we only handle the first expr
@@ -63,7 +98,7 @@ impl BlockPruner {
         let segment_num = segment_locs.len();
 
         if segment_locs.is_empty() {
-            return Ok(vec![]);
+            return Ok((vec![], PruningStatistics::default()));
         };
 
         let limit = push_down
@@ -77,6 +112,7 @@ impl BlockPruner {
         // "accuracy". In [FuseTable::do_read_partitions], the "limit" will be treated precisely.
 
         let accumulated_rows = AtomicUsize::new(0);
+        let counters = PruningCounters::default();
 
         // A !Copy Wrapper of u64
         struct NonCopy(u64);
@@ -97,8 +133,12 @@ impl BlockPruner {
                         &block_pred,
                         &accumulated_rows,
                         limit,
+                        &counters,
                     )
                 } else {
+                    counters
+                        .segments_pruned_by_limit
+                        .fetch_add(1, Ordering::Relaxed);
                     Ok(vec![])
                 }
             })
@@ -108,8 +148,23 @@ impl BlockPruner {
             .await?
             .into_iter()
             .flatten();
-
-        Ok(stream.collect::<Vec<_>>())
+        let block_metas = stream.collect::<Vec<_>>();
+
+        let blocks_total = self.table_snapshot.summary.block_count;
+        let blocks_scanned = block_metas.len() as u64;
+        let blocks_pruned_by_zone_map = counters.blocks_pruned_by_zone_map.into_inner();
+        let statistics = PruningStatistics {
+            segments_total: segment_num as u64,
+            segments_pruned_by_zone_map: counters.segments_pruned_by_zone_map.into_inner(),
+            segments_pruned_by_limit: counters.segments_pruned_by_limit.into_inner(),
+            blocks_total,
+            blocks_pruned_by_zone_map,
+            // Including the blocks of the segments not read.
+            blocks_pruned_by_limit: blocks_total
+                .saturating_sub(blocks_scanned + blocks_pruned_by_zone_map),
+            blocks_scanned,
+        };
+        Ok((block_metas, statistics))
     }
 
     #[inline]
@@ -118,6 +173,7 @@ impl BlockPruner {
         pred: &Pred,
         accumulated_rows: &AtomicUsize,
         limit: usize,
+        counters: &PruningCounters,
     ) -> Result<Vec<BlockMeta>> {
         if pred(&segment_info.summary.col_stats)? {
             let block_num = segment_info.blocks.len();
@@ -128,10 +184,20 @@ impl BlockPruner {
                     if accumulated_rows.fetch_add(num_rows, Ordering::Release) < limit {
                         acc.push(block_meta.clone());
                     }
+                } else {
+                    counters
+                        .blocks_pruned_by_zone_map
+                        .fetch_add(1, Ordering::Relaxed);
                 }
             }
             Ok(acc)
         } else {
+            counters
+                .segments_pruned_by_zone_map
+                .fetch_add(1, Ordering::Relaxed);
+            counters
+                .blocks_pruned_by_zone_map
+                .fetch_add(segment_info.blocks.len() as u64, Ordering::Relaxed);
             Ok(vec![])
         }
     }
@@ -0,0 +1,216 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::tokio::sync::Mutex;
+use common_base::infallible::RwLock;
+use common_metrics::LABEL_KEY_CLUSTER;
+use common_metrics::LABEL_KEY_TENANT;
+use metrics::gauge;
+use uuid::Uuid;
+
+use crate::config::QueryConfig;
+
+const REPLICATION_LAG_COMMITS: &str = "replication_lag_commits";
+const REPLICATION_LAG_SECONDS: &str = "replication_lag_seconds";
+const LABEL_KEY_TABLE_ID: &str = "table_id";
+
+/// The latest snapshot of a table which has been fully copied to the replication target.
+///
+/// It is written to the target after the snapshot, so that the secondary could always
+/// find a complete snapshot by it, see `FuseTable::do_promote`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ReplicationCheckpoint {
+    pub snapshot_id: Uuid,
+    pub snapshot_location: String,
+    pub snapshot_timestamp: Option<DateTime<Utc>>,
+    pub replicated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplicationState {
+    Pending,
+    Running,
+    Idle,
+    Failed,
+}
+
+impl fmt::Display for ReplicationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplicationState::Pending => write!(f, "Pending"),
+            ReplicationState::Running => write!(f, "Running"),
+            ReplicationState::Idle => write!(f, "Idle"),
+            ReplicationState::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplicationStatus {
+    pub table_id: u64,
+    pub target: String,
+    pub state: ReplicationState,
+    /// Number of commits scheduled for replication, since the server started.
+    pub scheduled_commits: u64,
+    /// Number of the scheduled commits which have been replicated.
+    pub replicated_commits: u64,
+    /// Time of the earliest commit which has not been replicated yet.
+    pub pending_since: Option<SystemTime>,
+    pub checkpoint: Option<ReplicationCheckpoint>,
+    pub last_error: Option<String>,
+}
+
+impl ReplicationStatus {
+    pub fn lag_commits(&self) -> u64 {
+        self.scheduled_commits - self.replicated_commits
+    }
+
+    pub fn lag_seconds(&self) -> u64 {
+        self.pending_since
+            .and_then(|t| t.elapsed().ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+/// Book-keeping of the asynchronous table replications of this node.
+///
+/// Replications are kicked off by the commits of tables which have the `replicate_to`
+/// option, a replication of a table copies everything committed since the last checkpoint,
+/// so commits that happen while a replication is running are picked up by the next one.
+pub struct ReplicationManager {
+    tenant_id: String,
+    cluster_id: String,
+    status: RwLock<HashMap<u64, ReplicationStatus>>,
+    // Replications of the same table are serialized.
+    table_locks: RwLock<HashMap<u64, Arc<Mutex<()>>>>,
+}
+
+impl ReplicationManager {
+    pub fn init(config: &QueryConfig) -> ReplicationManager {
+        ReplicationManager {
+            tenant_id: config.tenant_id.clone(),
+            cluster_id: config.cluster_id.clone(),
+            status: Default::default(),
+            table_locks: Default::default(),
+        }
+    }
+
+    pub fn get_status(&self, table_id: u64) -> Option<ReplicationStatus> {
+        self.status.read().get(&table_id).cloned()
+    }
+
+    pub fn get_all_status(&self) -> Vec<ReplicationStatus> {
+        self.status.read().values().cloned().collect()
+    }
+
+    pub fn table_lock(&self, table_id: u64) -> Arc<Mutex<()>> {
+        self.table_locks
+            .write()
+            .entry(table_id)
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    }
+
+    /// Registers a new commit of the table, returns the sequence of the commit.
+    pub fn schedule(&self, table_id: u64, target: &str) -> u64 {
+        let mut status = self.status.write();
+        let entry = status.entry(table_id).or_insert_with(|| ReplicationStatus {
+            table_id,
+            target: target.to_string(),
+            state: ReplicationState::Idle,
+            scheduled_commits: 0,
+            replicated_commits: 0,
+            pending_since: None,
+            checkpoint: None,
+            last_error: None,
+        });
+
+        entry.target = target.to_string();
+        entry.scheduled_commits += 1;
+        if entry.state != ReplicationState::Running {
+            entry.state = ReplicationState::Pending;
+        }
+        if entry.pending_since.is_none() {
+            entry.pending_since = Some(SystemTime::now());
+        }
+        self.report_lag(entry);
+        entry.scheduled_commits
+    }
+
+    /// Marks the replication started, returns the sequence of the latest scheduled commit,
+    /// all the commits up to this one will be covered by the replication.
+    pub fn start(&self, table_id: u64) -> u64 {
+        let mut status = self.status.write();
+        match status.get_mut(&table_id) {
+            None => 0,
+            Some(entry) => {
+                entry.state = ReplicationState::Running;
+                entry.scheduled_commits
+            }
+        }
+    }
+
+    pub fn finish(&self, table_id: u64, covered: u64, checkpoint: Option<ReplicationCheckpoint>) {
+        let mut status = self.status.write();
+        if let Some(entry) = status.get_mut(&table_id) {
+            entry.replicated_commits = std::cmp::max(entry.replicated_commits, covered);
+            if checkpoint.is_some() {
+                entry.checkpoint = checkpoint;
+            }
+            entry.last_error = None;
+            if entry.lag_commits() == 0 {
+                entry.state = ReplicationState::Idle;
+                entry.pending_since = None;
+            } else {
+                entry.state = ReplicationState::Pending;
+            }
+            self.report_lag(entry);
+        }
+    }
+
+    pub fn fail(&self, table_id: u64, error: String) {
+        let mut status = self.status.write();
+        if let Some(entry) = status.get_mut(&table_id) {
+            entry.state = ReplicationState::Failed;
+            entry.last_error = Some(error);
+            self.report_lag(entry);
+        }
+    }
+
+    fn report_lag(&self, status: &ReplicationStatus) {
+        let labels = [
+            (LABEL_KEY_TENANT, self.tenant_id.clone()),
+            (LABEL_KEY_CLUSTER, self.cluster_id.clone()),
+            (LABEL_KEY_TABLE_ID, status.table_id.to_string()),
+        ];
+        gauge!(
+            REPLICATION_LAG_COMMITS,
+            status.lag_commits() as f64,
+            &labels
+        );
+        gauge!(
+            REPLICATION_LAG_SECONDS,
+            status.lag_seconds() as f64,
+            &labels
+        );
+    }
+}
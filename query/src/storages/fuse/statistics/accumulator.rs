@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::eval_aggr;
 
+use crate::storages::fuse::corruption::column_checksums;
 use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::ColumnMeta;
@@ -73,6 +74,7 @@ impl StatisticsAccumulator {
         file_size: u64,
         meta: FileMetaData,
         statistics: BlockStatistics,
+        data: &[u8],
     ) -> Result<()> {
         self.file_size += file_size;
         self.summary_block_count += 1;
@@ -81,6 +83,8 @@ impl StatisticsAccumulator {
         self.blocks_statistics
             .push(statistics.block_column_statistics.clone());
 
+        let col_metas = Self::column_metas(&meta)?;
+        let checksums = column_checksums(data, &col_metas)?;
         self.blocks_metas.push(BlockMeta {
             file_size,
             compression: Compression::Lz4Raw,
@@ -88,8 +92,9 @@ impl StatisticsAccumulator {
             block_size: statistics.block_bytes_size,
             col_stats: statistics.block_column_statistics.clone(),
             location: (statistics.block_file_location, DataBlock::VERSION),
-            col_metas: Self::column_metas(&meta)?,
+            col_metas,
             cluster_stats: statistics.block_cluster_statistics,
+            checksums: Some(checksums),
         });
 
         Ok(())
@@ -199,6 +204,7 @@ impl PartiallyAccumulated {
         file_size: u64,
         location: String,
         col_metas: HashMap<ColumnId, ColumnMeta>,
+        checksums: HashMap<ColumnId, u64>,
     ) -> StatisticsAccumulator {
         let mut stats = &mut self.accumulator;
         stats.file_size += file_size;
@@ -211,6 +217,7 @@ impl PartiallyAccumulated {
             cluster_stats: self.block_cluster_statistics,
             location: (location, DataBlock::VERSION),
             compression: Compression::Lz4Raw,
+            checksums: Some(checksums),
         };
         stats.blocks_metas.push(block_meta);
         self.accumulator
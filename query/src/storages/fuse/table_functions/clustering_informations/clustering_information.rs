@@ -36,12 +36,12 @@ pub struct ClusteringInformation<'a> {
     pub cluster_keys: Vec<Expression>,
 }
 
-struct ClusteringStatistics {
-    total_block_count: u64,
-    total_constant_block_count: u64,
-    average_overlaps: f64,
-    average_depth: f64,
-    block_depth_histogram: VariantValue,
+pub struct ClusteringStatistics {
+    pub total_block_count: u64,
+    pub total_constant_block_count: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub block_depth_histogram: VariantValue,
 }
 
 impl<'a> ClusteringInformation<'a> {
@@ -58,19 +58,7 @@ impl<'a> ClusteringInformation<'a> {
     }
 
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
-        let snapshot = self.table.read_table_snapshot(self.ctx.as_ref()).await?;
-
-        let mut blocks = Vec::new();
-        if let Some(snapshot) = snapshot {
-            let reader = MetaReaders::segment_info_reader(self.ctx.as_ref());
-            for (x, ver) in &snapshot.segments {
-                let res = reader.read(x, None, *ver).await?;
-                let mut block = res.blocks.clone();
-                blocks.append(&mut block);
-            }
-        };
-
-        let info = self.get_clustering_stats(blocks)?;
+        let info = self.get_clustering_stats_of_table().await?;
 
         let names = self
             .cluster_keys
@@ -90,6 +78,23 @@ impl<'a> ClusteringInformation<'a> {
         ]))
     }
 
+    /// Computes the clustering statistics over the blocks of the current snapshot.
+    pub async fn get_clustering_stats_of_table(&self) -> Result<ClusteringStatistics> {
+        let snapshot = self.table.read_table_snapshot(self.ctx.as_ref()).await?;
+
+        let mut blocks = Vec::new();
+        if let Some(snapshot) = snapshot {
+            let reader = MetaReaders::segment_info_reader(self.ctx.as_ref());
+            for (x, ver) in &snapshot.segments {
+                let res = reader.read(x, None, *ver).await?;
+                let mut block = res.blocks.clone();
+                blocks.append(&mut block);
+            }
+        };
+
+        self.get_clustering_stats(blocks)
+    }
+
     fn get_min_max_stats(&self, block: &BlockMeta) -> Result<(Vec<DataValue>, Vec<DataValue>)> {
         if self.table.cluster_keys() != self.cluster_keys || block.cluster_stats.is_none() {
             // Todo(zhyass): support manually specifying the cluster key.
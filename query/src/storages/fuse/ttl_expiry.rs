@@ -0,0 +1,108 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_planners::Optimization;
+use common_planners::OptimizeTablePlan;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sql::OPT_KEY_TTL;
+
+/// Drops the expired rows of the tables with a TTL every `query.table_ttl_interval_secs`,
+/// the same as `OPTIMIZE TABLE ... COMPACT` does.
+///
+/// Only the node with the smallest id of the cluster runs the job, so that the tables are not
+/// rewritten by several nodes at the same time.
+pub struct TtlExpiryJob;
+
+impl TtlExpiryJob {
+    pub fn start(sessions: Arc<SessionManager>) {
+        let interval = sessions.get_conf().query.table_ttl_interval_secs;
+        if interval == 0 {
+            return;
+        }
+
+        let interval = Duration::from_secs(interval);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(cause) = Self::expire_tables(&sessions).await {
+                    tracing::warn!("TTL expiry of the tables failed: {}", cause);
+                }
+            }
+        });
+    }
+
+    async fn expire_tables(sessions: &Arc<SessionManager>) -> Result<()> {
+        let session = sessions
+            .create_session(SessionType::Background("TtlExpiry".to_string()))
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        let cluster = ctx.get_cluster();
+        let nodes = cluster.get_nodes();
+        if let Some(first) = nodes.iter().map(|node| &node.id).min() {
+            if *first != cluster.local_id() {
+                return Ok(());
+            }
+        }
+
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        for database in catalog.list_databases(&tenant).await? {
+            for table in catalog.list_tables(&tenant, database.name()).await? {
+                if !table.get_table_info().options().contains_key(OPT_KEY_TTL) {
+                    continue;
+                }
+
+                let plan = OptimizeTablePlan {
+                    catalog: CATALOG_DEFAULT.to_string(),
+                    database: database.name().to_string(),
+                    table: table.name().to_string(),
+                    operation: Optimization::COMPACT,
+                };
+                // The context caches the tables it reads, every table is rewritten by a new one.
+                let ctx = session.create_query_context().await?;
+                if let Err(cause) = Self::expire_table(ctx, plan).await {
+                    tracing::warn!(
+                        "TTL expiry of table {}.{} failed: {}",
+                        database.name(),
+                        table.name(),
+                        cause
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn expire_table(ctx: Arc<QueryContext>, plan: OptimizeTablePlan) -> Result<()> {
+        let interpreter = OptimizeTableInterpreter::try_create(ctx, plan)?;
+        let mut stream = interpreter.execute(None).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+}
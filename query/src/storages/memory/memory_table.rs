@@ -18,6 +18,7 @@ use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_base::base::ProgressValues;
 use common_base::infallible::Mutex;
 use common_base::infallible::RwLock;
 use common_datablocks::DataBlock;
@@ -221,6 +222,7 @@ impl Table for MemoryTable {
         let settings = ctx.get_settings();
         let mut builder = SourcePipeBuilder::create();
         let read_data_blocks = self.get_read_data_blocks();
+        let num_blocks = read_data_blocks.lock().len();
 
         for _index in 0..settings.get_max_threads()? {
             let output = OutputPort::create();
@@ -230,6 +232,7 @@ impl Table for MemoryTable {
                     ctx.clone(),
                     output,
                     read_data_blocks.clone(),
+                    num_blocks,
                     plan.push_downs.clone(),
                 )?,
             );
@@ -274,6 +277,12 @@ impl Table for MemoryTable {
             .get_metrics()
             .inc_write_bytes(written_bytes);
 
+        let progress_values = ProgressValues {
+            rows: operations.iter().map(|b| b.num_rows()).sum(),
+            bytes: written_bytes,
+        };
+        ctx.get_write_progress().incr(&progress_values);
+
         if overwrite {
             let mut blocks = self.blocks.write();
             blocks.clear();
@@ -299,6 +308,8 @@ impl Table for MemoryTable {
 struct MemoryTableSource {
     extras: Option<Extras>,
     data_blocks: Arc<Mutex<VecDeque<DataBlock>>>,
+    // The number of blocks before any of them is read, to number the blocks in order.
+    num_blocks: usize,
 }
 
 impl MemoryTableSource {
@@ -306,11 +317,13 @@ impl MemoryTableSource {
         ctx: Arc<QueryContext>,
         output: Arc<OutputPort>,
         data_blocks: Arc<Mutex<VecDeque<DataBlock>>>,
+        num_blocks: usize,
         extras: Option<Extras>,
     ) -> Result<ProcessorPtr> {
         SyncSourcer::create(ctx, output, MemoryTableSource {
             extras,
             data_blocks,
+            num_blocks,
         })
     }
 
@@ -337,9 +350,12 @@ impl SyncSource for MemoryTableSource {
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         let mut blocks_guard = self.data_blocks.lock();
+        let sequence = (self.num_blocks - blocks_guard.len()) as u64;
         match blocks_guard.pop_front() {
             None => Ok(None),
-            Some(data_block) => self.projection(data_block),
+            Some(data_block) => Ok(self
+                .projection(data_block)?
+                .map(|block| block.with_sequence(Some(sequence)))),
         }
     }
 }
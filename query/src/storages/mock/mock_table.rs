@@ -0,0 +1,284 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
+use common_planners::PartInfoPtr;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::processor::ProcessorPtr;
+use crate::pipelines::new::processors::EmptySource;
+use crate::pipelines::new::processors::SyncSource;
+use crate::pipelines::new::processors::SyncSourcer;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::pipelines::new::SourcePipeBuilder;
+use crate::sessions::QueryContext;
+use crate::storages::mock::MockPartInfo;
+use crate::storages::Table;
+use crate::storages::TableStatistics;
+
+/// The declarative description of a mock table: the schema, the data of each partition
+/// and the statistics reported to the planner. The tables are registered in the system
+/// database by `Catalog::register_test_table`, e.g. as `system.test_xxx`.
+#[derive(Clone)]
+pub struct MockTableSpec {
+    /// The name of the table, which must start with `test_`.
+    pub name: String,
+    pub schema: DataSchemaRef,
+    /// The blocks of each partition, a partition may have no block.
+    pub partitions: Vec<Vec<DataBlock>>,
+    /// The statistics returned by `read_partitions`.
+    /// If `None`, the exact statistics of all the partitions are returned.
+    pub statistics: Option<Statistics>,
+}
+
+impl MockTableSpec {
+    pub fn create(name: &str, schema: DataSchemaRef) -> Self {
+        MockTableSpec {
+            name: name.to_string(),
+            schema,
+            partitions: vec![],
+            statistics: None,
+        }
+    }
+
+    pub fn with_partition(mut self, blocks: Vec<DataBlock>) -> Self {
+        self.partitions.push(blocks);
+        self
+    }
+
+    pub fn with_statistics(mut self, statistics: Statistics) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+}
+
+/// A table of the test engine, its partitions, statistics and data are the ones of its
+/// `MockTableSpec`, so planner and executor tests don't assemble the plans by hand.
+pub struct MockTable {
+    table_info: TableInfo,
+    spec: MockTableSpec,
+}
+
+impl MockTable {
+    pub fn try_create(table_id: u64, spec: MockTableSpec) -> Result<Arc<dyn Table>> {
+        if !spec.name.starts_with("test_") {
+            return Err(ErrorCode::BadArguments(format!(
+                "The name of the mock table must start with 'test_', but got: {}",
+                spec.name
+            )));
+        }
+
+        for block in spec.partitions.iter().flatten() {
+            if block.schema() != &spec.schema {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The schema of the blocks of the mock table {} mismatches the schema of the table",
+                    spec.name
+                )));
+            }
+        }
+
+        let table_info = TableInfo {
+            desc: format!("'system'.'{}'", spec.name),
+            name: spec.name.clone(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema: spec.schema.clone(),
+                engine: "SystemMock".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(MockTable { table_info, spec }))
+    }
+
+    fn exact_statistics(&self) -> Statistics {
+        let blocks = self.spec.partitions.iter().flatten();
+        let partitions = self.spec.partitions.len();
+        Statistics::new_exact(
+            blocks.clone().map(|block| block.num_rows()).sum(),
+            blocks.map(|block| block.memory_size()).sum(),
+            partitions,
+            partitions,
+        )
+    }
+
+    fn partition_blocks(
+        &self,
+        part: &PartInfoPtr,
+        extras: &Option<Extras>,
+    ) -> Result<Vec<DataBlock>> {
+        let part = MockPartInfo::from_part(part)?;
+        let blocks = self.spec.partitions.get(part.index).ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "The mock table {} has no partition {}",
+                self.spec.name, part.index
+            ))
+        })?;
+
+        blocks
+            .iter()
+            .map(|block| Self::projection(block.clone(), extras))
+            .collect()
+    }
+
+    fn projection(data_block: DataBlock, extras: &Option<Extras>) -> Result<DataBlock> {
+        if let Some(extras) = extras {
+            if let Some(projection) = &extras.projection {
+                let pruned_schema = data_block.schema().project(projection.clone());
+                let raw_columns = data_block.columns();
+                let columns = projection
+                    .iter()
+                    .map(|idx| raw_columns[*idx].clone())
+                    .collect();
+
+                return Ok(DataBlock::create(Arc::new(pruned_schema), columns));
+            }
+        }
+
+        Ok(data_block)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for MockTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn has_exact_total_row_count(&self) -> bool {
+        self.spec
+            .statistics
+            .as_ref()
+            .map_or(true, |statistics| statistics.is_exact)
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let statistics = match &self.spec.statistics {
+            Some(statistics) => statistics.clone(),
+            None => self.exact_statistics(),
+        };
+
+        let parts = (0..self.spec.partitions.len())
+            .map(MockPartInfo::create)
+            .collect();
+        Ok((statistics, parts))
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let mut blocks = vec![];
+        for part in &plan.parts {
+            blocks.extend(self.partition_blocks(part, &plan.push_downs)?);
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            plan.schema(),
+            None,
+            blocks,
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        if plan.parts.is_empty() {
+            let output = OutputPort::create();
+            pipeline.add_pipe(NewPipe::SimplePipe {
+                inputs_port: vec![],
+                outputs_port: vec![output.clone()],
+                processors: vec![EmptySource::create(ctx, output, plan.schema())?],
+            });
+
+            return Ok(());
+        }
+
+        let mut builder = SourcePipeBuilder::create();
+        for part in &plan.parts {
+            let output = OutputPort::create();
+            let blocks = self.partition_blocks(part, &plan.push_downs)?;
+            builder.add_source(
+                output.clone(),
+                MockSource::create(ctx.clone(), output, blocks)?,
+            );
+        }
+
+        pipeline.add_pipe(builder.finalize());
+        Ok(())
+    }
+
+    async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
+        let statistics = self.exact_statistics();
+        Ok(Some(TableStatistics {
+            num_rows: Some(statistics.read_rows as u64),
+            data_size: Some(statistics.read_bytes as u64),
+            data_size_compressed: None,
+            index_length: None,
+        }))
+    }
+}
+
+struct MockSource {
+    blocks: VecDeque<DataBlock>,
+}
+
+impl MockSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        blocks: Vec<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, MockSource {
+            blocks: blocks.into(),
+        })
+    }
+}
+
+impl SyncSource for MockSource {
+    const NAME: &'static str = "MockSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.blocks.pop_front())
+    }
+}
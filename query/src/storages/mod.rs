@@ -13,11 +13,13 @@
 // limitations under the License.
 
 pub mod cache;
+pub mod encryption;
 pub mod fuse;
 pub mod github;
 pub mod index;
 pub mod information_schema;
 pub mod memory;
+pub mod mock;
 pub mod null;
 pub mod result;
 pub mod stage;
@@ -30,8 +32,11 @@ mod storage_table;
 mod storage_table_read_plan;
 
 pub use storage_context::StorageContext;
+pub use storage_factory::validate_delete;
+pub use storage_factory::validate_time_travel;
 pub use storage_factory::StorageCreator;
 pub use storage_factory::StorageDescription;
+pub use storage_factory::StorageDescriptor;
 pub use storage_factory::StorageFactory;
 pub use storage_table::NavigationPoint;
 pub use storage_table::Table;
@@ -20,7 +20,7 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::SourceInfo;
 use futures::StreamExt;
 
-use crate::formats::output_format::OutputFormatType;
+use crate::formats::FormatFactory;
 use crate::sessions::QueryContext;
 use crate::storages::result::ResultTable;
 use crate::storages::Table;
@@ -32,7 +32,7 @@ impl ResultTable {
     pub async fn download(
         &self,
         ctx: Arc<QueryContext>,
-        fmt: OutputFormatType,
+        format: &str,
     ) -> Result<SendableVu8Stream> {
         let (_, parts) = self.read_partitions(ctx.clone(), None).await?;
         ctx.try_set_partitions(parts)?;
@@ -49,7 +49,7 @@ impl ResultTable {
             })
             .await?;
         let fmt_setting = ctx.get_format_settings()?;
-        let mut output_format = fmt.create_format(self.schema());
+        let mut output_format = FormatFactory::instance().get_output(format, self.schema())?;
 
         let stream = stream! {
             while let Some(block) = block_stream.next().await {
@@ -99,6 +99,8 @@ impl ResultTableWriter {
         let block_statistics = BlockStatistics::from(&block, location.clone(), None)?;
         let schema = block.schema().clone();
         let (size, meta_data) = serialize_data_blocks(vec![block], &schema, &mut data)?;
+        self.accumulator
+            .add_block(size, meta_data, block_statistics, &data)?;
         self.data_accessor
             .object(&location)
             .write(data)
@@ -107,8 +109,6 @@ impl ResultTableWriter {
                 println!("error {}", e);
                 e
             })?;
-        self.accumulator
-            .add_block(size, meta_data, block_statistics)?;
         Ok(self.get_last_part_info())
     }
 
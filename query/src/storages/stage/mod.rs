@@ -12,8 +12,11 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod stage_column_mapping;
 mod stage_source;
 mod stage_table;
 
+pub use stage_column_mapping::MatchByColumnNameSource;
 pub use stage_source::StageSource;
+pub use stage_table::StageFileWritten;
 pub use stage_table::StageTable;
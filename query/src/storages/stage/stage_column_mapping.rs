@@ -0,0 +1,149 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MatchByColumnName;
+use common_streams::CsvSource;
+use common_streams::ParquetSource;
+use common_streams::Source;
+use futures::AsyncRead;
+use futures::AsyncSeek;
+
+use crate::pipelines::new::processors::TransformAddOn;
+use crate::sessions::QueryContext;
+
+/// Reads the columns of a file by name, the columns of the table missing from the file are
+/// filled with their default values.
+pub struct MatchByColumnNameSource {
+    source: Box<dyn Source>,
+    add_on: TransformAddOn,
+}
+
+impl MatchByColumnNameSource {
+    /// Map the columns in the header of a csv file onto the schema.
+    pub async fn try_create_csv<R>(
+        ctx: &Arc<QueryContext>,
+        schema: DataSchemaRef,
+        match_by_column_name: MatchByColumnName,
+        mut source: CsvSource<R>,
+    ) -> Result<Box<dyn Source>>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let names = source.headers().await?;
+        let (matched, positions) = Self::match_columns(ctx, &schema, match_by_column_name, &names)?;
+        source.project(matched.clone(), positions);
+        Self::try_create(ctx, schema, matched, Box::new(source))
+    }
+
+    /// Map the columns of a parquet file onto the schema.
+    pub async fn try_create_parquet<R>(
+        ctx: &Arc<QueryContext>,
+        schema: DataSchemaRef,
+        match_by_column_name: MatchByColumnName,
+        mut source: ParquetSource<R>,
+    ) -> Result<Box<dyn Source>>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
+        let names = source.column_names().await?;
+        let (matched, positions) = Self::match_columns(ctx, &schema, match_by_column_name, &names)?;
+        let names = positions.iter().map(|i| names[*i].clone()).collect();
+        source.project(matched.clone(), names);
+        Self::try_create(ctx, schema, matched, Box::new(source))
+    }
+
+    fn try_create(
+        ctx: &Arc<QueryContext>,
+        schema: DataSchemaRef,
+        matched: DataSchemaRef,
+        source: Box<dyn Source>,
+    ) -> Result<Box<dyn Source>> {
+        let add_on = TransformAddOn::try_new(matched, schema, ctx.clone())?;
+        Ok(Box::new(MatchByColumnNameSource { source, add_on }))
+    }
+
+    // The fields of the schema found in the file, with the positions of their file columns.
+    fn match_columns(
+        ctx: &Arc<QueryContext>,
+        schema: &DataSchemaRef,
+        match_by_column_name: MatchByColumnName,
+        names: &[String],
+    ) -> Result<(DataSchemaRef, Vec<usize>)> {
+        let mut fields = Vec::with_capacity(schema.num_fields());
+        let mut positions = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let matched = names
+                .iter()
+                .enumerate()
+                .filter(|(_, name)| match_by_column_name.matches(field.name(), name))
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>();
+
+            match matched.as_slice() {
+                [] => {}
+                [position] => {
+                    fields.push(field.clone());
+                    positions.push(*position);
+                }
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Column {} matches more than one column of the file",
+                        field.name()
+                    )))
+                }
+            }
+        }
+
+        if fields.is_empty() {
+            return Err(ErrorCode::BadBytes(format!(
+                "None of the file columns {:?} matches a column of the table",
+                names
+            )));
+        }
+
+        if ctx.get_settings().get_error_on_extra_columns()? != 0 {
+            let extra = names
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| !positions.contains(position))
+                .map(|(_, name)| name.as_str())
+                .collect::<Vec<_>>();
+
+            if !extra.is_empty() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "The file columns {:?} are not in the table",
+                    extra
+                )));
+            }
+        }
+
+        Ok((DataSchemaRefExt::create(fields), positions))
+    }
+}
+
+#[async_trait::async_trait]
+impl Source for MatchByColumnNameSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        match self.source.read().await? {
+            None => Ok(None),
+            Some(block) => Ok(Some(self.add_on.add_missing_columns(block)?)),
+        }
+    }
+}
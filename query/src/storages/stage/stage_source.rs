@@ -21,8 +21,10 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::init_fs_operator;
 use common_io::prelude::init_s3_operator;
 use common_io::prelude::StorageParams;
+use common_meta_types::MatchByColumnName;
 use common_meta_types::StageFileCompression;
 use common_meta_types::StageFileFormatType;
 use common_meta_types::StageType;
@@ -44,6 +46,7 @@ use crate::pipelines::new::processors::processor::ProcessorPtr;
 use crate::pipelines::new::processors::AsyncSource;
 use crate::pipelines::new::processors::AsyncSourcer;
 use crate::sessions::QueryContext;
+use crate::storages::stage::MatchByColumnNameSource;
 
 pub struct StageSource {
     ctx: Arc<QueryContext>,
@@ -98,9 +101,13 @@ impl StageSource {
             builder.block_size(max_block_size as usize);
         }
 
-        // Skip header.
+        // Skip header, the header has the column names when matching by name.
+        let match_by_column_name = stage_info.copy_options.match_by_column_name;
         {
-            builder.skip_header(stage_info.file_format_options.skip_header > 0);
+            builder.skip_header(
+                stage_info.file_format_options.skip_header > 0
+                    || match_by_column_name != MatchByColumnName::None,
+            );
         }
 
         // Field delimiter, default ','.
@@ -115,7 +122,11 @@ impl StageSource {
             builder.record_delimiter(record_delimiter);
         }
 
-        Ok(Box::new(builder.build(reader)?))
+        let source = builder.build(reader)?;
+        match match_by_column_name {
+            MatchByColumnName::None => Ok(Box::new(source)),
+            mode => MatchByColumnNameSource::try_create_csv(&ctx, schema, mode, source).await,
+        }
     }
 
     // Get json source stream.
@@ -146,9 +157,9 @@ impl StageSource {
 
     // Get parquet source stream.
     async fn parquet_source(
-        _ctx: Arc<QueryContext>,
+        ctx: Arc<QueryContext>,
         schema: DataSchemaRef,
-        _stage_info: &UserStageInfo,
+        stage_info: &UserStageInfo,
         reader: SeekableReader,
     ) -> Result<Box<dyn Source>> {
         let mut builder = ParquetSourceBuilder::create(schema.clone());
@@ -159,7 +170,11 @@ impl StageSource {
             .collect::<Vec<usize>>();
         builder.projection(default_proj);
 
-        Ok(Box::new(builder.build(reader)?))
+        let source = builder.build(reader)?;
+        match stage_info.copy_options.match_by_column_name {
+            MatchByColumnName::None => Ok(Box::new(source)),
+            mode => MatchByColumnNameSource::try_create_parquet(&ctx, schema, mode, source).await,
+        }
     }
 
     pub async fn get_op(ctx: &Arc<QueryContext>, stage: &UserStageInfo) -> Result<Operator> {
@@ -179,6 +194,7 @@ impl StageSource {
 
                     init_s3_operator(&cfg).await
                 }
+                StorageParams::Fs(cfg) => init_fs_operator(cfg).await,
                 _ => todo!("other storage type are not supported"),
             }
         }
@@ -14,13 +14,15 @@
 
 use std::any::Any;
 use std::collections::VecDeque;
-use std::str::FromStr;
 use std::sync::Arc;
 
+use common_base::base::TrySpawn;
 use common_base::infallible::Mutex;
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FormatSettings;
 use common_meta_app::schema::TableInfo;
 use common_planners::Extras;
 use common_planners::Partitions;
@@ -29,15 +31,31 @@ use common_planners::StageTableInfo;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
+use futures::stream::FuturesOrdered;
+use futures::Future;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
 
 use super::StageSource;
-use crate::formats::output_format::OutputFormatType;
+use crate::formats::FormatFactory;
 use crate::pipelines::new::processors::port::OutputPort;
 use crate::pipelines::new::NewPipeline;
 use crate::pipelines::new::SourcePipeBuilder;
 use crate::sessions::QueryContext;
 use crate::storages::Table;
 
+const MIN_FILE_BYTES: usize = 16 * 1024 * 1024;
+const SPOOL_FILE_BYTES: usize = 64 * 1024 * 1024;
+
+/// A file written to the stage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StageFileWritten {
+    pub path: String,
+    pub rows: usize,
+    pub bytes: usize,
+}
+
 pub struct StageTable {
     table_info: StageTableInfo,
     // This is no used but a placeholder.
@@ -47,12 +65,276 @@ pub struct StageTable {
 }
 
 impl StageTable {
-    pub fn try_create(table_info: StageTableInfo) -> Result<Arc<dyn Table>> {
-        let table_info_placeholder = TableInfo::default();
-        Ok(Arc::new(Self {
+    pub fn create(table_info: StageTableInfo) -> StageTable {
+        StageTable {
             table_info,
-            table_info_placeholder,
-        }))
+            table_info_placeholder: TableInfo::default(),
+        }
+    }
+
+    pub fn try_create(table_info: StageTableInfo) -> Result<Arc<dyn Table>> {
+        Ok(Arc::new(Self::create(table_info)))
+    }
+
+    /// Writes the blocks to the files under the path of the stage in parallel, at most
+    /// `max_threads` files at a time, returns the files written. The files of a statement
+    /// are named by their sequence, `<path>/<uuid>_<sequence>.<format>`.
+    ///
+    /// If `max_file_size` is not 0, the blocks are split into files of `max_file_size`
+    /// bytes at most, measured by the size of the blocks in memory, in the order of the
+    /// blocks. Otherwise there is one file per writer and each of them writes 16MB at least.
+    ///
+    /// If `ordered`, the N-th file has the N-th range of the consecutive blocks, so the
+    /// rows of a file are all before the rows of the next file, in the order of the blocks.
+    /// Otherwise the blocks are dealt out to the writers in turn.
+    pub async fn write_files(
+        &self,
+        ctx: Arc<QueryContext>,
+        blocks: Vec<DataBlock>,
+        ordered: bool,
+        max_file_size: usize,
+    ) -> Result<Vec<StageFileWritten>> {
+        let writer = StageFileWriter::try_create(&ctx, &self.table_info).await?;
+        let max_writers = (ctx.get_settings().get_max_threads()? as usize).max(1);
+        let ranges = if max_file_size > 0 {
+            Self::split_by_size(blocks, max_file_size)?
+        } else {
+            // Small results are not worth splitting, every writer gets at least MIN_FILE_BYTES.
+            let total_bytes: usize = blocks.iter().map(|b| b.memory_size()).sum();
+            let writers = (total_bytes / MIN_FILE_BYTES).clamp(1, max_writers);
+            match ordered {
+                true => Self::split_ranges(blocks, writers),
+                false => Self::deal_out(blocks, writers),
+            }
+        };
+
+        let mut writes = Vec::with_capacity(ranges.len());
+        for (sequence, range) in ranges.into_iter().enumerate() {
+            writes.push(writer.write(&ctx, sequence, range)?);
+        }
+
+        // The writers are spawned when they are polled, at most max_writers at a time.
+        futures::stream::iter(writes)
+            .map(|write| {
+                let ctx = ctx.clone();
+                async move {
+                    match ctx.try_spawn(write)?.await {
+                        Ok(res) => res,
+                        Err(cause) => Err(ErrorCode::TokioError(format!(
+                            "Cannot join the stage file writer, cause: {}",
+                            cause
+                        ))),
+                    }
+                }
+            })
+            .buffered(max_writers)
+            .try_collect()
+            .await
+    }
+
+    /// Writes the blocks of the stream to the files under the path of the stage while
+    /// the stream is consumed, so the result is never held in memory as a whole. A file
+    /// is rolled when its blocks reach `max_file_size` bytes in memory, or 64MB if
+    /// `max_file_size` is 0; the files are in the order of the blocks, see `write_files`.
+    ///
+    /// At most `max_threads` files are being written at a time, the stream is not polled
+    /// until one of them completes.
+    pub async fn spool_files(
+        &self,
+        ctx: Arc<QueryContext>,
+        mut stream: SendableDataBlockStream,
+        max_file_size: usize,
+    ) -> Result<Vec<StageFileWritten>> {
+        let writer = StageFileWriter::try_create(&ctx, &self.table_info).await?;
+        let max_writers = (ctx.get_settings().get_max_threads()? as usize).max(1);
+        let max_bytes = match max_file_size {
+            0 => SPOOL_FILE_BYTES,
+            v => v,
+        };
+
+        let spawn = |sequence: usize, range: Vec<DataBlock>| {
+            let write = writer.write(&ctx, sequence, range)?;
+            let handle = ctx.try_spawn(write)?;
+            Ok::<_, ErrorCode>(async move {
+                match handle.await {
+                    Ok(res) => res,
+                    Err(cause) => Err(ErrorCode::TokioError(format!(
+                        "Cannot join the stage file writer, cause: {}",
+                        cause
+                    ))),
+                }
+            })
+        };
+
+        let mut files = Vec::new();
+        let mut writes = FuturesOrdered::new();
+        let mut range = vec![];
+        let mut bytes = 0;
+        while let Some(block) = stream.next().await {
+            for piece in Self::slice_by_size(block?, max_bytes)? {
+                let piece_bytes = piece.memory_size();
+                if bytes > 0 && bytes + piece_bytes > max_bytes {
+                    if writes.len() >= max_writers {
+                        if let Some(file) = writes.next().await {
+                            files.push(file?);
+                        }
+                    }
+                    let sequence = files.len() + writes.len();
+                    writes.push(spawn(sequence, std::mem::take(&mut range))?);
+                    bytes = 0;
+                }
+                bytes += piece_bytes;
+                range.push(piece);
+            }
+        }
+
+        // An empty result is still written as a file.
+        let sequence = files.len() + writes.len();
+        writes.push(spawn(sequence, range)?);
+        while let Some(file) = writes.next().await {
+            files.push(file?);
+        }
+        Ok(files)
+    }
+
+    // Split the blocks into at most `writers` ranges of consecutive blocks of similar sizes.
+    // There is always one range, an empty result is still written as a file.
+    fn split_ranges(blocks: Vec<DataBlock>, writers: usize) -> Vec<Vec<DataBlock>> {
+        let total_bytes: usize = blocks.iter().map(|b| b.memory_size()).sum();
+        let range_bytes = (total_bytes / writers.max(1)).max(1);
+
+        let mut ranges = vec![vec![]];
+        let mut bytes = 0;
+        for block in blocks {
+            if bytes >= range_bytes && ranges.len() < writers {
+                ranges.push(vec![]);
+                bytes = 0;
+            }
+            bytes += block.memory_size();
+            ranges.last_mut().unwrap().push(block);
+        }
+        ranges
+    }
+
+    // Split the blocks into ranges of consecutive rows of `max_bytes` at most, a block larger
+    // than `max_bytes` is sliced by rows. A single row larger than `max_bytes` is a range.
+    fn split_by_size(blocks: Vec<DataBlock>, max_bytes: usize) -> Result<Vec<Vec<DataBlock>>> {
+        let mut ranges = vec![vec![]];
+        let mut bytes = 0;
+        for block in blocks {
+            for piece in Self::slice_by_size(block, max_bytes)? {
+                let piece_bytes = piece.memory_size();
+                if bytes > 0 && bytes + piece_bytes > max_bytes {
+                    ranges.push(vec![]);
+                    bytes = 0;
+                }
+                bytes += piece_bytes;
+                ranges.last_mut().unwrap().push(piece);
+            }
+        }
+        Ok(ranges)
+    }
+
+    // Slice a block larger than `max_bytes` by rows.
+    fn slice_by_size(block: DataBlock, max_bytes: usize) -> Result<Vec<DataBlock>> {
+        let block_bytes = block.memory_size();
+        if block_bytes > max_bytes && block.num_rows() > 1 {
+            let row_bytes = (block_bytes / block.num_rows()).max(1);
+            DataBlock::split_block_by_size(&block, (max_bytes / row_bytes).max(1))
+        } else {
+            Ok(vec![block])
+        }
+    }
+
+    fn deal_out(blocks: Vec<DataBlock>, writers: usize) -> Vec<Vec<DataBlock>> {
+        let writers = writers.clamp(1, blocks.len().max(1));
+        let mut ranges = vec![vec![]; writers];
+        for (index, block) in blocks.into_iter().enumerate() {
+            ranges[index % writers].push(block);
+        }
+        ranges
+    }
+}
+
+/// Serializes ranges of blocks into the files of a statement.
+struct StageFileWriter {
+    operator: Operator,
+    format_name: String,
+    format_settings: FormatSettings,
+    schema: DataSchemaRef,
+    path: String,
+    prefix: uuid::Uuid,
+}
+
+impl StageFileWriter {
+    async fn try_create(ctx: &Arc<QueryContext>, table_info: &StageTableInfo) -> Result<Self> {
+        let format_name = format!("{:?}", table_info.stage_info.file_format_options.format);
+        let operator = StageSource::get_op(ctx, &table_info.stage_info).await?;
+
+        let mut format_settings = ctx.get_format_settings()?;
+        let format_options = &table_info.stage_info.file_format_options;
+        {
+            format_settings.skip_header = format_options.skip_header > 0;
+            if !format_options.field_delimiter.is_empty() {
+                format_settings.field_delimiter =
+                    format_options.field_delimiter.as_bytes().to_vec();
+            }
+            if !format_options.record_delimiter.is_empty() {
+                format_settings.record_delimiter =
+                    format_options.record_delimiter.as_bytes().to_vec();
+            }
+        }
+
+        Ok(StageFileWriter {
+            operator,
+            format_name,
+            format_settings,
+            schema: table_info.schema(),
+            path: table_info.path.trim_end_matches('/').to_string(),
+            prefix: uuid::Uuid::new_v4(),
+        })
+    }
+
+    // The file of the `sequence`, `<path>/<uuid>_<sequence>.<format>`.
+    fn write(
+        &self,
+        ctx: &Arc<QueryContext>,
+        sequence: usize,
+        range: Vec<DataBlock>,
+    ) -> Result<impl Future<Output = Result<StageFileWritten>> + Send + 'static> {
+        let path = format!(
+            "{}/{}_{:04}.{}",
+            self.path,
+            self.prefix,
+            sequence,
+            self.format_name.to_ascii_lowercase()
+        );
+        let mut output_format =
+            FormatFactory::instance().get_output(&self.format_name, self.schema.clone())?;
+        let format_settings = self.format_settings.clone();
+        let object = self.operator.object(&path);
+        let metrics = ctx.get_dal_context().get_metrics();
+
+        Ok(async move {
+            let rows = range.iter().map(|b| b.num_rows()).sum();
+            let memory_bytes: usize = range.iter().map(|b| b.memory_size()).sum();
+            let mut bytes = Vec::with_capacity(memory_bytes);
+            for block in range {
+                let bs = output_format.serialize_block(&block, &format_settings)?;
+                bytes.extend_from_slice(bs.as_slice());
+            }
+
+            let bs = output_format.finalize()?;
+            bytes.extend_from_slice(bs.as_slice());
+
+            metrics.inc_write_bytes(bytes.len());
+            object.write(bytes.as_slice()).await?;
+            Ok(StageFileWritten {
+                path,
+                rows,
+                bytes: bytes.len(),
+            })
+        })
     }
 }
 
@@ -138,52 +420,7 @@ impl Table for StageTable {
         operations: Vec<DataBlock>,
         _overwrite: bool,
     ) -> Result<()> {
-        let format_name = format!(
-            "{:?}",
-            self.table_info.stage_info.file_format_options.format
-        );
-        let path = format!(
-            "{}/{}.{}",
-            self.table_info.path,
-            uuid::Uuid::new_v4(),
-            format_name.to_ascii_lowercase()
-        );
-
-        let op = StageSource::get_op(&ctx, &self.table_info.stage_info).await?;
-
-        let fmt = OutputFormatType::from_str(format_name.as_str())?;
-        let mut output_format = fmt.create_format(self.table_info.schema());
-        let mut format_settings = ctx.get_format_settings()?;
-
-        let format_options = &self.table_info.stage_info.file_format_options;
-        {
-            format_settings.skip_header = format_options.skip_header > 0;
-            if !format_options.field_delimiter.is_empty() {
-                format_settings.field_delimiter =
-                    format_options.field_delimiter.as_bytes().to_vec();
-            }
-            if !format_options.record_delimiter.is_empty() {
-                format_settings.record_delimiter =
-                    format_options.record_delimiter.as_bytes().to_vec();
-            }
-        }
-
-        let written_bytes: usize = operations.iter().map(|b| b.memory_size()).sum();
-        let mut bytes = Vec::with_capacity(written_bytes);
-        for block in operations {
-            let bs = output_format.serialize_block(&block, &format_settings)?;
-            bytes.extend_from_slice(bs.as_slice());
-        }
-
-        let bs = output_format.finalize()?;
-        bytes.extend_from_slice(bs.as_slice());
-
-        ctx.get_dal_context()
-            .get_metrics()
-            .inc_write_bytes(bytes.len());
-
-        let object = op.object(&path);
-        object.write(bytes.as_slice()).await?;
+        self.write_files(ctx, operations, false, 0).await?;
         Ok(())
     }
 
@@ -20,6 +20,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
 
+use crate::catalogs::Catalog;
 use crate::storages::fuse::FuseTable;
 use crate::storages::github::GithubTable;
 use crate::storages::memory::MemoryTable;
@@ -43,11 +44,19 @@ where
     }
 }
 
+/// The name and the capabilities of a table engine, the planner validates the
+/// statements against them before the tables are touched.
 #[derive(Default, Clone)]
 pub struct StorageDescription {
     pub engine_name: String,
     pub comment: String,
     pub support_cluster_key: bool,
+    // DELETE from and UPDATE the tables.
+    pub support_delete: bool,
+    // Read the tables at a snapshot in the past.
+    pub support_time_travel: bool,
+    // The tables have statistics, e.g. the number of rows and the size of the data.
+    pub support_statistics: bool,
 }
 
 pub trait StorageDescriptor: Send + Sync {
@@ -117,6 +126,21 @@ impl StorageFactory {
         }
     }
 
+    /// Registers a table engine, which can be used by `ENGINE = <name>` of CREATE TABLE.
+    /// The engine of the same name is replaced.
+    pub fn register(
+        &self,
+        name: &str,
+        creator: Arc<dyn StorageCreator>,
+        descriptor: Arc<dyn StorageDescriptor>,
+    ) {
+        let mut lock = self.storages.write();
+        lock.insert(name.to_uppercase(), Storage {
+            creator,
+            descriptor,
+        });
+    }
+
     pub fn get_table(&self, ctx: StorageContext, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let engine = table_info.engine().to_uppercase();
         let lock = self.storages.read();
@@ -137,3 +161,34 @@ impl StorageFactory {
         descriptors
     }
 }
+
+/// Validates the engine of the table supports time travel, for the reading of the table
+/// at a snapshot in the past.
+pub fn validate_time_travel(catalog: &dyn Catalog, table: &dyn Table) -> Result<()> {
+    match catalog.get_table_engine(table.engine()) {
+        Some(engine) if engine.support_time_travel => Ok(()),
+        _ => Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Table engine {} does not support time travel, table: {}",
+            table.engine(),
+            table.name()
+        ))),
+    }
+}
+
+/// Validates the engine of the table supports DELETE and UPDATE, which rewrite the rows of
+/// the table in place.
+pub fn validate_delete(
+    catalog: &dyn Catalog,
+    table: &dyn Table,
+    statement_name: &str,
+) -> Result<()> {
+    match catalog.get_table_engine(table.engine()) {
+        Some(engine) if engine.support_delete => Ok(()),
+        _ => Err(ErrorCode::UnsupportedEngineParams(format!(
+            "Table engine {} does not support {}, table: {}",
+            table.engine(),
+            statement_name,
+            table.name()
+        ))),
+    }
+}
@@ -16,6 +16,8 @@ use std::any::Any;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
@@ -24,11 +26,13 @@ use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::PartitionRef;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
+use opendal::Operator;
 
 use crate::pipelines::new::NewPipeline;
 use crate::sessions::QueryContext;
@@ -146,6 +150,82 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    async fn drop_partition(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _catalog_name: &str,
+        _partitions: Vec<PartitionRef>,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "drop partition for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Rewrites the data of the table to restore the ordering of its cluster key.
+    async fn recluster(&self, _ctx: Arc<QueryContext>, _catalog_name: &str) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "recluster for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Deletes the rows matching the selection, all of them if there is no selection.
+    /// Returns the number of the deleted rows.
+    async fn delete(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _catalog_name: &str,
+        _selection: Option<Expression>,
+    ) -> Result<u64> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Assigns the values to the columns of the rows matching the selection, all of them if
+    /// there is no selection. Returns the number of the updated rows.
+    async fn update(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _catalog_name: &str,
+        _assignments: Vec<(String, Expression)>,
+        _selection: Option<Expression>,
+    ) -> Result<u64> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Makes a replica table writable, see `OPT_KEY_REPLICA_OF`.
+    async fn promote(&self, _ctx: Arc<QueryContext>, _catalog_name: &str) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "promote for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    /// Exports the current snapshot of the table in Parquet into the directory `path` of `target`.
+    async fn export_snapshot(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _target: &Operator,
+        _path: &str,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "export snapshot for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     async fn truncate(
         &self,
         _ctx: Arc<QueryContext>,
@@ -161,6 +241,11 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Merges the small blocks and segments of the table, nothing to do by default.
+    async fn compact(&self, _ctx: Arc<QueryContext>, _catalog_name: &str) -> Result<()> {
+        Ok(())
+    }
+
     async fn statistics(&self, _ctx: Arc<QueryContext>) -> Result<Option<TableStatistics>> {
         Ok(None)
     }
@@ -180,6 +265,7 @@ pub trait Table: Sync + Send {
 
 pub enum NavigationPoint {
     SnapshotID(String),
+    TimePoint(DateTime<Utc>),
 }
 
 #[derive(Debug)]
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
 use common_planners::Extras;
@@ -54,8 +55,11 @@ impl ToReadDataSourcePlan for dyn Table {
         catalog: String,
         push_downs: Option<Extras>,
     ) -> Result<ReadDataSourcePlan> {
-        let (statistics, parts) = self.read_partitions(ctx, push_downs.clone()).await?;
+        let (statistics, parts) = self
+            .read_partitions(ctx.clone(), push_downs.clone())
+            .await?;
         let table_info = self.get_table_info();
+        check_read_limits(&ctx, table_info, &statistics)?;
         let description = get_description(table_info, &statistics);
 
         let scan_fields = match (self.benefit_column_prune(), &push_downs) {
@@ -87,6 +91,34 @@ impl ToReadDataSourcePlan for dyn Table {
     }
 }
 
+/// Rejects the scans exceeding `max_bytes_to_read` or `max_partitions_to_read` before any
+/// data is read, the statistics are the ones after pruning.
+fn check_read_limits(
+    ctx: &Arc<QueryContext>,
+    table_info: &TableInfo,
+    statistics: &Statistics,
+) -> Result<()> {
+    let settings = ctx.get_settings();
+
+    let max_bytes = settings.get_max_bytes_to_read()?;
+    if max_bytes > 0 && statistics.read_bytes as u64 > max_bytes {
+        return Err(ErrorCode::QueryLimitExceeded(format!(
+            "Reading {} table needs to read {} bytes, which exceeds max_bytes_to_read {}, please add filters or limit the query",
+            table_info.desc, statistics.read_bytes, max_bytes
+        )));
+    }
+
+    let max_partitions = settings.get_max_partitions_to_read()?;
+    if max_partitions > 0 && statistics.partitions_scanned as u64 > max_partitions {
+        return Err(ErrorCode::QueryLimitExceeded(format!(
+            "Reading {} table needs to read {} partitions, which exceeds max_partitions_to_read {}, please add filters or limit the query",
+            table_info.desc, statistics.partitions_scanned, max_partitions
+        )));
+    }
+
+    Ok(())
+}
+
 fn get_description(table_info: &TableInfo, statistics: &Statistics) -> String {
     if statistics.read_rows > 0 {
         format!(
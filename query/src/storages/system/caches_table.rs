@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+pub struct CachesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CachesTable {
+    const NAME: &'static str = "system.caches";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let mut caches = ctx.get_storage_cache_manager().status().await;
+        caches.push(ctx.get_query_cache().status());
+
+        let names: Vec<&str> = caches.iter().map(|x| x.name).collect();
+        let units: Vec<&str> = caches.iter().map(|x| x.unit).collect();
+        let capacities: Vec<u64> = caches.iter().map(|x| x.capacity).collect();
+        let sizes: Vec<u64> = caches.iter().map(|x| x.size).collect();
+        let entries: Vec<u64> = caches.iter().map(|x| x.entries).collect();
+        let hits: Vec<u64> = caches.iter().map(|x| x.hits).collect();
+        let misses: Vec<u64> = caches.iter().map(|x| x.misses).collect();
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(names),
+            Series::from_data(units),
+            Series::from_data(capacities),
+            Series::from_data(sizes),
+            Series::from_data(entries),
+            Series::from_data(hits),
+            Series::from_data(misses),
+        ]))
+    }
+}
+
+impl CachesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("unit", Vu8::to_data_type()),
+            DataField::new("capacity", u64::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+            DataField::new("entries", u64::to_data_type()),
+            DataField::new("hits", u64::to_data_type()),
+            DataField::new("misses", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'caches'".to_string(),
+            name: "caches".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCaches".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(CachesTable { table_info })
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::table_functions::ClusteringInformation;
+use crate::storages::fuse::FuseTable;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The clustering depth of the FUSE tables which have a cluster key.
+pub struct ClusteringTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ClusteringTable {
+    const NAME: &'static str = "system.clustering";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let mut databases: Vec<Vec<u8>> = vec![];
+        let mut tables: Vec<Vec<u8>> = vec![];
+        let mut cluster_by_keys: Vec<Vec<u8>> = vec![];
+        let mut block_counts: Vec<Option<u64>> = vec![];
+        let mut average_overlaps: Vec<Option<f64>> = vec![];
+        let mut average_depths: Vec<Option<f64>> = vec![];
+
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            let database_name = database.name();
+            for table in catalog.list_tables(tenant.as_str(), database_name).await? {
+                let tbl = match FuseTable::try_from_table(table.as_ref()) {
+                    Ok(tbl) if !tbl.cluster_keys.is_empty() => tbl,
+                    _ => continue,
+                };
+
+                let names = tbl
+                    .cluster_keys
+                    .iter()
+                    .map(|x| x.column_name())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                // the statistics are unknown until the blocks written before the
+                // current cluster key are reclustered
+                let info = ClusteringInformation::new(ctx.clone(), tbl, tbl.cluster_keys.clone())
+                    .get_clustering_stats_of_table()
+                    .await
+                    .ok();
+
+                databases.push(database_name.as_bytes().to_vec());
+                tables.push(table.name().as_bytes().to_vec());
+                cluster_by_keys.push(format!("({})", names).into_bytes());
+                block_counts.push(info.as_ref().map(|i| i.total_block_count));
+                average_overlaps.push(info.as_ref().map(|i| i.average_overlaps));
+                average_depths.push(info.as_ref().map(|i| i.average_depth));
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(cluster_by_keys),
+            Series::from_data(block_counts),
+            Series::from_data(average_overlaps),
+            Series::from_data(average_depths),
+        ]))
+    }
+}
+
+impl ClusteringTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("cluster_by_keys", Vu8::to_data_type()),
+            DataField::new_nullable("total_block_count", u64::to_data_type()),
+            DataField::new_nullable("average_overlaps", f64::to_data_type()),
+            DataField::new_nullable("average_depth", f64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'clustering'".to_string(),
+            name: "clustering".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemClustering".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(ClusteringTable { table_info })
+    }
+}
@@ -95,16 +95,13 @@ impl ColumnsTable {
         &self,
         ctx: Arc<QueryContext>,
     ) -> Result<Vec<(String, String, DataField)>> {
-        let tenant = ctx.get_tenant();
-        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
+        let snapshot = ctx.get_catalog_snapshot(CATALOG_DEFAULT).await?;
+        let databases = snapshot.list_databases();
 
         let mut rows: Vec<(String, String, DataField)> = vec![];
         for database in databases {
-            for table in catalog
-                .list_tables(tenant.as_str(), database.name())
-                .await?
-            {
+            let tables = snapshot.list_tables(database.name()).unwrap_or_default();
+            for table in tables {
                 for field in table.schema().fields() {
                     rows.push((database.name().into(), table.name().into(), field.clone()))
                 }
@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::sessions::QueryContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+pub struct CorruptBlocksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CorruptBlocksTable {
+    const NAME: &'static str = "system.corrupt_blocks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let mut corrupt_blocks = ctx.get_corrupt_block_manager().get_all();
+        corrupt_blocks.sort_by(|a, b| a.detected_at.cmp(&b.detected_at));
+
+        // the tables may be dropped since the blocks are found corrupted
+        let mut names = HashMap::new();
+        if !corrupt_blocks.is_empty() {
+            let tenant = ctx.get_tenant();
+            let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+            for database in catalog.list_databases(tenant.as_str()).await? {
+                for table in catalog
+                    .list_tables(tenant.as_str(), database.name())
+                    .await?
+                {
+                    names.insert(
+                        table.get_id(),
+                        (database.name().to_string(), table.name().to_string()),
+                    );
+                }
+            }
+        }
+
+        let mut databases: Vec<Option<Vec<u8>>> = vec![];
+        let mut tables: Vec<Option<Vec<u8>>> = vec![];
+        let mut table_ids: Vec<u64> = vec![];
+        let mut locations: Vec<Vec<u8>> = vec![];
+        let mut column_ids: Vec<u64> = vec![];
+        let mut expected_checksums: Vec<u64> = vec![];
+        let mut actual_checksums: Vec<u64> = vec![];
+        let mut detected_ats: Vec<Vec<u8>> = vec![];
+        for block in corrupt_blocks {
+            let name = names.get(&block.table_id);
+            databases.push(name.map(|(database, _)| database.as_bytes().to_vec()));
+            tables.push(name.map(|(_, table)| table.as_bytes().to_vec()));
+            table_ids.push(block.table_id);
+            locations.push(block.location.into_bytes());
+            column_ids.push(block.column_id as u64);
+            expected_checksums.push(block.expected_checksum);
+            actual_checksums.push(block.actual_checksum);
+            detected_ats.push(
+                block
+                    .detected_at
+                    .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                    .to_string()
+                    .into_bytes(),
+            );
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(table_ids),
+            Series::from_data(locations),
+            Series::from_data(column_ids),
+            Series::from_data(expected_checksums),
+            Series::from_data(actual_checksums),
+            Series::from_data(detected_ats),
+        ]))
+    }
+}
+
+impl CorruptBlocksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new_nullable("database", Vu8::to_data_type()),
+            DataField::new_nullable("table", Vu8::to_data_type()),
+            DataField::new("table_id", u64::to_data_type()),
+            DataField::new("block_location", Vu8::to_data_type()),
+            DataField::new("column_id", u64::to_data_type()),
+            DataField::new("expected_checksum", u64::to_data_type()),
+            DataField::new("actual_checksum", u64::to_data_type()),
+            DataField::new("detected_at", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'corrupt_blocks'".to_string(),
+            name: "corrupt_blocks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCorruptBlocks".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(CorruptBlocksTable { table_info })
+    }
+}
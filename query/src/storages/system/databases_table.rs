@@ -39,9 +39,10 @@ impl AsyncSystemTable for DatabasesTable {
     }
 
     async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
-        let tenant = ctx.get_tenant();
-        let catalog = ctx.get_catalog(ctx.get_current_catalog().as_str())?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
+        let snapshot = ctx
+            .get_catalog_snapshot(ctx.get_current_catalog().as_str())
+            .await?;
+        let databases = snapshot.list_databases();
 
         let db_names: Vec<&[u8]> = databases
             .iter()
@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::formats::FormatFactory;
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct FormatsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for FormatsTable {
+    const NAME: &'static str = "system.formats";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let factory = FormatFactory::instance();
+        let formats = factory
+            .input_formats()
+            .map(|(name, options)| (name, "input", options))
+            .chain(
+                factory
+                    .output_formats()
+                    .map(|(name, options)| (name, "output", options)),
+            );
+
+        let mut names: Vec<&str> = vec![];
+        let mut directions: Vec<&str> = vec![];
+        let mut options: Vec<&str> = vec![];
+        let mut expects: Vec<&str> = vec![];
+        let mut descs: Vec<&str> = vec![];
+        for (name, direction, format_options) in formats {
+            // A format without options still has a row.
+            let rows = match format_options.is_empty() {
+                true => vec![None],
+                false => format_options.iter().map(Some).collect(),
+            };
+
+            for option in rows {
+                names.push(name);
+                directions.push(direction);
+                options.push(option.map(|option| option.name).unwrap_or(""));
+                expects.push(option.map(|option| option.kind.expected()).unwrap_or(""));
+                descs.push(option.map(|option| option.description).unwrap_or(""));
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(names),
+            Series::from_data(directions),
+            Series::from_data(options),
+            Series::from_data(expects),
+            Series::from_data(descs),
+        ]))
+    }
+}
+
+impl FormatsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("direction", Vu8::to_data_type()),
+            DataField::new("option", Vu8::to_data_type()),
+            DataField::new("expected", Vu8::to_data_type()),
+            DataField::new("description", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'formats'".to_string(),
+            name: "formats".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemFormats".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(FormatsTable { table_info })
+    }
+}
@@ -46,18 +46,19 @@ impl AsyncSystemTable for FunctionsTable {
     async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
         let function_factory = FunctionFactory::instance();
         let aggregate_function_factory = AggregateFunctionFactory::instance();
-        let func_names = function_factory.registered_names();
-        let aggr_func_names = aggregate_function_factory.registered_names();
+        let func_features = function_factory.registered_features();
+        let aggr_func_features = aggregate_function_factory.registered_features();
         let udfs = FunctionsTable::get_udfs(ctx).await?;
 
-        let names: Vec<&str> = func_names
+        let names: Vec<&str> = func_features
             .iter()
-            .chain(aggr_func_names.iter())
+            .map(|(name, _)| name)
+            .chain(aggr_func_features.iter().map(|(name, _)| name))
             .chain(udfs.iter().map(|udf| &udf.name))
             .map(|x| x.as_str())
             .collect();
 
-        let builtin_func_len = func_names.len() + aggr_func_names.len();
+        let builtin_func_len = func_features.len() + aggr_func_features.len();
 
         let docs = (0..names.len())
             .map(|i| {
@@ -75,7 +76,7 @@ impl AsyncSystemTable for FunctionsTable {
             .collect::<Vec<bool>>();
 
         let is_aggregate = (0..names.len())
-            .map(|i| i >= func_names.len() && i < builtin_func_len)
+            .map(|i| i >= func_features.len() && i < builtin_func_len)
             .collect::<Vec<bool>>();
 
         let definitions = (0..names.len())
@@ -91,7 +92,17 @@ impl AsyncSystemTable for FunctionsTable {
 
         let categorys = (0..names.len())
             .map(|i| {
-                if i < builtin_func_len {
+                if i < func_features.len() && !func_features[i].1.category.is_empty() {
+                    func_features[i].1.category
+                } else if i >= func_features.len()
+                    && i < builtin_func_len
+                    && !aggr_func_features[i - func_features.len()]
+                        .1
+                        .category
+                        .is_empty()
+                {
+                    aggr_func_features[i - func_features.len()].1.category
+                } else if i < builtin_func_len {
                     docs[i].category.as_str()
                 } else {
                     "UDF"
@@ -101,7 +112,17 @@ impl AsyncSystemTable for FunctionsTable {
 
         let descriptions = (0..names.len())
             .map(|i| {
-                if i < builtin_func_len {
+                if i < func_features.len() && !func_features[i].1.description.is_empty() {
+                    func_features[i].1.description
+                } else if i >= func_features.len()
+                    && i < builtin_func_len
+                    && !aggr_func_features[i - func_features.len()]
+                        .1
+                        .description
+                        .is_empty()
+                {
+                    aggr_func_features[i - func_features.len()].1.description
+                } else if i < builtin_func_len {
                     docs[i].description.as_str()
                 } else {
                     udfs.get(i - builtin_func_len)
@@ -121,6 +142,18 @@ impl AsyncSystemTable for FunctionsTable {
             })
             .collect::<Vec<&str>>();
 
+        // Aggregate functions and UDFs don't declare their arity, so only
+        // scalar functions have an argument signature.
+        let arguments = (0..names.len())
+            .map(|i| {
+                if i < func_features.len() {
+                    func_features[i].1.arguments_signature()
+                } else {
+                    "".to_string()
+                }
+            })
+            .collect::<Vec<String>>();
+
         let examples = (0..names.len())
             .map(|i| {
                 if i < builtin_func_len {
@@ -139,6 +172,7 @@ impl AsyncSystemTable for FunctionsTable {
             Series::from_data(categorys),
             Series::from_data(descriptions),
             Series::from_data(syntaxs),
+            Series::from_data(arguments),
             Series::from_data(examples),
         ]))
     }
@@ -154,6 +188,7 @@ impl FunctionsTable {
             DataField::new("category", Vu8::to_data_type()),
             DataField::new("description", Vu8::to_data_type()),
             DataField::new("syntax", Vu8::to_data_type()),
+            DataField::new("arguments", Vu8::to_data_type()),
             DataField::new("example", Vu8::to_data_type()),
         ]);
 
@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::clusters::NodeLoadReporter;
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct LoadTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for LoadTable {
+    const NAME: &'static str = "system.load";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let cluster = ctx.get_cluster();
+        let cluster_nodes = cluster.get_nodes();
+        let local_load = ctx.get_current_session().get_session_manager().node_load();
+
+        let mut nodes: Vec<Vec<u8>> = Vec::with_capacity(cluster_nodes.len());
+        let mut http_addresses: Vec<Vec<u8>> = Vec::with_capacity(cluster_nodes.len());
+        let mut cpu_nums: Vec<u64> = Vec::with_capacity(cluster_nodes.len());
+        let mut active_sessions: Vec<u64> = Vec::with_capacity(cluster_nodes.len());
+        let mut running_queries: Vec<u64> = Vec::with_capacity(cluster_nodes.len());
+        let mut max_sessions: Vec<u64> = Vec::with_capacity(cluster_nodes.len());
+        for node in &cluster_nodes {
+            // Remote nodes report their load on heartbeat, the local one is always up to date.
+            let load = match cluster.is_local(node) {
+                true => &local_load,
+                false => &node.load,
+            };
+
+            nodes.push(node.id.as_bytes().to_vec());
+            http_addresses.push(node.http_address.as_bytes().to_vec());
+            cpu_nums.push(node.cpu_nums);
+            active_sessions.push(load.active_sessions);
+            running_queries.push(load.running_queries);
+            max_sessions.push(load.max_sessions);
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(nodes),
+            Series::from_data(http_addresses),
+            Series::from_data(cpu_nums),
+            Series::from_data(active_sessions),
+            Series::from_data(running_queries),
+            Series::from_data(max_sessions),
+        ]))
+    }
+}
+
+impl LoadTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("node", Vu8::to_data_type()),
+            DataField::new("http_address", Vu8::to_data_type()),
+            DataField::new("cpu_nums", u64::to_data_type()),
+            DataField::new("active_sessions", u64::to_data_type()),
+            DataField::new("running_queries", u64::to_data_type()),
+            DataField::new("max_sessions", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'load'".to_string(),
+            name: "load".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemLoad".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(LoadTable { table_info })
+    }
+}
@@ -12,43 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod caches_table;
+mod clustering_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
+mod corrupt_blocks_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod formats_table;
 mod functions_table;
+mod load_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
 mod query_log_table;
+mod query_profile_table;
+mod replications_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod table;
 mod tables_table;
+mod temp_storage_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 
+pub use caches_table::CachesTable;
+pub use clustering_table::ClusteringTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
+pub use corrupt_blocks_table::CorruptBlocksTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
+pub use formats_table::FormatsTable;
 pub use functions_table::FunctionsTable;
+pub use load_table::LoadTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
 pub use query_log_table::QueryLogTable;
+pub use query_profile_table::QueryProfileTable;
+pub use replications_table::ReplicationsTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use tables_table::TablesTable;
+pub use temp_storage_table::TempStorageTable;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
 pub use users_table::UsersTable;
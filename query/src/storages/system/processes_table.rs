@@ -58,6 +58,8 @@ impl AsyncSystemTable for ProcessesTable {
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
+        let mut processes_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_elapsed_ms = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
@@ -79,6 +81,8 @@ impl AsyncSystemTable for ProcessesTable {
             processes_scan_progress_read_rows.push(scan_progress_read_rows);
             processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
+            processes_query_id.push(process_info.query_id.clone().map(|s| s.into_bytes()));
+            processes_elapsed_ms.push(process_info.elapsed.map(|d| d.as_millis() as u64));
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -95,6 +99,8 @@ impl AsyncSystemTable for ProcessesTable {
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
             Series::from_data(processes_mysql_connection_id),
+            Series::from_data(processes_query_id),
+            Series::from_data(processes_elapsed_ms),
         ]))
     }
 }
@@ -115,6 +121,8 @@ impl ProcessesTable {
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
             DataField::new_nullable("mysql_connection_id", u32::to_data_type()),
+            DataField::new_nullable("query_id", Vu8::to_data_type()),
+            DataField::new_nullable("elapsed_ms", u64::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -63,8 +63,10 @@ impl QueryLogTable {
             DataField::new("query_id", Vu8::to_data_type()),
             DataField::new("query_kind", Vu8::to_data_type()),
             DataField::new("query_text", Vu8::to_data_type()),
+            DataField::new("query_tag", Vu8::to_data_type()),
             DataField::new("event_date", DateType::new_impl()),
             DataField::new("event_time", TimestampType::new_impl(3)),
+            DataField::new("query_duration_ms", u64::to_data_type()),
             // Schema.
             DataField::new("current_database", Vu8::to_data_type()),
             DataField::new("databases", Vu8::to_data_type()),
@@ -82,6 +84,11 @@ impl QueryLogTable {
             DataField::new("scan_io_bytes_cost_ms", u64::to_data_type()),
             DataField::new("scan_partitions", u64::to_data_type()),
             DataField::new("total_partitions", u64::to_data_type()),
+            DataField::new("io_requests", u64::to_data_type()),
+            DataField::new("cache_hits", u64::to_data_type()),
+            DataField::new("cache_misses", u64::to_data_type()),
+            DataField::new("network_received_bytes", u64::to_data_type()),
+            DataField::new("spill_bytes", u64::to_data_type()),
             DataField::new("result_rows", u64::to_data_type()),
             DataField::new("result_bytes", u64::to_data_type()),
             DataField::new("cpu_usage", u32::to_data_type()),
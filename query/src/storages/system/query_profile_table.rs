@@ -0,0 +1,118 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct QueryProfileTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryProfileTable {
+    const NAME: &'static str = "system.query_profile";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let profiles = ctx.get_query_profile_manager().get_all();
+
+        let mut query_ids: Vec<Vec<u8>> = vec![];
+        let mut pipeline_ids: Vec<u64> = vec![];
+        let mut processor_ids: Vec<u64> = vec![];
+        let mut processor_names: Vec<Vec<u8>> = vec![];
+        let mut cpu_time_ms: Vec<f64> = vec![];
+        let mut async_time_ms: Vec<f64> = vec![];
+        let mut input_rows: Vec<u64> = vec![];
+        let mut input_wait_ms: Vec<f64> = vec![];
+        let mut output_rows: Vec<u64> = vec![];
+        let mut output_stall_ms: Vec<f64> = vec![];
+        let mut output_fill: Vec<f64> = vec![];
+        for query_profile in &profiles {
+            for (pipeline_id, pipeline) in query_profile.pipelines.iter().enumerate() {
+                for processor in &pipeline.processors {
+                    query_ids.push(query_profile.query_id.as_bytes().to_vec());
+                    pipeline_ids.push(pipeline_id as u64);
+                    processor_ids.push(processor.id as u64);
+                    processor_names.push(processor.name.as_bytes().to_vec());
+                    cpu_time_ms.push(processor.cpu_time.as_secs_f64() * 1000.0);
+                    async_time_ms.push(processor.async_time.as_secs_f64() * 1000.0);
+                    input_rows.push(processor.input_rows);
+                    input_wait_ms.push(processor.input_wait_time.as_secs_f64() * 1000.0);
+                    output_rows.push(processor.output_rows);
+                    output_stall_ms.push(processor.output_stall_time.as_secs_f64() * 1000.0);
+                    output_fill.push(pipeline.output_fill(processor));
+                }
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(query_ids),
+            Series::from_data(pipeline_ids),
+            Series::from_data(processor_ids),
+            Series::from_data(processor_names),
+            Series::from_data(cpu_time_ms),
+            Series::from_data(async_time_ms),
+            Series::from_data(input_rows),
+            Series::from_data(input_wait_ms),
+            Series::from_data(output_rows),
+            Series::from_data(output_stall_ms),
+            Series::from_data(output_fill),
+        ]))
+    }
+}
+
+impl QueryProfileTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("pipeline_id", u64::to_data_type()),
+            DataField::new("processor_id", u64::to_data_type()),
+            DataField::new("processor_name", Vu8::to_data_type()),
+            DataField::new("cpu_time_ms", f64::to_data_type()),
+            DataField::new("async_time_ms", f64::to_data_type()),
+            DataField::new("input_rows", u64::to_data_type()),
+            DataField::new("input_wait_ms", f64::to_data_type()),
+            DataField::new("output_rows", u64::to_data_type()),
+            DataField::new("output_stall_ms", f64::to_data_type()),
+            DataField::new("output_fill", f64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_profile'".to_string(),
+            name: "query_profile".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryProfile".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(QueryProfileTable { table_info })
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::sessions::QueryContext;
+use crate::sql::OPT_KEY_REPLICATE_TO;
+use crate::storages::fuse::replication::ReplicationState;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+pub struct ReplicationsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ReplicationsTable {
+    const NAME: &'static str = "system.replications";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let manager = ctx.get_replication_manager();
+
+        let mut databases: Vec<Vec<u8>> = vec![];
+        let mut tables: Vec<Vec<u8>> = vec![];
+        let mut targets: Vec<Vec<u8>> = vec![];
+        let mut states: Vec<Vec<u8>> = vec![];
+        let mut snapshot_ids: Vec<Option<Vec<u8>>> = vec![];
+        let mut replicated_ats: Vec<Option<Vec<u8>>> = vec![];
+        let mut lag_commits: Vec<u64> = vec![];
+        let mut lag_seconds: Vec<u64> = vec![];
+        let mut last_errors: Vec<Option<Vec<u8>>> = vec![];
+
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            let database_name = database.name();
+            for table in catalog.list_tables(tenant.as_str(), database_name).await? {
+                let table_info = table.get_table_info();
+                let target = match table_info.options().get(OPT_KEY_REPLICATE_TO) {
+                    None => continue,
+                    Some(target) => target,
+                };
+
+                // tables which have not been committed since the server started
+                let status = manager.get_status(table_info.ident.table_id);
+                let checkpoint = status.as_ref().and_then(|s| s.checkpoint.as_ref());
+
+                databases.push(database_name.as_bytes().to_vec());
+                tables.push(table.name().as_bytes().to_vec());
+                targets.push(target.as_bytes().to_vec());
+                states.push(
+                    status
+                        .as_ref()
+                        .map(|s| s.state)
+                        .unwrap_or(ReplicationState::Idle)
+                        .to_string()
+                        .into_bytes(),
+                );
+                snapshot_ids
+                    .push(checkpoint.map(|c| c.snapshot_id.to_simple().to_string().into_bytes()));
+                replicated_ats.push(checkpoint.map(|c| {
+                    c.replicated_at
+                        .format("%Y-%m-%d %H:%M:%S.%3f %z")
+                        .to_string()
+                        .into_bytes()
+                }));
+                lag_commits.push(status.as_ref().map(|s| s.lag_commits()).unwrap_or(0));
+                lag_seconds.push(status.as_ref().map(|s| s.lag_seconds()).unwrap_or(0));
+                last_errors.push(
+                    status
+                        .as_ref()
+                        .and_then(|s| s.last_error.clone())
+                        .map(|e| e.into_bytes()),
+                );
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(targets),
+            Series::from_data(states),
+            Series::from_data(snapshot_ids),
+            Series::from_data(replicated_ats),
+            Series::from_data(lag_commits),
+            Series::from_data(lag_seconds),
+            Series::from_data(last_errors),
+        ]))
+    }
+}
+
+impl ReplicationsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("target", Vu8::to_data_type()),
+            DataField::new("state", Vu8::to_data_type()),
+            DataField::new_nullable("replicated_snapshot_id", Vu8::to_data_type()),
+            DataField::new_nullable("replicated_at", Vu8::to_data_type()),
+            DataField::new("lag_commits", u64::to_data_type()),
+            DataField::new("lag_seconds", u64::to_data_type()),
+            DataField::new_nullable("last_error", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'replications'".to_string(),
+            name: "replications".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemReplications".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(ReplicationsTable { table_info })
+    }
+}
@@ -40,15 +40,15 @@ impl AsyncSystemTable for TablesTable {
     }
 
     async fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
-        let tenant = ctx.get_tenant();
         // TODO pass catalog in or embed catalog in table info?
         let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
+        let snapshot = ctx.get_catalog_snapshot(CATALOG_DEFAULT).await?;
+        let databases = snapshot.list_databases();
 
         let mut database_tables = vec![];
         for database in databases {
             let name = database.name();
-            for table in catalog.list_tables_history(tenant.as_str(), name).await? {
+            for table in snapshot.list_tables_history(name).unwrap_or_default() {
                 database_tables.push((name.to_string(), table));
             }
         }
@@ -59,7 +59,10 @@ impl AsyncSystemTable for TablesTable {
         let mut index_size: Vec<Option<u64>> = Vec::new();
 
         for (_, tbl) in &database_tables {
-            let stats = tbl.statistics(ctx.clone()).await?;
+            let stats = match catalog.get_table_engine(tbl.engine()) {
+                Some(engine) if engine.support_statistics => tbl.statistics(ctx.clone()).await?,
+                _ => None,
+            };
             num_rows.push(stats.as_ref().and_then(|v| v.num_rows));
             data_size.push(stats.as_ref().and_then(|v| v.data_size));
             data_compressed_size.push(stats.and_then(|v| v.data_size_compressed));
@@ -0,0 +1,96 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::QueryContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+pub struct TempStorageTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for TempStorageTable {
+    const NAME: &'static str = "system.temp_storage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<QueryContext>) -> Result<DataBlock> {
+        let manager = ctx.get_temp_storage_manager();
+        let mut usages = manager.usages();
+        usages.sort_by(|a, b| (&a.user, &a.query_id).cmp(&(&b.user, &b.query_id)));
+
+        let mut users: Vec<Vec<u8>> = Vec::with_capacity(usages.len());
+        let mut session_ids: Vec<Vec<u8>> = Vec::with_capacity(usages.len());
+        let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(usages.len());
+        let mut used_bytes: Vec<u64> = Vec::with_capacity(usages.len());
+        let mut user_used_bytes: Vec<u64> = Vec::with_capacity(usages.len());
+        let mut user_quota_bytes: Vec<u64> = Vec::with_capacity(usages.len());
+        for usage in &usages {
+            users.push(usage.user.as_bytes().to_vec());
+            session_ids.push(usage.session_id.as_bytes().to_vec());
+            query_ids.push(usage.query_id.as_bytes().to_vec());
+            used_bytes.push(usage.used_bytes);
+            user_used_bytes.push(manager.user_used_bytes(&usage.user));
+            user_quota_bytes.push(manager.quota_per_user());
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(users),
+            Series::from_data(session_ids),
+            Series::from_data(query_ids),
+            Series::from_data(used_bytes),
+            Series::from_data(user_used_bytes),
+            Series::from_data(user_quota_bytes),
+        ]))
+    }
+}
+
+impl TempStorageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("user", Vu8::to_data_type()),
+            DataField::new("session_id", Vu8::to_data_type()),
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("used_bytes", u64::to_data_type()),
+            DataField::new("user_used_bytes", u64::to_data_type()),
+            DataField::new("user_quota_bytes", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'temp_storage'".to_string(),
+            name: "temp_storage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTempStorage".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(TempStorageTable { table_info })
+    }
+}
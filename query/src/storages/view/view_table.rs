@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -30,6 +31,8 @@ pub struct ViewTable {
 
 pub const VIEW_ENGINE: &str = "VIEW";
 pub const QUERY: &str = "query";
+/// The tables the query of the view reads, as `[database, table]` pairs in the catalog of the view.
+pub const DEPENDENCIES: &str = "dependencies";
 
 impl ViewTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
@@ -62,6 +65,28 @@ impl ViewTable {
     }
 }
 
+/// Returns the `CREATE VIEW` statement of a view, with its query as it was written.
+pub fn show_create_view(name: &str, options: &BTreeMap<String, String>) -> Result<String> {
+    match options.get(QUERY) {
+        Some(query) => Ok(format!(
+            "CREATE VIEW `{}` AS {}",
+            name.replace('`', "``"),
+            query
+        )),
+        None => Err(ErrorCode::LogicalError(
+            "Logical error: the query of the view is missing",
+        )),
+    }
+}
+
+/// Returns the tables a view depends on, views created before dependencies were recorded have none.
+pub fn view_dependencies(options: &BTreeMap<String, String>) -> Result<Vec<(String, String)>> {
+    match options.get(DEPENDENCIES) {
+        Some(dependencies) => Ok(serde_json::from_str(dependencies)?),
+        None => Ok(vec![]),
+    }
+}
+
 #[async_trait::async_trait]
 impl Table for ViewTable {
     fn as_any(&self) -> &dyn Any {
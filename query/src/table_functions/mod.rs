@@ -18,12 +18,15 @@ mod memory_block_part;
 mod numbers_part;
 mod numbers_stream;
 mod numbers_table;
+mod result_scan_table;
 mod table_function;
 mod table_function_factory;
 
 pub use memory_block_part::generate_numbers_parts;
 pub use numbers_part::NumbersPartInfo;
 pub use numbers_table::NumbersTable;
+pub use result_scan_table::ResultScanTable;
+pub use result_scan_table::RESULT_SCAN_FUNC_NAME;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableArgs;
 pub use table_function_factory::TableFunctionFactory;
@@ -269,11 +269,12 @@ impl SyncSource for NumbersSource {
                 let step = std::cmp::min(remain_size, self.step);
                 let column_data = (self.begin..self.begin + step).collect();
 
+                // The first number orders the blocks of all the parts.
+                let sequence = self.begin;
                 self.begin += step;
                 let column = UInt64Column::new_from_vec(column_data);
-                Ok(Some(DataBlock::create(self.schema.clone(), vec![
-                    Arc::new(column),
-                ])))
+                let block = DataBlock::create(self.schema.clone(), vec![Arc::new(column)]);
+                Ok(Some(block.with_sequence(Some(sequence))))
             }
         }
     }
@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::infallible::Mutex;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::SYS_TBL_FUC_ID_END;
+use crate::pipelines::new::processors::port::OutputPort;
+use crate::pipelines::new::processors::BlocksSource;
+use crate::pipelines::new::NewPipe;
+use crate::pipelines::new::NewPipeline;
+use crate::sessions::CachedQueryResult;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::storages::fuse::table_functions::string_literal;
+use crate::storages::fuse::table_functions::string_value;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const RESULT_SCAN_FUNC_NAME: &str = "result_scan";
+
+// RESULT_SCAN is not registered in the table function factory, it takes the last id of the range.
+const RESULT_SCAN_TABLE_ID: u64 = SYS_TBL_FUC_ID_END - 1;
+
+/// RESULT_SCAN('query_id') reads the result of a finished query of the current session.
+pub struct ResultScanTable {
+    table_info: TableInfo,
+    result: Arc<CachedQueryResult>,
+}
+
+impl ResultScanTable {
+    pub fn create(session: &Session, table_args: TableArgs) -> Result<Arc<dyn TableFunction>> {
+        let query_id = match table_args {
+            Some(args) if args.len() == 1 => string_value(&args[0])?,
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "RESULT_SCAN expects exactly one argument, the query id",
+                ));
+            }
+        };
+
+        let result = session.get_query_result(&query_id).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The result of query '{}' is not found in the current session",
+                query_id
+            ))
+        })?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(RESULT_SCAN_TABLE_ID, 0),
+            desc: format!("'system'.'{}'", RESULT_SCAN_FUNC_NAME),
+            name: RESULT_SCAN_FUNC_NAME.to_string(),
+            meta: TableMeta {
+                schema: result.schema.clone(),
+                engine: RESULT_SCAN_FUNC_NAME.to_string(),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(ResultScanTable { table_info, result }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ResultScanTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let rows = self.result.blocks.iter().map(|b| b.num_rows()).sum();
+        let statistics = Statistics::new_exact(rows, self.result.bytes, 0, 0);
+        Ok((statistics, vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![string_literal(&self.result.query_id)])
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(
+            self.result.schema.clone(),
+            None,
+            self.result.blocks.clone(),
+        )))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<QueryContext>,
+        _: &ReadDataSourcePlan,
+        pipeline: &mut NewPipeline,
+    ) -> Result<()> {
+        let blocks = VecDeque::from(self.result.blocks.clone());
+        let output = OutputPort::create();
+        pipeline.add_pipe(NewPipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![BlocksSource::create(
+                ctx,
+                output,
+                Arc::new(Mutex::new(blocks)),
+            )?],
+        });
+
+        Ok(())
+    }
+}
+
+impl TableFunction for ResultScanTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -97,9 +97,16 @@ impl TableFunctionFactory {
             (next_id(), number_table_func_creator),
         );
 
+        let fuse_snapshot_func_creator: Arc<dyn TableFunctionCreator> =
+            Arc::new(FuseSnapshotTable::create);
+
         creators.insert(
             "fuse_snapshot".to_string(),
-            (next_id(), Arc::new(FuseSnapshotTable::create)),
+            (next_id(), fuse_snapshot_func_creator.clone()),
+        );
+        creators.insert(
+            "table_snapshots".to_string(),
+            (next_id(), fuse_snapshot_func_creator),
         );
         creators.insert(
             "fuse_segment".to_string(),
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
+use common_meta_types::PasswordHashMethod;
 use common_meta_types::UserInfo;
 
 use crate::users::auth::jwt::JwtAuthenticator;
@@ -39,6 +40,13 @@ pub enum Credential {
         password: Option<Vec<u8>>,
         hostname: Option<String>,
     },
+    /// The scrambled password of the MySQL authentication plugins.
+    MySQL {
+        name: String,
+        salt: Vec<u8>,
+        scramble: Vec<u8>,
+        hostname: Option<String>,
+    },
 }
 
 impl AuthMgr {
@@ -102,25 +110,76 @@ impl AuthMgr {
                         h.as_ref().unwrap_or(&"%".to_string()),
                     )
                     .await?;
-                let user_info = match &user.auth_info {
-                    AuthInfo::None => Ok(user),
+                let authenticated = match &user.auth_info {
+                    AuthInfo::None => Ok(true),
                     AuthInfo::Password {
                         hash_value: h,
                         hash_method: t,
                     } => match p {
                         None => Err(ErrorCode::AuthenticateFailure("password required")),
-                        Some(p) => {
-                            if *h == t.hash(p) {
-                                Ok(user)
-                            } else {
-                                Err(ErrorCode::AuthenticateFailure("wrong password"))
-                            }
-                        }
+                        Some(p) => Ok(t.verify(p, h)),
                     },
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 }?;
-                Ok((None, user_info))
+                // The full authentication of caching_sha2_password, the following logins of
+                // the user over MySQL can use the fast one.
+                if let (true, Some(PasswordHashMethod::Sha256), Some(p)) =
+                    (authenticated, user.auth_info.get_password_type(), p)
+                {
+                    self.user_mgr
+                        .set_caching_sha2_digest(&self.tenant, &user, p);
+                }
+                self.check_password_policy(user, authenticated).await
             }
+            Credential::MySQL {
+                name: n,
+                salt: s,
+                scramble: p,
+                hostname: h,
+            } => {
+                let user = self
+                    .user_mgr
+                    .get_user_with_client_ip(
+                        &self.tenant,
+                        n,
+                        h.as_ref().unwrap_or(&"%".to_string()),
+                    )
+                    .await?;
+                let authenticated = match user.auth_info.get_password_type() {
+                    // The fast authentication of caching_sha2_password, the digest is only
+                    // cached once the user passed the full authentication.
+                    Some(PasswordHashMethod::Sha256) => {
+                        let digest = self
+                            .user_mgr
+                            .get_caching_sha2_digest(&self.tenant, &user)
+                            .ok_or_else(|| {
+                                ErrorCode::AuthenticateFailure(
+                                    "caching_sha2_password requires the full authentication",
+                                )
+                            })?;
+                        AuthInfo::auth_caching_sha2_mysql(&digest, p, s)?
+                    }
+                    _ => user.auth_info.auth_mysql(p, s)?,
+                };
+                self.check_password_policy(user, authenticated).await
+            }
+        }
+    }
+
+    // Enforce the lockout and the expiry of the password policy around a password check.
+    async fn check_password_policy(
+        &self,
+        user: UserInfo,
+        authenticated: bool,
+    ) -> Result<(Option<String>, UserInfo)> {
+        let identity = user.identity();
+        self.user_mgr.check_login(&self.tenant, &identity).await?;
+        self.user_mgr
+            .record_login_attempt(&self.tenant, &identity, authenticated)
+            .await?;
+        match authenticated {
+            true => Ok((None, user)),
+            false => Err(ErrorCode::AuthenticateFailure("wrong password")),
         }
     }
 }
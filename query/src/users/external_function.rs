@@ -0,0 +1,229 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_base::base::tokio::time::Duration;
+use common_base::infallible::RwLock;
+use common_datablocks::DataBlock;
+use common_datavalues::combine_validities_2;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::cast_with_type;
+use common_functions::scalars::Function;
+use common_functions::scalars::FunctionContext;
+use common_functions::scalars::FunctionDescription;
+use common_functions::scalars::FunctionFactory;
+use common_functions::scalars::FunctionFeatures;
+use common_functions::scalars::DEFAULT_CAST_OPTIONS;
+use once_cell::sync::Lazy;
+
+use crate::api::UDFFlightClient;
+
+// The registered external functions by their lowercase registered names.
+static EXTERNAL_FUNCTIONS: Lazy<RwLock<HashMap<String, ExternalFunction>>> =
+    Lazy::new(Default::default);
+
+/// A user-defined function served by the handler of an external UDF server, see `UDFFlightClient`.
+///
+/// The calls are evaluated by `TransformExternalFunction` without blocking the executor, the
+/// arguments are sent in batches of at most `batch_rows` rows, NULLs never reach the server.
+#[derive(Clone)]
+pub struct ExternalFunction {
+    display_name: String,
+    handler: String,
+    address: String,
+    arg_types: Vec<DataTypeImpl>,
+    return_type: DataTypeImpl,
+    batch_rows: usize,
+    timeout: Duration,
+}
+
+impl ExternalFunction {
+    pub fn create(
+        name: &str,
+        handler: &str,
+        address: &str,
+        arg_types: Vec<DataTypeImpl>,
+        return_type: DataTypeImpl,
+        batch_rows: usize,
+        timeout: Duration,
+    ) -> ExternalFunction {
+        ExternalFunction {
+            display_name: name.to_string(),
+            handler: handler.to_string(),
+            address: address.to_string(),
+            arg_types,
+            return_type,
+            batch_rows: batch_rows.max(1),
+            timeout,
+        }
+    }
+
+    /// Register the function to the function factory with the name.
+    pub fn register(self, registered_name: &str) {
+        let function = self.clone();
+        let creator = move |_display_name: &str, _args: &[&DataTypeImpl]| {
+            Ok(Box::new(function.clone()) as Box<dyn Function>)
+        };
+
+        let desc = FunctionDescription::creator(Box::new(creator))
+            .features(
+                FunctionFeatures::default()
+                    .num_arguments(self.arg_types.len())
+                    .category("UDF")
+                    .description("External user-defined function"),
+            )
+            .implicit_cast_arguments(self.arg_types.clone());
+
+        FunctionFactory::instance().register_user_function(registered_name, desc);
+        EXTERNAL_FUNCTIONS
+            .write()
+            .insert(registered_name.to_lowercase(), self);
+    }
+
+    /// The external function registered with the name, if any.
+    pub fn get(registered_name: &str) -> Option<ExternalFunction> {
+        EXTERNAL_FUNCTIONS
+            .read()
+            .get(&registered_name.to_lowercase())
+            .cloned()
+    }
+
+    /// Call the handler with the argument columns of the rows, `result_type` is the type of the
+    /// call, which is nullable if any of the arguments is. A row is NULL without being sent if any
+    /// of its arguments is NULL.
+    pub async fn eval_async(
+        &self,
+        func_ctx: &FunctionContext,
+        columns: &[ColumnWithField],
+        input_rows: usize,
+        result_type: &DataTypeImpl,
+    ) -> Result<ColumnRef> {
+        if result_type.data_type_id() == TypeID::Null {
+            return Ok(NullColumn::new(input_rows).arc());
+        }
+
+        let mut validity: Option<Bitmap> = None;
+        let mut args = Vec::with_capacity(columns.len());
+        for column in columns {
+            let full_column = column.column().convert_full_column();
+            let (_, valid) = full_column.validity();
+            validity = combine_validities_2(validity, valid.cloned());
+            args.push((
+                Series::remove_nullable(&full_column),
+                remove_nullable(column.data_type()),
+            ));
+        }
+
+        let null_count = validity.as_ref().map_or(0, |v| v.null_count());
+        let column = if null_count == input_rows {
+            self.return_type
+                .create_constant_column(&self.return_type.default_value(), input_rows)?
+                .convert_full_column()
+        } else if null_count > 0 {
+            let validity = validity.as_ref().unwrap();
+            let filter = BooleanColumn::from_arrow_data(validity.clone());
+            let args = args
+                .into_iter()
+                .map(|(column, data_type)| (column.filter(&filter), data_type))
+                .collect::<Vec<_>>();
+            let column = self.call(func_ctx, args).await?;
+
+            // offsets[i] is the end of the rows replicated from the i-th valid row, the values of
+            // the NULL rows are masked out by the validity.
+            let mut offsets = validity
+                .iter()
+                .enumerate()
+                .filter(|(_, valid)| *valid)
+                .skip(1)
+                .map(|(row, _)| row)
+                .collect::<Vec<_>>();
+            offsets.push(input_rows);
+            column.replicate(&offsets)
+        } else {
+            self.call(func_ctx, args).await?
+        };
+
+        match result_type.is_nullable() {
+            true => Ok(NullableColumn::wrap_inner(column, validity)),
+            false => Ok(column),
+        }
+    }
+
+    async fn call(
+        &self,
+        func_ctx: &FunctionContext,
+        args: Vec<(ColumnRef, DataTypeImpl)>,
+    ) -> Result<ColumnRef> {
+        let mut fields = Vec::with_capacity(args.len());
+        let mut columns = Vec::with_capacity(args.len());
+        for (index, ((column, data_type), arg_type)) in
+            args.iter().zip(self.arg_types.iter()).enumerate()
+        {
+            let column =
+                cast_with_type(column, data_type, arg_type, &DEFAULT_CAST_OPTIONS, func_ctx)?;
+            fields.push(DataField::new(
+                &format!("arg{}", index + 1),
+                arg_type.clone(),
+            ));
+            columns.push(column);
+        }
+
+        let block = DataBlock::create(DataSchemaRefExt::create(fields), columns);
+        let blocks = DataBlock::split_block_by_size(&block, self.batch_rows)?;
+
+        let result = match UDFFlightClient::connect(&self.address, self.timeout).await {
+            Ok(mut client) => client.call(&self.handler, blocks, &self.return_type).await,
+            Err(cause) => Err(cause),
+        };
+        result.map_err(|e| {
+            e.add_message_back(format!(
+                " (while calling external function {})",
+                self.name()
+            ))
+        })
+    }
+}
+
+impl Function for ExternalFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.return_type.clone()
+    }
+
+    fn eval(
+        &self,
+        _func_ctx: FunctionContext,
+        _columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        Err(ErrorCode::LogicalError(format!(
+            "External function {} must be evaluated by TransformExternalFunction",
+            self.name()
+        )))
+    }
+}
+
+impl fmt::Display for ExternalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod external_function;
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_auth_cache;
 mod user_mgr;
+mod user_password_policy;
+mod user_setting;
 mod user_stage;
 mod user_udf;
 
@@ -24,7 +28,9 @@ pub mod role_cache_mgr;
 
 pub use auth::auth_mgr::AuthMgr;
 pub use auth::auth_mgr::Credential;
+pub use external_function::ExternalFunction;
 pub use role_cache_mgr::RoleCacheMgr;
 pub use user::CertifiedInfo;
 pub use user::User;
 pub use user_api::UserApiProvider;
+pub use user_udf::register_udf_function;
@@ -12,13 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::infallible::RwLock;
 use common_exception::Result;
+use common_management::CopyApi;
+use common_management::CopyMgr;
+use common_management::LoadApi;
+use common_management::LoadMgr;
+use common_management::PasswordPolicyApi;
+use common_management::PasswordPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::SettingApi;
+use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
 use common_management::UdfApi;
@@ -28,10 +39,13 @@ use common_management::UserMgr;
 use common_meta_api::KVApi;
 
 use crate::common::MetaStoreProvider;
+use crate::users::user_auth_cache::CachedSha2Digest;
 use crate::Config;
 
 pub struct UserApiProvider {
     client: Arc<dyn KVApi>,
+    // Only held in memory, the digests are equivalent to the passwords for the fast authentication.
+    pub(crate) caching_sha2_digests: RwLock<HashMap<String, CachedSha2Digest>>,
 }
 
 impl UserApiProvider {
@@ -41,6 +55,7 @@ impl UserApiProvider {
             .await?;
         Ok(Arc::new(UserApiProvider {
             client: client.arc(),
+            caching_sha2_digests: RwLock::new(HashMap::new()),
         }))
     }
 
@@ -63,4 +78,30 @@ impl UserApiProvider {
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
+
+    pub fn get_password_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn PasswordPolicyApi>> {
+        Ok(Arc::new(PasswordPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_setting_api_client(&self, tenant: &str) -> Result<Arc<dyn SettingApi>> {
+        Ok(Arc::new(SettingMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_copy_api_client(&self, tenant: &str) -> Result<Arc<dyn CopyApi>> {
+        Ok(Arc::new(CopyMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_load_api_client(&self, tenant: &str, lease: Duration) -> Result<Arc<dyn LoadApi>> {
+        Ok(Arc::new(LoadMgr::create(
+            self.client.clone(),
+            tenant,
+            lease,
+        )?))
+    }
 }
@@ -0,0 +1,53 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
+
+use crate::users::UserApiProvider;
+
+/// The SHA256( SHA256( password ) ) of a user that passed the full authentication, with the
+/// stored hash of the password it was checked against.
+pub(crate) struct CachedSha2Digest {
+    hash_value: Vec<u8>,
+    digest: Vec<u8>,
+}
+
+impl UserApiProvider {
+    // The digest cached for the caching_sha2_password fast authentication of the user,
+    // it is ignored once the password of the user has been changed.
+    pub fn get_caching_sha2_digest(&self, tenant: &str, user: &UserInfo) -> Option<Vec<u8>> {
+        let hash_value = user.auth_info.get_password()?;
+        let cached = self.caching_sha2_digests.read();
+        match cached.get(&Self::caching_sha2_key(tenant, user)) {
+            Some(cached) if cached.hash_value == hash_value => Some(cached.digest.clone()),
+            _ => None,
+        }
+    }
+
+    // Cache the digest of a password that passed the full authentication of the user.
+    pub fn set_caching_sha2_digest(&self, tenant: &str, user: &UserInfo, password: &[u8]) {
+        if let Some(hash_value) = user.auth_info.get_password() {
+            let mut cached = self.caching_sha2_digests.write();
+            cached.insert(Self::caching_sha2_key(tenant, user), CachedSha2Digest {
+                hash_value,
+                digest: AuthInfo::caching_sha2_digest(password),
+            });
+        }
+    }
+
+    fn caching_sha2_key(tenant: &str, user: &UserInfo) -> String {
+        format!("{}/{}", tenant, user.identity())
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::PasswordPolicy;
+use common_meta_types::UserIdentity;
+use common_meta_types::UserPasswordState;
+
+use crate::users::UserApiProvider;
+
+// The max times to retry updating the password state on concurrent updates.
+const UPDATE_PASSWORD_STATE_MAX_RETRIES: usize = 10;
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// password policy operations.
+impl UserApiProvider {
+    // Get the password policy of the tenant, the default policy disables all the rules.
+    pub async fn get_password_policy(&self, tenant: &str) -> Result<PasswordPolicy> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let get_policy = client.get_policy(None);
+        match get_policy.await {
+            Err(e) => Err(e.add_message_back("(while get password policy).")),
+            Ok(policy) => Ok(policy.data),
+        }
+    }
+
+    // Set the password policy of the tenant, overwrite the old one if any.
+    pub async fn set_password_policy(&self, tenant: &str, policy: &PasswordPolicy) -> Result<u64> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let set_policy = client.set_policy(policy, None);
+        match set_policy.await {
+            Err(e) => Err(e.add_message_back("(while set password policy).")),
+            Ok(seq) => Ok(seq),
+        }
+    }
+
+    // Check the length and the complexity of a new plaintext password.
+    pub async fn check_password(&self, tenant: &str, password: &[u8]) -> Result<()> {
+        let password = String::from_utf8_lossy(password);
+        self.get_password_policy(tenant)
+            .await?
+            .check_password(&password)
+    }
+
+    // Record the new password of the user, rejects a password in the history.
+    pub async fn change_password(
+        &self,
+        tenant: &str,
+        user: &UserIdentity,
+        auth_info: &AuthInfo,
+    ) -> Result<()> {
+        let hash_value = match auth_info {
+            AuthInfo::Password { hash_value, .. } => hash_value,
+            _ => return Ok(()),
+        };
+
+        let policy = self.get_password_policy(tenant).await?;
+        let now = now_secs();
+        self.update_password_state(tenant, user, |state| {
+            state.change_password(&policy, hash_value, now)
+        })
+        .await
+    }
+
+    // Drop the password history and the login failures of a dropped user.
+    pub async fn drop_password_state(&self, tenant: &str, user: &UserIdentity) -> Result<()> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        client.drop_password_state(user).await
+    }
+
+    // Reject the login if the user is locked or the password is expired.
+    pub async fn check_login(&self, tenant: &str, user: &UserIdentity) -> Result<()> {
+        if user.is_root() {
+            return Ok(());
+        }
+
+        let policy = self.get_password_policy(tenant).await?;
+        if policy == PasswordPolicy::default() {
+            return Ok(());
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        let state = client.get_password_state(user).await?.data;
+        let now = now_secs();
+        if policy.is_locked(&state, now) {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "user {} is locked for too many failed login attempts, retry in {} seconds",
+                user,
+                state.locked_until - now
+            )));
+        }
+        if policy.is_expired(&state, now) {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "the password of user {} is expired, ask the administrator to reset it",
+                user
+            )));
+        }
+        Ok(())
+    }
+
+    // Record a login attempt, locks the user after `max_retries` continuous failures.
+    pub async fn record_login_attempt(
+        &self,
+        tenant: &str,
+        user: &UserIdentity,
+        success: bool,
+    ) -> Result<()> {
+        if user.is_root() {
+            return Ok(());
+        }
+
+        let policy = self.get_password_policy(tenant).await?;
+        if policy.max_retries == 0 {
+            return Ok(());
+        }
+
+        let now = now_secs();
+        self.update_password_state(tenant, user, |state| {
+            state.login_attempt(&policy, success, now);
+            Ok(())
+        })
+        .await
+    }
+
+    // Update the password state of the user by the seq it is read with, so that the concurrent
+    // updates (e.g. the failed login attempts) don't overwrite each other.
+    async fn update_password_state<F>(
+        &self,
+        tenant: &str,
+        user: &UserIdentity,
+        update: F,
+    ) -> Result<()>
+    where
+        F: Fn(&mut UserPasswordState) -> Result<()>,
+    {
+        let client = self.get_password_policy_api_client(tenant)?;
+        for _ in 0..UPDATE_PASSWORD_STATE_MAX_RETRIES {
+            let state = client.get_password_state(user).await?;
+            let mut new_state = state.data.clone();
+            update(&mut new_state)?;
+            if new_state == state.data {
+                return Ok(());
+            }
+
+            match client
+                .set_password_state(user, &new_state, Some(state.seq))
+                .await
+            {
+                Ok(_) => return Ok(()),
+                Err(e) if e.code() == ErrorCode::PasswordPolicyUnknown("").code() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(ErrorCode::PasswordPolicyUnknown(format!(
+            "update the password state of user {} failed after {} retries on concurrent updates",
+            user, UPDATE_PASSWORD_STATE_MAX_RETRIES
+        )))
+    }
+}
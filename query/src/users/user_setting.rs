@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserSetting;
+
+use crate::users::UserApiProvider;
+
+/// user global setting operations.
+impl UserApiProvider {
+    // Set a global setting, overwrite the old value if any.
+    pub async fn set_setting(&self, tenant: &str, setting: UserSetting) -> Result<u64> {
+        let setting_api_provider = self.get_setting_api_client(tenant)?;
+        let set_setting = setting_api_provider.set_setting(setting);
+        match set_setting.await {
+            Err(e) => Err(e.add_message_back("(while set setting).")),
+            Ok(seq) => Ok(seq),
+        }
+    }
+
+    // Get all the global settings of the tenant.
+    pub async fn get_settings(&self, tenant: &str) -> Result<Vec<UserSetting>> {
+        let setting_api_provider = self.get_setting_api_client(tenant)?;
+        let get_settings = setting_api_provider.get_settings();
+        match get_settings.await {
+            Err(e) => Err(e.add_message_back("(while get settings).")),
+            Ok(settings) => Ok(settings),
+        }
+    }
+
+    // Drop a global setting by name.
+    pub async fn drop_setting(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let setting_api_provider = self.get_setting_api_client(tenant)?;
+        let drop_setting = setting_api_provider.drop_setting(name, None);
+        match drop_setting.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::unknown_variable_code() {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop setting)"))
+                }
+            }
+        }
+    }
+}
@@ -12,10 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use common_base::base::tokio::time::Duration;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::TypeFactory;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+#[cfg(feature = "wasm-udf")]
+use common_functions::scalars::WasmFunction;
+#[cfg(feature = "wasm-udf")]
+use common_functions::scalars::WasmLimits;
+use common_meta_types::UDFLanguage;
 use common_meta_types::UserDefinedFunction;
 
+use crate::sessions::QueryContext;
+use crate::sessions::Settings;
+use crate::users::external_function::ExternalFunction;
 use crate::users::UserApiProvider;
 
 /// UDF operations.
@@ -85,3 +101,125 @@ impl UserApiProvider {
         }
     }
 }
+
+/// Register a WASM or external UDF to the function factory, returns the name it is registered with.
+/// The name covers the definition, the signature and the settings the function depends on, so a
+/// replaced function or changed settings never hit a stale registration.
+pub fn register_udf_function(udf: &UserDefinedFunction, ctx: &QueryContext) -> Result<String> {
+    match udf.language {
+        UDFLanguage::Wasm => register_wasm_udf(udf, &ctx.get_settings()),
+        UDFLanguage::Python => register_external_udf(udf, ctx),
+        UDFLanguage::Sql => Err(ErrorCode::LogicalError(format!(
+            "SQL function {} is expanded by the planner",
+            udf.name
+        ))),
+    }
+}
+
+fn register_external_udf(udf: &UserDefinedFunction, ctx: &QueryContext) -> Result<String> {
+    if udf.arg_types.is_empty() {
+        return Err(ErrorCode::IllegalUDFFormat(format!(
+            "External function {} requires at least one parameter",
+            udf.name
+        )));
+    }
+
+    let settings = ctx.get_settings();
+    if settings.is_sandboxed() {
+        return Err(ErrorCode::PermissionDenied(format!(
+            "Access denied for external function {} in sandbox",
+            udf.name
+        )));
+    }
+
+    let allowed_addresses = ctx.get_config().query.external_udf_allowed_addresses;
+    if !allowed_addresses
+        .split(',')
+        .map(str::trim)
+        .any(|address| !address.is_empty() && address == udf.address)
+    {
+        return Err(ErrorCode::PermissionDenied(format!(
+            "The address {} of external function {} is not in query.external_udf_allowed_addresses",
+            udf.address, udf.name
+        )));
+    }
+
+    let batch_rows = settings.get_external_udf_batch_rows()?;
+    let timeout_secs = settings.get_external_udf_timeout_secs()?;
+
+    let mut hasher = DefaultHasher::new();
+    udf.name.hash(&mut hasher);
+    udf.handler.hash(&mut hasher);
+    udf.address.hash(&mut hasher);
+    udf.arg_types.hash(&mut hasher);
+    udf.return_type.hash(&mut hasher);
+    batch_rows.hash(&mut hasher);
+    timeout_secs.hash(&mut hasher);
+    let registered_name = format!("{}${:x}", udf.name, hasher.finish());
+
+    if !FunctionFactory::instance().check_user_function(&registered_name) {
+        let (arg_types, return_type) = resolve_udf_types(udf)?;
+        ExternalFunction::create(
+            &udf.name,
+            &udf.handler,
+            &udf.address,
+            arg_types,
+            return_type,
+            batch_rows as usize,
+            Duration::from_secs(timeout_secs),
+        )
+        .register(&registered_name);
+    }
+
+    Ok(registered_name)
+}
+
+fn resolve_udf_types(udf: &UserDefinedFunction) -> Result<(Vec<DataTypeImpl>, DataTypeImpl)> {
+    let type_factory = TypeFactory::instance();
+    let arg_types = udf
+        .arg_types
+        .iter()
+        .map(|name| type_factory.get(name).cloned())
+        .collect::<Result<Vec<_>>>()?;
+    let return_type = type_factory.get(&udf.return_type)?.clone();
+    Ok((arg_types, return_type))
+}
+
+#[cfg(feature = "wasm-udf")]
+fn register_wasm_udf(udf: &UserDefinedFunction, settings: &Settings) -> Result<String> {
+    let limits = WasmLimits {
+        fuel_per_row: settings.get_wasm_udf_fuel_per_row()?,
+        max_memory_bytes: settings.get_wasm_udf_max_memory_mb()? as usize * 1024 * 1024,
+    };
+
+    let mut hasher = DefaultHasher::new();
+    udf.name.hash(&mut hasher);
+    udf.definition.hash(&mut hasher);
+    udf.arg_types.hash(&mut hasher);
+    udf.return_type.hash(&mut hasher);
+    limits.hash(&mut hasher);
+    let registered_name = format!("{}${:x}", udf.name, hasher.finish());
+
+    let factory = FunctionFactory::instance();
+    if !factory.check_user_function(&registered_name) {
+        let (arg_types, return_type) = resolve_udf_types(udf)?;
+        let desc = WasmFunction::try_create_desc(
+            &udf.name,
+            &udf.definition,
+            arg_types,
+            return_type,
+            limits,
+        )?;
+        factory.register_user_function(&registered_name, desc);
+    }
+
+    Ok(registered_name)
+}
+
+#[cfg(not(feature = "wasm-udf"))]
+fn register_wasm_udf(udf: &UserDefinedFunction, _settings: &Settings) -> Result<String> {
+    Err(ErrorCode::UnImplement(format!(
+        "Cannot use WASM function {}, databend-query is built without the `wasm-udf` feature",
+        udf.name
+    )))
+}
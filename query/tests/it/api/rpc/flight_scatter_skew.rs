@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::Result;
+use databend_query::api::ExchangeSkewDetector;
+
+#[test]
+fn test_exchange_skew_detector_hot_key() -> Result<()> {
+    let detector = ExchangeSkewDetector::create(4, 2);
+
+    // Keys are spread uniformly, except that hash 7 is sent far more often than the others.
+    let mut hashes = (0..40000u64).collect::<Vec<_>>();
+    hashes.extend(std::iter::repeat(7).take(40000));
+    detector.observe(&hashes);
+
+    let skew = detector.take_skew().unwrap();
+    assert_eq!(skew.partition, 3);
+    assert_eq!(skew.rows, 50000);
+    assert_eq!(skew.avg_rows, 20000.0);
+    assert_eq!(skew.hot_keys[0].0, 7);
+    assert!(skew.hot_keys[0].1 >= 40000);
+
+    // Skew is only reported once per exchange.
+    assert!(detector.take_skew().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_exchange_skew_detector_uniform() -> Result<()> {
+    let detector = ExchangeSkewDetector::create(4, 2);
+
+    detector.observe(&(0..1000u64).collect::<Vec<_>>());
+    assert!(detector.take_skew().is_none());
+
+    detector.observe(&(1000..100000u64).collect::<Vec<_>>());
+    assert!(detector.take_skew().is_none());
+    Ok(())
+}
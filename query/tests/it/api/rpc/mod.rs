@@ -14,5 +14,6 @@
 
 mod flight_actions;
 mod flight_dispatcher;
+mod flight_scatter_skew;
 mod flight_service;
 mod flight_tickets;
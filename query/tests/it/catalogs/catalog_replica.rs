@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+use databend_query::catalogs::default::apply_catalog_change;
+use databend_query::catalogs::default::sync_catalog;
+
+async fn put(kv: &MetaEmbedded, key: &str, value: &[u8]) -> Result<()> {
+    kv.upsert_kv(UpsertKVReq::new(
+        key,
+        MatchSeq::Any,
+        Operation::Update(value.to_vec()),
+        None,
+    ))
+    .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalog_replica_sync() -> Result<()> {
+    let source = MetaEmbedded::new_temp().await?;
+    let target = MetaEmbedded::new_temp().await?;
+
+    put(&source, "__fd_database/tenant/db1", b"1").await?;
+    put(&source, "__fd_table/1/t1", b"2").await?;
+    put(&source, "__fd_table_by_id/2", b"meta").await?;
+    put(&source, "__fd_users/tenant/u1", b"user").await?;
+
+    // Stale and outdated keys of the replica.
+    put(&target, "__fd_database/tenant/dropped", b"3").await?;
+    put(&target, "__fd_table/1/t1", b"old").await?;
+
+    let copied = sync_catalog(&source, &target).await?;
+    assert_eq!(copied, 3);
+
+    let dbs = target.prefix_list_kv("__fd_database/").await?;
+    assert_eq!(dbs.len(), 1);
+    assert_eq!(dbs[0].0, "__fd_database/tenant/db1");
+
+    let t1 = target.get_kv("__fd_table/1/t1").await?;
+    assert_eq!(t1.unwrap().data, b"2".to_vec());
+
+    // Keys not in the catalog are not replicated.
+    assert!(target.get_kv("__fd_users/tenant/u1").await?.is_none());
+
+    // Nothing to copy once in sync.
+    assert_eq!(sync_catalog(&source, &target).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalog_replica_apply_change() -> Result<()> {
+    let target = MetaEmbedded::new_temp().await?;
+
+    assert!(apply_catalog_change(&target, "__fd_table/1/t1", Some(b"1".to_vec())).await?);
+    let t1 = target.get_kv("__fd_table/1/t1").await?;
+    assert_eq!(t1.unwrap().data, b"1".to_vec());
+
+    assert!(apply_catalog_change(&target, "__fd_table/1/t1", None).await?);
+    assert!(target.get_kv("__fd_table/1/t1").await?.is_none());
+
+    assert!(!apply_catalog_change(&target, "__fd_id_gen/table_id", Some(b"1".to_vec())).await?);
+    assert!(target.get_kv("__fd_id_gen/table_id").await?.is_none());
+
+    Ok(())
+}
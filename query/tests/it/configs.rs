@@ -17,6 +17,7 @@ use std::fs;
 use std::io::Write;
 
 use common_exception::Result;
+use common_io::prelude::StorageParams;
 use databend_query::Config;
 use pretty_assertions::assert_eq;
 
@@ -34,6 +35,9 @@ cluster_id = ""
 num_cpus = 0
 mysql_handler_host = "127.0.0.1"
 mysql_handler_port = 3307
+mysql_tls_server_cert = ""
+mysql_tls_server_key = ""
+mysql_require_secure_transport = false
 max_active_sessions = 256
 clickhouse_handler_host = "127.0.0.1"
 clickhouse_handler_port = 9000
@@ -64,6 +68,15 @@ table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
 table_disk_cache_root = "_cache"
 table_disk_cache_mb_size = 1024
+query_result_cache_mb_size = 64
+temp_storage_root = "_temp"
+temp_storage_quota_mb = 0
+temp_storage_quota_mb_per_user = 0
+storage_encryption_key_file = ""
+storage_encryption_kms_command = ""
+storage_encryption_required = false
+table_ttl_interval_secs = 0
+external_udf_allowed_addresses = ""
 management_mode = false
 jwt_key_file = ""
 
@@ -81,6 +94,8 @@ password = ""
 client_timeout_in_second = 10
 rpc_tls_meta_server_root_ca_cert = ""
 rpc_tls_meta_service_domain_name = "localhost"
+replica_source_address = ""
+replica_max_lag_in_second = 60
 
 [storage]
 type = "fs"
@@ -105,6 +120,13 @@ container = ""
 endpoint_url = ""
 root = ""
 
+[storage.gcs]
+bucket = ""
+access_key_id = ""
+secret_access_key = ""
+endpoint_url = "https://storage.googleapis.com"
+root = ""
+
 [storage.hdfs]
 name_node = ""
 root = ""
@@ -275,6 +297,45 @@ fn test_env_config_fs() -> Result<()> {
     Ok(())
 }
 
+// From env, defaulting.
+#[test]
+fn test_env_config_gcs() -> Result<()> {
+    temp_env::with_vars(
+        vec![
+            ("STORAGE_TYPE", Some("gcs")),
+            ("STORAGE_NUM_CPUS", Some("16")),
+            ("STORAGE_GCS_BUCKET", Some("gcs.bucket")),
+            ("STORAGE_GCS_ACCESS_KEY_ID", Some("gcs.key.id")),
+            ("STORAGE_GCS_SECRET_ACCESS_KEY", Some("gcs.key")),
+            ("STORAGE_GCS_ROOT", Some("/path/to/root")),
+            ("STORAGE_S3_BUCKET", Some("us.bucket")),
+            ("CONFIG_FILE", None),
+        ],
+        || {
+            let configured = Config::load().expect("must success");
+
+            match &configured.storage.params {
+                StorageParams::Gcs(cfg) => {
+                    assert_eq!("https://storage.googleapis.com", cfg.endpoint_url);
+                    assert_eq!("gcs.bucket", cfg.bucket);
+                    assert_eq!("gcs.key.id", cfg.access_key_id);
+                    assert_eq!("gcs.key", cfg.secret_access_key);
+                    assert_eq!("/path/to/root", cfg.root);
+                }
+                v => panic!("storage params must be gcs, but got: {:?}", v),
+            }
+
+            let configured = configured.into_outer();
+            assert_eq!("gcs", configured.storage.storage_type);
+
+            // Storage type is gcs, s3 related value should be default.
+            assert_eq!("", configured.storage.s3.bucket);
+        },
+    );
+
+    Ok(())
+}
+
 /// Test whether override works as expected.
 #[test]
 fn test_override_config() -> Result<()> {
@@ -290,6 +351,9 @@ cluster_id = ""
 num_cpus = 0
 mysql_handler_host = "127.0.0.1"
 mysql_handler_port = 3307
+mysql_tls_server_cert = ""
+mysql_tls_server_key = ""
+mysql_require_secure_transport = false
 max_active_sessions = 256
 clickhouse_handler_host = "127.0.0.1"
 clickhouse_handler_port = 9000
@@ -320,6 +384,15 @@ table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
 table_disk_cache_root = "_cache"
 table_disk_cache_mb_size = 1024
+query_result_cache_mb_size = 64
+temp_storage_root = "_temp"
+temp_storage_quota_mb = 0
+temp_storage_quota_mb_per_user = 0
+storage_encryption_key_file = ""
+storage_encryption_kms_command = ""
+storage_encryption_required = false
+table_ttl_interval_secs = 0
+external_udf_allowed_addresses = ""
 management_mode = false
 jwt_key_file = ""
 
@@ -337,6 +410,8 @@ password = "password_from_file"
 client_timeout_in_second = 10
 rpc_tls_meta_server_root_ca_cert = ""
 rpc_tls_meta_service_domain_name = "localhost"
+replica_source_address = ""
+replica_max_lag_in_second = 60
 
 [storage]
 type = "s3"
@@ -361,6 +436,13 @@ container = ""
 endpoint_url = ""
 root = ""
 
+[storage.gcs]
+bucket = ""
+access_key_id = ""
+secret_access_key = ""
+endpoint_url = "https://storage.googleapis.com"
+root = ""
+
 [storage.hdfs]
 name_node = ""
 root = ""
@@ -0,0 +1,147 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::type_primitive::UInt32Type;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::NullableType;
+use common_datavalues::StringType;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use databend_query::formats::format_tsv::TsvInputFormat;
+use databend_query::formats::FormatFactory;
+
+fn test_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("a", DataTypeImpl::UInt32(UInt32Type::default())),
+        DataField::new("b", NullableType::new_impl(StringType::new_impl())),
+    ]))
+}
+
+#[test]
+fn test_deserialize_escaped_fields() -> Result<()> {
+    let tsv_input_format = TsvInputFormat::try_create(
+        "tsv",
+        test_schema(),
+        FormatSettings::default(),
+        8192,
+        10 * 1024 * 1024,
+    )?;
+
+    let mut state = tsv_input_format.create_state();
+    let bytes = "1\tfirst\\\\second\n2\t\\N\r\n3\tit's \"quoted\"\n".as_bytes();
+    assert_eq!(bytes.len(), tsv_input_format.read_buf(bytes, &mut state)?);
+
+    assert_blocks_eq(
+        vec![
+            "+---+---------------+",
+            "| a | b             |",
+            "+---+---------------+",
+            "| 1 | first\\second  |",
+            "| 2 | NULL          |",
+            "| 3 | it's \"quoted\" |",
+            "+---+---------------+",
+        ],
+        &tsv_input_format.deserialize_data(&mut state)?,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_header_with_names_and_types() -> Result<()> {
+    let factory = FormatFactory::instance();
+    let settings = FormatSettings::default();
+
+    for (format, header) in [
+        ("TSVWithNames", "a\tb\n"),
+        ("TSVWithNamesAndTypes", "a\tb\nUInt32\tString\n"),
+        ("CSVWithNames", "a,b\n"),
+        (
+            "CSVWithNamesAndTypes",
+            "\"a\",\"b\"\nUInt32,Nullable(String)\n",
+        ),
+    ] {
+        let input_format = factory.get_input(format, test_schema(), settings.clone())?;
+        let mut state = input_format.create_state();
+        let skipped = input_format.skip_header(header.as_bytes(), &mut state)?;
+        assert_eq!(header.len(), skipped, "format: {}", format);
+    }
+
+    for (format, header) in [
+        ("TSVWithNames", "a\tc\n"),
+        ("TSVWithNames", "a\n"),
+        ("TSVWithNamesAndTypes", "a\tb\nString\tString\n"),
+        ("CSVWithNames", "b,a\n"),
+    ] {
+        let input_format = factory.get_input(format, test_schema(), settings.clone())?;
+        let mut state = input_format.create_state();
+        let result = input_format.skip_header(header.as_bytes(), &mut state);
+        assert!(result.is_err(), "format: {}, header: {:?}", format, header);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_header_across_buffers() -> Result<()> {
+    let input_format = FormatFactory::instance().get_input(
+        "csvwithnames",
+        test_schema(),
+        FormatSettings::default(),
+    )?;
+
+    let mut state = input_format.create_state();
+    assert_eq!(2, input_format.skip_header("a,".as_bytes(), &mut state)?);
+    assert_eq!(
+        2,
+        input_format.skip_header("b\n1,x\n".as_bytes(), &mut state)?
+    );
+
+    let mut state = input_format.create_state();
+    assert_eq!(2, input_format.skip_header("a,".as_bytes(), &mut state)?);
+    assert!(input_format
+        .skip_header("c\n1,x\n".as_bytes(), &mut state)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_csv_custom_quote_char() -> Result<()> {
+    let settings = FormatSettings {
+        quote_char: vec![b'"'],
+        ..Default::default()
+    };
+
+    let input_format = FormatFactory::instance().get_input("csv", test_schema(), settings)?;
+    let mut state = input_format.create_state();
+    input_format.read_buf("1,it's\n2,\"a,b\"\n".as_bytes(), &mut state)?;
+
+    assert_blocks_eq(
+        vec![
+            "+---+------+",
+            "| a | b    |",
+            "+---+------+",
+            "| 1 | it's |",
+            "| 2 | a,b  |",
+            "+---+------+",
+        ],
+        &input_format.deserialize_data(&mut state)?,
+    );
+    Ok(())
+}
@@ -13,4 +13,6 @@
 // limitations under the License.
 
 mod format_csv;
+mod format_tsv;
+mod output_format_json;
 mod output_format_tcsv;
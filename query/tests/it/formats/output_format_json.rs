@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use databend_query::formats::FormatFactory;
+use pretty_assertions::assert_eq;
+
+fn test_blocks() -> (DataSchemaRef, Vec<DataBlock>) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("b", i32::to_data_type()),
+        DataField::new_nullable("a", Vu8::to_data_type()),
+    ]);
+
+    let blocks = vec![
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![1, 2]),
+            Series::from_data(vec![Some("x"), None]),
+        ]),
+        DataBlock::create(schema.clone(), vec![
+            Series::from_data(vec![3]),
+            Series::from_data(vec![Some("z\"")]),
+        ]),
+    ];
+    (schema, blocks)
+}
+
+fn serialize(format: &str, schema: DataSchemaRef, blocks: &[DataBlock]) -> Result<Vec<u8>> {
+    let settings = FormatSettings::default();
+    let mut output_format = FormatFactory::instance().get_output(format, schema)?;
+
+    let mut buf = vec![];
+    for block in blocks {
+        buf.extend_from_slice(&output_format.serialize_block(block, &settings)?);
+    }
+    buf.extend_from_slice(&output_format.finalize()?);
+    Ok(buf)
+}
+
+#[test]
+fn test_json_output_format() -> Result<()> {
+    let (schema, blocks) = test_blocks();
+
+    let json = String::from_utf8(serialize("JSON", schema.clone(), &blocks)?)?;
+    let expect = "{\"meta\":[{\"name\":\"b\",\"type\":\"Int32\"},{\"name\":\"a\",\"type\":\"Nullable(String)\"}],\"data\":[\n\
+                  {\"b\":1,\"a\":\"x\"},\n\
+                  {\"b\":2,\"a\":null},\n\
+                  {\"b\":3,\"a\":\"z\\\"\"}\n\
+                  ],\"rows\":3}\n";
+    assert_eq!(json, expect);
+
+    let json = String::from_utf8(serialize("JSONCompact", schema.clone(), &blocks)?)?;
+    let expect = "{\"meta\":[{\"name\":\"b\",\"type\":\"Int32\"},{\"name\":\"a\",\"type\":\"Nullable(String)\"}],\"data\":[\n\
+                  [1,\"x\"],\n\
+                  [2,null],\n\
+                  [3,\"z\\\"\"]\n\
+                  ],\"rows\":3}\n";
+    assert_eq!(json, expect);
+
+    // The document is complete even without any block.
+    let json = String::from_utf8(serialize("jsoncompact", schema, &[])?)?;
+    let value: serde_json::Value = serde_json::from_str(&json)?;
+    assert_eq!(value["rows"], 0);
+    assert_eq!(value["data"], serde_json::json!([]));
+    Ok(())
+}
+
+#[test]
+fn test_arrow_output_format() -> Result<()> {
+    let (schema, blocks) = test_blocks();
+
+    let bytes = serialize("arrow", schema, &blocks)?;
+    // Every IPC stream message starts with the continuation marker.
+    assert_eq!(&bytes[0..4], &[0xff, 0xff, 0xff, 0xff]);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_output_format() -> Result<()> {
+    let (schema, _) = test_blocks();
+
+    assert!(FormatFactory::instance().has_output("TabSeparated"));
+    assert!(!FormatFactory::instance().has_output("xml"));
+    assert!(FormatFactory::instance().get_output("xml", schema).is_err());
+    Ok(())
+}
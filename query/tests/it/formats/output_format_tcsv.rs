@@ -17,7 +17,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
-use databend_query::formats::output_format::OutputFormatType;
+use databend_query::formats::FormatFactory;
 use pretty_assertions::assert_eq;
 
 fn test_data_block(is_nullable: bool) -> Result<()> {
@@ -64,8 +64,7 @@ fn test_data_block(is_nullable: bool) -> Result<()> {
     let mut format_setting = FormatSettings::default();
 
     {
-        let fmt = OutputFormatType::Tsv;
-        let mut formater = fmt.create_format(schema.clone());
+        let mut formater = FormatFactory::instance().get_output("tsv", schema.clone())?;
         let buffer = formater.serialize_block(&block, &format_setting)?;
 
         let json_block = String::from_utf8(buffer)?;
@@ -79,8 +78,7 @@ fn test_data_block(is_nullable: bool) -> Result<()> {
         format_setting.record_delimiter = vec![b'%'];
         format_setting.field_delimiter = vec![b'$'];
 
-        let fmt = OutputFormatType::Csv;
-        let mut formater = fmt.create_format(schema);
+        let mut formater = FormatFactory::instance().get_output("csv", schema)?;
         let buffer = formater.serialize_block(&block, &format_setting)?;
 
         let json_block = String::from_utf8(buffer)?;
@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod management_mode_access;
+mod privilege_access;
+mod sandbox_access;
@@ -0,0 +1,128 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+async fn run_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_privilege_access() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        is_denied: bool,
+    }
+
+    let tests = vec![
+        Test {
+            name: "select-passed",
+            query: "SELECT * FROM numbers(10)",
+            is_denied: false,
+        },
+        Test {
+            name: "show-grants-passed",
+            query: "SHOW GRANTS",
+            is_denied: false,
+        },
+        Test {
+            name: "set-passed",
+            query: "SET max_threads = 1",
+            is_denied: false,
+        },
+        Test {
+            name: "set-global-denied",
+            query: "SET GLOBAL max_threads = 1",
+            is_denied: true,
+        },
+        Test {
+            name: "show-users-denied",
+            query: "SHOW USERS",
+            is_denied: true,
+        },
+        Test {
+            name: "show-grants-of-root-denied",
+            query: "SHOW GRANTS FOR 'root'",
+            is_denied: true,
+        },
+        Test {
+            name: "create-function-denied",
+            query: "CREATE FUNCTION isnotempty AS (p) -> not(is_null(p))",
+            is_denied: true,
+        },
+        Test {
+            name: "drop-function-denied",
+            query: "DROP FUNCTION IF EXISTS isnotempty",
+            is_denied: true,
+        },
+        Test {
+            name: "create-stage-denied",
+            query: "CREATE STAGE test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z')",
+            is_denied: true,
+        },
+        Test {
+            name: "desc-stage-denied",
+            query: "DESC STAGE test_stage",
+            is_denied: true,
+        },
+        Test {
+            name: "call-denied",
+            query: "CALL system$search_tables('t')",
+            is_denied: true,
+        },
+        Test {
+            name: "rename-table-denied",
+            query: "RENAME TABLE default.a TO default.b",
+            is_denied: true,
+        },
+        Test {
+            name: "optimize-table-denied",
+            query: "OPTIMIZE TABLE default.a",
+            is_denied: true,
+        },
+        Test {
+            name: "describe-table-denied",
+            query: "DESC default.a",
+            is_denied: true,
+        },
+    ];
+
+    let user_info = UserInfo::new("test_user", "%", AuthInfo::None);
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+
+    for test in tests {
+        let res = run_query(ctx.clone(), test.query).await;
+        let is_denied =
+            matches!(&res, Err(e) if e.code() == ErrorCode::PermissionDenied("").code());
+        assert_eq!(test.is_denied, is_denied, "in test case:{:?}", test.name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::GrantObject;
+use common_meta_types::UserInfo;
+use common_meta_types::UserOptionFlag;
+use common_meta_types::UserPrivilegeSet;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+async fn run_query(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute(None).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sandbox_access() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        is_err: bool,
+    }
+
+    let tests = vec![
+        Test {
+            name: "select-passed",
+            query: "SELECT * FROM numbers(10)",
+            is_err: false,
+        },
+        Test {
+            name: "show-tables-passed",
+            query: "SHOW TABLES",
+            is_err: false,
+        },
+        Test {
+            name: "system-one-passed",
+            query: "SELECT * FROM system.one",
+            is_err: false,
+        },
+        Test {
+            name: "set-passed",
+            query: "SET max_threads = 1",
+            is_err: false,
+        },
+        Test {
+            name: "create-database-denied",
+            query: "CREATE DATABASE db1",
+            is_err: true,
+        },
+        Test {
+            name: "create-table-denied",
+            query: "CREATE TABLE t1(a int)",
+            is_err: true,
+        },
+        Test {
+            name: "create-user-denied",
+            query: "CREATE USER 'test' IDENTIFIED BY 'password'",
+            is_err: true,
+        },
+        Test {
+            name: "system-users-denied",
+            query: "SELECT * FROM system.users",
+            is_err: true,
+        },
+        Test {
+            name: "system-configs-denied",
+            query: "SELECT * FROM system.configs",
+            is_err: true,
+        },
+        Test {
+            name: "fuse-snapshot-denied",
+            query: "SELECT * FROM fuse_snapshot('system', 'one')",
+            is_err: true,
+        },
+        Test {
+            name: "show-processlist-denied",
+            query: "SHOW PROCESSLIST",
+            is_err: true,
+        },
+        Test {
+            name: "set-raise-limit-denied",
+            query: "SET max_execution_time = 0",
+            is_err: true,
+        },
+        Test {
+            name: "set-planner-v2-denied",
+            query: "SET enable_planner_v2 = 1",
+            is_err: true,
+        },
+        Test {
+            name: "set-global-denied",
+            query: "SET GLOBAL max_threads = 1",
+            is_err: true,
+        },
+        Test {
+            name: "max-result-rows-denied",
+            query: "SELECT * FROM numbers(100000)",
+            is_err: true,
+        },
+    ];
+
+    let mut user_info = UserInfo::new("sandbox", "%", AuthInfo::None);
+    user_info.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    user_info.option.set_option_flag(UserOptionFlag::Sandbox);
+
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+    assert!(ctx.get_settings().is_sandboxed());
+    assert_eq!(ctx.get_settings().get_max_result_rows()?, 10000);
+    assert_eq!(ctx.get_settings().get_enable_planner_v2()?, 0);
+
+    for test in tests {
+        let res = run_query(ctx.clone(), test.query).await;
+        assert_eq!(test.is_err, res.is_err(), "in test case:{:?}", test.name);
+    }
+
+    Ok(())
+}
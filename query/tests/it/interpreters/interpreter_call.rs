@@ -414,6 +414,74 @@ async fn test_call_tenant_quota_interpreter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_call_password_policy_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let conf = crate::tests::ConfigBuilder::create()
+        .with_management_mode()
+        .config();
+    let ctx = crate::tests::create_query_context_with_config(conf.clone(), None).await?;
+    let mut user_info = ctx.get_current_user()?;
+    user_info
+        .option
+        .set_option_flag(UserOptionFlag::TenantSetting);
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+
+    // current tenant
+    {
+        let plan = PlanParser::parse(ctx.clone(), "call admin$password_policy()").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+            "| min_length | min_upper_case_chars | min_lower_case_chars | min_numeric_chars | min_special_chars | max_age_days | history | max_retries | lockout_time_mins |",
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+            "| 0          | 0                    | 0                    | 0                 | 0                 | 0            | 0       | 0           | 0                 |",
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // set the policy of the current tenant
+    {
+        let query = format!("call admin$password_policy({}, 8, 1)", ctx.get_tenant());
+        let plan = PlanParser::parse(ctx.clone(), &query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+            "| min_length | min_upper_case_chars | min_lower_case_chars | min_numeric_chars | min_special_chars | max_age_days | history | max_retries | lockout_time_mins |",
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+            "| 8          | 1                    | 0                    | 0                 | 0                 | 0            | 0       | 0           | 0                 |",
+            "+------------+----------------------+----------------------+-------------------+-------------------+--------------+---------+-------------+-------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // the new password must follow the policy
+    {
+        let res =
+            PlanParser::parse(ctx.clone(), "CREATE USER 'u1'@'%' IDENTIFIED BY 'Abc123'").await;
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::InvalidPassword("").code()
+        );
+
+        let plan = PlanParser::parse(
+            ctx.clone(),
+            "CREATE USER 'u1'@'%' IDENTIFIED BY 'Abcdef123'",
+        )
+        .await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_call_stats_tenant_tables_interpreter() -> Result<()> {
     common_tracing::init_default_ut_tracing();
@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sessions::SessionType;
+use databend_query::sql::DfParser;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_factory_router() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let settings = ctx.get_settings();
+    settings.set_settings(
+        "enable_new_processor_framework".to_string(),
+        "1".to_string(),
+        false,
+    )?;
+
+    let cases = vec![
+        ("select number from numbers(3)", 0, PlannerKind::Legacy),
+        ("select number from numbers(3)", 1, PlannerKind::V2),
+        // Not supported by the new planner.
+        ("show databases", 1, PlannerKind::Legacy),
+        ("select 1; select 2", 1, PlannerKind::Legacy),
+    ];
+
+    for (query, enable_planner_v2, expect) in cases {
+        settings.set_settings(
+            "enable_planner_v2".to_string(),
+            enable_planner_v2.to_string(),
+            false,
+        )?;
+        let (stmts, _) = DfParser::parse_sql(query, SessionType::Dummy)?;
+        let actual = InterpreterFactoryRouter::route(&ctx, &stmts)?;
+        assert_eq!(actual, expect, "query: {}", query);
+    }
+
+    // The query is served by the routed planner.
+    {
+        let query = "select number from numbers(3)";
+        let (stmts, _) = DfParser::parse_sql(query, SessionType::Dummy)?;
+        let (interpreter, planner) =
+            InterpreterFactoryRouter::get(ctx.clone(), query, &stmts).await?;
+        assert_eq!(planner, PlannerKind::V2);
+        assert_eq!(interpreter.name(), "SelectInterpreterV2");
+
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| number |",
+            "+--------+",
+            "| 0      |",
+            "| 1      |",
+            "| 2      |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    assert!(InterpreterFactoryRouter::is_fallback_error(
+        &ErrorCode::UnImplement("")
+    ));
+    assert!(!InterpreterFactoryRouter::is_fallback_error(
+        &ErrorCode::UnknownTable("")
+    ));
+
+    Ok(())
+}
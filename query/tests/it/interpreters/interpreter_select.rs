@@ -13,7 +13,13 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::GrantObject;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserPrivilegeType;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
 use futures::TryStreamExt;
@@ -76,3 +82,105 @@ async fn test_select_interpreter() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_privilege() -> Result<()> {
+    let mut privileges = UserPrivilegeSet::empty();
+    privileges.set_privilege(UserPrivilegeType::Select);
+
+    let mut user_info = UserInfo::new("test_user", "%", AuthInfo::None);
+    user_info.grants.grant_privileges(
+        &GrantObject::Table(
+            "default".to_string(),
+            "default".to_string(),
+            "t1".to_string(),
+        ),
+        privileges,
+    );
+
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+
+    // Granted, fails for the table does not exist.
+    let res = PlanParser::parse(ctx.clone(), "select * from default.t1").await;
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::UnknownTable("").code()
+    );
+
+    let res = PlanParser::parse(ctx.clone(), "select * from default.t2").await;
+    assert_eq!(
+        res.err().unwrap().message(),
+        "Permission denied, user 'test_user'@'%' requires SELECT privilege on 'default'.'default'.'t2'"
+    );
+
+    // The system databases are always readable.
+    let plan = PlanParser::parse(ctx.clone(), "select * from system.one").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(result[0].num_rows(), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_max_execution_time() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings()
+        .set_settings("max_execution_time".to_string(), "100".to_string(), false)?;
+
+    let query = "select sum(number) from numbers_mt(100000000000)";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute(None).await?;
+    let res = stream.try_collect::<Vec<_>>().await;
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::AbortedQuery("").code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_interpreter_cpu_affinity() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let query = "select sum(number) from numbers_mt(10000)";
+
+    for affinity in ["none", "core", "numa"] {
+        ctx.get_settings().set_settings(
+            "executor_cpu_affinity".to_string(),
+            affinity.to_string(),
+            false,
+        )?;
+
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------------+",
+            "| sum(number) |",
+            "+-------------+",
+            "| 49995000    |",
+            "+-------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    ctx.get_settings().set_settings(
+        "executor_cpu_affinity".to_string(),
+        "socket".to_string(),
+        false,
+    )?;
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let res = executor.execute(None).await;
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::BadArguments("").code()
+    );
+
+    Ok(())
+}
@@ -46,3 +46,39 @@ async fn test_setting_interpreter_error() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_interpreter_global() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+
+    // SET GLOBAL is persisted and applied if the session has not set it.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "SET GLOBAL max_block_size=2").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+
+        assert_eq!(ctx.get_settings().get_max_block_size()?, 2);
+        let global_settings = ctx.get_user_manager().get_settings(&tenant).await?;
+        assert_eq!(global_settings.len(), 1);
+        assert_eq!(global_settings[0].name, "max_block_size");
+    }
+
+    // The session value takes precedence over the GLOBAL one.
+    {
+        let plan = PlanParser::parse(ctx.clone(), "SET max_block_size=3").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+
+        let plan = PlanParser::parse(ctx.clone(), "SET GLOBAL max_block_size=4").await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let mut stream = executor.execute(None).await?;
+        while let Some(_block) = stream.next().await {}
+
+        assert_eq!(ctx.get_settings().get_max_block_size()?, 3);
+    }
+
+    Ok(())
+}
@@ -96,3 +96,23 @@ async fn test_alter_user_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_user_interpreter_self_option_without_privilege() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let user_info = UserInfo::new("test_user", "%", AuthInfo::None);
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+
+    let query = "ALTER USER 'test_user'@'%' WITH TENANTSETTING";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan.clone())?;
+    let res = executor.execute(None).await;
+    assert_eq!(
+        res.err().unwrap().message(),
+        "Permission denied, user 'test_user'@'%' requires CREATE USER privilege on *.*"
+    );
+
+    Ok(())
+}
@@ -14,6 +14,8 @@
 
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
 use futures::stream::StreamExt;
@@ -34,3 +36,23 @@ async fn test_create_user_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_user_interpreter_without_privilege() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let user_info = UserInfo::new("test_user", "%", AuthInfo::None);
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, Some(user_info)).await?;
+
+    let query = "CREATE USER 'test'@'localhost' IDENTIFIED BY 'password'";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx, plan.clone())?;
+    let res = executor.execute(None).await;
+    assert_eq!(
+        res.err().unwrap().message(),
+        "Permission denied, user 'test_user'@'%' requires CREATE USER privilege on *.*"
+    );
+
+    Ok(())
+}
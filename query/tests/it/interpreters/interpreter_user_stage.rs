@@ -53,11 +53,11 @@ async fn test_user_stage_interpreter() -> Result<()> {
 
         common_datablocks::assert_blocks_eq(
             vec![
-                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
-                "| name       | stage_type | stage_params                                                                                                                                                                                                                                        | copy_options                                  | file_format_options                                                                                                | comment |",
-                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
-                r#"| test_stage | External   | StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "load", root: "/files/", disable_credential_loader: true, access_key_id: "******b3c", secret_access_key: "******y6z", master_key: "" }) } | CopyOptions { on_error: None, size_limit: 0 } | FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", compression: None } |         |"#,
-                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+                "| name       | stage_type | stage_params                                                                                                                                                                                                                                        | copy_options                                                              | file_format_options                                                                                                | comment |",
+                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+                r#"| test_stage | External   | StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "load", root: "/files/", disable_credential_loader: true, access_key_id: "******b3c", secret_access_key: "******y6z", master_key: "" }) } | CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None } | FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", compression: None } |         |"#,
+                "+------------+------------+-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
             ],
             &blocks,
         );
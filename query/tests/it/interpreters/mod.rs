@@ -22,6 +22,7 @@ mod interpreter_database_show_create;
 mod interpreter_empty;
 mod interpreter_explain;
 mod interpreter_factory_interceptor;
+mod interpreter_factory_router;
 mod interpreter_insert;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -29,6 +30,7 @@ mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_select;
 mod interpreter_setting;
+mod interpreter_show_clusters;
 mod interpreter_show_databases;
 mod interpreter_show_engines;
 mod interpreter_show_functions;
@@ -41,6 +43,7 @@ mod interpreter_show_stages;
 mod interpreter_show_tab_stat;
 mod interpreter_show_tables;
 mod interpreter_show_users;
+mod interpreter_show_variables;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
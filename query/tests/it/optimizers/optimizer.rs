@@ -12,54 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::any::Any;
-use std::sync::Arc;
-
-use common_planners::*;
-
-#[derive(serde::Serialize, serde::Deserialize, PartialEq)]
-struct OptimizerTestPartInfo {}
-
-#[typetag::serde(name = "optimizer_test")]
-impl PartInfo for OptimizerTestPartInfo {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
-        match info.as_any().downcast_ref::<OptimizerTestPartInfo>() {
-            None => false,
-            Some(other) => self == other,
-        }
-    }
-}
-
-impl OptimizerTestPartInfo {
-    pub fn create() -> Arc<Box<dyn PartInfo>> {
-        Arc::new(Box::new(OptimizerTestPartInfo {}))
-    }
-}
-
-pub fn generate_partitions(workers: u64, total: u64) -> Partitions {
-    let part_size = total / workers;
-    // let part_remain = total % workers;
-
-    let mut partitions = Vec::with_capacity(workers as usize);
-    if part_size == 0 {
-        partitions.push(OptimizerTestPartInfo::create())
-    } else {
-        for _part in 0..workers {
-            // let part_begin = part * part_size;
-            // let mut part_end = (part + 1) * part_size;
-            // if part == (workers - 1) && part_remain > 0 {
-            //     part_end += part_remain;
-            // }
-            partitions.push(OptimizerTestPartInfo::create())
-        }
-    }
-    partitions
-}
-
 use common_base::base::tokio;
 use common_exception::Result;
 use databend_query::optimizers::Optimizers;
@@ -0,0 +1,79 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+async fn common_subexpression(query: &str) -> Result<String> {
+    let ctx = crate::tests::create_query_context().await?;
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let plan = match plan {
+        PlanNode::Select(plan) => plan.input.as_ref().clone(),
+        plan => plan,
+    };
+
+    let mut optimizer = CommonSubexpressionOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+    Ok(format!("{:?}", plan_node))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_common_subexpression_optimizer() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "Common expression in projection and filter",
+            query: "select (number + 1) + 2 from numbers_mt(10) where (number + 1) > 3",
+            expect: "\
+            Projection: ((number + 1) + 2):UInt64\
+            \n  Expression: ((number + 1) + 2):UInt64 (Before Projection)\
+            \n    Filter: ((number + 1) > 3)\
+            \n      Expression: (number + 1):UInt64 (Common Subexpressions)\
+            \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number + 1) > 3)]]",
+        },
+        Test {
+            name: "No common expression",
+            query: "select number + 2 from numbers_mt(10) where (number + 1) > 3",
+            expect: "\
+            Projection: (number + 2):UInt64\
+            \n  Expression: (number + 2):UInt64 (Before Projection)\
+            \n    Filter: ((number + 1) > 3)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((number + 1) > 3)]]",
+        },
+        Test {
+            name: "Non-deterministic expression",
+            query: "select rand() + number from numbers_mt(10) where rand() + number > 3",
+            expect: "\
+            Projection: (rand() + number):Float64\
+            \n  Expression: (rand() + number):Float64 (Before Projection)\
+            \n    Filter: ((rand() + number) > 3)\
+            \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [((rand() + number) > 3)]]",
+        },
+    ];
+
+    for test in tests {
+        let actual = common_subexpression(test.query).await?;
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
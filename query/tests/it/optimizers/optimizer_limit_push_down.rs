@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+async fn limit_on_query(query: &str, n: Option<usize>, offset: usize) -> Result<String> {
+    let ctx = crate::tests::create_query_context().await?;
+    let plan = match PlanParser::parse(ctx.clone(), query).await? {
+        PlanNode::Select(plan) => plan.input.as_ref().clone(),
+        plan => plan,
+    };
+    let plan = PlanBuilder::from(&plan).limit_offset(n, offset)?.build()?;
+
+    let mut optimizer = LimitPushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+    Ok(format!("{:?}", plan_node))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_push_down_below_projection() -> Result<()> {
+    let actual = limit_on_query("select number from numbers_mt(10)", Some(3), 0).await?;
+
+    let expect = "\
+    Limit: 3\
+    \n  Projection: number:UInt64\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 3]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_push_down_with_offset() -> Result<()> {
+    let actual = limit_on_query("select number from numbers_mt(10)", Some(3), 2).await?;
+
+    let expect = "\
+    Limit: 3, 2\
+    \n  Projection: number:UInt64\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 5]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_push_down_keep_smaller_limit() -> Result<()> {
+    let actual = limit_on_query("select number from numbers_mt(10) limit 2", Some(5), 0).await?;
+
+    let expect = "\
+    Limit: 5\
+    \n  Limit: 2\
+    \n    Projection: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 2, read_bytes: 16, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 2]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_not_push_down_below_filter() -> Result<()> {
+    let actual = limit_on_query(
+        "select number from numbers_mt(10) where number > 1",
+        Some(3),
+        0,
+    )
+    .await?;
+
+    let expect = "\
+    Limit: 3\
+    \n  Projection: number:UInt64\
+    \n    Filter: (number > 1)\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_not_push_down_below_sort() -> Result<()> {
+    let actual = limit_on_query(
+        "select number from numbers_mt(10) order by number",
+        Some(3),
+        0,
+    )
+    .await?;
+
+    let expect = "\
+    Limit: 3\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+async fn filter_on_query(query: &str, predicate: Expression) -> Result<String> {
+    let ctx = crate::tests::create_query_context().await?;
+    let plan = match PlanParser::parse(ctx.clone(), query).await? {
+        PlanNode::Select(plan) => plan.input.as_ref().clone(),
+        plan => plan,
+    };
+    let plan = PlanBuilder::from(&plan).filter(predicate)?.build()?;
+
+    let mut optimizer = PredicatePushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+    Ok(format!("{:?}", plan_node))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_push_down_where() -> Result<()> {
+    let query = "select number from numbers_mt(10) where number > 1";
+    let ctx = crate::tests::create_query_context().await?;
+
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+
+    let mut optimizer = PredicatePushDownOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(&plan)?;
+
+    let expect = "\
+    Projection: number:UInt64\
+    \n  Filter: (number > 1)\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 1)]]";
+
+    let actual = format!("{:?}", plan_node);
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_push_down_below_sort_and_projection() -> Result<()> {
+    let actual = filter_on_query(
+        "select number from numbers_mt(10) order by number",
+        col("number").gt(lit(5u64)),
+    )
+    .await?;
+
+    let expect = "\
+    Projection: number:UInt64\
+    \n  Sort: number:UInt64\
+    \n    Filter: (number > 5)\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], filters: [(number > 5)], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_not_push_down_below_alias() -> Result<()> {
+    let actual = filter_on_query(
+        "select number as c1 from numbers_mt(10)",
+        col("c1").gt(lit(5u64)),
+    )
+    .await?;
+
+    let expect = "\
+    Filter: (c1 > 5)\
+    \n  Projection: number as c1:UInt64\
+    \n    ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_not_push_down_below_limit() -> Result<()> {
+    let actual = filter_on_query(
+        "select number from numbers_mt(10) limit 3",
+        col("number").gt(lit(5u64)),
+    )
+    .await?;
+
+    let expect = "\
+    Filter: (number > 5)\
+    \n  Limit: 3\
+    \n    Projection: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 3, read_bytes: 24, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], limit: 3]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -220,3 +220,48 @@ async fn test_scatter_optimizer() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scatter_optimizer_with_group_by_shuffle_threshold() -> Result<()> {
+    let ctx = create_query_context_with_cluster(
+        ClusterDescriptor::new()
+            .with_node("Github", "www.github.com:9090")
+            .with_node("dummy_local", "127.0.0.1:9090")
+            .with_local_id("dummy_local"),
+    )
+    .await?;
+    ctx.get_settings().set_settings(
+        "group_by_shuffle_threshold".to_string(),
+        "1000".to_string(),
+        false,
+    )?;
+
+    // Small input converges the partial states instead of shuffling them by key.
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "SELECT SUM(number) FROM numbers(100) GROUP BY number % 3",
+    )
+    .await?;
+    let mut optimizer = ScattersOptimizer::create(ctx.clone());
+    let optimized = optimizer.optimize(&plan)?;
+    let expect = "\
+    Projection: SUM(number):UInt64\
+    \n  AggregatorFinal: groupBy=[[(number % 3)]], aggr=[[SUM(number)]]\
+    \n    RedistributeStage[expr: 0]\
+    \n      AggregatorPartial: groupBy=[[(number % 3)]], aggr=[[SUM(number)]]\
+    \n        Expression: (number % 3):UInt8, number:UInt64 (Before GroupBy)\
+    \n          ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 100, read_bytes: 800, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0]]";
+    assert_eq!(expect, format!("{:?}", optimized));
+
+    // Large input keeps shuffling by key.
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "SELECT SUM(number) FROM numbers(100000) GROUP BY number % 3",
+    )
+    .await?;
+    let mut optimizer = ScattersOptimizer::create(ctx);
+    let optimized = optimizer.optimize(&plan)?;
+    assert!(format!("{:?}", optimized).contains("RedistributeStage[expr: sipHash(_group_by_key)]"));
+
+    Ok(())
+}
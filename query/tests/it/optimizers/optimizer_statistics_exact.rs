@@ -17,13 +17,13 @@ use std::mem::size_of;
 use common_base::base::tokio;
 use common_datavalues::*;
 use common_exception::Result;
-use common_meta_app::schema::TableInfo;
 use common_planners::*;
+use databend_query::catalogs::CATALOG_DEFAULT;
 use databend_query::optimizers::*;
+use databend_query::storages::mock::MockTableSpec;
+use databend_query::storages::ToReadDataSourcePlan;
 use pretty_assertions::assert_eq;
 
-use crate::optimizers::optimizer::*;
-
 #[tokio::test]
 async fn test_statistics_exact_optimizer() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
@@ -36,27 +36,19 @@ async fn test_statistics_exact_optimizer() -> Result<()> {
         total as usize,
     );
     ctx.try_set_statistics(&statistics)?;
-    let source_plan = PlanNode::ReadSource(ReadDataSourcePlan {
-        catalog: "default".to_owned(),
-        source_info: SourceInfo::TableSource(TableInfo::simple(
-            "system",
-            "test",
-            DataSchemaRefExt::create(vec![
-                DataField::new("a", Vu8::to_data_type()),
-                DataField::new("b", Vu8::to_data_type()),
-                DataField::new("c", Vu8::to_data_type()),
-            ]),
-        )),
-        scan_fields: None,
-        parts: generate_partitions(8, total as u64),
-        statistics: statistics.clone(),
-        description: format!(
-            "(Read from system.{} table, Exactly Read Rows:{}, Read Bytes:{})",
-            "test", statistics.read_rows, statistics.read_bytes
-        ),
-        tbl_args: None,
-        push_downs: None,
-    });
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", Vu8::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+        DataField::new("c", Vu8::to_data_type()),
+    ]);
+    let spec = (0..8).fold(
+        MockTableSpec::create("test_statistics_exact", schema),
+        |spec, _| spec.with_partition(vec![]),
+    );
+    let table = ctx
+        .get_catalog(CATALOG_DEFAULT)?
+        .register_test_table(spec.with_statistics(statistics.clone()))?;
+    let source_plan = PlanNode::ReadSource(table.read_plan(ctx.clone(), None).await?);
 
     let aggr_expr = Expression::AggregateFunction {
         op: "count".to_string(),
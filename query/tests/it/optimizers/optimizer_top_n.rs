@@ -0,0 +1,102 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::optimizers::*;
+use databend_query::sql::PlanParser;
+
+async fn parse_query(query: &str) -> Result<PlanNode> {
+    let ctx = crate::tests::create_query_context().await?;
+    match PlanParser::parse(ctx, query).await? {
+        PlanNode::Select(plan) => Ok(plan.input.as_ref().clone()),
+        plan => Ok(plan),
+    }
+}
+
+async fn top_n(plan: &PlanNode) -> Result<String> {
+    let ctx = crate::tests::create_query_context().await?;
+    let mut optimizer = TopNOptimizer::create(ctx);
+    let plan_node = optimizer.optimize(plan)?;
+    Ok(format!("{:?}", plan_node))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_top_n_below_projection() -> Result<()> {
+    let plan = parse_query("select number from numbers_mt(10) order by number").await?;
+    let plan = PlanBuilder::from(&plan).limit(3)?.build()?;
+    let actual = top_n(&plan).await?;
+
+    let expect = "\
+    Limit: 3\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64, limit: 3\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_top_n_with_offset() -> Result<()> {
+    let plan = parse_query("select number from numbers_mt(10) order by number").await?;
+    let plan = PlanBuilder::from(&plan).limit_offset(Some(3), 2)?.build()?;
+    let actual = top_n(&plan).await?;
+
+    let expect = "\
+    Limit: 3, 2\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64, limit: 5\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_top_n_without_limit() -> Result<()> {
+    let plan = parse_query("select number from numbers_mt(10) order by number").await?;
+    let plan = PlanBuilder::from(&plan).limit_offset(None, 2)?.build()?;
+    let actual = top_n(&plan).await?;
+
+    let expect = "\
+    Limit: all, 2\
+    \n  Projection: number:UInt64\
+    \n    Sort: number:UInt64\
+    \n      ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_top_n_not_below_filter() -> Result<()> {
+    let plan = parse_query("select number from numbers_mt(10) order by number").await?;
+    let plan = PlanBuilder::from(&plan)
+        .filter(col("number").gt(lit(1)))?
+        .limit(3)?
+        .build()?;
+    let actual = top_n(&plan).await?;
+
+    let expect = "\
+    Limit: 3\
+    \n  Filter: (number > 1)\
+    \n    Projection: number:UInt64\
+    \n      Sort: number:UInt64\
+    \n        ReadDataSource: scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80, partitions_scanned: 1, partitions_total: 1], push_downs: [projections: [0], order_by: [number]]";
+
+    assert_eq!(expect, actual);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_query::pipelines::new::executor::PipelineProfile;
+use databend_query::pipelines::new::executor::ProcessorProfileInfo;
+use databend_query::pipelines::new::executor::QueryProfileManager;
+
+fn processor(id: usize, name: &str, cpu_ms: u64, async_ms: u64, rows: u64) -> ProcessorProfileInfo {
+    ProcessorProfileInfo {
+        id,
+        name: name.to_string(),
+        cpu_time: Duration::from_millis(cpu_ms),
+        async_time: Duration::from_millis(async_ms),
+        input_rows: rows,
+        input_wait_time: Duration::from_millis(0),
+        output_rows: rows,
+        output_stall_time: Duration::from_millis(cpu_ms),
+    }
+}
+
+#[test]
+fn test_pipeline_profile_diagnose() {
+    let profile = PipelineProfile {
+        elapsed: Duration::from_millis(100),
+        processors: vec![
+            processor(0, "Source", 1, 80, 0),
+            processor(1, "Transform", 5, 0, 1000),
+            processor(2, "Transform", 5, 0, 1000),
+        ],
+    };
+    assert_eq!(profile.diagnose(), vec![
+        "IO-bound: async time 80ms, cpu time 11ms".to_string()
+    ]);
+    assert_eq!(profile.output_fill(&profile.processors[1]), 0.05);
+
+    let profile = PipelineProfile {
+        elapsed: Duration::from_millis(100),
+        processors: vec![
+            processor(0, "Source", 1, 0, 0),
+            processor(1, "Transform", 50, 0, 9000),
+            processor(2, "Transform", 5, 0, 500),
+            processor(3, "Transform", 5, 0, 500),
+        ],
+    };
+    assert_eq!(profile.diagnose(), vec![
+        "CPU-bound: cpu time 61ms, async time 0ns".to_string(),
+        "Skewed: Transform × 3 processors, max input rows 9000, avg input rows 3333".to_string(),
+    ]);
+}
+
+#[test]
+fn test_query_profile_manager() {
+    let manager = QueryProfileManager::init();
+    let profile = PipelineProfile {
+        elapsed: Duration::from_millis(1),
+        processors: vec![processor(0, "Source", 1, 0, 0)],
+    };
+
+    manager.record("query-1", profile.clone());
+    manager.record("query-2", profile.clone());
+    manager.record("query-1", profile);
+
+    assert_eq!(manager.get("query-1").unwrap().pipelines.len(), 2);
+    assert_eq!(manager.get("query-2").unwrap().pipelines.len(), 1);
+    assert!(manager.get("query-3").is_none());
+    assert_eq!(manager.get_all().len(), 2);
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod port_test;
+mod sequence_merge_test;
 // use super::processor
 
 use databend_query::pipelines::new::processors;
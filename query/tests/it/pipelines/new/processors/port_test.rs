@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 use std::sync::Barrier;
+use std::time::Duration;
 
 use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::pipelines::new::processors::connect;
@@ -82,3 +85,30 @@ async fn test_input_and_output_flags() -> Result<()> {
     // assert_eq!(!output.can_push());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_port_profile() -> Result<()> {
+    unsafe {
+        let input = InputPort::create();
+        let output = OutputPort::create();
+
+        connect(&input, &output);
+
+        let sleep = Duration::from_millis(10);
+        input.set_need_data();
+        std::thread::sleep(sleep);
+        let block = DataBlock::create(DataSchemaRefExt::create(vec![]), vec![]);
+        output.push_data(Ok(block));
+        std::thread::sleep(sleep);
+        assert!(input.pull_data().is_some());
+
+        // the input and the output share the same profile
+        for profile in [input.profile(), output.profile()] {
+            assert_eq!(profile.blocks(), 1);
+            assert!(profile.empty_time() >= sleep);
+            assert!(profile.full_time() >= sleep);
+        }
+    }
+
+    Ok(())
+}
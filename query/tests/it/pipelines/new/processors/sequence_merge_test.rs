@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::pipelines::new::processors::connect;
+use databend_query::pipelines::new::processors::port::InputPort;
+use databend_query::pipelines::new::processors::port::OutputPort;
+use databend_query::pipelines::new::processors::processor::Event;
+use databend_query::pipelines::new::processors::Processor;
+use databend_query::pipelines::new::processors::SequenceMergeProcessor;
+
+fn sequenced_block(value: u64, sequence: u64) -> DataBlock {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", u64::to_data_type())]);
+    DataBlock::create(schema, vec![Series::from_data(vec![value])]).with_sequence(Some(sequence))
+}
+
+fn pull_value(input: &Arc<InputPort>) -> Result<DataValue> {
+    let block = input.pull_data().unwrap()?;
+    block.column(0).get_checked(0)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sequence_merge_processor() -> Result<()> {
+    let mut processor = SequenceMergeProcessor::create(2);
+    let upstreams = vec![OutputPort::create(), OutputPort::create()];
+    let downstream = InputPort::create();
+
+    unsafe {
+        for (input, upstream) in processor.get_inputs().iter().zip(upstreams.iter()) {
+            connect(input, upstream);
+        }
+        connect(&downstream, processor.get_output());
+    }
+
+    downstream.set_need_data();
+    upstreams[0].push_data(Ok(sequenced_block(2, 2)));
+    // Waits for the second input, which may have a smaller sequence number.
+    assert!(matches!(processor.event()?, Event::NeedData));
+
+    upstreams[1].push_data(Ok(sequenced_block(1, 1)));
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+    assert_eq!(pull_value(&downstream)?, DataValue::UInt64(1));
+
+    downstream.set_need_data();
+    upstreams[1].finish();
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+    assert_eq!(pull_value(&downstream)?, DataValue::UInt64(2));
+
+    downstream.set_need_data();
+    upstreams[0].finish();
+    assert!(matches!(processor.event()?, Event::Finished));
+    assert!(downstream.is_finished());
+
+    Ok(())
+}
@@ -99,6 +99,34 @@ async fn test_select() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_select_format() -> PoemResult<()> {
+    let server = Server::new();
+    let sql = "select number as n, 'a' as s from numbers(2) order by number";
+
+    {
+        let (status, body) = server.get(&format!("{} format CSV", sql)).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "0,\"a\"\n1,\"a\"\n");
+    }
+
+    {
+        let (status, body) = server.get(&format!("{} format JSONCompact", sql)).await;
+        assert_ok!(status, body);
+        assert_eq!(
+            &body,
+            "{\"meta\":[{\"name\":\"n\",\"type\":\"UInt64\"},{\"name\":\"s\",\"type\":\"String\"}],\"data\":[\n[0,\"a\"],\n[1,\"a\"]\n],\"rows\":2}\n"
+        );
+    }
+
+    {
+        let (status, body) = server.get(&format!("{} format XML", sql)).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_error!(body, "Unsupported output formats: XML");
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_insert_values() -> PoemResult<()> {
     let server = Server::new();
@@ -203,9 +231,55 @@ async fn test_insert_format_ndjson() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_insert_with_idempotency_key() -> PoemResult<()> {
+    let server = Server::new();
+    {
+        let (status, body) = server.post("create table t1(a int, b string)", "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_error!(body, "");
+    }
+
+    // The retry with the same key is skipped.
+    for _ in 0..2 {
+        let req = QueryBuilder::new("insert into table t1 format values")
+            .body("(0, 'a'), (1, 'b')")
+            .idempotency_key("load_1")
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+    }
+
+    {
+        let (status, body) = server.get(r#"select * from t1 order by a"#).await;
+        assert_eq!(status, StatusCode::OK, "{} {}", status, body);
+        assert_eq!(&body, "0\ta\n1\tb\n");
+    }
+
+    // The keys are scoped by table.
+    {
+        let (status, body) = server.post("create table t2(a int, b string)", "").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_error!(body, "");
+
+        let req = QueryBuilder::new("insert into table t2 format values")
+            .body("(2, 'c')")
+            .idempotency_key("load_1")
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+
+        let (status, body) = server.get(r#"select * from t2 order by a"#).await;
+        assert_eq!(status, StatusCode::OK, "{} {}", status, body);
+        assert_eq!(&body, "2\tc\n");
+    }
+    Ok(())
+}
+
 struct QueryBuilder {
     sql: String,
     body: Option<Body>,
+    idempotency_key: Option<String>,
 }
 
 impl QueryBuilder {
@@ -213,6 +287,14 @@ impl QueryBuilder {
         QueryBuilder {
             sql: sql.to_string(),
             body: None,
+            idempotency_key: None,
+        }
+    }
+
+    pub fn idempotency_key(self, key: &str) -> Self {
+        Self {
+            idempotency_key: Some(key.to_string()),
+            ..self
         }
     }
 
@@ -224,9 +306,12 @@ impl QueryBuilder {
     }
 
     pub fn build(self) -> Request {
-        let uri = url::form_urlencoded::Serializer::new(String::new())
-            .append_pair("query", &self.sql)
-            .finish();
+        let mut uri = url::form_urlencoded::Serializer::new(String::new());
+        uri.append_pair("query", &self.sql);
+        if let Some(key) = &self.idempotency_key {
+            uri.append_pair("idempotency_key", key);
+        }
+        let uri = uri.finish();
         let uri = "/?".to_string() + &uri;
         let uri = uri.parse::<Uri>().unwrap();
         let (method, body) = match self.body {
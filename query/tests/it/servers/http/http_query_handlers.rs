@@ -31,6 +31,7 @@ use databend_query::servers::http::v1::query_route;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSession;
 use databend_query::servers::http::v1::QueryResponse;
+use databend_query::servers::http::v1::ScanPartitions;
 use databend_query::servers::HttpHandler;
 use databend_query::sessions::SessionManager;
 use databend_query::users::auth::jwt::CustomClaims;
@@ -454,6 +455,23 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_node_load_hints() -> Result<()> {
+    let ep = create_endpoint();
+    let json = serde_json::json!({"sql": "select 1", "session": {"max_idle_time": 10}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.nodes.len(), 1, "{:?}", result);
+
+    let node = &result.nodes[0];
+    assert!(!node.http_address.is_empty(), "{:?}", node);
+    assert!(node.active_sessions >= 1, "{:?}", node);
+    assert!(node.max_sessions >= node.active_sessions, "{:?}", node);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[ignore = "flaky, sleep time unreliable"]
 async fn test_result_timeout() -> Result<()> {
@@ -552,6 +570,43 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_scan_partitions() -> Result<()> {
+    let route = create_endpoint();
+
+    // two insertions, two blocks
+    let sqls = vec![
+        "create table t(a int) engine=fuse",
+        "insert into t(a) values (1),(2)",
+        "insert into t(a) values (3),(4)",
+    ];
+
+    for sql in sqls {
+        let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 3}});
+        let (status, result) = post_json_to_endpoint(&route, &json).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result.error);
+    }
+
+    // the block of the first insertion is pruned by its min/max
+    let sql = "select * from t where a > 2";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 3}});
+    let (status, result) = post_json_to_endpoint(&route, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data.len(), 2, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    assert_eq!(
+        result.stats.scan_partitions,
+        Some(ScanPartitions {
+            total: 2,
+            scanned: 1
+        }),
+        "{:?}",
+        result
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_query_log() -> Result<()> {
     let session_manager = SessionManagerBuilder::create().build().unwrap();
@@ -710,6 +765,33 @@ async fn post_sql_to_endpoint(
     post_sql_to_endpoint_new_session(ep, sql, wait_time_secs, 0).await
 }
 
+#[tokio::test]
+async fn test_column_name_case() -> Result<()> {
+    let ep = create_endpoint();
+    let sql = "select number as Num, Number, number + 1, t.number from numbers(1) t";
+    let cases = [
+        ("lower", ["num", "number", "number + 1", "number"]),
+        ("preserve", ["Num", "Number", "number + 1", "number"]),
+        ("alias", ["Num", "Number", "_col3", "number"]),
+    ];
+    for (case, expected) in cases {
+        let json = serde_json::json!({"sql": sql.to_string(), "session": { "settings": {"enable_planner_v2": "1", "column_name_case": case}}});
+        let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result.error);
+        let names = result
+            .schema
+            .as_ref()
+            .unwrap()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, expected, "{}", case);
+    }
+    Ok(())
+}
+
 async fn post_sql_to_endpoint_new_session(
     ep: &EndpointType,
     sql: &str,
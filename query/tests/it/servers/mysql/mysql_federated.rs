@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::base::tokio;
 use common_datablocks::assert_blocks_eq;
 use common_exception::Result;
 use databend_query::servers::MySQLFederated;
@@ -78,3 +79,56 @@ fn test_mysql_federated() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mysql_federated_with_settings() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let settings = ctx.get_settings();
+    settings.set_settings("timezone".to_string(), "Asia/Shanghai".to_string(), false)?;
+    let federated = MySQLFederated::create_with_settings(&settings);
+
+    // select @@variable of the settings.
+    {
+        let query = "select @@max_block_size, @@session.time_zone as tz";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some(block) = result {
+            let expect = vec![
+                "+------------------+---------------+",
+                "| @@max_block_size | tz            |",
+                "+------------------+---------------+",
+                "| 10000            | Asia/Shanghai |",
+                "+------------------+---------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // select @@version_comment of the mysql client.
+    {
+        let query = "select @@version_comment limit 1";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some(block) = result {
+            assert_eq!(block.num_rows(), 1);
+            assert_eq!(block.schema().field(0).name(), "@@version_comment");
+            assert_ne!(block.column(0).get_checked(0)?.to_string(), "0");
+        }
+    }
+
+    // SET and SHOW VARIABLES of the settings go to the query.
+    {
+        assert!(federated.check("SET max_block_size = 1").is_none());
+        assert!(federated.check("SET SESSION max_block_size = 1").is_none());
+        assert!(federated.check("SET GLOBAL max_block_size = 1").is_none());
+        assert!(federated
+            .check("SHOW VARIABLES LIKE 'max_block_size'")
+            .is_none());
+        assert!(federated.check("SET SESSION sql_mode = ''").is_some());
+    }
+
+    Ok(())
+}
@@ -21,6 +21,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::MySQLTlsConfig;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -28,6 +29,8 @@ use mysql_async::Row;
 use tokio::sync::Barrier;
 use tokio::task::JoinHandle;
 
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
 use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -45,6 +48,184 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stream_query_result_with_flush_threshold() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    connection
+        .query_drop("SET max_block_size = 100")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    connection
+        .query_drop("SET mysql_flush_threshold = 1000")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+
+    let numbers: Vec<u64> = connection
+        .query("SELECT number FROM numbers(100000)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(numbers.len(), 100000);
+    assert_eq!(numbers.iter().sum::<u64>(), 99999 * 100000 / 2);
+
+    // Errors raised by the query are still reported to the client.
+    let result = connection
+        .query_drop("SELECT * FROM system.not_exists")
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_multi_statements_with_on_query() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    let mut result = connection
+        .query_iter("SET max_block_size = 100; SELECT 1; SELECT 'a;b'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+
+    let mut result_sets = vec![];
+    while !result.is_empty() {
+        let rows: Vec<Row> = result
+            .collect()
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+        result_sets.push(rows.len());
+    }
+    drop(result);
+    // Only the result of the last statement is returned.
+    assert_eq!(result_sets, vec![1]);
+
+    // The statements are executed in the same session.
+    let values: Vec<String> = connection
+        .query("SELECT value FROM system.settings WHERE name = 'max_block_size'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(values, vec!["100".to_string()]);
+
+    // The remaining statements are skipped once one of them fails.
+    let result = connection
+        .query_drop("SELECT 1; SELECT * FROM system.not_exists; SET max_block_size = 200")
+        .await;
+    assert!(result.is_err());
+
+    let values: Vec<String> = connection
+        .query("SELECT value FROM system.settings WHERE name = 'max_block_size'")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(values, vec!["100".to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_affected_rows_with_insert() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+
+    connection
+        .query_drop("CREATE TABLE default.t_affected(a INT) ENGINE = Memory")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    connection
+        .query_drop("INSERT INTO default.t_affected VALUES (1), (2), (3)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(connection.affected_rows(), 3);
+    assert!(connection.info().starts_with("Written 3 rows"));
+
+    connection
+        .query_drop("SELECT * FROM default.t_affected")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(connection.affected_rows(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plain_connection_with_tls_enabled() -> Result<()> {
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(1)
+            .mysql_tls_server_cert(TEST_SERVER_CERT)
+            .mysql_tls_server_key(TEST_SERVER_KEY)
+            .build()?,
+    );
+
+    // Clients without SSL still work when the server advertises it.
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port()).await?;
+    let numbers: Vec<u64> = connection
+        .query("SELECT number FROM numbers(3)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query error")?;
+    assert_eq!(numbers, vec![0, 1, 2]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plain_connection_with_secure_transport_required() -> Result<()> {
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(1)
+            .mysql_tls_server_cert(TEST_SERVER_CERT)
+            .mysql_tls_server_key(TEST_SERVER_KEY)
+            .mysql_require_secure_transport(true)
+            .build()?,
+    );
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    match create_connection(runnable_server.port()).await {
+        Ok(_) => panic!("Expected rejected connection"),
+        Err(error) => assert!(error.message().contains("(3159)")),
+    };
+
+    // TLS must be enabled to require it.
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(1)
+            .mysql_require_secure_transport(true)
+            .build()?,
+    );
+    assert!(handler.start(listening).await.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_tls_config() -> Result<()> {
+    // TLS is disabled without cert and key.
+    assert!(MySQLTlsConfig::try_create("", "")?.is_none());
+
+    let config = MySQLTlsConfig::try_create(TEST_SERVER_CERT, TEST_SERVER_KEY)?;
+    assert!(config.is_some());
+
+    let config = MySQLTlsConfig::try_create(TEST_SERVER_CERT, "not_exists.key");
+    assert!(config.is_err());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod query_cache;
 mod query_ctx;
 mod session;
 mod session_context;
 mod session_setting;
+mod session_txn;
+mod temp_storage;
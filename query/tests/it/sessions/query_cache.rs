@@ -0,0 +1,178 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::normalize_query;
+use databend_query::sessions::QueryCacheKey;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn new_query_context(fixture: &TestFixture, query: &str) -> Result<Arc<QueryContext>> {
+    // A new query sees the latest version of the tables.
+    let ctx = fixture
+        .ctx()
+        .get_current_session()
+        .create_query_context()
+        .await?;
+    ctx.attach_query_str(query);
+    Ok(ctx)
+}
+
+async fn cache_key(fixture: &TestFixture, query: &str) -> Result<Option<QueryCacheKey>> {
+    let ctx = new_query_context(fixture, query).await?;
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    QueryCacheKey::try_create(&ctx, &plan)
+}
+
+#[test]
+fn test_normalize_query() {
+    assert_eq!(
+        normalize_query("  select  a,\n\tb from t  ;  "),
+        "select a, b from t"
+    );
+    assert_eq!(
+        normalize_query("select 'a  b' from t where c = \"x  ;\";"),
+        "select 'a  b' from t where c = \"x  ;\""
+    );
+}
+
+#[tokio::test]
+async fn test_query_cache_key() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = format!(
+        "{}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let query = format!("select * from {}", table);
+    let key = cache_key(&fixture, &query).await?;
+    assert!(key.is_some());
+
+    // formatted differently
+    let reformatted = format!("select  *\n  from {} ;", table);
+    assert_eq!(key, cache_key(&fixture, &reformatted).await?);
+
+    // non-deterministic functions
+    let query_now = format!("select now() from {}", table);
+    assert!(cache_key(&fixture, &query_now).await?.is_none());
+
+    // table functions are not versioned
+    assert!(cache_key(&fixture, "select * from numbers(10)")
+        .await?
+        .is_none());
+
+    // a new version of the table
+    append_sample_data(1, &fixture).await?;
+    let new_key = cache_key(&fixture, &query).await?;
+    assert!(new_key.is_some());
+    assert_ne!(key, new_key);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_cache_hit() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let query = format!(
+        "select count(*) from {}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let key = cache_key(&fixture, &query).await?.unwrap();
+
+    let ctx = new_query_context(&fixture, &query).await?;
+    let expected = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let cached = ctx.get_query_cache().get(&key).unwrap();
+    assert_eq!(cached.blocks.len(), expected.len());
+
+    // served from the cache
+    let ctx = new_query_context(&fixture, &query).await?;
+    let result = execute_query(ctx, &query)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(result, expected);
+
+    // disabled by the setting
+    let ctx = new_query_context(&fixture, &query).await?;
+    ctx.get_settings().set_settings(
+        "enable_query_result_cache".to_string(),
+        "0".to_string(),
+        false,
+    )?;
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let disabled_key = QueryCacheKey::try_create(&ctx, &plan)?.unwrap();
+    execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(ctx.get_query_cache().get(&disabled_key).is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_drop_query_cache() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let query = format!(
+        "select count(*) from {}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    for _ in 0..2 {
+        let ctx = new_query_context(&fixture, &query).await?;
+        execute_command(ctx, &query).await?;
+    }
+
+    let status = fixture.ctx().get_query_cache().status();
+    assert_eq!(status.name, "query_result");
+    assert_eq!(status.entries, 1);
+    assert!(status.size > 0);
+    assert_eq!((status.hits, status.misses), (1, 1));
+
+    execute_command(fixture.ctx(), "SYSTEM DROP CACHE query_result").await?;
+    let status = fixture.ctx().get_query_cache().status();
+    assert_eq!((status.entries, status.size), (0, 0));
+    assert_eq!((status.hits, status.misses), (1, 1));
+
+    let res = execute_command(fixture.ctx(), "SYSTEM DROP CACHE plan").await;
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().message(),
+        "Unknown cache 'plan', expected one of: table_snapshot, segment_info, query_result"
+    );
+
+    Ok(())
+}
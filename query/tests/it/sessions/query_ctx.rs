@@ -12,11 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::StorageFsConfig;
 use common_io::prelude::StorageParams;
 use common_io::prelude::StorageS3Config;
+use databend_query::catalogs::CatalogSnapshot;
+use databend_query::catalogs::CATALOG_DEFAULT;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::PlanParser;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 use wiremock::Mock;
@@ -62,3 +70,55 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    let _ = interpreter.execute(None).await?;
+    Ok(())
+}
+
+fn table_names(snapshot: &CatalogSnapshot) -> Vec<String> {
+    let mut names: Vec<String> = snapshot
+        .list_tables("db_snapshot")
+        .unwrap()
+        .iter()
+        .map(|t| t.name().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_catalog_snapshot() -> Result<()> {
+    let ddl_ctx = crate::tests::create_query_context().await?;
+    let session = ddl_ctx.get_current_session();
+    execute(ddl_ctx.clone(), "create database db_snapshot").await?;
+    execute(ddl_ctx, "create table db_snapshot.t1(a int)").await?;
+
+    // The query lists the catalog before the DDL of the other session.
+    let ctx = session.create_query_context().await?;
+    let snapshot = ctx.get_catalog_snapshot(CATALOG_DEFAULT).await?;
+    assert_eq!(table_names(&snapshot), vec!["t1"]);
+
+    let ddl_ctx = session.create_query_context().await?;
+    execute(ddl_ctx.clone(), "create table db_snapshot.t2(a int)").await?;
+    execute(ddl_ctx, "drop table db_snapshot.t1").await?;
+
+    // Listing and resolving again in the same query sees the same catalog.
+    let snapshot = ctx.get_catalog_snapshot(CATALOG_DEFAULT).await?;
+    assert_eq!(table_names(&snapshot), vec!["t1"]);
+    assert!(ctx
+        .get_table(CATALOG_DEFAULT, "db_snapshot", "t1")
+        .await
+        .is_ok());
+    let res = ctx.get_table(CATALOG_DEFAULT, "db_snapshot", "t2").await;
+    assert_eq!(res.err().unwrap().code(), ErrorCode::unknown_table_code());
+
+    // A new query sees the DDL.
+    let ctx = session.create_query_context().await?;
+    let snapshot = ctx.get_catalog_snapshot(CATALOG_DEFAULT).await?;
+    assert_eq!(table_names(&snapshot), vec!["t2"]);
+
+    Ok(())
+}
@@ -92,3 +92,32 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_mysql_conn_id() -> Result<()> {
+    let sessions = crate::tests::SessionManagerBuilder::create().build()?;
+
+    let session1 = sessions.create_session(SessionType::MySQL).await?;
+    let session2 = sessions.create_session(SessionType::MySQL).await?;
+
+    let conn_id1 = session1.get_mysql_conn_id();
+    let conn_id2 = session2.get_mysql_conn_id();
+    assert!(conn_id1.is_some());
+    assert!(conn_id2.is_some());
+    assert_ne!(conn_id1, conn_id2);
+
+    // Connection id resolves to the session id until the session is destroyed.
+    let session_id = session1.get_id();
+    let actual = sessions.get_id_by_mysql_conn_id(&conn_id1).await;
+    assert_eq!(actual, Some(session_id));
+
+    drop(session1);
+    let actual = sessions.get_id_by_mysql_conn_id(&conn_id1).await;
+    assert!(actual.is_none());
+
+    // Other session types have no MySQL connection id.
+    let session3 = sessions.create_session(SessionType::Dummy).await?;
+    assert!(session3.get_mysql_conn_id().is_none());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use databend_query::sessions::SessionTransaction;
+
+fn update(table_id: u64, seq: u64, snapshot: &str) -> UpdateTableMetaReq {
+    let mut new_table_meta = TableMeta::default();
+    new_table_meta
+        .options
+        .insert("snapshot_location".to_string(), snapshot.to_string());
+    UpdateTableMetaReq {
+        table_id,
+        seq: MatchSeq::Exact(seq),
+        new_table_meta,
+    }
+}
+
+#[test]
+fn test_session_transaction() -> Result<()> {
+    let txn = SessionTransaction::default();
+
+    // Nothing written, nothing to commit.
+    assert!(txn.take_updates().is_none());
+
+    txn.update_table_meta("default", update(1, 10, "s1"))?;
+    txn.update_table_meta("default", update(2, 20, "s2"))?;
+    assert_eq!(txn.get_table_meta(1), Some(update(1, 10, "s1").new_table_meta));
+    assert_eq!(txn.get_table_meta(3), None);
+
+    // The table is written again as of the transaction, the version read first is kept.
+    txn.update_table_meta("default", update(1, 11, "s3"))?;
+    assert_eq!(txn.get_table_meta(1), Some(update(1, 10, "s3").new_table_meta));
+
+    // The tables of another catalog can't be committed along.
+    let res = txn.update_table_meta("hive", update(4, 40, "s4"));
+    assert_eq!(res.unwrap_err().code(), ErrorCode::InvalidTransactionStateCode());
+
+    txn.add_copied_files(1, BTreeMap::new());
+    assert_eq!(txn.take_copied_files().len(), 1);
+
+    let (catalog, updates) = txn.take_updates().unwrap();
+    assert_eq!(catalog, "default");
+    assert_eq!(updates, vec![update(1, 10, "s3"), update(2, 20, "s2")]);
+    assert!(txn.take_updates().is_none());
+
+    Ok(())
+}
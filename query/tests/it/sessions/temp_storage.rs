@@ -0,0 +1,139 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::TempStorageReservation;
+
+#[tokio::test]
+async fn test_temp_storage_query_limit() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_settings(
+        "max_temp_storage_bytes".to_string(),
+        "100".to_string(),
+        false,
+    )?;
+    let manager = ctx.get_temp_storage_manager();
+
+    let mut first = TempStorageReservation::create(&ctx)?;
+    let mut second = TempStorageReservation::create(&ctx)?;
+    first.reserve(60)?;
+
+    // the reservations of the same query share the limit
+    let err = second.reserve(50).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::QueryLimitExceeded("").code());
+    assert_eq!(second.reserved_bytes(), 0);
+
+    second.reserve(40)?;
+    assert_eq!(manager.user_used_bytes("root"), 100);
+    // only the successful reservations are accounted as spilled
+    assert_eq!(ctx.get_dal_metrics().get_spill_bytes(), 100);
+
+    first.release(30);
+    assert_eq!(first.reserved_bytes(), 30);
+    assert_eq!(manager.user_used_bytes("root"), 70);
+
+    // released on drop
+    drop(first);
+    drop(second);
+    assert_eq!(manager.user_used_bytes("root"), 0);
+    assert!(manager.usages().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_temp_storage_user_quota() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.temp_storage_quota_mb_per_user = 1;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    let manager = ctx.get_temp_storage_manager();
+    assert_eq!(manager.quota_per_user(), 1024 * 1024);
+
+    let other_ctx = ctx.get_current_session().create_query_context().await?;
+
+    let mut first = TempStorageReservation::create(&ctx)?;
+    first.reserve(1024 * 1024 - 10)?;
+
+    // only the query asking for more space fails
+    let mut second = TempStorageReservation::create(&other_ctx)?;
+    let err = second.reserve(11).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::QueryLimitExceeded("").code());
+    second.reserve(10)?;
+    assert_eq!(first.reserved_bytes(), 1024 * 1024 - 10);
+
+    let usages = manager.usages();
+    assert_eq!(usages.len(), 2);
+    assert!(usages.iter().all(|usage| usage.user == "root"));
+
+    drop(first);
+    second.reserve(11)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_temp_storage_node_quota() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.temp_storage_quota_mb = 1;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    let manager = ctx.get_temp_storage_manager();
+    assert_eq!(manager.quota(), 1024 * 1024);
+
+    let other_ctx = ctx.get_current_session().create_query_context().await?;
+
+    let mut first = TempStorageReservation::create(&ctx)?;
+    first.reserve(1024 * 1024 - 10)?;
+
+    let mut second = TempStorageReservation::create(&other_ctx)?;
+    let err = second.reserve(11).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::QueryLimitExceeded("").code());
+    second.reserve(10)?;
+    assert_eq!(manager.used_bytes(), 1024 * 1024);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_temp_storage_query_dir() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let manager = ctx.get_temp_storage_manager();
+    let query_dir = manager.root().join(ctx.get_id());
+
+    let reservation = TempStorageReservation::create(&ctx)?;
+    let path = reservation.file_path("sort-0");
+    std::fs::write(&path, b"spilled")?;
+    assert_eq!(path.parent(), Some(query_dir.as_path()));
+
+    // removed once the query ends
+    drop(reservation);
+    drop(ctx);
+    assert!(!query_dir.exists());
+    assert!(manager.root().exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_temp_storage_orphans() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let orphan = Path::new(&conf.query.temp_storage_root).join("crashed-query");
+    std::fs::create_dir_all(&orphan)?;
+    std::fs::write(orphan.join("sort-0"), b"spilled")?;
+
+    // the files left by a crashed node are removed when it starts
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    assert!(!orphan.exists());
+    assert!(ctx.get_temp_storage_manager().root().exists());
+    Ok(())
+}
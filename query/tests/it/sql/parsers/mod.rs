@@ -15,11 +15,15 @@
 mod parser_call;
 mod parser_copy;
 mod parser_database;
+mod parser_delete;
 mod parser_optimize;
 mod parser_select_table_at;
 mod parser_show;
 mod parser_stage;
 mod parser_table;
+mod parser_transaction;
 mod parser_udf;
+mod parser_update;
 mod parser_use;
 mod parser_user;
+mod parser_view;
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use common_exception::Result;
+use databend_query::sessions::SessionType;
 use databend_query::sql::statements::DfCopy;
+use databend_query::sql::DfParser;
 use databend_query::sql::DfStatement;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
@@ -29,38 +31,73 @@ fn copy_from_external_test() -> Result<()> {
         expect: Option<DfCopy>,
     }
 
-    let tests = vec![Test {
-        query: "copy into mytable
+    let tests = vec![
+        Test {
+            query: "copy into mytable
         from 's3://mybucket/data/files'
         credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1);",
-        err: "",
-        expect: Some(DfCopy {
-            name: ObjectName(vec![Ident::new("mytable")]),
-            columns: vec![],
-            location: "s3://mybucket/data/files".to_string(),
-            credential_options: maplit::btreemap! {
-                   "aws_key_id".into() => "my_key_id".into(),
-                   "aws_secret_key".into() => "my_secret_key".into(),
-            },
-            encryption_options: maplit::btreemap! {
-                   "master_key".into() => "my_master_key".into(),
-            },
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: maplit::btreemap! {
+                       "aws_key_id".into() => "my_key_id".into(),
+                       "aws_secret_key".into() => "my_secret_key".into(),
+                },
+                encryption_options: maplit::btreemap! {
+                       "master_key".into() => "my_master_key".into(),
+                },
 
-            file_format_options: maplit::btreemap! {
-                   "type".into() => "csv".into(),
-                   "field_delimiter".into() => "|".into(),
-                   "skip_header".into() => "1".into(),
-            },
-            files: vec![],
-            pattern: "".to_string(),
-            on_error: "".to_string(),
-            size_limit: "".to_string(),
-            validation_mode: "".to_string(),
-            query: None,
-        }),
-    }];
+                file_format_options: maplit::btreemap! {
+                       "type".into() => "csv".into(),
+                       "field_delimiter".into() => "|".into(),
+                       "skip_header".into() => "1".into(),
+                },
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "".to_string(),
+                max_file_size: "".to_string(),
+                ordered: "".to_string(),
+                validation_mode: "".to_string(),
+                query: None,
+                spool: false,
+            }),
+        },
+        Test {
+            query: "copy into mytable
+        from 's3://mybucket/data/files'
+        file_format = (type = csv)
+        match_by_column_name = case_insensitive
+        max_file_size = 1024
+        ordered = true;",
+            err: "",
+            expect: Some(DfCopy {
+                name: ObjectName(vec![Ident::new("mytable")]),
+                columns: vec![],
+                location: "s3://mybucket/data/files".to_string(),
+                credential_options: Default::default(),
+                encryption_options: Default::default(),
+                file_format_options: maplit::btreemap! {
+                       "type".into() => "csv".into(),
+                },
+                files: vec![],
+                pattern: "".to_string(),
+                on_error: "".to_string(),
+                size_limit: "".to_string(),
+                match_by_column_name: "case_insensitive".to_string(),
+                max_file_size: "1024".to_string(),
+                ordered: "true".to_string(),
+                validation_mode: "".to_string(),
+                query: None,
+                spool: false,
+            }),
+        },
+    ];
 
     for test in tests {
         if test.err.is_empty() {
@@ -72,3 +109,39 @@ fn copy_from_external_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn select_into_stage_test() -> Result<()> {
+    // SELECT ... INTO is COPY INTO the location FROM the query, with the result spooled.
+    let copy = |sql: &str| -> Result<DfCopy> {
+        match DfParser::parse_sql(sql, SessionType::Dummy)?.0.remove(0) {
+            DfStatement::Copy(copy) => Ok(copy),
+            other => panic!("expect copy statement, got {:?}", other),
+        }
+    };
+
+    let mut expect = copy(
+        "copy into @my_stage/exports/ from (select a, count(*) from t group by a) file_format = (type = parquet) max_file_size = 1024",
+    )?;
+    expect.spool = true;
+    expect_parse_ok(
+        "select a, count(*) from t group by a into @my_stage/exports/ file_format = (type = parquet) max_file_size = 1024",
+        DfStatement::Copy(expect),
+    )?;
+
+    let mut expect = copy(
+        "copy into 's3://mybucket/exports/' from (select * from (select a from t) s where a > 1) credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')",
+    )?;
+    expect.spool = true;
+    expect_parse_ok(
+        "select * from (select a from t) s where a > 1 into 's3://mybucket/exports/' credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')",
+        DfStatement::Copy(expect),
+    )?;
+
+    expect_parse_err(
+        "select a from t into @my_stage/exports/ pattern",
+        "sql parser error: Expected =, found: EOF".to_string(),
+    )?;
+
+    Ok(())
+}
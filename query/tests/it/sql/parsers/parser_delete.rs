@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfDeleteStatement;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn delete_from() -> Result<()> {
+    {
+        let sql = "DELETE FROM t1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "delete from db1.t1 where a > 1 and b = 'x'";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(parse_sql_to_expr("a > 1 and b = 'x'")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DELETE t1";
+        expect_parse_err_contains(sql, "Expected FROM, found: t1".to_string())?;
+    }
+
+    Ok(())
+}
@@ -58,6 +58,15 @@ fn optimize_table() -> Result<()> {
         expect_parse_ok(sql, expected)?;
     }
 
+    {
+        let sql = "optimize TABLE t1 recluster";
+        let expected = DfStatement::OptimizeTable(DfOptimizeTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: Optimization::RECLUSTER,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
     {
         let sql = "optimize TABLE t1 all";
         let expected = DfStatement::OptimizeTable(DfOptimizeTable {
@@ -71,7 +80,7 @@ fn optimize_table() -> Result<()> {
         let sql = "optimize TABLE t1 unacceptable";
         expect_parse_err(
             sql,
-            "sql parser error: Expected one of PURGE, COMPACT, ALL, found: unacceptable"
+            "sql parser error: Expected one of PURGE, COMPACT, RECLUSTER, ALL, found: unacceptable"
                 .to_string(),
         )?;
     }
@@ -80,7 +89,7 @@ fn optimize_table() -> Result<()> {
         let sql = "optimize TABLE t1 (";
         expect_parse_err(
             sql,
-            "sql parser error: Expected Nothing, or one of PURGE, COMPACT, ALL, found: ("
+            "sql parser error: Expected Nothing, or one of PURGE, COMPACT, RECLUSTER, ALL, found: ("
                 .to_string(),
         )?;
     }
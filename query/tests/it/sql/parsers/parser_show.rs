@@ -14,14 +14,18 @@
 
 use common_exception::Result;
 use databend_query::sql::statements::DfDescribeTable;
+use databend_query::sql::statements::DfDropCache;
+use databend_query::sql::statements::DfShowCaches;
 use databend_query::sql::statements::DfShowDatabases;
 use databend_query::sql::statements::DfShowEngines;
 use databend_query::sql::statements::DfShowFunctions;
 use databend_query::sql::statements::DfShowKind;
+use databend_query::sql::statements::DfShowReplicationStatus;
 use databend_query::sql::statements::DfShowSettings;
 use databend_query::sql::statements::DfShowStages;
 use databend_query::sql::statements::DfShowTabStat;
 use databend_query::sql::statements::DfShowTables;
+use databend_query::sql::statements::DfShowVariables;
 use databend_query::sql::*;
 use sqlparser::ast::*;
 
@@ -279,6 +283,32 @@ fn show_functions_tests() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn show_variables_test() -> Result<()> {
+    expect_parse_ok(
+        "SHOW VARIABLES",
+        DfStatement::ShowVariables(DfShowVariables::create(DfShowKind::All)),
+    )?;
+    expect_synonym_parse_eq("SHOW VARIABLES", "SHOW SESSION VARIABLES")?;
+    expect_synonym_parse_eq("SHOW VARIABLES", "SHOW GLOBAL VARIABLES")?;
+
+    expect_parse_ok(
+        "SHOW VARIABLES LIKE 'max%'",
+        DfStatement::ShowVariables(DfShowVariables::create(DfShowKind::Like(
+            Ident::with_quote('\'', "max%"),
+        ))),
+    )?;
+
+    expect_parse_ok(
+        "SHOW SESSION VARIABLES WHERE name = 'max_threads'",
+        DfStatement::ShowVariables(DfShowVariables::create(DfShowKind::Where(
+            parse_sql_to_expr("name = 'max_threads'"),
+        ))),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn show_databases_test() -> Result<()> {
     expect_parse_ok(
@@ -334,6 +364,42 @@ fn show_engines_test() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn show_replication_status_test() -> Result<()> {
+    expect_parse_ok(
+        "SHOW REPLICATION STATUS",
+        DfStatement::ShowReplicationStatus(DfShowReplicationStatus),
+    )?;
+    expect_parse_ok(
+        "show replication status;",
+        DfStatement::ShowReplicationStatus(DfShowReplicationStatus),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn show_caches_test() -> Result<()> {
+    expect_parse_ok("SHOW CACHES", DfStatement::ShowCaches(DfShowCaches))?;
+    expect_parse_ok("show caches;", DfStatement::ShowCaches(DfShowCaches))?;
+    expect_parse_ok(
+        "SYSTEM DROP CACHE query_result",
+        DfStatement::DropCache(DfDropCache {
+            name: Ident::new("query_result"),
+        }),
+    )?;
+    expect_parse_ok(
+        "system drop cache 'segment_info';",
+        DfStatement::DropCache(DfDropCache {
+            name: Ident::with_quote('\'', "segment_info"),
+        }),
+    )?;
+    expect_parse_err_contains(
+        "SYSTEM DROP CACHE",
+        "Expected cache name, found: EOF".to_string(),
+    )?;
+    Ok(())
+}
+
 #[test]
 fn show_tab_stat_test() -> Result<()> {
     expect_parse_ok(
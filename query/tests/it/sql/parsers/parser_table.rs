@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use common_exception::Result;
@@ -189,6 +190,7 @@ fn drop_table() -> Result<()> {
             if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             all: false,
+            cascade: false,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -199,6 +201,7 @@ fn drop_table() -> Result<()> {
             if_exists: true,
             name: ObjectName(vec![Ident::new("t1")]),
             all: false,
+            cascade: false,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -209,6 +212,29 @@ fn drop_table() -> Result<()> {
             if_exists: false,
             name: ObjectName(vec![Ident::new("t1")]),
             all: true,
+            cascade: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP TABLE t1 CASCADE";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("t1")]),
+            all: false,
+            cascade: true,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP TABLE t1 ALL CASCADE";
+        let expected = DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("t1")]),
+            all: true,
+            cascade: true,
         });
         expect_parse_ok(sql, expected)?;
     }
@@ -321,3 +347,250 @@ fn alter_cluster_key() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn create_table_with_ttl() -> Result<()> {
+    {
+        let sql = "CREATE TABLE t(c1 int) TTL c1 + 1";
+        let expected = DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "FUSE".to_string(),
+            options: maplit::btreemap! {"ttl".into() => "c1 + 1".into()},
+            like: None,
+            query: None,
+            cluster_keys: vec![],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        // the form rendered by `SHOW CREATE TABLE`
+        let sql = "CREATE TABLE t(c1 int) ENGINE = Fuse TTL = 'c1 + 1' comment = 'foo'";
+        let expected = DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "Fuse".to_string(),
+            options: maplit::btreemap! {
+                "ttl".into() => "c1 + 1".into(),
+                "comment".into() => "foo".into(),
+            },
+            like: None,
+            query: None,
+            cluster_keys: vec![],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn create_table_with_broadcast() -> Result<()> {
+    {
+        let sql = "CREATE TABLE t(c1 int) BROADCAST";
+        let expected = DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "FUSE".to_string(),
+            options: maplit::btreemap! {"broadcast".into() => "true".into()},
+            like: None,
+            query: None,
+            cluster_keys: vec![],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        // the form rendered by `SHOW CREATE TABLE`
+        let sql = "CREATE TABLE t(c1 int) ENGINE = Fuse BROADCAST = 'false' comment = 'foo'";
+        let expected = DfStatement::CreateTable(DfCreateTable {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("t")]),
+            columns: vec![make_column_def("c1", None, DataType::Int(None))],
+            engine: "Fuse".to_string(),
+            options: maplit::btreemap! {
+                "broadcast".into() => "false".into(),
+                "comment".into() => "foo".into(),
+            },
+            like: None,
+            query: None,
+            cluster_keys: vec![],
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn create_table_with_partition_by() -> Result<()> {
+    let sql = "CREATE TABLE t(c1 int, c2 date) PARTITION BY (c2)";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def("c1", None, DataType::Int(None)),
+            make_column_def("c2", None, DataType::Date),
+        ],
+        engine: "FUSE".to_string(),
+        options: maplit::btreemap! {"partition_by".into() => "c2".into()},
+        like: None,
+        query: None,
+        cluster_keys: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn create_table_with_range_and_list_partitions() -> Result<()> {
+    let sql = "CREATE TABLE t(c1 int) PARTITION BY RANGE (c1) \
+        (PARTITION p0 VALUES LESS THAN (10), PARTITION p1 VALUES LESS THAN MAXVALUE)";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", None, DataType::Int(None))],
+        engine: "FUSE".to_string(),
+        options: maplit::btreemap! {
+            "partition_by".into() => "c1".into(),
+            "partitions".into() => r#"{"Range":[["p0","10"],["p1",null]]}"#.into(),
+        },
+        like: None,
+        query: None,
+        cluster_keys: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+
+    let sql = "CREATE TABLE t(c1 int) PARTITION BY LIST (c1) \
+        (PARTITION p0 VALUES IN (1, 2), PARTITION p1 VALUES IN (3))";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", None, DataType::Int(None))],
+        engine: "FUSE".to_string(),
+        options: maplit::btreemap! {
+            "partition_by".into() => "c1".into(),
+            "partitions".into() => r#"{"List":[["p0",["1","2"]],["p1",["3"]]]}"#.into(),
+        },
+        like: None,
+        query: None,
+        cluster_keys: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+
+    expect_parse_err(
+        "CREATE TABLE t(c1 int) PARTITION BY RANGE (c1) (PARTITION p0 VALUES IN (1))",
+        "sql parser error: Expected LESS, found: IN".to_string(),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn create_table_with_replica_of() -> Result<()> {
+    let sql = "CREATE TABLE t(c1 int) REPLICA OF '1/2'";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![make_column_def("c1", None, DataType::Int(None))],
+        engine: "FUSE".to_string(),
+        options: maplit::btreemap! {"replica_of".into() => "1/2".into()},
+        like: None,
+        query: None,
+        cluster_keys: vec![],
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn create_table_with_ddl_option_keys() -> Result<()> {
+    for key in ["partition_by", "PARTITIONS", "replica_of"] {
+        expect_parse_err(
+            &format!("CREATE TABLE t(c1 int) {} = 'c1'", key),
+            format!(
+                "sql parser error: table option {} is reserved, it is set by the PARTITION BY and REPLICA OF clauses",
+                key.to_lowercase()
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn alter_drop_partition() -> Result<()> {
+    let sql = "ALTER TABLE t1 DROP PARTITION '2022-01-01'";
+    let expected = DfStatement::AlterTable(DfAlterTable {
+        if_exists: false,
+        table_name: ObjectName(vec![Ident::new("t1")]),
+        action: AlterTableAction::DropPartition(vec![Expr::Value(Value::SingleQuotedString(
+            "2022-01-01".to_string(),
+        ))]),
+    });
+    expect_parse_ok(sql, expected)?;
+
+    let sql = "ALTER TABLE t1 DROP PARTITION p0, p1";
+    let expected = DfStatement::AlterTable(DfAlterTable {
+        if_exists: false,
+        table_name: ObjectName(vec![Ident::new("t1")]),
+        action: AlterTableAction::DropPartition(vec![
+            Expr::Identifier(Ident::new("p0")),
+            Expr::Identifier(Ident::new("p1")),
+        ]),
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn alter_promote() -> Result<()> {
+    let sql = "ALTER TABLE db1.t1 PROMOTE";
+    let expected = DfStatement::AlterTable(DfAlterTable {
+        if_exists: false,
+        table_name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        action: AlterTableAction::Promote,
+    });
+    expect_parse_ok(sql, expected)?;
+    Ok(())
+}
+
+#[test]
+fn alter_export_snapshot() -> Result<()> {
+    let sql = "ALTER TABLE t1 EXPORT SNAPSHOT TO '@s1/exports/t1/' FORMAT PARQUET";
+    let expected = DfStatement::AlterTable(DfAlterTable {
+        if_exists: false,
+        table_name: ObjectName(vec![Ident::new("t1")]),
+        action: AlterTableAction::ExportSnapshot {
+            location: "@s1/exports/t1/".to_string(),
+            credential_options: BTreeMap::default(),
+            encryption_options: BTreeMap::default(),
+            format: "PARQUET".to_string(),
+        },
+    });
+    expect_parse_ok(sql, expected)?;
+
+    let sql = "ALTER TABLE t1 EXPORT SNAPSHOT TO 's3://bucket/t1/' CREDENTIALS=(aws_key_id='a' aws_secret_key='b') FORMAT parquet";
+    let expected = DfStatement::AlterTable(DfAlterTable {
+        if_exists: false,
+        table_name: ObjectName(vec![Ident::new("t1")]),
+        action: AlterTableAction::ExportSnapshot {
+            location: "s3://bucket/t1/".to_string(),
+            credential_options: [
+                ("aws_key_id".to_string(), "a".to_string()),
+                ("aws_secret_key".to_string(), "b".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+            encryption_options: BTreeMap::default(),
+            format: "parquet".to_string(),
+        },
+    });
+    expect_parse_ok(sql, expected)?;
+
+    expect_parse_err_contains(
+        "ALTER TABLE t1 EXPORT SNAPSHOT TO '@s1/t1/'",
+        "Expected FORMAT, found: EOF".to_string(),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_planners::TransactionAction;
+use databend_query::sql::statements::DfTransaction;
+use databend_query::sql::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn transaction_test() -> Result<()> {
+    let cases = [
+        ("BEGIN", TransactionAction::Begin),
+        ("begin work;", TransactionAction::Begin),
+        ("BEGIN TRANSACTION", TransactionAction::Begin),
+        ("start transaction", TransactionAction::Begin),
+        ("COMMIT", TransactionAction::Commit),
+        ("commit work;", TransactionAction::Commit),
+        ("ROLLBACK", TransactionAction::Rollback),
+        ("rollback work", TransactionAction::Rollback),
+    ];
+    for (sql, action) in cases {
+        expect_parse_ok(sql, DfStatement::Transaction(DfTransaction { action }))?;
+    }
+
+    expect_parse_err_contains("START", "Expected TRANSACTION, found: EOF".to_string())?;
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_meta_types::UDFLanguage;
 use databend_query::sql::statements::DfAlterUDF;
 use databend_query::sql::statements::DfCreateUDF;
 use databend_query::sql::statements::DfDropUDF;
@@ -65,6 +66,11 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string()],
             definition: "not(is_not_null(p))".to_string(),
             description: "".to_string(),
+            language: UDFLanguage::Sql,
+            arg_types: vec![],
+            return_type: "".to_string(),
+            handler: "".to_string(),
+            address: "".to_string(),
         }),
     )?;
 
@@ -76,6 +82,11 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(is_not_null(p,d))".to_string(),
             description: "".to_string(),
+            language: UDFLanguage::Sql,
+            arg_types: vec![],
+            return_type: "".to_string(),
+            handler: "".to_string(),
+            address: "".to_string(),
         }),
     )?;
 
@@ -97,6 +108,11 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(is_not_null(p,d))".to_string(),
             description: "this is a description".to_string(),
+            language: UDFLanguage::Sql,
+            arg_types: vec![],
+            return_type: "".to_string(),
+            handler: "".to_string(),
+            address: "".to_string(),
         }),
     )?;
 
@@ -108,9 +124,71 @@ fn test_create_udf() -> Result<()> {
             parameters: vec!["p".to_string(), "d".to_string()],
             definition: "not(is_not_null(p,d))".to_string(),
             description: "this is a description".to_string(),
+            language: UDFLanguage::Sql,
+            arg_types: vec![],
+            return_type: "".to_string(),
+            handler: "".to_string(),
+            address: "".to_string(),
         }),
     )?;
 
+    expect_parse_ok(
+        "CREATE FUNCTION plus AS (a Int64, b Int64) RETURNS Int64 LANGUAGE WASM AS '(module)' DESC = 'wasm'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "plus".to_string(),
+            parameters: vec!["a".to_string(), "b".to_string()],
+            definition: "(module)".to_string(),
+            description: "wasm".to_string(),
+            language: UDFLanguage::Wasm,
+            arg_types: vec!["Int64".to_string(), "Int64".to_string()],
+            return_type: "Int64".to_string(),
+            handler: "".to_string(),
+            address: "".to_string(),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "CREATE FUNCTION gcd AS (a Int32, b Int32) RETURNS Int32 LANGUAGE PYTHON HANDLER = 'gcd' ADDRESS = 'grpc://127.0.0.1:8815'",
+        DfStatement::CreateUDF(DfCreateUDF {
+            if_not_exists: false,
+            udf_name: "gcd".to_string(),
+            parameters: vec!["a".to_string(), "b".to_string()],
+            definition: "".to_string(),
+            description: "".to_string(),
+            language: UDFLanguage::Python,
+            arg_types: vec!["Int32".to_string(), "Int32".to_string()],
+            return_type: "Int32".to_string(),
+            handler: "gcd".to_string(),
+            address: "grpc://127.0.0.1:8815".to_string(),
+        }),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION gcd AS (a Int32) RETURNS Int32 LANGUAGE PYTHON ADDRESS = 'grpc://127.0.0.1:8815'",
+        "Expected HANDLER, found: ADDRESS".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION plus AS (a Int64, b) RETURNS Int64 LANGUAGE WASM AS '(module)'",
+        "Either all or none of the parameters should have a type".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION plus AS (a, b) RETURNS Int64 LANGUAGE WASM AS '(module)'",
+        "Parameters of WASM or PYTHON function must have types".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION plus AS (a Int64) RETURNS Int64 LANGUAGE JS AS '(module)'",
+        "Expected WASM or PYTHON after LANGUAGE, found: JS".to_string(),
+    )?;
+
+    expect_parse_err_contains(
+        "CREATE FUNCTION plus AS (a Int64) -> a + 1",
+        "Parameter types are only allowed in WASM or PYTHON function".to_string(),
+    )?;
+
     Ok(())
 }
 
@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sql::statements::DfUpdateStatement;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+
+use crate::sql::sql_parser::*;
+
+#[test]
+fn update_table() -> Result<()> {
+    {
+        let sql = "UPDATE t1 SET a = 1";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![(Ident::new("a"), parse_sql_to_expr("1"))],
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "update db1.t1 set a = a + 1, b = 'x' where c is null";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![
+                (Ident::new("a"), parse_sql_to_expr("a + 1")),
+                (Ident::new("b"), parse_sql_to_expr("'x'")),
+            ],
+            selection: Some(parse_sql_to_expr("c is null")),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE t1 a = 1";
+        expect_parse_err_contains(sql, "Expected SET, found: a".to_string())?;
+    }
+
+    {
+        let sql = "UPDATE t1 SET a 1";
+        expect_parse_err_contains(sql, "Expected =, found: 1".to_string())?;
+    }
+
+    Ok(())
+}
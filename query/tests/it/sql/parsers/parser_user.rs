@@ -133,6 +133,17 @@ fn create_user_test() -> Result<()> {
         }),
     )?;
 
+    let with_options = vec![DfUserWithOption::Sandbox];
+    expect_parse_ok(
+        "CREATE USER 'guest' WITH SANDBOX NOT IDENTIFIED",
+        DfStatement::CreateUser(DfCreateUser {
+            if_not_exists: false,
+            user: UserIdentity::new("guest", "%"),
+            auth_option: DfAuthOption::no_password(),
+            with_options,
+        }),
+    )?;
+
     let with_options = vec![
         DfUserWithOption::NoTenantSetting,
         DfUserWithOption::ConfigReload,
@@ -410,6 +421,32 @@ fn grant_privilege_test() -> Result<()> {
         }),
     )?;
 
+    expect_parse_ok(
+        "GRANT SELECT ON FUTURE TABLES IN DATABASE db1 TO ROLE 'myrole'",
+        DfStatement::GrantPrivilege(DfGrantPrivilegeStatement {
+            principal: PrincipalIdentity::role("myrole".to_string()),
+            on: DfGrantObject::FutureTables(Some("db1".into())),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Select);
+                privileges
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "GRANT INSERT ON future TO 'test'@'localhost'",
+        DfStatement::GrantPrivilege(DfGrantPrivilegeStatement {
+            principal: PrincipalIdentity::user("test".to_string(), "localhost".to_string()),
+            on: DfGrantObject::Table(None, "future".into()),
+            priv_types: {
+                let mut privileges = UserPrivilegeSet::empty();
+                privileges.set_privilege(UserPrivilegeType::Insert);
+                privileges
+            },
+        }),
+    )?;
+
     expect_parse_ok(
         "GRANT CREATE, SELECT ON * TO USER 'test'@'localhost'",
         DfStatement::GrantPrivilege(DfGrantPrivilegeStatement {
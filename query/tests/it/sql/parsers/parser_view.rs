@@ -0,0 +1,62 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use databend_query::sessions::SessionType;
+use databend_query::sql::statements::DfShowCreateView;
+use databend_query::sql::*;
+use sqlparser::ast::*;
+
+use crate::sql::sql_parser::*;
+
+fn parse_view_query(sql: &str) -> Result<String> {
+    let (statements, _) = DfParser::parse_sql(sql, SessionType::MySQL)?;
+    match &statements[..] {
+        [DfStatement::CreateView(create)] => Ok(create.subquery.clone()),
+        [DfStatement::AlterView(alter)] => Ok(alter.subquery.clone()),
+        _ => panic!("Expected a CREATE VIEW or ALTER VIEW statement: {}", sql),
+    }
+}
+
+#[test]
+fn view_query_keeps_original_text_test() -> Result<()> {
+    let cases = [
+        (
+            "CREATE VIEW v AS select a as b, count(*) from t where c like 'x;y' group by a",
+            "select a as b, count(*) from t where c like 'x;y' group by a",
+        ),
+        (
+            "create view if not exists db.v as  SELECT *\n  FROM t1 join t2 on t1.a = t2.a ;",
+            "SELECT *\n  FROM t1 join t2 on t1.a = t2.a",
+        ),
+        ("ALTER VIEW `as` AS select 1 AS `as`", "select 1 AS `as`"),
+    ];
+    for (sql, query) in cases {
+        assert_eq!(parse_view_query(sql)?, query, "{}", sql);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn show_create_view_test() -> Result<()> {
+    expect_parse_ok(
+        "SHOW CREATE VIEW db.v",
+        DfStatement::ShowCreateView(DfShowCreateView {
+            name: ObjectName(vec![Ident::new("db"), Ident::new("v")]),
+        }),
+    )?;
+
+    Ok(())
+}
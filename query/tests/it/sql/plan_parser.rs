@@ -267,6 +267,12 @@ async fn test_plan_parser() -> Result<()> {
             expect: "",
             error: "",
         },
+        Test {
+            name: "show-clusters",
+            sql: "show clusters",
+            expect: "",
+            error: "",
+        },
         Test {
             name: "show-processlist",
             sql: "show processlist",
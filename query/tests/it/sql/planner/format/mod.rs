@@ -18,23 +18,39 @@ use common_base::infallible::RwLock;
 use common_datavalues::BooleanType;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataValue;
+use common_datavalues::UInt64Type;
+use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_planners::ReadDataSourcePlan;
 use common_planners::SourceInfo;
 use common_planners::Statistics;
+use databend_query::sql::optimizer::ColumnSet;
 use databend_query::sql::optimizer::SExpr;
 use databend_query::sql::planner::plans::JoinType;
+use databend_query::sql::plans::AggregateFunction;
+use databend_query::sql::plans::AggregatePlan;
 use databend_query::sql::plans::BoundColumnRef;
+use databend_query::sql::plans::ComparisonExpr;
+use databend_query::sql::plans::ComparisonOp;
 use databend_query::sql::plans::ConstantExpr;
+use databend_query::sql::plans::EvalScalar;
 use databend_query::sql::plans::FilterPlan;
 use databend_query::sql::plans::FunctionCall;
+use databend_query::sql::plans::LogicalGet;
 use databend_query::sql::plans::PhysicalHashJoin;
 use databend_query::sql::plans::PhysicalScan;
+use databend_query::sql::plans::Project;
+use databend_query::sql::plans::Scalar;
+use databend_query::sql::plans::ScalarItem;
+use databend_query::sql::plans::SubqueryExpr;
+use databend_query::sql::plans::SubqueryType;
 use databend_query::sql::ColumnBinding;
 use databend_query::sql::Metadata;
+use databend_query::sql::SubqueryRewriter;
 use databend_query::storages::Table;
+use serde_json::json;
 
 struct DummyTable {
     table_info: TableInfo,
@@ -174,10 +190,277 @@ fn test_format() {
 
     let tree = s_expr.to_format_tree(&metadata_ref);
     let result = tree.format_indent().unwrap();
-    let expect = r#"PhysicalHashJoin: build keys: [plus(col1, 123)], probe keys: [col2]
+    let expect = r#"PhysicalHashJoin: build keys: [plus(col1, 123)], probe keys: [col2], estimated rows: 0
     Filter: [true]
-        PhysicalScan: catalog.database.table
-    PhysicalScan: catalog.database.table
+        PhysicalScan: catalog.database.table, estimated rows: 0
+    PhysicalScan: catalog.database.table, estimated rows: 0
 "#;
     assert_eq!(result.as_str(), expect);
+
+    let result = tree.format_dot().unwrap();
+    let expect = r#"digraph plan {
+    node [shape=box];
+    0 [label="PhysicalHashJoin: build keys: [plus(col1, 123)], probe keys: [col2], estimated rows: 0"];
+    1 [label="Filter: [true]"];
+    2 [label="PhysicalScan: catalog.database.table, estimated rows: 0"];
+    1 -> 2;
+    0 -> 1;
+    3 [label="PhysicalScan: catalog.database.table, estimated rows: 0"];
+    0 -> 3;
+}
+"#;
+    assert_eq!(result.as_str(), expect);
+
+    let result = tree.format_json();
+    let expect = json!({
+        "operator": "PhysicalHashJoin: build keys: [plus(col1, 123)], probe keys: [col2], estimated rows: 0",
+        "children": [
+            {
+                "operator": "Filter: [true]",
+                "children": [
+                    {
+                        "operator": "PhysicalScan: catalog.database.table, estimated rows: 0",
+                        "children": [],
+                    }
+                ],
+            },
+            {
+                "operator": "PhysicalScan: catalog.database.table, estimated rows: 0",
+                "children": [],
+            }
+        ],
+    });
+    assert_eq!(result, expect);
+}
+
+fn column_ref(metadata: &Metadata, index: usize) -> Scalar {
+    let column = metadata.column(index);
+    BoundColumnRef {
+        column: ColumnBinding {
+            table_name: None,
+            column_name: column.name.clone(),
+            index,
+            data_type: column.data_type.clone(),
+            visible_in_unqualified_wildcard: false,
+        },
+    }
+    .into()
+}
+
+fn equal(left: Scalar, right: Scalar) -> Scalar {
+    ComparisonExpr {
+        op: ComparisonOp::Equal,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+    .into()
+}
+
+// Rewrite the subqueries in `s_expr` and format the result.
+fn format_rewritten(s_expr: SExpr, metadata: Metadata) -> Result<String> {
+    let metadata_ref = Arc::new(RwLock::new(metadata));
+    let mut rewriter = SubqueryRewriter::new(metadata_ref.clone());
+    let s_expr = rewriter.rewrite(&s_expr)?;
+    s_expr.to_format_tree(&metadata_ref).format_indent()
+}
+
+#[test]
+fn test_format_decorrelated_exists() -> Result<()> {
+    for negated in [false, true] {
+        let mut metadata = Metadata::create();
+        let t = metadata.add_table(
+            "catalog".to_string(),
+            "database".to_string(),
+            Arc::new(DummyTable::new("t".to_string())),
+            get_dummy_read_source(),
+        );
+        let t1 = metadata.add_table(
+            "catalog".to_string(),
+            "database".to_string(),
+            Arc::new(DummyTable::new("t1".to_string())),
+            get_dummy_read_source(),
+        );
+        let a = metadata.add_column("a".to_string(), UInt64Type::new_impl(), Some(t));
+        let a1 = metadata.add_column("a1".to_string(), UInt64Type::new_impl(), Some(t1));
+        let b1 = metadata.add_column("b1".to_string(), UInt64Type::new_impl(), Some(t1));
+
+        // EXISTS (SELECT a1 FROM t1 WHERE a = a1 AND b1 > 1)
+        let subquery = SExpr::create_unary(
+            Project {
+                columns: ColumnSet::from([a1]),
+            }
+            .into(),
+            SExpr::create_unary(
+                FilterPlan {
+                    predicates: vec![
+                        equal(column_ref(&metadata, a), column_ref(&metadata, a1)),
+                        ComparisonExpr {
+                            op: ComparisonOp::GT,
+                            left: Box::new(column_ref(&metadata, b1)),
+                            right: Box::new(
+                                ConstantExpr {
+                                    value: DataValue::UInt64(1),
+                                    data_type: UInt64Type::new_impl(),
+                                }
+                                .into(),
+                            ),
+                        }
+                        .into(),
+                    ],
+                    is_having: false,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    LogicalGet {
+                        table_index: t1,
+                        columns: ColumnSet::from([a1, b1]),
+                    }
+                    .into(),
+                ),
+            ),
+        );
+        let mut predicate: Scalar = SubqueryExpr {
+            typ: SubqueryType::Exists,
+            subquery,
+            data_type: BooleanType::new_impl(),
+            allow_multi_rows: true,
+            outer_columns: ColumnSet::from([a]),
+        }
+        .into();
+        if negated {
+            predicate = FunctionCall {
+                func_name: "not".to_string(),
+                arg_types: vec![BooleanType::new_impl()],
+                arguments: vec![predicate],
+                return_type: BooleanType::new_impl(),
+            }
+            .into();
+        }
+
+        let s_expr = SExpr::create_unary(
+            FilterPlan {
+                predicates: vec![predicate],
+                is_having: false,
+            }
+            .into(),
+            SExpr::create_leaf(
+                LogicalGet {
+                    table_index: t,
+                    columns: ColumnSet::from([a]),
+                }
+                .into(),
+            ),
+        );
+
+        let result = format_rewritten(s_expr, metadata)?;
+        let expect = format!(
+            r#"LogicalInnerJoin: a = a1, join type: {}, estimated rows: 0
+    LogicalGet: catalog.database.t, estimated rows: 0
+    Filter: [b1 > 1]
+        LogicalGet: catalog.database.t1, estimated rows: 0
+"#,
+            if negated { "Anti" } else { "Semi" }
+        );
+        assert_eq!(result, expect);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_format_decorrelated_scalar_subquery() -> Result<()> {
+    let mut metadata = Metadata::create();
+    let t = metadata.add_table(
+        "catalog".to_string(),
+        "database".to_string(),
+        Arc::new(DummyTable::new("t".to_string())),
+        get_dummy_read_source(),
+    );
+    let t1 = metadata.add_table(
+        "catalog".to_string(),
+        "database".to_string(),
+        Arc::new(DummyTable::new("t1".to_string())),
+        get_dummy_read_source(),
+    );
+    let a = metadata.add_column("a".to_string(), UInt64Type::new_impl(), Some(t));
+    let a1 = metadata.add_column("a1".to_string(), UInt64Type::new_impl(), Some(t1));
+    let b1 = metadata.add_column("b1".to_string(), UInt64Type::new_impl(), Some(t1));
+    let max_b1 = metadata.add_column("max(b1)".to_string(), UInt64Type::new_impl(), None);
+    let output = metadata.add_column("output".to_string(), UInt64Type::new_impl(), None);
+
+    // (SELECT MAX(b1) FROM t1 WHERE a = a1)
+    let subquery = SExpr::create_unary(
+        Project {
+            columns: ColumnSet::from([max_b1]),
+        }
+        .into(),
+        SExpr::create_unary(
+            AggregatePlan {
+                group_items: vec![],
+                aggregate_functions: vec![ScalarItem {
+                    scalar: AggregateFunction {
+                        display_name: "max(b1)".to_string(),
+                        func_name: "max".to_string(),
+                        distinct: false,
+                        params: vec![],
+                        args: vec![column_ref(&metadata, b1)],
+                        return_type: UInt64Type::new_impl(),
+                    }
+                    .into(),
+                    index: max_b1,
+                }],
+                from_distinct: false,
+            }
+            .into(),
+            SExpr::create_unary(
+                FilterPlan {
+                    predicates: vec![equal(column_ref(&metadata, a), column_ref(&metadata, a1))],
+                    is_having: false,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    LogicalGet {
+                        table_index: t1,
+                        columns: ColumnSet::from([a1, b1]),
+                    }
+                    .into(),
+                ),
+            ),
+        ),
+    );
+
+    let s_expr = SExpr::create_unary(
+        EvalScalar {
+            items: vec![ScalarItem {
+                scalar: SubqueryExpr {
+                    typ: SubqueryType::Scalar,
+                    subquery,
+                    data_type: UInt64Type::new_impl(),
+                    allow_multi_rows: false,
+                    outer_columns: ColumnSet::from([a]),
+                }
+                .into(),
+                index: output,
+            }],
+        }
+        .into(),
+        SExpr::create_leaf(
+            LogicalGet {
+                table_index: t,
+                columns: ColumnSet::from([a]),
+            }
+            .into(),
+        ),
+    );
+
+    let result = format_rewritten(s_expr, metadata)?;
+    let expect = r#"EvalScalar: [subquery_3]
+    LogicalInnerJoin: a = a1, join type: Left, estimated rows: 0
+        LogicalGet: catalog.database.t, estimated rows: 0
+        Aggregate: group items: [a1], aggregate functions: [max(b1)]
+            LogicalGet: catalog.database.t1, estimated rows: 0
+"#;
+    assert_eq!(result, expect);
+
+    Ok(())
 }
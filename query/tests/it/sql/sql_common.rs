@@ -0,0 +1,48 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_query::sql::SQLCommon;
+
+#[test]
+fn test_split_statements() {
+    let tests = vec![
+        ("SELECT 1", vec!["SELECT 1"]),
+        ("SET a = 1; SELECT 2;", vec!["SET a = 1", "SELECT 2;"]),
+        ("SELECT ';'; SELECT \"a;b\"", vec![
+            "SELECT ';'",
+            "SELECT \"a;b\"",
+        ]),
+        ("SELECT 'it''s;'; SELECT 'a\\';'", vec![
+            "SELECT 'it''s;'",
+            "SELECT 'a\\';'",
+        ]),
+        ("SELECT 1 /* ; */; SELECT 2 -- ;", vec![
+            "SELECT 1 /* ; */",
+            "SELECT 2 -- ;",
+        ]),
+        ("SELECT 1; -- {ErrorCode 1002}", vec![
+            "SELECT 1; -- {ErrorCode 1002}",
+        ]),
+        ("", vec![""]),
+    ];
+
+    for (query, expect) in tests {
+        assert_eq!(
+            SQLCommon::split_statements(query),
+            expect,
+            "query: {}",
+            query
+        );
+    }
+}
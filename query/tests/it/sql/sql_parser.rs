@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datablocks::DataBlock;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::Series;
+use common_datavalues::SeriesFrom;
+use common_datavalues::ToDataType;
+use common_datavalues::Vu8;
 use common_exception::Result;
 use databend_query::sessions::SessionType;
 use databend_query::sql::statements::DfQueryStatement;
@@ -141,5 +148,53 @@ fn hint_test() -> Result<()> {
         assert_eq!(expected.error_code, None);
     }
 
+    {
+        let comment = " { rows 3 } { result_hash B78A19 }";
+        let expected = DfHint::create_from_comment(comment, "--");
+        assert_eq!(expected.error_code, None);
+        assert_eq!(expected.rows, Some(3));
+        assert_eq!(expected.result_hash, Some("b78a19".to_string()));
+        assert!(expected.has_result_hints());
+    }
+
+    {
+        let comment = " { rows three } { result_hash }";
+        let expected = DfHint::create_from_comment(comment, "--");
+        assert_eq!(expected.rows, None);
+        assert_eq!(expected.result_hash, None);
+        assert!(!expected.has_result_hints());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn result_hints_test() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+    let block = DataBlock::create(schema, vec![
+        Series::from_data(vec![0u64, 1]),
+        Series::from_data(vec!["a", "b"]),
+    ]);
+    let hash = "a2b804b1740ccd2fc6e05d726ef2753cc62bc024e643716f632d0d01a2736de6";
+    assert_eq!(result_hash(&[block.clone()])?, hash);
+
+    let hints = vec![DfHint::create_from_comment(
+        &format!(" {{ rows 2 }} {{ result_hash {} }}", hash),
+        "--",
+    )];
+    assert!(check_result_hints(&hints, &[block.clone()])?.is_empty());
+
+    let hints = vec![DfHint::create_from_comment(
+        " { rows 3 } { result_hash 00 }",
+        "--",
+    )];
+    assert_eq!(check_result_hints(&hints, &[block])?, vec![
+        DfHintMismatch::create("rows", 3, 2),
+        DfHintMismatch::create("result_hash", "00", hash),
+    ]);
+
     Ok(())
 }
@@ -39,7 +39,7 @@ async fn test_statement_copy() -> Result<()> {
         credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1)",
-            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,validation_mode:None"#,
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,validation_mode:None"#,
             err: "",
         },
 
@@ -52,7 +52,7 @@ async fn test_statement_copy() -> Result<()> {
         file_format = (type = csv field_delimiter = '|' skip_header = 1)
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
@@ -66,7 +66,7 @@ async fn test_statement_copy() -> Result<()> {
         file_format = (type = csv field_delimiter = '|' skip_header = 1)
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
@@ -81,10 +81,28 @@ async fn test_statement_copy() -> Result<()> {
         on_error = CONTINUE size_limit = 10
         VALIDATION_MODE = RETURN_13_ROWS
         ",
-            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: Continue, size_limit: 10 }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "s3://mybucket/data/files", stage_type: External, stage_params: StageParams { storage: S3(StorageS3Config { endpoint_url: "https://s3.amazonaws.com", region: "", bucket: "mybucket", root: "/", disable_credential_loader: true, access_key_id: "******_id", secret_access_key: "******key", master_key: "******key" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 1, field_delimiter: "|", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: Continue, size_limit: 10, match_by_column_name: None }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,files:["file1.csv", "file2.csv"] ,validation_mode:ReturnNRows(13)"#,
             err: "",
         },
 
+        TestCase {
+            name: "copy-external-fs-ok",
+            query: "copy into system.configs
+        from 'fs:///tmp/data/'
+        file_format = (type = csv)",
+            expect: r#"Copy into system.configs, ReadDataSourcePlan { catalog: "default", source_info: StageSource(UserStageInfo { stage_name: "fs:///tmp/data/", stage_type: External, stage_params: StageParams { storage: Fs(StorageFsConfig { root: "/tmp/data/" }) }, file_format_options: FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: "", record_delimiter: "", compression: None }, copy_options: CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None }, comment: "" }), scan_fields: None, parts: [], statistics: Statistics { read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0, is_exact: false }, description: "", tbl_args: None, push_downs: None } ,validation_mode:None"#,
+            err: "",
+        },
+
+        TestCase {
+            name: "copy-external-fs-relative-error",
+            query: "copy into system.configs
+        from 'fs://tmp/data/'
+        file_format = (type = csv)",
+            expect: "",
+            err: "Code: 1005, displayText = File location uri of local fs must be absolute, for example: 'fs:///<path>[/]'.",
+        },
+
         TestCase {
             name: "copy-external-size-limit-error",
             query: "copy into system.configs
@@ -100,6 +118,39 @@ async fn test_statement_copy() -> Result<()> {
             err: "Code: 1005, displayText = size_limit must be number, got: x0.",
         },
 
+        TestCase {
+            name: "copy-external-unknown-format-option-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv field_delimeter = '|')
+        ",
+            expect: "",
+            err: "Code: 1022, displayText = Unknown option field_delimeter of input format csv, did you mean field_delimiter?.",
+        },
+
+        TestCase {
+            name: "copy-external-invalid-format-option-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = csv skip_header = x1)
+        ",
+            expect: "",
+            err: "Code: 1022, displayText = Invalid value 'x1' of skip_header, expected an unsigned integer.",
+        },
+
+        TestCase {
+            name: "copy-external-unknown-format-error",
+            query: "copy into system.configs
+        from 's3://mybucket/data/files'
+        credentials=(aws_key_id='my_key_id' aws_secret_key='my_secret_key')
+        file_format = (type = parquett)
+        ",
+            expect: "",
+            err: "Code: 1074, displayText = Unsupported input format: parquett, did you mean parquet?.",
+        },
+
         TestCase {
             name: "copy-external-validation-mode-error",
             query: "copy into system.configs
@@ -143,7 +194,7 @@ async fn test_statement_copy() -> Result<()> {
         encryption=(master_key = 'my_master_key')
         file_format = (type = csv field_delimiter = '|' skip_header = 1)",
             expect: "",
-            err: "Code: 1005, displayText = File location uri unsupported, must be one of [s3, @stage].",
+            err: "Code: 1005, displayText = File location uri unsupported, must be one of [s3, fs, @stage].",
         },
         TestCase {
             name: "copy-internal-ok",
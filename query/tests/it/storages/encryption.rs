@@ -0,0 +1,195 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_io::prelude::init_memory_operator;
+use databend_query::storages::encryption::reencrypt_objects;
+use databend_query::storages::encryption::EncryptionHeader;
+use databend_query::storages::encryption::StorageEncryption;
+use databend_query::storages::encryption::StorageKeyring;
+use databend_query::storages::encryption::FRAME_LEN;
+use databend_query::storages::encryption::HEADER_LEN;
+
+fn keyring(ids: &[u32]) -> Arc<StorageKeyring> {
+    let content = ids
+        .iter()
+        .map(|id| format!("{}:{}", id, base64::encode([*id as u8; 32])))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Arc::new(StorageKeyring::parse(&content).unwrap())
+}
+
+#[test]
+fn test_storage_keyring_parse() -> Result<()> {
+    let keyring = StorageKeyring::parse(&format!(
+        "# rotated at 2022-07-01\n1:{}\n\n3:{}\n",
+        base64::encode([1u8; 32]),
+        base64::encode([3u8; 32])
+    ))?;
+    assert_eq!(keyring.active_key(), (3, &[3u8; 32][..]));
+    assert_eq!(keyring.key(1)?, &[1u8; 32][..]);
+    assert!(keyring.key(2).is_err());
+
+    // no keys
+    assert!(StorageKeyring::parse("# nothing\n").is_err());
+    // invalid key id
+    assert!(StorageKeyring::parse(&format!("x:{}", base64::encode([1u8; 32]))).is_err());
+    // wrong key length
+    assert!(StorageKeyring::parse(&format!("1:{}", base64::encode([1u8; 16]))).is_err());
+    // duplicated key id
+    let key = base64::encode([1u8; 32]);
+    assert!(StorageKeyring::parse(&format!("1:{}\n1:{}", key, key)).is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_encryption_read_write() -> Result<()> {
+    let raw = init_memory_operator().await?;
+    let op = raw.clone().layer(StorageEncryption::create(keyring(&[1]), false));
+
+    // spans 3 frames, the last one is partial
+    let data = (0..FRAME_LEN * 2 + 100)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+    op.object("t/block").write(data.clone()).await?;
+
+    // the stored object is encrypted
+    let stored = raw.object("t/block").read().await?;
+    let header = EncryptionHeader::parse(&stored).unwrap();
+    assert_eq!(header.key_id, 1);
+    assert_ne!(&stored[HEADER_LEN..HEADER_LEN + 100], &data[..100]);
+
+    // decrypted transparently
+    assert_eq!(op.object("t/block").read().await?, data);
+    assert_eq!(
+        op.object("t/block").metadata().await?.content_length(),
+        data.len() as u64
+    );
+
+    // range across the frames
+    let start = FRAME_LEN as u64 - 10;
+    let end = FRAME_LEN as u64 * 2 + 50;
+    assert_eq!(
+        op.object("t/block").range_read(start..end).await?,
+        data[start as usize..end as usize].to_vec()
+    );
+    // range at the tail
+    assert_eq!(
+        op.object("t/block")
+            .range_read(FRAME_LEN as u64 * 2..)
+            .await?,
+        data[FRAME_LEN * 2..].to_vec()
+    );
+
+    // empty object
+    op.object("t/empty").write(vec![]).await?;
+    assert!(op.object("t/empty").read().await?.is_empty());
+
+    // the objects written before the encryption is enabled are still readable
+    raw.object("t/plain").write(b"plain".to_vec()).await?;
+    assert_eq!(op.object("t/plain").read().await?, b"plain".to_vec());
+
+    // the objects could not be read without the key
+    let other = raw.clone().layer(StorageEncryption::create(keyring(&[2]), false));
+    assert!(other.object("t/block").read().await.is_err());
+
+    // the plain objects are rejected if the encryption is required
+    let required = raw.clone().layer(StorageEncryption::create(keyring(&[1]), true));
+    assert!(required.object("t/plain").read().await.is_err());
+    assert!(required.object("t/plain").range_read(1..3).await.is_err());
+    assert_eq!(required.object("t/block").read().await?, data);
+    assert!(required.object("t/empty").read().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_encryption_truncated() -> Result<()> {
+    let raw = init_memory_operator().await?;
+    let op = raw.clone().layer(StorageEncryption::create(keyring(&[1]), false));
+
+    // spans 2 full frames
+    let data = vec![7; FRAME_LEN * 2];
+    op.object("t/block").write(data.clone()).await?;
+    assert_eq!(op.object("t/block").read().await?, data);
+
+    // truncated at the frame boundary
+    let stored = raw.object("t/block").read().await?;
+    let one_frame = HEADER_LEN + FRAME_LEN + 16;
+    raw.object("t/truncated")
+        .write(stored[..one_frame].to_vec())
+        .await?;
+    assert!(op.object("t/truncated").read().await.is_err());
+    assert!(op
+        .object("t/truncated")
+        .range_read(FRAME_LEN as u64 - 10..)
+        .await
+        .is_err());
+    assert!(op
+        .object("t/truncated")
+        .range_read(FRAME_LEN as u64..)
+        .await
+        .is_err());
+    // the range within the remaining frames can't tell
+    assert_eq!(
+        op.object("t/truncated").range_read(0..10).await?,
+        data[..10].to_vec()
+    );
+
+    // only the header is left
+    raw.object("t/header")
+        .write(stored[..HEADER_LEN].to_vec())
+        .await?;
+    assert!(op.object("t/header").read().await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_reencrypt_objects() -> Result<()> {
+    let raw = init_memory_operator().await?;
+    let old = raw.clone().layer(StorageEncryption::create(keyring(&[1]), false));
+    old.object("db/t/_sg/1").write(vec![1; 100]).await?;
+    old.object("db/t/_b/1")
+        .write(vec![2; FRAME_LEN + 1])
+        .await?;
+    raw.object("db/t/_ss/1").write(vec![3; 10]).await?;
+
+    // rotate the key
+    let keyring = keyring(&[1, 2]);
+    let new = raw
+        .clone()
+        .layer(StorageEncryption::create(keyring.clone(), false));
+    new.object("db/t/_sg/2").write(vec![4; 100]).await?;
+
+    let stats = reencrypt_objects(&raw, &keyring, "db/").await?;
+    assert_eq!(stats.scanned, 4);
+    assert_eq!(stats.reencrypted, 3);
+
+    for path in ["db/t/_sg/1", "db/t/_sg/2", "db/t/_b/1", "db/t/_ss/1"] {
+        let header = raw.object(path).range_read(0..HEADER_LEN as u64).await?;
+        assert_eq!(EncryptionHeader::parse(&header).unwrap().key_id, 2);
+    }
+    assert_eq!(new.object("db/t/_b/1").read().await?, vec![
+        2;
+        FRAME_LEN + 1
+    ]);
+    assert_eq!(new.object("db/t/_ss/1").read().await?, vec![3; 10]);
+
+    // nothing to do after the re-encryption
+    let stats = reencrypt_objects(&raw, &keyring, "db/").await?;
+    assert_eq!(stats.reencrypted, 0);
+    Ok(())
+}
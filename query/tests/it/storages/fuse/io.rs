@@ -13,6 +13,9 @@
 //  limitations under the License.
 //
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use common_base::base::tokio;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -20,6 +23,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::storages::fuse::io::BlockCompactor;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::io::HedgedReadPolicy;
+use databend_query::storages::fuse::io::ReadLatencyTracker;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::meta::Versioned;
@@ -48,6 +53,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         0,
         locs.clone(),
         None,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -75,6 +81,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         max_blocks_per_segment,
         locs.clone(),
         None,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -95,6 +102,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         0,
         locs,
         None,
+        None,
     )
     .await
     .collect::<Vec<_>>()
@@ -264,6 +272,7 @@ async fn test_block_stream_writer() -> Result<()> {
             max_blocks_per_segment,
             locs,
             None,
+            None,
         )
         .await;
         let segs = stream.try_collect::<Vec<_>>().await?;
@@ -322,3 +331,42 @@ fn test_meta_locations() -> Result<()> {
     assert!(snapshot_loc.starts_with(test_prefix));
     Ok(())
 }
+
+#[test]
+fn test_read_latency_tracker() -> Result<()> {
+    let tracker = ReadLatencyTracker::init();
+    for ms in 1..=10 {
+        tracker.record(Duration::from_millis(ms));
+    }
+    // not enough samples
+    assert_eq!(tracker.percentile(95), None);
+
+    for ms in 11..=100 {
+        tracker.record(Duration::from_millis(ms));
+    }
+    assert_eq!(tracker.percentile(50), Some(Duration::from_millis(51)));
+    assert_eq!(tracker.percentile(95), Some(Duration::from_millis(96)));
+    assert_eq!(tracker.percentile(100), Some(Duration::from_millis(100)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hedged_read() -> Result<()> {
+    let ctx = create_query_context().await?;
+    let operator = ctx.get_storage_operator()?;
+    let location = format!("_hedged_read/{}", Uuid::new_v4().simple());
+    let data = (0..255u8).collect::<Vec<_>>();
+    operator.object(&location).write(data.clone()).await?;
+
+    let tracker = Arc::new(ReadLatencyTracker::init());
+    // the backup requests are issued right away once the threshold is known
+    let policy = HedgedReadPolicy::create(tracker.clone(), 0, Duration::from_millis(0));
+    for _ in 0..100 {
+        let chunk = policy
+            .range_read(operator.object(&location), 10..20)
+            .await?;
+        assert_eq!(chunk, data[10..20]);
+    }
+    assert!(policy.delay().is_some());
+    Ok(())
+}
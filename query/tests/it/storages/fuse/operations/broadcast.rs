@@ -0,0 +1,134 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use databend_query::sessions::QueryContext;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn new_query_context(fixture: &TestFixture) -> Result<Arc<QueryContext>> {
+    // A new query sees the latest version of the tables.
+    fixture
+        .ctx()
+        .get_current_session()
+        .create_query_context()
+        .await
+}
+
+async fn run(fixture: &TestFixture, query: &str) -> Result<Vec<DataBlock>> {
+    let ctx = new_query_context(fixture).await?;
+    execute_query(ctx, query).await?.try_collect().await
+}
+
+#[tokio::test]
+async fn test_fuse_broadcast_table_cache() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let cache = fixture
+        .ctx()
+        .get_storage_cache_manager()
+        .get_broadcast_table_cache();
+
+    let ctx = new_query_context(&fixture).await?;
+    execute_command(
+        ctx,
+        &format!("create table {}.t(a int, b int) broadcast", db),
+    )
+    .await?;
+    let ctx = new_query_context(&fixture).await?;
+    execute_command(ctx, &format!("insert into {}.t values(1, 10),(2, 20)", db)).await?;
+
+    // the first read caches the block, the second one is served by the cache
+    let blocks = run(&fixture, &format!("select a, b from {}.t", db)).await?;
+    let expected = vec![
+        "+---+----+",
+        "| a | b  |",
+        "+---+----+",
+        "| 1 | 10 |",
+        "| 2 | 20 |",
+        "+---+----+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let blocks = run(&fixture, &format!("select b from {}.t", db)).await?;
+    let expected = vec![
+        "+----+", //
+        "| b  |", //
+        "+----+", //
+        "| 10 |", //
+        "| 20 |", //
+        "+----+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    let status = cache.status();
+    assert_eq!(status.entries, 1);
+    assert_eq!(status.misses, 1);
+    assert_eq!(status.hits, 1);
+
+    // the cached blocks are dropped by the commit of the table
+    let ctx = new_query_context(&fixture).await?;
+    execute_command(ctx, &format!("insert into {}.t values(3, 30)", db)).await?;
+    assert_eq!(cache.status().entries, 0);
+
+    let blocks = run(&fixture, &format!("select a from {}.t", db)).await?;
+    let expected = vec![
+        "+---+", //
+        "| a |", //
+        "+---+", //
+        "| 1 |", //
+        "| 2 |", //
+        "| 3 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, blocks.as_slice());
+    assert_eq!(cache.status().entries, 2);
+
+    let ctx = new_query_context(&fixture).await?;
+    execute_command(ctx, &format!("truncate table {}.t", db)).await?;
+    assert_eq!(cache.status().entries, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_broadcast_table_option() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+
+    let ctx = new_query_context(&fixture).await?;
+    let res = execute_command(
+        ctx,
+        &format!("create table {}.t(a int) broadcast = 'yes'", db),
+    )
+    .await;
+    assert!(res.is_err());
+
+    let ctx = new_query_context(&fixture).await?;
+    let res = execute_command(
+        ctx,
+        &format!("create table {}.t(a int) engine = memory broadcast", db),
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_io::prelude::init_memory_operator;
+use databend_query::storages::fuse::operations::SnapshotExportManifest;
+use databend_query::storages::fuse::FuseTable;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_export_snapshot() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    let target = init_memory_operator().await?;
+
+    // an empty table exports the manifest only
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let manifest = fuse_table
+        .do_export_snapshot(ctx.as_ref(), &target, "/empty/")
+        .await?;
+    assert!(manifest.snapshot_id.is_none());
+    assert!(manifest.files.is_empty());
+    assert_eq!(
+        manifest.columns.len(),
+        TestFixture::default_schema().num_fields()
+    );
+
+    append_sample_data(2, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let manifest = fuse_table
+        .do_export_snapshot(ctx.as_ref(), &target, "/export/")
+        .await?;
+    assert_eq!(
+        manifest.row_count,
+        fuse_table.get_table_info().meta.statistics.number_of_rows
+    );
+    assert!(!manifest.files.is_empty());
+    assert_eq!(manifest.format, "parquet");
+
+    // the files are the blocks of the snapshot, copied as they are
+    for file in &manifest.files {
+        let object = target.object(&format!("/export/{}", file.path));
+        assert_eq!(object.read().await?.len() as u64, file.file_size);
+    }
+
+    let bytes = target.object("/export/manifest.json").read().await?;
+    let written: SnapshotExportManifest = serde_json::from_slice(&bytes)?;
+    assert_eq!(written, manifest);
+
+    Ok(())
+}
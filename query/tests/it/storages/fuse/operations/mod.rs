@@ -13,8 +13,12 @@
 //  limitations under the License.
 //
 
+mod broadcast;
 mod commit;
+mod export;
 mod navigate;
 mod optimize;
 mod purge_drop;
 mod read_plan;
+mod repair;
+mod replicate;
@@ -107,3 +107,39 @@ async fn test_fuse_snapshot_optimize_compact() -> Result<()> {
     )
     .await
 }
+
+#[tokio::test]
+async fn test_fuse_snapshot_optimize_compact_small_blocks() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture.create_default_table().await?;
+
+    // 5 small blocks, each in a segment of its own
+    for _ in 0..5 {
+        append_sample_data(1, &fixture).await?;
+    }
+
+    // the small blocks are merged into 1 block of 1 segment, the history is kept
+    let qry = format!("optimize table '{}'.'{}' compact", db, tbl);
+    execute_command(fixture.ctx(), qry.as_str()).await?;
+    check_data_dir(&fixture, "compact small blocks", 6, 6, 6).await;
+
+    let expected = vec![
+        "+-------------+-------------+-------------+",
+        "| segment_cnt | block_count | row_count   |",
+        "+-------------+-------------+-------------+",
+        "| 1           | 1           | 15          |",
+        "+-------------+-------------+-------------+",
+    ];
+    let qry = format!(
+        "select segment_count as segment_cnt, block_count, row_count from fuse_snapshot('{}', '{}') order by timestamp desc limit 1",
+        db, tbl
+    );
+    expects_ok(
+        "compacted snapshot",
+        execute_query(fixture.ctx(), qry.as_str()).await,
+        expected,
+    )
+    .await
+}
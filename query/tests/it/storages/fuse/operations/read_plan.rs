@@ -72,6 +72,7 @@ fn test_to_partitions() -> Result<()> {
         cluster_stats: None,
         location: ("".to_owned(), 0),
         compression: Compression::Lz4Raw,
+        checksums: None,
     };
 
     let blocks_metas = (0..num_of_block)
@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::storages::fuse::corruption::column_checksums;
+use databend_query::storages::fuse::io::MetaReaders;
+use databend_query::storages::fuse::operations::BlockRepairState;
+use databend_query::storages::fuse::FuseTable;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_block_checksum() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = MetaReaders::table_snapshot_reader(ctx.as_ref())
+        .read(
+            fuse_table.snapshot_loc().unwrap().as_str(),
+            None,
+            fuse_table.snapshot_format_version(),
+        )
+        .await?;
+    let (segment_location, ver) = &snapshot.segments[0];
+    let segment = MetaReaders::segment_info_reader(ctx.as_ref())
+        .read(segment_location, None, *ver)
+        .await?;
+    let block = &segment.blocks[0];
+
+    // the checksums are written along with the block
+    let operator = ctx.get_storage_operator()?;
+    let mut data = operator.object(&block.location.0).read().await?;
+    assert_eq!(
+        block.checksums,
+        Some(column_checksums(&data, &block.col_metas)?)
+    );
+
+    let query = format!(
+        "select * from {}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let blocks: Vec<DataBlock> = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 3);
+
+    // flip a byte of the first column
+    let offset = block.col_metas[&0].offset as usize;
+    data[offset] ^= 0xff;
+    operator.object(&block.location.0).write(data).await?;
+
+    let res = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await;
+    expects_err("corrupted", ErrorCode::corrupted_block_code(), res);
+
+    let manager = ctx.get_corrupt_block_manager();
+    let corrupt_blocks = manager.get_table_blocks(table.get_id());
+    assert_eq!(corrupt_blocks.len(), 1);
+    assert_eq!(corrupt_blocks[0].location, block.location.0);
+    assert_eq!(corrupt_blocks[0].column_id, 0);
+
+    // the table has no replication target to repair from
+    let repaired = fuse_table.do_repair(&ctx).await?;
+    assert_eq!(repaired, vec![(
+        block.location.0.clone(),
+        BlockRepairState::NoBackup
+    )]);
+    assert!(manager.is_quarantined(&block.location.0));
+
+    // the quarantined block fails fast
+    let res = execute_query(ctx.clone(), &query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await;
+    expects_err("quarantined", ErrorCode::corrupted_block_code(), res);
+    Ok(())
+}
@@ -0,0 +1,147 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_io::prelude::init_memory_operator;
+use databend_query::storages::fuse::io::MetaReaders;
+use databend_query::storages::fuse::replication::ReplicationManager;
+use databend_query::storages::fuse::replication::ReplicationState;
+use databend_query::storages::fuse::FuseTable;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_replicate() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    let target = init_memory_operator().await?;
+
+    // nothing to replicate for an empty table
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let checkpoint = fuse_table.do_replicate(ctx.as_ref(), &target, None).await?;
+    assert!(checkpoint.is_none());
+
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let checkpoint = fuse_table
+        .do_replicate(ctx.as_ref(), &target, None)
+        .await?
+        .unwrap();
+    assert_eq!(
+        Some(checkpoint.snapshot_location.clone()),
+        fuse_table.snapshot_loc()
+    );
+    assert!(
+        target
+            .object(&checkpoint.snapshot_location)
+            .is_exist()
+            .await?
+    );
+    let checkpoint_location = fuse_table
+        .meta_location_generator()
+        .replication_checkpoint_location();
+    assert!(target.object(&checkpoint_location).is_exist().await?);
+
+    // every segment and block referenced by the snapshot are copied as well
+    let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+    let snapshot = reader
+        .read(
+            checkpoint.snapshot_location.as_str(),
+            None,
+            fuse_table.snapshot_format_version(),
+        )
+        .await?;
+    assert_eq!(snapshot.segments.len(), 2);
+    for (segment_location, _) in &snapshot.segments {
+        assert!(target.object(segment_location).is_exist().await?);
+    }
+
+    // replicating again without new commits does nothing
+    let same = fuse_table
+        .do_replicate(ctx.as_ref(), &target, Some(&checkpoint))
+        .await?;
+    assert_eq!(Some(checkpoint.clone()), same);
+
+    // only the new commits are replicated
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let new_checkpoint = fuse_table
+        .do_replicate(ctx.as_ref(), &target, Some(&checkpoint))
+        .await?
+        .unwrap();
+    assert_ne!(new_checkpoint.snapshot_id, checkpoint.snapshot_id);
+    assert_eq!(
+        Some(new_checkpoint.snapshot_location.clone()),
+        fuse_table.snapshot_loc()
+    );
+    assert!(
+        target
+            .object(&new_checkpoint.snapshot_location)
+            .is_exist()
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replication_manager_lag() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let manager = ReplicationManager::init(&ctx.get_config().query);
+    let table_id = 1;
+
+    assert!(manager.get_status(table_id).is_none());
+
+    // two commits, the replication starts after both of them
+    manager.schedule(table_id, "s1");
+    manager.schedule(table_id, "s1");
+    let status = manager.get_status(table_id).unwrap();
+    assert_eq!(status.state, ReplicationState::Pending);
+    assert_eq!(status.lag_commits(), 2);
+    assert!(status.pending_since.is_some());
+
+    let covered = manager.start(table_id);
+    assert_eq!(covered, 2);
+
+    // a commit comes in while replicating
+    manager.schedule(table_id, "s1");
+    manager.finish(table_id, covered, None);
+    let status = manager.get_status(table_id).unwrap();
+    assert_eq!(status.state, ReplicationState::Pending);
+    assert_eq!(status.lag_commits(), 1);
+
+    let covered = manager.start(table_id);
+    manager.fail(table_id, "stage not found".to_string());
+    let status = manager.get_status(table_id).unwrap();
+    assert_eq!(status.state, ReplicationState::Failed);
+    assert_eq!(status.last_error, Some("stage not found".to_string()));
+
+    manager.finish(table_id, covered, None);
+    let status = manager.get_status(table_id).unwrap();
+    assert_eq!(status.state, ReplicationState::Idle);
+    assert_eq!(status.lag_commits(), 0);
+    assert!(status.pending_since.is_none());
+    assert!(status.last_error.is_none());
+
+    Ok(())
+}
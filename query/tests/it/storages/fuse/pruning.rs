@@ -35,6 +35,7 @@ use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::meta::BlockMeta;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::pruning::BlockPruner;
+use databend_query::storages::fuse::pruning::PruningStatistics;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use futures::TryStreamExt;
@@ -190,6 +191,24 @@ async fn test_block_pruner() -> Result<()> {
 
     assert_eq!((num_blocks - max_val_of_b as usize - 1), blocks.len());
 
+    // one block per segment, the pruned segments are reported with their blocks
+    let mut extra = Extras::default();
+    extra.filters = vec![col("b").gt(lit(max_val_of_b))];
+    let (blocks, statistics) = BlockPruner::new(snapshot.clone())
+        .apply_with_statistics(ctx.as_ref(), table.get_table_info().schema(), &Some(extra))
+        .await?;
+    let pruned = max_val_of_b + 1;
+    assert_eq!(statistics, PruningStatistics {
+        segments_total: num_blocks as u64,
+        segments_pruned_by_zone_map: pruned,
+        segments_pruned_by_limit: 0,
+        blocks_total: num_blocks as u64,
+        blocks_pruned_by_zone_map: pruned,
+        blocks_pruned_by_limit: 0,
+        blocks_scanned: blocks.len() as u64,
+    });
+    assert_eq!(num_blocks as u64 - pruned, statistics.blocks_scanned);
+
     Ok(())
 }
 
@@ -63,7 +63,12 @@ fn test_ft_stats_accumulator() -> common_exception::Result<()> {
     let test_file_size = 1;
     for item in blocks {
         let block_acc = stats_acc.begin(&item?, None)?;
-        stats_acc = block_acc.end(test_file_size, "".to_owned(), HashMap::new());
+        stats_acc = block_acc.end(
+            test_file_size,
+            "".to_owned(),
+            HashMap::new(),
+            HashMap::new(),
+        );
     }
     assert_eq!(10, stats_acc.blocks_statistics.len());
     // TODO more cases here pls
@@ -88,10 +88,10 @@ async fn test_fuse_snapshot_table_read() -> Result<()> {
 
     {
         let expected = vec![
-            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+",
-            "| snapshot_id | snapshot_location | format_version | previous_snapshot_id | segment_count | block_count | row_count | bytes_uncompressed | bytes_compressed |",
-            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+",
-            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+",
+            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+-----------+",
+            "| snapshot_id | snapshot_location | format_version | previous_snapshot_id | segment_count | block_count | row_count | bytes_uncompressed | bytes_compressed | timestamp |",
+            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+-----------+",
+            "+-------------+-------------------+----------------+----------------------+---------------+-------------+-----------+--------------------+------------------+-----------+",
 
         ];
 
@@ -0,0 +1,176 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use databend_query::catalogs::CATALOG_DEFAULT;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use databend_query::storages::mock::MockTableSpec;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+fn create_spec(name: &str) -> MockTableSpec {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", u64::to_data_type()),
+        DataField::new("b", Vu8::to_data_type()),
+    ]);
+
+    MockTableSpec::create(name, schema.clone())
+        .with_partition(vec![
+            DataBlock::create(schema.clone(), vec![
+                Series::from_data(vec![1u64, 2]),
+                Series::from_data(vec!["x", "y"]),
+            ]),
+            DataBlock::create(schema.clone(), vec![
+                Series::from_data(vec![3u64]),
+                Series::from_data(vec!["z"]),
+            ]),
+        ])
+        .with_partition(vec![])
+        .with_partition(vec![DataBlock::create(schema, vec![
+            Series::from_data(vec![4u64]),
+            Series::from_data(vec!["w"]),
+        ])])
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mock_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+    let table = catalog.register_test_table(create_spec("test_mock"))?;
+
+    // read plan.
+    {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        assert_eq!(source_plan.parts.len(), 3);
+        assert_eq!(source_plan.statistics.read_rows, 4);
+        assert_eq!(source_plan.statistics.partitions_total, 3);
+        assert!(source_plan.statistics.is_exact);
+
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "| 3 | z |",
+            "| 4 | w |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // query.
+    {
+        let query = "select sum(a) from system.test_mock where b <> 'x'";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+--------+",
+            "| sum(a) |",
+            "+--------+",
+            "| 9      |",
+            "+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // the same name.
+    {
+        let res = catalog.register_test_table(create_spec("test_mock"));
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::TableAlreadyExists("").code())
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mock_table_spec() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+
+    // the statistics of the spec are reported as is.
+    {
+        let statistics = Statistics::new_estimated(1000, 8000, 2, 3);
+        let spec = create_spec("test_estimated").with_statistics(statistics.clone());
+        let table = catalog.register_test_table(spec)?;
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        assert_eq!(source_plan.statistics, statistics);
+        assert!(!table.has_exact_total_row_count());
+    }
+
+    // the name must start with test_.
+    {
+        let res = catalog.register_test_table(create_spec("mock"));
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::BadArguments("").code())
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_read_limits() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+    let table = catalog.register_test_table(create_spec("test_read_limits"))?;
+    let settings = ctx.get_settings();
+
+    // 3 partitions.
+    {
+        settings.set_settings("max_partitions_to_read".to_string(), "2".to_string(), false)?;
+        let res = table.read_plan(ctx.clone(), None).await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::QueryLimitExceeded("").code())
+        );
+
+        settings.set_settings("max_partitions_to_read".to_string(), "3".to_string(), false)?;
+        table.read_plan(ctx.clone(), None).await?;
+    }
+
+    // estimated bytes.
+    {
+        let statistics = Statistics::new_estimated(1000, 8000, 3, 3);
+        let spec = create_spec("test_read_limits_bytes").with_statistics(statistics);
+        let table = catalog.register_test_table(spec)?;
+
+        settings.set_settings("max_bytes_to_read".to_string(), "7999".to_string(), false)?;
+        let res = table.read_plan(ctx.clone(), None).await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::QueryLimitExceeded("").code())
+        );
+
+        settings.set_settings("max_bytes_to_read".to_string(), "8000".to_string(), false)?;
+        table.read_plan(ctx.clone(), None).await?;
+    }
+
+    Ok(())
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod encryption;
 mod fuse;
 mod index;
 mod memory;
+mod mock;
 mod null;
 mod result;
+mod stage;
 mod system;
@@ -0,0 +1,173 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use common_planners::StageTableInfo;
+use databend_query::storages::stage::StageTable;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stage_table_ordered_write() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_max_threads(4)?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u64::to_data_type()),
+        DataField::new("payload", Vu8::to_data_type()),
+    ]);
+    let table = StageTable::create(StageTableInfo {
+        schema: schema.clone(),
+        stage_info: UserStageInfo {
+            stage_name: "test_stage".to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        },
+        path: "unload".to_string(),
+        files: vec![],
+    });
+
+    // 4 blocks of about 10MB, 40 rows in all.
+    let payload = "x".repeat(1024 * 1024);
+    let blocks = (0..4u64)
+        .map(|i| {
+            DataBlock::create(schema.clone(), vec![
+                Series::from_data((i * 10..(i + 1) * 10).collect::<Vec<u64>>()),
+                Series::from_data(vec![payload.as_str(); 10]),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    // 40MB is split into 2 files of consecutive rows.
+    let files = table.write_files(ctx.clone(), blocks, true, 0).await?;
+    assert_eq!(files.len(), 2);
+
+    let operator = ctx.get_storage_operator()?;
+    let mut expected_id = 0u64;
+    for file in &files {
+        let data = operator.object(&file.path).read().await?;
+        assert!(file.path.starts_with("unload/"));
+        assert_eq!(file.rows, 20);
+        assert_eq!(file.bytes, data.len());
+        let ids = String::from_utf8_lossy(&data)
+            .lines()
+            .map(|line| line.split(',').next().unwrap().parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, (expected_id..expected_id + 20).collect::<Vec<_>>());
+        expected_id += 20;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_stage_table_max_file_size() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_max_threads(4)?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", u64::to_data_type()),
+        DataField::new("payload", Vu8::to_data_type()),
+    ]);
+    let table = StageTable::create(StageTableInfo {
+        schema: schema.clone(),
+        stage_info: UserStageInfo {
+            stage_name: "test_stage".to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        },
+        path: "unload_max_size/".to_string(),
+        files: vec![],
+    });
+
+    // 4 blocks of about 10MB, 40 rows in all.
+    let payload = "x".repeat(1024 * 1024);
+    let blocks = (0..4u64)
+        .map(|i| {
+            DataBlock::create(schema.clone(), vec![
+                Series::from_data((i * 10..(i + 1) * 10).collect::<Vec<u64>>()),
+                Series::from_data(vec![payload.as_str(); 10]),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    // The blocks are sliced, no file is larger than 5MB.
+    let max_file_size = 5 * 1024 * 1024;
+    let files = table
+        .write_files(ctx.clone(), blocks, false, max_file_size)
+        .await?;
+    assert!(files.len() >= 8);
+
+    let operator = ctx.get_storage_operator()?;
+    let mut ids = vec![];
+    for file in &files {
+        // The path of the stage is not followed by an empty segment.
+        assert!(!file.path.contains("//"));
+        assert!(file.bytes <= max_file_size);
+
+        let data = operator.object(&file.path).read().await?;
+        assert_eq!(file.bytes, data.len());
+        assert_eq!(file.rows, String::from_utf8_lossy(&data).lines().count());
+        for line in String::from_utf8_lossy(&data).lines() {
+            ids.push(line.split(',').next().unwrap().parse::<u64>().unwrap());
+        }
+    }
+    // The files are in the order of the blocks.
+    assert_eq!(ids, (0..40).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stage_table_small_write() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_max_threads(4)?;
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", u64::to_data_type())]);
+    let table = StageTable::create(StageTableInfo {
+        schema: schema.clone(),
+        stage_info: UserStageInfo {
+            stage_name: "test_stage".to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        },
+        path: "unload".to_string(),
+        files: vec![],
+    });
+
+    let blocks = (0..4u64)
+        .map(|i| DataBlock::create(schema.clone(), vec![Series::from_data(vec![i])]))
+        .collect::<Vec<_>>();
+
+    // A small result is written as one file, ordered or not.
+    assert_eq!(
+        table
+            .write_files(ctx.clone(), blocks.clone(), true, 0)
+            .await?
+            .len(),
+        1
+    );
+    assert_eq!(
+        table
+            .write_files(ctx.clone(), blocks, false, 0)
+            .await?
+            .len(),
+        1
+    );
+
+    Ok(())
+}
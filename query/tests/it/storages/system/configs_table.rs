@@ -28,7 +28,8 @@ use wiremock::ResponseTemplate;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_configs_table() -> Result<()> {
-    let conf = crate::tests::ConfigBuilder::create().config();
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.temp_storage_root = "_temp".to_string();
     let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
     ctx.get_settings().set_max_threads(8)?;
 
@@ -41,75 +42,94 @@ async fn test_configs_table() -> Result<()> {
     assert_eq!(block.num_columns(), 4);
 
     let expected = vec![
-        "+---------+--------------------------------------+---------------------------+-------------+",
-        "| group   | name                                 | value                     | description |",
-        "+---------+--------------------------------------+---------------------------+-------------+",
-        "| log     | dir                                  | ./.databend/logs          |             |",
-        "| log     | level                                | INFO                      |             |",
-        "| log     | query_enabled                        | false                     |             |",
-        "| meta    | address                              |                           |             |",
-        "| meta    | client_timeout_in_second             | 10                        |             |",
-        "| meta    | embedded_dir                         | ./.databend/meta_embedded |             |",
-        "| meta    | endpoints                            |                           |             |",
-        "| meta    | password                             |                           |             |",
-        "| meta    | rpc_tls_meta_server_root_ca_cert     |                           |             |",
-        "| meta    | rpc_tls_meta_service_domain_name     | localhost                 |             |",
-        "| meta    | username                             | root                      |             |",
-        "| query   | admin_api_address                    | 127.0.0.1:8080            |             |",
-        "| query   | api_tls_server_cert                  |                           |             |",
-        "| query   | api_tls_server_key                   |                           |             |",
-        "| query   | api_tls_server_root_ca_cert          |                           |             |",
-        "| query   | clickhouse_handler_host              | 127.0.0.1                 |             |",
-        "| query   | clickhouse_handler_port              | 9000                      |             |",
-        "| query   | cluster_id                           |                           |             |",
-        "| query   | database_engine_github_enabled       | true                      |             |",
-        "| query   | flight_api_address                   | 127.0.0.1:9090            |             |",
-        "| query   | http_handler_host                    | 127.0.0.1                 |             |",
-        "| query   | http_handler_port                    | 8000                      |             |",
-        "| query   | http_handler_result_timeout_millis   | 10000                     |             |",
-        "| query   | http_handler_tls_server_cert         |                           |             |",
-        "| query   | http_handler_tls_server_key          |                           |             |",
-        "| query   | http_handler_tls_server_root_ca_cert |                           |             |",
-        "| query   | jwt_key_file                         |                           |             |",
-        "| query   | management_mode                      | false                     |             |",
-        "| query   | max_active_sessions                  | 256                       |             |",
-        "| query   | max_query_log_size                   | 10000                     |             |",
-        "| query   | metric_api_address                   | 127.0.0.1:7070            |             |",
-        "| query   | mysql_handler_host                   | 127.0.0.1                 |             |",
-        "| query   | mysql_handler_port                   | 3307                      |             |",
-        "| query   | num_cpus                             | 0                         |             |",
-        "| query   | rpc_tls_query_server_root_ca_cert    |                           |             |",
-        "| query   | rpc_tls_query_service_domain_name    | localhost                 |             |",
-        "| query   | rpc_tls_server_cert                  |                           |             |",
-        "| query   | rpc_tls_server_key                   |                           |             |",
-        "| query   | table_cache_block_meta_count         | 102400                    |             |",
-        "| query   | table_cache_enabled                  | false                     |             |",
-        "| query   | table_cache_segment_count            | 10240                     |             |",
-        "| query   | table_cache_snapshot_count           | 256                       |             |",
-        "| query   | table_disk_cache_mb_size             | 1024                      |             |",
-        "| query   | table_disk_cache_root                | _cache                    |             |",
-        "| query   | table_engine_memory_enabled          | true                      |             |",
-        "| query   | table_memory_cache_mb_size           | 256                       |             |",
-        "| query   | tenant_id                            | test                      |             |",
-        "| query   | wait_timeout_mills                   | 5000                      |             |",
-        "| storage | azblob.account_key                   |                           |             |",
-        "| storage | azblob.account_name                  |                           |             |",
-        "| storage | azblob.container                     |                           |             |",
-        "| storage | azblob.endpoint_url                  |                           |             |",
-        "| storage | azblob.root                          |                           |             |",
-        "| storage | fs.data_path                         | _data                     |             |",
-        "| storage | hdfs.name_node                       |                           |             |",
-        "| storage | hdfs.root                            |                           |             |",
-        "| storage | num_cpus                             | 0                         |             |",
-        "| storage | s3.access_key_id                     |                           |             |",
-        "| storage | s3.bucket                            |                           |             |",
-        "| storage | s3.endpoint_url                      | https://s3.amazonaws.com  |             |",
-        "| storage | s3.master_key                        |                           |             |",
-        "| storage | s3.region                            |                           |             |",
-        "| storage | s3.root                              |                           |             |",
-        "| storage | s3.secret_access_key                 |                           |             |",
-        "| storage | type                                 | fs                        |             |",
-        "+---------+--------------------------------------+---------------------------+-------------+",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
+        "| group   | name                                 | value                          | description |",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
+        "| log     | dir                                  | ./.databend/logs               |             |",
+        "| log     | level                                | INFO                           |             |",
+        "| log     | query_enabled                        | false                          |             |",
+        "| meta    | address                              |                                |             |",
+        "| meta    | client_timeout_in_second             | 10                             |             |",
+        "| meta    | embedded_dir                         | ./.databend/meta_embedded      |             |",
+        "| meta    | endpoints                            |                                |             |",
+        "| meta    | password                             |                                |             |",
+        "| meta    | replica_max_lag_in_second            | 60                             |             |",
+        "| meta    | replica_source_address               |                                |             |",
+        "| meta    | rpc_tls_meta_server_root_ca_cert     |                                |             |",
+        "| meta    | rpc_tls_meta_service_domain_name     | localhost                      |             |",
+        "| meta    | username                             | root                           |             |",
+        "| query   | admin_api_address                    | 127.0.0.1:8080                 |             |",
+        "| query   | api_tls_server_cert                  |                                |             |",
+        "| query   | api_tls_server_key                   |                                |             |",
+        "| query   | api_tls_server_root_ca_cert          |                                |             |",
+        "| query   | clickhouse_handler_host              | 127.0.0.1                      |             |",
+        "| query   | clickhouse_handler_port              | 9000                           |             |",
+        "| query   | cluster_id                           |                                |             |",
+        "| query   | database_engine_github_enabled       | true                           |             |",
+        "| query   | external_udf_allowed_addresses       |                                |             |",
+        "| query   | flight_api_address                   | 127.0.0.1:9090                 |             |",
+        "| query   | http_handler_host                    | 127.0.0.1                      |             |",
+        "| query   | http_handler_port                    | 8000                           |             |",
+        "| query   | http_handler_result_timeout_millis   | 10000                          |             |",
+        "| query   | http_handler_tls_server_cert         |                                |             |",
+        "| query   | http_handler_tls_server_key          |                                |             |",
+        "| query   | http_handler_tls_server_root_ca_cert |                                |             |",
+        "| query   | jwt_key_file                         |                                |             |",
+        "| query   | management_mode                      | false                          |             |",
+        "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
+        "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
+        "| query   | mysql_handler_port                   | 3307                           |             |",
+        "| query   | mysql_require_secure_transport       | false                          |             |",
+        "| query   | mysql_tls_server_cert                |                                |             |",
+        "| query   | mysql_tls_server_key                 |                                |             |",
+        "| query   | num_cpus                             | 0                              |             |",
+        "| query   | query_result_cache_mb_size           | 64                             |             |",
+        "| query   | rpc_tls_query_server_root_ca_cert    |                                |             |",
+        "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
+        "| query   | rpc_tls_server_cert                  |                                |             |",
+        "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | storage_encryption_key_file          |                                |             |",
+        "| query   | storage_encryption_kms_command       |                                |             |",
+        "| query   | storage_encryption_required          | false                          |             |",
+        "| query   | table_cache_block_meta_count         | 102400                         |             |",
+        "| query   | table_cache_enabled                  | false                          |             |",
+        "| query   | table_cache_segment_count            | 10240                          |             |",
+        "| query   | table_cache_snapshot_count           | 256                            |             |",
+        "| query   | table_disk_cache_mb_size             | 1024                           |             |",
+        "| query   | table_disk_cache_root                | _cache                         |             |",
+        "| query   | table_engine_memory_enabled          | true                           |             |",
+        "| query   | table_memory_cache_mb_size           | 256                            |             |",
+        "| query   | table_ttl_interval_secs              | 0                              |             |",
+        "| query   | temp_storage_quota_mb                | 0                              |             |",
+        "| query   | temp_storage_quota_mb_per_user       | 0                              |             |",
+        "| query   | temp_storage_root                    | _temp                          |             |",
+        "| query   | tenant_id                            | test                           |             |",
+        "| query   | wait_timeout_mills                   | 5000                           |             |",
+        "| storage | azblob.account_key                   |                                |             |",
+        "| storage | azblob.account_name                  |                                |             |",
+        "| storage | azblob.container                     |                                |             |",
+        "| storage | azblob.endpoint_url                  |                                |             |",
+        "| storage | azblob.root                          |                                |             |",
+        "| storage | fs.data_path                         | _data                          |             |",
+        "| storage | gcs.access_key_id                    |                                |             |",
+        "| storage | gcs.bucket                           |                                |             |",
+        "| storage | gcs.endpoint_url                     | https://storage.googleapis.com |             |",
+        "| storage | gcs.root                             |                                |             |",
+        "| storage | gcs.secret_access_key                |                                |             |",
+        "| storage | hdfs.name_node                       |                                |             |",
+        "| storage | hdfs.root                            |                                |             |",
+        "| storage | num_cpus                             | 0                              |             |",
+        "| storage | s3.access_key_id                     |                                |             |",
+        "| storage | s3.bucket                            |                                |             |",
+        "| storage | s3.endpoint_url                      | https://s3.amazonaws.com       |             |",
+        "| storage | s3.master_key                        |                                |             |",
+        "| storage | s3.region                            |                                |             |",
+        "| storage | s3.root                              |                                |             |",
+        "| storage | s3.secret_access_key                 |                                |             |",
+        "| storage | type                                 | fs                             |             |",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
@@ -125,6 +145,7 @@ async fn test_configs_table_redact() -> Result<()> {
         .await;
 
     let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.temp_storage_root = "_temp".to_string();
     conf.storage.params = StorageParams::S3(StorageS3Config {
         region: "us-east-2".to_string(),
         endpoint_url: mock_server.uri(),
@@ -145,80 +166,99 @@ async fn test_configs_table_redact() -> Result<()> {
     assert_eq!(block.num_columns(), 4);
 
     let endpoint_url_link = format!(
-        "| storage | s3.endpoint_url                      | {:<24}  |             |",
+        "| storage | s3.endpoint_url                      | {:<29}  |             |",
         mock_server.uri()
     );
 
     let expected = vec![
-        "+---------+--------------------------------------+---------------------------+-------------+",
-        "| group   | name                                 | value                     | description |",
-        "+---------+--------------------------------------+---------------------------+-------------+",
-        "| log     | dir                                  | ./.databend/logs          |             |",
-        "| log     | level                                | INFO                      |             |",
-        "| log     | query_enabled                        | false                     |             |",
-        "| meta    | address                              |                           |             |",
-        "| meta    | client_timeout_in_second             | 10                        |             |",
-        "| meta    | embedded_dir                         | ./.databend/meta_embedded |             |",
-        "| meta    | endpoints                            |                           |             |",
-        "| meta    | password                             |                           |             |",
-        "| meta    | rpc_tls_meta_server_root_ca_cert     |                           |             |",
-        "| meta    | rpc_tls_meta_service_domain_name     | localhost                 |             |",
-        "| meta    | username                             | root                      |             |",
-        "| query   | admin_api_address                    | 127.0.0.1:8080            |             |",
-        "| query   | api_tls_server_cert                  |                           |             |",
-        "| query   | api_tls_server_key                   |                           |             |",
-        "| query   | api_tls_server_root_ca_cert          |                           |             |",
-        "| query   | clickhouse_handler_host              | 127.0.0.1                 |             |",
-        "| query   | clickhouse_handler_port              | 9000                      |             |",
-        "| query   | cluster_id                           |                           |             |",
-        "| query   | database_engine_github_enabled       | true                      |             |",
-        "| query   | flight_api_address                   | 127.0.0.1:9090            |             |",
-        "| query   | http_handler_host                    | 127.0.0.1                 |             |",
-        "| query   | http_handler_port                    | 8000                      |             |",
-        "| query   | http_handler_result_timeout_millis   | 10000                     |             |",
-        "| query   | http_handler_tls_server_cert         |                           |             |",
-        "| query   | http_handler_tls_server_key          |                           |             |",
-        "| query   | http_handler_tls_server_root_ca_cert |                           |             |",
-        "| query   | jwt_key_file                         |                           |             |",
-        "| query   | management_mode                      | false                     |             |",
-        "| query   | max_active_sessions                  | 256                       |             |",
-        "| query   | max_query_log_size                   | 10000                     |             |",
-        "| query   | metric_api_address                   | 127.0.0.1:7070            |             |",
-        "| query   | mysql_handler_host                   | 127.0.0.1                 |             |",
-        "| query   | mysql_handler_port                   | 3307                      |             |",
-        "| query   | num_cpus                             | 0                         |             |",
-        "| query   | rpc_tls_query_server_root_ca_cert    |                           |             |",
-        "| query   | rpc_tls_query_service_domain_name    | localhost                 |             |",
-        "| query   | rpc_tls_server_cert                  |                           |             |",
-        "| query   | rpc_tls_server_key                   |                           |             |",
-        "| query   | table_cache_block_meta_count         | 102400                    |             |",
-        "| query   | table_cache_enabled                  | false                     |             |",
-        "| query   | table_cache_segment_count            | 10240                     |             |",
-        "| query   | table_cache_snapshot_count           | 256                       |             |",
-        "| query   | table_disk_cache_mb_size             | 1024                      |             |",
-        "| query   | table_disk_cache_root                | _cache                    |             |",
-        "| query   | table_engine_memory_enabled          | true                      |             |",
-        "| query   | table_memory_cache_mb_size           | 256                       |             |",
-        "| query   | tenant_id                            | test                      |             |",
-        "| query   | wait_timeout_mills                   | 5000                      |             |",
-        "| storage | azblob.account_key                   |                           |             |",
-        "| storage | azblob.account_name                  |                           |             |",
-        "| storage | azblob.container                     |                           |             |",
-        "| storage | azblob.endpoint_url                  |                           |             |",
-        "| storage | azblob.root                          |                           |             |",
-        "| storage | fs.data_path                         | _data                     |             |",
-        "| storage | hdfs.name_node                       |                           |             |",
-        "| storage | hdfs.root                            |                           |             |",
-        "| storage | num_cpus                             | 0                         |             |",
-        "| storage | s3.access_key_id                     | ******_id                 |             |",
-        "| storage | s3.bucket                            | test                      |             |",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
+        "| group   | name                                 | value                          | description |",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
+        "| log     | dir                                  | ./.databend/logs               |             |",
+        "| log     | level                                | INFO                           |             |",
+        "| log     | query_enabled                        | false                          |             |",
+        "| meta    | address                              |                                |             |",
+        "| meta    | client_timeout_in_second             | 10                             |             |",
+        "| meta    | embedded_dir                         | ./.databend/meta_embedded      |             |",
+        "| meta    | endpoints                            |                                |             |",
+        "| meta    | password                             |                                |             |",
+        "| meta    | replica_max_lag_in_second            | 60                             |             |",
+        "| meta    | replica_source_address               |                                |             |",
+        "| meta    | rpc_tls_meta_server_root_ca_cert     |                                |             |",
+        "| meta    | rpc_tls_meta_service_domain_name     | localhost                      |             |",
+        "| meta    | username                             | root                           |             |",
+        "| query   | admin_api_address                    | 127.0.0.1:8080                 |             |",
+        "| query   | api_tls_server_cert                  |                                |             |",
+        "| query   | api_tls_server_key                   |                                |             |",
+        "| query   | api_tls_server_root_ca_cert          |                                |             |",
+        "| query   | clickhouse_handler_host              | 127.0.0.1                      |             |",
+        "| query   | clickhouse_handler_port              | 9000                           |             |",
+        "| query   | cluster_id                           |                                |             |",
+        "| query   | database_engine_github_enabled       | true                           |             |",
+        "| query   | external_udf_allowed_addresses       |                                |             |",
+        "| query   | flight_api_address                   | 127.0.0.1:9090                 |             |",
+        "| query   | http_handler_host                    | 127.0.0.1                      |             |",
+        "| query   | http_handler_port                    | 8000                           |             |",
+        "| query   | http_handler_result_timeout_millis   | 10000                          |             |",
+        "| query   | http_handler_tls_server_cert         |                                |             |",
+        "| query   | http_handler_tls_server_key          |                                |             |",
+        "| query   | http_handler_tls_server_root_ca_cert |                                |             |",
+        "| query   | jwt_key_file                         |                                |             |",
+        "| query   | management_mode                      | false                          |             |",
+        "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
+        "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
+        "| query   | mysql_handler_port                   | 3307                           |             |",
+        "| query   | mysql_require_secure_transport       | false                          |             |",
+        "| query   | mysql_tls_server_cert                |                                |             |",
+        "| query   | mysql_tls_server_key                 |                                |             |",
+        "| query   | num_cpus                             | 0                              |             |",
+        "| query   | query_result_cache_mb_size           | 64                             |             |",
+        "| query   | rpc_tls_query_server_root_ca_cert    |                                |             |",
+        "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
+        "| query   | rpc_tls_server_cert                  |                                |             |",
+        "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | storage_encryption_key_file          |                                |             |",
+        "| query   | storage_encryption_kms_command       |                                |             |",
+        "| query   | storage_encryption_required          | false                          |             |",
+        "| query   | table_cache_block_meta_count         | 102400                         |             |",
+        "| query   | table_cache_enabled                  | false                          |             |",
+        "| query   | table_cache_segment_count            | 10240                          |             |",
+        "| query   | table_cache_snapshot_count           | 256                            |             |",
+        "| query   | table_disk_cache_mb_size             | 1024                           |             |",
+        "| query   | table_disk_cache_root                | _cache                         |             |",
+        "| query   | table_engine_memory_enabled          | true                           |             |",
+        "| query   | table_memory_cache_mb_size           | 256                            |             |",
+        "| query   | table_ttl_interval_secs              | 0                              |             |",
+        "| query   | temp_storage_quota_mb                | 0                              |             |",
+        "| query   | temp_storage_quota_mb_per_user       | 0                              |             |",
+        "| query   | temp_storage_root                    | _temp                          |             |",
+        "| query   | tenant_id                            | test                           |             |",
+        "| query   | wait_timeout_mills                   | 5000                           |             |",
+        "| storage | azblob.account_key                   |                                |             |",
+        "| storage | azblob.account_name                  |                                |             |",
+        "| storage | azblob.container                     |                                |             |",
+        "| storage | azblob.endpoint_url                  |                                |             |",
+        "| storage | azblob.root                          |                                |             |",
+        "| storage | fs.data_path                         | _data                          |             |",
+        "| storage | gcs.access_key_id                    |                                |             |",
+        "| storage | gcs.bucket                           |                                |             |",
+        "| storage | gcs.endpoint_url                     | https://storage.googleapis.com |             |",
+        "| storage | gcs.root                             |                                |             |",
+        "| storage | gcs.secret_access_key                |                                |             |",
+        "| storage | hdfs.name_node                       |                                |             |",
+        "| storage | hdfs.root                            |                                |             |",
+        "| storage | num_cpus                             | 0                              |             |",
+        "| storage | s3.access_key_id                     | ******_id                      |             |",
+        "| storage | s3.bucket                            | test                           |             |",
         &endpoint_url_link,
-        "| storage | s3.master_key                        |                           |             |",
-        "| storage | s3.region                            | us-east-2                 |             |",
-        "| storage | s3.root                              |                           |             |",
-        "| storage | s3.secret_access_key                 | ******key                 |             |",
-        "| storage | type                                 | s3                        |             |",
-        "+---------+--------------------------------------+---------------------------+-------------+",
+        "| storage | s3.master_key                        |                                |             |",
+        "| storage | s3.region                            | us-east-2                      |             |",
+        "| storage | s3.root                              |                                |             |",
+        "| storage | s3.secret_access_key                 | ******key                      |             |",
+        "| storage | type                                 | s3                             |             |",
+        "+---------+--------------------------------------+--------------------------------+-------------+",
     ];
 
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::storages::system::FormatsTable;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_formats_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = FormatsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 5);
+    assert_eq!(block.num_rows(), 41);
+
+    let expected = vec![
+        "+----------------------+-----------+------------------+-------------------------+---------------------------------------------------------------------+",
+        "| name                 | direction | option           | expected                | description                                                         |",
+        "+----------------------+-----------+------------------+-------------------------+---------------------------------------------------------------------+",
+        "| arrow                | output    |                  |                         |                                                                     |",
+        "| csv                  | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| csv                  | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| csv                  | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| csv                  | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| csv                  | output    | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| csv                  | output    | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| csvwithnames         | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| csvwithnames         | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| csvwithnames         | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| csvwithnames         | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| csvwithnamesandtypes | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| csvwithnamesandtypes | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| csvwithnamesandtypes | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| csvwithnamesandtypes | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| json                 | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| json                 | output    |                  |                         |                                                                     |",
+        "| jsoncompact          | output    |                  |                         |                                                                     |",
+        "| jsoneachrow          | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| jsoneachrow          | output    |                  |                         |                                                                     |",
+        "| ndjson               | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| ndjson               | output    |                  |                         |                                                                     |",
+        "| parquet              | input     |                  |                         |                                                                     |",
+        "| parquet              | output    |                  |                         |                                                                     |",
+        "| tabseparated         | output    | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| tabseparated         | output    | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| tsv                  | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| tsv                  | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| tsv                  | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| tsv                  | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| tsv                  | output    | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| tsv                  | output    | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| tsvwithnames         | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| tsvwithnames         | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| tsvwithnames         | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| tsvwithnames         | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| tsvwithnamesandtypes | input     | compression      | a compression type      | The compression of the files, auto detects it by the file extension |",
+        "| tsvwithnamesandtypes | input     | field_delimiter  | one character           | The character between the fields of a record                        |",
+        r"| tsvwithnamesandtypes | input     | record_delimiter | one character or '\r\n' | The character or '\r\n' between the records                         |",
+        "| tsvwithnamesandtypes | input     | skip_header      | an unsigned integer     | Number of lines at the start of the file to skip                    |",
+        "| values               | output    |                  |                         |                                                                     |",
+        "+----------------------+-----------+------------------+-------------------------+---------------------------------------------------------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod caches_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
@@ -19,7 +20,9 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod formats_table;
 mod functions_table;
+mod load_table;
 mod metrics_table;
 mod query_log_table;
 mod roles_table;
@@ -34,19 +34,64 @@ async fn test_settings_table() -> Result<()> {
         "| name                           | value   | default | level   | description                                                                                        | type   |",
         "+--------------------------------+---------+---------+---------+----------------------------------------------------------------------------------------------------+--------+",
         "|                                |         |         |         |                                                                                                    |        |",
-        "| compression                    | None    | None    | SESSION | Format compression, default value: None                                                            | String |",
-        "| empty_as_default               | 1       | 1       | SESSION | Format empty_as_default, default value: 1                                                          | UInt64 |",
-        "| enable_new_processor_framework | 1       | 1       | SESSION | Enable new processor framework if value != 0, default value: 1                                     | UInt64 |",
-        "| enable_planner_v2              | 0       | 0       | SESSION | Enable planner v2 by setting this variable to 1, default value: 0                                  | UInt64 |",
-        "| field_delimiter                | ,       | ,       | SESSION | Format field delimiter, default value: ,                                                           | String |",
-        "| flight_client_timeout          | 60      | 60      | SESSION | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds | UInt64 |",
-        "| group_by_two_level_threshold   | 10000   | 10000   | SESSION | The threshold of keys to open two-level aggregation, default value: 10000                          | UInt64 |",
-        "| max_block_size                 | 10000   | 10000   | SESSION | Maximum block size for reading                                                                     | UInt64 |",
+        "| broadcast_join_threshold       | 1000000 | 1000000 | DEFAULT | Max estimated rows of the hash join build side to broadcast, bigger ones are hash repartitioned.   | UInt64 |",
+        "| column_name_case               | lower   | lower   | DEFAULT | Case of the result column names: lower, preserve or alias (alias only), default value: lower.      | String |",
+        "| compression                    | None    | None    | DEFAULT | Format compression, default value: None                                                            | String |",
+        "| copy_files_per_commit          | 0       | 0       | DEFAULT | The maximum files COPY commits at once, 0 means all the files in one commit.                       | UInt64 |",
+        "| empty_as_default               | 1       | 1       | DEFAULT | Format empty_as_default, default value: 1                                                          | UInt64 |",
+        "| enable_cbo                     | 0       | 0       | DEFAULT | Enable the cost-based join reorder of planner v2 if value != 0, default value: 0                   | UInt64 |",
+        "| enable_hedged_reads            | 0       | 0       | DEFAULT | Issue a backup request for the block reads slower than hedged_read_percentile, default value: 0    | UInt64 |",
+        "| enable_materialized_cte        | 0       | 0       | DEFAULT | Materialize the CTEs of planner v2 once instead of inlining them if value != 0, default value: 0   | UInt64 |",
+        "| enable_new_processor_framework | 1       | 1       | DEFAULT | Enable new processor framework if value != 0, default value: 1                                     | UInt64 |",
+        "| enable_planner_v2              | 0       | 0       | DEFAULT | Enable planner v2 by setting this variable to 1, default value: 0                                  | UInt64 |",
+        "| enable_query_result_cache      | 1       | 1       | DEFAULT | Enable the node level cache of deterministic SELECT results, default value: 1                      | UInt64 |",
+        "| enable_runtime_filter          | 1       | 1       | DEFAULT | Enables the hash join runtime filters to skip probe side blocks and rows, default value: 1.        | UInt64 |",
+        "| enable_shared_scan             | 0       | 0       | DEFAULT | Scan a table referenced several times in a query only once, default value: 0                       | UInt64 |",
+        "| enable_strict_type_coercion    | 0       | 0       | DEFAULT | Reject lossy implicit casts of function arguments and comparisons in planner v2, default value: 0  | UInt64 |",
+        "| error_on_extra_columns         | 0       | 0       | DEFAULT | Whether to error on the file columns not in the table, default value: 0                            | UInt64 |",
+        "| exchange_skew_factor           | 0       | 0       | DEFAULT | Warn when an exchange partition receives this many times the average rows, 0 disables it.          | UInt64 |",
+        "| executor_cpu_affinity          | none    | none    | DEFAULT | Bind pipeline executor threads to CPUs: none, core (a core per thread) or numa (a node per thread) | String |",
+        "| external_udf_batch_rows        | 65536   | 65536   | DEFAULT | The max rows of one batch sent to the external UDF server, default value: 65536                    | UInt64 |",
+        "| external_udf_timeout_secs      | 60      | 60      | DEFAULT | The timeout in seconds of calling the external UDF server, default value: 60                       | UInt64 |",
+        "| field_delimiter                | ,       | ,       | DEFAULT | Format field delimiter, default value: ,                                                           | String |",
+        "| flight_client_timeout          | 60      | 60      | DEFAULT | Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds | UInt64 |",
+        "| group_by_shuffle_threshold     | 0       | 0       | DEFAULT | Aggregate inputs with fewer rows than this are converged instead of shuffled, default value: 0     | UInt64 |",
+        "| group_by_two_level_threshold   | 10000   | 10000   | DEFAULT | The threshold of keys to open two-level aggregation, default value: 10000                          | UInt64 |",
+        "| hedged_read_min_delay_ms       | 10      | 10      | DEFAULT | The minimum delay in milliseconds to issue a backup request after, default value: 10               | UInt64 |",
+        "| hedged_read_percentile         | 95      | 95      | DEFAULT | The read latency percentile to issue a backup request after, default value: 95                     | UInt64 |",
+        "| join_sample_blocks             | 0       | 0       | DEFAULT | Number of blocks sampled per join side to estimate join cardinality, 0 disables it                 | UInt64 |",
+        "| join_sample_timeout_ms         | 100     | 100     | DEFAULT | Time budget in milliseconds for sampling join cardinality, default value: 100                      | UInt64 |",
+        "| load_idempotency_key_ttl       | 86400   | 86400   | DEFAULT | How long the idempotency key of a load is kept in seconds, 86400 by default.                       | UInt64 |",
+        "| match_by_column_name           | none    | none    | DEFAULT | Map the columns of the loaded files onto the table by name, default value: none                    | String |",
+        "| max_block_size                 | 10000   | 10000   | DEFAULT | Maximum block size for reading                                                                     | UInt64 |",
+        "| max_bytes_to_read              | 0       | 0       | DEFAULT | The maximum estimated bytes a query reads from a table, 0 means no limit.                          | UInt64 |",
+        "| max_distinct_memory_usage      | 0       | 0       | DEFAULT | The maximum memory in bytes of the distinct keys before spilling to disk, 0 means no limit.        | UInt64 |",
+        "| max_execution_time             | 0       | 0       | DEFAULT | The maximum query execution time in milliseconds, 0 means no limit.                                | UInt64 |",
+        "| max_memory_usage               | 0       | 0       | DEFAULT | The maximum memory usage in bytes of a query, 0 means no limit.                                    | UInt64 |",
+        "| max_partitions_per_insert      | 100     | 100     | DEFAULT | The maximum partitions an inserted block spans, 0 means no limit.                                  | UInt64 |",
+        "| max_partitions_to_read         | 0       | 0       | DEFAULT | The maximum partitions a query reads from a table, 0 means no limit.                               | UInt64 |",
+        "| max_recursion_depth            | 1000    | 1000    | DEFAULT | The maximum number of iterations of a recursive CTE, default value: 1000                           | UInt64 |",
+        "| max_result_rows                | 0       | 0       | DEFAULT | The maximum number of rows in the result of a query, 0 means no limit.                             | UInt64 |",
+        "| max_result_scan_cache_bytes    | 4194304 | 4194304 | DEFAULT | The maximum bytes of query results a session keeps for RESULT_SCAN, 0 means disabled               | UInt64 |",
+        "| max_temp_storage_bytes         | 0       | 0       | DEFAULT | The maximum bytes of temporary storage(e.g. spill files) a query uses, 0 means unlimited           | UInt64 |",
         "| max_threads                    | 2       | 16      | SESSION | The maximum number of threads to execute the request. By default, it is determined automatically.  | UInt64 |",
-        "| record_delimiter               |         |         | SESSION | Format record_delimiter, default value:                                                            | String |",
-        "| skip_header                    | 0       | 0       | SESSION | Whether to skip the input header, default value: 0                                                 | UInt64 |",
-        "| storage_read_buffer_size       | 1048576 | 1048576 | SESSION | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                     | UInt64 |",
-        "| timezone                       | UTC     | UTC     | SESSION | Timezone, default value: UTC,                                                                      | String |",
+        "| mysql_flush_threshold          | 10000   | 10000   | DEFAULT | Rows buffered before the results are written to MySQL clients, default value: 10000                | UInt64 |",
+        "| preserve_insertion_order       | 0       | 0       | DEFAULT | Keep the input order of blocks read in parallel in the query results, 0 for disable.               | UInt64 |",
+        "| purge_retention_minutes        | 0       | 0       | DEFAULT | The minutes of the table history OPTIMIZE TABLE PURGE keeps, 0 means only the current snapshot.    | UInt64 |",
+        "| query_tag                      |         |         | DEFAULT | Tag of the query, e.g. the job it belongs to                                                       | String |",
+        "| quote_char                     | \"'      | \"'      | DEFAULT | Format quote chars, default value: \"'                                                              | String |",
+        "| random_seed                    |         |         | DEFAULT | Seed of the random functions and join sampling, empty means unseeded, default value: empty         | String |",
+        "| record_delimiter               |         |         | DEFAULT | Format record_delimiter, default value:                                                            | String |",
+        "| shared_scan_spilling_ratio     | 0       | 0       | DEFAULT | The percentage of max_memory_usage a shared scan buffers before spilling to disk, 0: no spilling.  | UInt64 |",
+        "| skip_header                    | 0       | 0       | DEFAULT | Whether to skip the input header, default value: 0                                                 | UInt64 |",
+        "| sort_merge_join_threshold      | 0       | 0       | DEFAULT | Min estimated bytes of both join sides for a sort-merge join, 0 means half of max_memory_usage.    | UInt64 |",
+        "| sort_spilling_memory_ratio     | 0       | 0       | DEFAULT | The percentage of max_memory_usage the sort buffers before spilling to disk, 0 means no spilling.  | UInt64 |",
+        "| sql_strict_mode                | 0       | 0       | DEFAULT | ANSI strict mode: integer / truncates, overflows, division by zero and bad casts raise errors.     | UInt64 |",
+        "| storage_read_buffer_size       | 1048576 | 1048576 | DEFAULT | The size of buffer in bytes for buffered reader of dal. By default, it is 1MB.                     | UInt64 |",
+        "| timezone                       | UTC     | UTC     | DEFAULT | Timezone, default value: UTC,                                                                      | String |",
+        "| verify_block_checksum          | 1       | 1       | DEFAULT | Verify the checksums of the blocks on read, default value: 1.                                      | UInt64 |",
+        "| wasm_udf_fuel_per_row          | 100000  | 100000  | DEFAULT | The fuel each row may consume in WASM user-defined functions, default value: 100000                | UInt64 |",
+        "| wasm_udf_max_memory_mb         | 64      | 64      | DEFAULT | The max linear memory of one WASM user-defined function call in MB, default value: 64              | UInt64 |",
         "+--------------------------------+---------+---------+---------+----------------------------------------------------------------------------------------------------+--------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
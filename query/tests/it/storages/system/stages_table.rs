@@ -41,11 +41,11 @@ async fn test_stages_table() -> Result<()> {
     assert_eq!(block.num_columns(), 6);
 
     let expected = vec![
-        "+------------+------------+----------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
-        "| name       | stage_type | stage_params                                                   | copy_options                                  | file_format_options                                                                                                | comment |",
-        "+------------+------------+----------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
-        r#"| test_stage | External   | StageParams { storage: Fs(StorageFsConfig { root: "_data" }) } | CopyOptions { on_error: None, size_limit: 0 } | FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", compression: None } |         |"#,
-        "+------------+------------+----------------------------------------------------------------+-----------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+        "+------------+------------+----------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+        "| name       | stage_type | stage_params                                                   | copy_options                                                              | file_format_options                                                                                                | comment |",
+        "+------------+------------+----------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
+        r#"| test_stage | External   | StageParams { storage: Fs(StorageFsConfig { root: "_data" }) } | CopyOptions { on_error: None, size_limit: 0, match_by_column_name: None } | FileFormatOptions { format: Csv, skip_header: 0, field_delimiter: ",", record_delimiter: "\n", compression: None } |         |"#,
+        "+------------+------------+----------------------------------------------------------------+---------------------------------------------------------------------------+--------------------------------------------------------------------------------------------------------------------+---------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
@@ -15,7 +15,7 @@
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
-use common_meta_types::AuthType;
+use common_meta_types::PasswordHashMethod;
 use common_meta_types::UserGrantSet;
 use common_meta_types::UserInfo;
 use common_meta_types::UserOption;
@@ -24,6 +24,8 @@ use databend_query::storages::system::UsersTable;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
+use sha2::Digest;
+use sha2::Sha256;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_users_table() -> Result<()> {
@@ -45,13 +47,16 @@ async fn test_users_table() -> Result<()> {
             false,
         )
         .await?;
-    let auth_data = AuthInfo::new(AuthType::Sha256Password, &Some("123456789".to_string()));
-    assert!(auth_data.is_ok());
+    // The unsalted sha256 password of the previous versions, the salted one is random.
+    let auth_data = AuthInfo::Password {
+        hash_value: Sha256::digest(b"123456789").to_vec(),
+        hash_method: PasswordHashMethod::Sha256,
+    };
     ctx.get_user_manager()
         .add_user(
             &tenant,
             UserInfo {
-                auth_info: auth_data.unwrap(),
+                auth_info: auth_data,
                 name: "test1".to_string(),
                 hostname: "%".to_string(),
                 grants: UserGrantSet::empty(),
@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_scan_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let session = ctx.get_current_session();
+
+    let query = "select number, number * 2 as double from numbers_mt(5) where number > 1";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let query_id = ctx.get_id();
+
+    // The result is read again in another query of the same session.
+    let ctx = session.create_query_context().await?;
+    let query = format!(
+        "select double from result_scan('{}') where number < 4 order by double",
+        query_id
+    );
+    let plan = PlanParser::parse(ctx.clone(), &query).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let result = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+--------+",
+        "| double |",
+        "+--------+",
+        "| 4      |",
+        "| 6      |",
+        "+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // Unknown query id.
+    let ctx = session.create_query_context().await?;
+    let res = PlanParser::parse(ctx.clone(), "select * from result_scan('unknown')").await;
+    assert_eq!(
+        res.err().unwrap().code(),
+        ErrorCode::BadArguments("").code()
+    );
+
+    // Results are not kept when max_result_scan_cache_bytes is 0.
+    let ctx = session.create_query_context().await?;
+    ctx.get_settings().set_settings(
+        "max_result_scan_cache_bytes".to_string(),
+        "0".to_string(),
+        false,
+    )?;
+    let plan = PlanParser::parse(ctx.clone(), "select 1").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let _ = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(session.get_query_result(&ctx.get_id()).is_none());
+
+    Ok(())
+}
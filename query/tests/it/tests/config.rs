@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_query::Config;
+use uuid::Uuid;
 
 pub struct ConfigBuilder {
     conf: Config,
@@ -22,6 +23,12 @@ impl ConfigBuilder {
     pub fn create() -> ConfigBuilder {
         let mut conf = Config::default();
         conf.query.tenant_id = "test".to_string();
+        // The orphans under the root are removed when a node starts, the tests running in
+        // parallel must not share it.
+        conf.query.temp_storage_root = std::env::temp_dir()
+            .join(format!("databend-test-{}", Uuid::new_v4().to_simple()))
+            .to_string_lossy()
+            .to_string();
 
         ConfigBuilder { conf }
     }
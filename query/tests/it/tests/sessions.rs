@@ -95,6 +95,24 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::create_with_conf(new_config)
     }
 
+    pub fn mysql_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.mysql_tls_server_key = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn mysql_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.mysql_tls_server_cert = value.into();
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
+    pub fn mysql_require_secure_transport(self, value: bool) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.mysql_require_secure_transport = value;
+        SessionManagerBuilder::create_with_conf(new_config)
+    }
+
     pub fn http_handler_tls_server_root_ca_cert(
         self,
         value: impl Into<String>,
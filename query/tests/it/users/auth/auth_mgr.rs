@@ -16,7 +16,9 @@ use base64::encode_config;
 use base64::URL_SAFE_NO_PAD;
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
 use common_meta_types::UserIdentity;
+use common_meta_types::UserInfo;
 use databend_query::users::auth::jwt::CustomClaims;
 use databend_query::users::auth::jwt::EnsureUser;
 use databend_query::users::AuthMgr;
@@ -231,3 +233,150 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_mysql_scramble() -> Result<()> {
+    use sha1::Digest;
+
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let user_mgr = UserApiProvider::create_global(conf.clone()).await?;
+    let auth_mgr = AuthMgr::create(conf.clone(), user_mgr.clone()).await?;
+
+    let auth_info = AuthInfo::create(&None, &Some("password".to_string()))?;
+    let user_info = UserInfo::new("mysql_user", "%", auth_info);
+    user_mgr
+        .add_user(&conf.query.tenant_id, user_info, false)
+        .await?;
+
+    // SHA1( password ) XOR SHA1( salt <concat> SHA1( SHA1( password ) ) )
+    let scramble = |password: &[u8], salt: &[u8]| {
+        let stage1 = sha1::Sha1::digest(password);
+        let stage2 = sha1::Sha1::digest(&stage1);
+        let mut m = sha1::Sha1::new();
+        m.update(salt);
+        m.update(&stage2);
+        let xor = m.finalize();
+        stage1
+            .iter()
+            .zip(xor.iter())
+            .map(|(l, r)| l ^ r)
+            .collect::<Vec<_>>()
+    };
+
+    let salt = vec![1u8; 20];
+    let (tenant, user) = auth_mgr
+        .auth(&Credential::MySQL {
+            name: "mysql_user".to_string(),
+            salt: salt.clone(),
+            scramble: scramble(b"password", &salt),
+            hostname: Some("127.0.0.1".to_string()),
+        })
+        .await?;
+    assert!(tenant.is_none());
+    assert_eq!(user.name, "mysql_user");
+
+    let res = auth_mgr
+        .auth(&Credential::MySQL {
+            name: "mysql_user".to_string(),
+            salt: salt.clone(),
+            scramble: scramble(b"wrong_password", &salt),
+            hostname: Some("127.0.0.1".to_string()),
+        })
+        .await;
+    assert_eq!(
+        "Code: 1051, displayText = wrong password.",
+        res.err().unwrap().to_string()
+    );
+
+    let res = auth_mgr
+        .auth(&Credential::MySQL {
+            name: "not_exists".to_string(),
+            salt: salt.clone(),
+            scramble: scramble(b"password", &salt),
+            hostname: Some("127.0.0.1".to_string()),
+        })
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_caching_sha2_password() -> Result<()> {
+    use sha2::Digest;
+    use sha2::Sha256;
+
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let tenant = conf.query.tenant_id.clone();
+    let user_mgr = UserApiProvider::create_global(conf.clone()).await?;
+    let auth_mgr = AuthMgr::create(conf.clone(), user_mgr.clone()).await?;
+
+    let auth_info = AuthInfo::create(
+        &Some("sha256_password".to_string()),
+        &Some("password".to_string()),
+    )?;
+    let user_info = UserInfo::new("sha2_user", "%", auth_info);
+    user_mgr.add_user(&tenant, user_info, false).await?;
+
+    // SHA256( password ) XOR SHA256( SHA256( SHA256( password ) ) <concat> salt )
+    let scramble = |password: &[u8], salt: &[u8]| {
+        let stage1 = Sha256::digest(password);
+        let stage2 = Sha256::digest(&stage1);
+        let mut m = Sha256::new();
+        m.update(&stage2);
+        m.update(salt);
+        let xor = m.finalize();
+        stage1
+            .iter()
+            .zip(xor.iter())
+            .map(|(l, r)| l ^ r)
+            .collect::<Vec<_>>()
+    };
+    let fast_auth = |password: &[u8]| {
+        let salt = vec![1u8; 20];
+        Credential::MySQL {
+            name: "sha2_user".to_string(),
+            salt: salt.clone(),
+            scramble: scramble(password, &salt),
+            hostname: Some("127.0.0.1".to_string()),
+        }
+    };
+    let full_auth = |password: &[u8]| Credential::Password {
+        name: "sha2_user".to_string(),
+        password: Some(password.to_vec()),
+        hostname: Some("127.0.0.1".to_string()),
+    };
+
+    // Nothing is cached before the full authentication.
+    let res = auth_mgr.auth(&fast_auth(b"password")).await;
+    assert_eq!(
+        "Code: 1051, displayText = caching_sha2_password requires the full authentication.",
+        res.err().unwrap().to_string()
+    );
+
+    assert!(auth_mgr.auth(&full_auth(b"wrong_password")).await.is_err());
+    assert!(auth_mgr.auth(&fast_auth(b"password")).await.is_err());
+
+    auth_mgr.auth(&full_auth(b"password")).await?;
+    let (_, user) = auth_mgr.auth(&fast_auth(b"password")).await?;
+    assert_eq!(user.name, "sha2_user");
+    assert!(auth_mgr.auth(&fast_auth(b"wrong_password")).await.is_err());
+
+    // The cached digest is dropped once the password is changed.
+    let auth_info = AuthInfo::create(
+        &Some("sha256_password".to_string()),
+        &Some("new_password".to_string()),
+    )?;
+    user_mgr
+        .update_user(
+            &tenant,
+            UserIdentity::new("sha2_user", "%"),
+            Some(auth_info),
+            None,
+        )
+        .await?;
+    assert!(auth_mgr.auth(&fast_auth(b"password")).await.is_err());
+    assert!(auth_mgr.auth(&fast_auth(b"new_password")).await.is_err());
+
+    Ok(())
+}
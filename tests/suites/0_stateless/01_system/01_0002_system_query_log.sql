@@ -15,4 +15,17 @@ select count(*) from tbl_01_0002 where a > 1;
 -- let's check it out
 select count(1) from system.query_log where query_text='select count(*) from tbl_01_0002 where a > 1' and scan_partitions = 1 and total_partitions = 2;
 
+-- reading the blocks sends requests to the storage
+select sum(a) from tbl_01_0002;
+select count(*) > 0 from system.query_log where query_text='select sum(a) from tbl_01_0002' and log_type = 2 and io_requests > 0;
+
 drop table tbl_01_0002;
+
+-- every finished query records its duration
+select count(*) > 0 from system.query_log where log_type = 2 and query_duration_ms >= 0;
+
+-- the queries are tagged by the query_tag setting
+set query_tag = 'job_01_0002';
+select 1;
+select count(*) > 0 from system.query_log where query_tag = 'job_01_0002' and query_text = 'select 1';
+set query_tag = '';
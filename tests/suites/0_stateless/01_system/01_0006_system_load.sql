@@ -0,0 +1,2 @@
+SELECT count(*) > 0, sum(active_sessions) > 0, min(max_sessions) > 0 FROM system.load;
+SELECT http_address != '' FROM system.load LIMIT 1;
@@ -0,0 +1,3 @@
+SELECT option, expected FROM system.formats WHERE name = 'csv' AND direction = 'input' ORDER BY option;
+SELECT name FROM system.formats WHERE direction = 'output' AND option = 'field_delimiter' ORDER BY name;
+SELECT count() FROM system.formats WHERE name = 'parquet';
@@ -1,2 +1,4 @@
 SELECT current_user();
 SELECT user();
+SELECT current_role();
+SELECT length(last_query_id());
@@ -48,3 +48,11 @@ SELECT EXPORT_SET(1,    'NU_LL', NULL,   'NU_LL', 1);
 SELECT EXPORT_SET(NULL, NULL,   'NU_LL', 'NU_LL', 1);
 SELECT EXPORT_SET(1,    NULL,   'NU_LL', 'NU_LL', 1);
 SELECT EXPORT_SET(NULL, 'NU_LL', 'NU_LL', 'NU_LL', 1);
+
+SELECT '=== coercion ===';
+SELECT EXPORT_SET(5, 1, 0, '', 4);
+SET enable_planner_v2 = 1;
+SET enable_strict_type_coercion = 1;
+SELECT EXPORT_SET(5, 'Y', 'N', '', 4);
+SELECT EXPORT_SET(5, 1, 0, '', 4); -- {ErrorCode 1007}
+SELECT EXPORT_SET(-1, 'Y', 'N', '', 4); -- {ErrorCode 1007}
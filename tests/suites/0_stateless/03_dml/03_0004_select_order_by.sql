@@ -43,3 +43,8 @@ SELECT id, arr FROM t3 ORDER BY arr ASC;
 SELECT id, arr FROM t3 ORDER BY arr DESC;
 
 DROP TABLE t3;
+
+SELECT '==Top-N==';
+SELECT number FROM numbers(100) ORDER BY number DESC LIMIT 3;
+SELECT number FROM numbers(100) ORDER BY number DESC LIMIT 2 OFFSET 3;
+SELECT number FROM (SELECT number FROM numbers(100) ORDER BY number DESC) WHERE number % 10 = 0 LIMIT 3;
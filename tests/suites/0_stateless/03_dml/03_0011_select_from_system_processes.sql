@@ -1 +1,2 @@
 select state, database from system.processes;
+select query_id is not null, elapsed_ms is not null from system.processes;
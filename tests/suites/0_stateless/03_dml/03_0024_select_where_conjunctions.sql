@@ -0,0 +1,5 @@
+SELECT number FROM numbers(10) WHERE number > 2 AND number < 6 AND number != 4 ORDER BY number;
+SELECT number FROM numbers(100) WHERE to_varchar(number) LIKE '%7' AND number % 2 = 1 AND number > 50 ORDER BY number;
+SELECT count() FROM numbers(1000) WHERE number = 3 AND to_varchar(number) REGEXP '^[0-9]+$';
+SELECT count() FROM numbers(1000) WHERE number > 1000 AND to_varchar(number) REGEXP '^[0-9]+$';
+SELECT count() FROM numbers(1000) WHERE (number < 10 OR number > 990) AND number % 3 = 0;
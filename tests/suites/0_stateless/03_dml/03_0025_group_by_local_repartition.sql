@@ -0,0 +1,9 @@
+set max_threads = 8;
+
+select number % 3 as a, count() as c, sum(number) as s from numbers(100000) group by a order by a;
+select count() from (select number % 1000 as a, number % 7 as b from numbers(100000) group by a, b);
+select sum(number), count() from numbers(100000);
+
+set enable_planner_v2 = 1;
+select number % 3 as a, count() as c, sum(number) as s from numbers(100000) group by a order by a;
+set enable_planner_v2 = 0;
@@ -0,0 +1,3 @@
+SELECT (number + 1) * 2, (number + 1) + 2 FROM numbers(5) WHERE (number + 1) > 3 ORDER BY number;
+SELECT number % 3 + 1 AS a, count() FROM numbers(10) WHERE number % 3 + 1 > 1 GROUP BY a ORDER BY a;
+SELECT to_string(number + 1) FROM numbers(3) WHERE length(to_string(number + 1)) = 1 ORDER BY number;
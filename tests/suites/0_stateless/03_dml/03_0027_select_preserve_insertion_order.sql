@@ -0,0 +1,18 @@
+set max_threads = 8;
+set max_block_size = 3;
+set preserve_insertion_order = 1;
+
+select number from numbers(30) limit 4 offset 10;
+
+DROP TABLE IF EXISTS t_insertion_order;
+CREATE TABLE t_insertion_order(a UInt64) Engine = Memory;
+INSERT INTO t_insertion_order VALUES (3);
+INSERT INTO t_insertion_order VALUES (1);
+INSERT INTO t_insertion_order VALUES (2);
+select a from t_insertion_order;
+
+set enable_planner_v2 = 1;
+select a from t_insertion_order limit 2;
+set enable_planner_v2 = 0;
+
+DROP TABLE t_insertion_order;
@@ -0,0 +1,9 @@
+set max_memory_usage = 67108864;
+select count(), sum(c), max(c) from (select number % 300000 as k, count() as c from numbers(1000000) group by k);
+select count(), sum(c) from (select number % 300000 as k, to_varchar(number % 7) as s, count() as c from numbers(1000000) group by k, s);
+select count() from (select number % 300000 as k from numbers(1000000) group by k);
+
+set enable_planner_v2 = 1;
+select count(), sum(c), max(c) from (select number % 300000 as k, count() as c from numbers(1000000) group by k);
+set enable_planner_v2 = 0;
+set max_memory_usage = 0;
@@ -0,0 +1,13 @@
+set max_memory_usage = 67108864;
+set sort_spilling_memory_ratio = 1;
+
+select number from (select number from numbers(1000000) order by number desc) where number % 200000 = 0;
+select number % 3 as a, number from (select number from numbers(1000000) order by number % 3, number desc) where number % 250000 = 0;
+select count(), sum(number) from (select number from numbers(1000000) order by number);
+
+set enable_planner_v2 = 1;
+select number from (select number from numbers(1000000) order by number desc) where number % 200000 = 0;
+set enable_planner_v2 = 0;
+
+set sort_spilling_memory_ratio = 0;
+set max_memory_usage = 0;
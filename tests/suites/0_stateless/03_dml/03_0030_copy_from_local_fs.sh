@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATADIR=/tmp/03_0030_copy_from_local_fs
+rm -rf $DATADIR && mkdir -p $DATADIR
+printf '1,a\n2,b\n' > $DATADIR/f1.csv
+printf '3,c\n' > $DATADIR/f2.csv
+printf '4,d\n' > $DATADIR/skip.txt
+
+echo "drop table if exists t03_0030" | $MYSQL_CLIENT_CONNECT
+echo "create table t03_0030(id int, name varchar)" | $MYSQL_CLIENT_CONNECT
+
+echo "copy into t03_0030 from 'fs://$DATADIR/' pattern = '.*csv' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t03_0030" | $MYSQL_CLIENT_CONNECT
+
+## the files are loaded once
+echo "copy into t03_0030 from 'fs://$DATADIR/' pattern = '.*csv' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t03_0030" | $MYSQL_CLIENT_CONNECT
+
+## a changed file is loaded again
+printf '3,c\n5,e\n' > $DATADIR/f2.csv
+echo "copy into t03_0030 from 'fs://$DATADIR/' pattern = '.*csv' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t03_0030" | $MYSQL_CLIENT_CONNECT
+
+## the files can be loaded again after truncate
+echo "truncate table t03_0030" | $MYSQL_CLIENT_CONNECT
+echo "copy into t03_0030 from 'fs://$DATADIR/' pattern = '.*csv' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from t03_0030" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t03_0030" | $MYSQL_CLIENT_CONNECT
+rm -rf $DATADIR
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATADIR=/tmp/03_0031_copy_match_by_column_name
+rm -rf $DATADIR && mkdir -p $DATADIR
+printf 'NAME,extra,id\na,x,1\nb,y,2\n' > $DATADIR/f1.csv
+cp $DATADIR/f1.csv $DATADIR/f2.csv
+cp $DATADIR/f1.csv $DATADIR/f3.csv
+cp "$CURDIR"/../../../data/alltypes_plain.parquet $DATADIR/f4.parquet
+
+echo "drop table if exists t03_0031" | $MYSQL_CLIENT_CONNECT
+echo "create table t03_0031(id int null, name varchar, score int default 42)" | $MYSQL_CLIENT_CONNECT
+
+## columns are mapped by name, the missing column takes its default value
+echo "copy into t03_0031 from 'fs://$DATADIR/' pattern = 'f1.csv' file_format = (type = csv) match_by_column_name = case_insensitive" | $MYSQL_CLIENT_CONNECT
+echo "select * from t03_0031 order by id" | $MYSQL_CLIENT_CONNECT
+
+## NAME does not match name case sensitively
+echo "truncate table t03_0031" | $MYSQL_CLIENT_CONNECT
+echo "copy into t03_0031 from 'fs://$DATADIR/' pattern = 'f2.csv' file_format = (type = csv) match_by_column_name = case_sensitive" | $MYSQL_CLIENT_CONNECT
+echo "select * from t03_0031 order by id" | $MYSQL_CLIENT_CONNECT
+
+## the extra columns of the file are an error with error_on_extra_columns
+echo "set error_on_extra_columns = 1; copy into t03_0031 from 'fs://$DATADIR/' pattern = 'f3.csv' file_format = (type = csv) match_by_column_name = case_insensitive" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "are not in the table"
+
+## parquet columns are mapped by name
+echo "truncate table t03_0031" | $MYSQL_CLIENT_CONNECT
+echo "copy into t03_0031 from 'fs://$DATADIR/' pattern = 'f4.parquet' file_format = (type = parquet) match_by_column_name = case_insensitive" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id), sum(score) from t03_0031" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t03_0031" | $MYSQL_CLIENT_CONNECT
+rm -rf $DATADIR
@@ -0,0 +1,40 @@
+DROP DATABASE IF EXISTS db_03_0032;
+CREATE DATABASE db_03_0032;
+USE db_03_0032;
+
+CREATE TABLE t(a int, b varchar);
+INSERT INTO t VALUES(1, 'a'),(2, 'b'),(3, 'c');
+INSERT INTO t VALUES(4, 'd'),(5, 'e');
+
+-- delete the matched rows only
+DELETE FROM t WHERE a > 4 OR b = 'b';
+SELECT * FROM t ORDER BY a;
+
+-- blocks without matched rows are kept as they are
+DELETE FROM t WHERE a > 100;
+SELECT count(*) FROM t;
+
+-- update the matched rows, the values are cast to the type of the column
+UPDATE t SET b = 'x', a = a * 10 WHERE a >= 3;
+SELECT * FROM t ORDER BY a;
+UPDATE t SET a = '7' WHERE b = 'a';
+SELECT * FROM t ORDER BY a;
+
+-- without WHERE, all the rows are updated or deleted
+UPDATE t SET b = 'y';
+SELECT * FROM t ORDER BY a;
+DELETE FROM t;
+SELECT count(*) FROM t;
+
+-- errors
+UPDATE t SET c = 1; -- {ErrorCode 1006}
+UPDATE t SET a = 1, a = 2; -- {ErrorCode 1005}
+DELETE FROM t WHERE a IN (SELECT 1); -- {ErrorCode 1005}
+DELETE FROM t WHERE sum(a) > 1; -- {ErrorCode 1005}
+
+CREATE TABLE m(a int) ENGINE = Memory;
+DELETE FROM m WHERE a = 1; -- {ErrorCode 2703}
+
+DROP TABLE t;
+DROP TABLE m;
+DROP DATABASE db_03_0032;
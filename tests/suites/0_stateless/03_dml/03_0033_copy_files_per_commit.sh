@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATADIR=/tmp/03_0033_copy_files_per_commit
+rm -rf $DATADIR && mkdir -p $DATADIR
+printf '1,a\n2,b\n' > $DATADIR/f1.csv
+printf '3,c\n' > $DATADIR/f2.csv
+printf '4,d\n' > $DATADIR/f3.csv
+
+echo "drop table if exists t03_0033" | $MYSQL_CLIENT_CONNECT
+echo "create table t03_0033(id int, name varchar)" | $MYSQL_CLIENT_CONNECT
+
+## a commit for each two files
+echo "set copy_files_per_commit = 2; copy into t03_0033 from 'fs://$DATADIR/' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id) from t03_0033" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from fuse_snapshot('default', 't03_0033')" | $MYSQL_CLIENT_CONNECT
+
+## the committed files are recorded, a new file is loaded alone
+printf '5,e\n' > $DATADIR/f4.csv
+echo "set copy_files_per_commit = 1; copy into t03_0033 from 'fs://$DATADIR/' file_format = (type = csv)" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id) from t03_0033" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from fuse_snapshot('default', 't03_0033')" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t03_0033" | $MYSQL_CLIENT_CONNECT
+rm -rf $DATADIR
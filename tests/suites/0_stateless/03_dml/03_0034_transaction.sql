@@ -0,0 +1,42 @@
+DROP TABLE IF EXISTS t03_0034_1;
+DROP TABLE IF EXISTS t03_0034_2;
+CREATE TABLE t03_0034_1(a INT);
+CREATE TABLE t03_0034_2(a INT);
+
+-- the writes rolled back are discarded
+BEGIN;
+INSERT INTO t03_0034_1 VALUES(1);
+SELECT COUNT(*) FROM t03_0034_1;
+ROLLBACK;
+SELECT COUNT(*) FROM t03_0034_1;
+
+-- the writes to both tables are committed at once
+BEGIN;
+INSERT INTO t03_0034_1 VALUES(1),(2);
+INSERT INTO t03_0034_2 VALUES(3);
+DELETE FROM t03_0034_1 WHERE a = 1;
+SELECT SUM(a) FROM t03_0034_1;
+COMMIT;
+SELECT SUM(a) FROM t03_0034_1;
+SELECT SUM(a) FROM t03_0034_2;
+SELECT COUNT(*) FROM fuse_snapshot('default', 't03_0034_1');
+
+BEGIN;
+BEGIN; -- {ErrorCode 2016}
+TRUNCATE TABLE t03_0034_1 PURGE; -- {ErrorCode 2016}
+INSERT INTO t03_0034_1 VALUES(4);
+-- DDL, ALTER, OPTIMIZE and PROMOTE are not transactional
+CREATE TABLE t03_0034_3(a INT); -- {ErrorCode 2016}
+DROP TABLE t03_0034_2; -- {ErrorCode 2016}
+ALTER TABLE t03_0034_1 CLUSTER BY(a); -- {ErrorCode 2016}
+OPTIMIZE TABLE t03_0034_1 COMPACT; -- {ErrorCode 2016}
+ALTER TABLE t03_0034_1 PROMOTE; -- {ErrorCode 2016}
+ROLLBACK;
+SELECT SUM(a) FROM t03_0034_1;
+
+-- out of a transaction, COMMIT and ROLLBACK do nothing
+COMMIT;
+ROLLBACK;
+
+DROP TABLE t03_0034_1;
+DROP TABLE t03_0034_2;
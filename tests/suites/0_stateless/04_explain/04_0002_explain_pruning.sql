@@ -0,0 +1,14 @@
+DROP TABLE IF EXISTS t_pruning;
+CREATE TABLE t_pruning(a INT, b INT);
+
+-- one segment of one block for each insert
+INSERT INTO t_pruning VALUES (1, 1);
+INSERT INTO t_pruning VALUES (2, 2);
+INSERT INTO t_pruning VALUES (3, 3);
+
+EXPLAIN (PRUNING) SELECT * FROM t_pruning WHERE a > 2;
+EXPLAIN (PRUNING) SELECT * FROM t_pruning WHERE a > 5;
+EXPLAIN (PRUNING) SELECT * FROM t_pruning;
+EXPLAIN (PRUNING) SELECT * FROM numbers(10);
+
+DROP TABLE t_pruning;
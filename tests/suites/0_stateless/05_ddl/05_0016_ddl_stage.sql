@@ -3,7 +3,10 @@ CREATE STAGE if not exists test_stage url='s3://load/files/' credentials=(access
 CREATE STAGE test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z');  -- {ErrorCode 2502}
 
 CREATE STAGE test_stage_internal file_format=(type=csv compression=AUTO record_delimiter=NONE) comments='test';
+CREATE STAGE test_stage_invalid file_format=(type=csv record_delimiter='ab'); -- {ErrorCode 1022}
+CREATE STAGE test_stage_avro file_format=(type=avro);
 
 
 DROP STAGE test_stage;
 DROP STAGE test_stage_internal;
+DROP STAGE test_stage_avro;
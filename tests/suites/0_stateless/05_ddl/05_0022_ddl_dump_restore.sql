@@ -0,0 +1,18 @@
+DROP DATABASE IF EXISTS db_05_0022;
+DROP STAGE IF EXISTS s_05_0022;
+
+CREATE DATABASE db_05_0022;
+CREATE TABLE db_05_0022.t(a INT, b VARCHAR);
+INSERT INTO db_05_0022.t VALUES(1, 'a'), (2, 'b');
+CREATE VIEW db_05_0022.v AS SELECT a FROM db_05_0022.t;
+CREATE STAGE s_05_0022;
+
+CALL admin$dump('@s_05_0022/dump/', 'db_05_0022');
+DROP DATABASE db_05_0022;
+CALL admin$restore('@s_05_0022/dump/');
+
+SELECT * FROM db_05_0022.t ORDER BY a;
+SELECT * FROM db_05_0022.v ORDER BY a;
+
+DROP DATABASE db_05_0022;
+DROP STAGE s_05_0022;
@@ -0,0 +1,21 @@
+CREATE DATABASE IF NOT EXISTS `db_future`;
+CREATE TABLE `db_future`.`t0`(a int);
+
+CREATE ROLE 'test-future-role';
+CREATE USER 'test-future-user'@'localhost' IDENTIFIED BY 'password';
+GRANT SELECT ON FUTURE TABLES IN DATABASE db_future TO ROLE 'test-future-role';
+GRANT SELECT, INSERT ON FUTURE TABLES IN DATABASE db_future TO 'test-future-user'@'localhost';
+GRANT SELECT ON FUTURE TABLES IN DATABASE dbnotexists TO ROLE 'test-future-role'; -- {ErrorCode 1003}
+
+CREATE TABLE `db_future`.`t1`(a int);
+CREATE TABLE IF NOT EXISTS `db_future`.`t0`(a int);
+SHOW GRANTS FOR ROLE 'test-future-role';
+SHOW GRANTS FOR 'test-future-user'@'localhost';
+
+REVOKE SELECT ON FUTURE TABLES IN DATABASE db_future FROM ROLE 'test-future-role';
+CREATE TABLE `db_future`.`t2`(a int);
+SHOW GRANTS FOR ROLE 'test-future-role';
+
+DROP ROLE 'test-future-role';
+DROP USER 'test-future-user'@'localhost';
+DROP DATABASE `db_future`;
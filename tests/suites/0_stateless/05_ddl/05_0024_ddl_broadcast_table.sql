@@ -0,0 +1,22 @@
+DROP DATABASE IF EXISTS db_broadcast;
+CREATE DATABASE db_broadcast;
+USE db_broadcast;
+
+CREATE TABLE nation(n_nationkey INT, n_name VARCHAR) BROADCAST;
+CREATE TABLE customer(c_custkey INT, c_nationkey INT);
+CREATE TABLE t_invalid(a INT) BROADCAST = 'yes'; -- {ErrorCode 1022}
+CREATE TABLE t_memory(a INT) ENGINE = Memory BROADCAST; -- {ErrorCode 1022}
+
+INSERT INTO nation VALUES(1, 'FRANCE'), (2, 'GERMANY');
+INSERT INTO customer VALUES(10, 1), (20, 2), (30, 2);
+
+SELECT c_custkey, n_name FROM customer, nation WHERE c_nationkey = n_nationkey ORDER BY c_custkey;
+SELECT n_name FROM nation ORDER BY n_nationkey;
+
+-- the cached blocks are dropped by the writes
+INSERT INTO nation VALUES(3, 'JAPAN');
+SELECT n_nationkey, n_name FROM nation ORDER BY n_nationkey;
+TRUNCATE TABLE nation;
+SELECT count(*) FROM nation;
+
+DROP DATABASE db_broadcast;
@@ -0,0 +1,25 @@
+DROP DATABASE IF EXISTS db_view_deps;
+CREATE DATABASE db_view_deps;
+USE db_view_deps;
+
+CREATE TABLE t1(a INT, b INT);
+CREATE TABLE t2(a INT);
+INSERT INTO t1 VALUES (1, 2), (3, 4);
+CREATE VIEW v1 AS select a,   b from t1   where a > 1;
+CREATE VIEW v2 AS SELECT count(*) FROM t2;
+
+SHOW CREATE VIEW v1;
+SHOW CREATE TABLE v1;
+SHOW CREATE VIEW t1; -- {ErrorCode 1054}
+SELECT * FROM v1;
+
+DROP TABLE t1; -- {ErrorCode 2319}
+SELECT * FROM v1;
+DROP TABLE t1 CASCADE;
+SELECT name FROM system.tables WHERE database = 'db_view_deps' ORDER BY name;
+
+ALTER VIEW v2 AS SELECT 1;
+DROP TABLE t2;
+SELECT name FROM system.tables WHERE database = 'db_view_deps' ORDER BY name;
+
+DROP DATABASE db_view_deps;
@@ -12,7 +12,11 @@ SHOW CREATE TABLE `test`.`b`;
 CREATE TABLE test.c (a int) CLUSTER BY (a, a % 3);
 SHOW CREATE TABLE `test`.`c`;
 
+CREATE TABLE test.d (a int) PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), PARTITION p1 VALUES LESS THAN MAXVALUE) COMMENT = 'it''s';
+SHOW CREATE TABLE `test`.`d`;
+
 DROP TABLE `test`.`a`;
 DROP TABLE `test`.`b`;
 DROP TABLE `test`.`c`;
+DROP TABLE `test`.`d`;
 DROP DATABASE `test`;
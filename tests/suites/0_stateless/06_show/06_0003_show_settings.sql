@@ -1,3 +1,4 @@
 SET max_threads=11;
 SET unknown_settings=11; -- {ErrorCode 2801}
+SET executor_cpu_affinity='socket'; -- {ErrorCode 1006}
 SHOW SETTINGS;
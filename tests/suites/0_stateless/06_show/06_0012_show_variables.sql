@@ -0,0 +1,4 @@
+SET max_block_size=100;
+SHOW VARIABLES LIKE 'max_block_size';
+SHOW SESSION VARIABLES WHERE name = 'max_block_size';
+SHOW VARIABLES LIKE 'unknown_variable';
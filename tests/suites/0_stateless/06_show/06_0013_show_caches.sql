@@ -0,0 +1,7 @@
+SELECT name, unit FROM system.caches ORDER BY name;
+SYSTEM DROP CACHE query_result;
+SYSTEM DROP CACHE table_snapshot;
+SYSTEM DROP CACHE segment_info;
+SYSTEM DROP CACHE broadcast_table;
+SELECT name, size, entries FROM system.caches ORDER BY name;
+SYSTEM DROP CACHE plan; -- {ErrorCode 1006}
@@ -0,0 +1,19 @@
+DROP DATABASE IF EXISTS db_09_0016;
+CREATE DATABASE db_09_0016;
+USE db_09_0016;
+
+CREATE TABLE t(a UInt64, b VARCHAR, c Int32) Engine = Fuse;
+
+INSERT INTO t VALUES(1, 'a', -1),(2, 'b', -2);
+INSERT INTO t VALUES(3, 'c', -3),(4, 'd', -4);
+INSERT INTO t VALUES(5, 'e', -5),(6, 'f', -6);
+
+SELECT * FROM t WHERE a > 3 ORDER BY a;
+SELECT b, c FROM t WHERE a = 2;
+SELECT c, a FROM t WHERE a > 100;
+SELECT a, b FROM t WHERE b LIKE '%e' AND c < 0;
+SELECT count(*) FROM t WHERE a % 2 = 0;
+SELECT a FROM t WHERE a = 4 OR c = -1 ORDER BY a;
+
+DROP TABLE t;
+DROP DATABASE db_09_0016;
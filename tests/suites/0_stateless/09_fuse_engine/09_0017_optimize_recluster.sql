@@ -0,0 +1,31 @@
+DROP DATABASE IF EXISTS db_09_0017;
+CREATE DATABASE db_09_0017;
+USE db_09_0017;
+
+-- Create table t09_0017
+create table t09_0017(a int, b int) cluster by(b,a);
+
+insert into t09_0017 values(0,3),(1,1);
+insert into t09_0017 values(1,3),(2,1);
+insert into t09_0017 values(4,4);
+
+select * from clustering_information('db_09_0017','t09_0017');
+select `table`, cluster_by_keys, total_block_count, average_overlaps, average_depth from system.clustering where database = 'db_09_0017';
+
+optimize table t09_0017 recluster;
+
+select * from clustering_information('db_09_0017','t09_0017');
+select `table`, cluster_by_keys, total_block_count, average_overlaps, average_depth from system.clustering where database = 'db_09_0017';
+select * from t09_0017 order by b, a;
+
+-- nothing to recluster
+optimize table t09_0017 recluster;
+select count(*) from fuse_snapshot('db_09_0017', 't09_0017');
+
+-- tables without cluster key can not be reclustered
+create table t09_0017_1(a int);
+optimize table t09_0017_1 recluster; -- {ErrorCode 1006}
+
+DROP TABLE t09_0017;
+DROP TABLE t09_0017_1;
+DROP DATABASE db_09_0017;
@@ -0,0 +1,42 @@
+DROP DATABASE IF EXISTS db_09_0018;
+CREATE DATABASE db_09_0018;
+USE db_09_0018;
+
+-- one partition per distinct value
+CREATE TABLE t_value(a INT, b INT) PARTITION BY (a);
+INSERT INTO t_value VALUES(1, 1),(2, 2),(1, 3),(3, 4);
+SELECT block_count, row_count FROM fuse_snapshot('db_09_0018', 't_value');
+ALTER TABLE t_value DROP PARTITION 1, 3;
+SELECT * FROM t_value ORDER BY b;
+
+-- a block spans too many partitions
+SET max_partitions_per_insert = 2;
+INSERT INTO t_value VALUES(4, 5),(5, 6),(6, 7); -- {ErrorCode 1006}
+SET max_partitions_per_insert = 100;
+
+-- RANGE partitions
+CREATE TABLE t_range(a INT, b INT) PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), PARTITION p1 VALUES LESS THAN (20), PARTITION p2 VALUES LESS THAN MAXVALUE);
+INSERT INTO t_range VALUES(1, 1),(15, 2),(25, 3),(9, 4),(10, 5);
+SELECT block_count, row_count FROM fuse_snapshot('db_09_0018', 't_range');
+ALTER TABLE t_range DROP PARTITION p1;
+SELECT * FROM t_range ORDER BY b;
+-- the partition of the value
+ALTER TABLE t_range DROP PARTITION 30;
+SELECT * FROM t_range ORDER BY b;
+ALTER TABLE t_range DROP PARTITION p3; -- {ErrorCode 1006}
+
+-- LIST partitions
+CREATE TABLE t_list(a INT, b INT) PARTITION BY LIST (a) (PARTITION p0 VALUES IN (1, 2), PARTITION p1 VALUES IN (3));
+INSERT INTO t_list VALUES(1, 1),(3, 2),(2, 3);
+INSERT INTO t_list VALUES(4, 4); -- {ErrorCode 1010}
+SELECT block_count, row_count FROM fuse_snapshot('db_09_0018', 't_list');
+ALTER TABLE t_list DROP PARTITION p0;
+SELECT * FROM t_list ORDER BY b;
+
+-- invalid partitions
+CREATE TABLE t_invalid(a INT) PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (20), PARTITION p1 VALUES LESS THAN (10)); -- {ErrorCode 1022}
+CREATE TABLE t_invalid(a INT) PARTITION BY LIST (a) (PARTITION p0 VALUES IN (1), PARTITION p1 VALUES IN (1)); -- {ErrorCode 1022}
+-- the partitions are only set by PARTITION BY
+CREATE TABLE t_invalid(a INT) PARTITION_BY = 'a' PARTITIONS = '{"List":[["p0",["1"]]]}'; -- {ErrorCode 1005}
+
+DROP DATABASE db_09_0018;
@@ -0,0 +1,16 @@
+DROP DATABASE IF EXISTS db_09_0019;
+CREATE DATABASE db_09_0019;
+USE db_09_0019;
+
+-- TTL must be a Date or Timestamp
+CREATE TABLE t1(a INT) TTL a; -- {ErrorCode 1022}
+
+CREATE TABLE t(a INT, expire_at TIMESTAMP NULL) TTL expire_at;
+INSERT INTO t VALUES(1, '2000-01-01 00:00:00'),(2, '2999-01-01 00:00:00'),(3, NULL);
+INSERT INTO t VALUES(4, '2001-01-01 00:00:00'),(5, NULL);
+
+-- only the expired rows are dropped, the rows without TTL are kept
+OPTIMIZE TABLE t COMPACT;
+SELECT a FROM t ORDER BY a;
+
+DROP DATABASE db_09_0019;
@@ -0,0 +1,13 @@
+DROP TABLE IF EXISTS t12_0005;
+CREATE TABLE t12_0005(c int) ENGINE = Memory;
+INSERT INTO t12_0005 VALUES(1);
+
+SELECT * FROM t12_0005 AT (SNAPSHOT => 'abc'); -- {ErrorCode 2703}
+
+SET enable_planner_v2 = 1;
+SELECT * FROM t12_0005 AT (SNAPSHOT => 'abc'); -- {ErrorCode 2703}
+SET enable_planner_v2 = 0;
+
+SELECT c FROM t12_0005;
+
+DROP TABLE t12_0005;
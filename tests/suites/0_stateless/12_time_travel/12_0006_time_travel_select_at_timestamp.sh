@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0006
+echo "create table t12_0006(c int)" | $MYSQL_CLIENT_CONNECT
+echo "two insertions"
+echo "insert into t12_0006 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0006 values(3)" | $MYSQL_CLIENT_CONNECT
+echo "latest snapshot should contain 3 rows"
+echo "select count(*)  from t12_0006" | $MYSQL_CLIENT_CONNECT
+
+## Get the timestamp of the snapshot of the first insertion
+TIMESTAMP=$(echo "select timestamp from table_snapshots('default','t12_0006') where row_count=2 " | $MYSQL_CLIENT_CONNECT)
+
+echo "planner_v2: counting the data set at the time of first insertion, which should contains 2 rows"
+echo "set enable_planner_v2 = 1;select count(t.c) from t12_0006 at (timestamp => '$TIMESTAMP') as t" | $MYSQL_CLIENT_CONNECT
+
+echo "planner_v2: the time point could be an expression"
+echo "set enable_planner_v2 = 1;select count(*) from t12_0006 at (timestamp => now())" | $MYSQL_CLIENT_CONNECT
+
+echo "planner_v2: no historical data before the first insertion"
+echo "set enable_planner_v2 = 1;select count(*) from t12_0006 at (timestamp => '2000-01-01 00:00:00')" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table  t12_0006" | $MYSQL_CLIENT_CONNECT
@@ -251,6 +251,8 @@ create table t2(a int, b int);
 explain select t1.a from t1 where a > 0;
 select '===Explain Pipeline===';
 explain pipeline select t1.a from t1 join t2 on t1.a = t2.a;
+select '===Explain Graph===';
+explain (format graph) select t1.a from t1 where a > 0;
 drop table t1;
 drop table t2;
 -- position function
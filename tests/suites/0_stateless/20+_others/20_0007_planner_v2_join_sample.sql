@@ -0,0 +1,21 @@
+set enable_planner_v2 = 1;
+set join_sample_blocks = 2;
+
+drop table if exists t_small;
+drop table if exists t_large;
+create table t_small(a int);
+create table t_large(b int);
+insert into t_small values (1), (2), (3);
+insert into t_large select number from numbers(100);
+
+select * from t_small inner join t_large on t_small.a = t_large.b order by a;
+select * from t_large inner join t_small on t_small.a = t_large.b order by a;
+select count(*) from t_small inner join t_large on t_small.a = t_large.b inner join t_large as t3 on t_large.b = t3.b;
+
+set join_sample_timeout_ms = 0;
+select * from t_small inner join t_large on t_small.a = t_large.b order by a;
+
+drop table t_small;
+drop table t_large;
+set join_sample_blocks = 0;
+set enable_planner_v2 = 0;
@@ -0,0 +1,18 @@
+set enable_planner_v2 = 1;
+
+select '====DISTINCT====';
+select distinct number % 3 as a from numbers(100) order by a;
+select count() from (select distinct number % 1000 as a, number % 7 as b from numbers_mt(100000));
+
+select '====GROUP BY WITHOUT AGGREGATION====';
+select number % 3 as a, number % 2 as b from numbers(100) group by a, b order by a, b;
+
+select '====DISTINCT ON====';
+select distinct on (a) number % 3 as a, number as b from numbers(10) order by a, b desc;
+select distinct on (number % 3) number from numbers(10); -- {ErrorCode 1065}
+
+select '====DISTINCT SPILLING====';
+set max_distinct_memory_usage = 1024;
+select count(), sum(a) from (select distinct number % 5000 as a from numbers_mt(100000));
+select distinct number % 3 as a from numbers(100) order by a;
+set max_distinct_memory_usage = 0;
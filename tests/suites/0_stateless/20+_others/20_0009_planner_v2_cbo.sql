@@ -0,0 +1,20 @@
+set enable_planner_v2 = 1;
+set enable_cbo = 1;
+
+drop table if exists t_small;
+drop table if exists t_large;
+create table t_small(a int);
+create table t_large(b int);
+insert into t_small values (1), (2), (3);
+insert into t_large select number from numbers(100);
+
+explain select a from t_small inner join t_large on t_small.a = t_large.b;
+select * from t_small inner join t_large on t_small.a = t_large.b order by a;
+select * from t_large inner join t_small on t_small.a = t_large.b order by a;
+select count(*) from t_small inner join t_large on t_small.a = t_large.b inner join t_large as t3 on t_large.b = t3.b;
+select count(*) from t_large inner join t_large as t3 on t_large.b = t3.b inner join t_small on t3.b = t_small.a;
+
+drop table t_small;
+drop table t_large;
+set enable_cbo = 0;
+set enable_planner_v2 = 0;
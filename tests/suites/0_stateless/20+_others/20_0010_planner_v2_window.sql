@@ -0,0 +1,20 @@
+set enable_planner_v2 = 1;
+
+select '====RANKING====';
+select number % 2 as a, number as b, row_number() over (partition by number % 2 order by number) from numbers(6) order by a, b;
+select number % 3 as a, rank() over (order by number % 3), dense_rank() over (order by number % 3) from numbers(6) order by a;
+
+select '====LAG LEAD====';
+select number, lag(number) over (order by number), lead(number, 2, 0) over (order by number) from numbers(5) order by number;
+
+select '====AGGREGATE====';
+select number, sum(number) over (order by number rows between 1 preceding and 1 following), sum(number) over (order by number) from numbers(5) order by number;
+select number, max(number) over (partition by number % 2), min(number) over (partition by number % 2) from numbers(4) order by number;
+select number % 2 as a, sum(number), sum(sum(number)) over (order by number % 2) from numbers(4) group by a order by a;
+select number, sum(number) over (partition by number % 2 order by number rows between unbounded preceding and 1 following), sum(number) over (order by number % 3) from numbers(6) order by number;
+
+select '====ERROR====';
+select row_number() over (order by row_number() over ()) from numbers(2); -- {ErrorCode 1065}
+select foo(number) over () from numbers(2); -- {ErrorCode 1065}
+
+set enable_planner_v2 = 0;
@@ -0,0 +1,28 @@
+set enable_planner_v2 = 1;
+
+select '====INLINE====';
+with t as (select number as a from numbers(3)) select a from t order by a;
+with t(x, y) as (select number, number + 1 from numbers(2)) select x, y from t order by x;
+with t as (select number as a from numbers(3)) select t1.a, t2.a from t as t1, t as t2 where t1.a = t2.a order by t1.a;
+with t1 as (select 1 as a), t2 as (select a + 1 as b from t1) select b from t2;
+with t as (select 'it''s' as a) select a from t;
+
+select '====MATERIALIZED====';
+set enable_materialized_cte = 1;
+with t as (select number as a from numbers(3)) select t1.a, t2.a from t as t1, t as t2 where t1.a = t2.a order by t1.a;
+with t(x) as (select number from numbers(3)) select sum(x) from t;
+set enable_materialized_cte = 0;
+
+select '====RECURSIVE====';
+with recursive t(n) as (select 1 union all select n + 1 from t where n < 5) select n from t order by n;
+with recursive t(n, f) as (select 1, 1 union all select n + 1, f * (n + 1) from t where n < 5) select n, f from t order by n;
+set max_recursion_depth = 10;
+with recursive t(n) as (select 1 union all select n + 1 from t) select n from t; -- {ErrorCode 1006}
+set max_recursion_depth = 1000;
+
+select '====ERROR====';
+with t as (select 1), t as (select 2) select * from t; -- {ErrorCode 1065}
+with t(a, b) as (select 1) select * from t; -- {ErrorCode 1065}
+with t1 as (select a from t2), t2 as (select 1 as a) select * from t1; -- {ErrorCode 1025}
+
+set enable_planner_v2 = 0;
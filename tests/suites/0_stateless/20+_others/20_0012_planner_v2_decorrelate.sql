@@ -0,0 +1,34 @@
+set enable_planner_v2 = 1;
+
+drop table if exists t1;
+create table t1(a UInt64, b UInt64);
+insert into t1 values (1, 10), (1, 20), (2, 5), (5, 1);
+
+select '====EXISTS====';
+select * from numbers(4) as t where exists (select * from t1 where t1.a = t.number) order by number;
+select * from numbers(4) as t where exists (select * from t1 where t1.a = t.number and t1.b > 5) order by number;
+
+select '====NOT EXISTS====';
+select * from numbers(4) as t where not exists (select * from t1 where t1.a = t.number) order by number;
+
+select '====SCALAR====';
+select number, (select max(b) from t1 where t1.a = t.number) from numbers(4) as t order by number;
+select number from numbers(4) as t where (select min(b) from t1 where t.number = t1.a) > 5 order by number;
+
+select '====NULLABLE====';
+drop table if exists t2;
+drop table if exists t3;
+create table t2(a UInt64 null, b UInt64);
+create table t3(c UInt64 null);
+insert into t2 values (1, 10), (NULL, 7), (2, 5);
+insert into t3 values (1), (NULL), (3);
+select c from t3 where exists (select * from t2 where t2.a = t3.c) order by c;
+select count(*) from t3 where not exists (select * from t2 where t2.a = t3.c);
+select c from t3 where c is null and not exists (select * from t2 where t2.a = t3.c);
+select c, (select max(b) from t2 where t2.a = t3.c) from t3 where c is null;
+select c, (select max(b) from t2 where t2.a = t3.c) from t3 where c is not null order by c;
+
+drop table t1;
+drop table t2;
+drop table t3;
+set enable_planner_v2 = 0;
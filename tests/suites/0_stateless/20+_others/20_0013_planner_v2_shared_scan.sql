@@ -0,0 +1,28 @@
+set enable_planner_v2 = 1;
+set enable_shared_scan = 1;
+
+drop table if exists t_shared_scan;
+create table t_shared_scan(a int, b int);
+insert into t_shared_scan values (1, 10), (2, 20), (3, 30);
+
+select '====SELF JOIN====';
+select t1.a, t2.b from t_shared_scan t1 join t_shared_scan t2 on t1.a = t2.a order by t1.a;
+select t1.a, t2.a, t3.b from t_shared_scan t1 join t_shared_scan t2 on t1.a = t2.a + 1 join t_shared_scan t3 on t2.a = t3.a order by t1.a;
+
+select '====SCALAR SUBQUERY====';
+select a from t_shared_scan where b > (select avg(b) from t_shared_scan) order by a;
+
+select '====SPILLING====';
+drop table if exists t_shared_scan_big;
+create table t_shared_scan_big(a bigint);
+insert into t_shared_scan_big select number from numbers(100000);
+set max_memory_usage = 67108864;
+set shared_scan_spilling_ratio = 1;
+select count(), sum(t2.a) from t_shared_scan_big t1 join t_shared_scan_big t2 on t1.a = t2.a;
+set shared_scan_spilling_ratio = 0;
+set max_memory_usage = 0;
+
+drop table t_shared_scan;
+drop table t_shared_scan_big;
+set enable_shared_scan = 0;
+set enable_planner_v2 = 0;
@@ -0,0 +1,43 @@
+set enable_planner_v2 = 1;
+
+drop table if exists t_outer1;
+drop table if exists t_outer2;
+create table t_outer1(a int, b int);
+create table t_outer2(a int, c int);
+insert into t_outer1 values (1, 10), (2, 20), (3, 30);
+insert into t_outer2 values (2, 200), (3, 300), (3, 301), (4, 400);
+
+select '==left join==';
+select t_outer1.a, b, t_outer2.a, c from t_outer1 left join t_outer2 on t_outer1.a = t_outer2.a order by t_outer1.a, c;
+select '==right join==';
+select t_outer1.a, b, t_outer2.a, c from t_outer1 right join t_outer2 on t_outer1.a = t_outer2.a order by t_outer2.a, c;
+select '==full join==';
+select t_outer1.a, b, t_outer2.a, c from t_outer1 full join t_outer2 on t_outer1.a = t_outer2.a order by t_outer1.a, c;
+select t_outer1.a, t_outer2.a from t_outer1 left join t_outer2 on t_outer1.a = t_outer2.a and b > 10; -- {ErrorCode 1002}
+
+select '==nullable keys==';
+drop table if exists t_outer3;
+drop table if exists t_outer4;
+create table t_outer3(a int null, d int null);
+create table t_outer4(a int null, e int null);
+insert into t_outer3 values (1, 1), (null, 2), (3, 3), (null, 4);
+insert into t_outer4 values (1, 10), (null, 20), (4, 40);
+select t_outer1.a, b, t_outer3.a, d from t_outer1 left join t_outer3 on t_outer1.a = t_outer3.a order by t_outer1.a, d;
+select t_outer3.a, d, t_outer1.a, b from t_outer3 left join t_outer1 on t_outer3.a = t_outer1.a order by d;
+select t_outer1.a, b, t_outer3.a, d from t_outer1 right join t_outer3 on t_outer1.a = t_outer3.a order by d;
+select t_outer1.a, b, t_outer3.a, d from t_outer1 full join t_outer3 on t_outer1.a = t_outer3.a order by t_outer1.a, d;
+select t_outer3.a, d, t_outer4.a, e from t_outer3 full join t_outer4 on t_outer3.a = t_outer4.a order by d, e;
+
+select '==hash distribution==';
+set broadcast_join_threshold = 0;
+explain select t_outer1.a from t_outer1 inner join t_outer2 on t_outer1.a = t_outer2.a;
+select t_outer1.a, b, c from t_outer1 inner join t_outer2 on t_outer1.a = t_outer2.a order by c;
+select t_outer1.a, b, t_outer2.a, c from t_outer1 full join t_outer2 on t_outer1.a = t_outer2.a order by t_outer1.a, c;
+select t_outer3.a, d, t_outer4.a, e from t_outer3 full join t_outer4 on t_outer3.a = t_outer4.a order by d, e;
+set broadcast_join_threshold = 1000000;
+
+drop table t_outer1;
+drop table t_outer2;
+drop table t_outer3;
+drop table t_outer4;
+set enable_planner_v2 = 0;
@@ -0,0 +1,31 @@
+set enable_planner_v2 = 1;
+
+drop table if exists t_rf_probe;
+drop table if exists t_rf_build;
+create table t_rf_probe(a int, b varchar);
+create table t_rf_build(a int, c int);
+insert into t_rf_probe values (1, 'a'), (2, 'b'), (3, 'c');
+insert into t_rf_probe values (10, 'j'), (11, 'k'), (12, 'l');
+insert into t_rf_probe values (20, 't'), (21, 'u'), (null, 'n');
+insert into t_rf_build values (2, 200), (11, 1100), (11, 1101), (30, 3000), (null, 0);
+
+select '==inner join==';
+select t_rf_probe.a, b, c from t_rf_probe inner join t_rf_build on t_rf_probe.a = t_rf_build.a order by c;
+select '==right join==';
+select t_rf_probe.a, b, t_rf_build.a, c from t_rf_probe right join t_rf_build on t_rf_probe.a = t_rf_build.a order by c;
+select '==left join==';
+select t_rf_probe.a, b, c from t_rf_probe left join t_rf_build on t_rf_probe.a = t_rf_build.a order by t_rf_probe.a, c;
+select '==empty build side==';
+select count(*) from t_rf_probe inner join (select * from t_rf_build where c < 0) t on t_rf_probe.a = t.a;
+select '==hash distribution==';
+set broadcast_join_threshold = 0;
+select t_rf_probe.a, b, c from t_rf_probe inner join t_rf_build on t_rf_probe.a = t_rf_build.a order by c;
+set broadcast_join_threshold = 1000000;
+select '==disabled==';
+set enable_runtime_filter = 0;
+select t_rf_probe.a, b, c from t_rf_probe inner join t_rf_build on t_rf_probe.a = t_rf_build.a order by c;
+set enable_runtime_filter = 1;
+
+drop table t_rf_probe;
+drop table t_rf_build;
+set enable_planner_v2 = 0;
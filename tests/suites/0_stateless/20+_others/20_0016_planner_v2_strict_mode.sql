@@ -0,0 +1,20 @@
+set enable_planner_v2 = 1;
+
+select '==default==';
+select 7 / 2, 7 div 2, -7 / 2;
+select 18446744073709551615 + 1;
+select 1 / 0;
+select cast(number + 300 as UInt8) from numbers(1);
+
+select '==strict==';
+set sql_strict_mode = 1;
+select 7 / 2, 7 div 2, -7 / 2, 7.0 / 2;
+select 18446744073709551615 + 1; -- {ErrorCode 1049}
+select number - 9223372036854775807 - 2 from numbers(1); -- {ErrorCode 1049}
+select 1 / 0; -- {ErrorCode 1006}
+select 1.0 / 0; -- {ErrorCode 1006}
+select cast(number + 300 as UInt8) from numbers(1); -- {ErrorCode 1010}
+select try_cast(number + 300 as UInt8) from numbers(1);
+set sql_strict_mode = 0;
+
+set enable_planner_v2 = 0;
@@ -0,0 +1,3 @@
+SELECT number FROM numbers(3); -- { rows 3 } { result_hash b78a1987bcbdc0903ba6ba29ee3e1f4e7cc1ca868a60889beb141e26e06cb005 }
+SELECT number FROM numbers(3) WHERE number > 5; -- { rows 0 }
+SELECT * FROM numbers(2); -- { rows 2 }
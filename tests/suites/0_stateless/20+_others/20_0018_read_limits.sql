@@ -0,0 +1,24 @@
+set max_bytes_to_read = 800;
+select count(*) from numbers(100);
+select count(*) from numbers(101); -- {ErrorCode 1105}
+set max_bytes_to_read = 0;
+select count(*) from numbers(1000);
+
+DROP DATABASE IF EXISTS db_20_0018;
+CREATE DATABASE db_20_0018;
+USE db_20_0018;
+CREATE TABLE t(a UInt64);
+INSERT INTO t VALUES(1);
+INSERT INTO t VALUES(2);
+INSERT INTO t VALUES(3);
+
+set max_partitions_to_read = 2;
+select count(*) from t where a > 0; -- {ErrorCode 1105}
+select a from t where a = 2;
+set enable_planner_v2 = 1;
+select count(*) from t where a > 0; -- {ErrorCode 1105}
+set enable_planner_v2 = 0;
+set max_partitions_to_read = 0;
+select count(*) from t where a > 0;
+
+DROP DATABASE db_20_0018;
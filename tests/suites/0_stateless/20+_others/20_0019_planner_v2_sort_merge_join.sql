@@ -0,0 +1,26 @@
+set enable_planner_v2 = 1;
+
+drop table if exists t_smj_left;
+drop table if exists t_smj_right;
+create table t_smj_left(a int, b varchar);
+create table t_smj_right(a int, c int);
+insert into t_smj_left values (1, 'a'), (2, 'b'), (3, 'c');
+insert into t_smj_left values (11, 'k'), (11, 'kk'), (20, 't'), (null, 'n');
+insert into t_smj_right values (2, 200), (11, 1100), (11, 1101), (30, 3000), (null, 0);
+
+-- Both sides exceed the threshold, every sorted block is spilled
+set sort_merge_join_threshold = 1;
+select '==inner join==';
+select t_smj_left.a, b, c from t_smj_left inner join t_smj_right on t_smj_left.a = t_smj_right.a order by b, c;
+select '==left join==';
+select t_smj_left.a, b, c from t_smj_left left join t_smj_right on t_smj_left.a = t_smj_right.a order by b, c;
+select '==empty right side==';
+select count(*) from t_smj_left inner join (select * from t_smj_right where c < 0) t on t_smj_left.a = t.a;
+select '==hash join==';
+set sort_merge_join_threshold = 1000000000;
+select t_smj_left.a, b, c from t_smj_left inner join t_smj_right on t_smj_left.a = t_smj_right.a order by b, c;
+set sort_merge_join_threshold = 0;
+
+drop table t_smj_left;
+drop table t_smj_right;
+set enable_planner_v2 = 0;
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "drop STAGE if exists s_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s_ordered;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_ordered (id INT, name VARCHAR);" | $MYSQL_CLIENT_CONNECT
+
+for i in `seq 1 5`;do
+    echo "insert into test_ordered (id,name) values($i,'a'), ($((i + 10)), 'b');" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "copy into @s_ordered from (select id, name from test_ordered order by id) FILE_FORMAT = (type = 'CSV') ORDERED = TRUE;" | $MYSQL_CLIENT_CONNECT | awk '{print $2}'
+echo "list @s_ordered;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
+
+echo "copy into @s_ordered from (select id, name from test_ordered) FILE_FORMAT = (type = 'CSV') ORDERED = TRUE;" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -c "requires the query to have ORDER BY"
+
+echo "drop STAGE s_ordered;" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_ordered;" | $MYSQL_CLIENT_CONNECT
+
+aws --endpoint-url http://127.0.0.1:9900/ s3 rm s3://testbucket/admin/stage/s_ordered --recursive  > /dev/null 2>&1
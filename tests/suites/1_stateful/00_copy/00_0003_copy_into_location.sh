@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+aws --endpoint-url http://127.0.0.1:9900/ s3 rm s3://testbucket/admin/unload/ --recursive > /dev/null 2>&1
+
+## Unload 100000 rows of 800KB into files of 100KB at most, the sum of the rows of the files.
+echo "copy into 's3://testbucket/admin/unload/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') from (select number from numbers(100000)) FILE_FORMAT = (type = 'CSV') MAX_FILE_SIZE = 100000;" | $MYSQL_CLIENT_CONNECT | awk '{rows += $2; files += 1} END {print rows, (files > 1)}'
+
+## Load the files back.
+echo "drop table if exists t_unload;" | $MYSQL_CLIENT_CONNECT
+echo "create table t_unload(number bigint unsigned);" | $MYSQL_CLIENT_CONNECT
+echo "copy into t_unload from 's3://testbucket/admin/unload/' credentials=(aws_key_id='minioadmin' aws_secret_key='minioadmin') FILE_FORMAT = (type = 'CSV');" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(number) from t_unload;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t_unload;" | $MYSQL_CLIENT_CONNECT
+aws --endpoint-url http://127.0.0.1:9900/ s3 rm s3://testbucket/admin/unload/ --recursive > /dev/null 2>&1
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists test_spool;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists test_spool_copy;" | $MYSQL_CLIENT_CONNECT
+echo "drop STAGE if exists s_spool;" | $MYSQL_CLIENT_CONNECT
+echo "CREATE STAGE s_spool;" | $MYSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_spool (id INT, name VARCHAR);" | $MYSQL_CLIENT_CONNECT
+echo "CREATE TABLE test_spool_copy (id INT, name VARCHAR);" | $MYSQL_CLIENT_CONNECT
+
+for i in `seq 1 5`;do
+    echo "insert into test_spool (id,name) values($i,'a'), ($((i + 10)), 'b');" | $MYSQL_CLIENT_CONNECT
+done
+
+# one file, the rows are reported
+echo "select id, name from test_spool order by id into @s_spool/csv/ FILE_FORMAT = (type = 'CSV');" | $MYSQL_CLIENT_CONNECT | awk '{print $2}'
+echo "list @s_spool/csv/;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
+
+# the files are rolled by MAX_FILE_SIZE
+echo "select id, name from test_spool into @s_spool/parquet/ FILE_FORMAT = (type = 'PARQUET') MAX_FILE_SIZE = 1;" | $MYSQL_CLIENT_CONNECT | awk '{s += $2} END {print s}'
+echo "list @s_spool/parquet/;" | $MYSQL_CLIENT_CONNECT | wc -l | sed 's/ //g'
+
+# the files can be loaded back
+echo "copy into test_spool_copy from @s_spool/parquet/ FILE_FORMAT = (type = 'PARQUET');" | $MYSQL_CLIENT_CONNECT
+echo "select count(*), sum(id) from test_spool_copy;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop STAGE s_spool;" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_spool;" | $MYSQL_CLIENT_CONNECT
+echo "drop table test_spool_copy;" | $MYSQL_CLIENT_CONNECT
+
+aws --endpoint-url http://127.0.0.1:9900/ s3 rm s3://testbucket/admin/stage/s_spool --recursive  > /dev/null 2>&1
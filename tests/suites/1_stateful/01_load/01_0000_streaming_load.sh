@@ -65,4 +65,24 @@ curl -H "insert_sql:insert into ontime_streaming_load format NdJson" -H "skip_he
 echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
 echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
 
+# load csv sent as a chunked body, with the table and format in the headers
+curl -H "database:default" -H "table:ontime_streaming_load" -H "format:Csv" -H "skip_header:1" -H "Transfer-Encoding: chunked" -T /tmp/ontime_200.csv -u root: "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+
+# load csv gz with the file format of the stage
+echo "create stage if not exists s_streaming_load file_format=(type=csv skip_header=1 compression=gzip);" | $MYSQL_CLIENT_CONNECT
+curl -H "insert_sql:insert into ontime_streaming_load format Csv" -H "stage_name:s_streaming_load" -F "upload=@/tmp/ontime_200.csv.gz" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s_streaming_load;" | $MYSQL_CLIENT_CONNECT
+
+# load csv and parquet with the columns mapped by name
+curl -H "insert_sql:insert into ontime_streaming_load format Csv" -H "match_by_column_name:case_insensitive" -F "upload=@/tmp/ontime_200.csv" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+curl -H "insert_sql:insert into ontime_streaming_load format Parquet" -H "match_by_column_name:case_sensitive" -F "upload=@/tmp/ontime_200.parquet" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(1) ,avg(Year), sum(DayOfWeek)  from ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT
+echo "truncate table ontime_streaming_load" | $MYSQL_CLIENT_CONNECT
+
 echo "drop table ontime_streaming_load;" | $MYSQL_CLIENT_CONNECT